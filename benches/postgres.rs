@@ -447,6 +447,49 @@ fn bench_sync(c: &mut Criterion) {
     }
     scale.finish();
 
+    let mut decode = c.benchmark_group("sync/decode");
+    decode.throughput(Throughput::Elements(1_000));
+    let mut conn = ps_raw();
+    for i in 0..1_000 {
+        conn.execute(
+            "INSERT INTO bench_users (name, email) VALUES ($1, $2)",
+            &[&format!("User {}", i), &format!("user{}@x.dev", i)],
+        )
+        .expect("seed");
+    }
+    let stmt = conn
+        .prepare("SELECT id, name, email FROM bench_users")
+        .expect("prepare");
+    decode.bench_function("manual", |b| {
+        b.iter(|| {
+            let rows = conn
+                .query(&stmt, &[])
+                .expect("query")
+                .iter()
+                .map(|r| {
+                    (
+                        r.get::<_, i32>(0),
+                        r.get::<_, String>(1),
+                        r.get::<_, String>(2),
+                    )
+                })
+                .collect::<Vec<_>>();
+            black_box(rows);
+        });
+    });
+    decode.bench_function("from_row", |b| {
+        b.iter(|| {
+            let rows = conn
+                .query(&stmt, &[])
+                .expect("query")
+                .iter()
+                .map(|r| SelectUser::try_from(r).expect("decode"))
+                .collect::<Vec<_>>();
+            black_box(rows);
+        });
+    });
+    decode.finish();
+
     let _ = ps_raw_blog;
 }
 
@@ -0,0 +1,240 @@
+//! Query building and SQL rendering without a database: the cost drizzle adds
+//! on top of the driver, isolated from I/O.
+
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+use criterion::{BatchSize, Throughput};
+use criterion::{Criterion, criterion_group, criterion_main};
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+use std::hint::black_box;
+
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+use drizzle::core::expr::eq;
+
+/// One hub table plus ten spokes referencing it, for the 10-join select.
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+macro_rules! join_tables {
+    ($table:ident, $schema_derive:ident, $($spoke:ident => $name:literal),+ $(,)?) => {
+        #[$table(name = "bench_hub")]
+        struct Hub {
+            #[column(primary)]
+            id: i32,
+            name: String,
+        }
+
+        $(
+            #[$table(name = $name)]
+            struct $spoke {
+                #[column(primary)]
+                id: i32,
+                hub_id: i32,
+                label: String,
+            }
+        )+
+
+        #[derive($schema_derive)]
+        #[allow(non_snake_case)]
+        struct JoinSchema {
+            hub: Hub,
+            $($spoke: $spoke,)+
+        }
+    };
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use super::*;
+    use drizzle::sqlite::builder::QueryBuilder;
+    use drizzle::sqlite::prelude::*;
+
+    #[SQLiteTable(name = "bench_users")]
+    pub struct User {
+        #[column(primary)]
+        id: i32,
+        name: String,
+        email: String,
+    }
+
+    #[derive(SQLiteSchema)]
+    pub struct Schema {
+        user: User,
+    }
+
+    join_tables!(
+        SQLiteTable, SQLiteSchema,
+        S1 => "bench_s1", S2 => "bench_s2", S3 => "bench_s3", S4 => "bench_s4",
+        S5 => "bench_s5", S6 => "bench_s6", S7 => "bench_s7", S8 => "bench_s8",
+        S9 => "bench_s9", S10 => "bench_s10",
+    );
+
+    pub fn bench(c: &mut Criterion) {
+        let Schema { user } = Schema::new();
+        let builder = QueryBuilder::new::<Schema>();
+
+        let mut g = c.benchmark_group("sqlite/render");
+        g.bench_function("select_simple", |b| {
+            b.iter(|| {
+                let query = builder
+                    .select((user.id, user.name, user.email))
+                    .from(user)
+                    .r#where(eq(user.id, 1));
+                black_box(query.to_sql().build().0);
+            });
+        });
+
+        let JoinSchema {
+            hub,
+            S1: s1,
+            S2: s2,
+            S3: s3,
+            S4: s4,
+            S5: s5,
+            S6: s6,
+            S7: s7,
+            S8: s8,
+            S9: s9,
+            S10: s10,
+        } = JoinSchema::new();
+        let join_builder = QueryBuilder::new::<JoinSchema>();
+        g.bench_function("select_10_joins", |b| {
+            b.iter(|| {
+                let query = join_builder
+                    .select((hub.name, s1.label, s5.label, s10.label))
+                    .from(hub)
+                    .join((s1, eq(hub.id, s1.hub_id)))
+                    .join((s2, eq(hub.id, s2.hub_id)))
+                    .join((s3, eq(hub.id, s3.hub_id)))
+                    .join((s4, eq(hub.id, s4.hub_id)))
+                    .join((s5, eq(hub.id, s5.hub_id)))
+                    .join((s6, eq(hub.id, s6.hub_id)))
+                    .join((s7, eq(hub.id, s7.hub_id)))
+                    .join((s8, eq(hub.id, s8.hub_id)))
+                    .join((s9, eq(hub.id, s9.hub_id)))
+                    .join((s10, eq(hub.id, s10.hub_id)))
+                    .r#where(eq(hub.id, 1));
+                black_box(query.to_sql().build().0);
+            });
+        });
+
+        g.throughput(Throughput::Elements(1_000));
+        g.bench_function("insert_1000", |b| {
+            b.iter_batched(
+                || {
+                    (0..1_000)
+                        .map(|i| InsertUser::new(format!("User {i}"), format!("user{i}@x.dev")))
+                        .collect::<Vec<_>>()
+                },
+                |rows| {
+                    let query = builder.insert(user).values(rows);
+                    black_box(query.to_sql().build().0);
+                },
+                BatchSize::SmallInput,
+            );
+        });
+        g.finish();
+    }
+}
+
+#[cfg(feature = "postgres")]
+mod postgres {
+    use super::*;
+    use drizzle::postgres::builder::QueryBuilder;
+    use drizzle::postgres::prelude::*;
+
+    #[PostgresTable(name = "bench_users")]
+    pub struct User {
+        #[column(serial, primary)]
+        id: i32,
+        name: String,
+        email: String,
+    }
+
+    #[derive(PostgresSchema)]
+    pub struct Schema {
+        user: User,
+    }
+
+    join_tables!(
+        PostgresTable, PostgresSchema,
+        S1 => "bench_s1", S2 => "bench_s2", S3 => "bench_s3", S4 => "bench_s4",
+        S5 => "bench_s5", S6 => "bench_s6", S7 => "bench_s7", S8 => "bench_s8",
+        S9 => "bench_s9", S10 => "bench_s10",
+    );
+
+    pub fn bench(c: &mut Criterion) {
+        let Schema { user } = Schema::new();
+        let builder = QueryBuilder::new::<Schema>();
+
+        let mut g = c.benchmark_group("postgres/render");
+        g.bench_function("select_simple", |b| {
+            b.iter(|| {
+                let query = builder
+                    .select((user.id, user.name, user.email))
+                    .from(user)
+                    .r#where(eq(user.id, 1));
+                black_box(query.to_sql().build().0);
+            });
+        });
+
+        let JoinSchema {
+            hub,
+            S1: s1,
+            S2: s2,
+            S3: s3,
+            S4: s4,
+            S5: s5,
+            S6: s6,
+            S7: s7,
+            S8: s8,
+            S9: s9,
+            S10: s10,
+        } = JoinSchema::new();
+        let join_builder = QueryBuilder::new::<JoinSchema>();
+        g.bench_function("select_10_joins", |b| {
+            b.iter(|| {
+                let query = join_builder
+                    .select((hub.name, s1.label, s5.label, s10.label))
+                    .from(hub)
+                    .join((s1, eq(hub.id, s1.hub_id)))
+                    .join((s2, eq(hub.id, s2.hub_id)))
+                    .join((s3, eq(hub.id, s3.hub_id)))
+                    .join((s4, eq(hub.id, s4.hub_id)))
+                    .join((s5, eq(hub.id, s5.hub_id)))
+                    .join((s6, eq(hub.id, s6.hub_id)))
+                    .join((s7, eq(hub.id, s7.hub_id)))
+                    .join((s8, eq(hub.id, s8.hub_id)))
+                    .join((s9, eq(hub.id, s9.hub_id)))
+                    .join((s10, eq(hub.id, s10.hub_id)))
+                    .r#where(eq(hub.id, 1));
+                black_box(query.to_sql().build().0);
+            });
+        });
+
+        g.throughput(Throughput::Elements(1_000));
+        g.bench_function("insert_1000", |b| {
+            b.iter_batched(
+                || {
+                    (0..1_000)
+                        .map(|i| InsertUser::new(format!("User {i}"), format!("user{i}@x.dev")))
+                        .collect::<Vec<_>>()
+                },
+                |rows| {
+                    let query = builder.insert(user).values(rows);
+                    black_box(query.to_sql().build().0);
+                },
+                BatchSize::SmallInput,
+            );
+        });
+        g.finish();
+    }
+}
+
+fn bench_render(c: &mut Criterion) {
+    #[cfg(feature = "sqlite")]
+    sqlite::bench(c);
+
+    #[cfg(feature = "postgres")]
+    postgres::bench(c);
+}
+
+criterion_group!(render, bench_render);
+criterion_main!(render);
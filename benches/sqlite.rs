@@ -510,6 +510,50 @@ fn bench_rusqlite(c: &mut Criterion) {
     }
     scale.finish();
 
+    let mut decode = c.benchmark_group("rusqlite/decode");
+    decode.throughput(Throughput::Elements(1_000));
+    let conn = rs_raw();
+    for i in 0..1_000 {
+        conn.execute(
+            "INSERT INTO bench_users (name, email) VALUES (?1, ?2)",
+            [format!("User {}", i), format!("user{}@x.dev", i)],
+        )
+        .expect("seed");
+    }
+    decode.bench_function("manual", |b| {
+        let mut stmt = conn
+            .prepare("SELECT id, name, email FROM bench_users")
+            .expect("prepare");
+        b.iter(|| {
+            let rows = stmt
+                .query_map([], |r| {
+                    Ok((
+                        r.get::<_, i32>(0)?,
+                        r.get::<_, String>(1)?,
+                        r.get::<_, String>(2)?,
+                    ))
+                })
+                .expect("query")
+                .collect::<Result<Vec<_>, _>>()
+                .expect("decode");
+            black_box(rows);
+        });
+    });
+    decode.bench_function("from_row", |b| {
+        let mut stmt = conn
+            .prepare("SELECT id, name, email FROM bench_users")
+            .expect("prepare");
+        b.iter(|| {
+            let rows = stmt
+                .query_map([], |r| Ok(SelectUser::try_from(r)))
+                .expect("query")
+                .map(|row| row.expect("row").expect("decode"))
+                .collect::<Vec<_>>();
+            black_box(rows);
+        });
+    });
+    decode.finish();
+
     let _ = rs_raw_blog;
 }
 
@@ -2,7 +2,9 @@
 
 use std::path::{Path, PathBuf};
 
-use crate::config::{Config, Credentials, Dialect, PostgresCreds};
+use crate::config::{
+    Config, Credentials, Dialect, PostgresCreds, check_credentials, check_writable,
+};
 use crate::error::CliError;
 use crate::output;
 
@@ -24,8 +26,8 @@ pub struct CheckOptions {
 ///
 /// Returns a [`CliError`] if the requested database cannot be resolved from the
 /// config, if schema-file discovery fails, if the credentials block is
-/// malformed, or if a warning-as-error condition is encountered (currently only
-/// if the filesystem enumeration of the migrations directory fails).
+/// malformed or can't be parsed, if the output directory isn't writable, or if
+/// the filesystem enumeration of the migrations directory fails.
 pub fn run(config: &Config, db_name: Option<&str>, opts: &CheckOptions) -> Result<(), CliError> {
     let db = config.database(db_name)?;
 
@@ -102,14 +104,34 @@ pub fn run(config: &Config, db_name: Option<&str>, opts: &CheckOptions) -> Resul
         warnings.push("Migrations directory doesn't exist yet");
     }
 
+    // Output dir
+    println!();
+    print!("  {} Output directory... ", output::label("Checking"));
+    match check_writable(&effective_out) {
+        Ok(()) => println!("{}", output::status_ok()),
+        Err(e) => {
+            println!("{}", output::status_error());
+            println!("    {e}");
+            has_errors = true;
+        }
+    }
+
     // Credentials
     println!();
     print!("  {} Credentials... ", output::label("Checking"));
     match db.credentials() {
-        Ok(Some(creds)) => {
-            println!("{}", output::status_ok());
-            print_credentials(&creds);
-        }
+        Ok(Some(creds)) => match check_credentials(&creds) {
+            Ok(()) => {
+                println!("{}", output::status_ok());
+                print_credentials(&creds);
+            }
+            Err(e) => {
+                println!("{}", output::status_error());
+                print_credentials(&creds);
+                println!("    {e}");
+                has_errors = true;
+            }
+        },
         Ok(None) => {
             println!("{}", output::status_warning("NOT SET"));
             warnings.push("No credentials (needed for push/pull/migrate)");
@@ -171,11 +193,18 @@ fn print_credentials(creds: &Credentials) {
         Credentials::Sqlite { path } => {
             println!("    {}: {path}", output::label("SQLite"));
         }
-        Credentials::Turso { url, auth_token } => {
+        Credentials::Turso {
+            url,
+            auth_token,
+            encryption_key,
+        } => {
             println!("    {}: {}", output::label("Turso"), mask_url(url));
             if auth_token.is_some() {
                 println!("    Token: ****");
             }
+            if encryption_key.is_some() {
+                println!("    Encryption key: ****");
+            }
         }
         Credentials::Postgres(pg) => match pg {
             PostgresCreds::Url(url) => {
@@ -186,6 +215,8 @@ fn print_credentials(creds: &Credentials) {
                 port,
                 database,
                 user,
+                ssl,
+                ssl_root_cert,
                 ..
             } => {
                 println!(
@@ -195,6 +226,10 @@ fn print_credentials(creds: &Credentials) {
                 if let Some(u) = user {
                     println!("    User: {u}");
                 }
+                println!("    SSL: {}", ssl.as_str());
+                if let Some(cert) = ssl_root_cert {
+                    println!("    Root cert: {}", cert.display());
+                }
             }
         },
         Credentials::D1 {
@@ -1,22 +1,41 @@
 //! Export command implementation
 //!
-//! Exports the schema as SQL statements.
+//! Renders the schema as a single ordered SQL dump of CREATE statements,
+//! taken from the schema files, the latest migration snapshot, or the live
+//! database.
 
 use std::path::PathBuf;
 
-use crate::commands::overrides;
-use crate::config::Config;
-use crate::config::Dialect;
+use drizzle_migrations::schema::Snapshot;
+
+use crate::commands::overrides::{self, ConnectionOverrides, FilterArgs};
+use crate::config::{Config, DatabaseConfig, Dialect};
 use crate::error::CliError;
 use crate::output;
 use crate::snapshot::parse_result_to_snapshot;
 
+/// Where `drizzle export` reads the schema from.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportSource {
+    /// Parse the configured schema files
+    #[default]
+    Schema,
+    /// Use the latest snapshot in the migrations folder
+    Snapshot,
+    /// Introspect the live database
+    Database,
+}
+
 #[derive(clap::Args, Debug, Clone)]
 pub struct ExportOptions {
     /// Output SQL to a file (default: stdout)
     #[arg(long = "sql")]
     pub output_path: Option<PathBuf>,
 
+    /// Schema source to export
+    #[arg(long, value_enum, default_value_t)]
+    pub from: ExportSource,
+
     /// Override dialect from config
     #[arg(long)]
     pub dialect: Option<Dialect>,
@@ -24,6 +43,16 @@ pub struct ExportOptions {
     /// Override schema path(s)
     #[arg(long, value_delimiter = ',')]
     pub schema: Option<Vec<String>>,
+
+    /// Override migrations output directory (for `--from snapshot`)
+    #[arg(long)]
+    pub out: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub filters: FilterArgs,
+
+    #[command(flatten)]
+    pub connection: ConnectionOverrides,
 }
 
 /// Run the export command.
@@ -31,11 +60,10 @@ pub struct ExportOptions {
 /// # Errors
 ///
 /// Returns [`CliError`] if the requested database cannot be resolved, the
-/// schema files cannot be read/parsed, the resolved snapshot cannot be
-/// generated, or if writing the output SQL file fails.
+/// schema files cannot be read/parsed, the latest snapshot cannot be loaded,
+/// introspecting the live database fails, or if writing the output SQL file
+/// fails.
 pub fn run(config: &Config, db_name: Option<&str>, opts: ExportOptions) -> Result<(), CliError> {
-    use drizzle_migrations::parser::SchemaParser;
-
     let db = config.database(db_name)?;
     let effective_dialect = overrides::resolve_dialect(db, opts.dialect);
 
@@ -50,12 +78,65 @@ pub fn run(config: &Config, db_name: Option<&str>, opts: ExportOptions) -> Resul
         effective_dialect.as_str()
     );
 
+    let snapshot = match opts.from {
+        ExportSource::Schema => schema_snapshot(db, effective_dialect, opts.schema.as_deref())?,
+        ExportSource::Snapshot => latest_snapshot(db, effective_dialect, &opts)?,
+        ExportSource::Database => {
+            database_snapshot(db, effective_dialect, &opts.connection, &opts.filters)?
+        }
+    };
+    let Some(snapshot) = snapshot else {
+        return Ok(());
+    };
+
+    // Generate SQL from snapshot (create statements for all entities)
+    let sql_statements = generate_create_sql(&snapshot, db.breakpoints);
+
+    if sql_statements.is_empty() {
+        println!("{}", output::warning("No SQL statements generated."));
+        return Ok(());
+    }
+
+    let sql_content = sql_statements.join("\n\n");
+
+    // Output to file or stdout
+    if let Some(path) = opts.output_path {
+        std::fs::write(&path, &sql_content)
+            .map_err(|e| CliError::IoError(format!("Failed to write {}: {}", path.display(), e)))?;
+        println!();
+        println!(
+            "{}",
+            output::success(&format!(
+                "Exported {} SQL statement(s) to {}",
+                sql_statements.len(),
+                path.display()
+            ))
+        );
+    } else {
+        println!();
+        println!("{}", output::muted("-- Generated SQL --"));
+        println!();
+        println!("{sql_content}");
+        println!();
+        println!("{}", output::muted("-- End of SQL --"));
+    }
+
+    Ok(())
+}
+
+/// Build a snapshot from the configured schema files, or `schema` if given.
+pub(crate) fn schema_snapshot(
+    db: &DatabaseConfig,
+    dialect: Dialect,
+    schema: Option<&[String]>,
+) -> Result<Option<Snapshot>, CliError> {
+    use drizzle_migrations::parser::SchemaParser;
+
     // Parse schema files
-    let schema_files = overrides::resolve_schema_files(db, opts.schema.as_deref())?;
+    let schema_files = overrides::resolve_schema_files(db, schema)?;
     if schema_files.is_empty() {
         return Err(CliError::NoSchemaFiles(overrides::resolve_schema_display(
-            db,
-            opts.schema.as_deref(),
+            db, schema,
         )));
     }
 
@@ -80,7 +161,7 @@ pub fn run(config: &Config, db_name: Option<&str>, opts: ExportOptions) -> Resul
             "{}",
             output::warning("No tables or indexes found in schema files.")
         );
-        return Ok(());
+        return Ok(None);
     }
 
     println!(
@@ -91,42 +172,60 @@ pub fn run(config: &Config, db_name: Option<&str>, opts: ExportOptions) -> Resul
     );
 
     // Build snapshot from parsed schema (use config dialect)
-    let dialect = effective_dialect.to_base();
-    let snapshot = parse_result_to_snapshot(&parse_result, dialect, db.casing);
+    Ok(Some(parse_result_to_snapshot(
+        &parse_result,
+        dialect.to_base(),
+        db.casing,
+    )))
+}
 
-    // Generate SQL from snapshot (create statements for all entities)
-    let sql_statements = generate_create_sql(&snapshot, db.breakpoints);
+/// Load the snapshot of the most recent migration.
+fn latest_snapshot(
+    db: &DatabaseConfig,
+    dialect: Dialect,
+    opts: &ExportOptions,
+) -> Result<Option<Snapshot>, CliError> {
+    let out_dir = opts.out.as_deref().unwrap_or_else(|| db.migrations_dir());
+    let Some(path) = crate::commands::generate::latest_v3_snapshot_path(out_dir)? else {
+        println!(
+            "{}",
+            output::warning(&format!(
+                "No migration snapshots found in {}",
+                out_dir.display()
+            ))
+        );
+        return Ok(None);
+    };
 
-    if sql_statements.is_empty() {
-        println!("{}", output::warning("No SQL statements generated."));
-        return Ok(());
-    }
+    println!("  {}: {}", output::label("Snapshot"), path.display());
 
-    let sql_content = sql_statements.join("\n\n");
+    Snapshot::load(&path, dialect.to_base())
+        .map(Some)
+        .map_err(|e| CliError::IoError(format!("Failed to read {}: {}", path.display(), e)))
+}
 
-    // Output to file or stdout
-    if let Some(path) = opts.output_path {
-        std::fs::write(&path, &sql_content)
-            .map_err(|e| CliError::IoError(format!("Failed to write {}: {}", path.display(), e)))?;
-        println!();
+/// Introspect the live database, honoring the configured filters.
+pub(crate) fn database_snapshot(
+    db: &DatabaseConfig,
+    dialect: Dialect,
+    connection: &ConnectionOverrides,
+    filters: &FilterArgs,
+) -> Result<Option<Snapshot>, CliError> {
+    let Some(credentials) = overrides::resolve_credentials(db, dialect, connection)? else {
         println!(
             "{}",
-            output::success(&format!(
-                "Exported {} SQL statement(s) to {}",
-                sql_statements.len(),
-                path.display()
-            ))
+            output::warning(
+                "No database credentials configured. Set [dbCredentials] or pass --url."
+            )
         );
-    } else {
-        println!();
-        println!("{}", output::muted("-- Generated SQL --"));
-        println!();
-        println!("{sql_content}");
-        println!();
-        println!("{}", output::muted("-- End of SQL --"));
-    }
+        return Ok(None);
+    };
 
-    Ok(())
+    let filters = overrides::resolve_snapshot_filters(db, dialect, filters);
+
+    println!("  {}: live database", output::label("Source"));
+
+    crate::db::introspect_snapshot(&credentials, dialect, &filters).map(Some)
 }
 
 /// Generate CREATE SQL statements from a snapshot
@@ -161,3 +260,23 @@ fn generate_create_sql(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use drizzle_migrations::sqlite::{Column, SQLiteSnapshot, SqliteEntity, Table};
+
+    #[test]
+    fn snapshot_exports_create_statements() {
+        let mut snapshot = SQLiteSnapshot::new();
+        snapshot.add_entity(SqliteEntity::Table(Table::new("users")));
+        snapshot.add_entity(SqliteEntity::Column(
+            Column::new("users", "id", "integer").not_null(),
+        ));
+
+        let sql = generate_create_sql(&Snapshot::Sqlite(snapshot), false);
+
+        assert_eq!(sql.len(), 1);
+        assert!(sql[0].starts_with("CREATE TABLE `users`"), "{sql:?}");
+    }
+}
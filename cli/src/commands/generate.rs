@@ -3,12 +3,15 @@
 //! Generates migration files from schema changes.
 
 use std::fmt::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use crate::commands::overrides;
-use crate::config::{Casing, Config, Dialect, Driver, MigrationPrefix};
+use drizzle_migrations::TagFormat;
+use serde::Serialize;
+
+use crate::commands::{overrides, renames};
+use crate::config::{Casing, Config, Dialect, Driver};
 use crate::error::CliError;
-use crate::output;
+use crate::output::{self, outln};
 use crate::snapshot::parse_result_to_snapshot;
 
 #[derive(clap::Args, Debug, Clone)]
@@ -44,6 +47,31 @@ pub struct GenerateOptions {
     /// Override breakpoints setting
     #[arg(long)]
     pub breakpoints: Option<bool>,
+
+    /// Build indexes on existing tables with `CREATE INDEX CONCURRENTLY` (PostgreSQL)
+    #[arg(long)]
+    pub concurrently: bool,
+
+    /// Renumber migration folders that share an index (e.g. after merging
+    /// branches) instead of failing. Only safe for migrations not yet applied.
+    #[arg(long)]
+    pub renumber: bool,
+
+    /// Keep regenerating the new migration as the schema files change
+    #[arg(long, conflicts_with = "custom")]
+    pub watch: bool,
+}
+
+/// `--json` output of `drizzle generate`.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateReport {
+    /// Folder name of the new migration, `None` when nothing changed
+    tag: Option<String>,
+    /// Files written, including a regenerated `migrations.js`
+    files: Vec<PathBuf>,
+    statements: Vec<String>,
+    warnings: Vec<String>,
 }
 
 /// Run the generate command.
@@ -54,9 +82,61 @@ pub struct GenerateOptions {
 /// schema files fail to parse, snapshot/diff generation fails, or writing the
 /// new migration and journal files to disk fails.
 pub fn run(config: &Config, db_name: Option<&str>, opts: GenerateOptions) -> Result<(), CliError> {
-    use drizzle_migrations::words::{PrefixMode, generate_migration_tag_with_mode};
+    if opts.watch {
+        return watch(config, db_name, &opts);
+    }
+    let report = generate(config, db_name, opts)?;
+    output::emit_json(&report)
+}
 
+/// `generate --watch`: keep one migration in step with the schema.
+///
+/// The migration written by this session is deleted and regenerated on every
+/// change, so the session leaves a single migration holding the net change
+/// instead of one per save. Don't apply it until the session is over.
+fn watch(config: &Config, db_name: Option<&str>, opts: &GenerateOptions) -> Result<(), CliError> {
     let db = config.database(db_name)?;
+    let out_dir = opts
+        .out
+        .clone()
+        .unwrap_or_else(|| db.migrations_dir().to_path_buf());
+
+    let mut session_tag: Option<String> = None;
+    crate::commands::watch::watch(db, opts.schema.as_deref(), || {
+        if let Some(tag) = session_tag.take() {
+            std::fs::remove_dir_all(out_dir.join(&tag))
+                .map_err(|e| CliError::IoError(format!("Failed to remove {tag}: {e}")))?;
+            if db.bundle_enabled() {
+                write_migrations_js(&out_dir)?;
+            }
+        }
+
+        let report = generate(config, db_name, opts.clone())?;
+        if !report.statements.is_empty() {
+            outln!();
+            outln!("{}", output::muted("--- Migration SQL ---"));
+            outln!();
+            for stmt in &report.statements {
+                outln!("{stmt}\n");
+            }
+            outln!("{}", output::muted("--- End SQL ---"));
+        }
+        session_tag = report.tag;
+        Ok(())
+    })
+}
+
+fn generate(
+    config: &Config,
+    db_name: Option<&str>,
+    opts: GenerateOptions,
+) -> Result<GenerateReport, CliError> {
+    let db = config.database(db_name)?;
+    let tag_format = db.migration_tag_format()?;
+    if let Some(name) = opts.name.as_deref() {
+        drizzle_migrations::words::validate_migration_name(name)
+            .map_err(|e| CliError::MigrationError(e.to_string()))?;
+    }
 
     // CLI flag overrides config
     let effective_casing = opts.casing.or(db.casing);
@@ -70,15 +150,15 @@ pub fn run(config: &Config, db_name: Option<&str>, opts: GenerateOptions) -> Res
 
     crate::commands::harness::print_db_header(config, db_name);
 
-    println!("{}", output::heading("Generating migration..."));
+    outln!("{}", output::heading("Generating migration..."));
 
-    println!(
+    outln!(
         "  {}: {}",
         output::label("Dialect"),
         effective_dialect.as_str()
     );
     if let Some(driver) = effective_driver {
-        println!("  {}: {}", output::label("Driver"), driver);
+        outln!("  {}: {}", output::label("Driver"), driver);
     }
 
     // Create output directories if they don't exist
@@ -92,13 +172,15 @@ pub fn run(config: &Config, db_name: Option<&str>, opts: GenerateOptions) -> Res
         ));
     }
 
+    resolve_index_collisions(&out_dir, &tag_format, opts.renumber)?;
+
     // Handle custom migration (empty migration file for manual SQL)
     if opts.custom {
         let bundle = db.bundle_enabled();
         return generate_custom_migration(
             &out_dir,
             effective_breakpoints,
-            db.migrations.as_ref().and_then(|m| m.prefix),
+            &tag_format,
             opts.name,
             bundle,
         );
@@ -108,14 +190,14 @@ pub fn run(config: &Config, db_name: Option<&str>, opts: GenerateOptions) -> Res
     let parse_result = parse_schema_files(db, opts.schema.as_deref())?;
 
     if parse_result.tables.is_empty() && parse_result.indexes.is_empty() {
-        println!(
+        outln!(
             "{}",
             output::warning("No tables or indexes found in schema files.")
         );
-        return Ok(());
+        return Ok(GenerateReport::default());
     }
 
-    println!(
+    outln!(
         "  {} {} table(s), {} index(es)",
         output::label("Found"),
         parse_result.tables.len(),
@@ -131,46 +213,59 @@ pub fn run(config: &Config, db_name: Option<&str>, opts: GenerateOptions) -> Res
     // Load previous snapshot if exists
     let prev_snapshot = load_previous_snapshot(&out_dir, dialect)?;
 
-    // Generate diff
-    let generated = generate_diff(&prev_snapshot, &current_snapshot)?;
+    // Resolve renames (config + interactive prompts), then generate diff
+    let diff_options =
+        renames::generate_options(db.rename_hints(), &prev_snapshot, &current_snapshot)?
+            .concurrent_indexes(opts.concurrently);
+    let generated = generate_diff(&prev_snapshot, &current_snapshot, &diff_options)?;
 
     if generated.is_empty() {
-        println!("{}", output::warning("No schema changes detected 😴"));
-        return Ok(());
+        outln!("{}", output::warning("No schema changes detected 😴"));
+        return Ok(GenerateReport::default());
     }
 
-    println!(
+    outln!(
         "  {} {} SQL statement(s)",
         output::label("Generated"),
         generated.statements.len()
     );
 
-    let prefix_mode = db
-        .migrations
-        .as_ref()
-        .and_then(|m| m.prefix)
-        .map_or(PrefixMode::Timestamp, map_prefix_mode);
+    let next_idx = next_migration_index(&out_dir, &tag_format)?;
+    let migration_tag = tag_format
+        .render(next_idx, opts.name.as_deref())
+        .map_err(|e| CliError::MigrationError(e.to_string()))?;
 
-    let next_idx = next_migration_index(&out_dir)?;
-    let migration_tag =
-        generate_migration_tag_with_mode(prefix_mode, next_idx, opts.name.as_deref());
+    let migration_dir = write_migration_files(
+        &out_dir,
+        &migration_tag,
+        &generated,
+        effective_breakpoints,
+        db.format_sql,
+    )?;
 
-    let migration_dir =
-        write_migration_files(&out_dir, &migration_tag, &generated, effective_breakpoints)?;
+    let mut files = vec![
+        migration_dir.join("migration.sql"),
+        migration_dir.join("snapshot.json"),
+    ];
 
     // Regenerate {out_dir}/migrations.js bundle index when enabled.
     // Auto-enabled for driver = durable-sqlite (see `DatabaseConfig::bundle_enabled`).
     if db.bundle_enabled() {
-        write_migrations_js(&out_dir)?;
+        files.push(write_migrations_js(&out_dir)?);
     }
 
-    println!(
+    outln!(
         "{}",
         output::success(&format!("Migration generated: {migration_tag}"))
     );
-    println!("   {}", migration_dir.display());
+    outln!("   {}", migration_dir.display());
 
-    Ok(())
+    Ok(GenerateReport {
+        tag: Some(migration_tag),
+        files,
+        statements: generated.statements,
+        warnings: generated.warnings,
+    })
 }
 
 /// Resolve and parse schema files.
@@ -188,7 +283,7 @@ fn parse_schema_files(
         )));
     }
 
-    println!(
+    outln!(
         "  {} {} schema file(s)",
         output::label("Parsing"),
         schema_files.len()
@@ -211,11 +306,21 @@ fn write_migration_files(
     migration_tag: &str,
     generated: &drizzle_migrations::Plan,
     breakpoints: bool,
+    format_sql: bool,
 ) -> Result<std::path::PathBuf, CliError> {
+    let statements: Vec<String> = if format_sql {
+        generated
+            .statements
+            .iter()
+            .map(|s| drizzle_migrations::format::format_sql(s))
+            .collect()
+    } else {
+        generated.statements.clone()
+    };
     let sql_content = if breakpoints {
-        generated.statements.join("\n--> statement-breakpoint\n")
+        statements.join("\n--> statement-breakpoint\n")
     } else {
-        generated.statements.join("\n\n")
+        statements.join("\n\n")
     };
 
     drizzle_migrations::writer::publish_migration_directory(out_dir, migration_tag, |folder| {
@@ -233,21 +338,18 @@ fn write_migration_files(
 fn generate_custom_migration(
     out_dir: &Path,
     _breakpoints: bool,
-    prefix: Option<MigrationPrefix>,
+    tag_format: &TagFormat,
     name: Option<String>,
     bundle: bool,
-) -> Result<(), CliError> {
-    use drizzle_migrations::words::{PrefixMode, generate_migration_tag_with_mode};
-
+) -> Result<GenerateReport, CliError> {
     let custom_name = name.unwrap_or_else(|| "custom".to_string());
 
-    let prefix_mode = prefix.map_or(PrefixMode::Timestamp, map_prefix_mode);
-
-    let migration_tag = generate_migration_tag_with_mode(
-        prefix_mode,
-        next_migration_index(out_dir)?,
-        Some(&custom_name),
-    );
+    let migration_tag = tag_format
+        .render(
+            next_migration_index(out_dir, tag_format)?,
+            Some(&custom_name),
+        )
+        .map_err(|e| CliError::MigrationError(e.to_string()))?;
 
     let sql_content = "-- Custom SQL migration file, put your code below! --\n\n";
     let migration_dir = drizzle_migrations::writer::publish_migration_directory(
@@ -260,32 +362,28 @@ fn generate_custom_migration(
     )
     .map_err(map_migration_error)?;
 
+    let mut files = vec![migration_dir.join("migration.sql")];
+
     // Regenerate {out_dir}/migrations.js bundle index when enabled.
     if bundle {
-        write_migrations_js(out_dir)?;
+        files.push(write_migrations_js(out_dir)?);
     }
 
-    println!(
+    outln!(
         "{}",
         output::success(&format!("Custom migration created: {migration_tag}"))
     );
-    println!("   {}", migration_dir.display());
-    println!(
+    outln!("   {}", migration_dir.display());
+    outln!(
         "{}",
         output::label("   Edit the migration file to add your SQL statements.")
     );
 
-    Ok(())
-}
-
-const fn map_prefix_mode(p: MigrationPrefix) -> drizzle_migrations::PrefixMode {
-    match p {
-        MigrationPrefix::Index => drizzle_migrations::PrefixMode::Index,
-        MigrationPrefix::Timestamp => drizzle_migrations::PrefixMode::Timestamp,
-        MigrationPrefix::Supabase => drizzle_migrations::PrefixMode::Supabase,
-        MigrationPrefix::Unix => drizzle_migrations::PrefixMode::Unix,
-        MigrationPrefix::None => drizzle_migrations::PrefixMode::None,
-    }
+    Ok(GenerateReport {
+        tag: Some(migration_tag),
+        files,
+        ..GenerateReport::default()
+    })
 }
 
 /// Load the previous snapshot from the migration directory
@@ -304,28 +402,60 @@ fn load_previous_snapshot(
     Ok(Snapshot::empty(dialect))
 }
 
-fn next_migration_index(out_dir: &Path) -> Result<u32, CliError> {
-    let entries = collect_v3_migration_tags(out_dir)?;
-    let mut max_index: Option<u32> = None;
+fn next_migration_index(out_dir: &Path, tag_format: &TagFormat) -> Result<u32, CliError> {
+    let tags = collect_v3_migration_tags(out_dir)?;
+    Ok(tag_format.next_index(tags.iter().map(String::as_str)))
+}
 
-    for tag in &entries {
-        let Some(prefix) = tag.split('_').next() else {
-            continue;
-        };
+/// Reject existing folders that share an `{index}`, as happens when two
+/// branches each generate `0005_*` and are merged. With `renumber`, every
+/// folder but the first of each group moves past the highest index instead.
+fn resolve_index_collisions(
+    out_dir: &Path,
+    tag_format: &TagFormat,
+    renumber: bool,
+) -> Result<(), CliError> {
+    if !tag_format.has_index() {
+        return Ok(());
+    }
 
-        if prefix.len() > 10 || !prefix.chars().all(|c| c.is_ascii_digit()) {
-            continue;
+    let tags = collect_v3_migration_tags(out_dir)?;
+    let collisions = tag_format.collisions(tags.iter().map(String::as_str));
+    if collisions.is_empty() {
+        return Ok(());
+    }
+
+    if !renumber {
+        let mut message =
+            String::from("Migration folders share an index, likely from merged branches:\n");
+        for collision in &collisions {
+            let _ = writeln!(
+                message,
+                "  {:04}: {}",
+                collision.index,
+                collision.tags.join(", ")
+            );
         }
+        message.push_str(
+            "Renumber the ones that haven't been applied anywhere, or rerun with --renumber.",
+        );
+        return Err(CliError::MigrationError(message));
+    }
 
-        if let Ok(idx) = prefix.parse::<u32>() {
-            max_index = Some(max_index.map_or(idx, |curr| curr.max(idx)));
+    let mut next = tag_format.next_index(tags.iter().map(String::as_str));
+    for collision in &collisions {
+        for tag in &collision.tags[1..] {
+            let Some(renamed) = tag_format.with_index(tag, next) else {
+                continue;
+            };
+            std::fs::rename(out_dir.join(tag), out_dir.join(&renamed))
+                .map_err(|e| CliError::IoError(format!("Failed to rename {tag}: {e}")))?;
+            outln!("  {} {tag} -> {renamed}", output::label("Renumbered"));
+            next = next.saturating_add(1);
         }
     }
 
-    Ok(max_index.map_or_else(
-        || u32::try_from(entries.len()).unwrap_or(u32::MAX),
-        |idx| idx.saturating_add(1),
-    ))
+    Ok(())
 }
 
 fn collect_v3_migration_tags(out_dir: &Path) -> Result<Vec<String>, CliError> {
@@ -358,7 +488,9 @@ fn collect_v3_migration_tags(out_dir: &Path) -> Result<Vec<String>, CliError> {
     Ok(tags)
 }
 
-fn latest_v3_snapshot_path(out_dir: &Path) -> Result<Option<std::path::PathBuf>, CliError> {
+pub(crate) fn latest_v3_snapshot_path(
+    out_dir: &Path,
+) -> Result<Option<std::path::PathBuf>, CliError> {
     if !out_dir.exists() {
         return Ok(None);
     }
@@ -404,7 +536,7 @@ fn latest_v3_snapshot_path(out_dir: &Path) -> Result<Option<std::path::PathBuf>,
 ///
 /// Returns [`CliError`] if the migrations directory cannot be enumerated or if
 /// writing the `migrations.js` file fails.
-pub fn write_migrations_js(out_dir: &Path) -> Result<(), CliError> {
+pub fn write_migrations_js(out_dir: &Path) -> Result<PathBuf, CliError> {
     let tags = collect_v3_migration_tags(out_dir)?;
 
     let mut content = String::new();
@@ -427,7 +559,7 @@ pub fn write_migrations_js(out_dir: &Path) -> Result<(), CliError> {
     let migrations_js_path = out_dir.join("migrations.js");
     std::fs::write(&migrations_js_path, content).map_err(|e| CliError::IoError(e.to_string()))?;
 
-    Ok(())
+    Ok(migrations_js_path)
 }
 
 /// Map migration infrastructure errors to CLI errors.
@@ -439,7 +571,8 @@ fn map_migration_error(error: drizzle_migrations::MigrationError) -> CliError {
         }
         drizzle_migrations::MigrationError::ConfigError(_)
         | drizzle_migrations::MigrationError::IoError(_)
-        | drizzle_migrations::MigrationError::SnapshotError(_) => {
+        | drizzle_migrations::MigrationError::SnapshotError(_)
+        | drizzle_migrations::MigrationError::Destructive(_) => {
             CliError::MigrationError(error.to_string())
         }
     }
@@ -449,8 +582,9 @@ fn map_migration_error(error: drizzle_migrations::MigrationError) -> CliError {
 fn generate_diff(
     prev: &drizzle_migrations::schema::Snapshot,
     current: &drizzle_migrations::schema::Snapshot,
+    options: &drizzle_migrations::Options,
 ) -> Result<drizzle_migrations::Plan, CliError> {
-    drizzle_migrations::diff(prev, current).map_err(map_migration_error)
+    drizzle_migrations::diff_with(prev, current, options).map_err(map_migration_error)
 }
 
 #[cfg(test)]
@@ -470,7 +604,7 @@ mod tests {
         let error = generate_custom_migration(
             tmp.path(),
             false,
-            Some(MigrationPrefix::None),
+            &TagFormat::from(drizzle_migrations::PrefixMode::None),
             Some("../escape".to_string()),
             false,
         )
@@ -489,7 +623,7 @@ mod tests {
         generate_custom_migration(
             tmp.path(),
             false,
-            Some(MigrationPrefix::None),
+            &TagFormat::from(drizzle_migrations::PrefixMode::None),
             Some("manual_change".to_string()),
             false,
         )
@@ -503,6 +637,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn index_collisions_fail_unless_renumbered() {
+        let tmp = tempdir().expect("tempdir");
+        let out_dir = tmp.path();
+        let format = TagFormat::parse("{index}_{name}").expect("tag format");
+
+        touch_migration(out_dir, "0000_init");
+        touch_migration(out_dir, "0001_users");
+        touch_migration(out_dir, "0001_posts");
+
+        let error = resolve_index_collisions(out_dir, &format, false)
+            .expect_err("colliding indexes must fail");
+        assert!(
+            error.to_string().contains("0001: 0001_posts, 0001_users"),
+            "{error}"
+        );
+
+        resolve_index_collisions(out_dir, &format, true).expect("renumber");
+        assert_eq!(
+            collect_v3_migration_tags(out_dir).expect("tags"),
+            ["0000_init", "0001_posts", "0002_users"]
+        );
+        assert_eq!(next_migration_index(out_dir, &format).expect("index"), 3);
+    }
+
+    #[test]
+    fn custom_migration_uses_tag_format() {
+        let tmp = tempdir().expect("tempdir");
+        touch_migration(tmp.path(), "m0004-init");
+        let format = TagFormat::parse("m{index}-{name}").expect("tag format");
+
+        generate_custom_migration(tmp.path(), false, &format, Some("seed".to_string()), false)
+            .expect("generate custom migration");
+
+        assert!(
+            tmp.path()
+                .join("m0005-seed")
+                .join("migration.sql")
+                .is_file()
+        );
+    }
+
     #[test]
     fn migrations_js_contains_import_and_export_map_in_tag_order() {
         let tmp = tempdir().expect("tempdir");
@@ -2,11 +2,14 @@
 //!
 //! Runs pending migrations against the database.
 
+use serde::Serialize;
+
 use crate::config::{Config, Driver};
+use crate::db::{MigrationPlan, MigrationResult};
 use crate::error::CliError;
-use crate::output;
+use crate::output::{self, outln};
 
-#[derive(clap::Args, Debug, Clone, Copy, Default)]
+#[derive(clap::Args, Debug, Clone, Default)]
 pub struct MigrateOptions {
     /// Verify migration consistency without applying changes
     #[arg(long)]
@@ -19,6 +22,36 @@ pub struct MigrateOptions {
     /// Verify first, then apply if checks pass
     #[arg(long)]
     pub safe: bool,
+
+    /// Re-record the hashes of applied migrations that were edited locally
+    #[arg(long)]
+    pub repair: bool,
+
+    /// Stop after the migration with this tag (folder name), leaving later ones pending
+    #[arg(long, value_name = "TAG")]
+    pub to: Option<String>,
+}
+
+/// `--json` output of `drizzle migrate`.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MigrateReport {
+    /// Pending work found by `--verify`, `--plan` or `--safe`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    plan: Option<MigrationPlan>,
+    /// Migrations applied by this run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<MigrationResult>,
+    /// Hashes re-recorded by `--repair`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repaired: Option<Vec<RepairedHash>>,
+}
+
+#[derive(Debug, Serialize)]
+struct RepairedHash {
+    name: String,
+    recorded: String,
+    local: String,
 }
 
 /// Run the migrate command.
@@ -28,23 +61,32 @@ pub struct MigrateOptions {
 /// Returns [`CliError`] if mutually exclusive flags are combined, the database
 /// or credentials cannot be resolved, connecting to the database fails, or
 /// applying migrations fails.
-pub fn run(config: &Config, db_name: Option<&str>, opts: MigrateOptions) -> Result<(), CliError> {
+pub fn run(config: &Config, db_name: Option<&str>, opts: &MigrateOptions) -> Result<(), CliError> {
+    let report = migrate(config, db_name, opts)?;
+    output::emit_json(&report)
+}
+
+fn migrate(
+    config: &Config,
+    db_name: Option<&str>,
+    opts: &MigrateOptions,
+) -> Result<MigrateReport, CliError> {
     validate_mutex_opts(opts)?;
 
     let db = config.database(db_name)?;
 
     crate::commands::harness::print_db_header(config, db_name);
 
-    println!("{}", output::heading(migrate_heading(opts)));
-    println!();
+    outln!("{}", output::heading(migrate_heading(opts)));
+    outln!();
 
     let out_dir = db.migrations_dir();
 
     // Check if migrations directory exists
     if !out_dir.exists() {
-        println!("  {}", output::warning("No migrations directory found."));
-        println!("  Run 'drizzle generate' to create your first migration.");
-        return Ok(());
+        outln!("  {}", output::warning("No migrations directory found."));
+        outln!("  Run 'drizzle generate' to create your first migration.");
+        return Ok(MigrateReport::default());
     }
 
     // Codegen-only drivers (e.g. durable-sqlite) have no remote endpoint for the
@@ -52,7 +94,7 @@ pub fn run(config: &Config, db_name: Option<&str>, opts: MigrateOptions) -> Resu
     // with a pointed message instead of the generic "no credentials" fallback.
     if matches!(db.driver, Some(Driver::DurableSqlite)) {
         print_durable_sqlite_notice(out_dir);
-        return Ok(());
+        return Ok(MigrateReport::default());
     }
 
     // Get credentials
@@ -60,9 +102,33 @@ pub fn run(config: &Config, db_name: Option<&str>, opts: MigrateOptions) -> Resu
 
     let Some(credentials) = credentials else {
         print_missing_credentials_help();
-        return Ok(());
+        return Ok(MigrateReport::default());
     };
 
+    if opts.repair {
+        let repaired = crate::db::repair_migrations(
+            &credentials,
+            db.dialect,
+            out_dir,
+            db.migrations_table(),
+            db.migrations_schema(),
+        )?;
+        print_repair_result(&repaired);
+        return Ok(MigrateReport {
+            repaired: Some(
+                repaired
+                    .into_iter()
+                    .map(|mismatch| RepairedHash {
+                        name: mismatch.name,
+                        recorded: mismatch.recorded,
+                        local: mismatch.local,
+                    })
+                    .collect(),
+            ),
+            ..MigrateReport::default()
+        });
+    }
+
     let plan = if opts.verify || opts.plan || opts.safe {
         Some(crate::db::verify_migrations(
             &credentials,
@@ -70,6 +136,7 @@ pub fn run(config: &Config, db_name: Option<&str>, opts: MigrateOptions) -> Resu
             out_dir,
             db.migrations_table(),
             db.migrations_schema(),
+            opts.to.as_deref(),
         )?)
     } else {
         None
@@ -78,7 +145,10 @@ pub fn run(config: &Config, db_name: Option<&str>, opts: MigrateOptions) -> Resu
     if let Some(plan) = &plan
         && handle_plan_short_circuit(plan, opts)
     {
-        return Ok(());
+        return Ok(MigrateReport {
+            plan: Some(plan.clone()),
+            ..MigrateReport::default()
+        });
     }
 
     // Run migrations
@@ -88,13 +158,24 @@ pub fn run(config: &Config, db_name: Option<&str>, opts: MigrateOptions) -> Resu
         out_dir,
         db.migrations_table(),
         db.migrations_schema(),
+        db.migrations_audit(),
+        opts.to.as_deref(),
     )?;
 
     print_migration_result(&result, opts.safe);
-    Ok(())
+    Ok(MigrateReport {
+        plan,
+        result: Some(result),
+        repaired: None,
+    })
 }
 
-fn validate_mutex_opts(opts: MigrateOptions) -> Result<(), CliError> {
+fn validate_mutex_opts(opts: &MigrateOptions) -> Result<(), CliError> {
+    if opts.repair && (opts.verify || opts.plan || opts.safe || opts.to.is_some()) {
+        return Err(CliError::Other(
+            "--repair can't be combined with --verify, --plan, --safe or --to".to_string(),
+        ));
+    }
     if opts.safe && opts.verify {
         return Err(CliError::Other(
             "--safe can't be combined with --verify".to_string(),
@@ -108,8 +189,10 @@ fn validate_mutex_opts(opts: MigrateOptions) -> Result<(), CliError> {
     Ok(())
 }
 
-const fn migrate_heading(opts: MigrateOptions) -> &'static str {
-    if opts.verify {
+const fn migrate_heading(opts: &MigrateOptions) -> &'static str {
+    if opts.repair {
+        "Repairing migration hashes..."
+    } else if opts.verify {
         "Verifying migrations..."
     } else if opts.plan {
         "Planning migrations..."
@@ -121,45 +204,45 @@ const fn migrate_heading(opts: MigrateOptions) -> &'static str {
 }
 
 fn print_durable_sqlite_notice(out_dir: &std::path::Path) {
-    println!(
+    outln!(
         "{}",
         output::warning("Durable Objects SQLite runs inside the Workers runtime.")
     );
-    println!();
-    println!("  The CLI can't apply migrations to a DO from outside.");
-    println!(
+    outln!();
+    outln!("  The CLI can't apply migrations to a DO from outside.");
+    outln!(
         "  Apply them at `DurableObject` init time by importing `{}/migrations.js`",
         out_dir.display()
     );
-    println!("  and running each statement against `state.storage().sql()`.");
-    println!();
-    println!(
+    outln!("  and running each statement against `state.storage().sql()`.");
+    outln!();
+    outln!(
         "  (This command only generates the SQL + JS bundle — run `drizzle generate` for that.)"
     );
 }
 
 fn print_missing_credentials_help() {
-    println!("{}", output::warning("No database credentials configured."));
-    println!();
-    println!("Add credentials to your drizzle.config.toml:");
-    println!();
-    println!("  {}", output::muted("[dbCredentials]"));
-    println!("  {}", output::muted("url = \"./dev.db\""));
-    println!();
-    println!("Or use an environment variable:");
-    println!();
-    println!("  {}", output::muted("[dbCredentials]"));
-    println!("  {}", output::muted("url = { env = \"DATABASE_URL\" }"));
+    outln!("{}", output::warning("No database credentials configured."));
+    outln!();
+    outln!("Add credentials to your drizzle.config.toml:");
+    outln!();
+    outln!("  {}", output::muted("[dbCredentials]"));
+    outln!("  {}", output::muted("url = \"./dev.db\""));
+    outln!();
+    outln!("Or use an environment variable:");
+    outln!();
+    outln!("  {}", output::muted("[dbCredentials]"));
+    outln!("  {}", output::muted("url = { env = \"DATABASE_URL\" }"));
 }
 
 /// Print plan summary and return `true` if the caller should return early.
-fn handle_plan_short_circuit(plan: &crate::db::MigrationPlan, opts: MigrateOptions) -> bool {
-    println!(
+fn handle_plan_short_circuit(plan: &crate::db::MigrationPlan, opts: &MigrateOptions) -> bool {
+    outln!(
         "  {} {}",
         output::label("Applied migrations:"),
         plan.applied_count
     );
-    println!(
+    outln!(
         "  {} {} ({} statement(s))",
         output::label("Pending migrations:"),
         plan.pending_count,
@@ -167,51 +250,77 @@ fn handle_plan_short_circuit(plan: &crate::db::MigrationPlan, opts: MigrateOptio
     );
 
     if !plan.pending_migrations.is_empty() {
-        println!("  {}", output::label("Pending tags:"));
+        outln!("  {}", output::label("Pending tags:"));
         for tag in &plan.pending_migrations {
-            println!("    {} {}", output::label("->"), tag);
+            outln!("    {} {}", output::label("->"), tag);
         }
     }
-    println!();
+    outln!();
 
     if opts.verify {
-        println!("{}", output::success("Migration verification passed."));
+        outln!("{}", output::success("Migration verification passed."));
         return true;
     }
 
     if opts.plan {
-        println!("{}", output::success("Migration plan complete."));
+        outln!("{}", output::success("Migration plan complete."));
         return true;
     }
 
     if opts.safe && plan.pending_count == 0 {
-        println!("  {}", output::success("No pending migrations."));
-        println!();
-        println!("{}", output::success("Safe migration complete!"));
+        outln!("  {}", output::success("No pending migrations."));
+        outln!();
+        outln!("{}", output::success("Safe migration complete!"));
         return true;
     }
 
     false
 }
 
+fn print_repair_result(repaired: &[drizzle_migrations::ChecksumMismatch]) {
+    if repaired.is_empty() {
+        outln!(
+            "  {}",
+            output::success("All applied migration hashes match.")
+        );
+        return;
+    }
+
+    outln!(
+        "  {} {} migration hash(es):",
+        output::success("Re-recorded"),
+        repaired.len()
+    );
+    for mismatch in repaired {
+        outln!(
+            "    {} {} {}",
+            output::label("->"),
+            mismatch.name,
+            output::muted(&format!("{} -> {}", mismatch.recorded, mismatch.local))
+        );
+    }
+    outln!();
+    outln!("{}", output::success("Repair complete!"));
+}
+
 fn print_migration_result(result: &crate::db::MigrationResult, safe: bool) {
     if result.applied_count == 0 {
-        println!("  {}", output::success("No pending migrations."));
+        outln!("  {}", output::success("No pending migrations."));
     } else {
-        println!(
+        outln!(
             "  {} {} migration(s):",
             output::success("Applied"),
             result.applied_count
         );
         for hash in &result.applied_migrations {
-            println!("    {} {}", output::label("->"), hash);
+            outln!("    {} {}", output::label("->"), hash);
         }
     }
 
-    println!();
+    outln!();
     if safe {
-        println!("{}", output::success("Safe migration complete!"));
+        outln!("{}", output::success("Safe migration complete!"));
     } else {
-        println!("{}", output::success("Migrations complete!"));
+        outln!("{}", output::success("Migrations complete!"));
     }
 }
@@ -3,6 +3,7 @@
 //! Each command module implements a specific drizzle CLI command.
 
 pub mod check;
+pub mod diff;
 pub mod export;
 pub mod generate;
 pub mod harness;
@@ -11,5 +12,8 @@ pub mod migrate;
 pub mod new;
 pub mod overrides;
 pub mod push;
+pub mod renames;
+pub mod seed;
 pub mod status;
 pub mod upgrade;
+pub mod watch;
@@ -0,0 +1,345 @@
+//! Rename resolution for `drizzle generate`
+//!
+//! Combines the `renames` entries from the config with interactive
+//! "created or renamed?" prompts, so renamed tables and columns produce
+//! `ALTER ... RENAME` instead of a drop and create.
+
+use std::io::IsTerminal;
+
+use drizzle_migrations::schema::Snapshot;
+use drizzle_migrations::{Options, RenameHints};
+use inquire::Select;
+
+use crate::error::CliError;
+
+/// A created table or column that may be a renamed dropped one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenameQuestion {
+    Table {
+        schema: Option<String>,
+        name: String,
+        candidates: Vec<String>,
+    },
+    Column {
+        schema: Option<String>,
+        table: String,
+        name: String,
+        candidates: Vec<String>,
+    },
+}
+
+/// Build diff options for `generate`.
+///
+/// Config renames always apply. When both stdin and stdout are terminals,
+/// every remaining created/dropped pair is confirmed with the user and the
+/// rename heuristics are turned off; otherwise the heuristics fill the gaps.
+///
+/// # Errors
+///
+/// Returns [`CliError::Other`] if a prompt is cancelled.
+pub fn generate_options(
+    config_hints: RenameHints,
+    prev: &Snapshot,
+    current: &Snapshot,
+) -> Result<Options, CliError> {
+    if !(std::io::stdin().is_terminal() && std::io::stdout().is_terminal()) {
+        return Ok(Options::new().with_renames(config_hints));
+    }
+
+    let hints = resolve_renames(prev, current, config_hints, prompt)?;
+    Ok(Options::new()
+        .with_renames(hints)
+        .explicit_renames_only(true))
+}
+
+fn prompt(question: &RenameQuestion) -> Result<Option<String>, CliError> {
+    let (message, create, candidates, kind) = match question {
+        RenameQuestion::Table {
+            name, candidates, ..
+        } => (
+            format!("Is `{name}` table created or renamed from another table?"),
+            name,
+            candidates,
+            "table",
+        ),
+        RenameQuestion::Column {
+            table,
+            name,
+            candidates,
+            ..
+        } => (
+            format!(
+                "Is `{name}` column in `{table}` table created or renamed from another column?"
+            ),
+            name,
+            candidates,
+            "column",
+        ),
+    };
+
+    let mut options = vec![format!("+ {create}  create {kind}")];
+    options.extend(
+        candidates
+            .iter()
+            .map(|from| format!("~ {from} › {create}  rename {kind}")),
+    );
+
+    let answer = Select::new(&message, options)
+        .raw_prompt()
+        .map_err(|e| CliError::Other(format!("Prompt cancelled: {e}")))?;
+    Ok(answer
+        .index
+        .checked_sub(1)
+        .map(|idx| candidates[idx].clone()))
+}
+
+/// Ask `choose` about every created table and column that has dropped
+/// counterparts not already covered by `hints`, and add the answers.
+///
+/// `choose` returns the name being renamed from, or `None` for a create.
+///
+/// # Errors
+///
+/// Propagates any error returned by `choose`.
+pub fn resolve_renames(
+    prev: &Snapshot,
+    current: &Snapshot,
+    mut hints: RenameHints,
+    mut choose: impl FnMut(&RenameQuestion) -> Result<Option<String>, CliError>,
+) -> Result<RenameHints, CliError> {
+    let prev = SnapshotNames::new(prev);
+    let cur = SnapshotNames::new(current);
+
+    // (schema, previous name, current name) for every table that survives.
+    let mut table_map: Vec<(Option<String>, String, String)> = Vec::new();
+    let mut dropped: Vec<(Option<String>, String)> = Vec::new();
+    for (schema, name) in &prev.tables {
+        let hinted = hints
+            .table_renames
+            .iter()
+            .find(|hint| hint.from == *name && same_schema(hint.schema.as_ref(), schema.as_ref()));
+        if let Some(hint) = hinted {
+            table_map.push((schema.clone(), name.clone(), hint.to.clone()));
+        } else if cur.has_table(schema.as_ref(), name) {
+            table_map.push((schema.clone(), name.clone(), name.clone()));
+        } else {
+            dropped.push((schema.clone(), name.clone()));
+        }
+    }
+
+    for (schema, name) in &cur.tables {
+        let known = table_map.iter().any(|(s, _, to)| to == name && s == schema);
+        let candidates: Vec<String> = dropped
+            .iter()
+            .filter(|(s, _)| s == schema)
+            .map(|(_, from)| from.clone())
+            .collect();
+        if known || candidates.is_empty() {
+            continue;
+        }
+
+        let question = RenameQuestion::Table {
+            schema: schema.clone(),
+            name: name.clone(),
+            candidates,
+        };
+        if let Some(from) = choose(&question)? {
+            dropped.retain(|(s, n)| !(s == schema && *n == from));
+            hints = match schema {
+                Some(schema) => hints.rename_table_in(schema, &from, name),
+                None => hints.rename_table(&from, name),
+            };
+            table_map.push((schema.clone(), from, name.clone()));
+        }
+    }
+
+    for (schema, from_table, table) in &table_map {
+        let prev_cols = prev.columns(schema.as_ref(), from_table);
+        let cur_cols = cur.columns(schema.as_ref(), table);
+
+        let hinted_from = |col: &str| {
+            hints.column_renames.iter().any(|hint| {
+                hint.table == *table
+                    && hint.from == col
+                    && same_schema(hint.schema.as_ref(), schema.as_ref())
+            })
+        };
+        let hinted_to = |col: &str| {
+            hints.column_renames.iter().any(|hint| {
+                hint.table == *table
+                    && hint.to == col
+                    && same_schema(hint.schema.as_ref(), schema.as_ref())
+            })
+        };
+
+        let mut dropped_cols: Vec<String> = prev_cols
+            .iter()
+            .filter(|col| !cur_cols.contains(col) && !hinted_from(col))
+            .map(|col| (*col).to_string())
+            .collect();
+        let created_cols: Vec<String> = cur_cols
+            .iter()
+            .filter(|col| !prev_cols.contains(col) && !hinted_to(col))
+            .map(|col| (*col).to_string())
+            .collect();
+
+        for name in created_cols {
+            if dropped_cols.is_empty() {
+                break;
+            }
+            let question = RenameQuestion::Column {
+                schema: schema.clone(),
+                table: table.clone(),
+                name: name.clone(),
+                candidates: dropped_cols.clone(),
+            };
+            if let Some(from) = choose(&question)? {
+                dropped_cols.retain(|col| *col != from);
+                hints = match schema {
+                    Some(schema) => hints.rename_column_in(schema, table, &from, &name),
+                    None => hints.rename_column(table, &from, &name),
+                };
+            }
+        }
+    }
+
+    Ok(hints)
+}
+
+/// Hint schemas default to `public`; snapshot tables without a schema are `SQLite`.
+fn same_schema(hint: Option<&String>, table: Option<&String>) -> bool {
+    match table {
+        None => hint.is_none(),
+        Some(table) => hint.map_or("public", String::as_str) == table,
+    }
+}
+
+/// Table and column names of a snapshot, in declaration order.
+struct SnapshotNames {
+    tables: Vec<(Option<String>, String)>,
+    columns: Vec<(Option<String>, String, String)>,
+}
+
+impl SnapshotNames {
+    fn new(snapshot: &Snapshot) -> Self {
+        use drizzle_migrations::postgres::PostgresEntity;
+        use drizzle_migrations::sqlite::SqliteEntity;
+
+        let mut names = Self {
+            tables: Vec::new(),
+            columns: Vec::new(),
+        };
+        match snapshot {
+            Snapshot::Sqlite(snapshot) => {
+                for entity in &snapshot.ddl {
+                    match entity {
+                        SqliteEntity::Table(table) => {
+                            names.tables.push((None, table.name.to_string()));
+                        }
+                        SqliteEntity::Column(column) => names.columns.push((
+                            None,
+                            column.table.to_string(),
+                            column.name.to_string(),
+                        )),
+                        _ => {}
+                    }
+                }
+            }
+            Snapshot::Postgres(snapshot) => {
+                for entity in &snapshot.ddl {
+                    match entity {
+                        PostgresEntity::Table(table) => names
+                            .tables
+                            .push((Some(table.schema.to_string()), table.name.to_string())),
+                        PostgresEntity::Column(column) => names.columns.push((
+                            Some(column.schema.to_string()),
+                            column.table.to_string(),
+                            column.name.to_string(),
+                        )),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        names
+    }
+
+    fn has_table(&self, schema: Option<&String>, name: &str) -> bool {
+        self.tables
+            .iter()
+            .any(|(s, n)| s.as_ref() == schema && n == name)
+    }
+
+    fn columns(&self, schema: Option<&String>, table: &str) -> Vec<&str> {
+        self.columns
+            .iter()
+            .filter(|(s, t, _)| s.as_ref() == schema && t == table)
+            .map(|(_, _, name)| name.as_str())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use drizzle_migrations::sqlite::{Column, SQLiteSnapshot, SqliteEntity, Table};
+
+    fn sqlite_snapshot(tables: &[(&'static str, &[&'static str])]) -> Snapshot {
+        let mut snapshot = SQLiteSnapshot::new();
+        for (table, columns) in tables {
+            snapshot.add_entity(SqliteEntity::Table(Table::new(*table)));
+            for column in *columns {
+                snapshot.add_entity(SqliteEntity::Column(Column::new(*table, *column, "text")));
+            }
+        }
+        Snapshot::Sqlite(snapshot)
+    }
+
+    #[test]
+    fn answers_become_table_and_column_hints() {
+        let prev = sqlite_snapshot(&[("users", &["id", "name"])]);
+        let cur = sqlite_snapshot(&[("accounts", &["id", "full_name"])]);
+
+        let mut asked = Vec::new();
+        let hints = resolve_renames(&prev, &cur, RenameHints::new(), |question| {
+            asked.push(question.clone());
+            Ok(match question {
+                RenameQuestion::Table { candidates, .. }
+                | RenameQuestion::Column { candidates, .. } => candidates.first().cloned(),
+            })
+        })
+        .unwrap();
+
+        assert_eq!(asked.len(), 2);
+        assert_eq!(
+            hints,
+            RenameHints::new()
+                .rename_table("users", "accounts")
+                .rename_column("accounts", "name", "full_name")
+        );
+    }
+
+    #[test]
+    fn config_hints_are_not_asked_again() {
+        let prev = sqlite_snapshot(&[("users", &["id", "name"])]);
+        let cur = sqlite_snapshot(&[("users", &["id", "full_name", "nickname"])]);
+        let config = RenameHints::new().rename_column("users", "name", "full_name");
+
+        let hints = resolve_renames(&prev, &cur, config.clone(), |question| {
+            panic!("unexpected question: {question:?}")
+        })
+        .unwrap();
+
+        assert_eq!(hints, config);
+    }
+
+    #[test]
+    fn declining_keeps_create() {
+        let prev = sqlite_snapshot(&[("users", &["id", "name"])]);
+        let cur = sqlite_snapshot(&[("users", &["id", "email"])]);
+
+        let hints = resolve_renames(&prev, &cur, RenameHints::new(), |_| Ok(None)).unwrap();
+
+        assert_eq!(hints, RenameHints::new());
+    }
+}
@@ -0,0 +1,247 @@
+//! Seed command implementation
+//!
+//! Fills the schema's tables with deterministic fake rows, either by running
+//! the INSERT statements against the database or by writing them to a `.sql`
+//! script (`--out`) that can be committed and applied by any SQL client.
+
+use std::path::PathBuf;
+
+use drizzle_migrations::schema::Snapshot;
+
+use crate::commands::overrides::{self, ConnectionOverrides};
+use crate::config::{Config, Dialect};
+use crate::error::CliError;
+use crate::output::{self, outln};
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct SeedOptions {
+    /// Write the INSERT statements to a SQL file instead of running them
+    #[arg(long, value_name = "PATH")]
+    pub out: Option<PathBuf>,
+
+    /// Random seed; the same seed and schema always produce the same rows
+    #[arg(long, default_value_t = 0)]
+    pub seed: u64,
+
+    /// Rows per table
+    #[arg(long, default_value_t = 10)]
+    pub count: usize,
+
+    /// Rows for one table, overriding `--count` (repeatable)
+    #[arg(long = "table", value_name = "NAME=ROWS", value_parser = parse_table_count)]
+    pub tables: Vec<(String, usize)>,
+
+    /// Override dialect from config
+    #[arg(long)]
+    pub dialect: Option<Dialect>,
+
+    /// Override schema path(s)
+    #[arg(long, value_delimiter = ',')]
+    pub schema: Option<Vec<String>>,
+
+    #[command(flatten)]
+    pub connection: ConnectionOverrides,
+}
+
+fn parse_table_count(arg: &str) -> Result<(String, usize), String> {
+    let (name, rows) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=ROWS, got `{arg}`"))?;
+    let rows = rows
+        .trim()
+        .parse()
+        .map_err(|e| format!("invalid row count for `{name}`: {e}"))?;
+    Ok((name.trim().to_string(), rows))
+}
+
+/// Run the seed command.
+///
+/// # Errors
+///
+/// Returns [`CliError`] if the database cannot be resolved, the schema files
+/// cannot be read, `--table` names a table that isn't in the schema, no
+/// driver for the dialect is compiled in, or writing the script or running
+/// the statements fails.
+pub fn run(config: &Config, db_name: Option<&str>, opts: &SeedOptions) -> Result<(), CliError> {
+    let db = config.database(db_name)?;
+    let effective_dialect = overrides::resolve_dialect(db, opts.dialect);
+
+    crate::commands::harness::print_db_header(config, db_name);
+
+    outln!("{}", output::heading("Seeding database..."));
+    outln!();
+
+    outln!(
+        "  {}: {}",
+        output::label("Dialect"),
+        effective_dialect.as_str()
+    );
+
+    // Resolve credentials before doing any work, unless we only write a file.
+    let credentials = if opts.out.is_some() {
+        None
+    } else {
+        let credentials = overrides::resolve_credentials(db, effective_dialect, &opts.connection)?;
+        if credentials.is_none() {
+            outln!(
+                "{}",
+                output::warning(
+                    "No database credentials configured. Set [dbCredentials], pass --url, or write a script with --out."
+                )
+            );
+            return Ok(());
+        }
+        credentials
+    };
+
+    let Some(snapshot) =
+        crate::commands::export::schema_snapshot(db, effective_dialect, opts.schema.as_deref())?
+    else {
+        return Ok(());
+    };
+
+    let statements = seed_statements(&snapshot, opts)?;
+    if statements.is_empty() {
+        outln!("{}", output::warning("No rows to seed."));
+        return Ok(());
+    }
+
+    if let Some(path) = &opts.out {
+        write_script(path, &statements)?;
+        outln!();
+        outln!(
+            "{}",
+            output::success(&format!(
+                "Wrote {} INSERT statement(s) to {}",
+                statements.len(),
+                path.display()
+            ))
+        );
+        return Ok(());
+    }
+
+    if let Some(credentials) = credentials {
+        crate::db::execute_statements(&credentials, effective_dialect, &statements)?;
+        outln!();
+        outln!(
+            "{}",
+            output::success(&format!(
+                "Seed complete! Ran {} INSERT statement(s).",
+                statements.len()
+            ))
+        );
+    }
+
+    Ok(())
+}
+
+/// Render the seed rows for `snapshot` as literal INSERT statements.
+fn seed_statements(snapshot: &Snapshot, opts: &SeedOptions) -> Result<Vec<String>, CliError> {
+    let known: Vec<&str> = match snapshot {
+        Snapshot::Sqlite(snap) => snap
+            .ddl
+            .iter()
+            .filter_map(|entity| match entity {
+                drizzle_migrations::sqlite::SqliteEntity::Table(table) => Some(table.name.as_ref()),
+                _ => None,
+            })
+            .collect(),
+        Snapshot::Postgres(snap) => snap
+            .ddl
+            .iter()
+            .filter_map(|entity| match entity {
+                drizzle_migrations::postgres::PostgresEntity::Table(table) => {
+                    Some(table.name.as_ref())
+                }
+                _ => None,
+            })
+            .collect(),
+    };
+    if let Some((name, _)) = opts
+        .tables
+        .iter()
+        .find(|(name, _)| !known.contains(&name.as_str()))
+    {
+        return Err(CliError::Other(format!(
+            "--table {name}: no table named `{name}` in the schema"
+        )));
+    }
+
+    match snapshot {
+        Snapshot::Sqlite(snap) => {
+            core::cfg_select! {
+                any(
+                    feature = "rusqlite",
+                    feature = "libsql",
+                    feature = "turso",
+                    feature = "d1-http"
+                ) => {
+                    let mut config = drizzle_seed::SeedConfig::from_snapshot(snap)
+                        .seed(opts.seed)
+                        .default_count(opts.count);
+                    for (name, rows) in &opts.tables {
+                        config = config.count_by_name(name, *rows);
+                    }
+                    Ok(config.generate_sql())
+                }
+                _ => {
+                    let _ = snap;
+                    Err(CliError::MissingDriver {
+                        dialect: "SQLite",
+                        feature: "rusqlite",
+                    })
+                }
+            }
+        }
+        Snapshot::Postgres(snap) => {
+            core::cfg_select! {
+                any(feature = "postgres-sync", feature = "tokio-postgres") => {
+                    let mut config = drizzle_seed::SeedConfig::from_snapshot(snap)
+                        .seed(opts.seed)
+                        .default_count(opts.count);
+                    for (name, rows) in &opts.tables {
+                        config = config.count_by_name(name, *rows);
+                    }
+                    Ok(config.generate_sql())
+                }
+                _ => {
+                    let _ = snap;
+                    Err(CliError::MissingDriver {
+                        dialect: "PostgreSQL",
+                        feature: "postgres-sync or tokio-postgres",
+                    })
+                }
+            }
+        }
+    }
+}
+
+/// Write `statements` as a SQL script, creating parent directories as needed.
+fn write_script(path: &std::path::Path, statements: &[String]) -> Result<(), CliError> {
+    let io_err =
+        |e: std::io::Error| CliError::IoError(format!("Failed to write {}: {e}", path.display()));
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(io_err)?;
+    }
+    let mut script = String::new();
+    for stmt in statements {
+        script.push_str(stmt);
+        script.push_str(";\n");
+    }
+    std::fs::write(path, script).map_err(io_err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_counts_parse_name_and_rows() {
+        assert_eq!(
+            parse_table_count("users=100"),
+            Ok(("users".to_string(), 100))
+        );
+        assert!(parse_table_count("users").is_err());
+        assert!(parse_table_count("users=lots").is_err());
+    }
+}
@@ -2,9 +2,40 @@
 //!
 //! Shows migration status (applied vs pending).
 
-use crate::config::Config;
+use serde::Serialize;
+
+use crate::config::{Config, Dialect};
+use crate::db::AppliedMigrationHistory;
 use crate::error::CliError;
-use crate::output;
+use crate::output::{self, outln};
+
+#[derive(clap::Args, Debug, Clone, Copy, Default)]
+pub struct StatusOptions {
+    /// Also list applied migrations with who ran them and how long they took
+    #[arg(long, short)]
+    pub verbose: bool,
+}
+
+/// `--json` output of `drizzle status`.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatusReport {
+    /// Local migration folders, in order
+    migrations: Vec<LocalMigration>,
+    /// Tracking table rows (`--verbose` on `PostgreSQL`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applied: Option<Vec<AppliedMigrationHistory>>,
+    /// Local migrations missing from `applied`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pending: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LocalMigration {
+    tag: String,
+    has_snapshot: bool,
+}
 
 /// Run the status command.
 ///
@@ -13,15 +44,24 @@ use crate::output;
 /// Returns [`CliError`] if the database cannot be resolved, credentials are
 /// invalid, or connecting to the database to read the migration tracking
 /// table fails.
-pub fn run(config: &Config, db_name: Option<&str>) -> Result<(), CliError> {
+pub fn run(config: &Config, db_name: Option<&str>, opts: StatusOptions) -> Result<(), CliError> {
+    let report = status(config, db_name, opts)?;
+    output::emit_json(&report)
+}
+
+fn status(
+    config: &Config,
+    db_name: Option<&str>,
+    opts: StatusOptions,
+) -> Result<StatusReport, CliError> {
     let db = config.database(db_name)?;
 
-    println!("{}", output::heading("Migration Status"));
-    println!();
+    outln!("{}", output::heading("Migration Status"));
+    outln!();
 
     if !config.is_single_database() {
         crate::commands::harness::print_db_header(config, db_name);
-        println!();
+        outln!();
     }
 
     let out_dir = db.migrations_dir();
@@ -29,27 +69,27 @@ pub fn run(config: &Config, db_name: Option<&str>) -> Result<(), CliError> {
 
     // Check if migrations directory exists
     if !out_dir.exists() {
-        println!("  {}", output::warning("No migrations directory found."));
-        println!("  Run 'drizzle generate' to create your first migration.");
-        return Ok(());
+        outln!("  {}", output::warning("No migrations directory found."));
+        outln!("  Run 'drizzle generate' to create your first migration.");
+        return Ok(StatusReport::default());
     }
 
     if journal_path.exists() {
-        println!(
+        outln!(
             "  {}",
             output::warning("Legacy migration journal detected. Run 'drizzle upgrade' first.")
         );
-        println!();
+        outln!();
     }
 
     let entries = discover_migration_dirs(out_dir)?;
     if entries.is_empty() {
-        println!("  {}", output::warning("No migrations found."));
-        return Ok(());
+        outln!("  {}", output::warning("No migrations found."));
+        return Ok(StatusReport::default());
     }
 
     // Display migration entries
-    println!("  {} migration folder(s):\n", entries.len());
+    outln!("  {} migration folder(s):\n", entries.len());
 
     for (i, (tag, migration_path, snapshot_path)) in entries.iter().enumerate() {
         // Migration is in {out}/{tag}/migration.sql
@@ -65,29 +105,121 @@ pub fn run(config: &Config, db_name: Option<&str>) -> Result<(), CliError> {
         };
         let idx_display = output::muted(&format!("{:3}.", i + 1));
 
-        println!("  {idx_display} {status_icon} {tag}");
+        outln!("  {idx_display} {status_icon} {tag}");
 
         if !sql_exists {
-            println!("      {}", output::error("Migration file missing!"));
+            outln!("      {}", output::error("Migration file missing!"));
         }
         if !snapshot_exists && sql_exists {
-            println!("      {}", output::warning("Snapshot file missing"));
+            outln!("      {}", output::warning("Snapshot file missing"));
         }
     }
 
-    println!();
-    println!(
+    outln!();
+    outln!(
         "  {}: {}",
         output::muted("Migrations directory"),
         out_dir.display()
     );
-    println!(
+    outln!(
         "  {}: {}",
         output::muted("Schema files"),
         db.schema_display()
     );
 
-    Ok(())
+    let mut report = StatusReport {
+        migrations: entries
+            .iter()
+            .map(|(tag, _, snapshot_path)| LocalMigration {
+                tag: tag.clone(),
+                has_snapshot: snapshot_path.exists(),
+            })
+            .collect(),
+        ..StatusReport::default()
+    };
+
+    if opts.verbose {
+        outln!();
+        if let Some(history) = print_applied_history(db)? {
+            report.pending = Some(
+                report
+                    .migrations
+                    .iter()
+                    .filter(|local| !history.iter().any(|entry| entry.name == local.tag))
+                    .map(|local| local.tag.clone())
+                    .collect(),
+            );
+            report.applied = Some(history);
+        }
+    }
+
+    Ok(report)
+}
+
+/// Print the tracking table rows; `None` when they can't be read.
+fn print_applied_history(
+    db: &crate::config::DatabaseConfig,
+) -> Result<Option<Vec<AppliedMigrationHistory>>, CliError> {
+    outln!("  {}", output::heading("Applied migrations"));
+
+    if db.dialect != Dialect::Postgresql {
+        outln!(
+            "  {}",
+            output::muted("Applied-by and duration are only recorded for PostgreSQL.")
+        );
+        return Ok(None);
+    }
+
+    let Some(credentials) = db.credentials()? else {
+        outln!(
+            "  {}",
+            output::warning("No database credentials configured; skipping.")
+        );
+        return Ok(None);
+    };
+
+    let history = crate::db::migration_history(
+        &credentials,
+        db.dialect,
+        db.migrations_table(),
+        db.migrations_schema(),
+    )?;
+    if history.is_empty() {
+        outln!("  {}", output::muted("None yet."));
+        return Ok(Some(history));
+    }
+
+    for entry in &history {
+        outln!("  {} {}", output::success("✓"), entry.name);
+        let mut details = Vec::new();
+        if let Some(applied_at) = &entry.applied_at {
+            details.push(format!("at {applied_at}"));
+        }
+        if let Some(applied_by) = &entry.applied_by {
+            details.push(format!("by {applied_by}"));
+        }
+        if let Some(duration_ms) = entry.duration_ms {
+            details.push(format!("{duration_ms} ms"));
+        }
+        if let Some(statement_count) = entry.statement_count {
+            details.push(format!("{statement_count} statement(s)"));
+        }
+        if !details.is_empty() {
+            outln!("      {}", output::muted(&details.join(", ")));
+        }
+    }
+
+    if !db.migrations_audit() {
+        outln!();
+        outln!(
+            "  {}",
+            output::muted(
+                "Set `audit = true` under [migrations] to record who ran each migration."
+            )
+        );
+    }
+
+    Ok(Some(history))
 }
 
 fn discover_migration_dirs(
@@ -1,12 +1,14 @@
 //! Upgrade command - upgrades migration snapshots to the latest version
 //!
-//! This command scans the migrations folder and upgrades any old snapshot
-//! versions to the latest format, matching drizzle-kit's `up` command.
+//! This command converts legacy drizzle-kit folders (`meta/_journal.json`)
+//! to the V3 folder layout, then scans the migrations folder and upgrades
+//! any old snapshot versions to the latest format, matching drizzle-kit's
+//! `up` command.
 
 use crate::config::{Config, Dialect as CliDialect};
 use crate::error::CliError;
 use crate::output;
-use drizzle_migrations::upgrade::upgrade_to_latest;
+use drizzle_migrations::upgrade::{import_legacy_folder, upgrade_to_latest};
 use drizzle_migrations::version::{is_supported_version, snapshot_version};
 use drizzle_types::Dialect;
 use std::fs;
@@ -56,6 +58,22 @@ pub fn run(config: &Config, db_name: Option<&str>, opts: &UpgradeOptions) -> Res
         return Ok(());
     }
 
+    let imported = import_legacy_folder(out_dir, dialect).map_err(|e| {
+        CliError::Other(format!(
+            "Failed to convert legacy migrations in {}: {e}",
+            out_dir.display()
+        ))
+    })?;
+    if !imported.is_empty() {
+        println!(
+            "{}",
+            output::success(&format!(
+                "Converted {} legacy migration(s) to the folder layout",
+                imported.len()
+            ))
+        );
+    }
+
     let upgraded = upgrade_snapshots(out_dir, dialect)?;
 
     if upgraded == 0 {
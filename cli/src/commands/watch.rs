@@ -0,0 +1,148 @@
+//! `--watch` support for `generate` and `push`.
+//!
+//! Polls the schema files' modification times rather than subscribing to OS
+//! events: editors that save by writing a temp file and renaming it over the
+//! original look the same as an in-place write, and globs are re-resolved on
+//! every tick so newly created schema files are picked up too.
+
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use crate::commands::overrides;
+use crate::config::DatabaseConfig;
+use crate::error::CliError;
+use crate::output::{self, outln};
+
+/// How often schema files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Tracks the schema files and their last-seen modification times.
+pub(crate) struct SchemaWatcher<'a> {
+    db: &'a DatabaseConfig,
+    schema_override: Option<&'a [String]>,
+    seen: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl<'a> SchemaWatcher<'a> {
+    pub(crate) fn new(
+        db: &'a DatabaseConfig,
+        schema_override: Option<&'a [String]>,
+    ) -> Result<Self, CliError> {
+        let mut watcher = Self {
+            db,
+            schema_override,
+            seen: Vec::new(),
+        };
+        watcher.seen = watcher.scan()?;
+        Ok(watcher)
+    }
+
+    fn scan(&self) -> Result<Vec<(PathBuf, Option<SystemTime>)>, CliError> {
+        let mut files = overrides::resolve_schema_files(self.db, self.schema_override)?;
+        files.sort();
+        Ok(files
+            .into_iter()
+            .map(|path| {
+                let modified = std::fs::metadata(&path)
+                    .and_then(|meta| meta.modified())
+                    .ok();
+                (path, modified)
+            })
+            .collect())
+    }
+
+    /// Has a schema file been added, removed or modified since the last call?
+    pub(crate) fn changed(&mut self) -> Result<bool, CliError> {
+        let current = self.scan()?;
+        if current == self.seen {
+            return Ok(false);
+        }
+        self.seen = current;
+        Ok(true)
+    }
+
+    /// Block until the schema files change and then stay unchanged for one
+    /// poll, so a burst of saves triggers a single run.
+    fn wait_for_change(&mut self) {
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+            // A scan error (e.g. the only schema file is mid-rename) is
+            // retried on the next tick.
+            if matches!(self.changed(), Ok(true)) {
+                while matches!(self.changed(), Ok(true)) {
+                    std::thread::sleep(POLL_INTERVAL);
+                }
+                return;
+            }
+        }
+    }
+}
+
+/// Run `on_change` now and again after every schema change, until the process
+/// is interrupted. Errors are printed rather than returned, so a typo mid-edit
+/// doesn't end the session.
+pub(crate) fn watch(
+    db: &DatabaseConfig,
+    schema_override: Option<&[String]>,
+    mut on_change: impl FnMut() -> Result<(), CliError>,
+) -> Result<(), CliError> {
+    if output::json_enabled() {
+        return Err(CliError::Other(
+            "--watch can't be combined with --json".to_string(),
+        ));
+    }
+
+    let mut watcher = SchemaWatcher::new(db, schema_override)?;
+    loop {
+        if let Err(error) = on_change() {
+            outln!("{}", output::err_line(&error.to_string()));
+        }
+        outln!();
+        outln!(
+            "{}",
+            output::muted("Watching schema files for changes (Ctrl-C to stop)...")
+        );
+        watcher.wait_for_change();
+        outln!();
+        outln!("{}", output::info("Schema changed"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn watcher_notices_edits_and_new_files() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let root = tmp.path();
+        std::fs::write(root.join("a.rs"), "// a\n").expect("write a.rs");
+        let config_path = root.join("drizzle.config.toml");
+        std::fs::write(
+            &config_path,
+            format!(
+                "dialect = \"sqlite\"\nschema = '{}'\n",
+                root.join("*.rs").to_string_lossy()
+            ),
+        )
+        .expect("write config");
+        let config = Config::load_from(&config_path).expect("load config");
+        let db = config.default_database().expect("default db");
+
+        let mut watcher = SchemaWatcher::new(db, None).expect("watcher");
+        assert!(!watcher.changed().expect("scan"));
+
+        std::fs::write(root.join("b.rs"), "// b\n").expect("write b.rs");
+        assert!(watcher.changed().expect("scan"));
+        assert!(!watcher.changed().expect("scan"));
+
+        let file = std::fs::File::options()
+            .write(true)
+            .open(root.join("a.rs"))
+            .expect("open a.rs");
+        file.set_modified(SystemTime::now() + Duration::from_secs(60))
+            .expect("touch a.rs");
+        assert!(watcher.changed().expect("scan"));
+    }
+}
@@ -446,6 +446,9 @@ pub enum Credentials {
     Turso {
         url: Box<str>,
         auth_token: Option<Box<str>>,
+        /// Encryption-at-rest key for local files; the base64 key of an
+        /// encrypted database for remote URLs.
+        encryption_key: Option<Box<str>>,
     },
 
     /// `PostgreSQL`
@@ -485,6 +488,8 @@ pub enum PostgresCreds {
         password: Option<Box<str>>,
         database: Box<str>,
         ssl: PostgresSslMode,
+        /// PEM bundle of CAs trusted for the server certificate.
+        ssl_root_cert: Option<PathBuf>,
     },
 }
 
@@ -492,10 +497,18 @@ impl std::fmt::Debug for Credentials {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Sqlite { path } => f.debug_struct("Sqlite").field("path", path).finish(),
-            Self::Turso { url, auth_token } => f
+            Self::Turso {
+                url,
+                auth_token,
+                encryption_key,
+            } => f
                 .debug_struct("Turso")
                 .field("url", url)
                 .field("auth_token", &auth_token.as_ref().map(|_| "[REDACTED]"))
+                .field(
+                    "encryption_key",
+                    &encryption_key.as_ref().map(|_| "[REDACTED]"),
+                )
                 .finish(),
             Self::Postgres(credentials) => f.debug_tuple("Postgres").field(credentials).finish(),
             Self::D1 {
@@ -533,6 +546,7 @@ impl std::fmt::Debug for PostgresCreds {
                 password,
                 database,
                 ssl,
+                ssl_root_cert,
             } => f
                 .debug_struct("Host")
                 .field("host", host)
@@ -541,6 +555,7 @@ impl std::fmt::Debug for PostgresCreds {
                 .field("password", &password.as_ref().map(|_| "[REDACTED]"))
                 .field("database", database)
                 .field("ssl", ssl)
+                .field("ssl_root_cert", ssl_root_cert)
                 .finish(),
         }
     }
@@ -558,6 +573,19 @@ pub enum PostgresSslMode {
 }
 
 impl PostgresSslMode {
+    /// The libpq `sslmode` spelling.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Disable => "disable",
+            Self::Allow => "allow",
+            Self::Prefer => "prefer",
+            Self::Require => "require",
+            Self::VerifyCa => "verify-ca",
+            Self::VerifyFull => "verify-full",
+        }
+    }
+
     pub(crate) fn parse(value: &str) -> Result<Self, String> {
         match value.trim().to_ascii_lowercase().as_str() {
             "false" | "0" | "no" | "off" | "disable" => Ok(Self::Disable),
@@ -569,12 +597,86 @@ impl PostgresSslMode {
             _ => Err(format!("invalid PostgreSQL SSL mode `{value}`")),
         }
     }
+
+    /// The closest mode tokio-postgres understands; certificate checks for
+    /// the `verify-*` modes happen in the TLS connector instead.
+    #[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+    const fn tokio_mode(self) -> tokio_postgres::config::SslMode {
+        match self {
+            Self::Disable => tokio_postgres::config::SslMode::Disable,
+            Self::Allow | Self::Prefer => tokio_postgres::config::SslMode::Prefer,
+            Self::Require | Self::VerifyCa | Self::VerifyFull => {
+                tokio_postgres::config::SslMode::Require
+            }
+        }
+    }
 }
 
 #[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
 pub struct PostgresConnectionConfig {
     pub config: tokio_postgres::Config,
     pub ssl: PostgresSslMode,
+    /// CA bundle from `sslRootCert` or the URL's `sslrootcert`.
+    pub root_cert: Option<PathBuf>,
+}
+
+/// TLS settings libpq reads from a connection string but tokio-postgres
+/// rejects (`sslmode=verify-ca|verify-full`, `sslrootcert`).
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+#[derive(Default)]
+struct LibpqTlsParams {
+    ssl: Option<PostgresSslMode>,
+    root_cert: Option<PathBuf>,
+}
+
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+impl LibpqTlsParams {
+    /// Split the TLS settings out of `conn`, returning what's left for
+    /// tokio-postgres to parse. Handles both URLs and `key=value` strings.
+    fn split(conn: &str) -> Result<(Self, String), String> {
+        let mut params = Self::default();
+        let rest = if conn.starts_with("postgres://") || conn.starts_with("postgresql://") {
+            match conn.split_once('?') {
+                Some((base, query)) => {
+                    let mut kept = Vec::new();
+                    for pair in query.split('&') {
+                        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                        if !params.take(key, value)? {
+                            kept.push(pair);
+                        }
+                    }
+                    if kept.is_empty() {
+                        base.to_string()
+                    } else {
+                        format!("{base}?{}", kept.join("&"))
+                    }
+                }
+                None => conn.to_string(),
+            }
+        } else if conn.contains('\'') {
+            // Quoted values may hold spaces; leave those strings to tokio-postgres.
+            conn.to_string()
+        } else {
+            let mut kept = Vec::new();
+            for token in conn.split_whitespace() {
+                let (key, value) = token.split_once('=').unwrap_or((token, ""));
+                if !params.take(key, value)? {
+                    kept.push(token);
+                }
+            }
+            kept.join(" ")
+        };
+        Ok((params, rest))
+    }
+
+    fn take(&mut self, key: &str, value: &str) -> Result<bool, String> {
+        match key {
+            "sslmode" => self.ssl = Some(PostgresSslMode::parse(value)?),
+            "sslrootcert" => self.root_cert = Some(PathBuf::from(value)),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
 }
 
 #[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
@@ -582,16 +684,18 @@ impl PostgresCreds {
     pub fn connection_config(&self) -> Result<PostgresConnectionConfig, String> {
         match self {
             Self::Url(url) => {
-                let config = url
+                let (tls, url) = LibpqTlsParams::split(url)?;
+                let mut config = url
                     .parse::<tokio_postgres::Config>()
                     .map_err(|error| error.to_string())?;
-                let ssl = match config.get_ssl_mode() {
-                    tokio_postgres::config::SslMode::Disable => PostgresSslMode::Disable,
-                    tokio_postgres::config::SslMode::Prefer => PostgresSslMode::Prefer,
-                    tokio_postgres::config::SslMode::Require => PostgresSslMode::Require,
-                    _ => PostgresSslMode::Require,
-                };
-                Ok(PostgresConnectionConfig { config, ssl })
+                // tokio-postgres defaults to `prefer`, same as libpq.
+                let ssl = tls.ssl.unwrap_or(PostgresSslMode::Prefer);
+                config.ssl_mode(ssl.tokio_mode());
+                Ok(PostgresConnectionConfig {
+                    config,
+                    ssl,
+                    root_cert: tls.root_cert,
+                })
             }
             Self::Host {
                 host,
@@ -600,6 +704,7 @@ impl PostgresCreds {
                 password,
                 database,
                 ssl,
+                ssl_root_cert,
             } => {
                 let mut config = tokio_postgres::Config::new();
                 config
@@ -612,16 +717,12 @@ impl PostgresCreds {
                 if let Some(password) = password {
                     config.password(password.as_bytes());
                 }
-                config.ssl_mode(match ssl {
-                    PostgresSslMode::Disable => tokio_postgres::config::SslMode::Disable,
-                    PostgresSslMode::Allow | PostgresSslMode::Prefer => {
-                        tokio_postgres::config::SslMode::Prefer
-                    }
-                    PostgresSslMode::Require
-                    | PostgresSslMode::VerifyCa
-                    | PostgresSslMode::VerifyFull => tokio_postgres::config::SslMode::Require,
-                });
-                Ok(PostgresConnectionConfig { config, ssl: *ssl })
+                config.ssl_mode(ssl.tokio_mode());
+                Ok(PostgresConnectionConfig {
+                    config,
+                    ssl: *ssl,
+                    root_cert: ssl_root_cert.clone(),
+                })
             }
         }
     }
@@ -677,6 +778,12 @@ pub struct MigrationsOpts {
     pub table: Option<String>,
     pub schema: Option<String>,
     pub prefix: Option<MigrationPrefix>,
+    /// Template for migration folder names, e.g. `"{index}_{name}"`.
+    ///
+    /// Supports `{index}`, `{timestamp}`, `{unix}`, `{millis}` and `{name}`.
+    /// Replaces `prefix`; set one or the other.
+    #[serde(default)]
+    pub tag_format: Option<String>,
     /// Emit a `migrations.js` index at the root of the migrations output folder.
     ///
     /// Matches drizzle-kit's `bundle: true` behavior. The file statically
@@ -685,6 +792,28 @@ pub struct MigrationsOpts {
     /// SQL text at build time. Harmless for Rust-only consumers.
     #[serde(default)]
     pub bundle: Option<bool>,
+    /// Record who applied each migration, how long it took and how many
+    /// statements it ran (`PostgreSQL` only).
+    #[serde(default)]
+    pub audit: Option<bool>,
+}
+
+/// Explicit rename for `drizzle generate`
+///
+/// An entry with `table` renames a column of that table; without it, the
+/// entry renames a table.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, JsonSchema)]
+pub struct RenameEntry {
+    /// Schema of the renamed table (`PostgreSQL` only, default: public)
+    #[serde(default)]
+    pub schema: Option<String>,
+    /// Table containing the renamed column
+    #[serde(default)]
+    pub table: Option<String>,
+    /// Previous name
+    pub from: String,
+    /// New name
+    pub to: String,
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
@@ -697,6 +826,19 @@ pub enum MigrationPrefix {
     None,
 }
 
+impl MigrationPrefix {
+    #[must_use]
+    pub const fn prefix_mode(self) -> drizzle_migrations::PrefixMode {
+        match self {
+            Self::Index => drizzle_migrations::PrefixMode::Index,
+            Self::Timestamp => drizzle_migrations::PrefixMode::Timestamp,
+            Self::Supabase => drizzle_migrations::PrefixMode::Supabase,
+            Self::Unix => drizzle_migrations::PrefixMode::Unix,
+            Self::None => drizzle_migrations::PrefixMode::None,
+        }
+    }
+}
+
 // ============================================================================
 // Raw credentials (serde parsing helper)
 // ============================================================================
@@ -734,7 +876,24 @@ enum RawCreds {
         url: EnvOr,
         #[serde(default, rename = "authToken")]
         auth_token: Option<EnvOr>,
+        #[serde(default, rename = "encryptionKey", alias = "encryption_key")]
+        encryption_key: Option<EnvOr>,
     },
+    /// `{ urlEnv = "DATABASE_URL" }` — shorthand for `url = { env = "DATABASE_URL" }`.
+    ///
+    /// Desugared into [`RawCreds::Url`] when the config is loaded, so nothing
+    /// past [`DatabaseConfig::normalize`] sees this variant.
+    UrlEnv {
+        #[serde(rename = "urlEnv", alias = "url_env")]
+        url_env: String,
+        #[serde(default, rename = "authToken")]
+        auth_token: Option<EnvOr>,
+        #[serde(default, rename = "encryptionKey", alias = "encryption_key")]
+        encryption_key: Option<EnvOr>,
+    },
+    /// Host-based `PostgreSQL` credentials. Anything left out falls back to the
+    /// libpq environment (`PGPORT`, `PGUSER`, `PGPASSWORD`, `PGDATABASE`,
+    /// `PGSSLMODE`, `PGSSLROOTCERT`).
     Host {
         host: EnvOr,
         #[serde(default)]
@@ -743,12 +902,36 @@ enum RawCreds {
         user: Option<EnvOr>,
         #[serde(default)]
         password: Option<EnvOr>,
-        database: EnvOr,
+        /// File holding the password, e.g. a Docker/Kubernetes secret mount.
+        #[serde(default, rename = "passwordFile", alias = "password_file")]
+        password_file: Option<PathBuf>,
+        #[serde(default)]
+        database: Option<EnvOr>,
         #[serde(default)]
         ssl: Option<SslVal>,
+        /// PEM bundle of CAs trusted for the server certificate.
+        #[serde(default, rename = "sslRootCert", alias = "ssl_root_cert")]
+        ssl_root_cert: Option<PathBuf>,
     },
 }
 
+impl RawCreds {
+    fn desugar(self) -> Self {
+        match self {
+            Self::UrlEnv {
+                url_env,
+                auth_token,
+                encryption_key,
+            } => Self::Url {
+                url: EnvOr::Env(url_env),
+                auth_token,
+                encryption_key,
+            },
+            other => other,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 #[serde(untagged)]
 enum SslVal {
@@ -766,6 +949,80 @@ impl SslVal {
     }
 }
 
+/// Host-form `PostgreSQL` settings from the config, before libpq fallback.
+#[derive(Default)]
+struct PostgresHostParts<'a> {
+    host: Option<String>,
+    port: Option<u16>,
+    user: Option<String>,
+    password: Option<String>,
+    password_file: Option<&'a Path>,
+    database: Option<String>,
+    ssl: Option<&'a SslVal>,
+    ssl_root_cert: Option<&'a Path>,
+}
+
+impl PostgresHostParts<'_> {
+    /// Fill unset fields the way libpq does: from `PG*` variables, then
+    /// `localhost:5432`, with the database defaulting to the user name.
+    fn resolve(self, pg_env: &dyn Fn(&str) -> Option<String>) -> Result<PostgresCreds, Error> {
+        let err = |msg: String| Error::InvalidCredentials(msg);
+
+        let host = self
+            .host
+            .or_else(|| pg_env("PGHOST"))
+            .unwrap_or_else(|| "localhost".into());
+        let port = match (self.port, pg_env("PGPORT")) {
+            (Some(port), _) => port,
+            (None, Some(port)) => port
+                .trim()
+                .parse()
+                .map_err(|_| err(format!("PGPORT `{port}` is not a valid port")))?,
+            (None, None) => 5432,
+        };
+        let user = self.user.or_else(|| pg_env("PGUSER"));
+        let password = match (self.password, self.password_file) {
+            (Some(password), _) => Some(password),
+            (None, Some(path)) => Some(read_secret_file(path)?),
+            (None, None) => pg_env("PGPASSWORD"),
+        };
+        let database = self
+            .database
+            .or_else(|| pg_env("PGDATABASE"))
+            .or_else(|| user.clone())
+            .ok_or_else(|| {
+                err("no database name: set dbCredentials.database, PGDATABASE or PGUSER".into())
+            })?;
+        let ssl = match (self.ssl, pg_env("PGSSLMODE")) {
+            (Some(ssl), _) => ssl.mode(),
+            (None, Some(mode)) => PostgresSslMode::parse(&mode),
+            (None, None) => Ok(PostgresSslMode::Disable),
+        }
+        .map_err(err)?;
+        let ssl_root_cert = self
+            .ssl_root_cert
+            .map(Path::to_path_buf)
+            .or_else(|| pg_env("PGSSLROOTCERT").map(PathBuf::from));
+
+        Ok(PostgresCreds::Host {
+            host: host.into_boxed_str(),
+            port,
+            user: user.map(String::into_boxed_str),
+            password: password.map(String::into_boxed_str),
+            database: database.into_boxed_str(),
+            ssl,
+            ssl_root_cert,
+        })
+    }
+}
+
+/// Read a secret from a file, dropping the trailing newline most secret
+/// stores append.
+fn read_secret_file(path: &Path) -> Result<String, Error> {
+    let secret = std::fs::read_to_string(path).map_err(|e| Error::Io(path.into(), e))?;
+    Ok(secret.trim_end_matches(['\r', '\n']).to_string())
+}
+
 // ============================================================================
 // DatabaseConfig - Per-database configuration
 // ============================================================================
@@ -791,6 +1048,10 @@ pub struct DatabaseConfig {
     #[serde(default = "yes")]
     pub breakpoints: bool,
 
+    /// Pretty-print generated migration SQL (default: false)
+    #[serde(default)]
+    pub format_sql: bool,
+
     /// Database driver for Rust connections
     #[serde(default)]
     pub driver: Option<Driver>,
@@ -830,6 +1091,10 @@ pub struct DatabaseConfig {
     /// Migration table configuration
     #[serde(default)]
     pub migrations: Option<MigrationsOpts>,
+
+    /// Explicit table/column renames applied by `drizzle generate`
+    #[serde(default)]
+    pub renames: Vec<RenameEntry>,
 }
 
 fn default_out() -> PathBuf {
@@ -841,7 +1106,23 @@ const fn yes() -> bool {
 }
 
 impl DatabaseConfig {
-    fn normalize_paths(&mut self, base_dir: &Path) {
+    fn normalize(&mut self, base_dir: &Path) {
+        self.db_credentials = self.db_credentials.take().map(RawCreds::desugar);
+
+        // Secret and certificate files, like `out`, are relative to the config file.
+        if let Some(RawCreds::Host {
+            password_file,
+            ssl_root_cert,
+            ..
+        }) = &mut self.db_credentials
+        {
+            for file in [password_file, ssl_root_cert].into_iter().flatten() {
+                if file.is_relative() {
+                    *file = base_dir.join(&*file);
+                }
+            }
+        }
+
         // Resolve `out` relative to the config file directory for predictable behavior,
         // especially when `--config` points at a file outside the current working directory.
         if self.out.is_relative() {
@@ -891,6 +1172,8 @@ impl DatabaseConfig {
             self.validate_creds(raw, name)?;
         }
 
+        self.migration_tag_format()?;
+
         // PostgreSQL-only settings
         if self.dialect != Dialect::Postgresql {
             if self.schema_filter.is_some() {
@@ -944,6 +1227,15 @@ impl DatabaseConfig {
             }
         }
 
+        if let RawCreds::Host {
+            password: Some(_),
+            password_file: Some(_),
+            ..
+        } = raw
+        {
+            return Err(err("set either password or passwordFile, not both"));
+        }
+
         // Conversely, if the user picked driver = d1-http but didn't supply the
         // D1 shape, flag it early — otherwise `credentials()` would silently
         // return None and the CLI would fail much later with a confusing error.
@@ -987,6 +1279,15 @@ impl DatabaseConfig {
             ) => Err(err(
                 "SQLite doesn't support authToken (use dialect = \"turso\")",
             )),
+            (
+                Dialect::Sqlite,
+                RawCreds::Url {
+                    encryption_key: Some(_),
+                    ..
+                },
+            ) => Err(err(
+                "SQLite doesn't support encryptionKey (use dialect = \"turso\" with a local file)",
+            )),
             (
                 Dialect::Sqlite,
                 RawCreds::Url {
@@ -1033,15 +1334,36 @@ impl DatabaseConfig {
         }
     }
 
-    /// Get typed credentials, resolving any environment variable references.
+    /// Get typed credentials, resolving any environment variable references
+    /// and secret files.
+    ///
+    /// `PostgreSQL` configs follow libpq: host-based credentials take unset
+    /// fields from `PGPORT`/`PGUSER`/`PGPASSWORD`/`PGDATABASE`/`PGSSLMODE`, and
+    /// with no `dbCredentials` at all, setting `PGHOST` or `PGDATABASE` is
+    /// enough to connect.
     ///
     /// # Errors
     ///
     /// Returns [`Error`] if a referenced environment variable is missing or
-    /// invalid, or if the credentials block does not match the configured
-    /// dialect.
+    /// invalid, if a `passwordFile` can't be read, or if the credentials block
+    /// does not match the configured dialect.
     pub fn credentials(&self) -> Result<Option<Credentials>, Error> {
+        self.resolve_credentials(&|name| std::env::var(name).ok())
+    }
+
+    fn resolve_credentials(
+        &self,
+        pg_env: &dyn Fn(&str) -> Option<String>,
+    ) -> Result<Option<Credentials>, Error> {
         let Some(raw) = self.db_credentials.as_ref() else {
+            let libpq_env = pg_env("PGHOST").is_some() || pg_env("PGDATABASE").is_some();
+            if self.dialect == Dialect::Postgresql
+                && self.driver.is_none_or(|d| d != Driver::AwsDataApi)
+                && libpq_env
+            {
+                let creds = PostgresHostParts::default().resolve(pg_env)?;
+                return Ok(Some(Credentials::Postgres(creds)));
+            }
             return Ok(None);
         };
 
@@ -1088,9 +1410,17 @@ impl DatabaseConfig {
                 path: url.resolve()?.into_boxed_str(),
             },
             // Turso
-            (Dialect::Turso, RawCreds::Url { url, auth_token }) => Credentials::Turso {
+            (
+                Dialect::Turso,
+                RawCreds::Url {
+                    url,
+                    auth_token,
+                    encryption_key,
+                },
+            ) => Credentials::Turso {
                 url: url.resolve()?.into_boxed_str(),
                 auth_token: resolve_opt(auth_token)?,
+                encryption_key: resolve_opt(encryption_key)?,
             },
             // PostgreSQL URL
             (Dialect::Postgresql, RawCreds::Url { url, .. }) => {
@@ -1104,20 +1434,24 @@ impl DatabaseConfig {
                     port,
                     user,
                     password,
+                    password_file,
                     database,
                     ssl,
+                    ssl_root_cert,
                 },
-            ) => Credentials::Postgres(PostgresCreds::Host {
-                host: host.resolve()?.into_boxed_str(),
-                port: port.unwrap_or(5432),
-                user: resolve_opt(user)?,
-                password: resolve_opt(password)?,
-                database: database.resolve()?.into_boxed_str(),
-                ssl: ssl
-                    .as_ref()
-                    .map_or(Ok(PostgresSslMode::Disable), SslVal::mode)
-                    .map_err(Error::InvalidCredentials)?,
-            }),
+            ) => {
+                let parts = PostgresHostParts {
+                    host: Some(host.resolve()?),
+                    port: *port,
+                    user: resolve_opt(user)?.map(String::from),
+                    password: resolve_opt(password)?.map(String::from),
+                    password_file: password_file.as_deref(),
+                    database: resolve_opt(database)?.map(String::from),
+                    ssl: ssl.as_ref(),
+                    ssl_root_cert: ssl_root_cert.as_deref(),
+                };
+                Credentials::Postgres(parts.resolve(pg_env)?)
+            }
             _ => return Ok(None),
         };
 
@@ -1206,6 +1540,37 @@ impl DatabaseConfig {
         Ok(files)
     }
 
+    /// Environment-dependent problems with this database's config: missing
+    /// schema files, credentials that don't resolve or parse, and an `out`
+    /// directory that can't be written.
+    #[must_use]
+    pub fn issues(&self) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+
+        if let Err(e) = self.schema_files() {
+            issues.push(ConfigIssue::new(
+                "schema",
+                format!("{e} (relative paths resolve from the config file's directory)"),
+            ));
+        }
+
+        match self.credentials() {
+            Ok(Some(creds)) => {
+                if let Err(msg) = check_credentials(&creds) {
+                    issues.push(ConfigIssue::new("dbCredentials", msg));
+                }
+            }
+            Ok(None) => {}
+            Err(e) => issues.push(ConfigIssue::new("dbCredentials", e.to_string())),
+        }
+
+        if let Err(msg) = check_writable(&self.out) {
+            issues.push(ConfigIssue::new("out", msg));
+        }
+
+        issues
+    }
+
     /// Get effective casing mode (default: camelCase)
     #[inline]
     #[must_use]
@@ -1276,6 +1641,39 @@ impl DatabaseConfig {
             .unwrap_or("drizzle")
     }
 
+    /// Record audit metadata for applied migrations (`PostgreSQL` only, default: false)
+    #[must_use]
+    pub fn migrations_audit(&self) -> bool {
+        self.migrations
+            .as_ref()
+            .and_then(|m| m.audit)
+            .unwrap_or(false)
+    }
+
+    /// Folder naming template for new migrations (default: `{timestamp}_{name}`)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if both `prefix` and `tag_format` are
+    /// set, or `tag_format` is not a valid template.
+    pub fn migration_tag_format(&self) -> Result<drizzle_migrations::TagFormat, Error> {
+        let Some(opts) = self.migrations.as_ref() else {
+            return Ok(drizzle_migrations::TagFormat::default());
+        };
+        match (opts.prefix, opts.tag_format.as_deref()) {
+            (Some(_), Some(_)) => Err(Error::InvalidConfig(
+                "[migrations] set either prefix or tag_format, not both".into(),
+            )),
+            (_, Some(format)) => drizzle_migrations::TagFormat::parse(format)
+                .map_err(|e| Error::InvalidConfig(e.to_string())),
+            (prefix, None) => Ok(
+                prefix.map_or_else(drizzle_migrations::TagFormat::default, |p| {
+                    p.prefix_mode().into()
+                }),
+            ),
+        }
+    }
+
     /// Should a bundled `migrations.js` index be emitted alongside `migration.sql`?
     ///
     /// Resolution order:
@@ -1291,6 +1689,22 @@ impl DatabaseConfig {
         }
         matches!(self.driver, Some(Driver::DurableSqlite))
     }
+
+    /// Rename hints from the `renames` entries, in declaration order
+    #[must_use]
+    pub fn rename_hints(&self) -> drizzle_migrations::RenameHints {
+        self.renames.iter().fold(
+            drizzle_migrations::RenameHints::new(),
+            |hints, entry| match (&entry.schema, &entry.table) {
+                (None, None) => hints.rename_table(&entry.from, &entry.to),
+                (Some(schema), None) => hints.rename_table_in(schema, &entry.from, &entry.to),
+                (None, Some(table)) => hints.rename_column(table, &entry.from, &entry.to),
+                (Some(schema), Some(table)) => {
+                    hints.rename_column_in(schema, table, &entry.from, &entry.to)
+                }
+            },
+        )
+    }
 }
 
 // ============================================================================
@@ -1380,9 +1794,9 @@ impl Config {
                 is_single: false,
             };
             for db in config.databases.values_mut() {
-                db.normalize_paths(base_dir);
+                db.normalize(base_dir);
             }
-            config.validate()?;
+            config.validate_shape()?;
             return Ok(config);
         }
 
@@ -1398,19 +1812,60 @@ impl Config {
             is_single: true,
         };
         for db in config.databases.values_mut() {
-            db.normalize_paths(base_dir);
+            db.normalize(base_dir);
         }
-        config.validate()?;
+        config.validate_shape()?;
         Ok(config)
     }
 
-    fn validate(&self) -> Result<(), Error> {
+    fn validate_shape(&self) -> Result<(), Error> {
         for (name, db) in &self.databases {
             db.validate(name)?;
         }
         Ok(())
     }
 
+    /// Check every database against the filesystem and environment.
+    ///
+    /// Loading already rejects malformed configs (driver/dialect mismatches,
+    /// credentials in the wrong shape); this goes further and verifies that
+    /// schema files exist, credentials resolve and parse, and each `out`
+    /// directory is writable. Meant for build scripts that want to fail fast:
+    ///
+    /// ```no_run
+    /// // build.rs
+    /// let config = drizzle_cli::Config::load().expect("load drizzle.config.toml");
+    /// if let Err(e) = config.validate() {
+    ///     panic!("{e}");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] listing every issue found.
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut names = self.databases.keys().collect::<Vec<_>>();
+        names.sort();
+
+        let issues = names
+            .into_iter()
+            .flat_map(|name| {
+                self.databases[name].issues().into_iter().map(|mut issue| {
+                    if !self.is_single {
+                        issue.database = Some(name.clone());
+                    }
+                    issue
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Validation(issues))
+        }
+    }
+
     /// Check if this is a single-database config
     #[must_use]
     pub const fn is_single_database(&self) -> bool {
@@ -1531,6 +1986,163 @@ impl Config {
     }
 }
 
+// ============================================================================
+// Validation
+// ============================================================================
+
+/// A problem found by [`Config::validate`], tied to the config key at fault.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    /// Database the issue belongs to (`None` for single-database configs).
+    pub database: Option<String>,
+    /// Config key at fault, e.g. `schema` or `dbCredentials`.
+    pub field: &'static str,
+    /// What is wrong and how to fix it.
+    pub message: String,
+}
+
+impl ConfigIssue {
+    fn new(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            database: None,
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.database {
+            Some(db) => write!(f, "databases.{db}.{}: {}", self.field, self.message),
+            None => write!(f, "{}: {}", self.field, self.message),
+        }
+    }
+}
+
+/// Check that resolved credentials can actually be used to connect.
+///
+/// Never echoes secrets back: messages name the problem, not the value.
+pub(crate) fn check_credentials(creds: &Credentials) -> Result<(), String> {
+    match creds {
+        Credentials::Sqlite { path } => {
+            if path.as_ref() == ":memory:" || path.starts_with("file:") {
+                return Ok(());
+            }
+            match Path::new(path.as_ref()).parent() {
+                Some(dir) if !dir.as_os_str().is_empty() && !dir.is_dir() => Err(format!(
+                    "directory `{}` for the SQLite database doesn't exist; create it first",
+                    dir.display()
+                )),
+                _ => Ok(()),
+            }
+        }
+        Credentials::Turso { url, .. } => {
+            let host = ["libsql://", "https://", "http://"]
+                .iter()
+                .find_map(|scheme| url.strip_prefix(scheme))
+                .ok_or("Turso URL must start with libsql:// or http(s)://")?;
+            if host.is_empty() || host.starts_with('/') {
+                return Err("Turso URL has no host (expected libsql://<db>.turso.io)".into());
+            }
+            Ok(())
+        }
+        Credentials::Postgres(pg) => check_postgres_credentials(pg),
+        Credentials::D1 {
+            account_id,
+            database_id,
+            token,
+        } => {
+            for (key, value) in [
+                ("accountId", account_id),
+                ("databaseId", database_id),
+                ("token", token),
+            ] {
+                if value.trim().is_empty() {
+                    return Err(format!("D1 {key} is empty"));
+                }
+            }
+            Ok(())
+        }
+        Credentials::AwsDataApi {
+            secret_arn,
+            resource_arn,
+            ..
+        } => {
+            for (key, value) in [("secretArn", secret_arn), ("resourceArn", resource_arn)] {
+                if !value.starts_with("arn:") {
+                    return Err(format!("AWS Data API {key} must be an ARN (arn:aws:...)"));
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+fn check_postgres_credentials(pg: &PostgresCreds) -> Result<(), String> {
+    let connection = pg
+        .connection_config()
+        .map_err(|e| format!("can't parse PostgreSQL connection settings: {e}"))?;
+    check_root_cert(connection.root_cert.as_deref())
+}
+
+#[cfg(not(any(feature = "postgres-sync", feature = "tokio-postgres")))]
+fn check_postgres_credentials(pg: &PostgresCreds) -> Result<(), String> {
+    // Without a postgres driver there's no parser to lean on; catch the
+    // obvious mistake of a non-URL value coming from an env var.
+    match pg {
+        PostgresCreds::Url(url) if !url.starts_with("postgres") => {
+            Err("PostgreSQL URL must start with postgres:// or postgresql://".into())
+        }
+        PostgresCreds::Url(_) => Ok(()),
+        PostgresCreds::Host { ssl_root_cert, .. } => check_root_cert(ssl_root_cert.as_deref()),
+    }
+}
+
+fn check_root_cert(path: Option<&Path>) -> Result<(), String> {
+    match path {
+        Some(path) if !path.is_file() => Err(format!(
+            "sslRootCert `{}` doesn't exist or isn't a file",
+            path.display()
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Check that `out` (or, if it doesn't exist yet, the directory it would be
+/// created in) accepts new files.
+pub(crate) fn check_writable(out: &Path) -> Result<(), String> {
+    let mut dir = out;
+    while !dir.exists() {
+        match dir.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => dir = parent,
+            _ => {
+                dir = Path::new(".");
+                break;
+            }
+        }
+    }
+
+    if !dir.is_dir() {
+        return Err(format!("`{}` exists but is not a directory", dir.display()));
+    }
+
+    let probe = dir.join(".drizzle-write-check");
+    match std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+    {
+        Ok(_) => {
+            let _ = std::fs::remove_file(&probe);
+            Ok(())
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(()),
+        Err(e) => Err(format!("`{}` is not writable: {e}", dir.display())),
+    }
+}
+
 // ============================================================================
 // Errors
 // ============================================================================
@@ -1575,6 +2187,13 @@ pub enum Error {
 
     #[error("multiple databases configured, use --db to specify: {}", .0.join(", "))]
     DatabaseRequired(Vec<String>),
+
+    #[error(
+        "config has {} problem(s):\n{}",
+        .0.len(),
+        .0.iter().map(|issue| format!("  - {issue}")).collect::<Vec<_>>().join("\n")
+    )]
+    Validation(Vec<ConfigIssue>),
 }
 
 impl From<EnvOrError> for Error {
@@ -1631,6 +2250,27 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn renames_become_rename_hints() {
+        let cfg = Config::load_from_str(
+            r#"
+            dialect = "postgresql"
+            renames = [
+                { from = "users", to = "accounts" },
+                { schema = "auth", table = "accounts", from = "name", to = "full_name" },
+            ]
+        "#,
+            Path::new("test.toml"),
+        )
+        .unwrap();
+        assert_eq!(
+            cfg.default_database().unwrap().rename_hints(),
+            drizzle_migrations::RenameHints::new()
+                .rename_table("users", "accounts")
+                .rename_column_in("auth", "accounts", "name", "full_name")
+        );
+    }
+
     #[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
     #[test]
     fn postgres_host_credentials_are_structured_without_url_encoding() {
@@ -1641,6 +2281,7 @@ mod tests {
             password: Some("p:a/ss% word".into()),
             database: "app/db name".into(),
             ssl: PostgresSslMode::VerifyFull,
+            ssl_root_cert: None,
         };
 
         let connection = credentials.connection_config().expect("build config");
@@ -1654,6 +2295,68 @@ mod tests {
         assert_eq!(connection.ssl, PostgresSslMode::VerifyFull);
     }
 
+    #[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+    #[test]
+    fn postgres_url_accepts_libpq_tls_params() {
+        let url = PostgresCreds::Url(
+            "postgres://u:p@db.example.com/app?sslmode=verify-full&application_name=cli&sslrootcert=/certs/ca.pem"
+                .into(),
+        );
+        let connection = url.connection_config().expect("build config");
+        assert_eq!(connection.ssl, PostgresSslMode::VerifyFull);
+        assert_eq!(
+            connection.root_cert.as_deref(),
+            Some(Path::new("/certs/ca.pem"))
+        );
+        assert_eq!(connection.config.get_application_name(), Some("cli"));
+        assert_eq!(
+            connection.config.get_ssl_mode(),
+            tokio_postgres::config::SslMode::Require
+        );
+
+        let key_value = PostgresCreds::Url("host=db user=u dbname=app sslmode=verify-ca".into());
+        let connection = key_value.connection_config().expect("build config");
+        assert_eq!(connection.ssl, PostgresSslMode::VerifyCa);
+        assert_eq!(connection.config.get_dbname(), Some("app"));
+
+        let plain = PostgresCreds::Url("postgres://u@db/app".into());
+        let connection = plain.connection_config().expect("build config");
+        assert_eq!(connection.ssl, PostgresSslMode::Prefer);
+        assert!(connection.root_cert.is_none());
+    }
+
+    #[test]
+    fn ssl_root_cert_is_resolved_relative_to_config_dir() {
+        let tmp = TempDir::new().unwrap();
+        let cfg = Config::load_from_str(
+            r#"
+            dialect = "postgresql"
+            [dbCredentials]
+            host = "db"
+            database = "app"
+            ssl = "verify-full"
+            sslRootCert = "certs/ca.pem"
+        "#,
+            &tmp.path().join("drizzle.config.toml"),
+        )
+        .unwrap();
+        let db = cfg.default_database().unwrap();
+
+        match db.resolve_credentials(&|_| None) {
+            Ok(Some(Credentials::Postgres(PostgresCreds::Host { ssl_root_cert, .. }))) => {
+                assert_eq!(ssl_root_cert, Some(tmp.path().join("certs/ca.pem")));
+            }
+            other => panic!("expected Credentials::Postgres(Host), got {other:?}"),
+        }
+
+        // The bundle doesn't exist yet, which `validate` should point out.
+        assert!(
+            db.issues().iter().any(
+                |issue| issue.field == "dbCredentials" && issue.message.contains("sslRootCert")
+            )
+        );
+    }
+
     #[test]
     fn multi_database() {
         let cfg = Config::load_from_str(
@@ -1867,6 +2570,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn migrations_tag_format() {
+        let load = |migrations: &str| {
+            Config::load_from_str(
+                &format!(
+                    r#"
+                    dialect = "sqlite"
+                    [migrations]
+                    {migrations}
+                    [dbCredentials]
+                    url = "./dev.db"
+                "#
+                ),
+                Path::new("test.toml"),
+            )
+        };
+
+        let cfg = load(r#"tag_format = "{index}_{name}""#).unwrap();
+        let format = cfg
+            .default_database()
+            .unwrap()
+            .migration_tag_format()
+            .unwrap();
+        assert_eq!(format.render(2, Some("users")).unwrap(), "0002_users");
+
+        let cfg = load(r#"prefix = "index""#).unwrap();
+        let format = cfg
+            .default_database()
+            .unwrap()
+            .migration_tag_format()
+            .unwrap();
+        assert!(format.has_index());
+
+        assert!(matches!(
+            load(r#"tag_format = "{index}_{oops}""#),
+            Err(Error::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            load(
+                r#"prefix = "index"
+                   tag_format = "{index}_{name}""#
+            ),
+            Err(Error::InvalidConfig(_))
+        ));
+    }
+
     #[test]
     fn migrations_config() {
         let cfg = Config::load_from_str(
@@ -1875,6 +2624,7 @@ mod tests {
             [migrations]
             table = "custom_migrations"
             schema = "custom_schema"
+            audit = true
             [dbCredentials]
             url = "postgres://localhost/db"
         "#,
@@ -1884,6 +2634,7 @@ mod tests {
         let db = cfg.default_database().unwrap();
         assert_eq!(db.migrations_table(), "custom_migrations");
         assert_eq!(db.migrations_schema(), "custom_schema");
+        assert!(db.migrations_audit());
 
         // Test defaults
         let cfg2 = Config::load_from_str(
@@ -1898,6 +2649,7 @@ mod tests {
         let db2 = cfg2.default_database().unwrap();
         assert_eq!(db2.migrations_table(), "__drizzle_migrations");
         assert_eq!(db2.migrations_schema(), "drizzle");
+        assert!(!db2.migrations_audit());
     }
 
     #[test]
@@ -1931,6 +2683,76 @@ mod tests {
         assert_eq!(files[0], schema_path);
     }
 
+    #[test]
+    fn validate_reports_environment_issues_per_database() {
+        let tmp = TempDir::new().unwrap();
+        let schema_path = tmp.path().join("schema.rs");
+        fs::write(&schema_path, "#[allow(dead_code)]\npub struct X;").unwrap();
+        let missing_dir = tmp.path().join("missing").join("dev.db");
+
+        let cfg = Config::load_from_str(
+            &format!(
+                r#"
+            [databases.good]
+            dialect = "sqlite"
+            schema = "schema.rs"
+            [databases.good.dbCredentials]
+            url = "./dev.db"
+
+            [databases.bad]
+            dialect = "sqlite"
+            schema = "nope/*.rs"
+            [databases.bad.dbCredentials]
+            url = "{}"
+        "#,
+                missing_dir.display().to_string().replace('\\', "/")
+            ),
+            &tmp.path().join("drizzle.config.toml"),
+        )
+        .unwrap();
+
+        assert!(cfg.database(Some("good")).unwrap().issues().is_empty());
+
+        let Err(Error::Validation(issues)) = cfg.validate() else {
+            panic!("expected validation issues");
+        };
+        let fields = issues
+            .iter()
+            .map(|issue| (issue.database.as_deref(), issue.field))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![(Some("bad"), "schema"), (Some("bad"), "dbCredentials")]
+        );
+        assert!(
+            issues[1]
+                .to_string()
+                .starts_with("databases.bad.dbCredentials: ")
+        );
+    }
+
+    #[test]
+    fn validate_rejects_out_path_that_is_a_file() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("schema.rs"), "pub struct X;").unwrap();
+        fs::write(tmp.path().join("drizzle"), "").unwrap();
+
+        let cfg = Config::load_from_str(
+            r#"
+            dialect = "sqlite"
+            schema = "schema.rs"
+            out = "drizzle"
+        "#,
+            &tmp.path().join("drizzle.config.toml"),
+        )
+        .unwrap();
+
+        let issues = cfg.default_database().unwrap().issues();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].field, "out");
+        assert!(issues[0].message.contains("not a directory"));
+    }
+
     #[test]
     fn rejects_host_credentials_for_sqlite() {
         let err = Config::load_from_str(
@@ -2020,6 +2842,196 @@ mod tests {
         }
     }
 
+    #[test]
+    fn url_env_is_shorthand_for_env_url() {
+        unsafe {
+            std::env::set_var("TEST_URL_ENV_SQLITE", "./from-env.db");
+            std::env::set_var("TEST_URL_ENV_PG", "postgres://u@db/app");
+        }
+        let sqlite = Config::load_from_str(
+            r#"
+            dialect = "sqlite"
+            [dbCredentials]
+            urlEnv = "TEST_URL_ENV_SQLITE"
+        "#,
+            Path::new("test.toml"),
+        )
+        .unwrap();
+        match sqlite.credentials().unwrap() {
+            Some(Credentials::Sqlite { path }) => assert_eq!(&*path, "./from-env.db"),
+            other => panic!("expected Credentials::Sqlite, got {other:?}"),
+        }
+
+        let pg = Config::load_from_str(
+            r#"
+            dialect = "postgresql"
+            [dbCredentials]
+            url_env = "TEST_URL_ENV_PG"
+        "#,
+            Path::new("test.toml"),
+        )
+        .unwrap();
+        match pg.credentials().unwrap() {
+            Some(Credentials::Postgres(PostgresCreds::Url(url))) => {
+                assert_eq!(&*url, "postgres://u@db/app");
+            }
+            other => panic!("expected Credentials::Postgres(Url), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn turso_encryption_key_resolves_from_env() {
+        unsafe {
+            std::env::set_var("TEST_TURSO_ENCRYPTION_KEY", "c2VjcmV0");
+        }
+        let turso = Config::load_from_str(
+            r#"
+            dialect = "turso"
+            [dbCredentials]
+            url = "libsql://example.turso.io"
+            encryptionKey = { env = "TEST_TURSO_ENCRYPTION_KEY" }
+        "#,
+            Path::new("test.toml"),
+        )
+        .unwrap();
+        match turso.credentials().unwrap() {
+            Some(Credentials::Turso { encryption_key, .. }) => {
+                assert_eq!(encryption_key.as_deref(), Some("c2VjcmV0"));
+            }
+            other => panic!("expected Credentials::Turso, got {other:?}"),
+        }
+
+        let err = Config::load_from_str(
+            r#"
+            dialect = "sqlite"
+            [dbCredentials]
+            url = "./local.db"
+            encryptionKey = "secret"
+        "#,
+            Path::new("test.toml"),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("encryptionKey"), "{err}");
+    }
+
+    #[test]
+    fn password_file_is_read_relative_to_config_dir() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("db_password"), "s3cret\n").unwrap();
+
+        let cfg = Config::load_from_str(
+            r#"
+            dialect = "postgresql"
+            [dbCredentials]
+            host = "db"
+            user = "app"
+            password_file = "db_password"
+            database = "app"
+        "#,
+            &tmp.path().join("drizzle.config.toml"),
+        )
+        .unwrap();
+
+        match cfg
+            .default_database()
+            .unwrap()
+            .resolve_credentials(&|_| None)
+        {
+            Ok(Some(Credentials::Postgres(PostgresCreds::Host { password, .. }))) => {
+                assert_eq!(password.as_deref(), Some("s3cret"));
+            }
+            other => panic!("expected Credentials::Postgres(Host), got {other:?}"),
+        }
+
+        let err = Config::load_from_str(
+            r#"
+            dialect = "postgresql"
+            [dbCredentials]
+            host = "db"
+            password = "inline"
+            passwordFile = "/run/secrets/db_password"
+        "#,
+            Path::new("test.toml"),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("password or passwordFile"));
+    }
+
+    #[test]
+    fn postgres_credentials_fall_back_to_libpq_env() {
+        let env = |name: &str| {
+            match name {
+                "PGHOST" => Some("pg.internal"),
+                "PGPORT" => Some("6543"),
+                "PGUSER" => Some("svc"),
+                "PGPASSWORD" => Some("pw"),
+                "PGSSLMODE" => Some("require"),
+                "PGSSLROOTCERT" => Some("/certs/ca.pem"),
+                _ => None,
+            }
+            .map(String::from)
+        };
+
+        let bare =
+            Config::load_from_str(r#"dialect = "postgresql""#, Path::new("test.toml")).unwrap();
+        match bare.default_database().unwrap().resolve_credentials(&env) {
+            Ok(Some(Credentials::Postgres(PostgresCreds::Host {
+                host,
+                port,
+                user,
+                password,
+                database,
+                ssl,
+                ssl_root_cert,
+            }))) => {
+                assert_eq!(&*host, "pg.internal");
+                assert_eq!(port, 6543);
+                assert_eq!(user.as_deref(), Some("svc"));
+                assert_eq!(password.as_deref(), Some("pw"));
+                // libpq defaults the database to the user name.
+                assert_eq!(&*database, "svc");
+                assert_eq!(ssl, PostgresSslMode::Require);
+                assert_eq!(ssl_root_cert.as_deref(), Some(Path::new("/certs/ca.pem")));
+            }
+            other => panic!("expected Credentials::Postgres(Host), got {other:?}"),
+        }
+        assert!(
+            bare.default_database()
+                .unwrap()
+                .resolve_credentials(&|_| None)
+                .unwrap()
+                .is_none()
+        );
+
+        let partial = Config::load_from_str(
+            r#"
+            dialect = "postgresql"
+            [dbCredentials]
+            host = "explicit"
+            database = "app"
+        "#,
+            Path::new("test.toml"),
+        )
+        .unwrap();
+        match partial
+            .default_database()
+            .unwrap()
+            .resolve_credentials(&env)
+        {
+            Ok(Some(Credentials::Postgres(PostgresCreds::Host {
+                host,
+                port,
+                database,
+                ..
+            }))) => {
+                assert_eq!(&*host, "explicit");
+                assert_eq!(port, 6543);
+                assert_eq!(&*database, "app");
+            }
+            other => panic!("expected Credentials::Postgres(Host), got {other:?}"),
+        }
+    }
+
     #[test]
     fn d1_credentials_require_sqlite_dialect() {
         let err = Config::load_from_str(
@@ -2322,10 +3334,12 @@ mod tests {
             password: Some("super-secret-password".into()),
             database: "app".into(),
             ssl: PostgresSslMode::Require,
+            ssl_root_cert: None,
         });
         let turso = Credentials::Turso {
             url: "libsql://example.turso.io".into(),
             auth_token: Some("super-secret-token".into()),
+            encryption_key: Some("super-secret-key".into()),
         };
         let d1 = Credentials::D1 {
             account_id: "account".into(),
@@ -253,13 +253,12 @@ pub(super) fn inspect_migrations(
     account_id: &str,
     database_id: &str,
     token: &str,
-) -> Result<super::MigrationPlan, CliError> {
+) -> Result<Vec<AppliedMigrationRecord>, CliError> {
     let rt = rt()?;
     rt.block_on(async {
         let c = client(account_id, database_id, token)?;
         ensure_tracking_table(&c, set).await?;
-        let applied = query_applied_records(&c, set).await?;
-        super::build_migration_plan(set, &applied)
+        query_applied_records(&c, set).await
     })
 }
 
@@ -285,26 +284,34 @@ pub(super) fn run_migrations(
 
         // Build one big SQL blob per migration: statements + the record_migration
         // INSERT. Each migration is sent as its own batch so a failure in a later
-        // migration doesn't erase the success of the earlier ones.
+        // migration doesn't erase the success of the earlier ones. Statements
+        // that can't run inside a transaction split the batch and go alone.
         let mut applied_hashes = Vec::new();
         for migration in &pending {
-            let mut stmts: Vec<&str> = migration
-                .statements()
-                .iter()
-                .map(std::string::String::as_str)
-                .filter(|s| !s.trim().is_empty())
-                .collect();
             let record_sql = set.record_migration_sql(migration);
+            let mut batches = Vec::new();
+            let mut stmts: Vec<&str> = Vec::new();
+            for step in migration.steps() {
+                if step.no_transaction {
+                    batches.push(std::mem::take(&mut stmts));
+                    batches.push(vec![step.sql]);
+                } else {
+                    stmts.push(step.sql);
+                }
+            }
             stmts.push(&record_sql);
-
-            c.batch(&stmts).await.map_err(|e| match e {
-                CliError::MigrationError(inner) => CliError::MigrationError(format!(
-                    "Migration '{}' failed: {}",
-                    migration.hash(),
-                    inner
-                )),
-                other => other,
-            })?;
+            batches.push(stmts);
+
+            for stmts in batches.iter().filter(|stmts| !stmts.is_empty()) {
+                c.batch(stmts).await.map_err(|e| match e {
+                    CliError::MigrationError(inner) => CliError::MigrationError(format!(
+                        "Migration '{}' failed: {}",
+                        migration.hash(),
+                        inner
+                    )),
+                    other => other,
+                })?;
+            }
 
             applied_hashes.push(migration.hash().to_string());
         }
@@ -0,0 +1,284 @@
+//! Driver-agnostic migration application.
+//!
+//! Each driver implements [`MigrationExecutor`] over its connection;
+//! [`apply_migrations`] and [`apply_statements`] hold the one copy of the
+//! transaction handling. Blocking drivers implement the trait too — their
+//! futures never yield, so [`block_on_sync`] runs them without a runtime.
+
+use std::fmt::Display;
+
+use drizzle_migrations::Migrations;
+
+use super::MigrationResult;
+use crate::error::CliError;
+
+/// A connection migrations can be applied through.
+pub(crate) trait MigrationExecutor {
+    type Error: Display;
+
+    /// Runs `sql`, which may hold several statements.
+    async fn execute(&mut self, sql: &str) -> Result<(), Self::Error>;
+
+    /// Runs a query and returns the first column of every row.
+    async fn query_strings(&mut self, sql: &str) -> Result<Vec<String>, Self::Error>;
+
+    /// Opens the transaction migrations run in.
+    async fn begin(&mut self) -> Result<(), Self::Error>;
+
+    async fn commit(&mut self) -> Result<(), Self::Error>;
+
+    async fn rollback(&mut self) -> Result<(), Self::Error>;
+}
+
+fn migration_error(error: impl Display) -> CliError {
+    CliError::MigrationError(error.to_string())
+}
+
+/// Applies the pending migrations of `set` in one transaction.
+///
+/// Steps marked `no_transaction` commit what ran so far, run on their own,
+/// and reopen the transaction. The tracking table must already exist.
+pub(crate) async fn apply_migrations<E: MigrationExecutor>(
+    executor: &mut E,
+    set: &Migrations,
+) -> Result<MigrationResult, CliError> {
+    executor.begin().await.map_err(migration_error)?;
+    match apply_pending(executor, set).await {
+        Ok(applied) => {
+            executor.commit().await.map_err(migration_error)?;
+            Ok(MigrationResult {
+                applied_count: applied.len(),
+                applied_migrations: applied,
+            })
+        }
+        Err(error) => {
+            let _ = executor.rollback().await;
+            Err(error)
+        }
+    }
+}
+
+async fn apply_pending<E: MigrationExecutor>(
+    executor: &mut E,
+    set: &Migrations,
+) -> Result<Vec<String>, CliError> {
+    let applied_names = executor
+        .query_strings(&set.applied_names_sql())
+        .await
+        .map_err(migration_error)?;
+
+    let mut applied = Vec::new();
+    for migration in set.pending(&applied_names) {
+        let started = std::time::Instant::now();
+        let failed = |error: E::Error| {
+            CliError::MigrationError(format!("Migration '{}' failed: {error}", migration.hash()))
+        };
+        for step in migration.steps() {
+            if step.no_transaction {
+                // e.g. VACUUM or CREATE INDEX CONCURRENTLY can't run inside a
+                // transaction block.
+                executor.commit().await.map_err(failed)?;
+                executor.execute(step.sql).await.map_err(failed)?;
+                executor.begin().await.map_err(failed)?;
+            } else {
+                executor.execute(step.sql).await.map_err(failed)?;
+            }
+        }
+        executor
+            .execute(&set.record_applied_migration_sql(migration, started.elapsed()))
+            .await
+            .map_err(migration_error)?;
+        applied.push(migration.hash().to_string());
+    }
+    Ok(applied)
+}
+
+/// Records the first migration of `set` as applied without running it, for
+/// `--init` against a database that already has the schema.
+pub(crate) async fn record_baseline<E: MigrationExecutor>(
+    executor: &mut E,
+    set: &Migrations,
+) -> Result<(), CliError> {
+    let applied_names = executor
+        .query_strings(&set.applied_names_sql())
+        .await
+        .map_err(migration_error)?;
+    super::validate_init_metadata(&applied_names, set)?;
+
+    let Some(first) = set.all().first() else {
+        return Ok(());
+    };
+    executor
+        .execute(&set.record_migration_sql(first))
+        .await
+        .map_err(migration_error)
+}
+
+/// Runs `statements` in order, skipping blank ones, inside a transaction
+/// unless `transactional` is false.
+pub(crate) async fn apply_statements<E: MigrationExecutor>(
+    executor: &mut E,
+    statements: &[String],
+    transactional: bool,
+) -> Result<(), CliError> {
+    if transactional {
+        executor.begin().await.map_err(migration_error)?;
+    }
+    for stmt in statements {
+        let s = stmt.trim();
+        if s.is_empty() {
+            continue;
+        }
+        if let Err(error) = executor.execute(s).await {
+            if transactional {
+                let _ = executor.rollback().await;
+            }
+            return Err(CliError::MigrationError(format!(
+                "Statement failed: {error}\n{s}"
+            )));
+        }
+    }
+    if transactional {
+        executor.commit().await.map_err(migration_error)?;
+    }
+    Ok(())
+}
+
+/// Runs a future built on a blocking executor to completion.
+#[cfg(any(feature = "rusqlite", feature = "postgres-sync"))]
+pub(crate) fn block_on_sync<F: Future>(future: F) -> F::Output {
+    use std::task::{Context, Poll, Waker};
+
+    let mut future = std::pin::pin!(future);
+    match future
+        .as_mut()
+        .poll(&mut Context::from_waker(Waker::noop()))
+    {
+        Poll::Ready(output) => output,
+        Poll::Pending => unreachable!("blocking migration executors never yield"),
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+pub(crate) struct RusqliteExecutor<'a>(pub(crate) &'a rusqlite::Connection);
+
+#[cfg(feature = "rusqlite")]
+impl MigrationExecutor for RusqliteExecutor<'_> {
+    type Error = rusqlite::Error;
+
+    async fn execute(&mut self, sql: &str) -> Result<(), Self::Error> {
+        self.0.execute_batch(sql)
+    }
+
+    async fn query_strings(&mut self, sql: &str) -> Result<Vec<String>, Self::Error> {
+        let mut stmt = match self.0.prepare(sql) {
+            Ok(stmt) => stmt,
+            Err(error) if super::is_sqlite_missing_table(&error) => return Ok(Vec::new()),
+            Err(error) => return Err(error),
+        };
+        stmt.query_map([], |row| row.get::<_, String>(0))?.collect()
+    }
+
+    async fn begin(&mut self) -> Result<(), Self::Error> {
+        self.0.execute_batch("BEGIN IMMEDIATE")
+    }
+
+    async fn commit(&mut self) -> Result<(), Self::Error> {
+        self.0.execute_batch("COMMIT")
+    }
+
+    async fn rollback(&mut self) -> Result<(), Self::Error> {
+        self.0.execute_batch("ROLLBACK")
+    }
+}
+
+/// Local libsql files and remote Turso databases alike.
+#[cfg(any(feature = "libsql", feature = "turso"))]
+pub(crate) struct LibsqlExecutor<'a>(pub(crate) &'a libsql::Connection);
+
+#[cfg(any(feature = "libsql", feature = "turso"))]
+impl MigrationExecutor for LibsqlExecutor<'_> {
+    type Error = libsql::Error;
+
+    async fn execute(&mut self, sql: &str) -> Result<(), Self::Error> {
+        self.0.execute_batch(sql).await.map(|_| ())
+    }
+
+    async fn query_strings(&mut self, sql: &str) -> Result<Vec<String>, Self::Error> {
+        let mut rows = self.0.query(sql, ()).await?;
+        let mut values = Vec::new();
+        while let Some(row) = rows.next().await? {
+            values.push(row.get::<String>(0)?);
+        }
+        Ok(values)
+    }
+
+    async fn begin(&mut self) -> Result<(), Self::Error> {
+        self.execute("BEGIN IMMEDIATE").await
+    }
+
+    async fn commit(&mut self) -> Result<(), Self::Error> {
+        self.execute("COMMIT").await
+    }
+
+    async fn rollback(&mut self) -> Result<(), Self::Error> {
+        self.execute("ROLLBACK").await
+    }
+}
+
+#[cfg(feature = "postgres-sync")]
+impl MigrationExecutor for postgres::Client {
+    type Error = postgres::Error;
+
+    async fn execute(&mut self, sql: &str) -> Result<(), Self::Error> {
+        self.batch_execute(sql)
+    }
+
+    async fn query_strings(&mut self, sql: &str) -> Result<Vec<String>, Self::Error> {
+        self.query(sql, &[])?
+            .iter()
+            .map(|row| row.try_get(0))
+            .collect()
+    }
+
+    async fn begin(&mut self) -> Result<(), Self::Error> {
+        self.batch_execute("BEGIN")
+    }
+
+    async fn commit(&mut self) -> Result<(), Self::Error> {
+        self.batch_execute("COMMIT")
+    }
+
+    async fn rollback(&mut self) -> Result<(), Self::Error> {
+        self.batch_execute("ROLLBACK")
+    }
+}
+
+#[cfg(feature = "tokio-postgres")]
+impl MigrationExecutor for tokio_postgres::Client {
+    type Error = tokio_postgres::Error;
+
+    async fn execute(&mut self, sql: &str) -> Result<(), Self::Error> {
+        self.batch_execute(sql).await
+    }
+
+    async fn query_strings(&mut self, sql: &str) -> Result<Vec<String>, Self::Error> {
+        self.query(sql, &[])
+            .await?
+            .iter()
+            .map(|row| row.try_get(0))
+            .collect()
+    }
+
+    async fn begin(&mut self) -> Result<(), Self::Error> {
+        self.batch_execute("BEGIN").await
+    }
+
+    async fn commit(&mut self) -> Result<(), Self::Error> {
+        self.batch_execute("COMMIT").await
+    }
+
+    async fn rollback(&mut self) -> Result<(), Self::Error> {
+        self.batch_execute("ROLLBACK").await
+    }
+}
@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 
 use drizzle_migrations::schema::Snapshot;
+use drizzle_types::MigrationTracking;
 
 use super::SnapshotFilters;
 use crate::config::{Dialect, Extension};
@@ -18,15 +19,98 @@ pub fn apply_snapshot_filters(
 
     match (dialect, snapshot) {
         (Dialect::Sqlite | Dialect::Turso, Snapshot::Sqlite(sqlite)) => {
+            if let Some(tracking) = &filters.tracking {
+                exclude_sqlite_tracking_table(sqlite, tracking);
+            }
             apply_sqlite_snapshot_filters(sqlite, filters)
         }
         (Dialect::Postgresql, Snapshot::Postgres(postgres)) => {
+            if let Some(tracking) = &filters.tracking {
+                exclude_postgres_tracking_table(postgres, tracking);
+            }
             apply_postgres_snapshot_filters(postgres, filters)
         }
         _ => Err(CliError::DialectMismatch),
     }
 }
 
+/// Drop the migrations tracking table so push and pull never treat it as user schema.
+///
+/// The introspection queries already skip the default `__drizzle_migrations`
+/// name; this covers a renamed table.
+fn exclude_sqlite_tracking_table(
+    snapshot: &mut drizzle_migrations::sqlite::SQLiteSnapshot,
+    tracking: &MigrationTracking,
+) {
+    use drizzle_types::sqlite::ddl::SqliteEntity;
+
+    let is_tracking = |table: &str| table == tracking.table.as_ref();
+
+    snapshot.ddl.retain(|entity| match entity {
+        SqliteEntity::Table(table) => !is_tracking(table.name.as_ref()),
+        SqliteEntity::Column(column) => !is_tracking(column.table.as_ref()),
+        SqliteEntity::Index(index) => !is_tracking(index.table.as_ref()),
+        SqliteEntity::ForeignKey(foreign_key) => !is_tracking(foreign_key.table.as_ref()),
+        SqliteEntity::PrimaryKey(primary_key) => !is_tracking(primary_key.table.as_ref()),
+        SqliteEntity::UniqueConstraint(unique) => !is_tracking(unique.table.as_ref()),
+        SqliteEntity::CheckConstraint(check) => !is_tracking(check.table.as_ref()),
+        SqliteEntity::View(_) => true,
+    });
+}
+
+/// Drop the migrations tracking table with its `SERIAL` sequence, and its
+/// schema once nothing else lives there.
+fn exclude_postgres_tracking_table(
+    snapshot: &mut drizzle_migrations::postgres::PostgresSnapshot,
+    tracking: &MigrationTracking,
+) {
+    use drizzle_types::postgres::ddl::PostgresEntity;
+
+    let tracking_schema = tracking.schema.as_deref().unwrap_or("public");
+    let is_tracking =
+        |schema: &str, table: &str| schema == tracking_schema && table == tracking.table.as_ref();
+    let id_sequence = format!("{}_id_seq", tracking.table);
+
+    snapshot.ddl.retain(|entity| match entity {
+        PostgresEntity::Table(table) => !is_tracking(&table.schema, &table.name),
+        PostgresEntity::Column(column) => !is_tracking(&column.schema, &column.table),
+        PostgresEntity::Index(index) => !is_tracking(&index.schema, &index.table),
+        PostgresEntity::ForeignKey(foreign_key) => {
+            !is_tracking(&foreign_key.schema, &foreign_key.table)
+        }
+        PostgresEntity::PrimaryKey(primary_key) => {
+            !is_tracking(&primary_key.schema, &primary_key.table)
+        }
+        PostgresEntity::UniqueConstraint(unique) => !is_tracking(&unique.schema, &unique.table),
+        PostgresEntity::CheckConstraint(check) => !is_tracking(&check.schema, &check.table),
+        PostgresEntity::ExcludeConstraint(exclude) => !is_tracking(&exclude.schema, &exclude.table),
+        PostgresEntity::Policy(policy) => !is_tracking(&policy.schema, &policy.table),
+        PostgresEntity::Sequence(sequence) => {
+            !(sequence.schema == tracking_schema && sequence.name == id_sequence)
+        }
+        _ => true,
+    });
+
+    if tracking_schema == "public" {
+        return;
+    }
+
+    let schema_in_use = snapshot.ddl.iter().any(|entity| match entity {
+        PostgresEntity::Table(table) => table.schema == tracking_schema,
+        PostgresEntity::View(view) => view.schema == tracking_schema,
+        PostgresEntity::Enum(value) => value.schema == tracking_schema,
+        PostgresEntity::Domain(domain) => domain.schema == tracking_schema,
+        PostgresEntity::Composite(composite) => composite.schema == tracking_schema,
+        PostgresEntity::Sequence(sequence) => sequence.schema == tracking_schema,
+        _ => false,
+    });
+    if !schema_in_use {
+        snapshot.ddl.retain(|entity| {
+            !matches!(entity, PostgresEntity::Schema(schema) if schema.name == tracking_schema)
+        });
+    }
+}
+
 fn apply_sqlite_snapshot_filters(
     snapshot: &mut drizzle_migrations::sqlite::SQLiteSnapshot,
     filters: &SnapshotFilters,
@@ -127,6 +211,8 @@ fn apply_postgres_snapshot_filters(
     snapshot.ddl.retain(|entity| match entity {
         PostgresEntity::Schema(schema) => keep_schemas.contains(schema.name.as_ref()),
         PostgresEntity::Enum(value) => keep_schemas.contains(value.schema.as_ref()),
+        PostgresEntity::Domain(domain) => keep_schemas.contains(domain.schema.as_ref()),
+        PostgresEntity::Composite(composite) => keep_schemas.contains(composite.schema.as_ref()),
         PostgresEntity::Sequence(sequence) => keep_schemas.contains(sequence.schema.as_ref()),
         PostgresEntity::Role(_) | PostgresEntity::Privilege(_) => true,
         PostgresEntity::Policy(policy) => {
@@ -160,6 +246,9 @@ fn apply_postgres_snapshot_filters(
         PostgresEntity::CheckConstraint(check) => {
             keep_tables.contains(&(check.schema.to_string(), check.table.to_string()))
         }
+        PostgresEntity::ExcludeConstraint(exclude) => {
+            keep_tables.contains(&(exclude.schema.to_string(), exclude.table.to_string()))
+        }
         PostgresEntity::View(view) => {
             keep_schemas.contains(view.schema.as_ref())
                 && matches_patterns(view.name.as_ref(), table_patterns.as_deref())
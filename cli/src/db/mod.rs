@@ -9,21 +9,21 @@ use std::path::Path;
 use crate::config::PostgresCreds;
 use crate::config::{Credentials, Dialect, Extension, IntrospectCasing};
 use crate::error::CliError;
-use crate::output;
+use crate::output::{self, out, outln};
+use drizzle_migrations::schema::Snapshot;
+use drizzle_migrations::{ChecksumMismatch, Migrations, is_destructive_statement};
+use serde::Serialize;
+
+#[cfg(feature = "d1-http")]
+mod d1_http;
 #[cfg(any(
-    test,
     feature = "rusqlite",
     feature = "libsql",
     feature = "turso",
     feature = "postgres-sync",
     feature = "tokio-postgres",
-    feature = "d1-http",
 ))]
-use drizzle_migrations::Migrations;
-use drizzle_migrations::schema::Snapshot;
-
-#[cfg(feature = "d1-http")]
-mod d1_http;
+mod executor;
 mod filters;
 
 pub use filters::apply_snapshot_filters;
@@ -31,7 +31,8 @@ pub use filters::apply_snapshot_filters;
 use filters::{compile_patterns, matches_patterns};
 
 /// Result of a migration run
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MigrationResult {
     /// Number of migrations applied
     pub applied_count: usize,
@@ -40,7 +41,8 @@ pub struct MigrationResult {
 }
 
 /// Planned migration execution details.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MigrationPlan {
     /// Number of already-applied migrations found in the database metadata table.
     pub applied_count: usize,
@@ -52,15 +54,22 @@ pub struct MigrationPlan {
     pub pending_statements: usize,
 }
 
-#[cfg(any(
-    test,
-    feature = "rusqlite",
-    feature = "libsql",
-    feature = "turso",
-    feature = "postgres-sync",
-    feature = "tokio-postgres",
-    feature = "d1-http",
-))]
+/// An applied migration as recorded in the tracking table.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppliedMigrationHistory {
+    /// Migration folder name
+    pub name: String,
+    /// When the migration was recorded
+    pub applied_at: Option<String>,
+    /// Role and client host that applied it (audited tables only)
+    pub applied_by: Option<String>,
+    /// Time spent applying it, in milliseconds (audited tables only)
+    pub duration_ms: Option<i64>,
+    /// Number of statements it ran (audited tables only)
+    pub statement_count: Option<i32>,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct AppliedMigrationRecord {
     pub(crate) hash: String,
@@ -68,7 +77,8 @@ pub(crate) struct AppliedMigrationRecord {
 }
 
 /// Planned SQL changes for `drizzle push`
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PushPlan {
     pub sql_statements: Vec<String>,
     pub warnings: Vec<String>,
@@ -81,11 +91,16 @@ pub struct SnapshotFilters {
     pub tables: Option<Vec<String>>,
     pub schemas: Option<Vec<String>>,
     pub extensions: Option<Vec<Extension>>,
+    /// Migrations tracking table to leave out of the snapshot.
+    pub tracking: Option<drizzle_types::MigrationTracking>,
 }
 
 impl SnapshotFilters {
     const fn is_empty(&self) -> bool {
-        self.tables.is_none() && self.schemas.is_none() && self.extensions.is_none()
+        self.tables.is_none()
+            && self.schemas.is_none()
+            && self.extensions.is_none()
+            && self.tracking.is_none()
     }
 }
 
@@ -102,9 +117,22 @@ pub fn plan_push(
     breakpoints: bool,
     filters: &SnapshotFilters,
 ) -> Result<PushPlan, CliError> {
-    let mut current = introspect_database(credentials, dialect)?.snapshot;
-    apply_snapshot_filters(&mut current, dialect, filters)?;
-    let (sql_statements, warnings) = generate_push_sql(&current, desired, breakpoints)?;
+    let current = introspect_snapshot(credentials, dialect, filters)?;
+    plan_diff(&current, desired, breakpoints)
+}
+
+/// Plan the SQL that brings `current` in line with `desired`.
+///
+/// # Errors
+///
+/// Returns [`CliError::DialectMismatch`] if the snapshots are for different
+/// dialects.
+pub fn plan_diff(
+    current: &Snapshot,
+    desired: &Snapshot,
+    breakpoints: bool,
+) -> Result<PushPlan, CliError> {
+    let (sql_statements, warnings) = generate_push_sql(current, desired, breakpoints)?;
     let destructive = sql_statements.iter().any(|s| is_destructive_statement(s));
 
     Ok(PushPlan {
@@ -114,6 +142,22 @@ pub fn plan_push(
     })
 }
 
+/// Introspect the live database into a snapshot, with the given filters applied.
+///
+/// # Errors
+///
+/// Returns [`CliError`] if introspecting the live database fails or if
+/// applying the given snapshot filters fails.
+pub fn introspect_snapshot(
+    credentials: &Credentials,
+    dialect: Dialect,
+    filters: &SnapshotFilters,
+) -> Result<Snapshot, CliError> {
+    let mut snapshot = introspect_database(credentials, dialect)?.snapshot;
+    apply_snapshot_filters(&mut snapshot, dialect, filters)?;
+    Ok(snapshot)
+}
+
 /// Apply a previously planned push.
 ///
 /// # Errors
@@ -151,27 +195,79 @@ pub fn apply_push(
 /// Returns [`CliError`] if no compiled driver matches the credentials, if
 /// connecting to the database fails, or if reading the migration tracking
 /// table or on-disk migration files fails.
-#[allow(unused_variables)] // params consumed inside feature-gated block
 pub fn plan_migrations(
     credentials: &Credentials,
     dialect: Dialect,
     migrations_dir: &Path,
     migrations_table: &str,
     migrations_schema: &str,
+    to: Option<&str>,
 ) -> Result<MigrationPlan, CliError> {
-    #[cfg(any(
-        feature = "rusqlite",
-        feature = "libsql",
-        feature = "turso",
-        feature = "postgres-sync",
-        feature = "tokio-postgres",
-        feature = "d1-http",
-    ))]
-    let set = load_migration_set(dialect, migrations_dir, migrations_table, migrations_schema)?;
+    let set = load_migration_set(
+        dialect,
+        migrations_dir,
+        migrations_table,
+        migrations_schema,
+        false,
+        to,
+    )?;
+    let applied = inspect_applied_migrations(credentials, &set)?;
+    build_migration_plan(&set, &applied)
+}
+
+/// Re-baseline the recorded hashes of applied migrations whose local files
+/// were edited after they ran.
+///
+/// Returns the migrations whose hashes were rewritten; nothing is executed
+/// when there is no drift.
+///
+/// # Errors
+///
+/// Returns the same errors as [`plan_migrations`], or [`CliError`] if
+/// updating the tracking table fails.
+pub fn repair_migrations(
+    credentials: &Credentials,
+    dialect: Dialect,
+    migrations_dir: &Path,
+    migrations_table: &str,
+    migrations_schema: &str,
+) -> Result<Vec<ChecksumMismatch>, CliError> {
+    let set = load_migration_set(
+        dialect,
+        migrations_dir,
+        migrations_table,
+        migrations_schema,
+        false,
+        None,
+    )?;
+    let applied = inspect_applied_migrations(credentials, &set)?
+        .into_iter()
+        .map(|record| (record.name, record.hash))
+        .collect::<Vec<_>>();
+
+    let mismatches = set.verify(&applied);
+    let statements = mismatches
+        .iter()
+        .filter_map(|mismatch| set.all().iter().find(|m| m.name() == mismatch.name))
+        .map(|migration| set.update_hash_sql(migration))
+        .collect::<Vec<_>>();
+    if !statements.is_empty() {
+        execute_statements(credentials, dialect, &statements)?;
+    }
+
+    Ok(mismatches)
+}
 
+/// Connect to the database, make sure the tracking table exists and read
+/// the applied migration records.
+#[allow(unused_variables)] // params consumed inside feature-gated block
+fn inspect_applied_migrations(
+    credentials: &Credentials,
+    set: &Migrations,
+) -> Result<Vec<AppliedMigrationRecord>, CliError> {
     match credentials {
         #[cfg(feature = "rusqlite")]
-        Credentials::Sqlite { path } => inspect_sqlite_migrations(&set, path),
+        Credentials::Sqlite { path } => inspect_sqlite_migrations(set, path),
 
         #[cfg(not(feature = "rusqlite"))]
         Credentials::Sqlite { .. } => Err(CliError::MissingDriver {
@@ -180,11 +276,15 @@ pub fn plan_migrations(
         }),
 
         #[cfg(any(feature = "libsql", feature = "turso"))]
-        Credentials::Turso { url, auth_token } => {
+        Credentials::Turso {
+            url,
+            auth_token,
+            encryption_key,
+        } => {
             if is_local_libsql(url) {
                 #[cfg(feature = "libsql")]
                 {
-                    inspect_libsql_local_migrations(&set, url)
+                    inspect_libsql_local_migrations(set, url, encryption_key.as_deref())
                 }
                 #[cfg(not(feature = "libsql"))]
                 {
@@ -196,7 +296,12 @@ pub fn plan_migrations(
             } else {
                 #[cfg(feature = "turso")]
                 {
-                    inspect_turso_migrations(&set, url, auth_token.as_deref())
+                    inspect_turso_migrations(
+                        set,
+                        url,
+                        auth_token.as_deref(),
+                        encryption_key.as_deref(),
+                    )
                 }
                 #[cfg(not(feature = "turso"))]
                 {
@@ -218,8 +323,8 @@ pub fn plan_migrations(
         Credentials::Postgres(creds) => {
             let _ = creds;
             core::cfg_select! {
-                feature = "postgres-sync" => inspect_postgres_sync_migrations(&set, creds),
-                feature = "tokio-postgres" => inspect_postgres_async_migrations(&set, creds),
+                feature = "postgres-sync" => inspect_postgres_sync_migrations(set, creds),
+                feature = "tokio-postgres" => inspect_postgres_async_migrations(set, creds),
                 _ => Err(CliError::MissingDriver {
                     dialect: "PostgreSQL",
                     feature: "postgres-sync or tokio-postgres",
@@ -232,7 +337,7 @@ pub fn plan_migrations(
             account_id,
             database_id,
             token,
-        } => d1_http::inspect_migrations(&set, account_id, database_id, token),
+        } => d1_http::inspect_migrations(set, account_id, database_id, token),
 
         #[cfg(not(feature = "d1-http"))]
         Credentials::D1 { .. } => Err(CliError::MissingDriver {
@@ -263,6 +368,7 @@ pub fn verify_migrations(
     migrations_dir: &Path,
     migrations_table: &str,
     migrations_schema: &str,
+    to: Option<&str>,
 ) -> Result<MigrationPlan, CliError> {
     plan_migrations(
         credentials,
@@ -270,12 +376,52 @@ pub fn verify_migrations(
         migrations_dir,
         migrations_table,
         migrations_schema,
+        to,
     )
 }
 
+/// Read the applied migration history, including the audit metadata written
+/// when `[migrations] audit = true`.
+///
+/// Nothing is created: a database without a tracking table has no history.
+///
+/// # Errors
+///
+/// Returns [`CliError`] if the database isn't `PostgreSQL`, no compiled
+/// driver matches, or querying the tracking table fails.
+pub fn migration_history(
+    credentials: &Credentials,
+    dialect: Dialect,
+    migrations_table: &str,
+    migrations_schema: &str,
+) -> Result<Vec<AppliedMigrationHistory>, CliError> {
+    let tracking = migration_tracking(dialect, migrations_table, migrations_schema);
+    let set = Migrations::with_tracking(Vec::new(), dialect.to_base(), tracking);
+
+    match credentials {
+        Credentials::Postgres(creds) => {
+            let _ = (creds, &set);
+            core::cfg_select! {
+                feature = "postgres-sync" => history_postgres_sync(&set, creds),
+                feature = "tokio-postgres" => history_postgres_async(&set, creds),
+                _ => Err(CliError::MissingDriver {
+                    dialect: "PostgreSQL",
+                    feature: "postgres-sync or tokio-postgres",
+                }),
+            }
+        }
+        _ => Err(CliError::Other(
+            "Migration audit history is only recorded for PostgreSQL".to_string(),
+        )),
+    }
+}
+
 /// Apply any pending migrations against the database referenced by
 /// `credentials`.
 ///
+/// With `audit`, `PostgreSQL` tracking tables also record who applied each
+/// migration, how long it took and how many statements it ran.
+///
 /// # Errors
 ///
 /// Returns [`CliError`] if no compiled driver matches, if connecting or
@@ -288,6 +434,8 @@ pub fn run_migrations(
     migrations_dir: &Path,
     migrations_table: &str,
     migrations_schema: &str,
+    audit: bool,
+    to: Option<&str>,
 ) -> Result<MigrationResult, CliError> {
     #[cfg(any(
         feature = "rusqlite",
@@ -297,7 +445,14 @@ pub fn run_migrations(
         feature = "tokio-postgres",
         feature = "d1-http",
     ))]
-    let set = load_migration_set(dialect, migrations_dir, migrations_table, migrations_schema)?;
+    let set = load_migration_set(
+        dialect,
+        migrations_dir,
+        migrations_table,
+        migrations_schema,
+        audit,
+        to,
+    )?;
 
     match credentials {
         #[cfg(feature = "rusqlite")]
@@ -310,11 +465,15 @@ pub fn run_migrations(
         }),
 
         #[cfg(any(feature = "libsql", feature = "turso"))]
-        Credentials::Turso { url, auth_token } => {
+        Credentials::Turso {
+            url,
+            auth_token,
+            encryption_key,
+        } => {
             if is_local_libsql(url) {
                 #[cfg(feature = "libsql")]
                 {
-                    run_libsql_local_migrations(&set, url)
+                    run_libsql_local_migrations(&set, url, encryption_key.as_deref())
                 }
                 #[cfg(not(feature = "libsql"))]
                 {
@@ -326,7 +485,12 @@ pub fn run_migrations(
             } else {
                 #[cfg(feature = "turso")]
                 {
-                    run_turso_migrations(&set, url, auth_token.as_deref())
+                    run_turso_migrations(
+                        &set,
+                        url,
+                        auth_token.as_deref(),
+                        encryption_key.as_deref(),
+                    )
                 }
                 #[cfg(not(feature = "turso"))]
                 {
@@ -381,42 +545,30 @@ pub fn run_migrations(
     }
 }
 
-#[cfg(any(
-    feature = "rusqlite",
-    feature = "libsql",
-    feature = "turso",
-    feature = "postgres-sync",
-    feature = "tokio-postgres",
-    feature = "d1-http",
-))]
 fn load_migration_set(
     dialect: Dialect,
     migrations_dir: &Path,
     migrations_table: &str,
     migrations_schema: &str,
+    audit: bool,
+    to: Option<&str>,
 ) -> Result<Migrations, CliError> {
-    let tracking = migration_tracking(dialect, migrations_table, migrations_schema);
+    let tracking = migration_tracking(dialect, migrations_table, migrations_schema).audit(audit);
 
     // Load migrations from filesystem
     let migrations = drizzle_migrations::MigrationDir::new(migrations_dir)
         .discover()
         .map_err(|e| CliError::Other(format!("Failed to load migrations: {e}")))?;
-    Ok(Migrations::with_tracking(
-        migrations,
-        dialect.to_base(),
-        tracking,
-    ))
+    let set = Migrations::with_tracking(migrations, dialect.to_base(), tracking);
+    match to {
+        Some(tag) => set
+            .up_to(tag)
+            .map_err(|_| CliError::Other(format!("No local migration is tagged '{tag}'"))),
+        None => Ok(set),
+    }
 }
 
-#[cfg(any(
-    feature = "rusqlite",
-    feature = "libsql",
-    feature = "turso",
-    feature = "postgres-sync",
-    feature = "tokio-postgres",
-    feature = "d1-http",
-))]
-fn migration_tracking(
+pub(crate) fn migration_tracking(
     dialect: Dialect,
     migrations_table: &str,
     migrations_schema: &str,
@@ -437,15 +589,6 @@ fn migration_tracking(
     tracking
 }
 
-#[cfg(any(
-    test,
-    feature = "rusqlite",
-    feature = "libsql",
-    feature = "turso",
-    feature = "postgres-sync",
-    feature = "tokio-postgres",
-    feature = "d1-http",
-))]
 pub(crate) fn build_migration_plan(
     set: &Migrations,
     applied: &[AppliedMigrationRecord],
@@ -473,15 +616,6 @@ pub(crate) fn build_migration_plan(
     })
 }
 
-#[cfg(any(
-    test,
-    feature = "rusqlite",
-    feature = "libsql",
-    feature = "turso",
-    feature = "postgres-sync",
-    feature = "tokio-postgres",
-    feature = "d1-http",
-))]
 fn verify_applied_migrations_consistency(
     set: &Migrations,
     applied: &[AppliedMigrationRecord],
@@ -771,6 +905,11 @@ fn ensure_postgres_tracking_table_sync(
     client
         .execute(&set.create_table_sql(), &[])
         .map_err(|e| CliError::MigrationError(format!("Failed to create migrations table: {e}")))?;
+    if let Some(sql) = set.add_audit_columns_sql() {
+        client
+            .execute(&sql, &[])
+            .map_err(|e| CliError::MigrationError(format!("Failed to add audit columns: {e}")))?;
+    }
 
     let schema = set.schema_name().unwrap_or("public");
     let rows = client
@@ -866,6 +1005,12 @@ async fn ensure_postgres_tracking_table_async(
         .execute(&set.create_table_sql(), &[])
         .await
         .map_err(|e| CliError::MigrationError(format!("Failed to create migrations table: {e}")))?;
+    if let Some(sql) = set.add_audit_columns_sql() {
+        client
+            .execute(&sql, &[])
+            .await
+            .map_err(|e| CliError::MigrationError(format!("Failed to add audit columns: {e}")))?;
+    }
 
     let schema = set.schema_name().unwrap_or("public");
     let rows = client
@@ -957,22 +1102,6 @@ async fn ensure_postgres_tracking_table_async(
     Ok(())
 }
 
-fn is_destructive_statement(sql: &str) -> bool {
-    let s = sql.trim().to_uppercase();
-    s.contains("DROP TABLE")
-        || s.contains("DROP COLUMN")
-        || s.contains("DROP INDEX")
-        || s.contains("DROP VIEW")
-        || s.contains("DROP MATERIALIZED VIEW")
-        || s.contains("DROP TYPE")
-        || s.contains("DROP SCHEMA")
-        || s.contains("DROP SEQUENCE")
-        || s.contains("DROP ROLE")
-        || s.contains("DROP POLICY")
-        || s.contains("TRUNCATE")
-        || (s.contains("ALTER TABLE") && s.contains(" DROP "))
-}
-
 #[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
 fn is_postgres_concurrent_index_statement(sql: &str) -> bool {
     drizzle_migrations::is_postgres_concurrent_index_statement(sql)
@@ -995,13 +1124,14 @@ fn confirm_destructive() -> Result<bool, CliError> {
         ));
     }
 
-    println!(
+    outln!(
         "{}",
         output::warning("Potentially destructive changes detected (DROP/TRUNCATE/etc).")
     );
-    print!("Apply anyway? [y/N]: ");
+    out!("Apply anyway? [y/N]: ");
     io::stdout()
         .flush()
+        .and_then(|()| io::stderr().flush())
         .map_err(|e| CliError::IoError(e.to_string()))?;
 
     let mut line = String::new();
@@ -1040,7 +1170,14 @@ fn generate_push_sql(
     }
 }
 
-fn execute_statements(
+/// Execute plain SQL statements against the database, in one transaction
+/// where the driver allows it.
+///
+/// # Errors
+///
+/// Returns [`CliError`] if no compiled driver matches the credentials, if
+/// connecting to the database fails, or if any statement fails.
+pub fn execute_statements(
     credentials: &Credentials,
     _dialect: Dialect,
     statements: &[String],
@@ -1060,11 +1197,15 @@ fn execute_statements(
         }),
 
         #[cfg(any(feature = "libsql", feature = "turso"))]
-        Credentials::Turso { url, auth_token } => {
+        Credentials::Turso {
+            url,
+            auth_token,
+            encryption_key,
+        } => {
             if is_local_libsql(url) {
                 #[cfg(feature = "libsql")]
                 {
-                    execute_libsql_local_statements(url, statements)
+                    execute_libsql_local_statements(url, encryption_key.as_deref(), statements)
                 }
                 #[cfg(not(feature = "libsql"))]
                 {
@@ -1076,7 +1217,12 @@ fn execute_statements(
             } else {
                 #[cfg(feature = "turso")]
                 {
-                    execute_turso_statements(url, auth_token.as_deref(), statements)
+                    execute_turso_statements(
+                        url,
+                        auth_token.as_deref(),
+                        encryption_key.as_deref(),
+                        statements,
+                    )
                 }
                 #[cfg(not(feature = "turso"))]
                 {
@@ -1149,26 +1295,11 @@ fn execute_sqlite_statements(path: &str, statements: &[String]) -> Result<(), Cl
         CliError::ConnectionError(format!("Failed to open SQLite database '{path}': {e}"))
     })?;
 
-    conn.execute("BEGIN", [])
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-    for stmt in statements {
-        let s = stmt.trim();
-        if s.is_empty() {
-            continue;
-        }
-        if let Err(e) = conn.execute(s, []) {
-            let _ = conn.execute("ROLLBACK", []);
-            return Err(CliError::MigrationError(format!(
-                "Statement failed: {e}\n{s}"
-            )));
-        }
-    }
-
-    conn.execute("COMMIT", [])
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-    Ok(())
+    executor::block_on_sync(executor::apply_statements(
+        &mut executor::RusqliteExecutor(&conn),
+        statements,
+        true,
+    ))
 }
 
 #[cfg(feature = "rusqlite")]
@@ -1180,60 +1311,23 @@ fn run_sqlite_migrations(set: &Migrations, path: &str) -> Result<MigrationResult
     ensure_sqlite_tracking_table(&conn, set)?;
     conn.busy_timeout(std::time::Duration::from_secs(30))
         .map_err(|e| CliError::MigrationError(e.to_string()))?;
-    conn.execute("BEGIN IMMEDIATE", [])
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-    let applied_names = query_applied_names_sqlite(&conn, set)?;
-
-    // Get pending migrations
-    let pending: Vec<_> = set.pending(&applied_names).collect();
-    if pending.is_empty() {
-        conn.execute("COMMIT", [])
-            .map_err(|e| CliError::MigrationError(e.to_string()))?;
-        return Ok(MigrationResult {
-            applied_count: 0,
-            applied_migrations: vec![],
-        });
-    }
-
-    let mut applied = Vec::new();
-    for migration in &pending {
-        for stmt in migration.statements() {
-            if !stmt.trim().is_empty()
-                && let Err(e) = conn.execute(stmt, [])
-            {
-                let _ = conn.execute("ROLLBACK", []);
-                return Err(CliError::MigrationError(format!(
-                    "Migration '{}' failed: {}",
-                    migration.hash(),
-                    e
-                )));
-            }
-        }
-        if let Err(e) = conn.execute(&set.record_migration_sql(migration), []) {
-            let _ = conn.execute("ROLLBACK", []);
-            return Err(CliError::MigrationError(e.to_string()));
-        }
-        applied.push(migration.hash().to_string());
-    }
-
-    conn.execute("COMMIT", [])
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-    Ok(MigrationResult {
-        applied_count: applied.len(),
-        applied_migrations: applied,
-    })
+    executor::block_on_sync(executor::apply_migrations(
+        &mut executor::RusqliteExecutor(&conn),
+        set,
+    ))
 }
 
 #[cfg(feature = "rusqlite")]
-fn inspect_sqlite_migrations(set: &Migrations, path: &str) -> Result<MigrationPlan, CliError> {
+fn inspect_sqlite_migrations(
+    set: &Migrations,
+    path: &str,
+) -> Result<Vec<AppliedMigrationRecord>, CliError> {
     let conn = rusqlite::Connection::open(path).map_err(|e| {
         CliError::ConnectionError(format!("Failed to open SQLite database '{path}': {e}"))
     })?;
 
     ensure_sqlite_tracking_table(&conn, set)?;
-    let applied = query_applied_records_sqlite(&conn, set)?;
-    build_migration_plan(set, &applied)
+    query_applied_records_sqlite(&conn, set)
 }
 
 #[cfg(feature = "rusqlite")]
@@ -1274,38 +1368,44 @@ fn query_applied_records_sqlite(
     Ok(applied)
 }
 
-#[cfg(feature = "rusqlite")]
-fn query_applied_names_sqlite(
-    conn: &rusqlite::Connection,
-    set: &Migrations,
-) -> Result<Vec<String>, CliError> {
-    let mut stmt = match conn.prepare(&set.applied_names_sql()) {
-        Ok(stmt) => stmt,
-        Err(error) if is_sqlite_missing_table(&error) => return Ok(vec![]),
-        Err(error) => return Err(CliError::MigrationError(error.to_string())),
-    };
-
-    let names = stmt
-        .query_map([], |row| row.get::<_, String>(0))
-        .map_err(|e| CliError::MigrationError(e.to_string()))?
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|error| CliError::MigrationError(error.to_string()))?;
-
-    Ok(names)
-}
-
 // ============================================================================
 // PostgreSQL (postgres - sync)
 // ============================================================================
 
+/// TLS connector following libpq's `sslmode` rules: below `verify-ca` the
+/// link is encrypted but the certificate isn't checked, unless a root
+/// certificate is given, in which case the chain is verified too.
 #[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
 fn postgres_tls_connector(
     mode: crate::config::PostgresSslMode,
+    root_cert: Option<&Path>,
 ) -> Result<postgres_native_tls::MakeTlsConnector, CliError> {
+    use crate::config::PostgresSslMode;
+
     let mut builder = native_tls::TlsConnector::builder();
-    if mode == crate::config::PostgresSslMode::VerifyCa {
-        builder.danger_accept_invalid_hostnames(true);
+    if let Some(path) = root_cert {
+        let pem = std::fs::read(path).map_err(|error| {
+            CliError::ConnectionError(format!(
+                "failed to read sslrootcert {}: {error}",
+                path.display()
+            ))
+        })?;
+        let certs = native_tls::Certificate::stack_from_pem(&pem).map_err(|error| {
+            CliError::ConnectionError(format!("invalid sslrootcert {}: {error}", path.display()))
+        })?;
+        for cert in certs {
+            builder.add_root_certificate(cert);
+        }
     }
+
+    let verify_chain = root_cert.is_some()
+        || matches!(
+            mode,
+            PostgresSslMode::VerifyCa | PostgresSslMode::VerifyFull
+        );
+    builder.danger_accept_invalid_certs(!verify_chain);
+    builder.danger_accept_invalid_hostnames(mode != PostgresSslMode::VerifyFull);
+
     let connector = builder
         .build()
         .map_err(|error| CliError::ConnectionError(error.to_string()))?;
@@ -1317,39 +1417,13 @@ fn connect_postgres_sync(creds: &PostgresCreds) -> Result<postgres::Client, CliE
     let connection = creds
         .connection_config()
         .map_err(CliError::ConnectionError)?;
-    let config = match creds {
-        PostgresCreds::Url(url) => url
-            .parse::<postgres::Config>()
-            .map_err(|error| CliError::ConnectionError(error.to_string()))?,
-        PostgresCreds::Host {
-            host,
-            port,
-            user,
-            password,
-            database,
-            ..
-        } => {
-            let mut config = postgres::Config::new();
-            config
-                .host(host.as_ref())
-                .port(*port)
-                .dbname(database.as_ref());
-            if let Some(user) = user {
-                config.user(user.as_ref());
-            }
-            if let Some(password) = password {
-                config.password(password.as_bytes());
-            }
-            config.ssl_mode(connection.config.get_ssl_mode());
-            config
-        }
-    };
+    let config = postgres::Config::from(connection.config);
     if connection.ssl == crate::config::PostgresSslMode::Disable {
         config
             .connect(postgres::NoTls)
             .map_err(|error| CliError::ConnectionError(error.to_string()))
     } else {
-        let connector = postgres_tls_connector(connection.ssl)?;
+        let connector = postgres_tls_connector(connection.ssl, connection.root_cert.as_deref())?;
         config
             .connect(connector)
             .map_err(|error| CliError::ConnectionError(error.to_string()))
@@ -1374,7 +1448,7 @@ async fn connect_postgres_async(creds: &PostgresCreds) -> Result<tokio_postgres:
         });
         Ok(client)
     } else {
-        let connector = postgres_tls_connector(connection.ssl)?;
+        let connector = postgres_tls_connector(connection.ssl, connection.root_cert.as_deref())?;
         let (client, driver) = connection
             .config
             .connect(connector)
@@ -1396,36 +1470,13 @@ fn execute_postgres_sync_statements(
 ) -> Result<(), CliError> {
     let mut client = connect_postgres_sync(creds)?;
 
-    if has_postgres_concurrent_index(statements) {
-        // CREATE/DROP INDEX CONCURRENTLY cannot run inside a transaction block.
-        for stmt in statements {
-            let s = stmt.trim();
-            if s.is_empty() {
-                continue;
-            }
-            client
-                .execute(s, &[])
-                .map_err(|e| CliError::MigrationError(format!("Statement failed: {e}\n{s}")))?;
-        }
-    } else {
-        let mut tx = client
-            .transaction()
-            .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-        for stmt in statements {
-            let s = stmt.trim();
-            if s.is_empty() {
-                continue;
-            }
-            tx.execute(s, &[])
-                .map_err(|e| CliError::MigrationError(format!("Statement failed: {e}\n{s}")))?;
-        }
-
-        tx.commit()
-            .map_err(|e| CliError::MigrationError(e.to_string()))?;
-    }
-
-    Ok(())
+    // CREATE/DROP INDEX CONCURRENTLY cannot run inside a transaction block.
+    let transactional = !has_postgres_concurrent_index(statements);
+    executor::block_on_sync(executor::apply_statements(
+        &mut client,
+        statements,
+        transactional,
+    ))
 }
 
 #[cfg(feature = "postgres-sync")]
@@ -1463,76 +1514,14 @@ fn run_postgres_sync_migrations_locked(
     set: &Migrations,
 ) -> Result<MigrationResult, CliError> {
     ensure_postgres_tracking_table_sync(client, set)?;
-    let rows = client
-        .query(&set.applied_names_sql(), &[])
-        .map_err(|error| CliError::MigrationError(error.to_string()))?;
-    let applied_names = rows
-        .iter()
-        .map(|row| row.try_get(0))
-        .collect::<Result<Vec<String>, _>>()
-        .map_err(|error| CliError::MigrationError(error.to_string()))?;
-    let pending: Vec<_> = set.pending(&applied_names).collect();
-    if pending.is_empty() {
-        return Ok(MigrationResult {
-            applied_count: 0,
-            applied_migrations: vec![],
-        });
-    }
-
-    let mut applied = Vec::new();
-    if set.has_postgres_concurrent_index() {
-        for migration in &pending {
-            for statement in migration.statements() {
-                if !statement.trim().is_empty() {
-                    client.execute(statement, &[]).map_err(|error| {
-                        CliError::MigrationError(format!(
-                            "Migration '{}' failed: {error}",
-                            migration.hash()
-                        ))
-                    })?;
-                }
-            }
-            client
-                .execute(&set.record_migration_sql(migration), &[])
-                .map_err(|error| CliError::MigrationError(error.to_string()))?;
-            applied.push(migration.hash().to_string());
-        }
-    } else {
-        let mut transaction = client
-            .transaction()
-            .map_err(|error| CliError::MigrationError(error.to_string()))?;
-        for migration in &pending {
-            for statement in migration.statements() {
-                if !statement.trim().is_empty() {
-                    transaction.execute(statement, &[]).map_err(|error| {
-                        CliError::MigrationError(format!(
-                            "Migration '{}' failed: {error}",
-                            migration.hash()
-                        ))
-                    })?;
-                }
-            }
-            transaction
-                .execute(&set.record_migration_sql(migration), &[])
-                .map_err(|error| CliError::MigrationError(error.to_string()))?;
-            applied.push(migration.hash().to_string());
-        }
-        transaction
-            .commit()
-            .map_err(|error| CliError::MigrationError(error.to_string()))?;
-    }
-
-    Ok(MigrationResult {
-        applied_count: applied.len(),
-        applied_migrations: applied,
-    })
+    executor::block_on_sync(executor::apply_migrations(client, set))
 }
 
 #[cfg(feature = "postgres-sync")]
 fn inspect_postgres_sync_migrations(
     set: &Migrations,
     creds: &PostgresCreds,
-) -> Result<MigrationPlan, CliError> {
+) -> Result<Vec<AppliedMigrationRecord>, CliError> {
     let mut client = connect_postgres_sync(creds)?;
 
     if let Some(schema_sql) = set.create_schema_sql() {
@@ -1542,8 +1531,38 @@ fn inspect_postgres_sync_migrations(
     }
 
     ensure_postgres_tracking_table_sync(&mut client, set)?;
-    let applied = query_applied_records_postgres_sync(&mut client, set)?;
-    build_migration_plan(set, &applied)
+    query_applied_records_postgres_sync(&mut client, set)
+}
+
+#[cfg(feature = "postgres-sync")]
+fn history_postgres_sync(
+    set: &Migrations,
+    creds: &PostgresCreds,
+) -> Result<Vec<AppliedMigrationHistory>, CliError> {
+    let mut client = connect_postgres_sync(creds)?;
+
+    let exists = client
+        .query(&set.table_exists_sql(), &[])
+        .map_err(|error| CliError::MigrationError(error.to_string()))?;
+    if exists.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let rows = client
+        .query(&set.applied_history_sql(), &[])
+        .map_err(|error| CliError::MigrationError(error.to_string()))?;
+    rows.iter()
+        .map(|row| {
+            Ok(AppliedMigrationHistory {
+                name: row.try_get(0)?,
+                applied_at: row.try_get(1)?,
+                applied_by: row.try_get(2)?,
+                duration_ms: row.try_get(3)?,
+                statement_count: row.try_get(4)?,
+            })
+        })
+        .collect::<Result<Vec<_>, postgres::Error>>()
+        .map_err(|error| CliError::MigrationError(error.to_string()))
 }
 
 #[cfg(feature = "postgres-sync")]
@@ -1599,40 +1618,9 @@ async fn execute_postgres_async_inner(
 ) -> Result<(), CliError> {
     let mut client = connect_postgres_async(creds).await?;
 
-    if has_postgres_concurrent_index(statements) {
-        // CREATE/DROP INDEX CONCURRENTLY cannot run inside a transaction block.
-        for stmt in statements {
-            let s = stmt.trim();
-            if s.is_empty() {
-                continue;
-            }
-            client
-                .execute(s, &[])
-                .await
-                .map_err(|e| CliError::MigrationError(format!("Statement failed: {}\n{}", e, s)))?;
-        }
-    } else {
-        let tx = client
-            .transaction()
-            .await
-            .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-        for stmt in statements {
-            let s = stmt.trim();
-            if s.is_empty() {
-                continue;
-            }
-            tx.execute(s, &[])
-                .await
-                .map_err(|e| CliError::MigrationError(format!("Statement failed: {}\n{}", e, s)))?;
-        }
-
-        tx.commit()
-            .await
-            .map_err(|e| CliError::MigrationError(e.to_string()))?;
-    }
-
-    Ok(())
+    // CREATE/DROP INDEX CONCURRENTLY cannot run inside a transaction block.
+    let transactional = !has_postgres_concurrent_index(statements);
+    executor::apply_statements(&mut client, statements, transactional).await
 }
 
 #[cfg(feature = "tokio-postgres")]
@@ -1654,7 +1642,7 @@ fn run_postgres_async_migrations(
 fn inspect_postgres_async_migrations(
     set: &Migrations,
     creds: &PostgresCreds,
-) -> Result<MigrationPlan, CliError> {
+) -> Result<Vec<AppliedMigrationRecord>, CliError> {
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
@@ -1668,7 +1656,7 @@ fn inspect_postgres_async_migrations(
 async fn inspect_postgres_async_inner(
     set: &Migrations,
     creds: &PostgresCreds,
-) -> Result<MigrationPlan, CliError> {
+) -> Result<Vec<AppliedMigrationRecord>, CliError> {
     let client = connect_postgres_async(creds).await?;
 
     if let Some(schema_sql) = set.create_schema_sql() {
@@ -1679,8 +1667,48 @@ async fn inspect_postgres_async_inner(
     }
 
     ensure_postgres_tracking_table_async(&client, set).await?;
-    let applied = query_applied_records_postgres_async(&client, set).await?;
-    build_migration_plan(set, &applied)
+    query_applied_records_postgres_async(&client, set).await
+}
+
+#[cfg(feature = "tokio-postgres")]
+#[allow(dead_code)] // Used when postgres-sync is not enabled
+fn history_postgres_async(
+    set: &Migrations,
+    creds: &PostgresCreds,
+) -> Result<Vec<AppliedMigrationHistory>, CliError> {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| CliError::Other(format!("Failed to create async runtime: {e}")))?;
+
+    rt.block_on(async {
+        let client = connect_postgres_async(creds).await?;
+
+        let exists = client
+            .query(&set.table_exists_sql(), &[])
+            .await
+            .map_err(|error| CliError::MigrationError(error.to_string()))?;
+        if exists.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rows = client
+            .query(&set.applied_history_sql(), &[])
+            .await
+            .map_err(|error| CliError::MigrationError(error.to_string()))?;
+        rows.iter()
+            .map(|row| {
+                Ok(AppliedMigrationHistory {
+                    name: row.try_get(0)?,
+                    applied_at: row.try_get(1)?,
+                    applied_by: row.try_get(2)?,
+                    duration_ms: row.try_get(3)?,
+                    statement_count: row.try_get(4)?,
+                })
+            })
+            .collect::<Result<Vec<_>, tokio_postgres::Error>>()
+            .map_err(|error| CliError::MigrationError(error.to_string()))
+    })
 }
 
 #[cfg(feature = "tokio-postgres")]
@@ -1752,74 +1780,7 @@ async fn run_postgres_async_migrations_locked(
     set: &Migrations,
 ) -> Result<MigrationResult, CliError> {
     ensure_postgres_tracking_table_async(client, set).await?;
-    let rows = client
-        .query(&set.applied_names_sql(), &[])
-        .await
-        .map_err(|error| CliError::MigrationError(error.to_string()))?;
-    let applied_names = rows
-        .iter()
-        .map(|row| row.try_get(0))
-        .collect::<Result<Vec<String>, _>>()
-        .map_err(|error| CliError::MigrationError(error.to_string()))?;
-    let pending: Vec<_> = set.pending(&applied_names).collect();
-    if pending.is_empty() {
-        return Ok(MigrationResult {
-            applied_count: 0,
-            applied_migrations: vec![],
-        });
-    }
-
-    let mut applied = Vec::new();
-    if set.has_postgres_concurrent_index() {
-        for migration in &pending {
-            for statement in migration.statements() {
-                if !statement.trim().is_empty() {
-                    client.execute(statement, &[]).await.map_err(|error| {
-                        CliError::MigrationError(format!(
-                            "Migration '{}' failed: {error}",
-                            migration.hash()
-                        ))
-                    })?;
-                }
-            }
-            client
-                .execute(&set.record_migration_sql(migration), &[])
-                .await
-                .map_err(|error| CliError::MigrationError(error.to_string()))?;
-            applied.push(migration.hash().to_string());
-        }
-    } else {
-        let transaction = client
-            .transaction()
-            .await
-            .map_err(|error| CliError::MigrationError(error.to_string()))?;
-        for migration in &pending {
-            for statement in migration.statements() {
-                if !statement.trim().is_empty() {
-                    transaction.execute(statement, &[]).await.map_err(|error| {
-                        CliError::MigrationError(format!(
-                            "Migration '{}' failed: {error}",
-                            migration.hash()
-                        ))
-                    })?;
-                }
-            }
-            transaction
-                .execute(&set.record_migration_sql(migration), &[])
-                .await
-                .map_err(|error| CliError::MigrationError(error.to_string()))?;
-            applied.push(migration.hash().to_string());
-        }
-        transaction
-            .commit()
-            .await
-            .map_err(|error| CliError::MigrationError(error.to_string()))?;
-    }
-
-    Ok(MigrationResult {
-        applied_count: applied.len(),
-        applied_migrations: applied,
-    })
+    executor::apply_migrations(client, set).await
 }
 
 // ============================================================================
@@ -1827,183 +1788,109 @@ async fn run_postgres_async_migrations_locked(
 // ============================================================================
 
 #[cfg(feature = "libsql")]
-fn execute_libsql_local_statements(path: &str, statements: &[String]) -> Result<(), CliError> {
+async fn open_libsql_local(
+    path: &str,
+    encryption_key: Option<&str>,
+) -> Result<libsql::Database, CliError> {
+    let mut builder = libsql::Builder::new_local(path);
+    if let Some(key) = encryption_key {
+        builder = builder.encryption_config(libsql::EncryptionConfig::new(
+            libsql::Cipher::Aes256Cbc,
+            key.as_bytes().to_vec().into(),
+        ));
+    }
+    builder.build().await.map_err(|e| {
+        CliError::ConnectionError(format!("Failed to open LibSQL database '{path}': {e}"))
+    })
+}
+
+#[cfg(feature = "libsql")]
+fn execute_libsql_local_statements(
+    path: &str,
+    encryption_key: Option<&str>,
+    statements: &[String],
+) -> Result<(), CliError> {
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
         .map_err(|e| CliError::Other(format!("Failed to create async runtime: {e}")))?;
 
-    rt.block_on(execute_libsql_local_inner(path, statements))
+    rt.block_on(execute_libsql_local_inner(path, encryption_key, statements))
 }
 
 #[cfg(feature = "libsql")]
-async fn execute_libsql_local_inner(path: &str, statements: &[String]) -> Result<(), CliError> {
-    let db = libsql::Builder::new_local(path)
-        .build()
-        .await
-        .map_err(|e| {
-            CliError::ConnectionError(format!("Failed to open LibSQL database '{path}': {e}"))
-        })?;
+async fn execute_libsql_local_inner(
+    path: &str,
+    encryption_key: Option<&str>,
+    statements: &[String],
+) -> Result<(), CliError> {
+    let db = open_libsql_local(path, encryption_key).await?;
 
     let conn = db
         .connect()
         .map_err(|e| CliError::ConnectionError(e.to_string()))?;
 
-    let tx = conn
-        .transaction()
-        .await
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-    for stmt in statements {
-        let s = stmt.trim();
-        if s.is_empty() {
-            continue;
-        }
-        if let Err(e) = tx.execute(s, ()).await {
-            tx.rollback().await.ok();
-            return Err(CliError::MigrationError(format!(
-                "Statement failed: {e}\n{s}"
-            )));
-        }
-    }
-
-    tx.commit()
-        .await
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-    Ok(())
+    executor::apply_statements(&mut executor::LibsqlExecutor(&conn), statements, true).await
 }
 
 #[cfg(feature = "libsql")]
-fn run_libsql_local_migrations(set: &Migrations, path: &str) -> Result<MigrationResult, CliError> {
+fn run_libsql_local_migrations(
+    set: &Migrations,
+    path: &str,
+    encryption_key: Option<&str>,
+) -> Result<MigrationResult, CliError> {
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
         .map_err(|e| CliError::Other(format!("Failed to create async runtime: {e}")))?;
 
-    rt.block_on(run_libsql_local_inner(set, path))
+    rt.block_on(run_libsql_local_inner(set, path, encryption_key))
 }
 
 #[cfg(feature = "libsql")]
 fn inspect_libsql_local_migrations(
     set: &Migrations,
     path: &str,
-) -> Result<MigrationPlan, CliError> {
+    encryption_key: Option<&str>,
+) -> Result<Vec<AppliedMigrationRecord>, CliError> {
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
         .map_err(|e| CliError::Other(format!("Failed to create async runtime: {e}")))?;
 
-    rt.block_on(inspect_libsql_local_inner(set, path))
+    rt.block_on(inspect_libsql_local_inner(set, path, encryption_key))
 }
 
 #[cfg(feature = "libsql")]
 async fn inspect_libsql_local_inner(
     set: &Migrations,
     path: &str,
-) -> Result<MigrationPlan, CliError> {
-    let db = libsql::Builder::new_local(path)
-        .build()
-        .await
-        .map_err(|e| {
-            CliError::ConnectionError(format!("Failed to open LibSQL database '{path}': {e}"))
-        })?;
+    encryption_key: Option<&str>,
+) -> Result<Vec<AppliedMigrationRecord>, CliError> {
+    let db = open_libsql_local(path, encryption_key).await?;
 
     let conn = db
         .connect()
         .map_err(|e| CliError::ConnectionError(e.to_string()))?;
 
     ensure_sqlite_tracking_table_libsql(&conn, set).await?;
-    let applied = query_applied_records_libsql(&conn, set).await?;
-    build_migration_plan(set, &applied)
+    query_applied_records_libsql(&conn, set).await
 }
 
 #[cfg(feature = "libsql")]
-async fn run_libsql_local_inner(set: &Migrations, path: &str) -> Result<MigrationResult, CliError> {
-    let db = libsql::Builder::new_local(path)
-        .build()
-        .await
-        .map_err(|e| {
-            CliError::ConnectionError(format!("Failed to open LibSQL database '{path}': {e}"))
-        })?;
+async fn run_libsql_local_inner(
+    set: &Migrations,
+    path: &str,
+    encryption_key: Option<&str>,
+) -> Result<MigrationResult, CliError> {
+    let db = open_libsql_local(path, encryption_key).await?;
 
     let conn = db
         .connect()
         .map_err(|e| CliError::ConnectionError(e.to_string()))?;
 
     ensure_sqlite_tracking_table_libsql(&conn, set).await?;
-    let tx = conn
-        .transaction_with_behavior(libsql::TransactionBehavior::Immediate)
-        .await
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-    let applied_names = query_applied_names_libsql(&tx, set).await?;
-
-    // Get pending migrations
-    let pending: Vec<_> = set.pending(&applied_names).collect();
-    if pending.is_empty() {
-        tx.commit()
-            .await
-            .map_err(|e| CliError::MigrationError(e.to_string()))?;
-        return Ok(MigrationResult {
-            applied_count: 0,
-            applied_migrations: vec![],
-        });
-    }
-
-    let mut applied = Vec::new();
-    for migration in &pending {
-        for stmt in migration.statements() {
-            if !stmt.trim().is_empty()
-                && let Err(e) = tx.execute(stmt, ()).await
-            {
-                tx.rollback().await.ok();
-                return Err(CliError::MigrationError(format!(
-                    "Migration '{}' failed: {}",
-                    migration.hash(),
-                    e
-                )));
-            }
-        }
-        if let Err(e) = tx.execute(&set.record_migration_sql(migration), ()).await {
-            tx.rollback().await.ok();
-            return Err(CliError::MigrationError(e.to_string()));
-        }
-        applied.push(migration.hash().to_string());
-    }
-
-    tx.commit()
-        .await
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-    Ok(MigrationResult {
-        applied_count: applied.len(),
-        applied_migrations: applied,
-    })
-}
-
-#[cfg(feature = "libsql")]
-async fn query_applied_names_libsql(
-    conn: &libsql::Connection,
-    set: &Migrations,
-) -> Result<Vec<String>, CliError> {
-    let mut rows = conn
-        .query(&set.applied_names_sql(), ())
-        .await
-        .map_err(|error| CliError::MigrationError(error.to_string()))?;
-
-    let mut names = Vec::new();
-    while let Some(row) = rows
-        .next()
-        .await
-        .map_err(|error| CliError::MigrationError(error.to_string()))?
-    {
-        names.push(
-            row.get::<String>(0)
-                .map_err(|error| CliError::MigrationError(error.to_string()))?,
-        );
-    }
-
-    Ok(names)
+    executor::apply_migrations(&mut executor::LibsqlExecutor(&conn), set).await
 }
 
 #[cfg(feature = "libsql")]
@@ -2042,10 +1929,30 @@ async fn query_applied_records_libsql(
 // Turso (remote)
 // ============================================================================
 
+#[cfg(feature = "turso")]
+async fn open_turso(
+    url: &str,
+    auth_token: Option<&str>,
+    encryption_key: Option<&str>,
+) -> Result<libsql::Database, CliError> {
+    let mut builder =
+        libsql::Builder::new_remote(url.to_string(), auth_token.unwrap_or("").to_string());
+    if let Some(key) = encryption_key {
+        builder = builder.remote_encryption(libsql::EncryptionContext {
+            key: libsql::EncryptionKey::Base64Encoded(key.to_string()),
+        });
+    }
+    builder
+        .build()
+        .await
+        .map_err(|e| CliError::ConnectionError(format!("Failed to connect to Turso '{url}': {e}")))
+}
+
 #[cfg(feature = "turso")]
 fn execute_turso_statements(
     url: &str,
     auth_token: Option<&str>,
+    encryption_key: Option<&str>,
     statements: &[String],
 ) -> Result<(), CliError> {
     let rt = tokio::runtime::Builder::new_current_thread()
@@ -2053,49 +1960,28 @@ fn execute_turso_statements(
         .build()
         .map_err(|e| CliError::Other(format!("Failed to create async runtime: {e}")))?;
 
-    rt.block_on(execute_turso_inner(url, auth_token, statements))
+    rt.block_on(execute_turso_inner(
+        url,
+        auth_token,
+        encryption_key,
+        statements,
+    ))
 }
 
 #[cfg(feature = "turso")]
 async fn execute_turso_inner(
     url: &str,
     auth_token: Option<&str>,
+    encryption_key: Option<&str>,
     statements: &[String],
 ) -> Result<(), CliError> {
-    let builder =
-        libsql::Builder::new_remote(url.to_string(), auth_token.unwrap_or("").to_string());
-
-    let db = builder.build().await.map_err(|e| {
-        CliError::ConnectionError(format!("Failed to connect to Turso '{url}': {e}"))
-    })?;
+    let db = open_turso(url, auth_token, encryption_key).await?;
 
     let conn = db
         .connect()
         .map_err(|e| CliError::ConnectionError(e.to_string()))?;
 
-    let tx = conn
-        .transaction()
-        .await
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-    for stmt in statements {
-        let s = stmt.trim();
-        if s.is_empty() {
-            continue;
-        }
-        if let Err(e) = tx.execute(s, ()).await {
-            tx.rollback().await.ok();
-            return Err(CliError::MigrationError(format!(
-                "Statement failed: {e}\n{s}"
-            )));
-        }
-    }
-
-    tx.commit()
-        .await
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-    Ok(())
+    executor::apply_statements(&mut executor::LibsqlExecutor(&conn), statements, true).await
 }
 
 #[cfg(feature = "turso")]
@@ -2103,13 +1989,14 @@ fn run_turso_migrations(
     set: &Migrations,
     url: &str,
     auth_token: Option<&str>,
+    encryption_key: Option<&str>,
 ) -> Result<MigrationResult, CliError> {
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
         .map_err(|e| CliError::Other(format!("Failed to create async runtime: {e}")))?;
 
-    rt.block_on(run_turso_inner(set, url, auth_token))
+    rt.block_on(run_turso_inner(set, url, auth_token, encryption_key))
 }
 
 #[cfg(feature = "turso")]
@@ -2117,13 +2004,14 @@ fn inspect_turso_migrations(
     set: &Migrations,
     url: &str,
     auth_token: Option<&str>,
-) -> Result<MigrationPlan, CliError> {
+    encryption_key: Option<&str>,
+) -> Result<Vec<AppliedMigrationRecord>, CliError> {
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
         .map_err(|e| CliError::Other(format!("Failed to create async runtime: {e}")))?;
 
-    rt.block_on(inspect_turso_inner(set, url, auth_token))
+    rt.block_on(inspect_turso_inner(set, url, auth_token, encryption_key))
 }
 
 #[cfg(feature = "turso")]
@@ -2131,21 +2019,16 @@ async fn inspect_turso_inner(
     set: &Migrations,
     url: &str,
     auth_token: Option<&str>,
-) -> Result<MigrationPlan, CliError> {
-    let builder =
-        libsql::Builder::new_remote(url.to_string(), auth_token.unwrap_or("").to_string());
-
-    let db = builder.build().await.map_err(|e| {
-        CliError::ConnectionError(format!("Failed to connect to Turso '{url}': {e}"))
-    })?;
+    encryption_key: Option<&str>,
+) -> Result<Vec<AppliedMigrationRecord>, CliError> {
+    let db = open_turso(url, auth_token, encryption_key).await?;
 
     let conn = db
         .connect()
         .map_err(|e| CliError::ConnectionError(e.to_string()))?;
 
     ensure_sqlite_tracking_table_libsql(&conn, set).await?;
-    let applied = query_applied_records_turso(&conn, set).await?;
-    build_migration_plan(set, &applied)
+    query_applied_records_turso(&conn, set).await
 }
 
 #[cfg(feature = "turso")]
@@ -2153,91 +2036,16 @@ async fn run_turso_inner(
     set: &Migrations,
     url: &str,
     auth_token: Option<&str>,
+    encryption_key: Option<&str>,
 ) -> Result<MigrationResult, CliError> {
-    let builder =
-        libsql::Builder::new_remote(url.to_string(), auth_token.unwrap_or("").to_string());
-
-    let db = builder.build().await.map_err(|e| {
-        CliError::ConnectionError(format!("Failed to connect to Turso '{url}': {e}"))
-    })?;
+    let db = open_turso(url, auth_token, encryption_key).await?;
 
     let conn = db
         .connect()
         .map_err(|e| CliError::ConnectionError(e.to_string()))?;
 
     ensure_sqlite_tracking_table_libsql(&conn, set).await?;
-    let tx = conn
-        .transaction_with_behavior(libsql::TransactionBehavior::Immediate)
-        .await
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-    let applied_names = query_applied_names_turso(&tx, set).await?;
-
-    // Get pending migrations
-    let pending: Vec<_> = set.pending(&applied_names).collect();
-    if pending.is_empty() {
-        tx.commit()
-            .await
-            .map_err(|e| CliError::MigrationError(e.to_string()))?;
-        return Ok(MigrationResult {
-            applied_count: 0,
-            applied_migrations: vec![],
-        });
-    }
-
-    let mut applied = Vec::new();
-    for migration in &pending {
-        for stmt in migration.statements() {
-            if !stmt.trim().is_empty()
-                && let Err(e) = tx.execute(stmt, ()).await
-            {
-                tx.rollback().await.ok();
-                return Err(CliError::MigrationError(format!(
-                    "Migration '{}' failed: {}",
-                    migration.hash(),
-                    e
-                )));
-            }
-        }
-        if let Err(e) = tx.execute(&set.record_migration_sql(migration), ()).await {
-            tx.rollback().await.ok();
-            return Err(CliError::MigrationError(e.to_string()));
-        }
-        applied.push(migration.hash().to_string());
-    }
-
-    tx.commit()
-        .await
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-    Ok(MigrationResult {
-        applied_count: applied.len(),
-        applied_migrations: applied,
-    })
-}
-
-#[cfg(feature = "turso")]
-async fn query_applied_names_turso(
-    conn: &libsql::Connection,
-    set: &Migrations,
-) -> Result<Vec<String>, CliError> {
-    let mut rows = conn
-        .query(&set.applied_names_sql(), ())
-        .await
-        .map_err(|error| CliError::MigrationError(error.to_string()))?;
-
-    let mut names = Vec::new();
-    while let Some(row) = rows
-        .next()
-        .await
-        .map_err(|error| CliError::MigrationError(error.to_string()))?
-    {
-        names.push(
-            row.get::<String>(0)
-                .map_err(|error| CliError::MigrationError(error.to_string()))?,
-        );
-    }
-
-    Ok(names)
+    executor::apply_migrations(&mut executor::LibsqlExecutor(&conn), set).await
 }
 
 #[cfg(feature = "turso")]
@@ -2428,8 +2236,15 @@ fn apply_init_metadata(
         feature = "tokio-postgres",
         feature = "d1-http",
     ))]
-    let set = load_migration_set(dialect, out_dir, migrations_table, migrations_schema)?;
-
+    let set = load_migration_set(
+        dialect,
+        out_dir,
+        migrations_table,
+        migrations_schema,
+        false,
+        None,
+    )?;
+
     match credentials {
         #[cfg(feature = "rusqlite")]
         Credentials::Sqlite { path } => init_sqlite_metadata(path, &set),
@@ -2441,11 +2256,15 @@ fn apply_init_metadata(
         }),
 
         #[cfg(any(feature = "libsql", feature = "turso"))]
-        Credentials::Turso { url, auth_token } => {
+        Credentials::Turso {
+            url,
+            auth_token,
+            encryption_key,
+        } => {
             if is_local_libsql(url) {
                 #[cfg(feature = "libsql")]
                 {
-                    init_libsql_local_metadata(url, &set)
+                    init_libsql_local_metadata(url, encryption_key.as_deref(), &set)
                 }
                 #[cfg(not(feature = "libsql"))]
                 {
@@ -2457,7 +2276,7 @@ fn apply_init_metadata(
             } else {
                 #[cfg(feature = "turso")]
                 {
-                    init_turso_metadata(url, auth_token.as_deref(), &set)
+                    init_turso_metadata(url, auth_token.as_deref(), encryption_key.as_deref(), &set)
                 }
                 #[cfg(not(feature = "turso"))]
                 {
@@ -2550,37 +2369,33 @@ fn init_sqlite_metadata(path: &str, set: &Migrations) -> Result<(), CliError> {
 
     ensure_sqlite_tracking_table(&conn, set)?;
 
-    let applied_names = query_applied_names_sqlite(&conn, set)?;
-    validate_init_metadata(&applied_names, set)?;
-
-    let Some(first) = set.all().first() else {
-        return Ok(());
-    };
-
-    conn.execute(&set.record_migration_sql(first), [])
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-    Ok(())
+    executor::block_on_sync(executor::record_baseline(
+        &mut executor::RusqliteExecutor(&conn),
+        set,
+    ))
 }
 
 #[cfg(feature = "libsql")]
-fn init_libsql_local_metadata(path: &str, set: &Migrations) -> Result<(), CliError> {
+fn init_libsql_local_metadata(
+    path: &str,
+    encryption_key: Option<&str>,
+    set: &Migrations,
+) -> Result<(), CliError> {
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
         .map_err(|e| CliError::Other(format!("Failed to create async runtime: {e}")))?;
 
-    rt.block_on(init_libsql_local_metadata_inner(path, set))
+    rt.block_on(init_libsql_local_metadata_inner(path, encryption_key, set))
 }
 
 #[cfg(feature = "libsql")]
-async fn init_libsql_local_metadata_inner(path: &str, set: &Migrations) -> Result<(), CliError> {
-    let db = libsql::Builder::new_local(path)
-        .build()
-        .await
-        .map_err(|e| {
-            CliError::ConnectionError(format!("Failed to open LibSQL database '{path}': {e}"))
-        })?;
+async fn init_libsql_local_metadata_inner(
+    path: &str,
+    encryption_key: Option<&str>,
+    set: &Migrations,
+) -> Result<(), CliError> {
+    let db = open_libsql_local(path, encryption_key).await?;
 
     let conn = db
         .connect()
@@ -2588,24 +2403,14 @@ async fn init_libsql_local_metadata_inner(path: &str, set: &Migrations) -> Resul
 
     ensure_sqlite_tracking_table_libsql(&conn, set).await?;
 
-    let applied_names = query_applied_names_libsql(&conn, set).await?;
-    validate_init_metadata(&applied_names, set)?;
-
-    let Some(first) = set.all().first() else {
-        return Ok(());
-    };
-
-    conn.execute(&set.record_migration_sql(first), ())
-        .await
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-    Ok(())
+    executor::record_baseline(&mut executor::LibsqlExecutor(&conn), set).await
 }
 
 #[cfg(feature = "turso")]
 fn init_turso_metadata(
     url: &str,
     auth_token: Option<&str>,
+    encryption_key: Option<&str>,
     set: &Migrations,
 ) -> Result<(), CliError> {
     let rt = tokio::runtime::Builder::new_current_thread()
@@ -2613,21 +2418,22 @@ fn init_turso_metadata(
         .build()
         .map_err(|e| CliError::Other(format!("Failed to create async runtime: {e}")))?;
 
-    rt.block_on(init_turso_metadata_inner(url, auth_token, set))
+    rt.block_on(init_turso_metadata_inner(
+        url,
+        auth_token,
+        encryption_key,
+        set,
+    ))
 }
 
 #[cfg(feature = "turso")]
 async fn init_turso_metadata_inner(
     url: &str,
     auth_token: Option<&str>,
+    encryption_key: Option<&str>,
     set: &Migrations,
 ) -> Result<(), CliError> {
-    let builder =
-        libsql::Builder::new_remote(url.to_string(), auth_token.unwrap_or("").to_string());
-
-    let db = builder.build().await.map_err(|e| {
-        CliError::ConnectionError(format!("Failed to connect to Turso '{url}': {e}"))
-    })?;
+    let db = open_turso(url, auth_token, encryption_key).await?;
 
     let conn = db
         .connect()
@@ -2635,18 +2441,7 @@ async fn init_turso_metadata_inner(
 
     ensure_sqlite_tracking_table_libsql(&conn, set).await?;
 
-    let applied_names = query_applied_names_turso(&conn, set).await?;
-    validate_init_metadata(&applied_names, set)?;
-
-    let Some(first) = set.all().first() else {
-        return Ok(());
-    };
-
-    conn.execute(&set.record_migration_sql(first), ())
-        .await
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-    Ok(())
+    executor::record_baseline(&mut executor::LibsqlExecutor(&conn), set).await
 }
 
 #[cfg(feature = "postgres-sync")]
@@ -2661,26 +2456,7 @@ fn init_postgres_sync_metadata(creds: &PostgresCreds, set: &Migrations) -> Resul
 
     ensure_postgres_tracking_table_sync(&mut client, set)?;
 
-    let rows = client
-        .query(&set.applied_names_sql(), &[])
-        .map_err(|error| CliError::MigrationError(error.to_string()))?;
-    let applied_names = rows
-        .iter()
-        .map(|row| row.try_get(0))
-        .collect::<Result<Vec<String>, _>>()
-        .map_err(|error| CliError::MigrationError(error.to_string()))?;
-
-    validate_init_metadata(&applied_names, set)?;
-
-    let Some(first) = set.all().first() else {
-        return Ok(());
-    };
-
-    client
-        .execute(&set.record_migration_sql(first), &[])
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-    Ok(())
+    executor::block_on_sync(executor::record_baseline(&mut client, set))
 }
 
 #[cfg(all(feature = "tokio-postgres", not(feature = "postgres-sync")))]
@@ -2698,7 +2474,7 @@ async fn init_postgres_async_inner(
     creds: &PostgresCreds,
     set: &Migrations,
 ) -> Result<(), CliError> {
-    let client = connect_postgres_async(creds).await?;
+    let mut client = connect_postgres_async(creds).await?;
 
     if let Some(schema_sql) = set.create_schema_sql() {
         client
@@ -2709,28 +2485,7 @@ async fn init_postgres_async_inner(
 
     ensure_postgres_tracking_table_async(&client, set).await?;
 
-    let rows = client
-        .query(&set.applied_names_sql(), &[])
-        .await
-        .map_err(|error| CliError::MigrationError(error.to_string()))?;
-    let applied_names = rows
-        .iter()
-        .map(|row| row.try_get(0))
-        .collect::<Result<Vec<String>, _>>()
-        .map_err(|error| CliError::MigrationError(error.to_string()))?;
-
-    validate_init_metadata(&applied_names, set)?;
-
-    let Some(first) = set.all().first() else {
-        return Ok(());
-    };
-
-    client
-        .execute(&set.record_migration_sql(first), &[])
-        .await
-        .map_err(|e| CliError::MigrationError(e.to_string()))?;
-
-    Ok(())
+    executor::record_baseline(&mut client, set).await
 }
 
 /// Generate migration SQL from snapshot diff (for introspection)
@@ -2864,11 +2619,15 @@ fn introspect_sqlite_dialect(credentials: &Credentials) -> Result<IntrospectResu
         }),
 
         #[cfg(any(feature = "libsql", feature = "turso"))]
-        Credentials::Turso { url, auth_token } => {
+        Credentials::Turso {
+            url,
+            auth_token,
+            encryption_key,
+        } => {
             if is_local_libsql(url) {
                 #[cfg(feature = "libsql")]
                 {
-                    introspect_libsql_local(url)
+                    introspect_libsql_local(url, encryption_key.as_deref())
                 }
                 #[cfg(not(feature = "libsql"))]
                 {
@@ -2880,7 +2639,7 @@ fn introspect_sqlite_dialect(credentials: &Credentials) -> Result<IntrospectResu
             } else {
                 #[cfg(feature = "turso")]
                 {
-                    introspect_turso(url, auth_token.as_deref())
+                    introspect_turso(url, auth_token.as_deref(), encryption_key.as_deref())
                 }
                 #[cfg(not(feature = "turso"))]
                 {
@@ -2921,522 +2680,36 @@ fn introspect_postgres_dialect(credentials: &Credentials) -> Result<IntrospectRe
         }
 
         _ => Err(CliError::Other(
-            "PostgreSQL introspection requires postgres credentials".into(),
-        )),
-    }
-}
-
-// ============================================================================
-// SQLite Introspection (rusqlite)
-// ============================================================================
-
-#[cfg(any(feature = "rusqlite", feature = "libsql", feature = "turso"))]
-struct SqliteRawData {
-    tables: Vec<(String, Option<String>)>,
-    raw_columns: Vec<drizzle_migrations::sqlite::introspect::RawColumnInfo>,
-    all_indexes: Vec<drizzle_migrations::sqlite::introspect::RawIndexInfo>,
-    all_index_columns: Vec<drizzle_migrations::sqlite::introspect::RawIndexColumn>,
-    all_fks: Vec<drizzle_migrations::sqlite::introspect::RawForeignKey>,
-    all_views: Vec<drizzle_migrations::sqlite::introspect::RawViewInfo>,
-}
-
-#[cfg(any(feature = "rusqlite", feature = "libsql", feature = "turso"))]
-impl SqliteRawData {
-    const fn empty() -> Self {
-        Self {
-            tables: Vec::new(),
-            raw_columns: Vec::new(),
-            all_indexes: Vec::new(),
-            all_index_columns: Vec::new(),
-            all_fks: Vec::new(),
-            all_views: Vec::new(),
-        }
-    }
-}
-
-#[cfg(feature = "rusqlite")]
-fn query_rusqlite_tables_and_columns(
-    conn: &rusqlite::Connection,
-    raw: &mut SqliteRawData,
-) -> Result<(), CliError> {
-    use drizzle_migrations::sqlite::introspect::{RawColumnInfo, queries};
-
-    let mut tables_stmt = conn
-        .prepare(queries::TABLES_QUERY)
-        .map_err(|e| CliError::Other(format!("Failed to prepare tables query: {e}")))?;
-
-    raw.tables = tables_stmt
-        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
-        .map_err(|e| CliError::Other(e.to_string()))?
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| CliError::Other(e.to_string()))?;
-
-    let mut columns_stmt = conn
-        .prepare(queries::COLUMNS_QUERY)
-        .map_err(|e| CliError::Other(format!("Failed to prepare columns query: {e}")))?;
-
-    raw.raw_columns = columns_stmt
-        .query_map([], |row| {
-            Ok(RawColumnInfo {
-                table: row.get(0)?,
-                cid: row.get(1)?,
-                name: row.get(2)?,
-                column_type: row.get(3)?,
-                not_null: row.get(4)?,
-                default_value: row.get(5)?,
-                pk: row.get(6)?,
-                hidden: row.get(7)?,
-                sql: row.get(8)?,
-            })
-        })
-        .map_err(|e| CliError::Other(e.to_string()))?
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| CliError::Other(e.to_string()))?;
-
-    Ok(())
-}
-
-#[cfg(feature = "rusqlite")]
-fn query_rusqlite_indexes_and_foreign_keys(
-    conn: &rusqlite::Connection,
-    raw: &mut SqliteRawData,
-) -> Result<(), CliError> {
-    use drizzle_migrations::sqlite::introspect::{
-        RawForeignKey, RawIndexColumn, RawIndexInfo, queries,
-    };
-
-    let mut indexes_stmt = conn
-        .prepare(queries::INDEXES_QUERY)
-        .map_err(|e| CliError::Other(e.to_string()))?;
-    raw.all_indexes = indexes_stmt
-        .query_map([], |row| {
-            Ok(RawIndexInfo {
-                table: row.get(0)?,
-                name: row.get(1)?,
-                unique: row.get::<_, i32>(2)? != 0,
-                origin: row.get(3)?,
-                partial: row.get::<_, i32>(4)? != 0,
-            })
-        })
-        .map_err(|e| CliError::Other(e.to_string()))?
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| CliError::Other(e.to_string()))?;
-
-    let mut columns_stmt = conn
-        .prepare(queries::INDEX_COLUMNS_QUERY)
-        .map_err(|e| CliError::Other(e.to_string()))?;
-    raw.all_index_columns = columns_stmt
-        .query_map([], |row| {
-            Ok(RawIndexColumn {
-                index_name: row.get(0)?,
-                seqno: row.get(1)?,
-                cid: row.get(2)?,
-                name: row.get(3)?,
-                desc: row.get::<_, i32>(4)? != 0,
-                coll: row.get(5)?,
-                key: row.get::<_, i32>(6)? != 0,
-            })
-        })
-        .map_err(|e| CliError::Other(e.to_string()))?
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| CliError::Other(e.to_string()))?;
-
-    let mut foreign_keys_stmt = conn
-        .prepare(queries::FOREIGN_KEYS_QUERY)
-        .map_err(|e| CliError::Other(e.to_string()))?;
-    raw.all_fks = foreign_keys_stmt
-        .query_map([], |row| {
-            Ok(RawForeignKey {
-                table: row.get(0)?,
-                id: row.get(1)?,
-                seq: row.get(2)?,
-                to_table: row.get(3)?,
-                from_column: row.get(4)?,
-                to_column: row.get(5)?,
-                on_update: row.get(6)?,
-                on_delete: row.get(7)?,
-                r#match: row.get(8)?,
-            })
-        })
-        .map_err(|e| CliError::Other(e.to_string()))?
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| CliError::Other(e.to_string()))?;
-
-    Ok(())
-}
-
-#[cfg(feature = "rusqlite")]
-fn query_rusqlite_views_and_view_columns(
-    conn: &rusqlite::Connection,
-    raw: &mut SqliteRawData,
-) -> Result<(), CliError> {
-    use drizzle_migrations::sqlite::introspect::{RawColumnInfo, RawViewInfo, queries};
-
-    let mut views_stmt = conn
-        .prepare(queries::VIEWS_QUERY)
-        .map_err(|e| CliError::Other(e.to_string()))?;
-    let view_iter = views_stmt
-        .query_map([], |row| {
-            Ok(RawViewInfo {
-                name: row.get(0)?,
-                sql: row.get(1)?,
-            })
-        })
-        .map_err(|e| CliError::Other(e.to_string()))?;
-    raw.all_views.extend(
-        view_iter
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| CliError::Other(e.to_string()))?,
-    );
-
-    let mut view_cols_stmt = conn
-        .prepare(queries::VIEW_COLUMNS_QUERY)
-        .map_err(|e| CliError::Other(e.to_string()))?;
-    let col_iter = view_cols_stmt
-        .query_map([], |row| {
-            Ok(RawColumnInfo {
-                table: row.get(0)?,
-                cid: row.get(1)?,
-                name: row.get(2)?,
-                column_type: row.get(3)?,
-                not_null: row.get::<_, i32>(4)? != 0,
-                default_value: row.get(5)?,
-                pk: row.get(6)?,
-                hidden: row.get(7)?,
-                sql: row.get(8)?,
-            })
-        })
-        .map_err(|e| CliError::Other(e.to_string()))?;
-    raw.raw_columns.extend(
-        col_iter
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| CliError::Other(e.to_string()))?,
-    );
-    Ok(())
-}
-
-#[cfg(feature = "rusqlite")]
-fn query_rusqlite_raw(conn: &rusqlite::Connection) -> Result<SqliteRawData, CliError> {
-    let mut raw = SqliteRawData::empty();
-    query_rusqlite_tables_and_columns(conn, &mut raw)?;
-    query_rusqlite_indexes_and_foreign_keys(conn, &mut raw)?;
-    query_rusqlite_views_and_view_columns(conn, &mut raw)?;
-    Ok(raw)
-}
-
-#[cfg(any(feature = "rusqlite", feature = "libsql", feature = "turso"))]
-fn build_sqlite_ddl(raw: SqliteRawData) -> drizzle_migrations::sqlite::SQLiteDDL {
-    drizzle_migrations::sqlite::introspect::assemble_ddl(
-        drizzle_migrations::sqlite::introspect::RawIntrospection {
-            tables: raw.tables,
-            columns: raw.raw_columns,
-            indexes: raw.all_indexes,
-            index_columns: raw.all_index_columns,
-            foreign_keys: raw.all_fks,
-            views: raw.all_views,
-        },
-    )
-}
-
-#[cfg(feature = "rusqlite")]
-fn introspect_rusqlite(path: &str) -> Result<IntrospectResult, CliError> {
-    use drizzle_migrations::sqlite::codegen::{CodegenOptions, FieldCasing, generate_rust_schema};
-
-    let conn = rusqlite::Connection::open(path).map_err(|e| {
-        CliError::ConnectionError(format!("Failed to open SQLite database '{path}': {e}"))
-    })?;
-
-    let raw = query_rusqlite_raw(&conn)?;
-    let ddl = build_sqlite_ddl(raw);
-
-    let options = CodegenOptions {
-        module_doc: Some(format!("Schema introspected from {path}")),
-        include_schema: true,
-        schema_name: "Schema".to_string(),
-        use_pub: true,
-        field_casing: FieldCasing::default(),
-    };
-
-    let generated = generate_rust_schema(&ddl, &options);
-
-    let mut sqlite_snapshot = drizzle_migrations::sqlite::SQLiteSnapshot::new();
-    for entity in ddl.to_entities() {
-        sqlite_snapshot.add_entity(entity);
-    }
-    let snapshot = Snapshot::Sqlite(sqlite_snapshot);
-
-    Ok(IntrospectResult {
-        schema_code: generated.code,
-        table_count: generated.tables.len(),
-        index_count: generated.indexes.len(),
-        view_count: ddl.views.len(),
-        warnings: generated.warnings,
-        snapshot,
-        snapshot_path: std::path::PathBuf::new(),
-    })
-}
-
-// ============================================================================
-// LibSQL Introspection (local)
-// ============================================================================
-
-#[cfg(feature = "libsql")]
-fn introspect_libsql_local(path: &str) -> Result<IntrospectResult, CliError> {
-    let rt = tokio::runtime::Builder::new_current_thread()
-        .enable_all()
-        .build()
-        .map_err(|e| CliError::Other(format!("Failed to create async runtime: {e}")))?;
-
-    rt.block_on(introspect_libsql_inner(path, None))
-}
-
-#[cfg(any(feature = "libsql", feature = "turso"))]
-async fn query_libsql_tables_and_columns(
-    conn: &libsql::Connection,
-) -> Result<
-    (
-        Vec<(String, Option<String>)>,
-        Vec<drizzle_migrations::sqlite::introspect::RawColumnInfo>,
-    ),
-    CliError,
-> {
-    use drizzle_migrations::sqlite::introspect::{RawColumnInfo, queries};
-
-    let mut tables_rows = conn
-        .query(queries::TABLES_QUERY, ())
-        .await
-        .map_err(|e| CliError::Other(format!("Failed to query tables: {e}")))?;
-
-    let mut tables: Vec<(String, Option<String>)> = Vec::new();
-    while let Some(row) = tables_rows
-        .next()
-        .await
-        .map_err(|e| CliError::Other(e.to_string()))?
-    {
-        let name: String = row.get(0).map_err(|e| CliError::Other(e.to_string()))?;
-        let sql: Option<String> = row.get(1).ok();
-        tables.push((name, sql));
-    }
-
-    let mut columns_rows = conn
-        .query(queries::COLUMNS_QUERY, ())
-        .await
-        .map_err(|e| CliError::Other(format!("Failed to query columns: {e}")))?;
-
-    let mut raw_columns: Vec<RawColumnInfo> = Vec::new();
-    while let Some(row) = columns_rows
-        .next()
-        .await
-        .map_err(|e| CliError::Other(e.to_string()))?
-    {
-        raw_columns.push(RawColumnInfo {
-            table: row.get(0).map_err(|e| CliError::Other(e.to_string()))?,
-            cid: row.get(1).map_err(|e| CliError::Other(e.to_string()))?,
-            name: row.get(2).map_err(|e| CliError::Other(e.to_string()))?,
-            column_type: row.get(3).map_err(|e| CliError::Other(e.to_string()))?,
-            not_null: row
-                .get::<i32>(4)
-                .map_err(|e| CliError::Other(e.to_string()))?
-                != 0,
-            default_value: row.get(5).ok(),
-            pk: row.get(6).map_err(|e| CliError::Other(e.to_string()))?,
-            hidden: row.get(7).map_err(|e| CliError::Other(e.to_string()))?,
-            sql: row.get(8).ok(),
-        });
-    }
-
-    Ok((tables, raw_columns))
-}
-
-#[cfg(any(feature = "libsql", feature = "turso"))]
-async fn query_libsql_indexes_and_foreign_keys(
-    conn: &libsql::Connection,
-) -> Result<
-    (
-        Vec<drizzle_migrations::sqlite::introspect::RawIndexInfo>,
-        Vec<drizzle_migrations::sqlite::introspect::RawIndexColumn>,
-        Vec<drizzle_migrations::sqlite::introspect::RawForeignKey>,
-    ),
-    CliError,
-> {
-    use drizzle_migrations::sqlite::introspect::{
-        RawForeignKey, RawIndexColumn, RawIndexInfo, queries,
-    };
-
-    let mut all_indexes = Vec::<RawIndexInfo>::new();
-    let mut index_rows = conn
-        .query(queries::INDEXES_QUERY, ())
-        .await
-        .map_err(|e| CliError::Other(e.to_string()))?;
-    while let Some(row) = index_rows
-        .next()
-        .await
-        .map_err(|e| CliError::Other(e.to_string()))?
-    {
-        all_indexes.push(RawIndexInfo {
-            table: row.get(0).map_err(|e| CliError::Other(e.to_string()))?,
-            name: row.get(1).map_err(|e| CliError::Other(e.to_string()))?,
-            unique: row
-                .get::<i32>(2)
-                .map_err(|e| CliError::Other(e.to_string()))?
-                != 0,
-            origin: row.get(3).map_err(|e| CliError::Other(e.to_string()))?,
-            partial: row
-                .get::<i32>(4)
-                .map_err(|e| CliError::Other(e.to_string()))?
-                != 0,
-        });
-    }
-
-    let mut all_index_columns = Vec::<RawIndexColumn>::new();
-    let mut column_rows = conn
-        .query(queries::INDEX_COLUMNS_QUERY, ())
-        .await
-        .map_err(|e| CliError::Other(e.to_string()))?;
-    while let Some(row) = column_rows
-        .next()
-        .await
-        .map_err(|e| CliError::Other(e.to_string()))?
-    {
-        all_index_columns.push(RawIndexColumn {
-            index_name: row.get(0).map_err(|e| CliError::Other(e.to_string()))?,
-            seqno: row.get(1).map_err(|e| CliError::Other(e.to_string()))?,
-            cid: row.get(2).map_err(|e| CliError::Other(e.to_string()))?,
-            name: row.get(3).ok(),
-            desc: row
-                .get::<i32>(4)
-                .map_err(|e| CliError::Other(e.to_string()))?
-                != 0,
-            coll: row.get(5).map_err(|e| CliError::Other(e.to_string()))?,
-            key: row
-                .get::<i32>(6)
-                .map_err(|e| CliError::Other(e.to_string()))?
-                != 0,
-        });
-    }
-
-    let mut all_fks = Vec::<RawForeignKey>::new();
-    let mut foreign_key_rows = conn
-        .query(queries::FOREIGN_KEYS_QUERY, ())
-        .await
-        .map_err(|e| CliError::Other(e.to_string()))?;
-    while let Some(row) = foreign_key_rows
-        .next()
-        .await
-        .map_err(|e| CliError::Other(e.to_string()))?
-    {
-        all_fks.push(RawForeignKey {
-            table: row.get(0).map_err(|e| CliError::Other(e.to_string()))?,
-            id: row.get(1).map_err(|e| CliError::Other(e.to_string()))?,
-            seq: row.get(2).map_err(|e| CliError::Other(e.to_string()))?,
-            to_table: row.get(3).map_err(|e| CliError::Other(e.to_string()))?,
-            from_column: row.get(4).map_err(|e| CliError::Other(e.to_string()))?,
-            to_column: row.get(5).map_err(|e| CliError::Other(e.to_string()))?,
-            on_update: row.get(6).map_err(|e| CliError::Other(e.to_string()))?,
-            on_delete: row.get(7).map_err(|e| CliError::Other(e.to_string()))?,
-            r#match: row.get(8).map_err(|e| CliError::Other(e.to_string()))?,
-        });
-    }
-
-    Ok((all_indexes, all_index_columns, all_fks))
-}
-
-#[cfg(any(feature = "libsql", feature = "turso"))]
-async fn query_libsql_views_and_view_columns(
-    conn: &libsql::Connection,
-    raw_columns: &mut Vec<drizzle_migrations::sqlite::introspect::RawColumnInfo>,
-) -> Result<Vec<drizzle_migrations::sqlite::introspect::RawViewInfo>, CliError> {
-    use drizzle_migrations::sqlite::introspect::{RawColumnInfo, RawViewInfo, queries};
-
-    let mut all_views: Vec<RawViewInfo> = Vec::new();
-    let mut views_rows = conn
-        .query(queries::VIEWS_QUERY, ())
-        .await
-        .map_err(|e| CliError::Other(e.to_string()))?;
-    while let Some(row) = views_rows
-        .next()
-        .await
-        .map_err(|e| CliError::Other(e.to_string()))?
-    {
-        let name: String = row.get(0).map_err(|e| CliError::Other(e.to_string()))?;
-        let sql: String = row.get(1).map_err(|e| CliError::Other(e.to_string()))?;
-        all_views.push(RawViewInfo { name, sql });
-    }
-
-    let mut view_cols_rows = conn
-        .query(queries::VIEW_COLUMNS_QUERY, ())
-        .await
-        .map_err(|e| CliError::Other(e.to_string()))?;
-    while let Some(row) = view_cols_rows
-        .next()
-        .await
-        .map_err(|e| CliError::Other(e.to_string()))?
-    {
-        raw_columns.push(RawColumnInfo {
-            table: row.get(0).map_err(|e| CliError::Other(e.to_string()))?,
-            cid: row.get(1).map_err(|e| CliError::Other(e.to_string()))?,
-            name: row.get(2).map_err(|e| CliError::Other(e.to_string()))?,
-            column_type: row.get(3).map_err(|e| CliError::Other(e.to_string()))?,
-            not_null: row
-                .get::<i32>(4)
-                .map_err(|e| CliError::Other(e.to_string()))?
-                != 0,
-            default_value: row.get(5).ok(),
-            pk: row.get(6).map_err(|e| CliError::Other(e.to_string()))?,
-            hidden: row.get(7).map_err(|e| CliError::Other(e.to_string()))?,
-            sql: row.get(8).ok(),
-        });
-    }
-    Ok(all_views)
-}
-
-#[cfg(any(feature = "libsql", feature = "turso"))]
-async fn query_libsql_raw(conn: &libsql::Connection) -> Result<SqliteRawData, CliError> {
-    let (tables, mut raw_columns) = query_libsql_tables_and_columns(conn).await?;
-    let (all_indexes, all_index_columns, all_fks) =
-        query_libsql_indexes_and_foreign_keys(conn).await?;
-    let all_views = query_libsql_views_and_view_columns(conn, &mut raw_columns).await?;
-
-    Ok(SqliteRawData {
-        tables,
-        raw_columns,
-        all_indexes,
-        all_index_columns,
-        all_fks,
-        all_views,
-    })
-}
-
-#[cfg(feature = "libsql")]
-async fn introspect_libsql_inner(
-    path: &str,
-    _auth_token: Option<&str>,
-) -> Result<IntrospectResult, CliError> {
-    use drizzle_migrations::sqlite::codegen::{CodegenOptions, FieldCasing, generate_rust_schema};
+            "PostgreSQL introspection requires postgres credentials".into(),
+        )),
+    }
+}
 
-    let db = libsql::Builder::new_local(path)
-        .build()
-        .await
-        .map_err(|e| {
-            CliError::ConnectionError(format!("Failed to open LibSQL database '{path}': {e}"))
-        })?;
+// ============================================================================
+// SQLite Introspection
+// ============================================================================
 
-    let conn = db
-        .connect()
-        .map_err(|e| CliError::ConnectionError(e.to_string()))?;
+#[cfg(any(feature = "rusqlite", feature = "libsql", feature = "turso"))]
+fn introspect_error(error: drizzle_migrations::sqlite::IntrospectError) -> CliError {
+    CliError::Other(error.to_string())
+}
 
-    let raw = query_libsql_raw(&conn).await?;
-    let ddl = build_sqlite_ddl(raw);
+#[cfg(any(feature = "rusqlite", feature = "libsql", feature = "turso"))]
+fn sqlite_introspect_result(
+    ddl: &drizzle_migrations::sqlite::SQLiteDDL,
+    module_doc: String,
+) -> IntrospectResult {
+    use drizzle_migrations::sqlite::codegen::{CodegenOptions, FieldCasing, generate_rust_schema};
 
     let options = CodegenOptions {
-        module_doc: Some(format!("Schema introspected from {path}")),
+        module_doc: Some(module_doc),
         include_schema: true,
         schema_name: "Schema".to_string(),
         use_pub: true,
         field_casing: FieldCasing::default(),
     };
 
-    let generated = generate_rust_schema(&ddl, &options);
+    let generated = generate_rust_schema(ddl, &options);
 
     let mut sqlite_snapshot = drizzle_migrations::sqlite::SQLiteSnapshot::new();
     for entity in ddl.to_entities() {
@@ -3444,7 +2717,7 @@ async fn introspect_libsql_inner(
     }
     let snapshot = Snapshot::Sqlite(sqlite_snapshot);
 
-    Ok(IntrospectResult {
+    IntrospectResult {
         schema_code: generated.code,
         table_count: generated.tables.len(),
         index_count: generated.indexes.len(),
@@ -3452,7 +2725,68 @@ async fn introspect_libsql_inner(
         warnings: generated.warnings,
         snapshot,
         snapshot_path: std::path::PathBuf::new(),
-    })
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+fn introspect_rusqlite(path: &str) -> Result<IntrospectResult, CliError> {
+    use drizzle_migrations::sqlite::SqliteIntrospector;
+
+    let conn = rusqlite::Connection::open(path).map_err(|e| {
+        CliError::ConnectionError(format!("Failed to open SQLite database '{path}': {e}"))
+    })?;
+
+    let ddl = SqliteIntrospector::new(&conn)
+        .with_view_columns()
+        .ddl()
+        .map_err(introspect_error)?;
+
+    Ok(sqlite_introspect_result(
+        &ddl,
+        format!("Schema introspected from {path}"),
+    ))
+}
+
+// ============================================================================
+// LibSQL Introspection (local)
+// ============================================================================
+
+#[cfg(feature = "libsql")]
+fn introspect_libsql_local(
+    path: &str,
+    encryption_key: Option<&str>,
+) -> Result<IntrospectResult, CliError> {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| CliError::Other(format!("Failed to create async runtime: {e}")))?;
+
+    rt.block_on(introspect_libsql_inner(path, encryption_key))
+}
+
+#[cfg(feature = "libsql")]
+async fn introspect_libsql_inner(
+    path: &str,
+    encryption_key: Option<&str>,
+) -> Result<IntrospectResult, CliError> {
+    use drizzle_migrations::sqlite::SqliteIntrospector;
+
+    let db = open_libsql_local(path, encryption_key).await?;
+
+    let conn = db
+        .connect()
+        .map_err(|e| CliError::ConnectionError(e.to_string()))?;
+
+    let ddl = SqliteIntrospector::new(&conn)
+        .with_view_columns()
+        .ddl_async()
+        .await
+        .map_err(introspect_error)?;
+
+    Ok(sqlite_introspect_result(
+        &ddl,
+        format!("Schema introspected from {path}"),
+    ))
 }
 
 // ============================================================================
@@ -3460,61 +2794,43 @@ async fn introspect_libsql_inner(
 // ============================================================================
 
 #[cfg(feature = "turso")]
-fn introspect_turso(url: &str, auth_token: Option<&str>) -> Result<IntrospectResult, CliError> {
+fn introspect_turso(
+    url: &str,
+    auth_token: Option<&str>,
+    encryption_key: Option<&str>,
+) -> Result<IntrospectResult, CliError> {
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
         .map_err(|e| CliError::Other(format!("Failed to create async runtime: {e}")))?;
 
-    rt.block_on(introspect_turso_inner(url, auth_token))
+    rt.block_on(introspect_turso_inner(url, auth_token, encryption_key))
 }
 
 #[cfg(feature = "turso")]
 async fn introspect_turso_inner(
     url: &str,
     auth_token: Option<&str>,
+    encryption_key: Option<&str>,
 ) -> Result<IntrospectResult, CliError> {
-    use drizzle_migrations::sqlite::codegen::{CodegenOptions, FieldCasing, generate_rust_schema};
-
-    let builder =
-        libsql::Builder::new_remote(url.to_string(), auth_token.unwrap_or("").to_string());
+    use drizzle_migrations::sqlite::SqliteIntrospector;
 
-    let db = builder.build().await.map_err(|e| {
-        CliError::ConnectionError(format!("Failed to connect to Turso '{url}': {e}"))
-    })?;
+    let db = open_turso(url, auth_token, encryption_key).await?;
 
     let conn = db
         .connect()
         .map_err(|e| CliError::ConnectionError(e.to_string()))?;
 
-    let raw = query_libsql_raw(&conn).await?;
-    let ddl = build_sqlite_ddl(raw);
-
-    let options = CodegenOptions {
-        module_doc: Some(format!("Schema introspected from Turso: {url}")),
-        include_schema: true,
-        schema_name: "Schema".to_string(),
-        use_pub: true,
-        field_casing: FieldCasing::default(),
-    };
-
-    let generated = generate_rust_schema(&ddl, &options);
-
-    let mut sqlite_snapshot = drizzle_migrations::sqlite::SQLiteSnapshot::new();
-    for entity in ddl.to_entities() {
-        sqlite_snapshot.add_entity(entity);
-    }
-    let snapshot = Snapshot::Sqlite(sqlite_snapshot);
+    let ddl = SqliteIntrospector::new(&conn)
+        .with_view_columns()
+        .ddl_async()
+        .await
+        .map_err(introspect_error)?;
 
-    Ok(IntrospectResult {
-        schema_code: generated.code,
-        table_count: generated.tables.len(),
-        index_count: generated.indexes.len(),
-        view_count: ddl.views.len(),
-        warnings: generated.warnings,
-        snapshot,
-        snapshot_path: std::path::PathBuf::new(),
-    })
+    Ok(sqlite_introspect_result(
+        &ddl,
+        format!("Schema introspected from Turso: {url}"),
+    ))
 }
 
 // ============================================================================
@@ -3542,6 +2858,8 @@ impl PostgresRawData {
             tables: Vec::new(),
             columns: Vec::new(),
             enums: Vec::new(),
+            domains: Vec::new(),
+            composites: Vec::new(),
             sequences: Vec::new(),
             views: Vec::new(),
             indexes: Vec::new(),
@@ -3549,6 +2867,7 @@ impl PostgresRawData {
             primary_keys: Vec::new(),
             uniques: Vec::new(),
             checks: Vec::new(),
+            excludes: Vec::new(),
             roles: Vec::new(),
             policies: Vec::new(),
         }
@@ -3628,7 +2947,7 @@ fn query_pg_sync_codegen_meta(
     raw: &mut PostgresRawData,
 ) -> Result<(), CliError> {
     use drizzle_migrations::postgres::introspect::{
-        RawEnumInfo, RawSequenceInfo, RawViewInfo, queries,
+        RawCompositeInfo, RawDomainInfo, RawEnumInfo, RawSequenceInfo, RawViewInfo, queries,
     };
 
     raw.enums = client
@@ -3642,6 +2961,32 @@ fn query_pg_sync_codegen_meta(
         })
         .collect();
 
+    raw.domains = client
+        .query(queries::DOMAINS_QUERY, &[])
+        .map_err(|e| CliError::Other(format!("Failed to query domains: {e}")))?
+        .into_iter()
+        .map(|row| RawDomainInfo {
+            schema: row.get::<_, String>(0),
+            name: row.get::<_, String>(1),
+            base_type: row.get::<_, String>(2),
+            not_null: row.get::<_, bool>(3),
+            default_value: row.get::<_, Option<String>>(4),
+            check: row.get::<_, Option<String>>(5),
+        })
+        .collect();
+
+    raw.composites = client
+        .query(queries::COMPOSITES_QUERY, &[])
+        .map_err(|e| CliError::Other(format!("Failed to query composite types: {e}")))?
+        .into_iter()
+        .map(|row| RawCompositeInfo {
+            schema: row.get::<_, String>(0),
+            name: row.get::<_, String>(1),
+            field_names: row.get::<_, Vec<String>>(2),
+            field_types: row.get::<_, Vec<String>>(3),
+        })
+        .collect();
+
     raw.sequences = client
         .query(queries::SEQUENCES_QUERY, &[])
         .map_err(|e| CliError::Other(format!("Failed to query sequences: {e}")))?
@@ -3681,8 +3026,8 @@ fn query_pg_sync_constraints(
     raw: &mut PostgresRawData,
 ) -> Result<(), CliError> {
     use drizzle_migrations::postgres::introspect::{
-        RawCheckInfo, RawForeignKeyInfo, RawIndexInfo, RawPrimaryKeyInfo, RawUniqueInfo,
-        parse_index_columns, pg_action_code_to_string, queries,
+        RawCheckInfo, RawExcludeInfo, RawForeignKeyInfo, RawIndexInfo, RawPrimaryKeyInfo,
+        RawUniqueInfo, parse_index_columns, pg_action_code_to_string, queries,
     };
 
     raw.indexes = client
@@ -3763,6 +3108,23 @@ fn query_pg_sync_constraints(
         })
         .collect();
 
+    raw.excludes = client
+        .query(queries::EXCLUDES_QUERY, &[])
+        .map_err(|e| CliError::Other(format!("Failed to query exclusion constraints: {e}")))?
+        .into_iter()
+        .map(|row| RawExcludeInfo {
+            schema: row.get::<_, String>(0),
+            table: row.get::<_, String>(1),
+            name: row.get::<_, String>(2),
+            method: row.get::<_, String>(3),
+            columns: row.get::<_, Vec<String>>(4),
+            operators: row.get::<_, Vec<String>>(5),
+            where_clause: row.get::<_, Option<String>>(6),
+            deferrable: row.get::<_, bool>(7),
+            initially_deferred: row.get::<_, bool>(8),
+        })
+        .collect();
+
     Ok(())
 }
 
@@ -3907,7 +3269,7 @@ async fn query_pg_async_codegen_meta(
     raw: &mut PostgresRawData,
 ) -> Result<(), CliError> {
     use drizzle_migrations::postgres::introspect::{
-        RawEnumInfo, RawSequenceInfo, RawViewInfo, queries,
+        RawCompositeInfo, RawDomainInfo, RawEnumInfo, RawSequenceInfo, RawViewInfo, queries,
     };
 
     raw.enums = client
@@ -3922,6 +3284,34 @@ async fn query_pg_async_codegen_meta(
         })
         .collect();
 
+    raw.domains = client
+        .query(queries::DOMAINS_QUERY, &[])
+        .await
+        .map_err(|e| CliError::Other(format!("Failed to query domains: {e}")))?
+        .into_iter()
+        .map(|row| RawDomainInfo {
+            schema: row.get::<_, String>(0),
+            name: row.get::<_, String>(1),
+            base_type: row.get::<_, String>(2),
+            not_null: row.get::<_, bool>(3),
+            default_value: row.get::<_, Option<String>>(4),
+            check: row.get::<_, Option<String>>(5),
+        })
+        .collect();
+
+    raw.composites = client
+        .query(queries::COMPOSITES_QUERY, &[])
+        .await
+        .map_err(|e| CliError::Other(format!("Failed to query composite types: {e}")))?
+        .into_iter()
+        .map(|row| RawCompositeInfo {
+            schema: row.get::<_, String>(0),
+            name: row.get::<_, String>(1),
+            field_names: row.get::<_, Vec<String>>(2),
+            field_types: row.get::<_, Vec<String>>(3),
+        })
+        .collect();
+
     raw.sequences = client
         .query(queries::SEQUENCES_QUERY, &[])
         .await
@@ -3963,8 +3353,8 @@ async fn query_pg_async_constraints(
     raw: &mut PostgresRawData,
 ) -> Result<(), CliError> {
     use drizzle_migrations::postgres::introspect::{
-        RawCheckInfo, RawForeignKeyInfo, RawIndexInfo, RawPrimaryKeyInfo, RawUniqueInfo,
-        parse_index_columns, pg_action_code_to_string, queries,
+        RawCheckInfo, RawExcludeInfo, RawForeignKeyInfo, RawIndexInfo, RawPrimaryKeyInfo,
+        RawUniqueInfo, parse_index_columns, pg_action_code_to_string, queries,
     };
 
     raw.indexes = client
@@ -4050,6 +3440,24 @@ async fn query_pg_async_constraints(
         })
         .collect();
 
+    raw.excludes = client
+        .query(queries::EXCLUDES_QUERY, &[])
+        .await
+        .map_err(|e| CliError::Other(format!("Failed to query exclusion constraints: {e}")))?
+        .into_iter()
+        .map(|row| RawExcludeInfo {
+            schema: row.get::<_, String>(0),
+            table: row.get::<_, String>(1),
+            name: row.get::<_, String>(2),
+            method: row.get::<_, String>(3),
+            columns: row.get::<_, Vec<String>>(4),
+            operators: row.get::<_, Vec<String>>(5),
+            where_clause: row.get::<_, Option<String>>(6),
+            deferrable: row.get::<_, bool>(7),
+            initially_deferred: row.get::<_, bool>(8),
+        })
+        .collect();
+
     Ok(())
 }
 
@@ -4111,6 +3519,8 @@ struct PostgresRawData {
     tables: Vec<drizzle_migrations::postgres::introspect::RawTableInfo>,
     columns: Vec<drizzle_migrations::postgres::introspect::RawColumnInfo>,
     enums: Vec<drizzle_migrations::postgres::introspect::RawEnumInfo>,
+    domains: Vec<drizzle_migrations::postgres::introspect::RawDomainInfo>,
+    composites: Vec<drizzle_migrations::postgres::introspect::RawCompositeInfo>,
     sequences: Vec<drizzle_migrations::postgres::introspect::RawSequenceInfo>,
     views: Vec<drizzle_migrations::postgres::introspect::RawViewInfo>,
     indexes: Vec<drizzle_migrations::postgres::introspect::RawIndexInfo>,
@@ -4118,6 +3528,7 @@ struct PostgresRawData {
     primary_keys: Vec<drizzle_migrations::postgres::introspect::RawPrimaryKeyInfo>,
     uniques: Vec<drizzle_migrations::postgres::introspect::RawUniqueInfo>,
     checks: Vec<drizzle_migrations::postgres::introspect::RawCheckInfo>,
+    excludes: Vec<drizzle_migrations::postgres::introspect::RawExcludeInfo>,
     roles: Vec<drizzle_migrations::postgres::introspect::RawRoleInfo>,
     policies: Vec<drizzle_migrations::postgres::introspect::RawPolicyInfo>,
 }
@@ -4141,6 +3552,8 @@ fn build_postgres_ddl(
         tables: raw.tables,
         columns: raw.columns,
         enums: raw.enums,
+        domains: raw.domains,
+        composites: raw.composites,
         sequences: raw.sequences,
         views: raw.views,
         indexes: raw.indexes,
@@ -4148,6 +3561,7 @@ fn build_postgres_ddl(
         primary_keys: raw.primary_keys,
         unique_constraints: raw.uniques,
         check_constraints: raw.checks,
+        exclude_constraints: raw.excludes,
         roles: raw.roles,
         policies: raw.policies,
     })
@@ -4227,25 +3641,51 @@ mod tests {
         format!("{}_{}_{}", prefix, std::process::id(), nanos)
     }
 
+    #[cfg(feature = "rusqlite")]
     #[test]
-    fn destructive_statement_detection_covers_drop_variants() {
-        assert!(is_destructive_statement("DROP TABLE users;"));
-        assert!(is_destructive_statement("DROP VIEW active_users;"));
-        assert!(is_destructive_statement("DROP TYPE status;"));
-        assert!(is_destructive_statement("DROP SCHEMA auth;"));
-        assert!(is_destructive_statement("DROP ROLE app_user;"));
-        assert!(is_destructive_statement(
-            "DROP POLICY users_rls_policy ON users;"
-        ));
-        assert!(is_destructive_statement("TRUNCATE users;"));
-        assert!(is_destructive_statement(
-            "ALTER TABLE users DROP CONSTRAINT users_email_key;"
-        ));
+    fn sqlite_failed_migration_rolls_back_the_whole_run() {
+        use drizzle_migrations::{Migration, Migrations};
 
-        assert!(!is_destructive_statement("CREATE TABLE users(id INTEGER);"));
-        assert!(!is_destructive_statement(
-            "ALTER TABLE users ADD COLUMN email text;"
-        ));
+        let dir = tempfile::tempdir().expect("tempdir");
+        let db_path = dir.path().join("rollback.sqlite");
+        let db_path_str = db_path.to_string_lossy().to_string();
+
+        let set = Migrations::new(
+            vec![
+                Migration::with_hash(
+                    "20240101000000_ok",
+                    "hash_ok",
+                    1_704_067_200_000,
+                    vec!["CREATE TABLE rollback_ok (id INTEGER PRIMARY KEY)".to_string()],
+                ),
+                Migration::with_hash(
+                    "20240102000000_broken",
+                    "hash_broken",
+                    1_704_153_600_000,
+                    vec!["CREATE TABLE rollback_ok (id INTEGER PRIMARY KEY)".to_string()],
+                ),
+            ],
+            drizzle_types::Dialect::SQLite,
+        );
+
+        let err = run_sqlite_migrations(&set, &db_path_str).expect_err("second migration fails");
+        assert!(err.to_string().contains("hash_broken"), "{err}");
+
+        let conn = rusqlite::Connection::open(&db_path).expect("open sqlite");
+        let tables: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='rollback_ok'",
+                [],
+                |row| row.get(0),
+            )
+            .expect("query sqlite_master");
+        assert_eq!(tables, 0, "the first migration should be rolled back too");
+        let rows: i64 = conn
+            .query_row("SELECT COUNT(*) FROM __drizzle_migrations", [], |row| {
+                row.get(0)
+            })
+            .expect("count migrations rows");
+        assert_eq!(rows, 0);
     }
 
     #[cfg(feature = "rusqlite")]
@@ -4338,6 +3778,8 @@ mod tests {
             &migrations_dir,
             "__drizzle_migrations",
             "drizzle",
+            false,
+            None,
         )
         .expect("run migrations");
         assert_eq!(result.applied_count, 0);
@@ -4475,6 +3917,35 @@ mod tests {
         assert_eq!(plan.pending_migrations, vec!["20230331150000_second"]);
     }
 
+    #[test]
+    fn build_migration_plan_stops_at_target_tag() {
+        use drizzle_migrations::{Migration, Migrations};
+
+        let set = Migrations::new(
+            vec![
+                Migration::with_hash(
+                    "20230331141203_first",
+                    "hash_a",
+                    1_680_271_923_000,
+                    vec!["CREATE TABLE a(id INTEGER PRIMARY KEY)".to_string()],
+                ),
+                Migration::with_hash(
+                    "20230331150000_second",
+                    "hash_b",
+                    1_680_275_400_000,
+                    vec!["CREATE TABLE b(id INTEGER PRIMARY KEY)".to_string()],
+                ),
+            ],
+            drizzle_types::Dialect::SQLite,
+        )
+        .up_to("20230331141203_first")
+        .expect("known tag");
+
+        let plan = build_migration_plan(&set, &[]).expect("build migration plan");
+        assert_eq!(plan.pending_count, 1);
+        assert_eq!(plan.pending_migrations, vec!["20230331141203_first"]);
+    }
+
     #[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
     #[test]
     fn postgres_concurrent_index_detection_is_case_insensitive() {
@@ -4591,6 +4062,7 @@ pub struct Users {
             tables: Some(vec!["admin.*".to_string()]),
             schemas: None,
             extensions: None,
+            tracking: None,
         };
 
         apply_snapshot_filters(&mut snapshot, crate::config::Dialect::Postgresql, &filters)
@@ -4641,6 +4113,7 @@ pub struct UsersPublic {
             tables: Some(vec!["users_*".to_string()]),
             schemas: Some(vec!["!dev".to_string()]),
             extensions: None,
+            tracking: None,
         };
 
         apply_snapshot_filters(&mut snapshot, crate::config::Dialect::Postgresql, &filters)
@@ -4700,6 +4173,7 @@ pub struct Users {
             tables: None,
             schemas: None,
             extensions: Some(vec![Extension::Postgis]),
+            tracking: None,
         };
 
         apply_snapshot_filters(&mut snapshot, crate::config::Dialect::Postgresql, &filters)
@@ -4726,6 +4200,120 @@ pub struct Users {
         );
     }
 
+    #[test]
+    fn postgres_tracking_filter_excludes_custom_migrations_table() {
+        use crate::snapshot::parse_result_to_snapshot;
+        use drizzle_migrations::parser::SchemaParser;
+        use drizzle_migrations::schema::Snapshot;
+        use drizzle_types::Dialect as BaseDialect;
+        use drizzle_types::postgres::ddl::PostgresEntity;
+
+        let code = r#"
+#[PostgresTable(schema = "ops", name = "my_migrations")]
+pub struct MyMigrations {
+    #[column(primary)]
+    pub id: i32,
+}
+
+#[PostgresTable]
+pub struct Users {
+    #[column(primary)]
+    pub id: i32,
+}
+"#;
+
+        let parsed = SchemaParser::parse(code);
+        let mut snapshot = parse_result_to_snapshot(&parsed, BaseDialect::PostgreSQL, None);
+        let filters = SnapshotFilters {
+            tracking: Some(migration_tracking(
+                crate::config::Dialect::Postgresql,
+                "my_migrations",
+                "ops",
+            )),
+            ..SnapshotFilters::default()
+        };
+
+        apply_snapshot_filters(&mut snapshot, crate::config::Dialect::Postgresql, &filters)
+            .expect("apply filters");
+
+        let Snapshot::Postgres(s) = snapshot else {
+            panic!("expected postgres snapshot");
+        };
+        let remaining_tables = s
+            .ddl
+            .iter()
+            .filter_map(|e| match e {
+                PostgresEntity::Table(t) => Some((t.schema.to_string(), t.name.to_string())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            remaining_tables,
+            vec![("public".to_string(), "users".to_string())]
+        );
+        assert!(
+            !s.ddl
+                .iter()
+                .any(|e| matches!(e, PostgresEntity::Schema(schema) if schema.name == "ops"))
+        );
+    }
+
+    #[test]
+    fn sqlite_tracking_filter_excludes_custom_migrations_table() {
+        use crate::snapshot::parse_result_to_snapshot;
+        use drizzle_migrations::parser::SchemaParser;
+        use drizzle_migrations::schema::Snapshot;
+        use drizzle_types::Dialect as BaseDialect;
+        use drizzle_types::sqlite::ddl::SqliteEntity;
+
+        let code = r#"
+#[SQLiteTable(name = "my_migrations")]
+pub struct MyMigrations {
+    #[column(primary)]
+    pub id: i32,
+}
+
+#[SQLiteTable]
+pub struct Users {
+    #[column(primary)]
+    pub id: i32,
+}
+"#;
+
+        let parsed = SchemaParser::parse(code);
+        let mut snapshot = parse_result_to_snapshot(&parsed, BaseDialect::SQLite, None);
+        let filters = SnapshotFilters {
+            tracking: Some(migration_tracking(
+                crate::config::Dialect::Sqlite,
+                "my_migrations",
+                "drizzle",
+            )),
+            ..SnapshotFilters::default()
+        };
+
+        apply_snapshot_filters(&mut snapshot, crate::config::Dialect::Sqlite, &filters)
+            .expect("apply filters");
+
+        let Snapshot::Sqlite(s) = snapshot else {
+            panic!("expected sqlite snapshot");
+        };
+        let remaining_tables = s
+            .ddl
+            .iter()
+            .filter_map(|e| match e {
+                SqliteEntity::Table(t) => Some(t.name.to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(remaining_tables, vec!["users".to_string()]);
+        assert!(s.ddl.iter().all(|e| match e {
+            SqliteEntity::Column(c) => c.table != "my_migrations",
+            _ => true,
+        }));
+    }
+
     #[test]
     fn format_migration_sql_respects_breakpoints_flag() {
         let sql = vec![
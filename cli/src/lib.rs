@@ -39,6 +39,7 @@
 //! - `drizzle generate` - Generate a new migration from schema changes
 //! - `drizzle generate --custom` - Create an empty migration for manual SQL
 //! - `drizzle status` - Show migration status
+//! - `drizzle config check` - Validate the config against the environment (also [`Config::validate`])
 //! - `drizzle migrate` - Run pending migrations (requires database connection)
 //! - `drizzle push` - Push schema directly to database (requires database connection)
 //! - `drizzle introspect` - Introspect database and generate snapshot (requires database connection)
@@ -50,5 +51,5 @@ pub mod error;
 pub mod output;
 pub mod snapshot;
 
-pub use config::{Config, Credentials, Dialect, Driver, Error};
+pub use config::{Config, ConfigIssue, Credentials, Dialect, Driver, Error};
 pub use error::CliError;
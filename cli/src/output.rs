@@ -1,6 +1,66 @@
 //! CLI output helpers for consistent, drizzle-kit-like formatting.
+//!
+//! Under `--json` ([`set_json`]) commands still narrate through [`outln!`],
+//! but those lines go to stderr; stdout carries only the JSON document
+//! passed to [`emit_json`].
+
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use colored::Colorize;
+use serde::Serialize;
+
+use crate::error::CliError;
+
+static JSON: AtomicBool = AtomicBool::new(false);
+
+/// Switch machine-readable output on or off (the global `--json` flag).
+pub fn set_json(enabled: bool) {
+    JSON.store(enabled, Ordering::Relaxed);
+}
+
+/// Is `--json` in effect?
+#[must_use]
+pub fn json_enabled() -> bool {
+    JSON.load(Ordering::Relaxed)
+}
+
+/// `print!` for human-readable output; stderr under `--json`.
+macro_rules! out {
+    ($($arg:tt)*) => {
+        if $crate::output::json_enabled() {
+            eprint!($($arg)*);
+        } else {
+            print!($($arg)*);
+        }
+    };
+}
+
+/// `println!` for human-readable output; stderr under `--json`.
+macro_rules! outln {
+    ($($arg:tt)*) => {
+        if $crate::output::json_enabled() {
+            eprintln!($($arg)*);
+        } else {
+            println!($($arg)*);
+        }
+    };
+}
+
+pub(crate) use {out, outln};
+
+/// Print a command's result to stdout as JSON when `--json` is on.
+///
+/// # Errors
+///
+/// Returns [`CliError::Other`] if `value` fails to serialize.
+pub fn emit_json<T: Serialize>(value: &T) -> Result<(), CliError> {
+    if json_enabled() {
+        let json =
+            serde_json::to_string_pretty(value).map_err(|e| CliError::Other(e.to_string()))?;
+        println!("{json}");
+    }
+    Ok(())
+}
 
 #[must_use]
 pub fn heading(text: &str) -> String {
@@ -0,0 +1,62 @@
+#![cfg(feature = "rusqlite")]
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::PredicateBooleanExt;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn diff_prints_sql_between_two_databases() {
+    let dir = tempdir().unwrap();
+    let root = dir.path();
+
+    let staging = root.join("staging.db");
+    let production = root.join("production.db");
+    rusqlite::Connection::open(&staging)
+        .unwrap()
+        .execute_batch("CREATE TABLE users (id INTEGER PRIMARY KEY);")
+        .unwrap();
+    rusqlite::Connection::open(&production)
+        .unwrap()
+        .execute_batch(
+            "CREATE TABLE users (id INTEGER PRIMARY KEY);
+             CREATE TABLE posts (id INTEGER PRIMARY KEY, title TEXT NOT NULL);",
+        )
+        .unwrap();
+
+    fs::write(
+        root.join("drizzle.config.toml"),
+        format!(
+            r#"
+dialect = "sqlite"
+schema = "schema.rs"
+
+[dbCredentials]
+url = '{db_url}'
+"#,
+            db_url = staging.to_string_lossy()
+        ),
+    )
+    .unwrap();
+
+    cargo_bin_cmd!("drizzle")
+        .current_dir(root)
+        .args(["diff", "--from", "db", "--to"])
+        .arg(&production)
+        .assert()
+        .success()
+        .stdout(
+            predicates::str::contains("CREATE TABLE `posts`")
+                .and(predicates::str::contains("CREATE TABLE `users`").not()),
+        );
+
+    cargo_bin_cmd!("drizzle")
+        .current_dir(root)
+        .args(["diff", "--from"])
+        .arg(&production)
+        .arg("--to")
+        .arg(&production)
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("No differences found."));
+}
@@ -0,0 +1,101 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+use tempfile::tempdir;
+
+/// Kills the watcher even when an assertion fails.
+struct Watcher(Child);
+
+impl Drop for Watcher {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+fn migration_sqls(migrations_dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(migrations_dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| fs::read_to_string(entry.path().join("migration.sql")).ok())
+        .collect()
+}
+
+fn wait_for(what: &str, mut done: impl FnMut() -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(30);
+    while !done() {
+        assert!(Instant::now() < deadline, "timed out waiting for {what}");
+        std::thread::sleep(Duration::from_millis(100));
+    }
+}
+
+#[test]
+fn generate_watch_rewrites_the_session_migration() {
+    let dir = tempdir().expect("temp dir");
+    let root = dir.path();
+    let migrations_dir = root.join("migrations");
+    let schema_path = root.join("schema.rs");
+
+    fs::write(
+        root.join("drizzle.config.toml"),
+        format!(
+            "dialect = \"sqlite\"\nschema = '{}'\nout = '{}'\n",
+            schema_path.to_string_lossy(),
+            migrations_dir.to_string_lossy()
+        ),
+    )
+    .expect("write config");
+    fs::write(
+        &schema_path,
+        r#"
+#[SQLiteTable]
+pub struct Users {
+    #[column(primary)]
+    pub id: i64,
+}
+"#,
+    )
+    .expect("write schema");
+
+    let _watcher = Watcher(
+        Command::new(assert_cmd::cargo::cargo_bin!("drizzle"))
+            .current_dir(root)
+            .args(["generate", "--watch", "--name", "dev"])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("spawn drizzle generate --watch"),
+    );
+
+    wait_for("the first migration", || {
+        migration_sqls(&migrations_dir)
+            .iter()
+            .any(|sql| sql.contains("`users`"))
+    });
+
+    fs::write(
+        &schema_path,
+        r#"
+#[SQLiteTable]
+pub struct Users {
+    #[column(primary)]
+    pub id: i64,
+}
+
+#[SQLiteTable]
+pub struct Posts {
+    #[column(primary)]
+    pub id: i64,
+}
+"#,
+    )
+    .expect("rewrite schema");
+
+    wait_for("the regenerated migration", || {
+        let sqls = migration_sqls(&migrations_dir);
+        sqls.len() == 1 && sqls[0].contains("`users`") && sqls[0].contains("`posts`")
+    });
+}
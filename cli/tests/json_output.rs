@@ -0,0 +1,102 @@
+#![cfg(feature = "rusqlite")]
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn write_config(root: &Path) {
+    fs::write(
+        root.join("drizzle.config.toml"),
+        format!(
+            r#"
+dialect = "sqlite"
+schema = '{schema_path}'
+out = '{out_dir}'
+
+[dbCredentials]
+url = '{db_url}'
+"#,
+            schema_path = root.join("schema.rs").to_string_lossy(),
+            out_dir = root.join("migrations").to_string_lossy(),
+            db_url = root.join("dev.db").to_string_lossy()
+        ),
+    )
+    .expect("write config");
+
+    fs::write(root.join("schema.rs"), "// test schema\n").expect("write schema");
+}
+
+/// Run `drizzle <args> --json` and parse stdout, which must hold only JSON.
+fn run_json(root: &Path, args: &[&str], success: bool) -> Value {
+    let mut cmd = cargo_bin_cmd!("drizzle");
+    cmd.current_dir(root).args(args).arg("--json");
+    let assert = if success {
+        cmd.assert().success()
+    } else {
+        cmd.assert().failure()
+    };
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).expect("utf8 stdout");
+    serde_json::from_str(&stdout).unwrap_or_else(|e| panic!("stdout is not JSON ({e}):\n{stdout}"))
+}
+
+#[test]
+fn json_reports_generate_migrate_and_status() {
+    let dir = tempdir().expect("tempdir");
+    let root = dir.path();
+    write_config(root);
+
+    let generated = run_json(root, &["generate", "--custom", "--name", "json"], true);
+    let tag = generated["tag"].as_str().expect("tag").to_string();
+    assert!(tag.ends_with("_json"), "{generated}");
+    let migration_sql = root.join("migrations").join(&tag).join("migration.sql");
+    assert_eq!(
+        generated["files"][0].as_str(),
+        Some(migration_sql.to_string_lossy().as_ref())
+    );
+    fs::write(
+        &migration_sql,
+        "CREATE TABLE json_table (id INTEGER PRIMARY KEY);\n",
+    )
+    .expect("write migration.sql");
+
+    let plan = run_json(root, &["migrate", "--plan"], true);
+    assert_eq!(plan["plan"]["pendingCount"], 1);
+    assert_eq!(plan["plan"]["pendingMigrations"][0], tag.as_str());
+    assert!(plan.get("result").is_none(), "{plan}");
+
+    let migrated = run_json(root, &["migrate"], true);
+    assert_eq!(migrated["result"]["appliedCount"], 1);
+
+    let status = run_json(root, &["status"], true);
+    assert_eq!(status["migrations"][0]["tag"], tag.as_str());
+    assert_eq!(status["migrations"][0]["hasSnapshot"], false);
+}
+
+#[test]
+fn json_reports_errors_and_unsupported_commands() {
+    let dir = tempdir().expect("tempdir");
+    let root = dir.path();
+    write_config(root);
+
+    let error = run_json(
+        root,
+        &["generate", "--custom", "--name", "../escape"],
+        false,
+    );
+    assert!(
+        error["error"]
+            .as_str()
+            .is_some_and(|msg| msg.contains("invalid migration name")),
+        "{error}"
+    );
+
+    let error = run_json(root, &["check"], false);
+    assert!(
+        error["error"]
+            .as_str()
+            .is_some_and(|msg| msg.contains("--json is only supported")),
+        "{error}"
+    );
+}
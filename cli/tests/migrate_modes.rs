@@ -147,6 +147,53 @@ fn migrate_verify_detects_hash_drift() {
         .stderr(contains("Migration failed: Migration hash mismatch"));
 }
 
+#[test]
+fn migrate_repair_rebaselines_drifted_hashes() {
+    let dir = tempdir().expect("tempdir");
+    let root = dir.path();
+    let db_path = root.join("dev.db");
+    let migrations_dir = root.join("migrations");
+
+    write_config(root, &db_path, &migrations_dir);
+
+    let tag = generate_custom_migration(root, &migrations_dir, "repair");
+    let migration_sql = migrations_dir.join(&tag).join("migration.sql");
+
+    fs::write(
+        &migration_sql,
+        "CREATE TABLE repair_original (id INTEGER PRIMARY KEY);\n",
+    )
+    .expect("write initial migration.sql");
+
+    cargo_bin_cmd!("drizzle")
+        .current_dir(root)
+        .args(["migrate"])
+        .assert()
+        .success();
+
+    fs::write(
+        &migration_sql,
+        "CREATE TABLE repair_changed (id INTEGER PRIMARY KEY);\n",
+    )
+    .expect("rewrite migration.sql");
+
+    cargo_bin_cmd!("drizzle")
+        .current_dir(root)
+        .args(["migrate", "--repair"])
+        .assert()
+        .success()
+        .stdout(contains(tag.as_str()));
+
+    cargo_bin_cmd!("drizzle")
+        .current_dir(root)
+        .args(["migrate", "--verify"])
+        .assert()
+        .success();
+
+    let conn = rusqlite::Connection::open(&db_path).expect("open sqlite");
+    assert_eq!(table_exists(&conn, "repair_changed"), 0);
+}
+
 #[test]
 fn migrate_safe_applies_after_verification() {
     let dir = tempdir().expect("tempdir");
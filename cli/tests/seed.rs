@@ -0,0 +1,100 @@
+#![cfg(feature = "rusqlite")]
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn dump(db: &Path, table: &str) -> Vec<(i64, String)> {
+    let conn = rusqlite::Connection::open(db).unwrap();
+    let mut stmt = conn
+        .prepare(&format!("SELECT id, name FROM {table} ORDER BY id"))
+        .unwrap();
+    stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+#[test]
+fn seed_out_writes_a_script_that_matches_a_live_seed() {
+    let dir = tempdir().unwrap();
+    let root = dir.path();
+    let db_path = root.join("dev.db");
+
+    fs::write(
+        root.join("schema.rs"),
+        r#"
+#[SQLiteTable]
+pub struct Users {
+    #[column(primary)]
+    pub id: i64,
+    pub name: String,
+}
+
+#[SQLiteTable]
+pub struct Posts {
+    #[column(primary)]
+    pub id: i64,
+    pub name: String,
+    #[column(references = Users::id)]
+    pub author_id: i64,
+}
+"#,
+    )
+    .unwrap();
+    fs::write(
+        root.join("drizzle.config.toml"),
+        format!(
+            r#"
+dialect = "sqlite"
+schema = "schema.rs"
+
+[dbCredentials]
+url = '{db_url}'
+"#,
+            db_url = db_path.to_string_lossy()
+        ),
+    )
+    .unwrap();
+
+    cargo_bin_cmd!("drizzle")
+        .current_dir(root)
+        .args(["push", "--force"])
+        .assert()
+        .success();
+    let scripted_db = root.join("scripted.db");
+    fs::copy(&db_path, &scripted_db).unwrap();
+
+    cargo_bin_cmd!("drizzle")
+        .current_dir(root)
+        .args(["seed", "--seed", "7", "--table", "posts=30"])
+        .args(["--out", "seeds/dev.sql"])
+        .assert()
+        .success();
+    let script = fs::read_to_string(root.join("seeds/dev.sql")).unwrap();
+    assert!(script.starts_with("INSERT INTO"));
+    assert!(!script.contains('?'));
+
+    cargo_bin_cmd!("drizzle")
+        .current_dir(root)
+        .args(["seed", "--seed", "7", "--table", "posts=30"])
+        .assert()
+        .success();
+    rusqlite::Connection::open(&scripted_db)
+        .unwrap()
+        .execute_batch(&script)
+        .unwrap();
+
+    assert_eq!(dump(&db_path, "users").len(), 10);
+    assert_eq!(dump(&db_path, "posts").len(), 30);
+    assert_eq!(dump(&scripted_db, "users"), dump(&db_path, "users"));
+    assert_eq!(dump(&scripted_db, "posts"), dump(&db_path, "posts"));
+
+    cargo_bin_cmd!("drizzle")
+        .current_dir(root)
+        .args(["seed", "--table", "comments=5", "--out", "x.sql"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("no table named `comments`"));
+}
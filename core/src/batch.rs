@@ -0,0 +1,37 @@
+//! Helpers for loading many rows by key (`get_many`).
+//!
+//! Drivers split the key list into chunks that fit the dialect's bind
+//! parameter limit, run one `WHERE key IN (...)` query per chunk, and use
+//! [`order_by_keys`] to line the results up with the requested keys.
+
+use crate::prelude::*;
+use core::hash::Hash;
+
+/// Maximum keys bound per `get_many` query on `SQLite`.
+///
+/// 999 is the `SQLITE_MAX_VARIABLE_NUMBER` default before `SQLite` 3.32 and
+/// stays safe on builds that never raised it.
+pub const SQLITE_MAX_BATCH_KEYS: usize = 999;
+
+/// Maximum keys bound per `get_many` query on `PostgreSQL`.
+///
+/// `rust-postgres` encodes the bind parameter count as an `i16`.
+pub const POSTGRES_MAX_BATCH_KEYS: usize = 32767;
+
+/// Arranges `rows` in the order of `keys`.
+///
+/// Each position holds the row whose `key_of` matches that key, or `None`
+/// when no row was found. Repeated keys each get a clone of the same row.
+pub fn order_by_keys<K, R>(
+    keys: &[K],
+    rows: impl IntoIterator<Item = R>,
+    key_of: impl Fn(&R) -> K,
+) -> Vec<Option<R>>
+where
+    K: Hash + Eq,
+    R: Clone,
+{
+    let by_key: HashMap<K, R> = rows.into_iter().map(|row| (key_of(&row), row)).collect();
+
+    keys.iter().map(|key| by_key.get(key).cloned()).collect()
+}
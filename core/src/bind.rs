@@ -258,6 +258,14 @@ impl_value_type!(PostgresDialect, pg_ty::Point => geo_types::Point<f64>);
 impl_value_type!(PostgresDialect, pg_ty::LineString => geo_types::LineString<f64>);
 #[cfg(feature = "geo-types")]
 impl_value_type!(PostgresDialect, pg_ty::Rect => geo_types::Rect<f64>);
+#[cfg(feature = "geo-types")]
+impl_value_type!(PostgresDialect, pg_ty::Geometry =>
+    geo_types::Geometry<f64>,
+    geo_types::Polygon<f64>,
+    geo_types::MultiPoint<f64>,
+    geo_types::MultiLineString<f64>,
+    geo_types::MultiPolygon<f64>,
+);
 
 #[cfg(feature = "bit-vec")]
 impl_value_type!(PostgresDialect, pg_ty::BitString => bit_vec::BitVec);
@@ -9,7 +9,10 @@ pub use states::*;
 /// This is an extension point for driver crates to opt in builder state
 /// markers that represent complete, executable queries (for example, to
 /// enable set operations or prepared statements on those states).
-pub trait ExecutableState {}
+pub trait ExecutableState {
+    /// Whether queries in this state are `INSERT` statements.
+    const INSERTS: bool = false;
+}
 
 #[derive(Debug, Clone)]
 pub struct BuilderInit;
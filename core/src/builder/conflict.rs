@@ -66,7 +66,7 @@ impl<'a, V: SQLParam> ConflictTargetSql<'a, V> for PostgresConflictTarget<'a, V>
             Self::Columns(columns) => (*columns).into_target_sql(target_where),
             Self::Constraint(name) => {
                 SQL::from_iter([Token::ON, Token::CONFLICT, Token::ON, Token::CONSTRAINT])
-                    .append(SQL::ident(name))
+                    .append(SQL::name(name))
             }
         }
     }
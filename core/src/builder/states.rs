@@ -124,10 +124,18 @@ pub struct InsertOnConflictSet;
 #[derive(Debug, Clone, Copy, Default)]
 pub struct InsertDoUpdateSet;
 
-impl ExecutableState for InsertValuesSet {}
-impl ExecutableState for InsertReturningSet {}
-impl ExecutableState for InsertOnConflictSet {}
-impl ExecutableState for InsertDoUpdateSet {}
+impl ExecutableState for InsertValuesSet {
+    const INSERTS: bool = true;
+}
+impl ExecutableState for InsertReturningSet {
+    const INSERTS: bool = true;
+}
+impl ExecutableState for InsertOnConflictSet {
+    const INSERTS: bool = true;
+}
+impl ExecutableState for InsertDoUpdateSet {
+    const INSERTS: bool = true;
+}
 
 //------------------------------------------------------------------------------
 // DELETE states
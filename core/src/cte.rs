@@ -82,7 +82,7 @@ macro_rules! impl_cte_types {
             Query: $crate::ToSQL<'a, $ValueType>,
         {
             fn to_sql(&self) -> $crate::SQL<'a, $ValueType> {
-                $crate::SQL::ident(self.name)
+                $crate::SQL::name(self.name)
             }
         }
     };
@@ -127,17 +127,27 @@ impl ParamStyle {
         match self {
             Self::DollarNumbered => {
                 let _ = buf.write_char('$');
-                let _ = write!(buf, "{index}");
+                crate::sql::write_usize(buf, index);
             }
             Self::ColonNumbered => {
                 let _ = buf.write_char(':');
-                let _ = write!(buf, "{index}");
+                crate::sql::write_usize(buf, index);
             }
             Self::Question => {
                 let _ = buf.write_char('?');
             }
         }
     }
+
+    /// Byte length of the placeholder [`write`](Self::write) emits for `index`.
+    #[inline]
+    #[must_use]
+    pub const fn rendered_len(self, index: usize) -> usize {
+        match self {
+            Self::DollarNumbered | Self::ColonNumbered => 1 + crate::sql::usize_len(index),
+            Self::Question => 1,
+        }
+    }
 }
 
 /// Writes a dialect-appropriate placeholder directly to a buffer.
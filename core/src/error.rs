@@ -4,6 +4,11 @@ use crate::prelude::{Box, String, ToString, Vec, format};
 use compact_str::CompactString;
 use thiserror::Error;
 
+#[cfg(all(feature = "postgres-sync", not(feature = "tokio-postgres")))]
+use postgres::error as pg_error;
+#[cfg(feature = "tokio-postgres")]
+use tokio_postgres::error as pg_error;
+
 const MAX_CONTEXT_PARAMS: usize = 32;
 const MAX_CONTEXT_PARAM_CHARS: usize = 128;
 
@@ -59,6 +64,68 @@ impl QueryContext {
     }
 }
 
+/// Row and column context captured when a result row fails to decode.
+#[derive(Debug, Clone, Default)]
+pub struct DecodeContext {
+    /// Index of the row within the result set.
+    pub row: Option<usize>,
+    /// Index of the column within the row.
+    pub column_index: Option<usize>,
+    /// Column name, when the driver reports one.
+    pub column: Option<CompactString>,
+    /// Rust type the column was decoded into.
+    pub expected: Option<CompactString>,
+    /// Storage class (`SQLite`) or type name (Postgres) the database returned.
+    pub actual: Option<CompactString>,
+}
+
+impl core::fmt::Display for DecodeContext {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Decode error")?;
+        if let Some(row) = self.row {
+            write!(f, " at row {row}")?;
+        }
+        match (&self.column, self.column_index) {
+            (Some(name), Some(index)) => write!(f, ", column \"{name}\" (index {index})")?,
+            (Some(name), None) => write!(f, ", column \"{name}\"")?,
+            (None, Some(index)) => write!(f, ", column {index}")?,
+            (None, None) => {}
+        }
+        if let Some(expected) = &self.expected {
+            write!(f, ": expected {expected}")?;
+            if let Some(actual) = &self.actual {
+                write!(f, ", found {actual}")?;
+            }
+        } else if let Some(actual) = &self.actual {
+            write!(f, ": found {actual}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Rust type name without module paths, e.g. `Option<String>`.
+///
+/// Used for the expected type in [`DecodeContext`].
+#[must_use]
+pub fn type_name<T: ?Sized>() -> CompactString {
+    let full = core::any::type_name::<T>();
+    let mut short = CompactString::default();
+    let mut segment_start = 0;
+    let mut chars = full.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            short.truncate(segment_start);
+        } else {
+            short.push(ch);
+            if !(ch.is_alphanumeric() || ch == '_') {
+                segment_start = short.len();
+            }
+        }
+    }
+    short
+}
+
 fn truncate_param(mut value: String) -> CompactString {
     if value.chars().count() <= MAX_CONTEXT_PARAM_CHARS {
         return value.into();
@@ -113,6 +180,16 @@ pub enum DrizzleError {
         source: Box<DrizzleError>,
     },
 
+    /// A result row failed to decode, with the row and column it failed on.
+    #[error("{ctx}: {source}")]
+    Decode {
+        /// Row and column context.
+        ctx: Box<DecodeContext>,
+        /// Original error.
+        #[source]
+        source: Box<DrizzleError>,
+    },
+
     /// Error converting parameters
     #[error("Parameter conversion error: {0}")]
     ParameterError(compact_str::CompactString),
@@ -145,29 +222,69 @@ pub enum DrizzleError {
     #[error("Database error: {0}")]
     Other(compact_str::CompactString),
 
+    /// A UNIQUE or PRIMARY KEY constraint was violated
+    #[error("Unique violation: {message}")]
+    UniqueViolation {
+        /// Constraint or index name, when the database reports one.
+        constraint: Option<CompactString>,
+        /// Columns covered by the constraint, when the database reports them.
+        columns: Vec<CompactString>,
+        /// Database error message.
+        message: CompactString,
+    },
+
+    /// A FOREIGN KEY constraint was violated
+    #[error("Foreign key violation: {message}")]
+    ForeignKeyViolation {
+        /// Constraint name, when the database reports one.
+        constraint: Option<CompactString>,
+        /// Database error message.
+        message: CompactString,
+    },
+
+    /// A NOT NULL constraint was violated
+    #[error("Not null violation: {message}")]
+    NotNullViolation {
+        /// Column that received the NULL, when the database reports it.
+        column: Option<CompactString>,
+        /// Database error message.
+        message: CompactString,
+    },
+
+    /// The query was canceled before finishing, usually because it ran past a timeout
+    #[error("Timeout: {0}")]
+    Timeout(CompactString),
+
+    /// The transaction conflicted with a concurrent one and can be retried
+    #[error("Serialization failure: {message}")]
+    SerializationFailure {
+        /// Database error message.
+        message: CompactString,
+    },
+
     /// Rusqlite specific errors
     #[cfg(feature = "rusqlite")]
     #[error("Rusqlite error: {0}")]
-    Rusqlite(#[from] rusqlite::Error),
+    Rusqlite(rusqlite::Error),
 
     /// Turso specific errors
     #[cfg(feature = "turso")]
     #[error("Turso error: {0}")]
-    Turso(#[from] turso::Error),
+    Turso(turso::Error),
 
     /// `LibSQL` specific errors
     #[cfg(feature = "libsql")]
     #[error("LibSQL error: {0}")]
-    LibSQL(#[from] libsql::Error),
+    LibSQL(libsql::Error),
 
     /// Postgres specific errors
     #[cfg(feature = "tokio-postgres")]
     #[error("Postgres error: {0}")]
-    Postgres(#[from] tokio_postgres::Error),
+    Postgres(tokio_postgres::Error),
 
     #[cfg(all(feature = "postgres-sync", not(feature = "tokio-postgres")))]
     #[error("Postgres error: {0}")]
-    Postgres(#[from] postgres::Error),
+    Postgres(postgres::Error),
 
     /// UUID parsing error
     #[cfg(feature = "uuid")]
@@ -187,6 +304,353 @@ pub enum DrizzleError {
 /// Result type for database operations
 pub type Result<T> = core::result::Result<T, DrizzleError>;
 
+impl DrizzleError {
+    /// Returns the underlying error, looking through [`DrizzleError::QueryFailed`].
+    ///
+    /// Drivers attach SQL context to most failures, so match on this when
+    /// checking for a specific variant such as [`DrizzleError::UniqueViolation`].
+    #[must_use]
+    pub fn root(&self) -> &Self {
+        match self {
+            Self::QueryFailed { source, .. } => source.root(),
+            other => other,
+        }
+    }
+
+    /// Decode context of the error, looking through [`DrizzleError::QueryFailed`].
+    #[must_use]
+    pub fn decode_context(&self) -> Option<&DecodeContext> {
+        match self.root() {
+            Self::Decode { ctx, .. } => Some(ctx),
+            _ => None,
+        }
+    }
+
+    /// Attaches the column a decode failure happened on.
+    ///
+    /// `expected` replaces any type recorded by an inner decode, so wrapper
+    /// types report themselves rather than the primitive they decode through.
+    #[must_use]
+    pub fn decode_column(
+        self,
+        index: usize,
+        column: Option<&str>,
+        expected: &str,
+        actual: Option<&str>,
+    ) -> Self {
+        let (mut ctx, source) = self.into_decode_parts();
+        ctx.column_index.get_or_insert(index);
+        if ctx.column.is_none() {
+            ctx.column = column.map(CompactString::from);
+        }
+        ctx.expected = Some(expected.into());
+        if ctx.actual.is_none() {
+            ctx.actual = actual.map(CompactString::from);
+        }
+        Self::Decode { ctx, source }
+    }
+
+    /// Records the Rust type a failed decode was reading into.
+    #[must_use]
+    pub fn expecting(self, expected: &str) -> Self {
+        let (mut ctx, source) = self.into_decode_parts();
+        ctx.expected = Some(expected.into());
+        Self::Decode { ctx, source }
+    }
+
+    /// Attaches the index of the row a decode failure happened on.
+    #[must_use]
+    pub fn at_row(self, row: usize) -> Self {
+        let (mut ctx, source) = self.into_decode_parts();
+        ctx.row.get_or_insert(row);
+        Self::Decode { ctx, source }
+    }
+
+    fn into_decode_parts(self) -> (Box<DecodeContext>, Box<Self>) {
+        match self {
+            Self::Decode { ctx, source } => (ctx, source),
+            #[cfg(feature = "rusqlite")]
+            Self::Rusqlite(rusqlite::Error::InvalidColumnType(index, ref name, ty)) => {
+                let ctx = DecodeContext {
+                    column_index: Some(index),
+                    column: Some(name.as_str().into()),
+                    actual: Some(format!("{ty}").to_uppercase().into()),
+                    ..DecodeContext::default()
+                };
+                (Box::new(ctx), Box::new(self))
+            }
+            #[cfg(feature = "rusqlite")]
+            Self::Rusqlite(rusqlite::Error::FromSqlConversionFailure(index, ty, _)) => {
+                let ctx = DecodeContext {
+                    column_index: Some(index),
+                    actual: Some(format!("{ty}").to_uppercase().into()),
+                    ..DecodeContext::default()
+                };
+                (Box::new(ctx), Box::new(self))
+            }
+            other => (Box::default(), Box::new(other)),
+        }
+    }
+
+    /// Whether running the same statement or transaction again may succeed.
+    ///
+    /// True for serialization failures and deadlocks on Postgres
+    /// (`40001`, `40P01`) and for `SQLITE_BUSY` / `SQLITE_LOCKED` on `SQLite`.
+    /// Connection errors are reported by [`Self::is_connection_error`] instead,
+    /// since retrying them needs a new connection.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        const SQLITE_BUSY: i32 = 5;
+        const SQLITE_LOCKED: i32 = 6;
+
+        let error = self.root();
+        matches!(error, Self::SerializationFailure { .. })
+            || matches!(error.sqlite_code(), Some(SQLITE_BUSY | SQLITE_LOCKED))
+            || matches!(error.sqlstate(), Some("40001" | "40P01"))
+    }
+
+    /// Whether the connection to the database failed or was closed.
+    ///
+    /// Covers closed or unreachable Postgres connections (including SQLSTATE
+    /// class `08`), libsql remote connection failures, and `SQLITE_CANTOPEN`.
+    #[must_use]
+    pub fn is_connection_error(&self) -> bool {
+        const SQLITE_CANTOPEN: i32 = 14;
+
+        let error = self.root();
+        if error.sqlite_code() == Some(SQLITE_CANTOPEN)
+            || error.sqlstate().is_some_and(|code| code.starts_with("08"))
+        {
+            return true;
+        }
+        match error {
+            #[cfg(feature = "libsql")]
+            Self::LibSQL(libsql::Error::ConnectionFailed(_) | libsql::Error::Hrana(_)) => true,
+            #[cfg(any(feature = "tokio-postgres", feature = "postgres-sync"))]
+            Self::Postgres(error) => {
+                error.is_closed()
+                    || (error.as_db_error().is_none()
+                        && core::error::Error::source(error)
+                            .is_some_and(|source| source.is::<std::io::Error>()))
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether a constraint (unique, foreign key, not null, check, ...) rejected the write.
+    #[must_use]
+    pub fn is_constraint_violation(&self) -> bool {
+        const SQLITE_CONSTRAINT: i32 = 19;
+
+        let error = self.root();
+        matches!(
+            error,
+            Self::UniqueViolation { .. }
+                | Self::ForeignKeyViolation { .. }
+                | Self::NotNullViolation { .. }
+        ) || error.sqlite_code() == Some(SQLITE_CONSTRAINT)
+            || error.sqlstate().is_some_and(|code| code.starts_with("23"))
+    }
+
+    /// Primary `SQLite` result code of a driver error, if it carries one.
+    fn sqlite_code(&self) -> Option<i32> {
+        match self {
+            #[cfg(feature = "rusqlite")]
+            Self::Rusqlite(rusqlite::Error::SqliteFailure(failure, _)) => {
+                Some(failure.extended_code & 0xff)
+            }
+            #[cfg(feature = "libsql")]
+            Self::LibSQL(
+                libsql::Error::SqliteFailure(code, _)
+                | libsql::Error::RemoteSqliteFailure(_, code, _),
+            ) => Some(code & 0xff),
+            _ => None,
+        }
+    }
+
+    /// SQLSTATE of a Postgres driver error, if it carries one.
+    fn sqlstate(&self) -> Option<&str> {
+        match self {
+            #[cfg(any(feature = "tokio-postgres", feature = "postgres-sync"))]
+            Self::Postgres(error) => error.code().map(pg_error::SqlState::code),
+            _ => None,
+        }
+    }
+
+    /// Classifies a `SQLite` failure by its extended result code.
+    ///
+    /// Drivers that only report the primary `SQLITE_CONSTRAINT` code fall back
+    /// to the message prefix `SQLite` uses for each constraint kind.
+    #[cfg(any(feature = "rusqlite", feature = "libsql", feature = "turso"))]
+    fn from_sqlite_failure(extended_code: i32, message: &str) -> Option<Self> {
+        const SQLITE_INTERRUPT: i32 = 9;
+        const SQLITE_CONSTRAINT: i32 = 19;
+        const SQLITE_BUSY_SNAPSHOT: i32 = 517;
+        const SQLITE_CONSTRAINT_FOREIGNKEY: i32 = 787;
+        const SQLITE_CONSTRAINT_NOTNULL: i32 = 1299;
+        const SQLITE_CONSTRAINT_PRIMARYKEY: i32 = 1555;
+        const SQLITE_CONSTRAINT_UNIQUE: i32 = 2067;
+
+        let kind = match extended_code {
+            SQLITE_CONSTRAINT_UNIQUE | SQLITE_CONSTRAINT_PRIMARYKEY => "UNIQUE",
+            SQLITE_CONSTRAINT_FOREIGNKEY => "FOREIGN KEY",
+            SQLITE_CONSTRAINT_NOTNULL => "NOT NULL",
+            SQLITE_BUSY_SNAPSHOT => {
+                return Some(Self::SerializationFailure {
+                    message: message.into(),
+                });
+            }
+            SQLITE_INTERRUPT => return Some(Self::Timeout(message.into())),
+            SQLITE_CONSTRAINT => ["UNIQUE", "FOREIGN KEY", "NOT NULL"]
+                .into_iter()
+                .find(|kind| message.starts_with(kind))?,
+            _ => return None,
+        };
+
+        // "UNIQUE constraint failed: users.email, users.name"
+        // "UNIQUE constraint failed: index 'users_email_idx'"
+        // "NOT NULL constraint failed: users.name"
+        let target = message
+            .split_once("constraint failed: ")
+            .map_or("", |(_, target)| target);
+        let column_of = |qualified: &str| {
+            CompactString::from(qualified.rsplit_once('.').map_or(qualified, |(_, c)| c))
+        };
+
+        Some(match kind {
+            "UNIQUE" => match target.strip_prefix("index ") {
+                Some(index) => Self::UniqueViolation {
+                    constraint: Some(index.trim_matches('\'').into()),
+                    columns: Vec::new(),
+                    message: message.into(),
+                },
+                None => Self::UniqueViolation {
+                    constraint: None,
+                    columns: target
+                        .split(", ")
+                        .filter(|column| !column.is_empty())
+                        .map(column_of)
+                        .collect(),
+                    message: message.into(),
+                },
+            },
+            "FOREIGN KEY" => Self::ForeignKeyViolation {
+                constraint: None,
+                message: message.into(),
+            },
+            _ => Self::NotNullViolation {
+                column: (!target.is_empty()).then(|| column_of(target)),
+                message: message.into(),
+            },
+        })
+    }
+
+    /// Classifies a Postgres error by its SQLSTATE code.
+    #[cfg(any(feature = "tokio-postgres", feature = "postgres-sync"))]
+    fn from_postgres_db_error(error: &pg_error::DbError) -> Option<Self> {
+        let constraint = error.constraint().map(CompactString::from);
+        let message = CompactString::from(error.message());
+
+        Some(match error.code().code() {
+            "23505" => Self::UniqueViolation {
+                constraint,
+                // DETAIL: Key (email, name)=(a@example.com, Alice) already exists.
+                columns: error
+                    .detail()
+                    .and_then(|detail| detail.strip_prefix("Key ("))
+                    .and_then(|rest| rest.split_once(")=("))
+                    .map(|(columns, _)| columns.split(", ").map(CompactString::from).collect())
+                    .unwrap_or_default(),
+                message,
+            },
+            "23503" => Self::ForeignKeyViolation {
+                constraint,
+                message,
+            },
+            "23502" => Self::NotNullViolation {
+                column: error.column().map(CompactString::from),
+                message,
+            },
+            "40001" => Self::SerializationFailure { message },
+            "57014" => Self::Timeout(message),
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl From<rusqlite::Error> for DrizzleError {
+    fn from(error: rusqlite::Error) -> Self {
+        if let rusqlite::Error::SqliteFailure(failure, message) = &error {
+            let message = message.clone().unwrap_or_else(|| failure.to_string());
+            if let Some(classified) = Self::from_sqlite_failure(failure.extended_code, &message) {
+                return classified;
+            }
+        }
+        Self::Rusqlite(error)
+    }
+}
+
+#[cfg(feature = "libsql")]
+impl From<libsql::Error> for DrizzleError {
+    fn from(error: libsql::Error) -> Self {
+        let classified = match &error {
+            libsql::Error::SqliteFailure(code, message)
+            | libsql::Error::RemoteSqliteFailure(_, code, message) => {
+                Self::from_sqlite_failure(*code, message)
+            }
+            _ => None,
+        };
+        classified.unwrap_or(Self::LibSQL(error))
+    }
+}
+
+/// Turso doesn't expose result codes, so constraint failures are recognized
+/// by the message `SQLite` reports for them.
+#[cfg(feature = "turso")]
+impl From<turso::Error> for DrizzleError {
+    fn from(error: turso::Error) -> Self {
+        const SQLITE_CONSTRAINT: i32 = 19;
+        let rendered = error.to_string();
+        ["UNIQUE", "FOREIGN KEY", "NOT NULL"]
+            .into_iter()
+            .find_map(|kind| rendered.find(&format!("{kind} constraint failed")))
+            .and_then(|start| {
+                let message = rendered[start..].trim_end_matches(['`', '"', '\'']);
+                Self::from_sqlite_failure(SQLITE_CONSTRAINT, message)
+            })
+            .unwrap_or(Self::Turso(error))
+    }
+}
+
+#[cfg(feature = "tokio-postgres")]
+impl From<tokio_postgres::Error> for DrizzleError {
+    fn from(error: tokio_postgres::Error) -> Self {
+        error
+            .as_db_error()
+            .and_then(Self::from_postgres_db_error)
+            .unwrap_or(Self::Postgres(error))
+    }
+}
+
+#[cfg(all(feature = "postgres-sync", not(feature = "tokio-postgres")))]
+impl From<postgres::Error> for DrizzleError {
+    fn from(error: postgres::Error) -> Self {
+        error
+            .as_db_error()
+            .and_then(Self::from_postgres_db_error)
+            .unwrap_or(Self::Postgres(error))
+    }
+}
+
+/// Lets serde-driven row decoding report failures as [`DrizzleError::Mapping`].
+#[cfg(feature = "serde")]
+impl serde::de::Error for DrizzleError {
+    fn custom<T: core::fmt::Display>(msg: T) -> Self {
+        Self::Mapping(compact_str::format_compact!("{msg}"))
+    }
+}
+
 /// Attaches SQL and parameter context to a database error.
 pub trait ResultExt<T> {
     /// Attach SQL and parameter context lazily on the error path.
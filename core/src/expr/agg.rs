@@ -8,6 +8,24 @@
 //! - `sum`, `avg`: Require `Numeric` types (Int, `BigInt`, Float, Double)
 //! - `count`: Works with any type
 //! - `min`, `max`: Work with any type (ordered types in SQL)
+//!
+//! # Nullability
+//!
+//! Only `count`, `count_distinct` and `total` are `NonNull`. Every other
+//! aggregate yields NULL over an empty set (or a group whose inputs are all
+//! NULL), so it is typed `Null` and decodes as `Option<T>`:
+//!
+//! ```rust
+//! # let _ = r####"
+//! let (total,): (Option<i64>,) = db.select((sum(orders.amount),)).from(orders).get()?;
+//!
+//! // Supply a default to get a non-null value back
+//! let (total,): (i64,) = db
+//!     .select((coalesce(sum(orders.amount), 0),))
+//!     .from(orders)
+//!     .get()?;
+//! # "####;
+//! ```
 
 use crate::dialect::DialectTypes;
 use crate::sql::SQL;
@@ -689,6 +707,42 @@ where
     SQLExpr::new(SQL::func("GROUP_CONCAT", expr.into_expr_sql()))
 }
 
+/// `GROUP_CONCAT(expr, separator)` - concatenates values with a custom
+/// separator (`SQLite`).
+///
+/// [`group_concat`] always joins with `,`; this is the two-argument form.
+/// Returns Text type, nullable.
+///
+/// # Example
+///
+/// ```rust
+/// # let _ = r####"
+/// use drizzle_core::expr::group_concat_with;
+///
+/// let names = group_concat_with(users.name, " | ");
+/// // Generates: GROUP_CONCAT("users"."name", ?)
+/// # "####;
+/// ```
+pub fn group_concat_with<'a, V, E, S>(
+    expr: E,
+    separator: S,
+) -> SQLExpr<'a, V, <V::DialectMarker as DialectTypes>::Text, Null, Agg>
+where
+    V: SQLParam + 'a,
+    V::DialectMarker: SQLiteAggregateSupport,
+    E: Expr<'a, V>,
+    E::SQLType: crate::types::Textual,
+    S: Expr<'a, V>,
+    S::SQLType: crate::types::Textual,
+{
+    SQLExpr::new(SQL::func(
+        "GROUP_CONCAT",
+        expr.into_expr_sql()
+            .push(crate::Token::COMMA)
+            .append(separator.into_expr_sql()),
+    ))
+}
+
 /// `STRING_AGG` - concatenates text values using a delimiter (`PostgreSQL`).
 pub fn string_agg<'a, V, E, D>(
     expr: E,
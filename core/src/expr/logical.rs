@@ -14,20 +14,22 @@
 //! condition1 | condition2   // BitOr
 //! !condition                 // Not
 //!
-//! // Multiple conditions (iterator)
-//! and_all([condition1, condition2, condition3])
-//! or_all([condition1, condition2, condition3])
+//! // Conditions assembled at runtime
+//! ConditionSet::all()
+//!     .push(condition1)
+//!     .push(maybe(optional_condition))
 //! # "####;
 //! ```
 
 use core::ops::{BitAnd, BitOr, Not};
 
 use crate::dialect::DialectTypes;
+use crate::prelude::*;
 use crate::sql::{SQL, SQLChunk, Token};
-use crate::traits::SQLParam;
+use crate::traits::{SQLParam, ToSQL};
 use crate::types::BooleanLike;
 
-use super::{AggOr, AggregateKind, Expr, NullOr, Nullability, SQLExpr};
+use super::{AggOr, AggregateKind, Expr, Null, NullOr, Nullability, SQLExpr, Scalar};
 
 #[inline]
 fn operand_sql<'a, V, E>(value: E) -> SQL<'a, V>
@@ -76,7 +78,7 @@ where
         || (expr_sql.chunks.len() == 1
             && !matches!(
                 expr_sql.chunks[0],
-                SQLChunk::Raw(_) | SQLChunk::Ident(_) | SQLChunk::Number(_)
+                SQLChunk::Raw(_) | SQLChunk::Ident(_) | SQLChunk::Name(_) | SQLChunk::Number(_)
             ));
 
     let sql = if needs_paren {
@@ -163,6 +165,151 @@ where
     SQLExpr::new(binary_logical_op(left, Token::OR, right))
 }
 
+// =============================================================================
+// Runtime Condition Sets
+// =============================================================================
+
+/// A list of conditions combined with AND or OR, assembled at runtime.
+///
+/// Unlike [`and`] and [`or`], the number of conditions does not need to be
+/// known at compile time, so filters can be collected from optional request
+/// parameters. Wrap an `Option` in [`maybe`] to push it only when present.
+///
+/// Rendering:
+/// - no conditions: nothing (`where`/`having` omit the clause entirely)
+/// - one condition: the condition itself
+/// - several: `(a AND b AND c)` or `(a OR b OR c)`
+///
+/// ```rust
+/// # let _ = r####"
+/// use drizzle_core::expr::{ConditionSet, eq, gt, maybe};
+///
+/// let filter = ConditionSet::all()
+///     .push(eq(users.active, true))
+///     .push(maybe(query.name.map(|name| eq(users.name, name))))
+///     .push(maybe(query.min_age.map(|age| gt(users.age, age))));
+///
+/// db.select(()).from(users).r#where(filter)
+/// # "####;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConditionSet<'a, V: SQLParam> {
+    separator: Token,
+    conditions: Vec<SQL<'a, V>>,
+}
+
+impl<'a, V: SQLParam + 'a> ConditionSet<'a, V> {
+    /// Creates an empty set whose conditions are combined with AND.
+    #[must_use]
+    pub const fn all() -> Self {
+        Self {
+            separator: Token::AND,
+            conditions: Vec::new(),
+        }
+    }
+
+    /// Creates an empty set whose conditions are combined with OR.
+    #[must_use]
+    pub const fn any() -> Self {
+        Self {
+            separator: Token::OR,
+            conditions: Vec::new(),
+        }
+    }
+
+    /// Adds a condition. Conditions that render to nothing, such as an empty
+    /// [`maybe`] or an empty nested set, are skipped.
+    #[must_use]
+    pub fn push<E>(mut self, condition: E) -> Self
+    where
+        E: Expr<'a, V>,
+        E::SQLType: BooleanLike,
+    {
+        self.push_mut(condition);
+        self
+    }
+
+    /// Adds a condition in place. See [`ConditionSet::push`].
+    pub fn push_mut<E>(&mut self, condition: E)
+    where
+        E: Expr<'a, V>,
+        E::SQLType: BooleanLike,
+    {
+        let sql = operand_sql(condition);
+        if !sql.chunks.is_empty() {
+            self.conditions.push(sql);
+        }
+    }
+
+    /// Returns the number of conditions in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.conditions.len()
+    }
+
+    /// Returns `true` if no conditions have been added.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+}
+
+impl<'a, V, E> Extend<E> for ConditionSet<'a, V>
+where
+    V: SQLParam + 'a,
+    E: Expr<'a, V>,
+    E::SQLType: BooleanLike,
+{
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        for condition in iter {
+            self.push_mut(condition);
+        }
+    }
+}
+
+impl<'a, V: SQLParam + 'a> ToSQL<'a, V> for ConditionSet<'a, V> {
+    fn to_sql(&self) -> SQL<'a, V> {
+        self.clone().into_sql()
+    }
+
+    fn into_sql(mut self) -> SQL<'a, V> {
+        match self.conditions.len() {
+            0 => SQL::empty(),
+            1 => self.conditions.pop().unwrap_or_else(SQL::empty),
+            _ => SQL::join(self.conditions, self.separator).parens(),
+        }
+    }
+}
+
+impl<'a, V: SQLParam + 'a> Expr<'a, V> for ConditionSet<'a, V> {
+    type SQLType = <V::DialectMarker as DialectTypes>::Bool;
+    type Nullable = Null;
+    type Aggregate = Scalar;
+}
+
+/// Wraps an optional condition so a [`ConditionSet`] skips it when `None`.
+///
+/// The result is a set holding zero or one condition; pushing an empty set
+/// into another adds nothing.
+///
+/// ```rust
+/// # let _ = r####"
+/// ConditionSet::all().push(maybe(name.map(|name| eq(users.name, name))))
+/// # "####;
+/// ```
+pub fn maybe<'a, V, E>(condition: Option<E>) -> ConditionSet<'a, V>
+where
+    V: SQLParam + 'a,
+    E: Expr<'a, V>,
+    E::SQLType: BooleanLike,
+{
+    let set = ConditionSet::all();
+    match condition {
+        Some(condition) => set.push(condition),
+        None => set,
+    }
+}
+
 // =============================================================================
 // Operator Trait Implementations
 // =============================================================================
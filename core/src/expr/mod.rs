@@ -57,6 +57,9 @@ pub use typed::*;
 pub use util::*;
 pub use window::*;
 
+#[cfg(feature = "query")]
+pub use crate::query::json_agg_rows;
+
 use crate::traits::{SQLParam, ToSQL};
 use crate::types::DataType;
 
@@ -10,6 +10,7 @@
 //! - `substr`, `replace`, `instr`: Require `Textual` types
 
 use crate::dialect::DialectTypes;
+use crate::prelude::*;
 use crate::sql::{SQL, Token};
 use crate::traits::{SQLParam, ToSQL};
 use crate::types::{DataType, Integral, Textual};
@@ -149,10 +149,12 @@ pub type ScalarExpr<'a, V, T> = SQLExpr<'a, V, T, NonNull, Scalar>;
 /// A scalar, nullable expression.
 pub type NullableExpr<'a, V, T> = SQLExpr<'a, V, T, Null, Scalar>;
 
-/// An aggregate, non-null expression.
+/// An aggregate, non-null expression (`COUNT`, `TOTAL`).
 pub type AggExpr<'a, V, T> = SQLExpr<'a, V, T, NonNull, Agg>;
 
-/// An aggregate, nullable expression.
+/// An aggregate, nullable expression (`SUM`, `AVG`, `MIN`, `MAX`, ...).
+///
+/// These are NULL over an empty set and decode as `Option<T>`.
 pub type NullableAggExpr<'a, V, T> = SQLExpr<'a, V, T, Null, Agg>;
 
 // =============================================================================
@@ -1,11 +1,14 @@
-//! Utility SQL functions (alias, cast, distinct, typeof, concat, excluded).
+//! Utility SQL functions (alias, cast, distinct, typeof, concat, call, excluded).
 
 use crate::dialect::{PostgresDialect, SQLiteDialect};
 use crate::sql::{SQL, Token};
 use crate::traits::{SQLColumnInfo, SQLParam, ToSQL};
 use crate::types::{Compatible, DataType, Textual};
 
-use super::{AggOr, AggregateKind, Expr, NonNull, Null, NullOr, Nullability, SQLExpr, Scalar};
+use super::{
+    Agg, AggOr, AggregateKind, Expr, Multi, NonNull, Null, NullOr, Nullability, SQLExpr, Scalar,
+    Single,
+};
 
 // =============================================================================
 // ALIAS
@@ -267,6 +270,12 @@ impl DefaultCastTypeName for drizzle_types::postgres::types::Polygon {
 impl DefaultCastTypeName for drizzle_types::postgres::types::Circle {
     const CAST_TYPE_NAME: &'static str = "CIRCLE";
 }
+impl DefaultCastTypeName for drizzle_types::postgres::types::Geometry {
+    const CAST_TYPE_NAME: &'static str = "GEOMETRY";
+}
+impl DefaultCastTypeName for drizzle_types::postgres::types::Vector {
+    const CAST_TYPE_NAME: &'static str = "VECTOR";
+}
 impl DefaultCastTypeName for drizzle_types::postgres::types::Enum {
     const CAST_TYPE_NAME: &'static str = "TEXT";
 }
@@ -414,6 +423,101 @@ where
     super::concat(left, right)
 }
 
+// =============================================================================
+// CALL (user-defined functions)
+// =============================================================================
+
+/// Arguments to a [`call`]ed function: a single expression or a tuple.
+///
+/// The marker `M` is inferred — callers never specify it.
+pub trait CallArgs<'a, V: SQLParam, M>: Sized {
+    type Aggregate: AggregateKind;
+    fn into_args_sql(self) -> SQL<'a, V>;
+}
+
+/// Single argument: `call("slugify", users.name)`
+impl<'a, V, E> CallArgs<'a, V, Single> for E
+where
+    V: SQLParam + 'a,
+    E: Expr<'a, V>,
+{
+    type Aggregate = E::Aggregate;
+    fn into_args_sql(self) -> SQL<'a, V> {
+        self.into_expr_sql()
+    }
+}
+
+/// Tuple impls: `call("fmt_price", (items.amount, items.currency))`
+macro_rules! impl_call_args_tuple {
+    ($($E:ident),+; $($idx:tt),+) => {
+        impl<'a, V, $($E),+> CallArgs<'a, V, Multi> for ($($E,)+)
+        where
+            V: SQLParam + 'a,
+            $($E: Expr<'a, V>,)+
+        {
+            type Aggregate = Scalar;
+            fn into_args_sql(self) -> SQL<'a, V> {
+                ToSQL::into_sql(self)
+            }
+        }
+    };
+}
+
+with_col_sizes_8!(impl_call_args_tuple);
+
+/// Call a scalar SQL function by name, typically one registered on the
+/// connection (e.g. `Drizzle::create_scalar_function` with rusqlite).
+///
+/// The result type `T` is not checked against the function; the result is
+/// treated as nullable.
+///
+/// # Example
+///
+/// ```rust
+/// # let _ = r####"
+/// use drizzle_core::expr::call;
+/// use drizzle_sqlite::types::Text;
+///
+/// // SELECT slugify("users"."name")
+/// let slug = call::<_, Text, _, _>("slugify", users.name);
+/// # "####;
+/// ```
+#[must_use]
+pub fn call<'a, V, T, A, M>(name: &'static str, args: A) -> SQLExpr<'a, V, T, Null, A::Aggregate>
+where
+    V: SQLParam + 'a,
+    T: DataType,
+    A: CallArgs<'a, V, M>,
+{
+    SQLExpr::new(SQL::func(name, args.into_args_sql()))
+}
+
+/// Call a user-defined aggregate function by name.
+///
+/// Like [`call`], but the result is an aggregate, so it follows the same
+/// GROUP BY rules as `count` or `sum`.
+///
+/// # Example
+///
+/// ```rust
+/// # let _ = r####"
+/// use drizzle_core::expr::call_agg;
+/// use drizzle_sqlite::types::Real;
+///
+/// // SELECT median("orders"."total") FROM "orders"
+/// let median = call_agg::<_, Real, _, _>("median", orders.total);
+/// # "####;
+/// ```
+#[must_use]
+pub fn call_agg<'a, V, T, A, M>(name: &'static str, args: A) -> SQLExpr<'a, V, T, Null, Agg>
+where
+    V: SQLParam + 'a,
+    T: DataType,
+    A: CallArgs<'a, V, M>,
+{
+    SQLExpr::new(SQL::func(name, args.into_args_sql()))
+}
+
 // =============================================================================
 // RAW SQL Expression
 // =============================================================================
@@ -515,6 +619,6 @@ where
         SQL::empty()
             .push(Token::EXCLUDED)
             .push(Token::DOT)
-            .append(SQL::ident(self.column.name()))
+            .append(SQL::name(self.column.name()))
     }
 }
@@ -118,13 +118,19 @@ where
 }
 
 /// Helper function to create a WHERE clause
+///
+/// An empty condition, such as an empty [`crate::expr::ConditionSet`], produces no clause.
 pub fn r#where<'a, V, E>(condition: E) -> SQL<'a, V>
 where
     V: SQLParam + 'a,
     E: Expr<'a, V>,
     E::SQLType: BooleanLike,
 {
-    SQL::from(Token::WHERE).append(condition.into_sql())
+    let condition = condition.into_sql();
+    if condition.chunks.is_empty() {
+        return SQL::empty();
+    }
+    SQL::from(Token::WHERE).append(condition)
 }
 
 /// Helper function to create a GROUP BY clause
@@ -160,7 +166,11 @@ where
     E: Expr<'a, V>,
     E::SQLType: BooleanLike,
 {
-    SQL::from(Token::HAVING).append(condition.into_sql())
+    let condition = condition.into_sql();
+    if condition.chunks.is_empty() {
+        return SQL::empty();
+    }
+    SQL::from(Token::HAVING).append(condition)
 }
 
 /// Helper function to create an ORDER BY clause
@@ -386,15 +386,15 @@ macro_rules! impl_join_arg_trait {
                         condition.push_mut($crate::Token::AND);
                     }
                     condition.append_mut(
-                        $crate::SQL::ident(join_name)
+                        $crate::SQL::name(join_name)
                             .push($crate::Token::DOT)
-                            .append($crate::SQL::ident(*self_col)),
+                            .append($crate::SQL::name(*self_col)),
                     );
                     condition.push_mut($crate::Token::EQ);
                     condition.append_mut(
-                        $crate::SQL::ident(from_name)
+                        $crate::SQL::name(from_name)
                             .push($crate::Token::DOT)
-                            .append($crate::SQL::ident(*target_col)),
+                            .append($crate::SQL::name(*target_col)),
                     );
                 }
 
@@ -52,6 +52,7 @@ pub(crate) mod prelude {
     pub use hashbrown::{HashMap, HashSet};
 }
 
+pub mod batch;
 pub mod bind;
 pub mod builder;
 pub mod conv;
@@ -83,6 +84,9 @@ pub mod schema;
 pub mod sql;
 pub mod tracing;
 pub mod types;
+pub mod vector;
+#[cfg(feature = "geo-types")]
+pub mod wkb;
 
 // Re-export key types and traits
 pub use bind::{BindValue, NullableBindValue, ValueTypeForDialect};
@@ -93,25 +97,31 @@ pub use builder::{
 pub use dialect::{Dialect, DialectTypes, PostgresDialect, SQLiteDialect};
 pub use join::{Join, JoinType};
 pub use pagination::PaginationArg;
-pub use param::{OwnedParam, Param, ParamBind, ParamSet};
+pub use param::{BindParams, OwnedParam, Param, ParamBind, ParamSet};
 pub use placeholder::*;
 #[cfg(feature = "query")]
 pub use relation::{AssembleRel, CardWrap, Many, One, OptionalOne, RelationDef};
 pub use relation::{Joinable, Relation, SchemaHasTable};
 pub use row::{
     AfterFullJoin, AfterJoin, AfterLeftJoin, AfterRightJoin, DecodeSelectedRef, ExprValueType,
-    FromDrizzleRow, GroupByIdentity, HasSelectModel, IntoGroupBy, IntoSelectTarget,
+    FromDrizzleRow, GroupByIdentity, HCons, HNil, HasSelectModel, IntoGroupBy, IntoSelectTarget,
     MarkerAggValidFor, MarkerColumnCountValid, MarkerScopeValidFor, NullProbeRow, ResolveRow,
-    RowColumnList, SQLTypeToRust, ScopePush, Scoped, SelectAs, SelectAsFrom, SelectCols,
-    SelectExpr, SelectRequiredTables, SelectStar, WrapNullable,
+    RowColumnCount, RowColumnList, SQLTypeToRust, ScopePush, Scoped, SelectAs, SelectAsFrom, SelectCols,
+    SelectExpr, SelectRequiredTables, SelectStar, SelectTyped, WrapNullable,
 };
-pub use schema::{OrderBy, asc, desc};
+#[cfg(feature = "std")]
+pub use schema::truncation_order;
+pub use schema::{OrderBy, asc, desc, schema_item_hash, undeferred_fk_cycle};
 pub use sql::{
-    ColumnDialect, ColumnFlags, ColumnRef, ColumnSqlRef, ConstraintRef, ForeignKeyRef, OwnedSQL,
-    OwnedSQLChunk, PrimaryKeyRef, SQL, SQLChunk, TableDialect, TableRef, TableSqlRef, Token,
+    ColumnDialect, ColumnFlags, ColumnRef, ColumnSqlRef, ConstraintRef, ExclusionRef,
+    ForeignKeyRef, OwnedSQL, OwnedSQLChunk, PrimaryKeyRef, SQL, SQLChunk, TableDialect, TableRef,
+    TableSqlRef, Token, TypedSQL,
 };
 pub use traits::*;
 
+#[doc(hidden)]
+pub use sql::interpolate as sql_interpolate;
+
 // =============================================================================
 // Helper Macros - Used by proc macros for code generation
 // =============================================================================
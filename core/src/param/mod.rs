@@ -109,6 +109,11 @@ impl<'a, V: SQLParam, const N: usize> ParamSet<'a, V, N> {
     pub const fn new(binds: [ParamBind<'a, V>; N]) -> Self {
         Self { binds }
     }
+
+    /// Binds one named placeholder per field of `params`.
+    pub fn bind_from<P: BindParams<'a, V, N>>(params: &'a P) -> Self {
+        Self::new(params.bind_params())
+    }
 }
 
 impl<'a, V: SQLParam, const N: usize> From<[ParamBind<'a, V>; N]> for ParamSet<'a, V, N> {
@@ -117,6 +122,22 @@ impl<'a, V: SQLParam, const N: usize> From<[ParamBind<'a, V>; N]> for ParamSet<'
     }
 }
 
+impl<'a, V: SQLParam, P: BindParams<'a, V, N>, const N: usize> From<&'a P> for ParamSet<'a, V, N> {
+    fn from(params: &'a P) -> Self {
+        Self::bind_from(params)
+    }
+}
+
+/// A struct whose fields bind named placeholders in a prepared statement.
+///
+/// Usually derived with `#[derive(BindParams)]`: each field binds the
+/// placeholder of the same name, so a `&SearchParams { name, min_age }` can
+/// be passed wherever a prepared statement takes its parameters.
+pub trait BindParams<'a, V: SQLParam, const N: usize> {
+    /// Returns one binding per field, named after the field.
+    fn bind_params(&'a self) -> [ParamBind<'a, V>; N];
+}
+
 impl<'a, V: SQLParam, const N: usize> IntoIterator for ParamSet<'a, V, N> {
     type Item = ParamBind<'a, V>;
     type IntoIter = core::array::IntoIter<ParamBind<'a, V>, N>;
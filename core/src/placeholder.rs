@@ -31,6 +31,11 @@ impl Placeholder {
     /// - `PostgreSQL`: `$1`, `$2`, ... (positional, name ignored in SQL)
     /// - `SQLite`: `:name` for named placeholders
     /// - `MySQL`: `?` (positional, name ignored in SQL)
+    ///
+    /// Values bound to an untyped placeholder are only checked when the
+    /// statement runs. Use [`Placeholder::typed`] or a column's
+    /// `placeholder()` to have [`TypedPlaceholder::bind`] reject values of the
+    /// wrong SQL type at compile time.
     #[must_use]
     pub const fn named(name: &'static str) -> Self {
         Self { name: Some(name) }
@@ -0,0 +1,114 @@
+//! `json_agg_rows` — aggregate joined child rows into a JSON array column.
+//!
+//! A lightweight alternative to `.with()` relation loading for regular
+//! `select` queries: join the child table, group by the parent, and select
+//! `json_agg_rows(child)` alongside the parent columns.
+
+use crate::SQLParam;
+use crate::dialect::Dialect;
+use crate::expr::{Agg, NonNull, SQLExpr};
+use crate::prelude::*;
+use crate::sql::SQL;
+use crate::traits::DrizzleTable;
+use crate::types::JsonRows;
+
+use super::builder::QueryTable;
+use super::sql::{write_json_column, write_json_object_open, write_qualified_column};
+
+/// Aggregates every joined row of `table` into a JSON array of its select
+/// model.
+///
+/// - `SQLite`: `json_group_array(json_object('col', "table"."col", ...))`
+/// - `PostgreSQL`: `COALESCE(json_agg(row_to_json("table")), '[]'::json)::text`
+///
+/// Rows whose primary key is NULL (the unmatched side of a LEFT JOIN) are
+/// filtered out, so a parent without children yields `[]` rather than
+/// `[{"id": null, ...}]`. The result is never NULL.
+///
+/// Decode the column with a `#[json]` field of type `Vec<SelectTable>` in a
+/// `FromRow` struct, aliasing the expression to the field name. Requires the
+/// `query` feature, which provides the JSON decoder for select models.
+///
+/// # Example
+///
+/// ```rust
+/// # let _ = r####"
+/// use drizzle_core::expr::{AliasExt, eq, json_agg_rows};
+///
+/// #[derive(SQLiteFromRow)]
+/// struct UserWithPosts {
+///     id: i32,
+///     name: String,
+///     #[json]
+///     posts: Vec<SelectPosts>,
+/// }
+///
+/// let rows: Vec<UserWithPosts> = db
+///     .select((users.id, users.name, json_agg_rows(posts).alias("posts")))
+///     .from(users)
+///     .left_join((posts, eq(posts.author_id, users.id)))
+///     .group_by((users.id, users.name))
+///     .all()?;
+/// # "####;
+/// ```
+pub fn json_agg_rows<'a, V, T>(
+    _table: T,
+) -> SQLExpr<'a, V, JsonRows<<T as QueryTable>::Select>, NonNull, Agg>
+where
+    V: SQLParam + 'a,
+    T: QueryTable + DrizzleTable,
+    <T as QueryTable>::Select: 'static,
+{
+    let name = <T as QueryTable>::TABLE_NAME;
+    let columns = <T as QueryTable>::COLUMN_NAMES;
+    let dialect = V::DIALECT;
+
+    let mut sql = String::with_capacity(64 + columns.len() * 32);
+    match dialect {
+        Dialect::SQLite | Dialect::MySQL => {
+            sql.push_str("json_group_array(");
+            write_json_object_open(dialect, &mut sql);
+            for (i, c) in columns.iter().enumerate() {
+                if i > 0 {
+                    sql.push_str(", ");
+                }
+                sql.push('\'');
+                sql.push_str(c);
+                sql.push_str("', ");
+                write_json_column(name, c, <T as QueryTable>::BLOB_COLUMNS, dialect, &mut sql);
+            }
+            sql.push_str("))");
+            write_present_filter::<T>(name, columns, &mut sql);
+        }
+        Dialect::PostgreSQL => {
+            sql.push_str("COALESCE(json_agg(row_to_json(\"");
+            sql.push_str(name);
+            sql.push_str("\"))");
+            write_present_filter::<T>(name, columns, &mut sql);
+            // Cast to text so the driver reads the column as String
+            sql.push_str(", '[]'::json)::text");
+        }
+    }
+
+    SQLExpr::new(SQL::raw(sql))
+}
+
+/// Writes ` FILTER (WHERE ...)`, keeping only rows that exist.
+///
+/// Uses the primary key when the table has one; otherwise a row counts as
+/// present when any of its columns is non-NULL.
+fn write_present_filter<T: DrizzleTable>(name: &str, columns: &[&str], sql: &mut String) {
+    let (keys, joiner) = match T::TABLE_REF.primary_key {
+        Some(pk) if !pk.columns.is_empty() => (pk.columns, " AND "),
+        _ => (columns, " OR "),
+    };
+    sql.push_str(" FILTER (WHERE ");
+    for (i, c) in keys.iter().enumerate() {
+        if i > 0 {
+            sql.push_str(joiner);
+        }
+        write_qualified_column(name, c, sql);
+        sql.push_str(" IS NOT NULL");
+    }
+    sql.push(')');
+}
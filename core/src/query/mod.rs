@@ -8,6 +8,7 @@
 //! [`FromJsonObject`] and are assembled into concrete `*With*` row types by
 //! [`BuildRow`].
 
+mod agg;
 mod builder;
 mod deser;
 #[doc(hidden)]
@@ -16,6 +17,7 @@ mod row;
 mod sql;
 mod store;
 
+pub use agg::json_agg_rows;
 pub use builder::{
     AllColumns, BuildRow, BuildStore, Clauses, HasLimit, HasOffset, HasOrderBy, HasWhere,
     IntoColumnSelection, NoLimit, NoOrderBy, NoWhere, PartialColumns, QueryBuilder, QueryTable,
@@ -641,7 +641,7 @@ fn write_relation_subquery<'a, V: SQLParam>(
 // =============================================================================
 
 /// Writes `"alias"."column"` into the buffer.
-fn write_qualified_column(alias: &str, column: &str, sql: &mut String) {
+pub(super) fn write_qualified_column(alias: &str, column: &str, sql: &mut String) {
     sql.push('"');
     sql.push_str(alias);
     sql.push_str("\".\"");
@@ -690,7 +690,7 @@ fn write_junction_join(
 ///
 /// `PostgreSQL` handles all types natively in `json_build_object()`, so no
 /// wrapping is needed regardless of column type.
-fn write_json_column(
+pub(super) fn write_json_column(
     alias: &str,
     column: &str,
     blob_columns: &[&str],
@@ -711,7 +711,7 @@ fn write_json_column(
 
 /// Opens a JSON object constructor.
 /// `SQLite`: `json_object(`, `PostgreSQL`: `json_build_object(`
-fn write_json_object_open(dialect: Dialect, sql: &mut String) {
+pub(super) fn write_json_object_open(dialect: Dialect, sql: &mut String) {
     match dialect {
         Dialect::SQLite | Dialect::MySQL => sql.push_str("json_object("),
         Dialect::PostgreSQL => sql.push_str("json_build_object("),
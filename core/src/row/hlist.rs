@@ -0,0 +1,209 @@
+//! Heterogeneous lists for select targets of any width.
+//!
+//! Tuples stop at 8 columns unless one of the `col*` features is enabled, and
+//! each wider feature multiplies the number of tuple impls the compiler has to
+//! check. [`HCons`]/[`HNil`] implement the same select-target traits
+//! recursively, so a single set of impls covers every column count:
+//!
+//! ```rust
+//! # let _ = r####"
+//! let rows: Vec<HList![i32, String, Option<String>]> = db
+//!     .select(hlist![users.id, users.name, users.email])
+//!     .from(users)
+//!     .all()?;
+//!
+//! for hlist_pat![id, name, email] in rows {
+//!     // ...
+//! }
+//! # "####;
+//! ```
+//!
+//! The type-level [`Cons`]/[`Nil`] lists used for scope and column-count
+//! checks carry no values; `HCons` is their value-carrying counterpart and
+//! maps onto them one node at a time.
+
+use crate::error::DrizzleError;
+use crate::{Cons, Nil};
+
+use super::{
+    AggStatus, ExprValueType, FromDrizzleRow, IntoSelectTarget, ResolveRow, RowColumnList,
+    ScalarColumnsIn, SelectCols, SelectedColumnList, SingleColGroupCheck,
+};
+
+/// Non-empty heterogeneous list node holding a value and the rest of the list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HCons<H, T> {
+    pub head: H,
+    pub tail: T,
+}
+
+/// Empty heterogeneous list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HNil;
+
+impl<H, T> HCons<H, T> {
+    /// Creates a list node from a head value and the remaining list.
+    #[inline]
+    pub const fn new(head: H, tail: T) -> Self {
+        Self { head, tail }
+    }
+}
+
+/// Builds an [`HCons`] value list: `hlist![a, b, c]`.
+#[macro_export]
+macro_rules! hlist {
+    () => { $crate::row::HNil };
+    ($head:expr $(, $tail:expr)* $(,)?) => {
+        $crate::row::HCons::new($head, $crate::hlist!($($tail),*))
+    };
+}
+
+/// Names an [`HCons`] list type: `HList![i32, String]`.
+#[macro_export]
+#[allow(non_snake_case)]
+macro_rules! HList {
+    () => { $crate::row::HNil };
+    ($head:ty $(, $tail:ty)* $(,)?) => {
+        $crate::row::HCons<$head, $crate::HList!($($tail),*)>
+    };
+}
+
+/// Destructures an [`HCons`] list in patterns: `let hlist_pat![a, b] = row;`.
+#[macro_export]
+macro_rules! hlist_pat {
+    () => { $crate::row::HNil };
+    ($head:pat $(, $tail:pat)* $(,)?) => {
+        $crate::row::HCons { head: $head, tail: $crate::hlist_pat!($($tail),*) }
+    };
+}
+
+// -- SQL rendering: comma-joined, same as tuples --
+
+impl<'a, V: crate::SQLParam, H> crate::ToSQL<'a, V> for HCons<H, HNil>
+where
+    H: crate::ToSQL<'a, V>,
+{
+    fn to_sql(&self) -> crate::SQL<'a, V> {
+        self.head.to_sql()
+    }
+}
+
+impl<'a, V: crate::SQLParam, H, H2, T> crate::ToSQL<'a, V> for HCons<H, HCons<H2, T>>
+where
+    H: crate::ToSQL<'a, V>,
+    HCons<H2, T>: crate::ToSQL<'a, V>,
+{
+    fn to_sql(&self) -> crate::SQL<'a, V> {
+        self.head
+            .to_sql()
+            .push(crate::Token::COMMA)
+            .append(self.tail.to_sql())
+    }
+}
+
+// -- Select target inference --
+
+impl<H, T> IntoSelectTarget for HCons<H, T> {
+    type Marker = SelectCols<Self>;
+}
+
+impl<Table> ResolveRow<Table> for SelectCols<HNil> {
+    type Row = HNil;
+}
+
+impl<Table, H, T> ResolveRow<Table> for SelectCols<HCons<H, T>>
+where
+    H: ExprValueType,
+    SelectCols<T>: ResolveRow<Table>,
+{
+    type Row = HCons<H::ValueType, <SelectCols<T> as ResolveRow<Table>>::Row>;
+}
+
+impl SelectedColumnList for HNil {
+    type Columns = Nil;
+}
+
+impl<H, T> SelectedColumnList for HCons<H, T>
+where
+    H: ExprValueType,
+    T: SelectedColumnList,
+{
+    type Columns = Cons<H::ValueType, T::Columns>;
+}
+
+// -- GROUP BY / aggregate validation --
+
+impl<Grouped> ScalarColumnsIn<Grouped, ()> for HNil {}
+
+impl<H, T, Grouped, HeadProof, TailProof> ScalarColumnsIn<Grouped, (HeadProof, TailProof)>
+    for HCons<H, T>
+where
+    H: SingleColGroupCheck<Grouped, HeadProof>,
+    T: ScalarColumnsIn<Grouped, TailProof>,
+{
+}
+
+impl<H: crate::expr::HasAggStatus> AggStatus for HCons<H, HNil> {
+    type Status = H::Status;
+}
+
+impl<H, H2, T> AggStatus for HCons<H, HCons<H2, T>>
+where
+    H: crate::expr::HasAggStatus,
+    HCons<H2, T>: AggStatus,
+    H::Status: crate::expr::CombineAggStatus<<HCons<H2, T> as AggStatus>::Status>,
+{
+    type Status =
+        <H::Status as crate::expr::CombineAggStatus<<HCons<H2, T> as AggStatus>::Status>>::Output;
+}
+
+// -- Row decoding --
+
+impl<Row: ?Sized> FromDrizzleRow<Row> for HNil {
+    const COLUMN_COUNT: usize = 0;
+
+    fn from_row_at(_row: &Row, _offset: usize) -> Result<Self, DrizzleError> {
+        Ok(Self)
+    }
+}
+
+impl<Row: ?Sized, H, T> FromDrizzleRow<Row> for HCons<H, T>
+where
+    H: FromDrizzleRow<Row>,
+    T: FromDrizzleRow<Row>,
+{
+    const COLUMN_COUNT: usize = H::COLUMN_COUNT + T::COLUMN_COUNT;
+
+    fn from_row_at(row: &Row, offset: usize) -> Result<Self, DrizzleError> {
+        let head = H::from_row_at(row, offset)?;
+        let tail = T::from_row_at(row, offset + H::COLUMN_COUNT)?;
+        Ok(Self { head, tail })
+    }
+}
+
+impl<Row: ?Sized> RowColumnList<Row> for HNil {
+    type Columns = Nil;
+}
+
+impl<Row: ?Sized, H, T> RowColumnList<Row> for HCons<H, T>
+where
+    H: RowColumnList<Row>,
+    T: RowColumnList<Row>,
+    H::Columns: crate::Concat<T::Columns>,
+{
+    type Columns = <H::Columns as crate::Concat<T::Columns>>::Output;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hlist_macros_build_and_destructure() {
+        let list: HList![i32, &str, bool] = hlist![1, "a", true];
+        assert_eq!(list, HCons::new(1, HCons::new("a", HCons::new(true, HNil))));
+
+        let hlist_pat![n, s, b] = list;
+        assert_eq!((n, s, b), (1, "a", true));
+    }
+}
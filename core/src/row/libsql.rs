@@ -6,6 +6,7 @@
 //! [`super::sqlite_value::SqliteCell`].
 
 use crate::error::DrizzleError;
+use crate::row::RowColumnCount;
 use crate::row::sqlite_value::{SqliteCell, SqliteValueRow};
 
 /// Convert a 0-based column offset into the `i32` index expected by
@@ -30,4 +31,15 @@ impl SqliteValueRow for ::libsql::Row {
             ::libsql::Value::Blob(b) => SqliteCell::Blob(b),
         })
     }
+
+    fn column_name_at(&self, offset: usize) -> Option<&str> {
+        self.column_name(column_index(offset).ok()?)
+    }
+}
+
+impl RowColumnCount for ::libsql::Row {
+    #[inline]
+    fn column_count(&self) -> usize {
+        usize::try_from(self.column_count()).unwrap_or(0)
+    }
 }
@@ -8,7 +8,7 @@
 //!
 //! ```rust
 //! # let _ = r####"
-//! .select(cols)    → Marker  (SelectStar | SelectCols<C> | SelectExpr)
+//! .select(cols)    → Marker  (SelectStar | SelectCols<C> | SelectExpr | SelectTyped<R>)
 //! .from(table)     → R       (Marker + Table → row type via ResolveRow)
 //! .join(t2)        → R'      (Marker + R + JoinedTable → new R via AfterJoin)
 //! .all()           → Vec<R>  (R: FromDrizzleRow)
@@ -30,9 +30,12 @@ pub(crate) mod sqlite_value;
 #[cfg(feature = "turso")]
 mod turso;
 
+mod hlist;
+pub use hlist::{HCons, HNil};
+
 use core::marker::PhantomData;
 
-use crate::error::DrizzleError;
+use crate::error::{DrizzleError, type_name};
 use crate::{Cons, Nil};
 
 // =============================================================================
@@ -55,6 +58,10 @@ pub struct SelectExpr;
 #[derive(Debug, Clone, Copy, Default)]
 pub struct SelectAs<R>(PhantomData<R>);
 
+/// Marker: raw SQL annotated with its row type (`sql!("...", as R)`) — R is fixed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SelectTyped<R>(PhantomData<R>);
+
 /// Marker wrapper that carries in-scope tables.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Scoped<Marker, Scope>(PhantomData<(Marker, Scope)>);
@@ -111,6 +118,10 @@ impl MarkerRequiredTables for SelectExpr {
     type RequiredTables = Nil;
 }
 
+impl<R> MarkerRequiredTables for SelectTyped<R> {
+    type RequiredTables = Nil;
+}
+
 impl<R> MarkerRequiredTables for SelectAs<R>
 where
     R: SelectRequiredTables,
@@ -457,6 +468,9 @@ impl<Scope, Head, Tail> MarkerAggValidFor<Cons<Head, Tail>> for Scoped<SelectExp
 // SelectAs with GROUP BY: user-specified type, always passes
 impl<Scope, R, Head, Tail> MarkerAggValidFor<Cons<Head, Tail>> for Scoped<SelectAs<R>, Scope> {}
 
+// SelectTyped with GROUP BY: raw SQL, can't check, always passes
+impl<Scope, R, Head, Tail> MarkerAggValidFor<Cons<Head, Tail>> for Scoped<SelectTyped<R>, Scope> {}
+
 // SelectCols with GROUP BY: check each scalar column is in the Grouped list
 impl<Scope, Cols, Head, Tail, Proof> MarkerAggValidFor<Cons<Head, Tail>, Proof>
     for Scoped<SelectCols<Cols>, Scope>
@@ -639,6 +653,31 @@ impl<Row: ?Sized> RowColumnList<Row> for geo_types::Rect<f64> {
     type Columns = crate::Cons<Self, crate::Nil>;
 }
 
+#[cfg(feature = "geo-types")]
+impl<Row: ?Sized> RowColumnList<Row> for geo_types::Geometry<f64> {
+    type Columns = crate::Cons<Self, crate::Nil>;
+}
+
+#[cfg(feature = "geo-types")]
+impl<Row: ?Sized> RowColumnList<Row> for geo_types::Polygon<f64> {
+    type Columns = crate::Cons<Self, crate::Nil>;
+}
+
+#[cfg(feature = "geo-types")]
+impl<Row: ?Sized> RowColumnList<Row> for geo_types::MultiPoint<f64> {
+    type Columns = crate::Cons<Self, crate::Nil>;
+}
+
+#[cfg(feature = "geo-types")]
+impl<Row: ?Sized> RowColumnList<Row> for geo_types::MultiLineString<f64> {
+    type Columns = crate::Cons<Self, crate::Nil>;
+}
+
+#[cfg(feature = "geo-types")]
+impl<Row: ?Sized> RowColumnList<Row> for geo_types::MultiPolygon<f64> {
+    type Columns = crate::Cons<Self, crate::Nil>;
+}
+
 #[cfg(feature = "bit-vec")]
 impl<Row: ?Sized> RowColumnList<Row> for bit_vec::BitVec {
     type Columns = crate::Cons<Self, crate::Nil>;
@@ -783,7 +822,7 @@ pub trait MarkerColumnCountValid<Row: ?Sized, Inferred, Actual> {}
 /// Marker-level guard for strict decode entry points.
 ///
 /// Raw `SelectExpr` (`select(sql!(...))`) is intentionally excluded so strict
-/// decode requires either typed expressions (`raw_non_null`, `sql!(.., Type)`) or
+/// decode requires either typed expressions (`raw_non_null`, `sql!(.., as Type)`) or
 /// explicit remapping via typed expressions or `FromRow` derive.
 #[diagnostic::on_unimplemented(
     message = "raw select expressions require explicit typing in strict decode",
@@ -795,6 +834,7 @@ pub trait StrictDecodeMarker {}
 impl StrictDecodeMarker for SelectStar {}
 impl<Cols> StrictDecodeMarker for SelectCols<Cols> {}
 impl<R> StrictDecodeMarker for SelectAs<R> {}
+impl<R> StrictDecodeMarker for SelectTyped<R> {}
 impl<M, Scope> StrictDecodeMarker for Scoped<M, Scope> where M: StrictDecodeMarker {}
 
 impl<Row: ?Sized, Inferred, Actual> MarkerColumnCountValid<Row, Inferred, Actual> for SelectStar {}
@@ -814,6 +854,13 @@ impl<Row: ?Sized, Inferred, Actual> MarkerColumnCountValid<Row, Inferred, Actual
 {
 }
 
+impl<Row: ?Sized, R, Inferred, Actual> MarkerColumnCountValid<Row, Inferred, Actual>
+    for SelectTyped<R>
+where
+    Inferred: SameType<Actual>,
+{
+}
+
 impl<Row: ?Sized, R, Inferred, Actual> MarkerColumnCountValid<Row, Inferred, Actual>
     for SelectAs<R>
 {
@@ -868,9 +915,11 @@ where
 impl<RowRef, Row: ?Sized, R> DecodeSelectedRef<RowRef, R> for SelectStar
 where
     RowRef: core::ops::Deref<Target = Row>,
+    Row: RowColumnCount,
     R: FromDrizzleRow<Row>,
 {
     fn decode(row: RowRef) -> Result<R, DrizzleError> {
+        check_column_count::<Row, R>(&row)?;
         R::from_row(&*row)
     }
 }
@@ -878,9 +927,11 @@ where
 impl<RowRef, Row: ?Sized, Cols, R> DecodeSelectedRef<RowRef, R> for SelectCols<Cols>
 where
     RowRef: core::ops::Deref<Target = Row>,
+    Row: RowColumnCount,
     R: FromDrizzleRow<Row>,
 {
     fn decode(row: RowRef) -> Result<R, DrizzleError> {
+        check_column_count::<Row, R>(&row)?;
         R::from_row(&*row)
     }
 }
@@ -888,13 +939,60 @@ where
 impl<RowRef, Row: ?Sized, R> DecodeSelectedRef<RowRef, R> for SelectExpr
 where
     RowRef: core::ops::Deref<Target = Row>,
+    Row: RowColumnCount,
     R: FromDrizzleRow<Row>,
 {
     fn decode(row: RowRef) -> Result<R, DrizzleError> {
+        check_column_count::<Row, R>(&row)?;
         R::from_row(&*row)
     }
 }
 
+impl<RowRef, Row: ?Sized, Typed, R> DecodeSelectedRef<RowRef, R> for SelectTyped<Typed>
+where
+    RowRef: core::ops::Deref<Target = Row>,
+    Row: RowColumnCount,
+    R: FromDrizzleRow<Row>,
+{
+    fn decode(row: RowRef) -> Result<R, DrizzleError> {
+        check_column_count::<Row, R>(&row)?;
+        R::from_row(&*row)
+    }
+}
+
+/// Row types that can report how many columns the statement produced.
+///
+/// Used by [`DecodeSelectedRef`] to catch SELECT lists that are narrower than
+/// the decode target before offset-based reading runs past the end of the
+/// row (or, in joins, silently reads a neighbouring model's columns).
+pub trait RowColumnCount {
+    /// Number of columns in this row.
+    fn column_count(&self) -> usize;
+}
+
+/// Runtime counterpart to [`MarkerColumnCountValid`]: rejects rows with fewer
+/// columns than `R::COLUMN_COUNT`.
+///
+/// Wider rows are accepted, since targets such as partial `FromRow` structs
+/// legitimately read a prefix of `SELECT *`.
+fn check_column_count<Row, R>(row: &Row) -> Result<(), DrizzleError>
+where
+    Row: RowColumnCount + ?Sized,
+    R: FromDrizzleRow<Row>,
+{
+    let actual = row.column_count();
+    if actual >= R::COLUMN_COUNT {
+        return Ok(());
+    }
+    let target = type_name::<R>();
+    Err(DrizzleError::Mapping(compact_str::format_compact!(
+        "row has {actual} column(s) but `{target}` reads {} by offset; \
+         the SELECT list does not match the decode target",
+        R::COLUMN_COUNT
+    ))
+    .expecting(&target))
+}
+
 // =============================================================================
 // FromDrizzleRow — offset-based row extraction
 // =============================================================================
@@ -1039,6 +1137,10 @@ where
     type RustType = crate::prelude::Vec<<T as SQLTypeToRust<D>>::RustType>;
 }
 
+impl<D, M: 'static> SQLTypeToRust<D> for crate::types::JsonRows<M> {
+    type RustType = crate::prelude::Vec<M>;
+}
+
 impl SQLTypeToRust<SQLiteDialect> for drizzle_types::sqlite::types::Integer {
     type RustType = i64;
 }
@@ -1253,6 +1355,20 @@ impl SQLTypeToRust<PostgresDialect> for drizzle_types::postgres::types::Circle {
     type RustType = crate::prelude::String;
 }
 
+#[cfg(feature = "geo-types")]
+impl SQLTypeToRust<PostgresDialect> for drizzle_types::postgres::types::Geometry {
+    type RustType = geo_types::Geometry<f64>;
+}
+
+#[cfg(not(feature = "geo-types"))]
+impl SQLTypeToRust<PostgresDialect> for drizzle_types::postgres::types::Geometry {
+    type RustType = crate::prelude::Vec<u8>;
+}
+
+impl SQLTypeToRust<PostgresDialect> for drizzle_types::postgres::types::Vector {
+    type RustType = crate::prelude::Vec<f32>;
+}
+
 impl SQLTypeToRust<PostgresDialect> for drizzle_types::postgres::types::Enum {
     type RustType = crate::prelude::String;
 }
@@ -1347,6 +1463,10 @@ impl<T> ResolveRow<T> for SelectExpr {
     type Row = ();
 }
 
+impl<R, T> ResolveRow<T> for SelectTyped<R> {
+    type Row = R;
+}
+
 impl<R, T> ResolveRow<T> for SelectAs<R>
 where
     R: SelectAsFrom<T>,
@@ -1533,6 +1653,23 @@ impl<R, T> AfterFullJoin<R, T> for SelectExpr {
     type NewRow = R;
 }
 
+/// Typed raw SQL + JOIN → R unchanged.
+impl<Row, R, T> AfterJoin<R, T> for SelectTyped<Row> {
+    type NewRow = R;
+}
+
+impl<Row, R, T> AfterLeftJoin<R, T> for SelectTyped<Row> {
+    type NewRow = R;
+}
+
+impl<Row, R, T> AfterRightJoin<R, T> for SelectTyped<Row> {
+    type NewRow = R;
+}
+
+impl<Row, R, T> AfterFullJoin<R, T> for SelectTyped<Row> {
+    type NewRow = R;
+}
+
 /// Explicit model + JOIN → R unchanged.
 impl<Row, R, T> AfterJoin<R, T> for SelectAs<Row> {
     type NewRow = R;
@@ -1588,12 +1725,15 @@ where
 /// - `SelectStar` — infer R from the table's Select model
 /// - `SelectCols<C>` — infer R from the column value types
 /// - `SelectExpr` — R must be specified by the user
+/// - `SelectTyped<R>` — R declared on the raw fragment
 ///
 /// Implemented automatically for:
 /// - `()` → `SelectStar`
 /// - `SQL<'a, V>` → `SelectExpr`
+/// - `TypedSQL<'a, V, R>` (`sql!("...", as R)`) → `SelectTyped<R>`
 /// - `SQLExpr<'a, V, T, N, A>` → `SelectCols<(Self,)>`
 /// - Tuples `(A, B, ...)` → `SelectCols<(A, B, ...)>`
+/// - `hlist![A, B, ...]` → `SelectCols<HList![A, B, ...]>`, for any width
 /// - Column ZSTs (proc macro generated)
 /// - Table structs (proc macro generated) → `SelectStar`
 #[diagnostic::on_unimplemented(
@@ -1615,6 +1755,11 @@ impl<V: crate::SQLParam> IntoSelectTarget for crate::sql::SQL<'_, V> {
     type Marker = SelectExpr;
 }
 
+/// `select(sql!("...", as R))` → `SelectTyped<R>` — row type declared on the fragment.
+impl<V: crate::SQLParam, R> IntoSelectTarget for crate::sql::TypedSQL<'_, V, R> {
+    type Marker = SelectTyped<R>;
+}
+
 /// `select(typed_expr)` → `SelectCols<(Expr,)>` — single typed expression.
 impl<V: crate::SQLParam, T, N, A> IntoSelectTarget for crate::expr::SQLExpr<'_, V, T, N, A>
 where
@@ -24,8 +24,8 @@
 //! for its row type + `impl_postgres_value_row!(NewRow);`. The full type
 //! list lives in one place.
 
-use crate::error::DrizzleError;
-use crate::row::{FromDrizzleRow, NullProbeRow};
+use crate::error::{DrizzleError, type_name};
+use crate::row::{FromDrizzleRow, NullProbeRow, RowColumnCount};
 
 // Re-export the `FromSql` trait from whichever postgres crate is enabled.
 // Both `tokio_postgres::types::FromSql` and `postgres::types::FromSql`
@@ -33,8 +33,12 @@ use crate::row::{FromDrizzleRow, NullProbeRow};
 // only one needs to be in scope.
 #[cfg(all(feature = "postgres-sync", not(feature = "tokio-postgres")))]
 pub use ::postgres::types::FromSql;
+#[cfg(all(feature = "postgres-sync", not(feature = "tokio-postgres")))]
+use ::postgres::types::Type;
 #[cfg(feature = "tokio-postgres")]
 pub use ::tokio_postgres::types::FromSql;
+#[cfg(feature = "tokio-postgres")]
+use ::tokio_postgres::types::Type;
 
 /// Implemented by Postgres-flavored row types whose cells are decoded through
 /// `postgres_types::FromSql`. Drivers supply a one-method adapter that
@@ -71,6 +75,156 @@ macro_rules! postgres_leaf_impls {
     )* };
 }
 
+/// Leaf impls for the `geo-types` shapes with a native `PostgreSQL` type
+/// (`point`, `path`, `box`). The same Rust types also back PostGIS
+/// `geometry` columns, so cells of those types are decoded from WKB instead.
+#[cfg(feature = "geo-types")]
+macro_rules! postgres_geo_leaf_impls {
+    ($row_ty:ty; $($ty:ty),* $(,)?) => { $(
+        impl FromDrizzleRow<$row_ty> for $ty {
+            const COLUMN_COUNT: usize = 1;
+            fn from_row_at(row: &$row_ty, offset: usize) -> Result<Self, DrizzleError> {
+                <$row_ty as PostgresValueRow>::try_get_from_sql::<GeoCell<$ty>>(row, offset)
+                    .map(|cell| cell.0)
+            }
+        }
+        impl FromDrizzleRow<$row_ty> for Option<$ty> {
+            const COLUMN_COUNT: usize = 1;
+            fn from_row_at(row: &$row_ty, offset: usize) -> Result<Self, DrizzleError> {
+                <$row_ty as PostgresValueRow>::try_get_from_sql::<Option<GeoCell<$ty>>>(
+                    row, offset,
+                )
+                .map(|cell| cell.map(|cell| cell.0))
+            }
+        }
+    )* };
+}
+
+/// Leaf impls for `geo-types` shapes that only exist as PostGIS geometries.
+#[cfg(feature = "geo-types")]
+macro_rules! postgres_wkb_leaf_impls {
+    ($row_ty:ty; $($ty:ty),* $(,)?) => { $(
+        impl FromDrizzleRow<$row_ty> for $ty {
+            const COLUMN_COUNT: usize = 1;
+            fn from_row_at(row: &$row_ty, offset: usize) -> Result<Self, DrizzleError> {
+                <$row_ty as PostgresValueRow>::try_get_from_sql::<WkbCell<$ty>>(row, offset)
+                    .map(|cell| cell.0)
+            }
+        }
+        impl FromDrizzleRow<$row_ty> for Option<$ty> {
+            const COLUMN_COUNT: usize = 1;
+            fn from_row_at(row: &$row_ty, offset: usize) -> Result<Self, DrizzleError> {
+                <$row_ty as PostgresValueRow>::try_get_from_sql::<Option<WkbCell<$ty>>>(
+                    row, offset,
+                )
+                .map(|cell| cell.map(|cell| cell.0))
+            }
+        }
+    )* };
+}
+
+/// Returns true for the PostGIS extension types, which have no fixed OID.
+#[cfg(feature = "geo-types")]
+fn is_postgis_type(ty: &Type) -> bool {
+    matches!(ty.name(), "geometry" | "geography")
+}
+
+#[cfg(feature = "geo-types")]
+fn decode_postgis<T>(raw: &[u8]) -> Result<T, Box<dyn std::error::Error + Sync + Send>>
+where
+    T: TryFrom<geo_types::Geometry<f64>>,
+{
+    let (geometry, _srid) = crate::wkb::decode(raw)?;
+    let name = geometry_name(&geometry);
+    T::try_from(geometry).map_err(|_| {
+        format!(
+            "cannot decode PostGIS {name} as {}",
+            core::any::type_name::<T>()
+        )
+        .into()
+    })
+}
+
+#[cfg(feature = "geo-types")]
+const fn geometry_name(geometry: &geo_types::Geometry<f64>) -> &'static str {
+    match geometry {
+        geo_types::Geometry::Point(_) => "Point",
+        geo_types::Geometry::Line(_) => "Line",
+        geo_types::Geometry::LineString(_) => "LineString",
+        geo_types::Geometry::Polygon(_) => "Polygon",
+        geo_types::Geometry::MultiPoint(_) => "MultiPoint",
+        geo_types::Geometry::MultiLineString(_) => "MultiLineString",
+        geo_types::Geometry::MultiPolygon(_) => "MultiPolygon",
+        geo_types::Geometry::GeometryCollection(_) => "GeometryCollection",
+        geo_types::Geometry::Rect(_) => "Rect",
+        geo_types::Geometry::Triangle(_) => "Triangle",
+    }
+}
+
+/// Decodes a native geometric cell, or a PostGIS cell holding the same shape.
+#[cfg(feature = "geo-types")]
+struct GeoCell<T>(T);
+
+#[cfg(feature = "geo-types")]
+impl<'a, T> FromSql<'a> for GeoCell<T>
+where
+    T: FromSql<'a> + TryFrom<geo_types::Geometry<f64>>,
+{
+    fn from_sql(
+        ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        if is_postgis_type(ty) {
+            decode_postgis(raw).map(GeoCell)
+        } else {
+            T::from_sql(ty, raw).map(GeoCell)
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        is_postgis_type(ty) || T::accepts(ty)
+    }
+}
+
+/// Decodes a PostGIS `geometry` / `geography` cell.
+#[cfg(feature = "geo-types")]
+struct WkbCell<T>(T);
+
+#[cfg(feature = "geo-types")]
+impl<T> FromSql<'_> for WkbCell<T>
+where
+    T: TryFrom<geo_types::Geometry<f64>>,
+{
+    fn from_sql(
+        _ty: &Type,
+        raw: &[u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        decode_postgis(raw).map(WkbCell)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        is_postgis_type(ty)
+    }
+}
+
+/// Decodes a `real[]` cell, or a pgvector `vector` cell. pgvector is an
+/// extension, so its type has no fixed OID and is matched by name.
+struct VectorCell(Vec<f32>);
+
+impl FromSql<'_> for VectorCell {
+    fn from_sql(ty: &Type, raw: &[u8]) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        if ty.name() == "vector" {
+            Ok(Self(crate::vector::decode_pgvector(raw)?))
+        } else {
+            Vec::<f32>::from_sql(ty, raw).map(Self)
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        ty.name() == "vector" || Vec::<f32>::accepts(ty)
+    }
+}
+
 /// Emit every `FromDrizzleRow` leaf impl and the composite
 /// `Option<T: NullProbeRow<R>>` impl for `$row_ty`. Invoked once per enabled
 /// postgres-flavored driver.
@@ -80,10 +234,27 @@ macro_rules! impl_postgres_value_row {
             $row_ty;
             i8, i16, i32, i64, f32, f64, bool, String,
             Vec<u8>,
-            Vec<i16>, Vec<i32>, Vec<i64>, Vec<f32>, Vec<f64>,
+            Vec<i16>, Vec<i32>, Vec<i64>, Vec<f64>,
             Vec<bool>, Vec<String>,
         );
 
+        impl FromDrizzleRow<$row_ty> for Vec<f32> {
+            const COLUMN_COUNT: usize = 1;
+            fn from_row_at(row: &$row_ty, offset: usize) -> Result<Self, DrizzleError> {
+                <$row_ty as PostgresValueRow>::try_get_from_sql::<VectorCell>(row, offset)
+                    .map(|cell| cell.0)
+            }
+        }
+        impl FromDrizzleRow<$row_ty> for Option<Vec<f32>> {
+            const COLUMN_COUNT: usize = 1;
+            fn from_row_at(row: &$row_ty, offset: usize) -> Result<Self, DrizzleError> {
+                <$row_ty as PostgresValueRow>::try_get_from_sql::<Option<VectorCell>>(
+                    row, offset,
+                )
+                .map(|cell| cell.map(|cell| cell.0))
+            }
+        }
+
         #[cfg(feature = "uuid")]
         postgres_leaf_impls!($row_ty; uuid::Uuid, Vec<uuid::Uuid>);
 
@@ -106,13 +277,23 @@ macro_rules! impl_postgres_value_row {
         postgres_leaf_impls!($row_ty; cidr::IpInet, cidr::IpCidr);
 
         #[cfg(feature = "geo-types")]
-        postgres_leaf_impls!(
+        postgres_geo_leaf_impls!(
             $row_ty;
             geo_types::Point<f64>,
             geo_types::LineString<f64>,
             geo_types::Rect<f64>,
         );
 
+        #[cfg(feature = "geo-types")]
+        postgres_wkb_leaf_impls!(
+            $row_ty;
+            geo_types::Geometry<f64>,
+            geo_types::Polygon<f64>,
+            geo_types::MultiPoint<f64>,
+            geo_types::MultiLineString<f64>,
+            geo_types::MultiPolygon<f64>,
+        );
+
         #[cfg(feature = "bit-vec")]
         postgres_leaf_impls!($row_ty; bit_vec::BitVec);
 
@@ -145,8 +326,15 @@ impl PostgresValueRow for ::tokio_postgres::Row {
     where
         T: FromSql<'a>,
     {
-        self.try_get(offset)
-            .map_err(|e| DrizzleError::ConversionError(e.to_string().into()))
+        self.try_get(offset).map_err(|e| {
+            let column = self.columns().get(offset);
+            DrizzleError::ConversionError(e.to_string().into()).decode_column(
+                offset,
+                column.map(|c| c.name()),
+                &type_name::<T>(),
+                column.map(|c| c.type_().name()),
+            )
+        })
     }
 }
 
@@ -159,10 +347,33 @@ impl PostgresValueRow for ::postgres::Row {
     where
         T: FromSql<'a>,
     {
-        self.try_get(offset)
-            .map_err(|e| DrizzleError::ConversionError(e.to_string().into()))
+        self.try_get(offset).map_err(|e| {
+            let column = self.columns().get(offset);
+            DrizzleError::ConversionError(e.to_string().into()).decode_column(
+                offset,
+                column.map(|c| c.name()),
+                &type_name::<T>(),
+                column.map(|c| c.type_().name()),
+            )
+        })
     }
 }
 
 #[cfg(all(feature = "postgres-sync", not(feature = "tokio-postgres")))]
 impl_postgres_value_row!(::postgres::Row);
+
+#[cfg(feature = "tokio-postgres")]
+impl RowColumnCount for ::tokio_postgres::Row {
+    #[inline]
+    fn column_count(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(all(feature = "postgres-sync", not(feature = "tokio-postgres")))]
+impl RowColumnCount for ::postgres::Row {
+    #[inline]
+    fn column_count(&self) -> usize {
+        self.len()
+    }
+}
@@ -7,6 +7,7 @@
 //! zero-allocation NULL probe.
 
 use crate::error::DrizzleError;
+use crate::row::RowColumnCount;
 use crate::row::sqlite_value::{SqliteCell, SqliteValueRow};
 
 impl SqliteValueRow for ::rusqlite::Row<'_> {
@@ -38,4 +39,15 @@ impl SqliteValueRow for ::rusqlite::Row<'_> {
             .map_err(|e| DrizzleError::ConversionError(e.to_string().into()))?;
         Ok(matches!(value, ::rusqlite::types::ValueRef::Null))
     }
+
+    fn column_name_at(&self, offset: usize) -> Option<&str> {
+        self.as_ref().column_name(offset).ok()
+    }
+}
+
+impl RowColumnCount for ::rusqlite::Row<'_> {
+    #[inline]
+    fn column_count(&self) -> usize {
+        self.as_ref().column_count()
+    }
 }
@@ -22,7 +22,7 @@
 //! `cell_at`-based default impl. Drivers that can probe NULL without
 //! allocating (e.g. `rusqlite::Row::get_ref`) override it.
 
-use crate::error::DrizzleError;
+use crate::error::{DrizzleError, type_name};
 use crate::row::FromDrizzleRow;
 
 /// SQLite-flavored cell value. The union of the four storage classes plus NULL,
@@ -41,6 +41,18 @@ impl SqliteCell {
     pub fn is_null(&self) -> bool {
         matches!(self, Self::Null)
     }
+
+    /// `SQLite` storage class of the cell, e.g. `INTEGER`.
+    #[must_use]
+    pub const fn storage_class(&self) -> &'static str {
+        match self {
+            Self::Null => "NULL",
+            Self::Integer(_) => "INTEGER",
+            Self::Real(_) => "REAL",
+            Self::Text(_) => "TEXT",
+            Self::Blob(_) => "BLOB",
+        }
+    }
 }
 
 /// Implemented by SQLite-flavored row types whose cells live in a tagged
@@ -64,6 +76,32 @@ pub trait SqliteValueRow {
     fn is_null_at(&self, offset: usize) -> Result<bool, DrizzleError> {
         Ok(self.cell_at(offset)?.is_null())
     }
+
+    /// Name of the column at `offset`, when the driver exposes it. Only
+    /// read on the error path to describe decode failures.
+    #[inline]
+    fn column_name_at(&self, _offset: usize) -> Option<&str> {
+        None
+    }
+}
+
+/// Runs a leaf decode and, if it fails, records the column it failed on
+/// along with the storage class the driver actually returned.
+#[inline]
+fn decode_at<R: SqliteValueRow, T>(
+    row: &R,
+    offset: usize,
+    decode: impl FnOnce() -> Result<T, DrizzleError>,
+) -> Result<T, DrizzleError> {
+    decode().map_err(|error| {
+        let actual = row.cell_at(offset).ok();
+        error.decode_column(
+            offset,
+            row.column_name_at(offset),
+            &type_name::<T>(),
+            actual.as_ref().map(SqliteCell::storage_class),
+        )
+    })
 }
 
 // =============================================================================
@@ -89,32 +127,73 @@ macro_rules! sqlite_value_int_impl {
         impl<R: SqliteValueRow> FromDrizzleRow<R> for $ty {
             const COLUMN_COUNT: usize = 1;
             fn from_row_at(row: &R, offset: usize) -> Result<Self, DrizzleError> {
-                match row.cell_at(offset)? {
-                    SqliteCell::Integer(i) => i.try_into().map_err(
-                        |e: core::num::TryFromIntError| {
-                            DrizzleError::ConversionError(e.to_string().into())
-                        },
-                    ),
-                    SqliteCell::Null => Err(DrizzleError::ConversionError(
-                        "unexpected NULL for integer".into(),
-                    )),
-                    _ => Err(DrizzleError::ConversionError(
-                        "expected integer value".into(),
-                    )),
-                }
+                decode_at(row, offset, || {
+                    match row.cell_at(offset)? {
+                        SqliteCell::Integer(i) => i.try_into().map_err(
+                            |e: core::num::TryFromIntError| {
+                                DrizzleError::ConversionError(e.to_string().into())
+                            },
+                        ),
+                        SqliteCell::Null => Err(DrizzleError::ConversionError(
+                            "unexpected NULL for integer".into(),
+                        )),
+                        _ => Err(DrizzleError::ConversionError(
+                            "expected integer value".into(),
+                        )),
+                    }
+                })
+            }
+        }
+    )* }
+}
+
+sqlite_value_int_impl!(i8, i16, i32, isize, u8, u16, u32);
+
+// `u64` / `usize` values above `i64::MAX` are stored as an 8-byte big-endian
+// BLOB, since SQLite INTEGER is signed.
+macro_rules! sqlite_value_unsigned_impl {
+    ($($ty:ty),*) => { $(
+        impl<R: SqliteValueRow> FromDrizzleRow<R> for $ty {
+            const COLUMN_COUNT: usize = 1;
+            fn from_row_at(row: &R, offset: usize) -> Result<Self, DrizzleError> {
+                decode_at(row, offset, || {
+                    match row.cell_at(offset)? {
+                        SqliteCell::Integer(i) => i.try_into().map_err(
+                            |e: core::num::TryFromIntError| {
+                                DrizzleError::ConversionError(e.to_string().into())
+                            },
+                        ),
+                        SqliteCell::Blob(bytes) => {
+                            let bytes: [u8; 8] = bytes.as_slice().try_into().map_err(|_| {
+                                DrizzleError::ConversionError(
+                                    "expected 8-byte unsigned integer blob".into(),
+                                )
+                            })?;
+                            u64::from_be_bytes(bytes)
+                                .try_into()
+                                .map_err(|e| DrizzleError::ConversionError(format!("{e}").into()))
+                        }
+                        SqliteCell::Null => Err(DrizzleError::ConversionError(
+                            "unexpected NULL for integer".into(),
+                        )),
+                        _ => Err(DrizzleError::ConversionError(
+                            "expected integer value".into(),
+                        )),
+                    }
+                })
             }
         }
     )* }
 }
 
-sqlite_value_int_impl!(i8, i16, i32, isize, u8, u16, u32, u64, usize);
+sqlite_value_unsigned_impl!(u64, usize);
 
 // `i64` is the identity conversion — skip the `try_into` indirection so the
 // fast path doesn't even mention `TryFromIntError`.
 impl<R: SqliteValueRow> FromDrizzleRow<R> for i64 {
     const COLUMN_COUNT: usize = 1;
     fn from_row_at(row: &R, offset: usize) -> Result<Self, DrizzleError> {
-        match row.cell_at(offset)? {
+        decode_at(row, offset, || match row.cell_at(offset)? {
             SqliteCell::Integer(i) => Ok(i),
             SqliteCell::Null => Err(DrizzleError::ConversionError(
                 "unexpected NULL for integer".into(),
@@ -122,7 +201,7 @@ impl<R: SqliteValueRow> FromDrizzleRow<R> for i64 {
             _ => Err(DrizzleError::ConversionError(
                 "expected integer value".into(),
             )),
-        }
+        })
     }
 }
 
@@ -133,39 +212,43 @@ impl<R: SqliteValueRow> FromDrizzleRow<R> for i64 {
 impl<R: SqliteValueRow> FromDrizzleRow<R> for f64 {
     const COLUMN_COUNT: usize = 1;
     fn from_row_at(row: &R, offset: usize) -> Result<Self, DrizzleError> {
-        match row.cell_at(offset)? {
-            SqliteCell::Real(r) => Ok(r),
-            // SQLite's NUMERIC affinity allows an integer to come back from a
-            // column declared REAL; preserve the existing libsql behavior of
-            // accepting that and round-tripping via the exact `i64_to_f64`
-            // helper. (turso used a decimal-string parse for the same idea —
-            // this is the more correct path.)
-            SqliteCell::Integer(i) => Ok(i64_to_f64(i)),
-            SqliteCell::Null => Err(DrizzleError::ConversionError(
-                "unexpected NULL for float".into(),
-            )),
-            _ => Err(DrizzleError::ConversionError("expected real value".into())),
-        }
+        decode_at(row, offset, || {
+            match row.cell_at(offset)? {
+                SqliteCell::Real(r) => Ok(r),
+                // SQLite's NUMERIC affinity allows an integer to come back from a
+                // column declared REAL; preserve the existing libsql behavior of
+                // accepting that and round-tripping via the exact `i64_to_f64`
+                // helper. (turso used a decimal-string parse for the same idea —
+                // this is the more correct path.)
+                SqliteCell::Integer(i) => Ok(i64_to_f64(i)),
+                SqliteCell::Null => Err(DrizzleError::ConversionError(
+                    "unexpected NULL for float".into(),
+                )),
+                _ => Err(DrizzleError::ConversionError("expected real value".into())),
+            }
+        })
     }
 }
 
 impl<R: SqliteValueRow> FromDrizzleRow<R> for f32 {
     const COLUMN_COUNT: usize = 1;
     fn from_row_at(row: &R, offset: usize) -> Result<Self, DrizzleError> {
-        let v = f64::from_row_at(row, offset)?;
-        // Decimal-string round-trip matches IEEE-754 round-to-nearest
-        // semantics and avoids the lossy `as` cast.
-        let f: Self = format!("{v}")
-            .parse()
-            .map_err(|e: core::num::ParseFloatError| {
-                DrizzleError::ConversionError(e.to_string().into())
-            })?;
-        if v.is_finite() && !f.is_finite() {
-            return Err(DrizzleError::ConversionError(
-                format!("f64 value {v} overflows f32").into(),
-            ));
-        }
-        Ok(f)
+        decode_at(row, offset, || {
+            let v = f64::from_row_at(row, offset)?;
+            // Decimal-string round-trip matches IEEE-754 round-to-nearest
+            // semantics and avoids the lossy `as` cast.
+            let f: Self = format!("{v}")
+                .parse()
+                .map_err(|e: core::num::ParseFloatError| {
+                    DrizzleError::ConversionError(e.to_string().into())
+                })?;
+            if v.is_finite() && !f.is_finite() {
+                return Err(DrizzleError::ConversionError(
+                    format!("f64 value {v} overflows f32").into(),
+                ));
+            }
+            Ok(f)
+        })
     }
 }
 
@@ -176,7 +259,7 @@ impl<R: SqliteValueRow> FromDrizzleRow<R> for f32 {
 impl<R: SqliteValueRow> FromDrizzleRow<R> for bool {
     const COLUMN_COUNT: usize = 1;
     fn from_row_at(row: &R, offset: usize) -> Result<Self, DrizzleError> {
-        match row.cell_at(offset)? {
+        decode_at(row, offset, || match row.cell_at(offset)? {
             SqliteCell::Integer(i) => Ok(i != 0),
             SqliteCell::Null => Err(DrizzleError::ConversionError(
                 "unexpected NULL for bool".into(),
@@ -184,33 +267,33 @@ impl<R: SqliteValueRow> FromDrizzleRow<R> for bool {
             _ => Err(DrizzleError::ConversionError(
                 "expected integer for bool".into(),
             )),
-        }
+        })
     }
 }
 
 impl<R: SqliteValueRow> FromDrizzleRow<R> for String {
     const COLUMN_COUNT: usize = 1;
     fn from_row_at(row: &R, offset: usize) -> Result<Self, DrizzleError> {
-        match row.cell_at(offset)? {
+        decode_at(row, offset, || match row.cell_at(offset)? {
             SqliteCell::Text(s) => Ok(s),
             SqliteCell::Null => Err(DrizzleError::ConversionError(
                 "unexpected NULL for string".into(),
             )),
             _ => Err(DrizzleError::ConversionError("expected text value".into())),
-        }
+        })
     }
 }
 
 impl<R: SqliteValueRow> FromDrizzleRow<R> for Vec<u8> {
     const COLUMN_COUNT: usize = 1;
     fn from_row_at(row: &R, offset: usize) -> Result<Self, DrizzleError> {
-        match row.cell_at(offset)? {
+        decode_at(row, offset, || match row.cell_at(offset)? {
             SqliteCell::Blob(b) => Ok(b),
             SqliteCell::Null => Err(DrizzleError::ConversionError(
                 "unexpected NULL for blob".into(),
             )),
             _ => Err(DrizzleError::ConversionError("expected blob value".into())),
-        }
+        })
     }
 }
 
@@ -240,14 +323,14 @@ where
 impl<R: SqliteValueRow> FromDrizzleRow<R> for uuid::Uuid {
     const COLUMN_COUNT: usize = 1;
     fn from_row_at(row: &R, offset: usize) -> Result<Self, DrizzleError> {
-        match row.cell_at(offset)? {
+        decode_at(row, offset, || match row.cell_at(offset)? {
             SqliteCell::Text(s) => Self::parse_str(&s).map_err(Into::into),
             SqliteCell::Blob(b) => Self::from_slice(&b)
                 .map_err(|e| DrizzleError::ConversionError(e.to_string().into())),
             _ => Err(DrizzleError::ConversionError(
                 "expected TEXT or BLOB for UUID".into(),
             )),
-        }
+        })
     }
 }
 
@@ -255,9 +338,12 @@ impl<R: SqliteValueRow> FromDrizzleRow<R> for uuid::Uuid {
 impl<R: SqliteValueRow> FromDrizzleRow<R> for chrono::NaiveDate {
     const COLUMN_COUNT: usize = 1;
     fn from_row_at(row: &R, offset: usize) -> Result<Self, DrizzleError> {
-        let s = String::from_row_at(row, offset)?;
-        s.parse()
-            .map_err(|e: chrono::ParseError| DrizzleError::ConversionError(e.to_string().into()))
+        decode_at(row, offset, || {
+            let s = String::from_row_at(row, offset)?;
+            s.parse().map_err(|e: chrono::ParseError| {
+                DrizzleError::ConversionError(e.to_string().into())
+            })
+        })
     }
 }
 
@@ -265,9 +351,12 @@ impl<R: SqliteValueRow> FromDrizzleRow<R> for chrono::NaiveDate {
 impl<R: SqliteValueRow> FromDrizzleRow<R> for chrono::NaiveTime {
     const COLUMN_COUNT: usize = 1;
     fn from_row_at(row: &R, offset: usize) -> Result<Self, DrizzleError> {
-        let s = String::from_row_at(row, offset)?;
-        s.parse()
-            .map_err(|e: chrono::ParseError| DrizzleError::ConversionError(e.to_string().into()))
+        decode_at(row, offset, || {
+            let s = String::from_row_at(row, offset)?;
+            s.parse().map_err(|e: chrono::ParseError| {
+                DrizzleError::ConversionError(e.to_string().into())
+            })
+        })
     }
 }
 
@@ -275,9 +364,12 @@ impl<R: SqliteValueRow> FromDrizzleRow<R> for chrono::NaiveTime {
 impl<R: SqliteValueRow> FromDrizzleRow<R> for chrono::NaiveDateTime {
     const COLUMN_COUNT: usize = 1;
     fn from_row_at(row: &R, offset: usize) -> Result<Self, DrizzleError> {
-        let s = String::from_row_at(row, offset)?;
-        s.parse()
-            .map_err(|e: chrono::ParseError| DrizzleError::ConversionError(e.to_string().into()))
+        decode_at(row, offset, || {
+            let s = String::from_row_at(row, offset)?;
+            s.parse().map_err(|e: chrono::ParseError| {
+                DrizzleError::ConversionError(e.to_string().into())
+            })
+        })
     }
 }
 
@@ -285,11 +377,13 @@ impl<R: SqliteValueRow> FromDrizzleRow<R> for chrono::NaiveDateTime {
 impl<R: SqliteValueRow> FromDrizzleRow<R> for chrono::DateTime<chrono::Utc> {
     const COLUMN_COUNT: usize = 1;
     fn from_row_at(row: &R, offset: usize) -> Result<Self, DrizzleError> {
-        let s = String::from_row_at(row, offset)?;
-        let ndt: chrono::NaiveDateTime = s
-            .parse()
-            .map_err(|e: chrono::ParseError| DrizzleError::ConversionError(e.to_string().into()))?;
-        Ok(Self::from_naive_utc_and_offset(ndt, chrono::Utc))
+        decode_at(row, offset, || {
+            let s = String::from_row_at(row, offset)?;
+            let ndt: chrono::NaiveDateTime = s.parse().map_err(|e: chrono::ParseError| {
+                DrizzleError::ConversionError(e.to_string().into())
+            })?;
+            Ok(Self::from_naive_utc_and_offset(ndt, chrono::Utc))
+        })
     }
 }
 
@@ -297,7 +391,9 @@ impl<R: SqliteValueRow> FromDrizzleRow<R> for chrono::DateTime<chrono::Utc> {
 impl<R: SqliteValueRow> FromDrizzleRow<R> for serde_json::Value {
     const COLUMN_COUNT: usize = 1;
     fn from_row_at(row: &R, offset: usize) -> Result<Self, DrizzleError> {
-        let s = String::from_row_at(row, offset)?;
-        serde_json::from_str(&s).map_err(Into::into)
+        decode_at(row, offset, || {
+            let s = String::from_row_at(row, offset)?;
+            serde_json::from_str(&s).map_err(Into::into)
+        })
     }
 }
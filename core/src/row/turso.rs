@@ -6,6 +6,7 @@
 //! [`super::sqlite_value::SqliteCell`].
 
 use crate::error::DrizzleError;
+use crate::row::RowColumnCount;
 use crate::row::sqlite_value::{SqliteCell, SqliteValueRow};
 
 impl SqliteValueRow for ::turso::Row {
@@ -33,3 +34,10 @@ impl SqliteValueRow for ::turso::Row {
         ))
     }
 }
+
+impl RowColumnCount for ::turso::Row {
+    #[inline]
+    fn column_count(&self) -> usize {
+        self.column_count()
+    }
+}
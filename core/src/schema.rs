@@ -26,6 +26,58 @@ impl core::fmt::Debug for dyn SQLEnumInfo {
     }
 }
 
+/// Trait for database domain types that can be part of a schema
+pub trait SQLDomainInfo: Any + Send + Sync {
+    /// The name of this domain
+    fn name(&self) -> &'static str;
+
+    /// The underlying data type of this domain
+    fn base_type(&self) -> &'static str;
+
+    /// Whether values of this domain must be non-null
+    fn not_null(&self) -> bool;
+
+    /// The default expression of this domain
+    fn default_value(&self) -> Option<&'static str>;
+
+    /// The check expression of this domain, referring to the value as `VALUE`
+    fn check(&self) -> Option<&'static str>;
+
+    /// The SQL CREATE DOMAIN statement for this domain
+    fn create_domain_sql(&self) -> String;
+}
+
+impl core::fmt::Debug for dyn SQLDomainInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SQLDomainInfo")
+            .field("name", &self.name())
+            .field("base_type", &self.base_type())
+            .field("check", &self.check())
+            .finish()
+    }
+}
+
+/// Trait for database composite (row) types that can be part of a schema
+pub trait SQLCompositeInfo: Any + Send + Sync {
+    /// The name of this composite type
+    fn name(&self) -> &'static str;
+
+    /// The attribute names and SQL types, in declaration order
+    fn fields(&self) -> &'static [(&'static str, &'static str)];
+
+    /// The SQL CREATE TYPE statement for this composite type
+    fn create_composite_sql(&self) -> String;
+}
+
+impl core::fmt::Debug for dyn SQLCompositeInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SQLCompositeInfo")
+            .field("name", &self.name())
+            .field("fields", &self.fields())
+            .finish()
+    }
+}
+
 /// Sort direction for ORDER BY clauses
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum OrderBy {
@@ -51,16 +103,40 @@ where
     column.to_sql().append(&OrderBy::Desc)
 }
 
+/// Hash of one schema item, combined by the schema derives into
+/// `SCHEMA_HASH`.
+///
+/// FNV-1a over the item's name and its `CREATE` statement, so it is stable
+/// across builds, platforms and compiler versions.
+#[must_use]
+pub const fn schema_item_hash(name: &str, sql: &str) -> u64 {
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let parts = [name.as_bytes(), b"\0", sql.as_bytes()];
+    let mut part = 0;
+    while part < parts.len() {
+        let bytes = parts[part];
+        let mut i = 0;
+        while i < bytes.len() {
+            hash ^= bytes[i] as u64;
+            hash = hash.wrapping_mul(PRIME);
+            i += 1;
+        }
+        part += 1;
+    }
+    hash
+}
+
 /// Topological sort of `(name, dependency_names)` pairs using Kahn's algorithm.
 ///
 /// Returns names in dependency order (dependencies before dependents).
-/// Uses `BTreeSet` for deterministic tie-breaking (lexicographic).
+/// Self-references are ignored. Uses `BTreeSet` for deterministic tie-breaking (lexicographic).
 ///
 /// # Errors
 ///
 /// Returns an error if a cycle is detected.
 #[cfg(feature = "std")]
-#[allow(dead_code)]
 pub(crate) fn topological_order<'a>(
     items: impl IntoIterator<Item = (&'a str, &'a [&'a str])>,
 ) -> crate::error::Result<Vec<&'a str>> {
@@ -75,8 +151,11 @@ pub(crate) fn topological_order<'a>(
     let mut indegree = vec![0usize; n];
     let mut reverse_edges: Vec<Vec<usize>> = vec![Vec::new(); n];
 
-    for (i, (_name, deps)) in items.iter().enumerate() {
+    for (i, (name, deps)) in items.iter().enumerate() {
         for dep in *deps {
+            if dep == name {
+                continue;
+            }
             if let Some(&j) = name_set.get(dep) {
                 indegree[i] += 1;
                 reverse_edges[j].push(i);
@@ -114,6 +193,143 @@ pub(crate) fn topological_order<'a>(
     Ok(result)
 }
 
+/// Orders `tables` so that every table comes before the tables it references,
+/// i.e. the order rows can be deleted in without breaking foreign keys.
+/// Repeated tables are kept once.
+///
+/// A cycle is broken at its deferrable foreign keys, if it has any.
+///
+/// # Errors
+///
+/// Returns an error if the tables reference each other in a cycle of
+/// non-deferrable foreign keys.
+#[cfg(feature = "std")]
+pub fn truncation_order(
+    tables: &[&'static crate::TableRef],
+) -> crate::error::Result<Vec<&'static crate::TableRef>> {
+    let mut seen = HashSet::new();
+    let tables: Vec<_> = tables
+        .iter()
+        .copied()
+        .filter(|t| seen.insert(t.name))
+        .collect();
+    let resolve = |order: Vec<&str>| {
+        order
+            .into_iter()
+            .rev()
+            .filter_map(|name| tables.iter().copied().find(|t| t.name == name))
+            .collect()
+    };
+    if let Ok(order) = topological_order(tables.iter().map(|t| (t.name, t.dependency_names))) {
+        return Ok(resolve(order));
+    }
+
+    let hard_deps: Vec<Vec<&str>> = tables
+        .iter()
+        .map(|t| {
+            t.foreign_keys
+                .iter()
+                .filter(|fk| !fk.is_deferrable())
+                .map(|fk| fk.target_table)
+                .collect()
+        })
+        .collect();
+    let order = topological_order(
+        tables
+            .iter()
+            .zip(&hard_deps)
+            .map(|(t, deps)| (t.name, deps.as_slice())),
+    )?;
+    Ok(resolve(order))
+}
+
+/// Finds a table caught in a cycle of non-deferrable foreign keys.
+///
+/// Such a cycle can never be satisfied, so the schema derives evaluate this
+/// in a `const` block and reject the schema at compile time. `None` entries
+/// (schema items that are not tables) are skipped, as are self-references.
+/// Returns the name of one table on the cycle.
+#[must_use]
+pub const fn undeferred_fk_cycle<const N: usize>(
+    tables: [Option<&'static crate::TableRef>; N],
+) -> Option<&'static str> {
+    let mut remaining = [false; N];
+    let mut i = 0;
+    while i < N {
+        remaining[i] = tables[i].is_some();
+        i += 1;
+    }
+
+    // Repeatedly peel off tables that no remaining table points into, or
+    // that point into no remaining table. Whatever is left lies on a cycle.
+    let mut changed = true;
+    while changed {
+        changed = false;
+        let mut i = 0;
+        while i < N {
+            if remaining[i] {
+                let mut has_out = false;
+                let mut has_in = false;
+                let mut j = 0;
+                while j < N {
+                    if j != i
+                        && remaining[j]
+                        && let (Some(a), Some(b)) = (tables[i], tables[j])
+                    {
+                        has_out = has_out || references_undeferred(a, b);
+                        has_in = has_in || references_undeferred(b, a);
+                    }
+                    j += 1;
+                }
+                if !has_out || !has_in {
+                    remaining[i] = false;
+                    changed = true;
+                }
+            }
+            i += 1;
+        }
+    }
+
+    let mut i = 0;
+    while i < N {
+        if remaining[i]
+            && let Some(table) = tables[i]
+        {
+            return Some(table.name);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Whether `from` has a non-deferrable foreign key into `to`.
+const fn references_undeferred(from: &crate::TableRef, to: &crate::TableRef) -> bool {
+    let mut k = 0;
+    while k < from.foreign_keys.len() {
+        let fk = &from.foreign_keys[k];
+        if !fk.is_deferrable() && str_eq(fk.target_table, to.name) {
+            return true;
+        }
+        k += 1;
+    }
+    false
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
 impl<'a, V: SQLParam + 'a> ToSQL<'a, V> for OrderBy {
     fn to_sql(&self) -> SQL<'a, V> {
         let sql_str = match self {
@@ -14,6 +14,7 @@ pub enum ColumnDialect {
         generated_expression: Option<&'static str>,
         generated_stored: bool,
         collate: Option<&'static str>,
+        comment: Option<&'static str>,
     },
     PostgreSQL {
         postgres_type: &'static str,
@@ -44,6 +45,7 @@ pub enum TableDialect {
     SQLite {
         without_rowid: bool,
         strict: bool,
+        comment: Option<&'static str>,
     },
 }
 
@@ -77,6 +79,16 @@ pub struct ForeignKeyRef {
     pub initially_deferred: bool,
 }
 
+impl ForeignKeyRef {
+    /// Whether the constraint can be checked at commit instead of per
+    /// statement. `INITIALLY DEFERRED` implies `DEFERRABLE`.
+    #[inline]
+    #[must_use]
+    pub const fn is_deferrable(&self) -> bool {
+        self.deferrable || self.initially_deferred
+    }
+}
+
 /// Primary key reference as a const Copy struct.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct PrimaryKeyRef {
@@ -91,10 +103,21 @@ pub struct ConstraintRef {
     pub kind: SQLConstraintKind,
     pub columns: &'static [&'static str],
     pub check_expression: Option<&'static str>,
+    pub exclusion: Option<ExclusionRef>,
     pub deferrable: bool,
     pub initially_deferred: bool,
 }
 
+/// EXCLUDE constraint details carried by a [`ConstraintRef`].
+///
+/// `operators` pairs up with the constraint's `columns`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExclusionRef {
+    pub using: &'static str,
+    pub operators: &'static [&'static str],
+    pub predicate: Option<&'static str>,
+}
+
 // ==================== Enhanced TableRef and ColumnRef ====================
 
 /// Table reference with full schema metadata.
@@ -155,24 +178,30 @@ impl TableRef {
 pub struct TableSqlRef {
     pub name: &'static str,
     pub column_names: &'static [&'static str],
+    /// Attached `SQLite` database the table lives in, rendered as
+    /// `"database"."name"`. `PostgreSQL` tables resolve through the search
+    /// path, so this is always `None` for them.
+    pub database: Option<&'static str>,
 }
 
 impl TableSqlRef {
     #[inline]
     #[must_use]
     pub const fn from_table_ref(table: TableRef) -> Self {
-        Self {
-            name: table.name,
-            column_names: table.column_names,
-        }
+        Self::from_table_ref_ref(&table)
     }
 
     #[inline]
     #[must_use]
     pub const fn from_table_ref_ref(table: &TableRef) -> Self {
+        let database = match table.dialect {
+            TableDialect::SQLite { .. } => table.schema,
+            TableDialect::PostgreSQL { .. } => None,
+        };
         Self {
             name: table.name,
             column_names: table.column_names,
+            database,
         }
     }
 }
@@ -290,6 +319,7 @@ impl ColumnRef {
                 generated_expression: None,
                 generated_stored: false,
                 collate: None,
+                comment: None,
             },
         }
     }
@@ -389,6 +419,47 @@ pub fn write_quoted_ident(buf: &mut impl core::fmt::Write, name: &str) {
     let _ = buf.write_char('"');
 }
 
+/// Byte length of [`write_quoted_ident`]'s output for `name`.
+#[inline]
+#[must_use]
+pub fn quoted_ident_len(name: &str) -> usize {
+    let escapes = if name.contains('"') {
+        name.bytes().filter(|&b| b == b'"').count()
+    } else {
+        0
+    };
+    name.len() + escapes + 2
+}
+
+/// Writes an unsigned integer in decimal without going through `core::fmt`.
+#[inline]
+pub fn write_usize(buf: &mut impl core::fmt::Write, mut value: usize) {
+    let mut digits = [0u8; 20];
+    let mut pos = digits.len();
+    loop {
+        pos -= 1;
+        digits[pos] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    // Every byte written above is an ASCII digit, so this never fails.
+    if let Ok(text) = core::str::from_utf8(&digits[pos..]) {
+        let _ = buf.write_str(text);
+    }
+}
+
+/// Number of decimal digits [`write_usize`] emits for `value`.
+#[inline]
+#[must_use]
+pub const fn usize_len(value: usize) -> usize {
+    match value.checked_ilog10() {
+        Some(log) => log as usize + 1,
+        None => 1,
+    }
+}
+
 // ==================== SQLChunk ====================
 
 /// A SQL chunk represents a part of an SQL statement.
@@ -396,6 +467,7 @@ pub fn write_quoted_ident(buf: &mut impl core::fmt::Write, name: &str) {
 /// Each variant has a clear semantic purpose:
 /// - `Token` - SQL keywords and operators (SELECT, FROM, =, etc.)
 /// - `Ident` - Quoted identifiers ("`table_name`", "`column_name`")
+/// - `Name` - Quoted schema identifiers with `'static` lifetime
 /// - `Raw` - Unquoted raw SQL text (function names, expressions)
 /// - `Param` - Parameter placeholders with values
 /// - `Table` - Table reference via `TableSqlRef`
@@ -411,6 +483,12 @@ pub enum SQLChunk<'a, V: SQLParam> {
     /// Use for: table names, column names, alias names
     Ident(Cow<'a, str>),
 
+    /// Quoted identifier generated from schema metadata.
+    /// Renders as: "name" (with quotes)
+    /// Use for: unqualified table/column names from `TableRef`/`ColumnRef`,
+    /// so builders, prepared statements and seed never copy them to the heap.
+    Name(&'static str),
+
     /// Raw SQL text (unquoted) for expressions, function names
     /// Renders as: text (no quotes, as-is)
     /// Use for: function names like COUNT, expressions, numeric literals
@@ -453,6 +531,13 @@ impl<'a, V: SQLParam> SQLChunk<'a, V> {
         Self::Ident(Cow::Borrowed(name))
     }
 
+    /// Creates a quoted schema identifier - const
+    #[inline]
+    #[must_use]
+    pub const fn name(name: &'static str) -> Self {
+        Self::Name(name)
+    }
+
     /// Creates raw SQL text from a static string - const
     #[inline]
     #[must_use]
@@ -525,16 +610,23 @@ impl<'a, V: SQLParam> SQLChunk<'a, V> {
             SQLChunk::Ident(name) => {
                 write_quoted_ident(buf, name);
             }
+            SQLChunk::Name(name) => {
+                write_quoted_ident(buf, name);
+            }
             SQLChunk::Raw(text) => {
                 let _ = buf.write_str(text);
             }
             SQLChunk::Number(value) => {
-                let _ = write!(buf, "{value}");
+                write_usize(buf, *value);
             }
             SQLChunk::Param(Param { placeholder, .. }) => {
                 let _ = write!(buf, "{placeholder}");
             }
             SQLChunk::Table(t) => {
+                if let Some(database) = t.database {
+                    write_quoted_ident(buf, database);
+                    let _ = buf.write_char('.');
+                }
                 write_quoted_ident(buf, t.name);
             }
             SQLChunk::Column(c) => {
@@ -551,6 +643,7 @@ impl<'a, V: SQLParam> SQLChunk<'a, V> {
         match self {
             SQLChunk::Token(t) => !t.is_punctuation() && !t.is_operator(),
             SQLChunk::Ident(_)
+            | SQLChunk::Name(_)
             | SQLChunk::Raw(_)
             | SQLChunk::Number(_)
             | SQLChunk::Param(_)
@@ -565,6 +658,7 @@ impl<V: SQLParam + core::fmt::Debug> core::fmt::Debug for SQLChunk<'_, V> {
         match self {
             SQLChunk::Token(token) => f.debug_tuple("Token").field(token).finish(),
             SQLChunk::Ident(name) => f.debug_tuple("Ident").field(name).finish(),
+            SQLChunk::Name(name) => f.debug_tuple("Name").field(name).finish(),
             SQLChunk::Raw(text) => f.debug_tuple("Raw").field(text).finish(),
             SQLChunk::Number(value) => f.debug_tuple("Number").field(value).finish(),
             SQLChunk::Param(param) => f.debug_tuple("Param").field(param).finish(),
@@ -627,4 +721,33 @@ mod tests {
         // Param is the dominant variant for this 32-byte test parameter.
         assert!(size_of::<SQLChunk<'static, TestParam>>() <= 64);
     }
+
+    #[test]
+    fn usize_rendering_matches_len() {
+        for value in [0, 7, 10, 99, 1_000, usize::MAX] {
+            let mut buf = String::new();
+            write_usize(&mut buf, value);
+            assert_eq!(buf, value.to_string());
+            assert_eq!(buf.len(), usize_len(value));
+        }
+    }
+
+    #[test]
+    fn quoted_ident_len_counts_escapes() {
+        for name in ["users", "we\"ird", "\"\""] {
+            let mut buf = String::new();
+            write_quoted_ident(&mut buf, name);
+            assert_eq!(buf.len(), quoted_ident_len(name));
+        }
+    }
+
+    #[test]
+    fn name_chunks_stay_borrowed_when_owned() {
+        let owned: crate::OwnedSQLChunk<TestParam> = SQLChunk::name("users").into();
+        assert!(matches!(owned, crate::OwnedSQLChunk::Name("users")));
+
+        let mut buf = String::new();
+        SQLChunk::<TestParam>::name("users").write(&mut buf);
+        assert_eq!(buf, "\"users\"");
+    }
 }
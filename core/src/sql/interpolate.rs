@@ -0,0 +1,115 @@
+//! Dispatch for `{expr}` interpolation in the `sql!` macro.
+//!
+//! `sql!` expands `{expr}` to `(&&&Interpolate(&expr)).interpolate_sql()` and
+//! method resolution picks the first impl whose bounds hold:
+//!
+//! 1. Values with a `SQLite` mapping (`Option` included) → bound parameter.
+//! 2. Values with a `PostgreSQL` mapping (`Option` included) → bound parameter.
+//! 3. Anything else implementing `ToSQL` → spliced as before, with a
+//!    deprecation warning pointing at `{#expr}` / `{!expr}`.
+//!
+//! The value checks only look at the Rust type, so they resolve before the
+//! dialect value type is known.
+
+use crate::bind::ValueTypeForDialect;
+use crate::dialect::{PostgresDialect, SQLiteDialect};
+use crate::prelude::*;
+use crate::sql::SQL;
+use crate::traits::{SQLParam, ToSQL};
+
+/// Borrowed `sql!` interpolation argument.
+#[doc(hidden)]
+pub struct Interpolate<'r, T>(pub &'r T);
+
+/// Binds values that have a `SQLite` mapping.
+#[doc(hidden)]
+pub trait InterpolateSQLiteValue {
+    type Value: Clone;
+
+    fn value(&self) -> &Self::Value;
+
+    fn interpolate_sql<'a, V>(&self) -> SQL<'a, V>
+    where
+        V: SQLParam + From<Self::Value> + Into<Cow<'a, V>>,
+    {
+        SQL::param_from(self.value().clone())
+    }
+}
+
+impl<T> InterpolateSQLiteValue for &&Interpolate<'_, T>
+where
+    T: ValueTypeForDialect<SQLiteDialect> + Clone,
+{
+    type Value = T;
+
+    fn value(&self) -> &T {
+        self.0
+    }
+}
+
+impl<T> InterpolateSQLiteValue for &&Interpolate<'_, Option<T>>
+where
+    T: ValueTypeForDialect<SQLiteDialect> + Clone,
+{
+    type Value = Option<T>;
+
+    fn value(&self) -> &Option<T> {
+        self.0
+    }
+}
+
+/// Binds values that only have a `PostgreSQL` mapping.
+#[doc(hidden)]
+pub trait InterpolatePostgresValue {
+    type Value: Clone;
+
+    fn value(&self) -> &Self::Value;
+
+    fn interpolate_sql<'a, V>(&self) -> SQL<'a, V>
+    where
+        V: SQLParam + From<Self::Value> + Into<Cow<'a, V>>,
+    {
+        SQL::param_from(self.value().clone())
+    }
+}
+
+impl<T> InterpolatePostgresValue for &Interpolate<'_, T>
+where
+    T: ValueTypeForDialect<PostgresDialect> + Clone,
+{
+    type Value = T;
+
+    fn value(&self) -> &T {
+        self.0
+    }
+}
+
+impl<T> InterpolatePostgresValue for &Interpolate<'_, Option<T>>
+where
+    T: ValueTypeForDialect<PostgresDialect> + Clone,
+{
+    type Value = Option<T>;
+
+    fn value(&self) -> &Option<T> {
+        self.0
+    }
+}
+
+/// Legacy fallback: splices any `ToSQL` value.
+#[doc(hidden)]
+pub trait InterpolateSplice<'a, V: SQLParam> {
+    #[deprecated(
+        note = "`{expr}` in sql!() binds values only; use `{#expr}` to splice tables, columns and expressions, or `{!expr}` for raw SQL text"
+    )]
+    fn interpolate_sql(&self) -> SQL<'a, V>;
+}
+
+impl<'a, V, T> InterpolateSplice<'a, V> for Interpolate<'_, T>
+where
+    V: SQLParam,
+    T: ToSQL<'a, V>,
+{
+    fn interpolate_sql(&self) -> SQL<'a, V> {
+        self.0.to_sql()
+    }
+}
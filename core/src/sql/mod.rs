@@ -1,8 +1,11 @@
 mod chunk;
 mod comment;
 mod cte;
+#[doc(hidden)]
+pub mod interpolate;
 mod owned;
 mod tokens;
+mod typed;
 
 use crate::prelude::*;
 use crate::{
@@ -16,6 +19,7 @@ use core::fmt::{Display, Write};
 pub use owned::*;
 use smallvec::SmallVec;
 pub use tokens::*;
+pub use typed::TypedSQL;
 
 #[cfg(feature = "profiling")]
 use crate::profile_sql;
@@ -71,6 +75,18 @@ impl<'a, V: SQLParam> SQL<'a, V> {
         }
     }
 
+    /// Creates SQL with a quoted schema identifier that is never copied.
+    ///
+    /// Prefer this over [`ident`](Self::ident) for table and column names
+    /// taken from generated metadata.
+    #[inline]
+    #[must_use]
+    pub fn name(name: &'static str) -> Self {
+        Self {
+            chunks: smallvec::smallvec![SQLChunk::Name(name)],
+        }
+    }
+
     /// Creates SQL with raw text (unquoted)
     #[inline]
     pub fn raw(text: impl Into<Cow<'a, str>>) -> Self {
@@ -99,6 +115,17 @@ impl<'a, V: SQLParam> SQL<'a, V> {
         }
     }
 
+    /// Creates SQL with a single parameter converted into the dialect value type.
+    ///
+    /// Used by `sql!("... {value:param}")` to force a bound parameter.
+    #[inline]
+    pub fn param_from<T>(value: T) -> Self
+    where
+        V: From<T> + Into<Cow<'a, V>>,
+    {
+        Self::param(V::from(value))
+    }
+
     /// Creates SQL with a binary parameter value (BLOB/bytea)
     ///
     /// Prefer this over `SQL::param(Vec<u8>)` to avoid list semantics.
@@ -201,6 +228,15 @@ impl<'a, V: SQLParam> SQL<'a, V> {
         self
     }
 
+    /// Reserves capacity for at least `additional` more chunks in place.
+    ///
+    /// Use when the final size of a fragment is known up front (e.g. one
+    /// param per column per row) to avoid repeated reallocation while pushing.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.chunks.reserve(additional);
+    }
+
     // ==================== combinators ====================
 
     /// Joins multiple SQL fragments with a separator
@@ -310,7 +346,7 @@ impl<'a, V: SQLParam> SQL<'a, V> {
             if i > 0 {
                 chunks.push(SQLChunk::Token(Token::COMMA));
             }
-            chunks.push(SQLChunk::Ident(Cow::Borrowed(col)));
+            chunks.push(SQLChunk::Name(col));
             chunks.push(SQLChunk::Token(Token::EQ));
             chunks.push(SQLChunk::Param(Param {
                 value: Some(val.into()),
@@ -338,7 +374,7 @@ impl<'a, V: SQLParam> SQL<'a, V> {
             if i > 0 {
                 chunks.push(SQLChunk::Token(Token::COMMA));
             }
-            chunks.push(SQLChunk::Ident(Cow::Borrowed(col)));
+            chunks.push(SQLChunk::Name(col));
             chunks.push(SQLChunk::Token(Token::EQ));
             chunks.extend(sql.chunks);
         }
@@ -359,6 +395,7 @@ impl<'a, V: SQLParam> SQL<'a, V> {
             .map(|chunk| match chunk {
                 SQLChunk::Token(t) => SQLChunk::Token(t),
                 SQLChunk::Ident(s) => SQLChunk::Ident(s),
+                SQLChunk::Name(s) => SQLChunk::Name(s),
                 SQLChunk::Raw(s) => SQLChunk::Raw(s),
                 SQLChunk::Number(n) => SQLChunk::Number(n),
                 SQLChunk::Param(param) => SQLChunk::Param(Param::new(
@@ -385,8 +422,7 @@ impl<'a, V: SQLParam> SQL<'a, V> {
         profile_sql!("sql");
         #[cfg(feature = "profiling")]
         crate::drizzle_profile_scope!("sql_render", "sql.estimate");
-        let (sql_cap, _) = self.render_capacity_estimate();
-        let mut buf = String::with_capacity(sql_cap);
+        let mut buf = String::with_capacity(self.rendered_len());
         self.write_to(&mut buf);
         buf
     }
@@ -410,7 +446,7 @@ impl<'a, V: SQLParam> SQL<'a, V> {
         crate::drizzle_profile_scope!("sql_render", "build");
         #[cfg(feature = "profiling")]
         crate::drizzle_profile_scope!("sql_render", "build.estimate");
-        let (sql_cap, param_cap) = self.render_capacity_estimate();
+        let (sql_cap, param_cap) = self.render_size(style);
         let mut buf = String::with_capacity(sql_cap);
         let mut params: SmallVec<[&V; 8]> = SmallVec::with_capacity(param_cap);
         let mut param_index = 1usize;
@@ -445,6 +481,7 @@ impl<'a, V: SQLParam> SQL<'a, V> {
             }
         }
 
+        debug_assert_eq!(buf.len(), sql_cap, "render_size drifted from build_with");
         (buf, params)
     }
 
@@ -560,25 +597,78 @@ impl<'a, V: SQLParam> SQL<'a, V> {
         chunk_needs_space(current, next)
     }
 
+    /// Exact byte length of the SQL text [`build`](Self::build) produces.
+    ///
+    /// Rendering runs this pre-pass so the output `String` is allocated once
+    /// at its final size, which matters for multi-thousand-row `VALUES` lists.
     #[inline]
-    fn render_capacity_estimate(&self) -> (usize, usize) {
-        let mut sql_cap = 0usize;
-        let mut param_cap = 0usize;
+    #[must_use]
+    pub fn rendered_len(&self) -> usize {
+        self.render_size(crate::dialect::ParamStyle::for_dialect(V::DIALECT))
+            .0
+    }
+
+    /// Returns `(sql_len, param_count)` for rendering with `style`.
+    fn render_size(&self, style: crate::dialect::ParamStyle) -> (usize, usize) {
+        use crate::dialect::Dialect;
 
-        for chunk in &self.chunks {
-            sql_cap = sql_cap.saturating_add(match chunk {
-                SQLChunk::Ident(_) | SQLChunk::Raw(_) => 20,
-                SQLChunk::Column(_) => 30,
-                SQLChunk::Table(_) => 15,
-                SQLChunk::Token(_) => 8,
-                SQLChunk::Number(_) | SQLChunk::Param(_) => 4,
-            });
-            if matches!(chunk, SQLChunk::Param(_)) {
-                param_cap = param_cap.saturating_add(1);
+        let mut sql_len = 0usize;
+        let mut param_index = 1usize;
+
+        for (i, chunk) in self.chunks.iter().enumerate() {
+            sql_len += match chunk {
+                SQLChunk::Token(Token::SELECT) => {
+                    Token::SELECT.as_str().len() + self.select_columns_len(i)
+                }
+                SQLChunk::Token(token) => token.as_str().len(),
+                SQLChunk::Ident(name) => chunk::quoted_ident_len(name),
+                SQLChunk::Name(name) => chunk::quoted_ident_len(name),
+                SQLChunk::Raw(text) => text.len(),
+                SQLChunk::Number(value) => chunk::usize_len(*value),
+                SQLChunk::Param(param) => {
+                    let len = match param.placeholder.name {
+                        Some(name) if V::DIALECT == Dialect::SQLite => name.len() + 1,
+                        _ => style.rendered_len(param_index),
+                    };
+                    param_index += 1;
+                    len
+                }
+                SQLChunk::Table(t) => {
+                    t.database
+                        .map_or(0, |database| chunk::quoted_ident_len(database) + 1)
+                        + chunk::quoted_ident_len(t.name)
+                }
+                SQLChunk::Column(c) => {
+                    chunk::quoted_ident_len(c.table) + chunk::quoted_ident_len(c.name) + 1
+                }
+            };
+            if self.needs_space(i) {
+                sql_len += 1;
             }
         }
 
-        (sql_cap.max(128), param_cap)
+        (sql_len, param_index - 1)
+    }
+
+    /// Byte length of the columns [`write_select_columns`](Self::write_select_columns)
+    /// expands after the `SELECT` at `select_index`.
+    fn select_columns_len(&self, select_index: usize) -> usize {
+        match self.chunks.get(select_index + 1..select_index + 3) {
+            Some([SQLChunk::Token(Token::FROM), SQLChunk::Table(table)]) => {
+                if table.column_names.is_empty() {
+                    return 2;
+                }
+                let qualifier = chunk::quoted_ident_len(table.name) + 1;
+                let columns: usize = table
+                    .column_names
+                    .iter()
+                    .map(|name| qualifier + chunk::quoted_ident_len(name))
+                    .sum();
+                1 + columns + (table.column_names.len() - 1) * 2
+            }
+            Some([SQLChunk::Token(Token::FROM), _]) => 1 + Token::STAR.as_str().len(),
+            _ => 0,
+        }
     }
 
     /// Returns an iterator over references to parameter values
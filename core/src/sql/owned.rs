@@ -8,6 +8,7 @@ use smallvec::SmallVec;
 pub enum OwnedSQLChunk<V: SQLParam> {
     Token(Token),
     Ident(Box<str>),
+    Name(&'static str),
     Raw(Box<str>),
     Number(usize),
     Param(OwnedParam<V>),
@@ -61,6 +62,7 @@ impl<'a, V: SQLParam> From<SQLChunk<'a, V>> for OwnedSQLChunk<V> {
         match value {
             SQLChunk::Token(token) => Self::Token(token),
             SQLChunk::Ident(cow) => Self::Ident(cow.into_owned().into_boxed_str()),
+            SQLChunk::Name(name) => Self::Name(name),
             SQLChunk::Raw(cow) => Self::Raw(cow.into_owned().into_boxed_str()),
             SQLChunk::Number(value) => Self::Number(value),
             SQLChunk::Param(param) => Self::Param(param.into()),
@@ -75,6 +77,7 @@ impl<V: SQLParam> From<OwnedSQLChunk<V>> for SQLChunk<'static, V> {
         match value {
             OwnedSQLChunk::Token(token) => SQLChunk::Token(token),
             OwnedSQLChunk::Ident(s) => SQLChunk::Ident(Cow::Owned(String::from(s))),
+            OwnedSQLChunk::Name(name) => SQLChunk::Name(name),
             OwnedSQLChunk::Raw(s) => SQLChunk::Raw(Cow::Owned(String::from(s))),
             OwnedSQLChunk::Number(value) => SQLChunk::Number(value),
             OwnedSQLChunk::Param(param) => SQLChunk::Param(param.into()),
@@ -0,0 +1,45 @@
+//! Raw SQL fragments annotated with the row type they decode into.
+
+use crate::sql::SQL;
+use crate::traits::{SQLParam, ToSQL};
+use core::marker::PhantomData;
+
+/// Raw SQL fragment that declares its output row type.
+///
+/// Produced by `sql!("...", as R)`. Passing it to `.select()` resolves the
+/// query's row type to `R`, so strict `.all()` / `.get()` infer it without a
+/// `FromRow` target.
+#[derive(Debug, Clone)]
+pub struct TypedSQL<'a, V: SQLParam, R> {
+    sql: SQL<'a, V>,
+    row: PhantomData<fn() -> R>,
+}
+
+impl<'a, V: SQLParam, R> TypedSQL<'a, V, R> {
+    /// Annotates `sql` with the row type `R`.
+    #[inline]
+    #[must_use]
+    pub const fn new(sql: SQL<'a, V>) -> Self {
+        Self {
+            sql,
+            row: PhantomData,
+        }
+    }
+
+    /// Drops the row annotation and returns the underlying fragment.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> SQL<'a, V> {
+        self.sql
+    }
+}
+
+impl<'a, V: SQLParam + 'a, R> ToSQL<'a, V> for TypedSQL<'a, V, R> {
+    fn to_sql(&self) -> SQL<'a, V> {
+        self.sql.clone()
+    }
+
+    fn into_sql(self) -> SQL<'a, V> {
+        self.sql
+    }
+}
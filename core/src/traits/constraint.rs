@@ -7,6 +7,7 @@ pub enum SQLConstraintKind {
     ForeignKey,
     Unique,
     Check,
+    Exclude,
 }
 
 /// Typed (non-dyn) constraint metadata.
@@ -66,8 +67,18 @@ pub trait ColumnNotNull {}
 
 pub trait ColumnValueType {
     type ValueType;
+    /// SQL type marker of the column (e.g. `Integer`, `Text`), without nullability.
+    type SQLType;
 }
 
+#[diagnostic::on_unimplemented(
+    message = "foreign key SQL type mismatch: a `{Self}` column cannot reference a `{T}` column",
+    label = "this column's SQL type differs from the referenced column",
+    note = "change the column type attribute (e.g. `#[column(integer)]`) to match the referenced column"
+)]
+pub trait SQLTypeEq<T> {}
+impl<T> SQLTypeEq<T> for T {}
+
 #[diagnostic::on_unimplemented(
     message = "one or more columns do not belong to table `{Table}`",
     label = "these columns are not all defined on the same table",
@@ -149,6 +160,7 @@ macro_rules! impl_fk_type_match_cb {
                 $S: ColumnValueType,
                 $D: ColumnValueType,
                 <$S as ColumnValueType>::ValueType: TypeEq<<$D as ColumnValueType>::ValueType>,
+                <$S as ColumnValueType>::SQLType: SQLTypeEq<<$D as ColumnValueType>::SQLType>,
             )+
         {}
     };
@@ -16,6 +16,18 @@ pub trait DrizzleIndex: Send + Sync + 'static {
     /// Whether this is a unique index.
     const IS_UNIQUE: bool = false;
 
+    /// Per-column collation overrides, parallel to [`Self::COLUMN_NAMES`].
+    const COLUMN_COLLATIONS: &'static [Option<&'static str>] = &[];
+
+    /// Index access method, when not the dialect's default (e.g. `hnsw`).
+    const METHOD: Option<&'static str> = None;
+
+    /// Per-column operator classes, parallel to [`Self::COLUMN_NAMES`].
+    const COLUMN_OPCLASSES: &'static [Option<&'static str>] = &[];
+
+    /// Storage parameters for the index's `WITH (...)` clause.
+    const STORAGE_PARAMETERS: Option<&'static str> = None;
+
     /// The table this index belongs to.
     fn table_ref() -> &'static TableRef;
 }
@@ -37,6 +49,22 @@ impl<T: DrizzleIndex> SQLIndexInfo for T {
     fn is_unique(&self) -> bool {
         T::IS_UNIQUE
     }
+
+    fn column_collations(&self) -> &'static [Option<&'static str>] {
+        T::COLUMN_COLLATIONS
+    }
+
+    fn method(&self) -> Option<&'static str> {
+        T::METHOD
+    }
+
+    fn column_opclasses(&self) -> &'static [Option<&'static str>] {
+        T::COLUMN_OPCLASSES
+    }
+
+    fn storage_parameters(&self) -> Option<&'static str> {
+        T::STORAGE_PARAMETERS
+    }
 }
 
 pub trait SQLIndexInfo: Any + Send + Sync {
@@ -51,6 +79,29 @@ pub trait SQLIndexInfo: Any + Send + Sync {
     fn is_unique(&self) -> bool {
         false
     }
+
+    /// Per-column collation overrides, parallel to [`Self::columns`].
+    ///
+    /// Shorter than `columns()` (or empty) when trailing members use the
+    /// column's own collation.
+    fn column_collations(&self) -> &'static [Option<&'static str>] {
+        &[]
+    }
+
+    /// Index access method, when not the dialect's default.
+    fn method(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Per-column operator classes, parallel to [`Self::columns`].
+    fn column_opclasses(&self) -> &'static [Option<&'static str>] {
+        &[]
+    }
+
+    /// Storage parameters for the index's `WITH (...)` clause.
+    fn storage_parameters(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 impl core::fmt::Debug for dyn SQLIndexInfo {
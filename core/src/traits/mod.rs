@@ -77,8 +77,12 @@ pub trait SchemaItemTables {
 )]
 pub trait SQLSchemaType: core::fmt::Debug + Any + Send + Sync {}
 
-/// Trait for schema implementations that can generate CREATE statements.
+/// Trait for schema implementations that can generate CREATE and DROP statements.
 pub trait SQLSchemaImpl: Any + Send + Sync {
+    /// Stable hash of every item's name and `CREATE` statement, independent
+    /// of field order. Changes whenever the schema's DDL does.
+    const SCHEMA_HASH: u64;
+
     fn table_refs(&self) -> &'static [&'static TableRef];
     /// Generate CREATE statements for every table in this schema.
     ///
@@ -86,4 +90,11 @@ pub trait SQLSchemaImpl: Any + Send + Sync {
     ///
     /// Returns an error if SQL generation for any table fails.
     fn create_statements(&self) -> crate::error::Result<impl Iterator<Item = String>>;
+    /// Generate DROP statements for every view, index and table in this
+    /// schema, dependents before the objects they depend on.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tables reference each other in a cycle.
+    fn drop_statements(&self) -> crate::error::Result<impl Iterator<Item = String>>;
 }
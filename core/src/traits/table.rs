@@ -151,6 +151,29 @@ pub trait DrizzleTable: Send + Sync + 'static {
     const TABLE_REF: TableRef;
 }
 
+/// One table or a tuple of tables, as taken by `db.truncate(..)`.
+pub trait TableSet {
+    /// Metadata of each table, in the order given.
+    fn table_refs(&self) -> Vec<&'static TableRef>;
+}
+
+impl<T: DrizzleTable> TableSet for T {
+    fn table_refs(&self) -> Vec<&'static TableRef> {
+        vec![&T::TABLE_REF]
+    }
+}
+
+macro_rules! impl_table_set_cb {
+    ($($T:ident),+) => {
+        impl<$($T: DrizzleTable),+> TableSet for ($($T,)+) {
+            fn table_refs(&self) -> Vec<&'static TableRef> {
+                vec![$(&$T::TABLE_REF),+]
+            }
+        }
+    };
+}
+with_tuple_sizes!(impl_table_set_cb);
+
 /// Blanket: any `DrizzleTable` automatically satisfies `SQLTableInfo`.
 impl<T: DrizzleTable> SQLTableInfo for T {
     fn name(&self) -> &'static str {
@@ -0,0 +1,129 @@
+//! Binary encodings for `Vec<f32>` embedding vectors.
+//!
+//! Both dialects store embeddings as packed `f32` components, but in
+//! different layouts:
+//!
+//! - [`encode_pgvector`] / [`decode_pgvector`] use pgvector's binary
+//!   send/receive format for the Postgres `vector` type: a big-endian `i16`
+//!   dimension count, an unused `i16`, then big-endian `f32` components.
+//! - [`encode_blob`] / [`decode_blob`] use the sqlite-vec BLOB format: the
+//!   little-endian `f32` components with no header.
+//!
+//! # Example
+//!
+//! ```
+//! use drizzle_core::vector;
+//!
+//! let embedding = vec![0.25_f32, -1.0, 3.5];
+//! let bytes = vector::encode_pgvector(&embedding);
+//! assert_eq!(vector::decode_pgvector(&bytes).unwrap(), embedding);
+//!
+//! let blob = vector::encode_blob(&embedding);
+//! assert_eq!(blob.len(), 12);
+//! assert_eq!(vector::decode_blob(&blob).unwrap(), embedding);
+//! ```
+
+use crate::error::DrizzleError;
+use crate::prelude::{Vec, format};
+
+/// Encodes `vector` in pgvector's binary send format.
+///
+/// # Panics
+///
+/// Panics if `vector` has more than `i16::MAX` components, which pgvector
+/// cannot represent.
+#[must_use]
+pub fn encode_pgvector(vector: &[f32]) -> Vec<u8> {
+    let dim = i16::try_from(vector.len()).expect("pgvector supports at most 32767 dimensions");
+    let mut out = Vec::with_capacity(4 + vector.len() * 4);
+    out.extend_from_slice(&dim.to_be_bytes());
+    out.extend_from_slice(&0_i16.to_be_bytes());
+    for component in vector {
+        out.extend_from_slice(&component.to_be_bytes());
+    }
+    out
+}
+
+/// Decodes pgvector's binary receive format.
+///
+/// # Errors
+///
+/// Returns [`DrizzleError::ConversionError`] when the header is missing or
+/// the payload length does not match the encoded dimension count.
+pub fn decode_pgvector(bytes: &[u8]) -> Result<Vec<f32>, DrizzleError> {
+    let Some((header, body)) = bytes.split_first_chunk::<4>() else {
+        return Err(vector_error("missing pgvector header"));
+    };
+    let dim = i16::from_be_bytes([header[0], header[1]]);
+    let dim = usize::try_from(dim).map_err(|_| vector_error("negative dimension count"))?;
+    if body.len() != dim * 4 {
+        return Err(vector_error(format!(
+            "expected {dim} components, got {} bytes",
+            body.len()
+        )));
+    }
+    Ok(body
+        .chunks_exact(4)
+        .map(|chunk| f32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect())
+}
+
+/// Encodes `vector` as a sqlite-vec `float32` BLOB.
+#[must_use]
+pub fn encode_blob(vector: &[f32]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(vector.len() * 4);
+    for component in vector {
+        out.extend_from_slice(&component.to_le_bytes());
+    }
+    out
+}
+
+/// Decodes a sqlite-vec `float32` BLOB.
+///
+/// # Errors
+///
+/// Returns [`DrizzleError::ConversionError`] when the BLOB length is not a
+/// multiple of four bytes.
+pub fn decode_blob(bytes: &[u8]) -> Result<Vec<f32>, DrizzleError> {
+    if !bytes.len().is_multiple_of(4) {
+        return Err(vector_error(format!(
+            "BLOB length {} is not a multiple of 4",
+            bytes.len()
+        )));
+    }
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect())
+}
+
+fn vector_error(message: impl core::fmt::Display) -> DrizzleError {
+    DrizzleError::ConversionError(format!("invalid vector: {message}").into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pgvector_round_trip() {
+        let bytes = encode_pgvector(&[1.0, 2.5]);
+        assert_eq!(
+            bytes,
+            [0, 2, 0, 0, 0x3f, 0x80, 0, 0, 0x40, 0x20, 0, 0].to_vec()
+        );
+        assert_eq!(decode_pgvector(&bytes).unwrap(), [1.0, 2.5].to_vec());
+    }
+
+    #[test]
+    fn pgvector_rejects_truncated_payload() {
+        assert!(decode_pgvector(&[0, 2, 0, 0, 0x3f, 0x80]).is_err());
+        assert!(decode_pgvector(&[0]).is_err());
+    }
+
+    #[test]
+    fn blob_rejects_partial_component() {
+        assert!(decode_blob(&[0, 0, 0x80]).is_err());
+        assert_eq!(decode_blob(&[]).unwrap(), Vec::<f32>::new());
+    }
+}
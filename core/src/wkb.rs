@@ -0,0 +1,412 @@
+//! Well-known binary (WKB) encoding for `geo-types` geometries.
+//!
+//! PostGIS `geometry` / `geography` columns and `SQLite` geometry BLOBs both
+//! store shapes as WKB. The codec here is shared by both dialects:
+//!
+//! - [`encode`] writes little-endian WKB, switching to the PostGIS extended
+//!   form (EWKB) when an SRID is given.
+//! - [`decode`] reads WKB or EWKB in either byte order and returns the SRID
+//!   when one is embedded.
+//!
+//! Only 2D geometries are supported; Z/M coordinates are rejected on decode.
+//!
+//! # Example
+//!
+//! ```
+//! use drizzle_core::wkb;
+//! use geo_types::{Geometry, Point};
+//!
+//! let point = Geometry::Point(Point::new(-71.06, 42.36));
+//! let bytes = wkb::encode(&point, Some(4326));
+//! assert_eq!(wkb::decode(&bytes).unwrap(), (point, Some(4326)));
+//! ```
+
+use crate::error::DrizzleError;
+use crate::prelude::{Vec, format};
+use geo_types::{
+    Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOINT: u32 = 4;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+const WKB_GEOMETRYCOLLECTION: u32 = 7;
+
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// Encodes `geometry` as little-endian WKB.
+///
+/// When `srid` is set the output is PostGIS EWKB with the SRID embedded in
+/// the outer geometry header. `Line`, `Rect` and `Triangle` are written as
+/// their `LineString` / `Polygon` equivalents.
+#[must_use]
+pub fn encode(geometry: &Geometry<f64>, srid: Option<i32>) -> Vec<u8> {
+    let mut out = Vec::with_capacity(32);
+    write_geometry(&mut out, geometry, srid);
+    out
+}
+
+/// Decodes WKB or EWKB bytes into a geometry and its embedded SRID.
+///
+/// # Errors
+///
+/// Returns [`DrizzleError::ConversionError`] when the input is truncated,
+/// uses an unknown geometry type, carries Z/M coordinates, or has trailing
+/// bytes.
+pub fn decode(bytes: &[u8]) -> Result<(Geometry<f64>, Option<i32>), DrizzleError> {
+    let mut reader = Reader { bytes, pos: 0 };
+    let (geometry, srid) = reader.geometry()?;
+    if reader.pos != bytes.len() {
+        return Err(wkb_error(format!(
+            "{} trailing bytes after geometry",
+            bytes.len() - reader.pos
+        )));
+    }
+    Ok((geometry, srid))
+}
+
+fn wkb_error(message: impl core::fmt::Display) -> DrizzleError {
+    DrizzleError::ConversionError(format!("invalid WKB: {message}").into())
+}
+
+// =============================================================================
+// Encoding
+// =============================================================================
+
+fn write_header(out: &mut Vec<u8>, kind: u32, srid: Option<i32>) {
+    out.push(1);
+    match srid {
+        Some(srid) => {
+            out.extend_from_slice(&(kind | EWKB_SRID_FLAG).to_le_bytes());
+            out.extend_from_slice(&srid.to_le_bytes());
+        }
+        None => out.extend_from_slice(&kind.to_le_bytes()),
+    }
+}
+
+fn write_len(out: &mut Vec<u8>, len: usize) {
+    let len = u32::try_from(len).unwrap_or(u32::MAX);
+    out.extend_from_slice(&len.to_le_bytes());
+}
+
+fn write_coord(out: &mut Vec<u8>, coord: Coord<f64>) {
+    out.extend_from_slice(&coord.x.to_le_bytes());
+    out.extend_from_slice(&coord.y.to_le_bytes());
+}
+
+fn write_coords(out: &mut Vec<u8>, line: &LineString<f64>) {
+    write_len(out, line.0.len());
+    for coord in &line.0 {
+        write_coord(out, *coord);
+    }
+}
+
+fn write_polygon_rings(out: &mut Vec<u8>, polygon: &Polygon<f64>) {
+    if polygon.exterior().0.is_empty() {
+        write_len(out, 0);
+        return;
+    }
+    write_len(out, polygon.interiors().len() + 1);
+    write_coords(out, polygon.exterior());
+    for ring in polygon.interiors() {
+        write_coords(out, ring);
+    }
+}
+
+fn write_geometry(out: &mut Vec<u8>, geometry: &Geometry<f64>, srid: Option<i32>) {
+    match geometry {
+        Geometry::Point(point) => {
+            write_header(out, WKB_POINT, srid);
+            write_coord(out, point.0);
+        }
+        Geometry::Line(line) => {
+            write_header(out, WKB_LINESTRING, srid);
+            write_coords(out, &LineString::from(*line));
+        }
+        Geometry::LineString(line) => {
+            write_header(out, WKB_LINESTRING, srid);
+            write_coords(out, line);
+        }
+        Geometry::Polygon(polygon) => {
+            write_header(out, WKB_POLYGON, srid);
+            write_polygon_rings(out, polygon);
+        }
+        Geometry::Rect(rect) => {
+            write_header(out, WKB_POLYGON, srid);
+            write_polygon_rings(out, &rect.to_polygon());
+        }
+        Geometry::Triangle(triangle) => {
+            write_header(out, WKB_POLYGON, srid);
+            write_polygon_rings(out, &triangle.to_polygon());
+        }
+        Geometry::MultiPoint(points) => {
+            write_header(out, WKB_MULTIPOINT, srid);
+            write_len(out, points.0.len());
+            for point in &points.0 {
+                write_geometry(out, &Geometry::Point(*point), None);
+            }
+        }
+        Geometry::MultiLineString(lines) => {
+            write_header(out, WKB_MULTILINESTRING, srid);
+            write_len(out, lines.0.len());
+            for line in &lines.0 {
+                write_header(out, WKB_LINESTRING, None);
+                write_coords(out, line);
+            }
+        }
+        Geometry::MultiPolygon(polygons) => {
+            write_header(out, WKB_MULTIPOLYGON, srid);
+            write_len(out, polygons.0.len());
+            for polygon in &polygons.0 {
+                write_header(out, WKB_POLYGON, None);
+                write_polygon_rings(out, polygon);
+            }
+        }
+        Geometry::GeometryCollection(collection) => {
+            write_header(out, WKB_GEOMETRYCOLLECTION, srid);
+            write_len(out, collection.0.len());
+            for member in &collection.0 {
+                write_geometry(out, member, None);
+            }
+        }
+    }
+}
+
+// =============================================================================
+// Decoding
+// =============================================================================
+
+struct Reader<'b> {
+    bytes: &'b [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], DrizzleError> {
+        let end = self.pos + N;
+        let chunk = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or_else(|| wkb_error("unexpected end of input"))?;
+        self.pos = end;
+        let mut buf = [0u8; N];
+        buf.copy_from_slice(chunk);
+        Ok(buf)
+    }
+
+    fn u32(&mut self, little: bool) -> Result<u32, DrizzleError> {
+        let buf = self.take::<4>()?;
+        Ok(if little {
+            u32::from_le_bytes(buf)
+        } else {
+            u32::from_be_bytes(buf)
+        })
+    }
+
+    fn f64(&mut self, little: bool) -> Result<f64, DrizzleError> {
+        let buf = self.take::<8>()?;
+        Ok(if little {
+            f64::from_le_bytes(buf)
+        } else {
+            f64::from_be_bytes(buf)
+        })
+    }
+
+    fn len(&mut self, little: bool) -> Result<usize, DrizzleError> {
+        let len = self.u32(little)? as usize;
+        // Every element takes at least 16 bytes, so a larger count can't be
+        // satisfied by the remaining input; reject it before allocating.
+        if len > self.bytes.len().saturating_sub(self.pos) {
+            return Err(wkb_error(format!("element count {len} exceeds input")));
+        }
+        Ok(len)
+    }
+
+    fn coord(&mut self, little: bool) -> Result<Coord<f64>, DrizzleError> {
+        Ok(Coord {
+            x: self.f64(little)?,
+            y: self.f64(little)?,
+        })
+    }
+
+    fn line_string(&mut self, little: bool) -> Result<LineString<f64>, DrizzleError> {
+        let len = self.len(little)?;
+        let mut coords = Vec::with_capacity(len);
+        for _ in 0..len {
+            coords.push(self.coord(little)?);
+        }
+        Ok(LineString(coords))
+    }
+
+    fn polygon(&mut self, little: bool) -> Result<Polygon<f64>, DrizzleError> {
+        let rings = self.len(little)?;
+        if rings == 0 {
+            return Ok(Polygon::new(LineString(Vec::new()), Vec::new()));
+        }
+        let exterior = self.line_string(little)?;
+        let mut interiors = Vec::with_capacity(rings - 1);
+        for _ in 1..rings {
+            interiors.push(self.line_string(little)?);
+        }
+        Ok(Polygon::new(exterior, interiors))
+    }
+
+    /// Reads a nested member header and checks it has the `expected` type.
+    fn member(&mut self, expected: u32) -> Result<bool, DrizzleError> {
+        let (kind, little, _) = self.header()?;
+        if kind != expected {
+            return Err(wkb_error(format!(
+                "expected member type {expected}, found {kind}"
+            )));
+        }
+        Ok(little)
+    }
+
+    fn header(&mut self) -> Result<(u32, bool, Option<i32>), DrizzleError> {
+        let little = match self.take::<1>()?[0] {
+            0 => false,
+            1 => true,
+            other => return Err(wkb_error(format!("unknown byte order {other}"))),
+        };
+        let raw = self.u32(little)?;
+        if raw & (EWKB_Z_FLAG | EWKB_M_FLAG) != 0 || raw & 0xffff > 1000 {
+            return Err(wkb_error("Z/M coordinates are not supported"));
+        }
+        let srid = if raw & EWKB_SRID_FLAG == 0 {
+            None
+        } else {
+            Some(self.u32(little)?.cast_signed())
+        };
+        Ok((raw & 0xffff, little, srid))
+    }
+
+    fn geometry(&mut self) -> Result<(Geometry<f64>, Option<i32>), DrizzleError> {
+        let (kind, little, srid) = self.header()?;
+        let geometry = match kind {
+            WKB_POINT => Geometry::Point(Point(self.coord(little)?)),
+            WKB_LINESTRING => Geometry::LineString(self.line_string(little)?),
+            WKB_POLYGON => Geometry::Polygon(self.polygon(little)?),
+            WKB_MULTIPOINT => {
+                let len = self.len(little)?;
+                let mut points = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let little = self.member(WKB_POINT)?;
+                    points.push(Point(self.coord(little)?));
+                }
+                Geometry::MultiPoint(MultiPoint(points))
+            }
+            WKB_MULTILINESTRING => {
+                let len = self.len(little)?;
+                let mut lines = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let little = self.member(WKB_LINESTRING)?;
+                    lines.push(self.line_string(little)?);
+                }
+                Geometry::MultiLineString(MultiLineString(lines))
+            }
+            WKB_MULTIPOLYGON => {
+                let len = self.len(little)?;
+                let mut polygons = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let little = self.member(WKB_POLYGON)?;
+                    polygons.push(self.polygon(little)?);
+                }
+                Geometry::MultiPolygon(MultiPolygon(polygons))
+            }
+            WKB_GEOMETRYCOLLECTION => {
+                let len = self.len(little)?;
+                let mut members = Vec::with_capacity(len);
+                for _ in 0..len {
+                    members.push(self.geometry()?.0);
+                }
+                Geometry::GeometryCollection(GeometryCollection(members))
+            }
+            other => return Err(wkb_error(format!("unknown geometry type {other}"))),
+        };
+        Ok((geometry, srid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_types::{line_string, point, polygon};
+
+    fn roundtrip(geometry: Geometry<f64>, srid: Option<i32>) {
+        let bytes = encode(&geometry, srid);
+        assert_eq!(decode(&bytes).unwrap(), (geometry, srid));
+    }
+
+    #[test]
+    fn point_matches_reference_bytes() {
+        // POINT(1 2) as produced by PostGIS `ST_AsBinary`
+        let bytes = encode(&Geometry::Point(point!(x: 1.0, y: 2.0)), None);
+        assert_eq!(
+            bytes,
+            [
+                0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x3f,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40,
+            ]
+        );
+    }
+
+    #[test]
+    fn decodes_big_endian_ewkb() {
+        // SRID=4326;POINT(1 2) in XDR byte order
+        let bytes = [
+            0x00, 0x20, 0x00, 0x00, 0x01, 0x00, 0x00, 0x10, 0xe6, 0x3f, 0xf0, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        assert_eq!(
+            decode(&bytes).unwrap(),
+            (Geometry::Point(point!(x: 1.0, y: 2.0)), Some(4326))
+        );
+    }
+
+    #[test]
+    fn roundtrips_every_shape() {
+        let line = line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0), (x: 2.0, y: 0.5)];
+        let square = polygon![
+            exterior: [(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0), (x: 0.0, y: 0.0)],
+            interiors: [[(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 2.0, y: 2.0), (x: 1.0, y: 1.0)]],
+        ];
+
+        roundtrip(Geometry::Point(point!(x: -71.06, y: 42.36)), Some(4326));
+        roundtrip(Geometry::LineString(line.clone()), None);
+        roundtrip(Geometry::Polygon(square.clone()), Some(3857));
+        roundtrip(
+            Geometry::MultiPoint(MultiPoint(vec![point!(x: 1.0, y: 2.0)])),
+            None,
+        );
+        roundtrip(
+            Geometry::MultiLineString(MultiLineString(vec![line.clone()])),
+            None,
+        );
+        roundtrip(
+            Geometry::MultiPolygon(MultiPolygon(vec![square.clone()])),
+            Some(4326),
+        );
+        roundtrip(
+            Geometry::GeometryCollection(GeometryCollection(vec![
+                Geometry::Point(point!(x: 0.0, y: 0.0)),
+                Geometry::LineString(line),
+                Geometry::Polygon(square),
+            ])),
+            None,
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        let bytes = encode(&Geometry::Point(point!(x: 1.0, y: 2.0)), None);
+        assert!(decode(&bytes[..bytes.len() - 1]).is_err());
+        assert!(decode(&[bytes.as_slice(), &[0]].concat()).is_err());
+        assert!(decode(&[0x01, 0xe9, 0x03, 0x00, 0x00]).is_err());
+    }
+}
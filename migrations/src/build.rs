@@ -48,6 +48,7 @@ pub struct Config {
     dialect: Dialect,
     casing: Option<Casing>,
     breakpoints: bool,
+    format_sql: bool,
     prefix_mode: PrefixMode,
     custom_name: Option<String>,
     url: Option<EnvOr>,
@@ -74,6 +75,7 @@ impl Config {
             dialect,
             casing: None,
             breakpoints: true,
+            format_sql: false,
             prefix_mode: PrefixMode::Timestamp,
             custom_name: None,
             url: None,
@@ -87,7 +89,8 @@ impl Config {
     ///
     /// Reads `dialect`, `schema` (one path or a list), `out`, `dbCredentials.url`
     /// (literal string or `{ env = "VAR" }`), and an optional `[migrations]`
-    /// section with `table` / `schema` overrides for the tracking table.
+    /// section with `table` / `schema` / `audit` overrides for the tracking
+    /// table.
     ///
     /// Anything else in the file is ignored — this loader covers only what
     /// the build-time generate/migrate flow needs. The CLI's full loader
@@ -127,6 +130,9 @@ impl Config {
         if let Some(b) = raw.breakpoints {
             cfg.breakpoints = b;
         }
+        if let Some(f) = raw.format_sql {
+            cfg.format_sql = f;
+        }
         if let Some(c) = raw.casing {
             cfg.casing = Some(c);
         }
@@ -143,6 +149,9 @@ impl Config {
             if let Some(s) = m.schema {
                 cfg.tracking = cfg.tracking.schema(s);
             }
+            if let Some(a) = m.audit {
+                cfg.tracking = cfg.tracking.audit(a);
+            }
         }
 
         Ok(cfg)
@@ -177,6 +186,13 @@ impl Config {
         self
     }
 
+    /// Pretty-print written SQL (see [`crate::format`]). Off by default.
+    #[must_use]
+    pub const fn format_sql(mut self, enabled: bool) -> Self {
+        self.format_sql = enabled;
+        self
+    }
+
     /// Set migration tag prefix mode.
     #[must_use]
     pub const fn prefix_mode(mut self, mode: PrefixMode) -> Self {
@@ -279,6 +295,8 @@ struct RawConfig {
     #[serde(default)]
     breakpoints: Option<bool>,
     #[serde(default)]
+    format_sql: Option<bool>,
+    #[serde(default)]
     casing: Option<Casing>,
     #[serde(default)]
     db_credentials: Option<RawCreds>,
@@ -304,6 +322,8 @@ struct RawMigrations {
     table: Option<String>,
     #[serde(default)]
     schema: Option<String>,
+    #[serde(default)]
+    audit: Option<bool>,
 }
 
 /// Result of a build-time migration generation run.
@@ -417,7 +437,7 @@ pub fn run(config: &Config) -> Result<Output, BuildError> {
 
     let current_snapshot = parse_result_to_snapshot(&parse_result, config.dialect, config.casing);
     let previous_snapshot = load_previous_snapshot(&config.out_dir, config.dialect)?;
-    let generated = diff(&previous_snapshot, &current_snapshot)?;
+    let mut generated = diff(&previous_snapshot, &current_snapshot)?;
 
     if generated.is_empty() {
         return Ok(Output::NoChanges);
@@ -438,6 +458,14 @@ pub fn run(config: &Config) -> Result<Output, BuildError> {
     let migration_dir = config.out_dir.join(&tag);
     std::fs::create_dir_all(&migration_dir)?;
 
+    if config.format_sql {
+        generated.statements = generated
+            .statements
+            .iter()
+            .map(|s| crate::format::format_sql(s))
+            .collect();
+    }
+
     let sql = if config.breakpoints {
         generated.to_sql()
     } else {
@@ -692,6 +720,7 @@ url = { env = "DRIZZLE_BUILD_TEST_URL" }
 [migrations]
 table = "my_migrations"
 schema = "drizzle_meta"
+audit = true
 "#,
         )
         .expect("write config");
@@ -702,6 +731,7 @@ schema = "drizzle_meta"
         let tracking = cfg.tracking();
         assert_eq!(tracking.table, "my_migrations");
         assert_eq!(tracking.schema.as_deref(), Some("drizzle_meta"));
+        assert!(tracking.audit);
 
         // SAFETY: single-test scope, no other env consumers race here.
         unsafe { std::env::set_var("DRIZZLE_BUILD_TEST_URL", "postgres://x") };
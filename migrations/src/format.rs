@@ -0,0 +1,446 @@
+//! Pretty-printer for generated migration SQL.
+//!
+//! Generated statements are correct but often come out as one long line
+//! (view bodies, table rebuilds, partial indexes). [`format_sql`] uppercases
+//! keywords, starts each top-level clause on its own line, and puts the items
+//! of multi-item `SELECT` / `GROUP BY` / `ORDER BY` / `VALUES` / `RETURNING`
+//! lists on indented lines:
+//!
+//! ```
+//! use drizzle_migrations::format::format_sql;
+//!
+//! let sql = format_sql(r#"create view "names" as select "id", "name" from "users" where "active""#);
+//! assert_eq!(
+//!     sql,
+//!     "CREATE VIEW \"names\" AS\nSELECT\n\t\"id\",\n\t\"name\"\nFROM \"users\"\nWHERE \"active\""
+//! );
+//! ```
+//!
+//! Statements that already span several lines keep their layout and only get
+//! keywords uppercased. Anything with a comment is returned unchanged.
+
+/// Keywords written in upper case.
+const KEYWORDS: &[&str] = &[
+    "ADD",
+    "ALL",
+    "ALTER",
+    "AND",
+    "AS",
+    "ASC",
+    "BETWEEN",
+    "BY",
+    "CASCADE",
+    "CASE",
+    "CHECK",
+    "COLLATE",
+    "COLUMN",
+    "CONFLICT",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "DEFAULT",
+    "DELETE",
+    "DESC",
+    "DISTINCT",
+    "DO",
+    "DROP",
+    "ELSE",
+    "END",
+    "EXCEPT",
+    "EXISTS",
+    "FALSE",
+    "FOREIGN",
+    "FROM",
+    "FULL",
+    "GROUP",
+    "HAVING",
+    "IF",
+    "ILIKE",
+    "IN",
+    "INDEX",
+    "INNER",
+    "INSERT",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "JOIN",
+    "KEY",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "NATURAL",
+    "NOT",
+    "NOTHING",
+    "NULL",
+    "OFFSET",
+    "ON",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "PRIMARY",
+    "REFERENCES",
+    "RENAME",
+    "RESTRICT",
+    "RETURNING",
+    "RIGHT",
+    "SELECT",
+    "SET",
+    "TABLE",
+    "THEN",
+    "TO",
+    "TRUE",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USING",
+    "VALUES",
+    "VIEW",
+    "WHEN",
+    "WHERE",
+];
+
+/// Clauses whose comma-separated items go on their own lines.
+const LIST_CLAUSES: &[&str] = &["SELECT", "GROUP", "ORDER", "VALUES", "RETURNING"];
+
+/// Words that may precede `JOIN` on the same clause line.
+const JOIN_MODIFIERS: &[&str] = &[
+    "LEFT", "RIGHT", "FULL", "INNER", "OUTER", "CROSS", "NATURAL",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Word,
+    Literal,
+    Punct,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    text: String,
+    kind: Kind,
+    space_before: bool,
+}
+
+impl Token {
+    fn keyword(&self) -> Option<&str> {
+        (self.kind == Kind::Word && KEYWORDS.contains(&self.text.as_str()))
+            .then_some(self.text.as_str())
+    }
+
+    fn is(&self, punct: char) -> bool {
+        self.kind == Kind::Punct && self.text.len() == 1 && self.text.starts_with(punct)
+    }
+}
+
+/// Formats one SQL statement for a migration file.
+#[must_use]
+pub fn format_sql(statement: &str) -> String {
+    let Some(tokens) = tokenize(statement) else {
+        return statement.to_string();
+    };
+    if tokens
+        .iter()
+        .any(|token| token.kind == Kind::Punct && token.text.contains('\n'))
+    {
+        return tokens.iter().map(|token| token.text.as_str()).collect();
+    }
+
+    let clauses = split_clauses(tokens);
+    let mut out = String::new();
+    for (i, clause) in clauses.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        render_clause(clause, &mut out);
+    }
+    out
+}
+
+/// Splits a statement into tokens, uppercasing keywords.
+///
+/// Whitespace is folded into the next token's `space_before`, except that a
+/// statement with line breaks keeps its whitespace verbatim as `Punct`
+/// tokens. Returns `None` if the statement holds a comment or an unterminated
+/// literal.
+fn tokenize(sql: &str) -> Option<Vec<Token>> {
+    let multiline = has_line_break_outside_literals(sql)?;
+    let chars: Vec<char> = sql.chars().collect();
+    let mut tokens = Vec::new();
+    let mut space_before = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        let kind = if c.is_whitespace() {
+            while i < chars.len() && chars[i].is_whitespace() {
+                i += 1;
+            }
+            if multiline {
+                tokens.push(Token {
+                    text: chars[start..i].iter().collect(),
+                    kind: Kind::Punct,
+                    space_before: false,
+                });
+            } else {
+                space_before = true;
+            }
+            continue;
+        } else if matches!(c, '\'' | '"' | '`') {
+            i = skip_quoted(&chars, i, c)?;
+            Kind::Literal
+        } else if c == '$' && dollar_tag(&chars, i).is_some() {
+            i = skip_dollar_quoted(&chars, i)?;
+            Kind::Literal
+        } else if c.is_alphanumeric() || c == '_' || c == '$' {
+            while i < chars.len()
+                && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '$')
+            {
+                i += 1;
+            }
+            Kind::Word
+        } else {
+            i += 1;
+            Kind::Punct
+        };
+
+        let mut text: String = chars[start..i].iter().collect();
+        if kind == Kind::Word {
+            let upper = text.to_ascii_uppercase();
+            if KEYWORDS.contains(&upper.as_str()) {
+                text = upper;
+            }
+        }
+        tokens.push(Token {
+            text,
+            kind,
+            space_before,
+        });
+        space_before = false;
+    }
+
+    Some(tokens)
+}
+
+/// `Some(true)` if `sql` has a line break outside literals, `None` if it has
+/// a comment.
+fn has_line_break_outside_literals(sql: &str) -> Option<bool> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut line_break = false;
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            quote @ ('\'' | '"' | '`') => i = skip_quoted(&chars, i, quote)?,
+            '$' if dollar_tag(&chars, i).is_some() => i = skip_dollar_quoted(&chars, i)?,
+            '-' if chars.get(i + 1) == Some(&'-') => return None,
+            '/' if chars.get(i + 1) == Some(&'*') => return None,
+            c => {
+                line_break |= c == '\n';
+                i += 1;
+            }
+        }
+    }
+    Some(line_break)
+}
+
+/// Index just past the literal opened by `quote` at `start`.
+fn skip_quoted(chars: &[char], start: usize, quote: char) -> Option<usize> {
+    let mut i = start + 1;
+    while i < chars.len() {
+        if chars[i] == quote {
+            // A doubled quote is an escaped quote
+            if chars.get(i + 1) == Some(&quote) {
+                i += 2;
+                continue;
+            }
+            return Some(i + 1);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// The `$tag$` opener at `start`, if any.
+fn dollar_tag(chars: &[char], start: usize) -> Option<String> {
+    let mut i = start + 1;
+    while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+        if i == start + 1 && chars[i].is_ascii_digit() {
+            // `$1` is a parameter
+            return None;
+        }
+        i += 1;
+    }
+    (chars.get(i) == Some(&'$')).then(|| chars[start..=i].iter().collect())
+}
+
+/// Index just past the dollar-quoted body opened at `start`.
+fn skip_dollar_quoted(chars: &[char], start: usize) -> Option<usize> {
+    let tag: Vec<char> = dollar_tag(chars, start)?.chars().collect();
+    let mut i = start + tag.len();
+    while i + tag.len() <= chars.len() {
+        if chars[i..i + tag.len()] == tag[..] {
+            return Some(i + tag.len());
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Splits tokens before each top-level clause keyword.
+fn split_clauses(tokens: Vec<Token>) -> Vec<Vec<Token>> {
+    let mut clauses: Vec<Vec<Token>> = vec![Vec::new()];
+    let mut depth = 0usize;
+
+    for (i, token) in tokens.iter().enumerate() {
+        if depth == 0 && i > 0 && starts_clause(&tokens, i) {
+            clauses.push(Vec::new());
+        }
+        if token.is('(') {
+            depth += 1;
+        } else if token.is(')') {
+            depth = depth.saturating_sub(1);
+        }
+        if let Some(clause) = clauses.last_mut() {
+            clause.push(token.clone());
+        }
+    }
+
+    clauses.retain(|clause| !clause.is_empty());
+    clauses
+}
+
+fn starts_clause(tokens: &[Token], i: usize) -> bool {
+    let Some(keyword) = tokens[i].keyword() else {
+        return false;
+    };
+    let prev = tokens[..i].iter().rev().find_map(Token::keyword);
+    let prev_is_adjacent = tokens[i - 1].keyword().is_some();
+
+    match keyword {
+        "SELECT" | "WHERE" | "HAVING" | "LIMIT" | "OFFSET" | "VALUES" | "RETURNING" | "UNION"
+        | "INTERSECT" | "EXCEPT" => true,
+        // `DELETE FROM` and `DISTINCT FROM` stay together
+        "FROM" => !(prev_is_adjacent && matches!(prev, Some("DELETE" | "DISTINCT"))),
+        "GROUP" | "ORDER" => tokens.get(i + 1).and_then(Token::keyword) == Some("BY"),
+        "JOIN" => !(prev_is_adjacent && prev.is_some_and(|p| JOIN_MODIFIERS.contains(&p))),
+        k if JOIN_MODIFIERS.contains(&k) => {
+            !(prev_is_adjacent && prev.is_some_and(|p| JOIN_MODIFIERS.contains(&p)))
+                && tokens[i + 1..]
+                    .iter()
+                    .take_while(|t| t.keyword().is_some())
+                    .any(|t| t.text == "JOIN")
+        }
+        _ => false,
+    }
+}
+
+fn render_clause(clause: &[Token], out: &mut String) {
+    let keyword = clause[0].keyword().unwrap_or_default();
+    let header_len = match keyword {
+        "GROUP" | "ORDER" => 2,
+        "SELECT"
+            if clause
+                .get(1)
+                .and_then(Token::keyword)
+                .is_some_and(|k| matches!(k, "DISTINCT" | "ALL")) =>
+        {
+            2
+        }
+        _ => 1,
+    };
+
+    let items = split_items(&clause[header_len.min(clause.len())..]);
+    if LIST_CLAUSES.contains(&keyword) && items.len() > 1 {
+        render_tokens(&clause[..header_len], out);
+        for (i, item) in items.iter().enumerate() {
+            out.push_str("\n\t");
+            render_tokens(item, out);
+            if i + 1 < items.len() {
+                out.push(',');
+            }
+        }
+    } else {
+        render_tokens(clause, out);
+    }
+}
+
+/// Splits a clause body at top-level commas, dropping the commas.
+fn split_items(tokens: &[Token]) -> Vec<&[Token]> {
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        if token.is('(') {
+            depth += 1;
+        } else if token.is(')') {
+            depth = depth.saturating_sub(1);
+        } else if depth == 0 && token.is(',') {
+            items.push(&tokens[start..i]);
+            start = i + 1;
+        }
+    }
+    items.push(&tokens[start..]);
+    items
+}
+
+fn render_tokens(tokens: &[Token], out: &mut String) {
+    for (i, token) in tokens.iter().enumerate() {
+        if i > 0 && token.space_before {
+            out.push(' ');
+        }
+        out.push_str(&token.text);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breaks_table_rebuild_copy() {
+        assert_eq!(
+            format_sql(
+                "INSERT INTO `__new_users`(\"id\", \"name\") SELECT \"id\", \"name\" FROM `users`;"
+            ),
+            "INSERT INTO `__new_users`(\"id\", \"name\")\nSELECT\n\t\"id\",\n\t\"name\"\nFROM `users`;"
+        );
+    }
+
+    #[test]
+    fn keeps_single_items_and_joins_on_one_line() {
+        assert_eq!(
+            format_sql(
+                "select count(*) from a left outer join b on a.id = b.a_id where a.x in (select y from c) order by a.id desc"
+            ),
+            "SELECT count(*)\nFROM a\nLEFT OUTER JOIN b ON a.id = b.a_id\nWHERE a.x IN (SELECT y FROM c)\nORDER BY a.id DESC"
+        );
+    }
+
+    #[test]
+    fn leaves_literals_and_parameters_alone() {
+        assert_eq!(
+            format_sql("CREATE INDEX \"i\" ON \"t\" (\"from\") where \"kind\" = 'select from'"),
+            "CREATE INDEX \"i\" ON \"t\" (\"from\")\nWHERE \"kind\" = 'select from'"
+        );
+        assert_eq!(
+            format_sql("create function f() returns int as $body$ select 1 from t $body$"),
+            "CREATE function f() returns int AS $body$ select 1 from t $body$"
+        );
+        assert_eq!(
+            format_sql("delete from t where id = $1"),
+            "DELETE FROM t\nWHERE id = $1"
+        );
+    }
+
+    #[test]
+    fn keeps_existing_layout_and_comments() {
+        let create = "create table `users` (\n\t`id` integer primary key\n);";
+        assert_eq!(
+            format_sql(create),
+            "CREATE TABLE `users` (\n\t`id` integer PRIMARY KEY\n);"
+        );
+
+        let commented = "select a, b from t -- keep me";
+        assert_eq!(format_sql(commented), commented);
+    }
+}
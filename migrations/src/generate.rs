@@ -92,6 +92,79 @@ impl Plan {
         let mut writer = writer;
         writer.write_all(self.to_sql().as_bytes())
     }
+
+    /// Statements that drop objects or rebuild tables, see
+    /// [`is_destructive_statement`].
+    pub fn destructive_statements(&self) -> impl Iterator<Item = &str> {
+        self.statements
+            .iter()
+            .map(String::as_str)
+            .filter(|statement| is_destructive_statement(statement))
+    }
+}
+
+/// Whether `sql` drops or truncates something, and may lose data when run.
+///
+/// `SQLite` table rebuilds count too, since they end in a `DROP TABLE`.
+#[must_use]
+pub fn is_destructive_statement(sql: &str) -> bool {
+    let s = sql.trim().to_uppercase();
+    s.contains("DROP TABLE")
+        || s.contains("DROP COLUMN")
+        || s.contains("DROP INDEX")
+        || s.contains("DROP VIEW")
+        || s.contains("DROP MATERIALIZED VIEW")
+        || s.contains("DROP TYPE")
+        || s.contains("DROP SCHEMA")
+        || s.contains("DROP SEQUENCE")
+        || s.contains("DROP ROLE")
+        || s.contains("DROP POLICY")
+        || s.contains("TRUNCATE")
+        || (s.contains("ALTER TABLE") && s.contains(" DROP "))
+}
+
+/// How `sync_schema_with` applies a [`Plan`] to a live database.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SyncOptions {
+    allow_destructive: bool,
+}
+
+impl SyncOptions {
+    /// Only additive changes: new tables, columns, indexes and views.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            allow_destructive: false,
+        }
+    }
+
+    /// Also apply statements that drop objects or rebuild tables.
+    #[must_use]
+    pub const fn allow_destructive(mut self, allow: bool) -> Self {
+        self.allow_destructive = allow;
+        self
+    }
+
+    /// Check that `plan` may be applied with these options.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MigrationError::Destructive`] listing the destructive
+    /// statements when the plan has any and they aren't allowed.
+    pub fn check(&self, plan: &Plan) -> Result<(), MigrationError> {
+        if self.allow_destructive {
+            return Ok(());
+        }
+        let destructive: Vec<String> = plan
+            .destructive_statements()
+            .map(ToString::to_string)
+            .collect();
+        if destructive.is_empty() {
+            Ok(())
+        } else {
+            Err(MigrationError::Destructive(destructive))
+        }
+    }
 }
 
 /// Explicit rename hints used during migration generation.
@@ -219,6 +292,13 @@ pub struct Options {
     pub renames: RenameHints,
     /// If true, every hint must apply; otherwise generation fails.
     pub strict_renames: bool,
+    /// If true, only the explicit hints rename anything; the rename
+    /// heuristics are skipped and unmatched entities are dropped/created.
+    pub explicit_renames_only: bool,
+    /// If true, `PostgreSQL` indexes added to tables that already exist are
+    /// built with `CREATE INDEX CONCURRENTLY`, so the build doesn't block
+    /// writes. Indexes on tables created in the same migration are unaffected.
+    pub concurrent_indexes: bool,
 }
 
 impl Options {
@@ -239,6 +319,18 @@ impl Options {
         self
     }
 
+    #[must_use]
+    pub const fn explicit_renames_only(mut self, explicit_only: bool) -> Self {
+        self.explicit_renames_only = explicit_only;
+        self
+    }
+
+    #[must_use]
+    pub const fn concurrent_indexes(mut self, concurrent: bool) -> Self {
+        self.concurrent_indexes = concurrent;
+        self
+    }
+
     #[must_use]
     pub fn rename_schema(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
         self.renames = self.renames.rename_schema(from, to);
@@ -325,15 +417,26 @@ pub fn diff_with(
             let mut prev_ddl = SQLiteDDL::from_entities(p.ddl.clone());
             let cur_ddl = crate::sqlite::collection::SQLiteDDL::from_entities(c.ddl.clone());
             let mut statements = apply_sqlite_rename_hints(&mut prev_ddl, &cur_ddl, options)?;
-            let diff = crate::sqlite::diff::compute_migration(&prev_ddl, &cur_ddl);
+            let diff = crate::sqlite::diff::compute_migration_with(
+                &prev_ddl,
+                &cur_ddl,
+                !options.explicit_renames_only,
+            );
             statements.extend(diff.sql_statements);
             (statements, diff.warnings)
         }
         (Snapshot::Postgres(p), Snapshot::Postgres(c)) => {
             let mut prev_ddl = PostgresDDL::from_entities(p.ddl.clone());
-            let cur_ddl = PostgresDDL::from_entities(c.ddl.clone());
+            let mut cur_ddl = PostgresDDL::from_entities(c.ddl.clone());
             let mut statements = apply_postgres_rename_hints(&mut prev_ddl, &cur_ddl, options)?;
-            let diff = crate::postgres::diff::compute_migration(&prev_ddl, &cur_ddl);
+            if options.concurrent_indexes {
+                mark_concurrent_indexes(&prev_ddl, &mut cur_ddl);
+            }
+            let diff = crate::postgres::diff::compute_migration_with(
+                &prev_ddl,
+                &cur_ddl,
+                !options.explicit_renames_only,
+            );
             statements.extend(diff.sql_statements);
             (statements, diff.warnings)
         }
@@ -510,6 +613,21 @@ fn apply_sqlite_rename_hints(
     Ok(statements)
 }
 
+/// Build new indexes on already existing tables concurrently.
+fn mark_concurrent_indexes(prev: &PostgresDDL, cur: &mut PostgresDDL) {
+    let existing = |schema: &str, table: &str| {
+        prev.tables
+            .list()
+            .iter()
+            .any(|t| t.schema == schema && t.name == table)
+    };
+    for index in cur.indexes.list_mut() {
+        if existing(&index.schema, &index.table) {
+            index.concurrently = true;
+        }
+    }
+}
+
 fn apply_postgres_rename_hints(
     prev: &mut PostgresDDL,
     cur: &PostgresDDL,
@@ -1008,7 +1126,8 @@ mod tests {
     use super::*;
     use crate::postgres::PostgresSnapshot;
     use crate::postgres::ddl::{
-        Column as PgColumn, PostgresEntity, Schema as PgSchema, Table as PgTable,
+        Column as PgColumn, Index as PgIndex, IndexColumn as PgIndexColumn, PostgresEntity,
+        Schema as PgSchema, Table as PgTable,
     };
     use crate::schema::Schema as MigrationSchema;
     use crate::sqlite::SQLiteSnapshot;
@@ -1055,6 +1174,54 @@ mod tests {
         assert!(migration.statements[0].contains("users"));
     }
 
+    #[test]
+    fn destructive_statement_detection_covers_drop_variants() {
+        assert!(is_destructive_statement("DROP TABLE users;"));
+        assert!(is_destructive_statement("DROP VIEW active_users;"));
+        assert!(is_destructive_statement("DROP TYPE status;"));
+        assert!(is_destructive_statement("DROP SCHEMA auth;"));
+        assert!(is_destructive_statement("DROP ROLE app_user;"));
+        assert!(is_destructive_statement(
+            "DROP POLICY users_rls_policy ON users;"
+        ));
+        assert!(is_destructive_statement("TRUNCATE users;"));
+        assert!(is_destructive_statement(
+            "ALTER TABLE users DROP CONSTRAINT users_email_key;"
+        ));
+
+        assert!(!is_destructive_statement("CREATE TABLE users(id INTEGER);"));
+        assert!(!is_destructive_statement(
+            "ALTER TABLE users ADD COLUMN email text;"
+        ));
+    }
+
+    #[test]
+    fn sync_options_refuse_destructive_plans_by_default() {
+        let mut prev = SQLiteSnapshot::new();
+        prev.add_entity(SqliteEntity::Table(Table::new("users")));
+        prev.add_entity(SqliteEntity::Column(
+            Column::new("users", "id", "integer").not_null(),
+        ));
+        let prev = Snapshot::Sqlite(prev);
+        let empty = Snapshot::empty(drizzle_types::Dialect::SQLite);
+
+        let additive = diff(&empty, &prev).unwrap();
+        assert!(SyncOptions::new().check(&additive).is_ok());
+
+        let dropping = diff(&prev, &empty).unwrap();
+        let err = SyncOptions::new().check(&dropping).unwrap_err();
+        assert!(
+            matches!(&err, MigrationError::Destructive(statements) if statements[0].contains("DROP TABLE")),
+            "{err}"
+        );
+        assert!(
+            SyncOptions::new()
+                .allow_destructive(true)
+                .check(&dropping)
+                .is_ok()
+        );
+    }
+
     #[test]
     fn test_generate_dialect_mismatch() {
         let prev = Snapshot::empty(drizzle_types::Dialect::SQLite);
@@ -1110,6 +1277,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_diff_with_explicit_renames_only_skips_heuristics() {
+        let mut prev_snap = SQLiteSnapshot::new();
+        prev_snap.add_entity(SqliteEntity::Table(Table::new("users")));
+        prev_snap.add_entity(SqliteEntity::Column(
+            Column::new("users", "id", "integer").not_null(),
+        ));
+
+        let mut cur_snap = SQLiteSnapshot::new();
+        cur_snap.add_entity(SqliteEntity::Table(Table::new("accounts")));
+        cur_snap.add_entity(SqliteEntity::Column(
+            Column::new("accounts", "id", "integer").not_null(),
+        ));
+
+        let prev = Snapshot::Sqlite(prev_snap);
+        let cur = Snapshot::Sqlite(cur_snap);
+
+        let heuristic = diff(&prev, &cur).unwrap();
+        assert_eq!(
+            heuristic.statements,
+            vec!["ALTER TABLE `users` RENAME TO `accounts`;".to_string()]
+        );
+
+        let explicit = diff_with(&prev, &cur, &Options::new().explicit_renames_only(true)).unwrap();
+        assert!(
+            explicit
+                .statements
+                .iter()
+                .any(|sql| sql.starts_with("CREATE TABLE `accounts`"))
+        );
+        assert!(
+            explicit
+                .statements
+                .contains(&"DROP TABLE `users`;".to_string())
+        );
+    }
+
     #[test]
     fn test_diff_with_sqlite_table_rename_hint_and_add_column() {
         let mut prev_snap = SQLiteSnapshot::new();
@@ -1178,6 +1382,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_diff_with_concurrent_indexes_only_touches_existing_tables() {
+        let mut prev_snap = PostgresSnapshot::new();
+        prev_snap.add_entity(PostgresEntity::Schema(PgSchema::new("public")));
+        prev_snap.add_entity(PostgresEntity::Table(PgTable::new("public", "users")));
+        prev_snap.add_entity(PostgresEntity::Column(PgColumn::new(
+            "public", "users", "email", "text",
+        )));
+
+        let mut cur_snap = prev_snap.clone();
+        cur_snap.add_entity(PostgresEntity::Index(PgIndex::new(
+            "public",
+            "users",
+            "users_email_idx",
+            vec![PgIndexColumn::new("email")],
+        )));
+        cur_snap.add_entity(PostgresEntity::Table(PgTable::new("public", "posts")));
+        cur_snap.add_entity(PostgresEntity::Column(PgColumn::new(
+            "public", "posts", "title", "text",
+        )));
+        cur_snap.add_entity(PostgresEntity::Index(PgIndex::new(
+            "public",
+            "posts",
+            "posts_title_idx",
+            vec![PgIndexColumn::new("title")],
+        )));
+
+        let migration = diff_with(
+            &Snapshot::Postgres(prev_snap),
+            &Snapshot::Postgres(cur_snap),
+            &Options::new().concurrent_indexes(true),
+        )
+        .unwrap();
+
+        let indexes = migration
+            .statements
+            .iter()
+            .filter(|sql| sql.starts_with("CREATE INDEX"))
+            .collect::<Vec<_>>();
+        assert_eq!(indexes.len(), 2);
+        assert!(
+            indexes
+                .iter()
+                .any(|sql| sql.starts_with("CREATE INDEX CONCURRENTLY \"users_email_idx\""))
+        );
+        assert!(
+            indexes
+                .iter()
+                .any(|sql| sql.starts_with("CREATE INDEX \"posts_title_idx\""))
+        );
+    }
+
     #[test]
     fn test_diff_with_strict_rename_hints_errors() {
         let prev = Snapshot::empty(drizzle_types::Dialect::SQLite);
@@ -118,6 +118,7 @@ pub mod build;
 pub mod collection;
 pub mod config;
 pub mod dir;
+pub mod format;
 pub mod generate;
 pub mod journal;
 pub mod migrator;
@@ -139,10 +140,12 @@ pub use config::Tracking;
 pub use dir::MigrationDir;
 pub use journal::{Journal, JournalEntry};
 pub use migrator::{
-    AppliedMigrationMetadata, MatchedMigrationMetadata, MigrateOutcome, Migration, Migrations,
-    MigratorError, is_postgres_concurrent_index_statement, match_applied_migration_metadata,
+    AppliedMigrationMetadata, ChecksumMismatch, MatchedMigrationMetadata, MigrateOutcome,
+    Migration, MigrationStep, Migrations, MigratorError, NO_TRANSACTION_MARKER, check_schema_hash,
+    is_postgres_concurrent_index_statement, match_applied_migration_metadata,
+    requires_no_transaction,
 };
-pub use words::{PrefixMode, generate_migration_tag};
+pub use words::{PrefixMode, TagFormat, generate_migration_tag};
 pub use writer::{MigrationError, Writer};
 
 // Version constants
@@ -153,7 +156,9 @@ pub use version::{
 };
 
 // Upgrade utilities
-pub use upgrade::{latest_version_for_dialect, needs_upgrade_for_dialect, upgrade_to_latest};
+pub use upgrade::{
+    import_legacy_folder, latest_version_for_dialect, needs_upgrade_for_dialect, upgrade_to_latest,
+};
 
 // Core traits and dialect markers
 pub use traits::{
@@ -173,8 +178,8 @@ pub use schema::{Schema, Snapshot};
 
 // Programmatic migration generation
 pub use generate::{
-    ColumnRenameHint, Options, Plan, RenameHints, SchemaRenameHint, TableRenameHint, diff,
-    diff_schemas, diff_schemas_with, diff_with,
+    ColumnRenameHint, Options, Plan, RenameHints, SchemaRenameHint, SyncOptions, TableRenameHint,
+    diff, diff_schemas, diff_schemas_with, diff_with, is_destructive_statement,
 };
 pub use snapshot_builder::parse_result_to_snapshot;
 
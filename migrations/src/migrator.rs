@@ -58,6 +58,12 @@ use crate::config::Tracking;
 use drizzle_types::Dialect;
 use sha2::{Digest, Sha256};
 
+/// `hash` value of the transient `SQLite` lock marker row.
+const LOCK_MARKER_HASH: &str = "__drizzle_migrate_lock";
+
+/// `hash` prefix of the row holding the recorded schema hash.
+const SCHEMA_HASH_PREFIX: &str = "__drizzle_schema:";
+
 fn quote_identifier(dialect: Dialect, identifier: &str) -> String {
     match dialect {
         Dialect::MySQL => format!("`{}`", identifier.replace('`', "``")),
@@ -121,6 +127,27 @@ impl MigrateOutcome {
     }
 }
 
+/// An applied migration whose local SQL no longer matches the recorded hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecksumMismatch {
+    /// Migration name (folder name)
+    pub name: String,
+    /// Hash stored in the tracking table
+    pub recorded: String,
+    /// Hash of the local migration file
+    pub local: String,
+}
+
+impl std::fmt::Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (database={}, local={})",
+            self.name, self.recorded, self.local
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AppliedMigrationMetadata {
     pub id: Option<i64>,
@@ -219,6 +246,42 @@ impl Migration {
             .iter()
             .any(|statement| is_postgres_concurrent_index_statement(statement))
     }
+
+    /// Whether the whole migration runs outside the wrapping transaction,
+    /// i.e. `migration.sql` starts with a [`NO_TRANSACTION_MARKER`] header.
+    #[must_use]
+    pub fn is_no_transaction(&self) -> bool {
+        self.sql
+            .iter()
+            .find(|statement| !statement.trim().is_empty())
+            .is_some_and(|statement| statement.trim_start().starts_with(NO_TRANSACTION_MARKER))
+    }
+
+    /// Non-empty statements in execution order, each flagged when it has to
+    /// run outside the wrapping transaction.
+    ///
+    /// Runners execute the transactional steps in one transaction and commit
+    /// it before every [`MigrationStep::no_transaction`] step. Every step of a
+    /// [`Migration::is_no_transaction`] migration is flagged.
+    pub fn steps(&self) -> impl Iterator<Item = MigrationStep<'_>> {
+        let whole = self.is_no_transaction();
+        self.sql
+            .iter()
+            .filter(|statement| !statement.trim().is_empty())
+            .map(move |statement| MigrationStep {
+                sql: statement,
+                no_transaction: whole || requires_no_transaction(statement),
+            })
+    }
+}
+
+/// A single statement of a [`Migration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MigrationStep<'a> {
+    /// SQL to execute
+    pub sql: &'a str,
+    /// The statement can't run inside a transaction block
+    pub no_transaction: bool,
 }
 
 /// A collection of migrations ready to be applied
@@ -232,6 +295,14 @@ pub struct Migrations {
     table: String,
     /// Migrations schema (`PostgreSQL` only)
     schema: Option<String>,
+    /// Reject checksum drift of applied migrations
+    strict: bool,
+    /// Record audit metadata for applied migrations (`PostgreSQL` only)
+    audit: bool,
+    /// Schema hash recorded by `migrate()`
+    schema_hash: Option<u64>,
+    /// Number of leading migrations [`Migrations::pending`] considers
+    target: Option<usize>,
 }
 
 impl Migrations {
@@ -246,6 +317,10 @@ impl Migrations {
                 Dialect::PostgreSQL => Some("drizzle".to_string()),
                 _ => None,
             },
+            strict: false,
+            audit: false,
+            schema_hash: None,
+            target: None,
         }
     }
 
@@ -255,6 +330,10 @@ impl Migrations {
             dialect,
             table: tracking.table.into_owned(),
             schema: tracking.schema.map(std::borrow::Cow::into_owned),
+            strict: tracking.strict,
+            audit: tracking.audit,
+            schema_hash: tracking.schema_hash,
+            target: None,
         }
     }
 
@@ -282,14 +361,57 @@ impl Migrations {
     /// `applied_names` should contain the non-null `name` column values from
     /// the migrations tracking table, typically loaded via
     /// [`Migrations::applied_names_sql`].
+    ///
+    /// After [`Migrations::up_to`], migrations past the target tag are never
+    /// pending.
     pub fn pending<'a, S>(&'a self, applied_names: &'a [S]) -> impl Iterator<Item = &'a Migration>
     where
         S: AsRef<str>,
     {
-        self.list.iter().filter(move |m| {
-            let name = m.name();
-            name.is_empty() || !applied_names.iter().any(|applied| applied.as_ref() == name)
-        })
+        let end = self.target.unwrap_or(self.list.len());
+        pending_in(&self.list[..end], applied_names)
+    }
+
+    /// Get pending migrations up to and including `tag`, in order.
+    ///
+    /// Migrations after `tag` are left for a later run, e.g. when they must
+    /// wait for an application deploy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MigratorError::MissingMigration`] if no local migration has
+    /// that tag.
+    pub fn pending_up_to<'a, S>(
+        &'a self,
+        applied_names: &'a [S],
+        tag: &str,
+    ) -> Result<impl Iterator<Item = &'a Migration>, MigratorError>
+    where
+        S: AsRef<str>,
+    {
+        let end = self.position(tag)? + 1;
+        Ok(pending_in(&self.list[..end], applied_names))
+    }
+
+    /// Stop at `tag`: [`Migrations::pending`] then yields only migrations up
+    /// to and including it.
+    ///
+    /// Use this to hand a partial rollout to code that only calls `pending`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MigratorError::MissingMigration`] if no local migration has
+    /// that tag.
+    pub fn up_to(mut self, tag: &str) -> Result<Self, MigratorError> {
+        self.target = Some(self.position(tag)? + 1);
+        Ok(self)
+    }
+
+    fn position(&self, tag: &str) -> Result<usize, MigratorError> {
+        self.list
+            .iter()
+            .position(|m| m.tag() == tag)
+            .ok_or_else(|| MigratorError::MissingMigration(tag.to_string()))
     }
 
     /// Check if there are pending migrations, by name set-difference.
@@ -300,6 +422,75 @@ impl Migrations {
         self.pending(applied_names).next().is_some()
     }
 
+    /// Compare the hashes recorded for applied migrations against the local
+    /// migration files.
+    ///
+    /// `applied` holds `(name, hash)` pairs, typically loaded via
+    /// [`Migrations::applied_hashes_sql`]. Rows without a local migration of
+    /// the same name are ignored.
+    pub fn verify<N, H>(&self, applied: &[(N, H)]) -> Vec<ChecksumMismatch>
+    where
+        N: AsRef<str>,
+        H: AsRef<str>,
+    {
+        applied
+            .iter()
+            .filter_map(|(name, hash)| {
+                let migration = self.list.iter().find(|m| m.name() == name.as_ref())?;
+                (migration.hash() != hash.as_ref()).then(|| ChecksumMismatch {
+                    name: migration.name().to_string(),
+                    recorded: hash.as_ref().to_string(),
+                    local: migration.hash().to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Run [`Migrations::verify`] when the set is strict.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MigratorError::ChecksumMismatch`] if the set was built with
+    /// [`Tracking::strict`] and any applied migration was edited afterwards.
+    pub fn check_checksums<N, H>(&self, applied: &[(N, H)]) -> Result<(), MigratorError>
+    where
+        N: AsRef<str>,
+        H: AsRef<str>,
+    {
+        if !self.strict {
+            return Ok(());
+        }
+        let mismatches = self.verify(applied);
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(MigratorError::ChecksumMismatch(mismatches))
+        }
+    }
+
+    /// Whether `migrate()` fails on checksum drift.
+    #[inline]
+    #[must_use]
+    pub const fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Whether applied migrations are recorded with audit metadata.
+    ///
+    /// Only `PostgreSQL` sets built with [`Tracking::audit`] are audited.
+    #[inline]
+    #[must_use]
+    pub fn is_audited(&self) -> bool {
+        self.audit && self.dialect == Dialect::PostgreSQL
+    }
+
+    /// Schema hash recorded by `migrate()`, from [`Tracking::schema_hash`].
+    #[inline]
+    #[must_use]
+    pub const fn schema_hash(&self) -> Option<u64> {
+        self.schema_hash
+    }
+
     /// Get the dialect
     #[inline]
     #[must_use]
@@ -431,6 +622,21 @@ impl Migrations {
         }
     }
 
+    /// Get the SQL that adds the audit columns to an existing tracking table.
+    ///
+    /// Run it after [`Migrations::create_table_sql`]; it is a no-op when the
+    /// columns are already there. Returns `None` unless
+    /// [`Migrations::is_audited`].
+    #[must_use]
+    pub fn add_audit_columns_sql(&self) -> Option<String> {
+        self.is_audited().then(|| {
+            format!(
+                r#"ALTER TABLE {} ADD COLUMN IF NOT EXISTS "applied_by" TEXT, ADD COLUMN IF NOT EXISTS "duration_ms" BIGINT, ADD COLUMN IF NOT EXISTS "statement_count" INTEGER;"#,
+                self.table_ident()
+            )
+        })
+    }
+
     /// Get the SQL to record a migration as applied.
     #[must_use]
     pub fn record_migration_sql(&self, migration: &Migration) -> String {
@@ -453,6 +659,77 @@ impl Migrations {
         }
     }
 
+    /// Get the SQL to record a migration that took `duration` to apply.
+    ///
+    /// Audited sets also store the connected role and client host in
+    /// `applied_by`, along with the elapsed time and the number of statements
+    /// run; otherwise this is [`Migrations::record_migration_sql`].
+    #[must_use]
+    pub fn record_applied_migration_sql(
+        &self,
+        migration: &Migration,
+        duration: std::time::Duration,
+    ) -> String {
+        if !self.is_audited() {
+            return self.record_migration_sql(migration);
+        }
+
+        let table = self.table_ident();
+        let hash = escape_sql_string(migration.hash());
+        let name = escape_sql_string(migration.name());
+        let created_at = migration.created_at();
+        let duration_ms = i64::try_from(duration.as_millis()).unwrap_or(i64::MAX);
+        let statement_count = migration
+            .statements()
+            .iter()
+            .filter(|stmt| !stmt.trim().is_empty())
+            .count();
+
+        format!(
+            r#"INSERT INTO {table} ("hash", "created_at", "name", "applied_at", "applied_by", "duration_ms", "statement_count") VALUES ('{hash}', {created_at}, '{name}', CURRENT_TIMESTAMP, session_user || '@' || COALESCE(host(inet_client_addr()), 'local'), {duration_ms}, {statement_count});"#
+        )
+    }
+
+    /// Get the SQL to overwrite the recorded hash of an applied migration
+    /// with its current local hash.
+    #[must_use]
+    pub fn update_hash_sql(&self, migration: &Migration) -> String {
+        let table = self.table_ident();
+        let hash = escape_sql_string(migration.hash());
+        let name = escape_sql_string(migration.name());
+
+        match self.dialect {
+            Dialect::SQLite | Dialect::PostgreSQL => {
+                format!(r#"UPDATE {table} SET "hash" = '{hash}' WHERE "name" = '{name}';"#)
+            }
+            Dialect::MySQL => {
+                format!(r"UPDATE {table} SET `hash` = '{hash}' WHERE `name` = '{name}';")
+            }
+        }
+    }
+
+    /// Get the SQL to query applied migration names and hashes, in that
+    /// column order. Pair with [`Migrations::verify`].
+    #[must_use]
+    pub fn applied_hashes_sql(&self) -> String {
+        let table = self.table_ident();
+        format!(r#"SELECT "name", "hash" FROM {table} WHERE "name" IS NOT NULL ORDER BY id;"#)
+    }
+
+    /// Get the SQL to query the applied migration history (`PostgreSQL` only).
+    ///
+    /// Columns are `name`, `applied_at` (as text), `applied_by`,
+    /// `duration_ms` and `statement_count`, oldest first. The audit columns
+    /// read as `NULL` for rows recorded before auditing was enabled, or when
+    /// the table was never audited at all.
+    #[must_use]
+    pub fn applied_history_sql(&self) -> String {
+        let table = self.table_ident();
+        format!(
+            r#"SELECT t."name", t."applied_at"::text, to_jsonb(t) ->> 'applied_by', (to_jsonb(t) ->> 'duration_ms')::bigint, (to_jsonb(t) ->> 'statement_count')::integer FROM {table} AS t WHERE t."name" IS NOT NULL ORDER BY t.id;"#
+        )
+    }
+
     /// Get the SQL to query applied migration names.
     ///
     /// Only rows with a non-null `name` are returned; rows written before the
@@ -465,6 +742,57 @@ impl Migrations {
         format!(r#"SELECT "name" FROM {table} WHERE "name" IS NOT NULL ORDER BY id;"#)
     }
 
+    /// Get the SQL that claims the `SQLite` migration lock marker row.
+    ///
+    /// Run it first inside the `BEGIN IMMEDIATE` transaction: the write
+    /// conflicts with any other runner holding the marker, so exactly one
+    /// connection proceeds while the others wait for it to commit. The row
+    /// has a `NULL` name and is removed again by
+    /// [`Migrations::release_lock_sql`] before commit, so it is never visible
+    /// outside the transaction.
+    #[must_use]
+    pub fn claim_lock_sql(&self) -> String {
+        let table = self.table_ident();
+        format!(r#"INSERT INTO {table} (id, "hash") VALUES (0, '{LOCK_MARKER_HASH}');"#)
+    }
+
+    /// Get the SQL that removes the marker row written by
+    /// [`Migrations::claim_lock_sql`].
+    #[must_use]
+    pub fn release_lock_sql(&self) -> String {
+        let table = self.table_ident();
+        format!(r#"DELETE FROM {table} WHERE id = 0 AND "hash" = '{LOCK_MARKER_HASH}';"#)
+    }
+
+    /// Get the SQL that records [`Migrations::schema_hash`], replacing any
+    /// hash recorded before. Returns `None` when the set has no schema hash.
+    ///
+    /// The hash lives in a row with id `-1` and a `NULL` name, so it never
+    /// shows up as an applied migration.
+    #[must_use]
+    pub fn record_schema_hash_sql(&self) -> Option<String> {
+        let table = self.table_ident();
+        self.schema_hash.map(|hash| {
+            let hash = format!("{SCHEMA_HASH_PREFIX}{hash:016x}");
+            match self.dialect {
+                Dialect::SQLite | Dialect::PostgreSQL => format!(
+                    r#"INSERT INTO {table} (id, "hash") VALUES (-1, '{hash}') ON CONFLICT (id) DO UPDATE SET "hash" = excluded."hash";"#
+                ),
+                Dialect::MySQL => format!(
+                    r"INSERT INTO {table} (id, `hash`) VALUES (-1, '{hash}') ON DUPLICATE KEY UPDATE `hash` = VALUES(`hash`);"
+                ),
+            }
+        })
+    }
+
+    /// Get the SQL to query the recorded schema hash. Returns no rows when
+    /// none was recorded; pass the value to [`check_schema_hash`].
+    #[must_use]
+    pub fn schema_hash_sql(&self) -> String {
+        let table = self.table_ident();
+        format!(r#"SELECT "hash" FROM {table} WHERE id = -1;"#)
+    }
+
     /// Get the SQL to check if migrations table exists
     #[must_use]
     pub fn table_exists_sql(&self) -> String {
@@ -495,6 +823,19 @@ impl Migrations {
     }
 }
 
+fn pending_in<'a, S>(
+    migrations: &'a [Migration],
+    applied_names: &'a [S],
+) -> impl Iterator<Item = &'a Migration>
+where
+    S: AsRef<str>,
+{
+    migrations.iter().filter(move |m| {
+        let name = m.name();
+        name.is_empty() || !applied_names.iter().any(|applied| applied.as_ref() == name)
+    })
+}
+
 /// Errors that can occur during migration
 #[derive(Debug, thiserror::Error)]
 pub enum MigratorError {
@@ -509,6 +850,57 @@ pub enum MigratorError {
 
     #[error("Migration failed: {0}")]
     ExecutionError(String),
+
+    #[error(
+        "Applied migrations were edited after they ran: {}",
+        .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+    )]
+    ChecksumMismatch(Vec<ChecksumMismatch>),
+
+    #[error("No schema hash recorded; run migrate() with Tracking::schema_hash first")]
+    SchemaHashMissing,
+
+    #[error(
+        "Database schema hash {recorded:016x} does not match the application's {expected:016x}"
+    )]
+    SchemaHashMismatch { recorded: u64, expected: u64 },
+}
+
+/// Compare a schema hash read with [`Migrations::schema_hash_sql`] against
+/// the application's `expected` hash.
+///
+/// # Errors
+///
+/// Returns [`MigratorError::SchemaHashMissing`] if nothing was recorded and
+/// [`MigratorError::SchemaHashMismatch`] if the hashes differ.
+pub fn check_schema_hash(recorded: Option<&str>, expected: u64) -> Result<(), MigratorError> {
+    let recorded = recorded
+        .and_then(|value| value.strip_prefix(SCHEMA_HASH_PREFIX))
+        .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+        .ok_or(MigratorError::SchemaHashMissing)?;
+    if recorded == expected {
+        Ok(())
+    } else {
+        Err(MigratorError::SchemaHashMismatch { recorded, expected })
+    }
+}
+
+/// Comment that marks hand-written migration SQL as SQL that must run
+/// outside the wrapping transaction (e.g. `ALTER TYPE ... ADD VALUE` on old
+/// PostgreSQL versions, or `VACUUM`).
+///
+/// As the first line of `migration.sql` it applies to every statement of the
+/// migration; as the first line of a later statement (after a
+/// `--> statement-breakpoint`) it applies to that statement only.
+pub const NO_TRANSACTION_MARKER: &str = "-- drizzle:no-transaction";
+
+/// Whether a migration statement must run outside the wrapping transaction:
+/// either a `CREATE/DROP INDEX CONCURRENTLY` or a statement starting with
+/// [`NO_TRANSACTION_MARKER`].
+#[must_use]
+pub fn requires_no_transaction(sql: &str) -> bool {
+    let sql = sql.trim_start();
+    sql.starts_with(NO_TRANSACTION_MARKER) || is_postgres_concurrent_index_statement(sql)
 }
 
 /// Detect PostgreSQL `CREATE/DROP INDEX CONCURRENTLY` statements.
@@ -948,8 +1340,8 @@ macro_rules! migrations {
 mod tests {
     use super::{
         AppliedMigrationMetadata, Migrations, compute_hash, is_postgres_concurrent_index_statement,
-        match_applied_migration_metadata, parse_timestamp_from_tag, split_on_semicolons,
-        split_statements,
+        match_applied_migration_metadata, parse_timestamp_from_tag, requires_no_transaction,
+        split_on_semicolons, split_statements,
     };
     use crate::config::Tracking;
     use crate::dir::MigrationDir;
@@ -1111,6 +1503,35 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn steps_flag_statements_that_need_no_transaction() {
+        let migration = super::Migration::new(
+            "20240101000000_indexes",
+            "CREATE TABLE t(id INTEGER);\n--> statement-breakpoint\n\
+             CREATE INDEX CONCURRENTLY t_id ON t (id);\n--> statement-breakpoint\n\
+             -- drizzle:no-transaction\nALTER TYPE mood ADD VALUE 'meh';",
+        );
+
+        let flags = migration
+            .steps()
+            .map(|step| step.no_transaction)
+            .collect::<Vec<_>>();
+        assert_eq!(flags, vec![false, true, true]);
+        assert!(!requires_no_transaction("CREATE INDEX t_id ON t (id)"));
+        assert!(!migration.is_no_transaction());
+    }
+
+    #[test]
+    fn no_transaction_header_flags_every_step() {
+        let migration = super::Migration::new(
+            "20240101000000_vacuum",
+            "-- drizzle:no-transaction\nDELETE FROM logs;\n--> statement-breakpoint\nVACUUM;",
+        );
+
+        assert!(migration.is_no_transaction());
+        assert!(migration.steps().all(|step| step.no_transaction));
+    }
+
     #[test]
     fn postgres_advisory_lock_key_is_stable_per_tracking_table() {
         let first = Migrations::with_tracking(
@@ -1218,6 +1639,62 @@ mod tests {
         assert_eq!(pending, vec!["20240101010101_feature_a".to_string()]);
     }
 
+    #[test]
+    fn verify_reports_edited_migrations_and_strict_rejects_them() {
+        let migrations = vec![
+            super::Migration::new("20240101000000_init", "CREATE TABLE a(id INTEGER);"),
+            super::Migration::new("20240102000000_more", "CREATE TABLE b(id INTEGER);"),
+        ];
+        let applied = [
+            (
+                "20240101000000_init",
+                compute_hash("CREATE TABLE a(id INTEGER);"),
+            ),
+            ("20240102000000_more", "stale".to_string()),
+            ("20230101000000_gone", "other".to_string()),
+        ];
+
+        let lenient = Migrations::new(migrations.clone(), Dialect::SQLite);
+        let mismatches = lenient.verify(&applied);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].name, "20240102000000_more");
+        assert_eq!(mismatches[0].recorded, "stale");
+        assert!(lenient.check_checksums(&applied).is_ok());
+
+        let strict = Migrations::with_tracking(
+            migrations,
+            Dialect::SQLite,
+            crate::Tracking::SQLITE.strict(true),
+        );
+        let err = strict.check_checksums(&applied).unwrap_err();
+        assert!(err.to_string().contains("20240102000000_more"));
+    }
+
+    #[test]
+    fn update_hash_sql_rebaselines_by_name() {
+        let set = Migrations::new(Vec::new(), Dialect::SQLite);
+        let migration = super::Migration::with_hash("20240101000000_it's", "abc", 0, Vec::new());
+
+        assert_eq!(
+            set.update_hash_sql(&migration),
+            r#"UPDATE "__drizzle_migrations" SET "hash" = 'abc' WHERE "name" = '20240101000000_it''s';"#
+        );
+    }
+
+    #[test]
+    fn lock_marker_sql_targets_reserved_row() {
+        let set = Migrations::new(Vec::new(), Dialect::SQLite);
+
+        assert_eq!(
+            set.claim_lock_sql(),
+            r#"INSERT INTO "__drizzle_migrations" (id, "hash") VALUES (0, '__drizzle_migrate_lock');"#
+        );
+        assert_eq!(
+            set.release_lock_sql(),
+            r#"DELETE FROM "__drizzle_migrations" WHERE id = 0 AND "hash" = '__drizzle_migrate_lock';"#
+        );
+    }
+
     #[test]
     fn applied_names_sql_selects_only_non_null_rows() {
         let set = Migrations::new(Vec::new(), Dialect::PostgreSQL);
@@ -1244,6 +1721,113 @@ mod tests {
         assert!(sql.contains("20230331141203_test"));
     }
 
+    #[test]
+    fn pending_up_to_stops_at_tag() {
+        let migrations = ["20240101000000_a", "20240102000000_b", "20240103000000_c"]
+            .into_iter()
+            .map(|tag| super::Migration::new(tag, "SELECT 1;"))
+            .collect::<Vec<_>>();
+        let set = Migrations::new(migrations, Dialect::SQLite);
+        let applied = ["20240101000000_a"];
+
+        let tags = |iter: &mut dyn Iterator<Item = &super::Migration>| {
+            iter.map(|m| m.tag().to_string()).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            tags(&mut set.pending_up_to(&applied, "20240102000000_b").unwrap()),
+            ["20240102000000_b"]
+        );
+        assert!(
+            set.pending_up_to(&applied, "20240101000000_a")
+                .unwrap()
+                .next()
+                .is_none()
+        );
+        assert!(matches!(
+            set.pending_up_to(&applied, "missing").map(|_| ()),
+            Err(super::MigratorError::MissingMigration(tag)) if tag == "missing"
+        ));
+
+        let set = set.up_to("20240102000000_b").unwrap();
+        assert_eq!(tags(&mut set.pending(&applied)), ["20240102000000_b"]);
+        assert_eq!(set.all().len(), 3);
+    }
+
+    #[test]
+    fn audited_postgres_sets_record_runner_metadata() {
+        let migration = super::Migration::with_hash(
+            "20230331141203_test",
+            "abc123",
+            1_680_271_923_000,
+            vec![
+                "CREATE TABLE users(id INTEGER PRIMARY KEY)".to_string(),
+                "CREATE INDEX users_id ON users(id)".to_string(),
+            ],
+        );
+        let duration = std::time::Duration::from_millis(42);
+
+        let plain = Migrations::new(vec![migration.clone()], Dialect::PostgreSQL);
+        assert!(plain.add_audit_columns_sql().is_none());
+        assert_eq!(
+            plain.record_applied_migration_sql(&migration, duration),
+            plain.record_migration_sql(&migration)
+        );
+
+        let audited = Migrations::with_tracking(
+            vec![migration.clone()],
+            Dialect::PostgreSQL,
+            Tracking::POSTGRES.audit(true),
+        );
+        let alter = audited.add_audit_columns_sql().expect("audit columns");
+        assert!(alter.starts_with("ALTER TABLE \"drizzle\".\"__drizzle_migrations\""));
+        assert!(alter.contains("ADD COLUMN IF NOT EXISTS \"applied_by\" TEXT"));
+        let sql = audited.record_applied_migration_sql(&migration, duration);
+        assert!(sql.contains("\"applied_by\", \"duration_ms\", \"statement_count\""));
+        assert!(sql.contains("inet_client_addr()"));
+        assert!(sql.ends_with(", 42, 2);"));
+
+        // Audit columns are PostgreSQL-only.
+        let sqlite = Migrations::with_tracking(
+            vec![migration.clone()],
+            Dialect::SQLite,
+            Tracking::SQLITE.audit(true),
+        );
+        assert!(!sqlite.is_audited());
+        assert!(sqlite.add_audit_columns_sql().is_none());
+    }
+
+    #[test]
+    fn schema_hash_row_round_trips() {
+        let plain = Migrations::empty(Dialect::SQLite);
+        assert!(plain.record_schema_hash_sql().is_none());
+
+        let set = Migrations::with_tracking(
+            Vec::new(),
+            Dialect::SQLite,
+            Tracking::SQLITE.schema_hash(0xdead_beef),
+        );
+        assert_eq!(
+            set.record_schema_hash_sql().as_deref(),
+            Some(
+                r#"INSERT INTO "__drizzle_migrations" (id, "hash") VALUES (-1, '__drizzle_schema:00000000deadbeef') ON CONFLICT (id) DO UPDATE SET "hash" = excluded."hash";"#
+            )
+        );
+
+        let recorded = "__drizzle_schema:00000000deadbeef";
+        assert!(super::check_schema_hash(Some(recorded), 0xdead_beef).is_ok());
+        assert!(matches!(
+            super::check_schema_hash(Some(recorded), 1),
+            Err(super::MigratorError::SchemaHashMismatch {
+                recorded: 0xdead_beef,
+                expected: 1
+            })
+        ));
+        assert!(matches!(
+            super::check_schema_hash(None, 1),
+            Err(super::MigratorError::SchemaHashMissing)
+        ));
+    }
+
     #[test]
     fn match_applied_metadata_prefers_hash_when_created_at_collides() {
         let migrations = vec![
@@ -221,12 +221,14 @@ pub fn parse_index_struct(input: &str) -> IResult<&str, ParsedIndex> {
     let (input, cols_content) = take_until(")").parse(input)?;
     let (input, _) = char(')').parse(input)?;
 
-    // Parse columns (comma-separated path expressions)
-    let columns: Vec<String> = cols_content
+    // Parse columns (comma-separated path expressions, optionally prefixed
+    // with a `#[collate = "..."]` member attribute)
+    let (columns, collations): (Vec<String>, Vec<Option<String>>) = cols_content
         .split(',')
-        .map(|s| s.trim().trim_end_matches(';').to_string())
+        .map(|s| s.trim().trim_end_matches(';'))
         .filter(|s| !s.is_empty())
-        .collect();
+        .map(parse_index_member)
+        .unzip();
 
     // Optional semicolon
     let (input, _) = multispace0.parse(input)?;
@@ -238,11 +240,28 @@ pub fn parse_index_struct(input: &str) -> IResult<&str, ParsedIndex> {
             name: name.to_string(),
             attr: attr.to_string(),
             columns,
+            collations,
             dialect: drizzle_types::Dialect::default(),
         },
     ))
 }
 
+/// Split an index member like `#[collate = "NOCASE"] Users::email` into its
+/// column reference and optional collation.
+fn parse_index_member(member: &str) -> (String, Option<String>) {
+    let Some(attr) = member.strip_prefix("#[") else {
+        return (member.to_string(), None);
+    };
+    let Some((attr, column)) = attr.split_once(']') else {
+        return (member.to_string(), None);
+    };
+    let collate = attr.split_once('=').and_then(|(key, value)| {
+        matches!(key.trim(), "collate" | "collation")
+            .then(|| value.trim().trim_matches('"').to_string())
+    });
+    (column.trim().to_string(), collate)
+}
+
 // =============================================================================
 // Schema Parsing
 // =============================================================================
@@ -378,6 +397,14 @@ struct Users {
         assert_eq!(index.columns, vec!["Users::email"]);
     }
 
+    #[test]
+    fn test_parse_index_struct_with_collation() {
+        let code = "#[SQLiteIndex]\nstruct IdxUsersEmail(#[collate = \"NOCASE\"] Users::email, Users::name);";
+        let (_, index) = parse_index_struct(code).unwrap();
+        assert_eq!(index.columns, vec!["Users::email", "Users::name"]);
+        assert_eq!(index.collations, vec![Some("NOCASE".to_string()), None]);
+    }
+
     #[test]
     fn test_nullable_detection() {
         let (_, field) = parse_field("email: Option<String>,").unwrap();
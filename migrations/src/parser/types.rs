@@ -2,7 +2,7 @@
 
 use std::collections::HashMap;
 
-use drizzle_types::Dialect;
+use drizzle_types::{Casing, Dialect};
 
 // =============================================================================
 // Parsed Types
@@ -30,6 +30,9 @@ pub struct ParsedIndex {
     pub attr: String,
     /// Column references (e.g., `["Users::id", "Users::name"]`)
     pub columns: Vec<String>,
+    /// Per-column collations from `#[collate = "..."]` member attributes,
+    /// parallel to `columns`
+    pub collations: Vec<Option<String>>,
     /// Detected dialect
     pub dialect: Dialect,
 }
@@ -99,6 +102,14 @@ impl ParsedTable {
             .map(|v| trim_wrapping_quotes(v.trim()).to_string())
     }
 
+    /// Get the naming casing set with `CASING = "..."`, if any.
+    #[must_use]
+    pub fn casing(&self) -> Option<Casing> {
+        self.attr_value("casing")
+            .or_else(|| self.attr_value("CASING"))
+            .and_then(|v| trim_wrapping_quotes(v.trim()).parse().ok())
+    }
+
     /// Check if table is marked as `SQLite` STRICT.
     #[must_use]
     pub fn is_strict(&self) -> bool {
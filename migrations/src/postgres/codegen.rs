@@ -6,7 +6,8 @@
 
 use super::collection::PostgresDDL;
 use super::ddl::{
-    CheckConstraint, Column, Enum, ForeignKey, Index, Policy, Table, UniqueConstraint, View,
+    CheckConstraint, Column, Composite, Domain, Enum, ForeignKey, Index, Policy, Table,
+    UniqueConstraint, View,
 };
 use crate::utils::escape_for_rust_literal;
 use heck::{ToLowerCamelCase, ToPascalCase, ToSnakeCase};
@@ -20,6 +21,10 @@ pub struct GeneratedSchema {
     pub code: String,
     /// Enums that were generated
     pub enums: Vec<String>,
+    /// Domains that were generated
+    pub domains: Vec<String>,
+    /// Composite types that were generated
+    pub composites: Vec<String>,
     /// Tables that were generated
     pub tables: Vec<String>,
     /// Indexes that were generated
@@ -90,6 +95,8 @@ fn apply_field_casing(name: &str, casing: FieldCasing) -> String {
 /// per-entity generation pass.
 struct SchemaMaps<'a> {
     enum_map: HashMap<(String, String), String>,
+    domain_map: HashMap<(String, String), &'a Domain>,
+    composite_map: HashMap<(String, String), String>,
     table_columns: HashMap<(String, String), Vec<&'a Column>>,
     table_pks: HashMap<(String, String), HashSet<String>>,
     single_unique_columns: HashMap<(String, String), HashSet<String>>,
@@ -105,6 +112,19 @@ fn build_schema_maps(ddl: &PostgresDDL) -> SchemaMaps<'_> {
         enum_map.insert((e.schema.to_string(), e.name.to_string()), type_name);
     }
 
+    let mut domain_map: HashMap<(String, String), &Domain> = HashMap::new();
+    for d in ddl.domains.list() {
+        domain_map.insert((d.schema.to_string(), d.name.to_string()), d);
+    }
+
+    let mut composite_map: HashMap<(String, String), String> = HashMap::new();
+    for c in ddl.composites.list() {
+        composite_map.insert(
+            (c.schema.to_string(), c.name.to_string()),
+            c.name.to_pascal_case(),
+        );
+    }
+
     let mut table_columns: HashMap<(String, String), Vec<&Column>> = HashMap::new();
     for column in ddl.columns.list() {
         table_columns
@@ -161,6 +181,8 @@ fn build_schema_maps(ddl: &PostgresDDL) -> SchemaMaps<'_> {
 
     SchemaMaps {
         enum_map,
+        domain_map,
+        composite_map,
         table_columns,
         table_pks,
         single_unique_columns,
@@ -201,6 +223,25 @@ pub fn generate_rust_schema(ddl: &PostgresDDL, options: &CodegenOptions) -> Gene
         result.enums.push(e.name.to_string());
     }
 
+    // Generate domain definitions
+    for d in ddl.domains.list() {
+        code.push_str(&generate_domain_struct(d, options.use_pub));
+        code.push('\n');
+        result.domains.push(d.name.to_string());
+    }
+
+    // Generate composite type definitions
+    for c in ddl.composites.list() {
+        code.push_str(&generate_composite_struct(
+            c,
+            &maps.enum_map,
+            options.use_pub,
+            options.field_casing,
+        ));
+        code.push('\n');
+        result.composites.push(c.name.to_string());
+    }
+
     // Generate table structs
     for table in ddl.tables.list() {
         let key = (table.schema.to_string(), table.name.to_string());
@@ -230,6 +271,8 @@ pub fn generate_rust_schema(ddl: &PostgresDDL, options: &CodegenOptions) -> Gene
             is_composite_pk,
             fk_map: &maps.fk_map,
             enum_map: &maps.enum_map,
+            domain_map: &maps.domain_map,
+            composite_map: &maps.composite_map,
             use_pub: options.use_pub,
             field_casing: options.field_casing,
         }));
@@ -278,6 +321,8 @@ pub fn generate_rust_schema(ddl: &PostgresDDL, options: &CodegenOptions) -> Gene
     if options.include_schema {
         code.push_str(&generate_schema_struct(
             &options.schema_name,
+            &result.domains,
+            &result.composites,
             &result.tables,
             &result.indexes,
             &result.policies,
@@ -301,6 +346,8 @@ struct TableGenContext<'a> {
     is_composite_pk: bool,
     fk_map: &'a HashMap<(String, String, String), (&'a ForeignKey, usize)>,
     enum_map: &'a HashMap<(String, String), String>,
+    domain_map: &'a HashMap<(String, String), &'a Domain>,
+    composite_map: &'a HashMap<(String, String), String>,
     use_pub: bool,
     field_casing: FieldCasing,
 }
@@ -611,6 +658,16 @@ fn generate_column_field(column: &Column, ctx: &TableGenContext<'_>) -> String {
         .enum_map
         .get(&(type_schema.to_string(), column.sql_type.to_string()));
 
+    // Check if this column uses a domain type
+    let domain = ctx
+        .domain_map
+        .get(&(type_schema.to_string(), column.sql_type.to_string()));
+
+    // Check if this column uses a composite type
+    let composite_type = ctx
+        .composite_map
+        .get(&(type_schema.to_string(), column.sql_type.to_string()));
+
     // Build column attributes
     let mut attrs = Vec::new();
 
@@ -638,6 +695,28 @@ fn generate_column_field(column: &Column, ctx: &TableGenContext<'_>) -> String {
         attrs.push("enum".to_string());
     }
 
+    // Add "domain" attribute for domain-typed columns
+    if let Some(domain) = domain {
+        attrs.push(format!("domain = {}", domain.name.to_pascal_case()));
+    }
+
+    // MAC addresses map to byte arrays, which would otherwise infer BYTEA
+    if domain.is_none() && column.dimensions.is_none_or(|dims| dims == 0) {
+        if column.sql_type.eq_ignore_ascii_case("macaddr") {
+            attrs.push("macaddr".to_string());
+        } else if column.sql_type.eq_ignore_ascii_case("macaddr8") {
+            attrs.push("macaddr8".to_string());
+        } else if let Some((kind, srid)) = geometry_type_parts(&column.sql_type) {
+            attrs.push(match (kind, srid) {
+                (Some(kind), Some(srid)) => format!("geometry({kind}, srid = {srid})"),
+                (Some(kind), None) => format!("geometry({kind})"),
+                (None, _) => "geometry".to_string(),
+            });
+        } else if let Some(dims) = vector_type_dimensions(&column.sql_type) {
+            attrs.push(dims.map_or_else(|| "vector".to_string(), |dims| format!("vector({dims})")));
+        }
+    }
+
     if let Some(collate) = &column.collate {
         attrs.push(format!(
             "collate = \"{}\"",
@@ -692,14 +771,12 @@ fn generate_column_field(column: &Column, ctx: &TableGenContext<'_>) -> String {
         let _ = writeln!(result, "    #[column({})]", attrs.join(", "));
     }
 
-    // Determine Rust type - use enum type if available, otherwise map SQL type
-    let rust_type = enum_type.map_or_else(
+    // Determine Rust type - use the enum or composite struct if available, the
+    // domain's base type for domain columns, otherwise map SQL type
+    let rust_type = enum_type.or(composite_type).map_or_else(
         || {
-            sql_type_to_rust_type_with_dimensions(
-                &column.sql_type,
-                column.dimensions,
-                column.not_null,
-            )
+            let sql_type = domain.map_or(&column.sql_type, |d| &d.base_type);
+            sql_type_to_rust_type_with_dimensions(sql_type, column.dimensions, column.not_null)
         },
         |enum_name| {
             if column.not_null {
@@ -742,6 +819,68 @@ fn generate_enum_struct(e: &Enum, use_pub: bool) -> String {
     code
 }
 
+/// Generate a Rust unit struct from a `PostgreSQL` domain
+fn generate_domain_struct(d: &Domain, use_pub: bool) -> String {
+    let struct_name = d.name.to_pascal_case();
+    let vis = if use_pub { "pub " } else { "" };
+
+    let mut attrs = vec![
+        format!("name = \"{}\"", escape_for_rust_literal(&d.name)),
+        format!("base = \"{}\"", escape_for_rust_literal(&d.base_type)),
+    ];
+    if d.not_null {
+        attrs.push("not_null".to_string());
+    }
+    if let Some(default) = &d.default {
+        attrs.push(format!(
+            "default = \"{}\"",
+            escape_for_rust_literal(default)
+        ));
+    }
+    if let Some(check) = &d.check {
+        attrs.push(format!("check = \"{}\"", escape_for_rust_literal(check)));
+    }
+
+    let mut code = String::new();
+    let _ = writeln!(code, "#[PostgresDomain({})]", attrs.join(", "));
+    let _ = writeln!(code, "{vis}struct {struct_name};");
+    code
+}
+
+/// Generate a Rust struct from a `PostgreSQL` composite type
+///
+/// Composite attributes can always be NULL, so every field is optional.
+fn generate_composite_struct(
+    c: &Composite,
+    enum_map: &HashMap<(String, String), String>,
+    use_pub: bool,
+    field_casing: FieldCasing,
+) -> String {
+    let struct_name = c.name.to_pascal_case();
+    let vis = if use_pub { "pub " } else { "" };
+
+    let mut code = String::new();
+    let _ = writeln!(
+        code,
+        "#[PostgresComposite(name = \"{}\")]",
+        escape_for_rust_literal(&c.name)
+    );
+    code.push_str("#[derive(Debug, Clone, PartialEq)]\n");
+    let _ = writeln!(code, "{vis}struct {struct_name} {{");
+    for field in &c.fields {
+        let field_name = apply_field_casing(&field.name, field_casing);
+        let rust_type = enum_map
+            .get(&(c.schema.to_string(), field.sql_type.to_string()))
+            .map_or_else(
+                || sql_type_to_rust_type(&field.sql_type, false),
+                |enum_name| format!("Option<{enum_name}>"),
+            );
+        let _ = writeln!(code, "    {vis}{field_name}: {rust_type},");
+    }
+    code.push_str("}\n");
+    code
+}
+
 /// Format a default value for Rust syntax
 fn format_default_value(default: &str, sql_type: &str) -> Option<String> {
     let default = default.trim();
@@ -804,6 +943,23 @@ pub fn sql_type_to_rust_type(sql_type: &str, not_null: bool) -> String {
         };
     }
 
+    if let Some((kind, _)) = geometry_type_parts(sql_type) {
+        let base = format!("geo_types::{}<f64>", geo_shape_for_kind(kind));
+        return if not_null {
+            base
+        } else {
+            format!("Option<{base}>")
+        };
+    }
+
+    if vector_type_dimensions(sql_type).is_some() {
+        return if not_null {
+            "Vec<f32>".to_string()
+        } else {
+            "Option<Vec<f32>>".to_string()
+        };
+    }
+
     let base_type = match sql_type {
         // Integer types
         s if s.eq_ignore_ascii_case("int2") || s.eq_ignore_ascii_case("smallint") => "i16",
@@ -855,6 +1011,20 @@ pub fn sql_type_to_rust_type(sql_type: &str, not_null: bool) -> String {
             "serde_json::Value"
         }
 
+        // Network address types
+        s if s.eq_ignore_ascii_case("inet") => "cidr::IpInet",
+        s if s.eq_ignore_ascii_case("cidr") => "cidr::IpCidr",
+        s if s.eq_ignore_ascii_case("macaddr") => "[u8; 6]",
+        s if s.eq_ignore_ascii_case("macaddr8") => "[u8; 8]",
+
+        // Range types
+        s if s.eq_ignore_ascii_case("int4range") => "PgRange<i32>",
+        s if s.eq_ignore_ascii_case("int8range") => "PgRange<i64>",
+        s if s.eq_ignore_ascii_case("numrange") => "PgRange<rust_decimal::Decimal>",
+        s if s.eq_ignore_ascii_case("daterange") => "PgRange<chrono::NaiveDate>",
+        s if s.eq_ignore_ascii_case("tsrange") => "PgRange<chrono::NaiveDateTime>",
+        s if s.eq_ignore_ascii_case("tstzrange") => "PgRange<chrono::DateTime<chrono::Utc>>",
+
         // Default to String for unknown types
         _ => "String",
     };
@@ -866,6 +1036,54 @@ pub fn sql_type_to_rust_type(sql_type: &str, not_null: bool) -> String {
     }
 }
 
+/// Split a PostGIS `geometry`, `geometry(Point)` or `geometry(Point,4326)`
+/// type into its lowercased subtype and SRID.
+fn geometry_type_parts(sql_type: &str) -> Option<(Option<String>, Option<i32>)> {
+    let ty = sql_type.trim();
+    let rest = ty
+        .get(..8)
+        .filter(|head| head.eq_ignore_ascii_case("geometry"))
+        .map(|_| ty[8..].trim())?;
+    if rest.is_empty() {
+        return Some((None, None));
+    }
+    let mut parts = rest
+        .strip_prefix('(')?
+        .strip_suffix(')')?
+        .split(',')
+        .map(str::trim);
+    let kind = parts.next().map(str::to_ascii_lowercase);
+    let srid = parts.next().and_then(|srid| srid.parse().ok());
+    Some((kind, srid))
+}
+
+/// Split a pgvector `vector` or `vector(1536)` type into its dimension count.
+fn vector_type_dimensions(sql_type: &str) -> Option<Option<u32>> {
+    let ty = sql_type.trim();
+    let rest = ty
+        .get(..6)
+        .filter(|head| head.eq_ignore_ascii_case("vector"))
+        .map(|_| ty[6..].trim())?;
+    if rest.is_empty() {
+        return Some(None);
+    }
+    let dims = rest.strip_prefix('(')?.strip_suffix(')')?.trim();
+    Some(Some(dims.parse().ok()?))
+}
+
+/// `geo_types` shape for a PostGIS geometry subtype.
+fn geo_shape_for_kind(kind: Option<String>) -> &'static str {
+    match kind.as_deref() {
+        Some("point") => "Point",
+        Some("linestring") => "LineString",
+        Some("polygon") => "Polygon",
+        Some("multipoint") => "MultiPoint",
+        Some("multilinestring") => "MultiLineString",
+        Some("multipolygon") => "MultiPolygon",
+        _ => "Geometry",
+    }
+}
+
 /// Convert `PostgreSQL` type and array dimensions to a Rust type.
 #[must_use]
 pub fn sql_type_to_rust_type_with_dimensions(
@@ -919,6 +1137,9 @@ fn generate_index_struct(index: &Index, use_pub: bool, field_casing: FieldCasing
     {
         attrs.push(format!("method = \"{}\"", escape_for_rust_literal(method)));
     }
+    if let Some(with) = &index.with {
+        attrs.push(format!("with = \"{}\"", escape_for_rust_literal(with)));
+    }
     if let Some(where_clause) = &index.where_clause {
         attrs.push(format!(
             "where = \"{}\"",
@@ -936,7 +1157,7 @@ fn generate_index_struct(index: &Index, use_pub: bool, field_casing: FieldCasing
         .columns
         .iter()
         .map(|c| {
-            if c.is_expression {
+            let member = if c.is_expression {
                 format!("\"{}\"", c.value) // Expression indexes use string literals
             } else {
                 format!(
@@ -944,6 +1165,20 @@ fn generate_index_struct(index: &Index, use_pub: bool, field_casing: FieldCasing
                     table_name,
                     apply_field_casing(c.value.as_ref(), field_casing)
                 )
+            };
+            let member = match &c.opclass {
+                Some(opclass) if !opclass.default => format!(
+                    "#[opclass = \"{}\"] {member}",
+                    escape_for_rust_literal(&opclass.name)
+                ),
+                _ => member,
+            };
+            match &c.collate {
+                Some(collate) => format!(
+                    "#[collate = \"{}\"] {member}",
+                    escape_for_rust_literal(collate)
+                ),
+                None => member,
             }
         })
         .collect();
@@ -1107,8 +1342,11 @@ fn generate_policy_struct(policy: &Policy, use_pub: bool) -> String {
 }
 
 /// Generate a schema struct
+#[allow(clippy::too_many_arguments)]
 fn generate_schema_struct(
     schema_name: &str,
+    domains: &[String],
+    composites: &[String],
     tables: &[String],
     indexes: &[String],
     policies: &[String],
@@ -1123,6 +1361,20 @@ fn generate_schema_struct(
     code.push_str("#[derive(PostgresSchema)]\n");
     let _ = writeln!(code, "{vis}struct {schema_name} {{");
 
+    // Domain fields
+    for domain in domains {
+        let field_name = apply_field_casing(domain, field_casing);
+        let type_name = domain.to_pascal_case();
+        let _ = writeln!(code, "    {vis}{field_name}: {type_name},");
+    }
+
+    // Composite type fields
+    for composite in composites {
+        let field_name = apply_field_casing(composite, field_casing);
+        let type_name = composite.to_pascal_case();
+        let _ = writeln!(code, "    {vis}{field_name}: {type_name}Composite,");
+    }
+
     // Table fields
     for table in tables {
         let field_name = apply_field_casing(table, field_casing);
@@ -1162,6 +1414,20 @@ mod tests {
         assert_eq!(sql_type_to_rust_type("bool", true), "bool");
         assert_eq!(sql_type_to_rust_type("bytea", true), "Vec<u8>");
 
+        assert_eq!(sql_type_to_rust_type("int4range", true), "PgRange<i32>");
+        assert_eq!(sql_type_to_rust_type("inet", true), "cidr::IpInet");
+        assert_eq!(sql_type_to_rust_type("macaddr", false), "Option<[u8; 6]>");
+        assert_eq!(
+            sql_type_to_rust_type("geometry(Point,4326)", true),
+            "geo_types::Point<f64>"
+        );
+        assert_eq!(
+            sql_type_to_rust_type("geometry", false),
+            "Option<geo_types::Geometry<f64>>"
+        );
+        assert_eq!(sql_type_to_rust_type("vector(3)", true), "Vec<f32>");
+        assert_eq!(sql_type_to_rust_type("vector", false), "Option<Vec<f32>>");
+
         // Nullable types
         assert_eq!(sql_type_to_rust_type("int4", false), "Option<i32>");
         assert_eq!(sql_type_to_rust_type("text", false), "Option<String>");
@@ -6,8 +6,8 @@
 //! Postgres entity's identity (`(schema, name)`, `(schema, table, name)`).
 
 use super::ddl::{
-    CheckConstraint, Column, Enum, ForeignKey, Index, Policy, PostgresEntity, PrimaryKey, Role,
-    Schema, Sequence, Table, UniqueConstraint, View,
+    CheckConstraint, Column, Composite, Domain, Enum, ExcludeConstraint, ForeignKey, Index, Policy,
+    PostgresEntity, PrimaryKey, Role, Schema, Sequence, Table, UniqueConstraint, View,
 };
 use crate::collection::EntityCollection;
 use crate::traits::EntityKind;
@@ -36,6 +36,26 @@ impl EntityCollection<Enum> {
     }
 }
 
+// Domain-specific operations
+impl EntityCollection<Domain> {
+    #[must_use]
+    pub fn one(&self, schema: &str, name: &str) -> Option<&Domain> {
+        self.entities
+            .iter()
+            .find(|d| d.schema == schema && d.name == name)
+    }
+}
+
+// Composite-specific operations
+impl EntityCollection<Composite> {
+    #[must_use]
+    pub fn one(&self, schema: &str, name: &str) -> Option<&Composite> {
+        self.entities
+            .iter()
+            .find(|c| c.schema == schema && c.name == name)
+    }
+}
+
 // Sequence-specific operations
 impl EntityCollection<Sequence> {
     #[must_use]
@@ -182,6 +202,23 @@ impl EntityCollection<CheckConstraint> {
     }
 }
 
+// ExcludeConstraint-specific operations
+impl EntityCollection<ExcludeConstraint> {
+    #[must_use]
+    pub fn one(&self, schema: &str, name: &str) -> Option<&ExcludeConstraint> {
+        self.entities
+            .iter()
+            .find(|e| e.schema == schema && e.name == name)
+    }
+    #[must_use]
+    pub fn for_table(&self, schema: &str, table: &str) -> Vec<&ExcludeConstraint> {
+        self.entities
+            .iter()
+            .filter(|e| e.schema == schema && e.table == table)
+            .collect()
+    }
+}
+
 // View-specific operations
 impl EntityCollection<View> {
     #[must_use]
@@ -201,6 +238,8 @@ impl EntityCollection<View> {
 pub struct PostgresDDL {
     pub schemas: EntityCollection<Schema>,
     pub enums: EntityCollection<Enum>,
+    pub domains: EntityCollection<Domain>,
+    pub composites: EntityCollection<Composite>,
     pub sequences: EntityCollection<Sequence>,
     pub roles: EntityCollection<Role>,
     pub policies: EntityCollection<Policy>,
@@ -211,6 +250,7 @@ pub struct PostgresDDL {
     pub pks: EntityCollection<PrimaryKey>,
     pub uniques: EntityCollection<UniqueConstraint>,
     pub checks: EntityCollection<CheckConstraint>,
+    pub excludes: EntityCollection<ExcludeConstraint>,
     pub views: EntityCollection<View>,
 }
 
@@ -236,6 +276,8 @@ impl PostgresDDL {
         match entity {
             PostgresEntity::Schema(s) => self.schemas.push(s),
             PostgresEntity::Enum(e) => self.enums.push(e),
+            PostgresEntity::Domain(d) => self.domains.push(d),
+            PostgresEntity::Composite(c) => self.composites.push(c),
             PostgresEntity::Sequence(s) => self.sequences.push(s),
             PostgresEntity::Role(r) => self.roles.push(r),
             PostgresEntity::Policy(p) => self.policies.push(p),
@@ -246,6 +288,7 @@ impl PostgresDDL {
             PostgresEntity::PrimaryKey(p) => self.pks.push(p),
             PostgresEntity::UniqueConstraint(u) => self.uniques.push(u),
             PostgresEntity::CheckConstraint(c) => self.checks.push(c),
+            PostgresEntity::ExcludeConstraint(e) => self.excludes.push(e),
             PostgresEntity::View(v) => self.views.push(v),
             // Privileges are not yet tracked in the DDL collection.
             PostgresEntity::Privilege(_) => {}
@@ -264,6 +307,12 @@ impl PostgresDDL {
         for e in self.enums.list() {
             entities.push(PostgresEntity::Enum(e.clone()));
         }
+        for e in self.domains.list() {
+            entities.push(PostgresEntity::Domain(e.clone()));
+        }
+        for e in self.composites.list() {
+            entities.push(PostgresEntity::Composite(e.clone()));
+        }
         for e in self.sequences.list() {
             entities.push(PostgresEntity::Sequence(e.clone()));
         }
@@ -293,6 +342,9 @@ impl PostgresDDL {
         for e in self.checks.list() {
             entities.push(PostgresEntity::CheckConstraint(e.clone()));
         }
+        for e in self.excludes.list() {
+            entities.push(PostgresEntity::ExcludeConstraint(e.clone()));
+        }
         for e in self.policies.list() {
             entities.push(PostgresEntity::Policy(e.clone()));
         }
@@ -307,7 +359,11 @@ impl PostgresDDL {
     /// Check if DDL is empty
     #[must_use]
     pub const fn is_empty(&self) -> bool {
-        self.tables.is_empty() && self.enums.is_empty() && self.views.is_empty()
+        self.tables.is_empty()
+            && self.enums.is_empty()
+            && self.domains.is_empty()
+            && self.composites.is_empty()
+            && self.views.is_empty()
     }
 }
 
@@ -349,6 +405,24 @@ fn diff_top_level_entities(left: &PostgresDDL, right: &PostgresDDL, diffs: &mut
         EntityKind::Enum,
         diffs,
     );
+    diff_entity_type_with(
+        left.domains.list(),
+        right.domains.list(),
+        |e| format!("{}.{}", e.schema, e.name),
+        |e| PostgresEntity::Domain(e.clone()),
+        EntityKind::Domain,
+        diffs,
+        domains_equivalent,
+    );
+    diff_entity_type_with(
+        left.composites.list(),
+        right.composites.list(),
+        |e| format!("{}.{}", e.schema, e.name),
+        |e| PostgresEntity::Composite(e.clone()),
+        EntityKind::Composite,
+        diffs,
+        composites_equivalent,
+    );
     diff_entity_type(
         left.sequences.list(),
         right.sequences.list(),
@@ -435,6 +509,15 @@ fn diff_table_entities(left: &PostgresDDL, right: &PostgresDDL, diffs: &mut Vec<
         EntityKind::CheckConstraint,
         diffs,
     );
+    diff_entity_type_with(
+        left.excludes.list(),
+        right.excludes.list(),
+        |e| format!("{}.{}", e.schema, e.name),
+        |e| PostgresEntity::ExcludeConstraint(e.clone()),
+        EntityKind::ExcludeConstraint,
+        diffs,
+        excludes_equivalent,
+    );
     diff_entity_type_with(
         left.policies.list(),
         right.policies.list(),
@@ -551,6 +634,25 @@ fn columns_equivalent(left: &Column, right: &Column) -> bool {
     left == right
 }
 
+fn domains_equivalent(left: &Domain, right: &Domain) -> bool {
+    let mut left = left.clone();
+    let mut right = right.clone();
+    left.base_type = Cow::Owned(normalize_pg_type_for_compare(&left.base_type));
+    right.base_type = Cow::Owned(normalize_pg_type_for_compare(&right.base_type));
+    left == right
+}
+
+fn composites_equivalent(left: &Composite, right: &Composite) -> bool {
+    left.schema == right.schema
+        && left.name == right.name
+        && left.fields.len() == right.fields.len()
+        && left.fields.iter().zip(&right.fields).all(|(l, r)| {
+            l.name == r.name
+                && normalize_pg_type_for_compare(&l.sql_type)
+                    == normalize_pg_type_for_compare(&r.sql_type)
+        })
+}
+
 fn foreign_keys_equivalent(left: &ForeignKey, right: &ForeignKey) -> bool {
     let mut left = left.clone();
     let mut right = right.clone();
@@ -569,6 +671,21 @@ fn policies_equivalent(left: &Policy, right: &Policy) -> bool {
     left == right
 }
 
+/// Introspection always reports exclusion constraint names as explicit and
+/// the index method in lower case.
+fn excludes_equivalent(left: &ExcludeConstraint, right: &ExcludeConstraint) -> bool {
+    left.using.eq_ignore_ascii_case(&right.using)
+        && ExcludeConstraint {
+            name_explicit: false,
+            using: Cow::Borrowed(""),
+            ..left.clone()
+        } == ExcludeConstraint {
+            name_explicit: false,
+            using: Cow::Borrowed(""),
+            ..right.clone()
+        }
+}
+
 fn normalize_fk_action(action: Option<&str>) -> Option<Cow<'static, str>> {
     match action {
         None => None,
@@ -605,7 +722,7 @@ fn collapse_sql_whitespace(value: &str) -> String {
     value.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
-fn normalize_pg_type_for_compare(sql_type: &str) -> String {
+pub(crate) fn normalize_pg_type_for_compare(sql_type: &str) -> String {
     let mut ty = collapse_sql_whitespace(&sql_type.trim().to_ascii_lowercase());
     let mut dimensions = String::new();
 
@@ -5,9 +5,10 @@
 
 use super::collection::{DiffType, EntityDiff, PostgresDDL, diff_ddl};
 use super::statements::{JsonStatement, PostgresGenerator};
-use crate::postgres::ddl::PostgresEntity;
+use crate::postgres::ddl::{Column, Enum, PostgresEntity};
 use crate::postgres::snapshot::PostgresSnapshot;
 use crate::traits::EntityKind;
+use crate::utils::match_column_renames;
 use std::borrow::Cow;
 use std::collections::{BTreeMap, HashSet};
 
@@ -164,10 +165,23 @@ pub struct MigrationDiff {
 /// Compute a full migration diff between two `PostgreSQL` DDL states
 #[must_use]
 pub fn compute_migration(prev: &PostgresDDL, cur: &PostgresDDL) -> MigrationDiff {
+    compute_migration_with(prev, cur, true)
+}
+
+/// Compute a migration diff, optionally skipping heuristic rename detection.
+///
+/// With `detect_renames` off, any schema, table or column missing from `cur`
+/// is dropped and any new one is created.
+#[must_use]
+pub fn compute_migration_with(
+    prev: &PostgresDDL,
+    cur: &PostgresDDL,
+    detect_renames: bool,
+) -> MigrationDiff {
     // Heuristic rename detection (non-interactive):
     // - detect exact schema/table renames before normal diffing
-    // - detect simple column renames: one dropped + one created column in the same table
-    //   with identical column properties (type/nullability/default/etc).
+    // - detect column renames: dropped + created columns in the same table with identical
+    //   column properties (type/nullability/default/etc), paired one-to-one or by position.
     let mut prev_normalized = prev.clone();
     let mut schema_renames: Vec<SchemaRename> = Vec::new();
     let mut table_renames: Vec<TableRename> = Vec::new();
@@ -175,26 +189,36 @@ pub fn compute_migration(prev: &PostgresDDL, cur: &PostgresDDL) -> MigrationDiff
     let mut rename_statements: Vec<JsonStatement> = Vec::new();
     let mut warnings = Vec::new();
 
-    detect_and_apply_postgres_schema_renames(
-        &mut prev_normalized,
-        cur,
-        &mut schema_renames,
-        &mut rename_statements,
-        &mut warnings,
-    );
-    detect_and_apply_postgres_table_renames(
-        &mut prev_normalized,
-        cur,
-        &mut table_renames,
-        &mut rename_statements,
-        &mut warnings,
-    );
+    if detect_renames {
+        detect_and_apply_postgres_schema_renames(
+            &mut prev_normalized,
+            cur,
+            &mut schema_renames,
+            &mut rename_statements,
+            &mut warnings,
+        );
+        detect_and_apply_postgres_table_renames(
+            &mut prev_normalized,
+            cur,
+            &mut table_renames,
+            &mut rename_statements,
+            &mut warnings,
+        );
+        detect_and_apply_postgres_column_renames(
+            &mut prev_normalized,
+            cur,
+            &mut column_renames,
+            &mut rename_statements,
+            &mut warnings,
+        );
+    }
 
-    detect_and_apply_postgres_column_renames(
+    plan_postgres_enum_changes(
         &mut prev_normalized,
         cur,
-        &mut column_renames,
+        detect_renames,
         &mut rename_statements,
+        &mut warnings,
     );
 
     let schema_diff = diff_collections(&prev_normalized, cur);
@@ -204,7 +228,6 @@ pub fn compute_migration(prev: &PostgresDDL, cur: &PostgresDDL) -> MigrationDiff
         .map(PostgresGenerator::statement_to_sql)
         .collect::<Vec<_>>();
     sql_statements.extend(generator.generate(&schema_diff.diffs));
-    collect_enum_removal_warnings(&mut warnings, &schema_diff);
     collect_generated_recreate_warnings(&mut warnings, &schema_diff);
     collect_table_storage_warnings(&mut warnings, &schema_diff);
 
@@ -227,31 +250,120 @@ fn qualified_name(schema: &str, table: &str) -> String {
     }
 }
 
-fn collect_enum_removal_warnings(warnings: &mut Vec<String>, schema_diff: &SchemaDiff) {
-    for diff in schema_diff
-        .diffs
+/// Plan enum changes that `ALTER TYPE ... ADD VALUE` can't express.
+///
+/// Values replaced in place become `RENAME VALUE`s (with rename detection
+/// on); removed or reordered values recreate the type, casting dependent
+/// columns through `text`. `prev` is updated so the regular diff only sees
+/// the additions that are left.
+fn plan_postgres_enum_changes(
+    prev: &mut PostgresDDL,
+    cur: &PostgresDDL,
+    detect_renames: bool,
+    statements: &mut Vec<JsonStatement>,
+    warnings: &mut Vec<String>,
+) {
+    let changed: Vec<(Enum, Enum)> = prev
+        .enums
+        .list()
         .iter()
-        .filter(|diff| diff.diff_type == DiffType::Alter && diff.kind == EntityKind::Enum)
-    {
-        let (Some(PostgresEntity::Enum(old)), Some(PostgresEntity::Enum(new))) =
-            (diff.left.as_ref(), diff.right.as_ref())
-        else {
-            continue;
-        };
+        .filter_map(|old| {
+            let new = cur.enums.one(&old.schema, &old.name)?;
+            (old.values != new.values).then(|| (old.clone(), new.clone()))
+        })
+        .collect();
 
-        for value in old
+    for (old, new) in changed {
+        let removed: Vec<&str> = old
             .values
             .iter()
-            .filter(|old_value| !new.values.iter().any(|new_value| new_value == *old_value))
-        {
+            .filter(|value| !new.values.contains(value))
+            .map(AsRef::as_ref)
+            .collect();
+        let kept_old = old.values.iter().filter(|value| new.values.contains(value));
+        let kept_new = new.values.iter().filter(|value| old.values.contains(value));
+        if removed.is_empty() && kept_old.eq(kept_new) {
+            // Additions only: ALTER TYPE ... ADD VALUE handles them.
+            continue;
+        }
+
+        if detect_renames && old.values.len() == new.values.len() {
+            let renames: Vec<(&str, &str)> = old
+                .values
+                .iter()
+                .zip(new.values.iter())
+                .filter(|(from, to)| from != to)
+                .map(|(from, to)| (from.as_ref(), to.as_ref()))
+                .collect();
+            if renames.iter().all(|(from, to)| {
+                !new.values.iter().any(|value| value == from)
+                    && !old.values.iter().any(|value| value == to)
+            }) {
+                for (from, to) in renames {
+                    statements.push(JsonStatement::RenameEnumValue {
+                        enum_: new.clone(),
+                        from: from.to_string(),
+                        to: to.to_string(),
+                    });
+                }
+                set_enum_values(prev, &new);
+                continue;
+            }
+        }
+
+        for value in &removed {
             warnings.push(format!(
-                "PostgreSQL cannot drop enum value '{}.{}.{value}'; write a manual migration to rebuild dependent data safely.",
+                "Recreating enum '{}.{}' to drop value '{value}'; rows still holding it will fail the cast back to the enum.",
                 old.schema, old.name
             ));
         }
+
+        let columns: Vec<Column> = prev
+            .columns
+            .list()
+            .iter()
+            .filter(|column| column_uses_enum(column, &old))
+            .cloned()
+            .collect();
+        let restore: Vec<Column> = columns
+            .iter()
+            .filter_map(|column| cur.columns.one(&column.schema, &column.table, &column.name))
+            .filter(|column| column_uses_enum(column, &new))
+            .cloned()
+            .collect();
+
+        for column in prev.columns.list_mut() {
+            if let Some(restored) = restore.iter().find(|restored| {
+                restored.schema == column.schema
+                    && restored.table == column.table
+                    && restored.name == column.name
+            }) {
+                column.default.clone_from(&restored.default);
+            }
+        }
+        set_enum_values(prev, &new);
+
+        statements.push(JsonStatement::RecreateEnum {
+            to: new,
+            columns,
+            restore,
+        });
     }
 }
 
+fn set_enum_values(ddl: &mut PostgresDDL, to: &Enum) {
+    for enum_ in ddl.enums.list_mut() {
+        if enum_.schema == to.schema && enum_.name == to.name {
+            enum_.values.clone_from(&to.values);
+        }
+    }
+}
+
+fn column_uses_enum(column: &Column, enum_: &Enum) -> bool {
+    column.type_schema.as_deref().unwrap_or(&column.schema) == enum_.schema
+        && column.sql_type.trim_matches('"') == enum_.name
+}
+
 fn collect_generated_recreate_warnings(warnings: &mut Vec<String>, schema_diff: &SchemaDiff) {
     for diff in schema_diff
         .diffs
@@ -544,6 +656,7 @@ fn detect_and_apply_postgres_column_renames(
     cur: &PostgresDDL,
     out: &mut Vec<ColumnRename>,
     rename_statements: &mut Vec<JsonStatement>,
+    warnings: &mut Vec<String>,
 ) {
     let common_tables: Vec<(String, String)> = prev
         .tables
@@ -556,38 +669,51 @@ fn detect_and_apply_postgres_column_renames(
     for (schema, table) in common_tables {
         let prev_cols = prev.columns.for_table(&schema, &table);
         let cur_cols = cur.columns.for_table(&schema, &table);
+        let prev_names: Vec<&str> = prev_cols.iter().map(|c| c.name.as_ref()).collect();
+        let cur_names: Vec<&str> = cur_cols.iter().map(|c| c.name.as_ref()).collect();
+
+        let matched = match_column_renames(&prev_names, &cur_names, |from, to| {
+            match (
+                prev.columns.one(&schema, &table, from),
+                cur.columns.one(&schema, &table, to),
+            ) {
+                (Some(prev_col), Some(cur_col)) => {
+                    let mut prev_cmp = prev_col.clone();
+                    prev_cmp.name.clone_from(&cur_col.name);
+                    prev_cmp.ordinal_position = cur_col.ordinal_position;
+                    prev_cmp == *cur_col
+                }
+                _ => false,
+            }
+        });
 
-        let prev_names: HashSet<String> = prev_cols.iter().map(|c| c.name.to_string()).collect();
-        let cur_names: HashSet<String> = cur_cols.iter().map(|c| c.name.to_string()).collect();
-
-        let dropped: Vec<String> = prev_names.difference(&cur_names).cloned().collect();
-        let created: Vec<String> = cur_names.difference(&prev_names).cloned().collect();
-
-        if dropped.len() != 1 || created.len() != 1 {
-            continue;
+        if !matched.ambiguous.is_empty() {
+            warnings.push(format!(
+                "Ambiguous PostgreSQL column rename candidates in table '{}.{}' for dropped columns [{}]; no rename was inferred. Use Options::rename_column_in(...) with diff_with or diff_schemas_with to provide an explicit rename hint.",
+                schema,
+                table,
+                matched.ambiguous.join(", ")
+            ));
         }
 
-        let from = &dropped[0];
-        let to = &created[0];
-
-        let prev_col = prev.columns.one(&schema, &table, from);
-        let cur_col = cur.columns.one(&schema, &table, to);
-        if let (Some(prev_col), Some(cur_col)) = (prev_col, cur_col) {
-            let mut prev_cmp = prev_col.clone();
-            prev_cmp.name.clone_from(&cur_col.name);
-            if prev_cmp == *cur_col {
-                out.push(ColumnRename {
-                    schema: schema.clone(),
-                    table: table.clone(),
-                    from: from.clone(),
-                    to: to.clone(),
-                });
-                rename_statements.push(JsonStatement::RenameColumn {
-                    from: Box::new(prev_col.clone()),
-                    to: Box::new(cur_col.clone()),
-                });
-                apply_postgres_column_rename(prev, &schema, &table, from, to);
-            }
+        for (from, to) in matched.renames {
+            let (Some(prev_col), Some(cur_col)) = (
+                prev.columns.one(&schema, &table, &from),
+                cur.columns.one(&schema, &table, &to),
+            ) else {
+                continue;
+            };
+            rename_statements.push(JsonStatement::RenameColumn {
+                from: Box::new(prev_col.clone()),
+                to: Box::new(cur_col.clone()),
+            });
+            out.push(ColumnRename {
+                schema: schema.clone(),
+                table: table.clone(),
+                from: from.clone(),
+                to: to.clone(),
+            });
+            apply_postgres_column_rename(prev, &schema, &table, &from, &to);
         }
     }
 }
@@ -656,6 +782,10 @@ fn apply_postgres_schema_rename(ddl: &mut PostgresDDL, from: &str, to: &str) {
         rewrite_cow(&mut check.schema, from, to);
     }
 
+    for exclude in ddl.excludes.list_mut() {
+        rewrite_cow(&mut exclude.schema, from, to);
+    }
+
     for policy in ddl.policies.list_mut() {
         rewrite_cow(&mut policy.schema, from, to);
     }
@@ -664,6 +794,14 @@ fn apply_postgres_schema_rename(ddl: &mut PostgresDDL, from: &str, to: &str) {
         rewrite_cow(&mut enum_.schema, from, to);
     }
 
+    for domain in ddl.domains.list_mut() {
+        rewrite_cow(&mut domain.schema, from, to);
+    }
+
+    for composite in ddl.composites.list_mut() {
+        rewrite_cow(&mut composite.schema, from, to);
+    }
+
     for sequence in ddl.sequences.list_mut() {
         rewrite_cow(&mut sequence.schema, from, to);
     }
@@ -726,6 +864,15 @@ fn apply_postgres_table_rename(ddl: &mut PostgresDDL, schema: &str, from: &str,
         check.table = to.to_string().into();
     }
 
+    for exclude in ddl
+        .excludes
+        .list_mut()
+        .iter_mut()
+        .filter(|exclude| exclude.schema.as_ref() == schema && exclude.table.as_ref() == from)
+    {
+        exclude.table = to.to_string().into();
+    }
+
     for index in ddl
         .indexes
         .list_mut()
@@ -797,6 +944,20 @@ fn apply_postgres_column_rename(
         }
     }
 
+    // Exclusion constraints
+    for e in ddl
+        .excludes
+        .list_mut()
+        .iter_mut()
+        .filter(|e| e.schema.as_ref() == schema && e.table.as_ref() == table)
+    {
+        for element in e.elements.to_mut().iter_mut() {
+            if !element.is_expression && element.value.as_ref() == from {
+                element.value = to.clone().into();
+            }
+        }
+    }
+
     // FKs (both table side and referenced side)
     for fk in ddl.fks.list_mut().iter_mut() {
         if fk.schema.as_ref() == schema && fk.table.as_ref() == table {
@@ -1310,12 +1471,105 @@ mod tests {
             migration
                 .warnings
                 .iter()
-                .any(|warning| warning.contains("cannot drop enum value")),
+                .any(|warning| warning.contains("to drop value 'archived'")),
             "expected enum removal warning, got {:?}",
             migration.warnings
         );
     }
 
+    #[test]
+    fn enum_value_removal_recreates_type_through_text() {
+        let mut prev_ddl = PostgresDDL::new();
+        prev_ddl.enums.push(Enum::from_strings(
+            "public".to_string(),
+            "status".to_string(),
+            vec![
+                "active".to_string(),
+                "archived".to_string(),
+                "deleted".to_string(),
+            ],
+        ));
+        prev_ddl.tables.push(Table::new("public", "users"));
+        let mut column = Column::new("public", "users", "status", "status");
+        column.type_schema = Some("public".into());
+        column.default = Some("'active'::\"status\"".into());
+        prev_ddl.columns.push(column);
+
+        let mut cur_ddl = prev_ddl.clone();
+        cur_ddl.enums.entities.clear();
+        cur_ddl.enums.push(Enum::from_strings(
+            "public".to_string(),
+            "status".to_string(),
+            vec!["active".to_string(), "archived".to_string()],
+        ));
+
+        let migration = compute_migration(&prev_ddl, &cur_ddl);
+        assert_eq!(
+            migration.sql_statements,
+            vec![
+                "ALTER TABLE \"users\" ALTER COLUMN \"status\" DROP DEFAULT;\n\
+                 ALTER TABLE \"users\" ALTER COLUMN \"status\" SET DATA TYPE text;\n\
+                 DROP TYPE \"status\";\n\
+                 CREATE TYPE \"status\" AS ENUM ('active', 'archived');\n\
+                 ALTER TABLE \"users\" ALTER COLUMN \"status\" SET DATA TYPE status USING \"status\"::status;\n\
+                 ALTER TABLE \"users\" ALTER COLUMN \"status\" SET DEFAULT 'active'::\"status\";"
+            ]
+        );
+    }
+
+    #[test]
+    fn enum_reorder_recreates_type() {
+        let mut prev_ddl = PostgresDDL::new();
+        prev_ddl.enums.push(Enum::from_strings(
+            "public".to_string(),
+            "status".to_string(),
+            vec!["active".to_string(), "archived".to_string()],
+        ));
+
+        let mut cur_ddl = PostgresDDL::new();
+        cur_ddl.enums.push(Enum::from_strings(
+            "public".to_string(),
+            "status".to_string(),
+            vec!["archived".to_string(), "active".to_string()],
+        ));
+
+        let migration = compute_migration(&prev_ddl, &cur_ddl);
+        assert_eq!(
+            migration.sql_statements,
+            vec![
+                "DROP TYPE \"status\";\n\
+                 CREATE TYPE \"status\" AS ENUM ('archived', 'active');"
+            ]
+        );
+        assert!(migration.warnings.is_empty());
+    }
+
+    #[test]
+    fn enum_value_replaced_in_place_is_renamed() {
+        let mut prev_ddl = PostgresDDL::new();
+        prev_ddl.enums.push(Enum::from_strings(
+            "public".to_string(),
+            "status".to_string(),
+            vec!["active".to_string(), "archived".to_string()],
+        ));
+
+        let mut cur_ddl = PostgresDDL::new();
+        cur_ddl.enums.push(Enum::from_strings(
+            "public".to_string(),
+            "status".to_string(),
+            vec!["active".to_string(), "retired".to_string()],
+        ));
+
+        let migration = compute_migration(&prev_ddl, &cur_ddl);
+        assert_eq!(
+            migration.sql_statements,
+            vec!["ALTER TYPE \"status\" RENAME VALUE 'archived' TO 'retired';"]
+        );
+
+        let without_renames = compute_migration_with(&prev_ddl, &cur_ddl, false);
+        assert!(without_renames.sql_statements[0].starts_with("DROP TYPE"));
+    }
+
     #[test]
     fn enum_mid_list_addition_uses_before_clause() {
         let mut prev_ddl = PostgresDDL::new();
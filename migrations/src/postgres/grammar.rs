@@ -108,6 +108,7 @@ pub enum PgTypeCategory {
     Point,
     Line,
     Geometry,
+    Range,
     Serial,
     SmallSerial,
     BigSerial,
@@ -221,6 +222,16 @@ impl PgTypeCategory {
         None
     }
 
+    /// Match the built-in range types. Checked first because `daterange`
+    /// and `int4range` share prefixes with their element types.
+    fn match_range(s: &str) -> Option<Self> {
+        matches!(
+            s,
+            "int4range" | "int8range" | "numrange" | "tsrange" | "tstzrange" | "daterange"
+        )
+        .then_some(Self::Range)
+    }
+
     /// Match network, vector, bit, geometric and other specialized types.
     fn match_specialized(s: &str) -> Option<Self> {
         if s.starts_with("uuid") {
@@ -278,7 +289,8 @@ impl PgTypeCategory {
     pub fn from_sql_type(sql_type: &str) -> Self {
         let s = sql_type.trim().to_lowercase();
 
-        Self::match_numeric(&s)
+        Self::match_range(&s)
+            .or_else(|| Self::match_numeric(&s))
             .or_else(|| Self::match_string_or_json(&s))
             .or_else(|| Self::match_temporal(&s))
             .or_else(|| Self::match_specialized(&s))
@@ -317,6 +329,7 @@ impl PgTypeCategory {
             Self::Point => "point",
             Self::Line => "line",
             Self::Geometry => "geometry",
+            Self::Range => "customType",
             Self::Serial => "serial",
             Self::SmallSerial => "smallserial",
             Self::BigSerial => "bigserial",
@@ -330,6 +343,17 @@ impl PgTypeCategory {
     pub const fn is_serial(&self) -> bool {
         matches!(self, Self::Serial | Self::SmallSerial | Self::BigSerial)
     }
+
+    /// Integer type a serial pseudo-type is stored as
+    #[must_use]
+    pub const fn serial_storage(&self) -> Option<Self> {
+        match self {
+            Self::Serial => Some(Self::Integer),
+            Self::SmallSerial => Some(Self::SmallInt),
+            Self::BigSerial => Some(Self::BigInt),
+            _ => None,
+        }
+    }
 }
 
 // =============================================================================
@@ -738,6 +762,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_sql_type_range() {
+        assert_eq!(
+            PgTypeCategory::from_sql_type("int4range"),
+            PgTypeCategory::Range
+        );
+        assert_eq!(
+            PgTypeCategory::from_sql_type("daterange"),
+            PgTypeCategory::Range
+        );
+        assert_eq!(
+            PgTypeCategory::from_sql_type("tstzrange"),
+            PgTypeCategory::Range
+        );
+    }
+
     #[test]
     fn test_from_sql_type_postgis_surface() {
         assert_eq!(
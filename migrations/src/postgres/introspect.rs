@@ -4,8 +4,9 @@
 //! and extract its schema as DDL entities, matching drizzle-kit introspect.ts
 
 use super::ddl::{
-    CheckConstraint, Column, Enum, ForeignKey, Index, IndexColumn, Policy, PostgresEntity,
-    PrimaryKey, Role, Schema, Sequence, Table, UniqueConstraint, View,
+    CheckConstraint, Column, Composite, CompositeField, Domain, Enum, ExcludeConstraint, ExcludeElement, ForeignKey, Index,
+    IndexColumn, Policy, PostgresEntity, PrimaryKey, Role, Schema, Sequence, Table,
+    UniqueConstraint, View,
 };
 use super::grammar::{
     extract_nextval_sequence, is_serial_expression, is_system_namespace, is_system_role,
@@ -83,6 +84,26 @@ pub struct RawEnumInfo {
     pub values: Vec<String>,
 }
 
+/// Raw domain info
+#[derive(Debug, Clone)]
+pub struct RawDomainInfo {
+    pub schema: String,
+    pub name: String,
+    pub base_type: String,
+    pub not_null: bool,
+    pub default_value: Option<String>,
+    pub check: Option<String>,
+}
+
+/// Raw composite type info
+#[derive(Debug, Clone)]
+pub struct RawCompositeInfo {
+    pub schema: String,
+    pub name: String,
+    pub field_names: Vec<String>,
+    pub field_types: Vec<String>,
+}
+
 /// Raw sequence info
 ///
 /// Value columns are `Option` because `pg_sequences` returns NULL when the
@@ -122,6 +143,7 @@ pub struct RawIndexColumnInfo {
     pub asc: bool,
     pub nulls_first: bool,
     pub opclass: Option<String>,
+    pub collate: Option<String>,
 }
 
 /// Raw foreign key info
@@ -170,6 +192,20 @@ pub struct RawCheckInfo {
     pub expression: String,
 }
 
+/// Raw exclusion constraint info
+#[derive(Debug, Clone)]
+pub struct RawExcludeInfo {
+    pub schema: String,
+    pub table: String,
+    pub name: String,
+    pub method: String,
+    pub columns: Vec<String>,
+    pub operators: Vec<String>,
+    pub where_clause: Option<String>,
+    pub deferrable: bool,
+    pub initially_deferred: bool,
+}
+
 /// Raw view info
 #[derive(Debug, Clone)]
 pub struct RawViewInfo {
@@ -208,6 +244,8 @@ pub struct RawIntrospection {
     pub tables: Vec<RawTableInfo>,
     pub columns: Vec<RawColumnInfo>,
     pub enums: Vec<RawEnumInfo>,
+    pub domains: Vec<RawDomainInfo>,
+    pub composites: Vec<RawCompositeInfo>,
     pub sequences: Vec<RawSequenceInfo>,
     pub views: Vec<RawViewInfo>,
     pub indexes: Vec<RawIndexInfo>,
@@ -215,6 +253,7 @@ pub struct RawIntrospection {
     pub primary_keys: Vec<RawPrimaryKeyInfo>,
     pub unique_constraints: Vec<RawUniqueInfo>,
     pub check_constraints: Vec<RawCheckInfo>,
+    pub exclude_constraints: Vec<RawExcludeInfo>,
     pub roles: Vec<RawRoleInfo>,
     pub policies: Vec<RawPolicyInfo>,
 }
@@ -229,6 +268,12 @@ pub fn assemble_ddl(raw: RawIntrospection) -> super::PostgresDDL {
     for value in process_enums(&raw.enums) {
         ddl.enums.push(value);
     }
+    for domain in process_domains(&raw.domains) {
+        ddl.domains.push(domain);
+    }
+    for composite in process_composites(&raw.composites) {
+        ddl.composites.push(composite);
+    }
     for sequence in process_sequences(&raw.sequences) {
         ddl.sequences.push(sequence);
     }
@@ -259,6 +304,9 @@ pub fn assemble_ddl(raw: RawIntrospection) -> super::PostgresDDL {
     for check in process_check_constraints(&raw.check_constraints) {
         ddl.checks.push(check);
     }
+    for exclude in process_exclude_constraints(&raw.exclude_constraints) {
+        ddl.excludes.push(exclude);
+    }
     for view in process_views(&raw.views) {
         ddl.views.push(view);
     }
@@ -274,6 +322,8 @@ pub fn assemble_ddl(raw: RawIntrospection) -> super::PostgresDDL {
 pub struct IntrospectionResult {
     pub schemas: Vec<Schema>,
     pub enums: Vec<Enum>,
+    pub domains: Vec<Domain>,
+    pub composites: Vec<Composite>,
     pub sequences: Vec<Sequence>,
     pub roles: Vec<Role>,
     pub tables: Vec<Table>,
@@ -283,6 +333,7 @@ pub struct IntrospectionResult {
     pub primary_keys: Vec<PrimaryKey>,
     pub unique_constraints: Vec<UniqueConstraint>,
     pub check_constraints: Vec<CheckConstraint>,
+    pub exclude_constraints: Vec<ExcludeConstraint>,
     pub views: Vec<View>,
     pub policies: Vec<Policy>,
     pub errors: Vec<IntrospectError>,
@@ -318,6 +369,12 @@ impl IntrospectionResult {
         for e in &self.enums {
             snapshot.add_entity(PostgresEntity::Enum(e.clone()));
         }
+        for d in &self.domains {
+            snapshot.add_entity(PostgresEntity::Domain(d.clone()));
+        }
+        for c in &self.composites {
+            snapshot.add_entity(PostgresEntity::Composite(c.clone()));
+        }
         for seq in &self.sequences {
             // Skip sequences owned by serial/bigserial columns — they are
             // auto-managed by PostgreSQL and must not appear in the diff.
@@ -350,6 +407,9 @@ impl IntrospectionResult {
         for check in &self.check_constraints {
             snapshot.add_entity(PostgresEntity::CheckConstraint(check.clone()));
         }
+        for exclude in &self.exclude_constraints {
+            snapshot.add_entity(PostgresEntity::ExcludeConstraint(exclude.clone()));
+        }
         for view in &self.views {
             snapshot.add_entity(PostgresEntity::View(view.clone()));
         }
@@ -377,6 +437,12 @@ impl IntrospectionResult {
         for e in &self.enums {
             entities.push(PostgresEntity::Enum(e.clone()));
         }
+        for d in &self.domains {
+            entities.push(PostgresEntity::Domain(d.clone()));
+        }
+        for c in &self.composites {
+            entities.push(PostgresEntity::Composite(c.clone()));
+        }
         for s in &self.sequences {
             entities.push(PostgresEntity::Sequence(s.clone()));
         }
@@ -404,6 +470,9 @@ impl IntrospectionResult {
         for c in &self.check_constraints {
             entities.push(PostgresEntity::CheckConstraint(c.clone()));
         }
+        for e in &self.exclude_constraints {
+            entities.push(PostgresEntity::ExcludeConstraint(e.clone()));
+        }
         for v in &self.views {
             entities.push(PostgresEntity::View(v.clone()));
         }
@@ -538,6 +607,54 @@ pub fn process_enums(raw_enums: &[RawEnumInfo]) -> Vec<Enum> {
         .collect()
 }
 
+/// Process raw domain info into Domain entities
+#[must_use]
+pub fn process_domains(raw_domains: &[RawDomainInfo]) -> Vec<Domain> {
+    raw_domains
+        .iter()
+        .filter(|d| !is_system_namespace(&d.schema))
+        .map(|d| Domain {
+            schema: d.schema.clone().into(),
+            name: d.name.clone().into(),
+            base_type: d.base_type.clone().into(),
+            not_null: d.not_null,
+            default: d.default_value.clone().map(Into::into),
+            check: d.check.as_deref().map(strip_check_keyword).map(Into::into),
+        })
+        .collect()
+}
+
+/// Process raw composite type info into Composite entities
+#[must_use]
+pub fn process_composites(raw_composites: &[RawCompositeInfo]) -> Vec<Composite> {
+    raw_composites
+        .iter()
+        .filter(|c| !is_system_namespace(&c.schema))
+        .map(|c| Composite {
+            schema: c.schema.clone().into(),
+            name: c.name.clone().into(),
+            fields: c
+                .field_names
+                .iter()
+                .zip(&c.field_types)
+                .map(|(name, sql_type)| CompositeField::new(name.clone(), sql_type.clone()))
+                .collect(),
+        })
+        .collect()
+}
+
+/// Strip the `CHECK (...)` wrapper `pg_get_constraintdef` puts around a
+/// domain check expression.
+fn strip_check_keyword(def: &str) -> String {
+    def.trim()
+        .strip_prefix("CHECK")
+        .map(str::trim_start)
+        .and_then(|rest| rest.strip_prefix('('))
+        .and_then(|rest| rest.strip_suffix(')'))
+        .unwrap_or(def)
+        .to_string()
+}
+
 /// Process raw sequence info into Sequence entities
 #[must_use]
 pub fn process_sequences(raw_sequences: &[RawSequenceInfo]) -> Vec<Sequence> {
@@ -575,6 +692,7 @@ pub fn process_indexes(raw_indexes: &[RawIndexInfo]) -> Vec<Index> {
                     asc: c.asc,
                     nulls_first: c.nulls_first,
                     opclass: c.opclass.clone().map(Opclass::new),
+                    collate: c.collate.clone().map(Into::into),
                 })
                 .collect();
 
@@ -667,6 +785,53 @@ pub fn process_check_constraints(raw_checks: &[RawCheckInfo]) -> Vec<CheckConstr
         .collect()
 }
 
+/// Process raw exclusion constraint info into `ExcludeConstraint` entities
+#[must_use]
+pub fn process_exclude_constraints(raw_excludes: &[RawExcludeInfo]) -> Vec<ExcludeConstraint> {
+    raw_excludes
+        .iter()
+        .filter(|e| !is_system_namespace(&e.schema))
+        .map(|e| ExcludeConstraint {
+            schema: e.schema.clone().into(),
+            table: e.table.clone().into(),
+            name: e.name.clone().into(),
+            name_explicit: true,
+            using: e.method.clone().into(),
+            elements: e
+                .columns
+                .iter()
+                .zip(&e.operators)
+                .map(|(value, operator)| parse_exclude_element(value, operator))
+                .collect(),
+            where_clause: e.where_clause.clone().map(std::convert::Into::into),
+            deferrable: e.deferrable,
+            initially_deferred: e.initially_deferred,
+        })
+        .collect()
+}
+
+/// `pg_get_indexdef` renders plain columns as (possibly quoted) identifiers
+/// and anything else as an expression.
+fn parse_exclude_element(value: &str, operator: &str) -> ExcludeElement {
+    let value = value.trim();
+    let unquoted = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .map(|v| v.replace("\"\"", "\""));
+    let (value, is_expression) = match unquoted {
+        Some(ident) => (ident, false),
+        None => (
+            value.to_string(),
+            value.contains('(') || value.contains(' ') || value.contains("::"),
+        ),
+    };
+    ExcludeElement {
+        value: value.into(),
+        is_expression,
+        operator: operator.to_string().into(),
+    }
+}
+
 /// Process raw view info into View entities
 #[must_use]
 pub fn process_views(raw_views: &[RawViewInfo]) -> Vec<View> {
@@ -777,8 +942,12 @@ pub mod queries {
             c.table_schema AS schema,
             c.table_name AS table,
             c.column_name AS name,
-            c.udt_name AS column_type,
-            c.udt_schema AS type_schema,
+            CASE
+                WHEN c.domain_name IS NULL AND c.udt_name IN ('geometry', 'geography', 'vector')
+                    THEN format_type(a.atttypid, a.atttypmod)
+                ELSE COALESCE(c.domain_name, c.udt_name)
+            END AS column_type,
+            COALESCE(c.domain_schema, c.udt_schema) AS type_schema,
             c.is_nullable = 'NO' AS not_null,
             c.column_default AS default_value,
             c.is_identity = 'YES' AS is_identity,
@@ -823,6 +992,52 @@ pub mod queries {
         ORDER BY n.nspname, t.typname
     ";
 
+    /// Query to get all domains, with their first CHECK constraint
+    pub const DOMAINS_QUERY: &str = r"
+        SELECT
+            n.nspname AS schema,
+            t.typname AS name,
+            format_type(t.typbasetype, t.typtypmod) AS base_type,
+            t.typnotnull AS not_null,
+            t.typdefault AS default_value,
+            (
+                SELECT pg_get_constraintdef(con.oid)
+                FROM pg_constraint con
+                WHERE con.contypid = t.oid AND con.contype = 'c'
+                ORDER BY con.conname
+                LIMIT 1
+            ) AS check
+        FROM pg_type t
+        JOIN pg_namespace n ON n.oid = t.typnamespace
+        WHERE t.typtype = 'd'
+          AND n.nspname NOT LIKE 'pg_%'
+          AND n.nspname != 'information_schema'
+          AND has_schema_privilege(current_user, n.oid, 'USAGE')
+        ORDER BY n.nspname, t.typname
+    ";
+
+    /// Query to get all standalone composite types (excluding table row types)
+    pub const COMPOSITES_QUERY: &str = r"
+        SELECT
+            n.nspname AS schema,
+            t.typname AS name,
+            array_agg(a.attname::text ORDER BY a.attnum) AS field_names,
+            array_agg(format_type(a.atttypid, a.atttypmod) ORDER BY a.attnum) AS field_types
+        FROM pg_type t
+        JOIN pg_namespace n ON n.oid = t.typnamespace
+        JOIN pg_class c ON c.oid = t.typrelid
+        JOIN pg_attribute a ON a.attrelid = c.oid
+        WHERE t.typtype = 'c'
+          AND c.relkind = 'c'
+          AND a.attnum > 0
+          AND NOT a.attisdropped
+          AND n.nspname NOT LIKE 'pg_%'
+          AND n.nspname != 'information_schema'
+          AND has_schema_privilege(current_user, n.oid, 'USAGE')
+        GROUP BY n.nspname, t.typname
+        ORDER BY n.nspname, t.typname
+    ";
+
     /// Query to get all sequences
     ///
     /// Uses the underlying `pg_sequence` + `pg_class` catalog tables instead
@@ -921,6 +1136,12 @@ JOIN pg_am am ON am.oid = idx.relam
 JOIN generate_series(1, ix.indnkeyatts) AS s(n) ON TRUE
 WHERE ns.nspname NOT LIKE 'pg_%'
   AND ns.nspname <> 'information_schema'
+  AND NOT EXISTS (
+      SELECT 1 FROM pg_constraint con
+      WHERE con.conindid = ix.indexrelid
+        AND con.conrelid = ix.indrelid
+        AND con.contype IN ('u', 'x')
+  )
   AND has_schema_privilege(current_user, ns.oid, 'USAGE')
   AND has_table_privilege(current_user, tbl.oid, 'SELECT')
 GROUP BY ns.nspname, tbl.relname, idx.relname, ix.indisunique, ix.indisprimary, am.amname, ix.indpred, ix.indrelid
@@ -950,6 +1171,12 @@ JOIN pg_namespace ns ON ns.oid = tbl.relnamespace
 JOIN pg_am am ON am.oid = idx.relam
 JOIN generate_series(1, ix.indnkeyatts) AS s(n) ON TRUE
 WHERE ns.nspname = ANY($1::text[])
+  AND NOT EXISTS (
+      SELECT 1 FROM pg_constraint con
+      WHERE con.conindid = ix.indexrelid
+        AND con.conrelid = ix.indrelid
+        AND con.contype IN ('u', 'x')
+  )
   AND has_schema_privilege(current_user, ns.oid, 'USAGE')
   AND has_table_privilege(current_user, tbl.oid, 'SELECT')
 GROUP BY ns.nspname, tbl.relname, idx.relname, ix.indisunique, ix.indisprimary, am.amname, ix.indpred, ix.indrelid
@@ -1070,6 +1297,68 @@ WHERE con.contype = 'c'
   AND has_schema_privilege(current_user, ns.oid, 'USAGE')
   AND has_table_privilege(current_user, tbl.oid, 'SELECT')
 ORDER BY ns.nspname, tbl.relname, con.conname
+";
+
+    /// Query to get all exclusion constraints
+    pub const EXCLUDES_QUERY: &str = r"
+SELECT
+    ns.nspname AS schema,
+    tbl.relname AS table,
+    con.conname AS name,
+    am.amname AS method,
+    array_agg(pg_get_indexdef(con.conindid, s.n::int, true) ORDER BY s.n) AS columns,
+    array_agg(op.oprname::text ORDER BY s.n) AS operators,
+    pg_get_expr(ix.indpred, ix.indrelid) AS where_clause,
+    con.condeferrable AS deferrable,
+    con.condeferred AS initially_deferred
+FROM pg_constraint con
+JOIN pg_class tbl ON tbl.oid = con.conrelid
+JOIN pg_namespace ns ON ns.oid = tbl.relnamespace
+JOIN pg_index ix ON ix.indexrelid = con.conindid
+JOIN pg_class idx ON idx.oid = con.conindid
+JOIN pg_am am ON am.oid = idx.relam
+JOIN unnest(con.conexclop) WITH ORDINALITY AS s(oprid, n) ON TRUE
+JOIN pg_operator op ON op.oid = s.oprid
+WHERE con.contype = 'x'
+  AND ns.nspname NOT LIKE 'pg_%'
+  AND ns.nspname <> 'information_schema'
+  AND has_schema_privilege(current_user, ns.oid, 'USAGE')
+  AND has_table_privilege(current_user, tbl.oid, 'SELECT')
+GROUP BY ns.nspname, tbl.relname, con.conname, am.amname, ix.indpred, ix.indrelid,
+    con.condeferrable, con.condeferred
+ORDER BY ns.nspname, tbl.relname, con.conname
+";
+
+    /// Schema-filtered variant of [`EXCLUDES_QUERY`].
+    ///
+    /// Like [`CHECKS_QUERY_FILTERED`], scoped to specific schemas because
+    /// `pg_get_indexdef()` and `pg_get_expr()` are not MVCC-protected.
+    pub const EXCLUDES_QUERY_FILTERED: &str = r"
+SELECT
+    ns.nspname AS schema,
+    tbl.relname AS table,
+    con.conname AS name,
+    am.amname AS method,
+    array_agg(pg_get_indexdef(con.conindid, s.n::int, true) ORDER BY s.n) AS columns,
+    array_agg(op.oprname::text ORDER BY s.n) AS operators,
+    pg_get_expr(ix.indpred, ix.indrelid) AS where_clause,
+    con.condeferrable AS deferrable,
+    con.condeferred AS initially_deferred
+FROM pg_constraint con
+JOIN pg_class tbl ON tbl.oid = con.conrelid
+JOIN pg_namespace ns ON ns.oid = tbl.relnamespace
+JOIN pg_index ix ON ix.indexrelid = con.conindid
+JOIN pg_class idx ON idx.oid = con.conindid
+JOIN pg_am am ON am.oid = idx.relam
+JOIN unnest(con.conexclop) WITH ORDINALITY AS s(oprid, n) ON TRUE
+JOIN pg_operator op ON op.oid = s.oprid
+WHERE con.contype = 'x'
+  AND ns.nspname = ANY($1::text[])
+  AND has_schema_privilege(current_user, ns.oid, 'USAGE')
+  AND has_table_privilege(current_user, tbl.oid, 'SELECT')
+GROUP BY ns.nspname, tbl.relname, con.conname, am.amname, ix.indpred, ix.indrelid,
+    con.condeferrable, con.condeferred
+ORDER BY ns.nspname, tbl.relname, con.conname
 ";
 
     /// Query to get all roles
@@ -1146,12 +1435,12 @@ pub fn pg_action_code_to_string(code: &str) -> String {
 /// Parse raw index column strings from `pg_get_indexdef` into `RawIndexColumnInfo`.
 ///
 /// Each string is a single column expression like `"name"`, `"name DESC"`,
-/// `"lower(name)"`, or `"name text_pattern_ops"`.
+/// `"lower(name)"`, `"name text_pattern_ops"`, or `name COLLATE "C"`.
 #[must_use]
 pub fn parse_index_columns(cols: Vec<String>) -> Vec<RawIndexColumnInfo> {
     cols.into_iter()
         .map(|c| {
-            let trimmed = c.trim().to_string();
+            let (trimmed, collate) = split_index_collation(c.trim());
             let upper = trimmed.to_uppercase();
 
             let asc = !upper.contains(" DESC");
@@ -1191,11 +1480,29 @@ pub fn parse_index_columns(cols: Vec<String>) -> Vec<RawIndexColumnInfo> {
                 asc,
                 nulls_first,
                 opclass,
+                collate,
             }
         })
         .collect()
 }
 
+/// Remove a `COLLATE <name>` clause from an index column definition,
+/// returning the remaining text and the unquoted collation name.
+fn split_index_collation(def: &str) -> (String, Option<String>) {
+    let Some(pos) = def.to_uppercase().find(" COLLATE ") else {
+        return (def.to_string(), None);
+    };
+    let rest = &def[pos + " COLLATE ".len()..];
+    let (name, tail) = if let Some(quoted) = rest.strip_prefix('"') {
+        let end = quoted.find('"').unwrap_or(quoted.len());
+        (&quoted[..end], quoted.get(end + 1..).unwrap_or(""))
+    } else {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        (&rest[..end], &rest[end..])
+    };
+    (format!("{}{}", &def[..pos], tail), Some(name.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1247,12 +1554,94 @@ mod tests {
         assert_eq!(snapshot.ddl.len(), 2);
     }
 
+    #[test]
+    fn test_process_exclude_constraints() {
+        let raw = vec![RawExcludeInfo {
+            schema: "public".to_string(),
+            table: "bookings".to_string(),
+            name: "bookings_room_id_during_excl".to_string(),
+            method: "gist".to_string(),
+            columns: vec![
+                "room_id".to_string(),
+                "\"During\"".to_string(),
+                "tsrange(starts_at, ends_at)".to_string(),
+            ],
+            operators: vec!["=".to_string(), "&&".to_string(), "&&".to_string()],
+            where_clause: Some("NOT cancelled".to_string()),
+            deferrable: false,
+            initially_deferred: false,
+        }];
+
+        let excludes = process_exclude_constraints(&raw);
+        assert_eq!(excludes.len(), 1);
+        let elements = &excludes[0].elements;
+        assert_eq!(elements[0].value, "room_id");
+        assert!(!elements[0].is_expression);
+        assert_eq!(elements[1].value, "During");
+        assert!(!elements[1].is_expression);
+        assert!(elements[2].is_expression);
+        assert_eq!(elements[2].operator, "&&");
+        assert_eq!(excludes[0].where_clause.as_deref(), Some("NOT cancelled"));
+    }
+
+    #[test]
+    fn test_process_domains_strips_check_wrapper() {
+        let domains = process_domains(&[RawDomainInfo {
+            schema: "public".to_string(),
+            name: "email".to_string(),
+            base_type: "text".to_string(),
+            not_null: true,
+            default_value: None,
+            check: Some("CHECK ((VALUE ~ '@'::text))".to_string()),
+        }]);
+        assert_eq!(domains.len(), 1);
+        assert_eq!(domains[0].base_type, "text");
+        assert!(domains[0].not_null);
+        assert_eq!(domains[0].check.as_deref(), Some("(VALUE ~ '@'::text)"));
+    }
+
+    #[test]
+    fn test_process_composites_pairs_fields() {
+        let composites = process_composites(&[RawCompositeInfo {
+            schema: "public".to_string(),
+            name: "address_type".to_string(),
+            field_names: vec!["street".to_string(), "zip".to_string()],
+            field_types: vec!["text".to_string(), "integer".to_string()],
+        }]);
+        assert_eq!(composites.len(), 1);
+        assert_eq!(
+            composites[0].fields,
+            vec![
+                CompositeField::new("street", "text"),
+                CompositeField::new("zip", "integer"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_index_columns_with_collation() {
+        let cols = parse_index_columns(vec![
+            "email COLLATE \"und-x-icu\" DESC".to_string(),
+            "name COLLATE \"C\" text_pattern_ops".to_string(),
+            "lower(title)".to_string(),
+        ]);
+
+        assert_eq!(cols[0].name, "email");
+        assert!(!cols[0].is_expression);
+        assert!(!cols[0].asc);
+        assert_eq!(cols[0].collate.as_deref(), Some("und-x-icu"));
+        assert_eq!(cols[1].name, "name");
+        assert_eq!(cols[1].collate.as_deref(), Some("C"));
+        assert_eq!(cols[1].opclass.as_deref(), Some("text_pattern_ops"));
+        assert_eq!(cols[2].collate, None);
+    }
+
     #[test]
     fn postgres_catalog_queries_are_privilege_scoped() {
         use queries::{
-            CHECKS_QUERY, COLUMNS_QUERY, ENUMS_QUERY, FOREIGN_KEYS_QUERY, INDEXES_QUERY,
-            POLICIES_QUERY, PRIMARY_KEYS_QUERY, SCHEMAS_QUERY, SEQUENCES_QUERY, TABLES_QUERY,
-            UNIQUES_QUERY, VIEWS_QUERY,
+            CHECKS_QUERY, COLUMNS_QUERY, COMPOSITES_QUERY, DOMAINS_QUERY, ENUMS_QUERY, EXCLUDES_QUERY, FOREIGN_KEYS_QUERY,
+            INDEXES_QUERY, POLICIES_QUERY, PRIMARY_KEYS_QUERY, SCHEMAS_QUERY, SEQUENCES_QUERY,
+            TABLES_QUERY, UNIQUES_QUERY, VIEWS_QUERY,
         };
 
         for query in [
@@ -1260,6 +1649,8 @@ mod tests {
             TABLES_QUERY,
             COLUMNS_QUERY,
             ENUMS_QUERY,
+            DOMAINS_QUERY,
+            COMPOSITES_QUERY,
             SEQUENCES_QUERY,
             VIEWS_QUERY,
             INDEXES_QUERY,
@@ -1267,6 +1658,7 @@ mod tests {
             PRIMARY_KEYS_QUERY,
             UNIQUES_QUERY,
             CHECKS_QUERY,
+            EXCLUDES_QUERY,
             POLICIES_QUERY,
         ] {
             assert!(
@@ -1283,6 +1675,7 @@ mod tests {
             PRIMARY_KEYS_QUERY,
             UNIQUES_QUERY,
             CHECKS_QUERY,
+            EXCLUDES_QUERY,
             POLICIES_QUERY,
         ] {
             assert!(
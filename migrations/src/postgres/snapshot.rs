@@ -45,7 +45,8 @@ impl Snapshot<PostgresEntity> {
     /// - Schema entities are kept only if referenced by a desired table.
     /// - Table-scoped entities (Column, Index, FK, PK, Unique, Check,
     ///   Policy) are kept only when their parent table is in the set.
-    /// - Other global entities (Enum, Sequence, Role, View) pass through.
+    /// - Other global entities (Enum, Domain, Composite, Sequence,
+    ///   Role, View) pass through.
     ///
     /// The set contains `(schema, table_name)` pairs.
     #[must_use]
@@ -98,6 +99,11 @@ impl Snapshot<PostgresEntity> {
                         scoped.ddl.push(entity.clone());
                     }
                 }
+                PostgresEntity::ExcludeConstraint(e) => {
+                    if tables.contains(&(e.schema.to_string(), e.table.to_string())) {
+                        scoped.ddl.push(entity.clone());
+                    }
+                }
                 PostgresEntity::Policy(p) => {
                     if tables.contains(&(p.schema.to_string(), p.table.to_string())) {
                         scoped.ddl.push(entity.clone());
@@ -114,6 +120,16 @@ impl Snapshot<PostgresEntity> {
                         scoped.ddl.push(entity.clone());
                     }
                 }
+                PostgresEntity::Domain(d) => {
+                    if schemas.contains(d.schema.as_ref()) {
+                        scoped.ddl.push(entity.clone());
+                    }
+                }
+                PostgresEntity::Composite(c) => {
+                    if schemas.contains(c.schema.as_ref()) {
+                        scoped.ddl.push(entity.clone());
+                    }
+                }
                 PostgresEntity::View(v) => {
                     if schemas.contains(v.schema.as_ref()) {
                         scoped.ddl.push(entity.clone());
@@ -1,9 +1,9 @@
 //! `PostgreSQL` SQL generation from schema metadata
 
-use super::collection::{DiffType, EntityDiff};
+use super::collection::{DiffType, EntityDiff, normalize_pg_type_for_compare};
 use super::ddl::{
-    CheckConstraint, Column, Enum, ForeignKey, Index, Policy, PostgresEntity, PrimaryKey, Role,
-    Schema, Sequence, Table, TableSql, UniqueConstraint, View,
+    CheckConstraint, Column, Composite, Domain, Enum, ExcludeConstraint, ForeignKey, Index, Policy,
+    PostgresEntity, PrimaryKey, Role, Schema, Sequence, Table, TableSql, UniqueConstraint, View,
 };
 use crate::traits::EntityKind;
 use serde::Serialize;
@@ -90,6 +90,12 @@ pub enum JsonStatement {
     DropCheck {
         check: CheckConstraint,
     },
+    AddExclude {
+        exclude: ExcludeConstraint,
+    },
+    DropExclude {
+        exclude: ExcludeConstraint,
+    },
     CreateSchema {
         name: String,
     },
@@ -113,6 +119,40 @@ pub enum JsonStatement {
         to: Enum,
         diff: Vec<EnumDiff>,
     },
+    RenameEnumValue {
+        #[serde(rename = "enum")]
+        enum_: Enum,
+        from: String,
+        to: String,
+    },
+    /// Recreate an enum whose values were removed or reordered: dependent
+    /// `columns` are cast to text while the type is dropped and recreated,
+    /// then the ones in `restore` are cast back.
+    RecreateEnum {
+        to: Enum,
+        columns: Vec<Column>,
+        restore: Vec<Column>,
+    },
+    CreateDomain {
+        domain: Domain,
+    },
+    DropDomain {
+        domain: Domain,
+    },
+    AlterDomain {
+        from: Domain,
+        to: Domain,
+    },
+    CreateComposite {
+        composite: Composite,
+    },
+    DropComposite {
+        composite: Composite,
+    },
+    AlterComposite {
+        from: Composite,
+        to: Composite,
+    },
     CreateSequence {
         sequence: Sequence,
     },
@@ -154,6 +194,10 @@ pub enum JsonStatement {
         old_unique: UniqueConstraint,
         new_unique: UniqueConstraint,
     },
+    RecreateExclude {
+        old_exclude: ExcludeConstraint,
+        new_exclude: ExcludeConstraint,
+    },
     /// Recreate column by dropping and re-adding (for generated columns, type changes, etc.)
     RecreateColumn {
         old_column: Box<Column>,
@@ -184,6 +228,7 @@ pub struct RichTable {
     pub pk: Option<PrimaryKey>,
     pub uniques: Vec<UniqueConstraint>,
     pub checks: Vec<CheckConstraint>,
+    pub excludes: Vec<ExcludeConstraint>,
     pub policies: Vec<Policy>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
@@ -207,6 +252,7 @@ struct CreatedTableEntities<'a> {
     primary_keys: Vec<&'a PrimaryKey>,
     unique_constraints: Vec<&'a UniqueConstraint>,
     check_constraints: Vec<&'a CheckConstraint>,
+    exclude_constraints: Vec<&'a ExcludeConstraint>,
     policies: Vec<&'a Policy>,
 }
 
@@ -244,6 +290,9 @@ impl<'a> DiffIndex<'a> {
                     entries.unique_constraints.push(value);
                 }
                 PostgresEntity::CheckConstraint(value) => entries.check_constraints.push(value),
+                PostgresEntity::ExcludeConstraint(value) => {
+                    entries.exclude_constraints.push(value);
+                }
                 PostgresEntity::Policy(value) => entries.policies.push(value),
                 _ => {}
             }
@@ -339,6 +388,28 @@ impl PostgresGenerator {
             }
         }
 
+        // 4b. Process Domain creations and alterations (drops run last, once
+        // the columns using them are gone)
+        for d in diff
+            .iter()
+            .filter(|d| d.kind == EntityKind::Domain && d.diff_type != DiffType::Drop)
+        {
+            if let Some(stmt) = Self::diff_to_statement_with_context(d, &diff_index) {
+                sqls.push(Self::statement_to_sql(stmt));
+            }
+        }
+
+        // 4c. Process Composite creations and alterations; their attributes
+        // may use the enums and domains created above
+        for d in diff
+            .iter()
+            .filter(|d| d.kind == EntityKind::Composite && d.diff_type != DiffType::Drop)
+        {
+            if let Some(stmt) = Self::diff_to_statement_with_context(d, &diff_index) {
+                sqls.push(Self::statement_to_sql(stmt));
+            }
+        }
+
         // 5. Process Sequence creations
         for d in diff.iter().filter(|d| d.kind == EntityKind::Sequence) {
             if let Some(stmt) = Self::diff_to_statement_with_context(d, &diff_index) {
@@ -404,6 +475,8 @@ impl PostgresGenerator {
             // Skip if handled above
             if d.kind == EntityKind::Schema
                 || d.kind == EntityKind::Enum
+                || d.kind == EntityKind::Domain
+                || d.kind == EntityKind::Composite
                 || d.kind == EntityKind::Sequence
             {
                 continue;
@@ -438,6 +511,21 @@ impl PostgresGenerator {
             }
         }
 
+        // 9. Drop composites, then the domains they may use, once no longer
+        // in the schema
+        for d in diff
+            .iter()
+            .filter(|d| d.kind == EntityKind::Composite && d.diff_type == DiffType::Drop)
+            .chain(
+                diff.iter()
+                    .filter(|d| d.kind == EntityKind::Domain && d.diff_type == DiffType::Drop),
+            )
+        {
+            if let Some(stmt) = Self::diff_to_statement_with_context(d, &diff_index) {
+                sqls.push(Self::statement_to_sql(stmt));
+            }
+        }
+
         sqls
     }
 
@@ -458,7 +546,8 @@ impl PostgresGenerator {
             | EntityKind::ForeignKey
             | EntityKind::PrimaryKey
             | EntityKind::UniqueConstraint
-            | EntityKind::CheckConstraint => {
+            | EntityKind::CheckConstraint
+            | EntityKind::ExcludeConstraint => {
                 // key: schema.name (constraint/index name).
                 // Need the entity itself to know the table.
                 let entity = d.right.as_ref().or(d.left.as_ref())?;
@@ -470,6 +559,9 @@ impl PostgresGenerator {
                         Some(format!("{}.{}", u.schema, u.table))
                     }
                     PostgresEntity::CheckConstraint(c) => Some(format!("{}.{}", c.schema, c.table)),
+                    PostgresEntity::ExcludeConstraint(e) => {
+                        Some(format!("{}.{}", e.schema, e.table))
+                    }
                     _ => None,
                 }
             }
@@ -553,6 +645,15 @@ impl PostgresGenerator {
                     .collect()
             })
             .unwrap_or_default();
+        let excludes = entities
+            .map(|entries| {
+                entries
+                    .exclude_constraints
+                    .iter()
+                    .map(|value| (*value).clone())
+                    .collect()
+            })
+            .unwrap_or_default();
         let policies = entities
             .map(|entries| {
                 entries
@@ -580,6 +681,7 @@ impl PostgresGenerator {
             pk,
             uniques,
             checks,
+            excludes,
             policies,
             comment: table.comment.as_ref().map(ToString::to_string),
         }
@@ -607,6 +709,10 @@ impl PostgresGenerator {
                 name: s.name.to_string(),
             }),
             PostgresEntity::Enum(e) => Some(JsonStatement::CreateEnum { enum_: e.clone() }),
+            PostgresEntity::Domain(d) => Some(JsonStatement::CreateDomain { domain: d.clone() }),
+            PostgresEntity::Composite(c) => Some(JsonStatement::CreateComposite {
+                composite: c.clone(),
+            }),
             PostgresEntity::Sequence(s) => Some(JsonStatement::CreateSequence {
                 sequence: s.clone(),
             }),
@@ -629,6 +735,9 @@ impl PostgresGenerator {
             PostgresEntity::CheckConstraint(c) => {
                 Some(JsonStatement::AddCheck { check: c.clone() })
             }
+            PostgresEntity::ExcludeConstraint(e) => {
+                Some(JsonStatement::AddExclude { exclude: e.clone() })
+            }
             PostgresEntity::Policy(p) => Some(JsonStatement::CreatePolicy { policy: p.clone() }),
             // Handled separately in CreateTable; privileges not yet tracked
             PostgresEntity::Table(_) | PostgresEntity::Privilege(_) => None,
@@ -641,6 +750,10 @@ impl PostgresGenerator {
                 name: s.name.to_string(),
             }),
             PostgresEntity::Enum(e) => Some(JsonStatement::DropEnum { enum_: e.clone() }),
+            PostgresEntity::Domain(d) => Some(JsonStatement::DropDomain { domain: d.clone() }),
+            PostgresEntity::Composite(c) => Some(JsonStatement::DropComposite {
+                composite: c.clone(),
+            }),
             PostgresEntity::Sequence(s) => Some(JsonStatement::DropSequence {
                 sequence: s.clone(),
             }),
@@ -662,6 +775,9 @@ impl PostgresGenerator {
             PostgresEntity::CheckConstraint(c) => {
                 Some(JsonStatement::DropCheck { check: c.clone() })
             }
+            PostgresEntity::ExcludeConstraint(e) => {
+                Some(JsonStatement::DropExclude { exclude: e.clone() })
+            }
             PostgresEntity::Policy(p) => Some(JsonStatement::DropPolicy { policy: p.clone() }),
             PostgresEntity::Privilege(_) => None, // Privileges not yet tracked
         }
@@ -701,6 +817,18 @@ impl PostgresGenerator {
                     })
                 }
             }
+            (Some(PostgresEntity::Domain(old)), Some(PostgresEntity::Domain(new))) => {
+                Some(JsonStatement::AlterDomain {
+                    from: old.clone(),
+                    to: new.clone(),
+                })
+            }
+            (Some(PostgresEntity::Composite(old)), Some(PostgresEntity::Composite(new))) => {
+                Some(JsonStatement::AlterComposite {
+                    from: old.clone(),
+                    to: new.clone(),
+                })
+            }
             (Some(PostgresEntity::Column(old)), Some(PostgresEntity::Column(new))) => {
                 // PostgreSQL doesn't support ALTER COLUMN ... ADD GENERATED AS
                 let needs_recreate = old.generated.is_none() && new.generated.is_some();
@@ -744,6 +872,13 @@ impl PostgresGenerator {
                 old_unique: old.clone(),
                 new_unique: new.clone(),
             }),
+            (
+                Some(PostgresEntity::ExcludeConstraint(old)),
+                Some(PostgresEntity::ExcludeConstraint(new)),
+            ) => Some(JsonStatement::RecreateExclude {
+                old_exclude: old.clone(),
+                new_exclude: new.clone(),
+            }),
             // PostgreSQL doesn't support ALTER VIEW for definition changes,
             // so we drop and recreate the view.
             (Some(PostgresEntity::View(old)), Some(PostgresEntity::View(new))) => {
@@ -976,6 +1111,7 @@ impl PostgresGenerator {
             .foreign_keys(&table.foreign_keys)
             .unique_constraints(&table.uniques)
             .check_constraints(&table.checks)
+            .exclude_constraints(&table.excludes)
             .create_table_sql()
     }
 
@@ -998,6 +1134,79 @@ impl PostgresGenerator {
             .join("\n")
     }
 
+    fn alter_domain_sql(from: &Domain, to: &Domain) -> String {
+        // ALTER DOMAIN cannot change the base type, so the domain is
+        // recreated; this fails while columns still use it.
+        if normalize_pg_type_for_compare(&from.base_type)
+            != normalize_pg_type_for_compare(&to.base_type)
+        {
+            return format!("{}\n{}", from.drop_domain_sql(), to.create_domain_sql());
+        }
+        from.alter_domain_sql(to).join("\n")
+    }
+
+    fn alter_composite_sql(from: &Composite, to: &Composite) -> String {
+        // Keep the old spelling of attribute types that only differ by alias
+        // so they are not altered.
+        let mut from = from.clone();
+        for field in &mut from.fields {
+            if let Some(new) = to.fields.iter().find(|f| f.name == field.name)
+                && normalize_pg_type_for_compare(&field.sql_type)
+                    == normalize_pg_type_for_compare(&new.sql_type)
+            {
+                field.sql_type.clone_from(&new.sql_type);
+            }
+        }
+        from.alter_composite_sql(to).join("\n")
+    }
+
+    fn recreate_enum_sql(to: &super::ddl::Enum, columns: &[Column], restore: &[Column]) -> String {
+        let mut stmts = Vec::new();
+        for col in columns {
+            let table_key = Self::qualified_name(&col.schema, &col.table);
+            if col.default.is_some() {
+                stmts.push(format!(
+                    "ALTER TABLE {} ALTER COLUMN {} DROP DEFAULT;",
+                    table_key,
+                    Self::quote_ident(&col.name)
+                ));
+            }
+            let dimensions = usize::try_from(col.dimensions.unwrap_or(0)).unwrap_or(0);
+            stmts.push(format!(
+                "ALTER TABLE {} ALTER COLUMN {} SET DATA TYPE text{};",
+                table_key,
+                Self::quote_ident(&col.name),
+                "[]".repeat(dimensions)
+            ));
+        }
+
+        stmts.push(to.drop_enum_sql());
+        stmts.push(to.create_enum_sql());
+
+        for col in restore {
+            let table_key = Self::qualified_name(&col.schema, &col.table);
+            let type_sql = Self::column_type_sql(col);
+            stmts.push(format!(
+                "ALTER TABLE {} ALTER COLUMN {} SET DATA TYPE {} USING {}::{};",
+                table_key,
+                Self::quote_ident(&col.name),
+                type_sql,
+                Self::quote_ident(&col.name),
+                type_sql
+            ));
+            if let Some(default) = &col.default {
+                stmts.push(format!(
+                    "ALTER TABLE {} ALTER COLUMN {} SET DEFAULT {};",
+                    table_key,
+                    Self::quote_ident(&col.name),
+                    default
+                ));
+            }
+        }
+
+        stmts.join("\n")
+    }
+
     fn add_pk_sql(pk: &super::ddl::PrimaryKey) -> String {
         pk.add_pk_sql()
     }
@@ -1062,6 +1271,10 @@ impl PostgresGenerator {
         check.add_check_sql()
     }
 
+    fn add_exclude_sql(exclude: &ExcludeConstraint) -> String {
+        exclude.add_exclude_sql()
+    }
+
     fn drop_policy_sql(policy: &super::ddl::Policy) -> String {
         format!(
             "DROP POLICY {} ON {};",
@@ -1098,6 +1311,18 @@ impl PostgresGenerator {
                 format!("DROP TYPE {};", Self::qualified_name(&e.schema, &e.name))
             }
             JsonStatement::AlterEnum { from: _, to, diff } => Self::alter_enum_sql(&to, &diff),
+            JsonStatement::RenameEnumValue { enum_: e, from, to } => e.rename_value_sql(&from, &to),
+            JsonStatement::RecreateEnum {
+                to,
+                columns,
+                restore,
+            } => Self::recreate_enum_sql(&to, &columns, &restore),
+            JsonStatement::CreateDomain { domain } => domain.create_domain_sql(),
+            JsonStatement::DropDomain { domain } => domain.drop_domain_sql(),
+            JsonStatement::AlterDomain { from, to } => Self::alter_domain_sql(&from, &to),
+            JsonStatement::CreateComposite { composite } => composite.create_composite_sql(),
+            JsonStatement::DropComposite { composite } => composite.drop_composite_sql(),
+            JsonStatement::AlterComposite { from, to } => Self::alter_composite_sql(&from, &to),
             JsonStatement::CreateSequence { sequence: s } => Self::create_sequence_sql(&s),
             JsonStatement::DropSequence { sequence: s } => format!(
                 "DROP SEQUENCE {};",
@@ -1156,6 +1381,10 @@ impl PostgresGenerator {
             JsonStatement::DropCheck { check } => {
                 Self::drop_constraint_sql(&check.schema, &check.table, &check.name)
             }
+            JsonStatement::AddExclude { exclude } => Self::add_exclude_sql(&exclude),
+            JsonStatement::DropExclude { exclude } => {
+                Self::drop_constraint_sql(&exclude.schema, &exclude.table, &exclude.name)
+            }
             JsonStatement::CreateRole { role } => Self::create_role_sql(&role),
             JsonStatement::DropRole { role } => {
                 format!("DROP ROLE {};", Self::quote_ident(&role.name))
@@ -1180,6 +1409,18 @@ impl PostgresGenerator {
                 Self::drop_constraint_sql(&old_unique.schema, &old_unique.table, &old_unique.name),
                 Self::add_unique_sql(&new_unique)
             ),
+            JsonStatement::RecreateExclude {
+                old_exclude,
+                new_exclude,
+            } => format!(
+                "{}\n{}",
+                Self::drop_constraint_sql(
+                    &old_exclude.schema,
+                    &old_exclude.table,
+                    &old_exclude.name
+                ),
+                Self::add_exclude_sql(&new_exclude)
+            ),
         }
     }
 
@@ -1205,10 +1446,58 @@ impl PostgresGenerator {
     }
 
     fn alter_column_sql(to: &Column, diff: &HashMap<String, serde_json::Value>) -> String {
+        use super::grammar::PgTypeCategory;
+
         let table_key = Self::qualified_name(&to.schema, &to.table);
         let mut stmts = Vec::new();
 
-        if diff.contains_key("type") {
+        let from_type = diff
+            .get("type")
+            .and_then(|t| t.get("from"))
+            .and_then(serde_json::Value::as_str)
+            .map(PgTypeCategory::from_sql_type);
+        let to_type = PgTypeCategory::from_sql_type(&to.sql_type);
+        // A serial column is stored as a plain integer, so moving to or from
+        // serial (e.g. serial <-> identity) swaps the owned sequence instead
+        // of changing the column type.
+        let leaves_serial = from_type.and_then(|t| t.serial_storage()) == Some(to_type);
+        let becomes_serial = to_type
+            .serial_storage()
+            .is_some_and(|t| from_type == Some(t));
+
+        if leaves_serial {
+            stmts.push(format!(
+                "ALTER TABLE {} ALTER COLUMN {} DROP DEFAULT;",
+                table_key,
+                Self::quote_ident(&to.name)
+            ));
+            stmts.push(format!(
+                "DROP SEQUENCE IF EXISTS {};",
+                Self::serial_sequence_name(to)
+            ));
+        } else if becomes_serial {
+            if diff.contains_key("identity") {
+                stmts.push(format!(
+                    "ALTER TABLE {} ALTER COLUMN {} DROP IDENTITY;",
+                    table_key,
+                    Self::quote_ident(&to.name)
+                ));
+            }
+            stmts.push(format!(
+                "CREATE SEQUENCE {} AS {} OWNED BY {}.{};",
+                Self::serial_sequence_name(to),
+                from_type.map_or("integer", |t| t.drizzle_import()),
+                table_key,
+                Self::quote_ident(&to.name)
+            ));
+            stmts.push(format!(
+                "ALTER TABLE {} ALTER COLUMN {} SET DEFAULT nextval({});",
+                table_key,
+                Self::quote_ident(&to.name),
+                Self::quote_literal(&Self::serial_sequence_name(to))
+            ));
+            stmts.push(Self::restart_column_sequence_sql(to));
+        } else if diff.contains_key("type") {
             let type_sql = Self::column_type_sql(to);
             let using_clause = format!(" USING {}::{type_sql}", Self::quote_ident(&to.name));
             stmts.push(format!(
@@ -1236,7 +1525,7 @@ impl PostgresGenerator {
             }
         }
 
-        if diff.contains_key("default") {
+        if diff.contains_key("default") && !leaves_serial && !becomes_serial {
             if let Some(default) = &to.default {
                 stmts.push(format!(
                     "ALTER TABLE {} ALTER COLUMN {} SET DEFAULT {};",
@@ -1261,9 +1550,7 @@ impl PostgresGenerator {
             ));
         }
 
-        if diff.contains_key("identity")
-            && !super::grammar::PgTypeCategory::from_sql_type(&to.sql_type).is_serial()
-        {
+        if diff.contains_key("identity") && !to_type.is_serial() {
             if let Some(id) = &to.identity {
                 if let Some(identity_sql) = Self::identity_sql(to, id) {
                     stmts.push(format!(
@@ -1272,6 +1559,9 @@ impl PostgresGenerator {
                         Self::quote_ident(&to.name),
                         identity_sql
                     ));
+                    if leaves_serial {
+                        stmts.push(Self::restart_column_sequence_sql(to));
+                    }
                 }
             } else {
                 stmts.push(format!(
@@ -1298,6 +1588,23 @@ impl PostgresGenerator {
         }
     }
 
+    /// Name Postgres gives the sequence backing a serial column.
+    fn serial_sequence_name(col: &Column) -> String {
+        Self::qualified_name(&col.schema, &format!("{}_{}_seq", col.table, col.name))
+    }
+
+    /// Moves the column's sequence past the rows already in the table.
+    fn restart_column_sequence_sql(col: &Column) -> String {
+        let table_key = Self::qualified_name(&col.schema, &col.table);
+        format!(
+            "SELECT setval(pg_get_serial_sequence({}, {}), coalesce(max({}), 0) + 1, false) FROM {};",
+            Self::quote_literal(&table_key),
+            Self::quote_literal(&col.name),
+            Self::quote_ident(&col.name),
+            table_key
+        )
+    }
+
     fn create_role_sql(role: &super::ddl::Role) -> String {
         let mut sql = format!("CREATE ROLE {}", Self::quote_ident(&role.name));
         if role.create_db.unwrap_or(false) {
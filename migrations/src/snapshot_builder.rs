@@ -21,6 +21,9 @@ use std::collections::{HashMap, HashSet};
 ///
 /// Uses the provided `dialect` from config rather than the parser-detected dialect,
 /// allowing users to have multi-dialect schema files and select which to use via config.
+///
+/// `casing` (default `snake_case`) maps struct and field names to table and
+/// column names; a table's own `CASING = "..."` attribute takes precedence.
 #[must_use]
 pub fn parse_result_to_snapshot(
     result: &ParseResult,
@@ -101,16 +104,22 @@ fn postgres_type_sql(ty: &PostgreSQLType) -> String {
     ty.to_sql_type().to_ascii_lowercase()
 }
 
+/// A table's `CASING` attribute overrides the configured casing for its own
+/// table and column names.
+fn table_casing(table: &ParsedTable, casing: Casing) -> Casing {
+    table.casing().unwrap_or(casing)
+}
+
 fn resolve_table_name(table: &ParsedTable, casing: Casing) -> String {
     table.attr_value("name").map_or_else(
-        || apply_casing(&table.name, casing),
+        || apply_casing(&table.name, table_casing(table, casing)),
         |v| trim_wrapping_quotes(&v),
     )
 }
 
-fn resolve_field_name(field: &ParsedField, casing: Casing) -> String {
+fn resolve_field_name(table: &ParsedTable, field: &ParsedField, casing: Casing) -> String {
     field.attr_value("name").map_or_else(
-        || apply_casing(&field.name, casing),
+        || apply_casing(&field.name, table_casing(table, casing)),
         |v| trim_wrapping_quotes(&v),
     )
 }
@@ -297,7 +306,7 @@ fn build_sqlite_snapshot(result: &ParseResult, casing: Option<Casing>) -> SQLite
         for field in &table.fields {
             field_name_map.insert(
                 (table.name.clone(), field.name.clone()),
-                resolve_field_name(field, name_casing),
+                resolve_field_name(table, field, name_casing),
             );
         }
     }
@@ -322,7 +331,7 @@ fn build_sqlite_snapshot(result: &ParseResult, casing: Option<Casing>) -> SQLite
             let col_name = field_name_map
                 .get(&(table.name.clone(), field.name.clone()))
                 .cloned()
-                .unwrap_or_else(|| resolve_field_name(field, name_casing));
+                .unwrap_or_else(|| resolve_field_name(table, field, name_casing));
             let col = build_sqlite_column(&table_name, field, &col_name);
             snapshot.add_entity(SqliteEntity::Column(col));
 
@@ -402,7 +411,7 @@ fn build_pg_name_maps(pg_tables: &[&ParsedTable], casing: Casing) -> PgNameMaps
         for field in &table.fields {
             field_name_map.insert(
                 (table.name.clone(), field.name.clone()),
-                resolve_field_name(field, casing),
+                resolve_field_name(table, field, casing),
             );
         }
     }
@@ -464,7 +473,7 @@ fn add_postgres_table_entities(
             .field_name_map
             .get(&(table.name.clone(), field.name.clone()))
             .cloned()
-            .unwrap_or_else(|| resolve_field_name(field, casing));
+            .unwrap_or_else(|| resolve_field_name(table, field, casing));
         let col = build_postgres_column(&schema_name, &table_name, field, &col_name);
         snapshot.add_entity(PostgresEntity::Column(col));
 
@@ -580,6 +589,8 @@ fn build_sqlite_column(
         col = col.default_value(default);
     }
 
+    col.collate = field_marker_value(field, "collate").map(Cow::Owned);
+
     col
 }
 
@@ -690,7 +701,9 @@ fn build_postgres_column(
         identity,
         dimensions: None,
         comment: None,
-        collate: field_marker_value(field, "collate").map(Cow::Owned),
+        collate: field_marker_value(field, "collate")
+            .or_else(|| field_marker_value(field, "collation"))
+            .map(Cow::Owned),
         ordinal_position: None,
     }
 }
@@ -802,13 +815,18 @@ fn build_sqlite_index(
     let columns: Vec<IndexColumn> = index
         .columns
         .iter()
-        .filter_map(|c| {
+        .zip(index.collations.iter().chain(std::iter::repeat(&None)))
+        .filter_map(|(c, collate)| {
             let target = MemberRef::parse(c)?;
             let col_name = field_name_map
                 .get(&(target.table.to_string(), target.field.to_string()))
                 .cloned()
                 .unwrap_or_else(|| apply_casing(target.field, casing));
-            Some(IndexColumn::new(col_name))
+            let column = IndexColumn::new(col_name);
+            Some(match collate {
+                Some(collate) => column.with_collate(collate.clone()),
+                None => column,
+            })
         })
         .collect();
 
@@ -849,13 +867,18 @@ fn build_postgres_index(
     let columns: Vec<IndexColumn> = index
         .columns
         .iter()
-        .filter_map(|c| {
+        .zip(index.collations.iter().chain(std::iter::repeat(&None)))
+        .filter_map(|(c, collate)| {
             let target = MemberRef::parse(c)?;
             let col_name = field_name_map
                 .get(&(target.table.to_string(), target.field.to_string()))
                 .cloned()
                 .unwrap_or_else(|| apply_casing(target.field, casing));
-            Some(IndexColumn::new(col_name))
+            let column = IndexColumn::new(col_name);
+            Some(match collate {
+                Some(collate) => column.with_collate(collate.clone()),
+                None => column,
+            })
         })
         .collect();
 
@@ -1082,6 +1105,54 @@ pub struct UuidStorage {
         assert_eq!(column_type("legacy_text_uuid"), "text");
     }
 
+    #[test]
+    fn test_sqlite_snapshot_preserves_collations() {
+        use crate::parser::SchemaParser;
+        use crate::sqlite::SqliteEntity;
+
+        let code = r#"
+#[SQLiteTable]
+pub struct Accounts {
+    #[column(primary)]
+    pub id: i64,
+    #[column(collate = "NOCASE")]
+    pub email: String,
+    pub handle: String,
+}
+
+#[SQLiteIndex(unique)]
+pub struct AccountsHandleIdx(#[collate = "NOCASE"] Accounts::handle);
+"#;
+
+        let result = SchemaParser::parse(code);
+        let snapshot = parse_result_to_snapshot(&result, Dialect::SQLite, None);
+        let snap = match snapshot {
+            Snapshot::Sqlite(s) => s,
+            _ => panic!("Expected SQLite snapshot"),
+        };
+
+        let email = snap
+            .ddl
+            .iter()
+            .find_map(|entity| match entity {
+                SqliteEntity::Column(column) if column.name.as_ref() == "email" => Some(column),
+                _ => None,
+            })
+            .expect("expected email column");
+        assert_eq!(email.collate.as_deref(), Some("NOCASE"));
+
+        let index = snap
+            .ddl
+            .iter()
+            .find_map(|entity| match entity {
+                SqliteEntity::Index(index) => Some(index),
+                _ => None,
+            })
+            .expect("expected index");
+        assert_eq!(index.columns[0].value.as_ref(), "handle");
+        assert_eq!(index.columns[0].collate.as_deref(), Some("NOCASE"));
+    }
+
     /// Test that changing a column from Option<String> to String generates table recreation
     #[test]
     fn test_nullable_to_not_null_generates_migration() {
@@ -1417,6 +1488,64 @@ pub struct UsersEmailIdx(UsersTable::emailAddress);
         assert_eq!(index.name.as_ref(), "users_tbl_email_idx");
     }
 
+    #[test]
+    fn test_table_casing_attribute_overrides_config() {
+        use crate::parser::SchemaParser;
+        use crate::sqlite::SqliteEntity;
+
+        let code = r#"
+#[SQLiteTable(CASING = "camelCase")]
+pub struct UserAccounts {
+    #[column(primary)]
+    pub id: i64,
+    pub display_name: String,
+}
+
+#[SQLiteTable]
+pub struct AuditLog {
+    #[column(primary)]
+    pub id: i64,
+    #[column(references = UserAccounts::id)]
+    pub account_id: i64,
+}
+"#;
+
+        let result = SchemaParser::parse(code);
+        let snapshot = parse_result_to_snapshot(&result, Dialect::SQLite, Some(Casing::SnakeCase));
+        let Snapshot::Sqlite(snap) = snapshot else {
+            panic!("Expected SQLite snapshot");
+        };
+
+        let mut columns: Vec<(String, String)> = snap
+            .ddl
+            .iter()
+            .filter_map(|e| match e {
+                SqliteEntity::Column(c) => Some((c.table.to_string(), c.name.to_string())),
+                _ => None,
+            })
+            .collect();
+        columns.sort();
+        assert_eq!(
+            columns,
+            [
+                ("audit_log", "account_id"),
+                ("audit_log", "id"),
+                ("userAccounts", "displayName"),
+                ("userAccounts", "id"),
+            ]
+            .map(|(t, c)| (t.to_string(), c.to_string()))
+        );
+
+        let fk = snap.ddl.iter().find_map(|e| match e {
+            SqliteEntity::ForeignKey(fk) => Some(fk),
+            _ => None,
+        });
+        assert_eq!(
+            fk.expect("expected foreign key").table_to.as_ref(),
+            "userAccounts"
+        );
+    }
+
     #[test]
     fn test_postgres_casing_preserves_explicit_names() {
         use crate::parser::SchemaParser;
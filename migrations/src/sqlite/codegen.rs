@@ -311,6 +311,10 @@ fn generate_table_struct(ctx: &TableGenContext<'_>) -> String {
         }
     }
 
+    if let Some(comment) = ctx.table.comment.as_deref() {
+        write_doc_comment(&mut code, "", comment);
+    }
+
     // Table attribute
     if table_attrs.is_empty() {
         code.push_str("#[SQLiteTable]\n");
@@ -534,11 +538,13 @@ fn generate_column_field(column: &Column, ctx: &TableGenContext<'_>) -> String {
     }
 
     // Build the #[column(...)] attribute if there are any modifiers
-    let attr_str = if attrs.is_empty() {
-        String::new()
-    } else {
-        format!("    #[column({})]\n", attrs.join(", "))
-    };
+    let mut attr_str = String::new();
+    if let Some(comment) = column.comment.as_deref() {
+        write_doc_comment(&mut attr_str, "    ", comment);
+    }
+    if !attrs.is_empty() {
+        let _ = writeln!(attr_str, "    #[column({})]", attrs.join(", "));
+    }
 
     // Determine if column is effectively NOT NULL:
     // Per SQLite docs (https://sqlite.org/lang_createtable.html):
@@ -627,6 +633,16 @@ fn sql_type_to_rust_type(sql_type: &str, not_null: bool) -> String {
     }
 }
 
+fn write_doc_comment(code: &mut String, indent: &str, comment: &str) {
+    for line in comment.lines() {
+        if line.is_empty() {
+            let _ = writeln!(code, "{indent}///");
+        } else {
+            let _ = writeln!(code, "{indent}/// {line}");
+        }
+    }
+}
+
 /// Generate an index struct
 fn generate_index_struct(index: &Index, use_pub: bool, field_casing: FieldCasing) -> String {
     let mut code = String::new();
@@ -640,12 +656,18 @@ fn generate_index_struct(index: &Index, use_pub: bool, field_casing: FieldCasing
     let columns: Vec<String> = index
         .columns
         .iter()
-        .map(|c| format!("{}::{}", table_struct, c.value))
-        .map(|s| {
-            if let Some((table, col)) = s.split_once("::") {
-                format!("{}::{}", table, apply_field_casing(col, field_casing))
-            } else {
-                s
+        .map(|c| {
+            let member = format!(
+                "{}::{}",
+                table_struct,
+                apply_field_casing(&c.value, field_casing)
+            );
+            match &c.collate {
+                Some(collate) => format!(
+                    "#[collate = \"{}\"] {member}",
+                    escape_for_rust_literal(collate)
+                ),
+                None => member,
             }
         })
         .collect();
@@ -809,6 +831,46 @@ pub struct Users {
         assert_eq!(result.tables, vec!["users"]);
     }
 
+    #[test]
+    fn test_generate_comments_as_doc_comments() {
+        let mut ddl = SQLiteDDL::new();
+        ddl.tables
+            .push(Table::new("users").comment("Registered accounts"));
+        ddl.columns.push(
+            Column::new("users", "id", "integer")
+                .not_null()
+                .comment("Row id"),
+        );
+        ddl.pks.push(PrimaryKey::from_strings(
+            "users".to_string(),
+            "users_pk".to_string(),
+            vec!["id".to_string()],
+        ));
+
+        let options = CodegenOptions {
+            include_schema: false,
+            use_pub: true,
+            ..Default::default()
+        };
+
+        let result = generate_rust_schema(&ddl, &options);
+
+        assert!(
+            result
+                .code
+                .contains("/// Registered accounts\n#[SQLiteTable]\npub struct Users {"),
+            "{}",
+            result.code
+        );
+        assert!(
+            result
+                .code
+                .contains("    /// Row id\n    #[column(primary)]\n    pub id: i64,"),
+            "{}",
+            result.code
+        );
+    }
+
     #[test]
     fn test_generate_table_with_unique() {
         let mut ddl = SQLiteDDL::new();
@@ -899,6 +961,7 @@ struct Posts {
                 vec![IndexColumn {
                     value: "email".into(),
                     is_expression: false,
+                    collate: None,
                 }],
             )
             .unique(),
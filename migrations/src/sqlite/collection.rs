@@ -297,13 +297,14 @@ pub fn diff_ddl(left: &SQLiteDDL, right: &SQLiteDDL) -> Vec<EntityDiff> {
     let mut diffs = Vec::new();
 
     // Diff tables (no table_fn needed since these ARE tables)
-    diff_entity_type(
+    diff_entity_type_with(
         left.tables.list(),
         right.tables.list(),
         |t| t.name.to_string(),
         |t| SqliteEntity::Table(t.clone()),
         None,
         EntityKind::Table,
+        tables_equivalent,
         &mut diffs,
     );
 
@@ -476,11 +477,21 @@ fn diff_entity_type_with<T: Clone>(
     }
 }
 
+// Comments are snapshot-only metadata in SQLite; changing one must not force
+// a table rebuild.
+fn tables_equivalent(left: &Table, right: &Table) -> bool {
+    left.name == right.name
+        && left.strict == right.strict
+        && left.without_rowid == right.without_rowid
+}
+
 fn columns_equivalent(left: &Column, right: &Column) -> bool {
     let mut left = left.clone();
     let mut right = right.clone();
     left.sql_type = Cow::Owned(left.sql_type.to_ascii_lowercase());
     right.sql_type = Cow::Owned(right.sql_type.to_ascii_lowercase());
+    left.comment = None;
+    right.comment = None;
     left == right
 }
 
@@ -12,7 +12,8 @@ use super::statements::{
     RecreateTableStatement, RenameColumnStatement, RenameTableStatement, TableFull, from_json,
 };
 use crate::traits::EntityKind;
-use std::collections::{BTreeMap, HashSet};
+use crate::utils::match_column_renames;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 // Re-export diff types from collection
 pub use super::collection::{DiffType as SchemaDiffType, EntityDiff as SchemaEntityDiff};
@@ -178,8 +179,8 @@ fn collect_tables_to_recreate(
     schema_diff: &SchemaDiff,
     created: &HashSet<String>,
     dropped: &HashSet<String>,
-) -> HashSet<String> {
-    let mut out: HashSet<String> = HashSet::new();
+) -> BTreeSet<String> {
+    let mut out: BTreeSet<String> = BTreeSet::new();
 
     // Column alterations trigger recreation (SQLite has no ALTER COLUMN).
     for col_diff in schema_diff.by_kind(EntityKind::Column) {
@@ -208,6 +209,25 @@ fn collect_tables_to_recreate(
         }
     }
 
+    // Columns that ALTER TABLE ADD COLUMN rejects: NOT NULL without a default,
+    // or a default that is not a constant.
+    // See: https://www.sqlite.org/lang_altertable.html#altertabaddcol
+    for col_diff in schema_diff.by_kind(EntityKind::Column) {
+        if col_diff.diff_type == DiffType::Create
+            && let Some(SqliteEntity::Column(col)) = &col_diff.right
+            && col.generated.is_none()
+            && ((col.not_null && col.default.is_none())
+                || col
+                    .default
+                    .as_deref()
+                    .is_some_and(|d| !is_constant_default(d)))
+            && !created.contains(col.table.as_ref())
+            && !dropped.contains(col.table.as_ref())
+        {
+            out.insert(col.table.to_string());
+        }
+    }
+
     // FK, PK, unique, check constraint changes all require recreation.
     for kind in [
         EntityKind::ForeignKey,
@@ -242,27 +262,42 @@ fn collect_tables_to_recreate(
 /// Compute a full migration diff between two DDL states
 ///
 /// This is a simplified version of the TypeScript ddlDiff function.
-/// For a fully interactive migration with rename detection, you would
-/// need to provide resolver callbacks.
+/// Renames are inferred heuristically; see [`compute_migration_with`] to
+/// turn that off when every rename has been resolved up front.
 #[must_use]
 pub fn compute_migration(prev: &SQLiteDDL, cur: &SQLiteDDL) -> MigrationDiff {
+    compute_migration_with(prev, cur, true)
+}
+
+/// Compute a migration diff, optionally skipping heuristic rename detection.
+///
+/// With `detect_renames` off, any table or column missing from `cur` is
+/// dropped and any new one is created.
+#[must_use]
+pub fn compute_migration_with(
+    prev: &SQLiteDDL,
+    cur: &SQLiteDDL,
+    detect_renames: bool,
+) -> MigrationDiff {
     // Heuristic rename detection (non-interactive):
     // - detect exact table renames (same schema, identical entities)
-    // - detect exact column renames (same table, identical column properties)
+    // - detect column renames (same table, identical column properties)
     let mut prev_normalized = prev.clone();
     let mut rename_statements: Vec<JsonStatement> = Vec::new();
     let mut table_renames: Vec<TableRename> = Vec::new();
     let mut column_renames: Vec<ColumnRename> = Vec::new();
     let mut warnings = Vec::new();
 
-    detect_and_apply_sqlite_renames(
-        &mut prev_normalized,
-        cur,
-        &mut rename_statements,
-        &mut table_renames,
-        &mut column_renames,
-        &mut warnings,
-    );
+    if detect_renames {
+        detect_and_apply_sqlite_renames(
+            &mut prev_normalized,
+            cur,
+            &mut rename_statements,
+            &mut table_renames,
+            &mut column_renames,
+            &mut warnings,
+        );
+    }
 
     let schema_diff = diff_collections(&prev_normalized, cur);
     let mut statements = Vec::new();
@@ -289,12 +324,17 @@ pub fn compute_migration(prev: &SQLiteDDL, cur: &SQLiteDDL) -> MigrationDiff {
     let tables_to_recreate =
         collect_tables_to_recreate(&schema_diff, &created_table_names, &dropped_table_names);
 
+    // Views reading from a rebuilt table must be dropped first, or the
+    // final RENAME of the rebuilt table fails schema validation.
+    let rebuilt_views = views_depending_on(&prev_normalized, &tables_to_recreate);
+
     append_table_create_recreate_stmts(
         &mut statements,
         &schema_diff,
-        prev,
+        &prev_normalized,
         cur,
         &tables_to_recreate,
+        &rebuilt_views,
     );
     append_add_column_stmts(
         &mut statements,
@@ -307,11 +347,14 @@ pub fn compute_migration(prev: &SQLiteDDL, cur: &SQLiteDDL) -> MigrationDiff {
     append_drop_column_and_view_stmts(
         &mut statements,
         &schema_diff,
+        cur,
         &dropped_table_names,
         &tables_to_recreate,
+        &rebuilt_views,
     );
     append_drop_table_stmts(&mut statements, &schema_diff);
     collect_stored_generated_warnings(&mut warnings, &schema_diff);
+    collect_rebuild_warnings(&mut warnings, &prev_normalized, cur, &tables_to_recreate);
 
     // Convert to SQL
     let result = from_json(statements.clone());
@@ -329,7 +372,8 @@ fn append_table_create_recreate_stmts(
     schema_diff: &SchemaDiff,
     prev: &SQLiteDDL,
     cur: &SQLiteDDL,
-    tables_to_recreate: &HashSet<String>,
+    tables_to_recreate: &BTreeSet<String>,
+    rebuilt_views: &BTreeSet<String>,
 ) {
     // 1. Create tables
     for table_diff in schema_diff.created_tables() {
@@ -341,7 +385,14 @@ fn append_table_create_recreate_stmts(
         }
     }
 
-    // 2. Recreate tables that have column alterations
+    // 2. Recreate tables that have column alterations, dropping dependent views first
+    for view in prev.views.list() {
+        if rebuilt_views.contains(view.name.as_ref()) {
+            statements.push(JsonStatement::DropView(DropViewStatement {
+                view: view.clone(),
+            }));
+        }
+    }
     for table_name in tables_to_recreate {
         let from_table = table_from_ddl(table_name, prev);
         let to_table = table_from_ddl(table_name, cur);
@@ -357,7 +408,7 @@ fn append_add_column_stmts(
     schema_diff: &SchemaDiff,
     cur: &SQLiteDDL,
     created_table_names: &HashSet<String>,
-    tables_to_recreate: &HashSet<String>,
+    tables_to_recreate: &BTreeSet<String>,
 ) {
     // 3. Add columns (for existing tables only, skip tables being recreated)
     for col_diff in schema_diff.by_kind(EntityKind::Column) {
@@ -388,7 +439,7 @@ fn append_index_stmts(
     statements: &mut Vec<JsonStatement>,
     schema_diff: &SchemaDiff,
     cur: &SQLiteDDL,
-    tables_to_recreate: &HashSet<String>,
+    tables_to_recreate: &BTreeSet<String>,
 ) {
     // 4. Drop indexes (skip tables being recreated - indexes will be recreated with table)
     for idx_diff in schema_diff.by_kind(EntityKind::Index) {
@@ -448,8 +499,10 @@ fn append_index_stmts(
 fn append_drop_column_and_view_stmts(
     statements: &mut Vec<JsonStatement>,
     schema_diff: &SchemaDiff,
+    cur: &SQLiteDDL,
     dropped_table_names: &HashSet<String>,
-    tables_to_recreate: &HashSet<String>,
+    tables_to_recreate: &BTreeSet<String>,
+    rebuilt_views: &BTreeSet<String>,
 ) {
     // 7. Drop columns (for non-dropped tables, skip tables being recreated)
     for col_diff in schema_diff.by_kind(EntityKind::Column) {
@@ -466,11 +519,12 @@ fn append_drop_column_and_view_stmts(
         }
     }
 
-    // 8. Drop views
+    // 8. Drop views (views over rebuilt tables were dropped before the rebuild)
     for view_diff in schema_diff.by_kind(EntityKind::View) {
         if view_diff.diff_type == DiffType::Drop
             && let Some(SqliteEntity::View(view)) = &view_diff.left
             && !view.is_existing
+            && !rebuilt_views.contains(view.name.as_ref())
         {
             statements.push(JsonStatement::DropView(DropViewStatement {
                 view: view.clone(),
@@ -490,9 +544,23 @@ fn append_drop_column_and_view_stmts(
         }
     }
 
+    // 9b. Restore views that were dropped for a table rebuild
+    for view in cur.views.list() {
+        if rebuilt_views.contains(view.name.as_ref()) && !view.is_existing {
+            statements.push(JsonStatement::CreateView(CreateViewStatement {
+                view: view.clone(),
+            }));
+        }
+    }
+
     // 10. Alter views (drop and recreate)
     for view_diff in schema_diff.by_kind(EntityKind::View) {
-        if view_diff.diff_type == DiffType::Alter {
+        if view_diff.diff_type == DiffType::Alter
+            && !matches!(
+                &view_diff.left,
+                Some(SqliteEntity::View(view)) if rebuilt_views.contains(view.name.as_ref())
+            )
+        {
             if let Some(SqliteEntity::View(old_view)) = &view_diff.left {
                 statements.push(JsonStatement::DropView(DropViewStatement {
                     view: old_view.clone(),
@@ -516,6 +584,76 @@ fn append_drop_table_stmts(statements: &mut Vec<JsonStatement>, schema_diff: &Sc
     }
 }
 
+/// Names of managed views in `ddl` whose definition mentions one of `tables`.
+fn views_depending_on(ddl: &SQLiteDDL, tables: &BTreeSet<String>) -> BTreeSet<String> {
+    ddl.views
+        .list()
+        .iter()
+        .filter(|view| !view.is_existing)
+        .filter(|view| {
+            view.definition.as_deref().is_some_and(|definition| {
+                definition
+                    .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+                    .any(|word| tables.iter().any(|t| t.eq_ignore_ascii_case(word)))
+            })
+        })
+        .map(|view| view.name.to_string())
+        .collect()
+}
+
+/// Whether `default` is a constant SQLite accepts in `ALTER TABLE ADD COLUMN`.
+fn is_constant_default(default: &str) -> bool {
+    let value = default.trim();
+    let unsigned = value.trim_start_matches(['+', '-']);
+    if value.starts_with('(') {
+        return false;
+    }
+    value.eq_ignore_ascii_case("null")
+        || value.eq_ignore_ascii_case("true")
+        || value.eq_ignore_ascii_case("false")
+        || value.starts_with('\'')
+        || (value.len() > 2 && value[..2].eq_ignore_ascii_case("x'"))
+        || (!unsigned.is_empty() && unsigned.parse::<f64>().is_ok())
+}
+
+fn collect_rebuild_warnings(
+    warnings: &mut Vec<String>,
+    prev: &SQLiteDDL,
+    cur: &SQLiteDDL,
+    tables_to_recreate: &BTreeSet<String>,
+) {
+    for table in tables_to_recreate {
+        // An INTEGER PRIMARY KEY aliases the rowid and is filled in by SQLite.
+        let rowid_alias = cur
+            .pks
+            .for_table(table)
+            .filter(|pk| pk.columns.len() == 1)
+            .map(|pk| pk.columns[0].to_string());
+        for col in cur.columns.for_table(table) {
+            if !col.not_null
+                || col.default.is_some()
+                || col.generated.is_some()
+                || ((rowid_alias.as_deref() == Some(col.name.as_ref())
+                    || col.primary_key.unwrap_or(false))
+                    && col.sql_type.eq_ignore_ascii_case("integer"))
+            {
+                continue;
+            }
+            match prev.columns.one(table, &col.name) {
+                None => warnings.push(format!(
+                    "Column '{}' in table '{}' is NOT NULL without a default; rebuilding the table fails if it has rows",
+                    col.name, table
+                )),
+                Some(old) if !old.not_null => warnings.push(format!(
+                    "Column '{}' in table '{}' became NOT NULL; rebuilding the table fails if existing rows hold NULL",
+                    col.name, table
+                )),
+                Some(_) => {}
+            }
+        }
+    }
+}
+
 fn collect_stored_generated_warnings(warnings: &mut Vec<String>, schema_diff: &SchemaDiff) {
     // Add warnings for STORED generated columns
     for col_diff in schema_diff.by_kind(EntityKind::Column) {
@@ -661,7 +799,8 @@ fn detect_and_apply_sqlite_renames(
         }
     }
 
-    // Column renames (within tables that exist in both): exact property match, different name.
+    // Column renames (within tables that exist in both): identical properties, different
+    // name, and either a single candidate pair or the same position in the table.
     let common_tables: Vec<String> = prev
         .tables
         .list()
@@ -671,48 +810,43 @@ fn detect_and_apply_sqlite_renames(
         .collect();
 
     for table in common_tables {
-        let prev_cols: Vec<_> = prev.columns.for_table(&table);
-        let cur_cols: Vec<_> = cur.columns.for_table(&table);
-
-        let prev_names: Vec<String> = prev_cols.iter().map(|c| c.name.to_string()).collect();
-        let cur_names: Vec<String> = cur_cols.iter().map(|c| c.name.to_string()).collect();
-
-        let dropped_cols: Vec<String> = prev_names
-            .iter()
-            .filter(|c| !cur_names.contains(c))
-            .cloned()
-            .collect();
-        let created_cols: Vec<String> = cur_names
-            .iter()
-            .filter(|c| !prev_names.contains(c))
-            .cloned()
-            .collect();
+        let prev_cols = prev.columns.for_table(&table);
+        let cur_cols = cur.columns.for_table(&table);
+        let prev_names: Vec<&str> = prev_cols.iter().map(|c| c.name.as_ref()).collect();
+        let cur_names: Vec<&str> = cur_cols.iter().map(|c| c.name.as_ref()).collect();
+
+        let matched = match_column_renames(&prev_names, &cur_names, |from, to| {
+            match (prev.columns.one(&table, from), cur.columns.one(&table, to)) {
+                (Some(prev_col), Some(cur_col)) => {
+                    let mut prev_cmp = prev_col.clone();
+                    prev_cmp.name.clone_from(&cur_col.name);
+                    prev_cmp.ordinal_position = cur_col.ordinal_position;
+                    prev_cmp == *cur_col
+                }
+                _ => false,
+            }
+        });
 
-        if dropped_cols.len() != 1 || created_cols.len() != 1 {
-            continue;
+        if !matched.ambiguous.is_empty() {
+            warnings.push(format!(
+                "Ambiguous SQLite column rename candidates in table '{}' for dropped columns [{}]; no rename was inferred. Use Options::rename_column(...) with diff_with or diff_schemas_with to provide an explicit rename hint.",
+                table,
+                matched.ambiguous.join(", ")
+            ));
         }
 
-        let from = &dropped_cols[0];
-        let to = &created_cols[0];
-
-        let prev_col = prev.columns.one(&table, from);
-        let cur_col = cur.columns.one(&table, to);
-        if let (Some(prev_col), Some(cur_col)) = (prev_col, cur_col) {
-            let mut prev_cmp = prev_col.clone();
-            prev_cmp.name.clone_from(&cur_col.name);
-            if prev_cmp == *cur_col {
-                column_renames.push(ColumnRename {
-                    table: table.clone(),
-                    from: from.clone(),
-                    to: to.clone(),
-                });
-                rename_statements.push(JsonStatement::RenameColumn(RenameColumnStatement {
-                    table: table.clone(),
-                    from: from.clone(),
-                    to: to.clone(),
-                }));
-                apply_sqlite_column_rename(prev, &table, from, to);
-            }
+        for (from, to) in matched.renames {
+            column_renames.push(ColumnRename {
+                table: table.clone(),
+                from: from.clone(),
+                to: to.clone(),
+            });
+            rename_statements.push(JsonStatement::RenameColumn(RenameColumnStatement {
+                table: table.clone(),
+                from: from.clone(),
+                to: to.clone(),
+            }));
+            apply_sqlite_column_rename(prev, &table, &from, &to);
         }
     }
 }
@@ -875,7 +1009,7 @@ pub fn prepare_migration_renames(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::sqlite::ddl::{Column, ForeignKey, Index, IndexColumn, SqliteEntity, Table};
+    use crate::sqlite::ddl::{Column, ForeignKey, Index, IndexColumn, SqliteEntity, Table, View};
     use std::borrow::Cow;
 
     #[test]
@@ -1028,6 +1162,134 @@ mod tests {
         );
     }
 
+    fn sqlite_users_with_columns(columns: &[(&'static str, &'static str)]) -> SQLiteDDL {
+        let mut ddl = sqlite_table_with_id("users");
+        for (name, sql_type) in columns {
+            ddl.columns.push(Column::new("users", *name, *sql_type));
+        }
+        ddl
+    }
+
+    #[test]
+    fn column_renames_pair_by_position() {
+        let prev = sqlite_users_with_columns(&[("first", "text"), ("last", "text")]);
+        let cur = sqlite_users_with_columns(&[("given_name", "text"), ("family_name", "text")]);
+
+        let migration = compute_migration(&prev, &cur);
+
+        assert_eq!(
+            migration.sql_statements,
+            vec![
+                "ALTER TABLE `users` RENAME COLUMN `first` TO `given_name`;",
+                "ALTER TABLE `users` RENAME COLUMN `last` TO `family_name`;",
+            ]
+        );
+        assert!(migration.warnings.is_empty());
+    }
+
+    #[test]
+    fn shifted_column_renames_warn_instead_of_guessing() {
+        let prev = sqlite_users_with_columns(&[("first", "text"), ("last", "text")]);
+        let cur = sqlite_users_with_columns(&[
+            ("nickname", "integer"),
+            ("age", "integer"),
+            ("given_name", "text"),
+            ("family_name", "text"),
+        ]);
+
+        let migration = compute_migration(&prev, &cur);
+
+        assert!(
+            !migration
+                .statements
+                .iter()
+                .any(|statement| matches!(statement, JsonStatement::RenameColumn(_)))
+        );
+        assert!(
+            migration.warnings.iter().any(|warning| warning
+                .contains("Ambiguous SQLite column rename candidates in table 'users'")
+                && warning.contains("rename_column")),
+            "expected ambiguous column rename warning, got {:?}",
+            migration.warnings
+        );
+    }
+
+    #[test]
+    fn disabling_rename_detection_drops_and_adds() {
+        let prev = sqlite_users_with_columns(&[("first", "text")]);
+        let cur = sqlite_users_with_columns(&[("given_name", "text")]);
+
+        let migration = compute_migration_with(&prev, &cur, false);
+
+        assert!(
+            !migration
+                .statements
+                .iter()
+                .any(|statement| matches!(statement, JsonStatement::RenameColumn(_)))
+        );
+        assert!(
+            migration
+                .statements
+                .iter()
+                .any(|statement| matches!(statement, JsonStatement::DropColumn(_)))
+        );
+    }
+
+    #[test]
+    fn non_constant_default_rebuilds_instead_of_adding() {
+        let prev = sqlite_users_with_columns(&[]);
+        let mut cur = sqlite_users_with_columns(&[]);
+        cur.columns.push(
+            Column::new("users", "created_at", "text")
+                .not_null()
+                .default_value("CURRENT_TIMESTAMP"),
+        );
+
+        let migration = compute_migration(&prev, &cur);
+
+        assert!(
+            migration
+                .statements
+                .iter()
+                .any(|statement| matches!(statement, JsonStatement::RecreateTable(_)))
+        );
+        assert!(
+            migration
+                .sql_statements
+                .contains(&"INSERT INTO `__new_users`(`id`) SELECT `id` FROM `users`;".to_string())
+        );
+        assert!(migration.warnings.is_empty());
+    }
+
+    #[test]
+    fn rebuild_copies_renamed_columns_and_recreates_dependent_views() {
+        let mut prev = sqlite_users_with_columns(&[("first", "text"), ("age", "text")]);
+        let mut view = View::new("user_names");
+        view.definition = Some("SELECT first FROM users".into());
+        prev.views.push(view);
+
+        // `first` is renamed in place while `age` changes type, forcing a rebuild.
+        let mut cur = sqlite_users_with_columns(&[("first_name", "text"), ("age", "integer")]);
+        let mut view = View::new("user_names");
+        view.definition = Some("SELECT first_name FROM users".into());
+        cur.views.push(view);
+
+        let migration = compute_migration(&prev, &cur);
+        let sql = &migration.sql_statements;
+
+        let position = |prefix: &str| {
+            sql.iter()
+                .position(|statement| statement.starts_with(prefix))
+                .unwrap_or_else(|| panic!("missing `{prefix}` in {sql:?}"))
+        };
+        assert!(sql.contains(
+            &"INSERT INTO `__new_users`(`id`, `first_name`, `age`) SELECT `id`, `first_name`, `age` FROM `users`;"
+                .to_string()
+        ));
+        assert!(position("DROP VIEW `user_names`") < position("CREATE TABLE `__new_users`"));
+        assert!(position("PRAGMA foreign_keys=ON") < position("CREATE VIEW `user_names`"));
+    }
+
     #[test]
     fn test_column_nullable_change() {
         // Test that changing Option<String> to String (nullable to not null) is detected
@@ -1051,6 +1313,30 @@ mod tests {
         assert_eq!(altered[0].name, "users:email");
     }
 
+    #[test]
+    fn test_column_default_expression_change() {
+        let mut prev = SQLiteSnapshot::new();
+        prev.add_entity(SqliteEntity::Table(Table::new("users")));
+        prev.add_entity(SqliteEntity::Column(
+            Column::new("users", "created_at", "integer")
+                .not_null()
+                .default_value("(unixepoch())"),
+        ));
+
+        let mut cur = SQLiteSnapshot::new();
+        cur.add_entity(SqliteEntity::Table(Table::new("users")));
+        cur.add_entity(SqliteEntity::Column(
+            Column::new("users", "created_at", "integer")
+                .not_null()
+                .default_value("(unixepoch('subsec'))"),
+        ));
+
+        let diff = diff_snapshots(&prev, &cur);
+        let altered = diff.altered();
+        assert_eq!(altered.len(), 1, "Should detect default expression change");
+        assert_eq!(altered[0].name, "users:created_at");
+    }
+
     #[test]
     fn test_column_not_null_to_nullable() {
         // Test that changing String to Option<String> (not null to nullable) is detected
@@ -248,8 +248,10 @@ pub fn process_columns<S1: std::hash::BuildHasher, S2: std::hash::BuildHasher>(
     generated_columns: &std::collections::HashMap<String, super::ddl::ParsedGenerated, S1>,
     _pk_columns: &std::collections::HashSet<(String, String), S2>, // (table, column) - reserved for future use
 ) -> (Vec<Column>, Vec<PrimaryKey>) {
-    // Precompute AUTOINCREMENT columns once per table (avoids per-column regex compilation).
+    // Precompute AUTOINCREMENT columns and declared collations once per table
+    // (avoids per-column regex compilation).
     let mut autoinc_by_table: HashMap<String, std::collections::HashSet<String>> = HashMap::new();
+    let mut collations_by_table: HashMap<String, HashMap<String, String>> = HashMap::new();
     for c in raw_columns {
         if autoinc_by_table.contains_key(&c.table) {
             continue;
@@ -261,6 +263,7 @@ pub fn process_columns<S1: std::hash::BuildHasher, S2: std::hash::BuildHasher>(
             c.table.clone(),
             parse_autoincrement_columns_from_table_sql(sql),
         );
+        collations_by_table.insert(c.table.clone(), parse_collations_from_table_sql(sql));
     }
 
     let columns: Vec<Column> = raw_columns
@@ -287,11 +290,15 @@ pub fn process_columns<S1: std::hash::BuildHasher, S2: std::hash::BuildHasher>(
                 unique: None,      // Handled via UniqueConstraint entity
                 default: c.default_value.clone().map(std::convert::Into::into),
                 generated,
-                // PRAGMA table_info doesn't expose the collation per column;
-                // introspection-derived snapshots leave it as the default.
-                // Migration paths that need to detect collation drift will
-                // need to parse the CREATE TABLE SQL stored in sqlite_schema.
-                collate: None,
+                // PRAGMA table_info doesn't expose the collation per column,
+                // so it is read back from the CREATE TABLE SQL.
+                collate: collations_by_table
+                    .get(&c.table)
+                    .and_then(|cols| cols.get(&c.name))
+                    .cloned()
+                    .map(Into::into),
+                // SQLite has no catalog for comments.
+                comment: None,
                 ordinal_position: Some(c.cid),
             }
         })
@@ -443,24 +450,82 @@ fn parse_autoincrement_columns_from_table_sql(sql: &str) -> std::collections::Ha
     out
 }
 
+/// Parse declared column collations (`COLLATE <name>`) from a CREATE TABLE SQL statement.
+///
+/// Returns a map of column name to collation name. Collations nested inside
+/// parentheses (e.g. in CHECK expressions) are ignored.
+fn parse_collations_from_table_sql(sql: &str) -> HashMap<String, String> {
+    let mut out = HashMap::new();
+
+    let Some(body) = extract_table_body(sql) else {
+        return out;
+    };
+
+    for item in split_top_level_commas(body) {
+        if item.is_empty() || is_table_level_constraint(&item.to_uppercase()) {
+            continue;
+        }
+        let Some((col_name, rest)) = take_column_name(item) else {
+            continue;
+        };
+
+        let mut depth = 0i32;
+        let mut tokens = rest.split_whitespace();
+        while let Some(token) = tokens.next() {
+            if depth == 0 && token.eq_ignore_ascii_case("COLLATE") {
+                if let Some(name) = tokens.next() {
+                    let name = name.trim_end_matches(')');
+                    let name = name.trim_matches(|c| matches!(c, '"' | '`' | '\'' | '[' | ']'));
+                    out.insert(col_name, name.to_string());
+                }
+                break;
+            }
+            for ch in token.chars() {
+                match ch {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    out
+}
+
 /// Process raw index info into Index entities
 #[must_use]
 pub fn process_indexes<S: std::hash::BuildHasher>(
     raw_indexes: &[RawIndexInfo],
     index_columns: &[RawIndexColumn],
-    _table_sql_map: &std::collections::HashMap<String, String, S>,
+    table_sql_map: &std::collections::HashMap<String, String, S>,
 ) -> Vec<Index> {
     raw_indexes
         .iter()
         .filter(|idx| idx.origin == "c") // Only CREATE INDEX indexes
         .map(|idx| {
+            // `pragma_index_xinfo` reports the effective collation, which is
+            // inherited from the column unless the index overrides it.
+            let column_collations = table_sql_map
+                .get(&idx.table)
+                .map(|sql| parse_collations_from_table_sql(sql))
+                .unwrap_or_default();
             let columns: Vec<IndexColumn> = index_columns
                 .iter()
                 .filter(|c| c.index_name == idx.name && c.key)
                 .filter_map(|c| {
-                    c.name.clone().map(|name| IndexColumn {
-                        value: name.into(),
-                        is_expression: false,
+                    c.name.clone().map(|name| {
+                        let inherited = column_collations
+                            .get(&name)
+                            .map_or("BINARY", String::as_str);
+                        let collate = (!c.coll.is_empty()
+                            && !c.coll.eq_ignore_ascii_case(inherited))
+                        .then(|| c.coll.clone().into());
+                        IndexColumn {
+                            value: name.into(),
+                            is_expression: false,
+                            collate,
+                        }
                     })
                 })
                 .collect();
@@ -929,6 +994,49 @@ mod tests {
         assert!(!cols.contains("name"));
     }
 
+    #[test]
+    fn test_parse_collations_from_table_sql() {
+        let sql = "CREATE TABLE users (id INTEGER PRIMARY KEY, email TEXT NOT NULL COLLATE NOCASE, \
+                   name TEXT CHECK (name COLLATE NOCASE <> 'x'), `slug` TEXT COLLATE \"RTRIM\")";
+        let cols = parse_collations_from_table_sql(sql);
+        assert_eq!(cols.get("email").map(String::as_str), Some("NOCASE"));
+        assert_eq!(cols.get("slug").map(String::as_str), Some("RTRIM"));
+        assert!(!cols.contains_key("name"));
+        assert!(!cols.contains_key("id"));
+    }
+
+    #[test]
+    fn test_process_indexes_keeps_overridden_collation_only() {
+        let table_sql = HashMap::from([(
+            "users".to_string(),
+            "CREATE TABLE users (email TEXT COLLATE NOCASE, name TEXT)".to_string(),
+        )]);
+        let raw_indexes = vec![RawIndexInfo {
+            table: "users".to_string(),
+            name: "users_email_name_idx".to_string(),
+            unique: false,
+            origin: "c".to_string(),
+            partial: false,
+        }];
+        let raw_column = |seqno, name: &str, coll: &str| RawIndexColumn {
+            index_name: "users_email_name_idx".to_string(),
+            seqno,
+            cid: seqno,
+            name: Some(name.to_string()),
+            desc: false,
+            coll: coll.to_string(),
+            key: true,
+        };
+        let index_columns = vec![
+            raw_column(0, "email", "NOCASE"),
+            raw_column(1, "name", "NOCASE"),
+        ];
+
+        let indexes = process_indexes(&raw_indexes, &index_columns, &table_sql);
+        assert_eq!(indexes[0].columns[0].collate, None);
+        assert_eq!(indexes[0].columns[1].collate.as_deref(), Some("NOCASE"));
+    }
+
     #[test]
     fn test_parse_generated_columns_from_table_sql() {
         let sql = r#"
@@ -0,0 +1,504 @@
+//! Driver-agnostic `SQLite` introspection.
+//!
+//! [`SqliteIntrospector`] runs the [`queries`] against anything that can hand
+//! back rows as [`IntrospectValue`]s and decodes them into the [`SQLiteDDL`]
+//! model. Drivers only implement [`SqliteRowSource`] (blocking) or
+//! [`AsyncSqliteRowSource`]; `rusqlite`, `libsql` and `turso` connections are
+//! covered behind the features of the same name.
+
+use super::SQLiteDDL;
+use super::introspect::{
+    IntrospectError, IntrospectResult, RawColumnInfo, RawForeignKey, RawIndexColumn, RawIndexInfo,
+    RawIntrospection, RawViewInfo, assemble_ddl, queries,
+};
+use super::snapshot::SQLiteSnapshot;
+
+/// A single value of an introspection row.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntrospectValue {
+    Null,
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+/// Runs an introspection query on a blocking connection, returning every row
+/// with its values in column order.
+pub trait SqliteRowSource {
+    type Error: std::fmt::Display;
+
+    /// Runs `sql` (which takes no parameters) to completion.
+    ///
+    /// # Errors
+    ///
+    /// Returns the driver's error if the query fails.
+    fn fetch_rows(&self, sql: &str) -> Result<Vec<Vec<IntrospectValue>>, Self::Error>;
+}
+
+/// Async counterpart of [`SqliteRowSource`].
+pub trait AsyncSqliteRowSource {
+    type Error: std::fmt::Display;
+
+    /// Runs `sql` (which takes no parameters) to completion.
+    fn fetch_rows(
+        &self,
+        sql: &str,
+    ) -> impl Future<Output = Result<Vec<Vec<IntrospectValue>>, Self::Error>>;
+}
+
+impl<S: SqliteRowSource + ?Sized> SqliteRowSource for &S {
+    type Error = S::Error;
+
+    fn fetch_rows(&self, sql: &str) -> Result<Vec<Vec<IntrospectValue>>, Self::Error> {
+        (**self).fetch_rows(sql)
+    }
+}
+
+impl<S: AsyncSqliteRowSource + ?Sized> AsyncSqliteRowSource for &S {
+    type Error = S::Error;
+
+    fn fetch_rows(
+        &self,
+        sql: &str,
+    ) -> impl Future<Output = Result<Vec<Vec<IntrospectValue>>, Self::Error>> {
+        (**self).fetch_rows(sql)
+    }
+}
+
+/// Introspects a live `SQLite` database through a row source.
+///
+/// ```no_run
+/// # use drizzle_migrations::sqlite::SqliteIntrospector;
+/// let conn = rusqlite::Connection::open("app.db").unwrap();
+/// let ddl = SqliteIntrospector::new(&conn).ddl().unwrap();
+/// # let _ = ddl;
+/// ```
+#[derive(Debug, Clone)]
+pub struct SqliteIntrospector<S> {
+    source: S,
+    view_columns: bool,
+}
+
+impl<S> SqliteIntrospector<S> {
+    /// Introspects through `source`.
+    pub const fn new(source: S) -> Self {
+        Self {
+            source,
+            view_columns: false,
+        }
+    }
+
+    /// Also read the columns of views, as schema codegen needs them.
+    ///
+    /// Off by default: the differ doesn't track view columns.
+    #[must_use]
+    pub const fn with_view_columns(mut self) -> Self {
+        self.view_columns = true;
+        self
+    }
+
+    fn queries(&self) -> &'static [&'static str] {
+        const ALL: [&str; 7] = [
+            queries::TABLES_QUERY,
+            queries::COLUMNS_QUERY,
+            queries::INDEXES_QUERY,
+            queries::INDEX_COLUMNS_QUERY,
+            queries::FOREIGN_KEYS_QUERY,
+            queries::VIEWS_QUERY,
+            queries::VIEW_COLUMNS_QUERY,
+        ];
+        if self.view_columns { &ALL } else { &ALL[..6] }
+    }
+}
+
+impl<S: SqliteRowSource> SqliteIntrospector<S> {
+    /// Reads the raw catalog rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntrospectError`] if a query fails or returns rows of an
+    /// unexpected shape.
+    pub fn raw(&self) -> IntrospectResult<RawIntrospection> {
+        let mut results = Vec::with_capacity(7);
+        for sql in self.queries() {
+            results.push(self.source.fetch_rows(sql).map_err(source_error)?);
+        }
+        decode(results)
+    }
+
+    /// Introspects into the DDL model the differ works on.
+    ///
+    /// # Errors
+    ///
+    /// See [`raw`](Self::raw).
+    pub fn ddl(&self) -> IntrospectResult<SQLiteDDL> {
+        self.raw().map(assemble_ddl)
+    }
+
+    /// Introspects into a snapshot.
+    ///
+    /// # Errors
+    ///
+    /// See [`raw`](Self::raw).
+    pub fn snapshot(&self) -> IntrospectResult<SQLiteSnapshot> {
+        self.ddl().map(|ddl| snapshot_from_ddl(&ddl))
+    }
+}
+
+impl<S: AsyncSqliteRowSource> SqliteIntrospector<S> {
+    /// Async [`raw`](Self::raw).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IntrospectError`] if a query fails or returns rows of an
+    /// unexpected shape.
+    pub async fn raw_async(&self) -> IntrospectResult<RawIntrospection> {
+        let mut results = Vec::with_capacity(7);
+        for sql in self.queries() {
+            results.push(self.source.fetch_rows(sql).await.map_err(source_error)?);
+        }
+        decode(results)
+    }
+
+    /// Async [`ddl`](Self::ddl).
+    ///
+    /// # Errors
+    ///
+    /// See [`raw_async`](Self::raw_async).
+    pub async fn ddl_async(&self) -> IntrospectResult<SQLiteDDL> {
+        self.raw_async().await.map(assemble_ddl)
+    }
+
+    /// Async [`snapshot`](Self::snapshot).
+    ///
+    /// # Errors
+    ///
+    /// See [`raw_async`](Self::raw_async).
+    pub async fn snapshot_async(&self) -> IntrospectResult<SQLiteSnapshot> {
+        self.ddl_async().await.map(|ddl| snapshot_from_ddl(&ddl))
+    }
+}
+
+fn snapshot_from_ddl(ddl: &SQLiteDDL) -> SQLiteSnapshot {
+    let mut snapshot = SQLiteSnapshot::new();
+    for entity in ddl.to_entities() {
+        snapshot.add_entity(entity);
+    }
+    snapshot
+}
+
+fn source_error(error: impl std::fmt::Display) -> IntrospectError {
+    IntrospectError {
+        message: error.to_string(),
+        table: None,
+    }
+}
+
+type Rows = Vec<Vec<IntrospectValue>>;
+
+fn decode(results: Vec<Rows>) -> IntrospectResult<RawIntrospection> {
+    let mut results = results.into_iter();
+    let mut next = || results.next().unwrap_or_default();
+    let (tables, columns, indexes, index_columns, foreign_keys, views, view_columns) =
+        (next(), next(), next(), next(), next(), next(), next());
+
+    let mut raw = RawIntrospection {
+        tables: decode_rows(&tables, "tables", |row| {
+            Ok((row.text(0)?, row.opt_text(1)?))
+        })?,
+        columns: decode_rows(&columns, "columns", decode_column)?,
+        indexes: decode_rows(&indexes, "indexes", |row| {
+            Ok(RawIndexInfo {
+                table: row.text(0)?,
+                name: row.text(1)?,
+                unique: row.flag(2)?,
+                origin: row.text(3)?,
+                partial: row.flag(4)?,
+            })
+        })?,
+        index_columns: decode_rows(&index_columns, "index columns", |row| {
+            Ok(RawIndexColumn {
+                index_name: row.text(0)?,
+                seqno: row.int(1)?,
+                cid: row.int(2)?,
+                name: row.opt_text(3)?,
+                desc: row.flag(4)?,
+                coll: row.text(5)?,
+                key: row.flag(6)?,
+            })
+        })?,
+        foreign_keys: decode_rows(&foreign_keys, "foreign keys", |row| {
+            Ok(RawForeignKey {
+                table: row.text(0)?,
+                id: row.int(1)?,
+                seq: row.int(2)?,
+                to_table: row.text(3)?,
+                from_column: row.text(4)?,
+                to_column: row.text(5)?,
+                on_update: row.text(6)?,
+                on_delete: row.text(7)?,
+                r#match: row.text(8)?,
+            })
+        })?,
+        views: decode_rows(&views, "views", |row| {
+            Ok(RawViewInfo {
+                name: row.text(0)?,
+                sql: row.text(1)?,
+            })
+        })?,
+    };
+    raw.columns
+        .extend(decode_rows(&view_columns, "view columns", decode_column)?);
+    Ok(raw)
+}
+
+fn decode_column(row: Row<'_>) -> IntrospectResult<RawColumnInfo> {
+    Ok(RawColumnInfo {
+        table: row.text(0)?,
+        cid: row.int(1)?,
+        name: row.text(2)?,
+        column_type: row.text(3)?,
+        not_null: row.flag(4)?,
+        default_value: row.opt_text(5)?,
+        pk: row.int(6)?,
+        hidden: row.int(7)?,
+        sql: row.opt_text(8)?,
+    })
+}
+
+fn decode_rows<T>(
+    rows: &Rows,
+    query: &'static str,
+    mut decode: impl FnMut(Row<'_>) -> IntrospectResult<T>,
+) -> IntrospectResult<Vec<T>> {
+    rows.iter()
+        .map(|values| decode(Row { values, query }))
+        .collect()
+}
+
+#[derive(Clone, Copy)]
+struct Row<'a> {
+    values: &'a [IntrospectValue],
+    query: &'static str,
+}
+
+impl Row<'_> {
+    fn error(self, index: usize, expected: &str) -> IntrospectError {
+        IntrospectError {
+            message: format!(
+                "expected {expected} in column {index} of the {} query, got {:?}",
+                self.query,
+                self.values.get(index)
+            ),
+            table: None,
+        }
+    }
+
+    fn opt_text(self, index: usize) -> IntrospectResult<Option<String>> {
+        match self.values.get(index) {
+            Some(IntrospectValue::Null) => Ok(None),
+            Some(IntrospectValue::Text(text)) => Ok(Some(text.clone())),
+            Some(IntrospectValue::Integer(value)) => Ok(Some(value.to_string())),
+            Some(IntrospectValue::Real(value)) => Ok(Some(value.to_string())),
+            _ => Err(self.error(index, "text")),
+        }
+    }
+
+    fn text(self, index: usize) -> IntrospectResult<String> {
+        self.opt_text(index)?
+            .ok_or_else(|| self.error(index, "text"))
+    }
+
+    fn int(self, index: usize) -> IntrospectResult<i32> {
+        match self.values.get(index) {
+            Some(IntrospectValue::Integer(value)) => {
+                i32::try_from(*value).map_err(|_| self.error(index, "a 32-bit integer"))
+            }
+            _ => Err(self.error(index, "an integer")),
+        }
+    }
+
+    fn flag(self, index: usize) -> IntrospectResult<bool> {
+        self.int(index).map(|value| value != 0)
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl SqliteRowSource for rusqlite::Connection {
+    type Error = rusqlite::Error;
+
+    fn fetch_rows(&self, sql: &str) -> Result<Vec<Vec<IntrospectValue>>, Self::Error> {
+        use rusqlite::types::ValueRef;
+
+        let mut stmt = self.prepare(sql)?;
+        let column_count = stmt.column_count();
+        let mut rows = stmt.query([])?;
+        let mut out = Vec::new();
+        while let Some(row) = rows.next()? {
+            let mut values = Vec::with_capacity(column_count);
+            for index in 0..column_count {
+                values.push(match row.get_ref(index)? {
+                    ValueRef::Null => IntrospectValue::Null,
+                    ValueRef::Integer(value) => IntrospectValue::Integer(value),
+                    ValueRef::Real(value) => IntrospectValue::Real(value),
+                    ValueRef::Text(text) => {
+                        IntrospectValue::Text(String::from_utf8_lossy(text).into_owned())
+                    }
+                    ValueRef::Blob(blob) => IntrospectValue::Blob(blob.to_vec()),
+                });
+            }
+            out.push(values);
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(feature = "libsql")]
+impl AsyncSqliteRowSource for libsql::Connection {
+    type Error = libsql::Error;
+
+    async fn fetch_rows(&self, sql: &str) -> Result<Vec<Vec<IntrospectValue>>, Self::Error> {
+        let mut rows = self.query(sql, ()).await?;
+        let column_count = rows.column_count();
+        let mut out = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let mut values = Vec::new();
+            for index in 0..column_count {
+                values.push(match row.get_value(index)? {
+                    libsql::Value::Null => IntrospectValue::Null,
+                    libsql::Value::Integer(value) => IntrospectValue::Integer(value),
+                    libsql::Value::Real(value) => IntrospectValue::Real(value),
+                    libsql::Value::Text(text) => IntrospectValue::Text(text),
+                    libsql::Value::Blob(blob) => IntrospectValue::Blob(blob),
+                });
+            }
+            out.push(values);
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(feature = "turso")]
+impl AsyncSqliteRowSource for turso::Connection {
+    type Error = turso::Error;
+
+    async fn fetch_rows(&self, sql: &str) -> Result<Vec<Vec<IntrospectValue>>, Self::Error> {
+        let mut rows = self.query(sql, ()).await?;
+        let column_count = rows.column_count();
+        let mut out = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let mut values = Vec::with_capacity(column_count);
+            for index in 0..column_count {
+                values.push(match row.get_value(index)? {
+                    turso::Value::Null => IntrospectValue::Null,
+                    turso::Value::Integer(value) => IntrospectValue::Integer(value),
+                    turso::Value::Real(value) => IntrospectValue::Real(value),
+                    turso::Value::Text(text) => IntrospectValue::Text(text),
+                    turso::Value::Blob(blob) => IntrospectValue::Blob(blob),
+                });
+            }
+            out.push(values);
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Canned;
+
+    impl SqliteRowSource for Canned {
+        type Error = std::convert::Infallible;
+
+        fn fetch_rows(&self, sql: &str) -> Result<Vec<Vec<IntrospectValue>>, Self::Error> {
+            use IntrospectValue::{Integer, Null, Text};
+            let text = |value: &str| Text(value.to_string());
+            let rows = if sql == queries::TABLES_QUERY {
+                vec![vec![
+                    text("users"),
+                    text("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)"),
+                ]]
+            } else if sql == queries::COLUMNS_QUERY {
+                let sql = text("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)");
+                vec![
+                    vec![
+                        text("users"),
+                        Integer(0),
+                        text("id"),
+                        text("INTEGER"),
+                        Integer(0),
+                        Null,
+                        Integer(1),
+                        Integer(0),
+                        sql.clone(),
+                    ],
+                    vec![
+                        text("users"),
+                        Integer(1),
+                        text("name"),
+                        text("TEXT"),
+                        Integer(1),
+                        Null,
+                        Integer(0),
+                        Integer(0),
+                        sql,
+                    ],
+                ]
+            } else if sql == queries::VIEW_COLUMNS_QUERY {
+                vec![vec![
+                    text("user_names"),
+                    Integer(0),
+                    text("name"),
+                    text("TEXT"),
+                    Integer(0),
+                    Null,
+                    Integer(0),
+                    Integer(0),
+                    Null,
+                ]]
+            } else {
+                Vec::new()
+            };
+            Ok(rows)
+        }
+    }
+
+    #[test]
+    fn decodes_rows_from_any_source() {
+        let ddl = SqliteIntrospector::new(Canned).ddl().expect("introspect");
+        assert_eq!(ddl.tables.len(), 1);
+        assert_eq!(ddl.columns.len(), 2);
+        assert_eq!(ddl.pks.len(), 1);
+
+        let raw = SqliteIntrospector::new(&Canned)
+            .with_view_columns()
+            .raw()
+            .expect("introspect with view columns");
+        assert_eq!(raw.columns.len(), 3);
+    }
+
+    #[test]
+    fn rejects_rows_of_the_wrong_shape() {
+        struct Broken;
+
+        impl SqliteRowSource for Broken {
+            type Error = std::convert::Infallible;
+
+            fn fetch_rows(&self, sql: &str) -> Result<Vec<Vec<IntrospectValue>>, Self::Error> {
+                Ok(if sql == queries::TABLES_QUERY {
+                    vec![vec![IntrospectValue::Null]]
+                } else {
+                    Vec::new()
+                })
+            }
+        }
+
+        let err = SqliteIntrospector::new(Broken)
+            .ddl()
+            .expect_err("null table name");
+        assert!(err.message.contains("tables query"), "{err}");
+    }
+}
@@ -6,12 +6,15 @@
 //!
 //! * the [`SnapshotEntity`] impl that pins the SQLite dialect / version
 //!   constants used by `Snapshot::new()`;
+//! * push helpers (`scoped_to_tables`, `normalize_columns_for_push`,
+//!   `table_names`, `prepare_for_push`) on `Snapshot<SqliteEntity>`;
 //! * the legacy v6 types preserved for reading old snapshots.
 
 use super::ddl::SqliteEntity;
 use crate::snapshot::{Snapshot, SnapshotEntity};
 use crate::version::SQLITE_SNAPSHOT_VERSION;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 impl SnapshotEntity for SqliteEntity {
     const DIALECT: &'static str = "sqlite";
@@ -24,6 +27,89 @@ impl SnapshotEntity for SqliteEntity {
 /// for the field set and IO surface.
 pub type SQLiteSnapshot = Snapshot<SqliteEntity>;
 
+impl Snapshot<SqliteEntity> {
+    /// Return a new snapshot scoped to only the given tables.
+    ///
+    /// Table-scoped entities (Column, Index, FK, PK, Unique, Check) are kept
+    /// only when their parent table is in the set; views pass through.
+    #[must_use]
+    pub fn scoped_to_tables(&self, tables: &HashSet<String>) -> Self {
+        let mut scoped = Self::new();
+        scoped.ddl = self
+            .ddl
+            .iter()
+            .filter(|entity| {
+                let table = match entity {
+                    SqliteEntity::Table(t) => &t.name,
+                    SqliteEntity::Column(c) => &c.table,
+                    SqliteEntity::Index(i) => &i.table,
+                    SqliteEntity::ForeignKey(f) => &f.table,
+                    SqliteEntity::PrimaryKey(p) => &p.table,
+                    SqliteEntity::UniqueConstraint(u) => &u.table,
+                    SqliteEntity::CheckConstraint(c) => &c.table,
+                    SqliteEntity::View(_) => return true,
+                };
+                tables.contains(table.as_ref())
+            })
+            .cloned()
+            .collect();
+        scoped
+    }
+
+    /// Normalize introspected columns for push comparison.
+    ///
+    /// - Strips `ordinal_position` (desired snapshots don't have it).
+    /// - Marks primary key columns `NOT NULL`: `PRAGMA table_info` reports
+    ///   an `INTEGER PRIMARY KEY` as nullable, while schemas declare it
+    ///   not null.
+    pub fn normalize_columns_for_push(&mut self) {
+        let pk_columns: HashSet<(String, String)> = self
+            .ddl
+            .iter()
+            .filter_map(|entity| match entity {
+                SqliteEntity::PrimaryKey(pk) => Some(pk),
+                _ => None,
+            })
+            .flat_map(|pk| {
+                pk.columns
+                    .iter()
+                    .map(|column| (pk.table.to_string(), column.to_string()))
+            })
+            .collect();
+
+        for entity in &mut self.ddl {
+            if let SqliteEntity::Column(c) = entity {
+                c.ordinal_position = None;
+                if pk_columns.contains(&(c.table.to_string(), c.name.to_string())) {
+                    c.not_null = true;
+                }
+            }
+        }
+    }
+
+    /// Names of the tables in this snapshot.
+    #[must_use]
+    pub fn table_names(&self) -> HashSet<String> {
+        self.ddl
+            .iter()
+            .filter_map(|entity| match entity {
+                SqliteEntity::Table(t) => Some(t.name.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Prepare a live (introspected) snapshot for push comparison against
+    /// `desired`: scope it to the tables `desired` manages, so unmanaged
+    /// tables aren't dropped, and normalize its columns.
+    #[must_use]
+    pub fn prepare_for_push(&self, desired: &Self) -> Self {
+        let mut scoped = self.scoped_to_tables(&desired.table_names());
+        scoped.normalize_columns_for_push();
+        scoped
+    }
+}
+
 // =============================================================================
 // Legacy V6 Snapshot Format (for reading old snapshots)
 // =============================================================================
@@ -185,4 +271,37 @@ mod tests {
             "Should not contain 'not_null'"
         );
     }
+
+    #[test]
+    fn prepare_for_push_scopes_tables_and_normalizes_columns() {
+        use crate::sqlite::ddl::PrimaryKey;
+
+        let mut live = SQLiteSnapshot::new();
+        for table in ["users", "unmanaged"] {
+            live.add_entity(SqliteEntity::Table(Table::new(table)));
+            let mut id = Column::new(table, "id", "integer");
+            id.ordinal_position = Some(0);
+            live.add_entity(SqliteEntity::Column(id));
+            live.add_entity(SqliteEntity::PrimaryKey(PrimaryKey::from_strings(
+                table.to_string(),
+                format!("{table}_pk"),
+                vec!["id".to_string()],
+            )));
+        }
+        let mut desired = SQLiteSnapshot::new();
+        desired.add_entity(SqliteEntity::Table(Table::new("users")));
+
+        let prepared = live.prepare_for_push(&desired);
+        assert_eq!(prepared.table_names(), HashSet::from(["users".to_string()]));
+        let id = prepared
+            .ddl
+            .iter()
+            .find_map(|entity| match entity {
+                SqliteEntity::Column(c) => Some(c),
+                _ => None,
+            })
+            .expect("users.id");
+        assert!(id.not_null);
+        assert_eq!(id.ordinal_position, None);
+    }
 }
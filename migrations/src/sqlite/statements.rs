@@ -59,6 +59,7 @@ fn table_full_to_table(table: &TableFull) -> Table {
         name: table.name.clone().into(),
         strict: table.strict,
         without_rowid: table.without_rowid,
+        comment: None,
     }
 }
 
@@ -367,22 +368,29 @@ fn convert_recreate_table(st: &RecreateTableStatement) -> Vec<String> {
     let name = &st.to.name;
     let new_table_name = format!("__new_{name}");
 
-    // Get columns to copy (non-generated columns that exist in both)
-    let column_names: Vec<String> = st
+    // Copy non-generated columns that exist in both. A column that became
+    // NOT NULL falls back to its new default for rows holding NULL.
+    let (target_columns, source_columns): (Vec<String>, Vec<String>) = st
         .from
         .columns
         .iter()
-        .filter(|col| {
-            col.generated.is_none()
-                && st
-                    .to
-                    .columns
-                    .iter()
-                    .any(|c| c.name == col.name && c.generated.is_none())
+        .filter(|col| col.generated.is_none())
+        .filter_map(|col| {
+            let target = st
+                .to
+                .columns
+                .iter()
+                .find(|c| c.name == col.name && c.generated.is_none())?;
+            let ident = quote_ident(&col.name);
+            let source = match &target.default {
+                Some(default) if target.not_null && !col.not_null => {
+                    format!("coalesce({ident}, {default})")
+                }
+                _ => ident.clone(),
+            };
+            Some((ident, source))
         })
-        .map(|col| quote_ident(&col.name))
-        .collect();
-    let cols_str = column_names.join(", ");
+        .unzip();
 
     let mut statements = Vec::new();
 
@@ -401,11 +409,15 @@ fn convert_recreate_table(st: &RecreateTableStatement) -> Vec<String> {
     }));
 
     // 3. Copy data
-    statements.push(format!(
-        "INSERT INTO {}({cols_str}) SELECT {cols_str} FROM {};",
-        quote_ident(&new_table_name),
-        quote_ident(name)
-    ));
+    if !target_columns.is_empty() {
+        statements.push(format!(
+            "INSERT INTO {}({}) SELECT {} FROM {};",
+            quote_ident(&new_table_name),
+            target_columns.join(", "),
+            source_columns.join(", "),
+            quote_ident(name)
+        ));
+    }
 
     // 4. Drop old table
     statements.push(format!("DROP TABLE {};", quote_ident(name)));
@@ -902,6 +914,7 @@ mod tests {
             vec![IndexColumn {
                 value: "email".into(),
                 is_expression: false,
+                collate: None,
             }],
         )
         .unique();
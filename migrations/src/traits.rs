@@ -154,6 +154,8 @@ pub enum EntityKind {
     Enum = 1,
     Sequence = 2,
     Role = 3,
+    Domain = 4,
+    Composite = 5,
 
     // Table-level entities
     Table = 10,
@@ -163,6 +165,7 @@ pub enum EntityKind {
     PrimaryKey = 14,
     UniqueConstraint = 15,
     CheckConstraint = 16,
+    ExcludeConstraint = 17,
 
     // Other entities
     Policy = 20,
@@ -178,6 +181,8 @@ impl EntityKind {
             Self::Enum => "enums",
             Self::Sequence => "sequences",
             Self::Role => "roles",
+            Self::Domain => "domains",
+            Self::Composite => "composites",
             Self::Table => "tables",
             Self::Column => "columns",
             Self::Index => "indexes",
@@ -185,6 +190,7 @@ impl EntityKind {
             Self::PrimaryKey => "pks",
             Self::UniqueConstraint => "uniques",
             Self::CheckConstraint => "checks",
+            Self::ExcludeConstraint => "excludes",
             Self::Policy => "policies",
             Self::View => "views",
         }
@@ -198,6 +204,8 @@ impl EntityKind {
             "enums" => Some(Self::Enum),
             "sequences" => Some(Self::Sequence),
             "roles" => Some(Self::Role),
+            "domains" => Some(Self::Domain),
+            "composites" => Some(Self::Composite),
             "tables" => Some(Self::Table),
             "columns" => Some(Self::Column),
             "indexes" => Some(Self::Index),
@@ -205,6 +213,7 @@ impl EntityKind {
             "pks" => Some(Self::PrimaryKey),
             "uniques" => Some(Self::UniqueConstraint),
             "checks" => Some(Self::CheckConstraint),
+            "excludes" => Some(Self::ExcludeConstraint),
             "policies" => Some(Self::Policy),
             "views" => Some(Self::View),
             _ => None,
@@ -3,9 +3,13 @@
 //! These functions transform snapshot schemas from older versions to newer versions.
 //! The transformations match what drizzle-kit does to maintain compatibility.
 
+use std::path::Path;
+
 use serde_json::{Map, Value};
 
+use crate::journal::Journal;
 use crate::version::{POSTGRES_SNAPSHOT_VERSION, SQLITE_SNAPSHOT_VERSION};
+use crate::words::timestamp_prefix_from_secs;
 use drizzle_types::Dialect;
 
 /// Upgrade a `SQLite` snapshot from v5 to v6
@@ -252,6 +256,70 @@ pub fn upgrade_to_latest(json: Value, dialect: Dialect) -> Value {
     }
 }
 
+/// Convert a legacy drizzle-kit migrations folder (`meta/_journal.json`,
+/// `0000_tag.sql`, `meta/0000_snapshot.json`) into the V3 folder layout.
+///
+/// Each journal entry becomes `{YYYYMMDDHHMMSS}_{name}/migration.sql` (plus
+/// `snapshot.json` when one exists, upgraded to the latest version), using
+/// the entry's `when` for the timestamp so ordering and the migrator's
+/// `created_at` stay consistent with what drizzle-kit already applied. The
+/// legacy files are removed once every folder has been written.
+///
+/// Returns the new folder tags in journal order, or an empty list when
+/// `out_dir` has no legacy journal.
+///
+/// # Errors
+///
+/// Returns an error if the journal cannot be parsed, a journal entry's SQL
+/// file is missing, a snapshot is not valid JSON, or any file operation
+/// fails.
+pub fn import_legacy_folder(out_dir: &Path, dialect: Dialect) -> std::io::Result<Vec<String>> {
+    let meta_dir = out_dir.join("meta");
+    let journal_path = meta_dir.join("_journal.json");
+    if !journal_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let journal = Journal::load(&journal_path)?;
+    let mut written = Vec::with_capacity(journal.entries.len());
+    let mut legacy_sql = Vec::with_capacity(journal.entries.len());
+
+    for entry in &journal.entries {
+        let (prefix, name) = entry
+            .tag
+            .split_once('_')
+            .filter(|(prefix, _)| prefix.chars().all(|c| c.is_ascii_digit()))
+            .unwrap_or(("", entry.tag.as_str()));
+        let tag = format!("{}_{name}", timestamp_prefix_from_secs(entry.when / 1000));
+
+        let sql_path = out_dir.join(format!("{}.sql", entry.tag));
+        let sql = std::fs::read_to_string(&sql_path)?;
+        let folder = out_dir.join(&tag);
+        std::fs::create_dir_all(&folder)?;
+        std::fs::write(folder.join("migration.sql"), sql)?;
+
+        let snapshot_path = meta_dir.join(format!("{prefix}_snapshot.json"));
+        if !prefix.is_empty() && snapshot_path.exists() {
+            let contents = std::fs::read_to_string(&snapshot_path)?;
+            let json: Value = serde_json::from_str(&contents)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+            let upgraded = serde_json::to_string_pretty(&upgrade_to_latest(json, dialect))
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+            std::fs::write(folder.join("snapshot.json"), upgraded)?;
+        }
+
+        legacy_sql.push(sql_path);
+        written.push(tag);
+    }
+
+    for path in legacy_sql {
+        std::fs::remove_file(path)?;
+    }
+    std::fs::remove_dir_all(meta_dir)?;
+
+    Ok(written)
+}
+
 /// Check if a snapshot needs upgrade using the Dialect trait
 ///
 /// This provides type-safe version checking using the dialect marker types:
@@ -219,6 +219,76 @@ pub fn prepare_migration_renames<T>(
     renames
 }
 
+// =============================================================================
+// Column Rename Heuristics
+// =============================================================================
+
+/// Column renames inferred for one table by [`match_column_renames`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ColumnRenameMatch {
+    /// `(from, to)` pairs, in previous declaration order.
+    pub renames: Vec<(String, String)>,
+    /// Dropped columns that match a created column but could not be paired
+    /// unambiguously.
+    pub ambiguous: Vec<String>,
+}
+
+/// Pair dropped and created columns of one table as renames.
+///
+/// `prev` and `cur` are the table's column names in declaration order, and
+/// `same` reports whether a previous and a current column agree on
+/// everything but their name. One dropped and one created column pair on
+/// properties alone. With several, a dropped column only pairs with the
+/// created column at the same position.
+#[must_use]
+pub fn match_column_renames(
+    prev: &[&str],
+    cur: &[&str],
+    same: impl Fn(&str, &str) -> bool,
+) -> ColumnRenameMatch {
+    let dropped: Vec<(usize, &str)> = prev
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| !cur.contains(name))
+        .map(|(idx, name)| (idx, *name))
+        .collect();
+    let mut created: Vec<&str> = cur
+        .iter()
+        .filter(|name| !prev.contains(name))
+        .copied()
+        .collect();
+
+    let mut result = ColumnRenameMatch::default();
+    if let ([(_, from)], [to]) = (dropped.as_slice(), created.as_slice()) {
+        if same(from, to) {
+            result
+                .renames
+                .push(((*from).to_string(), (*to).to_string()));
+        }
+        return result;
+    }
+
+    let mut unmatched = Vec::new();
+    for (idx, from) in dropped {
+        let positional = cur
+            .get(idx)
+            .filter(|to| created.contains(to) && same(from, to));
+        if let Some(to) = positional {
+            created.retain(|name| name != to);
+            result.renames.push((from.to_string(), (*to).to_string()));
+        } else {
+            unmatched.push(from);
+        }
+    }
+
+    result.ambiguous = unmatched
+        .into_iter()
+        .filter(|from| created.iter().any(|to| same(from, to)))
+        .map(str::to_string)
+        .collect();
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,6 +310,28 @@ mod tests {
         assert_eq!(trim_char("hello", '\''), "hello");
     }
 
+    #[test]
+    fn match_column_renames_pairs_by_position() {
+        let same = |from: &str, to: &str| from.len() == to.len();
+
+        let single = match_column_renames(&["id", "name"], &["id", "title"], |_, _| true);
+        assert_eq!(single.renames, vec![("name".into(), "title".into())]);
+
+        let matched = match_column_renames(&["id", "abc", "wxyz"], &["id", "def", "stuv"], same);
+        assert_eq!(
+            matched.renames,
+            vec![("abc".into(), "def".into()), ("wxyz".into(), "stuv".into())]
+        );
+        assert!(matched.ambiguous.is_empty());
+
+        let shifted = match_column_renames(&["id", "abc", "def"], &["ghi", "id", "jklm"], same);
+        assert!(shifted.renames.is_empty());
+        assert_eq!(
+            shifted.ambiguous,
+            vec!["abc".to_string(), "def".to_string()]
+        );
+    }
+
     #[test]
     fn test_parse_params() {
         assert_eq!(parse_params("varchar(255)"), vec!["255"]);
@@ -1420,8 +1420,12 @@ pub fn generate_timestamp_prefix() -> String {
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or_default();
-    let secs = now.as_secs();
+    timestamp_prefix_from_secs(now.as_secs())
+}
 
+/// Format Unix seconds as a UTC `YYYYMMDDHHMMSS` prefix
+#[must_use]
+pub fn timestamp_prefix_from_secs(secs: u64) -> String {
     // Convert to datetime components (UTC)
     let days = secs / 86400;
     let time_of_day = secs % 86400;
@@ -1526,6 +1530,278 @@ pub fn generate_migration_tag_with_mode(
     }
 }
 
+// =============================================================================
+// Tag Format - configurable folder name templates
+// =============================================================================
+
+/// Error returned when a migration tag template cannot be used.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid migration tag format `{format}`: {reason}")]
+pub struct InvalidTagFormat {
+    format: String,
+    reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TagPart {
+    Literal(String),
+    Index,
+    Timestamp,
+    Unix,
+    Millis,
+    Name,
+}
+
+/// Template for migration folder names, e.g. `"{index}_{name}"`.
+///
+/// Placeholders:
+/// - `{index}`: next migration number, zero-padded to four digits
+/// - `{timestamp}`: UTC `YYYYMMDDHHMMSS`
+/// - `{unix}`: Unix seconds
+/// - `{millis}`: Unix milliseconds (Supabase style)
+/// - `{name}`: the `--name` value, or a random `adjective_hero` suffix
+///
+/// `{name}` is required. `{index}` may appear once, preceded only by literal
+/// text, so it can be read back from existing folders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagFormat {
+    parts: Vec<TagPart>,
+}
+
+/// Existing migration folders that share one index, typically after merging
+/// branches that each generated a migration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexCollision {
+    /// The shared index
+    pub index: u32,
+    /// Colliding folder names, sorted
+    pub tags: Vec<String>,
+}
+
+impl TagFormat {
+    /// Parse a tag template.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidTagFormat`] for unknown or unterminated placeholders,
+    /// a missing `{name}`, an `{index}` that can't be read back, or literal
+    /// text that isn't safe in a folder name.
+    pub fn parse(format: &str) -> Result<Self, InvalidTagFormat> {
+        let invalid = |reason: &str| InvalidTagFormat {
+            format: format.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let mut parts = Vec::new();
+        let mut rest = format;
+        while !rest.is_empty() {
+            let Some(open) = rest.find('{') else {
+                parts.push(TagPart::Literal(rest.to_string()));
+                break;
+            };
+            if open > 0 {
+                parts.push(TagPart::Literal(rest[..open].to_string()));
+            }
+            let Some(close) = rest[open..].find('}') else {
+                return Err(invalid("unterminated placeholder"));
+            };
+            parts.push(match &rest[open + 1..open + close] {
+                "index" => TagPart::Index,
+                "timestamp" => TagPart::Timestamp,
+                "unix" => TagPart::Unix,
+                "millis" => TagPart::Millis,
+                "name" => TagPart::Name,
+                other => {
+                    return Err(invalid(&format!(
+                        "unknown placeholder `{{{other}}}` (expected index, timestamp, unix, millis or name)"
+                    )));
+                }
+            });
+            rest = &rest[open + close + 1..];
+        }
+
+        if !parts.contains(&TagPart::Name) {
+            return Err(invalid("`{name}` is required"));
+        }
+        let indexes: Vec<_> = parts
+            .iter()
+            .enumerate()
+            .filter(|(_, part)| **part == TagPart::Index)
+            .map(|(i, _)| i)
+            .collect();
+        if let [at, ..] = indexes[..] {
+            if indexes.len() > 1 {
+                return Err(invalid("`{index}` may only appear once"));
+            }
+            if !parts[..at]
+                .iter()
+                .all(|part| matches!(part, TagPart::Literal(_)))
+            {
+                return Err(invalid("only literal text may precede `{index}`"));
+            }
+            match parts.get(at + 1) {
+                None => {}
+                Some(TagPart::Literal(lit)) if !lit.starts_with(|c: char| c.is_ascii_digit()) => {}
+                Some(_) => return Err(invalid("`{index}` must be followed by a separator")),
+            }
+        }
+
+        let tag_format = Self { parts };
+        validate_migration_name(&tag_format.render_at(0, "name", 0))
+            .map_err(|_| invalid("literal text is not safe in a folder name"))?;
+        Ok(tag_format)
+    }
+
+    /// Render a tag for migration number `idx`.
+    ///
+    /// `custom_name` fills `{name}`; without it a random suffix is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidMigrationName`] if `custom_name` (or the resulting
+    /// tag) is not one safe filesystem component.
+    pub fn render(
+        &self,
+        idx: u32,
+        custom_name: Option<&str>,
+    ) -> Result<String, InvalidMigrationName> {
+        let name = custom_name.map_or_else(generate_random_suffix, str::to_string);
+        validate_migration_name(&name)?;
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let millis = u64::try_from(now.as_millis()).unwrap_or(u64::MAX);
+        let tag = self.render_at(idx, &name, millis);
+        validate_migration_name(&tag)?;
+        Ok(tag)
+    }
+
+    fn render_at(&self, idx: u32, name: &str, millis: u64) -> String {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                TagPart::Literal(lit) => lit.clone(),
+                TagPart::Index => format!("{idx:04}"),
+                TagPart::Timestamp => timestamp_prefix_from_secs(millis / 1000),
+                TagPart::Unix => (millis / 1000).to_string(),
+                TagPart::Millis => millis.to_string(),
+                TagPart::Name => name.to_string(),
+            })
+            .collect()
+    }
+
+    /// Does this format number migrations with `{index}`?
+    #[must_use]
+    pub fn has_index(&self) -> bool {
+        self.parts.contains(&TagPart::Index)
+    }
+
+    /// Split an existing tag into the text before its index, the index
+    /// digits and the rest. `None` if the tag doesn't follow this format.
+    fn split_index<'t>(&self, tag: &'t str) -> Option<(&'t str, &'t str, &'t str)> {
+        let at = self.parts.iter().position(|part| *part == TagPart::Index)?;
+        let lead: String = self.parts[..at]
+            .iter()
+            .filter_map(|part| match part {
+                TagPart::Literal(lit) => Some(lit.as_str()),
+                _ => None,
+            })
+            .collect();
+        let rest = tag.strip_prefix(lead.as_str())?;
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 || digits > 10 {
+            return None;
+        }
+        let (number, tail) = rest.split_at(digits);
+        if let Some(TagPart::Literal(sep)) = self.parts.get(at + 1)
+            && !tail.starts_with(sep.as_str())
+        {
+            return None;
+        }
+        Some((&tag[..lead.len()], number, tail))
+    }
+
+    /// Read the `{index}` back out of an existing tag.
+    #[must_use]
+    pub fn index_of(&self, tag: &str) -> Option<u32> {
+        self.split_index(tag)?.1.parse().ok()
+    }
+
+    /// Replace the `{index}` of an existing tag, keeping the rest of it.
+    #[must_use]
+    pub fn with_index(&self, tag: &str, idx: u32) -> Option<String> {
+        let (lead, _, tail) = self.split_index(tag)?;
+        Some(format!("{lead}{idx:04}{tail}"))
+    }
+
+    /// The index for a new migration after `tags`: one past the highest
+    /// index found, or the number of existing folders if none carry one.
+    pub fn next_index<'t>(&self, tags: impl IntoIterator<Item = &'t str>) -> u32 {
+        let mut count = 0usize;
+        let mut max_index: Option<u32> = None;
+        for tag in tags {
+            count += 1;
+            if let Some(idx) = self.index_of(tag) {
+                max_index = Some(max_index.map_or(idx, |curr| curr.max(idx)));
+            }
+        }
+        max_index.map_or_else(
+            || u32::try_from(count).unwrap_or(u32::MAX),
+            |idx| idx.saturating_add(1),
+        )
+    }
+
+    /// Group existing tags that share an index, in index order.
+    pub fn collisions<'t>(&self, tags: impl IntoIterator<Item = &'t str>) -> Vec<IndexCollision> {
+        let mut by_index = std::collections::BTreeMap::<u32, Vec<String>>::new();
+        for tag in tags {
+            if let Some(idx) = self.index_of(tag) {
+                by_index.entry(idx).or_default().push(tag.to_string());
+            }
+        }
+        by_index
+            .into_iter()
+            .filter(|(_, tags)| tags.len() > 1)
+            .map(|(index, mut tags)| {
+                tags.sort();
+                IndexCollision { index, tags }
+            })
+            .collect()
+    }
+}
+
+impl From<PrefixMode> for TagFormat {
+    fn from(mode: PrefixMode) -> Self {
+        let prefix = match mode {
+            PrefixMode::Timestamp => Some(TagPart::Timestamp),
+            PrefixMode::Index => Some(TagPart::Index),
+            PrefixMode::Supabase => Some(TagPart::Millis),
+            PrefixMode::Unix => Some(TagPart::Unix),
+            PrefixMode::None => None,
+        };
+        let parts = match prefix {
+            Some(prefix) => vec![prefix, TagPart::Literal("_".to_string()), TagPart::Name],
+            None => vec![TagPart::Name],
+        };
+        Self { parts }
+    }
+}
+
+impl Default for TagFormat {
+    fn default() -> Self {
+        PrefixMode::default().into()
+    }
+}
+
+impl FromStr for TagFormat {
+    type Err = InvalidTagFormat;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1629,4 +1905,95 @@ mod tests {
             assert!(validate_migration_name(name).is_err(), "{name:?}");
         }
     }
+
+    #[test]
+    fn tag_format_renders_placeholders() {
+        let format = TagFormat::parse("{index}_{name}").unwrap();
+        assert_eq!(
+            format.render(7, Some("add_users")).unwrap(),
+            "0007_add_users"
+        );
+
+        let format = TagFormat::parse("v{index}-{name}").unwrap();
+        assert_eq!(format.render(12, Some("init")).unwrap(), "v0012-init");
+
+        let tag = TagFormat::parse("{timestamp}_{name}")
+            .unwrap()
+            .render(0, Some("init"))
+            .unwrap();
+        assert_eq!(tag.len(), "YYYYMMDDHHMMSS_init".len());
+        assert!(tag.ends_with("_init"));
+
+        assert_eq!(
+            TagFormat::from(PrefixMode::None)
+                .render(3, Some("plain"))
+                .unwrap(),
+            "plain"
+        );
+    }
+
+    #[test]
+    fn tag_format_rejects_bad_templates() {
+        for format in [
+            "{index}",
+            "{index}_{nme}",
+            "{index_{name}",
+            "{index}{timestamp}_{name}",
+            "{timestamp}_{index}_{name}",
+            "{index}_{index}_{name}",
+            "../{name}",
+            "a/{name}",
+        ] {
+            assert!(TagFormat::parse(format).is_err(), "{format:?}");
+        }
+    }
+
+    #[test]
+    fn tag_format_rejects_unsafe_names() {
+        let format = TagFormat::parse("{index}_{name}").unwrap();
+        assert!(format.render(0, Some("../escape")).is_err());
+        assert!(format.render(0, Some("a/b")).is_err());
+    }
+
+    #[test]
+    fn tag_format_reads_indexes_back() {
+        let format = TagFormat::parse("v{index}-{name}").unwrap();
+        assert_eq!(format.index_of("v0003-init"), Some(3));
+        assert_eq!(format.index_of("0003-init"), None);
+        assert_eq!(format.index_of("v0003_init"), None);
+        assert_eq!(
+            format.with_index("v0003-init", 9).as_deref(),
+            Some("v0009-init")
+        );
+
+        let timestamp = TagFormat::default();
+        assert!(!timestamp.has_index());
+        assert_eq!(timestamp.index_of("20240101000000_init"), None);
+    }
+
+    #[test]
+    fn tag_format_next_index_follows_highest_index() {
+        let format = TagFormat::from(PrefixMode::Index);
+        assert_eq!(format.next_index([]), 0);
+        assert_eq!(format.next_index(["0000_a", "0004_b", "0002_c"]), 5);
+        // Folders from another naming scheme count towards the index.
+        assert_eq!(
+            format.next_index(["20240101000000_a", "20240102000000_b"]),
+            2
+        );
+    }
+
+    #[test]
+    fn tag_format_detects_parallel_branch_collisions() {
+        let format = TagFormat::from(PrefixMode::Index);
+        let collisions = format.collisions(["0000_init", "0001_users", "0001_posts", "0002_tags"]);
+        assert_eq!(
+            collisions,
+            vec![IndexCollision {
+                index: 1,
+                tags: vec!["0001_posts".to_string(), "0001_users".to_string()],
+            }]
+        );
+        assert!(format.collisions(["0000_init", "0001_users"]).is_empty());
+    }
 }
@@ -11,6 +11,7 @@
 //!
 //! No journal file is used - migrations are discovered by scanning folders.
 
+use crate::format::format_sql;
 use crate::sqlite::statements::SqliteGenerator;
 use crate::sqlite::{SQLiteSnapshot, SchemaDiff as SqliteSchemaDiff};
 use crate::version::ORIGIN_UUID;
@@ -99,6 +100,8 @@ pub struct Writer {
     prefix_mode: PrefixMode,
     /// Optional custom name for migrations
     custom_name: Option<String>,
+    /// Pretty-print generated SQL
+    format_sql: bool,
 }
 
 impl Writer {
@@ -110,6 +113,7 @@ impl Writer {
             breakpoints: true,
             prefix_mode: PrefixMode::Timestamp, // V3 default
             custom_name: None,
+            format_sql: false,
         }
     }
 
@@ -120,6 +124,13 @@ impl Writer {
         self
     }
 
+    /// Set whether to pretty-print generated SQL (see [`crate::format`])
+    #[must_use]
+    pub const fn with_format_sql(mut self, enabled: bool) -> Self {
+        self.format_sql = enabled;
+        self
+    }
+
     /// Set the prefix mode for migration tags
     #[must_use]
     pub const fn with_prefix_mode(mut self, mode: PrefixMode) -> Self {
@@ -258,12 +269,16 @@ impl Writer {
 
         // Generate SQL
         let generator = SqliteGenerator::new().with_breakpoints(self.breakpoints);
-        let statements = generator.generate_migration(diff);
+        let mut statements = generator.generate_migration(diff);
 
         if statements.is_empty() {
             return Err(MigrationError::NoChanges);
         }
 
+        if self.format_sql {
+            statements = statements.iter().map(|s| format_sql(s)).collect();
+        }
+
         let sql = generator.statements_to_sql(&statements);
 
         // Create snapshot with proper chain
@@ -385,6 +400,12 @@ pub enum MigrationError {
 
     #[error("Dialect mismatch: cannot diff snapshots from different dialects")]
     DialectMismatch,
+
+    #[error(
+        "Refusing destructive schema changes (allow them with SyncOptions::allow_destructive): {}",
+        .0.join("; ")
+    )]
+    Destructive(Vec<String>),
 }
 
 #[cfg(test)]
@@ -0,0 +1,98 @@
+//! drizzle-kit file format conformance tests
+//!
+//! The fixtures under `tests/fixtures/drizzle-kit` are laid out the way
+//! drizzle-kit writes them (`JSON.stringify(value, null, 2)`). Snapshots and
+//! journals must round-trip through drizzle-rs byte for byte so a project can
+//! share one migrations folder between the TypeScript and Rust toolchains.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use drizzle_migrations::postgres::PostgresSnapshot;
+use drizzle_migrations::sqlite::SQLiteSnapshot;
+use drizzle_migrations::{Journal, MigrationDir, import_legacy_folder};
+use drizzle_types::Dialect;
+
+fn fixture(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/drizzle-kit")
+        .join(path)
+}
+
+fn read_fixture(path: &str) -> String {
+    fs::read_to_string(fixture(path)).expect("read fixture")
+}
+
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let target = to.join(entry.file_name());
+        if entry.file_type().unwrap().is_dir() {
+            copy_dir(&entry.path(), &target);
+        } else {
+            fs::copy(entry.path(), target).unwrap();
+        }
+    }
+}
+
+#[test]
+fn sqlite_snapshot_round_trips_byte_for_byte() {
+    let json = read_fixture("sqlite/snapshot.json");
+    let snapshot = SQLiteSnapshot::from_json(&json).unwrap();
+
+    assert_eq!(snapshot.to_json().unwrap(), json);
+}
+
+#[test]
+fn postgres_snapshot_round_trips_byte_for_byte() {
+    let json = read_fixture("postgres/snapshot.json");
+    let snapshot = PostgresSnapshot::from_json(&json).unwrap();
+
+    assert_eq!(snapshot.to_json().unwrap(), json);
+}
+
+#[test]
+fn journal_round_trips_byte_for_byte() {
+    let json = read_fixture("legacy/meta/_journal.json");
+    let journal = Journal::from_json(&json).unwrap();
+
+    assert_eq!(journal.dialect, Dialect::SQLite);
+    assert_eq!(journal.to_json().unwrap(), json);
+}
+
+#[test]
+fn legacy_folder_imports_to_v3_layout() {
+    let dir = tempfile::tempdir().unwrap();
+    copy_dir(&fixture("legacy"), dir.path());
+
+    let tags = import_legacy_folder(dir.path(), Dialect::SQLite).unwrap();
+
+    assert_eq!(
+        tags,
+        vec![
+            "20231114221320_silent_wolverine",
+            "20231114231320_brave_storm"
+        ]
+    );
+    assert!(!dir.path().join("meta").exists());
+    assert!(!dir.path().join("0000_silent_wolverine.sql").exists());
+    assert_eq!(
+        fs::read_to_string(dir.path().join(&tags[1]).join("migration.sql")).unwrap(),
+        read_fixture("legacy/0001_brave_storm.sql")
+    );
+    assert!(!dir.path().join(&tags[0]).join("snapshot.json").exists());
+    SQLiteSnapshot::load(&dir.path().join(&tags[1]).join("snapshot.json")).unwrap();
+
+    let migrations = MigrationDir::new(dir.path()).discover().unwrap();
+    assert_eq!(migrations.len(), 2);
+    assert_eq!(migrations[0].tag(), tags[0]);
+    assert_eq!(migrations[1].statements().len(), 2);
+
+    // Re-running is a no-op once the journal is gone.
+    assert!(
+        import_legacy_folder(dir.path(), Dialect::SQLite)
+            .unwrap()
+            .is_empty()
+    );
+}
@@ -0,0 +1,2 @@
+ALTER TABLE `users` ADD `name` text;--> statement-breakpoint
+CREATE INDEX `users_name_idx` ON `users` (`name`);
\ No newline at end of file
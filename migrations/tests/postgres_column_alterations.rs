@@ -137,6 +137,7 @@ fn index(table_name: &str, name: &str, columns: Vec<&str>) -> Index {
                 asc: true,
                 nulls_first: false,
                 opclass: None,
+                collate: None,
             })
             .collect(),
         method: None,
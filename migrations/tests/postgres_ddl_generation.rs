@@ -8,8 +8,9 @@ use drizzle_migrations::postgres::{
     PostgresDDL,
     collection::diff_ddl,
     ddl::{
-        Column, Enum, ForeignKey, Generated, GeneratedType, Index, IndexColumn, Opclass, Policy,
-        PrimaryKey, Table, UniqueConstraint,
+        Column, Composite, CompositeField, Domain, Enum, ExcludeConstraint, ExcludeElement,
+        ForeignKey, Generated, GeneratedType, Identity, Index, IndexColumn, Opclass, Policy,
+        PrimaryKey, Table, UniqueConstraint, View,
     },
     statements::PostgresGenerator,
 };
@@ -129,6 +130,7 @@ fn index(table_name: &str, name: &str, columns: Vec<&str>) -> Index {
                 asc: true,
                 nulls_first: false,
                 opclass: None,
+                collate: None,
             })
             .collect(),
         method: None,
@@ -473,6 +475,98 @@ fn test_create_table_with_deferrable_fk_and_composite_unique() {
     ));
 }
 
+fn bookings_exclude() -> ExcludeConstraint {
+    ExcludeConstraint::new(
+        "public",
+        "bookings",
+        "bookings_room_id_during_excl",
+        vec![
+            ExcludeElement::column("room_id", "="),
+            ExcludeElement::column("during", "&&"),
+        ],
+    )
+}
+
+fn bookings_table(ddl: &mut PostgresDDL) {
+    ddl.tables.push(table("bookings"));
+    ddl.columns
+        .push(column_not_null("bookings", "room_id", "integer"));
+    ddl.columns
+        .push(column_not_null("bookings", "during", "tstzrange"));
+}
+
+#[test]
+fn test_create_table_with_exclude_constraint() {
+    let from = PostgresDDL::new();
+    let mut to = PostgresDDL::new();
+    bookings_table(&mut to);
+    let mut exclude = bookings_exclude();
+    exclude.where_clause = Some(Cow::Borrowed("room_id > 0"));
+    to.excludes.push(exclude);
+
+    let sql = diff_to_sql(&from, &to);
+
+    assert_eq!(sql.len(), 1);
+    assert!(
+        sql[0].contains(
+            "CONSTRAINT \"bookings_room_id_during_excl\" EXCLUDE USING gist (\"room_id\" WITH =, \"during\" WITH &&) WHERE (room_id > 0)"
+        ),
+        "Unexpected CREATE TABLE with exclusion constraint SQL: {}",
+        sql[0]
+    );
+}
+
+#[test]
+fn test_add_and_drop_exclude_constraint() {
+    let mut from = PostgresDDL::new();
+    bookings_table(&mut from);
+    let mut to = from.clone();
+    to.excludes.push(bookings_exclude());
+
+    assert_eq!(
+        diff_to_sql(&from, &to),
+        vec![
+            "ALTER TABLE \"bookings\" ADD CONSTRAINT \"bookings_room_id_during_excl\" EXCLUDE USING gist (\"room_id\" WITH =, \"during\" WITH &&);"
+        ]
+    );
+    assert_eq!(
+        diff_to_sql(&to, &from),
+        vec!["ALTER TABLE \"bookings\" DROP CONSTRAINT \"bookings_room_id_during_excl\";"]
+    );
+}
+
+#[test]
+fn test_changed_exclude_constraint_is_recreated() {
+    let mut from = PostgresDDL::new();
+    bookings_table(&mut from);
+    let mut to = from.clone();
+    from.excludes.push(bookings_exclude());
+    let mut deferred = bookings_exclude();
+    deferred.deferrable = true;
+    to.excludes.push(deferred);
+
+    assert_eq!(
+        diff_to_sql(&from, &to),
+        vec![
+            "ALTER TABLE \"bookings\" DROP CONSTRAINT \"bookings_room_id_during_excl\";\nALTER TABLE \"bookings\" ADD CONSTRAINT \"bookings_room_id_during_excl\" EXCLUDE USING gist (\"room_id\" WITH =, \"during\" WITH &&) DEFERRABLE;"
+        ]
+    );
+}
+
+#[test]
+fn test_introspected_exclude_constraint_has_no_diff() {
+    let mut from = PostgresDDL::new();
+    bookings_table(&mut from);
+    let mut to = from.clone();
+    let mut introspected = bookings_exclude();
+    introspected.name_explicit = true;
+    introspected.using = Cow::Borrowed("GIST");
+    from.excludes.push(introspected);
+    to.excludes.push(bookings_exclude());
+
+    assert!(diff_to_sql(&from, &to).is_empty());
+}
+
 #[test]
 fn test_create_table_with_default() {
     let from = PostgresDDL::new();
@@ -749,6 +843,132 @@ fn test_drop_enum() {
     assert_eq!(sql[0], "DROP TYPE \"status\";", "Unexpected DROP TYPE SQL");
 }
 
+// =============================================================================
+// DOMAIN Tests
+// =============================================================================
+
+fn email_domain(check: &'static str) -> Domain {
+    let mut domain = Domain::new("public", "email", "text");
+    domain.check = Some(Cow::Borrowed(check));
+    domain
+}
+
+#[test]
+fn test_create_and_drop_domain() {
+    let from = PostgresDDL::new();
+    let mut to = PostgresDDL::new();
+    to.domains.push(email_domain("VALUE ~ '@'"));
+
+    let sql = diff_to_sql(&from, &to);
+    assert_eq!(
+        sql,
+        ["CREATE DOMAIN \"email\" AS text CONSTRAINT \"email_check\" CHECK (VALUE ~ '@');"]
+    );
+
+    let sql = diff_to_sql(&to, &from);
+    assert_eq!(sql, ["DROP DOMAIN \"email\";"]);
+}
+
+#[test]
+fn test_alter_domain_constraints() {
+    let mut from = PostgresDDL::new();
+    from.domains.push(email_domain("VALUE ~ '@'"));
+    let mut to = PostgresDDL::new();
+    let mut domain = email_domain("VALUE ~ '^[^@]+@[^@]+$'");
+    domain.not_null = true;
+    to.domains.push(domain);
+
+    let sql = diff_to_sql(&from, &to);
+    assert_eq!(
+        sql,
+        [
+            "ALTER DOMAIN \"email\" SET NOT NULL;\nALTER DOMAIN \"email\" DROP CONSTRAINT \"email_check\";\nALTER DOMAIN \"email\" ADD CONSTRAINT \"email_check\" CHECK (VALUE ~ '^[^@]+@[^@]+$');"
+        ]
+    );
+}
+
+#[test]
+fn test_domain_base_type_aliases_diff_clean() {
+    let mut from = PostgresDDL::new();
+    from.domains
+        .push(Domain::new("public", "short_code", "varchar(8)"));
+    let mut to = PostgresDDL::new();
+    to.domains
+        .push(Domain::new("public", "short_code", "character varying(8)"));
+
+    assert!(diff_to_sql(&from, &to).is_empty());
+}
+
+// =============================================================================
+// COMPOSITE TYPE Tests
+// =============================================================================
+
+fn address_type(fields: &[(&'static str, &'static str)]) -> Composite {
+    Composite::new(
+        "public",
+        "address_type",
+        fields
+            .iter()
+            .map(|(name, sql_type)| CompositeField::new(*name, *sql_type))
+            .collect(),
+    )
+}
+
+#[test]
+fn test_create_and_drop_composite() {
+    let from = PostgresDDL::new();
+    let mut to = PostgresDDL::new();
+    to.composites
+        .push(address_type(&[("street", "text"), ("zip", "integer")]));
+
+    let sql = diff_to_sql(&from, &to);
+    assert_eq!(
+        sql,
+        ["CREATE TYPE \"address_type\" AS (\"street\" text, \"zip\" integer);"]
+    );
+
+    let sql = diff_to_sql(&to, &from);
+    assert_eq!(sql, ["DROP TYPE \"address_type\";"]);
+}
+
+#[test]
+fn test_alter_composite_attributes() {
+    let mut from = PostgresDDL::new();
+    from.composites.push(address_type(&[
+        ("street", "text"),
+        ("zip", "integer"),
+        ("country", "text"),
+    ]));
+    let mut to = PostgresDDL::new();
+    to.composites.push(address_type(&[
+        ("street", "varchar(200)"),
+        ("zip", "int4"),
+        ("city", "text"),
+    ]));
+
+    let sql = diff_to_sql(&from, &to);
+    assert_eq!(
+        sql,
+        [concat!(
+            "ALTER TYPE \"address_type\" DROP ATTRIBUTE \"country\";\n",
+            "ALTER TYPE \"address_type\" ALTER ATTRIBUTE \"street\" TYPE varchar(200);\n",
+            "ALTER TYPE \"address_type\" ADD ATTRIBUTE \"city\" text;"
+        )]
+    );
+}
+
+#[test]
+fn test_composite_type_aliases_diff_clean() {
+    let mut from = PostgresDDL::new();
+    from.composites
+        .push(address_type(&[("street", "TEXT"), ("zip", "INTEGER")]));
+    let mut to = PostgresDDL::new();
+    to.composites
+        .push(address_type(&[("street", "text"), ("zip", "int4")]));
+
+    assert!(diff_to_sql(&from, &to).is_empty());
+}
+
 // =============================================================================
 // Column Types Tests
 // =============================================================================
@@ -1061,6 +1281,79 @@ fn test_add_virtual_generated_column_expression() {
     );
 }
 
+// =============================================================================
+// Identity Column Tests
+// =============================================================================
+
+fn identity_column(identity: Identity) -> Column {
+    Column {
+        identity: Some(identity),
+        ..column_not_null("users", "id", "integer")
+    }
+}
+
+#[test]
+fn test_create_table_with_identity_column() {
+    let from = PostgresDDL::new();
+    let mut to = PostgresDDL::new();
+    to.tables.push(table("users"));
+    to.columns
+        .push(identity_column(Identity::always("users_id_seq")));
+
+    let sql = diff_to_sql(&from, &to);
+
+    assert_eq!(sql.len(), 1, "Expected 1 SQL statement, got: {:?}", sql);
+    assert!(
+        sql[0].contains("\"id\" integer GENERATED ALWAYS AS IDENTITY"),
+        "Unexpected identity column SQL: {}",
+        sql[0]
+    );
+}
+
+#[test]
+fn test_serial_to_identity_swaps_sequence() {
+    let mut from = PostgresDDL::new();
+    from.tables.push(table("users"));
+    from.columns.push(column_not_null("users", "id", "serial"));
+    let mut to = PostgresDDL::new();
+    to.tables.push(table("users"));
+    to.columns
+        .push(identity_column(Identity::by_default("users_id_seq")));
+
+    let sql = diff_to_sql(&from, &to);
+
+    assert_eq!(sql.len(), 1, "Expected 1 SQL statement, got: {:?}", sql);
+    assert_eq!(
+        sql[0],
+        "ALTER TABLE \"users\" ALTER COLUMN \"id\" DROP DEFAULT;\n\
+         DROP SEQUENCE IF EXISTS \"users_id_seq\";\n\
+         ALTER TABLE \"users\" ALTER COLUMN \"id\" ADD GENERATED BY DEFAULT AS IDENTITY;\n\
+         SELECT setval(pg_get_serial_sequence('\"users\"', 'id'), coalesce(max(\"id\"), 0) + 1, false) FROM \"users\";"
+    );
+}
+
+#[test]
+fn test_identity_to_serial_restores_sequence() {
+    let mut from = PostgresDDL::new();
+    from.tables.push(table("users"));
+    from.columns
+        .push(identity_column(Identity::always("users_id_seq")));
+    let mut to = PostgresDDL::new();
+    to.tables.push(table("users"));
+    to.columns.push(column_not_null("users", "id", "serial"));
+
+    let sql = diff_to_sql(&from, &to);
+
+    assert_eq!(sql.len(), 1, "Expected 1 SQL statement, got: {:?}", sql);
+    assert_eq!(
+        sql[0],
+        "ALTER TABLE \"users\" ALTER COLUMN \"id\" DROP IDENTITY;\n\
+         CREATE SEQUENCE \"users_id_seq\" AS integer OWNED BY \"users\".\"id\";\n\
+         ALTER TABLE \"users\" ALTER COLUMN \"id\" SET DEFAULT nextval('\"users_id_seq\"');\n\
+         SELECT setval(pg_get_serial_sequence('\"users\"', 'id'), coalesce(max(\"id\"), 0) + 1, false) FROM \"users\";"
+    );
+}
+
 #[test]
 fn test_drop_policy_sql_is_well_formed() {
     let mut from = PostgresDDL::new();
@@ -1172,6 +1465,36 @@ fn test_first_migration_emits_index_rls_and_policy_after_table() {
     );
 }
 
+#[test]
+fn test_index_on_new_materialized_view_follows_the_view() {
+    let from = PostgresDDL::new();
+    let mut to = PostgresDDL::new();
+
+    to.tables.push(table("users"));
+    to.columns.push(column_not_null("users", "id", "integer"));
+    let mut view = View::new("public", "user_stats");
+    view.definition = Some(Cow::Borrowed("SELECT id FROM users"));
+    view.materialized = true;
+    view.with_no_data = Some(true);
+    to.views.push(view);
+    to.indexes
+        .push(unique_index("user_stats", "user_stats_id_idx", vec!["id"]));
+
+    let sql = diff_to_sql(&from, &to);
+    assert_eq!(sql.len(), 3, "expected table, view, index: {sql:?}");
+    assert!(sql[0].starts_with("CREATE TABLE \"users\""));
+    assert!(
+        sql[1].starts_with("CREATE MATERIALIZED VIEW \"user_stats\"")
+            && sql[1].contains("WITH NO DATA"),
+        "unexpected view SQL: {}",
+        sql[1]
+    );
+    assert_eq!(
+        sql[2],
+        "CREATE UNIQUE INDEX \"user_stats_id_idx\" ON \"user_stats\"(\"id\");"
+    );
+}
+
 #[test]
 fn test_table_unlogged_toggle_generates_alter_table() {
     let mut from = PostgresDDL::new();
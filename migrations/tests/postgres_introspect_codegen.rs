@@ -13,8 +13,9 @@ use drizzle_migrations::{
         },
         collection::diff_ddl,
         ddl::{
-            CheckConstraint, Column, Enum, ForeignKey, Generated, GeneratedType, Identity,
-            IdentityType, Index, IndexColumn, Policy, PrimaryKey, Table, UniqueConstraint,
+            CheckConstraint, Column, Composite, CompositeField, Domain, Enum, ForeignKey,
+            Generated, GeneratedType, Identity, IdentityType, Index, IndexColumn, Policy,
+            PrimaryKey, Table, UniqueConstraint,
         },
         introspect::{
             RawColumnInfo, RawForeignKeyInfo, RawIndexColumnInfo, RawIndexInfo, RawPrimaryKeyInfo,
@@ -359,6 +360,7 @@ fn test_postgres_codegen_new_macro_surfaces() {
             None,
         ),
         ("display_name", "text", false, None, None, Some("C")),
+        ("device_mac", "macaddr", false, None, None, None),
     ] {
         ddl.columns.push(Column {
             schema: "public".into(),
@@ -458,6 +460,8 @@ fn test_postgres_codegen_new_macro_surfaces() {
             .contains("generated(stored, \"length(email)\")")
     );
     assert!(generated.code.contains("collate = \"C\""));
+    assert!(generated.code.contains("#[column(macaddr)]"));
+    assert!(generated.code.contains("device_mac: Option<[u8; 6]>"));
     assert!(generated.code.contains(
         "#[PostgresIndex(unique, concurrent, method = \"hash\", where = \"deleted_at IS NULL\")]"
     ));
@@ -810,6 +814,7 @@ fn test_process_indexes() {
                 asc: true,
                 nulls_first: false,
                 opclass: None,
+                collate: None,
             }],
             where_clause: None,
             concurrent: false,
@@ -828,6 +833,7 @@ fn test_process_indexes() {
                 asc: true,
                 nulls_first: false,
                 opclass: None,
+                collate: None,
             }],
             where_clause: None,
             concurrent: false,
@@ -1919,6 +1925,86 @@ fn test_enum_with_special_values() {
     );
 }
 
+#[test]
+fn test_domain_codegen() {
+    let mut ddl = PostgresDDL::new();
+
+    let mut email = Domain::new("public", "email", "text");
+    email.check = Some("(VALUE ~ '@'::text)".into());
+    ddl.domains.push(email);
+
+    ddl.tables.push(Table::new("public", "users"));
+    let mut column = Column::new("public", "users", "email", "email");
+    column.type_schema = Some("public".into());
+    column.not_null = true;
+    ddl.columns.push(column);
+
+    let generated = generate_rust_schema(
+        &ddl,
+        &CodegenOptions {
+            include_schema: true,
+            schema_name: "AppSchema".to_string(),
+            ..Default::default()
+        },
+    );
+
+    assert!(generated.code.contains(concat!(
+        "#[PostgresDomain(name = \"email\", base = \"text\", check = \"(VALUE ~ '@'::text)\")]\n",
+        "struct Email;\n",
+    )));
+    assert!(
+        generated
+            .code
+            .contains("#[column(domain = Email)]\n    email: String,")
+    );
+    assert!(generated.code.contains("    email: Email,"));
+    assert_eq!(generated.domains, vec!["email".to_string()]);
+}
+
+#[test]
+fn test_composite_codegen() {
+    let mut ddl = PostgresDDL::new();
+
+    ddl.composites.push(Composite::new(
+        "public",
+        "address_type",
+        vec![
+            CompositeField::new("street", "text"),
+            CompositeField::new("zip", "integer"),
+        ],
+    ));
+
+    ddl.tables.push(Table::new("public", "users"));
+    let mut column = Column::new("public", "users", "address", "address_type");
+    column.type_schema = Some("public".into());
+    ddl.columns.push(column);
+
+    let generated = generate_rust_schema(
+        &ddl,
+        &CodegenOptions {
+            include_schema: true,
+            schema_name: "AppSchema".to_string(),
+            ..Default::default()
+        },
+    );
+
+    assert!(generated.code.contains(concat!(
+        "#[PostgresComposite(name = \"address_type\")]\n",
+        "#[derive(Debug, Clone, PartialEq)]\n",
+        "struct AddressType {\n",
+        "    street: Option<String>,\n",
+        "    zip: Option<i32>,\n",
+        "}\n",
+    )));
+    assert!(generated.code.contains("    address: Option<AddressType>,"));
+    assert!(
+        generated
+            .code
+            .contains("    address_type: AddressTypeComposite,")
+    );
+    assert_eq!(generated.composites, vec!["address_type".to_string()]);
+}
+
 // =============================================================================
 // Materialized View Tests
 // =============================================================================
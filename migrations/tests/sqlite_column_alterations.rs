@@ -75,12 +75,23 @@ fn test_add_column_not_null() {
             .into_column(),
     );
 
-    let sql = diff_sql(&from, &to);
+    // SQLite rejects `ADD COLUMN ... NOT NULL` without a default, so the
+    // table is rebuilt instead.
+    let migration = compute_migration(&from, &to);
+    let sql = &migration.sql_statements;
 
-    assert_eq!(sql.len(), 1, "Expected 1 SQL statement, got: {:?}", sql);
+    assert_eq!(sql.len(), 6, "Expected 6 SQL statements, got: {:?}", sql);
     assert_eq!(
-        sql[0], "ALTER TABLE `users` ADD `name` TEXT NOT NULL;",
-        "Unexpected ALTER TABLE ADD SQL"
+        sql[2],
+        "INSERT INTO `__new_users`(`id`) SELECT `id` FROM `users`;"
+    );
+    assert!(
+        migration
+            .warnings
+            .iter()
+            .any(|w| w.contains("'name'") && w.contains("NOT NULL without a default")),
+        "Expected a NOT NULL warning, got: {:?}",
+        migration.warnings
     );
 }
 
@@ -172,23 +183,15 @@ fn test_add_columns_with_modifiers() {
 
     let sql = diff_sql(&from, &to);
 
-    assert_eq!(sql.len(), 3, "Expected 3 SQL statements, got: {:?}", sql);
-
-    let has_name1 = sql
-        .iter()
-        .any(|s| *s == "ALTER TABLE `users` ADD `name1` TEXT DEFAULT 'name';");
-    let has_name2 = sql
-        .iter()
-        .any(|s| *s == "ALTER TABLE `users` ADD `name2` TEXT NOT NULL;");
-    let has_name3 = sql
-        .iter()
-        .any(|s| *s == "ALTER TABLE `users` ADD `name3` TEXT DEFAULT 'name' NOT NULL;");
-
-    assert!(has_name1, "Should have name1 with DEFAULT, got: {:?}", sql);
-    assert!(has_name2, "Should have name2 with NOT NULL, got: {:?}", sql);
+    // `name2` cannot be added in place, so the whole table is rebuilt.
+    assert!(has_recreate_table_statement(&from, &to));
+    assert_eq!(
+        sql[1],
+        "CREATE TABLE `__new_users` (\n\t`id` INTEGER AUTOINCREMENT NOT NULL,\n\t`name1` TEXT DEFAULT 'name',\n\t`name2` TEXT NOT NULL,\n\t`name3` TEXT DEFAULT 'name' NOT NULL\n);"
+    );
     assert!(
-        has_name3,
-        "Should have name3 with DEFAULT and NOT NULL, got: {:?}",
+        !sql.iter().any(|s| s.contains(" ADD ")),
+        "Should not ADD columns to a rebuilt table, got: {:?}",
         sql
     );
 }
@@ -237,10 +240,10 @@ fn test_add_column_to_table_with_unique() {
 
     let sql = diff_sql(&from, &to);
 
-    assert_eq!(sql.len(), 1, "Expected 1 SQL statement, got: {:?}", sql);
+    assert_eq!(sql.len(), 6, "Expected 6 SQL statements, got: {:?}", sql);
     assert_eq!(
-        sql[0], "ALTER TABLE `users` ADD `password` TEXT NOT NULL;",
-        "Unexpected ALTER TABLE ADD SQL"
+        sql[2],
+        "INSERT INTO `__new_users`(`id`, `name`, `email`) SELECT `id`, `name`, `email` FROM `users`;"
     );
 }
 
@@ -448,7 +451,7 @@ fn test_alter_column_add_default_not_null() {
     );
     assert_eq!(
         sql[2],
-        "INSERT INTO `__new_table`(`name`) SELECT `name` FROM `table`;"
+        "INSERT INTO `__new_table`(`name`) SELECT coalesce(`name`, 'dan') FROM `table`;"
     );
     assert_eq!(sql[3], "DROP TABLE `table`;");
     assert_eq!(sql[4], "ALTER TABLE `__new_table` RENAME TO `table`;");
@@ -931,7 +934,7 @@ fn test_recreate_with_indexes() {
     );
     assert_eq!(
         sql[2],
-        "INSERT INTO `__new_table`(`name`) SELECT `name` FROM `table`;"
+        "INSERT INTO `__new_table`(`name`) SELECT coalesce(`name`, 'dan') FROM `table`;"
     );
     assert_eq!(sql[3], "DROP TABLE `table`;");
     assert_eq!(sql[4], "ALTER TABLE `__new_table` RENAME TO `table`;");
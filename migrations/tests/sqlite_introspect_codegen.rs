@@ -9,20 +9,14 @@ use drizzle_migrations::{
     sqlite::{
         SQLiteDDL,
         codegen::{CodegenOptions, GeneratedSchema, generate_rust_schema},
-        ddl::{
-            CheckConstraint, Column, Generated, GeneratedType, Table, UniqueConstraint,
-            parse_table_ddl,
-        },
-        introspect::{
-            IntrospectionResult, RawColumnInfo, RawForeignKey, RawIndexColumn, RawIndexInfo,
-            process_columns, process_foreign_keys, process_indexes,
-            process_unique_constraints_from_indexes,
-        },
+        ddl::{CheckConstraint, Column, Generated, GeneratedType, Table, UniqueConstraint},
+        introspect::IntrospectionResult,
+        introspector::{IntrospectValue, SqliteIntrospector, SqliteRowSource},
     },
 };
 use drizzle_types::Dialect;
 use rusqlite::Connection;
-use std::collections::{HashMap, HashSet};
+use rusqlite::types::ValueRef;
 
 /// SQL to create a comprehensive test schema with various types and constraints
 const CREATE_SCHEMA_SQL: &str = r#"
@@ -82,169 +76,52 @@ CREATE UNIQUE INDEX idx_users_email ON users(email);
 CREATE INDEX idx_categories_parent ON categories(parent_id);
 "#;
 
-/// Introspect a SQLite database and return the DDL
-fn introspect_database(conn: &Connection) -> IntrospectionResult {
-    let mut result = IntrospectionResult::default();
-
-    // Get tables
-    let mut stmt = conn
-        .prepare(
-            "SELECT name, sql FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'",
-        )
-        .unwrap();
-
-    let table_rows: Vec<(String, String)> = stmt
-        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
-        .unwrap()
-        .filter_map(|r| r.ok())
-        .collect();
-
-    let mut table_sql_map: HashMap<String, String> = HashMap::new();
-    for (name, sql) in &table_rows {
-        // Parse table options from CREATE TABLE SQL
-        let parsed = parse_table_ddl(sql);
-        let mut table = Table::new(name.clone());
-        if parsed.strict {
-            table = table.strict();
-        }
-        if parsed.without_rowid {
-            table = table.without_rowid();
-        }
-        result.tables.push(table);
-        table_sql_map.insert(name.clone(), sql.clone());
-    }
-
-    // Get columns for each table
-    let mut raw_columns: Vec<RawColumnInfo> = Vec::new();
-    for (table_name, sql) in &table_sql_map {
-        let mut col_stmt = conn.prepare(&format!(
-            "SELECT cid, name, type, \"notnull\", dflt_value, pk, hidden FROM pragma_table_xinfo('{}')",
-            table_name
-        )).unwrap();
-
-        let cols: Vec<RawColumnInfo> = col_stmt
-            .query_map([], |row| {
-                Ok(RawColumnInfo {
-                    table: table_name.clone(),
-                    cid: row.get(0)?,
-                    name: row.get(1)?,
-                    column_type: row.get(2)?,
-                    not_null: row.get::<_, i32>(3)? != 0,
-                    default_value: row.get(4)?,
-                    pk: row.get(5)?,
-                    hidden: row.get(6)?,
-                    sql: Some(sql.clone()),
-                })
-            })
-            .unwrap()
-            .filter_map(|r| r.ok())
-            .collect();
-
-        raw_columns.extend(cols);
-    }
-
-    // Process columns and primary keys
-    let generated_columns = HashMap::new();
-    let pk_columns_set: HashSet<(String, String)> = HashSet::new();
-    let (columns, primary_keys) =
-        process_columns(&raw_columns, &generated_columns, &pk_columns_set);
-    result.columns = columns;
-    result.primary_keys = primary_keys;
-
-    // Get indexes for each table
-    let mut raw_indexes: Vec<RawIndexInfo> = Vec::new();
-    let mut raw_index_columns: Vec<RawIndexColumn> = Vec::new();
-
-    for table_name in table_sql_map.keys() {
-        let mut idx_stmt = conn
-            .prepare(&format!(
-                "SELECT name, \"unique\", origin, partial FROM pragma_index_list('{}')",
-                table_name
-            ))
-            .unwrap();
-
-        let idxs: Vec<RawIndexInfo> = idx_stmt
-            .query_map([], |row| {
-                Ok(RawIndexInfo {
-                    table: table_name.clone(),
-                    name: row.get(0)?,
-                    unique: row.get::<_, i32>(1)? != 0,
-                    origin: row.get(2)?,
-                    partial: row.get::<_, i32>(3)? != 0,
-                })
-            })
-            .unwrap()
-            .filter_map(|r| r.ok())
-            .collect();
-
-        for idx in &idxs {
-            let mut ic_stmt = conn
-                .prepare(&format!(
-                    "SELECT seqno, cid, name, \"desc\", coll, key FROM pragma_index_xinfo('{}')",
-                    idx.name
-                ))
-                .unwrap();
-
-            let cols: Vec<RawIndexColumn> = ic_stmt
-                .query_map([], |row| {
-                    Ok(RawIndexColumn {
-                        index_name: idx.name.clone(),
-                        seqno: row.get(0)?,
-                        cid: row.get(1)?,
-                        name: row.get(2)?,
-                        desc: row.get::<_, i32>(3)? != 0,
-                        coll: row.get(4)?,
-                        key: row.get::<_, i32>(5)? != 0,
-                    })
-                })
-                .unwrap()
-                .filter_map(|r| r.ok())
-                .collect();
-
-            raw_index_columns.extend(cols);
+/// Feeds `SqliteIntrospector` from a rusqlite connection.
+struct TestSource<'a>(&'a Connection);
+
+impl SqliteRowSource for TestSource<'_> {
+    type Error = rusqlite::Error;
+
+    fn fetch_rows(&self, sql: &str) -> Result<Vec<Vec<IntrospectValue>>, Self::Error> {
+        let mut stmt = self.0.prepare(sql)?;
+        let column_count = stmt.column_count();
+        let mut rows = stmt.query([])?;
+        let mut out = Vec::new();
+        while let Some(row) = rows.next()? {
+            let mut values = Vec::with_capacity(column_count);
+            for index in 0..column_count {
+                values.push(match row.get_ref(index)? {
+                    ValueRef::Null => IntrospectValue::Null,
+                    ValueRef::Integer(value) => IntrospectValue::Integer(value),
+                    ValueRef::Real(value) => IntrospectValue::Real(value),
+                    ValueRef::Text(text) => {
+                        IntrospectValue::Text(String::from_utf8_lossy(text).into_owned())
+                    }
+                    ValueRef::Blob(blob) => IntrospectValue::Blob(blob.to_vec()),
+                });
+            }
+            out.push(values);
         }
-
-        raw_indexes.extend(idxs);
+        Ok(out)
     }
+}
 
-    result.indexes = process_indexes(&raw_indexes, &raw_index_columns, &table_sql_map);
-
-    // Get foreign keys for each table
-    let mut raw_fks: Vec<RawForeignKey> = Vec::new();
-    for table_name in table_sql_map.keys() {
-        let mut fk_stmt = conn.prepare(&format!(
-            "SELECT id, seq, \"table\", \"from\", \"to\", on_update, on_delete, match FROM pragma_foreign_key_list('{}')",
-            table_name
-        )).unwrap();
-
-        let fks: Vec<RawForeignKey> = fk_stmt
-            .query_map([], |row| {
-                Ok(RawForeignKey {
-                    table: table_name.clone(),
-                    id: row.get(0)?,
-                    seq: row.get(1)?,
-                    to_table: row.get(2)?,
-                    from_column: row.get(3)?,
-                    to_column: row.get(4)?,
-                    on_update: row.get(5)?,
-                    on_delete: row.get(6)?,
-                    r#match: row.get(7)?,
-                })
-            })
-            .unwrap()
-            .filter_map(|r| r.ok())
-            .collect();
-
-        raw_fks.extend(fks);
+/// Introspect a SQLite database and return the DDL
+fn introspect_database(conn: &Connection) -> IntrospectionResult {
+    let ddl = SqliteIntrospector::new(TestSource(conn))
+        .ddl()
+        .expect("introspect test database");
+
+    IntrospectionResult {
+        tables: ddl.tables.into_vec(),
+        columns: ddl.columns.into_vec(),
+        indexes: ddl.indexes.into_vec(),
+        foreign_keys: ddl.fks.into_vec(),
+        primary_keys: ddl.pks.into_vec(),
+        unique_constraints: ddl.uniques.into_vec(),
+        views: ddl.views.into_vec(),
+        errors: Vec::new(),
     }
-
-    result.foreign_keys = process_foreign_keys(&raw_fks);
-
-    // Unique constraints (origin == 'u' indexes, including inline column UNIQUE)
-    result.unique_constraints =
-        process_unique_constraints_from_indexes(&raw_indexes, &raw_index_columns);
-
-    result
 }
 
 #[test]
@@ -163,6 +163,8 @@ pub const UNIQUE: ColumnMarker = ColumnMarker;
 /// ## Syntax
 /// - `identity(always)` - User values rejected unless OVERRIDING SYSTEM VALUE
 /// - `identity(by_default)` - User values take precedence
+/// - `identity = always` / `identity = by_default` - Same as above
+/// - `identity` - Shorthand for `identity(always)`
 ///
 /// ## Example
 /// ```rust
@@ -261,6 +263,25 @@ pub const JSONB: ColumnMarker = ColumnMarker;
 /// See: <https://www.postgresql.org/docs/current/datatype-enum.html>
 pub const ENUM: ColumnMarker = ColumnMarker;
 
+/// Stores this column as a `PostgreSQL` domain declared with `#[PostgresDomain]`.
+///
+/// The field keeps its Rust type; the column is declared with the domain
+/// instead of the inferred type, so the domain's constraints apply.
+///
+/// ## Example
+/// ```rust
+/// # let _ = r####"
+/// #[PostgresDomain(base = text, check = "VALUE ~ '^[^@]+@[^@]+$'")]
+/// struct Email;
+///
+/// #[column(DOMAIN = Email)]
+/// email: String,
+/// # "####;
+/// ```
+///
+/// See: <https://www.postgresql.org/docs/current/domains.html>
+pub const DOMAIN: ColumnMarker = ColumnMarker;
+
 //------------------------------------------------------------------------------
 // Collation Markers
 //------------------------------------------------------------------------------
@@ -276,9 +297,16 @@ pub const ENUM: ColumnMarker = ColumnMarker;
 /// # let _ = r####"
 /// #[column(COLLATE = "en_US")]
 /// name: String,
+///
+/// // `collation` is accepted as an alias:
+/// #[column(collation = "und-x-icu")]
+/// email: String,
 /// # "####;
 /// ```
 ///
+/// Index members take the same name as a field attribute:
+/// `struct EmailIdx(#[collation = "und-x-icu"] Users::email);`
+///
 /// See: <https://www.postgresql.org/docs/current/collation.html>
 pub const COLLATE: ColumnMarker = ColumnMarker;
 
@@ -591,6 +619,32 @@ pub struct NameMarker;
 /// ```
 pub const NAME: NameMarker = NameMarker;
 
+//------------------------------------------------------------------------------
+// Comment Marker (shared by column and table attributes)
+//------------------------------------------------------------------------------
+
+/// Marker struct for the COMMENT attribute.
+#[derive(Debug, Clone, Copy)]
+pub struct CommentMarker;
+
+/// Attaches a comment to a table or column.
+///
+/// Doc comments (`///`) are used by default; `COMMENT` overrides them.
+/// Emitted as `COMMENT ON TABLE` / `COMMENT ON COLUMN` and read back by
+/// introspection.
+///
+/// ## Example
+/// ```rust
+/// # let _ = r####"
+/// #[PostgresTable(COMMENT = "Registered accounts")]
+/// struct Users {
+///     #[column(COMMENT = "Login e-mail, unique per account")]
+///     email: String,
+/// }
+/// # "####;
+/// ```
+pub const COMMENT: CommentMarker = CommentMarker;
+
 //------------------------------------------------------------------------------
 // View Attribute Markers
 //------------------------------------------------------------------------------
@@ -727,6 +781,30 @@ pub struct TableMarker;
 /// See: <https://www.postgresql.org/docs/current/ddl-constraints.html#DDL-CONSTRAINTS-FK>
 pub const FOREIGN_KEY: TableMarker = TableMarker;
 
+/// Adds a table-level EXCLUDE constraint.
+///
+/// Each `column WITH operator` pair is compared between rows; an insert or
+/// update fails when every pair matches an existing row.
+///
+/// ## Example
+/// ```rust
+/// # let _ = r####"
+/// #[PostgresTable(EXCLUDE(
+///     using = gist,
+///     columns(room_id WITH =, during WITH &&),
+///     where = "NOT cancelled"
+/// ))]
+/// struct Bookings {
+///     room_id: i32,
+///     during: String,
+///     cancelled: bool,
+/// }
+/// # "####;
+/// ```
+///
+/// See: <https://www.postgresql.org/docs/current/sql-createtable.html#SQL-CREATETABLE-EXCLUDE>
+pub const EXCLUDE: TableMarker = TableMarker;
+
 /// Creates an UNLOGGED table.
 ///
 /// ## Example
@@ -938,6 +1016,31 @@ pub const TABLESPACE: TableMarker = TableMarker;
 /// See: <https://www.postgresql.org/docs/current/ddl-rowsecurity.html>
 pub const RLS: TableMarker = TableMarker;
 
+/// Sets how the struct and field names map to table and column names:
+/// `"snake_case"` (the default) or `"camelCase"`, like drizzle-kit's
+/// `casing` option. Explicit `NAME` / `name` overrides still win.
+///
+/// Set the same `casing` in `drizzle.toml` so `drizzle generate` names
+/// tables without the attribute the same way.
+///
+/// ## Example
+/// ```rust
+/// # let _ = r####"
+/// // Table `userAccounts` with columns `id` and `displayName`
+/// #[PostgresTable(CASING = "camelCase")]
+/// struct UserAccounts {
+///     #[column(PRIMARY)]
+///     id: i32,
+///     display_name: String,
+/// }
+/// # "####;
+/// ```
+///
+/// References from other tables resolve the target column by its field
+/// name, so columns used as reference targets should keep single-word
+/// field names or an explicit `name`.
+pub const CASING: TableMarker = TableMarker;
+
 //------------------------------------------------------------------------------
 // Index Attribute Markers
 //------------------------------------------------------------------------------
@@ -1339,6 +1442,47 @@ pub const POLYGON: TypeMarker = TypeMarker;
 /// See: <https://www.postgresql.org/docs/current/datatype-geometric.html>
 pub const CIRCLE: TypeMarker = TypeMarker;
 
+/// Specifies a PostGIS GEOMETRY column type.
+///
+/// Takes an optional subtype (`point`, `linestring`, `polygon`, `multipoint`,
+/// `multilinestring`, `multipolygon`, `geometrycollection` or `geometry`) and
+/// an optional `srid`. Fields use the matching `geo_types` shape, or
+/// `geo_types::Geometry<f64>` for any subtype; values travel as WKB.
+/// Requires the `geo-types` feature and the `postgis` extension.
+///
+/// ## Example
+/// ```rust
+/// # let _ = r####"
+/// #[column(geometry(point, srid = 4326))]
+/// location: geo_types::Point<f64>,
+///
+/// #[column(geometry(multipolygon))]
+/// area: Option<geo_types::MultiPolygon<f64>>,
+/// # "####;
+/// ```
+///
+/// See: <https://postgis.net/docs/geometry.html>
+pub const GEOMETRY: TypeMarker = TypeMarker;
+
+//--- Vector Types ---
+
+/// Specifies a pgvector VECTOR column type.
+///
+/// Takes the embedding's dimension count. The field must be a `Vec<f32>`;
+/// values travel in pgvector's binary format. Requires the `vector`
+/// extension (`CREATE EXTENSION vector`).
+///
+/// ## Example
+/// ```rust
+/// # let _ = r####"
+/// #[column(vector(1536))]
+/// embedding: Vec<f32>,
+/// # "####;
+/// ```
+///
+/// See: <https://github.com/pgvector/pgvector>
+pub const VECTOR: TypeMarker = TypeMarker;
+
 //--- Bit String Types ---
 
 /// Specifies a BIT column type.
@@ -1270,12 +1270,48 @@ mod geo_types_impls {
     //! Geo-types leaf impls. The Data API returns `PostGIS` geometries as
     //! `StringValues` containing either the Postgres literal (e.g. `(x,y)` for
     //! POINT) or WKT. We parse the simple `(x,y)` / `[(x,y),...]` / `((x1,y1),(x2,y2))`
-    //! forms emitted by our own `encode_field`, falling back to an error for
-    //! anything else — users that need WKB / WKT can implement their own wrappers.
+    //! forms emitted by our own `encode_field`. PostGIS `geometry` columns are
+    //! returned as hex-encoded EWKB, which the point/path impls also accept and
+    //! the polygon / multi-geometry impls require.
     use super::{
         DrizzleError, FromDrizzleRow, Row, Vec, expect_string, field_at, field_is_null, format,
     };
-    use geo_types::{Coord, LineString, Point, Rect};
+    use geo_types::{
+        Coord, Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+        Rect,
+    };
+
+    /// Decodes hex-encoded (E)WKB, as PostGIS renders GEOMETRY values.
+    fn parse_hex_wkb(s: &str) -> Result<Geometry<f64>, DrizzleError> {
+        let hex = s.trim();
+        let bad = || {
+            DrizzleError::ConversionError(
+                format!("AWS Data API geometry: bad hex WKB {s:?}").into(),
+            )
+        };
+        if !hex.len().is_multiple_of(2) {
+            return Err(bad());
+        }
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(hex.get(i..i + 2).ok_or_else(bad)?, 16).map_err(|_| bad()))
+            .collect::<Result<Vec<u8>, DrizzleError>>()?;
+        drizzle_core::wkb::decode(&bytes).map(|(geometry, _srid)| geometry)
+    }
+
+    /// Returns the shape held by a PostGIS hex WKB string, if `s` is one.
+    fn geometry_shape<T: TryFrom<Geometry<f64>>>(s: &str) -> Option<Result<T, DrizzleError>> {
+        if s.trim_start().starts_with(['(', '[']) {
+            return None;
+        }
+        Some(parse_hex_wkb(s).and_then(|geometry| {
+            T::try_from(geometry).map_err(|_| {
+                DrizzleError::ConversionError(
+                    format!("AWS Data API geometry: unexpected shape in {s:?}").into(),
+                )
+            })
+        }))
+    }
 
     fn parse_xy(s: &str) -> Result<(f64, f64), DrizzleError> {
         let trimmed = s.trim_matches(|c: char| c == '(' || c == ')');
@@ -1299,6 +1335,9 @@ mod geo_types_impls {
         const COLUMN_COUNT: usize = 1;
         fn from_row_at(row: &Row, offset: usize) -> Result<Self, DrizzleError> {
             let s = expect_string(field_at(row, offset)?)?;
+            if let Some(point) = geometry_shape(s) {
+                return point;
+            }
             let (x, y) = parse_xy(s)?;
             Ok(Self::new(x, y))
         }
@@ -1319,6 +1358,9 @@ mod geo_types_impls {
         const COLUMN_COUNT: usize = 1;
         fn from_row_at(row: &Row, offset: usize) -> Result<Self, DrizzleError> {
             let s = expect_string(field_at(row, offset)?)?;
+            if let Some(line) = geometry_shape(s) {
+                return line;
+            }
             // Accept either `[(x,y),(x,y)]` or Postgres `(p1,p2,...)` style.
             let inner = s
                 .trim()
@@ -1393,6 +1435,44 @@ mod geo_types_impls {
             <Rect<f64> as FromDrizzleRow<Row>>::from_row_at(row, offset).map(Some)
         }
     }
+
+    macro_rules! impl_wkb_leaf {
+        ($($ty:ty),+ $(,)?) => {
+            $(
+                impl FromDrizzleRow<Row> for $ty {
+                    const COLUMN_COUNT: usize = 1;
+                    fn from_row_at(row: &Row, offset: usize) -> Result<Self, DrizzleError> {
+                        let s = expect_string(field_at(row, offset)?)?;
+                        geometry_shape(s).unwrap_or_else(|| {
+                            Err(DrizzleError::ConversionError(
+                                format!("AWS Data API geometry: expected hex WKB, got {s:?}")
+                                    .into(),
+                            ))
+                        })
+                    }
+                }
+
+                impl FromDrizzleRow<Row> for Option<$ty> {
+                    const COLUMN_COUNT: usize = 1;
+                    fn from_row_at(row: &Row, offset: usize) -> Result<Self, DrizzleError> {
+                        let field = field_at(row, offset)?;
+                        if field_is_null(field) {
+                            return Ok(None);
+                        }
+                        <$ty as FromDrizzleRow<Row>>::from_row_at(row, offset).map(Some)
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_wkb_leaf!(
+        Geometry<f64>,
+        Polygon<f64>,
+        MultiPoint<f64>,
+        MultiLineString<f64>,
+        MultiPolygon<f64>,
+    );
 }
 
 // =============================================================================
@@ -1551,6 +1631,8 @@ fn encode_geo_field(value: &PostgresValue<'_>) -> Option<(Field, Option<TypeHint
             )),
             None,
         )),
+        // Hex-encoded WKB is accepted as PostGIS GEOMETRY text input
+        PostgresValue::Geometry(_) => Some((Field::StringValue(value.to_string()), None)),
         _ => None,
     }
 }
@@ -1599,6 +1681,10 @@ fn encode_core_field(value: &PostgresValue<'_>) -> Option<(Field, Option<TypeHin
         }
         PostgresValue::Enum(e) => Some((Field::StringValue(e.variant_name().to_string()), None)),
         PostgresValue::Array(items) => Some((encode_array(items), None)),
+        // The `[1,2,3]` text form is accepted as pgvector VECTOR input
+        PostgresValue::Vector(_) | PostgresValue::Composite(_) | PostgresValue::Range(_) => {
+            Some((Field::StringValue(value.to_string()), None))
+        }
         #[allow(unreachable_patterns)]
         _ => None,
     }
@@ -23,7 +23,7 @@ pub mod update;
 pub use cte::{CTEDefinition, CTEView};
 
 // Export state markers for easier use
-pub use delete::{DeleteInitial, DeleteReturningSet, DeleteWhereSet};
+pub use delete::{DeleteInitial, DeleteReturningSet, DeleteUsingSet, DeleteWhereSet};
 pub use insert::{
     InsertDoUpdateSet, InsertInitial, InsertOnConflictSet, InsertReturningSet, InsertValuesSet,
     OnConflictBuilder,
@@ -48,6 +48,12 @@ pub struct CTEInit;
 
 impl ExecutableState for CTEInit {}
 
+/// Marker for a `CREATE TEMP TABLE ... AS SELECT ...` statement.
+#[derive(Debug, Clone)]
+pub struct CreateTempTableSet;
+
+impl ExecutableState for CreateTempTableSet {}
+
 /// Main query builder for `PostgreSQL`
 ///
 /// The `S` type parameter represents the schema type, which is used
@@ -460,6 +466,33 @@ impl<'a, Schema> QueryBuilder<'a, Schema, BuilderInit> {
             grouped: PhantomData,
         }
     }
+
+    /// Creates a temporary table populated by the view's query.
+    ///
+    /// The view (usually from `.into_cte::<Tag>()`) doubles as the handle for the
+    /// new table, so later statements in the same session can select from and
+    /// join it with typed columns.
+    pub fn create_temp_table_as<Table, Query>(
+        &self,
+        view: &CTEView<'a, Table, Query>,
+    ) -> QueryBuilder<'a, Schema, CreateTempTableSet>
+    where
+        Query: ToSQL<'a, PostgresValue<'a>>,
+    {
+        let sql = SQL::from_iter([Token::CREATE, Token::TEMP, Token::TABLE])
+            .append(view.to_sql())
+            .push(Token::AS)
+            .append(view.query().to_sql());
+        QueryBuilder {
+            sql,
+            schema: PhantomData,
+            state: PhantomData,
+            table: PhantomData,
+            marker: PhantomData,
+            row: PhantomData,
+            grouped: PhantomData,
+        }
+    }
 }
 
 // Marker trait to indicate a query builder state is executable
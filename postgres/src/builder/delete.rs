@@ -1,6 +1,9 @@
 use crate::values::PostgresValue;
 use core::marker::PhantomData;
-use drizzle_core::ToSQL;
+use drizzle_core::{SQL, ToSQL, Token};
+
+// Import the ExecutableState trait
+use super::ExecutableState;
 
 //------------------------------------------------------------------------------
 // Type State Markers
@@ -8,6 +11,13 @@ use drizzle_core::ToSQL;
 
 pub use drizzle_core::builder::{DeleteInitial, DeleteReturningSet, DeleteWhereSet};
 
+/// Marker for the state after USING clause
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeleteUsingSet;
+
+// Mark states that can execute delete queries
+impl ExecutableState for DeleteUsingSet {}
+
 //------------------------------------------------------------------------------
 // DeleteBuilder Definition
 //------------------------------------------------------------------------------
@@ -38,6 +48,27 @@ type ReturningBuilder<'a, S, T, Columns> = DeleteBuilder<
 //------------------------------------------------------------------------------
 
 impl<'a, S, T> DeleteBuilder<'a, S, DeleteInitial, T> {
+    /// Adds a USING clause and transitions to the `UsingSet` state
+    ///
+    /// Rows of the source can be referenced in the WHERE clause, deleting
+    /// from the target based on a join.
+    #[inline]
+    pub fn using(
+        self,
+        source: impl ToSQL<'a, PostgresValue<'a>>,
+    ) -> DeleteBuilder<'a, S, DeleteUsingSet, T> {
+        let using_sql = SQL::from(Token::USING).append(source.into_sql());
+        DeleteBuilder {
+            sql: self.sql.append(using_sql),
+            schema: PhantomData,
+            state: PhantomData,
+            table: PhantomData,
+            marker: PhantomData,
+            row: PhantomData,
+            grouped: PhantomData,
+        }
+    }
+
     /// Adds a WHERE condition to the query
     #[inline]
     pub fn r#where<E>(self, condition: E) -> DeleteBuilder<'a, S, DeleteWhereSet, T>
@@ -77,6 +108,50 @@ impl<'a, S, T> DeleteBuilder<'a, S, DeleteInitial, T> {
     }
 }
 
+//------------------------------------------------------------------------------
+// Post-USING Implementation
+//------------------------------------------------------------------------------
+
+impl<'a, S, T> DeleteBuilder<'a, S, DeleteUsingSet, T> {
+    /// Adds a WHERE condition after USING
+    #[inline]
+    pub fn r#where<E>(self, condition: E) -> DeleteBuilder<'a, S, DeleteWhereSet, T>
+    where
+        E: drizzle_core::expr::Expr<'a, PostgresValue<'a>>,
+        E::SQLType: drizzle_core::types::BooleanLike,
+    {
+        let where_sql = crate::helpers::r#where(condition);
+        DeleteBuilder {
+            sql: self.sql.append(where_sql),
+            schema: PhantomData,
+            state: PhantomData,
+            table: PhantomData,
+            marker: PhantomData,
+            row: PhantomData,
+            grouped: PhantomData,
+        }
+    }
+
+    /// Adds a RETURNING clause after USING
+    #[inline]
+    pub fn returning<Columns>(self, columns: Columns) -> ReturningBuilder<'a, S, T, Columns>
+    where
+        Columns: ToSQL<'a, PostgresValue<'a>> + drizzle_core::IntoSelectTarget,
+        Columns::Marker: drizzle_core::ResolveRow<T>,
+    {
+        let returning_sql = crate::helpers::returning(columns);
+        DeleteBuilder {
+            sql: self.sql.append(returning_sql),
+            schema: PhantomData,
+            state: PhantomData,
+            table: PhantomData,
+            marker: PhantomData,
+            row: PhantomData,
+            grouped: PhantomData,
+        }
+    }
+}
+
 //------------------------------------------------------------------------------
 // Post-WHERE Implementation
 //------------------------------------------------------------------------------
@@ -68,9 +68,9 @@ impl<'a> RefreshMaterializedView<'a, RefreshInitial> {
 
         // Build: REFRESH MATERIALIZED VIEW "schema"."name"
         let sql = SQL::from_iter([Token::REFRESH, Token::MATERIALIZED, Token::VIEW])
-            .append(SQL::ident(schema))
+            .append(SQL::name(schema))
             .push(Token::DOT)
-            .append(SQL::ident(name));
+            .append(SQL::name(name));
 
         Self {
             sql,
@@ -1,4 +1,4 @@
-use drizzle_core::schema::SQLEnumInfo;
+use drizzle_core::schema::{SQLCompositeInfo, SQLDomainInfo, SQLEnumInfo};
 use drizzle_core::traits::SQLViewInfo;
 use drizzle_core::{SQLIndexInfo, SQLPolicyInfo, SQLSchemaType};
 
@@ -17,6 +17,10 @@ pub enum PostgresSchemaType {
     Trigger,
     /// A database enum type (`PostgreSQL`)
     Enum(&'static dyn SQLEnumInfo),
+    /// A database domain type (`PostgreSQL`)
+    Domain(&'static dyn SQLDomainInfo),
+    /// A database composite type (`PostgreSQL`)
+    Composite(&'static dyn SQLCompositeInfo),
 }
 
 impl SQLSchemaType for PostgresSchemaType {}
@@ -0,0 +1,113 @@
+//! Binary record decoding for `#[PostgresComposite]` types.
+//!
+//! `PostgreSQL` sends composite values in the binary record format: an `i32`
+//! attribute count followed by, for each attribute, its type OID, an `i32`
+//! length (`-1` for NULL), and the encoded bytes. Generated `FromSql` impls
+//! walk the record with [`CompositeReader`], decoding each attribute with the
+//! field type's own `FromSql` impl.
+
+#[cfg(feature = "postgres-sync")]
+use postgres::types::{Field, FromSql, Kind, Type};
+
+#[cfg(all(feature = "tokio-postgres", not(feature = "postgres-sync")))]
+use tokio_postgres::types::{Field, FromSql, Kind, Type};
+
+type BoxError = Box<dyn std::error::Error + Sync + Send>;
+
+fn decode_error(message: String) -> BoxError {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message).into()
+}
+
+/// Returns true when `ty` is the composite type named `name`.
+#[must_use]
+pub fn accepts(ty: &Type, name: &str) -> bool {
+    matches!(ty.kind(), Kind::Composite(_)) && ty.name() == name
+}
+
+/// Sequential reader over the attributes of a binary composite value.
+pub struct CompositeReader<'t, 'a> {
+    fields: &'t [Field],
+    raw: &'a [u8],
+    index: usize,
+}
+
+impl<'t, 'a> CompositeReader<'t, 'a> {
+    /// Start reading a composite value of type `ty`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `ty` is not a composite type or the attribute
+    /// count in `raw` does not match the type's attributes.
+    pub fn new(ty: &'t Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        let Kind::Composite(fields) = ty.kind() else {
+            return Err(decode_error(format!(
+                "cannot decode {} as a composite value",
+                ty.name()
+            )));
+        };
+        let mut reader = Self {
+            fields,
+            raw,
+            index: 0,
+        };
+        let count = reader.read_i32()?;
+        if usize::try_from(count).ok() != Some(fields.len()) {
+            return Err(decode_error(format!(
+                "composite {} has {} attributes, received {count}",
+                ty.name(),
+                fields.len()
+            )));
+        }
+        Ok(reader)
+    }
+
+    /// Decode the next attribute as `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the record is truncated, has no attributes
+    /// left, or `T` cannot decode the attribute.
+    pub fn read<T: FromSql<'a>>(&mut self) -> Result<T, BoxError> {
+        let field = self
+            .fields
+            .get(self.index)
+            .ok_or_else(|| decode_error("composite record has no attributes left".to_string()))?;
+        self.index += 1;
+
+        // The attribute OID is implied by the type's field list.
+        self.read_i32()?;
+        let len = self.read_i32()?;
+        let value = if len < 0 {
+            None
+        } else {
+            let len = usize::try_from(len).unwrap_or_default();
+            if self.raw.len() < len {
+                return Err(decode_error(format!(
+                    "composite attribute {} is truncated",
+                    field.name()
+                )));
+            }
+            let (value, rest) = self.raw.split_at(len);
+            self.raw = rest;
+            Some(value)
+        };
+
+        if !T::accepts(field.type_()) {
+            return Err(decode_error(format!(
+                "cannot decode composite attribute {} of type {} as {}",
+                field.name(),
+                field.type_().name(),
+                std::any::type_name::<T>()
+            )));
+        }
+        T::from_sql_nullable(field.type_(), value)
+    }
+
+    fn read_i32(&mut self) -> Result<i32, BoxError> {
+        let Some((bytes, rest)) = self.raw.split_first_chunk::<4>() else {
+            return Err(decode_error("composite record is truncated".to_string()));
+        };
+        self.raw = rest;
+        Ok(i32::from_be_bytes(*bytes))
+    }
+}
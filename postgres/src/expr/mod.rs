@@ -6,9 +6,21 @@
 mod array_ops;
 mod ilike;
 mod json_ops;
+#[cfg(feature = "cidr")]
+mod network_ops;
+#[cfg(feature = "geo-types")]
+mod postgis;
+mod range_ops;
 mod regex;
+mod vector;
 
 pub use array_ops::*;
 pub use ilike::*;
 pub use json_ops::*;
+#[cfg(feature = "cidr")]
+pub use network_ops::*;
+#[cfg(feature = "geo-types")]
+pub use postgis::*;
+pub use range_ops::*;
 pub use regex::*;
+pub use vector::*;
@@ -0,0 +1,171 @@
+//! `PostgreSQL` network address operators.
+//!
+//! This module provides PostgreSQL-specific operators for `INET` and `CIDR`
+//! values:
+//! - `>>` (network strictly contains)
+//! - `>>=` (network contains or equals)
+//! - `<<` (strictly contained by network)
+//! - `<<=` (contained by or equals network)
+//!
+//! Overlap (`&&`) is shared with ranges, see [`overlaps`](super::overlaps).
+//!
+//! # Example
+//!
+//! ```
+//! # use drizzle_postgres::expr::inet_contained_by;
+//! # use drizzle_core::{SQL, ToSQL};
+//! # use drizzle_postgres::values::PostgresValue;
+//! let addr = SQL::<PostgresValue>::raw("addr");
+//! let private: cidr::IpCidr = "10.0.0.0/8".parse().unwrap();
+//! let condition = inet_contained_by(addr, private);
+//! assert_eq!(condition.to_sql().sql(), "addr << $1");
+//! ```
+
+use crate::values::PostgresValue;
+use cidr::{IpCidr, IpInet};
+use drizzle_core::ToSQL;
+use drizzle_core::expr::{Expr, NonNull, SQLExpr, Scalar};
+use drizzle_core::sql::{SQL, SQLChunk};
+use drizzle_types::postgres::types::{Boolean, Cidr, Inet};
+
+impl<'a> ToSQL<'a, PostgresValue<'a>> for IpInet {
+    fn to_sql(&self) -> SQL<'a, PostgresValue<'a>> {
+        SQL::param(PostgresValue::Inet(*self))
+    }
+}
+
+impl<'a> Expr<'a, PostgresValue<'a>> for IpInet {
+    type SQLType = Inet;
+    type Nullable = NonNull;
+    type Aggregate = Scalar;
+}
+
+impl<'a> ToSQL<'a, PostgresValue<'a>> for IpCidr {
+    fn to_sql(&self) -> SQL<'a, PostgresValue<'a>> {
+        SQL::param(PostgresValue::Cidr(*self))
+    }
+}
+
+impl<'a> Expr<'a, PostgresValue<'a>> for IpCidr {
+    type SQLType = Cidr;
+    type Nullable = NonNull;
+    type Aggregate = Scalar;
+}
+
+fn network_operator<'a, L, R>(
+    left: L,
+    operator: &'static str,
+    right: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Boolean, NonNull, Scalar>
+where
+    L: ToSQL<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    SQLExpr::new(
+        left.to_sql()
+            .push(SQLChunk::Raw(operator.into()))
+            .append(right.to_sql()),
+    )
+}
+
+/// `PostgreSQL` `>>` operator - network strictly contains.
+///
+/// Returns true if the right address lies within the left network and is
+/// not the network itself.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::inet_contains;
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let subnet = SQL::<PostgresValue>::raw("subnet");
+/// let host: cidr::IpInet = "10.1.2.3".parse().unwrap();
+/// let condition = inet_contains(subnet, host);
+/// assert_eq!(condition.to_sql().sql(), "subnet >> $1");
+/// ```
+pub fn inet_contains<'a, L, R>(
+    network: L,
+    address: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Boolean, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    network_operator(network, ">>", address)
+}
+
+/// `PostgreSQL` `>>=` operator - network contains or equals.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::inet_contains_or_equals;
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let subnet = SQL::<PostgresValue>::raw("subnet");
+/// let net: cidr::IpCidr = "10.0.0.0/8".parse().unwrap();
+/// let condition = inet_contains_or_equals(subnet, net);
+/// assert_eq!(condition.to_sql().sql(), "subnet >>= $1");
+/// ```
+pub fn inet_contains_or_equals<'a, L, R>(
+    network: L,
+    address: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Boolean, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    network_operator(network, ">>=", address)
+}
+
+/// `PostgreSQL` `<<` operator - strictly contained by network.
+///
+/// Returns true if the left address lies within the right network and is
+/// not the network itself.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::inet_contained_by;
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let addr = SQL::<PostgresValue>::raw("addr");
+/// let net: cidr::IpCidr = "192.168.0.0/16".parse().unwrap();
+/// let condition = inet_contained_by(addr, net);
+/// assert_eq!(condition.to_sql().sql(), "addr << $1");
+/// ```
+pub fn inet_contained_by<'a, L, R>(
+    address: L,
+    network: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Boolean, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    network_operator(address, "<<", network)
+}
+
+/// `PostgreSQL` `<<=` operator - contained by or equals network.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::inet_contained_by_or_equals;
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let addr = SQL::<PostgresValue>::raw("addr");
+/// let net: cidr::IpCidr = "192.168.0.0/16".parse().unwrap();
+/// let condition = inet_contained_by_or_equals(addr, net);
+/// assert_eq!(condition.to_sql().sql(), "addr <<= $1");
+/// ```
+pub fn inet_contained_by_or_equals<'a, L, R>(
+    address: L,
+    network: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Boolean, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    network_operator(address, "<<=", network)
+}
@@ -0,0 +1,231 @@
+//! PostGIS geometry functions.
+//!
+//! This module provides helpers for columns declared with
+//! `#[column(geometry(...))]`:
+//! - `ST_Distance` (distance between two geometries)
+//! - `ST_DWithin` (geometries within a distance of each other)
+//! - `ST_Intersects` / `ST_Contains` (spatial relationships)
+//! - `ST_SetSRID` (tag a geometry with a spatial reference)
+//!
+//! `geo_types` shapes bind as WKB without an SRID. PostGIS rejects
+//! operations on mixed SRIDs, so wrap values compared against an
+//! SRID-constrained column in [`st_set_srid`]. Points bind as the native
+//! `POINT` type, so pass them as `Geometry::Point`.
+//!
+//! # Example
+//!
+//! ```
+//! # use drizzle_postgres::expr::{st_dwithin, st_set_srid};
+//! # use drizzle_core::{SQL, ToSQL};
+//! # use drizzle_postgres::values::PostgresValue;
+//! use geo_types::{Geometry, point};
+//!
+//! let location = SQL::<PostgresValue>::raw("location");
+//! let origin = st_set_srid(Geometry::Point(point!(x: 13.4, y: 52.5)), 4326);
+//! let condition = st_dwithin(location, origin, 0.1);
+//! assert_eq!(
+//!     condition.to_sql().sql(),
+//!     "ST_DWithin (location, ST_SetSRID (CAST ($1 AS GEOMETRY), $2), $3)"
+//! );
+//! ```
+
+use crate::values::PostgresValue;
+use drizzle_core::expr::{Expr, NonNull, SQLExpr, Scalar};
+use drizzle_core::sql::SQL;
+use drizzle_core::{ToSQL, Token};
+use drizzle_types::postgres::types::{Boolean, Float8, Geometry};
+
+macro_rules! impl_geometry_expr {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl<'a> ToSQL<'a, PostgresValue<'a>> for $ty {
+                fn to_sql(&self) -> SQL<'a, PostgresValue<'a>> {
+                    SQL::param(PostgresValue::from(self.clone()))
+                }
+            }
+
+            impl<'a> Expr<'a, PostgresValue<'a>> for $ty {
+                type SQLType = Geometry;
+                type Nullable = NonNull;
+                type Aggregate = Scalar;
+            }
+        )+
+    };
+}
+
+impl_geometry_expr!(
+    geo_types::Geometry<f64>,
+    geo_types::Polygon<f64>,
+    geo_types::MultiPoint<f64>,
+    geo_types::MultiLineString<f64>,
+    geo_types::MultiPolygon<f64>,
+);
+
+fn geometry_function<'a, T, L, R>(
+    name: &'static str,
+    left: L,
+    right: R,
+) -> SQLExpr<'a, PostgresValue<'a>, T, NonNull, Scalar>
+where
+    T: drizzle_core::types::DataType,
+    L: ToSQL<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    SQLExpr::new(SQL::func(
+        name,
+        left.to_sql().push(Token::COMMA).append(right.to_sql()),
+    ))
+}
+
+/// PostGIS `ST_Distance` - distance between two geometries.
+///
+/// Measured in the units of the geometries' spatial reference.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::st_distance;
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let a = SQL::<PostgresValue>::raw("a.location");
+/// let b = SQL::<PostgresValue>::raw("b.location");
+/// assert_eq!(
+///     st_distance(a, b).to_sql().sql(),
+///     "ST_Distance (a.location, b.location)"
+/// );
+/// ```
+pub fn st_distance<'a, L, R>(
+    left: L,
+    right: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Float8, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    geometry_function("ST_Distance", left, right)
+}
+
+/// PostGIS `ST_DWithin` - true if the geometries are within `distance` of
+/// each other.
+///
+/// Unlike comparing [`st_distance`], this can use a GIST index on either
+/// side.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::st_dwithin;
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let a = SQL::<PostgresValue>::raw("a.location");
+/// let b = SQL::<PostgresValue>::raw("b.location");
+/// assert_eq!(
+///     st_dwithin(a, b, 500.0).to_sql().sql(),
+///     "ST_DWithin (a.location, b.location, $1)"
+/// );
+/// ```
+pub fn st_dwithin<'a, L, R>(
+    left: L,
+    right: R,
+    distance: f64,
+) -> SQLExpr<'a, PostgresValue<'a>, Boolean, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    SQLExpr::new(SQL::func(
+        "ST_DWithin",
+        left.to_sql()
+            .push(Token::COMMA)
+            .append(right.to_sql())
+            .push(Token::COMMA)
+            .append(distance.to_sql()),
+    ))
+}
+
+/// PostGIS `ST_Intersects` - true if the geometries share any point.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::st_intersects;
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let area = SQL::<PostgresValue>::raw("area");
+/// let route = SQL::<PostgresValue>::raw("route");
+/// assert_eq!(
+///     st_intersects(area, route).to_sql().sql(),
+///     "ST_Intersects (area, route)"
+/// );
+/// ```
+pub fn st_intersects<'a, L, R>(
+    left: L,
+    right: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Boolean, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    geometry_function("ST_Intersects", left, right)
+}
+
+/// PostGIS `ST_Contains` - true if no point of `inner` lies outside
+/// `outer` and their interiors intersect.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::st_contains;
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let area = SQL::<PostgresValue>::raw("area");
+/// let location = SQL::<PostgresValue>::raw("location");
+/// assert_eq!(
+///     st_contains(area, location).to_sql().sql(),
+///     "ST_Contains (area, location)"
+/// );
+/// ```
+pub fn st_contains<'a, L, R>(
+    outer: L,
+    inner: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Boolean, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    geometry_function("ST_Contains", outer, inner)
+}
+
+/// PostGIS `ST_SetSRID` - tags a geometry with a spatial reference ID.
+///
+/// The argument is cast to `GEOMETRY` so untyped WKB parameters resolve to
+/// the geometry overload rather than the geography one.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::st_set_srid;
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let geom = SQL::<PostgresValue>::raw("geom");
+/// assert_eq!(
+///     st_set_srid(geom, 4326).to_sql().sql(),
+///     "ST_SetSRID (CAST (geom AS GEOMETRY), $1)"
+/// );
+/// ```
+pub fn st_set_srid<'a, E>(
+    geometry: E,
+    srid: i32,
+) -> SQLExpr<'a, PostgresValue<'a>, Geometry, NonNull, Scalar>
+where
+    E: ToSQL<'a, PostgresValue<'a>>,
+{
+    let geometry = SQL::func(
+        "CAST",
+        geometry
+            .to_sql()
+            .push(Token::AS)
+            .append(SQL::raw("GEOMETRY")),
+    );
+    geometry_function("ST_SetSRID", geometry, srid)
+}
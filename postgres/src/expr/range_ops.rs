@@ -0,0 +1,116 @@
+//! `PostgreSQL` range operators.
+//!
+//! This module provides PostgreSQL-specific range operators:
+//! - `@>` (range contains a value or range)
+//! - `<@` (value or range is contained by a range)
+//! - `&&` (ranges overlap)
+//!
+//! # Example
+//!
+//! ```
+//! # use drizzle_postgres::expr::overlaps;
+//! # use drizzle_core::{SQL, ToSQL};
+//! # use drizzle_postgres::values::{PgRange, PostgresValue};
+//! let during = SQL::<PostgresValue>::raw("during");
+//! let condition = overlaps(during, PgRange::from(3..7));
+//! assert_eq!(condition.to_sql().sql(), "during && $1");
+//! ```
+
+use crate::values::PostgresValue;
+use drizzle_core::ToSQL;
+use drizzle_core::expr::{Expr, NonNull, SQLExpr, Scalar};
+use drizzle_core::sql::SQLChunk;
+use drizzle_types::postgres::types::Boolean;
+
+fn range_operator<'a, L, R>(
+    left: L,
+    operator: &'static str,
+    right: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Boolean, NonNull, Scalar>
+where
+    L: ToSQL<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    SQLExpr::new(
+        left.to_sql()
+            .push(SQLChunk::Raw(operator.into()))
+            .append(right.to_sql()),
+    )
+}
+
+/// `PostgreSQL` `@>` operator - range contains.
+///
+/// Returns true if the range contains the right value, which may be a
+/// single element or another range.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::contains;
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let during = SQL::<PostgresValue>::raw("during");
+/// let condition = contains(during, 5);
+/// assert_eq!(condition.to_sql().sql(), "during @> $1");
+/// ```
+pub fn contains<'a, L, R>(
+    range: L,
+    value: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Boolean, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    range_operator(range, "@>", value)
+}
+
+/// `PostgreSQL` `<@` operator - contained by range.
+///
+/// Returns true if the left value, a single element or another range, lies
+/// within the range.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::contained_by;
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::{PgRange, PostgresValue};
+/// let during = SQL::<PostgresValue>::raw("during");
+/// let condition = contained_by(during, PgRange::from(1..100));
+/// assert_eq!(condition.to_sql().sql(), "during <@ $1");
+/// ```
+pub fn contained_by<'a, L, R>(
+    value: L,
+    range: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Boolean, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    range_operator(value, "<@", range)
+}
+
+/// `PostgreSQL` `&&` operator - ranges overlap.
+///
+/// Returns true if the ranges have any points in common.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::overlaps;
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::{PgRange, PostgresValue};
+/// let during = SQL::<PostgresValue>::raw("during");
+/// let condition = overlaps(during, PgRange::from(..10));
+/// assert_eq!(condition.to_sql().sql(), "during && $1");
+/// ```
+pub fn overlaps<'a, L, R>(
+    left: L,
+    right: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Boolean, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    range_operator(left, "&&", right)
+}
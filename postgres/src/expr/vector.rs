@@ -0,0 +1,228 @@
+//! pgvector distance operators.
+//!
+//! This module provides helpers for columns declared with
+//! `#[column(vector(N))]`:
+//! - `<->` (Euclidean / L2 distance)
+//! - `<=>` (cosine distance)
+//! - `<#>` (negative inner product)
+//! - `<+>` (taxicab / L1 distance)
+//! - [`nearest`] / [`nearest_cosine`] (nearest-neighbour ordering)
+//!
+//! Wrap a query embedding in [`PgVector`] so it binds as one pgvector
+//! value. Pair [`nearest`] with `limit(k)` for a k-nearest-neighbour query; with an
+//! HNSW or IVFFlat index built on the matching operator class, Postgres
+//! serves it from the index.
+//!
+//! # Example
+//!
+//! ```
+//! # use drizzle_postgres::expr::{nearest, PgVector};
+//! # use drizzle_core::{SQL, ToSQL};
+//! # use drizzle_postgres::values::PostgresValue;
+//! let embedding = SQL::<PostgresValue>::raw("embedding");
+//! let order = nearest(embedding, PgVector(vec![0.1, 0.2, 0.3]));
+//! assert_eq!(order.sql(), "embedding <-> $1 ASC");
+//! assert!(matches!(order.params().next(), Some(PostgresValue::Vector(_))));
+//! ```
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::values::PostgresValue;
+use drizzle_core::OrderBy;
+use drizzle_core::ToSQL;
+use drizzle_core::expr::{Expr, NonNull, SQLExpr, Scalar};
+use drizzle_core::sql::{SQL, SQLChunk};
+use drizzle_types::postgres::types::Float8;
+
+/// Wrapper for passing a `Vec<f32>` as a single pgvector parameter.
+///
+/// Like [`PgArray`](super::PgArray), this avoids `Vec<T>`'s comma-joined
+/// `ToSQL` (`$1, $2, $3`); the components are sent as one `vector` value.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::{cosine_distance, PgVector};
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let embedding = SQL::<PostgresValue>::raw("embedding");
+/// let distance = cosine_distance(embedding, PgVector(vec![1.0, 0.0])).to_sql();
+/// assert_eq!(distance.sql(), "embedding <=> $1");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgVector(pub Vec<f32>);
+
+impl<'a> ToSQL<'a, PostgresValue<'a>> for PgVector {
+    fn to_sql(&self) -> SQL<'a, PostgresValue<'a>> {
+        SQL::param(PostgresValue::Vector(self.0.clone()))
+    }
+}
+
+fn distance_operator<'a, L, R>(
+    left: L,
+    operator: &'static str,
+    right: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Float8, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    SQLExpr::new(
+        left.to_sql()
+            .push(SQLChunk::Raw(operator.into()))
+            .append(right.to_sql().map_params(PostgresValue::into_vector)),
+    )
+}
+
+/// pgvector `<->` - Euclidean (L2) distance between two vectors.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::l2_distance;
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let a = SQL::<PostgresValue>::raw("a.embedding");
+/// let b = SQL::<PostgresValue>::raw("b.embedding");
+/// assert_eq!(l2_distance(a, b).to_sql().sql(), "a.embedding <-> b.embedding");
+/// ```
+pub fn l2_distance<'a, L, R>(
+    left: L,
+    right: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Float8, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    distance_operator(left, "<->", right)
+}
+
+/// pgvector `<=>` - cosine distance (`1 - cosine similarity`).
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::{cosine_distance, PgVector};
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let embedding = SQL::<PostgresValue>::raw("embedding");
+/// assert_eq!(
+///     cosine_distance(embedding, PgVector(vec![1.0, 0.0])).to_sql().sql(),
+///     "embedding <=> $1"
+/// );
+/// ```
+pub fn cosine_distance<'a, L, R>(
+    left: L,
+    right: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Float8, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    distance_operator(left, "<=>", right)
+}
+
+/// pgvector `<#>` - negative inner product.
+///
+/// pgvector negates the inner product so that ascending order puts the most
+/// similar vectors first.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::{inner_product, PgVector};
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let embedding = SQL::<PostgresValue>::raw("embedding");
+/// assert_eq!(
+///     inner_product(embedding, PgVector(vec![1.0, 0.0])).to_sql().sql(),
+///     "embedding <#> $1"
+/// );
+/// ```
+pub fn inner_product<'a, L, R>(
+    left: L,
+    right: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Float8, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    distance_operator(left, "<#>", right)
+}
+
+/// pgvector `<+>` - taxicab (L1) distance. Requires pgvector 0.7 or later.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::{l1_distance, PgVector};
+/// # use drizzle_core::{SQL, ToSQL};
+/// # use drizzle_postgres::values::PostgresValue;
+/// let embedding = SQL::<PostgresValue>::raw("embedding");
+/// assert_eq!(
+///     l1_distance(embedding, PgVector(vec![1.0, 0.0])).to_sql().sql(),
+///     "embedding <+> $1"
+/// );
+/// ```
+pub fn l1_distance<'a, L, R>(
+    left: L,
+    right: R,
+) -> SQLExpr<'a, PostgresValue<'a>, Float8, NonNull, Scalar>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    distance_operator(left, "<+>", right)
+}
+
+/// ORDER BY expression putting the rows closest to `embedding` (by L2
+/// distance) first. Follow it with `limit(k)` for the k nearest neighbours.
+///
+/// An HNSW or IVFFlat index using `vector_l2_ops` serves this ordering.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::{nearest, PgVector};
+/// # use drizzle_postgres::values::PostgresValue;
+/// # use drizzle_core::SQL;
+/// let embedding = SQL::<PostgresValue>::raw("embedding");
+/// assert_eq!(
+///     nearest(embedding, PgVector(vec![1.0, 0.0])).sql(),
+///     "embedding <-> $1 ASC"
+/// );
+/// ```
+pub fn nearest<'a, L, R>(column: L, embedding: R) -> SQL<'a, PostgresValue<'a>>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    l2_distance(column, embedding)
+        .to_sql()
+        .append(&OrderBy::Asc)
+}
+
+/// Like [`nearest`], ranking by cosine distance. An index using
+/// `vector_cosine_ops` serves this ordering.
+///
+/// # Example
+///
+/// ```
+/// # use drizzle_postgres::expr::{nearest_cosine, PgVector};
+/// # use drizzle_postgres::values::PostgresValue;
+/// # use drizzle_core::SQL;
+/// let embedding = SQL::<PostgresValue>::raw("embedding");
+/// assert_eq!(
+///     nearest_cosine(embedding, PgVector(vec![1.0, 0.0])).sql(),
+///     "embedding <=> $1 ASC"
+/// );
+/// ```
+pub fn nearest_cosine<'a, L, R>(column: L, embedding: R) -> SQL<'a, PostgresValue<'a>>
+where
+    L: Expr<'a, PostgresValue<'a>>,
+    R: ToSQL<'a, PostgresValue<'a>>,
+{
+    cosine_distance(column, embedding)
+        .to_sql()
+        .append(&OrderBy::Asc)
+}
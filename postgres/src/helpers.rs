@@ -2,7 +2,9 @@
 use crate::prelude::*;
 use crate::traits::PostgresTable;
 use crate::values::PostgresValue;
-use drizzle_core::{ColumnRef, SQL, SQLTableInfo, ToSQL, Token, helpers, traits::SQLModel};
+use drizzle_core::{
+    ColumnRef, SQL, SQLChunk, SQLTableInfo, ToSQL, Token, helpers, traits::SQLModel,
+};
 
 // Re-export core helpers with PostgresValue type for convenience
 pub(crate) use helpers::{
@@ -27,7 +29,7 @@ fn columns_info_to_sql<'a>(columns: &[ColumnRef]) -> SQL<'a, PostgresValue<'a>>
         if idx > 0 {
             sql.push_mut(Token::COMMA);
         }
-        sql.append_mut(SQL::ident(col.name));
+        sql.push_mut(SQLChunk::Name(col.name));
     }
     sql
 }
@@ -200,18 +202,22 @@ where
         return SQL::from_iter([Token::DEFAULT, Token::VALUES]);
     }
 
-    let columns_sql = columns_info_to_sql(columns_slice);
-    let mut values_sql = SQL::with_capacity_chunks(rows.len().saturating_mul(4));
+    let mut sql = columns_info_to_sql(columns_slice)
+        .parens()
+        .push(Token::VALUES);
+    // Each row renders as `(p, p, ..., p)` plus a separating comma.
+    let row_chunks = columns_slice.len().saturating_mul(2).saturating_add(2);
+    sql.reserve(rows.len().saturating_mul(row_chunks));
     for (idx, row) in rows.iter().enumerate() {
         if idx > 0 {
-            values_sql.push_mut(Token::COMMA);
+            sql.push_mut(Token::COMMA);
         }
-        values_sql.push_mut(Token::LPAREN);
-        values_sql.append_mut(row.values());
-        values_sql.push_mut(Token::RPAREN);
+        sql.push_mut(Token::LPAREN);
+        sql.append_mut(row.values());
+        sql.push_mut(Token::RPAREN);
     }
 
-    columns_sql.parens().push(Token::VALUES).append(values_sql)
+    sql
 }
 
 /// Helper function to create a RETURNING clause - `PostgreSQL` specific
@@ -254,16 +260,14 @@ pub(crate) fn for_key_share<'a>() -> SQL<'a, PostgresValue<'a>> {
 
 /// Helper function to create a FOR UPDATE OF table clause.
 /// Uses unqualified table name as required by `PostgreSQL`.
-pub(crate) fn for_update_of<'a>(table_name: &str) -> SQL<'a, PostgresValue<'a>> {
-    SQL::from_iter([Token::FOR, Token::UPDATE, Token::OF])
-        .append(SQL::ident(String::from(table_name)))
+pub(crate) fn for_update_of<'a>(table_name: &'static str) -> SQL<'a, PostgresValue<'a>> {
+    SQL::from_iter([Token::FOR, Token::UPDATE, Token::OF]).append(SQL::name(table_name))
 }
 
 /// Helper function to create a FOR SHARE OF table clause.
 /// Uses unqualified table name as required by `PostgreSQL`.
-pub(crate) fn for_share_of<'a>(table_name: &str) -> SQL<'a, PostgresValue<'a>> {
-    SQL::from_iter([Token::FOR, Token::SHARE, Token::OF])
-        .append(SQL::ident(String::from(table_name)))
+pub(crate) fn for_share_of<'a>(table_name: &'static str) -> SQL<'a, PostgresValue<'a>> {
+    SQL::from_iter([Token::FOR, Token::SHARE, Token::OF]).append(SQL::name(table_name))
 }
 
 /// Helper function to add NOWAIT to a FOR clause
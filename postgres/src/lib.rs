@@ -37,6 +37,9 @@ pub mod attrs;
 pub mod aws_data_api;
 pub mod builder;
 pub mod common;
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+#[doc(hidden)]
+pub mod composite;
 pub mod expr;
 pub mod helpers;
 pub mod traits;
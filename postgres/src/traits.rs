@@ -180,3 +180,111 @@ pub trait DrizzlePostgresColumn: Sized {
         self.encode().into_owned()
     }
 }
+
+/// Returns true when the column at `idx` is SQL NULL, whatever its type.
+///
+/// Used by the table macros to decode nullable custom-type columns.
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+#[doc(hidden)]
+#[must_use]
+pub fn column_is_null(row: &crate::Row, idx: usize) -> bool {
+    #[cfg(feature = "postgres-sync")]
+    use postgres::types::{FromSql, Type};
+    #[cfg(all(feature = "tokio-postgres", not(feature = "postgres-sync")))]
+    use tokio_postgres::types::{FromSql, Type};
+
+    struct AnyValue;
+
+    impl<'a> FromSql<'a> for AnyValue {
+        fn from_sql(
+            _ty: &Type,
+            _raw: &'a [u8],
+        ) -> Result<Self, Box<dyn core::error::Error + Sync + Send>> {
+            Ok(Self)
+        }
+
+        fn accepts(_ty: &Type) -> bool {
+            true
+        }
+    }
+
+    row.try_get::<_, Option<AnyValue>>(idx)
+        .map_or(true, |value| value.is_none())
+}
+
+/// Maps a custom Rust type onto an already-supported `PostgreSQL` type.
+///
+/// Wrapper types (`Email(ArrayString<64>)`, `Cents(i64)`, ...) usually only
+/// need to convert to and from a representation drizzle already binds and
+/// decodes. A blanket impl provides [`DrizzlePostgresColumn`] for every
+/// implementor, so the table macros accept the type directly as a field.
+///
+/// ```ignore
+/// struct Cents(i64);
+///
+/// impl CustomPostgresType for Cents {
+///     type SQLType = drizzle::postgres::types::Int8;
+///     const SQL_TYPE: &'static str = "bigint";
+///     type Repr = i64;
+///
+///     fn to_repr(&self) -> i64 {
+///         self.0
+///     }
+///
+///     fn from_repr(repr: i64) -> Result<Self, DrizzleError> {
+///         Ok(Self(repr))
+///     }
+/// }
+/// ```
+///
+/// Implement [`DrizzlePostgresColumn`] directly instead when decoding needs
+/// access to the driver row.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not map to a PostgreSQL type",
+    note = "implement `CustomPostgresType` with a supported `Repr` type such as `i64`, `String`, or `Vec<u8>`"
+)]
+pub trait CustomPostgresType: Sized {
+    /// Drizzle SQL type marker for this column.
+    type SQLType: drizzle_core::types::DataType;
+
+    /// Declared `PostgreSQL` column type used in DDL, e.g. `"text"`.
+    const SQL_TYPE: &'static str;
+
+    /// The supported Rust type this value is stored as.
+    type Repr: FromPostgresValue + Into<OwnedPostgresValue>;
+
+    /// Convert self into its storage representation.
+    fn to_repr(&self) -> Self::Repr;
+
+    /// Rebuild self from its storage representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::ConversionError`] if `repr` is not a valid
+    /// value for this type.
+    fn from_repr(repr: Self::Repr) -> Result<Self, DrizzleError>;
+}
+
+impl<T> DrizzlePostgresColumn for T
+where
+    T: CustomPostgresType,
+{
+    type SQLType = <T as CustomPostgresType>::SQLType;
+
+    const SQL_TYPE: &'static str = <T as CustomPostgresType>::SQL_TYPE;
+
+    #[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+    fn decode(row: &crate::Row, idx: usize) -> Result<Self, DrizzleError> {
+        T::from_repr(<crate::Row as DrizzleRowByIndex>::get_column::<T::Repr>(
+            row, idx,
+        )?)
+    }
+
+    fn encode(&self) -> PostgresValue<'_> {
+        Into::<OwnedPostgresValue>::into(self.to_repr()).into()
+    }
+
+    fn encode_owned(self) -> OwnedPostgresValue {
+        self.to_repr().into()
+    }
+}
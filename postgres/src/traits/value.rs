@@ -7,7 +7,7 @@
 //! row conversions for postgres, tokio-postgres, and potentially other drivers.
 
 use crate::prelude::*;
-use crate::values::{OwnedPostgresValue, PostgresValue};
+use crate::values::{OwnedPostgresValue, PgRange, PostgresValue};
 use drizzle_core::conv::checked_float_to_int;
 use drizzle_core::error::DrizzleError;
 
@@ -141,6 +141,28 @@ pub trait FromPostgresValue: Sized {
         ))
     }
 
+    /// Convert from a composite (row) value
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::ConversionError`] if the target type cannot represent a composite value.
+    fn from_postgres_composite(_value: Vec<PostgresValue<'_>>) -> Result<Self, DrizzleError> {
+        Err(DrizzleError::ConversionError(
+            "cannot convert composite value to target type".into(),
+        ))
+    }
+
+    /// Convert from a range value
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::ConversionError`] if the target type cannot represent a range.
+    fn from_postgres_range(_value: PgRange<PostgresValue<'_>>) -> Result<Self, DrizzleError> {
+        Err(DrizzleError::ConversionError(
+            "cannot convert range value to target type".into(),
+        ))
+    }
+
     /// Convert from a DATE value
     ///
     /// # Errors
@@ -347,6 +369,19 @@ pub trait FromPostgresValue: Sized {
         ))
     }
 
+    /// Convert from a PostGIS GEOMETRY value
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::ConversionError`] if the target type cannot represent
+    /// the decoded geometry.
+    #[cfg(feature = "geo-types")]
+    fn from_postgres_geometry(value: geo_types::Geometry<f64>) -> Result<Self, DrizzleError> {
+        Err(DrizzleError::ConversionError(
+            format!("cannot convert GEOMETRY {value:?} to target type").into(),
+        ))
+    }
+
     /// Convert from a BIT/VARBIT value
     ///
     /// # Errors
@@ -358,6 +393,21 @@ pub trait FromPostgresValue: Sized {
             format!("cannot convert BITVEC {value:?} to target type").into(),
         ))
     }
+
+    /// Convert from a pgvector VECTOR value
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::ConversionError`] if the target type cannot represent a vector.
+    fn from_postgres_vector(value: Vec<f32>) -> Result<Self, DrizzleError> {
+        Err(DrizzleError::ConversionError(
+            format!(
+                "cannot convert VECTOR of {} dimensions to target type",
+                value.len()
+            )
+            .into(),
+        ))
+    }
 }
 
 /// Row capability for index-based extraction.
@@ -1227,15 +1277,32 @@ impl<T: FromPostgresValue> FromPostgresValue for Option<T> {
         T::from_postgres_rect(value).map(Some)
     }
 
+    #[cfg(feature = "geo-types")]
+    fn from_postgres_geometry(value: geo_types::Geometry<f64>) -> Result<Self, DrizzleError> {
+        T::from_postgres_geometry(value).map(Some)
+    }
+
     #[cfg(feature = "bit-vec")]
     fn from_postgres_bitvec(value: bit_vec::BitVec) -> Result<Self, DrizzleError> {
         T::from_postgres_bitvec(value).map(Some)
     }
 
+    fn from_postgres_vector(value: Vec<f32>) -> Result<Self, DrizzleError> {
+        T::from_postgres_vector(value).map(Some)
+    }
+
     fn from_postgres_array(value: Vec<PostgresValue<'_>>) -> Result<Self, DrizzleError> {
         T::from_postgres_array(value).map(Some)
     }
 
+    fn from_postgres_composite(value: Vec<PostgresValue<'_>>) -> Result<Self, DrizzleError> {
+        T::from_postgres_composite(value).map(Some)
+    }
+
+    fn from_postgres_range(value: PgRange<PostgresValue<'_>>) -> Result<Self, DrizzleError> {
+        T::from_postgres_range(value).map(Some)
+    }
+
     fn from_postgres_null() -> Result<Self, DrizzleError> {
         Ok(None)
     }
@@ -1255,6 +1322,11 @@ mod postgres_row_impl {
         DrizzleError, DrizzleRowByIndex, DrizzleRowByName, FromPostgresValue, PostgresValue,
         String, Vec,
     };
+    #[cfg(feature = "geo-types")]
+    use crate::values::GeometryWkb;
+    #[cfg(feature = "cidr")]
+    use crate::values::MacAddrBytes;
+    use crate::values::VectorF32;
 
     // Helper function to convert a row value to our type
     // This uses the native driver's try_get functionality
@@ -1276,6 +1348,20 @@ mod postgres_row_impl {
         T::from_postgres_null()
     }
 
+    /// Records the column a failed decode was reading, with its Postgres type.
+    fn decode_error<T>(
+        error: DrizzleError,
+        idx: usize,
+        column: Option<(&str, &str)>,
+    ) -> DrizzleError {
+        error.decode_column(
+            idx,
+            column.map(|(name, _)| name),
+            &drizzle_core::error::type_name::<T>(),
+            column.map(|(_, ty)| ty),
+        )
+    }
+
     /// `PostgreSQL` OID fast-path: when the column's declared type matches a
     /// known primitive OID, decode directly without running the full fallback
     /// chain.
@@ -1342,6 +1428,7 @@ mod postgres_row_impl {
             .or_else(|| try_scalar_cidr::<T, R>(row, column))
             .or_else(|| try_scalar_geo::<T, R>(row, column))
             .or_else(|| try_scalar_bitvec::<T, R>(row, column))
+            .or_else(|| try_scalar_vector::<T, R>(row, column))
     }
 
     /// Primitive scalar fallbacks: bool / integers / floats / text / bytes.
@@ -1484,6 +1571,9 @@ mod postgres_row_impl {
         if let Ok(Some(v)) = row.try_get_rect(column) {
             return Some(T::from_postgres_rect(v));
         }
+        if let Ok(Some(v)) = row.try_get_geometry(column) {
+            return Some(T::from_postgres_geometry(v));
+        }
         None
     }
 
@@ -1514,6 +1604,16 @@ mod postgres_row_impl {
         None
     }
 
+    fn try_scalar_vector<T: FromPostgresValue, R: PostgresRowLike>(
+        row: &R,
+        column: &impl ColumnRef,
+    ) -> Option<Result<T, DrizzleError>> {
+        row.try_get_vector(column)
+            .ok()
+            .flatten()
+            .map(T::from_postgres_vector)
+    }
+
     /// Array fallback chain: try each supported array element type in priority
     /// order.
     fn try_array_fallbacks<T: FromPostgresValue, R: PostgresRowLike>(
@@ -1743,40 +1843,8 @@ mod postgres_row_impl {
     }
 
     #[cfg(feature = "cidr")]
-    fn parse_mac<const N: usize>(value: &str) -> Option<[u8; N]> {
-        let mut bytes = [0u8; N];
-        let mut parts = value.split(':');
-
-        for slot in &mut bytes {
-            let part = parts.next()?;
-            if part.len() != 2 {
-                return None;
-            }
-            *slot = u8::from_str_radix(part, 16).ok()?;
-        }
-
-        if parts.next().is_some() {
-            return None;
-        }
-
-        Some(bytes)
-    }
-
-    #[cfg(feature = "cidr")]
-    fn parse_mac_array<const N: usize>(
-        values: Vec<Option<String>>,
-    ) -> Result<Vec<Option<[u8; N]>>, ()> {
-        let mut parsed = Vec::with_capacity(values.len());
-        for value in values {
-            match value {
-                Some(value) => {
-                    let parsed_value = parse_mac::<N>(&value).ok_or(())?;
-                    parsed.push(Some(parsed_value));
-                }
-                None => parsed.push(None),
-            }
-        }
-        Ok(parsed)
+    fn mac_array<const N: usize>(values: Vec<Option<MacAddrBytes<N>>>) -> Vec<Option<[u8; N]>> {
+        values.into_iter().map(|mac| mac.map(|mac| mac.0)).collect()
     }
 
     /// Resolve a `ColumnRef` to either an index-based or name-based `try_get` call
@@ -1841,8 +1909,14 @@ mod postgres_row_impl {
         #[cfg(feature = "geo-types")]
         fn try_get_rect(&self, column: &impl ColumnRef)
         -> Result<Option<geo_types::Rect<f64>>, ()>;
+        #[cfg(feature = "geo-types")]
+        fn try_get_geometry(
+            &self,
+            column: &impl ColumnRef,
+        ) -> Result<Option<geo_types::Geometry<f64>>, ()>;
         #[cfg(feature = "bit-vec")]
         fn try_get_bitvec(&self, column: &impl ColumnRef) -> Result<Option<bit_vec::BitVec>, ()>;
+        fn try_get_vector(&self, column: &impl ColumnRef) -> Result<Option<Vec<f32>>, ()>;
 
         fn try_get_array_bool(
             &self,
@@ -2041,20 +2115,12 @@ mod postgres_row_impl {
 
         #[cfg(feature = "cidr")]
         fn try_get_macaddr(&self, column: &impl ColumnRef) -> Result<Option<[u8; 6]>, ()> {
-            match self.try_get_string(column) {
-                Ok(Some(value)) => parse_mac::<6>(&value).ok_or(()).map(Some),
-                Ok(None) => Ok(None),
-                Err(()) => Err(()),
-            }
+            try_get_typed!(self, column, MacAddrBytes<6>).map(|mac| mac.map(|mac| mac.0))
         }
 
         #[cfg(feature = "cidr")]
         fn try_get_macaddr8(&self, column: &impl ColumnRef) -> Result<Option<[u8; 8]>, ()> {
-            match self.try_get_string(column) {
-                Ok(Some(value)) => parse_mac::<8>(&value).ok_or(()).map(Some),
-                Ok(None) => Ok(None),
-                Err(()) => Err(()),
-            }
+            try_get_typed!(self, column, MacAddrBytes<8>).map(|mac| mac.map(|mac| mac.0))
         }
 
         #[cfg(feature = "geo-types")]
@@ -2081,11 +2147,23 @@ mod postgres_row_impl {
             try_get_typed!(self, column, geo_types::Rect<f64>)
         }
 
+        #[cfg(feature = "geo-types")]
+        fn try_get_geometry(
+            &self,
+            column: &impl ColumnRef,
+        ) -> Result<Option<geo_types::Geometry<f64>>, ()> {
+            try_get_typed!(self, column, GeometryWkb).map(|geometry| geometry.map(|g| g.0))
+        }
+
         #[cfg(feature = "bit-vec")]
         fn try_get_bitvec(&self, column: &impl ColumnRef) -> Result<Option<bit_vec::BitVec>, ()> {
             try_get_typed!(self, column, bit_vec::BitVec)
         }
 
+        fn try_get_vector(&self, column: &impl ColumnRef) -> Result<Option<Vec<f32>>, ()> {
+            try_get_typed!(self, column, VectorF32).map(|vector| vector.map(|v| v.0))
+        }
+
         fn try_get_array_bool(
             &self,
             column: &impl ColumnRef,
@@ -2215,11 +2293,8 @@ mod postgres_row_impl {
             &self,
             column: &impl ColumnRef,
         ) -> Result<Option<Vec<Option<[u8; 6]>>>, ()> {
-            match self.try_get_array_text(column) {
-                Ok(Some(values)) => parse_mac_array::<6>(values).map(Some),
-                Ok(None) => Ok(None),
-                Err(()) => Err(()),
-            }
+            try_get_typed!(self, column, Vec<Option<MacAddrBytes<6>>>)
+                .map(|values| values.map(mac_array))
         }
 
         #[cfg(feature = "cidr")]
@@ -2227,11 +2302,8 @@ mod postgres_row_impl {
             &self,
             column: &impl ColumnRef,
         ) -> Result<Option<Vec<Option<[u8; 8]>>>, ()> {
-            match self.try_get_array_text(column) {
-                Ok(Some(values)) => parse_mac_array::<8>(values).map(Some),
-                Ok(None) => Ok(None),
-                Err(()) => Err(()),
-            }
+            try_get_typed!(self, column, Vec<Option<MacAddrBytes<8>>>)
+                .map(|values| values.map(mac_array))
         }
 
         #[cfg(feature = "geo-types")]
@@ -2270,14 +2342,33 @@ mod postgres_row_impl {
     #[cfg(feature = "tokio-postgres")]
     impl DrizzleRowByIndex for tokio_postgres::Row {
         fn get_column<T: FromPostgresValue>(&self, idx: usize) -> Result<T, DrizzleError> {
-            convert_column(self, idx)
+            convert_column(self, idx).map_err(|e| {
+                decode_error::<T>(
+                    e,
+                    idx,
+                    self.columns()
+                        .get(idx)
+                        .map(|c| (c.name(), c.type_().name())),
+                )
+            })
         }
     }
 
     #[cfg(feature = "tokio-postgres")]
     impl DrizzleRowByName for tokio_postgres::Row {
         fn get_column_by_name<T: FromPostgresValue>(&self, name: &str) -> Result<T, DrizzleError> {
-            convert_column(self, name)
+            convert_column(self, name).map_err(|e| {
+                match self.columns().iter().position(|c| c.name() == name) {
+                    Some(idx) => decode_error::<T>(
+                        e,
+                        idx,
+                        self.columns()
+                            .get(idx)
+                            .map(|c| (c.name(), c.type_().name())),
+                    ),
+                    None => e,
+                }
+            })
         }
     }
 
@@ -2376,20 +2467,12 @@ mod postgres_row_impl {
 
         #[cfg(feature = "cidr")]
         fn try_get_macaddr(&self, column: &impl ColumnRef) -> Result<Option<[u8; 6]>, ()> {
-            match self.try_get_string(column) {
-                Ok(Some(value)) => parse_mac::<6>(&value).ok_or(()).map(Some),
-                Ok(None) => Ok(None),
-                Err(()) => Err(()),
-            }
+            try_get_typed!(self, column, MacAddrBytes<6>).map(|mac| mac.map(|mac| mac.0))
         }
 
         #[cfg(feature = "cidr")]
         fn try_get_macaddr8(&self, column: &impl ColumnRef) -> Result<Option<[u8; 8]>, ()> {
-            match self.try_get_string(column) {
-                Ok(Some(value)) => parse_mac::<8>(&value).ok_or(()).map(Some),
-                Ok(None) => Ok(None),
-                Err(()) => Err(()),
-            }
+            try_get_typed!(self, column, MacAddrBytes<8>).map(|mac| mac.map(|mac| mac.0))
         }
 
         #[cfg(feature = "geo-types")]
@@ -2416,11 +2499,23 @@ mod postgres_row_impl {
             try_get_typed!(self, column, geo_types::Rect<f64>)
         }
 
+        #[cfg(feature = "geo-types")]
+        fn try_get_geometry(
+            &self,
+            column: &impl ColumnRef,
+        ) -> Result<Option<geo_types::Geometry<f64>>, ()> {
+            try_get_typed!(self, column, GeometryWkb).map(|geometry| geometry.map(|g| g.0))
+        }
+
         #[cfg(feature = "bit-vec")]
         fn try_get_bitvec(&self, column: &impl ColumnRef) -> Result<Option<bit_vec::BitVec>, ()> {
             try_get_typed!(self, column, bit_vec::BitVec)
         }
 
+        fn try_get_vector(&self, column: &impl ColumnRef) -> Result<Option<Vec<f32>>, ()> {
+            try_get_typed!(self, column, VectorF32).map(|vector| vector.map(|v| v.0))
+        }
+
         fn try_get_array_bool(
             &self,
             column: &impl ColumnRef,
@@ -2550,11 +2645,8 @@ mod postgres_row_impl {
             &self,
             column: &impl ColumnRef,
         ) -> Result<Option<Vec<Option<[u8; 6]>>>, ()> {
-            match self.try_get_array_text(column) {
-                Ok(Some(values)) => parse_mac_array::<6>(values).map(Some),
-                Ok(None) => Ok(None),
-                Err(()) => Err(()),
-            }
+            try_get_typed!(self, column, Vec<Option<MacAddrBytes<6>>>)
+                .map(|values| values.map(mac_array))
         }
 
         #[cfg(feature = "cidr")]
@@ -2562,11 +2654,8 @@ mod postgres_row_impl {
             &self,
             column: &impl ColumnRef,
         ) -> Result<Option<Vec<Option<[u8; 8]>>>, ()> {
-            match self.try_get_array_text(column) {
-                Ok(Some(values)) => parse_mac_array::<8>(values).map(Some),
-                Ok(None) => Ok(None),
-                Err(()) => Err(()),
-            }
+            try_get_typed!(self, column, Vec<Option<MacAddrBytes<8>>>)
+                .map(|values| values.map(mac_array))
         }
 
         #[cfg(feature = "geo-types")]
@@ -2605,14 +2694,33 @@ mod postgres_row_impl {
     #[cfg(all(feature = "postgres-sync", not(feature = "tokio-postgres")))]
     impl DrizzleRowByIndex for postgres::Row {
         fn get_column<T: FromPostgresValue>(&self, idx: usize) -> Result<T, DrizzleError> {
-            convert_column(self, idx)
+            convert_column(self, idx).map_err(|e| {
+                decode_error::<T>(
+                    e,
+                    idx,
+                    self.columns()
+                        .get(idx)
+                        .map(|c| (c.name(), c.type_().name())),
+                )
+            })
         }
     }
 
     #[cfg(all(feature = "postgres-sync", not(feature = "tokio-postgres")))]
     impl DrizzleRowByName for postgres::Row {
         fn get_column_by_name<T: FromPostgresValue>(&self, name: &str) -> Result<T, DrizzleError> {
-            convert_column(self, name)
+            convert_column(self, name).map_err(|e| {
+                match self.columns().iter().position(|c| c.name() == name) {
+                    Some(idx) => decode_error::<T>(
+                        e,
+                        idx,
+                        self.columns()
+                            .get(idx)
+                            .map(|c| (c.name(), c.type_().name())),
+                    ),
+                    None => e,
+                }
+            })
         }
     }
 }
@@ -2800,6 +2908,21 @@ impl FromPostgresValue for Vec<PostgresValue<'_>> {
     }
 }
 
+impl FromPostgresValue for Vec<f32> {
+    impl_from_postgres_value_errors!("Vec<f32>");
+
+    fn from_postgres_vector(value: Vec<f32>) -> Result<Self, DrizzleError> {
+        Ok(value)
+    }
+
+    fn from_postgres_array(value: Vec<PostgresValue<'_>>) -> Result<Self, DrizzleError> {
+        value
+            .into_iter()
+            .map(PostgresValue::convert::<f32>)
+            .collect()
+    }
+}
+
 impl FromPostgresValue for Vec<OwnedPostgresValue> {
     impl_from_postgres_value_errors!("ARRAY");
 
@@ -2987,6 +3110,10 @@ impl FromPostgresValue for geo_types::Point<f64> {
     fn from_postgres_point(value: geo_types::Point<f64>) -> Result<Self, DrizzleError> {
         Ok(value)
     }
+
+    fn from_postgres_geometry(value: geo_types::Geometry<f64>) -> Result<Self, DrizzleError> {
+        geometry_shape(value, "Point")
+    }
 }
 
 #[cfg(feature = "geo-types")]
@@ -2996,6 +3123,10 @@ impl FromPostgresValue for geo_types::LineString<f64> {
     fn from_postgres_linestring(value: geo_types::LineString<f64>) -> Result<Self, DrizzleError> {
         Ok(value)
     }
+
+    fn from_postgres_geometry(value: geo_types::Geometry<f64>) -> Result<Self, DrizzleError> {
+        geometry_shape(value, "LineString")
+    }
 }
 
 #[cfg(feature = "geo-types")]
@@ -3007,6 +3138,57 @@ impl FromPostgresValue for geo_types::Rect<f64> {
     }
 }
 
+/// Narrows a decoded PostGIS geometry to one concrete shape.
+#[cfg(feature = "geo-types")]
+fn geometry_shape<T>(value: geo_types::Geometry<f64>, target: &str) -> Result<T, DrizzleError>
+where
+    T: TryFrom<geo_types::Geometry<f64>>,
+{
+    T::try_from(value).map_err(|_| {
+        DrizzleError::ConversionError(format!("cannot convert GEOMETRY to {target}").into())
+    })
+}
+
+#[cfg(feature = "geo-types")]
+impl FromPostgresValue for geo_types::Geometry<f64> {
+    impl_from_postgres_value_errors!("Geometry");
+
+    fn from_postgres_point(value: geo_types::Point<f64>) -> Result<Self, DrizzleError> {
+        Ok(value.into())
+    }
+
+    fn from_postgres_linestring(value: geo_types::LineString<f64>) -> Result<Self, DrizzleError> {
+        Ok(value.into())
+    }
+
+    fn from_postgres_rect(value: geo_types::Rect<f64>) -> Result<Self, DrizzleError> {
+        Ok(value.into())
+    }
+
+    fn from_postgres_geometry(value: geo_types::Geometry<f64>) -> Result<Self, DrizzleError> {
+        Ok(value)
+    }
+}
+
+macro_rules! impl_from_postgres_geometry_shape {
+    ($($ty:ident),+ $(,)?) => {
+        $(
+            #[cfg(feature = "geo-types")]
+            impl FromPostgresValue for geo_types::$ty<f64> {
+                impl_from_postgres_value_errors!(stringify!($ty));
+
+                fn from_postgres_geometry(
+                    value: geo_types::Geometry<f64>,
+                ) -> Result<Self, DrizzleError> {
+                    geometry_shape(value, stringify!($ty))
+                }
+            }
+        )+
+    };
+}
+
+impl_from_postgres_geometry_shape!(Polygon, MultiPoint, MultiLineString, MultiPolygon);
+
 // =============================================================================
 // Bit string types (when feature enabled)
 // =============================================================================
@@ -19,7 +19,9 @@ use time::{
 use cidr::{IpCidr, IpInet};
 
 #[cfg(feature = "geo-types")]
-use geo_types::{LineString, Point, Rect};
+use geo_types::{
+    Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect,
+};
 
 #[cfg(feature = "bit-vec")]
 use bit_vec::BitVec;
@@ -753,6 +755,76 @@ impl<'a> From<&'a Rect<f64>> for PostgresValue<'a> {
     }
 }
 
+#[cfg(feature = "geo-types")]
+impl From<Geometry<f64>> for PostgresValue<'_> {
+    fn from(value: Geometry<f64>) -> Self {
+        PostgresValue::Geometry(value)
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<'a> From<&'a Geometry<f64>> for PostgresValue<'a> {
+    fn from(value: &'a Geometry<f64>) -> Self {
+        PostgresValue::Geometry(value.clone())
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl From<Polygon<f64>> for PostgresValue<'_> {
+    fn from(value: Polygon<f64>) -> Self {
+        PostgresValue::Geometry(value.into())
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<'a> From<&'a Polygon<f64>> for PostgresValue<'a> {
+    fn from(value: &'a Polygon<f64>) -> Self {
+        PostgresValue::Geometry(value.clone().into())
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl From<MultiPoint<f64>> for PostgresValue<'_> {
+    fn from(value: MultiPoint<f64>) -> Self {
+        PostgresValue::Geometry(value.into())
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<'a> From<&'a MultiPoint<f64>> for PostgresValue<'a> {
+    fn from(value: &'a MultiPoint<f64>) -> Self {
+        PostgresValue::Geometry(value.clone().into())
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl From<MultiLineString<f64>> for PostgresValue<'_> {
+    fn from(value: MultiLineString<f64>) -> Self {
+        PostgresValue::Geometry(value.into())
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<'a> From<&'a MultiLineString<f64>> for PostgresValue<'a> {
+    fn from(value: &'a MultiLineString<f64>) -> Self {
+        PostgresValue::Geometry(value.clone().into())
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl From<MultiPolygon<f64>> for PostgresValue<'_> {
+    fn from(value: MultiPolygon<f64>) -> Self {
+        PostgresValue::Geometry(value.into())
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<'a> From<&'a MultiPolygon<f64>> for PostgresValue<'a> {
+    fn from(value: &'a MultiPolygon<f64>) -> Self {
+        PostgresValue::Geometry(value.clone().into())
+    }
+}
+
 // --- Bit String Types ---
 
 #[cfg(feature = "bit-vec")]
@@ -1492,6 +1564,7 @@ impl<'a> TryFrom<PostgresValue<'a>> for Point<f64> {
     fn try_from(value: PostgresValue<'a>) -> Result<Self, Self::Error> {
         match value {
             PostgresValue::Point(point) => Ok(point),
+            PostgresValue::Geometry(Geometry::Point(point)) => Ok(point),
             _ => Err(DrizzleError::ConversionError(
                 format!("Cannot convert {value:?} to Point").into(),
             )),
@@ -1506,6 +1579,7 @@ impl<'a> TryFrom<PostgresValue<'a>> for LineString<f64> {
     fn try_from(value: PostgresValue<'a>) -> Result<Self, Self::Error> {
         match value {
             PostgresValue::LineString(line) => Ok(line),
+            PostgresValue::Geometry(Geometry::LineString(line)) => Ok(line),
             _ => Err(DrizzleError::ConversionError(
                 format!("Cannot convert {value:?} to LineString").into(),
             )),
@@ -1527,6 +1601,79 @@ impl<'a> TryFrom<PostgresValue<'a>> for Rect<f64> {
     }
 }
 
+#[cfg(feature = "geo-types")]
+impl<'a> TryFrom<PostgresValue<'a>> for Geometry<f64> {
+    type Error = DrizzleError;
+
+    fn try_from(value: PostgresValue<'a>) -> Result<Self, Self::Error> {
+        match value {
+            PostgresValue::Geometry(geometry) => Ok(geometry),
+            PostgresValue::Point(point) => Ok(point.into()),
+            PostgresValue::LineString(line) => Ok(line.into()),
+            PostgresValue::Rect(rect) => Ok(rect.into()),
+            _ => Err(DrizzleError::ConversionError(
+                format!("Cannot convert {value:?} to Geometry").into(),
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<'a> TryFrom<PostgresValue<'a>> for Polygon<f64> {
+    type Error = DrizzleError;
+
+    fn try_from(value: PostgresValue<'a>) -> Result<Self, Self::Error> {
+        match value {
+            PostgresValue::Geometry(Geometry::Polygon(shape)) => Ok(shape),
+            _ => Err(DrizzleError::ConversionError(
+                format!("Cannot convert {value:?} to Polygon").into(),
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<'a> TryFrom<PostgresValue<'a>> for MultiPoint<f64> {
+    type Error = DrizzleError;
+
+    fn try_from(value: PostgresValue<'a>) -> Result<Self, Self::Error> {
+        match value {
+            PostgresValue::Geometry(Geometry::MultiPoint(shape)) => Ok(shape),
+            _ => Err(DrizzleError::ConversionError(
+                format!("Cannot convert {value:?} to MultiPoint").into(),
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<'a> TryFrom<PostgresValue<'a>> for MultiLineString<f64> {
+    type Error = DrizzleError;
+
+    fn try_from(value: PostgresValue<'a>) -> Result<Self, Self::Error> {
+        match value {
+            PostgresValue::Geometry(Geometry::MultiLineString(shape)) => Ok(shape),
+            _ => Err(DrizzleError::ConversionError(
+                format!("Cannot convert {value:?} to MultiLineString").into(),
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<'a> TryFrom<PostgresValue<'a>> for MultiPolygon<f64> {
+    type Error = DrizzleError;
+
+    fn try_from(value: PostgresValue<'a>) -> Result<Self, Self::Error> {
+        match value {
+            PostgresValue::Geometry(Geometry::MultiPolygon(shape)) => Ok(shape),
+            _ => Err(DrizzleError::ConversionError(
+                format!("Cannot convert {value:?} to MultiPolygon").into(),
+            )),
+        }
+    }
+}
+
 // --- Bit String TryFrom implementations ---
 
 #[cfg(feature = "bit-vec")]
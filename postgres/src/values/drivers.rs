@@ -3,6 +3,164 @@
 #[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
 use super::PostgresValue;
 
+#[cfg(all(
+    feature = "cidr",
+    any(feature = "postgres-sync", feature = "tokio-postgres")
+))]
+pub(crate) use postgres_macaddr_impl::MacAddrBytes;
+
+#[cfg(all(
+    feature = "geo-types",
+    any(feature = "postgres-sync", feature = "tokio-postgres")
+))]
+pub(crate) use postgres_geometry_impl::GeometryWkb;
+
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+pub(crate) use postgres_vector_impl::VectorF32;
+
+//------------------------------------------------------------------------------
+// MACADDR/MACADDR8 wire format
+// postgres-types has no MAC address type without eui48, so encode the raw
+// binary form (6 or 8 bytes) directly.
+//------------------------------------------------------------------------------
+
+#[cfg(all(
+    feature = "cidr",
+    any(feature = "postgres-sync", feature = "tokio-postgres")
+))]
+mod postgres_macaddr_impl {
+    #[cfg(feature = "postgres-sync")]
+    use postgres::types::{FromSql, IsNull, ToSql, Type};
+
+    #[cfg(all(feature = "tokio-postgres", not(feature = "postgres-sync")))]
+    use tokio_postgres::types::{FromSql, IsNull, ToSql, Type};
+
+    use bytes::BytesMut;
+
+    type BoxError = Box<dyn std::error::Error + Sync + Send>;
+
+    /// A `macaddr` (`N = 6`) or `macaddr8` (`N = 8`) value.
+    #[derive(Debug, Clone, Copy)]
+    pub(crate) struct MacAddrBytes<const N: usize>(pub(crate) [u8; N]);
+
+    impl<const N: usize> MacAddrBytes<N> {
+        fn accepts_type(ty: &Type) -> bool {
+            match N {
+                6 => *ty == Type::MACADDR,
+                8 => *ty == Type::MACADDR8,
+                _ => false,
+            }
+        }
+    }
+
+    impl<'a, const N: usize> FromSql<'a> for MacAddrBytes<N> {
+        fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+            <[u8; N]>::try_from(raw).map(Self).map_err(|_| {
+                format!("expected {N} bytes for a MAC address, got {}", raw.len()).into()
+            })
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            Self::accepts_type(ty)
+        }
+    }
+
+    impl<const N: usize> ToSql for MacAddrBytes<N> {
+        fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+            out.extend_from_slice(&self.0);
+            Ok(IsNull::No)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            Self::accepts_type(ty)
+        }
+
+        #[cfg(feature = "postgres-sync")]
+        postgres::types::to_sql_checked!();
+
+        #[cfg(all(feature = "tokio-postgres", not(feature = "postgres-sync")))]
+        tokio_postgres::types::to_sql_checked!();
+    }
+}
+
+//------------------------------------------------------------------------------
+// PostGIS GEOMETRY/GEOGRAPHY wire format
+// PostGIS is an extension, so its types have no fixed OID; match on the type
+// name instead. The binary send/receive format is (E)WKB.
+//------------------------------------------------------------------------------
+
+#[cfg(all(
+    feature = "geo-types",
+    any(feature = "postgres-sync", feature = "tokio-postgres")
+))]
+mod postgres_geometry_impl {
+    #[cfg(feature = "postgres-sync")]
+    use postgres::types::{FromSql, Type};
+
+    #[cfg(all(feature = "tokio-postgres", not(feature = "postgres-sync")))]
+    use tokio_postgres::types::{FromSql, Type};
+
+    type BoxError = Box<dyn std::error::Error + Sync + Send>;
+
+    /// A decoded PostGIS `geometry` or `geography` value.
+    #[derive(Debug, Clone)]
+    pub(crate) struct GeometryWkb(pub(crate) geo_types::Geometry<f64>);
+
+    impl GeometryWkb {
+        pub(crate) fn accepts_type(ty: &Type) -> bool {
+            matches!(ty.name(), "geometry" | "geography")
+        }
+    }
+
+    impl FromSql<'_> for GeometryWkb {
+        fn from_sql(_ty: &Type, raw: &[u8]) -> Result<Self, BoxError> {
+            let (geometry, _srid) = drizzle_core::wkb::decode(raw)?;
+            Ok(Self(geometry))
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            Self::accepts_type(ty)
+        }
+    }
+}
+
+//------------------------------------------------------------------------------
+// pgvector VECTOR wire format
+// Like PostGIS, pgvector is an extension matched by type name. The binary
+// format is an i16 dimension count, an unused i16, then big-endian f32s.
+//------------------------------------------------------------------------------
+
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+mod postgres_vector_impl {
+    #[cfg(feature = "postgres-sync")]
+    use postgres::types::{FromSql, Type};
+
+    #[cfg(all(feature = "tokio-postgres", not(feature = "postgres-sync")))]
+    use tokio_postgres::types::{FromSql, Type};
+
+    type BoxError = Box<dyn std::error::Error + Sync + Send>;
+
+    /// A decoded pgvector `vector` value.
+    #[derive(Debug, Clone)]
+    pub(crate) struct VectorF32(pub(crate) Vec<f32>);
+
+    impl VectorF32 {
+        pub(crate) fn accepts_type(ty: &Type) -> bool {
+            ty.name() == "vector"
+        }
+    }
+
+    impl FromSql<'_> for VectorF32 {
+        fn from_sql(_ty: &Type, raw: &[u8]) -> Result<Self, BoxError> {
+            Ok(Self(drizzle_core::vector::decode_pgvector(raw)?))
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            Self::accepts_type(ty)
+        }
+    }
+}
+
 //------------------------------------------------------------------------------
 // postgres/tokio-postgres ToSql implementations
 // The two drivers expose the same ToSql contract, so one implementation covers both.
@@ -10,7 +168,14 @@ use super::PostgresValue;
 
 #[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
 mod postgres_tosql_impl {
-    use super::PostgresValue;
+    #[cfg(feature = "cidr")]
+    use super::MacAddrBytes;
+    use super::{PostgresValue, VectorF32};
+    use crate::values::PgRange;
+    use crate::values::range::{
+        RANGE_EMPTY, RANGE_LB_INC, RANGE_LB_INF, RANGE_UB_INC, RANGE_UB_INF,
+    };
+    use core::ops::Bound;
 
     // Import from whichever crate is available
     #[cfg(feature = "postgres-sync")]
@@ -211,6 +376,18 @@ mod postgres_tosql_impl {
         if *ty == Type::CIDR_ARRAY {
             return encode_array!(arr, ty, out, Cidr, cidr::IpCidr, |v: &cidr::IpCidr| *v);
         }
+        #[cfg(feature = "cidr")]
+        if *ty == Type::MACADDR_ARRAY {
+            return encode_array!(arr, ty, out, MacAddr, MacAddrBytes<6>, |v: &[u8; 6]| {
+                MacAddrBytes(*v)
+            });
+        }
+        #[cfg(feature = "cidr")]
+        if *ty == Type::MACADDR8_ARRAY {
+            return encode_array!(arr, ty, out, MacAddr8, MacAddrBytes<8>, |v: &[u8; 8]| {
+                MacAddrBytes(*v)
+            });
+        }
         #[cfg(feature = "bit-vec")]
         if *ty == Type::VARBIT_ARRAY || *ty == Type::BIT_ARRAY {
             return encode_array!(
@@ -235,6 +412,105 @@ mod postgres_tosql_impl {
         values.to_sql(ty, out)
     }
 
+    fn composite_to_sql(
+        fields: &[PostgresValue<'_>],
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        let Kind::Composite(type_fields) = ty.kind() else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("cannot encode PostgreSQL composite value as {}", ty.name()),
+            )
+            .into());
+        };
+        if type_fields.len() != fields.len() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "composite {} has {} attributes, got {} values",
+                    ty.name(),
+                    type_fields.len(),
+                    fields.len()
+                ),
+            )
+            .into());
+        }
+
+        // Binary record format: attribute count, then per attribute its type
+        // OID, byte length (-1 for NULL), and encoded value.
+        out.extend_from_slice(&i32::try_from(fields.len())?.to_be_bytes());
+        for (value, field) in fields.iter().zip(type_fields) {
+            out.extend_from_slice(&field.type_().oid().to_be_bytes());
+            let len_at = out.len();
+            out.extend_from_slice(&0i32.to_be_bytes());
+            let len = match value.to_sql(field.type_(), out)? {
+                IsNull::Yes => -1,
+                IsNull::No => i32::try_from(out.len() - len_at - 4)?,
+            };
+            out[len_at..len_at + 4].copy_from_slice(&len.to_be_bytes());
+        }
+        Ok(IsNull::No)
+    }
+
+    fn range_bound_to_sql(
+        bound: &Bound<PostgresValue<'_>>,
+        element: &Type,
+        out: &mut BytesMut,
+    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        let (Bound::Included(value) | Bound::Excluded(value)) = bound else {
+            return Ok(());
+        };
+        let len_at = out.len();
+        out.extend_from_slice(&0i32.to_be_bytes());
+        if matches!(value.to_sql(element, out)?, IsNull::Yes) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "range bounds cannot be NULL; use an unbounded bound instead",
+            )
+            .into());
+        }
+        let len = i32::try_from(out.len() - len_at - 4)?;
+        out[len_at..len_at + 4].copy_from_slice(&len.to_be_bytes());
+        Ok(())
+    }
+
+    fn range_to_sql(
+        range: &PgRange<PostgresValue<'_>>,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        let Kind::Range(element) = ty.kind() else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("cannot encode PostgreSQL range value as {}", ty.name()),
+            )
+            .into());
+        };
+        let PgRange::Bounds { lower, upper } = range else {
+            out.extend_from_slice(&[RANGE_EMPTY]);
+            return Ok(IsNull::No);
+        };
+
+        // Binary range format: a flags byte, then the length-prefixed finite
+        // bounds, lower first.
+        let mut flags = 0;
+        match lower {
+            Bound::Included(_) => flags |= RANGE_LB_INC,
+            Bound::Excluded(_) => {}
+            Bound::Unbounded => flags |= RANGE_LB_INF,
+        }
+        match upper {
+            Bound::Included(_) => flags |= RANGE_UB_INC,
+            Bound::Excluded(_) => {}
+            Bound::Unbounded => flags |= RANGE_UB_INF,
+        }
+        out.extend_from_slice(&[flags]);
+        range_bound_to_sql(lower, element, out)?;
+        range_bound_to_sql(upper, element, out)?;
+        Ok(IsNull::No)
+    }
+
     impl ToSql for PostgresValue<'_> {
         fn to_sql(
             &self,
@@ -283,19 +559,10 @@ mod postgres_tosql_impl {
                 PostgresValue::Inet(ip) => ip.to_sql(ty, out),
                 #[cfg(feature = "cidr")]
                 PostgresValue::Cidr(ip) => ip.to_sql(ty, out),
-                // MAC addresses don't have native ToSql in postgres-rs, use eui48 crate or string format
                 #[cfg(feature = "cidr")]
-                PostgresValue::MacAddr(mac) => format!(
-                    "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
-                    mac[0], mac[1], mac[2], mac[3], mac[4], mac[5]
-                )
-                .to_sql(ty, out),
+                PostgresValue::MacAddr(mac) => MacAddrBytes(*mac).to_sql(ty, out),
                 #[cfg(feature = "cidr")]
-                PostgresValue::MacAddr8(mac) => format!(
-                    "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
-                    mac[0], mac[1], mac[2], mac[3], mac[4], mac[5], mac[6], mac[7]
-                )
-                .to_sql(ty, out),
+                PostgresValue::MacAddr8(mac) => MacAddrBytes(*mac).to_sql(ty, out),
                 // Point has native ToSql in postgres-rs with geo-types feature
                 #[cfg(feature = "geo-types")]
                 PostgresValue::Point(p) => p.to_sql(ty, out),
@@ -305,10 +572,28 @@ mod postgres_tosql_impl {
                 // Rect maps to BOX in postgres, which has native support
                 #[cfg(feature = "geo-types")]
                 PostgresValue::Rect(rect) => rect.to_sql(ty, out),
+                // PostGIS reads WKB for GEOMETRY parameters; an SRID-less
+                // value takes on the SRID declared by the column
+                #[cfg(feature = "geo-types")]
+                PostgresValue::Geometry(geometry) => {
+                    out.extend_from_slice(&drizzle_core::wkb::encode(geometry, None));
+                    Ok(IsNull::No)
+                }
                 #[cfg(feature = "bit-vec")]
                 PostgresValue::BitVec(bits) => bits.to_sql(ty, out),
+                // Fall back to a REAL[] when the parameter isn't a VECTOR
+                PostgresValue::Vector(vector) => {
+                    if VectorF32::accepts_type(ty) {
+                        out.extend_from_slice(&drizzle_core::vector::encode_pgvector(vector));
+                        Ok(IsNull::No)
+                    } else {
+                        vector.to_sql(ty, out)
+                    }
+                }
                 PostgresValue::Enum(enum_val) => enum_val.variant_name().to_sql(ty, out),
                 PostgresValue::Array(arr) => array_to_sql(arr, ty, out),
+                PostgresValue::Composite(fields) => composite_to_sql(fields, ty, out),
+                PostgresValue::Range(range) => range_to_sql(range, ty, out),
             }
         }
 
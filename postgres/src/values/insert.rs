@@ -45,6 +45,54 @@ pub enum PostgresInsertValue<'a, V: SQLParam, T> {
 }
 
 impl<'a, T> PostgresInsertValue<'a, PostgresValue<'a>, T> {
+    /// Pushes `column` onto `columns` unless this value is omitted.
+    ///
+    /// Used by generated `SQLModel::columns` so each field expands to one call.
+    #[doc(hidden)]
+    #[inline]
+    pub fn push_column(
+        &self,
+        columns: &mut Vec<drizzle_core::ColumnRef>,
+        column: drizzle_core::ColumnRef,
+    ) {
+        if !matches!(self, Self::Omit) {
+            columns.push(column);
+        }
+    }
+
+    /// Pushes this value's SQL onto `values` unless it is omitted.
+    ///
+    /// Used by generated `SQLModel::values` so each field expands to one call.
+    #[doc(hidden)]
+    #[inline]
+    pub fn push_value(&self, values: &mut Vec<SQL<'a, PostgresValue<'a>>>) {
+        match self {
+            Self::Omit => {}
+            Self::Null => values.push(SQL::param(PostgresValue::Null)),
+            Self::Value(wrapper) => values.push(wrapper.value.clone()),
+        }
+    }
+
+    /// Like [`push_value`](Self::push_value), re-tagging each bound value
+    /// with `retag`.
+    ///
+    /// Used for extension-typed columns, e.g. [`PostgresValue::into_geometry`]
+    /// so PostGIS values bind as WKB, or [`PostgresValue::into_vector`] so
+    /// embeddings bind as pgvector values rather than `REAL[]`.
+    #[doc(hidden)]
+    #[inline]
+    pub fn push_value_as(
+        &self,
+        values: &mut Vec<SQL<'a, PostgresValue<'a>>>,
+        retag: fn(PostgresValue<'a>) -> PostgresValue<'a>,
+    ) {
+        match self {
+            Self::Omit => {}
+            Self::Null => values.push(SQL::param(PostgresValue::Null)),
+            Self::Value(wrapper) => values.push(wrapper.value.clone().map_params(retag)),
+        }
+    }
+
     /// Converts this `InsertValue` to an owned version with 'static lifetime
     #[must_use]
     pub fn into_owned(self) -> PostgresInsertValue<'static, PostgresValue<'static>, T> {
@@ -4,12 +4,29 @@ mod conversions;
 mod drivers;
 mod insert;
 mod owned;
+mod range;
 mod update;
 
 pub use insert::*;
 pub use owned::*;
+pub use range::PgRange;
 pub use update::*;
 
+#[cfg(all(
+    feature = "cidr",
+    any(feature = "postgres-sync", feature = "tokio-postgres")
+))]
+pub(crate) use drivers::MacAddrBytes;
+
+#[cfg(all(
+    feature = "geo-types",
+    any(feature = "postgres-sync", feature = "tokio-postgres")
+))]
+pub(crate) use drivers::GeometryWkb;
+
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+pub(crate) use drivers::VectorF32;
+
 use drizzle_core::{error::DrizzleError, sql::SQL, traits::SQLParam};
 
 #[cfg(feature = "uuid")]
@@ -27,7 +44,7 @@ use time::{
 use cidr::{IpCidr, IpInet};
 
 #[cfg(feature = "geo-types")]
-use geo_types::{LineString, Point, Rect};
+use geo_types::{Geometry, LineString, Point, Rect};
 
 #[cfg(feature = "bit-vec")]
 use bit_vec::BitVec;
@@ -155,16 +172,30 @@ pub enum PostgresValue<'a> {
     /// BOX values (bounding rectangle)
     #[cfg(feature = "geo-types")]
     Rect(Rect<f64>),
+    /// PostGIS GEOMETRY values, sent as WKB
+    #[cfg(feature = "geo-types")]
+    Geometry(Geometry<f64>),
 
     // Bit string types
     /// BIT, BIT VARYING values
     #[cfg(feature = "bit-vec")]
     BitVec(BitVec),
 
+    /// pgvector VECTOR values (embeddings)
+    Vector(Vec<f32>),
+
     // Array types (using Vec for simplicity)
     /// Array of any `PostgreSQL` type
     Array(Vec<Self>),
 
+    // Composite types
+    /// Attributes of a composite (row) type value, in declaration order
+    Composite(Vec<Self>),
+
+    // Range types
+    /// Range values (`int4range`, `tstzrange`, ...)
+    Range(Box<PgRange<Self>>),
+
     /// NULL value
     #[default]
     Null,
@@ -260,6 +291,16 @@ impl core::fmt::Display for PostgresValue<'_> {
                     rect.max().y
                 )
             }
+            #[cfg(feature = "geo-types")]
+            PostgresValue::Geometry(geometry) => {
+                use core::fmt::Write;
+                let wkb = drizzle_core::wkb::encode(geometry, None);
+                let mut s = String::with_capacity(wkb.len() * 2);
+                for byte in &wkb {
+                    write!(s, "{byte:02x}").expect("writing to String cannot fail");
+                }
+                s
+            }
 
             // Bit string types
             #[cfg(feature = "bit-vec")]
@@ -268,12 +309,23 @@ impl core::fmt::Display for PostgresValue<'_> {
                 .map(|b| if b { '1' } else { '0' })
                 .collect::<String>(),
 
+            PostgresValue::Vector(vector) => vector_literal(vector),
+
             // Array types
             PostgresValue::Array(arr) => {
                 let elements: Vec<String> = arr.iter().map(ToString::to_string).collect();
                 format!("{{{}}}", elements.join(","))
             }
 
+            // Composite types
+            PostgresValue::Composite(fields) => {
+                let elements: Vec<String> = fields.iter().map(ToString::to_string).collect();
+                format!("({})", elements.join(","))
+            }
+
+            // Range types
+            PostgresValue::Range(range) => range.to_string(),
+
             PostgresValue::Null => String::new(),
         };
         write!(f, "{value}")
@@ -554,6 +606,61 @@ impl PostgresValue<'_> {
         }
     }
 
+    /// Returns the geometry value if this is a PostGIS GEOMETRY.
+    #[inline]
+    #[cfg(feature = "geo-types")]
+    #[must_use]
+    pub const fn as_geometry(&self) -> Option<&Geometry<f64>> {
+        match self {
+            PostgresValue::Geometry(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Re-tags a native POINT, PATH or BOX value as a PostGIS GEOMETRY.
+    ///
+    /// Generated models call this for `#[column(geometry(...))]` fields so
+    /// `geo-types` shapes are sent as WKB rather than the built-in types.
+    #[cfg(feature = "geo-types")]
+    #[must_use]
+    pub fn into_geometry(self) -> Self {
+        match self {
+            PostgresValue::Point(point) => PostgresValue::Geometry(point.into()),
+            PostgresValue::LineString(line) => PostgresValue::Geometry(line.into()),
+            PostgresValue::Rect(rect) => PostgresValue::Geometry(rect.into()),
+            other => other,
+        }
+    }
+
+    /// Returns the components if this is a pgvector VECTOR.
+    #[inline]
+    #[must_use]
+    pub const fn as_vector(&self) -> Option<&[f32]> {
+        match self {
+            PostgresValue::Vector(value) => Some(value.as_slice()),
+            _ => None,
+        }
+    }
+
+    /// Re-tags a `REAL[]` array as a pgvector VECTOR.
+    ///
+    /// Generated models call this for `#[column(vector(N))]` fields so
+    /// `Vec<f32>` values are sent in pgvector's format rather than as arrays.
+    /// Arrays holding anything other than `REAL` values are left unchanged.
+    #[must_use]
+    pub fn into_vector(self) -> Self {
+        match self {
+            PostgresValue::Array(values) => {
+                if values.iter().all(|v| matches!(v, PostgresValue::Real(_))) {
+                    PostgresValue::Vector(values.into_iter().filter_map(|v| v.as_f32()).collect())
+                } else {
+                    PostgresValue::Array(values)
+                }
+            }
+            other => other,
+        }
+    }
+
     /// Returns the bit vector if this is BIT/VARBIT.
     #[inline]
     #[cfg(feature = "bit-vec")]
@@ -630,6 +737,26 @@ impl PostgresValue<'_> {
         }
     }
 
+    /// Returns the attribute values if this is a composite value.
+    #[inline]
+    #[must_use]
+    pub fn as_composite(&self) -> Option<&[Self]> {
+        match self {
+            PostgresValue::Composite(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the range if this is a range value.
+    #[inline]
+    #[must_use]
+    pub fn as_range(&self) -> Option<&PgRange<Self>> {
+        match self {
+            PostgresValue::Range(range) => Some(range),
+            _ => None,
+        }
+    }
+
     /// Converts this value into an owned representation.
     #[inline]
     #[must_use]
@@ -699,9 +826,14 @@ impl PostgresValue<'_> {
             PostgresValue::LineString(value) => T::from_postgres_linestring(value),
             #[cfg(feature = "geo-types")]
             PostgresValue::Rect(value) => T::from_postgres_rect(value),
+            #[cfg(feature = "geo-types")]
+            PostgresValue::Geometry(value) => T::from_postgres_geometry(value),
             #[cfg(feature = "bit-vec")]
             PostgresValue::BitVec(value) => T::from_postgres_bitvec(value),
+            PostgresValue::Vector(value) => T::from_postgres_vector(value),
             PostgresValue::Array(value) => T::from_postgres_array(value),
+            PostgresValue::Composite(value) => T::from_postgres_composite(value),
+            PostgresValue::Range(value) => T::from_postgres_range(*value),
             PostgresValue::Null => T::from_postgres_null(),
         }
     }
@@ -768,15 +900,26 @@ impl PostgresValue<'_> {
             PostgresValue::LineString(value) => T::from_postgres_linestring(value.clone()),
             #[cfg(feature = "geo-types")]
             PostgresValue::Rect(value) => T::from_postgres_rect(*value),
+            #[cfg(feature = "geo-types")]
+            PostgresValue::Geometry(value) => T::from_postgres_geometry(value.clone()),
             #[cfg(feature = "bit-vec")]
             PostgresValue::BitVec(value) => T::from_postgres_bitvec(value.clone()),
+            PostgresValue::Vector(value) => T::from_postgres_vector(value.clone()),
             PostgresValue::Array(value) => T::from_postgres_array(value.clone()),
+            PostgresValue::Composite(value) => T::from_postgres_composite(value.clone()),
+            PostgresValue::Range(value) => T::from_postgres_range((**value).clone()),
             PostgresValue::Null => T::from_postgres_null(),
         }
     }
 }
 
 // Implement core traits required by Drizzle
+/// Renders embedding components in pgvector's text format, e.g. `[1,2.5]`.
+pub(crate) fn vector_literal(vector: &[f32]) -> String {
+    let components: Vec<String> = vector.iter().map(ToString::to_string).collect();
+    format!("[{}]", components.join(","))
+}
+
 impl SQLParam for PostgresValue<'_> {
     const DIALECT: drizzle_core::dialect::Dialect = drizzle_core::dialect::Dialect::PostgreSQL;
     type DialectMarker = drizzle_core::dialect::PostgresDialect;
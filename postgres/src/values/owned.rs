@@ -1,6 +1,6 @@
 //! Owned `PostgreSQL` value types for static lifetime scenarios
 
-use super::PostgresValue;
+use super::{PgRange, PostgresValue};
 use crate::prelude::*;
 use crate::traits::{FromPostgresValue, PostgresEnum};
 use drizzle_core::{SQLParam, error::DrizzleError, sql::SQL};
@@ -19,7 +19,7 @@ use time::{
 use cidr::{IpCidr, IpInet};
 
 #[cfg(feature = "geo-types")]
-use geo_types::{LineString, Point, Rect};
+use geo_types::{Geometry, LineString, Point, Rect};
 
 #[cfg(feature = "bit-vec")]
 use bit_vec::BitVec;
@@ -117,16 +117,30 @@ pub enum OwnedPostgresValue {
     /// BOX values (bounding rectangle)
     #[cfg(feature = "geo-types")]
     Rect(Rect<f64>),
+    /// PostGIS GEOMETRY values, sent as WKB
+    #[cfg(feature = "geo-types")]
+    Geometry(Geometry<f64>),
 
     // Bit string types
     /// BIT, BIT VARYING values
     #[cfg(feature = "bit-vec")]
     BitVec(BitVec),
 
+    /// pgvector VECTOR values (embeddings)
+    Vector(Vec<f32>),
+
     // Array types (using Vec for simplicity)
     /// Array of any `PostgreSQL` type
     Array(Vec<Self>),
 
+    // Composite types
+    /// Attributes of a composite (row) type value, in declaration order
+    Composite(Vec<Self>),
+
+    // Range types
+    /// Range values (`int4range`, `tstzrange`, ...)
+    Range(Box<PgRange<Self>>),
+
     /// `PostgreSQL` ENUM values (native enum types via `CREATE TYPE ... AS ENUM`)
     Enum(Box<dyn PostgresEnum>),
 
@@ -477,6 +491,27 @@ impl OwnedPostgresValue {
         }
     }
 
+    /// Returns the geometry value if this is a PostGIS GEOMETRY.
+    #[inline]
+    #[cfg(feature = "geo-types")]
+    #[must_use]
+    pub const fn as_geometry(&self) -> Option<&Geometry<f64>> {
+        match self {
+            Self::Geometry(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the components if this is a pgvector VECTOR.
+    #[inline]
+    #[must_use]
+    pub const fn as_vector(&self) -> Option<&[f32]> {
+        match self {
+            Self::Vector(value) => Some(value.as_slice()),
+            _ => None,
+        }
+    }
+
     /// Returns the bit vector if this is BIT/VARBIT.
     #[inline]
     #[cfg(feature = "bit-vec")]
@@ -498,6 +533,26 @@ impl OwnedPostgresValue {
         }
     }
 
+    /// Returns the attribute values if this is a composite value.
+    #[inline]
+    #[must_use]
+    pub fn as_composite(&self) -> Option<&[Self]> {
+        match self {
+            Self::Composite(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the range if this is a range value.
+    #[inline]
+    #[must_use]
+    pub fn as_range(&self) -> Option<&PgRange<Self>> {
+        match self {
+            Self::Range(range) => Some(range),
+            _ => None,
+        }
+    }
+
     /// Returns a borrowed `PostgresValue` view of this owned value.
     #[inline]
     pub fn as_value(&self) -> PostgresValue<'_> {
@@ -552,11 +607,20 @@ impl OwnedPostgresValue {
             Self::LineString(value) => PostgresValue::LineString(value.clone()),
             #[cfg(feature = "geo-types")]
             Self::Rect(value) => PostgresValue::Rect(*value),
+            #[cfg(feature = "geo-types")]
+            Self::Geometry(value) => PostgresValue::Geometry(value.clone()),
             #[cfg(feature = "bit-vec")]
             Self::BitVec(value) => PostgresValue::BitVec(value.clone()),
+            Self::Vector(value) => PostgresValue::Vector(value.clone()),
             Self::Array(values) => {
                 PostgresValue::Array(values.iter().map(Self::as_value).collect())
             }
+            Self::Composite(values) => {
+                PostgresValue::Composite(values.iter().map(Self::as_value).collect())
+            }
+            Self::Range(range) => PostgresValue::Range(Box::new(
+                PgRange::as_ref(range).map(|value| value.as_value()),
+            )),
             Self::Enum(value) => PostgresValue::Enum(value.clone()),
             Self::Null => PostgresValue::Null,
         }
@@ -623,12 +687,20 @@ impl OwnedPostgresValue {
             Self::LineString(value) => T::from_postgres_linestring(value),
             #[cfg(feature = "geo-types")]
             Self::Rect(value) => T::from_postgres_rect(value),
+            #[cfg(feature = "geo-types")]
+            Self::Geometry(value) => T::from_postgres_geometry(value),
             #[cfg(feature = "bit-vec")]
             Self::BitVec(value) => T::from_postgres_bitvec(value),
+            Self::Vector(value) => T::from_postgres_vector(value),
             Self::Array(values) => {
                 let values = values.into_iter().map(PostgresValue::from).collect();
                 T::from_postgres_array(values)
             }
+            Self::Composite(values) => {
+                let values = values.into_iter().map(PostgresValue::from).collect();
+                T::from_postgres_composite(values)
+            }
+            Self::Range(range) => T::from_postgres_range(range.map(PostgresValue::from)),
             Self::Enum(value) => T::from_postgres_text(value.variant_name()),
             Self::Null => T::from_postgres_null(),
         }
@@ -695,12 +767,22 @@ impl OwnedPostgresValue {
             Self::LineString(value) => T::from_postgres_linestring(value.clone()),
             #[cfg(feature = "geo-types")]
             Self::Rect(value) => T::from_postgres_rect(*value),
+            #[cfg(feature = "geo-types")]
+            Self::Geometry(value) => T::from_postgres_geometry(value.clone()),
             #[cfg(feature = "bit-vec")]
             Self::BitVec(value) => T::from_postgres_bitvec(value.clone()),
+            Self::Vector(value) => T::from_postgres_vector(value.clone()),
             Self::Array(values) => {
                 let values = values.iter().map(Self::as_value).collect();
                 T::from_postgres_array(values)
             }
+            Self::Composite(values) => {
+                let values = values.iter().map(Self::as_value).collect();
+                T::from_postgres_composite(values)
+            }
+            Self::Range(range) => {
+                T::from_postgres_range(PgRange::as_ref(range).map(|value| value.as_value()))
+            }
             Self::Enum(value) => T::from_postgres_text(value.variant_name()),
             Self::Null => T::from_postgres_null(),
         }
@@ -796,6 +878,16 @@ impl core::fmt::Display for OwnedPostgresValue {
                     rect.max().y
                 )
             }
+            #[cfg(feature = "geo-types")]
+            Self::Geometry(geometry) => {
+                use core::fmt::Write;
+                let wkb = drizzle_core::wkb::encode(geometry, None);
+                let mut s = String::with_capacity(wkb.len() * 2);
+                for byte in &wkb {
+                    write!(s, "{byte:02x}").expect("writing to String cannot fail");
+                }
+                s
+            }
 
             // Bit string types
             #[cfg(feature = "bit-vec")]
@@ -804,12 +896,23 @@ impl core::fmt::Display for OwnedPostgresValue {
                 .map(|b| if b { '1' } else { '0' })
                 .collect::<String>(),
 
+            Self::Vector(vector) => super::vector_literal(vector),
+
             // Array types
             Self::Array(arr) => {
                 let elements: Vec<String> = arr.iter().map(ToString::to_string).collect();
                 format!("{{{}}}", elements.join(","))
             }
 
+            // Composite types
+            Self::Composite(fields) => {
+                let elements: Vec<String> = fields.iter().map(ToString::to_string).collect();
+                format!("({})", elements.join(","))
+            }
+
+            // Range types
+            Self::Range(range) => range.to_string(),
+
             Self::Enum(enum_val) => enum_val.variant_name().to_string(),
 
             Self::Null => String::new(),
@@ -874,12 +977,19 @@ impl<'a> From<PostgresValue<'a>> for OwnedPostgresValue {
             PostgresValue::LineString(line) => Self::LineString(line),
             #[cfg(feature = "geo-types")]
             PostgresValue::Rect(rect) => Self::Rect(rect),
+            #[cfg(feature = "geo-types")]
+            PostgresValue::Geometry(geometry) => Self::Geometry(geometry),
             #[cfg(feature = "bit-vec")]
             PostgresValue::BitVec(bv) => Self::BitVec(bv),
+            PostgresValue::Vector(vector) => Self::Vector(vector),
             PostgresValue::Array(arr) => {
                 let owned_arr = arr.into_iter().map(Self::from).collect();
                 Self::Array(owned_arr)
             }
+            PostgresValue::Composite(fields) => {
+                Self::Composite(fields.into_iter().map(Self::from).collect())
+            }
+            PostgresValue::Range(range) => Self::Range(Box::new(range.map(Self::from))),
         }
     }
 }
@@ -938,12 +1048,21 @@ impl<'a> From<&PostgresValue<'a>> for OwnedPostgresValue {
             PostgresValue::LineString(value) => Self::LineString(value.clone()),
             #[cfg(feature = "geo-types")]
             PostgresValue::Rect(value) => Self::Rect(*value),
+            #[cfg(feature = "geo-types")]
+            PostgresValue::Geometry(value) => Self::Geometry(value.clone()),
             #[cfg(feature = "bit-vec")]
             PostgresValue::BitVec(value) => Self::BitVec(value.clone()),
+            PostgresValue::Vector(value) => Self::Vector(value.clone()),
             PostgresValue::Array(arr) => {
                 let owned_arr = arr.iter().map(Self::from).collect();
                 Self::Array(owned_arr)
             }
+            PostgresValue::Composite(fields) => {
+                Self::Composite(fields.iter().map(Self::from).collect())
+            }
+            PostgresValue::Range(range) => {
+                Self::Range(Box::new(PgRange::as_ref(range).map(Self::from)))
+            }
             PostgresValue::Null => Self::Null,
         }
     }
@@ -1005,12 +1124,21 @@ impl From<OwnedPostgresValue> for PostgresValue<'_> {
             OwnedPostgresValue::LineString(line) => PostgresValue::LineString(line),
             #[cfg(feature = "geo-types")]
             OwnedPostgresValue::Rect(rect) => PostgresValue::Rect(rect),
+            #[cfg(feature = "geo-types")]
+            OwnedPostgresValue::Geometry(geometry) => PostgresValue::Geometry(geometry),
             #[cfg(feature = "bit-vec")]
             OwnedPostgresValue::BitVec(bv) => PostgresValue::BitVec(bv),
+            OwnedPostgresValue::Vector(vector) => PostgresValue::Vector(vector),
             OwnedPostgresValue::Array(arr) => {
                 let postgres_arr = arr.into_iter().map(PostgresValue::from).collect();
                 PostgresValue::Array(postgres_arr)
             }
+            OwnedPostgresValue::Composite(fields) => {
+                PostgresValue::Composite(fields.into_iter().map(PostgresValue::from).collect())
+            }
+            OwnedPostgresValue::Range(range) => {
+                PostgresValue::Range(Box::new(range.map(PostgresValue::from)))
+            }
 
             OwnedPostgresValue::Enum(enum_val) => PostgresValue::Enum(enum_val),
 
@@ -1072,11 +1200,20 @@ impl<'a> From<&'a OwnedPostgresValue> for PostgresValue<'a> {
             OwnedPostgresValue::LineString(value) => PostgresValue::LineString(value.clone()),
             #[cfg(feature = "geo-types")]
             OwnedPostgresValue::Rect(value) => PostgresValue::Rect(*value),
+            #[cfg(feature = "geo-types")]
+            OwnedPostgresValue::Geometry(value) => PostgresValue::Geometry(value.clone()),
             #[cfg(feature = "bit-vec")]
             OwnedPostgresValue::BitVec(value) => PostgresValue::BitVec(value.clone()),
+            OwnedPostgresValue::Vector(value) => PostgresValue::Vector(value.clone()),
             OwnedPostgresValue::Array(values) => {
                 PostgresValue::Array(values.iter().map(PostgresValue::from).collect())
             }
+            OwnedPostgresValue::Composite(values) => {
+                PostgresValue::Composite(values.iter().map(PostgresValue::from).collect())
+            }
+            OwnedPostgresValue::Range(range) => {
+                PostgresValue::Range(Box::new(PgRange::as_ref(range).map(PostgresValue::from)))
+            }
             OwnedPostgresValue::Enum(enum_val) => PostgresValue::Enum(enum_val.clone()),
             OwnedPostgresValue::Null => PostgresValue::Null,
         }
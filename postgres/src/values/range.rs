@@ -0,0 +1,318 @@
+//! `PostgreSQL` range values (`int4range`, `tstzrange`, ...)
+
+use core::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+use drizzle_core::error::DrizzleError;
+
+use drizzle_core::expr::{Expr, NonNull, Scalar};
+use drizzle_core::{SQL, ToSQL};
+
+use super::PostgresValue;
+use crate::prelude::*;
+use crate::traits::DrizzlePostgresColumn;
+
+/// A `PostgreSQL` range value.
+///
+/// Converts from the standard library range types, so `1..10` becomes the
+/// `int4range` `[1,10)` and `..=5` becomes `(,5]`:
+///
+/// ```
+/// use drizzle_postgres::values::PgRange;
+/// use std::ops::Bound;
+///
+/// let range = PgRange::from(1..10);
+/// assert_eq!(range.lower(), Some(Bound::Included(&1)));
+/// assert_eq!(range.to_string(), "[1,10)");
+/// assert_eq!(PgRange::<i32>::Empty.to_string(), "empty");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PgRange<T> {
+    /// The empty range
+    Empty,
+    /// A range between two bounds; either bound may be unbounded
+    Bounds {
+        /// Lower bound
+        lower: Bound<T>,
+        /// Upper bound
+        upper: Bound<T>,
+    },
+}
+
+impl<T> PgRange<T> {
+    /// Create a range between two bounds.
+    #[must_use]
+    pub const fn new(lower: Bound<T>, upper: Bound<T>) -> Self {
+        Self::Bounds { lower, upper }
+    }
+
+    /// Returns true if this is the empty range.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    /// Returns the lower bound, or `None` for the empty range.
+    #[must_use]
+    pub fn lower(&self) -> Option<Bound<&T>> {
+        match self {
+            Self::Empty => None,
+            Self::Bounds { lower, .. } => Some(lower.as_ref()),
+        }
+    }
+
+    /// Returns the upper bound, or `None` for the empty range.
+    #[must_use]
+    pub fn upper(&self) -> Option<Bound<&T>> {
+        match self {
+            Self::Empty => None,
+            Self::Bounds { upper, .. } => Some(upper.as_ref()),
+        }
+    }
+
+    /// Borrow the bounds of this range.
+    #[must_use]
+    pub fn as_ref(&self) -> PgRange<&T> {
+        match self {
+            Self::Empty => PgRange::Empty,
+            Self::Bounds { lower, upper } => PgRange::Bounds {
+                lower: lower.as_ref(),
+                upper: upper.as_ref(),
+            },
+        }
+    }
+
+    /// Convert both bounds with `f`.
+    #[must_use]
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> PgRange<U> {
+        match self {
+            Self::Empty => PgRange::Empty,
+            Self::Bounds { lower, upper } => PgRange::Bounds {
+                lower: lower.map(&mut f),
+                upper: upper.map(f),
+            },
+        }
+    }
+}
+
+impl<T: core::fmt::Display> core::fmt::Display for PgRange<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Self::Bounds { lower, upper } = self else {
+            return f.write_str("empty");
+        };
+        match lower {
+            Bound::Included(value) => write!(f, "[{value}")?,
+            Bound::Excluded(value) => write!(f, "({value}")?,
+            Bound::Unbounded => f.write_str("(")?,
+        }
+        f.write_str(",")?;
+        match upper {
+            Bound::Included(value) => write!(f, "{value}]"),
+            Bound::Excluded(value) => write!(f, "{value})"),
+            Bound::Unbounded => f.write_str(")"),
+        }
+    }
+}
+
+impl<T> From<Range<T>> for PgRange<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::new(Bound::Included(range.start), Bound::Excluded(range.end))
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for PgRange<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::new(Bound::Included(start), Bound::Included(end))
+    }
+}
+
+impl<T> From<RangeFrom<T>> for PgRange<T> {
+    fn from(range: RangeFrom<T>) -> Self {
+        Self::new(Bound::Included(range.start), Bound::Unbounded)
+    }
+}
+
+impl<T> From<RangeTo<T>> for PgRange<T> {
+    fn from(range: RangeTo<T>) -> Self {
+        Self::new(Bound::Unbounded, Bound::Excluded(range.end))
+    }
+}
+
+impl<T> From<RangeToInclusive<T>> for PgRange<T> {
+    fn from(range: RangeToInclusive<T>) -> Self {
+        Self::new(Bound::Unbounded, Bound::Included(range.end))
+    }
+}
+
+impl<T> From<RangeFull> for PgRange<T> {
+    fn from(_: RangeFull) -> Self {
+        Self::new(Bound::Unbounded, Bound::Unbounded)
+    }
+}
+
+impl<T> From<(Bound<T>, Bound<T>)> for PgRange<T> {
+    fn from((lower, upper): (Bound<T>, Bound<T>)) -> Self {
+        Self::new(lower, upper)
+    }
+}
+
+impl<'a, T> ToSQL<'a, PostgresValue<'a>> for PgRange<T>
+where
+    T: Into<PostgresValue<'a>> + Clone,
+{
+    fn to_sql(&self) -> SQL<'a, PostgresValue<'a>> {
+        SQL::param(PostgresValue::Range(Box::new(self.clone().map(Into::into))))
+    }
+}
+
+impl<'a, T> Expr<'a, PostgresValue<'a>> for PgRange<T>
+where
+    T: Into<PostgresValue<'a>> + Clone,
+{
+    type SQLType = drizzle_types::postgres::types::Any;
+    type Nullable = NonNull;
+    type Aggregate = Scalar;
+}
+
+//------------------------------------------------------------------------------
+// Column impls for the built-in range types
+//------------------------------------------------------------------------------
+
+macro_rules! impl_range_column {
+    ($($(#[$meta:meta])* $ty:ty => $sql_type:literal),+ $(,)?) => {
+        $(
+            $(#[$meta])*
+            impl DrizzlePostgresColumn for PgRange<$ty> {
+                type SQLType = drizzle_types::postgres::types::Any;
+
+                const SQL_TYPE: &'static str = $sql_type;
+
+                #[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+                fn decode(row: &crate::Row, idx: usize) -> Result<Self, DrizzleError> {
+                    row.try_get::<_, Self>(idx)
+                        .map_err(|e| DrizzleError::ConversionError(e.to_string().into()))
+                }
+
+                fn encode(&self) -> PostgresValue<'_> {
+                    PostgresValue::Range(Box::new(self.as_ref().map(PostgresValue::from)))
+                }
+            }
+        )+
+    };
+}
+
+impl_range_column!(
+    i32 => "int4range",
+    i64 => "int8range",
+    #[cfg(feature = "rust-decimal")]
+    rust_decimal::Decimal => "numrange",
+    #[cfg(feature = "chrono")]
+    chrono::NaiveDate => "daterange",
+    #[cfg(feature = "chrono")]
+    chrono::NaiveDateTime => "tsrange",
+    #[cfg(feature = "chrono")]
+    chrono::DateTime<chrono::Utc> => "tstzrange",
+    #[cfg(feature = "chrono")]
+    chrono::DateTime<chrono::FixedOffset> => "tstzrange",
+    #[cfg(feature = "time")]
+    time::Date => "daterange",
+    #[cfg(feature = "time")]
+    time::PrimitiveDateTime => "tsrange",
+    #[cfg(feature = "time")]
+    time::OffsetDateTime => "tstzrange",
+);
+
+//------------------------------------------------------------------------------
+// postgres/tokio-postgres FromSql implementation
+//------------------------------------------------------------------------------
+
+// Flag bits of the binary range format
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+pub(super) const RANGE_EMPTY: u8 = 0x01;
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+pub(super) const RANGE_LB_INC: u8 = 0x02;
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+pub(super) const RANGE_UB_INC: u8 = 0x04;
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+pub(super) const RANGE_LB_INF: u8 = 0x08;
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+pub(super) const RANGE_UB_INF: u8 = 0x10;
+
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+mod postgres_fromsql_impl {
+    use super::{PgRange, RANGE_EMPTY, RANGE_LB_INC, RANGE_LB_INF, RANGE_UB_INC, RANGE_UB_INF};
+    use core::ops::Bound;
+
+    #[cfg(feature = "postgres-sync")]
+    use postgres::types::{FromSql, Kind, Type};
+
+    #[cfg(all(feature = "tokio-postgres", not(feature = "postgres-sync")))]
+    use tokio_postgres::types::{FromSql, Kind, Type};
+
+    type BoxError = Box<dyn std::error::Error + Sync + Send>;
+
+    fn decode_error(message: &str) -> BoxError {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, message).into()
+    }
+
+    fn read_bound<'a, T: FromSql<'a>>(
+        element: &Type,
+        raw: &mut &'a [u8],
+        infinite: bool,
+        inclusive: bool,
+    ) -> Result<Bound<T>, BoxError> {
+        if infinite {
+            return Ok(Bound::Unbounded);
+        }
+        let Some((len, rest)) = raw.split_first_chunk::<4>() else {
+            return Err(decode_error("range value is truncated"));
+        };
+        let len = usize::try_from(i32::from_be_bytes(*len))
+            .map_err(|_| decode_error("range bound cannot be NULL"))?;
+        if rest.len() < len {
+            return Err(decode_error("range value is truncated"));
+        }
+        let (value, rest) = rest.split_at(len);
+        *raw = rest;
+        let value = T::from_sql(element, value)?;
+        Ok(if inclusive {
+            Bound::Included(value)
+        } else {
+            Bound::Excluded(value)
+        })
+    }
+
+    impl<'a, T: FromSql<'a>> FromSql<'a> for PgRange<T> {
+        fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+            let Kind::Range(element) = ty.kind() else {
+                return Err(decode_error("expected a range type"));
+            };
+            let Some((&flags, mut raw)) = raw.split_first() else {
+                return Err(decode_error("range value is truncated"));
+            };
+            if flags & RANGE_EMPTY != 0 {
+                return Ok(Self::Empty);
+            }
+            let lower = read_bound(
+                element,
+                &mut raw,
+                flags & RANGE_LB_INF != 0,
+                flags & RANGE_LB_INC != 0,
+            )?;
+            let upper = read_bound(
+                element,
+                &mut raw,
+                flags & RANGE_UB_INF != 0,
+                flags & RANGE_UB_INC != 0,
+            )?;
+            Ok(Self::new(lower, upper))
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            matches!(ty.kind(), Kind::Range(element) if T::accepts(element))
+        }
+    }
+}
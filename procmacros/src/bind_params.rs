@@ -0,0 +1,66 @@
+//! `#[derive(BindParams)]`: binds a prepared statement's named placeholders
+//! from the fields of a struct.
+
+use crate::paths::{core as core_paths, std as std_paths};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Result};
+
+pub fn generate_bind_params_impl(input: &DeriveInput) -> Result<TokenStream> {
+    let struct_name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    input,
+                    "BindParams requires a struct with named fields; each field binds the placeholder of the same name",
+                ));
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "BindParams can only be derived for structs",
+            ));
+        }
+    };
+
+    let bind_params = core_paths::bind_params();
+    let param_bind = core_paths::param_bind();
+    let sql_param = core_paths::sql_param();
+    let into = std_paths::into();
+
+    let count = fields.len();
+    let field_types = fields.iter().map(|field| &field.ty);
+    let binds = fields.iter().map(|field| {
+        let ident = field.ident.as_ref().expect("named field");
+        let name = ident.to_string();
+        let name = name.strip_prefix("r#").unwrap_or(&name);
+        quote! { #param_bind::new(#name, #into::into(::std::clone::Clone::clone(&self.#ident))) }
+    });
+
+    let mut generics = input.generics.clone();
+    generics.params.insert(0, syn::parse_quote!('__drizzle_a));
+    generics.params.push(syn::parse_quote!(__DrizzleV));
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut where_predicates: Vec<TokenStream> = where_clause
+        .map(|clause| clause.predicates.iter().map(|p| quote!(#p)).collect())
+        .unwrap_or_default();
+    where_predicates.push(quote!(__DrizzleV: #sql_param));
+    where_predicates.push(quote!(Self: '__drizzle_a));
+    where_predicates
+        .extend(field_types.map(|ty| quote!(#ty: ::std::clone::Clone + #into<__DrizzleV>)));
+
+    Ok(quote! {
+        impl #impl_generics #bind_params<'__drizzle_a, __DrizzleV, #count> for #struct_name #ty_generics
+        where
+            #(#where_predicates,)*
+        {
+            fn bind_params(&'__drizzle_a self) -> [#param_bind<'__drizzle_a, __DrizzleV>; #count] {
+                [#(#binds),*]
+            }
+        }
+    })
+}
@@ -291,14 +291,16 @@ pub fn generate_foreign_keys<F: ConstraintFieldInfo, C: CompositeForeignKeyRef>(
         let ref_column_zst_ident = format_ident!("{}{}", ref_table_ident, ref_column_pascal);
 
         let field_span = field.ident().span();
+        // Generic over the column tuples so a mismatch is reported once, at
+        // the call below, instead of again as a trivially-false bound.
         let type_match_assert = quote_spanned! {field_span=>
             const _: () = {
-                const fn assert_fk_types()
+                const fn assert_fk_types<Src, Dst>()
                 where
-                    (): #fk_type_match<(#source_col_zst_ident,), (#ref_column_zst_ident,)>,
+                    (): #fk_type_match<Src, Dst>,
                 {
                 }
-                assert_fk_types();
+                assert_fk_types::<(#source_col_zst_ident,), (#ref_column_zst_ident,)>();
             };
         };
 
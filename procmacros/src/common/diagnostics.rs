@@ -21,3 +21,91 @@ pub fn relation_requires_references_message() -> &'static str {
     "relation requires a references attribute.\n\
      Example: #[column(references = Table::column, relation = \"posts\")]"
 }
+
+/// Error for an unrecognized attribute name, with a "did you mean" hint when
+/// one of `supported` is a likely typo target.
+pub fn unknown_attribute_message(attribute: &str, name: &str, supported: &[&str]) -> String {
+    let hint = did_you_mean(name, supported);
+    format!(
+        "unknown {attribute} attribute `{name}`{hint}\n\
+         Supported: {}",
+        supported.join(", ")
+    )
+}
+
+/// `"; did you mean `x`?"` for the closest entry of `supported`, or an empty
+/// string when nothing is close.
+pub fn did_you_mean(name: &str, supported: &[&str]) -> String {
+    closest_match(name, supported)
+        .map(|candidate| format!("; did you mean `{candidate}`?"))
+        .unwrap_or_default()
+}
+
+/// Error when a primary key field is declared as `Option<T>`.
+pub fn nullable_primary_key_message(field: &str) -> String {
+    format!(
+        "primary key `{field}` cannot be `Option<T>`.\n\
+         Nullability comes from the Rust type: `T` is NOT NULL and `Option<T>` allows NULL, \
+         but a PRIMARY KEY column never holds NULL.\n\
+         Use the inner type instead; keys with a default (autoincrement, serial, identity, \
+         default_fn) are already optional in the generated Insert model."
+    )
+}
+
+/// Closest candidate to `input` by case-insensitive edit distance, if it is
+/// near enough to plausibly be a typo.
+fn closest_match<'a>(input: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let input = input.to_ascii_lowercase();
+    let max_distance = input.len().div_ceil(3).max(1);
+    candidates
+        .iter()
+        .map(|candidate| {
+            (
+                edit_distance(&input, &candidate.to_ascii_lowercase()),
+                *candidate,
+            )
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between two ASCII-ish strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggests_closest_attribute() {
+        let supported = ["primary", "unique", "autoincrement", "references"];
+        assert_eq!(closest_match("primay", &supported), Some("primary"));
+        assert_eq!(closest_match("UNIQE", &supported), Some("unique"));
+        assert_eq!(
+            closest_match("autoincrment", &supported),
+            Some("autoincrement")
+        );
+        assert_eq!(closest_match("nullable", &supported), None);
+    }
+
+    #[test]
+    fn unknown_attribute_message_lists_supported() {
+        let msg = unknown_attribute_message("#[column]", "uniq", &["primary", "unique"]);
+        assert!(msg.starts_with("unknown #[column] attribute `uniq`; did you mean `unique`?"));
+        assert!(msg.ends_with("Supported: primary, unique"));
+    }
+}
@@ -1,6 +1,10 @@
 use std::collections::HashMap;
 
-use syn::{Attribute, DataEnum, Expr, ExprLit, ExprUnary, Lit, UnOp, spanned::Spanned};
+use heck::{
+    ToKebabCase, ToLowerCamelCase, ToShoutyKebabCase, ToShoutySnakeCase, ToSnakeCase,
+    ToUpperCamelCase,
+};
+use syn::{Attribute, DataEnum, Expr, ExprLit, ExprUnary, Lit, LitStr, UnOp, spanned::Spanned};
 
 /// Parse a discriminant expression into an i64 value.
 ///
@@ -50,6 +54,14 @@ pub fn has_explicit_discriminants(data: &DataEnum) -> bool {
     data.variants.iter().any(|v| v.discriminant.is_some())
 }
 
+/// Check if any variant carries data (`Failed { reason }` or `Retry(u32)`).
+#[cfg(feature = "sqlite")]
+pub fn has_data_variants(data: &DataEnum) -> bool {
+    data.variants
+        .iter()
+        .any(|v| !matches!(v.fields, syn::Fields::Unit))
+}
+
 /// Check if the type has a `#[repr(iN)]` or `#[repr(uN)]` attribute.
 pub fn has_integer_repr(attrs: &[Attribute]) -> bool {
     attrs.iter().any(|attr| {
@@ -100,3 +112,135 @@ pub fn resolve_discriminants(data: &DataEnum) -> syn::Result<Vec<(&syn::Ident, i
 
     Ok(results)
 }
+
+/// Case conventions accepted by `#[drizzle(rename_all = "...")]`.
+const RENAME_ALL_RULES: &[&str] = &[
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+fn apply_rename_rule(rule: &str, variant: &str) -> String {
+    match rule {
+        "lowercase" => variant.to_lowercase(),
+        "UPPERCASE" => variant.to_uppercase(),
+        "PascalCase" => variant.to_upper_camel_case(),
+        "camelCase" => variant.to_lower_camel_case(),
+        "snake_case" => variant.to_snake_case(),
+        "SCREAMING_SNAKE_CASE" => variant.to_shouty_snake_case(),
+        "kebab-case" => variant.to_kebab_case(),
+        "SCREAMING-KEBAB-CASE" => variant.to_shouty_kebab_case(),
+        _ => variant.to_string(),
+    }
+}
+
+/// Parse the `#[drizzle(...)]` attributes on an enum or variant, returning the
+/// string value of `key`. Any other key is rejected.
+fn parse_drizzle_attr(attrs: &[Attribute], key: &str) -> syn::Result<Option<LitStr>> {
+    let mut value = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("drizzle")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(key) {
+                value = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error(format!("unsupported drizzle attribute, expected `{key}`")))
+            }
+        })?;
+    }
+    Ok(value)
+}
+
+/// Resolve the string each variant is stored as.
+///
+/// Defaults to the variant name; `#[drizzle(rename_all = "snake_case")]` on the
+/// enum applies a case convention and `#[drizzle(rename = "...")]` on a variant
+/// overrides it. Emits a compile error if two variants resolve to the same string.
+pub fn resolve_variant_names<'a>(
+    data: &'a DataEnum,
+    attrs: &[Attribute],
+) -> syn::Result<Vec<(&'a syn::Ident, String)>> {
+    let rule = parse_drizzle_attr(attrs, "rename_all")?;
+    if let Some(rule) = &rule
+        && !RENAME_ALL_RULES.contains(&rule.value().as_str())
+    {
+        return Err(syn::Error::new(
+            rule.span(),
+            format!(
+                "unknown rename_all rule `{}`, expected one of: {}",
+                rule.value(),
+                RENAME_ALL_RULES.join(", ")
+            ),
+        ));
+    }
+
+    let mut results = Vec::with_capacity(data.variants.len());
+    let mut seen: HashMap<String, &syn::Ident> = HashMap::new();
+
+    for variant in &data.variants {
+        let ident = variant.ident.to_string();
+        let value = match parse_drizzle_attr(&variant.attrs, "rename")? {
+            Some(rename) => rename.value(),
+            None => rule.as_ref().map_or_else(
+                || ident.clone(),
+                |rule| apply_rename_rule(&rule.value(), &ident),
+            ),
+        };
+
+        if let Some(prev_ident) = seen.get(&value) {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                format!(
+                    "duplicate enum value \"{}\": variant `{}` conflicts with `{}`",
+                    value, variant.ident, prev_ident,
+                ),
+            ));
+        }
+
+        seen.insert(value.clone(), &variant.ident);
+        results.push((&variant.ident, value));
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_variant_names;
+    use syn::{Data, DeriveInput};
+
+    fn names(input: &str) -> syn::Result<Vec<String>> {
+        let input: DeriveInput = syn::parse_str(input)?;
+        let Data::Enum(data) = &input.data else {
+            unreachable!("test input is an enum")
+        };
+        Ok(resolve_variant_names(data, &input.attrs)?
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect())
+    }
+
+    #[test]
+    fn rename_all_and_rename_resolve_stored_values() {
+        assert_eq!(
+            names(
+                r#"#[drizzle(rename_all = "kebab-case")]
+                enum Status { InReview, #[drizzle(rename = "ok")] Approved }"#
+            )
+            .unwrap(),
+            vec!["in-review", "ok"]
+        );
+        assert_eq!(names("enum Status { Open }").unwrap(), vec!["Open"]);
+    }
+
+    #[test]
+    fn rejects_unknown_rules_and_duplicate_values() {
+        assert!(names(r#"#[drizzle(rename_all = "Title Case")] enum S { A }"#).is_err());
+        assert!(names(r#"enum S { A, #[drizzle(rename = "A")] B }"#).is_err());
+    }
+}
@@ -33,6 +33,74 @@ pub fn make_uppercase_path(original_ident: &syn::Ident, uppercase_name: &str) ->
     }
 }
 
+/// Extract the SQL text from a `default = sql!("...")` column attribute.
+///
+/// The expression is emitted verbatim as the column's DDL `DEFAULT`, so it
+/// must be a single string literal; `{{`/`}}` unescape to literal braces and
+/// `{expr}` interpolation is rejected.
+pub fn sql_default_expression(mac: &syn::Macro) -> Result<String> {
+    if mac
+        .path
+        .segments
+        .last()
+        .is_none_or(|seg| seg.ident != "sql")
+    {
+        return Err(Error::new_spanned(
+            &mac.path,
+            "default only accepts literals or `sql!(\"...\")`, e.g. default = sql!(\"(unixepoch())\")",
+        ));
+    }
+    let lit: syn::LitStr = mac.parse_body().map_err(|_| {
+        Error::new_spanned(
+            &mac.tokens,
+            "sql! defaults take a single string literal, e.g. default = sql!(\"(unixepoch())\")",
+        )
+    })?;
+
+    let value = lit.value();
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if matches!(c, '{' | '}') {
+            if chars.peek() != Some(&c) {
+                return Err(Error::new_spanned(
+                    &lit,
+                    "sql! defaults cannot interpolate `{...}` expressions; use `{{`/`}}` for literal braces",
+                ));
+            }
+            chars.next();
+        }
+        out.push(c);
+    }
+    Ok(out)
+}
+
+/// Join `///` doc comment lines into a SQL comment, or `None` if there are none.
+pub fn doc_comment_from_attrs(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines = attrs.iter().filter_map(|attr| {
+        if !attr.path().is_ident("doc") {
+            return None;
+        }
+        let Meta::NameValue(meta) = &attr.meta else {
+            return None;
+        };
+        let Expr::Lit(expr_lit) = &meta.value else {
+            return None;
+        };
+        let syn::Lit::Str(lit) = &expr_lit.lit else {
+            return None;
+        };
+        let value = lit.value();
+        Some(value.strip_prefix(' ').unwrap_or(&value).to_string())
+    });
+    let comment = lines.collect::<Vec<_>>().join("\n");
+    if comment.is_empty() {
+        None
+    } else {
+        Some(comment)
+    }
+}
+
 /// Parse column reference from field attributes, looking for `#[column(Table::field)]`.
 ///
 /// This is used by `FromRow` derives to map struct fields to specific table columns,
@@ -176,6 +244,64 @@ pub fn has_attribute(field: &Field, attr_name: &str) -> bool {
     })
 }
 
+/// Read a per-member collation from an index tuple field, e.g.
+/// `struct EmailIdx(#[collate = "NOCASE"] Users::email);`.
+///
+/// `names` lists the accepted attribute names for the dialect. The value may
+/// be a string literal or a bare identifier (`#[collate = NOCASE]`).
+pub fn index_member_collate(field: &Field, names: &[&str]) -> Result<Option<String>> {
+    index_member_name(field, names, "collation", "NOCASE")
+}
+
+/// Read a per-member operator class from an index tuple field, e.g.
+/// `struct EmbeddingIdx(#[opclass = "vector_cosine_ops"] Docs::embedding);`.
+#[cfg(feature = "postgres")]
+pub fn index_member_opclass(field: &Field) -> Result<Option<String>> {
+    index_member_name(field, &["opclass"], "operator class", "vector_cosine_ops")
+}
+
+fn index_member_name(
+    field: &Field,
+    names: &[&str],
+    kind: &str,
+    example: &str,
+) -> Result<Option<String>> {
+    let mut found = None;
+    for attr in &field.attrs {
+        let Some(ident) = attr.path().get_ident() else {
+            continue;
+        };
+        if !names.iter().any(|name| ident == name) {
+            continue;
+        }
+        let nv = attr.meta.require_name_value()?;
+        let value = match &nv.value {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(s),
+                ..
+            }) => s.value(),
+            Expr::Path(path) if path.path.get_ident().is_some() => path
+                .path
+                .get_ident()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            other => {
+                return Err(Error::new_spanned(
+                    other,
+                    format!("{ident} expects a {kind} name, e.g. #[{ident} = \"{example}\"]"),
+                ));
+            }
+        };
+        if found.replace(value).is_some() {
+            return Err(Error::new_spanned(
+                attr,
+                format!("duplicate {kind} for index column"),
+            ));
+        }
+    }
+    Ok(found)
+}
+
 /// Check if a field has the `#[json]` attribute or `#[column(json)]`.
 ///
 /// This is used by `FromRow` derives to determine if a field should be
@@ -204,7 +330,7 @@ pub fn has_attribute(field: &Field, attr_name: &str) -> bool {
 /// });
 /// assert!(has_json);
 /// ```
-#[cfg(feature = "sqlite")]
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
 pub fn has_json_attribute(field: &Field) -> bool {
     field.attrs.iter().any(|attr| {
         if attr.path().get_ident().is_some_and(|ident| ident == "json") {
@@ -0,0 +1,68 @@
+//! Primary-key newtype generation for the `ID_TYPE = ...` table attribute.
+//!
+//! The newtype itself is dialect-agnostic; each dialect adds its own
+//! `CustomSQLiteType` / `CustomPostgresType` impl on top so the generated
+//! type flows through the same custom-column path as user-defined types.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Type, Visibility};
+
+/// Generate `#vis struct #ident(pub #inner);` plus conversions to and from
+/// the wrapped type. `copy` should be true for integer keys.
+pub fn generate_id_newtype(
+    ident: &Ident,
+    vis: &Visibility,
+    inner: &Type,
+    table_ident: &Ident,
+    copy: bool,
+) -> TokenStream {
+    let copy_derive = if copy { quote!(Copy,) } else { quote!() };
+    let doc = format!("Primary key of [`{table_ident}`].");
+
+    let serde_impls = if cfg!(any(feature = "serde", feature = "query")) {
+        quote! {
+            impl drizzle::core::serde::Serialize for #ident {
+                fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                where
+                    S: drizzle::core::serde::Serializer,
+                {
+                    drizzle::core::serde::Serialize::serialize(&self.0, serializer)
+                }
+            }
+
+            impl<'de> drizzle::core::serde::Deserialize<'de> for #ident {
+                fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+                where
+                    D: drizzle::core::serde::Deserializer<'de>,
+                {
+                    <#inner as drizzle::core::serde::Deserialize<'de>>::deserialize(deserializer)
+                        .map(Self)
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Clone, #copy_derive Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        #vis struct #ident(pub #inner);
+
+        impl ::core::convert::From<#inner> for #ident {
+            fn from(value: #inner) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::core::convert::From<#ident> for #inner {
+            fn from(value: #ident) -> Self {
+                value.0
+            }
+        }
+
+        #serde_impls
+    }
+}
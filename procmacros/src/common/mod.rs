@@ -10,6 +10,7 @@ mod diagnostics;
 pub mod enum_utils;
 pub mod generators;
 mod helpers;
+mod id_newtype;
 pub mod model_markers;
 pub mod paths;
 #[cfg(feature = "query")]
@@ -22,12 +23,20 @@ pub mod view_query;
 
 pub use constraint::Constraint;
 pub use context::ModelType;
-pub use diagnostics::{references_required_message, relation_requires_references_message};
-#[cfg(feature = "sqlite")]
+pub use diagnostics::{
+    did_you_mean, nullable_primary_key_message, references_required_message,
+    relation_requires_references_message, unknown_attribute_message,
+};
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
 pub use helpers::has_json_attribute;
-pub use helpers::{extract_struct_fields, make_uppercase_path, parse_column_reference};
+#[cfg(feature = "postgres")]
+pub use helpers::index_member_opclass;
+pub use helpers::{
+    doc_comment_from_attrs, extract_struct_fields, index_member_collate, make_uppercase_path, parse_column_reference, sql_default_expression,
+};
+pub use id_newtype::generate_id_newtype;
 pub use table_pipeline::{
-    count_primary_keys, required_fields_pattern, struct_fields, table_name_from_attrs,
+    apply_casing, count_primary_keys, required_fields_pattern, struct_fields, table_name_from_attrs,
 };
 pub use type_mapping::{generate_arithmetic_ops, generate_expr_impl, rust_type_to_nullability};
 #[cfg(feature = "postgres")]
@@ -41,13 +50,16 @@ pub use type_utils::{
     type_is_bool, type_is_datetime_tz, type_is_float, type_is_int, type_is_json_value,
     type_is_naive_date, type_is_naive_datetime, type_is_naive_time, type_is_offset_datetime,
     type_is_primitive_date_time, type_is_string_like, type_is_time_date, type_is_time_time,
-    type_is_uuid, type_is_vec_u8, unwrap_option,
+    type_is_uuid, type_is_vec_f32, type_is_vec_u8, unwrap_option,
 };
 #[cfg(feature = "postgres")]
 pub use type_utils::{
-    type_is_array_char, type_is_bit_vec, type_is_geo_linestring, type_is_geo_point,
-    type_is_geo_rect, type_is_ip_addr, type_is_ip_cidr, type_is_mac_addr, vec_inner_type,
+    array_u8_len, type_is_array_char, type_is_bit_vec, type_is_geo_linestring, type_is_geo_point,
+    type_is_geo_rect, type_is_ip_addr, type_is_ip_cidr, type_is_mac_addr, type_is_pg_range,
+    vec_inner_type,
 };
+#[cfg(any(feature = "postgres", feature = "sqlite"))]
+pub use type_utils::geo_shape_name;
 
 // Re-export dialect traits (always available)
 #[allow(unused_imports)]
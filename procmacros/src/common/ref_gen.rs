@@ -74,10 +74,18 @@ pub struct ConstraintRefInput {
     pub kind: TokenStream,
     pub columns: Vec<String>,
     pub check_expression: Option<String>,
+    pub exclusion: Option<ExclusionRefInput>,
     pub deferrable: bool,
     pub initially_deferred: bool,
 }
 
+/// EXCLUDE details for a [`ConstraintRefInput`].
+pub struct ExclusionRefInput {
+    pub using: String,
+    pub operators: Vec<String>,
+    pub predicate: Option<String>,
+}
+
 /// Generates the `const TABLE_REF: TableRef = ...;` body for a `DrizzleTable` impl.
 #[allow(clippy::too_many_arguments)]
 pub fn generate_table_ref_const(
@@ -98,6 +106,7 @@ pub fn generate_table_ref_const(
     let primary_key_ref = core_paths::primary_key_ref();
     let foreign_key_ref = core_paths::foreign_key_ref();
     let constraint_ref = core_paths::constraint_ref();
+    let exclusion_ref = core_paths::exclusion_ref();
 
     // Generate column ref literals
     let column_ref_literals: Vec<TokenStream> = columns
@@ -183,6 +192,24 @@ pub fn generate_table_ref_const(
                 || quote! { ::core::option::Option::None },
                 |e| quote! { ::core::option::Option::Some(#e) },
             );
+            let exclusion = c.exclusion.as_ref().map_or_else(
+                || quote! { ::core::option::Option::None },
+                |e| {
+                    let using = &e.using;
+                    let operators = &e.operators;
+                    let predicate = e.predicate.as_ref().map_or_else(
+                        || quote! { ::core::option::Option::None },
+                        |p| quote! { ::core::option::Option::Some(#p) },
+                    );
+                    quote! {
+                        ::core::option::Option::Some(#exclusion_ref {
+                            using: #using,
+                            operators: &[#(#operators),*],
+                            predicate: #predicate,
+                        })
+                    }
+                },
+            );
             let deferrable = c.deferrable;
             let initially_deferred = c.initially_deferred;
             quote! {
@@ -192,6 +219,7 @@ pub fn generate_table_ref_const(
                     kind: #kind,
                     columns: &[#(#columns),*],
                     check_expression: #check_expr,
+                    exclusion: #exclusion,
                     deferrable: #deferrable,
                     initially_deferred: #initially_deferred,
                 }
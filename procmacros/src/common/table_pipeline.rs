@@ -3,13 +3,26 @@
 //! This module centralizes common setup steps used by `SQLite` and `PostgreSQL`
 //! table macros to reduce duplication and keep behavior consistent.
 
-use heck::ToSnakeCase;
+use drizzle_types::Casing;
+use heck::{ToLowerCamelCase, ToSnakeCase};
 use syn::spanned::Spanned;
 use syn::{Data, DeriveInput, Fields, Result};
 
+/// Convert a struct or field name to a SQL identifier with `casing`.
+pub fn apply_casing(name: &str, casing: Casing) -> String {
+    match casing {
+        Casing::SnakeCase => name.to_snake_case(),
+        Casing::CamelCase => name.to_lower_camel_case(),
+    }
+}
+
 /// Resolve the SQL table name from the struct ident and optional name override.
-pub fn table_name_from_attrs(struct_ident: &syn::Ident, name_override: Option<String>) -> String {
-    name_override.unwrap_or_else(|| struct_ident.to_string().to_snake_case())
+pub fn table_name_from_attrs(
+    struct_ident: &syn::Ident,
+    name_override: Option<String>,
+    casing: Casing,
+) -> String {
+    name_override.unwrap_or_else(|| apply_casing(&struct_ident.to_string(), casing))
 }
 
 /// Extract struct fields for table macros, returning a helpful error for non-struct inputs.
@@ -91,6 +91,9 @@ pub fn postgres_column_type_to_sql_type(column_type: &PostgreSQLType) -> TokenSt
         PostgreSQLType::Polygon => quote!(drizzle::postgres::types::Polygon),
         #[cfg(feature = "geo-types")]
         PostgreSQLType::Circle => quote!(drizzle::postgres::types::Circle),
+        #[cfg(feature = "geo-types")]
+        PostgreSQLType::Geometry(_) => quote!(drizzle::postgres::types::Geometry),
+        PostgreSQLType::Vector(_) => quote!(drizzle::postgres::types::Vector),
         #[cfg(feature = "bit-vec")]
         PostgreSQLType::Bit | PostgreSQLType::Varbit => {
             quote!(drizzle::postgres::types::BitString)
@@ -88,6 +88,16 @@ pub fn type_is_vec_u8(ty: &Type) -> bool {
     })
 }
 
+/// `Vec<f32>`, the Rust type of vector embedding columns.
+pub fn type_is_vec_f32(ty: &Type) -> bool {
+    vec_inner_type(ty).is_some_and(|inner| {
+        matches!(
+            inner,
+            Type::Path(path) if path.path.segments.last().is_some_and(|seg| seg.ident == "f32")
+        )
+    })
+}
+
 pub fn vec_inner_type(ty: &Type) -> Option<&Type> {
     let path = type_path(ty)?;
     let segment = path.segments.last()?;
@@ -134,6 +144,25 @@ pub fn type_is_array_u8(ty: &Type) -> bool {
     }
 }
 
+/// Length of a `[u8; N]` type with a literal length.
+#[cfg(feature = "postgres")]
+pub fn array_u8_len(ty: &Type) -> Option<usize> {
+    let Type::Array(array) = ty else {
+        return None;
+    };
+    if !type_is_array_u8(ty) {
+        return None;
+    }
+    let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(len),
+        ..
+    }) = &array.len
+    else {
+        return None;
+    };
+    len.base10_parse().ok()
+}
+
 #[cfg(feature = "postgres")]
 pub fn type_is_array_char(ty: &Type) -> bool {
     match ty {
@@ -357,6 +386,29 @@ pub fn type_is_geo_point(ty: &Type) -> bool {
         .is_some_and(|id| id == "Point")
 }
 
+/// Name of the `geo_types` shape a PostGIS GEOMETRY column can hold, if `ty`
+/// is one (`Point`, `LineString`, `Polygon`, the `Multi*` shapes, or
+/// `Geometry`). On `SQLite` these shapes are stored as WKB blobs.
+#[cfg(any(feature = "postgres", feature = "sqlite"))]
+pub fn geo_shape_name(ty: &Type) -> Option<String> {
+    type_path(ty)
+        .and_then(last_path_ident)
+        .map(ToString::to_string)
+        .filter(|id| {
+            matches!(
+                id.as_str(),
+                "Point"
+                    | "LineString"
+                    | "Polygon"
+                    | "MultiPoint"
+                    | "MultiLineString"
+                    | "MultiPolygon"
+                    | "GeometryCollection"
+                    | "Geometry"
+            )
+        })
+}
+
 #[cfg(feature = "postgres")]
 pub fn type_is_geo_rect(ty: &Type) -> bool {
     type_path(ty)
@@ -377,3 +429,10 @@ pub fn type_is_bit_vec(ty: &Type) -> bool {
         .and_then(last_path_ident)
         .is_some_and(|id| id == "BitVec")
 }
+
+#[cfg(feature = "postgres")]
+pub fn type_is_pg_range(ty: &Type) -> bool {
+    type_path(ty)
+        .and_then(last_path_ident)
+        .is_some_and(|id| id == "PgRange")
+}
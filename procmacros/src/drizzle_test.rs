@@ -9,7 +9,9 @@
 //!
 //! 1. **Terminal method rewriting.** A [`BodyVisitor`] walks the body and
 //!    rewrites an allowlisted set of method calls (`.execute()`, `.all()`,
-//!    `.get()`, `.migrate()`, `.push()`, `.transaction(...)`, `.savepoint(...)`,
+//!    `.get()`, `.migrate()`, `.push()`, `.create()`, `.drop()`,
+//!    `.truncate(..)`, `.reset(..)`, `.refresh_materialized_view(..)`,
+//!    `.transaction(...)`, `.savepoint(...)`,
 //!    and the `(conn, params)` prepared-statement forms) into a block that
 //!    captures the rendered SQL, calls the terminal, records on `db`, and
 //!    panics with a rich `fail_with_op` report on `Err`. Async drivers get
@@ -553,7 +555,7 @@ impl VisitMut for BodyVisitor {
                     *expr = rewrite_no_capture_terminal(mc, self.async_mode);
                 }
             }
-            ("execute" | "all" | "get", 0) if !self.asyncify_only => {
+            ("execute" | "run" | "all" | "get", 0) if !self.asyncify_only => {
                 *expr = rewrite_zero_arg_terminal(mc, self.async_mode);
             }
             // Relations-query terminals — unlike `.all()`/`.get()`, their
@@ -573,9 +575,21 @@ impl VisitMut for BodyVisitor {
             {
                 *expr = rewrite_prepared_terminal(mc, self.async_mode);
             }
+            ("get_many", 4) if !self.asyncify_only => {
+                *expr = rewrite_no_capture_terminal(mc, self.async_mode);
+            }
             ("migrate" | "push", 0) if !self.asyncify_only => {
                 *expr = rewrite_no_capture_terminal(mc, self.async_mode);
             }
+            ("create" | "create_if_not_exists" | "drop", 0) if !self.asyncify_only => {
+                *expr = rewrite_no_capture_terminal(mc, self.async_mode);
+            }
+            ("truncate" | "reset", 1) if !self.asyncify_only => {
+                *expr = rewrite_no_capture_terminal(mc, self.async_mode);
+            }
+            ("refresh_materialized_view", 2) if !self.asyncify_only => {
+                *expr = rewrite_no_capture_terminal(mc, self.async_mode);
+            }
             _ => {}
         }
     }
@@ -735,7 +749,8 @@ fn rewrite_prepared_terminal(mc: &ExprMethodCall, async_mode: bool) -> Expr {
 }
 
 /// Rewrites terminal-ish methods that have no SQL to capture
-/// (`transaction`, `savepoint`, `migrate`, `push`). Just panic-on-Err with
+/// (`transaction`, `savepoint`, `migrate`, `push`, `create`, `drop`,
+/// `truncate`, `reset`, `refresh_materialized_view`). Just panic-on-Err with
 /// a rich report.
 fn rewrite_no_capture_terminal(mc: &ExprMethodCall, async_mode: bool) -> Expr {
     let await_kw = if async_mode { quote!(.await) } else { quote!() };
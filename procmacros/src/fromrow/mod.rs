@@ -265,7 +265,7 @@ fn generate_tosql_impl(
                     default_from.map_or_else(
                         || {
                             quote! {
-                                columns.push(#sql::ident(#field_name_str));
+                                columns.push(#sql::name(#field_name_str));
                             }
                         },
                         |default_table| {
@@ -293,6 +293,15 @@ fn generate_tosql_impl(
             #[doc(hidden)]
             #[allow(non_upper_case_globals)]
             #struct_vis const #select_const_ident: #selector_ident = #selector_ident;
+
+            /// Returns the SELECT list this struct decodes, for `.select(...)`.
+            ///
+            /// Built from the same field and `#[column(...)]` mappings as the
+            /// row decoder, so the query and the struct cannot drift apart.
+            #[must_use]
+            #struct_vis const fn columns() -> #selector_ident {
+                #selector_ident
+            }
         }
 
         impl<'a> #to_sql<'a, #value_type<'a>> for #struct_name {
@@ -3,6 +3,7 @@
 //! Both drivers use the shared `DrizzleRow::get_column` interface for unified type conversion
 //! via the `FromPostgresValue` trait, while standard types use the native driver's get method.
 
+use crate::common::{array_u8_len, has_json_attribute};
 use crate::postgres::field::TypeCategory;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -67,6 +68,23 @@ fn extract_inner_type(ty: &syn::Type) -> &syn::Type {
     ty
 }
 
+/// `[u8; 6]` and `[u8; 8]` hold `MACADDR`/`MACADDR8` values, which the drivers
+/// cannot decode natively.
+fn is_mac_address_bytes(ty: &syn::Type) -> bool {
+    matches!(array_u8_len(ty), Some(6 | 8))
+}
+
+/// Decode a `#[json]` field by reading the column as TEXT and deserializing it.
+fn json_accessor(index: &TokenStream) -> TokenStream {
+    quote! {
+        {
+            let json_str = row.try_get::<_, String>(#index)?;
+            serde_json::from_str(&json_str)
+                .map_err(|e| drizzle::error::DrizzleError::ConversionError(e.to_string().into()))?
+        }
+    }
+}
+
 /// Generate field assignment using the driver-agnostic approach.
 ///
 /// For special types like ArrayVec/ArrayString, uses `DrizzleRow::get_column`
@@ -93,9 +111,11 @@ pub fn generate_field_assignment(
     let needs_from_postgres_value = matches!(
         category,
         TypeCategory::ArrayString | TypeCategory::ArrayVec | TypeCategory::Uuid
-    );
+    ) || is_mac_address_bytes(target_type);
 
-    let assignment = if needs_from_postgres_value {
+    let assignment = if has_json_attribute(field) {
+        json_accessor(&idx_or_name)
+    } else if needs_from_postgres_value {
         // Use capability-specific row traits with FromPostgresValue conversion
         if is_optional {
             if field_name.is_some() {
@@ -165,9 +185,11 @@ pub fn generate_field_assignment_with_index_expr(
     let needs_from_postgres_value = matches!(
         category,
         TypeCategory::ArrayString | TypeCategory::ArrayVec | TypeCategory::Uuid
-    );
+    ) || is_mac_address_bytes(target_type);
 
-    let assignment = if needs_from_postgres_value {
+    let assignment = if has_json_attribute(field) {
+        json_accessor(idx_expr)
+    } else if needs_from_postgres_value {
         if is_optional {
             quote! {
                 {
@@ -222,9 +244,14 @@ pub fn generate_named_field_assignment_with_offset_fallback(
     let needs_from_postgres_value = matches!(
         category,
         TypeCategory::ArrayString | TypeCategory::ArrayVec | TypeCategory::Uuid
-    );
+    ) || is_mac_address_bytes(target_type);
 
-    let (by_name, by_index) = if needs_from_postgres_value {
+    let (by_name, by_index) = if has_json_attribute(field) {
+        (
+            json_accessor(&quote! { #field_name_str }),
+            json_accessor(&quote! { offset + #idx }),
+        )
+    } else if needs_from_postgres_value {
         if is_optional {
             (
                 quote! {
@@ -1,4 +1,4 @@
-use crate::common::has_json_attribute;
+use crate::common::{has_json_attribute, type_is_int, unwrap_option};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Field;
@@ -27,16 +27,28 @@ pub fn generate_field_assignment(
             quote! { #field_name_str }
         },
     );
+    let accessor = if is_unsigned_64(field) {
+        let field_type = &field.ty;
+        if field_name.is_some() {
+            quote! {
+                drizzle::sqlite::traits::DrizzleRowByName::get_column_by_name::<#field_type>(row, #idx_or_name)?
+            }
+        } else {
+            unsigned_accessor(field, &idx_or_name)
+        }
+    } else {
+        typed_accessor(field, &idx_or_name)
+    };
 
     field_name.map_or_else(
         || {
             quote! {
-                row.get(#idx_or_name)?,
+                #accessor,
             }
         },
         |field_name| {
             quote! {
-                #field_name: row.get(#idx_or_name)?,
+                #field_name: #accessor,
             }
         },
     )
@@ -54,20 +66,53 @@ pub fn generate_field_assignment_with_index_expr(
         return generate_json_field_assignment(field_name, idx_expr);
     }
 
+    let accessor = if is_unsigned_64(field) {
+        unsigned_accessor(field, idx_expr)
+    } else {
+        typed_accessor(field, idx_expr)
+    };
+
     field_name.map_or_else(
         || {
             quote! {
-                row.get(#idx_expr)?,
+                #accessor,
             }
         },
         |field_name| {
             quote! {
-                #field_name: row.get(#idx_expr)?,
+                #field_name: #accessor,
             }
         },
     )
 }
 
+/// Read through rusqlite's `FromSql`, recording the field type on failure so
+/// decode errors name what the column was expected to hold.
+fn typed_accessor(field: &Field, idx_expr: &TokenStream) -> TokenStream {
+    let field_type = &field.ty;
+    quote! {
+        row.get::<_, #field_type>(#idx_expr).map_err(|e| {
+            drizzle::error::DrizzleError::from(e)
+                .expecting(&drizzle::error::type_name::<#field_type>())
+        })?
+    }
+}
+
+/// `u64` / `usize` (or `Option` of either). rusqlite's `FromSql` can't read
+/// values above `i64::MAX`, which drizzle stores as an 8-byte BLOB, so these
+/// decode through `FromSQLiteValue` instead.
+fn is_unsigned_64(field: &Field) -> bool {
+    let ty = unwrap_option(&field.ty);
+    type_is_int(ty, "u64") || type_is_int(ty, "usize")
+}
+
+fn unsigned_accessor(field: &Field, idx_expr: &TokenStream) -> TokenStream {
+    let field_type = &field.ty;
+    quote! {
+        drizzle::sqlite::traits::DrizzleRowByIndex::get_column::<#field_type>(row, #idx_expr)?
+    }
+}
+
 /// Generate field assignment for `#[json]` fields, deserializing from a TEXT column.
 fn generate_json_field_assignment(
     field_name: Option<&syn::Ident>,
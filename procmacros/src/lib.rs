@@ -109,6 +109,7 @@
 
 extern crate proc_macro;
 
+mod bind_params;
 mod common;
 mod drizzle_test;
 mod fromrow;
@@ -140,6 +141,23 @@ use syn::parse_macro_input;
 /// - For `#[column(integer, enum)]`, variants can have explicit discriminants
 /// - Must derive `Default` to specify the default variant
 ///
+/// # Stored Names
+///
+/// TEXT storage uses the variant name unless renamed:
+/// - `#[drizzle(rename_all = "snake_case")]` on the enum applies a case convention
+///   (`lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`,
+///   `SCREAMING_SNAKE_CASE`, `kebab-case`, `SCREAMING-KEBAB-CASE`)
+/// - `#[drizzle(rename = "admin")]` on a variant overrides it for that variant
+///
+/// `Display`, `FromStr` and the `&str` conversions use the stored names.
+///
+/// # Data-Carrying Variants
+///
+/// With the `serde` feature, enums whose variants carry data (`Failed { reason: String }`)
+/// are stored as serde JSON in a TEXT column instead. The enum must also derive
+/// `Serialize` and `Deserialize`, and naming follows `#[serde(...)]` attributes.
+/// Mark the column with `#[column(json_enum)]` (or the legacy `#[text(json_enum)]`).
+///
 /// # Examples
 ///
 /// ## Text Storage (Variant Names)
@@ -314,7 +332,7 @@ use syn::parse_macro_input;
 /// - `From<EnumType>` for `SQLiteValue` - Database conversion
 /// - `TryFrom<SQLiteValue>` for `EnumType` - Database conversion
 #[cfg(feature = "sqlite")]
-#[proc_macro_derive(SQLiteEnum)]
+#[proc_macro_derive(SQLiteEnum, attributes(drizzle))]
 pub fn sqlite_enum_derive(input: TokenStream) -> TokenStream {
     use quote::quote;
     use syn::{Data, DeriveInput, parse_macro_input};
@@ -357,8 +375,18 @@ pub fn sqlite_enum_derive(input: TokenStream) -> TokenStream {
 /// # Table Attributes
 ///
 /// - `name = "table_name"` - Custom table name (defaults to struct name in `snake_case`)
+/// - `CASING = "camelCase"` - Name the table and unnamed columns in `camelCase`
+///   instead of `snake_case` (match `casing` in `drizzle.toml`)
 /// - `strict` - Enable [SQLite STRICT mode](https://sqlite.org/stricttables.html)  
 /// - `without_rowid` - Create a [WITHOUT ROWID table](https://sqlite.org/withoutrowid.html)
+/// - `ID_TYPE = UserId` - Generate a `UserId` newtype for the (single, integer or
+///   `String`) primary key. Models use it in place of the raw type, and columns
+///   declared with `references = Table::id` must use it too.
+/// - `COMMENT = "..."` - Table comment (defaults to the struct doc comment). `SQLite` has
+///   no `COMMENT` statement, so it is kept in migration snapshots only; the same applies
+///   to `#[column(COMMENT = "...")]` and field doc comments.
+/// - `DATABASE = "archive"` - Place the table in a database attached with `db.attach(path, "archive")`.
+///   Queries and `CREATE TABLE` qualify it as `"archive"."table"`; migrations skip it.
 ///
 /// # Field Attributes
 ///
@@ -374,6 +402,7 @@ pub fn sqlite_enum_derive(input: TokenStream) -> TokenStream {
 ///
 /// ## Defaults
 /// - `default = value` - Compile-time default value
+/// - `default = sql!("(unixepoch())")` / `default_sql = "..."` - SQL `DEFAULT` expression
 /// - `default_fn = function` - Runtime default function (called at insert time)
 ///
 /// ## Special Types
@@ -825,6 +854,11 @@ pub fn SQLiteView(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - `unique` - Create a unique index (enforces uniqueness constraint)
 /// - No attributes for standard index
 ///
+/// # Member Attributes
+///
+/// - `#[collate = "NOCASE"]` - Collation sequence for one indexed column,
+///   e.g. `struct EmailIdx(#[collate = "NOCASE"] Users::email);`
+///
 /// # Examples
 ///
 /// ## Unique Index
@@ -1080,7 +1114,8 @@ pub fn SQLiteIndex(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// | Rust Type | `SQLite` Type | Notes |
 /// |-----------|-------------|-------|
 /// | `i8`, `i16`, `i32`, `i64` | INTEGER | Auto-converts from i64 |
-/// | `u8`, `u16`, `u32`, `u64` | INTEGER | Auto-converts from i64 |
+/// | `u8`, `u16`, `u32` | INTEGER | Auto-converts from i64 |
+/// | `u64`, `usize` | INTEGER | Values above `i64::MAX` round-trip as an 8-byte BLOB |
 /// | `f32`, `f64` | REAL | Auto-converts from f64 |
 /// | `bool` | INTEGER | 0 = false, non-zero = true |
 /// | `String` | TEXT | |
@@ -1094,6 +1129,10 @@ pub fn SQLiteIndex(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - `#[json]` - Deserialize JSON from TEXT column (requires `serde` feature)
 /// - No attribute - Maps to column with same name as the field
 ///
+/// Named structs also get `Struct::columns()`, the SELECT list built from these
+/// mappings: `db.select(UserPost::columns()).from(users)` always selects
+/// exactly the columns the struct decodes.
+///
 /// # Struct Types
 ///
 /// Both named structs and tuple structs are supported:
@@ -1916,15 +1955,58 @@ pub fn postgres_schema_derive(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Binds a prepared statement's named placeholders from a struct's fields.
+///
+/// Each field binds the placeholder with the same name, so a reference to the
+/// struct can be passed wherever a prepared statement takes its parameters.
+/// Works with every dialect: fields are cloned and converted into the
+/// dialect's value type, so any `Clone` type that converts into it (`String`,
+/// the integer and float types, `bool`, `Option` of those) can be a field.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate self as drizzle;
+/// # pub mod core { pub use drizzle_core::*; }
+/// # fn main() {
+/// use drizzle_macros::BindParams;
+///
+/// #[derive(BindParams)]
+/// struct SearchParams {
+///     name: String,
+///     min_age: i32,
+/// }
+///
+/// // let name = users.name.placeholder("name");
+/// // let min_age = users.age.placeholder("min_age");
+/// // let stmt = db.select(()).from(users)
+/// //     .r#where(and(eq(users.name, name), gte(users.age, min_age)))
+/// //     .prepare();
+/// // let rows: Vec<SelectUsers> =
+/// //     stmt.all(db.conn(), &SearchParams { name: "Alice".into(), min_age: 18 })?;
+/// # }
+/// ```
+#[proc_macro_derive(BindParams)]
+pub fn bind_params_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match crate::bind_params::generate_bind_params_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 /// A procedural macro for building SQL queries with embedded expressions.
 ///
 /// This macro supports two different syntax forms:
-/// 1. **String literal syntax**: `sql!("SELECT * FROM {table}")`
-/// 2. **Printf-style syntax**: `sql!("SELECT * FROM {} WHERE {} = {}", table, column, value)`
+/// 1. **String literal syntax**: `sql!("SELECT * FROM {#table}")`
+/// 2. **Printf-style syntax**: `sql!("SELECT * FROM {#} WHERE {#} = {}", table, column, value)`
 ///
 /// The macro parses SQL templates and generates type-safe SQL code by:
 /// - Converting literal text to `SQL::text()` calls
-/// - Converting expressions in `{braces}` to `.to_sql()` calls on the expression
+/// - Binding values in `{braces}` as parameters
+/// - Splicing tables, columns and expressions in `{#braces}` via `.to_sql()`
+/// - Emitting strings in `{!braces}` as raw SQL text
 ///
 /// # Syntax Forms
 ///
@@ -2001,7 +2083,7 @@ pub fn postgres_schema_derive(input: TokenStream) -> TokenStream {
 /// pub struct Schema { pub users: Users }
 ///
 /// let users = Users::default();
-/// let query = sql!("SELECT * FROM {users} WHERE {users.id} = 42");
+/// let query = sql!("SELECT * FROM {#users} WHERE {#users.id} = 42");
 /// # }
 /// ```
 ///
@@ -2077,7 +2159,7 @@ pub fn postgres_schema_derive(input: TokenStream) -> TokenStream {
 /// pub struct Schema { pub users: Users }
 ///
 /// let users = Users::default();
-/// let query = sql!("SELECT * FROM {} WHERE {} = {}", users, users.id, 42);
+/// let query = sql!("SELECT * FROM {#} WHERE {#} = {}", users, users.id, 42);
 /// # }
 /// ```
 ///
@@ -2149,7 +2231,7 @@ pub fn postgres_schema_derive(input: TokenStream) -> TokenStream {
 /// }
 ///
 /// let users = Users::default();
-/// let query = drizzle::sql!("SELECT * FROM {users}");
+/// let query = drizzle::sql!("SELECT * FROM {#users}");
 /// // Generates: SQL::text("SELECT * FROM ").append(users.to_sql())
 /// # }
 /// ```
@@ -2228,7 +2310,7 @@ pub fn postgres_schema_derive(input: TokenStream) -> TokenStream {
 ///
 /// let users = Users::default();
 /// let posts = Posts::default();
-/// let query = drizzle::sql!("SELECT * FROM {users} WHERE {users.id} = {posts.author_id}");
+/// let query = drizzle::sql!("SELECT * FROM {#users} WHERE {#users.id} = {#posts.author_id}");
 /// # }
 /// ```
 ///
@@ -2300,19 +2382,90 @@ pub fn postgres_schema_derive(input: TokenStream) -> TokenStream {
 /// }
 ///
 /// let users = Users::default();
-/// let query = drizzle::sql!("SELECT JSON_OBJECT('key', {{literal}}) FROM {users}");
+/// let query = drizzle::sql!("SELECT JSON_OBJECT('key', {{literal}}) FROM {#users}");
 /// // Generates: SQL::text("SELECT JSON_OBJECT('key', {literal}) FROM ").append(users.to_sql())
 /// # }
 /// ```
 ///
+/// ## Bound Parameters
+///
+/// Plain `{expr}` binds values that map to a column type (numbers, strings,
+/// blobs, and `Option`s of them). Add `:param` to force a bound parameter for
+/// any other value that converts into the dialect value type:
+///
+/// ```rust
+/// # let _ = r####"
+/// let query = sql!("SELECT * FROM {#users} WHERE {#users.id} = {id}");
+/// let query = sql!("SELECT * FROM {#users} WHERE {#users.id} = {id:param}");
+/// let query = sql!("SELECT * FROM {#} WHERE {#} = {:param}", users, users.id, id);
+/// # "####;
+/// ```
+///
+/// ## Splicing SQL
+///
+/// `{#expr}` splices anything implementing `ToSQL` (tables, columns,
+/// expressions, other `sql!` fragments). `{!expr}` emits a string as raw SQL
+/// text and is never escaped, so only use it with trusted input:
+///
+/// ```rust
+/// # let _ = r####"
+/// let order = if newest_first { "DESC" } else { "ASC" };
+/// let query = sql!("SELECT * FROM {#users} ORDER BY {#users.id} {!order}");
+/// # "####;
+/// ```
+///
+/// ## Migrating
+///
+/// Plain `{expr}` used to splice via `.to_sql()`. Interpolating a non-value
+/// that way still compiles but emits a deprecation warning; switch it to
+/// `{#expr}` or `{!expr}`. [`sql_unsafe_raw!`] keeps the old splicing
+/// behaviour for code that cannot migrate yet.
+///
+/// ## Typed Output
+///
+/// End the arguments with `as Type` to declare the row the fragment produces.
+/// Selecting it lets `.all()` / `.get()` infer that row type:
+///
+/// ```rust
+/// # let _ = r####"
+/// let (count, newest): (i64, String) = db
+///     .select(sql!("count(*), max({#users.name})", as (i64, String)))
+///     .from(users)
+///     .get()?;
+/// # "####;
+/// ```
+///
 /// # Requirements
 ///
-/// All expressions within `{braces}` must implement the `ToSQL` trait.
+/// `{expr}` and `{expr:param}` values must convert into the dialect value
+/// type, `{#expr}` must implement the `ToSQL` trait, and `{!expr}` must
+/// convert into `Cow<str>`.
 #[proc_macro]
 pub fn sql(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as crate::sql::SqlInput);
 
-    match crate::sql::sql_impl(input) {
+    match crate::sql::sql_impl(input, crate::sql::InterpolationMode::Safe) {
+        Ok(output) => output.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// [`sql!`] with the pre-`{#expr}` interpolation rules.
+///
+/// Plain `{expr}` calls `.to_sql()` on the expression, so strings and other
+/// `ToSQL` values are spliced exactly as they render instead of being bound.
+/// `{#expr}`, `{!expr}`, `:param` and `as Type` work as in [`sql!`].
+///
+/// ```rust
+/// # let _ = r####"
+/// let query = sql_unsafe_raw!("SELECT * FROM {users} WHERE {users.id} = {id}");
+/// # "####;
+/// ```
+#[proc_macro]
+pub fn sql_unsafe_raw(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as crate::sql::SqlInput);
+
+    match crate::sql::sql_impl(input, crate::sql::InterpolationMode::Legacy) {
         Ok(output) => output.into(),
         Err(err) => err.into_compile_error().into(),
     }
@@ -2407,6 +2560,15 @@ pub fn include_migrations(input: TokenStream) -> TokenStream {
 /// - `#[drizzle::test(sqlite)]` — force `SQLite` driver expansion.
 /// - `#[drizzle::test(postgres)]` — force `PostgreSQL` driver expansion.
 ///
+/// # `PostgreSQL` variants
+///
+/// `PostgreSQL` tests expand to `<name>_postgres_sync` and
+/// `<name>_tokio_postgres` modules. Each connects to `DATABASE_URL` (or an
+/// embedded server with the `postgres-embedded` feature), creates a fresh
+/// schema for the test, points `search_path` at it, and drops it when the
+/// `TestDb` goes out of scope, so tests run in parallel without sharing
+/// tables.
+///
 /// # Signature requirements
 ///
 /// ```ignore
@@ -2441,6 +2603,13 @@ pub fn test(args: TokenStream, item: TokenStream) -> TokenStream {
 /// - For INTEGER storage, add an integer repr (`#[repr(i32)]`, `#[repr(i16)]`, etc.)
 /// - Must derive `Default` to specify the default variant
 ///
+/// # Stored Names
+///
+/// ENUM labels use the variant name unless renamed with
+/// `#[drizzle(rename_all = "snake_case")]` on the enum or
+/// `#[drizzle(rename = "admin")]` on a variant (same rules as `SQLiteEnum`).
+/// `CREATE TYPE`, `Display` and `FromStr` all use the stored names.
+///
 /// # Examples
 ///
 /// ## Native `PostgreSQL` ENUM Type (Default)
@@ -2607,7 +2776,7 @@ pub fn test(args: TokenStream, item: TokenStream) -> TokenStream {
 /// - `From<EnumType>` for `PostgresValue` - Database conversion
 /// - `TryFrom<PostgresValue>` for `EnumType` - Database conversion
 #[cfg(feature = "postgres")]
-#[proc_macro_derive(PostgresEnum)]
+#[proc_macro_derive(PostgresEnum, attributes(drizzle))]
 pub fn postgres_enum_derive(input: TokenStream) -> TokenStream {
     use quote::quote;
     use syn::{Data, DeriveInput, parse_macro_input};
@@ -2650,9 +2819,16 @@ pub fn postgres_enum_derive(input: TokenStream) -> TokenStream {
 /// # Table Attributes
 ///
 /// - `name = "table_name"` - Custom table name (defaults to struct name in `snake_case`)
+/// - `CASING = "camelCase"` - Name the table and unnamed columns in `camelCase`
+///   instead of `snake_case` (match `casing` in `drizzle.toml`)
 /// - `unlogged` - Create UNLOGGED table for better performance  
 /// - `temporary` - Create TEMPORARY table
 /// - `if_not_exists` - Add IF NOT EXISTS clause
+/// - `ID_TYPE = UserId` - Generate a `UserId` newtype for the (single, integer or
+///   `String`) primary key. Models use it in place of the raw type, and columns
+///   declared with `references = Table::id` must use it too.
+/// - `COMMENT = "..."` - Emit `COMMENT ON TABLE` (defaults to the struct doc comment).
+///   `#[column(COMMENT = "...")]` does the same for a column, overriding its doc comment.
 ///
 /// # Field Attributes
 ///
@@ -2667,6 +2843,7 @@ pub fn postgres_enum_derive(input: TokenStream) -> TokenStream {
 ///
 /// ## Defaults
 /// - `default = value` - Compile-time default value
+/// - `default = sql!("now()")` / `default_sql = "..."` - SQL `DEFAULT` expression
 /// - `default_fn = function` - Runtime default function
 ///
 /// ## Special Types
@@ -2959,7 +3136,8 @@ pub fn PostgresTable(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - `name/NAME = "view_name"` - Optional view name (defaults to struct name in `snake_case`)
 /// - `schema/SCHEMA = "schema_name"` - Optional schema (defaults to public)
 /// - `definition/DEFINITION = "SELECT ..."` - View definition SQL
-/// - `materialized/MATERIALIZED` - Mark as materialized view
+/// - `materialized/MATERIALIZED` - Mark as materialized view (which can then be
+///   indexed with `#[PostgresIndex]`)
 /// - `with/WITH = ViewWithOptionDef::new()...` - WITH options
 /// - `with_no_data/WITH_NO_DATA` - Materialized view WITH NO DATA
 /// - `using/USING = "..."` - USING clause for materialized views
@@ -2988,6 +3166,11 @@ pub fn PostgresView(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - `unique` - Create a unique index
 /// - No attributes for standard index
 ///
+/// # Member Attributes
+///
+/// - `#[collation = "und-x-icu"]` (or `collate`) - Collation for one indexed
+///   column, e.g. `struct EmailIdx(#[collation = "und-x-icu"] Users::email);`
+///
 /// # Examples
 ///
 /// ## Unique Index
@@ -3210,3 +3393,141 @@ pub fn PostgresPolicy(attr: TokenStream, item: TokenStream) -> TokenStream {
         Err(err) => err.to_compile_error().into(),
     }
 }
+
+/// Attribute macro for creating `PostgreSQL` domain types.
+///
+/// Apply this to a unit struct. The domain is created before the tables of a
+/// `PostgresSchema` and columns opt into it with `#[column(domain = ...)]`,
+/// keeping their Rust type while the database enforces the domain's
+/// constraints:
+///
+/// ```rust,no_run
+/// # extern crate self as drizzle;
+/// #  pub mod core { pub use drizzle_core::*; pub use drizzle_core::schema::{SQLDomainInfo, SQLEnumInfo}; }
+/// #  pub mod error { pub use drizzle_core::error::*; }
+/// #  pub use drizzle_types as ddl;
+/// #  pub mod postgres {
+/// #      pub mod values { pub use drizzle_postgres::values::*; }
+/// #      pub mod traits { pub use drizzle_postgres::traits::*; }
+/// #      pub mod common { pub use drizzle_postgres::common::*; }
+/// #      pub mod attrs { pub use drizzle_postgres::attrs::*; }
+/// #      pub mod builder { pub use drizzle_postgres::builder::*; }
+/// #      pub mod helpers { pub use drizzle_postgres::helpers::*; }
+/// #      pub mod expr { pub use drizzle_postgres::expr::*; }
+/// #      pub mod types { pub use drizzle_postgres::types::*; pub use drizzle_types::postgres::types::Int4 as Integer; }
+/// #      #[cfg(all(feature = "postgres-sync", not(feature = "tokio-postgres")))]
+/// #      pub use ::postgres::Row;
+/// #      #[cfg(feature = "tokio-postgres")]
+/// #      pub use ::tokio_postgres::Row;
+/// #      #[cfg(not(any(feature = "postgres-sync", feature = "tokio-postgres")))]
+/// #      pub struct Row;
+/// #      pub mod prelude {
+/// #          #[cfg(feature = "postgres")]
+/// #          pub use drizzle_macros::{PostgresTable, PostgresSchema, PostgresEnum, PostgresIndex, PostgresPolicy, PostgresDomain, PostgresFromRow};
+/// #          pub use drizzle_postgres::attrs::*;
+/// #          pub use drizzle_postgres::common::PostgresSchemaType;
+/// #          pub use drizzle_postgres::traits::{PostgresColumn, PostgresTable};
+/// #          pub use drizzle_postgres::values::{PostgresInsertValue, PostgresUpdateValue, PostgresValue};
+/// #          pub use drizzle_core::*;
+/// #      }
+/// #  }
+/// # fn main() {
+/// use drizzle::postgres::prelude::*;
+///
+/// #[PostgresDomain(name = "email", base = text, check = "VALUE ~ '^[^@]+@[^@]+$'")]
+/// struct Email;
+///
+/// #[PostgresTable]
+/// struct Users {
+///     #[column(primary)]
+///     id: i32,
+///     #[column(domain = Email)]
+///     email: String,
+/// }
+/// # }
+/// ```
+#[cfg(feature = "postgres")]
+#[allow(non_snake_case)]
+#[proc_macro_attribute]
+pub fn PostgresDomain(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+    let attr_input = syn::parse_macro_input!(attr as crate::postgres::domain::DomainAttributes);
+
+    match crate::postgres::domain::postgres_domain_attr_macro(&attr_input, &input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Attribute macro for creating `PostgreSQL` composite types.
+///
+/// Apply this to a struct with named fields. The struct becomes a column
+/// value type stored as the named row type, and a `<Struct>Composite` schema
+/// item is generated so a `PostgresSchema` creates the type before its tables:
+///
+/// ```rust,no_run
+/// # extern crate self as drizzle;
+/// #  pub mod core { pub use drizzle_core::*; pub use drizzle_core::schema::{SQLCompositeInfo, SQLEnumInfo}; }
+/// #  pub mod error { pub use drizzle_core::error::*; }
+/// #  pub use drizzle_types as ddl;
+/// #  pub mod postgres {
+/// #      pub mod values { pub use drizzle_postgres::values::*; }
+/// #      pub mod traits { pub use drizzle_postgres::traits::*; }
+/// #      pub mod common { pub use drizzle_postgres::common::*; }
+/// #      pub mod attrs { pub use drizzle_postgres::attrs::*; }
+/// #      pub mod builder { pub use drizzle_postgres::builder::*; }
+/// #      pub mod helpers { pub use drizzle_postgres::helpers::*; }
+/// #      pub mod expr { pub use drizzle_postgres::expr::*; }
+/// #      pub use drizzle_postgres::composite;
+/// #      pub mod types { pub use drizzle_postgres::types::*; pub use drizzle_types::postgres::types::Int4 as Integer; }
+/// #      #[cfg(all(feature = "postgres-sync", not(feature = "tokio-postgres")))]
+/// #      pub use ::postgres::Row;
+/// #      #[cfg(feature = "tokio-postgres")]
+/// #      pub use ::tokio_postgres::Row;
+/// #      #[cfg(not(any(feature = "postgres-sync", feature = "tokio-postgres")))]
+/// #      pub struct Row;
+/// #      pub mod prelude {
+/// #          #[cfg(feature = "postgres")]
+/// #          pub use drizzle_macros::{PostgresTable, PostgresSchema, PostgresEnum, PostgresIndex, PostgresPolicy, PostgresComposite, PostgresFromRow};
+/// #          pub use drizzle_postgres::attrs::*;
+/// #          pub use drizzle_postgres::common::PostgresSchemaType;
+/// #          pub use drizzle_postgres::traits::{PostgresColumn, PostgresTable};
+/// #          pub use drizzle_postgres::values::{PostgresInsertValue, PostgresUpdateValue, PostgresValue};
+/// #          pub use drizzle_core::*;
+/// #      }
+/// #  }
+/// # fn main() {
+/// use drizzle::postgres::prelude::*;
+///
+/// #[PostgresComposite(name = "address_type")]
+/// #[derive(Debug, Clone, PartialEq)]
+/// # #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// struct Address {
+///     street: Option<String>,
+///     zip: Option<i32>,
+/// }
+///
+/// #[PostgresTable]
+/// struct Users {
+///     #[column(primary)]
+///     id: i32,
+///     address: Option<Address>,
+/// }
+///
+/// // List `AddressComposite` in the `PostgresSchema` to create the type
+/// let _ = AddressComposite::new();
+/// # }
+/// ```
+#[cfg(feature = "postgres")]
+#[allow(non_snake_case)]
+#[proc_macro_attribute]
+pub fn PostgresComposite(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+    let attr_input =
+        syn::parse_macro_input!(attr as crate::postgres::composite::CompositeAttributes);
+
+    match crate::postgres::composite::postgres_composite_attr_macro(&attr_input, &input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -61,6 +61,15 @@ pub mod core {
         quote!(drizzle::core::SQL)
     }
 
+    pub fn typed_sql() -> TokenStream {
+        quote!(drizzle::core::TypedSQL)
+    }
+
+    /// Path to the `sql!` interpolation dispatch module.
+    pub fn sql_interpolate() -> TokenStream {
+        quote!(drizzle::core::sql_interpolate)
+    }
+
     pub fn sql_column_info() -> TokenStream {
         quote!(drizzle::core::SQLColumnInfo)
     }
@@ -113,6 +122,10 @@ pub mod core {
         quote!(drizzle::core::ConstraintRef)
     }
 
+    pub fn exclusion_ref() -> TokenStream {
+        quote!(drizzle::core::ExclusionRef)
+    }
+
     pub fn sql_foreign_key() -> TokenStream {
         quote!(drizzle::core::SQLForeignKey)
     }
@@ -281,6 +294,10 @@ pub mod core {
         quote!(drizzle::core::ParamBind)
     }
 
+    pub fn bind_params() -> TokenStream {
+        quote!(drizzle::core::BindParams)
+    }
+
     pub fn token() -> TokenStream {
         quote!(drizzle::core::Token)
     }
@@ -293,10 +310,26 @@ pub mod core {
         quote!(drizzle::core::SQLSchemaImpl)
     }
 
+    pub fn schema_item_hash() -> TokenStream {
+        quote!(drizzle::core::schema_item_hash)
+    }
+
+    pub fn undeferred_fk_cycle() -> TokenStream {
+        quote!(drizzle::core::undeferred_fk_cycle)
+    }
+
     pub fn sql_enum_info() -> TokenStream {
         quote!(drizzle::core::SQLEnumInfo)
     }
 
+    pub fn sql_domain_info() -> TokenStream {
+        quote!(drizzle::core::SQLDomainInfo)
+    }
+
+    pub fn sql_composite_info() -> TokenStream {
+        quote!(drizzle::core::SQLCompositeInfo)
+    }
+
     pub fn impl_try_from_int() -> TokenStream {
         quote!(drizzle::core::impl_try_from_int)
     }
@@ -466,6 +499,10 @@ pub mod sqlite {
         quote!(drizzle::sqlite::traits::DrizzleSQLiteColumn)
     }
 
+    pub fn custom_sqlite_type() -> TokenStream {
+        quote!(drizzle::sqlite::traits::CustomSQLiteType)
+    }
+
     pub fn drizzle_row() -> TokenStream {
         quote!(drizzle::sqlite::traits::DrizzleRowByIndex)
     }
@@ -571,10 +608,30 @@ pub mod ddl {
             quote!(drizzle::ddl::postgres::ddl::CheckConstraintDef)
         }
 
+        pub fn exclude_constraint_def() -> TokenStream {
+            quote!(drizzle::ddl::postgres::ddl::ExcludeConstraintDef)
+        }
+
+        pub fn exclude_element() -> TokenStream {
+            quote!(drizzle::ddl::postgres::ddl::ExcludeElement)
+        }
+
         pub fn policy_def() -> TokenStream {
             quote!(drizzle::ddl::postgres::ddl::PolicyDef)
         }
 
+        pub fn domain_def() -> TokenStream {
+            quote!(drizzle::ddl::postgres::ddl::DomainDef)
+        }
+
+        pub fn composite_def() -> TokenStream {
+            quote!(drizzle::ddl::postgres::ddl::CompositeDef)
+        }
+
+        pub fn composite_field_def() -> TokenStream {
+            quote!(drizzle::ddl::postgres::ddl::CompositeFieldDef)
+        }
+
         pub fn view() -> TokenStream {
             quote!(drizzle::ddl::postgres::ddl::View)
         }
@@ -591,6 +648,10 @@ pub mod ddl {
             quote!(drizzle::ddl::postgres::ddl::IndexColumn)
         }
 
+        pub fn opclass() -> TokenStream {
+            quote!(drizzle::ddl::postgres::ddl::Opclass)
+        }
+
         pub fn index_column_def() -> TokenStream {
             quote!(drizzle::ddl::postgres::ddl::IndexColumnDef)
         }
@@ -789,6 +850,10 @@ pub mod migrations {
             quote!(drizzle::ddl::postgres::ddl::IndexColumn)
         }
 
+        pub fn opclass() -> TokenStream {
+            quote!(drizzle::ddl::postgres::ddl::Opclass)
+        }
+
         pub fn primary_key() -> TokenStream {
             quote!(drizzle::ddl::postgres::ddl::PrimaryKey)
         }
@@ -809,6 +874,14 @@ pub mod migrations {
             quote!(drizzle::ddl::postgres::ddl::CheckConstraint)
         }
 
+        pub fn exclude_constraint() -> TokenStream {
+            quote!(drizzle::ddl::postgres::ddl::ExcludeConstraint)
+        }
+
+        pub fn exclude_element() -> TokenStream {
+            quote!(drizzle::ddl::postgres::ddl::ExcludeElement)
+        }
+
         pub fn policy() -> TokenStream {
             quote!(drizzle::ddl::postgres::ddl::Policy)
         }
@@ -833,6 +906,18 @@ pub mod migrations {
             quote!(drizzle::ddl::postgres::ddl::Enum)
         }
 
+        pub fn domain() -> TokenStream {
+            quote!(drizzle::ddl::postgres::ddl::Domain)
+        }
+
+        pub fn composite() -> TokenStream {
+            quote!(drizzle::ddl::postgres::ddl::Composite)
+        }
+
+        pub fn composite_field() -> TokenStream {
+            quote!(drizzle::ddl::postgres::ddl::CompositeField)
+        }
+
         pub fn referential_action() -> TokenStream {
             quote!(drizzle::ddl::postgres::ddl::ReferentialAction)
         }
@@ -855,6 +940,10 @@ pub mod postgres {
         quote!(drizzle::postgres::values::PostgresValue)
     }
 
+    pub fn owned_postgres_value() -> TokenStream {
+        quote!(drizzle::postgres::values::OwnedPostgresValue)
+    }
+
     pub fn postgres_insert_value() -> TokenStream {
         quote!(drizzle::postgres::values::PostgresInsertValue)
     }
@@ -909,4 +998,12 @@ pub mod postgres {
     pub fn drizzle_postgres_column() -> TokenStream {
         quote!(drizzle::postgres::traits::DrizzlePostgresColumn)
     }
+
+    pub fn composite() -> TokenStream {
+        quote!(drizzle::postgres::composite)
+    }
+
+    pub fn custom_postgres_type() -> TokenStream {
+        quote!(drizzle::postgres::traits::CustomPostgresType)
+    }
 }
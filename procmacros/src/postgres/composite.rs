@@ -0,0 +1,306 @@
+use super::field::TypeCategory;
+use crate::paths::{core as core_paths, ddl::postgres as ddl_paths, postgres as postgres_paths};
+use heck::AsSnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, Error, Meta, Result, Token, parse::Parse};
+
+/// Attributes for the `PostgresComposite` attribute macro.
+///
+/// Syntax:
+/// `#[PostgresComposite(name = "address_type")]`
+#[derive(Default)]
+pub struct CompositeAttributes {
+    pub name: Option<String>,
+}
+
+impl Parse for CompositeAttributes {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let mut attrs = Self::default();
+        if input.is_empty() {
+            return Ok(attrs);
+        }
+
+        let metas = input.parse_terminated(Meta::parse, Token![,])?;
+        for meta in metas {
+            match meta {
+                Meta::NameValue(nv)
+                    if nv
+                        .path
+                        .get_ident()
+                        .is_some_and(|ident| ident.to_string().eq_ignore_ascii_case("NAME")) =>
+                {
+                    let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(value),
+                        ..
+                    }) = &nv.value
+                    else {
+                        return Err(Error::new_spanned(
+                            nv.value,
+                            "composite name must be a string literal",
+                        ));
+                    };
+                    attrs.name = Some(value.value());
+                }
+                _ => {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "unrecognized PostgresComposite attribute; expected name",
+                    ));
+                }
+            }
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// Generates the `PostgresComposite` implementation.
+///
+/// The struct itself is the column value type; a `<Struct>Composite` unit
+/// struct is generated alongside it as the `PostgresSchema` member that
+/// creates the type.
+pub fn postgres_composite_attr_macro(
+    attr: &CompositeAttributes,
+    input: &DeriveInput,
+) -> Result<TokenStream> {
+    let struct_ident = &input.ident;
+    let struct_vis = &input.vis;
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) if !fields.named.is_empty() => &fields.named,
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "PostgresComposite can only be applied to structs with named fields",
+            ));
+        }
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "PostgresComposite does not support generic structs",
+        ));
+    }
+
+    let composite_name = attr
+        .name
+        .clone()
+        .unwrap_or_else(|| AsSnakeCase(struct_ident.to_string()).to_string());
+    let schema_ident = format_ident!("{}Composite", struct_ident);
+
+    let sql = core_paths::sql();
+    let sql_schema = core_paths::sql_schema();
+    let sql_composite_info = core_paths::sql_composite_info();
+    let schema_item_tables = core_paths::schema_item_tables();
+    let type_set_nil = core_paths::type_set_nil();
+    let to_sql = core_paths::to_sql();
+    let postgres_value = postgres_paths::postgres_value();
+    let owned_postgres_value = postgres_paths::owned_postgres_value();
+    let postgres_schema_type = postgres_paths::postgres_schema_type();
+    let postgres_types = postgres_paths::types();
+    let drizzle_postgres_column = postgres_paths::drizzle_postgres_column();
+    let composite_def = ddl_paths::composite_def();
+    let composite_field_def = ddl_paths::composite_field_def();
+    let const_format = crate::common::paths::const_format();
+
+    let mut field_idents = Vec::with_capacity(fields.len());
+    let mut field_names = Vec::with_capacity(fields.len());
+    let mut field_sql_types = Vec::with_capacity(fields.len());
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named fields have idents");
+        let base_type = crate::common::unwrap_option(&field.ty);
+        // Attribute types come from the Rust type, falling back to
+        // `DrizzlePostgresColumn::SQL_TYPE` for enums and nested composites.
+        let sql_type = TypeCategory::from_type(base_type)
+            .to_postgres_type()
+            .map_or_else(
+                || quote!(<#base_type as #drizzle_postgres_column>::SQL_TYPE),
+                |pg_type| {
+                    let sql_type = pg_type.to_sql_type();
+                    quote!(#sql_type)
+                },
+            );
+        field_idents.push(ident);
+        field_names.push(ident.to_string());
+        field_sql_types.push(sql_type);
+    }
+
+    let mut create_pieces = vec![quote!(::core::concat!(
+        "CREATE TYPE \"",
+        #composite_name,
+        "\" AS ("
+    ))];
+    for (i, (name, sql_type)) in field_names.iter().zip(&field_sql_types).enumerate() {
+        let separator = if i == 0 { "" } else { ", " };
+        let prefix = format!("{separator}\"{name}\" ");
+        create_pieces.push(quote!(#prefix));
+        create_pieces.push(sql_type.clone());
+    }
+    create_pieces.push(quote!(");"));
+
+    #[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+    let decode_impl = {
+        let drizzle_error = core_paths::drizzle_error();
+        quote! {
+            fn decode(row: &drizzle::postgres::Row, idx: usize) -> ::std::result::Result<Self, #drizzle_error> {
+                row.try_get::<_, #struct_ident>(idx)
+                    .map_err(|e| #drizzle_error::ConversionError(e.to_string().into()))
+            }
+        }
+    };
+    #[cfg(not(any(feature = "postgres-sync", feature = "tokio-postgres")))]
+    let decode_impl = quote! {};
+
+    // FromSql/ToSql impls for whichever driver the user crate enables
+    #[cfg(feature = "postgres")]
+    let driver_impls = {
+        let composite = postgres_paths::composite();
+        let driver_impl = |types: TokenStream| {
+            quote! {
+                impl<'a> #types::FromSql<'a> for #struct_ident {
+                    fn from_sql(
+                        ty: &#types::Type,
+                        raw: &'a [u8],
+                    ) -> ::std::result::Result<Self, ::std::boxed::Box<dyn ::std::error::Error + ::core::marker::Sync + ::core::marker::Send>> {
+                        let mut reader = #composite::CompositeReader::new(ty, raw)?;
+                        ::std::result::Result::Ok(Self {
+                            #(#field_idents: reader.read()?,)*
+                        })
+                    }
+
+                    fn accepts(ty: &#types::Type) -> bool {
+                        #composite::accepts(ty, #composite_name)
+                    }
+                }
+
+                impl #types::ToSql for #struct_ident {
+                    fn to_sql(
+                        &self,
+                        ty: &#types::Type,
+                        out: &mut ::bytes::BytesMut,
+                    ) -> ::std::result::Result<#types::IsNull, ::std::boxed::Box<dyn ::std::error::Error + ::core::marker::Sync + ::core::marker::Send>> {
+                        #types::ToSql::to_sql(&#drizzle_postgres_column::encode(self), ty, out)
+                    }
+
+                    fn accepts(ty: &#types::Type) -> bool {
+                        #composite::accepts(ty, #composite_name)
+                    }
+
+                    #types::to_sql_checked!();
+                }
+            }
+        };
+        let tokio_impls = driver_impl(quote!(::tokio_postgres::types));
+        let sync_impls = driver_impl(quote!(::postgres::types));
+        quote! {
+            #[cfg(feature = "tokio-postgres")]
+            const _: () = {
+                #tokio_impls
+            };
+
+            #[cfg(all(feature = "postgres-sync", not(feature = "tokio-postgres")))]
+            const _: () = {
+                #sync_impls
+            };
+        }
+    };
+    #[cfg(not(feature = "postgres"))]
+    let driver_impls = quote! {};
+
+    let schema_doc = format!(
+        "Schema item that creates the `{composite_name}` composite type of [`{struct_ident}`]."
+    );
+
+    Ok(quote! {
+        #input
+
+        impl #struct_ident {
+            pub const DDL_COMPOSITE: #composite_def = #composite_def::new(
+                "public",
+                #composite_name,
+                &[#(#composite_field_def::new(#field_names, #field_sql_types)),*],
+            );
+
+            /// Generate CREATE TYPE SQL using the DDL definition.
+            pub fn create_composite_sql() -> ::std::string::String {
+                Self::DDL_COMPOSITE.into_composite().create_composite_sql()
+            }
+        }
+
+        impl #drizzle_postgres_column for #struct_ident {
+            type SQLType = #postgres_types::Any;
+            const SQL_TYPE: &'static str = #composite_name;
+            const NEEDS_CREATE_TYPE: bool = true;
+
+            #decode_impl
+
+            fn encode(&self) -> #postgres_value<'_> {
+                #postgres_value::Composite(::std::vec![
+                    #(#postgres_value::from(::core::clone::Clone::clone(&self.#field_idents)),)*
+                ])
+            }
+
+            fn encode_owned(self) -> #owned_postgres_value {
+                #owned_postgres_value::Composite(::std::vec![
+                    #(#owned_postgres_value::from(#postgres_value::from(self.#field_idents)),)*
+                ])
+            }
+        }
+
+        #driver_impls
+
+        #[doc = #schema_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #struct_vis struct #schema_ident;
+
+        impl #schema_ident {
+            pub const fn new() -> Self {
+                Self
+            }
+        }
+
+        impl Default for #schema_ident {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl #sql_composite_info for #schema_ident {
+            fn name(&self) -> &'static str {
+                #composite_name
+            }
+
+            fn fields(&self) -> &'static [(&'static str, &'static str)] {
+                const FIELDS: &[(&str, &str)] = &[#((#field_names, #field_sql_types)),*];
+                FIELDS
+            }
+
+            fn create_composite_sql(&self) -> ::std::string::String {
+                <Self as #sql_schema<'_, #postgres_schema_type, #postgres_value<'_>>>::SQL.to_string()
+            }
+        }
+
+        impl<'a> #sql_schema<'a, #postgres_schema_type, #postgres_value<'a>> for #schema_ident {
+            const NAME: &'static str = #composite_name;
+            const TYPE: #postgres_schema_type = {
+                #[allow(non_upper_case_globals)]
+                static COMPOSITE_INSTANCE: #schema_ident = #schema_ident::new();
+                #postgres_schema_type::Composite(&COMPOSITE_INSTANCE)
+            };
+            const SQL: &'static str = #const_format::concatcp!(#(#create_pieces),*);
+        }
+
+        impl<'a> #to_sql<'a, #postgres_value<'a>> for #schema_ident {
+            fn to_sql(&self) -> #sql<'a, #postgres_value<'a>> {
+                #sql::raw(<Self as #sql_composite_info>::create_composite_sql(self))
+            }
+        }
+
+        impl #schema_item_tables for #schema_ident {
+            type Tables = #type_set_nil;
+        }
+    })
+}
@@ -0,0 +1,232 @@
+use crate::paths::{core as core_paths, ddl::postgres as ddl_paths, postgres as postgres_paths};
+use heck::AsSnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error, Meta, Result, Token, parse::Parse};
+
+/// Attributes for the `PostgresDomain` attribute macro.
+///
+/// Syntax:
+/// `#[PostgresDomain(name = "email", base = text, not_null, default = "...", check = "VALUE ~ '...'")]`
+#[derive(Default)]
+pub struct DomainAttributes {
+    pub name: Option<String>,
+    pub base: Option<String>,
+    pub not_null: bool,
+    pub default: Option<String>,
+    pub check: Option<String>,
+}
+
+impl Parse for DomainAttributes {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let mut attrs = Self::default();
+        if input.is_empty() {
+            return Ok(attrs);
+        }
+
+        let metas = input.parse_terminated(Meta::parse, Token![,])?;
+        for meta in metas {
+            match meta {
+                Meta::Path(path)
+                    if path.get_ident().is_some_and(|ident| {
+                        ident.to_string().eq_ignore_ascii_case("NOT_NULL")
+                    }) =>
+                {
+                    attrs.not_null = true;
+                }
+                Meta::NameValue(nv) => {
+                    let Some(ident) = nv.path.get_ident() else {
+                        return Err(Error::new_spanned(
+                            nv.path,
+                            "expected domain attribute name",
+                        ));
+                    };
+                    let key = ident.to_string().to_ascii_uppercase();
+                    let value = match &nv.value {
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(value),
+                            ..
+                        }) => value.value(),
+                        syn::Expr::Path(path) if key == "BASE" => match path.path.get_ident() {
+                            Some(base) => base.to_string(),
+                            None => {
+                                return Err(Error::new_spanned(
+                                    path,
+                                    "base must be a type name or string literal",
+                                ));
+                            }
+                        },
+                        other => {
+                            return Err(Error::new_spanned(
+                                other,
+                                "domain attribute values must be string literals",
+                            ));
+                        }
+                    };
+                    match key.as_str() {
+                        "NAME" => attrs.name = Some(value),
+                        "BASE" => attrs.base = Some(value),
+                        "DEFAULT" => attrs.default = Some(value),
+                        "CHECK" => attrs.check = Some(value),
+                        _ => {
+                            return Err(Error::new_spanned(
+                                ident,
+                                "unrecognized PostgresDomain attribute; expected name, base, not_null, default, or check",
+                            ));
+                        }
+                    }
+                }
+                _ => {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "unrecognized PostgresDomain attribute; expected name, base, not_null, default, or check",
+                    ));
+                }
+            }
+        }
+
+        Ok(attrs)
+    }
+}
+
+fn option_tokens(value: Option<&String>) -> TokenStream {
+    value.map_or_else(
+        || quote! { ::core::option::Option::None },
+        |value| quote! { ::core::option::Option::Some(#value) },
+    )
+}
+
+/// Generates the `PostgresDomain` implementation.
+pub fn postgres_domain_attr_macro(
+    attr: &DomainAttributes,
+    input: &DeriveInput,
+) -> Result<TokenStream> {
+    let struct_ident = &input.ident;
+    let struct_vis = &input.vis;
+    match &input.data {
+        syn::Data::Struct(data_struct) if matches!(data_struct.fields, syn::Fields::Unit) => {}
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "PostgresDomain can only be applied to unit structs, e.g. struct Email;",
+            ));
+        }
+    }
+    let Some(base_type) = attr.base.clone() else {
+        return Err(Error::new_spanned(
+            input,
+            "PostgresDomain requires a base type, e.g. #[PostgresDomain(base = text)]",
+        ));
+    };
+    let domain_name = attr
+        .name
+        .clone()
+        .unwrap_or_else(|| AsSnakeCase(struct_ident.to_string()).to_string());
+
+    let sql = core_paths::sql();
+    let sql_schema = core_paths::sql_schema();
+    let sql_domain_info = core_paths::sql_domain_info();
+    let schema_item_tables = core_paths::schema_item_tables();
+    let type_set_nil = core_paths::type_set_nil();
+    let to_sql = core_paths::to_sql();
+    let postgres_value = postgres_paths::postgres_value();
+    let postgres_schema_type = postgres_paths::postgres_schema_type();
+    let domain_def = ddl_paths::domain_def();
+
+    let not_null_modifier = if attr.not_null {
+        quote! { .not_null() }
+    } else {
+        quote! {}
+    };
+    let default_modifier = attr
+        .default
+        .as_ref()
+        .map_or_else(|| quote! {}, |default| quote! { .default_value(#default) });
+    let check_modifier = attr
+        .check
+        .as_ref()
+        .map_or_else(|| quote! {}, |check| quote! { .check(#check) });
+    let not_null = attr.not_null;
+    let default_const = option_tokens(attr.default.as_ref());
+    let check_const = option_tokens(attr.check.as_ref());
+
+    let mut domain =
+        drizzle_types::postgres::ddl::Domain::new("public", domain_name.clone(), base_type.clone());
+    domain.not_null = attr.not_null;
+    domain.default = attr.default.clone().map(Into::into);
+    domain.check = attr.check.clone().map(Into::into);
+    let create_domain_sql = domain.create_domain_sql();
+
+    Ok(quote! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #struct_vis struct #struct_ident;
+
+        impl #struct_ident {
+            pub const DDL_DOMAIN: #domain_def = #domain_def::new("public", #domain_name, #base_type)
+                #not_null_modifier
+                #default_modifier
+                #check_modifier;
+
+            pub const fn new() -> Self {
+                Self
+            }
+
+            /// Generate CREATE DOMAIN SQL using the DDL definition.
+            pub fn create_domain_sql() -> ::std::string::String {
+                Self::DDL_DOMAIN.into_domain().create_domain_sql()
+            }
+        }
+
+        impl Default for #struct_ident {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl #sql_domain_info for #struct_ident {
+            fn name(&self) -> &'static str {
+                #domain_name
+            }
+
+            fn base_type(&self) -> &'static str {
+                #base_type
+            }
+
+            fn not_null(&self) -> bool {
+                #not_null
+            }
+
+            fn default_value(&self) -> ::core::option::Option<&'static str> {
+                #default_const
+            }
+
+            fn check(&self) -> ::core::option::Option<&'static str> {
+                #check_const
+            }
+
+            fn create_domain_sql(&self) -> ::std::string::String {
+                <Self as #sql_schema<'_, #postgres_schema_type, #postgres_value<'_>>>::SQL.to_string()
+            }
+        }
+
+        impl<'a> #sql_schema<'a, #postgres_schema_type, #postgres_value<'a>> for #struct_ident {
+            const NAME: &'static str = #domain_name;
+            const TYPE: #postgres_schema_type = {
+                #[allow(non_upper_case_globals)]
+                static DOMAIN_INSTANCE: #struct_ident = #struct_ident::new();
+                #postgres_schema_type::Domain(&DOMAIN_INSTANCE)
+            };
+            const SQL: &'static str = #create_domain_sql;
+        }
+
+        impl<'a> #to_sql<'a, #postgres_value<'a>> for #struct_ident {
+            fn to_sql(&self) -> #sql<'a, #postgres_value<'a>> {
+                #sql::raw(Self::create_domain_sql())
+            }
+        }
+
+        impl #schema_item_tables for #struct_ident {
+            type Tables = #type_set_nil;
+        }
+    })
+}
@@ -1,4 +1,4 @@
-use crate::common::enum_utils::{has_integer_repr, resolve_discriminants};
+use crate::common::enum_utils::{has_integer_repr, resolve_discriminants, resolve_variant_names};
 use crate::paths::{core as core_paths, postgres as postgres_paths};
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -34,58 +34,48 @@ pub fn generate_enum_impl(
             "#[derive(PostgresEnum)] requires at least one variant",
         ));
     };
-    let variant_idents: Vec<_> = data.variants.iter().map(|v| &v.ident).collect();
+    // Stored string per variant, honoring #[drizzle(rename_all/rename)]
+    let variant_names = resolve_variant_names(data, attrs)?;
+    let variant_values: Vec<_> = variant_names.iter().map(|(_, value)| value).collect();
 
     // Build the CREATE TYPE SQL at macro time as a string literal
-    let variants_sql = variant_idents
+    let variants_sql = variant_values
         .iter()
-        .map(|v| format!("'{}'", v.to_string().replace('\'', "''")))
+        .map(|v| format!("'{}'", v.replace('\'', "''")))
         .collect::<Vec<_>>()
         .join(", ");
     let create_type_sql = format!("CREATE TYPE {name} AS ENUM ({variants_sql})");
     let create_type_sql_literal = create_type_sql.as_str();
 
-    let display_variants = data.variants.iter().map(|variant| {
-        let variant_name = &variant.ident;
-        let variant_str = variant_name.to_string();
-
+    let display_variants = variant_names.iter().map(|(ident, value)| {
         quote! {
-            #name::#variant_name => write!(f, #variant_str),
+            #name::#ident => f.write_str(#value),
         }
     });
 
-    let to_str_variants: Vec<_> = data
-        .variants
+    let to_str_variants: Vec<_> = variant_names
         .iter()
-        .map(|variant| {
-            let ident = &variant.ident;
-
+        .map(|(ident, value)| {
             quote! {
-                #name::#ident => stringify!(#ident)
+                #name::#ident => #value
             }
         })
         .collect();
 
-    let to_str_ref_variants: Box<_> = data
-        .variants
+    let to_str_ref_variants: Box<_> = variant_names
         .iter()
-        .map(|variant| {
-            let ident = &variant.ident;
-
+        .map(|(ident, value)| {
             quote! {
-                &#name::#ident => stringify!(#ident)
+                &#name::#ident => #value
             }
         })
         .collect();
 
-    let from_str_variants: Box<_> = data
-        .variants
+    let from_str_variants: Box<_> = variant_names
         .iter()
-        .map(|variant| {
-            let ident = &variant.ident;
-
+        .map(|(ident, value)| {
             quote! {
-                stringify!(#ident) => #name::#ident,
+                #value => #name::#ident,
             }
         })
         .collect();
@@ -580,7 +570,7 @@ pub fn generate_enum_impl(
                 }
 
                 fn variants(&self) -> &'static [&'static str] {
-                    &[#(stringify!(#variant_idents),)*]
+                    &[#(#variant_values,)*]
                 }
             }
 
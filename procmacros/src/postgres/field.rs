@@ -1,4 +1,4 @@
-use heck::ToSnakeCase;
+use drizzle_types::Casing;
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use std::fmt::Write;
@@ -7,14 +7,16 @@ use syn::{Attribute, Error, Expr, ExprPath, Field, Ident, Lit, Result, Token, Ty
 
 use crate::common::make_uppercase_path;
 use crate::common::{
-    is_option_type, option_inner_type, references_required_message,
-    relation_requires_references_message, type_is_array_char, type_is_array_string,
-    type_is_array_u8, type_is_arrayvec_u8, type_is_bit_vec, type_is_bool, type_is_datetime_tz,
-    type_is_float, type_is_geo_linestring, type_is_geo_point, type_is_geo_rect, type_is_int,
-    type_is_ip_addr, type_is_ip_cidr, type_is_json_value, type_is_mac_addr, type_is_naive_date,
-    type_is_naive_datetime, type_is_naive_time, type_is_offset_datetime,
-    type_is_primitive_date_time, type_is_string_like, type_is_time_date, type_is_time_time,
-    type_is_uuid, type_is_vec_u8, unwrap_option, vec_inner_type,
+    apply_casing, array_u8_len, doc_comment_from_attrs, is_option_type,
+    nullable_primary_key_message, option_inner_type, references_required_message,
+    relation_requires_references_message, sql_default_expression, type_is_array_char,
+    type_is_array_string, type_is_array_u8, type_is_arrayvec_u8, type_is_bit_vec, type_is_bool,
+    type_is_datetime_tz, type_is_float, type_is_geo_linestring, type_is_geo_point,
+    type_is_geo_rect, type_is_int, type_is_ip_addr, type_is_ip_cidr, type_is_json_value,
+    type_is_mac_addr, type_is_naive_date, type_is_naive_datetime, type_is_naive_time,
+    type_is_offset_datetime, type_is_primitive_date_time, type_is_string_like, type_is_time_date,
+    type_is_time_time, type_is_uuid, type_is_vec_u8, unknown_attribute_message, unwrap_option,
+    vec_inner_type,
 };
 
 // Note: drizzle_types::postgres::TypeCategory exists but has different feature gates.
@@ -492,6 +494,17 @@ pub enum PostgreSQLType {
     #[cfg(feature = "geo-types")]
     Circle,
 
+    /// PostGIS GEOMETRY type, rendered with its typmod, e.g. `geometry(Point,4326)`
+    ///
+    /// See: <https://postgis.net/docs/geometry.html>
+    #[cfg(feature = "geo-types")]
+    Geometry(String),
+
+    /// pgvector VECTOR type, rendered with its dimensions, e.g. `vector(1536)`
+    ///
+    /// See: <https://github.com/pgvector/pgvector>
+    Vector(String),
+
     /// `PostgreSQL` BIT type - fixed-length bit string
     ///
     /// See: <https://www.postgresql.org/docs/current/datatype-bit.html>
@@ -575,6 +588,9 @@ impl PostgreSQLType {
             Self::Polygon => "POLYGON",
             #[cfg(feature = "geo-types")]
             Self::Circle => "CIRCLE",
+            #[cfg(feature = "geo-types")]
+            Self::Geometry(sql_type) => sql_type.as_str(),
+            Self::Vector(sql_type) => sql_type.as_str(),
             #[cfg(feature = "bit-vec")]
             Self::Bit => "BIT",
             #[cfg(feature = "bit-vec")]
@@ -688,6 +704,9 @@ pub struct FieldInfo {
     pub marker_exprs: Vec<syn::ExprPath>,
     /// True for unknown types that are validated at type-check time via `DrizzlePostgresColumn` trait
     pub is_custom_type: bool,
+    /// True for the primary key of a table declared with `ID_TYPE = ...`;
+    /// `base_type` is then the generated newtype rather than the declared type.
+    pub is_id_newtype: bool,
     /// Resolved primary-key / unique state. Set during `from_field` once
     /// the table-level `is_composite_pk` decision is known. New emission
     /// sites should prefer this over the raw `is_primary` / `is_unique`
@@ -700,35 +719,13 @@ pub struct FieldInfo {
     /// (`"C"`, `"POSIX"`, `"en_US"`) or custom `CREATE COLLATION` value
     /// works.
     pub collate: Option<String>,
+    /// `#[PostgresDomain]` type from `#[column(DOMAIN = Email)]`. The column
+    /// is declared with the domain's name instead of the inferred type.
+    pub domain: Option<syn::Path>,
     /// SQL comment extracted from field doc comments.
     pub comment: Option<String>,
 }
 
-fn doc_comment_from_attrs(attrs: &[Attribute]) -> Option<String> {
-    let lines = attrs.iter().filter_map(|attr| {
-        if !attr.path().is_ident("doc") {
-            return None;
-        }
-        let syn::Meta::NameValue(meta) = &attr.meta else {
-            return None;
-        };
-        let Expr::Lit(expr_lit) = &meta.value else {
-            return None;
-        };
-        let Lit::Str(lit) = &expr_lit.lit else {
-            return None;
-        };
-        let value = lit.value();
-        Some(value.strip_prefix(' ').unwrap_or(&value).to_string())
-    });
-    let comment = lines.collect::<Vec<_>>().join("\n");
-    if comment.is_empty() {
-        None
-    } else {
-        Some(comment)
-    }
-}
-
 fn is_supported_array_category(category: TypeCategory) -> bool {
     matches!(
         category,
@@ -765,12 +762,49 @@ fn postgres_array_info(ty: &Type) -> Option<PostgreSQLType> {
     category.to_postgres_type()
 }
 
+/// Names accepted inside `#[column(...)]`, used for "did you mean" hints.
+const COLUMN_ATTRIBUTES: &[&str] = &[
+    "primary",
+    "unique",
+    "serial",
+    "bigserial",
+    "smallserial",
+    "identity",
+    "generated",
+    "json",
+    "jsonb",
+    "inet",
+    "cidr",
+    "macaddr",
+    "macaddr8",
+    "vector",
+    "timestamp",
+    "timestamptz",
+    "enum",
+    "domain",
+    "name",
+    "default",
+    "default_fn",
+    "default_sql",
+    "check",
+    "collate",
+    "collation",
+    "comment",
+    "references",
+    "relation",
+    "on_delete",
+    "on_update",
+    "deferrable",
+    "initially_deferred",
+];
+
 impl FieldInfo {
     /// Parse field information from a struct field.
     ///
     /// The `PostgreSQL` type is INFERRED from the Rust type, not from attributes.
     /// Attributes are only used for constraints (primary, unique, etc.).
-    pub(crate) fn from_field(field: &Field, is_composite_pk: bool) -> Result<Self> {
+    /// Unnamed columns are named from the field ident with `casing`.
+    pub(crate) fn from_field(field: &Field, is_composite_pk: bool, casing: Casing) -> Result<Self> {
         let Some(name) = field.ident.clone() else {
             return Err(Error::new_spanned(
                 field,
@@ -786,8 +820,7 @@ impl FieldInfo {
         // Infer PostgreSQL type from Rust type
         let _type_str = ty.to_token_stream().to_string();
         let type_category = TypeCategory::from_type(&ty);
-        let array_type = postgres_array_info(&ty);
-        let dimensions = array_type.as_ref().map(|_| 1);
+        let mut array_type = postgres_array_info(&ty);
 
         // Initialize constraint-related fields
         let mut flags = HashSet::new();
@@ -810,9 +843,15 @@ impl FieldInfo {
         // Parse #[column(...)] attributes for constraints
         let mut is_explicit_json = false;
         let mut is_explicit_jsonb = false;
+        let mut network_type = None;
+        let mut geometry_type = None;
+        let mut vector_type = None;
+        let mut timestamp_type = None;
         let mut column_name = None;
         let mut collate: Option<String> = None;
+        let mut domain: Option<syn::Path> = None;
         let mut relation_name: Option<String> = None;
+        let mut attr_comment: Option<String> = None;
         for attr in &field.attrs {
             if let Some(column_info) =
                 Self::parse_column_attribute(attr, type_category, name.span())?
@@ -831,14 +870,36 @@ impl FieldInfo {
                 is_pgenum = column_info.is_pgenum;
                 is_explicit_json = column_info.is_json;
                 is_explicit_jsonb = column_info.is_jsonb;
+                network_type = column_info.network_type;
+                geometry_type = column_info.geometry_type;
+                vector_type = column_info.vector_type;
+                timestamp_type = column_info.timestamp_type;
                 column_name = column_info.column_name;
                 collate = column_info.collate;
+                domain = column_info.domain;
                 relation_name = column_info.relation_name;
+                attr_comment = column_info.comment;
                 marker_exprs = column_info.marker_exprs;
                 break;
             }
         }
 
+        if let Some(network_type) = &network_type {
+            validate_network_type(network_type, &ty, type_category, array_type.is_some())?;
+        }
+        if let Some(geometry_type) = &geometry_type {
+            validate_geometry_type(geometry_type, &ty, array_type.is_some())?;
+        }
+        if let Some(vector_type) = &vector_type {
+            validate_vector_type(vector_type, &ty)?;
+            // The whole `Vec<f32>` is one pgvector value, not a REAL[]
+            array_type = None;
+        }
+        if let Some(timestamp_type) = &timestamp_type {
+            validate_timestamp_type(timestamp_type, &ty, array_type.is_some())?;
+        }
+        let dimensions = array_type.as_ref().map(|_| 1);
+
         // Determine the PostgreSQL column type
         #[cfg(feature = "serde")]
         let column_type = if is_smallserial {
@@ -854,6 +915,18 @@ impl FieldInfo {
             let base_type = option_inner_type(&ty).unwrap_or(&ty);
             let base_type_str = base_type.to_token_stream().to_string().replace(' ', "");
             PostgreSQLType::from_enum_attribute(&base_type_str)
+        } else if let Some(network_type) = network_type {
+            // Explicit #[column(inet/cidr/macaddr/macaddr8)]
+            network_type
+        } else if let Some(geometry_type) = geometry_type {
+            // Explicit #[column(geometry(...))]
+            geometry_type
+        } else if let Some(vector_type) = vector_type {
+            // Explicit #[column(vector(N))]
+            vector_type
+        } else if let Some(timestamp_type) = timestamp_type {
+            // Explicit #[column(timestamp)] / #[column(timestamptz)]
+            timestamp_type
         } else if is_explicit_json {
             // Explicit #[column(json)] - use JSON type for any Serialize/Deserialize type
             PostgreSQLType::Json
@@ -884,6 +957,18 @@ impl FieldInfo {
             let base_type = option_inner_type(&ty).unwrap_or(&ty);
             let base_type_str = base_type.to_token_stream().to_string().replace(' ', "");
             PostgreSQLType::from_enum_attribute(&base_type_str)
+        } else if let Some(network_type) = network_type {
+            // Explicit #[column(inet/cidr/macaddr/macaddr8)]
+            network_type
+        } else if let Some(geometry_type) = geometry_type {
+            // Explicit #[column(geometry(...))]
+            geometry_type
+        } else if let Some(vector_type) = vector_type {
+            // Explicit #[column(vector(N))]
+            vector_type
+        } else if let Some(timestamp_type) = timestamp_type {
+            // Explicit #[column(timestamp)] / #[column(timestamptz)]
+            timestamp_type
         } else if let Some(pg_type) = type_category.to_postgres_type() {
             // Infer from Rust type
             pg_type
@@ -901,6 +986,12 @@ impl FieldInfo {
         }
 
         let is_primary = flags.contains(&PostgreSQLFlag::Primary);
+        if (is_primary || is_composite_pk) && is_nullable {
+            return Err(Error::new_spanned(
+                &field.ty,
+                nullable_primary_key_message(&name.to_string()),
+            ));
+        }
         let is_unique = flags.contains(&PostgreSQLFlag::Unique);
         let is_enum = flags.contains(&PostgreSQLFlag::Enum);
         // is_json is true for both inferred serde_json::Value and explicit #[column(json/jsonb)]
@@ -912,9 +1003,9 @@ impl FieldInfo {
         // Compute base_type once and store it
         let base_type = option_inner_type(&ty).unwrap_or(&ty).clone();
 
-        // Column name defaults to field ident converted to snake_case.
-        let column_name = column_name.unwrap_or_else(|| name.to_string().to_snake_case());
-        let comment = doc_comment_from_attrs(&field.attrs);
+        // Column name defaults to the field ident in the table's casing.
+        let column_name = column_name.unwrap_or_else(|| apply_casing(&name.to_string(), casing));
+        let comment = attr_comment.or_else(|| doc_comment_from_attrs(&field.attrs));
 
         // Build SQL definition for this column
         let sql_definition = build_sql_definition(&SqlDefinitionContext {
@@ -959,12 +1050,14 @@ impl FieldInfo {
             has_default,
             marker_exprs,
             is_custom_type,
+            is_id_newtype: false,
             constraint: crate::common::Constraint::from_flags(
                 is_primary,
                 is_unique,
                 is_composite_pk,
             ),
             collate,
+            domain,
             comment,
         })
     }
@@ -1006,10 +1099,18 @@ impl FieldInfo {
         let mut is_pgenum = false;
         let mut is_json = false;
         let mut is_jsonb = false;
+        #[cfg_attr(not(feature = "cidr"), allow(unused_mut))]
+        let mut network_type: Option<PostgreSQLType> = None;
+        #[cfg_attr(not(feature = "geo-types"), allow(unused_mut))]
+        let mut geometry_type: Option<PostgreSQLType> = None;
+        let mut vector_type: Option<PostgreSQLType> = None;
+        let mut timestamp_type: Option<PostgreSQLType> = None;
         let enum_type_name: Option<String> = None;
         let mut column_name = None;
         let mut collate: Option<String> = None;
+        let mut domain: Option<syn::Path> = None;
         let mut relation_name: Option<String> = None;
+        let mut comment: Option<String> = None;
         let mut marker_exprs = Vec::new();
 
         // Parse attribute arguments: #[column(primary, unique, default = "foo")]
@@ -1026,8 +1127,8 @@ impl FieldInfo {
                     "SERIAL" => {
                         // Validate: serial only valid on i32
                         if !type_category.is_valid_constraint("serial") {
-                            return Err(syn::Error::new(
-                                span,
+                            return Err(syn::Error::new_spanned(
+                                path_ident,
                                 "#[column(serial)] requires the field type to be i32",
                             ));
                         }
@@ -1037,8 +1138,8 @@ impl FieldInfo {
                     "BIGSERIAL" => {
                         // Validate: bigserial only valid on i64
                         if !type_category.is_valid_constraint("bigserial") {
-                            return Err(syn::Error::new(
-                                span,
+                            return Err(syn::Error::new_spanned(
+                                path_ident,
                                 "#[column(bigserial)] requires the field type to be i64",
                             ));
                         }
@@ -1048,8 +1149,8 @@ impl FieldInfo {
                     "SMALLSERIAL" => {
                         // Validate: smallserial only valid on i16
                         if !type_category.is_valid_constraint("smallserial") {
-                            return Err(syn::Error::new(
-                                span,
+                            return Err(syn::Error::new_spanned(
+                                path_ident,
                                 "#[column(smallserial)] requires the field type to be i16",
                             ));
                         }
@@ -1065,15 +1166,25 @@ impl FieldInfo {
                         marker_exprs.push(make_uppercase_path(path_ident, "UNIQUE"));
                     }
                     "IDENTITY" => {
-                        // identity(always) or identity(by_default) syntax
+                        // identity(always), identity(by_default), or
+                        // identity = always / identity = by_default syntax
                         is_generated_identity = true;
                         flags.insert(PostgreSQLFlag::Identity);
 
-                        // Parse the parenthesized content: identity(always) or identity(by_default)
-                        if meta.input.peek(syn::token::Paren) {
+                        let mode_ident = if meta.input.peek(syn::token::Paren) {
                             let content;
                             syn::parenthesized!(content in meta.input);
-                            let mode_ident: Ident = content.parse()?;
+                            // TODO: Parse optional sequence options after a comma
+                            // e.g., identity(always, start = 100, increment = 10)
+                            Some(content.parse::<Ident>()?)
+                        } else if meta.input.peek(Token![=]) {
+                            meta.input.parse::<Token![=]>()?;
+                            Some(meta.input.parse::<Ident>()?)
+                        } else {
+                            None
+                        };
+
+                        if let Some(mode_ident) = mode_ident {
                             let mode_str = mode_ident.to_string().to_ascii_uppercase();
 
                             match mode_str.as_str() {
@@ -1090,9 +1201,6 @@ impl FieldInfo {
                                     ));
                                 }
                             }
-
-                            // TODO: Parse optional sequence options after a comma
-                            // e.g., identity(always, start = 100, increment = 10)
                         } else {
                             // Default to ALWAYS if no argument
                             identity_mode = Some(IdentityMode::Always);
@@ -1155,11 +1263,105 @@ impl FieldInfo {
                         is_jsonb = true;
                         marker_exprs.push(make_uppercase_path(path_ident, "JSONB"));
                     }
+                    "INET" | "CIDR" | "MACADDR" | "MACADDR8" => {
+                        #[cfg(feature = "cidr")]
+                        {
+                            network_type = Some(match path.as_str() {
+                                "INET" => PostgreSQLType::Inet,
+                                "CIDR" => PostgreSQLType::Cidr,
+                                "MACADDR" => PostgreSQLType::MacAddr,
+                                _ => PostgreSQLType::MacAddr8,
+                            });
+                            marker_exprs.push(make_uppercase_path(path_ident, &path));
+                        }
+                        #[cfg(not(feature = "cidr"))]
+                        return Err(syn::Error::new_spanned(
+                            path_ident,
+                            format!("#[column({path_ident})] requires the `cidr` feature"),
+                        ));
+                    }
+                    "GEOMETRY" => {
+                        // geometry, geometry(point) or geometry(point, srid = 4326)
+                        #[cfg(feature = "geo-types")]
+                        {
+                            let mut kind = None;
+                            let mut srid = None;
+                            if meta.input.peek(syn::token::Paren) {
+                                let content;
+                                syn::parenthesized!(content in meta.input);
+                                while !content.is_empty() {
+                                    let ident: Ident = content.parse()?;
+                                    if content.peek(Token![=]) {
+                                        if !ident.to_string().eq_ignore_ascii_case("srid") {
+                                            return Err(syn::Error::new_spanned(
+                                                &ident,
+                                                "expected `srid = <integer>`",
+                                            ));
+                                        }
+                                        content.parse::<Token![=]>()?;
+                                        srid = Some(content.parse::<syn::LitInt>()?.base10_parse::<i32>()?);
+                                    } else {
+                                        kind = Some(geometry_kind_name(&ident)?);
+                                    }
+                                    if !content.is_empty() {
+                                        content.parse::<Token![,]>()?;
+                                    }
+                                }
+                            }
+                            let sql_type = match (kind, srid) {
+                                (None, None) => "geometry".to_string(),
+                                (kind, None) => format!("geometry({})", kind.unwrap_or("Geometry")),
+                                (kind, Some(srid)) => {
+                                    format!("geometry({},{srid})", kind.unwrap_or("Geometry"))
+                                }
+                            };
+                            geometry_type = Some(PostgreSQLType::Geometry(sql_type));
+                            marker_exprs.push(make_uppercase_path(path_ident, "GEOMETRY"));
+                        }
+                        #[cfg(not(feature = "geo-types"))]
+                        return Err(syn::Error::new_spanned(
+                            path_ident,
+                            format!("#[column({path_ident})] requires the `geo-types` feature"),
+                        ));
+                    }
+                    "VECTOR" => {
+                        // vector or vector(1536)
+                        let sql_type = if meta.input.peek(syn::token::Paren) {
+                            let content;
+                            syn::parenthesized!(content in meta.input);
+                            let lit: syn::LitInt = content.parse()?;
+                            let dims = lit.base10_parse::<u32>()?;
+                            if !(1..=16_000).contains(&dims) {
+                                return Err(syn::Error::new_spanned(
+                                    &lit,
+                                    "pgvector supports 1 to 16000 dimensions",
+                                ));
+                            }
+                            format!("vector({dims})")
+                        } else {
+                            "vector".to_string()
+                        };
+                        vector_type = Some(PostgreSQLType::Vector(sql_type));
+                        marker_exprs.push(make_uppercase_path(path_ident, "VECTOR"));
+                    }
+                    "TIMESTAMP" | "TIMESTAMP_WITHOUT_TIME_ZONE" => {
+                        timestamp_type = Some(PostgreSQLType::Timestamp);
+                        marker_exprs.push(make_uppercase_path(path_ident, &path));
+                    }
+                    "TIMESTAMPTZ" | "TIMESTAMP_WITH_TIME_ZONE" => {
+                        timestamp_type = Some(PostgreSQLType::Timestamptz);
+                        marker_exprs.push(make_uppercase_path(path_ident, &path));
+                    }
                     "ENUM" => {
                         // Just mark as pgenum - the type is inferred from the field definition
                         is_pgenum = true;
                         marker_exprs.push(make_uppercase_path(path_ident, "ENUM"));
                     }
+                    "DOMAIN" => {
+                        meta.input.parse::<Token![=]>()?;
+                        domain = Some(meta.input.parse::<syn::Path>()?);
+                        marker_exprs.push(make_uppercase_path(path_ident, "DOMAIN"));
+                    }
                     "NAME" => {
                         meta.input.parse::<Token![=]>()?;
                         let lit: Lit = meta.input.parse()?;
@@ -1173,10 +1375,11 @@ impl FieldInfo {
                             ));
                         }
                     }
-                    "COLLATE" => {
+                    "COLLATE" | "COLLATION" => {
                         meta.input.parse::<Token![=]>()?;
                         // Accept either a string literal (`COLLATE = "en_US"`)
-                        // or a bare ident (`COLLATE = C`). The PostgreSQL DDL
+                        // or a bare ident (`COLLATE = C`). `collation` is an
+                        // alias spelled the way PostgreSQL's catalog names it. The PostgreSQL DDL
                         // emitter wraps whatever lands here in double quotes,
                         // so the user shouldn't escape the name themselves.
                         if meta.input.peek(Lit) {
@@ -1195,6 +1398,19 @@ impl FieldInfo {
                         }
                         marker_exprs.push(make_uppercase_path(path_ident, "COLLATE"));
                     }
+                    "COMMENT" => {
+                        meta.input.parse::<Token![=]>()?;
+                        let lit: Lit = meta.input.parse()?;
+                        if let Lit::Str(s) = lit {
+                            comment = Some(s.value());
+                        } else {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "COMMENT requires a string literal, e.g. COMMENT = \"Login e-mail\"",
+                            ));
+                        }
+                        marker_exprs.push(make_uppercase_path(path_ident, "COMMENT"));
+                    }
                     "DEFAULT" => {
                         if meta.input.peek(Token![=]) {
                             meta.input.parse::<Token![=]>()?;
@@ -1204,7 +1420,24 @@ impl FieldInfo {
                                     format!("default conflicts with existing {kind}"),
                                 ));
                             }
-                            let lit: Lit = meta.input.parse()?;
+                            let lit = match meta.input.parse::<Expr>()? {
+                                Expr::Lit(expr_lit) => expr_lit.lit,
+                                // `default = sql!("...")` is a raw DDL expression,
+                                // equivalent to `default_sql`.
+                                Expr::Macro(mac) => {
+                                    let expression = sql_default_expression(&mac.mac)?;
+                                    default = Some(PostgreSQLDefault::RawSql(expression));
+                                    default_kind = Some("default");
+                                    marker_exprs.push(make_uppercase_path(path_ident, "DEFAULT"));
+                                    return Ok(());
+                                }
+                                other => {
+                                    return Err(syn::Error::new_spanned(
+                                        other,
+                                        "unsupported default value; expected a literal or sql!(\"...\")",
+                                    ));
+                                }
+                            };
                             match lit {
                                 Lit::Str(s) => {
                                     let escaped = s.value().replace('\'', "''");
@@ -1375,10 +1608,11 @@ impl FieldInfo {
                     _ => {
                         return Err(syn::Error::new_spanned(
                             &meta.path,
-                            format!("unknown #[column] attribute `{path_ident}`.\n\
-                                     Supported: primary, unique, serial, bigserial, smallserial, identity, \
-                                     generated, json, jsonb, enum, name, default, default_fn, default_sql, check, references, \
-                                     relation, on_delete, on_update, deferrable, initially_deferred"),
+                            unknown_attribute_message(
+                                "#[column]",
+                                &path_ident.to_string(),
+                                COLUMN_ATTRIBUTES,
+                            ),
                         ));
                     }
                 }
@@ -1424,9 +1658,15 @@ impl FieldInfo {
             is_pgenum,
             is_json,
             is_jsonb,
+            network_type,
+            geometry_type,
+            vector_type,
+            timestamp_type,
             enum_type_name,
             column_name,
             collate,
+            domain,
+            comment,
             marker_exprs,
         }))
     }
@@ -1513,7 +1753,9 @@ impl FieldInfo {
     /// Built-in columns use a literal. Custom columns use the associated const
     /// from `DrizzlePostgresColumn`, making the trait the source of truth.
     pub(crate) fn sql_type_expr(&self) -> TokenStream {
-        if self.is_custom_type {
+        if let Some(domain) = &self.domain {
+            quote!(#domain::DDL_DOMAIN.name)
+        } else if self.is_custom_type {
             let base_type = &self.base_type;
             let drizzle_postgres_column = crate::paths::postgres::drizzle_postgres_column();
             quote!(<#base_type as #drizzle_postgres_column>::SQL_TYPE)
@@ -1542,7 +1784,7 @@ impl FieldInfo {
 
     /// Column SQL definition expression for `SQLSchema::SQL`.
     pub(crate) fn sql_definition_expr(&self) -> TokenStream {
-        if !self.is_custom_type {
+        if !self.is_custom_type && self.domain.is_none() {
             let sql_definition = &self.sql_definition;
             return quote!(#sql_definition);
         }
@@ -1606,7 +1848,7 @@ impl FieldInfo {
         if let Some(default) = self.default_to_string() {
             col = col.default_value(default);
         }
-        if self.is_pgenum || self.is_custom_type {
+        if self.is_pgenum || self.domain.is_some() || (self.is_custom_type && !self.is_id_newtype) {
             col.type_schema = Some(std::borrow::Cow::Owned(schema.to_string()));
         }
         col.dimensions = self.dimensions;
@@ -1874,13 +2116,213 @@ struct ColumnInfo {
     is_pgenum: bool,
     is_json: bool,
     is_jsonb: bool,
+    /// Network column type, from `#[column(INET)]`, `CIDR`, `MACADDR` or `MACADDR8`.
+    network_type: Option<PostgreSQLType>,
+    /// PostGIS column type, from `#[column(GEOMETRY(point, srid = 4326))]`.
+    geometry_type: Option<PostgreSQLType>,
+    /// pgvector column type, from `#[column(VECTOR(1536))]`.
+    vector_type: Option<PostgreSQLType>,
+    /// Explicit timestamp type, from `#[column(TIMESTAMP)]` or `#[column(TIMESTAMPTZ)]`.
+    timestamp_type: Option<PostgreSQLType>,
     enum_type_name: Option<String>,
     column_name: Option<String>,
     /// PostgreSQL collation name, from `#[column(COLLATE = "en_US")]`.
     collate: Option<String>,
+    /// Domain type path, from `#[column(DOMAIN = Email)]`.
+    domain: Option<syn::Path>,
+    /// Column comment from `#[column(COMMENT = "...")]`.
+    comment: Option<String>,
     marker_exprs: Vec<syn::ExprPath>,
 }
 
+/// Check that a `#[column(inet/cidr/macaddr/macaddr8)]` attribute matches the
+/// Rust field type.
+fn validate_network_type(
+    network_type: &PostgreSQLType,
+    ty: &syn::Type,
+    type_category: TypeCategory,
+    is_array: bool,
+) -> Result<()> {
+    let sql_type = network_type.to_sql_type();
+    if is_array {
+        return Err(Error::new_spanned(
+            ty,
+            format!("#[column({sql_type})] is not supported on array columns"),
+        ));
+    }
+
+    let (accepted, expected) = match sql_type {
+        "INET" => (
+            type_category == TypeCategory::IpAddr,
+            "`std::net::IpAddr` or `cidr::IpInet`",
+        ),
+        "CIDR" => (type_category == TypeCategory::Cidr, "`cidr::IpCidr`"),
+        "MACADDR" => (array_u8_len(unwrap_option(ty)) == Some(6), "`[u8; 6]`"),
+        _ => (array_u8_len(unwrap_option(ty)) == Some(8), "`[u8; 8]`"),
+    };
+    if accepted {
+        Ok(())
+    } else {
+        Err(Error::new_spanned(
+            ty,
+            format!("#[column({sql_type})] requires a {expected} field"),
+        ))
+    }
+}
+
+/// PostGIS subtype name for a `#[column(geometry(<kind>))]` identifier.
+#[cfg(feature = "geo-types")]
+fn geometry_kind_name(kind: &Ident) -> Result<&'static str> {
+    Ok(match kind.to_string().to_ascii_lowercase().as_str() {
+        "point" => "Point",
+        "linestring" => "LineString",
+        "polygon" => "Polygon",
+        "multipoint" => "MultiPoint",
+        "multilinestring" => "MultiLineString",
+        "multipolygon" => "MultiPolygon",
+        "geometrycollection" => "GeometryCollection",
+        "geometry" => "Geometry",
+        _ => {
+            return Err(Error::new_spanned(
+                kind,
+                "unknown geometry type; expected point, linestring, polygon, multipoint, \
+                 multilinestring, multipolygon, geometrycollection or geometry",
+            ));
+        }
+    })
+}
+
+/// Check that a `#[column(geometry(...))]` attribute matches the Rust field
+/// type: `geo_types::Geometry` fits any column, other shapes must match the
+/// column's subtype.
+fn validate_geometry_type(
+    geometry_type: &PostgreSQLType,
+    ty: &syn::Type,
+    is_array: bool,
+) -> Result<()> {
+    let sql_type = geometry_type.to_sql_type();
+    if is_array {
+        return Err(Error::new_spanned(
+            ty,
+            format!("#[column({sql_type})] is not supported on array columns"),
+        ));
+    }
+
+    let kind = sql_type
+        .strip_prefix("geometry(")
+        .and_then(|rest| rest.split([',', ')']).next())
+        .filter(|kind| *kind != "Geometry");
+    let shape = crate::common::geo_shape_name(unwrap_option(ty));
+    let accepted = match (shape.as_deref(), kind) {
+        (Some("Geometry"), _) => true,
+        (Some("GeometryCollection") | None, _) => false,
+        (Some(shape), Some(kind)) => shape == kind,
+        (Some(_), None) => true,
+    };
+    if accepted {
+        Ok(())
+    } else {
+        let expected = kind.map_or_else(
+            || "`geo_types` geometry".to_string(),
+            |kind| format!("`geo_types::{kind}<f64>` or `geo_types::Geometry<f64>`"),
+        );
+        Err(Error::new_spanned(
+            ty,
+            format!("#[column({sql_type})] requires a {expected} field"),
+        ))
+    }
+}
+
+/// Check that a `#[column(vector(N))]` field is a `Vec<f32>`.
+fn validate_vector_type(vector_type: &PostgreSQLType, ty: &syn::Type) -> Result<()> {
+    if crate::common::type_is_vec_f32(unwrap_option(ty)) {
+        Ok(())
+    } else {
+        Err(Error::new_spanned(
+            ty,
+            format!(
+                "#[column({})] requires a `Vec<f32>` field",
+                vector_type.to_sql_type()
+            ),
+        ))
+    }
+}
+
+/// Check that an explicit `#[column(timestamp)]` / `#[column(timestamptz)]`
+/// matches the Rust field type.
+///
+/// TIMESTAMPTZ values are instants, so they only map to `DateTime<Utc>` (or
+/// `time::OffsetDateTime`); TIMESTAMP values are wall-clock times and only map
+/// to `NaiveDateTime` (or `time::PrimitiveDateTime`).
+fn validate_timestamp_type(
+    timestamp_type: &PostgreSQLType,
+    ty: &syn::Type,
+    is_array: bool,
+) -> Result<()> {
+    let sql_type = timestamp_type.to_sql_type();
+    if is_array {
+        return Err(Error::new_spanned(
+            ty,
+            format!("#[column({sql_type})] is not supported on array columns"),
+        ));
+    }
+
+    let inner = unwrap_option(ty);
+    let (accepted, expected) = match timestamp_type {
+        PostgreSQLType::Timestamptz => (
+            datetime_is_utc(inner) || type_is_offset_datetime(inner),
+            "`chrono::DateTime<Utc>` or `time::OffsetDateTime`",
+        ),
+        _ => (
+            type_is_naive_datetime(inner) || type_is_primitive_date_time(inner),
+            "`chrono::NaiveDateTime` or `time::PrimitiveDateTime`",
+        ),
+    };
+    if accepted {
+        return Ok(());
+    }
+
+    let hint = match timestamp_type {
+        PostgreSQLType::Timestamptz if type_is_naive_datetime(inner) => {
+            "; use #[column(timestamp)] for naive date-times"
+        }
+        PostgreSQLType::Timestamptz if type_is_datetime_tz(inner) => {
+            "; convert to `DateTime<Utc>` before storing"
+        }
+        PostgreSQLType::Timestamp if type_is_datetime_tz(inner) => {
+            "; use #[column(timestamptz)] for time-zone aware date-times"
+        }
+        _ => "",
+    };
+    Err(Error::new_spanned(
+        ty,
+        format!("#[column({sql_type})] requires a {expected} field{hint}"),
+    ))
+}
+
+/// `chrono::DateTime<Utc>`, with the `Utc` argument named explicitly.
+fn datetime_is_utc(ty: &syn::Type) -> bool {
+    let syn::Type::Path(type_path) = ty else {
+        return false;
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+    if segment.ident != "DateTime" {
+        return false;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return false;
+    };
+    args.args.iter().any(|arg| {
+        matches!(
+            arg,
+            syn::GenericArgument::Type(syn::Type::Path(tz))
+                if tz.path.segments.last().is_some_and(|seg| seg.ident == "Utc")
+        )
+    })
+}
+
 /// Inherent accessors that delegate to the `Constraint` enum so callers
 /// don't have to know the enum's variants. See the matching SQLite impl
 /// in `procmacros::sqlite::field` for semantics — `is_primary()` covers
@@ -1896,6 +2338,19 @@ impl FieldInfo {
     pub fn is_unique(&self) -> bool {
         self.constraint.is_inline_unique()
     }
+
+    /// The `PostgresValue` re-tagging applied to this column's bound values,
+    /// for extension types whose Rust value would otherwise bind as a
+    /// built-in type: PostGIS `geometry(...)` columns bind as WKB, and
+    /// pgvector `vector(N)` columns bind `Vec<f32>` as a vector, not `REAL[]`.
+    pub fn value_retag(&self) -> Option<TokenStream> {
+        match &self.column_type {
+            #[cfg(feature = "geo-types")]
+            PostgreSQLType::Geometry(_) => Some(quote!(PostgresValue::into_geometry)),
+            PostgreSQLType::Vector(_) => Some(quote!(PostgresValue::into_vector)),
+            _ => None,
+        }
+    }
 }
 
 // Trait impls for shared constraint generation
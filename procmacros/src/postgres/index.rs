@@ -1,3 +1,4 @@
+use crate::common::{index_member_collate, index_member_opclass};
 use crate::paths::{core as core_paths, ddl::postgres as ddl_paths, postgres as postgres_paths};
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -8,9 +9,10 @@ use syn::{DeriveInput, Error, Expr, ExprPath, Ident, Meta, Result, Token, Type,
 pub struct IndexAttributes {
     pub unique: bool,
     pub concurrent: bool,
-    pub method: Option<String>, // btree, hash, gin, gist, spgist, brin
+    pub method: Option<String>, // btree, hash, gin, gist, spgist, brin, hnsw, ivfflat
     pub tablespace: Option<String>,
     pub where_clause: Option<String>,
+    pub with: Option<String>, // storage parameters, e.g. "m = 16, ef_construction = 64"
 }
 
 fn create_index_prefix(unique: bool, concurrent: bool, index_name: &str) -> String {
@@ -27,6 +29,7 @@ impl Default for IndexAttributes {
             method: Some("btree".to_string()), // Default to btree
             tablespace: None,
             where_clause: None,
+            with: None,
         }
     }
 }
@@ -46,7 +49,9 @@ impl Parse for IndexAttributes {
                 Meta::Path(path) if path.is_ident("unique") => {
                     attrs.unique = true;
                 }
-                Meta::Path(path) if path.is_ident("concurrent") => {
+                Meta::Path(path)
+                    if path.is_ident("concurrent") || path.is_ident("concurrently") =>
+                {
                     attrs.concurrent = true;
                 }
                 Meta::NameValue(nv) if nv.path.is_ident("method") => {
@@ -56,13 +61,15 @@ impl Parse for IndexAttributes {
                         let method = str_lit.value();
                         // Validate PostgreSQL index methods
                         match method.as_str() {
-                            "btree" | "hash" | "gin" | "gist" | "spgist" | "brin" => {
+                            // hnsw and ivfflat come from the pgvector extension
+                            "btree" | "hash" | "gin" | "gist" | "spgist" | "brin" | "hnsw"
+                            | "ivfflat" => {
                                 attrs.method = Some(method);
                             }
                             _ => {
                                 return Err(Error::new_spanned(
                                     str_lit,
-                                    "Invalid index method. Supported methods: btree, hash, gin, gist, spgist, brin",
+                                    "Invalid index method. Supported methods: btree, hash, gin, gist, spgist, brin, hnsw, ivfflat",
                                 ));
                             }
                         }
@@ -85,6 +92,18 @@ impl Parse for IndexAttributes {
                         ));
                     }
                 }
+                Meta::NameValue(nv) if nv.path.is_ident("with") => {
+                    if let syn::Expr::Lit(ref lit) = nv.value
+                        && let syn::Lit::Str(str_lit) = &lit.lit
+                    {
+                        attrs.with = Some(str_lit.value());
+                    } else {
+                        return Err(Error::new_spanned(
+                            &nv,
+                            "Expected string literal for with, e.g. with = \"m = 16\"",
+                        ));
+                    }
+                }
                 Meta::NameValue(nv) if nv.path.is_ident("where") => {
                     if let syn::Expr::Lit(ref lit) = nv.value
                         && let syn::Lit::Str(str_lit) = &lit.lit
@@ -103,9 +122,10 @@ impl Parse for IndexAttributes {
                         "Unrecognized index attribute.\n\
                          Supported attributes:\n\
                          - unique: Create unique index\n\
-                         - concurrent: Create index concurrently\n\
-                         - method: Index method (btree, hash, gin, gist, spgist, brin)\n\
+                         - concurrent / concurrently: Create index concurrently\n\
+                         - method: Index method (btree, hash, gin, gist, spgist, brin, hnsw, ivfflat)\n\
                          - tablespace: Specify tablespace\n\
+                         - with: Storage parameters, e.g. with = \"m = 16, ef_construction = 64\"\n\
                          - where: Partial index condition\n\
                          See: https://www.postgresql.org/docs/current/sql-createindex.html",
                     ));
@@ -141,7 +161,7 @@ pub fn postgres_index_attr_macro(
     let index_column_def = ddl_paths::index_column_def();
 
     // Extract columns from tuple struct fields: struct UserEmailIdx(User::email);
-    let columns = match &input.data {
+    let members: Vec<(Expr, Option<String>, Option<String>)> = match &input.data {
         syn::Data::Struct(data_struct) => {
             match &data_struct.fields {
                 syn::Fields::Unnamed(fields) => {
@@ -150,12 +170,18 @@ pub fn postgres_index_attr_macro(
                         .iter()
                         .map(|field| {
                             // Convert Type to Expr
+                            let collate = index_member_collate(field, &["collate", "collation"])?;
+                            let opclass = index_member_opclass(field)?;
                             match &field.ty {
-                                Type::Path(type_path) => Ok(Expr::Path(syn::ExprPath {
-                                    attrs: vec![],
-                                    qself: None,
-                                    path: type_path.path.clone(),
-                                })),
+                                Type::Path(type_path) => Ok((
+                                    Expr::Path(syn::ExprPath {
+                                        attrs: vec![],
+                                        qself: None,
+                                        path: type_path.path.clone(),
+                                    }),
+                                    collate,
+                                    opclass,
+                                )),
                                 _ => Err(Error::new_spanned(
                                     field,
                                     "Index columns must be table column references (e.g., Users::email)",
@@ -180,6 +206,10 @@ pub fn postgres_index_attr_macro(
         }
     };
 
+    let columns: Vec<Expr> = members.iter().map(|(col, _, _)| col.clone()).collect();
+    let collations: Vec<Option<String>> = members.iter().map(|(_, c, _)| c.clone()).collect();
+    let opclasses: Vec<Option<String>> = members.iter().map(|(_, _, o)| o.clone()).collect();
+
     // Parse column references (for index name generation)
     let column_info = parse_column_references(&columns)?;
 
@@ -201,7 +231,15 @@ pub fn postgres_index_attr_macro(
     // and extracts its NAME - this ensures we use the actual database column name
     let column_defs: Vec<_> = columns
         .iter()
-        .map(|col| {
+        .zip(&collations)
+        .zip(&opclasses)
+        .map(|((col, collate), opclass)| {
+            let collate_modifier = collate
+                .as_ref()
+                .map_or_else(|| quote! {}, |collate| quote! { .collate(#collate) });
+            let opclass_modifier = opclass
+                .as_ref()
+                .map_or_else(|| quote! {}, |opclass| quote! { .opclass_name(#opclass) });
             quote! {
                 #index_column_def::new({
                     // Const validation that the column implements SQLSchema
@@ -210,10 +248,19 @@ pub fn postgres_index_attr_macro(
                     }
                     column_name(&#col)
                 })
+                #collate_modifier
+                #opclass_modifier
             }
         })
         .collect();
 
+    let column_collations: Vec<_> = collations.iter().map(option_str_tokens).collect();
+    let column_opclasses: Vec<_> = opclasses.iter().map(option_str_tokens).collect();
+
+    // btree is the default method, so it stays out of the snapshot metadata
+    let method = option_str_tokens(&attr.method.clone().filter(|method| method != "btree"));
+    let storage_parameters = option_str_tokens(&attr.with);
+
     let column_names: Vec<_> = columns
         .iter()
         .map(|col| {
@@ -246,6 +293,11 @@ pub fn postgres_index_attr_macro(
         .as_ref()
         .map_or_else(|| quote! {}, |method| quote! { .method(#method) });
 
+    let with_modifier = attr
+        .with
+        .as_ref()
+        .map_or_else(|| quote! {}, |with| quote! { .with(#with) });
+
     let where_modifier = attr.where_clause.as_ref().map_or_else(
         || quote! {},
         |where_clause| quote! { .where_clause(#where_clause) },
@@ -262,10 +314,17 @@ pub fn postgres_index_attr_macro(
         .map_or_else(|| "\"(".to_string(), |method| format!("\" USING {method}("));
     let column_sql_parts: Vec<TokenStream> = columns
         .iter()
+        .zip(&collations)
+        .zip(&opclasses)
         .enumerate()
-        .map(|(i, col)| {
+        .map(|(i, ((col, collate), opclass))| {
             let prefix = if i > 0 { ", \"" } else { "\"" };
-            let suffix = "\"";
+            let mut suffix = collate
+                .as_ref()
+                .map_or_else(|| "\"".to_string(), |collate| format!("\" COLLATE \"{collate}\""));
+            if let Some(opclass) = opclass {
+                suffix = format!("{suffix} {opclass}");
+            }
             quote! {
                 #prefix,
                 {
@@ -278,10 +337,13 @@ pub fn postgres_index_attr_macro(
             }
         })
         .collect();
-    let close = attr
-        .where_clause
+    let mut close = attr
+        .with
         .as_ref()
-        .map_or_else(|| ")".to_string(), |wc| format!(") WHERE {wc}"));
+        .map_or_else(|| ")".to_string(), |with| format!(") WITH ({with})"));
+    if let Some(wc) = &attr.where_clause {
+        close = format!("{close} WHERE {wc}");
+    }
     let const_format = crate::common::paths::const_format();
     let const_sql = quote! {
         #const_format::concatcp!(
@@ -307,6 +369,12 @@ pub fn postgres_index_attr_macro(
             /// Column names for schema snapshot generation
             pub const COLUMN_NAMES: &'static [&'static str] = &[#(#column_names),*];
 
+            /// Per-column collation overrides for schema snapshot generation
+            pub const COLUMN_COLLATIONS: &'static [::core::option::Option<&'static str>] = &[#(#column_collations),*];
+
+            /// Per-column operator classes for schema snapshot generation
+            pub const COLUMN_OPCLASSES: &'static [::core::option::Option<&'static str>] = &[#(#column_opclasses),*];
+
             /// Const DDL index definition - single source of truth
             pub const DDL_INDEX: #index_def = #index_def::new(
                 #table_type::DDL_TABLE.schema,
@@ -317,6 +385,7 @@ pub fn postgres_index_attr_macro(
             #unique_modifier
             #concurrent_modifier
             #method_modifier
+            #with_modifier
             #where_modifier;
 
             pub const fn new() -> Self {
@@ -348,6 +417,10 @@ pub fn postgres_index_attr_macro(
             const INDEX_NAME: &'static str = #index_name;
             const COLUMN_NAMES: &'static [&'static str] = Self::COLUMN_NAMES;
             const IS_UNIQUE: bool = #is_unique;
+            const COLUMN_COLLATIONS: &'static [::core::option::Option<&'static str>] = Self::COLUMN_COLLATIONS;
+            const METHOD: ::core::option::Option<&'static str> = #method;
+            const COLUMN_OPCLASSES: &'static [::core::option::Option<&'static str>] = Self::COLUMN_OPCLASSES;
+            const STORAGE_PARAMETERS: ::core::option::Option<&'static str> = #storage_parameters;
 
             fn table_ref() -> &'static drizzle::core::TableRef {
                 &<#table_type as drizzle::core::DrizzleTable>::TABLE_REF
@@ -393,9 +466,16 @@ pub fn postgres_index_attr_macro(
     Ok(expanded)
 }
 
+fn option_str_tokens(value: &Option<String>) -> TokenStream {
+    match value {
+        Some(value) => quote! { ::core::option::Option::Some(#value) },
+        None => quote! { ::core::option::Option::None },
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::create_index_prefix;
+    use super::{IndexAttributes, create_index_prefix};
 
     #[test]
     fn create_index_prefix_places_concurrently_after_index() {
@@ -404,6 +484,23 @@ mod tests {
             "CREATE UNIQUE INDEX CONCURRENTLY \"users_email_idx\" ON \""
         );
     }
+
+    #[test]
+    fn concurrently_is_accepted_alongside_concurrent() {
+        for input in ["concurrent", "unique, concurrently"] {
+            let attrs: IndexAttributes = syn::parse_str(input).unwrap();
+            assert!(attrs.concurrent, "{input}");
+        }
+    }
+
+    #[test]
+    fn pgvector_methods_and_storage_parameters_are_accepted() {
+        let attrs: IndexAttributes =
+            syn::parse_str(r#"method = "hnsw", with = "m = 16, ef_construction = 64""#).unwrap();
+        assert_eq!(attrs.method.as_deref(), Some("hnsw"));
+        assert_eq!(attrs.with.as_deref(), Some("m = 16, ef_construction = 64"));
+        assert!(syn::parse_str::<IndexAttributes>(r#"method = "ivfflat""#).is_ok());
+    }
 }
 
 /// Information about a column reference in an index
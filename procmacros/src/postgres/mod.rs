@@ -1,3 +1,5 @@
+pub mod composite;
+pub mod domain;
 pub mod r#enum;
 pub mod field;
 pub mod generators;
@@ -74,6 +74,7 @@ pub fn generate_postgres_schema_derive_impl(input: &DeriveInput) -> Result<Token
     let all_field_types: Box<_> = all_fields.iter().map(|(_, ty)| *ty).collect();
 
     let create_statements_impl = generate_create_statements_method(&all_fields);
+    let drop_statements_impl = generate_drop_statements_method(&all_fields);
 
     // For Schema trait to_snapshot
     let field_types_for_snapshot: Vec<_> = all_fields.iter().map(|(_, ty)| *ty).collect();
@@ -93,14 +94,29 @@ pub fn generate_postgres_schema_derive_impl(input: &DeriveInput) -> Result<Token
     let mig_pg_sequence = mig_paths::postgres::sequence();
     let mig_pg_index = mig_paths::postgres::index();
     let mig_pg_index_column = mig_paths::postgres::index_column();
+    let mig_pg_opclass = mig_paths::postgres::opclass();
     let mig_pg_primary_key = mig_paths::postgres::primary_key();
     let mig_pg_foreign_key = mig_paths::postgres::foreign_key();
     let mig_pg_unique_constraint = mig_paths::postgres::unique_constraint();
     let mig_pg_check_constraint = mig_paths::postgres::check_constraint();
+    let mig_pg_exclude_constraint = mig_paths::postgres::exclude_constraint();
+    let mig_pg_exclude_element = mig_paths::postgres::exclude_element();
     let mig_pg_policy = mig_paths::postgres::policy();
     let mig_pg_enum = mig_paths::postgres::enum_type();
+    let mig_pg_domain = mig_paths::postgres::domain();
+    let mig_pg_composite = mig_paths::postgres::composite();
+    let mig_pg_composite_field = mig_paths::postgres::composite_field();
     let mig_pg_view = mig_paths::postgres::view();
 
+    let schema_item_hash = core_paths::schema_item_hash();
+    let schema_hash_terms = all_fields.iter().map(|(_, ty)| {
+        quote! {
+            .wrapping_add(#schema_item_hash(
+                <#ty as #sql_schema<'static, #postgres_schema_type, #postgres_value<'static>>>::NAME,
+                <#ty as #sql_schema<'static, #postgres_schema_type, #postgres_value<'static>>>::SQL,
+            ))
+        }
+    });
     let schema_table_refs_method = generate_schema_table_refs_method(&all_fields);
     let schema_has_table_impls = generate_schema_has_table_impls(struct_name, &all_fields);
     let schema_fk_validation_asserts = generate_schema_fk_validation_asserts(
@@ -108,6 +124,7 @@ pub fn generate_postgres_schema_derive_impl(input: &DeriveInput) -> Result<Token
         struct_name,
         &validate_schema_item_foreign_keys,
     );
+    let schema_fk_cycle_assert = generate_schema_fk_cycle_assert(&all_fields, struct_name);
 
     Ok(quote! {
         impl ::core::marker::Copy for #struct_name {}
@@ -139,10 +156,15 @@ pub fn generate_postgres_schema_derive_impl(input: &DeriveInput) -> Result<Token
 
             /// Get all schema items (tables, indexes, and enums) in field order
             #items_method
+
+            /// Stable hash of the schema's DDL, independent of field order.
+            pub const SCHEMA_HASH: u64 = 0u64 #(#schema_hash_terms)*;
         }
 
         // Implement SQLSchemaImpl trait
         impl #sql_schema_impl for #struct_name {
+            const SCHEMA_HASH: u64 = Self::SCHEMA_HASH;
+
             fn table_refs(&self) -> &'static [&'static drizzle::core::TableRef] {
                 #schema_table_refs_method
             }
@@ -151,6 +173,11 @@ pub fn generate_postgres_schema_derive_impl(input: &DeriveInput) -> Result<Token
                 let statements: ::std::vec::Vec<::std::string::String> = { #create_statements_impl };
                 ::std::result::Result::Ok(statements.into_iter())
             }
+
+            fn drop_statements(&self) -> ::std::result::Result<impl ::std::iter::Iterator<Item = ::std::string::String>, drizzle::error::DrizzleError> {
+                let statements: ::std::vec::Vec<::std::string::String> = { #drop_statements_impl };
+                ::std::result::Result::Ok(statements.into_iter())
+            }
         }
 
         // Implement tuple destructuring support
@@ -163,6 +190,7 @@ pub fn generate_postgres_schema_derive_impl(input: &DeriveInput) -> Result<Token
         #schema_has_table_impls
 
         #schema_fk_validation_asserts
+        #schema_fk_cycle_assert
 
         // Implement migrations Schema trait for migration config
         impl #mig_schema for #struct_name {
@@ -180,12 +208,18 @@ pub fn generate_postgres_schema_derive_impl(input: &DeriveInput) -> Result<Token
                 type MigIdentity = #mig_pg_identity;
                 type MigIndex = #mig_pg_index;
                 type MigIndexColumn = #mig_pg_index_column;
+                type MigOpclass = #mig_pg_opclass;
                 type MigPrimaryKey = #mig_pg_primary_key;
                 type MigForeignKey = #mig_pg_foreign_key;
                 type MigUniqueConstraint = #mig_pg_unique_constraint;
                 type MigCheckConstraint = #mig_pg_check_constraint;
+                type MigExcludeConstraint = #mig_pg_exclude_constraint;
+                type MigExcludeElement = #mig_pg_exclude_element;
                 type MigPolicy = #mig_pg_policy;
                 type MigEnum = #mig_pg_enum;
+                type MigDomain = #mig_pg_domain;
+                type MigComposite = #mig_pg_composite;
+                type MigCompositeField = #mig_pg_composite_field;
                 type MigView = #mig_pg_view;
                 type MigSequence = #mig_pg_sequence;
 
@@ -406,6 +440,31 @@ pub fn generate_postgres_schema_derive_impl(input: &DeriveInput) -> Result<Token
                                             )));
                                         }
                                     }
+                                    drizzle::core::SQLConstraintKind::Exclude => {
+                                        if let ::core::option::Option::Some(exclusion) = constraint.exclusion {
+                                            let exclude_name = constraint.name.unwrap_or("exclude");
+                                            let elements: ::std::vec::Vec<MigExcludeElement> = constraint
+                                                .columns
+                                                .iter()
+                                                .zip(exclusion.operators.iter())
+                                                .map(|(col, op)| MigExcludeElement::column(*col, *op))
+                                                .collect();
+                                            let mut exclude = MigExcludeConstraint::new(
+                                                table_schema,
+                                                table_name,
+                                                exclude_name,
+                                                elements,
+                                            );
+                                            exclude.name_explicit = constraint.name_explicit;
+                                            exclude.using = ::std::borrow::Cow::Borrowed(exclusion.using);
+                                            exclude.where_clause = exclusion
+                                                .predicate
+                                                .map(::std::borrow::Cow::Borrowed);
+                                            exclude.deferrable = constraint.deferrable || constraint.initially_deferred;
+                                            exclude.initially_deferred = constraint.initially_deferred;
+                                            snapshot.add_entity(MigEntity::ExcludeConstraint(exclude));
+                                        }
+                                    }
                                     _ => {}
                                 }
                             }
@@ -420,12 +479,30 @@ pub fn generate_postgres_schema_derive_impl(input: &DeriveInput) -> Result<Token
                                 #sql_index_info::name(index_info),
                                 #sql_index_info::columns(index_info)
                                     .iter()
-                                    .map(|c| MigIndexColumn::new(*c))
+                                    .enumerate()
+                                    .map(|(i, c)| {
+                                        let mut column = MigIndexColumn::new(*c);
+                                        if let ::core::option::Option::Some(::core::option::Option::Some(collate)) =
+                                            #sql_index_info::column_collations(index_info).get(i)
+                                        {
+                                            column = column.with_collate(*collate);
+                                        }
+                                        if let ::core::option::Option::Some(::core::option::Option::Some(opclass)) =
+                                            #sql_index_info::column_opclasses(index_info).get(i)
+                                        {
+                                            column = column.with_opclass(MigOpclass::new(*opclass));
+                                        }
+                                        column
+                                    })
                                     .collect::<::std::vec::Vec<_>>(),
                             );
                             if #sql_index_info::is_unique(index_info) {
                                 index = index.unique();
                             }
+                            index.method = #sql_index_info::method(index_info)
+                                .map(::std::borrow::Cow::Borrowed);
+                            index.with = #sql_index_info::storage_parameters(index_info)
+                                .map(::std::borrow::Cow::Borrowed);
                             snapshot.add_entity(MigEntity::Index(index));
                         }
                         #postgres_schema_type::Enum(enum_info) => {
@@ -436,6 +513,30 @@ pub fn generate_postgres_schema_derive_impl(input: &DeriveInput) -> Result<Token
                                 enum_info.variants().iter().map(|v| v.to_string()).collect(),
                             )));
                         }
+                        #postgres_schema_type::Domain(domain_info) => {
+                            let mut domain = MigDomain::new(
+                                "public",
+                                domain_info.name(),
+                                domain_info.base_type(),
+                            );
+                            domain.not_null = domain_info.not_null();
+                            domain.default = domain_info
+                                .default_value()
+                                .map(::std::borrow::Cow::Borrowed);
+                            domain.check = domain_info.check().map(::std::borrow::Cow::Borrowed);
+                            snapshot.add_entity(MigEntity::Domain(domain));
+                        }
+                        #postgres_schema_type::Composite(composite_info) => {
+                            snapshot.add_entity(MigEntity::Composite(MigComposite::new(
+                                "public",
+                                composite_info.name(),
+                                composite_info
+                                    .fields()
+                                    .iter()
+                                    .map(|(name, sql_type)| MigCompositeField::new(*name, *sql_type))
+                                    .collect(),
+                            )));
+                        }
                         #postgres_schema_type::View(view_info) => {
                             let view_schema = #sql_table_info::schema(view_info).unwrap_or("public");
                             let mut view = MigView::new(view_schema, #sql_table_info::name(view_info));
@@ -515,6 +616,35 @@ fn generate_schema_fk_validation_asserts(
     }
 }
 
+/// Rejects, at compile time, tables whose non-deferrable foreign keys form a
+/// cycle: no creation order can satisfy them.
+fn generate_schema_fk_cycle_assert(
+    fields: &[(&syn::Ident, &syn::Type)],
+    struct_name: &syn::Ident,
+) -> TokenStream {
+    let schema_item_tables = core_paths::schema_item_tables();
+    let undeferred_fk_cycle = core_paths::undeferred_fk_cycle();
+    let const_format = crate::common::paths::const_format();
+    let field_types: Vec<_> = fields.iter().map(|(_, ty)| *ty).collect();
+    let prefix =
+        format!("#[derive(PostgresSchema)] on `{struct_name}`: foreign keys through table `");
+
+    quote! {
+        const _: () = {
+            const CYCLE: ::core::option::Option<&str> = #undeferred_fk_cycle([
+                #(<#field_types as #schema_item_tables>::TABLE_REF_CONST,)*
+            ]);
+            const TABLE: &str = match CYCLE {
+                ::core::option::Option::Some(table) => table,
+                ::core::option::Option::None => "",
+            };
+            if CYCLE.is_some() {
+                ::core::panic!("{}", #const_format::concatcp!(#prefix, TABLE, "` form a cycle; mark one of them DEFERRABLE"));
+            }
+        };
+    }
+}
+
 fn generate_create_statements_method(fields: &[(&syn::Ident, &syn::Type)]) -> TokenStream {
     // Get paths for fully-qualified types
     let sql_schema = core_paths::sql_schema();
@@ -544,7 +674,9 @@ fn generate_create_statements_method(fields: &[(&syn::Ident, &syn::Type)]) -> To
         let mut policies: ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<::std::string::String>> = ::std::collections::HashMap::new();
         let mut policy_keys: ::std::collections::HashSet<::std::string::String> = ::std::collections::HashSet::new();
         let mut enums: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::new();
-        let mut views: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::new();
+        let mut domains: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::new();
+        let mut composites: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::new();
+        let mut views: ::std::vec::Vec<(::std::string::String, ::std::string::String)> = ::std::vec::Vec::new();
 
         // Collect all tables, indexes, and enums
         #(
@@ -614,6 +746,14 @@ fn generate_create_statements_method(fields: &[(&syn::Ident, &syn::Type)]) -> To
                     let enum_sql = <#field_types as #sql_schema<'_, #postgres_schema_type, #postgres_value<'_>>>::SQL.to_string();
                     enums.push(enum_sql);
                 }
+                #postgres_schema_type::Domain(_domain_info) => {
+                    let domain_sql = <#field_types as #sql_schema<'_, #postgres_schema_type, #postgres_value<'_>>>::SQL.to_string();
+                    domains.push(domain_sql);
+                }
+                #postgres_schema_type::Composite(_composite_info) => {
+                    let composite_sql = <#field_types as #sql_schema<'_, #postgres_schema_type, #postgres_value<'_>>>::SQL.to_string();
+                    composites.push(composite_sql);
+                }
                 #postgres_schema_type::View(view_info) => {
                     if !view_info.is_existing() {
                         let sql = <#field_types as #sql_schema<'_, #postgres_schema_type, #postgres_value<'_>>>::SQL;
@@ -649,7 +789,12 @@ fn generate_create_statements_method(fields: &[(&syn::Ident, &syn::Type)]) -> To
                         } else {
                             sql.to_string()
                         };
-                        views.push(view_sql);
+                        let view_key = ::std::format!(
+                            "{}.{}",
+                            #sql_table_info::schema(view_info).unwrap_or("public"),
+                            #sql_table_info::name(view_info)
+                        );
+                        views.push((view_key, view_sql));
                     }
                 }
                 #postgres_schema_type::Policy(policy_info) => {
@@ -706,93 +851,164 @@ fn generate_create_statements_method(fields: &[(&syn::Ident, &syn::Type)]) -> To
             ));
         }
 
-        let mut indegree: ::std::collections::HashMap<::std::string::String, usize> =
-            ::std::collections::HashMap::with_capacity(tables.len());
-        let mut reverse_edges: ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<::std::string::String>> =
-            ::std::collections::HashMap::new();
-
         // Map unqualified name → qualified name for dependency resolution
         let name_to_qualified: ::std::collections::HashMap<::std::string::String, ::std::string::String> =
             tables.iter().map(|(qname, _, tref, _, _)| (tref.name.to_string(), qname.clone())).collect();
 
-        for (table_name, _, table_ref, _, _) in &tables {
-            indegree.entry(table_name.clone()).or_insert(0);
-
-            for dep_name in table_ref.dependency_names
-                .iter()
-                .filter_map(|dep| name_to_qualified.get(*dep))
-                .filter(|dep_name| *dep_name != table_name)
-                .filter(|dep_name| table_names.contains(*dep_name))
-                .cloned()
-            {
-                *indegree
-                    .get_mut(table_name)
-                    .expect("indegree is initialized for each table") += 1;
-                reverse_edges
-                    .entry(dep_name)
-                    .or_insert_with(::std::vec::Vec::new)
-                    .push(table_name.clone());
-            }
-        }
-
-        let mut ready: ::std::collections::BTreeSet<::std::string::String> = indegree
-            .iter()
-            .filter(|(_, degree)| **degree == 0)
-            .map(|(name, _)| name.clone())
-            .collect();
-        let mut ordered_names: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::with_capacity(tables.len());
-
-        while let ::std::option::Option::Some(next) = ready.pop_first() {
-            ordered_names.push(next.clone());
-
-            if let ::std::option::Option::Some(children) = reverse_edges.get(&next) {
-                for child in children {
-                    let degree = indegree
-                        .get_mut(child)
-                        .expect("child table must exist in indegree map");
-                    *degree -= 1;
-                    if *degree == 0 {
-                        ready.insert(child.clone());
-                    }
+        // With `hard_only`, deferrable foreign keys are left out of the graph
+        // so a cycle running through one can still be ordered; those keys are
+        // added after every table exists. Returns the tables left on a cycle
+        // on failure.
+        let sort = |hard_only: bool| -> ::std::result::Result<::std::vec::Vec<::std::string::String>, ::std::vec::Vec<::std::string::String>> {
+            let mut indegree: ::std::collections::HashMap<::std::string::String, usize> =
+                ::std::collections::HashMap::with_capacity(tables.len());
+            let mut reverse_edges: ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<::std::string::String>> =
+                ::std::collections::HashMap::new();
+
+            for (table_name, _, table_ref, _, _) in &tables {
+                indegree.entry(table_name.clone()).or_insert(0);
+
+                let deps: ::std::vec::Vec<&str> = if hard_only {
+                    table_ref.foreign_keys
+                        .iter()
+                        .filter(|fk| !fk.is_deferrable())
+                        .map(|fk| fk.target_table)
+                        .collect()
+                } else {
+                    table_ref.dependency_names.to_vec()
+                };
+                for dep_name in deps
+                    .into_iter()
+                    .filter_map(|dep| name_to_qualified.get(dep))
+                    .filter(|dep_name| *dep_name != table_name)
+                    .cloned()
+                {
+                    *indegree
+                        .get_mut(table_name)
+                        .expect("indegree is initialized for each table") += 1;
+                    reverse_edges
+                        .entry(dep_name)
+                        .or_insert_with(::std::vec::Vec::new)
+                        .push(table_name.clone());
                 }
             }
-        }
 
-        if ordered_names.len() != tables.len() {
-            let mut remaining: ::std::vec::Vec<::std::string::String> = indegree
+            let mut ready: ::std::collections::BTreeSet<::std::string::String> = indegree
                 .iter()
-                .filter(|(_, degree)| **degree > 0)
+                .filter(|(_, degree)| **degree == 0)
                 .map(|(name, _)| name.clone())
                 .collect();
-            remaining.sort_unstable();
-            return ::std::result::Result::Err(drizzle::error::DrizzleError::Statement(
-                ::std::format!(
-                    "Cyclic table dependency detected in PostgresSchema: {}",
-                    remaining.join(", ")
-                )
-                .into(),
-            ));
-        }
+            let mut ordered_names: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::with_capacity(tables.len());
+
+            while let ::std::option::Option::Some(next) = ready.pop_first() {
+                ordered_names.push(next.clone());
+
+                if let ::std::option::Option::Some(children) = reverse_edges.get(&next) {
+                    for child in children {
+                        let degree = indegree
+                            .get_mut(child)
+                            .expect("child table must exist in indegree map");
+                        *degree -= 1;
+                        if *degree == 0 {
+                            ready.insert(child.clone());
+                        }
+                    }
+                }
+            }
+
+            if ordered_names.len() != tables.len() {
+                let mut remaining: ::std::vec::Vec<::std::string::String> = indegree
+                    .iter()
+                    .filter(|(_, degree)| **degree > 0)
+                    .map(|(name, _)| name.clone())
+                    .collect();
+                remaining.sort_unstable();
+                return ::std::result::Result::Err(remaining);
+            }
+            ::std::result::Result::Ok(ordered_names)
+        };
+
+        let ordered_names = match sort(false).or_else(|_| sort(true)) {
+            ::std::result::Result::Ok(ordered_names) => ordered_names,
+            ::std::result::Result::Err(remaining) => {
+                return ::std::result::Result::Err(drizzle::error::DrizzleError::Statement(
+                    ::std::format!(
+                        "Cyclic table dependency detected in PostgresSchema: {}",
+                        remaining.join(", ")
+                    )
+                    .into(),
+                ));
+            }
+        };
 
         let mut table_by_name: ::std::collections::HashMap<
             ::std::string::String,
-            (::std::string::String, ::std::vec::Vec<::std::string::String>, ::core::option::Option<::std::string::String>),
+            (
+                ::std::string::String,
+                &'static drizzle::core::TableRef,
+                ::std::vec::Vec<::std::string::String>,
+                ::core::option::Option<::std::string::String>,
+            ),
         > = ::std::collections::HashMap::with_capacity(tables.len());
-        for (table_name, table_sql, _table_ref, comment_sqls, rls_sql) in tables {
-            table_by_name.insert(table_name, (table_sql, comment_sqls, rls_sql));
+        for (table_name, table_sql, table_ref, comment_sqls, rls_sql) in tables {
+            table_by_name.insert(table_name, (table_sql, table_ref, comment_sqls, rls_sql));
         }
 
-        // Build final SQL statements: enums first, then tables in dependency order, then their indexes
+        // Build final SQL statements: enums, domains and composites first, then tables in dependency order, then their indexes
         let mut sql_statements = ::std::vec::Vec::<::std::string::String>::new();
 
         // Add all enums first (they must be created before tables that use them)
         sql_statements.extend(enums);
 
+        // Then domains (a domain may be based on an enum, and tables use them)
+        sql_statements.extend(domains);
+
+        // Then composite types (their attributes may use enums and domains)
+        sql_statements.extend(composites);
+
+        // Deferrable foreign keys into tables that don't exist yet, moved
+        // out of their CREATE TABLE and added once every table is created
+        let mut created: ::std::collections::HashSet<&str> = ::std::collections::HashSet::new();
+        let mut deferred_fks = ::std::vec::Vec::<::std::string::String>::new();
+
         // Add tables and their indexes
         for table_name in ordered_names {
-            let (table_sql, comment_sqls, rls_sql) = table_by_name
+            let (mut table_sql, table_ref, comment_sqls, rls_sql) = table_by_name
                 .remove(&table_name)
                 .expect("table exists after topological ordering");
+            created.insert(table_ref.name);
+            for fk in table_ref.foreign_keys.iter().filter(|fk| {
+                fk.is_deferrable()
+                    && !created.contains(fk.target_table)
+                    && name_to_qualified.contains_key(fk.target_table)
+            }) {
+                let marker = ::std::format!(",\n\tCONSTRAINT \"{}\" FOREIGN KEY ", fk.name);
+                let ::std::option::Option::Some(start) = table_sql.find(&marker) else {
+                    continue;
+                };
+                // Each constraint sits on its own line, ending at the next
+                // separator or at the closing parenthesis
+                let clause_start = start + ",\n\t".len();
+                let rest = &table_sql[clause_start..];
+                let clause_end = clause_start
+                    + [rest.find(",\n"), rest.find("\n)")]
+                        .into_iter()
+                        .flatten()
+                        .min()
+                        .unwrap_or(rest.len());
+                let qualified = match table_ref.schema {
+                    ::core::option::Option::Some(schema) if schema != "public" => {
+                        ::std::format!("\"{}\".\"{}\"", schema, table_ref.name)
+                    }
+                    _ => ::std::format!("\"{}\"", table_ref.name),
+                };
+                deferred_fks.push(::std::format!(
+                    "ALTER TABLE {} ADD {};",
+                    qualified,
+                    &table_sql[clause_start..clause_end]
+                ));
+                table_sql.replace_range(start..clause_end, "");
+            }
             sql_statements.push(table_sql);
             sql_statements.extend(comment_sqls);
 
@@ -814,8 +1030,101 @@ fn generate_create_statements_method(fields: &[(&syn::Ident, &syn::Type)]) -> To
             }
         }
 
-        // Add views last (they depend on tables)
-        sql_statements.extend(views);
+        sql_statements.extend(deferred_fks);
+
+        // Add views last (they depend on tables), each followed by the
+        // indexes declared on it (materialized views only)
+        for (view_key, view_sql) in views {
+            sql_statements.push(view_sql);
+            if let ::std::option::Option::Some(view_indexes) = indexes.get(&view_key) {
+                for index_sql in view_indexes {
+                    sql_statements.push(index_sql.clone());
+                }
+            }
+        }
+
+        sql_statements
+    }
+}
+
+fn generate_drop_statements_method(fields: &[(&syn::Ident, &syn::Type)]) -> TokenStream {
+    let sql_schema = core_paths::sql_schema();
+    let sql_index_info = core_paths::sql_index_info();
+    let sql_table_info = core_paths::sql_table_info();
+    let postgres_value = postgres_paths::postgres_value();
+    let postgres_schema_type = postgres_paths::postgres_schema_type();
+
+    let field_types: Vec<_> = fields.iter().map(|(_, ty)| *ty).collect();
+
+    quote! {
+        let quote_ident = |ident: &str| -> ::std::string::String {
+            ::std::format!("\"{}\"", ident.replace('"', "\"\""))
+        };
+        let qualify = |schema: ::core::option::Option<&str>, name: &str| -> ::std::string::String {
+            match schema {
+                ::core::option::Option::Some(schema) if schema != "public" => {
+                    ::std::format!("{}.{}", quote_ident(schema), quote_ident(name))
+                }
+                _ => quote_ident(name),
+            }
+        };
+        let mut sql_statements = ::std::vec::Vec::<::std::string::String>::new();
+        let mut index_statements = ::std::vec::Vec::<::std::string::String>::new();
+        let mut domain_statements = ::std::vec::Vec::<::std::string::String>::new();
+        let mut composite_statements = ::std::vec::Vec::<::std::string::String>::new();
+        let mut enum_statements = ::std::vec::Vec::<::std::string::String>::new();
+
+        // Views first (they depend on tables), then indexes
+        #(
+            match <#field_types as #sql_schema<'_, #postgres_schema_type, #postgres_value<'_>>>::TYPE {
+                #postgres_schema_type::View(view_info) => {
+                    if !view_info.is_existing() {
+                        let materialized_kw = if view_info.is_materialized() { "MATERIALIZED " } else { "" };
+                        sql_statements.push(::std::format!(
+                            "DROP {}VIEW IF EXISTS {} CASCADE",
+                            materialized_kw,
+                            qualify(#sql_table_info::schema(view_info), #sql_table_info::name(view_info))
+                        ));
+                    }
+                }
+                #postgres_schema_type::Index(index_info) => {
+                    index_statements.push(::std::format!(
+                        "DROP INDEX IF EXISTS {}",
+                        qualify(#sql_index_info::table(index_info).schema, #sql_index_info::name(index_info))
+                    ));
+                }
+                #postgres_schema_type::Enum(enum_info) => {
+                    enum_statements.push(::std::format!("DROP TYPE IF EXISTS {} CASCADE", enum_info.name()));
+                }
+                #postgres_schema_type::Domain(domain_info) => {
+                    domain_statements.push(::std::format!(
+                        "DROP DOMAIN IF EXISTS {} CASCADE",
+                        quote_ident(domain_info.name())
+                    ));
+                }
+                #postgres_schema_type::Composite(composite_info) => {
+                    composite_statements.push(::std::format!(
+                        "DROP TYPE IF EXISTS {} CASCADE",
+                        quote_ident(composite_info.name())
+                    ));
+                }
+                _ => {}
+            }
+        )*
+        sql_statements.extend(index_statements);
+
+        // Then tables, referencing tables before the tables they reference
+        for table_ref in drizzle::core::truncation_order(self.table_refs())? {
+            sql_statements.push(::std::format!(
+                "DROP TABLE IF EXISTS {} CASCADE",
+                qualify(table_ref.schema, table_ref.name)
+            ));
+        }
+
+        // Composites, domains and enums last (tables use them)
+        sql_statements.extend(composite_statements);
+        sql_statements.extend(domain_statements);
+        sql_statements.extend(enum_statements);
 
         sql_statements
     }
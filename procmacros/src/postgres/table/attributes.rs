@@ -1,9 +1,28 @@
+use drizzle_types::Casing;
 use syn::parse::Parser;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{ExprPath, Ident, Meta, Result, Token, parse::Parse};
 
-use crate::common::make_uppercase_path;
+use crate::common::{did_you_mean, make_uppercase_path};
+
+/// Names accepted inside `#[PostgresTable(...)]`, used for "did you mean" hints.
+const TABLE_ATTRIBUTES: &[&str] = &[
+    "NAME",
+    "SCHEMA",
+    "UNLOGGED",
+    "TEMPORARY",
+    "INHERITS",
+    "TABLESPACE",
+    "RLS",
+    "ID_TYPE",
+    "COMMENT",
+    "CASING",
+    "FOREIGN_KEY",
+    "UNIQUE",
+    "CHECK",
+    "EXCLUDE",
+];
 
 #[derive(Default)]
 pub struct TableAttributes {
@@ -17,6 +36,13 @@ pub struct TableAttributes {
     pub(crate) composite_foreign_keys: Vec<CompositeForeignKeyAttr>,
     pub(crate) unique_constraints: Vec<UniqueConstraintAttr>,
     pub(crate) check_constraints: Vec<CheckConstraintAttr>,
+    pub(crate) exclude_constraints: Vec<ExcludeConstraintAttr>,
+    /// Newtype generated for the primary key from `ID_TYPE = UserId`.
+    pub(crate) id_type: Option<Ident>,
+    /// Table comment from `COMMENT = "..."`; overrides the struct doc comment.
+    pub(crate) comment: Option<String>,
+    /// Name casing from `CASING = "..."`; `snake_case` when unset.
+    pub(crate) casing: Option<Casing>,
     /// Original marker paths for IDE hover documentation
     pub(crate) marker_exprs: Vec<ExprPath>,
 }
@@ -46,6 +72,161 @@ pub struct CheckConstraintAttr {
     pub(crate) expr: String,
 }
 
+#[derive(Clone)]
+pub struct ExcludeConstraintAttr {
+    /// `(column, operator)` pairs from `columns(col WITH op, ...)`.
+    pub(crate) elements: Vec<(Ident, String)>,
+    pub(crate) using: Option<String>,
+    pub(crate) predicate: Option<String>,
+    pub(crate) name: Option<String>,
+    pub(crate) deferrable: bool,
+    pub(crate) initially_deferred: bool,
+}
+
+struct ExcludeElementArg {
+    column: Ident,
+    operator: String,
+}
+
+impl Parse for ExcludeElementArg {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let column: Ident = input.parse()?;
+        let with: Ident = input.parse()?;
+        if !with.to_string().eq_ignore_ascii_case("with") {
+            return Err(syn::Error::new(
+                with.span(),
+                "expected `WITH`, e.g. columns(room_id WITH =, during WITH &&)",
+            ));
+        }
+        let mut operator = String::new();
+        while !input.is_empty() && !input.peek(Token![,]) {
+            let punct: proc_macro2::Punct = input.parse().map_err(|e| {
+                syn::Error::new(
+                    e.span(),
+                    "EXCLUDE operators must be punctuation such as `=` or `&&`",
+                )
+            })?;
+            operator.push(punct.as_char());
+        }
+        if operator.is_empty() {
+            return Err(syn::Error::new(
+                with.span(),
+                "missing operator after `WITH`",
+            ));
+        }
+        Ok(Self { column, operator })
+    }
+}
+
+impl Parse for ExcludeConstraintAttr {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let mut metas = Vec::new();
+        let mut predicate = None;
+        while !input.is_empty() {
+            // `where` is a keyword, which `Meta` refuses as a path.
+            if input.peek(Token![where]) {
+                input.parse::<Token![where]>()?;
+                input.parse::<Token![=]>()?;
+                let lit: syn::LitStr = input
+                    .parse()
+                    .map_err(|e| syn::Error::new(e.span(), "where must be a string literal"))?;
+                predicate = Some(lit.value());
+            } else {
+                metas.push(input.parse::<Meta>()?);
+            }
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+        let mut elements = Vec::new();
+        let mut using = None;
+        let mut name = None;
+        let mut deferrable = false;
+        let mut initially_deferred = false;
+
+        for meta in metas {
+            match meta {
+                Meta::List(list)
+                    if list
+                        .path
+                        .get_ident()
+                        .is_some_and(|ident| ident.to_string().eq_ignore_ascii_case("columns")) =>
+                {
+                    let args: Punctuated<ExcludeElementArg, Token![,]> =
+                        Punctuated::<ExcludeElementArg, Token![,]>::parse_terminated
+                            .parse2(list.tokens.clone())?;
+                    elements.extend(args.into_iter().map(|arg| (arg.column, arg.operator)));
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("using") || nv.path.is_ident("USING") => {
+                    match &nv.value {
+                        syn::Expr::Path(path) if path.path.get_ident().is_some() => {
+                            using = path.path.get_ident().map(ToString::to_string);
+                        }
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(s),
+                            ..
+                        }) => using = Some(s.value()),
+                        _ => {
+                            return Err(syn::Error::new(
+                                nv.value.span(),
+                                "using must be an index method such as gist",
+                            ));
+                        }
+                    }
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("WHERE") => {
+                    if let syn::Expr::Lit(lit) = &nv.value
+                        && let syn::Lit::Str(s) = &lit.lit
+                    {
+                        predicate = Some(s.value());
+                    } else {
+                        return Err(syn::Error::new(nv.span(), "where must be a string literal"));
+                    }
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("name") || nv.path.is_ident("NAME") => {
+                    if let syn::Expr::Lit(lit) = &nv.value
+                        && let syn::Lit::Str(s) = &lit.lit
+                    {
+                        name = Some(s.value());
+                    } else {
+                        return Err(syn::Error::new(nv.span(), "name must be a string literal"));
+                    }
+                }
+                Meta::Path(path) if path.is_ident("deferrable") => {
+                    deferrable = true;
+                }
+                Meta::Path(path) if path.is_ident("initially_deferred") => {
+                    deferrable = true;
+                    initially_deferred = true;
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        meta.span(),
+                        "unrecognized EXCLUDE argument; expected columns(col WITH op, ...), using = ..., where = \"...\", name = \"...\", deferrable, or initially_deferred",
+                    ));
+                }
+            }
+        }
+
+        if elements.is_empty() {
+            return Err(syn::Error::new(
+                input.span(),
+                "EXCLUDE requires columns(...), e.g. EXCLUDE(columns(room_id WITH =, during WITH &&))",
+            ));
+        }
+
+        Ok(Self {
+            elements,
+            using,
+            predicate,
+            name,
+            deferrable,
+            initially_deferred,
+        })
+    }
+}
+
 struct ReferencesArg {
     table: Ident,
     columns: Vec<Ident>,
@@ -332,6 +513,52 @@ impl Parse for TableAttributes {
                                     "NAME requires a string literal, e.g. NAME = \"my_table\"",
                                 ));
                             }
+                            "COMMENT" => {
+                                if let syn::Expr::Lit(lit) = &nv.value
+                                    && let syn::Lit::Str(str_lit) = &lit.lit
+                                {
+                                    attrs.comment = Some(str_lit.value());
+                                    attrs
+                                        .marker_exprs
+                                        .push(make_uppercase_path(ident, "COMMENT"));
+                                    continue;
+                                }
+                                return Err(syn::Error::new(
+                                    nv.value.span(),
+                                    "COMMENT requires a string literal, e.g. COMMENT = \"Registered users\"",
+                                ));
+                            }
+                            "CASING" => {
+                                if let syn::Expr::Lit(lit) = &nv.value
+                                    && let syn::Lit::Str(str_lit) = &lit.lit
+                                {
+                                    let casing = str_lit
+                                        .value()
+                                        .parse()
+                                        .map_err(|e: String| syn::Error::new(str_lit.span(), e))?;
+                                    attrs.casing = Some(casing);
+                                    attrs
+                                        .marker_exprs
+                                        .push(make_uppercase_path(ident, "CASING"));
+                                    continue;
+                                }
+                                return Err(syn::Error::new(
+                                    nv.value.span(),
+                                    "CASING requires a string literal, e.g. CASING = \"camelCase\"",
+                                ));
+                            }
+                            "ID_TYPE" => {
+                                if let syn::Expr::Path(path) = &nv.value
+                                    && let Some(id_ident) = path.path.get_ident()
+                                {
+                                    attrs.id_type = Some(id_ident.clone());
+                                    continue;
+                                }
+                                return Err(syn::Error::new(
+                                    nv.value.span(),
+                                    "ID_TYPE requires a type name, e.g. ID_TYPE = UserId",
+                                ));
+                            }
                             "SCHEMA" => {
                                 if let syn::Expr::Lit(lit) = nv.clone().value
                                     && let syn::Lit::Str(str_lit) = lit.lit
@@ -434,12 +661,26 @@ impl Parse for TableAttributes {
                             attrs.marker_exprs.push(make_uppercase_path(ident, "CHECK"));
                             continue;
                         }
+                        if ident_upper == "EXCLUDE" {
+                            let exclude: ExcludeConstraintAttr = syn::parse2(list.tokens.clone())?;
+                            attrs.exclude_constraints.push(exclude);
+                            attrs
+                                .marker_exprs
+                                .push(make_uppercase_path(ident, "EXCLUDE"));
+                            continue;
+                        }
                     }
                 }
             }
+            let name = meta
+                .path()
+                .get_ident()
+                .map(ToString::to_string)
+                .unwrap_or_default();
             return Err(syn::Error::new(
-                meta.span(),
-                "unrecognized table attribute.\n\
+                meta.path().span(),
+                format!(
+                    "unrecognized table attribute `{name}`{}\n\
                  Supported attributes (case-insensitive):\n\
                  - NAME: Custom table name (e.g., #[PostgresTable(NAME = \"custom_name\")])\n\
                  - SCHEMA: Custom schema name (e.g., #[PostgresTable(SCHEMA = \"auth\")])\n\
@@ -448,10 +689,15 @@ impl Parse for TableAttributes {
                  - INHERITS: Inherit from parent table (e.g., #[PostgresTable(INHERITS = \"parent_table\")])\n\
                  - TABLESPACE: Specify tablespace (e.g., #[PostgresTable(TABLESPACE = \"my_tablespace\")])\n\
                  - RLS: Enable row-level security (e.g., #[PostgresTable(RLS)])\n\
+                 - ID_TYPE: Generate a primary-key newtype (e.g., #[PostgresTable(ID_TYPE = UserId)])\n\
+                 - COMMENT: Table comment, defaults to the struct doc comment (e.g., #[PostgresTable(COMMENT = \"Users\")])\n\
                  - FOREIGN_KEY(...): Composite FK (e.g., #[PostgresTable(FOREIGN_KEY(columns(a,b), references(Parent,id_a,id_b)))])\n\
                  - UNIQUE(...): Table-level unique constraint (e.g., #[PostgresTable(UNIQUE(columns(a,b)))])\n\
                  - CHECK(...): Table-level check constraint (e.g., #[PostgresTable(CHECK(expr = \"score >= 0\"))])\n\
+                 - EXCLUDE(...): Exclusion constraint (e.g., #[PostgresTable(EXCLUDE(using = gist, columns(room_id WITH =, during WITH &&)))])\n\
                  See: https://www.postgresql.org/docs/current/sql-createtable.html",
+                    did_you_mean(&name, TABLE_ATTRIBUTES)
+                ),
             ));
         }
         Ok(attrs)
@@ -40,7 +40,8 @@ pub(super) fn generate_custom_comparison_operand_impls(
     zst_ident: &Ident,
     postgres_value: &TokenStream,
 ) -> TokenStream {
-    if !field_info.is_custom_type {
+    // `PgRange` is an `Expr` itself, so the core blanket impl already applies
+    if !field_info.is_custom_type || crate::common::type_is_pg_range(&field_info.base_type) {
         return TokenStream::new();
     }
 
@@ -284,13 +285,33 @@ pub fn generate_column_definitions(ctx: &MacroContext) -> Result<(TokenStream, V
             generate_arithmetic_ops(
                 &zst_ident,
                 postgres_value,
-                sql_type_marker,
+                sql_type_marker.clone(),
                 sql_nullable_marker,
             )
         } else {
             quote! {}
         };
 
+        // Lets the column be used as a SET value of a matching column, e.g. in
+        // `UPDATE ... FROM` where the joined table supplies the new values.
+        let postgres_update_value = postgres_paths::postgres_update_value();
+        let value_wrapper = postgres_paths::value_wrapper();
+        let update_inner_type =
+            super::models::convenience::update_value_inner_type(field_info, value_type);
+        let into_update_value_impl = quote! {
+            impl<'a> ::std::convert::From<#zst_ident>
+                for #postgres_update_value<'a, PostgresValue<'a>, #update_inner_type>
+            {
+                fn from(column: #zst_ident) -> Self {
+                    #postgres_update_value::Value(
+                        #value_wrapper::<PostgresValue<'a>, #update_inner_type>::new(
+                            ToSQL::to_sql(&column),
+                        ),
+                    )
+                }
+            }
+        };
+
         let column_not_null_impl = if !field_info.is_nullable || field_info.is_primary() {
             quote! {
                 impl #column_not_null for #zst_ident {}
@@ -376,6 +397,7 @@ pub fn generate_column_definitions(ctx: &MacroContext) -> Result<(TokenStream, V
             impl #column_of<#struct_ident> for #zst_ident {}
             impl #column_value_type for #zst_ident {
                 type ValueType = #value_type;
+                type SQLType = #sql_type_marker;
             }
             impl #expr_value_type for #zst_ident {
                 type ValueType = #rust_type;
@@ -402,6 +424,7 @@ pub fn generate_column_definitions(ctx: &MacroContext) -> Result<(TokenStream, V
             impl<'a> drizzle::core::IntoGroupBy<'a, PostgresValue<'a>> for #zst_ident {
                 type Columns = drizzle::core::Cons<#zst_ident, drizzle::core::Nil>;
             }
+            #into_update_value_impl
 
             // Expr trait implementation for type-safe expressions
             #expr_impl
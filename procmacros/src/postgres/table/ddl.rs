@@ -77,6 +77,12 @@ fn table_check_name(ctx: &MacroContext, idx: usize, explicit: &Option<String>) -
     })
 }
 
+fn table_exclude_name(ctx: &MacroContext, columns: &[String], explicit: &Option<String>) -> String {
+    explicit
+        .clone()
+        .unwrap_or_else(|| format!("{}_{}_excl", ctx.table_name, columns.join("_")))
+}
+
 /// Generate a compile-time `const SQL: &'static str` value for `SQLSchema`.
 ///
 /// Output mirrors `TableSql::create_table_sql()`: double-quoted identifiers,
@@ -280,6 +286,33 @@ fn build_create_table_pieces(ctx: &MacroContext) -> Vec<DdlPiece> {
         ))]);
     }
 
+    for exclude in &ctx.attrs.exclude_constraints {
+        let idents: Vec<Ident> = exclude.elements.iter().map(|(i, _)| i.clone()).collect();
+        let exclude_cols = table_unique_columns(ctx, &idents);
+        let exclude_name = table_exclude_name(ctx, &exclude_cols, &exclude.name);
+        let elements = exclude_cols
+            .iter()
+            .zip(&exclude.elements)
+            .map(|(col, (_, op))| format!("{} WITH {op}", quote_ident(col)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let using = exclude.using.as_deref().unwrap_or("gist");
+        let mut sql = format!(
+            "\tCONSTRAINT {} EXCLUDE USING {using} ({elements})",
+            quote_ident(&exclude_name)
+        );
+        if let Some(predicate) = exclude.predicate.as_ref() {
+            let _ = write!(sql, " WHERE ({predicate})");
+        }
+        if exclude.deferrable || exclude.initially_deferred {
+            sql.push_str(" DEFERRABLE");
+            if exclude.initially_deferred {
+                sql.push_str(" INITIALLY DEFERRED");
+            }
+        }
+        lines.push(vec![DdlPiece::Literal(sql)]);
+    }
+
     // Join lines with ",\n" by prepending it to the first piece of each
     // non-first line. If the first piece happens to be a `TableNameOf`
     // (shouldn't happen for our current shape, but handle defensively),
@@ -378,7 +411,7 @@ fn column_to_sql(field: &FieldInfo) -> String {
 }
 
 fn column_to_sql_pieces(field: &FieldInfo) -> Vec<DdlPiece> {
-    if !field.is_custom_type {
+    if !field.is_custom_type && field.domain.is_none() {
         return vec![DdlPiece::Literal(column_to_sql(field))];
     }
 
@@ -430,6 +463,8 @@ pub fn generate_const_ddl(ctx: &MacroContext, _column_zst_idents: &[Ident]) -> T
     let foreign_key_def = ddl_paths::foreign_key_def();
     let unique_constraint_def = ddl_paths::unique_constraint_def();
     let check_constraint_def = ddl_paths::check_constraint_def();
+    let exclude_constraint_def = ddl_paths::exclude_constraint_def();
+    let exclude_element = ddl_paths::exclude_element();
     let index_def = ddl_paths::index_def();
     let identity_def = ddl_paths::identity_def();
     let table_sql = ddl_paths::table_sql();
@@ -470,8 +505,20 @@ pub fn generate_const_ddl(ctx: &MacroContext, _column_zst_idents: &[Ident]) -> T
                 };
                 modifiers.push(quote! { .default_value(#default_str) });
             }
-            if field.is_pgenum || field.is_custom_type {
+            if field.is_pgenum {
                 modifiers.push(quote! { .type_schema(#schema_name) });
+            } else if field.is_custom_type && !field.is_id_newtype {
+                // Custom types backed by built-in types (ranges, integer enums)
+                // have no user-created type to qualify
+                let base_type = &field.base_type;
+                let drizzle_postgres_column = postgres_paths::drizzle_postgres_column();
+                modifiers.push(quote! {
+                    .type_schema_if(<#base_type as #drizzle_postgres_column>::NEEDS_CREATE_TYPE, #schema_name)
+                });
+            }
+            if field.domain.is_some() {
+                // Domains are always created in the public schema
+                modifiers.push(quote! { .type_schema("public") });
             }
             if let Some(ref collate_name) = field.collate {
                 modifiers.push(quote! { .collate(#collate_name) });
@@ -706,6 +753,46 @@ pub fn generate_const_ddl(ctx: &MacroContext, _column_zst_idents: &[Ident]) -> T
         });
     }
 
+    let exclude_defs: Vec<TokenStream> = ctx
+        .attrs
+        .exclude_constraints
+        .iter()
+        .map(|exclude| {
+            let idents: Vec<Ident> = exclude.elements.iter().map(|(i, _)| i.clone()).collect();
+            let exclude_columns = table_unique_columns(ctx, &idents);
+            let exclude_name = table_exclude_name(ctx, &exclude_columns, &exclude.name);
+            let elements: Vec<TokenStream> = exclude_columns
+                .iter()
+                .zip(&exclude.elements)
+                .map(|(col, (_, op))| quote! { #exclude_element::column(#col, #op) })
+                .collect();
+            let mut modifiers = Vec::new();
+            if let Some(using) = exclude.using.as_ref() {
+                modifiers.push(quote! { .using(#using) });
+            }
+            if let Some(predicate) = exclude.predicate.as_ref() {
+                modifiers.push(quote! { .where_clause(#predicate) });
+            }
+            if exclude.name.is_some() {
+                modifiers.push(quote! { .explicit_name() });
+            }
+            if exclude.deferrable {
+                modifiers.push(quote! { .deferrable() });
+            }
+            if exclude.initially_deferred {
+                modifiers.push(quote! { .initially_deferred() });
+            }
+            quote! {
+                {
+                    const EXCL_ELEMENTS: &[#exclude_element] = &[#(#elements),*];
+                    #exclude_constraint_def::new(#schema_name, #table_name, #exclude_name)
+                        .elements(EXCL_ELEMENTS)
+                        #(#modifiers)*
+                }
+            }
+        })
+        .collect();
+
     let mut table_modifiers = Vec::new();
     if ctx.attrs.temporary {
         table_modifiers.push(quote! { .temporary() });
@@ -754,6 +841,11 @@ pub fn generate_const_ddl(ctx: &MacroContext, _column_zst_idents: &[Ident]) -> T
                 #(#check_defs),*
             ];
 
+            /// Exclusion constraint definitions
+            pub const DDL_EXCLUDE_CONSTRAINTS: &'static [#exclude_constraint_def] = &[
+                #(#exclude_defs),*
+            ];
+
             /// Index definitions (defined via separate #[PostgresIndex] structs)
             pub const DDL_INDEXES: &'static [#index_def] = &[];
 
@@ -762,20 +854,15 @@ pub fn generate_const_ddl(ctx: &MacroContext, _column_zst_idents: &[Ident]) -> T
             /// This is the single source of truth for SQL generation, building
             /// the statement from the const DDL entities above.
             pub fn create_table_sql() -> ::std::string::String {
-                let table = Self::DDL_TABLE.into_table();
-                let columns: ::std::vec::Vec<_> = Self::DDL_COLUMNS.iter().map(|c| c.into_column()).collect();
-                let pk = Self::DDL_PRIMARY_KEY.map(|p| p.into_primary_key());
-                let fks: ::std::vec::Vec<_> = Self::DDL_FOREIGN_KEYS.iter().map(|f| f.into_foreign_key()).collect();
-                let uniques: ::std::vec::Vec<_> = Self::DDL_UNIQUE_CONSTRAINTS.iter().map(|u| u.into_unique_constraint()).collect();
-                let checks: ::std::vec::Vec<_> = Self::DDL_CHECK_CONSTRAINTS.iter().map(|c| c.into_check_constraint()).collect();
-
-                #table_sql::new(&table)
-                    .columns(&columns)
-                    .primary_key(pk.as_ref())
-                    .foreign_keys(&fks)
-                    .unique_constraints(&uniques)
-                    .check_constraints(&checks)
-                    .create_table_sql()
+                #table_sql::create_table_sql_from_defs(
+                    &Self::DDL_TABLE,
+                    Self::DDL_COLUMNS,
+                    Self::DDL_PRIMARY_KEY.as_ref(),
+                    Self::DDL_FOREIGN_KEYS,
+                    Self::DDL_UNIQUE_CONSTRAINTS,
+                    Self::DDL_CHECK_CONSTRAINTS,
+                    Self::DDL_EXCLUDE_CONSTRAINTS,
+                )
             }
 
             /// Returns the DDL SQL for creating this table.
@@ -818,6 +905,7 @@ mod tests {
             is_jsonb: false,
             is_serial: false,
             is_custom_type: false,
+            is_id_newtype: false,
             is_generated_identity: false,
             identity_mode: None,
             generated_column: None,
@@ -830,6 +918,7 @@ mod tests {
             marker_exprs: Vec::new(),
             constraint: crate::common::Constraint::None,
             collate: None,
+            domain: None,
             comment: None,
         }
     }
@@ -859,6 +948,7 @@ mod tests {
             is_jsonb: false,
             is_serial: false,
             is_custom_type: false,
+            is_id_newtype: false,
             is_generated_identity: false,
             identity_mode: None,
             generated_column: None,
@@ -878,6 +968,7 @@ mod tests {
             marker_exprs: Vec::new(),
             constraint: crate::common::Constraint::None,
             collate: None,
+            domain: None,
             comment: None,
         };
 
@@ -893,6 +984,10 @@ mod tests {
             composite_foreign_keys: Vec::new(),
             unique_constraints: Vec::new(),
             check_constraints: Vec::new(),
+            exclude_constraints: Vec::new(),
+            id_type: None,
+            comment: None,
+            casing: None,
             marker_exprs: Vec::new(),
         };
 
@@ -993,6 +1088,10 @@ mod tests {
             composite_foreign_keys: Vec::new(),
             unique_constraints: Vec::new(),
             check_constraints: Vec::new(),
+            exclude_constraints: Vec::new(),
+            id_type: None,
+            comment: None,
+            casing: None,
             marker_exprs: Vec::new(),
         };
 
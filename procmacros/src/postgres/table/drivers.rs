@@ -50,17 +50,14 @@ fn generate_select_field_conversion(idx: &TokenStream, info: &FieldInfo) -> Toke
     let needs_from_postgres_value = matches!(
         type_category,
         TypeCategory::ArrayString | TypeCategory::ArrayVec
-    );
+    ) || is_value_decoded_column(&info.column_type);
 
     // Custom types (auto-detected enums): use DrizzlePostgresColumn trait for deserialization
     if info.is_custom_type {
         if info.is_nullable {
             return quote! {
                 #name: {
-                    let is_null: bool = row.try_get::<_, Option<i32>>(#idx)
-                        .map(|v| v.is_none())
-                        .unwrap_or(true);
-                    if is_null {
+                    if drizzle::postgres::traits::column_is_null(row, #idx) {
                         None
                     } else {
                         Some(<#base_type as drizzle::postgres::traits::DrizzlePostgresColumn>::decode(row, #idx)?)
@@ -189,7 +186,7 @@ fn generate_partial_field_conversion(idx: usize, info: &FieldInfo) -> TokenStrea
     let needs_from_postgres_value = matches!(
         type_category,
         TypeCategory::ArrayString | TypeCategory::ArrayVec
-    );
+    ) || is_value_decoded_column(&info.column_type);
 
     // Custom types (auto-detected enums): use DrizzlePostgresColumn trait
     if info.is_custom_type {
@@ -246,6 +243,26 @@ fn generate_partial_field_conversion(idx: usize, info: &FieldInfo) -> TokenStrea
     }
 }
 
+/// Columns whose Rust type has no driver `FromSql` impl for the column's SQL
+/// type decode through `FromPostgresValue`: `MACADDR`/`MACADDR8` map to
+/// `[u8; N]`, PostGIS `GEOMETRY` arrives as WKB, and pgvector `VECTOR` is
+/// an extension type that `Vec<f32>`'s `FromSql` rejects.
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+const fn is_value_decoded_column(column_type: &PostgreSQLType) -> bool {
+    #[cfg(feature = "cidr")]
+    if matches!(
+        column_type,
+        PostgreSQLType::MacAddr | PostgreSQLType::MacAddr8
+    ) {
+        return true;
+    }
+    #[cfg(feature = "geo-types")]
+    if matches!(column_type, PostgreSQLType::Geometry(_)) {
+        return true;
+    }
+    matches!(column_type, PostgreSQLType::Vector(_))
+}
+
 /// Determine a "probe type" for NULL checking on the first column.
 ///
 /// Used by `Option<SelectModel>` to detect LEFT JOIN misses:
@@ -370,6 +387,15 @@ pub fn generate_all_driver_impls(ctx: &MacroContext) -> TokenStream {
     let null_probe_impl = field_infos.first().map_or_else(
         || quote! {},
         |first_field| {
+            if is_value_decoded_column(&first_field.column_type) {
+                return quote! {
+                    impl drizzle::core::NullProbeRow<drizzle::postgres::Row> for #select_model_ident {
+                        fn is_null_at(row: &drizzle::postgres::Row, offset: usize) -> ::std::result::Result<bool, #drizzle_error> {
+                            Ok(drizzle::postgres::traits::column_is_null(row, offset))
+                        }
+                    }
+                };
+            }
             let probe_ty = null_probe_type(first_field);
             quote! {
                 impl drizzle::core::NullProbeRow<drizzle::postgres::Row> for #select_model_ident {
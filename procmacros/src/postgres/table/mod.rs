@@ -12,7 +12,8 @@ pub mod traits;
 
 use super::field::{FieldInfo, generate_table_meta_json};
 use crate::common::{
-    count_primary_keys, required_fields_pattern, struct_fields, table_name_from_attrs,
+    count_primary_keys, doc_comment_from_attrs, required_fields_pattern, struct_fields,
+    table_name_from_attrs,
 };
 use alias::generate_aliased_table;
 pub use attributes::TableAttributes;
@@ -21,11 +22,12 @@ use column_definitions::{
 };
 use context::MacroContext;
 use ddl::generate_const_ddl;
+use drizzle_types::Casing;
 use models::generate_model_definitions;
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::{ToTokens, format_ident, quote};
 use syn::Ident;
-use syn::{DeriveInput, Expr, Lit, Result};
+use syn::{DeriveInput, Result};
 use traits::generate_table_impls;
 
 // ============================================================================
@@ -38,21 +40,30 @@ pub fn table_attr_macro(input: &DeriveInput, attrs: &TableAttributes) -> Result<
     // -------------------
     let struct_ident = &input.ident;
     let struct_vis = &input.vis;
-    let table_name = table_name_from_attrs(struct_ident, attrs.name.clone());
+    let casing = attrs.casing.unwrap_or(Casing::SnakeCase);
+    let table_name = table_name_from_attrs(struct_ident, attrs.name.clone(), casing);
 
     let fields = struct_fields(input, "PostgresTable")?;
-    let table_comment = doc_comment_from_attrs(&input.attrs);
+    let table_comment = attrs
+        .comment
+        .clone()
+        .or_else(|| doc_comment_from_attrs(&input.attrs));
 
     let primary_key_count = count_primary_keys(fields, |field| {
-        Ok(FieldInfo::from_field(field, false)?.is_primary())
+        Ok(FieldInfo::from_field(field, false, casing)?.is_primary())
     })?;
     let is_composite_pk = primary_key_count > 1;
 
-    let field_infos = fields
+    let mut field_infos = fields
         .iter()
-        .map(|field| FieldInfo::from_field(field, is_composite_pk))
+        .map(|field| FieldInfo::from_field(field, is_composite_pk, casing))
         .collect::<Result<Vec<_>>>()?;
 
+    let id_newtype = match &attrs.id_type {
+        Some(id_ident) => apply_id_type(&mut field_infos, id_ident, struct_ident, struct_vis)?,
+        None => TokenStream::new(),
+    };
+
     // Generate table metadata JSON for drizzle-kit compatible migrations
     let table_meta_json = generate_table_meta_json(
         &table_name,
@@ -124,6 +135,8 @@ pub fn table_attr_macro(input: &DeriveInput, attrs: &TableAttributes) -> Result<
         // Table marker const for IDE hover documentation
         #table_marker_const
 
+        #id_newtype
+
         #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
          #struct_vis struct #struct_ident {
          #column_fields
@@ -164,31 +177,6 @@ pub fn table_attr_macro(input: &DeriveInput, attrs: &TableAttributes) -> Result<
     Ok(expanded)
 }
 
-fn doc_comment_from_attrs(attrs: &[syn::Attribute]) -> Option<String> {
-    let lines = attrs.iter().filter_map(|attr| {
-        if !attr.path().is_ident("doc") {
-            return None;
-        }
-        let syn::Meta::NameValue(meta) = &attr.meta else {
-            return None;
-        };
-        let Expr::Lit(expr_lit) = &meta.value else {
-            return None;
-        };
-        let Lit::Str(lit) = &expr_lit.lit else {
-            return None;
-        };
-        let value = lit.value();
-        Some(value.strip_prefix(' ').unwrap_or(&value).to_string())
-    });
-    let comment = lines.collect::<Vec<_>>().join("\n");
-    if comment.is_empty() {
-        None
-    } else {
-        Some(comment)
-    }
-}
-
 /// Generate query API impls (`RelationDef`, accessors, JSON decoders) for `PostgreSQL`.
 ///
 /// Shared by both `#[PostgresTable]` and `#[PostgresView]`.
@@ -289,6 +277,83 @@ pub fn generate_query_api_impls(ctx: &MacroContext) -> TokenStream {
     )
 }
 
+/// Apply `ID_TYPE = ...`: retype the single primary-key column as the
+/// generated newtype and emit the newtype with its `CustomPostgresType` impl.
+///
+/// The column keeps its SQL type (including `SERIAL`); only the Rust type
+/// seen by models, comparisons and `references = ...` checks changes.
+fn apply_id_type(
+    field_infos: &mut [FieldInfo],
+    id_ident: &Ident,
+    struct_ident: &Ident,
+    struct_vis: &syn::Visibility,
+) -> Result<TokenStream> {
+    use crate::postgres::field::TypeCategory;
+
+    let mut primary_keys = field_infos.iter_mut().filter(|info| info.is_primary());
+    let (Some(pk), None) = (primary_keys.next(), primary_keys.next()) else {
+        return Err(syn::Error::new_spanned(
+            id_ident,
+            "ID_TYPE requires exactly one primary key column.\n\
+             Hint: mark a single column with #[column(primary)]",
+        ));
+    };
+
+    let is_copy = match pk.type_category() {
+        TypeCategory::I16 | TypeCategory::I32 | TypeCategory::I64 => true,
+        TypeCategory::String => false,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &pk.field_type,
+                format!(
+                    "ID_TYPE is only supported for integer or String primary keys, \
+                     but `{}` is `{}`",
+                    pk.ident,
+                    pk.base_type.to_token_stream()
+                ),
+            ));
+        }
+    };
+    if pk.is_nullable {
+        return Err(syn::Error::new_spanned(
+            &pk.field_type,
+            "ID_TYPE cannot wrap a nullable primary key",
+        ));
+    }
+
+    let id_type: syn::Type = syn::parse_quote!(#id_ident);
+    let inner = std::mem::replace(&mut pk.base_type, id_type.clone());
+    pk.field_type = id_type;
+    pk.is_custom_type = true;
+    pk.is_id_newtype = true;
+
+    let sql_type = pk.sql_type_with_dimensions();
+    let sql_type_marker = crate::common::postgres_column_type_to_sql_type(&pk.column_type);
+
+    let newtype =
+        crate::common::generate_id_newtype(id_ident, struct_vis, &inner, struct_ident, is_copy);
+    let custom_postgres_type = crate::paths::postgres::custom_postgres_type();
+    let drizzle_error = crate::paths::core::drizzle_error();
+
+    Ok(quote! {
+        #newtype
+
+        impl #custom_postgres_type for #id_ident {
+            type SQLType = #sql_type_marker;
+            const SQL_TYPE: &'static str = #sql_type;
+            type Repr = #inner;
+
+            fn to_repr(&self) -> #inner {
+                ::core::clone::Clone::clone(&self.0)
+            }
+
+            fn from_repr(repr: #inner) -> ::core::result::Result<Self, #drizzle_error> {
+                ::core::result::Result::Ok(Self(repr))
+            }
+        }
+    })
+}
+
 /// Generate a const that references the original table marker tokens from the attribute.
 ///
 /// This creates hidden const bindings that use the exact tokens from `#[PostgresTable(UNLOGGED)]`,
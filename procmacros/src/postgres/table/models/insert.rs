@@ -31,6 +31,7 @@ pub fn generate_insert_model(ctx: &MacroContext, required_fields_pattern: &[bool
     let mut insert_default_fields = Vec::new();
     let mut insert_field_names = Vec::new();
     let mut insert_field_indices = Vec::new();
+    let mut insert_value_pushes = Vec::new();
     let mut insert_convenience_methods = Vec::new();
     let mut required_constructor_params = Vec::new();
     let mut required_constructor_assignments = Vec::new();
@@ -44,6 +45,11 @@ pub fn generate_insert_model(ctx: &MacroContext, required_fields_pattern: &[bool
         insert_default_fields.push(get_insert_default_value(info));
         insert_field_names.push(name);
         insert_field_indices.push(quote! { #field_index });
+        insert_value_pushes.push(if let Some(retag) = info.value_retag() {
+            quote! { self.#name.push_value_as(&mut sql_parts, #retag); }
+        } else {
+            quote! { self.#name.push_value(&mut sql_parts); }
+        });
         if should_generate_insert_setter(info) {
             insert_convenience_methods.push(generate_convenience_method(
                 info,
@@ -118,12 +124,7 @@ pub fn generate_insert_model(ctx: &MacroContext, required_fields_pattern: &[bool
                 let mut result_columns = Vec::new();
 
                 #(
-                    match &self.#insert_field_names {
-                        PostgresInsertValue::Omit => {}
-                        _ => {
-                            result_columns.push(all_columns[#insert_field_indices]);
-                        }
-                    }
+                    self.#insert_field_names.push_column(&mut result_columns, all_columns[#insert_field_indices]);
                 )*
 
                 ::std::borrow::Cow::Owned(result_columns)
@@ -132,17 +133,7 @@ pub fn generate_insert_model(ctx: &MacroContext, required_fields_pattern: &[bool
             fn values(&self) -> SQL<'a, PostgresValue<'a>> {
                 let mut sql_parts = Vec::new();
 
-                #(
-                    match &self.#insert_field_names {
-                        PostgresInsertValue::Omit => {}
-                        PostgresInsertValue::Null => {
-                            sql_parts.push(SQL::param(PostgresValue::Null));
-                        }
-                        PostgresInsertValue::Value(wrapper) => {
-                            sql_parts.push(wrapper.value.clone());
-                        }
-                    }
-                )*
+                #(#insert_value_pushes)*
 
                 SQL::join(sql_parts, Token::COMMA)
             }
@@ -229,6 +220,7 @@ mod tests {
             is_jsonb: false,
             is_serial: false,
             is_custom_type: false,
+            is_id_newtype: false,
             is_generated_identity: false,
             identity_mode: None,
             generated_column: None,
@@ -241,6 +233,7 @@ mod tests {
             marker_exprs: Vec::new(),
             constraint: crate::common::Constraint::None,
             collate: None,
+            domain: None,
             comment: None,
         }
     }
@@ -327,6 +320,10 @@ mod tests {
             composite_foreign_keys: Vec::new(),
             unique_constraints: Vec::new(),
             check_constraints: Vec::new(),
+            exclude_constraints: Vec::new(),
+            id_type: None,
+            comment: None,
+            casing: None,
             marker_exprs: Vec::new(),
         };
 
@@ -32,12 +32,20 @@ pub fn generate_select_model(ctx: &MacroContext) -> TokenStream {
         select_types.push(select_type);
         tuple_indices.push(syn::Index::from(i));
     }
-    let select_model_derive = if ctx.field_infos.iter().any(|info| info.is_custom_type) {
+    let select_model_derive = if ctx
+        .field_infos
+        .iter()
+        .any(|info| info.is_custom_type && !info.is_id_newtype)
+    {
         quote! {}
     } else {
         quote! { #[derive(Debug, Clone, Default)] }
     };
-    let partial_select_model_derive = if ctx.field_infos.iter().any(|info| info.is_custom_type) {
+    let partial_select_model_derive = if ctx
+        .field_infos
+        .iter()
+        .any(|info| info.is_custom_type && !info.is_id_newtype)
+    {
         quote! { #[derive(Default)] }
     } else {
         quote! { #[derive(Debug, Clone, Default)] }
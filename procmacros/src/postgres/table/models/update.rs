@@ -75,6 +75,12 @@ pub fn generate_update_model(ctx: &MacroContext) -> TokenStream {
 fn get_update_field_conversion(field_info: &FieldInfo) -> TokenStream {
     let name = &field_info.ident;
     let column_name = &field_info.column_name;
+    // Extension-typed columns re-tag their bound values (see `value_retag`)
+    let value = if let Some(retag) = field_info.value_retag() {
+        quote! { wrapper.value.clone().map_params(#retag) }
+    } else {
+        quote! { wrapper.value.clone() }
+    };
 
     quote! {
         match &self.#name {
@@ -83,7 +89,7 @@ fn get_update_field_conversion(field_info: &FieldInfo) -> TokenStream {
                 assignments.push((#column_name, SQL::param(PostgresValue::Null)));
             },
             PostgresUpdateValue::Value(wrapper) => {
-                assignments.push((#column_name, wrapper.value.clone()));
+                assignments.push((#column_name, #value));
             },
         }
     }
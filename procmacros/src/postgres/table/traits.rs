@@ -1,5 +1,7 @@
 use super::context::MacroContext;
-use crate::common::ref_gen::{self, ColumnRefInput, ConstraintRefInput, ForeignKeyRefInput};
+use crate::common::ref_gen::{
+    self, ColumnRefInput, ConstraintRefInput, ExclusionRefInput, ForeignKeyRefInput,
+};
 use crate::generators::{DrizzleTableConfig, generate_drizzle_table};
 use crate::paths::core as core_paths;
 use crate::paths::postgres as postgres_paths;
@@ -331,6 +333,7 @@ pub(super) fn generate_table_impls(
                 kind: quote! { drizzle::core::SQLConstraintKind::Check },
                 columns: vec![field.column_name.clone()],
                 check_expression: Some(expr.clone()),
+                exclusion: None,
                 deferrable: false,
                 initially_deferred: false,
             })
@@ -345,6 +348,7 @@ pub(super) fn generate_table_impls(
             kind: quote! { drizzle::core::SQLConstraintKind::Unique },
             columns,
             check_expression: None,
+            exclusion: None,
             deferrable: unique.deferrable,
             initially_deferred: unique.initially_deferred,
         });
@@ -356,10 +360,30 @@ pub(super) fn generate_table_impls(
             kind: quote! { drizzle::core::SQLConstraintKind::Check },
             columns: Vec::new(),
             check_expression: Some(check.expr.clone()),
+            exclusion: None,
             deferrable: false,
             initially_deferred: false,
         });
     }
+    for exclude in &ctx.attrs.exclude_constraints {
+        let idents: Vec<Ident> = exclude.elements.iter().map(|(i, _)| i.clone()).collect();
+        let columns = table_unique_column_names(ctx, &idents);
+        let name = table_exclude_name(ctx, &columns, &exclude.name);
+        table_ref_constraints.push(ConstraintRefInput {
+            name: Some(name),
+            name_explicit: exclude.name.is_some(),
+            kind: quote! { drizzle::core::SQLConstraintKind::Exclude },
+            columns,
+            check_expression: None,
+            exclusion: Some(ExclusionRefInput {
+                using: exclude.using.clone().unwrap_or_else(|| "gist".to_string()),
+                operators: exclude.elements.iter().map(|(_, op)| op.clone()).collect(),
+                predicate: exclude.predicate.clone(),
+            }),
+            deferrable: exclude.deferrable,
+            initially_deferred: exclude.initially_deferred,
+        });
+    }
     let is_unlogged = ctx.attrs.unlogged;
     let is_temporary = ctx.attrs.temporary;
     let inherits = ctx.attrs.inherits.as_ref().map_or_else(
@@ -526,6 +550,12 @@ fn table_unique_name(ctx: &MacroContext, columns: &[String], explicit: &Option<S
         .unwrap_or_else(|| format!("{}_{}_key", ctx.table_name, columns.join("_")))
 }
 
+fn table_exclude_name(ctx: &MacroContext, columns: &[String], explicit: &Option<String>) -> String {
+    explicit
+        .clone()
+        .unwrap_or_else(|| format!("{}_{}_excl", ctx.table_name, columns.join("_")))
+}
+
 fn table_check_name(ctx: &MacroContext, idx: usize, explicit: &Option<String>) -> String {
     explicit.clone().unwrap_or_else(|| {
         if ctx.attrs.check_constraints.len() == 1 {
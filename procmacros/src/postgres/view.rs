@@ -16,6 +16,7 @@ use crate::postgres::generators::{
 use crate::postgres::table::{
     alias, attributes::TableAttributes, column_definitions, context::MacroContext, drivers, models,
 };
+use drizzle_types::Casing;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use std::fmt::Write;
@@ -218,16 +219,16 @@ pub fn view_attr_macro(input: &DeriveInput, attrs: &ViewAttributes) -> Result<To
     let fields = struct_fields(input, "PostgresView")?;
 
     let primary_key_count = count_primary_keys(fields, |field| {
-        Ok(FieldInfo::from_field(field, false)?.is_primary())
+        Ok(FieldInfo::from_field(field, false, Casing::SnakeCase)?.is_primary())
     })?;
     let is_composite_pk = primary_key_count > 1;
 
     let field_infos = fields
         .iter()
-        .map(|field| FieldInfo::from_field(field, is_composite_pk))
+        .map(|field| FieldInfo::from_field(field, is_composite_pk, Casing::SnakeCase))
         .collect::<Result<Vec<_>>>()?;
 
-    let view_name = table_name_from_attrs(struct_ident, attrs.name.clone());
+    let view_name = table_name_from_attrs(struct_ident, attrs.name.clone(), Casing::SnakeCase);
     let view_schema = attrs.schema.clone().unwrap_or_else(|| "public".to_string());
 
     if attrs.definition.is_none() && !attrs.existing {
@@ -249,6 +250,10 @@ pub fn view_attr_macro(input: &DeriveInput, attrs: &ViewAttributes) -> Result<To
         composite_foreign_keys: Vec::new(),
         unique_constraints: Vec::new(),
         check_constraints: Vec::new(),
+        exclude_constraints: Vec::new(),
+        id_type: None,
+        comment: None,
+        casing: None,
         marker_exprs: Vec::new(),
     };
 
@@ -355,6 +360,18 @@ pub fn view_attr_macro(input: &DeriveInput, attrs: &ViewAttributes) -> Result<To
     );
 
     let ddl_view_def = ddl_paths::postgres::view_def();
+    // Materialized views can carry indexes, so they expose the same
+    // `DDL_TABLE` that `#[PostgresIndex]` reads from tables. Plain views
+    // don't, which turns an index on one into a compile error.
+    let ddl_table_const = if attrs.materialized {
+        let ddl_table_def = ddl_paths::postgres::table_def();
+        quote! {
+            /// Relation definition used by indexes on this materialized view.
+            pub const DDL_TABLE: #ddl_table_def = #ddl_table_def::new(#view_schema_lit, #view_name_lit);
+        }
+    } else {
+        quote!()
+    };
     let mut ddl_view_expr = quote! { #ddl_view_def::new(#view_schema_lit, #view_name_lit) };
     if has_definition_literal {
         ddl_view_expr = quote! { #ddl_view_expr.definition(#definition_lit) };
@@ -662,6 +679,7 @@ pub fn view_attr_macro(input: &DeriveInput, attrs: &ViewAttributes) -> Result<To
             pub const VIEW_SCHEMA: &'static str = #view_schema_lit;
             pub const VIEW_DEFINITION_SQL: &'static str = #view_definition_sql_const;
             pub const DDL_VIEW: #ddl_view_def = #ddl_view_expr;
+            #ddl_table_const
 
             pub fn create_view_sql() -> ::std::string::String {
                 let mut view = <drizzle::ddl::postgres::ddl::View as ::std::convert::From<
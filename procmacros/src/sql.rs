@@ -1,47 +1,66 @@
 use crate::paths::core as core_paths;
-use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned};
 use syn::{
-    Expr, LitStr, Result,
+    Expr, LitStr, Result, Type,
     parse::{Parse, ParseStream},
+    spanned::Spanned,
 };
 
+/// How `{expr}` without a prefix is expanded
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum InterpolationMode {
+    /// `sql!`: bind values, warn on anything that would splice
+    Safe,
+    /// `sql_unsafe_raw!`: splice via `ToSQL` as before
+    Legacy,
+}
+
 /// Input for the sql! procedural macro
-pub enum SqlInput {
-    /// String literal input: sql!("SELECT * FROM {table}")
-    StringLiteral(LitStr),
-    /// Printf-style input: sql!("SELECT * FROM {} WHERE {} = {}", table, column, value)
-    Printf { template: LitStr, args: Vec<Expr> },
+pub struct SqlInput {
+    /// Template string: sql!("SELECT * FROM {table}")
+    template: LitStr,
+    /// Printf-style arguments: sql!("SELECT * FROM {} WHERE {} = {}", table, column, value)
+    args: Option<Vec<Expr>>,
+    /// Output row annotation: sql!("SELECT count(*), name FROM users", as (i64, String))
+    output: Option<Type>,
 }
 
 impl Parse for SqlInput {
     fn parse(input: ParseStream) -> Result<Self> {
         // Only parse string literals
         let template = input.parse::<LitStr>()?;
+        let mut args = None;
+        let mut output = None;
 
-        // Check if there are comma-separated arguments after the template
-        if input.peek(syn::Token![,]) {
+        // Comma-separated arguments, optionally ending with `as Type`
+        while input.peek(syn::Token![,]) {
             input.parse::<syn::Token![,]>()?; // consume comma
+            if input.is_empty() {
+                break;
+            }
 
-            let mut args = Vec::new();
-
-            // Parse first argument
-            if !input.is_empty() {
-                args.push(input.parse::<Expr>()?);
-
-                // Parse remaining arguments
-                while input.peek(syn::Token![,]) {
-                    input.parse::<syn::Token![,]>()?; // consume comma
-                    if !input.is_empty() {
-                        args.push(input.parse::<Expr>()?);
-                    }
+            if input.peek(syn::Token![as]) {
+                input.parse::<syn::Token![as]>()?;
+                output = Some(input.parse::<Type>()?);
+                if input.peek(syn::Token![,]) {
+                    input.parse::<syn::Token![,]>()?;
                 }
+                if !input.is_empty() {
+                    return Err(input.error("`as <Type>` must be the last argument to sql!()"));
+                }
+                break;
             }
 
-            Ok(Self::Printf { template, args })
-        } else {
-            Ok(Self::StringLiteral(template))
+            args.get_or_insert_with(Vec::new)
+                .push(input.parse::<Expr>()?);
         }
+
+        Ok(Self {
+            template,
+            args,
+            output,
+        })
     }
 }
 
@@ -50,8 +69,14 @@ impl Parse for SqlInput {
 enum SqlSegment {
     /// Raw SQL text that becomes `SQL::text()`
     Text(String),
-    /// An expression inside {braces} that should have .`to_sql()` called on it
+    /// An expression inside {braces}, bound as a value (or spliced in legacy mode)
     Expression(Expr),
+    /// An expression inside {braces:param} that is always bound as a parameter
+    Param(Expr),
+    /// An expression inside {#braces} that has .`to_sql()` called on it
+    Splice(Expr),
+    /// An expression inside {!braces} that is emitted as raw SQL text
+    Raw(Expr),
 }
 
 impl std::fmt::Debug for SqlSegment {
@@ -59,15 +84,20 @@ impl std::fmt::Debug for SqlSegment {
         match self {
             Self::Text(text) => f.debug_tuple("Text").field(text).finish(),
             Self::Expression(_) => f.debug_tuple("Expression").field(&"<expr>").finish(),
+            Self::Param(_) => f.debug_tuple("Param").field(&"<expr>").finish(),
+            Self::Splice(_) => f.debug_tuple("Splice").field(&"<expr>").finish(),
+            Self::Raw(_) => f.debug_tuple("Raw").field(&"<expr>").finish(),
         }
     }
 }
 
 /// Parse the template string into text and expression segments
-/// If `positional_args` is provided, empty braces {} will be replaced with the arguments
+/// If `positional_args` is provided, empty braces {} will be replaced with the arguments.
+/// Named expressions are given `span` so they resolve and lint in the caller's code.
 fn parse_template_with_args(
     template: &str,
     positional_args: Option<&[Expr]>,
+    span: Span,
 ) -> Result<Vec<SqlSegment>> {
     let mut segments = Vec::new();
     let mut chars = template.chars().peekable();
@@ -118,6 +148,34 @@ fn parse_template_with_args(
                     ));
                 }
 
+                // `{expr:param}` / `{:param}` force a bound parameter,
+                // `{#expr}` splices SQL and `{!expr}` splices raw text
+                let (expr_content, as_param) = split_param_spec(&expr_content);
+                let (expr_content, segment): (&str, fn(Expr) -> SqlSegment) = match expr_content
+                    .trim_start()
+                    .chars()
+                    .next()
+                {
+                    Some(prefix @ ('#' | '!')) => {
+                        if as_param {
+                            return Err(syn::Error::new(
+                                Span::call_site(),
+                                format!(
+                                    "`{{{prefix}...:param}}` in sql!() template; `:param` cannot be combined with `#` or `!`"
+                                ),
+                            ));
+                        }
+                        let rest = expr_content.trim_start()[1..].trim_start();
+                        if prefix == '#' {
+                            (rest, SqlSegment::Splice)
+                        } else {
+                            (rest, SqlSegment::Raw)
+                        }
+                    }
+                    _ if as_param => (expr_content, SqlSegment::Param),
+                    _ => (expr_content, SqlSegment::Expression),
+                };
+
                 // Handle empty braces for positional arguments
                 if expr_content.is_empty() {
                     if let Some(args) = positional_args {
@@ -131,7 +189,7 @@ fn parse_template_with_args(
                                 ),
                             ));
                         }
-                        segments.push(SqlSegment::Expression(args[arg_index].clone()));
+                        segments.push(segment(args[arg_index].clone()));
                         arg_index += 1;
                     } else {
                         return Err(syn::Error::new(
@@ -141,14 +199,19 @@ fn parse_template_with_args(
                     }
                 } else {
                     // Named expression - parse it
-                    let expr: Expr = syn::parse_str(&expr_content).map_err(|e| {
+                    let invalid = |e| {
                         syn::Error::new(
                             Span::call_site(),
                             format!("invalid expression in sql!() template: {e}"),
                         )
-                    })?;
-
-                    segments.push(SqlSegment::Expression(expr));
+                    };
+                    let tokens: TokenStream =
+                        expr_content.parse().map_err(|e: proc_macro2::LexError| {
+                            invalid(syn::Error::new(Span::call_site(), e))
+                        })?;
+                    let expr: Expr = syn::parse2(respan(tokens, span)).map_err(invalid)?;
+
+                    segments.push(segment(expr));
                 }
             }
             '}' => {
@@ -190,31 +253,72 @@ fn parse_template_with_args(
     Ok(segments)
 }
 
+/// Splits a trailing `:param` spec off a brace expression.
+///
+/// Only a single `:` counts, so paths like `{Type::param}` are left alone.
+fn split_param_spec(content: &str) -> (&str, bool) {
+    match content.trim_end().strip_suffix(":param") {
+        Some(expr) if !expr.ends_with(':') => (expr.trim_end(), true),
+        _ => (content, false),
+    }
+}
+
+/// Re-spans every token so template expressions point at the template literal.
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut tree| {
+            if let TokenTree::Group(group) = &tree {
+                let mut inner =
+                    proc_macro2::Group::new(group.delimiter(), respan(group.stream(), span));
+                inner.set_span(span);
+                tree = TokenTree::Group(inner);
+            } else {
+                tree.set_span(span);
+            }
+            tree
+        })
+        .collect()
+}
+
 /// Parse the template string into text and expression segments (convenience wrapper)
+#[cfg(test)]
 fn parse_template(template: &str) -> Result<Vec<SqlSegment>> {
-    parse_template_with_args(template, None)
+    parse_template_with_args(template, None, Span::call_site())
 }
 
 /// Generate the `TokenStream` for the sql! macro implementation
-pub fn sql_impl(input: SqlInput) -> Result<TokenStream> {
+pub fn sql_impl(input: SqlInput, mode: InterpolationMode) -> Result<TokenStream> {
+    let SqlInput {
+        template,
+        args,
+        output,
+    } = input;
+    let template_str = template.value();
+    let segments = parse_template_with_args(&template_str, args.as_deref(), template.span())?;
+    let sql = sql_segments(segments, mode);
+
+    Ok(match output {
+        Some(row) => {
+            let typed_sql = core_paths::typed_sql();
+            quote! {
+                #typed_sql::<_, #row>::new(#sql)
+            }
+        }
+        None => sql,
+    })
+}
+
+/// Generate the `SQL` expression for the parsed template segments
+fn sql_segments(segments: Vec<SqlSegment>, mode: InterpolationMode) -> TokenStream {
     let sql = core_paths::sql();
     let to_sql = core_paths::to_sql();
-
-    let segments = match input {
-        SqlInput::StringLiteral(template) => {
-            let template_str = template.value();
-            parse_template(&template_str)?
-        }
-        SqlInput::Printf { template, args } => {
-            let template_str = template.value();
-            parse_template_with_args(&template_str, Some(&args))?
-        }
-    };
+    let interpolate = core_paths::sql_interpolate();
 
     if segments.is_empty() {
-        return Ok(quote! {
+        return quote! {
             #sql::empty()
-        });
+        };
     }
 
     // Generate code for each segment
@@ -229,17 +333,40 @@ pub fn sql_impl(input: SqlInput) -> Result<TokenStream> {
                     });
                 }
             }
-            SqlSegment::Expression(expr) => {
+            SqlSegment::Expression(expr) if mode == InterpolationMode::Safe => {
+                // Spanned at the expression so the splice deprecation lands in user code
+                segment_tokens.push(quote_spanned! {expr.span()=>
+                    {
+                        #[allow(unused_imports)]
+                        use #interpolate::{
+                            InterpolatePostgresValue as _, InterpolateSQLiteValue as _,
+                            InterpolateSplice as _,
+                        };
+                        (&&&#interpolate::Interpolate(&#expr)).interpolate_sql()
+                    }
+                });
+            }
+            SqlSegment::Expression(expr) | SqlSegment::Splice(expr) => {
                 segment_tokens.push(quote! {
                     #to_sql::to_sql(&#expr)
                 });
             }
+            SqlSegment::Param(expr) => {
+                segment_tokens.push(quote! {
+                    #sql::param_from(#expr)
+                });
+            }
+            SqlSegment::Raw(expr) => {
+                segment_tokens.push(quote! {
+                    #sql::raw(#expr)
+                });
+            }
         }
     }
 
     // If we only have one segment, return it directly
     if segment_tokens.len() == 1 {
-        return Ok(segment_tokens.into_iter().next().expect("checked len == 1"));
+        return segment_tokens.into_iter().next().expect("checked len == 1");
     }
 
     // Chain multiple segments together with .append()
@@ -252,7 +379,7 @@ pub fn sql_impl(input: SqlInput) -> Result<TokenStream> {
         };
     }
 
-    Ok(result)
+    result
 }
 
 #[cfg(test)]
@@ -377,6 +504,83 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_param_spec() {
+        use syn::parse_str;
+
+        let args = vec![parse_str::<Expr>("name").unwrap()];
+        let segments = parse_template_with_args(
+            "WHERE {id:param} = {:param} OR {Kind::param}",
+            Some(&args),
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(segments.len(), 6);
+
+        match &segments[1] {
+            SqlSegment::Param(Expr::Path(path)) => {
+                assert_eq!(path.path.segments[0].ident.to_string(), "id");
+            }
+            _ => panic!("Expected param segment"),
+        }
+
+        match &segments[3] {
+            SqlSegment::Param(Expr::Path(path)) => {
+                assert_eq!(path.path.segments[0].ident.to_string(), "name");
+            }
+            _ => panic!("Expected positional param segment"),
+        }
+
+        // `::param` is a path, not a param spec
+        match &segments[5] {
+            SqlSegment::Expression(Expr::Path(path)) => {
+                assert_eq!(path.path.segments.len(), 2);
+            }
+            _ => panic!("Expected expression segment"),
+        }
+    }
+
+    #[test]
+    fn test_parse_splice_and_raw_prefixes() {
+        use syn::parse_str;
+
+        let args = vec![parse_str::<Expr>("users").unwrap()];
+        let segments = parse_template_with_args(
+            "SELECT * FROM {#} WHERE {#users.id} = {id} ORDER BY 1 {!order}",
+            Some(&args),
+            Span::call_site(),
+        )
+        .unwrap();
+        assert_eq!(segments.len(), 8);
+
+        match &segments[1] {
+            SqlSegment::Splice(Expr::Path(path)) => {
+                assert_eq!(path.path.segments[0].ident.to_string(), "users");
+            }
+            _ => panic!("Expected positional splice segment"),
+        }
+        assert!(matches!(&segments[3], SqlSegment::Splice(Expr::Field(_))));
+        assert!(matches!(&segments[5], SqlSegment::Expression(_)));
+        assert!(matches!(&segments[7], SqlSegment::Raw(Expr::Path(_))));
+
+        // Prefixes and `:param` are mutually exclusive
+        assert!(parse_template("WHERE {#id:param}").is_err());
+        assert!(parse_template("WHERE {!id:param}").is_err());
+    }
+
+    #[test]
+    fn test_parse_output_annotation() {
+        let input: SqlInput = syn::parse_str(r#""SELECT 1, 'a'", as (i64, String)"#).unwrap();
+        assert!(input.args.is_none());
+        assert!(input.output.is_some());
+
+        let input: SqlInput = syn::parse_str(r#""SELECT {}", value, as i64,"#).unwrap();
+        assert_eq!(input.args.map(|args| args.len()), Some(1));
+        assert!(input.output.is_some());
+
+        assert!(syn::parse_str::<SqlInput>(r#""SELECT {}", as i64, value"#).is_err());
+    }
+
     #[test]
     fn test_unmatched_braces() {
         assert!(parse_template("SELECT {unclosed FROM table").is_err());
@@ -394,8 +598,12 @@ mod tests {
             parse_str::<Expr>("42").unwrap(),
         ];
 
-        let segments =
-            parse_template_with_args("SELECT * FROM {} WHERE {} = {}", Some(&args)).unwrap();
+        let segments = parse_template_with_args(
+            "SELECT * FROM {} WHERE {} = {}",
+            Some(&args),
+            Span::call_site(),
+        )
+        .unwrap();
         assert_eq!(segments.len(), 6);
 
         match &segments[0] {
@@ -454,7 +662,11 @@ mod tests {
 
         // Test too few arguments
         let args = vec![parse_str::<Expr>("table").unwrap()];
-        let result = parse_template_with_args("SELECT * FROM {} WHERE {} = {}", Some(&args));
+        let result = parse_template_with_args(
+            "SELECT * FROM {} WHERE {} = {}",
+            Some(&args),
+            Span::call_site(),
+        );
         assert!(result.is_err());
 
         // Test too many arguments
@@ -464,7 +676,11 @@ mod tests {
             parse_str::<Expr>("value").unwrap(),
             parse_str::<Expr>("extra").unwrap(),
         ];
-        let result = parse_template_with_args("SELECT * FROM {} WHERE {} = {}", Some(&args));
+        let result = parse_template_with_args(
+            "SELECT * FROM {} WHERE {} = {}",
+            Some(&args),
+            Span::call_site(),
+        );
         assert!(result.is_err());
 
         // Test exact match should work
@@ -473,7 +689,11 @@ mod tests {
             parse_str::<Expr>("column").unwrap(),
             parse_str::<Expr>("value").unwrap(),
         ];
-        let result = parse_template_with_args("SELECT * FROM {} WHERE {} = {}", Some(&args));
+        let result = parse_template_with_args(
+            "SELECT * FROM {} WHERE {} = {}",
+            Some(&args),
+            Span::call_site(),
+        );
         assert!(result.is_ok());
     }
 
@@ -487,8 +707,12 @@ mod tests {
             parse_str::<Expr>("42").unwrap(),
         ];
 
-        let segments =
-            parse_template_with_args("SELECT * FROM {} WHERE {id} = {}", Some(&args)).unwrap();
+        let segments = parse_template_with_args(
+            "SELECT * FROM {} WHERE {id} = {}",
+            Some(&args),
+            Span::call_site(),
+        )
+        .unwrap();
         assert_eq!(segments.len(), 6);
 
         // First {} should be replaced with "users"
@@ -1,5 +1,6 @@
 use crate::common::enum_utils::{
-    has_explicit_discriminants, has_integer_repr, resolve_discriminants,
+    has_data_variants, has_explicit_discriminants, has_integer_repr, resolve_discriminants,
+    resolve_variant_names,
 };
 use crate::paths::{core as core_paths, sqlite as sqlite_paths};
 use proc_macro2::TokenStream;
@@ -11,61 +12,51 @@ pub fn generate_enum_impl(
     data: &DataEnum,
     attrs: &[Attribute],
 ) -> syn::Result<TokenStream> {
+    // Data-carrying variants can't be a bare name or discriminant
+    if has_data_variants(data) {
+        return generate_json_enum_impl(name, attrs);
+    }
+
     // Get paths for fully-qualified types
     let drizzle_error = core_paths::drizzle_error();
-    let schema_item_tables = core_paths::schema_item_tables();
-    let type_set_nil = core_paths::type_set_nil();
-    let row_column_list = core_paths::row_column_list();
-    let type_set_cons = core_paths::type_set_cons();
     let core_expr = core_paths::expr();
     let impl_try_from_int = core_paths::impl_try_from_int();
-    let value_type_for_dialect = core_paths::value_type_for_dialect();
-    let sqlite_dialect = core_paths::sqlite_dialect();
     let sqlite_value = sqlite_paths::sqlite_value();
     let sqlite_value_ref = sqlite_paths::sqlite_value_ref();
     let sqlite_types = sqlite_paths::types();
 
-    let display_variants = data.variants.iter().map(|variant| {
-        let variant_name = &variant.ident;
-        let variant_str = variant_name.to_string();
+    // Stored string per variant, honoring #[drizzle(rename_all/rename)]
+    let variant_names = resolve_variant_names(data, attrs)?;
 
+    let display_variants = variant_names.iter().map(|(ident, value)| {
         quote! {
-            #name::#variant_name => write!(f, #variant_str),
+            #name::#ident => f.write_str(#value),
         }
     });
 
-    let to_str_variants: Vec<_> = data
-        .variants
+    let to_str_variants: Vec<_> = variant_names
         .iter()
-        .map(|variant| {
-            let ident = &variant.ident;
-
+        .map(|(ident, value)| {
             quote! {
-                #name::#ident => stringify!(#ident)
+                #name::#ident => #value
             }
         })
         .collect();
 
-    let to_str_ref_variants: Box<_> = data
-        .variants
+    let to_str_ref_variants: Box<_> = variant_names
         .iter()
-        .map(|variant| {
-            let ident = &variant.ident;
-
+        .map(|(ident, value)| {
             quote! {
-                &#name::#ident => stringify!(#ident)
+                &#name::#ident => #value
             }
         })
         .collect();
 
-    let from_str_variants: Box<_> = data
-        .variants
+    let from_str_variants: Box<_> = variant_names
         .iter()
-        .map(|variant| {
-            let ident = &variant.ident;
-
+        .map(|(ident, value)| {
             quote! {
-                stringify!(#ident) => #name::#ident,
+                #value => #name::#ident,
             }
         })
         .collect();
@@ -279,33 +270,6 @@ pub fn generate_enum_impl(
     #[cfg(not(feature = "rusqlite"))]
     let rusqlite_impls = quote! {};
 
-    #[cfg(feature = "rusqlite")]
-    let row_column_list_rusqlite = quote! {
-        impl<'__drizzle_r> #row_column_list<drizzle::sqlite::rusqlite::Row<'__drizzle_r>> for #name {
-            type Columns = #type_set_cons<#name, #type_set_nil>;
-        }
-    };
-    #[cfg(not(feature = "rusqlite"))]
-    let row_column_list_rusqlite = quote! {};
-
-    #[cfg(feature = "libsql")]
-    let row_column_list_libsql = quote! {
-        impl #row_column_list<drizzle::sqlite::libsql::Row> for #name {
-            type Columns = #type_set_cons<#name, #type_set_nil>;
-        }
-    };
-    #[cfg(not(feature = "libsql"))]
-    let row_column_list_libsql = quote! {};
-
-    #[cfg(feature = "turso")]
-    let row_column_list_turso = quote! {
-        impl #row_column_list<drizzle::sqlite::turso::Row> for #name {
-            type Columns = #type_set_cons<#name, #type_set_nil>;
-        }
-    };
-    #[cfg(not(feature = "turso"))]
-    let row_column_list_turso = quote! {};
-
     let drizzle_sqlite_column = sqlite_paths::drizzle_sqlite_column();
 
     let drizzle_sqlite_column_impl = if is_integer_storage {
@@ -366,20 +330,70 @@ pub fn generate_enum_impl(
         }
     };
 
-    let to_sql = core_paths::to_sql_trait();
-    let sql = core_paths::sql();
+    let shared_impls = generate_shared_impls(name, &enum_sql_type);
 
     Ok(quote! {
         #base_impls
         #rusqlite_impls
-        #row_column_list_rusqlite
-        #row_column_list_libsql
-        #row_column_list_turso
 
         // DrizzleSQLiteColumn supplies schema metadata plus read/write conversion.
         // FromSQLiteValue is provided by the blanket DrizzleSQLiteColumn impl.
         #drizzle_sqlite_column_impl
 
+        #shared_impls
+    })
+}
+
+// Row, bind-parameter and value-layer impls shared by every storage format.
+// Each one routes through the enum's DrizzleSQLiteColumn impl.
+fn generate_shared_impls(name: &Ident, enum_sql_type: &TokenStream) -> TokenStream {
+    let drizzle_error = core_paths::drizzle_error();
+    let schema_item_tables = core_paths::schema_item_tables();
+    let type_set_nil = core_paths::type_set_nil();
+    let value_type_for_dialect = core_paths::value_type_for_dialect();
+    let sqlite_dialect = core_paths::sqlite_dialect();
+    let sqlite_value = sqlite_paths::sqlite_value();
+    let drizzle_sqlite_column = sqlite_paths::drizzle_sqlite_column();
+    let to_sql = core_paths::to_sql_trait();
+    let sql = core_paths::sql();
+
+    #[cfg(any(feature = "rusqlite", feature = "libsql", feature = "turso"))]
+    let row_column_list = core_paths::row_column_list();
+    #[cfg(any(feature = "rusqlite", feature = "libsql", feature = "turso"))]
+    let type_set_cons = core_paths::type_set_cons();
+
+    #[cfg(feature = "rusqlite")]
+    let row_column_list_rusqlite = quote! {
+        impl<'__drizzle_r> #row_column_list<drizzle::sqlite::rusqlite::Row<'__drizzle_r>> for #name {
+            type Columns = #type_set_cons<#name, #type_set_nil>;
+        }
+    };
+    #[cfg(not(feature = "rusqlite"))]
+    let row_column_list_rusqlite = quote! {};
+
+    #[cfg(feature = "libsql")]
+    let row_column_list_libsql = quote! {
+        impl #row_column_list<drizzle::sqlite::libsql::Row> for #name {
+            type Columns = #type_set_cons<#name, #type_set_nil>;
+        }
+    };
+    #[cfg(not(feature = "libsql"))]
+    let row_column_list_libsql = quote! {};
+
+    #[cfg(feature = "turso")]
+    let row_column_list_turso = quote! {
+        impl #row_column_list<drizzle::sqlite::turso::Row> for #name {
+            type Columns = #type_set_cons<#name, #type_set_nil>;
+        }
+    };
+    #[cfg(not(feature = "turso"))]
+    let row_column_list_turso = quote! {};
+
+    quote! {
+        #row_column_list_rusqlite
+        #row_column_list_libsql
+        #row_column_list_turso
+
         // ToSQL implementation (delegates to From)
         impl<'a> #to_sql<'a, #sqlite_value<'a>> for #name {
             fn to_sql(&self) -> #sql<'a, #sqlite_value<'a>> {
@@ -418,5 +432,131 @@ pub fn generate_enum_impl(
         impl #schema_item_tables for #name {
             type Tables = #type_set_nil;
         }
+    }
+}
+
+// Generate implementation for enums with data-carrying variants, stored as
+// serde JSON in a TEXT column
+fn generate_json_enum_impl(name: &Ident, attrs: &[Attribute]) -> syn::Result<TokenStream> {
+    if !cfg!(feature = "serde") {
+        return Err(syn::Error::new_spanned(
+            name,
+            "#[derive(SQLiteEnum)] on an enum with data-carrying variants stores it as JSON, \
+             which requires the 'serde' feature.\n\
+             Add to Cargo.toml: drizzle = { version = \"*\", features = [\"serde\"] }",
+        ));
+    }
+    if let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("drizzle")) {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "#[drizzle(...)] renames don't apply to enums stored as JSON; \
+             use #[serde(rename = \"...\")] or #[serde(rename_all = \"...\")] instead",
+        ));
+    }
+
+    let drizzle_error = core_paths::drizzle_error();
+    let core_expr = core_paths::expr();
+    let sqlite_value = sqlite_paths::sqlite_value();
+    let sqlite_value_ref = sqlite_paths::sqlite_value_ref();
+    let sqlite_types = sqlite_paths::types();
+    let drizzle_sqlite_column = sqlite_paths::drizzle_sqlite_column();
+    let enum_sql_type = quote! { #sqlite_types::Text };
+
+    #[cfg(feature = "rusqlite")]
+    let rusqlite_impls = quote! {
+        impl drizzle::sqlite::rusqlite::types::FromSql for #name {
+            fn column_result(value: drizzle::sqlite::rusqlite::types::ValueRef<'_>) -> drizzle::sqlite::rusqlite::types::FromSqlResult<Self> {
+                match value {
+                    drizzle::sqlite::rusqlite::types::ValueRef::Text(bytes)
+                    | drizzle::sqlite::rusqlite::types::ValueRef::Blob(bytes) => {
+                        ::serde_json::from_slice(bytes)
+                            .map_err(|e| drizzle::sqlite::rusqlite::types::FromSqlError::Other(::std::boxed::Box::new(e)))
+                    },
+                    _ => ::std::result::Result::Err(drizzle::sqlite::rusqlite::types::FromSqlError::InvalidType),
+                }
+            }
+        }
+
+        impl drizzle::sqlite::rusqlite::types::ToSql for #name {
+            fn to_sql(&self) -> drizzle::sqlite::rusqlite::Result<drizzle::sqlite::rusqlite::types::ToSqlOutput<'_>> {
+                let json = ::serde_json::to_string(self)
+                    .map_err(|e| drizzle::sqlite::rusqlite::Error::ToSqlConversionFailure(::std::boxed::Box::new(e)))?;
+                ::std::result::Result::Ok(drizzle::sqlite::rusqlite::types::ToSqlOutput::from(json))
+            }
+        }
+    };
+
+    #[cfg(not(feature = "rusqlite"))]
+    let rusqlite_impls = quote! {};
+
+    let shared_impls = generate_shared_impls(name, &enum_sql_type);
+
+    Ok(quote! {
+        impl<'a> #core_expr::Expr<'a, #sqlite_value<'a>> for #name {
+            type SQLType = #enum_sql_type;
+            type Nullable = #core_expr::NonNull;
+            type Aggregate = #core_expr::Scalar;
+        }
+
+        impl ::std::convert::TryFrom<&str> for #name {
+            type Error = #drizzle_error;
+
+            fn try_from(value: &str) -> ::std::result::Result<Self, Self::Error> {
+                ::serde_json::from_str(value).map_err(|e| {
+                    #drizzle_error::Mapping(::std::format!("{}: {e}", stringify!(#name)).into())
+                })
+            }
+        }
+
+        impl ::std::convert::TryFrom<&::std::string::String> for #name {
+            type Error = #drizzle_error;
+
+            fn try_from(value: &::std::string::String) -> ::std::result::Result<Self, Self::Error> {
+                Self::try_from(value.as_str())
+            }
+        }
+
+        impl ::std::convert::TryFrom<::std::string::String> for #name {
+            type Error = #drizzle_error;
+
+            fn try_from(value: ::std::string::String) -> ::std::result::Result<Self, Self::Error> {
+                Self::try_from(value.as_str())
+            }
+        }
+
+        impl ::std::str::FromStr for #name {
+            type Err = #drizzle_error;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                Self::try_from(s)
+            }
+        }
+
+        #rusqlite_impls
+
+        impl #drizzle_sqlite_column for #name {
+            type SQLType = #sqlite_types::Text;
+            const SQL_TYPE: &'static str = "TEXT";
+
+            fn decode(value: #sqlite_value_ref<'_>) -> ::std::result::Result<Self, #drizzle_error> {
+                match value {
+                    #sqlite_value_ref::Text(value) => Self::try_from(value),
+                    #sqlite_value_ref::Blob(value) => ::serde_json::from_slice(value).map_err(|e| {
+                        #drizzle_error::Mapping(::std::format!("{}: {e}", stringify!(#name)).into())
+                    }),
+                    _ => ::std::result::Result::Err(#drizzle_error::ConversionError(
+                        ::std::format!("expected JSON TEXT for {}", stringify!(#name)).into()
+                    )),
+                }
+            }
+
+            fn encode(&self) -> #sqlite_value<'_> {
+                let json = ::serde_json::to_string(self)
+                    .expect("failed to serialize JSON value for SQLite TEXT column");
+                #sqlite_value::Text(::std::borrow::Cow::Owned(json))
+            }
+        }
+
+        #shared_impls
     })
 }
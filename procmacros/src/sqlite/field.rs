@@ -1,21 +1,23 @@
+use drizzle_types::Casing;
 use heck::ToSnakeCase;
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use std::borrow::Cow;
 use std::{collections::HashSet, fmt::Display};
 use syn::{
     Attribute, Error, Expr, ExprPath, Field, Ident, Lit, Meta, Result, Token, Type, ext::IdentExt,
-    parse::ParseStream, punctuated::Punctuated,
+    parse::ParseStream, punctuated::Punctuated, spanned::Spanned,
 };
 
 use crate::common::make_uppercase_path;
 use crate::common::{
-    is_option_type, option_inner_type, references_required_message,
-    relation_requires_references_message, type_is_array_string, type_is_array_u8,
-    type_is_arrayvec_u8, type_is_bool, type_is_byte_slice, type_is_datetime_tz, type_is_float,
-    type_is_int, type_is_json_value, type_is_naive_date, type_is_naive_datetime,
-    type_is_naive_time, type_is_offset_datetime, type_is_primitive_date_time, type_is_string_like,
-    type_is_time_date, type_is_time_time, type_is_uuid, type_is_vec_u8, unwrap_option,
+    apply_casing, doc_comment_from_attrs, is_option_type, nullable_primary_key_message,
+    option_inner_type, references_required_message, relation_requires_references_message,
+    sql_default_expression, type_is_array_string, type_is_array_u8, type_is_arrayvec_u8,
+    type_is_bool, type_is_byte_slice, type_is_datetime_tz, type_is_float, type_is_int,
+    type_is_json_value, type_is_naive_date, type_is_naive_datetime, type_is_naive_time,
+    type_is_offset_datetime, type_is_primitive_date_time, type_is_string_like, type_is_time_date,
+    type_is_time_time, type_is_uuid, type_is_vec_u8, unknown_attribute_message, unwrap_option,
 };
 
 // =============================================================================
@@ -101,6 +103,7 @@ impl SQLiteType {
                 (Self::Integer, "autoincrement")
                     | (Self::Text | Self::Blob, "json")
                     | (Self::Text | Self::Integer, "enum")
+                    | (Self::Text, "json_enum")
             )
     }
 }
@@ -127,7 +130,7 @@ impl SQLiteType {
     ///
     /// Provides helpful error messages with links to relevant `SQLite` documentation
     /// when incompatible flag/type combinations are used.
-    pub(crate) fn validate_flag(&self, flag: &str, attr: &Attribute) -> Result<()> {
+    pub(crate) fn validate_flag(&self, flag: &str, span: Span) -> Result<()> {
         if !self.is_valid_flag(flag) {
             let error_msg = match flag {
                 "autoincrement" => {
@@ -156,20 +159,19 @@ impl SQLiteType {
                      \n\
                      Use: #[column(enum)] or #[column(integer, enum)]"
                 }
-                "not_null" => {
-                    "Use Option<T> in your struct field to represent nullable columns instead of 'not_null' attribute.\n\
+                "json_enum" => {
+                    "JSON enums are stored as serialized JSON in a TEXT column.\n\
                      \n\
-                     Drizzle RS uses Rust's type system for nullability:\n\
-                     - Field type `T` = NOT NULL column\n\
-                     - Field type `Option<T>` = NULL allowed column\n\
+                     Enums with data-carrying variants have no single name or discriminant, \
+                     so they can't use INTEGER or BLOB storage.\n\
                      \n\
-                     See: https://sqlite.org/lang_createtable.html#notnullconst\n\
-                     Example: pub email: Option<String> for nullable TEXT"
+                     See: https://sqlite.org/json1.html\n\
+                     Use: #[column(json_enum)] or #[text(json_enum)]"
                 }
                 _ => return Ok(()),
             };
 
-            return Err(Error::new_spanned(attr, error_msg));
+            return Err(Error::new(span, error_msg));
         }
 
         Ok(())
@@ -242,10 +244,15 @@ pub struct FieldInfo<'a> {
     pub(crate) is_autoincrement: bool,
     pub(crate) is_json: bool,
     pub(crate) is_enum: bool,
+    /// True for `#[column(json_enum)]`: the enum is stored as serde JSON TEXT.
+    pub(crate) is_json_enum: bool,
     pub(crate) is_uuid: bool,
     /// True when the type is unknown to the macro (e.g., a user-defined enum type).
     /// The type is validated at type-check time via `DrizzleSQLiteColumn` trait bounds.
     pub(crate) is_custom_type: bool,
+    /// True for the primary key of a table declared with `ID_TYPE = ...`;
+    /// `base_type` is then the generated newtype rather than the declared type.
+    pub(crate) is_id_newtype: bool,
     pub(crate) column_type: SQLiteType,
 
     // Foreign key support
@@ -267,6 +274,9 @@ pub struct FieldInfo<'a> {
     /// Stored as the uppercased / literal collation name; emitted verbatim
     /// in DDL after column constraints (`... NOT NULL COLLATE NOCASE`).
     pub(crate) collate: Option<String>,
+    /// Column comment from `#[column(comment = "...")]` or the field's doc
+    /// comment. Recorded in snapshots only; `SQLite` has no `COMMENT ON`.
+    pub(crate) comment: Option<String>,
 
     // Attribute values
     pub(crate) default_value: Option<Expr>,
@@ -344,6 +354,58 @@ fn parse_item(input: ParseStream) -> Result<Expr> {
     }
 }
 
+/// Span of the `#[column(...)]` marker that set `flag`, falling back to the
+/// whole attribute.
+fn flag_span(marker_exprs: &[ExprPath], flag: &str, attr: &Attribute) -> Span {
+    marker_exprs
+        .iter()
+        .find(|marker| {
+            marker.path.get_ident().is_some_and(|ident| {
+                let name = ident.to_string();
+                name.eq_ignore_ascii_case(flag) || (flag == "json" && name == "JSONB")
+            })
+        })
+        .map_or_else(|| attr.span(), Spanned::span)
+}
+
+/// Names accepted inside `#[column(...)]`, used for "did you mean" hints.
+const COLUMN_ATTRIBUTES: &[&str] = &[
+    "primary",
+    "autoincrement",
+    "unique",
+    "json",
+    "jsonb",
+    "enum",
+    "json_enum",
+    "default",
+    "default_fn",
+    "default_sql",
+    "references",
+    "relation",
+    "on_delete",
+    "on_update",
+    "name",
+    "collate",
+    "comment",
+    "check",
+    "generated",
+    "integer",
+    "text",
+    "blob",
+    "real",
+    "numeric",
+    "any",
+];
+
+const NULLABILITY_HELP: &str = "Use Option<T> in your struct field to represent nullable columns instead of a nullability attribute.\n\
+     \n\
+     Drizzle RS uses Rust's type system for nullability:\n\
+     - Field type `T` = NOT NULL column\n\
+     - Field type `Option<T>` = NULL allowed column\n\
+     \n\
+     See: https://sqlite.org/lang_createtable.html#notnullconst\n\
+     Example: pub email: Option<String> for nullable TEXT";
+
 #[derive(Default)]
 struct ParsedArgs {
     default_value: Option<Expr>,
@@ -361,6 +423,8 @@ struct ParsedArgs {
     /// custom registered collation). Stored as the literal name; emitted
     /// verbatim in DDL as `COLLATE <name>`.
     collate: Option<String>,
+    /// Column comment from `comment = "..."`.
+    comment: Option<String>,
     flags: HashSet<String>,
     /// Original marker expressions for IDE hover documentation
     /// These preserve the original tokens so rust-analyzer can resolve them
@@ -388,6 +452,8 @@ struct AttributeData {
     attr_name: Option<String>,
     /// SQLite collation name. See [`ParsedArgs::collate`].
     collate: Option<String>,
+    /// Column comment. See [`ParsedArgs::comment`].
+    comment: Option<String>,
     /// Original marker expressions for IDE hover documentation
     marker_exprs: Vec<syn::ExprPath>,
 }
@@ -415,7 +481,7 @@ impl<'a> FieldInfo<'a> {
     ///
     /// Supports:
     /// - `SQLite` type overrides: `text`, `integer`, `blob`, `real`, `any`
-    /// - Constraint flags: `primary`, `unique`, `autoincrement`, `json`, `enum`
+    /// - Constraint flags: `primary`, `unique`, `autoincrement`, `json`, `enum`, `json_enum`
     /// - Named parameters: `default = value`, `default_fn = func`, `references = Table::col`
     fn parse_args(input: ParseStream) -> Result<ParsedArgs> {
         if input.is_empty() {
@@ -453,6 +519,14 @@ impl<'a> FieldInfo<'a> {
                                 args.flags.insert("enum".to_string());
                                 args.marker_exprs.push(make_uppercase_path(ident, "ENUM"));
                             }
+                            "JSON_ENUM" => {
+                                // JSON_ENUM = TEXT storage through the SQLiteEnum JSON impls
+                                args.explicit_type = Some(SQLiteType::Text);
+                                args.flags.insert("enum".to_string());
+                                args.flags.insert("json_enum".to_string());
+                                args.marker_exprs
+                                    .push(make_uppercase_path(ident, "JSON_ENUM"));
+                            }
                             "PRIMARY" | "PRIMARY_KEY" => {
                                 args.flags.insert("primary".to_string());
                                 args.marker_exprs
@@ -473,8 +547,20 @@ impl<'a> FieldInfo<'a> {
                                     SQLiteType::from_attribute_name(&ident_str)
                                 {
                                     args.explicit_type = Some(sqlite_type);
+                                } else if matches!(
+                                    upper.as_str(),
+                                    "NOT_NULL" | "NOTNULL" | "NULLABLE"
+                                ) {
+                                    return Err(Error::new_spanned(ident, NULLABILITY_HELP));
                                 } else {
-                                    args.flags.insert(ident_str);
+                                    return Err(Error::new_spanned(
+                                        ident,
+                                        unknown_attribute_message(
+                                            "#[column]",
+                                            &ident_str,
+                                            COLUMN_ATTRIBUTES,
+                                        ),
+                                    ));
                                 }
                             }
                         }
@@ -489,7 +575,14 @@ impl<'a> FieldInfo<'a> {
                         let upper = param_str.to_ascii_uppercase();
                         match upper.as_str() {
                             "DEFAULT" => {
-                                args.default_value = Some(*assign.right);
+                                // `default = sql!("...")` is a DDL-level expression,
+                                // equivalent to `default_sql`.
+                                if let Expr::Macro(mac) = &*assign.right {
+                                    let expression = sql_default_expression(&mac.mac)?;
+                                    args.default_sql = Some(normalize_default_sql(&expression));
+                                } else {
+                                    args.default_value = Some(*assign.right);
+                                }
                                 args.marker_exprs
                                     .push(make_uppercase_path(param, "DEFAULT"));
                             }
@@ -605,6 +698,22 @@ impl<'a> FieldInfo<'a> {
                                 args.marker_exprs
                                     .push(make_uppercase_path(param, "COLLATE"));
                             }
+                            "COMMENT" => {
+                                if let Expr::Lit(syn::ExprLit {
+                                    lit: Lit::Str(lit_str),
+                                    ..
+                                }) = &*assign.right
+                                {
+                                    args.comment = Some(lit_str.value());
+                                    args.marker_exprs
+                                        .push(make_uppercase_path(param, "COMMENT"));
+                                } else {
+                                    return Err(Error::new_spanned(
+                                        &assign.right,
+                                        "COMMENT requires a string literal, e.g. COMMENT = \"Login e-mail\"",
+                                    ));
+                                }
+                            }
                             "CHECK" => {
                                 if let Expr::Lit(syn::ExprLit {
                                     lit: Lit::Str(lit_str),
@@ -620,7 +729,16 @@ impl<'a> FieldInfo<'a> {
                                     ));
                                 }
                             }
-                            _ => {}
+                            _ => {
+                                return Err(Error::new_spanned(
+                                    param,
+                                    unknown_attribute_message(
+                                        "#[column]",
+                                        &param_str,
+                                        COLUMN_ATTRIBUTES,
+                                    ),
+                                ));
+                            }
                         }
                     }
                 }
@@ -701,8 +819,13 @@ impl<'a> FieldInfo<'a> {
         Ok(args)
     }
 
-    /// Parse field information from a struct field
-    pub(crate) fn from_field(field: &'a Field, is_part_of_composite_pk: bool) -> Result<Self> {
+    /// Parse field information from a struct field, naming unnamed columns
+    /// with `casing`.
+    pub(crate) fn from_field(
+        field: &'a Field,
+        is_part_of_composite_pk: bool,
+        casing: Casing,
+    ) -> Result<Self> {
         let Some(field_name) = &field.ident else {
             return Err(Error::new_spanned(
                 field,
@@ -711,8 +834,17 @@ impl<'a> FieldInfo<'a> {
             ));
         };
 
-        let attrs = Self::parse_attributes(&field.attrs)?;
-        Self::build(field_name, &field.ty, attrs, is_part_of_composite_pk)
+        let mut attrs = Self::parse_attributes(&field.attrs)?;
+        attrs.comment = attrs
+            .comment
+            .or_else(|| doc_comment_from_attrs(&field.attrs));
+        Self::build(
+            field_name,
+            &field.ty,
+            attrs,
+            is_part_of_composite_pk,
+            casing,
+        )
     }
 
     /// Parse field attributes to extract column information.
@@ -745,9 +877,9 @@ impl<'a> FieldInfo<'a> {
                 // Parse arguments for legacy syntax
                 let args = attr.parse_args_with(Self::parse_args)?;
                 // Validate flags against the explicit column type
-                args.flags
-                    .iter()
-                    .try_for_each(|flag| column_type.validate_flag(flag, attr))?;
+                args.flags.iter().try_for_each(|flag| {
+                    column_type.validate_flag(flag, flag_span(&args.marker_exprs, flag, attr))
+                })?;
 
                 data.flags.extend(args.flags);
                 data.default_value = data.default_value.or(args.default_value);
@@ -760,6 +892,7 @@ impl<'a> FieldInfo<'a> {
                 data.on_update = data.on_update.or(args.on_update);
                 data.relation = data.relation.or(args.relation);
                 data.collate = data.collate.or(args.collate);
+                data.comment = data.comment.or(args.comment);
 
                 if let Some(Expr::Path(path)) = args.references {
                     data.references_path = Some(path);
@@ -788,9 +921,9 @@ impl<'a> FieldInfo<'a> {
                     data.has_explicit_type = true;
 
                     // Validate flags against the explicit type
-                    args.flags
-                        .iter()
-                        .try_for_each(|flag| explicit_type.validate_flag(flag, attr))?;
+                    args.flags.iter().try_for_each(|flag| {
+                        explicit_type.validate_flag(flag, flag_span(&args.marker_exprs, flag, attr))
+                    })?;
                 }
                 // Otherwise, type will be inferred in build()
 
@@ -805,6 +938,7 @@ impl<'a> FieldInfo<'a> {
                 data.on_update = data.on_update.or(args.on_update);
                 data.relation = data.relation.or(args.relation);
                 data.collate = data.collate.or(args.collate);
+                data.comment = data.comment.or(args.comment);
 
                 if let Some(Expr::Path(path)) = args.references {
                     data.references_path = Some(path);
@@ -848,11 +982,12 @@ impl<'a> FieldInfo<'a> {
         field_type: &'a Type,
         attrs: AttributeData,
         is_part_of_composite_pk: bool,
+        casing: Casing,
     ) -> Result<Self> {
         let column_name = attrs
             .attr_name
             .clone()
-            .unwrap_or_else(|| field_name.to_string().to_snake_case());
+            .unwrap_or_else(|| apply_casing(&field_name.to_string(), casing));
         let is_nullable = is_option_type(field_type);
         let base_type = option_inner_type(field_type).unwrap_or(field_type);
 
@@ -862,7 +997,15 @@ impl<'a> FieldInfo<'a> {
         let is_unique = attrs.flags.contains("unique");
         let is_json = attrs.flags.contains("json");
         let is_enum = attrs.flags.contains("enum");
+        let is_json_enum = attrs.flags.contains("json_enum");
         let is_uuid = type_is_uuid(base_type);
+
+        if (is_primary || is_part_of_composite_pk) && is_nullable {
+            return Err(Error::new_spanned(
+                field_type,
+                nullable_primary_key_message(&field_name.to_string()),
+            ));
+        }
         let has_default = attrs.default_value.is_some()
             || attrs.default_sql.is_some()
             || attrs.default_fn.is_some();
@@ -896,6 +1039,10 @@ impl<'a> FieldInfo<'a> {
             SQLiteType::Any
         };
 
+        if matches!(type_category, TypeCategory::DateTime) && attrs.has_explicit_type {
+            validate_datetime_storage(&column_type, base_type)?;
+        }
+
         Self::validate_constraints(
             &column_type,
             ConstraintFlags {
@@ -903,10 +1050,7 @@ impl<'a> FieldInfo<'a> {
                 is_autoincrement,
                 is_uuid,
             },
-            attrs.default_value.as_ref(),
-            attrs.default_sql.as_deref(),
-            attrs.default_fn.as_ref(),
-            attrs.generated_column.as_ref(),
+            &attrs,
             field_name,
         )?;
 
@@ -946,8 +1090,10 @@ impl<'a> FieldInfo<'a> {
             is_autoincrement,
             is_json,
             is_enum,
+            is_json_enum,
             is_uuid,
             is_custom_type,
+            is_id_newtype: false,
             column_type,
             foreign_key,
             relation_name: attrs.relation,
@@ -957,6 +1103,7 @@ impl<'a> FieldInfo<'a> {
                 is_part_of_composite_pk,
             ),
             collate: attrs.collate,
+            comment: attrs.comment,
             default_value: attrs.default_value,
             default_sql: attrs.default_sql,
             default_fn: attrs.default_fn,
@@ -972,43 +1119,58 @@ impl<'a> FieldInfo<'a> {
     fn validate_constraints(
         column_type: &SQLiteType,
         props: ConstraintFlags,
-        default_value: Option<&Expr>,
-        default_sql: Option<&str>,
-        default_fn: Option<&Expr>,
-        generated_column: Option<&GeneratedColumn>,
+        attrs: &AttributeData,
         field_name: &Ident,
     ) -> Result<()> {
+        let default_value = attrs.default_value.as_ref();
+        let default_sql = attrs.default_sql.as_deref();
+        let default_fn = attrs.default_fn.as_ref();
+        let generated_column = attrs.generated_column.as_ref();
+        let field_span = field_name.span();
+        let marker_span = |name: &str| {
+            attrs
+                .marker_exprs
+                .iter()
+                .find(|marker| marker.path.is_ident(name))
+                .map_or(field_span, Spanned::span)
+        };
         let validations = [
             (
                 props.is_autoincrement && !matches!(column_type, SQLiteType::Integer),
+                marker_span("AUTOINCREMENT"),
                 "AUTOINCREMENT can only be used with INTEGER PRIMARY KEY.\n\
               See: https://sqlite.org/autoinc.html\n\
               Hint: Change column type to '#[column(integer, primary, autoincrement)]'",
             ),
             (
                 props.is_autoincrement && !props.is_primary,
+                marker_span("AUTOINCREMENT"),
                 "AUTOINCREMENT requires PRIMARY KEY constraint.\n\
               See: https://sqlite.org/autoinc.html\n\
               Hint: Add 'primary' flag: '#[column(primary, autoincrement)]'",
             ),
             (
                 default_value.is_some() && default_fn.is_some(),
+                marker_span("DEFAULT_FN"),
                 "Cannot specify both 'default' (compile-time literal) and 'default_fn' (runtime function).\n\
               Choose one: either 'default = literal' or 'default_fn = function'\n\
               Examples:\n  #[column(default = \"hello\")] for compile-time defaults\n  #[column(default_fn = String::new)] for runtime defaults",
             ),
             (
                 default_value.is_some() && default_sql.is_some(),
+                marker_span("DEFAULT_SQL"),
                 "Cannot specify both 'default' (literal default) and 'default_sql' (raw SQL default).\n\
               Choose one: either 'default = literal' or 'default_sql = \"CURRENT_TIMESTAMP\"'",
             ),
             (
                 default_sql.is_some() && generated_column.is_some(),
+                marker_span("GENERATED"),
                 "Cannot specify both 'default_sql' and 'generated' on the same column.\n\
               SQLite generated columns cannot also declare a DEFAULT expression.",
             ),
             (
                 props.is_uuid && !matches!(column_type, SQLiteType::Blob | SQLiteType::Text),
+                field_span,
                 "UUID fields must use either BLOB or TEXT column type.\n\
               BLOB storage: Efficient 16-byte binary format (recommended)\n\
               TEXT storage: Human-readable string format\n\
@@ -1019,8 +1181,8 @@ impl<'a> FieldInfo<'a> {
 
         validations
             .iter()
-            .find(|(condition, _)| *condition)
-            .map_or(Ok(()), |(_, msg)| Err(Error::new_spanned(field_name, msg)))
+            .find(|(condition, _, _)| *condition)
+            .map_or(Ok(()), |(_, span, msg)| Err(Error::new(*span, msg)))
     }
 }
 
@@ -1166,6 +1328,26 @@ impl FieldInfo<'_> {
         type_category_from_type(self.base_type)
     }
 
+    /// Function that re-encodes this column's bound values, if its storage
+    /// differs from the value's default binding.
+    ///
+    /// Date-times bind as ISO-8601 TEXT; `#[column(integer)]` date-time
+    /// columns store unix seconds instead.
+    pub(crate) fn value_retag(&self) -> Option<TokenStream> {
+        if self.column_type == SQLiteType::Integer && is_date_time_type(self.base_type) {
+            let sqlite_value = crate::paths::sqlite::sqlite_value();
+            Some(quote!(#sqlite_value::into_unix_timestamp))
+        } else {
+            None
+        }
+    }
+
+    /// `u64` / `usize` columns. Values above `i64::MAX` are stored as 8-byte
+    /// BLOBs, so drivers can't read them with their integer accessor.
+    pub(crate) fn is_wide_unsigned(&self) -> bool {
+        type_is_int(self.base_type, "u64") || type_is_int(self.base_type, "usize")
+    }
+
     /// Get the inner type for `SQLiteInsertValue` wrapper.
     ///
     /// For types that use `impl Into<...>` parameters, this returns the
@@ -1406,6 +1588,42 @@ pub fn generate_table_meta_json(
     serde_json::to_string(&entities).unwrap_or_else(|_| "[]".to_string())
 }
 
+/// Date and time together (`NaiveDateTime`, `DateTime<Tz>`,
+/// `PrimitiveDateTime`, `OffsetDateTime`), as opposed to a date or time alone.
+fn is_date_time_type(ty: &Type) -> bool {
+    let ty = unwrap_option(ty);
+    type_is_naive_datetime(ty)
+        || type_is_datetime_tz(ty)
+        || type_is_primitive_date_time(ty)
+        || type_is_offset_datetime(ty)
+}
+
+/// Check an explicit storage type on a date/time column.
+///
+/// Date-times are stored as ISO-8601 TEXT (the default) or, with
+/// `#[column(integer)]`, as unix seconds. Dates and times alone only have the
+/// TEXT form.
+fn validate_datetime_storage(column_type: &SQLiteType, ty: &Type) -> Result<()> {
+    match column_type {
+        SQLiteType::Text => Ok(()),
+        SQLiteType::Integer if is_date_time_type(ty) => Ok(()),
+        SQLiteType::Integer => Err(Error::new_spanned(
+            ty,
+            "INTEGER storage is only supported for date-time columns.\n\
+             Dates and times without the other part are stored as ISO-8601 TEXT.",
+        )),
+        other => Err(Error::new_spanned(
+            ty,
+            format!(
+                "date/time columns cannot be stored as {}.\n\
+                 Use #[column(text)] for ISO-8601 TEXT (the default) or \
+                 #[column(integer)] for unix seconds.",
+                other.to_sql_type()
+            ),
+        )),
+    }
+}
+
 fn type_category_from_type(ty: &Type) -> TypeCategory {
     let ty = unwrap_option(ty);
 
@@ -1451,6 +1669,7 @@ fn type_category_from_type(ty: &Type) -> TypeCategory {
         || type_is_int(ty, "u8")
         || type_is_int(ty, "u16")
         || type_is_int(ty, "u32")
+        || type_is_int(ty, "u64")
         || type_is_int(ty, "isize")
         || type_is_int(ty, "usize")
     {
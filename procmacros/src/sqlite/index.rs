@@ -1,3 +1,4 @@
+use crate::common::index_member_collate;
 use crate::paths::{core as core_paths, ddl::sqlite as ddl_paths, sqlite as sqlite_paths};
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -61,7 +62,7 @@ pub fn sqlite_index_attr_macro(attr: IndexAttributes, input: &DeriveInput) -> Re
     let index_column_def = ddl_paths::index_column_def();
 
     // Extract columns from tuple struct fields: struct UserEmailIdx(User::email);
-    let columns = match &input.data {
+    let (columns, collations): (Vec<Expr>, Vec<Option<String>>) = match &input.data {
         syn::Data::Struct(data_struct) => {
             match &data_struct.fields {
                 syn::Fields::Unnamed(fields) => {
@@ -70,12 +71,16 @@ pub fn sqlite_index_attr_macro(attr: IndexAttributes, input: &DeriveInput) -> Re
                         .iter()
                         .map(|field| {
                             // Convert Type to Expr
+                            let collate = index_member_collate(field, &["collate"])?;
                             match &field.ty {
-                                Type::Path(type_path) => Ok(Expr::Path(syn::ExprPath {
-                                    attrs: vec![],
-                                    qself: type_path.qself.clone(),
-                                    path: type_path.path.clone(),
-                                })),
+                                Type::Path(type_path) => Ok((
+                                    Expr::Path(syn::ExprPath {
+                                        attrs: vec![],
+                                        qself: type_path.qself.clone(),
+                                        path: type_path.path.clone(),
+                                    }),
+                                    collate,
+                                )),
                                 _ => Err(Error::new_spanned(
                                     &field.ty,
                                     "Column must be a path like User::email",
@@ -83,6 +88,8 @@ pub fn sqlite_index_attr_macro(attr: IndexAttributes, input: &DeriveInput) -> Re
                             }
                         })
                         .collect::<Result<Vec<_>>>()?
+                        .into_iter()
+                        .unzip()
                 }
                 _ => {
                     return Err(Error::new_spanned(
@@ -140,7 +147,11 @@ pub fn sqlite_index_attr_macro(attr: IndexAttributes, input: &DeriveInput) -> Re
     // and extracts its NAME - this ensures we use the actual database column name
     let column_defs: Vec<_> = columns
         .iter()
-        .map(|col| {
+        .zip(&collations)
+        .map(|(col, collate)| {
+            let collate_modifier = collate
+                .as_ref()
+                .map_or_else(|| quote! {}, |collate| quote! { .collate(#collate) });
             quote! {
                 #index_column_def::new({
                     // Const validation that the column implements SQLSchema
@@ -149,10 +160,19 @@ pub fn sqlite_index_attr_macro(attr: IndexAttributes, input: &DeriveInput) -> Re
                     }
                     column_name(&#col)
                 })
+                #collate_modifier
             }
         })
         .collect();
 
+    let column_collations: Vec<_> = collations
+        .iter()
+        .map(|collate| match collate {
+            Some(collate) => quote! { ::core::option::Option::Some(#collate) },
+            None => quote! { ::core::option::Option::None },
+        })
+        .collect();
+
     let column_names: Vec<_> = columns
         .iter()
         .map(|col| {
@@ -182,10 +202,13 @@ pub fn sqlite_index_attr_macro(attr: IndexAttributes, input: &DeriveInput) -> Re
     // We need each column name from the column ZSTs
     let column_sql_parts: Vec<TokenStream> = columns
         .iter()
+        .zip(&collations)
         .enumerate()
-        .map(|(i, col)| {
+        .map(|(i, (col, collate))| {
             let prefix = if i > 0 { ", \"" } else { "\"" };
-            let suffix = "\"";
+            let suffix = collate
+                .as_ref()
+                .map_or_else(|| "\"".to_string(), |collate| format!("\" COLLATE {collate}"));
             quote! {
                 #prefix,
                 {
@@ -199,14 +222,30 @@ pub fn sqlite_index_attr_macro(attr: IndexAttributes, input: &DeriveInput) -> Re
         })
         .collect();
 
-    let create_index_prefix = format!("CREATE {unique_kw}INDEX \"{index_name_lit}\" ON \"");
+    // SQLite qualifies the index, not its table, with an attached database:
+    // `CREATE INDEX "archive"."idx" ON "orders" (...)`.
+    let create_index_kw = format!("CREATE {unique_kw}INDEX ");
+    let create_index_name = format!("\"{index_name_lit}\" ON \"");
     let create_index_mid = "\" (";
     let create_index_suffix = ")";
 
     let const_format = crate::common::paths::const_format();
     let const_sql = quote! {
         #const_format::concatcp!(
-            #create_index_prefix,
+            #create_index_kw,
+            match <#table_type as drizzle::core::DrizzleTable>::SCHEMA {
+                ::core::option::Option::Some(_) => "\"",
+                ::core::option::Option::None => "",
+            },
+            match <#table_type as drizzle::core::DrizzleTable>::SCHEMA {
+                ::core::option::Option::Some(database) => database,
+                ::core::option::Option::None => "",
+            },
+            match <#table_type as drizzle::core::DrizzleTable>::SCHEMA {
+                ::core::option::Option::Some(_) => "\".",
+                ::core::option::Option::None => "",
+            },
+            #create_index_name,
             <#table_type as #sql_schema<'_, #sqlite_schema_type, #sqlite_value<'_>>>::NAME,
             #create_index_mid,
             #(#column_sql_parts,)*
@@ -225,6 +264,9 @@ pub fn sqlite_index_attr_macro(attr: IndexAttributes, input: &DeriveInput) -> Re
             /// Column names for schema snapshot generation
             pub const COLUMN_NAMES: &'static [&'static str] = &[#(#column_names),*];
 
+            /// Per-column collation overrides for schema snapshot generation
+            pub const COLUMN_COLLATIONS: &'static [::core::option::Option<&'static str>] = &[#(#column_collations),*];
+
             /// Const DDL index definition - single source of truth
             pub const DDL_INDEX: #index_def = #index_def::new(
                 <#table_type as #sql_schema<'_, #sqlite_schema_type, #sqlite_value<'_>>>::NAME,
@@ -264,6 +306,7 @@ pub fn sqlite_index_attr_macro(attr: IndexAttributes, input: &DeriveInput) -> Re
             const INDEX_NAME: &'static str = #index_name;
             const COLUMN_NAMES: &'static [&'static str] = Self::COLUMN_NAMES;
             const IS_UNIQUE: bool = #is_unique;
+            const COLUMN_COLLATIONS: &'static [::core::option::Option<&'static str>] = Self::COLUMN_COLLATIONS;
 
             fn table_ref() -> &'static drizzle::core::TableRef {
                 &<#table_type as drizzle::core::DrizzleTable>::TABLE_REF
@@ -70,6 +70,7 @@ pub fn generate_sqlite_schema_derive_impl(input: &DeriveInput) -> Result<TokenSt
     let all_field_types: Box<_> = all_fields.iter().map(|(_, ty)| *ty).collect();
 
     let create_statements_impl = generate_create_statements_method(&all_fields);
+    let drop_statements_impl = generate_drop_statements_method(&all_fields);
 
     // For Schema trait to_snapshot
     let field_types_for_snapshot: Vec<_> = all_fields.iter().map(|(_, ty)| *ty).collect();
@@ -91,6 +92,15 @@ pub fn generate_sqlite_schema_derive_impl(input: &DeriveInput) -> Result<TokenSt
     let mig_sqlite_generated_type = quote! { drizzle::migrations::sqlite::GeneratedType };
     let mig_sqlite_view = mig_paths::sqlite::view();
 
+    let schema_item_hash = core_paths::schema_item_hash();
+    let schema_hash_terms = all_fields.iter().map(|(_, ty)| {
+        quote! {
+            .wrapping_add(#schema_item_hash(
+                <#ty as #sql_schema<'static, #sqlite_schema_type, #sqlite_value<'static>>>::NAME,
+                <#ty as #sql_schema<'static, #sqlite_schema_type, #sqlite_value<'static>>>::SQL,
+            ))
+        }
+    });
     let schema_table_refs_method = generate_schema_table_refs_method(&all_fields);
     let schema_has_table_impls = generate_schema_has_table_impls(struct_name, &all_fields);
     let schema_fk_validation_asserts = generate_schema_fk_validation_asserts(
@@ -98,6 +108,7 @@ pub fn generate_sqlite_schema_derive_impl(input: &DeriveInput) -> Result<TokenSt
         struct_name,
         &validate_schema_item_foreign_keys,
     );
+    let schema_fk_cycle_assert = generate_schema_fk_cycle_assert(&all_fields, struct_name);
 
     Ok(quote! {
         impl ::core::marker::Copy for #struct_name {}
@@ -129,10 +140,15 @@ pub fn generate_sqlite_schema_derive_impl(input: &DeriveInput) -> Result<TokenSt
 
             /// Get all schema items (tables and indexes) in field order
             #items_method
+
+            /// Stable hash of the schema's DDL, independent of field order.
+            pub const SCHEMA_HASH: u64 = 0u64 #(#schema_hash_terms)*;
         }
 
         // Implement SQLSchemaImpl trait
         impl #sql_schema_impl for #struct_name {
+            const SCHEMA_HASH: u64 = Self::SCHEMA_HASH;
+
             fn table_refs(&self) -> &'static [&'static drizzle::core::TableRef] {
                 #schema_table_refs_method
             }
@@ -141,6 +157,11 @@ pub fn generate_sqlite_schema_derive_impl(input: &DeriveInput) -> Result<TokenSt
                 let statements: ::std::vec::Vec<::std::string::String> = { #create_statements_impl };
                 ::std::result::Result::Ok(statements.into_iter())
             }
+
+            fn drop_statements(&self) -> ::std::result::Result<impl ::std::iter::Iterator<Item = ::std::string::String>, drizzle::error::DrizzleError> {
+                let statements: ::std::vec::Vec<::std::string::String> = { #drop_statements_impl };
+                ::std::result::Result::Ok(statements.into_iter())
+            }
         }
 
         // Implement tuple destructuring support
@@ -153,6 +174,7 @@ pub fn generate_sqlite_schema_derive_impl(input: &DeriveInput) -> Result<TokenSt
         #schema_has_table_impls
 
         #schema_fk_validation_asserts
+        #schema_fk_cycle_assert
 
         // Implement migrations Schema trait for migration config
         impl #mig_schema for #struct_name {
@@ -178,12 +200,21 @@ pub fn generate_sqlite_schema_derive_impl(input: &DeriveInput) -> Result<TokenSt
                 // Iterate through all schema fields and add DDL entities
                 #(
                     match <#field_types_for_snapshot as #sql_schema<'_, #sqlite_schema_type, #sqlite_value<'_>>>::TYPE {
+                        // Tables in attached databases live in other files;
+                        // migrations manage the main database only.
+                        #sqlite_schema_type::Table(table_info) if table_info.schema.is_some() => {}
+                        #sqlite_schema_type::Index(index_info)
+                            if #sql_index_info::table(index_info).schema.is_some() => {}
                         #sqlite_schema_type::Table(_table_info) => {
                             // Use const TABLE_REF for column metadata instead of dyn traits
                             let table_ref = <#field_types_for_snapshot as drizzle::core::SchemaItemTables>::TABLE_REF_CONST
                                 .expect("table must have TABLE_REF_CONST");
                             let table_name = table_ref.name;
-                            snapshot.add_entity(MigEntity::Table(MigTable::new(table_name)));
+                            let mut table = MigTable::new(table_name);
+                            if let drizzle::core::TableDialect::SQLite { comment: ::core::option::Option::Some(comment), .. } = table_ref.dialect {
+                                table = table.comment(comment);
+                            }
+                            snapshot.add_entity(MigEntity::Table(table));
 
                             // Add column entities from TABLE_REF
                             for col in table_ref.columns {
@@ -193,6 +224,7 @@ pub fn generate_sqlite_schema_derive_impl(input: &DeriveInput) -> Result<TokenSt
                                     generated_expression,
                                     generated_stored,
                                     collate,
+                                    comment,
                                 ) = match col.dialect {
                                     drizzle::core::ColumnDialect::SQLite {
                                         autoincrement,
@@ -200,14 +232,16 @@ pub fn generate_sqlite_schema_derive_impl(input: &DeriveInput) -> Result<TokenSt
                                         generated_expression,
                                         generated_stored,
                                         collate,
+                                        comment,
                                     } => (
                                         autoincrement,
                                         default,
                                         generated_expression,
                                         generated_stored,
                                         collate,
+                                        comment,
                                     ),
-                                    _ => (false, ::core::option::Option::None, ::core::option::Option::None, false, ::core::option::Option::None),
+                                    _ => (false, ::core::option::Option::None, ::core::option::Option::None, false, ::core::option::Option::None, ::core::option::Option::None),
                                 };
 
                                 let mut column = MigColumn::new(
@@ -237,6 +271,9 @@ pub fn generate_sqlite_schema_derive_impl(input: &DeriveInput) -> Result<TokenSt
                                 if let ::core::option::Option::Some(collate) = collate {
                                     column.collate = ::core::option::Option::Some(::std::borrow::Cow::Borrowed(collate));
                                 }
+                                if let ::core::option::Option::Some(comment) = comment {
+                                    column.comment = ::core::option::Option::Some(::std::borrow::Cow::Borrowed(comment));
+                                }
                                 snapshot.add_entity(MigEntity::Column(column));
 
                                 // Add primary key entity if this is a primary key column
@@ -292,7 +329,16 @@ pub fn generate_sqlite_schema_derive_impl(input: &DeriveInput) -> Result<TokenSt
                                 #sql_index_info::name(index_info),
                                 #sql_index_info::columns(index_info)
                                     .iter()
-                                    .map(|c| MigIndexColumn::new(*c))
+                                    .enumerate()
+                                    .map(|(i, c)| {
+                                        let column = MigIndexColumn::new(*c);
+                                        match #sql_index_info::column_collations(index_info).get(i) {
+                                            ::core::option::Option::Some(::core::option::Option::Some(collate)) => {
+                                                column.with_collate(*collate)
+                                            }
+                                            _ => column,
+                                        }
+                                    })
                                     .collect::<::std::vec::Vec<_>>(),
                             );
                             if #sql_index_info::is_unique(index_info) {
@@ -346,6 +392,35 @@ fn generate_schema_fk_validation_asserts(
     }
 }
 
+/// Rejects, at compile time, tables whose non-deferrable foreign keys form a
+/// cycle: no creation order can satisfy them.
+fn generate_schema_fk_cycle_assert(
+    fields: &[(&syn::Ident, &syn::Type)],
+    struct_name: &syn::Ident,
+) -> TokenStream {
+    let schema_item_tables = core_paths::schema_item_tables();
+    let undeferred_fk_cycle = core_paths::undeferred_fk_cycle();
+    let const_format = crate::common::paths::const_format();
+    let field_types: Vec<_> = fields.iter().map(|(_, ty)| *ty).collect();
+    let prefix =
+        format!("#[derive(SQLiteSchema)] on `{struct_name}`: foreign keys through table `");
+
+    quote! {
+        const _: () = {
+            const CYCLE: ::core::option::Option<&str> = #undeferred_fk_cycle([
+                #(<#field_types as #schema_item_tables>::TABLE_REF_CONST,)*
+            ]);
+            const TABLE: &str = match CYCLE {
+                ::core::option::Option::Some(table) => table,
+                ::core::option::Option::None => "",
+            };
+            if CYCLE.is_some() {
+                ::core::panic!("{}", #const_format::concatcp!(#prefix, TABLE, "` form a cycle"));
+            }
+        };
+    }
+}
+
 fn generate_create_statements_method(fields: &[(&syn::Ident, &syn::Type)]) -> TokenStream {
     // Get paths for fully-qualified types
     let sql_schema = core_paths::sql_schema();
@@ -527,6 +602,78 @@ fn generate_create_statements_method(fields: &[(&syn::Ident, &syn::Type)]) -> To
     impl_tokens
 }
 
+fn generate_drop_statements_method(fields: &[(&syn::Ident, &syn::Type)]) -> TokenStream {
+    let sql_schema = core_paths::sql_schema();
+    let sql_table_info = core_paths::sql_table_info();
+    let sql_index_info = core_paths::sql_index_info();
+    let sqlite_value = sqlite_paths::sqlite_value();
+    let sqlite_schema_type = sqlite_paths::sqlite_schema_type();
+
+    #[allow(unused_variables)]
+    let field_types: Vec<_> = fields.iter().map(|(_, ty)| *ty).collect();
+
+    #[cfg(feature = "sqlite")]
+    let impl_tokens = quote! {
+        let quote_ident = |ident: &str| -> ::std::string::String {
+            ::std::format!("\"{}\"", ident.replace('"', "\"\""))
+        };
+        // Tables in an attached database, and their indexes, are dropped
+        // through its schema qualifier.
+        let qualified = |database: ::std::option::Option<&str>, ident: &str| -> ::std::string::String {
+            match database {
+                ::std::option::Option::Some(database) => {
+                    ::std::format!("{}.{}", quote_ident(database), quote_ident(ident))
+                }
+                ::std::option::Option::None => quote_ident(ident),
+            }
+        };
+        let mut sql_statements = ::std::vec::Vec::<::std::string::String>::new();
+        let mut index_statements = ::std::vec::Vec::<::std::string::String>::new();
+
+        // Views first (they depend on tables), then indexes
+        #(
+            match <#field_types as #sql_schema<'_, #sqlite_schema_type, #sqlite_value<'_>>>::TYPE {
+                #sqlite_schema_type::View(view_info) => {
+                    if !view_info.is_existing() {
+                        sql_statements.push(::std::format!(
+                            "DROP VIEW IF EXISTS {}",
+                            quote_ident(#sql_table_info::name(view_info))
+                        ));
+                    }
+                }
+                #sqlite_schema_type::Index(index_info) => {
+                    index_statements.push(::std::format!(
+                        "DROP INDEX IF EXISTS {}",
+                        qualified(
+                            #sql_index_info::table(index_info).schema,
+                            #sql_index_info::name(index_info),
+                        )
+                    ));
+                }
+                _ => {}
+            }
+        )*
+        sql_statements.extend(index_statements);
+
+        // Tables last, referencing tables before the tables they reference
+        for table_ref in drizzle::core::truncation_order(self.table_refs())? {
+            sql_statements.push(::std::format!(
+                "DROP TABLE IF EXISTS {}",
+                qualified(table_ref.schema, table_ref.name)
+            ));
+        }
+
+        sql_statements
+    };
+
+    #[cfg(not(feature = "sqlite"))]
+    let impl_tokens = quote! {
+        ::std::vec::Vec::new()
+    };
+
+    impl_tokens
+}
+
 fn generate_items_method(fields: &[(&syn::Ident, &syn::Type)]) -> TokenStream {
     let (item_refs, item_types): (Vec<_>, Vec<_>) = fields
         .iter()
@@ -81,9 +81,9 @@ pub fn generate_aliased_table(ctx: &MacroContext) -> syn::Result<TokenStream> {
         let to_sql_custom_impl = quote! {
             impl<'a, V: #sql_param + 'a> #to_sql<'a, V> for #aliased_field_type {
                 fn to_sql(&self) -> #sql<'a, V> {
-                    #sql::ident(self.alias)
+                    #sql::name(self.alias)
                         .push(#token::DOT)
-                        .append(#sql::ident({
+                        .append(#sql::name({
                             static ORIGINAL_FIELD: #original_field_type = #original_field_type::new();
                             #sql_column_info::name(&ORIGINAL_FIELD)
                         }))
@@ -1,9 +1,25 @@
+use drizzle_types::Casing;
 use syn::parse::Parser;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{ExprPath, Ident, Meta, Result, Token, parse::Parse};
 
-use crate::common::make_uppercase_path;
+use crate::common::{did_you_mean, make_uppercase_path};
+
+/// Names accepted inside `#[SQLiteTable(...)]`, used for "did you mean" hints.
+const TABLE_ATTRIBUTES: &[&str] = &[
+    "name",
+    "crate",
+    "strict",
+    "without_rowid",
+    "id_type",
+    "comment",
+    "casing",
+    "database",
+    "foreign_key",
+    "unique",
+    "check",
+];
 
 #[derive(Default)]
 pub struct TableAttributes {
@@ -14,6 +30,14 @@ pub struct TableAttributes {
     pub(crate) composite_foreign_keys: Vec<CompositeForeignKeyAttr>,
     pub(crate) unique_constraints: Vec<UniqueConstraintAttr>,
     pub(crate) check_constraints: Vec<CheckConstraintAttr>,
+    /// Newtype generated for the primary key from `ID_TYPE = UserId`.
+    pub(crate) id_type: Option<Ident>,
+    /// Table comment from `COMMENT = "..."`; overrides the struct doc comment.
+    pub(crate) comment: Option<String>,
+    /// Name casing from `CASING = "..."`; `snake_case` when unset.
+    pub(crate) casing: Option<Casing>,
+    /// Attached database from `DATABASE = "..."`; the main database when unset.
+    pub(crate) database: Option<String>,
     /// Original marker paths for IDE hover documentation
     pub(crate) marker_exprs: Vec<ExprPath>,
 }
@@ -318,6 +342,67 @@ impl Parse for TableAttributes {
                                     "crate requires a string literal, e.g. crate = \"my_drizzle\"",
                                 ));
                             }
+                            "COMMENT" => {
+                                if let syn::Expr::Lit(lit) = &nv.value
+                                    && let syn::Lit::Str(str_lit) = &lit.lit
+                                {
+                                    attrs.comment = Some(str_lit.value());
+                                    attrs
+                                        .marker_exprs
+                                        .push(make_uppercase_path(ident, "COMMENT"));
+                                    continue;
+                                }
+                                return Err(syn::Error::new(
+                                    nv.value.span(),
+                                    "COMMENT requires a string literal, e.g. COMMENT = \"Registered users\"",
+                                ));
+                            }
+                            "CASING" => {
+                                if let syn::Expr::Lit(lit) = &nv.value
+                                    && let syn::Lit::Str(str_lit) = &lit.lit
+                                {
+                                    let casing = str_lit
+                                        .value()
+                                        .parse()
+                                        .map_err(|e: String| syn::Error::new(str_lit.span(), e))?;
+                                    attrs.casing = Some(casing);
+                                    attrs
+                                        .marker_exprs
+                                        .push(make_uppercase_path(ident, "CASING"));
+                                    continue;
+                                }
+                                return Err(syn::Error::new(
+                                    nv.value.span(),
+                                    "CASING requires a string literal, e.g. CASING = \"camelCase\"",
+                                ));
+                            }
+                            "DATABASE" => {
+                                if let syn::Expr::Lit(lit) = &nv.value
+                                    && let syn::Lit::Str(str_lit) = &lit.lit
+                                {
+                                    attrs.database = Some(str_lit.value());
+                                    attrs
+                                        .marker_exprs
+                                        .push(make_uppercase_path(ident, "DATABASE"));
+                                    continue;
+                                }
+                                return Err(syn::Error::new(
+                                    nv.value.span(),
+                                    "DATABASE requires a string literal, e.g. DATABASE = \"archive\"",
+                                ));
+                            }
+                            "ID_TYPE" => {
+                                if let syn::Expr::Path(path) = &nv.value
+                                    && let Some(id_ident) = path.path.get_ident()
+                                {
+                                    attrs.id_type = Some(id_ident.clone());
+                                    continue;
+                                }
+                                return Err(syn::Error::new(
+                                    nv.value.span(),
+                                    "ID_TYPE requires a type name, e.g. ID_TYPE = UserId",
+                                ));
+                            }
                             _ => {}
                         }
                     }
@@ -375,17 +460,28 @@ impl Parse for TableAttributes {
                     }
                 }
             }
+            let name = meta
+                .path()
+                .get_ident()
+                .map(ToString::to_string)
+                .unwrap_or_default();
             return Err(syn::Error::new(
-                meta.span(),
-                "unrecognized table attribute.\n\
+                meta.path().span(),
+                format!(
+                    "unrecognized table attribute `{name}`{}\n\
                  Supported attributes:\n\
                  - name/NAME: Custom table name (e.g., #[SQLiteTable(name = \"custom_name\")])\n\
                  - strict/STRICT: Enable STRICT mode (e.g., #[SQLiteTable(strict)])\n\
                  - without_rowid/WITHOUT_ROWID: Use WITHOUT ROWID optimization\n\
+                 - id_type/ID_TYPE: Generate a primary-key newtype (e.g., #[SQLiteTable(ID_TYPE = UserId)])\n\
+                 - comment/COMMENT: Table comment, defaults to the struct doc comment (e.g., #[SQLiteTable(COMMENT = \"Users\")])\n\
+                 - database/DATABASE: Attached database holding the table (e.g., #[SQLiteTable(DATABASE = \"archive\")])\n\
                  - FOREIGN_KEY(...): Composite FK (e.g., #[SQLiteTable(FOREIGN_KEY(columns(a,b), references(Parent,id_a,id_b)))])\n\
                  - UNIQUE(...): Table-level unique constraint (e.g., #[SQLiteTable(UNIQUE(columns(a,b)))])\n\
                  - CHECK(...): Table-level check constraint (e.g., #[SQLiteTable(CHECK(expr = \"score >= 0\"))])\n\
                  See: https://sqlite.org/lang_createtable.html",
+                    did_you_mean(&name, TABLE_ATTRIBUTES)
+                ),
             ));
         }
         Ok(attrs)
@@ -43,7 +43,12 @@ pub(super) fn generate_custom_comparison_operand_impls(
     zst_ident: &Ident,
     sqlite_value: &TokenStream,
 ) -> TokenStream {
-    if !info.is_custom_type {
+    // `geo_types` shapes and `Vec<f32>` embeddings are an `Expr` themselves,
+    // so the core blanket impl already applies
+    if !info.is_custom_type
+        || (cfg!(feature = "geo-types") && crate::common::geo_shape_name(info.base_type).is_some())
+        || crate::common::type_is_vec_f32(info.base_type)
+    {
         return TokenStream::new();
     }
 
@@ -101,6 +106,9 @@ pub fn generate_column_definitions(ctx: &MacroContext<'_>) -> Result<(TokenStrea
     let column_ref = core_paths::column_ref();
     let _sqlite_column = sqlite_paths::sqlite_column();
     let sqlite_value = sqlite_paths::sqlite_value();
+    let sqlite_update_value = sqlite_paths::sqlite_update_value();
+    let value_wrapper = sqlite_paths::value_wrapper();
+    let to_sql = core_paths::to_sql();
     let sqlite_schema_type = sqlite_paths::sqlite_schema_type();
 
     for info in field_infos {
@@ -182,6 +190,24 @@ pub fn generate_column_definitions(ctx: &MacroContext<'_>) -> Result<(TokenStrea
             }
         };
 
+        // Lets the column be used as a SET value of a matching column, e.g. in
+        // `UPDATE ... FROM` where the joined table supplies the new values.
+        let update_inner_type =
+            super::models::convenience::update_value_inner_type(info, value_type);
+        let into_update_value_impl = quote! {
+            impl<'a> ::std::convert::From<#zst_ident>
+                for #sqlite_update_value<'a, #sqlite_value<'a>, #update_inner_type>
+            {
+                fn from(column: #zst_ident) -> Self {
+                    #sqlite_update_value::Value(
+                        #value_wrapper::<#sqlite_value<'a>, #update_inner_type>::new(
+                            #to_sql::to_sql(&column),
+                        ),
+                    )
+                }
+            }
+        };
+
         // Use generators for trait implementations
         let sql_schema_field_impl =
             generate_sql_schema_field(&zst_ident, &quote! {#name}, &col_type, &sql_def);
@@ -267,7 +293,7 @@ pub fn generate_column_definitions(ctx: &MacroContext<'_>) -> Result<(TokenStrea
             generate_arithmetic_ops(
                 &zst_ident,
                 sqlite_value.clone(),
-                sql_type_marker,
+                sql_type_marker.clone(),
                 sql_nullable_marker,
             )
         } else {
@@ -284,6 +310,7 @@ pub fn generate_column_definitions(ctx: &MacroContext<'_>) -> Result<(TokenStrea
             impl #column_of<#struct_ident> for #zst_ident {}
             impl #column_value_type for #zst_ident {
                 type ValueType = #value_type;
+                type SQLType = #sql_type_marker;
             }
         };
         let column_not_null_impl = if !info.is_nullable || info.is_primary() {
@@ -331,6 +358,7 @@ pub fn generate_column_definitions(ctx: &MacroContext<'_>) -> Result<(TokenStrea
                 type Columns = drizzle::core::Cons<#zst_ident, drizzle::core::Nil>;
             }
             #into_sqlite_value_impl
+            #into_update_value_impl
             #expr_impl
             #custom_comparison_operand_impls
             #arithmetic_ops
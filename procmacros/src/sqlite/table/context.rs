@@ -29,6 +29,8 @@ pub struct MacroContext<'a> {
     pub(crate) update_model_ident: Ident,
     /// Table attributes (strict, `without_rowid`, etc.)
     pub(crate) attrs: &'a TableAttributes,
+    /// Table comment from `COMMENT = "..."` or the struct doc comment.
+    pub(crate) table_comment: Option<String>,
     #[allow(dead_code)]
     pub(crate) is_composite_pk: bool,
 }
@@ -141,6 +143,12 @@ impl MacroContext<'_> {
         let column_name = &field.column_name;
         let sqlite_value = sqlite_paths::sqlite_value();
         let sqlite_update_value = sqlite_paths::sqlite_update_value();
+        // Unix-timestamp date-times re-tag their bound values (see `value_retag`)
+        let value = if let Some(retag) = field.value_retag() {
+            quote! { wrapper.value.clone().map_params(#retag) }
+        } else {
+            quote! { wrapper.value.clone() }
+        };
 
         quote! {
             match &self.#name {
@@ -149,7 +157,7 @@ impl MacroContext<'_> {
                     assignments.push((#column_name, SQL::param(#sqlite_value::Null)));
                 },
                 #sqlite_update_value::Value(wrapper) => {
-                    assignments.push((#column_name, wrapper.value.clone()));
+                    assignments.push((#column_name, #value));
                 },
             }
         }
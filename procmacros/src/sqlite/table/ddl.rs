@@ -91,9 +91,16 @@ fn build_create_table_pieces(ctx: &MacroContext) -> Vec<DdlPiece> {
     let without_rowid = ctx.attrs.without_rowid;
     let field_infos = ctx.field_infos;
 
+    // Attached tables are left out of migration snapshots, so the runtime
+    // DDL never needs this qualifier.
+    let qualifier = ctx
+        .attrs
+        .database
+        .as_ref()
+        .map_or_else(String::new, |database| format!("`{database}`."));
     let mut pieces: Vec<DdlPiece> = Vec::new();
     pieces.push(DdlPiece::Literal(format!(
-        "CREATE TABLE `{table_name}` (\n"
+        "CREATE TABLE {qualifier}`{table_name}` (\n"
     )));
 
     // Lines are built as `Vec<DdlPiece>` so the FK case can splice in a
@@ -441,6 +448,9 @@ pub fn generate_const_ddl(ctx: &MacroContext) -> TokenStream {
     if without_rowid {
         table_modifiers.push(quote! { .without_rowid() });
     }
+    if let Some(comment) = &ctx.table_comment {
+        table_modifiers.push(quote! { .comment(#comment) });
+    }
 
     // Generate column definitions
     let column_defs: Vec<TokenStream> = ctx
@@ -500,6 +510,9 @@ pub fn generate_const_ddl(ctx: &MacroContext) -> TokenStream {
             if let Some(ref collate_name) = field.collate {
                 modifiers.push(quote! { .collate(#collate_name) });
             }
+            if let Some(comment) = &field.comment {
+                modifiers.push(quote! { .comment(#comment) });
+            }
 
             quote! {
                 #column_def::new(#table_name, #column_name, #sql_type)
@@ -747,20 +760,14 @@ pub fn generate_const_ddl(ctx: &MacroContext) -> TokenStream {
         /// This is the single source of truth for SQL generation, building
         /// the statement from the const DDL entities above.
         pub fn create_table_sql() -> ::std::string::String {
-            let table = Self::DDL_TABLE.into_table();
-            let columns: ::std::vec::Vec<_> = Self::DDL_COLUMNS.iter().map(|c| c.into_column()).collect();
-            let pk = Self::DDL_PRIMARY_KEY.map(|p| p.into_primary_key());
-            let fks: ::std::vec::Vec<_> = Self::DDL_FOREIGN_KEYS.iter().map(|f| f.into_foreign_key()).collect();
-            let uniques: ::std::vec::Vec<_> = Self::DDL_UNIQUE_CONSTRAINTS.iter().map(|u| u.into_unique_constraint()).collect();
-            let checks: ::std::vec::Vec<_> = Self::DDL_CHECK_CONSTRAINTS.iter().map(|c| c.into_check_constraint()).collect();
-
-            #table_sql::new(&table)
-                .columns(&columns)
-                .primary_key(pk.as_ref())
-                .foreign_keys(&fks)
-                .unique_constraints(&uniques)
-                .check_constraints(&checks)
-                .create_table_sql()
+            #table_sql::create_table_sql_from_defs(
+                &Self::DDL_TABLE,
+                Self::DDL_COLUMNS,
+                Self::DDL_PRIMARY_KEY.as_ref(),
+                Self::DDL_FOREIGN_KEYS,
+                Self::DDL_UNIQUE_CONSTRAINTS,
+                Self::DDL_CHECK_CONSTRAINTS,
+            )
         }
 
         /// Returns the DDL SQL for creating this table.
@@ -794,13 +801,16 @@ mod tests {
             is_autoincrement: false,
             is_json: false,
             is_enum: false,
+            is_json_enum: false,
             is_uuid: false,
             is_custom_type: false,
+            is_id_newtype: false,
             column_type: SQLiteType::Text,
             foreign_key: None,
             relation_name: None,
             constraint: Constraint::None,
             collate: None,
+            comment: None,
             default_value: default,
             default_sql: None,
             default_fn: None,
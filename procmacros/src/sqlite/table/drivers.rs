@@ -39,8 +39,9 @@ pub trait DriverConfig {
     /// Generate accessor for real column by index
     fn real_accessor(idx: &TokenStream) -> TokenStream;
 
-    /// Wrap the conversion result for non-optional fields
-    fn wrap_required(inner: TokenStream, field_name: &syn::Ident) -> TokenStream;
+    /// Wrap the conversion result for non-optional fields, reporting the
+    /// column at `idx` when it can't be converted
+    fn wrap_required(inner: TokenStream, info: &FieldInfo, idx: &TokenStream) -> TokenStream;
 
     /// Wrap the conversion result for optional fields
     fn wrap_optional(inner: TokenStream, field_name: &syn::Ident) -> TokenStream;
@@ -78,8 +79,8 @@ pub fn generate_field_conversion_with_index<D: DriverConfig>(
         ));
     }
 
-    // Custom types (auto-detected enums): use DrizzleRowByIndex for driver-agnostic conversion
-    if info.is_custom_type {
+    // Custom types (auto-detected enums) and wide unsigned integers: use DrizzleRowByIndex for driver-agnostic conversion
+    if info.is_custom_type || info.is_wide_unsigned() {
         let base_type = info.base_type;
         if is_optional {
             return Ok(quote! {
@@ -116,7 +117,7 @@ pub fn generate_field_conversion_with_index<D: DriverConfig>(
     let wrapped = if is_optional {
         D::wrap_optional(converted, name)
     } else {
-        D::wrap_required(converted, name)
+        D::wrap_required(converted, info, idx_tokens)
     };
 
     Ok(wrapped)
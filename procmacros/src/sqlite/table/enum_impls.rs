@@ -19,6 +19,14 @@ pub fn generate_enum_impls_for_field(info: &FieldInfo) -> Result<TokenStream> {
         return Ok(quote! {});
     }
 
+    if info.is_json_enum && !cfg!(feature = "serde") {
+        return Err(syn::Error::new_spanned(
+            info.ident,
+            "The 'serde' feature must be enabled to use #[column(json_enum)].\n\
+             Add to Cargo.toml: drizzle = { version = \"*\", features = [\"serde\"] }",
+        ));
+    }
+
     // Generate driver-specific implementations
     // Note: rusqlite FromSql/ToSql are generated by the SQLiteEnum derive directly
     #[cfg(feature = "rusqlite")]
@@ -125,8 +125,8 @@ fn generate_field_from_row_impl(
         ));
     }
 
-    // Custom types (auto-detected enums): use DrizzleRowByIndex for unified conversion
-    if info.is_custom_type {
+    // Custom types (auto-detected enums) and wide unsigned integers: use DrizzleRowByIndex for unified conversion
+    if info.is_custom_type || info.is_wide_unsigned() {
         // idx is a usize expression here (before i32 cast for libsql)
         if is_optional {
             return Ok(quote! {
@@ -452,6 +452,25 @@ pub fn generate_enum_impls(info: &FieldInfo) -> Result<TokenStream> {
                 }
             }
         }),
+        SQLiteType::Text if info.is_json_enum => Ok(quote! {
+            impl From<#value_type> for drizzle::sqlite::libsql::Value {
+                fn from(value: #value_type) -> Self {
+                    match serde_json::to_string(&value) {
+                        Ok(json_data) => drizzle::sqlite::libsql::Value::Text(json_data),
+                        Err(_) => drizzle::sqlite::libsql::Value::Null,
+                    }
+                }
+            }
+
+            impl From<&#value_type> for drizzle::sqlite::libsql::Value {
+                fn from(value: &#value_type) -> Self {
+                    match serde_json::to_string(value) {
+                        Ok(json_data) => drizzle::sqlite::libsql::Value::Text(json_data),
+                        Err(_) => drizzle::sqlite::libsql::Value::Null,
+                    }
+                }
+            }
+        }),
         SQLiteType::Text => Ok(quote! {
             impl From<#value_type> for drizzle::sqlite::libsql::Value {
                 fn from(value: #value_type) -> Self {
@@ -23,7 +23,8 @@ pub mod libsql;
 
 use super::field::{FieldInfo, generate_table_meta_json};
 use crate::common::{
-    count_primary_keys, required_fields_pattern, struct_fields, table_name_from_attrs,
+    count_primary_keys, doc_comment_from_attrs, required_fields_pattern, struct_fields,
+    table_name_from_attrs,
 };
 use alias::generate_aliased_table;
 pub use attributes::TableAttributes;
@@ -37,8 +38,9 @@ use models::generate_model_definitions;
 use traits::generate_table_impls;
 use validation::{generate_default_validations, validate_strict_affinity};
 
+use drizzle_types::Casing;
 use proc_macro2::{Ident, TokenStream};
-use quote::{format_ident, quote};
+use quote::{ToTokens, format_ident, quote};
 use syn::{DeriveInput, Result};
 
 // ============================================================================
@@ -51,20 +53,41 @@ pub fn table_attr_macro(input: &DeriveInput, attrs: &TableAttributes) -> Result<
     // -------------------
     let struct_ident = &input.ident;
     let struct_vis = &input.vis;
-    let table_name = table_name_from_attrs(struct_ident, attrs.name.clone());
+    let casing = attrs.casing.unwrap_or(Casing::SnakeCase);
+    let table_name = table_name_from_attrs(struct_ident, attrs.name.clone(), casing);
+    let table_comment = attrs
+        .comment
+        .clone()
+        .or_else(|| doc_comment_from_attrs(&input.attrs));
 
     let fields = struct_fields(input, "SQLiteTable")?;
 
     let primary_key_count = count_primary_keys(fields, |field| {
-        Ok(FieldInfo::from_field(field, false)?.is_primary())
+        Ok(FieldInfo::from_field(field, false, casing)?.is_primary())
     })?;
     let is_composite_pk = primary_key_count > 1;
 
-    let field_infos = fields
+    let id_type: Option<syn::Type> = attrs
+        .id_type
+        .as_ref()
+        .map(|ident| syn::parse_quote!(#ident));
+
+    let mut field_infos = fields
         .iter()
-        .map(|field| FieldInfo::from_field(field, is_composite_pk))
+        .map(|field| FieldInfo::from_field(field, is_composite_pk, casing))
         .collect::<Result<Vec<_>>>()?;
 
+    let id_newtype = match (&attrs.id_type, &id_type) {
+        (Some(id_ident), Some(id_type)) => apply_id_type(
+            &mut field_infos,
+            id_ident,
+            id_type,
+            struct_ident,
+            struct_vis,
+        )?,
+        _ => TokenStream::new(),
+    };
+
     validate_strict_affinity(&field_infos, attrs.strict)?;
 
     // Calculate required fields pattern for const generic
@@ -95,6 +118,7 @@ pub fn table_attr_macro(input: &DeriveInput, attrs: &TableAttributes) -> Result<
         insert_model_ident: format_ident!("Insert{}", struct_ident),
         update_model_ident: format_ident!("Update{}", struct_ident),
         attrs,
+        table_comment,
         is_composite_pk,
     };
 
@@ -149,6 +173,8 @@ pub fn table_attr_macro(input: &DeriveInput, attrs: &TableAttributes) -> Result<
         // Table marker const for IDE hover documentation
         #table_marker_const
 
+        #id_newtype
+
         #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
          #struct_vis struct #struct_ident {
          #column_fields
@@ -286,6 +312,86 @@ pub fn generate_query_api_impls(ctx: &MacroContext) -> TokenStream {
     )
 }
 
+/// Apply `ID_TYPE = ...`: retype the single primary-key column as the
+/// generated newtype and emit the newtype with its `CustomSQLiteType` impl.
+///
+/// The column keeps its SQL type; only the Rust type seen by models,
+/// comparisons and `references = ...` checks changes.
+fn apply_id_type<'a>(
+    field_infos: &mut [FieldInfo<'a>],
+    id_ident: &Ident,
+    id_type: &'a syn::Type,
+    struct_ident: &Ident,
+    struct_vis: &syn::Visibility,
+) -> Result<TokenStream> {
+    use crate::sqlite::field::TypeCategory;
+
+    let mut primary_keys = field_infos.iter_mut().filter(|info| info.is_primary());
+    let (Some(pk), None) = (primary_keys.next(), primary_keys.next()) else {
+        return Err(syn::Error::new_spanned(
+            id_ident,
+            "ID_TYPE requires exactly one primary key column.\n\
+             Hint: mark a single column with #[column(primary)]",
+        ));
+    };
+
+    let is_copy = match pk.type_category() {
+        TypeCategory::Integer => true,
+        TypeCategory::String => false,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                pk.field_type,
+                format!(
+                    "ID_TYPE is only supported for integer or String primary keys, \
+                     but `{}` is `{}`",
+                    pk.ident,
+                    pk.base_type.to_token_stream()
+                ),
+            ));
+        }
+    };
+    if pk.is_nullable {
+        return Err(syn::Error::new_spanned(
+            pk.field_type,
+            "ID_TYPE cannot wrap a nullable primary key",
+        ));
+    }
+
+    let inner = pk.base_type;
+    let sql_type = pk.column_type.to_sql_type();
+    let sql_type_marker = crate::common::sqlite_column_type_to_sql_type(&pk.column_type);
+
+    pk.base_type = id_type;
+    pk.field_type = id_type;
+    pk.is_custom_type = true;
+    pk.is_id_newtype = true;
+    pk.select_type = None;
+    pk.update_type = None;
+
+    let newtype =
+        crate::common::generate_id_newtype(id_ident, struct_vis, inner, struct_ident, is_copy);
+    let custom_sqlite_type = crate::paths::sqlite::custom_sqlite_type();
+    let drizzle_error = crate::paths::core::drizzle_error();
+
+    Ok(quote! {
+        #newtype
+
+        impl #custom_sqlite_type for #id_ident {
+            type SQLType = #sql_type_marker;
+            const SQL_TYPE: &'static str = #sql_type;
+            type Repr = #inner;
+
+            fn to_repr(&self) -> #inner {
+                ::core::clone::Clone::clone(&self.0)
+            }
+
+            fn from_repr(repr: #inner) -> ::core::result::Result<Self, #drizzle_error> {
+                ::core::result::Result::Ok(Self(repr))
+            }
+        }
+    })
+}
+
 /// Generate a const that references the original table marker tokens from the attribute.
 ///
 /// This creates hidden const bindings that use the exact tokens from `#[SQLiteTable(STRICT)]`,
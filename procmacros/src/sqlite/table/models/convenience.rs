@@ -304,6 +304,15 @@ fn generate_partial_select_convenience_method(
 // Update Model Convenience Methods
 // =============================================================================
 
+/// Determines the inner type for a field's `SQLiteUpdateValue` wrapper.
+pub(crate) fn update_value_inner_type(field: &FieldInfo, base_type: &syn::Type) -> TokenStream {
+    match field.type_category() {
+        TypeCategory::String => quote!(::std::string::String),
+        TypeCategory::Blob => quote!(::std::vec::Vec<u8>),
+        _ => quote!(#base_type),
+    }
+}
+
 fn generate_update_convenience_method(
     field: &FieldInfo,
     base_type: &syn::Type,
@@ -315,14 +324,7 @@ fn generate_update_convenience_method(
     let non_empty_marker = core_paths::non_empty_marker();
     let sqlite_update_value = sqlite_paths::sqlite_update_value();
     let sqlite_value = sqlite_paths::sqlite_value();
-    let category = field.type_category();
-
-    // Determine the inner type for the UpdateValue wrapper
-    let inner_type = match category {
-        TypeCategory::String => quote!(::std::string::String),
-        TypeCategory::Blob => quote!(::std::vec::Vec<u8>),
-        _ => quote!(#base_type),
-    };
+    let inner_type = update_value_inner_type(field, base_type);
 
     // Generate field assignments: the target field gets the new value, others are moved
     let field_assignments: Vec<_> = ctx
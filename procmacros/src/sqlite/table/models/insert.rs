@@ -24,7 +24,6 @@ pub fn generate_insert_model(ctx: &MacroContext, required_fields_pattern: &[bool
     let _sql_table_info = core_paths::sql_table_info();
     let token = core_paths::token();
     let sqlite_value = sqlite_paths::sqlite_value();
-    let sqlite_insert_value = sqlite_paths::sqlite_insert_value();
     let _value_wrapper = sqlite_paths::value_wrapper();
     let _expression = sqlite_paths::expr();
 
@@ -43,6 +42,7 @@ pub fn generate_insert_model(ctx: &MacroContext, required_fields_pattern: &[bool
     let mut insert_default_fields = Vec::new();
     let mut insert_field_names = Vec::new();
     let mut insert_field_indices = Vec::new();
+    let mut insert_value_pushes = Vec::new();
     let mut insert_convenience_methods = Vec::new();
     let mut required_constructor_params = Vec::new();
     let mut required_constructor_assignments = Vec::new();
@@ -56,6 +56,11 @@ pub fn generate_insert_model(ctx: &MacroContext, required_fields_pattern: &[bool
         insert_default_fields.push(MacroContext::get_insert_default_value(info));
         insert_field_names.push(name);
         insert_field_indices.push(quote! { #field_index });
+        insert_value_pushes.push(if let Some(retag) = info.value_retag() {
+            quote! { self.#name.push_value_as(&mut sql_parts, #retag); }
+        } else {
+            quote! { self.#name.push_value(&mut sql_parts); }
+        });
         if should_generate_insert_setter(info) {
             insert_convenience_methods.push(generate_convenience_method(
                 info,
@@ -130,12 +135,7 @@ pub fn generate_insert_model(ctx: &MacroContext, required_fields_pattern: &[bool
                 let mut result_columns = ::std::vec::Vec::new();
 
                 #(
-                    match &self.#insert_field_names {
-                        #sqlite_insert_value::Omit => {}
-                        _ => {
-                            result_columns.push(all_columns[#insert_field_indices]);
-                        }
-                    }
+                    self.#insert_field_names.push_column(&mut result_columns, all_columns[#insert_field_indices]);
                 )*
 
                 ::std::borrow::Cow::Owned(result_columns)
@@ -144,17 +144,7 @@ pub fn generate_insert_model(ctx: &MacroContext, required_fields_pattern: &[bool
             fn values(&self) -> #sql<'a, #sqlite_value<'a>> {
                 let mut sql_parts = ::std::vec::Vec::new();
 
-                #(
-                    match &self.#insert_field_names {
-                        #sqlite_insert_value::Omit => {}
-                        #sqlite_insert_value::Null => {
-                            sql_parts.push(#sql::param(#sqlite_value::Null));
-                        }
-                        #sqlite_insert_value::Value(wrapper) => {
-                            sql_parts.push(wrapper.value.clone());
-                        }
-                    }
-                )*
+                #(#insert_value_pushes)*
 
                 #sql::join(sql_parts, #token::COMMA)
             }
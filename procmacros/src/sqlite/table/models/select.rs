@@ -39,12 +39,18 @@ pub fn generate_select_model(ctx: &MacroContext) -> TokenStream {
             ctx,
         ));
     }
-    let select_model_derive = if field_infos.iter().any(|info| info.is_custom_type) {
+    let select_model_derive = if field_infos
+        .iter()
+        .any(|info| info.is_custom_type && !info.is_id_newtype)
+    {
         quote! {}
     } else {
         quote! { #[derive(Debug, Clone, PartialEq, Default)] }
     };
-    let partial_select_model_derive = if field_infos.iter().any(|info| info.is_custom_type) {
+    let partial_select_model_derive = if field_infos
+        .iter()
+        .any(|info| info.is_custom_type && !info.is_id_newtype)
+    {
         quote! { #[derive(Default)] }
     } else {
         quote! { #[derive(Debug, Clone, PartialEq, Default)] }
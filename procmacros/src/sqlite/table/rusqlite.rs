@@ -24,7 +24,7 @@ pub fn generate_rusqlite_impls(ctx: &MacroContext) -> Result<TokenStream> {
     let row_column_list = paths::core::row_column_list();
     let type_set_nil = paths::core::type_set_nil();
     let type_set_cons = paths::core::type_set_cons();
-    let _from_sqlite_value = paths::sqlite::from_sqlite_value();
+    let drizzle_row = paths::sqlite::drizzle_row();
     let MacroContext {
         field_infos,
         select_model_ident,
@@ -129,17 +129,11 @@ pub fn generate_rusqlite_impls(ctx: &MacroContext) -> Result<TokenStream> {
                 }
             } else if is_select_optional {
                 quote! {
-                    {
-                        use drizzle::sqlite::traits::DrizzleRowByIndex;
-                        DrizzleRowByIndex::get_column::<Option<#base_type>>(row, #idx_expr)?
-                    }
+                    #drizzle_row::get_column::<Option<#base_type>>(row, #idx_expr)?
                 }
             } else {
                 quote! {
-                    {
-                        use drizzle::sqlite::traits::DrizzleRowByIndex;
-                        DrizzleRowByIndex::get_column::<#base_type>(row, #idx_expr)?
-                    }
+                    #drizzle_row::get_column::<#base_type>(row, #idx_expr)?
                 }
             };
 
@@ -186,9 +180,7 @@ pub fn generate_rusqlite_impls(ctx: &MacroContext) -> Result<TokenStream> {
 
 /// Generate field conversion for `SelectModel`
 fn generate_field_from_row(idx: usize, info: &FieldInfo) -> Result<TokenStream> {
-    let from_sqlite_value = paths::sqlite::from_sqlite_value();
     let name = info.ident;
-    let base_type = info.base_type;
 
     // JSON fields use rusqlite's FromSql directly
     if info.type_category() == TypeCategory::Json {
@@ -291,53 +283,35 @@ fn generate_field_from_row(idx: usize, info: &FieldInfo) -> Result<TokenStream>
                     })
                 }
             }
-            SQLiteType::Numeric | SQLiteType::Any => {
-                if info.is_nullable {
-                    Ok(quote! {
-                        #name: {
-                            let value_ref = row.get_ref(#idx)?;
-                            match value_ref {
-                                drizzle::sqlite::rusqlite::types::ValueRef::Null => None,
-                                _ => Some(<#base_type as #from_sqlite_value>::from_value_ref(value_ref)?),
-                            }
-                        },
-                    })
-                } else {
-                    Ok(quote! {
-                        #name: {
-                            let value_ref = row.get_ref(#idx)?;
-                            <#base_type as #from_sqlite_value>::from_value_ref(value_ref)?
-                        },
-                    })
-                }
-            }
+            SQLiteType::Numeric | SQLiteType::Any => Ok(generic_field_from_row(idx, info)),
         };
     }
 
-    // All other types use FromSQLiteValue::from_value_ref
+    // All other types decode through the shared `DrizzleRowByIndex` impl
+    Ok(generic_field_from_row(idx, info))
+}
+
+/// Decode a field through `DrizzleRowByIndex::get_column`, which maps SQL NULL
+/// to `None` for nullable fields via `FromSQLiteValue for Option<T>`.
+fn generic_field_from_row(idx: usize, info: &FieldInfo) -> TokenStream {
+    let drizzle_row = paths::sqlite::drizzle_row();
+    let name = info.ident;
+    let base_type = info.base_type;
+
     if info.is_nullable {
-        Ok(quote! {
-            #name: {
-                let value_ref = row.get_ref(#idx)?;
-                match value_ref {
-                    drizzle::sqlite::rusqlite::types::ValueRef::Null => None,
-                    _ => Some(<#base_type as #from_sqlite_value>::from_value_ref(value_ref)?),
-                }
-            },
-        })
+        quote! {
+            #name: #drizzle_row::get_column::<::std::option::Option<#base_type>>(row, #idx)?,
+        }
     } else {
-        Ok(quote! {
-            #name: {
-                let value_ref = row.get_ref(#idx)?;
-                <#base_type as #from_sqlite_value>::from_value_ref(value_ref)?
-            },
-        })
+        quote! {
+            #name: #drizzle_row::get_column::<#base_type>(row, #idx)?,
+        }
     }
 }
 
 /// Generate field conversion for `PartialSelectModel` (all fields are Option<T>)
 fn generate_partial_field_from_row(idx: usize, info: &FieldInfo) -> TokenStream {
-    let from_sqlite_value = paths::sqlite::from_sqlite_value();
+    let drizzle_row = paths::sqlite::drizzle_row();
     let name = info.ident;
     let base_type = info.base_type;
 
@@ -348,15 +322,12 @@ fn generate_partial_field_from_row(idx: usize, info: &FieldInfo) -> TokenStream
         };
     }
 
-    // Partial models have all fields as Option<T>
+    // Partial models have all fields as Option<T>; missing or undecodable
+    // columns become `None`.
     quote! {
-        #name: {
-                    let value_ref = row.get_ref(#idx).unwrap_or(drizzle::sqlite::rusqlite::types::ValueRef::Null);
-                    match value_ref {
-                        drizzle::sqlite::rusqlite::types::ValueRef::Null => None,
-                _ => <#base_type as #from_sqlite_value>::from_value_ref(value_ref).ok(),
-            }
-        },
+        #name: #drizzle_row::get_column::<::std::option::Option<#base_type>>(row, #idx)
+            .ok()
+            .flatten(),
     }
 }
 
@@ -48,8 +48,16 @@ pub fn generate_table_impls(
     // Column names for TableRef
     let column_names: Vec<&String> = ctx.field_infos.iter().map(|f| &f.column_name).collect();
 
-    let to_sql_body = quote! {
-        #sql::table(#table_ref::sql(Self::TABLE_NAME, &[#(#column_names),*]))
+    // `TableRef::sql` carries no schema, so attached tables render from the
+    // full `TABLE_REF` to pick up their database qualifier.
+    let to_sql_body = if ctx.attrs.database.is_some() {
+        quote! {
+            #sql::table(<Self as drizzle::core::DrizzleTable>::TABLE_REF)
+        }
+    } else {
+        quote! {
+            #sql::table(#table_ref::sql(Self::TABLE_NAME, &[#(#column_names),*]))
+        }
     };
 
     let sql_schema_impl = generate_sql_schema(
@@ -149,7 +157,12 @@ pub fn generate_table_impls(
     let table_ref_qualified_name_expr = quote! {
         <Self as #sql_schema<'_, #sqlite_schema_type, #sqlite_value<'_>>>::NAME
     };
-    let table_ref_schema_expr = quote! { ::core::option::Option::None };
+    // An attached database is SQLite's schema; it qualifies the table in
+    // rendered SQL but not in `qualified_name`, which FK ordering keys on.
+    let table_ref_schema_expr = ctx.attrs.database.as_ref().map_or_else(
+        || quote! { ::core::option::Option::None },
+        |database| quote! { ::core::option::Option::Some(#database) },
+    );
     let table_ref_columns: Vec<ColumnRefInput> = ctx
         .field_infos
         .iter()
@@ -174,6 +187,10 @@ pub fn generate_table_impls(
                 || quote! { ::core::option::Option::None },
                 |collate| quote! { ::core::option::Option::Some(#collate) },
             );
+            let comment = f.comment.as_ref().map_or_else(
+                || quote! { ::core::option::Option::None },
+                |comment| quote! { ::core::option::Option::Some(#comment) },
+            );
             let flags = crate::common::ref_gen::ColumnRefFlags::new()
                 .with(
                     crate::common::ref_gen::ColumnRefFlags::NOT_NULL,
@@ -202,6 +219,7 @@ pub fn generate_table_impls(
                         generated_expression: #generated_expression,
                         generated_stored: #generated_stored,
                         collate: #collate,
+                        comment: #comment,
                     }
                 },
             }
@@ -283,6 +301,7 @@ pub fn generate_table_impls(
                 kind: quote! { drizzle::core::SQLConstraintKind::Check },
                 columns: vec![field.column_name.clone()],
                 check_expression: Some(expr.clone()),
+                exclusion: None,
                 deferrable: false,
                 initially_deferred: false,
             })
@@ -295,6 +314,7 @@ pub fn generate_table_impls(
             kind: quote! { drizzle::core::SQLConstraintKind::Check },
             columns: Vec::new(),
             check_expression: Some(check.expr.clone()),
+            exclusion: None,
             deferrable: false,
             initially_deferred: false,
         });
@@ -320,12 +340,22 @@ pub fn generate_table_impls(
             kind: quote! { drizzle::core::SQLConstraintKind::Unique },
             columns,
             check_expression: None,
+            exclusion: None,
             deferrable: false,
             initially_deferred: false,
         });
     }
-    let table_ref_dialect =
-        quote! { #table_dialect::SQLite { without_rowid: #without_rowid, strict: #strict } };
+    let table_comment = ctx.table_comment.as_ref().map_or_else(
+        || quote! { ::core::option::Option::None },
+        |comment| quote! { ::core::option::Option::Some(#comment) },
+    );
+    let table_ref_dialect = quote! {
+        #table_dialect::SQLite {
+            without_rowid: #without_rowid,
+            strict: #strict,
+            comment: #table_comment,
+        }
+    };
     let dep_names_expr = quote! { &[#(#dependency_name_exprs),*] };
     let table_ref_const = ref_gen::generate_table_ref_const(
         &table_ref_name_expr,
@@ -348,7 +378,7 @@ pub fn generate_table_impls(
         qualified_name: quote! {
             <Self as #sql_schema<'_, #sqlite_schema_type, #sqlite_value<'_>>>::NAME
         },
-        schema: quote! { ::std::option::Option::None },
+        schema: table_ref_schema_expr.clone(),
         dependency_names: quote! { &[#(#dependency_name_exprs),*] },
         table_ref_const,
     });
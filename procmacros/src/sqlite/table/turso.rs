@@ -40,12 +40,28 @@ impl DriverConfig for TursoDriver {
         quote!(row.get_value(#idx)?.as_real())
     }
 
-    fn wrap_required(inner: TokenStream, name: &syn::Ident) -> TokenStream {
+    fn wrap_required(inner: TokenStream, info: &FieldInfo, idx: &TokenStream) -> TokenStream {
         let drizzle_error = paths::core::drizzle_error();
+        let name = info.ident;
+        let base_type = info.base_type;
+        let column_name = &info.column_name;
         let error_msg = errors::conversion::required_field(&name.to_string());
         quote! {
-            #name: #inner
-                .ok_or_else(|| #drizzle_error::ConversionError(#error_msg.to_string().into()))?,
+            #name: #inner.ok_or_else(|| {
+                let actual = row.get_value(#idx).ok().map(|value| match value {
+                    drizzle::sqlite::turso::Value::Null => "NULL",
+                    drizzle::sqlite::turso::Value::Integer(_) => "INTEGER",
+                    drizzle::sqlite::turso::Value::Real(_) => "REAL",
+                    drizzle::sqlite::turso::Value::Text(_) => "TEXT",
+                    drizzle::sqlite::turso::Value::Blob(_) => "BLOB",
+                });
+                #drizzle_error::ConversionError(#error_msg.into()).decode_column(
+                    #idx,
+                    Some(#column_name),
+                    &drizzle::error::type_name::<#base_type>(),
+                    actual,
+                )
+            })?,
         }
     }
 
@@ -247,6 +263,23 @@ pub fn generate_enum_impls(info: &FieldInfo) -> Result<TokenStream> {
                 }
             }
         }),
+        SQLiteType::Text if info.is_json_enum => Ok(quote! {
+            impl drizzle::sqlite::turso::IntoValue for #value_type {
+                fn into_value(self) -> drizzle::sqlite::turso::Result<drizzle::sqlite::turso::Value> {
+                    let json_data = serde_json::to_string(&self)
+                        .map_err(|e| drizzle::sqlite::turso::Error::ToSqlConversionFailure(Box::new(e)))?;
+                    Ok(drizzle::sqlite::turso::Value::Text(json_data))
+                }
+            }
+
+            impl drizzle::sqlite::turso::IntoValue for &#value_type {
+                fn into_value(self) -> drizzle::sqlite::turso::Result<drizzle::sqlite::turso::Value> {
+                    let json_data = serde_json::to_string(self)
+                        .map_err(|e| drizzle::sqlite::turso::Error::ToSqlConversionFailure(Box::new(e)))?;
+                    Ok(drizzle::sqlite::turso::Value::Text(json_data))
+                }
+            }
+        }),
         SQLiteType::Text => Ok(quote! {
             impl drizzle::sqlite::turso::IntoValue for #value_type {
                 fn into_value(self) -> drizzle::sqlite::turso::Result<drizzle::sqlite::turso::Value> {
@@ -21,6 +21,7 @@ use crate::sqlite::table::turso;
 use crate::sqlite::table::{
     alias, attributes::TableAttributes, column_definitions, context::MacroContext, models,
 };
+use drizzle_types::Casing;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::spanned::Spanned;
@@ -140,16 +141,16 @@ pub fn view_attr_macro(input: &DeriveInput, attrs: &ViewAttributes) -> Result<To
     let fields = struct_fields(input, "SQLiteView")?;
 
     let primary_key_count = count_primary_keys(fields, |field| {
-        Ok(FieldInfo::from_field(field, false)?.is_primary())
+        Ok(FieldInfo::from_field(field, false, Casing::SnakeCase)?.is_primary())
     })?;
     let is_composite_pk = primary_key_count > 1;
 
     let field_infos = fields
         .iter()
-        .map(|field| FieldInfo::from_field(field, is_composite_pk))
+        .map(|field| FieldInfo::from_field(field, is_composite_pk, Casing::SnakeCase))
         .collect::<Result<Vec<_>>>()?;
 
-    let view_name = table_name_from_attrs(struct_ident, attrs.name.clone());
+    let view_name = table_name_from_attrs(struct_ident, attrs.name.clone(), Casing::SnakeCase);
 
     if attrs.definition.is_none() && !attrs.existing {
         return Err(syn::Error::new(
@@ -167,6 +168,10 @@ pub fn view_attr_macro(input: &DeriveInput, attrs: &ViewAttributes) -> Result<To
         composite_foreign_keys: Vec::new(),
         unique_constraints: Vec::new(),
         check_constraints: Vec::new(),
+        id_type: None,
+        comment: None,
+        casing: None,
+        database: None,
         marker_exprs: Vec::new(),
     };
 
@@ -190,6 +195,7 @@ pub fn view_attr_macro(input: &DeriveInput, attrs: &ViewAttributes) -> Result<To
         insert_model_ident: format_ident!("Insert{}", struct_ident),
         update_model_ident: format_ident!("Update{}", struct_ident),
         attrs: &table_attrs,
+        table_comment: None,
         is_composite_pk,
     };
 
@@ -333,6 +339,7 @@ pub fn view_attr_macro(input: &DeriveInput, attrs: &ViewAttributes) -> Result<To
                         generated_expression: ::core::option::Option::None,
                         generated_stored: false,
                         collate: ::core::option::Option::None,
+                        comment: ::core::option::Option::None,
                     },
                 }
             }
@@ -350,7 +357,11 @@ pub fn view_attr_macro(input: &DeriveInput, attrs: &ViewAttributes) -> Result<To
             foreign_keys: &[],
             constraints: &[],
             dependency_names: &[],
-            dialect: #table_dialect_path::SQLite { without_rowid: false, strict: false },
+            dialect: #table_dialect_path::SQLite {
+                without_rowid: false,
+                strict: false,
+                comment: ::core::option::Option::None,
+            },
         };
     };
 
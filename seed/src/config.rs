@@ -1,9 +1,12 @@
 //! Seeder configuration with type-safe builder API.
 
-use crate::generator::{Generator, GeneratorKind};
+use crate::distribution::Distribution;
+use crate::generator::pool::{SequenceGen, ValuesGen};
+use crate::generator::{Generator, GeneratorKind, SeedValue};
+use crate::refine::{Refiner, SeedRow};
+use drizzle_core::{ColumnRef, SQLSchemaImpl, SQLTableInfo, SchemaHasTable, TableRef};
 #[cfg(any(feature = "sqlite", feature = "postgres"))]
 use drizzle_core::{Relation, SQLColumn, SQLColumnInfo};
-use drizzle_core::{SQLSchemaImpl, SQLTableInfo, SchemaHasTable, TableRef};
 use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
 use std::sync::Arc;
@@ -26,6 +29,21 @@ use drizzle_postgres::traits::PostgresTable;
 #[cfg(feature = "postgres")]
 use drizzle_postgres::values::PostgresValue;
 
+/// The tables a [`SeedConfig`] seeds.
+///
+/// Implemented for every derived schema, and for
+/// [`SnapshotSchema`](crate::SnapshotSchema) with the `snapshot` feature.
+pub trait SeedSchema {
+    /// Every table in the schema.
+    fn seed_tables(&self) -> &[&'static TableRef];
+}
+
+impl<S: SQLSchemaImpl> SeedSchema for S {
+    fn seed_tables(&self) -> &[&'static TableRef] {
+        self.table_refs()
+    }
+}
+
 /// Configuration for seeding a schema.
 pub struct SeedConfig<'a, D, S> {
     /// Source schema.
@@ -43,15 +61,21 @@ pub struct SeedConfig<'a, D, S> {
     /// Per-column generator kind overrides.
     pub(crate) column_kinds: HashMap<(&'static str, &'static str), GeneratorKind>,
     /// Relation cardinality overrides. Key: (`parent_table`, `child_table`).
-    pub(crate) relation_counts: HashMap<(&'static str, &'static str), usize>,
+    pub(crate) relation_counts: HashMap<(&'static str, &'static str), Distribution>,
     /// Optional override for maximum parameters per INSERT statement batch.
     pub(crate) max_params_per_batch: Option<usize>,
+    /// Share of NULLs in nullable columns without their own ratio.
+    pub(crate) default_null_ratio: f64,
+    /// Per-column NULL ratio overrides.
+    pub(crate) null_ratios: HashMap<(&'static str, &'static str), f64>,
+    /// Per-table row refinements, run in the order they were added.
+    pub(crate) refiners: HashMap<&'static str, Vec<Refiner>>,
     _dialect: PhantomData<D>,
     _schema: PhantomData<&'a S>,
 }
 
 impl<'a, D, S> SeedConfig<'a, D, S> {
-    fn with_defaults(schema: &'a S) -> Self {
+    pub(crate) fn with_defaults(schema: &'a S) -> Self {
         Self {
             schema,
             skipped_tables: HashSet::new(),
@@ -62,6 +86,9 @@ impl<'a, D, S> SeedConfig<'a, D, S> {
             column_kinds: HashMap::new(),
             relation_counts: HashMap::new(),
             max_params_per_batch: None,
+            default_null_ratio: 0.0,
+            null_ratios: HashMap::new(),
+            refiners: HashMap::new(),
             _dialect: PhantomData,
             _schema: PhantomData,
         }
@@ -94,6 +121,33 @@ impl<'a, D, S> SeedConfig<'a, D, S> {
         self
     }
 
+    /// Set the share of rows (`0.0..=1.0`) that get NULL in every nullable
+    /// column without its own [`null_ratio`](Self::null_ratio). Defaults to
+    /// `0.0`, so nullable columns are always filled.
+    ///
+    /// Whether a row is NULL is drawn from its own RNG stream, so changing a
+    /// ratio doesn't change the values of the rows that stay non-NULL.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is outside `0.0..=1.0`.
+    #[must_use]
+    pub fn default_null_ratio(mut self, ratio: f64) -> Self {
+        assert_null_ratio(ratio);
+        self.default_null_ratio = ratio;
+        self
+    }
+
+    pub(crate) fn null_ratio_for(&self, column: &ColumnRef) -> f64 {
+        if column.not_null() || column.primary_key() {
+            return 0.0;
+        }
+        self.null_ratios
+            .get(&(column.table, column.name))
+            .copied()
+            .unwrap_or(self.default_null_ratio)
+    }
+
     pub(crate) fn count_for(&self, table: &str) -> usize {
         self.table_counts
             .get(table)
@@ -104,16 +158,131 @@ impl<'a, D, S> SeedConfig<'a, D, S> {
 
 impl<D, S> SeedConfig<'_, D, S>
 where
-    S: SQLSchemaImpl,
+    S: SeedSchema,
 {
     pub(crate) fn active_tables(&self) -> Vec<&'static TableRef> {
         self.schema
-            .table_refs()
+            .seed_tables()
             .iter()
             .copied()
             .filter(|t| !self.skipped_tables.contains(t.name))
             .collect()
     }
+
+    /// Skip a table from seeding, by name.
+    ///
+    /// The `*_by_name` methods are for schemas only known at runtime, such as
+    /// a [`SnapshotSchema`](crate::SnapshotSchema); derived schemas should use
+    /// the typed methods.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schema has no table called `table`.
+    #[must_use]
+    pub fn skip_by_name(mut self, table: &str) -> Self {
+        let table = self.table_named(table).name;
+        self.skipped_tables.insert(table);
+        self
+    }
+
+    /// Set the row count for a table, by name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schema has no table called `table`.
+    #[must_use]
+    pub fn count_by_name(mut self, table: &str, count: usize) -> Self {
+        let table = self.table_named(table).name;
+        self.table_counts.insert(table, count);
+        self
+    }
+
+    /// Set how many child rows to generate per parent row, by table name.
+    ///
+    /// `count` is a fixed count, a `min..=max` range, or any [`Distribution`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schema is missing either table, or if `count` is an
+    /// empty range or a Poisson distribution with a negative mean.
+    #[must_use]
+    pub fn relation_by_name(
+        mut self,
+        parent: &str,
+        child: &str,
+        count: impl Into<Distribution>,
+    ) -> Self {
+        let key = (self.table_named(parent).name, self.table_named(child).name);
+        self.insert_relation(key, count.into());
+        self
+    }
+
+    fn insert_relation(&mut self, key: (&'static str, &'static str), count: Distribution) {
+        count.validate();
+        self.relation_counts.insert(key, count);
+    }
+
+    /// Override the generator kind for a column, by name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schema has no such column.
+    #[must_use]
+    pub fn kind_by_name(mut self, table: &str, column: &str, kind: GeneratorKind) -> Self {
+        let key = self.column_named(table, column);
+        self.column_kinds.insert(key, kind);
+        self
+    }
+
+    /// Set the share of NULLs in a nullable column, by name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schema has no such column or `ratio` is outside
+    /// `0.0..=1.0`.
+    #[must_use]
+    pub fn null_ratio_by_name(mut self, table: &str, column: &str, ratio: f64) -> Self {
+        assert_null_ratio(ratio);
+        let key = self.column_named(table, column);
+        self.null_ratios.insert(key, ratio);
+        self
+    }
+
+    /// Override the generator for a column, by name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schema has no such column.
+    #[must_use]
+    pub fn generator_by_name(
+        mut self,
+        table: &str,
+        column: &str,
+        g: impl Generator + 'static,
+    ) -> Self {
+        let key = self.column_named(table, column);
+        self.column_generators.insert(key, Arc::new(g));
+        self
+    }
+
+    fn table_named(&self, name: &str) -> &'static TableRef {
+        self.schema
+            .seed_tables()
+            .iter()
+            .copied()
+            .find(|t| t.name == name)
+            .unwrap_or_else(|| panic!("unknown table `{name}`"))
+    }
+
+    fn column_named(&self, table: &str, column: &str) -> (&'static str, &'static str) {
+        let table_ref = self.table_named(table);
+        let column_ref = table_ref
+            .columns
+            .iter()
+            .find(|c| c.name == column)
+            .unwrap_or_else(|| panic!("unknown column `{table}.{column}`"));
+        (table_ref.name, column_ref.name)
+    }
 }
 
 impl<D, S> SeedConfig<'_, D, S> {
@@ -129,6 +298,13 @@ impl<D, S> SeedConfig<'_, D, S> {
     }
 }
 
+fn assert_null_ratio(ratio: f64) {
+    assert!(
+        (0.0..=1.0).contains(&ratio),
+        "null ratio must be within 0.0..=1.0, got {ratio}"
+    );
+}
+
 #[cfg(feature = "sqlite")]
 impl<'a> SeedConfig<'a, Sqlite, ()> {
     /// Create a `SQLite` seeder config from a derived schema.
@@ -157,15 +333,23 @@ where
     }
 
     /// Set how many child rows to generate per parent row for a relation.
+    ///
+    /// `count` is a fixed count, a `min..=max` range, or any [`Distribution`].
+    /// Without an explicit [`count`](Self::count), the child table gets the
+    /// sum of the sampled counts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is an empty range or a Poisson distribution with a
+    /// negative mean.
     #[must_use]
-    pub fn relation<P, C>(mut self, parent: &P, child: &C, count: usize) -> Self
+    pub fn relation<P, C>(mut self, parent: &P, child: &C, count: impl Into<Distribution>) -> Self
     where
         P: SQLTableInfo + SQLiteTable<'static>,
         C: SQLTableInfo + SQLiteTable<'static> + Relation<P>,
         S: SchemaHasTable<P> + SchemaHasTable<C>,
     {
-        self.relation_counts
-            .insert((parent.name(), child.name()), count);
+        self.insert_relation((parent.name(), child.name()), count.into());
         self
     }
 
@@ -193,11 +377,121 @@ where
         self
     }
 
+    /// Fill a column with values picked at random from `values`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    #[must_use]
+    pub fn values<C, V>(self, column: &C, values: Vec<V>) -> Self
+    where
+        C: SQLColumnInfo + SQLiteColumn<'static>,
+        S: SchemaHasTable<<C as SQLColumn<'static, SQLiteValue<'static>>>::Table>,
+        V: Into<SeedValue>,
+    {
+        self.generator(column, ValuesGen::sample(values))
+    }
+
+    /// Fill a column with `values` in order, repeating them as needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    #[must_use]
+    pub fn cycle<C, V>(self, column: &C, values: Vec<V>) -> Self
+    where
+        C: SQLColumnInfo + SQLiteColumn<'static>,
+        S: SchemaHasTable<<C as SQLColumn<'static, SQLiteValue<'static>>>::Table>,
+        V: Into<SeedValue>,
+    {
+        self.generator(column, ValuesGen::cycle(values))
+    }
+
+    /// Fill a column with `start`, `start + step`, `start + 2 * step`, ...
+    #[must_use]
+    pub fn sequence<C>(self, column: &C, start: i64, step: i64) -> Self
+    where
+        C: SQLColumnInfo + SQLiteColumn<'static>,
+        S: SchemaHasTable<<C as SQLColumn<'static, SQLiteValue<'static>>>::Table>,
+    {
+        self.generator(column, SequenceGen { start, step })
+    }
+
+    /// Set the share of rows (`0.0..=1.0`) that get NULL in a column.
+    /// Ignored for `NOT NULL` and primary key columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is outside `0.0..=1.0`.
+    #[must_use]
+    pub fn null_ratio<C>(mut self, column: &C, ratio: f64) -> Self
+    where
+        C: SQLColumnInfo + SQLiteColumn<'static>,
+        S: SchemaHasTable<<C as SQLColumn<'static, SQLiteValue<'static>>>::Table>,
+    {
+        assert_null_ratio(ratio);
+        let key = (column.table().name(), column.name());
+        self.null_ratios.insert(key, ratio);
+        self
+    }
+
+    /// Post-process every generated row of `table`, after generators,
+    /// relations and NULL ratios, for values that depend on other columns of
+    /// the same row. Refinements run in the order they were added.
+    ///
+    /// ```rust
+    /// # let _ = r####"
+    /// SeedConfig::sqlite(&schema).refine(&schema.users, |row| {
+    ///     let name = row.get(&Users::name);
+    ///     row.set(&Users::email, format!("{name}@test.local"));
+    /// });
+    /// # "####;
+    /// ```
+    #[must_use]
+    pub fn refine<T>(
+        mut self,
+        table: &T,
+        refine: impl Fn(&mut SeedRow<'_, Sqlite, T>) + Send + Sync + 'static,
+    ) -> Self
+    where
+        T: SQLTableInfo + SQLiteTable<'static> + 'static,
+        S: SchemaHasTable<T>,
+    {
+        let refiner: Refiner = Arc::new(move |columns, values, index| {
+            refine(&mut SeedRow::new(columns, values, index));
+        });
+        self.refiners.entry(table.name()).or_default().push(refiner);
+        self
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<S> SeedConfig<'_, Sqlite, S>
+where
+    S: SeedSchema,
+{
     /// Generate INSERT statements for the active table set.
     #[must_use]
     pub fn generate(&self) -> Vec<crate::SQLiteSeedStatement> {
         crate::Seeder::new(self).generate_sqlite()
     }
+
+    /// Generate the INSERT statements with values inlined as SQL literals
+    /// instead of bound parameters, for scripts run outside of Rust.
+    #[must_use]
+    pub fn generate_sql(&self) -> Vec<String> {
+        crate::Seeder::new(self).generate_sqlite_script()
+    }
+
+    /// Write [`generate_sql`](Self::generate_sql) to `path` as a single `.sql`
+    /// script, creating parent directories as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error from creating the directories or writing the file.
+    pub fn write_sql(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        crate::write_script(path.as_ref(), &self.generate_sql())
+    }
 }
 
 #[cfg(feature = "postgres")]
@@ -228,15 +522,23 @@ where
     }
 
     /// Set how many child rows to generate per parent row for a relation.
+    ///
+    /// `count` is a fixed count, a `min..=max` range, or any [`Distribution`].
+    /// Without an explicit [`count`](Self::count), the child table gets the
+    /// sum of the sampled counts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is an empty range or a Poisson distribution with a
+    /// negative mean.
     #[must_use]
-    pub fn relation<P, C>(mut self, parent: &P, child: &C, count: usize) -> Self
+    pub fn relation<P, C>(mut self, parent: &P, child: &C, count: impl Into<Distribution>) -> Self
     where
         P: SQLTableInfo + PostgresTable<'static>,
         C: SQLTableInfo + PostgresTable<'static> + Relation<P>,
         S: SchemaHasTable<P> + SchemaHasTable<C>,
     {
-        self.relation_counts
-            .insert((parent.name(), child.name()), count);
+        self.insert_relation((parent.name(), child.name()), count.into());
         self
     }
 
@@ -264,9 +566,119 @@ where
         self
     }
 
+    /// Fill a column with values picked at random from `values`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    #[must_use]
+    pub fn values<C, V>(self, column: &C, values: Vec<V>) -> Self
+    where
+        C: SQLColumnInfo + PostgresColumn<'static>,
+        S: SchemaHasTable<<C as SQLColumn<'static, PostgresValue<'static>>>::Table>,
+        V: Into<SeedValue>,
+    {
+        self.generator(column, ValuesGen::sample(values))
+    }
+
+    /// Fill a column with `values` in order, repeating them as needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    #[must_use]
+    pub fn cycle<C, V>(self, column: &C, values: Vec<V>) -> Self
+    where
+        C: SQLColumnInfo + PostgresColumn<'static>,
+        S: SchemaHasTable<<C as SQLColumn<'static, PostgresValue<'static>>>::Table>,
+        V: Into<SeedValue>,
+    {
+        self.generator(column, ValuesGen::cycle(values))
+    }
+
+    /// Fill a column with `start`, `start + step`, `start + 2 * step`, ...
+    #[must_use]
+    pub fn sequence<C>(self, column: &C, start: i64, step: i64) -> Self
+    where
+        C: SQLColumnInfo + PostgresColumn<'static>,
+        S: SchemaHasTable<<C as SQLColumn<'static, PostgresValue<'static>>>::Table>,
+    {
+        self.generator(column, SequenceGen { start, step })
+    }
+
+    /// Set the share of rows (`0.0..=1.0`) that get NULL in a column.
+    /// Ignored for `NOT NULL` and primary key columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is outside `0.0..=1.0`.
+    #[must_use]
+    pub fn null_ratio<C>(mut self, column: &C, ratio: f64) -> Self
+    where
+        C: SQLColumnInfo + PostgresColumn<'static>,
+        S: SchemaHasTable<<C as SQLColumn<'static, PostgresValue<'static>>>::Table>,
+    {
+        assert_null_ratio(ratio);
+        let key = (column.table().name(), column.name());
+        self.null_ratios.insert(key, ratio);
+        self
+    }
+
+    /// Post-process every generated row of `table`, after generators,
+    /// relations and NULL ratios, for values that depend on other columns of
+    /// the same row. Refinements run in the order they were added.
+    ///
+    /// ```rust
+    /// # let _ = r####"
+    /// SeedConfig::postgres(&schema).refine(&schema.users, |row| {
+    ///     let name = row.get(&Users::name);
+    ///     row.set(&Users::email, format!("{name}@test.local"));
+    /// });
+    /// # "####;
+    /// ```
+    #[must_use]
+    pub fn refine<T>(
+        mut self,
+        table: &T,
+        refine: impl Fn(&mut SeedRow<'_, Postgres, T>) + Send + Sync + 'static,
+    ) -> Self
+    where
+        T: SQLTableInfo + PostgresTable<'static> + 'static,
+        S: SchemaHasTable<T>,
+    {
+        let refiner: Refiner = Arc::new(move |columns, values, index| {
+            refine(&mut SeedRow::new(columns, values, index));
+        });
+        self.refiners.entry(table.name()).or_default().push(refiner);
+        self
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<S> SeedConfig<'_, Postgres, S>
+where
+    S: SeedSchema,
+{
     /// Generate INSERT statements for the active table set.
     #[must_use]
     pub fn generate(&self) -> Vec<crate::PostgresSeedStatement> {
         crate::Seeder::new(self).generate_postgres()
     }
+
+    /// Generate the INSERT statements with values inlined as SQL literals
+    /// instead of bound parameters, for scripts run outside of Rust.
+    #[must_use]
+    pub fn generate_sql(&self) -> Vec<String> {
+        crate::Seeder::new(self).generate_postgres_script()
+    }
+
+    /// Write [`generate_sql`](Self::generate_sql) to `path` as a single `.sql`
+    /// script, creating parent directories as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error from creating the directories or writing the file.
+    pub fn write_sql(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        crate::write_script(path.as_ref(), &self.generate_sql())
+    }
 }
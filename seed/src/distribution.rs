@@ -0,0 +1,133 @@
+//! Children-per-parent distributions for relations.
+
+use rand::Rng;
+use std::ops::RangeInclusive;
+
+/// How many child rows each parent row gets in a relation.
+///
+/// Counts are sampled once per parent row from a dedicated RNG, so the same
+/// seed always gives every parent the same number of children.
+///
+/// ```rust
+/// # let _ = r####"
+/// SeedConfig::sqlite(&schema)
+///     .relation(&schema.users, &schema.posts, 3)
+///     .relation(&schema.users, &schema.comments, 0..=10)
+///     .relation(&schema.posts, &schema.likes, Distribution::Poisson(4.0));
+/// # "####;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Distribution {
+    /// Exactly this many children for every parent.
+    Fixed(usize),
+    /// A uniformly sampled count in `min..=max`.
+    Range {
+        /// Fewest children a parent can get.
+        min: usize,
+        /// Most children a parent can get.
+        max: usize,
+    },
+    /// A Poisson-distributed count with this mean: most parents get close to
+    /// the mean, a few get many more.
+    Poisson(f64),
+}
+
+impl Distribution {
+    /// Panic unless the distribution can be sampled.
+    pub(crate) fn validate(self) {
+        match self {
+            Self::Fixed(_) => {}
+            Self::Range { min, max } => {
+                assert!(min <= max, "relation range {min}..={max} is empty");
+            }
+            Self::Poisson(mean) => {
+                assert!(
+                    mean.is_finite() && mean >= 0.0,
+                    "Poisson mean must be finite and >= 0, got {mean}"
+                );
+            }
+        }
+    }
+
+    /// Sample a children count for one parent row.
+    pub(crate) fn sample(self, rng: &mut impl Rng) -> usize {
+        match self {
+            Self::Fixed(count) => count,
+            Self::Range { min, max } => rng.random_range(min..=max),
+            Self::Poisson(mean) => poisson(rng, mean),
+        }
+    }
+}
+
+impl From<usize> for Distribution {
+    fn from(count: usize) -> Self {
+        Self::Fixed(count)
+    }
+}
+
+impl From<RangeInclusive<usize>> for Distribution {
+    fn from(range: RangeInclusive<usize>) -> Self {
+        Self::Range {
+            min: *range.start(),
+            max: *range.end(),
+        }
+    }
+}
+
+/// Largest mean sampled in one step; `exp(-mean)` underflows well above it.
+const POISSON_STEP: f64 = 500.0;
+
+/// Knuth's multiplication method. Large means are split into steps, since a
+/// sum of Poisson samples is Poisson with the summed mean.
+fn poisson(rng: &mut impl Rng, mut mean: f64) -> usize {
+    let mut count = 0;
+    while mean > 0.0 {
+        let step = mean.min(POISSON_STEP);
+        mean -= step;
+        let limit = (-step).exp();
+        let mut product: f64 = rng.random();
+        while product > limit {
+            count += 1;
+            product *= rng.random::<f64>();
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn samples(dist: Distribution, n: usize) -> Vec<usize> {
+        let mut rng = StdRng::seed_from_u64(7);
+        (0..n).map(|_| dist.sample(&mut rng)).collect()
+    }
+
+    #[test]
+    fn range_stays_in_bounds_and_hits_both_ends() {
+        let counts = samples((2..=5).into(), 500);
+        assert!(counts.iter().all(|c| (2..=5).contains(c)));
+        assert!(counts.contains(&2) && counts.contains(&5));
+    }
+
+    #[test]
+    fn poisson_mean_is_close_and_skewed() {
+        for mean in [3.0, 1200.0] {
+            let counts = samples(Distribution::Poisson(mean), 2000);
+            let avg = counts.iter().sum::<usize>() as f64 / counts.len() as f64;
+            assert!((avg - mean).abs() < mean * 0.05, "mean {mean}: got {avg}");
+        }
+        let counts = samples(Distribution::Poisson(3.0), 2000);
+        assert!(counts.contains(&0));
+        assert!(counts.iter().any(|&c| c >= 8));
+        assert_eq!(samples(Distribution::Poisson(0.0), 10), vec![0; 10]);
+    }
+
+    #[test]
+    #[should_panic(expected = "relation range 5..=2 is empty")]
+    fn empty_range_is_rejected() {
+        Distribution::Range { min: 5, max: 2 }.validate();
+    }
+}
@@ -0,0 +1,265 @@
+use super::{Generator, GeneratorKind, RngCore, SeedValue, numeric};
+use serde_json::{Map, Number, Value};
+
+/// Fill a JSON document template for every row.
+///
+/// String leaves of the form `"{{placeholder}}"` are replaced with a value
+/// from the matching generator; every other leaf is copied as is.
+///
+/// | Placeholder | Value |
+/// |-------------|-------|
+/// | `{{int}}`, `{{int:1..=100}}` | integer, optionally in a range |
+/// | `{{float}}`, `{{float:0..5}}` | number, optionally in a range |
+/// | `{{bool}}` | boolean |
+/// | `{{first_name}}`, `{{last_name}}`, `{{full_name}}`, `{{email}}`, `{{phone}}` | string |
+/// | `{{city}}`, `{{country}}`, `{{address}}`, `{{company}}`, `{{job_title}}` | string |
+/// | `{{text}}`, `{{lorem}}` | string |
+/// | `{{uuid}}`, `{{uuid_v7}}`, `{{ulid}}` | string |
+/// | `{{date}}`, `{{timestamp}}`, `{{time}}` | string |
+///
+/// ```rust
+/// # let _ = r####"
+/// SeedConfig::postgres(&schema).generator(
+///     &schema.users.profile,
+///     json_template(json!({
+///         "age": "{{int:18..=90}}",
+///         "address": { "city": "{{city}}", "country": "{{country}}" },
+///         "tags": ["{{lorem}}", "{{lorem}}"],
+///         "version": 1,
+///     })),
+/// );
+/// # "####;
+/// ```
+///
+/// # Panics
+///
+/// Panics if a placeholder names an unknown generator or has a malformed
+/// range.
+#[must_use]
+pub fn json_template(template: Value) -> JsonTemplateGen {
+    JsonTemplateGen {
+        root: Node::compile(template),
+    }
+}
+
+/// Generates JSON documents from a template; see [`json_template`].
+pub struct JsonTemplateGen {
+    root: Node,
+}
+
+enum Node {
+    Literal(Value),
+    Placeholder(Box<dyn Generator>),
+    Array(Vec<Self>),
+    Object(Vec<(String, Self)>),
+}
+
+impl Node {
+    fn compile(value: Value) -> Self {
+        match value {
+            Value::String(s) => match s.strip_prefix("{{").and_then(|s| s.strip_suffix("}}")) {
+                Some(placeholder) => Self::Placeholder(placeholder_generator(placeholder.trim())),
+                None => Self::Literal(Value::String(s)),
+            },
+            Value::Array(items) => Self::Array(items.into_iter().map(Self::compile).collect()),
+            Value::Object(fields) => Self::Object(
+                fields
+                    .into_iter()
+                    .map(|(key, value)| (key, Self::compile(value)))
+                    .collect(),
+            ),
+            literal => Self::Literal(literal),
+        }
+    }
+
+    fn render(&self, rng: &mut dyn RngCore, index: usize) -> Value {
+        match self {
+            Self::Literal(value) => value.clone(),
+            // Placeholders always render as text, even inside a BLOB column.
+            Self::Placeholder(generator) => to_json(generator.generate(rng, index, "TEXT")),
+            Self::Array(items) => {
+                Value::Array(items.iter().map(|n| n.render(rng, index)).collect())
+            }
+            Self::Object(fields) => Value::Object(
+                fields
+                    .iter()
+                    .map(|(key, node)| (key.clone(), node.render(rng, index)))
+                    .collect::<Map<_, _>>(),
+            ),
+        }
+    }
+}
+
+impl Generator for JsonTemplateGen {
+    fn generate(&self, rng: &mut dyn RngCore, index: usize, sql_type: &str) -> SeedValue {
+        let doc = self.root.render(rng, index).to_string();
+        // SQLite stores `#[column(JSONB)]` as a BLOB of JSON text.
+        if sql_type.to_ascii_uppercase().contains("BLOB") {
+            SeedValue::Blob(doc.into_bytes())
+        } else {
+            SeedValue::Text(doc)
+        }
+    }
+    fn name(&self) -> &'static str {
+        "JsonTemplate"
+    }
+}
+
+fn placeholder_generator(placeholder: &str) -> Box<dyn Generator> {
+    let (name, range) = match placeholder.split_once(':') {
+        Some((name, range)) => (name.trim(), Some(range.trim())),
+        None => (placeholder, None),
+    };
+    match (name, range) {
+        ("int", Some(range)) => {
+            let (min, max) = parse_range::<i64>(placeholder, range);
+            Box::new(numeric::IntGen { min, max })
+        }
+        ("float", Some(range)) => {
+            let (min, max) = parse_range::<f64>(placeholder, range);
+            Box::new(numeric::FloatGen { min, max })
+        }
+        (_, Some(_)) => panic!("JSON template placeholder `{{{{{placeholder}}}}}` takes no range"),
+        (name, None) => placeholder_kind(name)
+            .unwrap_or_else(|| panic!("unknown JSON template placeholder `{{{{{name}}}}}`"))
+            .into_generator(),
+    }
+}
+
+fn placeholder_kind(name: &str) -> Option<GeneratorKind> {
+    Some(match name {
+        "int" => GeneratorKind::Int,
+        "float" => GeneratorKind::Float,
+        "bool" => GeneratorKind::Bool,
+        "text" => GeneratorKind::Text,
+        "first_name" => GeneratorKind::FirstName,
+        "last_name" => GeneratorKind::LastName,
+        "full_name" => GeneratorKind::FullName,
+        "email" => GeneratorKind::Email,
+        "phone" => GeneratorKind::Phone,
+        "city" => GeneratorKind::City,
+        "country" => GeneratorKind::Country,
+        "address" => GeneratorKind::Address,
+        "job_title" => GeneratorKind::JobTitle,
+        "company" => GeneratorKind::Company,
+        "lorem" => GeneratorKind::LoremIpsum,
+        "uuid" => GeneratorKind::Uuid,
+        "uuid_v7" => GeneratorKind::UuidV7,
+        "ulid" => GeneratorKind::Ulid,
+        "date" => GeneratorKind::Date,
+        "timestamp" => GeneratorKind::Timestamp,
+        "time" => GeneratorKind::Time,
+        _ => return None,
+    })
+}
+
+/// Parse `min..max` (exclusive) or `min..=max` into inclusive bounds for
+/// integers, and `[min, max)` for floats.
+fn parse_range<T: RangeBound>(placeholder: &str, range: &str) -> (T, T) {
+    let parsed = range
+        .split_once("..=")
+        .map(|(min, max)| (min, max, true))
+        .or_else(|| range.split_once("..").map(|(min, max)| (min, max, false)))
+        .and_then(|(min, max, inclusive)| {
+            let min = min.trim().parse::<T>().ok()?;
+            let max = max.trim().parse::<T>().ok()?;
+            T::bounds(min, max, inclusive)
+        });
+    parsed.unwrap_or_else(|| {
+        panic!("invalid range in JSON template placeholder `{{{{{placeholder}}}}}`")
+    })
+}
+
+trait RangeBound: std::str::FromStr + Sized {
+    fn bounds(min: Self, max: Self, inclusive: bool) -> Option<(Self, Self)>;
+}
+
+impl RangeBound for i64 {
+    fn bounds(min: Self, max: Self, inclusive: bool) -> Option<(Self, Self)> {
+        let max = if inclusive { max } else { max.checked_sub(1)? };
+        (min <= max).then_some((min, max))
+    }
+}
+
+impl RangeBound for f64 {
+    fn bounds(min: Self, max: Self, _inclusive: bool) -> Option<(Self, Self)> {
+        (min < max).then_some((min, max))
+    }
+}
+
+fn to_json(value: SeedValue) -> Value {
+    match value {
+        SeedValue::Integer(v) => Value::Number(v.into()),
+        SeedValue::Float(v) => Number::from_f64(v).map_or(Value::Null, Value::Number),
+        SeedValue::Text(v) => Value::String(v),
+        SeedValue::Bool(v) => Value::Bool(v),
+        SeedValue::Default | SeedValue::Null | SeedValue::Blob(_) | SeedValue::CurrentTime => {
+            Value::Null
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use serde_json::json;
+
+    fn render(g: &JsonTemplateGen, seed: u64, rows: usize) -> Vec<Value> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..rows)
+            .map(|i| match g.generate(&mut rng, i, "JSONB") {
+                SeedValue::Text(doc) => serde_json::from_str(&doc).expect("valid JSON"),
+                other => panic!("expected Text, got {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fills_placeholders_and_keeps_literals() {
+        let g = json_template(json!({
+            "age": "{{int:18..=90}}",
+            "score": "{{float:0..5}}",
+            "address": { "city": "{{city}}", "zip": "00000" },
+            "tags": ["{{lorem}}", "{{ulid}}"],
+            "version": 1,
+        }));
+        let docs = render(&g, 42, 20);
+        assert_eq!(docs, render(&g, 42, 20));
+        for doc in &docs {
+            let age = doc["age"].as_i64().expect("age");
+            assert!((18..=90).contains(&age));
+            let score = doc["score"].as_f64().expect("score");
+            assert!((0.0..5.0).contains(&score));
+            assert!(doc["address"]["city"].is_string());
+            assert_eq!(doc["address"]["zip"], "00000");
+            assert_eq!(doc["tags"][1].as_str().map(str::len), Some(26));
+            assert_eq!(doc["version"], 1);
+        }
+        assert!(docs.iter().any(|d| d["age"] != docs[0]["age"]));
+    }
+
+    #[test]
+    fn blob_columns_get_json_bytes() {
+        let g = json_template(json!({ "n": "{{int}}" }));
+        let mut rng = StdRng::seed_from_u64(1);
+        let SeedValue::Blob(bytes) = g.generate(&mut rng, 0, "BLOB") else {
+            panic!("expected Blob");
+        };
+        let doc: Value = serde_json::from_slice(&bytes).expect("valid JSON");
+        assert!(doc["n"].is_i64());
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown JSON template placeholder `{{nickname}}`")]
+    fn unknown_placeholder_panics() {
+        let _ = json_template(json!({ "nick": "{{nickname}}" }));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid range in JSON template placeholder `{{int:9..1}}`")]
+    fn empty_range_panics() {
+        let _ = json_template(json!(["{{int:9..1}}"]));
+    }
+}
@@ -1,6 +1,9 @@
 //! Value generators for seeding database columns.
 
+#[cfg(feature = "serde")]
+pub mod json;
 pub mod numeric;
+pub mod pool;
 pub mod special;
 pub mod string;
 pub mod temporal;
@@ -19,6 +22,54 @@ pub enum SeedValue {
     CurrentTime,
 }
 
+impl From<i64> for SeedValue {
+    fn from(v: i64) -> Self {
+        Self::Integer(v)
+    }
+}
+
+impl From<i32> for SeedValue {
+    fn from(v: i32) -> Self {
+        Self::Integer(i64::from(v))
+    }
+}
+
+impl From<f64> for SeedValue {
+    fn from(v: f64) -> Self {
+        Self::Float(v)
+    }
+}
+
+impl From<bool> for SeedValue {
+    fn from(v: bool) -> Self {
+        Self::Bool(v)
+    }
+}
+
+impl From<&str> for SeedValue {
+    fn from(v: &str) -> Self {
+        Self::Text(v.to_string())
+    }
+}
+
+impl From<String> for SeedValue {
+    fn from(v: String) -> Self {
+        Self::Text(v)
+    }
+}
+
+impl From<Vec<u8>> for SeedValue {
+    fn from(v: Vec<u8>) -> Self {
+        Self::Blob(v)
+    }
+}
+
+impl<T: Into<Self>> From<Option<T>> for SeedValue {
+    fn from(v: Option<T>) -> Self {
+        v.map_or(Self::Null, Into::into)
+    }
+}
+
 /// Trait for deterministic value generators.
 ///
 /// Each generator produces a single column value given an RNG and a row index.
@@ -70,6 +121,10 @@ pub enum GeneratorKind {
     LoremIpsum,
     /// UUID v4
     Uuid,
+    /// UUID v7, ordered by row index
+    UuidV7,
+    /// ULID, ordered by row index
+    Ulid,
     /// JSON object
     Json,
     /// Date (YYYY-MM-DD)
@@ -145,6 +200,8 @@ impl GeneratorKind {
             Self::Company => Box::new(string::CompanyGen),
             Self::LoremIpsum => Box::new(string::LoremGen { words: 10 }),
             Self::Uuid => Box::new(special::UuidGen),
+            Self::UuidV7 => Box::new(special::UuidV7Gen),
+            Self::Ulid => Box::new(special::UlidGen),
             Self::Json => Box::new(special::JsonGen),
             Self::Date => Box::new(temporal::DateGen),
             Self::Timestamp => Box::new(temporal::TimestampGen),
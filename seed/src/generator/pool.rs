@@ -0,0 +1,128 @@
+use super::{Generator, RngCore, SeedValue};
+use rand::Rng;
+
+/// Picks each row's value from a fixed pool.
+pub struct ValuesGen {
+    values: Vec<SeedValue>,
+    cycle: bool,
+}
+
+impl ValuesGen {
+    /// Pick a random value from `values` for every row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    #[must_use]
+    pub fn sample<V: Into<SeedValue>>(values: Vec<V>) -> Self {
+        Self::new(values, false)
+    }
+
+    /// Use `values` in order, starting over after the last one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    #[must_use]
+    pub fn cycle<V: Into<SeedValue>>(values: Vec<V>) -> Self {
+        Self::new(values, true)
+    }
+
+    fn new<V: Into<SeedValue>>(values: Vec<V>, cycle: bool) -> Self {
+        assert!(!values.is_empty(), "value pool must not be empty");
+        Self {
+            values: values.into_iter().map(Into::into).collect(),
+            cycle,
+        }
+    }
+}
+
+impl Generator for ValuesGen {
+    fn generate(&self, rng: &mut dyn RngCore, index: usize, _sql_type: &str) -> SeedValue {
+        let idx = if self.cycle {
+            index % self.values.len()
+        } else {
+            rng.random_range(0..self.values.len())
+        };
+        self.values[idx].clone()
+    }
+    fn name(&self) -> &'static str {
+        if self.cycle {
+            "CycleValues"
+        } else {
+            "SampleValues"
+        }
+    }
+}
+
+/// Generates `start`, `start + step`, `start + 2 * step`, ...
+pub struct SequenceGen {
+    pub start: i64,
+    pub step: i64,
+}
+
+impl Generator for SequenceGen {
+    fn generate(&self, _rng: &mut dyn RngCore, index: usize, _sql_type: &str) -> SeedValue {
+        let index = i64::try_from(index).unwrap_or(i64::MAX);
+        SeedValue::Integer(self.start.saturating_add(self.step.saturating_mul(index)))
+    }
+    fn name(&self) -> &'static str {
+        "Sequence"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn cycle_repeats_in_order() {
+        let g = ValuesGen::cycle(vec!["us", "de", "jp"]);
+        let mut rng = StdRng::seed_from_u64(0);
+        let vals: Vec<SeedValue> = (0..5).map(|i| g.generate(&mut rng, i, "TEXT")).collect();
+        let text = |s: &str| SeedValue::Text(s.to_string());
+        assert_eq!(
+            vals,
+            vec![text("us"), text("de"), text("jp"), text("us"), text("de")]
+        );
+    }
+
+    #[test]
+    fn sample_stays_in_pool_and_is_deterministic() {
+        let g = ValuesGen::sample(vec!["free", "pro", "team"]);
+        let run = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..50)
+                .map(|i| g.generate(&mut rng, i, "TEXT"))
+                .collect::<Vec<_>>()
+        };
+        let vals = run(42);
+        assert_eq!(vals, run(42));
+        for val in &vals {
+            let SeedValue::Text(plan) = val else {
+                panic!("expected Text");
+            };
+            assert!(["free", "pro", "team"].contains(&plan.as_str()));
+        }
+        assert!(vals.iter().any(|v| *v != vals[0]), "sampling should vary");
+    }
+
+    #[test]
+    #[should_panic(expected = "value pool must not be empty")]
+    fn empty_pool_panics() {
+        let _ = ValuesGen::sample(Vec::<i64>::new());
+    }
+
+    #[test]
+    fn sequence_steps_from_start() {
+        let g = SequenceGen {
+            start: 1000,
+            step: 5,
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(g.generate(&mut rng, 0, "INTEGER"), SeedValue::Integer(1000));
+        assert_eq!(g.generate(&mut rng, 3, "INTEGER"), SeedValue::Integer(1015));
+    }
+}
@@ -1,43 +1,122 @@
 use super::{Generator, RngCore, SeedValue};
 use rand::Rng;
 
-/// Generates UUID v4 strings (random, formatted as 8-4-4-4-12 hex).
+/// Base of the timestamps embedded in UUID v7 and ULID keys
+/// (2024-01-01T00:00:00Z, in Unix milliseconds).
+const ID_EPOCH_MS: u64 = 1_704_067_200_000;
+
+/// Crockford base32 alphabet used by ULIDs.
+const ULID_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Generates UUID v4 keys (random, formatted as 8-4-4-4-12 hex).
+///
+/// BLOB/BYTEA columns get the 16 raw bytes instead of the text form.
 pub struct UuidGen;
 
 impl Generator for UuidGen {
-    fn generate(&self, rng: &mut dyn RngCore, _index: usize, _sql_type: &str) -> SeedValue {
+    fn generate(&self, rng: &mut dyn RngCore, _index: usize, sql_type: &str) -> SeedValue {
         let mut bytes = [0u8; 16];
         rng.fill(&mut bytes);
         // Set version 4
         bytes[6] = (bytes[6] & 0x0f) | 0x40;
         // Set variant 1
         bytes[8] = (bytes[8] & 0x3f) | 0x80;
-        let s = format!(
-            "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
-            bytes[0],
-            bytes[1],
-            bytes[2],
-            bytes[3],
-            bytes[4],
-            bytes[5],
-            bytes[6],
-            bytes[7],
-            bytes[8],
-            bytes[9],
-            bytes[10],
-            bytes[11],
-            bytes[12],
-            bytes[13],
-            bytes[14],
-            bytes[15],
-        );
-        SeedValue::Text(s)
+        uuid_value(bytes, sql_type)
     }
     fn name(&self) -> &'static str {
         "Uuid"
     }
 }
 
+/// Generates UUID v7 keys whose timestamp is the row index in milliseconds
+/// past a fixed epoch, so keys sort in row order and are identical on every
+/// run with the same seed.
+///
+/// BLOB/BYTEA columns get the 16 raw bytes instead of the text form.
+pub struct UuidV7Gen;
+
+impl Generator for UuidV7Gen {
+    fn generate(&self, rng: &mut dyn RngCore, index: usize, sql_type: &str) -> SeedValue {
+        let mut bytes = timestamped_bytes(rng, index);
+        // Set version 7
+        bytes[6] = (bytes[6] & 0x0f) | 0x70;
+        // Set variant 1
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+        uuid_value(bytes, sql_type)
+    }
+    fn name(&self) -> &'static str {
+        "UuidV7"
+    }
+}
+
+/// Generates ULIDs (26 Crockford base32 characters) with the same row-index
+/// timestamp as [`UuidV7Gen`].
+///
+/// BLOB/BYTEA columns get the 16 raw bytes, and UUID columns the 128 bits in
+/// UUID text form, since neither accepts the base32 string.
+pub struct UlidGen;
+
+impl Generator for UlidGen {
+    fn generate(&self, rng: &mut dyn RngCore, index: usize, sql_type: &str) -> SeedValue {
+        let bytes = timestamped_bytes(rng, index);
+        if is_binary_type(sql_type) || sql_type.to_ascii_uppercase().contains("UUID") {
+            return uuid_value(bytes, sql_type);
+        }
+        let bits = u128::from_be_bytes(bytes);
+        let ulid = (0..26)
+            .map(|i| {
+                // 26 characters hold 130 bits; the first one carries the top 3.
+                let idx = (bits >> (125 - 5 * i)) & 0x1f;
+                char::from(ULID_ALPHABET[idx as usize])
+            })
+            .collect();
+        SeedValue::Text(ulid)
+    }
+    fn name(&self) -> &'static str {
+        "Ulid"
+    }
+}
+
+/// 48-bit big-endian millisecond timestamp for row `index`, then 80 random bits.
+fn timestamped_bytes(rng: &mut dyn RngCore, index: usize) -> [u8; 16] {
+    let millis = ID_EPOCH_MS.saturating_add(index as u64);
+    let mut bytes = [0u8; 16];
+    bytes[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+    rng.fill(&mut bytes[6..]);
+    bytes
+}
+
+fn is_binary_type(sql_type: &str) -> bool {
+    let ty = sql_type.to_ascii_uppercase();
+    ty.contains("BLOB") || ty.contains("BYTEA")
+}
+
+fn uuid_value(bytes: [u8; 16], sql_type: &str) -> SeedValue {
+    if is_binary_type(sql_type) {
+        return SeedValue::Blob(bytes.to_vec());
+    }
+    let s = format!(
+        "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+        bytes[0],
+        bytes[1],
+        bytes[2],
+        bytes[3],
+        bytes[4],
+        bytes[5],
+        bytes[6],
+        bytes[7],
+        bytes[8],
+        bytes[9],
+        bytes[10],
+        bytes[11],
+        bytes[12],
+        bytes[13],
+        bytes[14],
+        bytes[15],
+    );
+    SeedValue::Text(s)
+}
+
 /// Generates simple JSON objects with random key-value pairs.
 pub struct JsonGen;
 
@@ -338,6 +417,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn uuid_blob_columns_get_raw_bytes() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let SeedValue::Blob(bytes) = UuidGen.generate(&mut rng, 0, "BLOB") else {
+            panic!("expected Blob");
+        };
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(bytes[6] >> 4, 4);
+    }
+
+    #[test]
+    fn uuid_v7_orders_by_row_and_repeats_per_seed() {
+        let run = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..50)
+                .map(|i| match UuidV7Gen.generate(&mut rng, i, "UUID") {
+                    SeedValue::Text(s) => s,
+                    other => panic!("expected Text, got {other:?}"),
+                })
+                .collect::<Vec<_>>()
+        };
+        let keys = run(7);
+        assert_eq!(keys, run(7));
+        assert_ne!(keys, run(8));
+        assert!(keys.windows(2).all(|w| w[0] < w[1]), "v7 keys not ordered");
+        assert!(keys.iter().all(|k| &k[14..15] == "7"), "uuid version not 7");
+        // 2024-01-01T00:00:00Z plus the row index
+        assert!(keys[0].starts_with("018cc251-f400-7"), "{}", keys[0]);
+        assert!(keys[1].starts_with("018cc251-f401-7"), "{}", keys[1]);
+    }
+
+    #[test]
+    fn ulid_format_and_order() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let ulids: Vec<String> = (0..50)
+            .map(|i| match UlidGen.generate(&mut rng, i, "TEXT") {
+                SeedValue::Text(s) => s,
+                other => panic!("expected Text, got {other:?}"),
+            })
+            .collect();
+        for ulid in &ulids {
+            assert_eq!(ulid.len(), 26, "ulid wrong length: {ulid}");
+            assert!(ulid.bytes().all(|b| ULID_ALPHABET.contains(&b)), "{ulid}");
+        }
+        assert!(ulids.windows(2).all(|w| w[0] < w[1]), "ulids not ordered");
+        assert!(ulids[0].starts_with("01HK153X00"), "{}", ulids[0]);
+
+        let SeedValue::Text(as_uuid) = UlidGen.generate(&mut rng, 0, "uuid") else {
+            panic!("expected Text");
+        };
+        assert_eq!(as_uuid.len(), 36);
+    }
+
     #[test]
     fn json_structure_and_index() {
         let g = JsonGen;
@@ -20,6 +20,11 @@ pub fn infer_generator(col: &ColumnRef) -> GeneratorKind {
         return kind;
     }
 
+    // Non-integer surrogate keys (`id TEXT PRIMARY KEY`, SQLite's BLOB UUIDs)
+    if col.primary_key() && is_key_type(&sql_type) {
+        return GeneratorKind::Uuid;
+    }
+
     // SQL type mapping (fallback)
     infer_from_type(&sql_type)
 }
@@ -78,6 +83,9 @@ fn infer_from_name(name: &str) -> Option<GeneratorKind> {
     {
         return Some(GeneratorKind::LoremIpsum);
     }
+    if name.contains("ulid") {
+        return Some(GeneratorKind::Ulid);
+    }
     if name.contains("uuid") || name.contains("guid") {
         return Some(GeneratorKind::Uuid);
     }
@@ -161,6 +169,13 @@ fn is_integer_type(sql_type: &str) -> bool {
     sql_type.contains("INT") || sql_type.contains("SERIAL")
 }
 
+/// Types a UUID key can be stored in: UUID itself, text, or 16 raw bytes.
+fn is_key_type(sql_type: &str) -> bool {
+    ["UUID", "TEXT", "CHAR", "BLOB", "BYTEA"]
+        .iter()
+        .any(|t| sql_type.contains(t))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,6 +193,8 @@ mod tests {
             Some(GeneratorKind::Timestamp)
         );
         assert_eq!(infer_from_name("is_active"), Some(GeneratorKind::Bool));
+        assert_eq!(infer_from_name("order_ulid"), Some(GeneratorKind::Ulid));
+        assert_eq!(infer_from_name("external_uuid"), Some(GeneratorKind::Uuid));
         assert_eq!(infer_from_name("some_field"), None);
     }
 
@@ -191,4 +208,22 @@ mod tests {
         assert_eq!(infer_from_type("UUID"), GeneratorKind::Uuid);
         assert_eq!(infer_from_type("TIMESTAMP"), GeneratorKind::Timestamp);
     }
+
+    #[test]
+    fn text_and_blob_primary_keys_get_uuids() {
+        use drizzle_core::ColumnFlags;
+
+        let key = |sql_type| ColumnRef {
+            sql_type,
+            flags: ColumnFlags::PRIMARY_KEY,
+            ..ColumnRef::sql("users", "id")
+        };
+        assert_eq!(infer_generator(&key("TEXT")), GeneratorKind::Uuid);
+        assert_eq!(infer_generator(&key("BLOB")), GeneratorKind::Uuid);
+        assert_eq!(infer_generator(&key("uuid")), GeneratorKind::Uuid);
+        assert_eq!(
+            infer_generator(&key("INTEGER")),
+            GeneratorKind::IntPrimaryKey
+        );
+    }
 }
@@ -18,20 +18,40 @@
 //!     .generate();
 //! # "####;
 //! ```
+//!
+//! Schemas that aren't compiled into the program can be seeded from a
+//! migration snapshot with `SeedConfig::from_snapshot` (`snapshot` feature).
+//! `generate_sql` and `write_sql` render the same rows with inline literals
+//! instead of bound parameters, as a script for non-Rust tooling.
+//!
+//! The `parallel` feature generates each table's columns and rows on a rayon
+//! thread pool. Every column has its own RNG stream, so the output is the
+//! same as the serial build.
 
 pub(crate) mod batch;
 pub(crate) mod config;
 pub(crate) mod datasets;
+pub(crate) mod distribution;
 pub(crate) mod generator;
 pub(crate) mod inference;
+pub(crate) mod refine;
 pub(crate) mod rng;
+#[cfg(feature = "snapshot")]
+pub(crate) mod snapshot;
 pub(crate) mod topology;
 
-pub use config::SeedConfig;
+pub use config::{SeedConfig, SeedSchema};
+pub use distribution::Distribution;
+#[cfg(feature = "serde")]
+pub use generator::json::{JsonTemplateGen, json_template};
+pub use generator::pool::{SequenceGen, ValuesGen};
 pub use generator::{Generator, GeneratorKind, RngCore, SeedValue};
+pub use refine::SeedRow;
+#[cfg(feature = "snapshot")]
+pub use snapshot::{SnapshotSchema, SnapshotTables};
 
 use drizzle_core::{ColumnRef, TableRef};
-use rand::rngs::StdRng;
+use rand::Rng;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
@@ -92,6 +112,7 @@ mod statement {
             .map(|chunk| match chunk {
                 drizzle_core::OwnedSQLChunk::Token(t) => SQLChunk::Token(*t),
                 drizzle_core::OwnedSQLChunk::Ident(s) => SQLChunk::Ident(Cow::Owned(s.to_string())),
+                drizzle_core::OwnedSQLChunk::Name(s) => SQLChunk::Name(s),
                 drizzle_core::OwnedSQLChunk::Raw(s) => SQLChunk::Raw(Cow::Owned(s.to_string())),
                 drizzle_core::OwnedSQLChunk::Number(v) => SQLChunk::Number(*v),
                 drizzle_core::OwnedSQLChunk::Param(p) => SQLChunk::Param(Param {
@@ -123,6 +144,7 @@ mod statement {
                 drizzle_core::OwnedSQLChunk::Ident(s) => {
                     SQLChunk::Ident(Cow::Owned(String::from(s)))
                 }
+                drizzle_core::OwnedSQLChunk::Name(s) => SQLChunk::Name(s),
                 drizzle_core::OwnedSQLChunk::Raw(s) => SQLChunk::Raw(Cow::Owned(String::from(s))),
                 drizzle_core::OwnedSQLChunk::Number(v) => SQLChunk::Number(v),
                 drizzle_core::OwnedSQLChunk::Param(p) => SQLChunk::Param(Param {
@@ -208,7 +230,9 @@ struct RelationSpec {
     target_table: &'static str,
     fk_columns: &'static [&'static str],
     ref_columns: &'static [&'static str],
-    children_per_parent: usize,
+    /// Parent row index for each child row, repeating once exhausted. `None`
+    /// while the parent table hasn't been seeded (self-references).
+    parent_slots: Option<Vec<usize>>,
 }
 
 // ---------------------------------------------------------------------------
@@ -221,22 +245,30 @@ struct Seeder<'a, D, S> {
 
 impl<'a, D, S> Seeder<'a, D, S>
 where
-    S: drizzle_core::SQLSchemaImpl,
+    S: SeedSchema,
 {
     const fn new(config: &'a SeedConfig<'a, D, S>) -> Self {
         Self { config }
     }
 
-    fn generate_chunks(&self, dialect_max_params: usize) -> Vec<GeneratedChunk<'a>> {
+    /// Generate every table in seeding order, handing each batch of rows to
+    /// `emit` as soon as its table is done so only one table's rows are held
+    /// at a time.
+    fn generate_chunks(&self, dialect_max_params: usize, mut emit: impl FnMut(GeneratedChunk<'a>)) {
         let active_tables = self.config.active_tables();
         let order = topology::seeding_order(&active_tables);
         let table_map: HashMap<&str, &TableRef> =
             active_tables.iter().map(|t| (t.name, *t)).collect();
+        // Only columns that foreign keys point at are kept after their table.
+        let referenced: HashSet<(&str, &str)> = active_tables
+            .iter()
+            .flat_map(|t| t.foreign_keys)
+            .flat_map(|fk| fk.target_columns.iter().map(|col| (fk.target_table, *col)))
+            .collect();
 
         let mut generated_values: HashMap<(&'static str, &'static str), Vec<SeedValue>> =
             HashMap::new();
         let mut generated_counts: HashMap<&'static str, usize> = HashMap::new();
-        let mut chunks_out = Vec::new();
 
         for &table_name in &order {
             let Some(&table) = table_map.get(table_name) else {
@@ -248,53 +280,83 @@ where
                 continue;
             }
 
-            let count = self.derived_count_for(table, &generated_counts);
+            let relation_specs = self.relation_specs_for(table, &generated_counts);
+            let count = self.derived_count_for(table, &relation_specs);
             if count == 0 {
                 generated_counts.insert(table_name, 0);
                 continue;
             }
 
             let generators = self.build_generators(table);
+            let refiners = self
+                .config
+                .refiners
+                .get(table_name)
+                .map_or(&[][..], Vec::as_slice);
             let col_index_map: HashMap<&str, usize> = columns
                 .iter()
                 .enumerate()
                 .map(|(idx, col)| (col.name, idx))
                 .collect();
-            let relation_specs = self.relation_specs_for(table);
-
-            let mut all_rows: Vec<Vec<SeedValue>> = Vec::with_capacity(count);
-            let mut col_rngs: Vec<StdRng> = columns
+            let null_ratios: Vec<f64> = columns
                 .iter()
-                .map(|c| rng::column_rng(table_name, c.name, self.config.seed))
+                .map(|c| self.config.null_ratio_for(c))
                 .collect();
+            let seed = self.config.seed;
+
+            // Every column draws from its own RNG streams, so columns can be
+            // generated independently of each other.
+            let generated_columns = map_indexed(columns.len(), |col_idx| {
+                let col = &columns[col_idx];
+                let mut col_rng = rng::column_rng(table_name, col.name, seed);
+                let values: Vec<SeedValue> = (0..count)
+                    .map(|row_idx| {
+                        generators[col_idx].generate(&mut col_rng, row_idx, col.sql_type)
+                    })
+                    .collect();
+                let ratio = null_ratios[col_idx];
+                let nulls = (ratio > 0.0).then(|| {
+                    let mut null_rng = rng::null_rng(table_name, col.name, seed);
+                    (0..count)
+                        .map(|_| null_rng.random_bool(ratio))
+                        .collect::<Vec<_>>()
+                });
+                (values, nulls)
+            });
 
-            for row_idx in 0..count {
-                let mut row = Vec::with_capacity(columns.len());
-                for (col_idx, generator) in generators.iter().enumerate() {
-                    let val = generator.generate(
-                        &mut col_rngs[col_idx],
-                        row_idx,
-                        columns[col_idx].sql_type,
-                    );
-                    row.push(val);
-                }
+            let (values, nulls): (Vec<_>, Vec<_>) = generated_columns.into_iter().unzip();
+            let mut values: Vec<_> = values.into_iter().map(Vec::into_iter).collect();
+            let mut all_rows: Vec<Vec<SeedValue>> = (0..count)
+                .map(|_| values.iter_mut().filter_map(Iterator::next).collect())
+                .collect();
 
+            for_each_indexed(&mut all_rows, |row_idx, row| {
                 Self::apply_many_to_one_relations(
-                    &mut row,
+                    row,
                     &col_index_map,
                     &relation_specs,
                     row_idx,
                     &generated_values,
                 );
 
-                all_rows.push(row);
-            }
+                for (value, nulls) in row.iter_mut().zip(&nulls) {
+                    if nulls.as_ref().is_some_and(|nulls| nulls[row_idx]) {
+                        *value = SeedValue::Null;
+                    }
+                }
 
-            // Store generated values for all columns for FK/composite resolution
+                for refine in refiners {
+                    refine(columns, row, row_idx);
+                }
+            });
+
+            // Keep referenced columns for FK/composite resolution
             for (col_idx, col) in columns.iter().enumerate() {
-                let vals: Vec<SeedValue> =
-                    all_rows.iter().map(|row| row[col_idx].clone()).collect();
-                generated_values.insert((table_name, col.name), vals);
+                if referenced.contains(&(table_name, col.name)) {
+                    let vals: Vec<SeedValue> =
+                        all_rows.iter().map(|row| row[col_idx].clone()).collect();
+                    generated_values.insert((table_name, col.name), vals);
+                }
             }
 
             generated_counts.insert(table_name, count);
@@ -305,36 +367,26 @@ where
                 .unwrap_or(dialect_max_params)
                 .max(1);
 
-            for (start, end) in batch_ranges_by_param_limit(&all_rows, param_limit) {
-                chunks_out.push(GeneratedChunk {
+            let ranges = batch_ranges_by_param_limit(&all_rows, param_limit);
+            let mut rows = all_rows.into_iter();
+            for (start, end) in ranges {
+                emit(GeneratedChunk {
                     table,
-                    rows: all_rows[start..end].to_vec(),
+                    rows: rows.by_ref().take(end - start).collect(),
                 });
             }
         }
-
-        chunks_out
     }
 
-    fn derived_count_for(
-        &self,
-        table: &TableRef,
-        generated_counts: &HashMap<&'static str, usize>,
-    ) -> usize {
+    fn derived_count_for(&self, table: &TableRef, relation_specs: &[RelationSpec]) -> usize {
         if let Some(&count) = self.config.table_counts.get(table.name) {
             return count;
         }
 
         let mut derived: Option<usize> = None;
-        for parent_name in Self::parent_table_names(table) {
-            if let Some(&parent_count) = generated_counts.get(parent_name) {
-                let children_per_parent = self
-                    .config
-                    .relation_counts
-                    .get(&(parent_name, table.name))
-                    .copied()
-                    .unwrap_or(1);
-                let child_count = parent_count.saturating_mul(children_per_parent);
+        for rel in relation_specs {
+            if let Some(parent_slots) = &rel.parent_slots {
+                let child_count = parent_slots.len();
                 derived = Some(derived.map_or(child_count, |current| current.max(child_count)));
             }
         }
@@ -342,20 +394,6 @@ where
         derived.unwrap_or_else(|| self.config.count_for(table.name))
     }
 
-    fn parent_table_names(table: &TableRef) -> Vec<&'static str> {
-        let mut seen = HashSet::new();
-        let mut parent_names = Vec::new();
-
-        for fk in table.foreign_keys {
-            let parent = fk.target_table;
-            if parent != table.name && seen.insert(parent) {
-                parent_names.push(parent);
-            }
-        }
-
-        parent_names
-    }
-
     fn build_generators(&self, table: &TableRef) -> Vec<Box<dyn Generator>> {
         let table_name = table.name;
         table
@@ -382,28 +420,55 @@ where
             .collect()
     }
 
-    fn relation_specs_for(&self, source_table: &TableRef) -> Vec<RelationSpec> {
+    fn relation_specs_for(
+        &self,
+        source_table: &TableRef,
+        generated_counts: &HashMap<&'static str, usize>,
+    ) -> Vec<RelationSpec> {
         source_table
             .foreign_keys
             .iter()
             .map(|fk| {
-                let children_per_parent = self
-                    .config
-                    .relation_counts
-                    .get(&(fk.target_table, source_table.name))
-                    .copied()
-                    .unwrap_or(1);
+                let parent_slots = generated_counts
+                    .get(fk.target_table)
+                    .filter(|_| fk.target_table != source_table.name)
+                    .map(|&parent_count| {
+                        self.parent_slots(fk.target_table, source_table.name, parent_count)
+                    });
 
                 RelationSpec {
                     target_table: fk.target_table,
                     fk_columns: fk.source_columns,
                     ref_columns: fk.target_columns,
-                    children_per_parent,
+                    parent_slots,
                 }
             })
             .collect()
     }
 
+    /// Assign child rows to `parent` rows: every parent index, repeated by
+    /// its sampled children count.
+    fn parent_slots(
+        &self,
+        parent: &'static str,
+        child: &'static str,
+        parent_count: usize,
+    ) -> Vec<usize> {
+        let distribution = self
+            .config
+            .relation_counts
+            .get(&(parent, child))
+            .copied()
+            .unwrap_or(Distribution::Fixed(1));
+        let mut rng = rng::relation_rng(parent, child, self.config.seed);
+        let mut slots = Vec::new();
+        for parent_idx in 0..parent_count {
+            let children = distribution.sample(&mut rng);
+            slots.extend(std::iter::repeat_n(parent_idx, children));
+        }
+        slots
+    }
+
     fn apply_many_to_one_relations(
         row: &mut [SeedValue],
         col_index_map: &HashMap<&str, usize>,
@@ -416,17 +481,8 @@ where
                 continue;
             }
 
-            let parent_count = rel
-                .ref_columns
-                .first()
-                .and_then(|first_ref| {
-                    generated_values
-                        .get(&(rel.target_table, first_ref))
-                        .map(std::vec::Vec::len)
-                })
-                .unwrap_or(0);
-
-            if parent_count == 0 || rel.children_per_parent == 0 {
+            let parent_slots = rel.parent_slots.as_deref().unwrap_or_default();
+            if parent_slots.is_empty() {
                 for fk_col in rel.fk_columns {
                     if let Some(&fk_idx) = col_index_map.get(fk_col) {
                         row[fk_idx] = SeedValue::Null;
@@ -435,7 +491,7 @@ where
                 continue;
             }
 
-            let parent_idx = (row_idx / rel.children_per_parent) % parent_count;
+            let parent_idx = parent_slots[row_idx % parent_slots.len()];
             for (fk_col, ref_col) in rel.fk_columns.iter().zip(rel.ref_columns.iter()) {
                 let Some(&fk_idx) = col_index_map.get(fk_col) else {
                     continue;
@@ -456,26 +512,77 @@ where
 #[cfg(feature = "sqlite")]
 impl<S> Seeder<'_, Sqlite, S>
 where
-    S: drizzle_core::SQLSchemaImpl,
+    S: SeedSchema,
 {
     fn generate_sqlite(&self) -> Vec<SQLiteSeedStatement> {
-        self.generate_chunks(batch::SQLITE_MAX_PARAMS)
-            .iter()
-            .map(|chunk| build_sqlite_statement(chunk))
-            .collect()
+        let mut statements = Vec::new();
+        self.generate_chunks(batch::SQLITE_MAX_PARAMS, |chunk| {
+            statements.push(build_sqlite_statement(&chunk));
+        });
+        statements
+    }
+
+    fn generate_sqlite_script(&self) -> Vec<String> {
+        let mut statements = Vec::new();
+        self.generate_chunks(batch::SQLITE_MAX_PARAMS, |chunk| {
+            statements.push(build_sqlite_script_statement(&chunk));
+        });
+        statements
     }
 }
 
 #[cfg(feature = "postgres")]
 impl<S> Seeder<'_, Postgres, S>
 where
-    S: drizzle_core::SQLSchemaImpl,
+    S: SeedSchema,
 {
     fn generate_postgres(&self) -> Vec<PostgresSeedStatement> {
-        self.generate_chunks(batch::POSTGRES_MAX_PARAMS)
-            .iter()
-            .map(|chunk| build_postgres_statement(chunk))
-            .collect()
+        let mut statements = Vec::new();
+        self.generate_chunks(batch::POSTGRES_MAX_PARAMS, |chunk| {
+            statements.push(build_postgres_statement(&chunk));
+        });
+        statements
+    }
+
+    fn generate_postgres_script(&self) -> Vec<String> {
+        let mut statements = Vec::new();
+        self.generate_chunks(batch::POSTGRES_MAX_PARAMS, |chunk| {
+            statements.push(build_postgres_script_statement(&chunk));
+        });
+        statements
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Row generation: on a rayon pool with the `parallel` feature, serial otherwise
+// ---------------------------------------------------------------------------
+
+fn map_indexed<T: Send>(len: usize, f: impl Fn(usize) -> T + Send + Sync) -> Vec<T> {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        (0..len).into_par_iter().map(f).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        (0..len).map(f).collect()
+    }
+}
+
+fn for_each_indexed<T: Send>(items: &mut [T], f: impl Fn(usize, &mut T) + Send + Sync) {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        items
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(idx, item)| f(idx, item));
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        for (idx, item) in items.iter_mut().enumerate() {
+            f(idx, item);
+        }
     }
 }
 
@@ -534,28 +641,30 @@ where
     let columns = table.columns;
 
     let column_idents = SQL::join(
-        columns
-            .iter()
-            .map(|c| SQL::<'static, V>::ident(c.name.to_string())),
+        columns.iter().map(|c| SQL::<'static, V>::name(c.name)),
         Token::COMMA,
     );
 
-    let sql = SQL::<'static, V>::token(Token::INSERT)
+    let mut sql = SQL::<'static, V>::token(Token::INSERT)
         .push(Token::INTO)
-        .append(SQL::<'static, V>::ident(table.name.to_string()))
+        .append(SQL::<'static, V>::name(table.name))
         .append(column_idents.parens())
         .push(Token::VALUES);
 
-    let mut values_sql = SQL::<'static, V>::empty();
+    sql.reserve(
+        rows.len()
+            .saturating_mul(columns.len().saturating_mul(2) + 2),
+    );
     for (row_idx, row) in rows.iter().enumerate() {
         if row_idx > 0 {
-            values_sql = values_sql.push(Token::COMMA);
+            sql.push_mut(Token::COMMA);
         }
-        let row_sql = SQL::join(row.iter().cloned(), Token::COMMA);
-        values_sql = values_sql.append(row_sql.parens());
+        sql.push_mut(Token::LPAREN);
+        sql.append_mut(SQL::join(row.iter().cloned(), Token::COMMA));
+        sql.push_mut(Token::RPAREN);
     }
 
-    sql.append(values_sql).into_owned()
+    sql.into_owned()
 }
 
 #[cfg(feature = "sqlite")]
@@ -587,6 +696,46 @@ fn build_sqlite_statement(chunk: &GeneratedChunk<'_>) -> SQLiteSeedStatement {
     }
 }
 
+#[cfg(feature = "sqlite")]
+fn seed_value_to_sqlite_literal(value: &SeedValue) -> String {
+    match value {
+        SeedValue::Default => "DEFAULT".to_string(),
+        SeedValue::Null => "NULL".to_string(),
+        SeedValue::Integer(v) => v.to_string(),
+        // SQLite has no NaN literal and stores NaN as NULL anyway.
+        SeedValue::Float(v) if v.is_nan() => "NULL".to_string(),
+        SeedValue::Float(v) if v.is_infinite() => if v.is_sign_positive() {
+            "9e999"
+        } else {
+            "-9e999"
+        }
+        .to_string(),
+        SeedValue::Float(v) => format!("{v:?}"),
+        SeedValue::Text(v) => quote_literal(v),
+        SeedValue::Bool(v) => i64::from(*v).to_string(),
+        SeedValue::Blob(v) => format!("X'{}'", hex(v)),
+        SeedValue::CurrentTime => "CURRENT_TIMESTAMP".to_string(),
+    }
+}
+
+#[cfg(feature = "sqlite")]
+fn build_sqlite_script_statement(chunk: &GeneratedChunk<'_>) -> String {
+    let rows: Vec<Vec<SQL<'static, OwnedSQLiteValue>>> = chunk
+        .rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|value| SQL::raw(seed_value_to_sqlite_literal(value)))
+                .collect()
+        })
+        .collect();
+
+    SQLiteSeedStatement {
+        inner: build_insert_sql(chunk.table, &rows),
+    }
+    .sql()
+}
+
 #[cfg(feature = "postgres")]
 fn seed_value_to_postgres_sql(
     value: &SeedValue,
@@ -595,29 +744,79 @@ fn seed_value_to_postgres_sql(
     match value {
         SeedValue::Default => SQL::token(Token::DEFAULT),
         SeedValue::Null => SQL::param(Cow::Owned(OwnedPostgresValue::Null)),
-        SeedValue::Integer(v) => {
-            let ty = normalize_pg_type(col.sql_type);
-            let owned = if ty.contains("SMALLINT") {
-                let clamped = (*v).clamp(i64::from(i16::MIN), i64::from(i16::MAX));
-                // Clamp guarantees the value fits in i16, so try_from cannot fail.
-                OwnedPostgresValue::Smallint(i16::try_from(clamped).unwrap_or(0))
-            } else if ty.contains("INT") || ty.contains("SERIAL") {
-                let clamped = (*v).clamp(i64::from(i32::MIN), i64::from(i32::MAX));
-                // Clamp guarantees the value fits in i32, so try_from cannot fail.
-                OwnedPostgresValue::Integer(i32::try_from(clamped).unwrap_or(0))
-            } else {
-                OwnedPostgresValue::Bigint(*v)
-            };
-            SQL::param(Cow::Owned(owned))
-        }
+        SeedValue::Integer(v) => SQL::param(Cow::Owned(postgres_integer(*v, col))),
         SeedValue::Float(v) => SQL::param(Cow::Owned(OwnedPostgresValue::DoublePrecision(*v))),
-        SeedValue::Text(v) => SQL::param(Cow::Owned(OwnedPostgresValue::Text(v.clone()))),
+        SeedValue::Text(v) => SQL::param(Cow::Owned(postgres_text(v, col))),
         SeedValue::Bool(v) => SQL::param(Cow::Owned(OwnedPostgresValue::Boolean(*v))),
         SeedValue::Blob(v) => SQL::param(Cow::Owned(OwnedPostgresValue::Bytea(v.clone()))),
         SeedValue::CurrentTime => SQL::raw("now()"),
     }
 }
 
+/// Narrow an integer to the column's width, clamping out-of-range values.
+#[cfg(feature = "postgres")]
+fn postgres_integer(v: i64, col: &ColumnRef) -> OwnedPostgresValue {
+    let ty = normalize_pg_type(col.sql_type);
+    if ty.contains("SMALLINT") {
+        let clamped = v.clamp(i64::from(i16::MIN), i64::from(i16::MAX));
+        // Clamp guarantees the value fits in i16, so try_from cannot fail.
+        OwnedPostgresValue::Smallint(i16::try_from(clamped).unwrap_or(0))
+    } else if ty.contains("INT") || ty.contains("SERIAL") {
+        let clamped = v.clamp(i64::from(i32::MIN), i64::from(i32::MAX));
+        // Clamp guarantees the value fits in i32, so try_from cannot fail.
+        OwnedPostgresValue::Integer(i32::try_from(clamped).unwrap_or(0))
+    } else {
+        OwnedPostgresValue::Bigint(v)
+    }
+}
+
+/// Bind text as JSON for `json`/`jsonb` columns; the driver refuses to send
+/// a plain string to them.
+#[cfg(feature = "postgres")]
+fn postgres_text(text: &str, col: &ColumnRef) -> OwnedPostgresValue {
+    #[cfg(feature = "serde")]
+    {
+        let ty = normalize_pg_type(col.sql_type);
+        if (ty == "JSON" || ty == "JSONB")
+            && let Ok(value) = serde_json::from_str(text)
+        {
+            return if ty == "JSONB" {
+                OwnedPostgresValue::Jsonb(value)
+            } else {
+                OwnedPostgresValue::Json(value)
+            };
+        }
+    }
+    #[cfg(not(feature = "serde"))]
+    let _ = col;
+    OwnedPostgresValue::Text(text.to_string())
+}
+
+#[cfg(feature = "postgres")]
+fn seed_value_to_postgres_literal(value: &SeedValue, col: &ColumnRef) -> String {
+    match value {
+        SeedValue::Default => "DEFAULT".to_string(),
+        SeedValue::Null => "NULL".to_string(),
+        SeedValue::Integer(v) => match postgres_integer(*v, col) {
+            OwnedPostgresValue::Smallint(n) => n.to_string(),
+            OwnedPostgresValue::Integer(n) => n.to_string(),
+            _ => v.to_string(),
+        },
+        SeedValue::Float(v) if v.is_nan() => "'NaN'".to_string(),
+        SeedValue::Float(v) if v.is_infinite() => if v.is_sign_positive() {
+            "'Infinity'"
+        } else {
+            "'-Infinity'"
+        }
+        .to_string(),
+        SeedValue::Float(v) => format!("{v:?}"),
+        SeedValue::Text(v) => quote_literal(v),
+        SeedValue::Bool(v) => if *v { "TRUE" } else { "FALSE" }.to_string(),
+        SeedValue::Blob(v) => format!("'\\x{}'", hex(v)),
+        SeedValue::CurrentTime => "now()".to_string(),
+    }
+}
+
 #[cfg(feature = "postgres")]
 fn normalize_pg_type(sql_type: &str) -> String {
     let mut out = String::new();
@@ -655,6 +854,57 @@ fn build_postgres_statement(chunk: &GeneratedChunk<'_>) -> PostgresSeedStatement
     }
 }
 
+#[cfg(feature = "postgres")]
+fn build_postgres_script_statement(chunk: &GeneratedChunk<'_>) -> String {
+    let columns = chunk.table.columns;
+    let rows: Vec<Vec<SQL<'static, OwnedPostgresValue>>> = chunk
+        .rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .map(|(idx, value)| SQL::raw(seed_value_to_postgres_literal(value, &columns[idx])))
+                .collect()
+        })
+        .collect();
+
+    PostgresSeedStatement {
+        inner: build_insert_sql(chunk.table, &rows),
+    }
+    .sql()
+}
+
+/// Quote `text` as a standard SQL string literal.
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+fn quote_literal(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+fn hex(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(out, "{byte:02x}");
+    }
+    out
+}
+
+/// Write `statements` to `path` as a SQL script, one statement per line.
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+fn write_script(path: &std::path::Path, statements: &[String]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut script = String::new();
+    for stmt in statements {
+        script.push_str(stmt);
+        script.push_str(";\n");
+    }
+    std::fs::write(path, script)
+}
+
 // ---------------------------------------------------------------------------
 // Internal generator types
 // ---------------------------------------------------------------------------
@@ -729,6 +979,7 @@ where
                 generated_expression: None,
                 generated_stored: false,
                 collate: None,
+                comment: None,
             },
         };
         inference::infer_generator(&col_ref)
@@ -772,6 +1023,72 @@ mod tests {
         assert_eq!(g.name(), "IntPrimaryKey");
     }
 
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn sqlite_literals_escape_and_encode() {
+        assert_eq!(
+            seed_value_to_sqlite_literal(&SeedValue::Text("O'Brien".into())),
+            "'O''Brien'"
+        );
+        assert_eq!(
+            seed_value_to_sqlite_literal(&SeedValue::Blob(vec![0x00, 0xab])),
+            "X'00ab'"
+        );
+        assert_eq!(seed_value_to_sqlite_literal(&SeedValue::Bool(true)), "1");
+        assert_eq!(seed_value_to_sqlite_literal(&SeedValue::Float(2.0)), "2.0");
+        assert_eq!(seed_value_to_sqlite_literal(&SeedValue::Null), "NULL");
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn postgres_literals_clamp_and_encode() {
+        let smallint = ColumnRef {
+            sql_type: "smallint",
+            ..ColumnRef::sql("t", "c")
+        };
+        let bytea = ColumnRef {
+            sql_type: "bytea",
+            ..ColumnRef::sql("t", "c")
+        };
+        assert_eq!(
+            seed_value_to_postgres_literal(&SeedValue::Integer(100_000), &smallint),
+            "32767"
+        );
+        assert_eq!(
+            seed_value_to_postgres_literal(&SeedValue::Blob(vec![0x0a, 0xff]), &bytea),
+            "'\\x0aff'"
+        );
+        assert_eq!(
+            seed_value_to_postgres_literal(&SeedValue::Bool(false), &smallint),
+            "FALSE"
+        );
+        assert_eq!(
+            seed_value_to_postgres_literal(&SeedValue::Float(f64::NAN), &smallint),
+            "'NaN'"
+        );
+    }
+
+    #[cfg(all(feature = "postgres", feature = "serde"))]
+    #[test]
+    fn postgres_json_columns_bind_json() {
+        let jsonb = ColumnRef {
+            sql_type: "jsonb",
+            ..ColumnRef::sql("t", "c")
+        };
+        let text = ColumnRef {
+            sql_type: "text",
+            ..ColumnRef::sql("t", "c")
+        };
+        assert!(matches!(
+            postgres_text(r#"{"a": 1}"#, &jsonb),
+            OwnedPostgresValue::Jsonb(value) if value["a"] == 1
+        ));
+        assert!(matches!(
+            postgres_text(r#"{"a": 1}"#, &text),
+            OwnedPostgresValue::Text(_)
+        ));
+    }
+
     #[test]
     fn fk_gen_picks_from_parent_values() {
         use rand::SeedableRng;
@@ -847,6 +1164,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn indexed_helpers_keep_order() {
+        let squares = map_indexed(1000, |i| i * i);
+        assert!(squares.iter().enumerate().all(|(i, &v)| v == i * i));
+
+        let mut rows = vec![0; 1000];
+        for_each_indexed(&mut rows, |i, row| *row = i);
+        assert!(rows.iter().enumerate().all(|(i, &v)| v == i));
+    }
+
     #[test]
     fn batch_ranges_split_on_param_limit() {
         let rows = vec![
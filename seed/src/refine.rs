@@ -0,0 +1,202 @@
+//! Typed post-processing of generated rows.
+
+use crate::generator::SeedValue;
+use drizzle_core::{ColumnRef, SQLColumnInfo};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+#[cfg(feature = "sqlite")]
+use crate::Sqlite;
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+use drizzle_core::SQLColumn;
+#[cfg(feature = "sqlite")]
+use drizzle_sqlite::traits::FromSQLiteValue;
+#[cfg(feature = "sqlite")]
+use drizzle_sqlite::values::{SQLiteValue, SQLiteValueRef};
+
+#[cfg(feature = "postgres")]
+use crate::Postgres;
+#[cfg(feature = "postgres")]
+use drizzle_core::error::DrizzleError;
+#[cfg(feature = "postgres")]
+use drizzle_postgres::traits::FromPostgresValue;
+#[cfg(feature = "postgres")]
+use drizzle_postgres::values::{OwnedPostgresValue, PostgresValue};
+
+/// A type-erased refinement, run on every generated row of one table.
+pub(crate) type Refiner = Arc<dyn Fn(&[ColumnRef], &mut [SeedValue], usize) + Send + Sync>;
+
+/// One generated row of table `T`, handed to a
+/// [`SeedConfig::refine`](crate::SeedConfig) closure.
+///
+/// Columns are read and written as their Rust types, and only columns of `T`
+/// are accepted.
+pub struct SeedRow<'r, D, T> {
+    columns: &'r [ColumnRef],
+    values: &'r mut [SeedValue],
+    index: usize,
+    _marker: PhantomData<(D, fn() -> T)>,
+}
+
+impl<'r, D, T> SeedRow<'r, D, T> {
+    pub(crate) const fn new(
+        columns: &'r [ColumnRef],
+        values: &'r mut [SeedValue],
+        index: usize,
+    ) -> Self {
+        Self {
+            columns,
+            values,
+            index,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Position of this row in its table, starting at 0.
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    fn position(&self, column: &impl SQLColumnInfo) -> usize {
+        let name = column.name();
+        self.columns
+            .iter()
+            .position(|col| col.name == name)
+            .unwrap_or_else(|| panic!("column `{name}` is not seeded"))
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<T> SeedRow<'_, Sqlite, T> {
+    /// Read `column` as its Rust type.
+    ///
+    /// Columns left to the database default read as NULL.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generated value doesn't decode as the column's type.
+    pub fn get<C>(&self, column: &C) -> C::Type
+    where
+        C: SQLColumn<'static, SQLiteValue<'static>, Table = T>,
+        C::Type: FromSQLiteValue,
+    {
+        let value = match &self.values[self.position(column)] {
+            SeedValue::Integer(v) => SQLiteValueRef::Integer(*v),
+            SeedValue::Float(v) => SQLiteValueRef::Real(*v),
+            SeedValue::Text(v) => SQLiteValueRef::Text(v),
+            SeedValue::Blob(v) => SQLiteValueRef::Blob(v),
+            SeedValue::Bool(v) => SQLiteValueRef::Integer(i64::from(*v)),
+            SeedValue::Null | SeedValue::Default | SeedValue::CurrentTime => SQLiteValueRef::Null,
+        };
+        C::Type::from_sqlite_ref(value)
+            .unwrap_or_else(|e| panic!("cannot read seeded column `{}`: {e}", column.name()))
+    }
+
+    /// Replace the generated value of `column`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` can't be converted to a `SQLite` value.
+    pub fn set<C>(&mut self, column: &C, value: C::Type)
+    where
+        C: SQLColumn<'static, SQLiteValue<'static>, Table = T>,
+    {
+        let position = self.position(column);
+        let Ok(value) = value.try_into() else {
+            panic!(
+                "cannot convert the value for seeded column `{}`",
+                column.name()
+            );
+        };
+        self.values[position] = match value {
+            SQLiteValue::Integer(v) => SeedValue::Integer(v),
+            SQLiteValue::Real(v) => SeedValue::Float(v),
+            SQLiteValue::Text(v) => SeedValue::Text(v.into_owned()),
+            SQLiteValue::Blob(v) => SeedValue::Blob(v.into_owned()),
+            SQLiteValue::Null => SeedValue::Null,
+        };
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<T> SeedRow<'_, Postgres, T> {
+    /// Read `column` as its Rust type.
+    ///
+    /// Columns left to the database default read as NULL.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generated value doesn't decode as the column's type.
+    pub fn get<C>(&self, column: &C) -> C::Type
+    where
+        C: SQLColumn<'static, PostgresValue<'static>, Table = T>,
+        C::Type: FromPostgresValue,
+    {
+        let position = self.position(column);
+        let col = &self.columns[position];
+        let value = match &self.values[position] {
+            SeedValue::Integer(v) => crate::postgres_integer(*v, col),
+            SeedValue::Float(v) => OwnedPostgresValue::DoublePrecision(*v),
+            SeedValue::Text(v) => crate::postgres_text(v, col),
+            SeedValue::Blob(v) => OwnedPostgresValue::Bytea(v.clone()),
+            SeedValue::Bool(v) => OwnedPostgresValue::Boolean(*v),
+            SeedValue::Null | SeedValue::Default | SeedValue::CurrentTime => {
+                OwnedPostgresValue::Null
+            }
+        };
+        decode_postgres(value)
+            .unwrap_or_else(|e| panic!("cannot read seeded column `{}`: {e}", column.name()))
+    }
+
+    /// Replace the generated value of `column`.
+    ///
+    /// Values without a [`SeedValue`] counterpart, such as timestamps, are
+    /// stored as their text form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` can't be converted to a `PostgreSQL` value.
+    pub fn set<C>(&mut self, column: &C, value: C::Type)
+    where
+        C: SQLColumn<'static, PostgresValue<'static>, Table = T>,
+    {
+        let position = self.position(column);
+        let Ok(value) = value.try_into() else {
+            panic!(
+                "cannot convert the value for seeded column `{}`",
+                column.name()
+            );
+        };
+        self.values[position] = match value {
+            PostgresValue::Null => SeedValue::Null,
+            PostgresValue::Smallint(v) => SeedValue::Integer(i64::from(v)),
+            PostgresValue::Integer(v) => SeedValue::Integer(i64::from(v)),
+            PostgresValue::Bigint(v) => SeedValue::Integer(v),
+            PostgresValue::Real(v) => SeedValue::Float(f64::from(v)),
+            PostgresValue::DoublePrecision(v) => SeedValue::Float(v),
+            PostgresValue::Text(v) => SeedValue::Text(v.into_owned()),
+            PostgresValue::Bytea(v) => SeedValue::Blob(v.into_owned()),
+            PostgresValue::Boolean(v) => SeedValue::Bool(v),
+            other => SeedValue::Text(other.to_string()),
+        };
+    }
+}
+
+#[cfg(feature = "postgres")]
+fn decode_postgres<V: FromPostgresValue>(value: OwnedPostgresValue) -> Result<V, DrizzleError> {
+    match value {
+        OwnedPostgresValue::Smallint(v) => V::from_postgres_i16(v),
+        OwnedPostgresValue::Integer(v) => V::from_postgres_i32(v),
+        OwnedPostgresValue::Bigint(v) => V::from_postgres_i64(v),
+        OwnedPostgresValue::DoublePrecision(v) => V::from_postgres_f64(v),
+        OwnedPostgresValue::Text(v) => V::from_postgres_text(&v),
+        OwnedPostgresValue::Bytea(v) => V::from_postgres_bytes(&v),
+        OwnedPostgresValue::Boolean(v) => V::from_postgres_bool(v),
+        #[cfg(feature = "serde")]
+        OwnedPostgresValue::Json(v) => V::from_postgres_json(v),
+        #[cfg(feature = "serde")]
+        OwnedPostgresValue::Jsonb(v) => V::from_postgres_jsonb(v),
+        _ => V::from_postgres_null(),
+    }
+}
@@ -31,6 +31,20 @@ pub fn column_rng(table: &str, column: &str, seed: u64) -> StdRng {
     StdRng::seed_from_u64(column_seed(table, column, seed))
 }
 
+/// Create the RNG that decides which rows of `table.column` are NULL.
+///
+/// Kept apart from [`column_rng`] so NULL ratios don't shift the values
+/// generated for the remaining rows.
+pub fn null_rng(table: &str, column: &str, seed: u64) -> StdRng {
+    StdRng::seed_from_u64(fnv1a_extend(column_seed(table, column, seed), b"#null"))
+}
+
+/// Create the RNG that samples children-per-parent counts for the relation
+/// from `parent` to `child`.
+pub fn relation_rng(parent: &str, child: &str, seed: u64) -> StdRng {
+    StdRng::seed_from_u64(fnv1a_extend(column_seed(parent, child, seed), b"#relation"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,426 @@
+//! Seeding from a migration snapshot instead of a derived schema.
+//!
+//! The seeder works on the same `&'static TableRef` metadata the derive
+//! macros emit. A snapshot is only known at runtime, so its tables are
+//! converted once and leaked: build one [`SnapshotSchema`] per snapshot and
+//! reuse it rather than rebuilding it in a loop.
+
+use drizzle_core::{ColumnFlags, ColumnRef, ForeignKeyRef, PrimaryKeyRef, TableRef};
+use drizzle_migrations::snapshot::{Snapshot, SnapshotEntity};
+
+use crate::config::{SeedConfig, SeedSchema};
+
+/// Tables read from a migration snapshot.
+#[derive(Clone, Copy, Debug)]
+pub struct SnapshotSchema {
+    tables: &'static [&'static TableRef],
+}
+
+impl SnapshotSchema {
+    /// Build the seeder's view of every table in `snapshot`.
+    #[must_use]
+    pub fn new<E: SnapshotTables>(snapshot: &Snapshot<E>) -> Self {
+        Self {
+            tables: leak_slice(E::table_refs(&snapshot.ddl)),
+        }
+    }
+
+    /// Every table in the snapshot, in snapshot order.
+    #[must_use]
+    pub const fn tables(&self) -> &'static [&'static TableRef] {
+        self.tables
+    }
+}
+
+impl SeedSchema for SnapshotSchema {
+    fn seed_tables(&self) -> &[&'static TableRef] {
+        self.tables
+    }
+}
+
+/// Snapshot entity types a [`SnapshotSchema`] can be built from.
+pub trait SnapshotTables: SnapshotEntity + Sized {
+    /// Dialect marker of the resulting [`SeedConfig`].
+    type Dialect;
+
+    /// Convert the tables in `ddl`, with their columns, primary and foreign
+    /// keys.
+    fn table_refs(ddl: &[Self]) -> Vec<&'static TableRef>;
+}
+
+impl SeedConfig<'static, (), ()> {
+    /// Create a seeder config from a migration snapshot, for schemas that
+    /// aren't compiled into the program (e.g. `drizzle seed`).
+    ///
+    /// Columns are configured with the `*_by_name` methods.
+    ///
+    /// ```rust
+    /// # let _ = r####"
+    /// let snapshot = SQLiteSnapshot::load(Path::new("drizzle/20240101_init/snapshot.json"))?;
+    /// let stmts = SeedConfig::from_snapshot(&snapshot)
+    ///     .seed(42)
+    ///     .count_by_name("users", 100)
+    ///     .generate();
+    /// # "####;
+    /// ```
+    #[must_use]
+    pub fn from_snapshot<E: SnapshotTables>(
+        snapshot: &Snapshot<E>,
+    ) -> SeedConfig<'static, E::Dialect, SnapshotSchema> {
+        let schema: &'static SnapshotSchema = Box::leak(Box::new(SnapshotSchema::new(snapshot)));
+        SeedConfig::with_defaults(schema)
+    }
+}
+
+fn leak_str(s: &str) -> &'static str {
+    Box::leak(s.into())
+}
+
+fn leak_slice<T>(items: Vec<T>) -> &'static [T] {
+    Box::leak(items.into_boxed_slice())
+}
+
+fn leak_names<S: AsRef<str>>(names: &[S]) -> &'static [&'static str] {
+    leak_slice(names.iter().map(|n| leak_str(n.as_ref())).collect())
+}
+
+fn column_flags(not_null: bool, primary_key: bool, unique: bool, has_default: bool) -> ColumnFlags {
+    let mut flags = ColumnFlags::empty();
+    for (flag, set) in [
+        (ColumnFlags::NOT_NULL, not_null),
+        (ColumnFlags::PRIMARY_KEY, primary_key),
+        (ColumnFlags::UNIQUE, unique),
+        (ColumnFlags::HAS_DEFAULT, has_default),
+    ] {
+        if set {
+            flags |= flag;
+        }
+    }
+    flags
+}
+
+#[cfg(feature = "sqlite")]
+impl SnapshotTables for drizzle_migrations::sqlite::SqliteEntity {
+    type Dialect = crate::Sqlite;
+
+    fn table_refs(ddl: &[Self]) -> Vec<&'static TableRef> {
+        use drizzle_core::{ColumnDialect, TableDialect};
+        use drizzle_migrations::sqlite::{GeneratedType, SqliteEntity};
+        use std::collections::HashSet;
+
+        let mut tables = Vec::new();
+        for entity in ddl {
+            let SqliteEntity::Table(table) = entity else {
+                continue;
+            };
+            let name = leak_str(&table.name);
+
+            let primary_key = ddl.iter().find_map(|e| match e {
+                SqliteEntity::PrimaryKey(pk) if pk.table == table.name => Some(&pk.columns),
+                _ => None,
+            });
+            let mut pk_columns: Vec<&str> = primary_key
+                .map(|columns| columns.iter().map(AsRef::as_ref).collect())
+                .unwrap_or_default();
+            let unique_columns: HashSet<&str> = ddl
+                .iter()
+                .filter_map(|e| match e {
+                    SqliteEntity::UniqueConstraint(u)
+                        if u.table == table.name && u.columns.len() == 1 =>
+                    {
+                        Some(u.columns[0].as_ref())
+                    }
+                    _ => None,
+                })
+                .collect();
+
+            let mut columns = Vec::new();
+            for entity in ddl {
+                let SqliteEntity::Column(column) = entity else {
+                    continue;
+                };
+                if column.table != table.name {
+                    continue;
+                }
+                if column.primary_key == Some(true) && !pk_columns.contains(&column.name.as_ref()) {
+                    pk_columns.push(&column.name);
+                }
+                columns.push(ColumnRef {
+                    table: name,
+                    name: leak_str(&column.name),
+                    sql_type: leak_str(&column.sql_type),
+                    flags: column_flags(
+                        column.not_null,
+                        pk_columns.contains(&column.name.as_ref()),
+                        column.unique == Some(true)
+                            || unique_columns.contains(column.name.as_ref()),
+                        column.default.is_some() || column.generated.is_some(),
+                    ),
+                    dialect: ColumnDialect::SQLite {
+                        autoincrement: column.autoincrement == Some(true),
+                        default: column.default.as_deref().map(leak_str),
+                        generated_expression: column
+                            .generated
+                            .as_ref()
+                            .map(|g| leak_str(&g.expression)),
+                        generated_stored: column
+                            .generated
+                            .as_ref()
+                            .is_some_and(|g| g.gen_type == GeneratedType::Stored),
+                        collate: column.collate.as_deref().map(leak_str),
+                        comment: column.comment.as_deref().map(leak_str),
+                    },
+                });
+            }
+
+            let foreign_keys = ddl
+                .iter()
+                .filter_map(|e| match e {
+                    SqliteEntity::ForeignKey(fk) if fk.table == table.name => Some(ForeignKeyRef {
+                        name: leak_str(&fk.name),
+                        name_explicit: fk.name_explicit,
+                        target_table: leak_str(&fk.table_to),
+                        target_schema: "",
+                        source_columns: leak_names(&fk.columns),
+                        target_columns: leak_names(&fk.columns_to),
+                        on_delete: fk.on_delete.as_deref().map(leak_str),
+                        on_update: fk.on_update.as_deref().map(leak_str),
+                        deferrable: false,
+                        initially_deferred: false,
+                    }),
+                    _ => None,
+                })
+                .collect();
+
+            tables.push(leak_table(
+                name,
+                None,
+                name,
+                columns,
+                &pk_columns,
+                foreign_keys,
+                TableDialect::SQLite {
+                    without_rowid: table.without_rowid,
+                    strict: table.strict,
+                    comment: table.comment.as_deref().map(leak_str),
+                },
+            ));
+        }
+        tables
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl SnapshotTables for drizzle_migrations::postgres::PostgresEntity {
+    type Dialect = crate::Postgres;
+
+    fn table_refs(ddl: &[Self]) -> Vec<&'static TableRef> {
+        use drizzle_core::{ColumnDialect, TableDialect};
+        use drizzle_migrations::postgres::PostgresEntity;
+        use drizzle_migrations::postgres::ddl::{GeneratedType, IdentityType};
+        use std::collections::HashSet;
+
+        let mut tables = Vec::new();
+        for entity in ddl {
+            let PostgresEntity::Table(table) = entity else {
+                continue;
+            };
+            let owns = |schema: &str, name: &str| schema == table.schema && name == table.name;
+            let name = leak_str(&table.name);
+            let schema = leak_str(&table.schema);
+
+            let pk_columns: Vec<&str> = ddl
+                .iter()
+                .find_map(|e| match e {
+                    PostgresEntity::PrimaryKey(pk) if owns(&pk.schema, &pk.table) => {
+                        Some(pk.columns.iter().map(AsRef::as_ref).collect())
+                    }
+                    _ => None,
+                })
+                .unwrap_or_default();
+            let unique_columns: HashSet<&str> = ddl
+                .iter()
+                .filter_map(|e| match e {
+                    PostgresEntity::UniqueConstraint(u)
+                        if owns(&u.schema, &u.table) && u.columns.len() == 1 =>
+                    {
+                        Some(u.columns[0].as_ref())
+                    }
+                    _ => None,
+                })
+                .collect();
+
+            let columns = ddl
+                .iter()
+                .filter_map(|e| match e {
+                    PostgresEntity::Column(column) if owns(&column.schema, &column.table) => {
+                        Some(column)
+                    }
+                    _ => None,
+                })
+                .map(|column| {
+                    let sql_type = column.sql_type.to_ascii_lowercase();
+                    ColumnRef {
+                        table: name,
+                        name: leak_str(&column.name),
+                        sql_type: leak_str(&column.sql_type),
+                        flags: column_flags(
+                            column.not_null,
+                            pk_columns.contains(&column.name.as_ref()),
+                            unique_columns.contains(column.name.as_ref()),
+                            column.default.is_some()
+                                || column.generated.is_some()
+                                || column.identity.is_some(),
+                        ),
+                        dialect: ColumnDialect::PostgreSQL {
+                            postgres_type: leak_str(&column.sql_type),
+                            dimensions: column.dimensions,
+                            is_serial: sql_type == "serial",
+                            is_bigserial: sql_type == "bigserial",
+                            is_generated_identity: column.identity.is_some(),
+                            is_identity_always: column
+                                .identity
+                                .as_ref()
+                                .is_some_and(|i| i.type_ == IdentityType::Always),
+                            default: column.default.as_deref().map(leak_str),
+                            generated_expression: column
+                                .generated
+                                .as_ref()
+                                .map(|g| leak_str(&g.expression)),
+                            generated_stored: column
+                                .generated
+                                .as_ref()
+                                .is_some_and(|g| g.gen_type == GeneratedType::Stored),
+                            collate: column.collate.as_deref().map(leak_str),
+                            comment: column.comment.as_deref().map(leak_str),
+                        },
+                    }
+                })
+                .collect();
+
+            let foreign_keys = ddl
+                .iter()
+                .filter_map(|e| match e {
+                    PostgresEntity::ForeignKey(fk) if owns(&fk.schema, &fk.table) => {
+                        Some(ForeignKeyRef {
+                            name: leak_str(&fk.name),
+                            name_explicit: fk.name_explicit,
+                            target_table: leak_str(&fk.table_to),
+                            target_schema: leak_str(&fk.schema_to),
+                            source_columns: leak_names(&fk.columns),
+                            target_columns: leak_names(&fk.columns_to),
+                            on_delete: fk.on_delete.as_deref().map(leak_str),
+                            on_update: fk.on_update.as_deref().map(leak_str),
+                            deferrable: fk.deferrable,
+                            initially_deferred: fk.initially_deferred,
+                        })
+                    }
+                    _ => None,
+                })
+                .collect();
+
+            tables.push(leak_table(
+                name,
+                Some(schema),
+                leak_str(&format!("{schema}.{name}")),
+                columns,
+                &pk_columns,
+                foreign_keys,
+                TableDialect::PostgreSQL {
+                    is_unlogged: table.is_unlogged == Some(true),
+                    is_temporary: table.is_temporary == Some(true),
+                    inherits: table.inherits.as_deref().map(leak_str),
+                    tablespace: table.tablespace.as_deref().map(leak_str),
+                    is_rls_enabled: table.is_rls_enabled == Some(true),
+                    comment: table.comment.as_deref().map(leak_str),
+                },
+            ));
+        }
+        tables
+    }
+}
+
+fn leak_table(
+    name: &'static str,
+    schema: Option<&'static str>,
+    qualified_name: &'static str,
+    columns: Vec<ColumnRef>,
+    pk_columns: &[&str],
+    foreign_keys: Vec<ForeignKeyRef>,
+    dialect: drizzle_core::TableDialect,
+) -> &'static TableRef {
+    let column_names = leak_slice(columns.iter().map(|c| c.name).collect());
+    Box::leak(Box::new(TableRef {
+        name,
+        column_names,
+        schema,
+        qualified_name,
+        columns: leak_slice(columns),
+        primary_key: (!pk_columns.is_empty()).then(|| PrimaryKeyRef {
+            columns: leak_names(pk_columns),
+        }),
+        foreign_keys: leak_slice(foreign_keys),
+        constraints: &[],
+        dependency_names: &[],
+        dialect,
+    }))
+}
+
+#[cfg(all(test, feature = "postgres"))]
+mod tests {
+    use super::*;
+    use drizzle_migrations::postgres::PostgresSnapshot;
+    use drizzle_migrations::postgres::ddl::{
+        Column, ForeignKey, PostgresEntity, PrimaryKey, Table,
+    };
+
+    #[test]
+    fn postgres_snapshot_tables_carry_keys() {
+        let mut snapshot = PostgresSnapshot::new();
+        snapshot.add_entity(PostgresEntity::Table(Table::new("public", "users")));
+        snapshot.add_entity(PostgresEntity::Column(
+            Column::new("public", "users", "id", "serial").not_null(),
+        ));
+        snapshot.add_entity(PostgresEntity::Column(
+            Column::new("public", "users", "created_at", "timestamp").default_value("now()"),
+        ));
+        snapshot.add_entity(PostgresEntity::PrimaryKey(PrimaryKey::from_strings(
+            "public".to_string(),
+            "users".to_string(),
+            "users_pkey".to_string(),
+            vec!["id".to_string()],
+        )));
+        snapshot.add_entity(PostgresEntity::Table(Table::new("public", "posts")));
+        snapshot.add_entity(PostgresEntity::Column(Column::new(
+            "public",
+            "posts",
+            "author_id",
+            "integer",
+        )));
+        snapshot.add_entity(PostgresEntity::ForeignKey(ForeignKey::from_strings(
+            "public".to_string(),
+            "posts".to_string(),
+            "posts_author_id_fkey".to_string(),
+            vec!["author_id".to_string()],
+            "public".to_string(),
+            "users".to_string(),
+            vec!["id".to_string()],
+        )));
+
+        let tables = SnapshotSchema::new(&snapshot).tables();
+        assert_eq!(tables.len(), 2);
+
+        let users = tables[0];
+        assert_eq!(users.qualified_name, "public.users");
+        assert_eq!(users.column_names, &["id", "created_at"]);
+        assert_eq!(users.primary_key.map(|pk| pk.columns), Some(&["id"][..]));
+        assert!(users.columns[0].primary_key() && users.columns[0].not_null());
+        assert!(users.columns[1].has_default() && !users.columns[1].primary_key());
+
+        let posts = tables[1];
+        assert_eq!(posts.foreign_keys.len(), 1);
+        assert_eq!(posts.foreign_keys[0].target_table, "users");
+        assert_eq!(posts.foreign_keys[0].source_columns, &["author_id"]);
+        assert_eq!(posts.foreign_keys[0].target_columns, &["id"]);
+    }
+}
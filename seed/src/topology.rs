@@ -98,6 +98,7 @@ mod tests {
             dialect: TableDialect::SQLite {
                 without_rowid: false,
                 strict: false,
+                comment: None,
             },
         }
     }
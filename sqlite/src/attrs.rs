@@ -135,6 +135,20 @@ pub const JSONB: ColumnMarker = ColumnMarker;
 /// The enum must derive `SQLiteEnum`.
 pub const ENUM: ColumnMarker = ColumnMarker;
 
+/// Marks this column as storing an enum with data-carrying variants as JSON TEXT.
+///
+/// ## Example
+/// ```rust
+/// # let _ = r####"
+/// #[column(json_enum)]
+/// status: JobStatus, // JobStatus::Failed { reason: String }
+/// # "####;
+/// ```
+///
+/// Requires the `serde` feature. The enum must derive `SQLiteEnum`,
+/// `Serialize` and `Deserialize`.
+pub const JSON_ENUM: ColumnMarker = ColumnMarker;
+
 //------------------------------------------------------------------------------
 // Default Value Parameters
 //------------------------------------------------------------------------------
@@ -389,6 +403,9 @@ pub const NO_ACTION: ColumnMarker = ColumnMarker;
 /// # "####;
 /// ```
 ///
+/// Index members take a `collate` field attribute:
+/// `struct EmailIdx(#[collate = "NOCASE"] Users::email);`
+///
 /// See: <https://sqlite.org/datatype3.html#collation>
 pub const COLLATE: ColumnMarker = ColumnMarker;
 
@@ -450,6 +467,32 @@ pub struct NameMarker;
 /// ```
 pub const NAME: NameMarker = NameMarker;
 
+//------------------------------------------------------------------------------
+// Comment Marker (shared by column and table attributes)
+//------------------------------------------------------------------------------
+
+/// Marker struct for the COMMENT attribute.
+#[derive(Debug, Clone, Copy)]
+pub struct CommentMarker;
+
+/// Attaches a comment to a table or column.
+///
+/// Doc comments (`///`) are used by default; `COMMENT` overrides them.
+/// `SQLite` has no `COMMENT ON`, so comments are recorded in migration
+/// snapshots only and re-emitted as doc comments by schema codegen.
+///
+/// ## Example
+/// ```rust
+/// # let _ = r####"
+/// #[SQLiteTable(COMMENT = "Registered accounts")]
+/// struct Users {
+///     #[column(COMMENT = "Login e-mail, unique per account")]
+///     email: String,
+/// }
+/// # "####;
+/// ```
+pub const COMMENT: CommentMarker = CommentMarker;
+
 //------------------------------------------------------------------------------
 // View Attribute Markers
 //------------------------------------------------------------------------------
@@ -565,6 +608,55 @@ pub const STRICT: TableMarker = TableMarker;
 /// See: <https://sqlite.org/withoutrowid.html>
 pub const WITHOUT_ROWID: TableMarker = TableMarker;
 
+/// Sets how the struct and field names map to table and column names:
+/// `"snake_case"` (the default) or `"camelCase"`, like drizzle-kit's
+/// `casing` option. Explicit `NAME` / `name` overrides still win.
+///
+/// Set the same `casing` in `drizzle.toml` so `drizzle generate` names
+/// tables without the attribute the same way.
+///
+/// ## Example
+/// ```rust
+/// # let _ = r####"
+/// // Table `userAccounts` with columns `id` and `displayName`
+/// #[SQLiteTable(CASING = "camelCase")]
+/// struct UserAccounts {
+///     #[column(PRIMARY)]
+///     id: i64,
+///     display_name: String,
+/// }
+/// # "####;
+/// ```
+///
+/// References from other tables resolve the target column by its field
+/// name, so columns used as reference targets should keep single-word
+/// field names or an explicit `name`.
+pub const CASING: TableMarker = TableMarker;
+
+/// Places the table in an attached database, so queries render it as
+/// `"archive"."table"`.
+///
+/// ## Example
+/// ```rust
+/// # let _ = r####"
+/// #[SQLiteTable(DATABASE = "archive")]
+/// struct ArchivedOrders {
+///     #[column(primary)]
+///     id: i64,
+///     total: f64,
+/// }
+///
+/// db.attach("archive.db", "archive")?;
+/// # "####;
+/// ```
+///
+/// The database must be attached on the connection before the table is used.
+/// Migrations manage the main database only, so attached tables are left out
+/// of migration snapshots.
+///
+/// See: <https://sqlite.org/lang_attach.html>
+pub const DATABASE: TableMarker = TableMarker;
+
 //------------------------------------------------------------------------------
 // Column Type Markers
 //------------------------------------------------------------------------------
@@ -3,3 +3,22 @@
 use crate::values::SQLiteValue;
 
 drizzle_core::impl_cte_types!(value_type: SQLiteValue<'a>);
+
+/// Tuple `(&view, condition)`: joins a CTE or temporary table by name.
+impl<'a, Table, Query, C, T> crate::helpers::JoinArg<'a, T> for (&CTEView<'a, Table, Query>, C)
+where
+    Query: drizzle_core::ToSQL<'a, SQLiteValue<'a>>,
+    C: drizzle_core::ToSQL<'a, SQLiteValue<'a>>,
+{
+    type JoinedTable = Table;
+
+    fn into_join_sql(self, join: drizzle_core::Join) -> drizzle_core::SQL<'a, SQLiteValue<'a>> {
+        use drizzle_core::ToSQL;
+
+        let (view, condition) = self;
+        join.to_sql()
+            .append(view.to_sql())
+            .push(drizzle_core::Token::ON)
+            .append(&condition)
+    }
+}
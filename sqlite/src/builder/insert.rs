@@ -1,10 +1,11 @@
+use crate::common::SQLiteSchemaType;
 use crate::traits::SQLiteTable;
 use crate::values::SQLiteValue;
 use core::marker::PhantomData;
 use drizzle_core::builder::{
     ConflictColumnsTarget, OnConflictBuilder as CoreOnConflictBuilder, OnConflictOutput,
 };
-use drizzle_core::{ConflictTarget, SQL, SQLModel, ToSQL, Token};
+use drizzle_core::{ConflictTarget, SQL, SQLModel, SQLPrimaryKey, SQLTable, ToSQL, Token};
 
 //------------------------------------------------------------------------------
 // Type State Markers
@@ -243,7 +244,7 @@ impl<'a, S, T> InsertBuilder<'a, S, InsertValuesSet, T> {
     /// ```
     pub fn on_conflict<C: ConflictTarget<T>>(self, target: C) -> OnConflictBuilder<'a, S, T> {
         let columns = target.conflict_columns();
-        let target_sql = SQL::join(columns.iter().map(|c| SQL::ident(*c)), Token::COMMA);
+        let target_sql = SQL::join(columns.iter().map(|c| SQL::name(c)), Token::COMMA);
         OnConflictBuilder::new(self.sql, ConflictColumnsTarget::new(target_sql))
     }
 
@@ -282,6 +283,23 @@ impl<'a, S, T> InsertBuilder<'a, S, InsertValuesSet, T> {
             grouped: PhantomData,
         }
     }
+
+    /// Adds `RETURNING` for the table's primary key.
+    ///
+    /// Running the query yields one generated id per inserted row, in the
+    /// order of the `VALUES` list. Only tables with a single-column primary
+    /// key are accepted.
+    #[inline]
+    pub fn returning_ids<Pk>(self) -> ReturningBuilder<'a, S, T, Pk>
+    where
+        T: SQLiteTable<'a>,
+        <T as SQLTable<'a, SQLiteSchemaType, SQLiteValue<'a>>>::PrimaryKey:
+            SQLPrimaryKey<Columns = (Pk,)>,
+        Pk: Default + ToSQL<'a, SQLiteValue<'a>> + drizzle_core::IntoSelectTarget,
+        Pk::Marker: drizzle_core::ResolveRow<T>,
+    {
+        self.returning(Pk::default())
+    }
 }
 
 //------------------------------------------------------------------------------
@@ -31,13 +31,21 @@ pub use select::{
     SelectFromSet, SelectGroupSet, SelectInitial, SelectJoinSet, SelectLimitSet, SelectOffsetSet,
     SelectOrderSet, SelectSetOpSet, SelectWhereSet,
 };
-pub use update::{UpdateInitial, UpdateReturningSet, UpdateSetClauseSet, UpdateWhereSet};
+pub use update::{
+    UpdateFromSet, UpdateInitial, UpdateReturningSet, UpdateSetClauseSet, UpdateWhereSet,
+};
 
 #[derive(Debug, Clone)]
 pub struct CTEInit;
 
 impl ExecutableState for CTEInit {}
 
+/// Marker for a `CREATE TEMP TABLE ... AS SELECT ...` statement.
+#[derive(Debug, Clone)]
+pub struct CreateTempTableSet;
+
+impl ExecutableState for CreateTempTableSet {}
+
 /// Main query builder for `SQLite` operations.
 ///
 /// `QueryBuilder` provides a type-safe, fluent API for building SQL queries. It uses compile-time
@@ -902,6 +910,92 @@ impl<'a, Schema> QueryBuilder<'a, Schema, BuilderInit> {
             grouped: PhantomData,
         }
     }
+
+    /// Creates a temporary table populated by the view's query.
+    ///
+    /// The view (usually from `.into_cte::<Tag>()`) doubles as the handle for the
+    /// new table: it renders as the table name and derefs to the aliased table, so
+    /// later statements on the same connection can select from and join it with
+    /// typed columns.
+    ///
+    /// ```rust
+    /// # mod drizzle {
+    /// #     pub mod core { pub use drizzle_core::*; }
+    /// #     pub mod error { pub use drizzle_core::error::*; }
+    /// #     pub mod types { pub use drizzle_types::*; }
+    /// #     pub mod migrations { pub use drizzle_migrations::*; }
+    /// #     pub use drizzle_types::Dialect;
+    /// #     pub use drizzle_types as ddl;
+    /// #     pub mod sqlite {
+    /// #         pub use drizzle_sqlite::*;
+    /// #         #[cfg(feature = "rusqlite")]
+    /// #         pub mod rusqlite { pub use ::rusqlite::{Error, Result, Row, types}; }
+    /// #         #[cfg(feature = "libsql")]
+    /// #         pub mod libsql { pub use ::libsql::{Row, Value}; }
+    /// #         #[cfg(feature = "turso")]
+    /// #         pub mod turso { pub use ::turso::{Error, IntoValue, Result, Row, Value}; }
+    /// #         pub mod prelude {
+    /// #             pub use drizzle_macros::{SQLiteTable, SQLiteSchema};
+    /// #             pub use drizzle_sqlite::{*, attrs::*};
+    /// #             pub use drizzle_core::*;
+    /// #         }
+    /// #     }
+    /// # }
+    /// # use drizzle::sqlite::prelude::*;
+    /// # use drizzle::core::expr::{eq, gt};
+    /// # use drizzle::sqlite::builder::QueryBuilder;
+    /// # #[SQLiteTable(name = "users")] struct User { #[column(primary)] id: i32, name: String }
+    /// # #[SQLiteTable(name = "posts")] struct Post { #[column(primary)] id: i32, user_id: i32, title: String }
+    /// # #[derive(SQLiteSchema)] struct Schema { user: User, post: Post }
+    /// # let builder = QueryBuilder::new::<Schema>();
+    /// # let Schema { user, post } = Schema::new();
+    /// struct ActiveUsers;
+    /// impl drizzle::core::Tag for ActiveUsers {
+    ///     const NAME: &'static str = "active_users";
+    /// }
+    ///
+    /// let active = builder
+    ///     .select((user.id, user.name))
+    ///     .from(user)
+    ///     .r#where(gt(user.id, 10))
+    ///     .into_cte::<ActiveUsers>();
+    ///
+    /// let create = builder.create_temp_table_as(&active);
+    /// assert_eq!(
+    ///     create.to_sql().sql(),
+    ///     r#"CREATE TEMP TABLE "active_users" AS SELECT "users"."id", "users"."name" FROM "users" WHERE "users"."id" > ?"#
+    /// );
+    ///
+    /// let query = builder
+    ///     .select(post.title)
+    ///     .from(post)
+    ///     .join((&active, eq(active.id, post.user_id)));
+    /// assert_eq!(
+    ///     query.to_sql().sql(),
+    ///     r#"SELECT "posts"."title" FROM "posts" JOIN "active_users" ON "active_users"."id" = "posts"."user_id""#
+    /// );
+    /// ```
+    pub fn create_temp_table_as<Table, Query>(
+        &self,
+        view: &CTEView<'a, Table, Query>,
+    ) -> QueryBuilder<'a, Schema, CreateTempTableSet>
+    where
+        Query: ToSQL<'a, SQLiteValue<'a>>,
+    {
+        let sql = SQL::from_iter([Token::CREATE, Token::TEMP, Token::TABLE])
+            .append(view.to_sql())
+            .push(Token::AS)
+            .append(view.query().to_sql());
+        QueryBuilder {
+            sql,
+            schema: PhantomData,
+            state: PhantomData,
+            table: PhantomData,
+            marker: PhantomData,
+            row: PhantomData,
+            grouped: PhantomData,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -4,6 +4,9 @@ use crate::values::SQLiteValue;
 use core::marker::PhantomData;
 use drizzle_core::ToSQL;
 
+// Import the ExecutableState trait
+use super::ExecutableState;
+
 //------------------------------------------------------------------------------
 // Type State Markers
 //------------------------------------------------------------------------------
@@ -12,6 +15,13 @@ pub use drizzle_core::builder::{
     UpdateInitial, UpdateReturningSet, UpdateSetClauseSet, UpdateWhereSet,
 };
 
+/// Marker for the state after FROM clause
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UpdateFromSet;
+
+// Mark states that can execute update queries
+impl ExecutableState for UpdateFromSet {}
+
 //------------------------------------------------------------------------------
 // UpdateBuilder Definition
 //------------------------------------------------------------------------------
@@ -269,6 +279,71 @@ where
 //------------------------------------------------------------------------------
 
 impl<'a, S, T> UpdateBuilder<'a, S, UpdateSetClauseSet, T> {
+    /// Adds a FROM clause and transitions to the `FromSet` state.
+    ///
+    /// Rows of the source can be referenced in the SET values and the WHERE
+    /// clause, updating the target from a join (`UPDATE ... FROM`, `SQLite` 3.33+).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # mod drizzle {
+    /// #     pub mod core { pub use drizzle_core::*; }
+    /// #     pub mod error { pub use drizzle_core::error::*; }
+    /// #     pub mod types { pub use drizzle_types::*; }
+    /// #     pub mod migrations { pub use drizzle_migrations::*; }
+    /// #     pub use drizzle_types::Dialect;
+    /// #     pub use drizzle_types as ddl;
+    /// #     pub mod sqlite {
+    /// #         pub use drizzle_sqlite::*;
+    /// #         #[cfg(feature = "rusqlite")]
+    /// #         pub mod rusqlite { pub use ::rusqlite::{Error, Result, Row, types}; }
+    /// #         #[cfg(feature = "libsql")]
+    /// #         pub mod libsql { pub use ::libsql::{Row, Value}; }
+    /// #         #[cfg(feature = "turso")]
+    /// #         pub mod turso { pub use ::turso::{Error, IntoValue, Result, Row, Value}; }
+    /// #         pub mod prelude {
+    /// #             pub use drizzle_macros::{SQLiteTable, SQLiteSchema};
+    /// #             pub use drizzle_sqlite::{*, attrs::*};
+    /// #             pub use drizzle_core::*;
+    /// #         }
+    /// #     }
+    /// # }
+    /// # use drizzle::sqlite::prelude::*;
+    /// # use drizzle::core::expr::eq;
+    /// # use drizzle::sqlite::builder::QueryBuilder;
+    /// # #[SQLiteTable(name = "users")] struct User { #[column(primary)] id: i32, name: String }
+    /// # #[SQLiteTable(name = "renames")] struct Rename { #[column(primary)] user_id: i32, name: String }
+    /// # #[derive(SQLiteSchema)] struct Schema { user: User, rename: Rename }
+    /// # let builder = QueryBuilder::new::<Schema>();
+    /// # let Schema { user, rename } = Schema::new();
+    /// let query = builder
+    ///     .update(user)
+    ///     .set(UpdateUser::default().with_name(rename.name))
+    ///     .from(rename)
+    ///     .r#where(eq(user.id, rename.user_id));
+    /// assert_eq!(
+    ///     query.to_sql().sql(),
+    ///     r#"UPDATE "users" SET "name" = "renames"."name" FROM "renames" WHERE "users"."id" = "renames"."user_id""#
+    /// );
+    /// ```
+    #[inline]
+    pub fn from(
+        self,
+        source: impl ToSQL<'a, SQLiteValue<'a>>,
+    ) -> UpdateBuilder<'a, S, UpdateFromSet, T> {
+        let from_sql = crate::helpers::from(source);
+        UpdateBuilder {
+            sql: self.sql.append(from_sql),
+            schema: PhantomData,
+            state: PhantomData,
+            table: PhantomData,
+            marker: PhantomData,
+            row: PhantomData,
+            grouped: PhantomData,
+        }
+    }
+
     /// Adds a WHERE clause to specify which rows to update.
     ///
     /// Without a WHERE clause, all rows in the table would be updated. This method
@@ -360,6 +435,50 @@ impl<'a, S, T> UpdateBuilder<'a, S, UpdateSetClauseSet, T> {
     }
 }
 
+//------------------------------------------------------------------------------
+// Post-FROM Implementation
+//------------------------------------------------------------------------------
+
+impl<'a, S, T> UpdateBuilder<'a, S, UpdateFromSet, T> {
+    /// Adds a WHERE condition after FROM
+    #[inline]
+    pub fn r#where<E>(self, condition: E) -> UpdateBuilder<'a, S, UpdateWhereSet, T>
+    where
+        E: drizzle_core::expr::Expr<'a, SQLiteValue<'a>>,
+        E::SQLType: drizzle_core::types::BooleanLike,
+    {
+        let where_sql = crate::helpers::r#where(condition);
+        UpdateBuilder {
+            sql: self.sql.append(where_sql),
+            schema: PhantomData,
+            state: PhantomData,
+            table: PhantomData,
+            marker: PhantomData,
+            row: PhantomData,
+            grouped: PhantomData,
+        }
+    }
+
+    /// Adds a RETURNING clause after FROM
+    #[inline]
+    pub fn returning<Columns>(self, columns: Columns) -> ReturningBuilder<'a, S, T, Columns>
+    where
+        Columns: ToSQL<'a, SQLiteValue<'a>> + drizzle_core::IntoSelectTarget,
+        Columns::Marker: drizzle_core::ResolveRow<T>,
+    {
+        let returning_sql = crate::helpers::returning(columns);
+        UpdateBuilder {
+            sql: self.sql.append(returning_sql),
+            schema: PhantomData,
+            state: PhantomData,
+            table: PhantomData,
+            marker: PhantomData,
+            row: PhantomData,
+            grouped: PhantomData,
+        }
+    }
+}
+
 //------------------------------------------------------------------------------
 // Post-WHERE Implementation
 //------------------------------------------------------------------------------
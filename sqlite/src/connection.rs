@@ -1,4 +1,27 @@
-//! Transaction-type marker and per-driver behavior conversions for `SQLite` drivers.
+//! Transaction-type marker, per-driver behavior conversions and execution
+//! results for `SQLite` drivers.
+
+/// Outcome of a statement run with a builder's `run()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ExecuteResult {
+    /// Number of rows inserted, updated or deleted.
+    pub rows_affected: u64,
+    /// Rowid of the last row inserted, or `None` when the statement was not
+    /// an `INSERT` or inserted nothing (e.g. `ON CONFLICT DO NOTHING`).
+    pub last_insert_rowid: Option<i64>,
+}
+
+impl ExecuteResult {
+    /// Builds a result from the connection's change count and
+    /// `last_insert_rowid()`, both read right after the statement ran.
+    #[must_use]
+    pub fn new(rows_affected: u64, inserts: bool, last_insert_rowid: i64) -> Self {
+        Self {
+            rows_affected,
+            last_insert_rowid: (inserts && rows_affected > 0).then_some(last_insert_rowid),
+        }
+    }
+}
 
 /// `SQLite` transaction types
 #[derive(Default, Debug, Clone, Copy)]
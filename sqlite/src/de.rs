@@ -0,0 +1,390 @@
+//! Serde-based row decoding.
+//!
+//! [`RowDeserializer`] presents one result row to serde as a map keyed by
+//! column name, so any `Deserialize` type can be read from a query without
+//! also deriving `FromRow`. Field renames, `#[serde(flatten)]`, defaults and
+//! unit enums stored as text all behave as they do for JSON input.
+//!
+//! Values map onto the serde data model as follows:
+//!
+//! - `INTEGER` → `i64` (also accepted for `bool`, as `0`/non-zero)
+//! - `REAL` → `f64`
+//! - `TEXT` → `str`; parsed as JSON when a struct, map or sequence is expected
+//! - `BLOB` → bytes, or a sequence of `u8` for `Vec<u8>`
+//! - `NULL` → `None`
+//!
+//! Columns are matched by the name `SQLite` reports for them, so duplicate
+//! names from joins should be aliased in the selection. Types using
+//! `#[serde(flatten)]` receive values through serde's buffering, which skips
+//! the `TEXT`-as-JSON, `BLOB`-as-`Vec<u8>` and integer-as-`bool` conversions.
+
+use crate::prelude::*;
+use crate::values::OwnedSQLiteValue;
+use drizzle_core::error::DrizzleError;
+use serde::de::value::{BorrowedStrDeserializer, SeqDeserializer};
+use serde::de::{DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, Visitor};
+use serde::forward_to_deserialize_any;
+
+/// Deserializes a `T` from a row's column names and values.
+///
+/// # Errors
+///
+/// Returns [`DrizzleError::Mapping`] if the row doesn't match `T`'s shape, or
+/// [`DrizzleError::JsonError`] if a nested value isn't valid JSON text.
+pub fn from_row<'de, T>(
+    columns: &'de [String],
+    values: &'de [OwnedSQLiteValue],
+) -> Result<T, DrizzleError>
+where
+    T: serde::Deserialize<'de>,
+{
+    T::deserialize(RowDeserializer::new(columns, values))
+}
+
+/// Serde [`Deserializer`] over a single row.
+///
+/// Deserializes as a map of column name to value for structs and maps, and
+/// as a sequence of values for tuples.
+#[derive(Debug, Clone, Copy)]
+pub struct RowDeserializer<'de> {
+    columns: &'de [String],
+    values: &'de [OwnedSQLiteValue],
+}
+
+impl<'de> RowDeserializer<'de> {
+    /// Creates a deserializer pairing `columns[i]` with `values[i]`.
+    #[must_use]
+    pub const fn new(columns: &'de [String], values: &'de [OwnedSQLiteValue]) -> Self {
+        Self { columns, values }
+    }
+}
+
+impl<'de> Deserializer<'de> for RowDeserializer<'de> {
+    type Error = DrizzleError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(RowAccess {
+            columns: self.columns,
+            values: self.values,
+            index: 0,
+        })
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(SeqDeserializer::new(
+            self.values.iter().map(ValueDeserializer),
+        ))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct map struct enum identifier
+        ignored_any
+    }
+}
+
+struct RowAccess<'de> {
+    columns: &'de [String],
+    values: &'de [OwnedSQLiteValue],
+    index: usize,
+}
+
+impl<'de> MapAccess<'de> for RowAccess<'de> {
+    type Error = DrizzleError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let Some(name) = self.columns.get(self.index) else {
+            return Ok(None);
+        };
+        seed.deserialize(BorrowedStrDeserializer::<DrizzleError>::new(name))
+            .map(Some)
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<S::Value, Self::Error> {
+        let value = self.values.get(self.index).ok_or_else(|| {
+            DrizzleError::Mapping(
+                format!("column '{}' has no value", self.columns[self.index]).into(),
+            )
+        })?;
+        self.index += 1;
+        seed.deserialize(ValueDeserializer(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.columns.len() - self.index)
+    }
+}
+
+/// Serde [`Deserializer`] over a single column value.
+#[derive(Debug, Clone, Copy)]
+pub struct ValueDeserializer<'de>(pub &'de OwnedSQLiteValue);
+
+impl<'de> IntoDeserializer<'de, DrizzleError> for ValueDeserializer<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> ValueDeserializer<'de> {
+    /// Hands JSON text to `visitor`, falling back to the plain value.
+    fn visit_json<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DrizzleError> {
+        match self.0 {
+            OwnedSQLiteValue::Text(text) => serde_json::Deserializer::from_str(text)
+                .deserialize_any(visitor)
+                .map_err(DrizzleError::from),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer<'de> {
+    type Error = DrizzleError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            OwnedSQLiteValue::Integer(i) => visitor.visit_i64(*i),
+            OwnedSQLiteValue::Real(r) => visitor.visit_f64(*r),
+            OwnedSQLiteValue::Text(s) => visitor.visit_borrowed_str(s),
+            OwnedSQLiteValue::Blob(b) => visitor.visit_borrowed_bytes(b),
+            OwnedSQLiteValue::Null => visitor.visit_none(),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            OwnedSQLiteValue::Integer(i) => visitor.visit_bool(*i != 0),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            OwnedSQLiteValue::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            OwnedSQLiteValue::Null => visitor.visit_unit(),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            OwnedSQLiteValue::Blob(b) => visitor.visit_seq(SeqDeserializer::new(b.iter().copied())),
+            _ => self.visit_json(visitor),
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.visit_json(visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.visit_json(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            OwnedSQLiteValue::Text(text) if !text.starts_with('{') => {
+                visitor.visit_enum(BorrowedStrDeserializer::<DrizzleError>::new(text))
+            }
+            OwnedSQLiteValue::Text(text) => serde_json::Deserializer::from_str(text)
+                .deserialize_enum(name, variants, visitor)
+                .map_err(DrizzleError::from),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit_struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Role {
+        Admin,
+        Member,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Meta {
+        tags: Vec<String>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Contact {
+        name: String,
+        email: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct UserDto {
+        #[serde(rename = "id")]
+        user_id: i32,
+        name: String,
+        email: Option<String>,
+        role: Role,
+        meta: Meta,
+        avatar: Vec<u8>,
+        active: bool,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct FlatDto {
+        id: i64,
+        #[serde(flatten)]
+        contact: Contact,
+    }
+
+    fn row() -> (Vec<String>, Vec<OwnedSQLiteValue>) {
+        let columns = ["id", "name", "email", "role", "meta", "avatar", "active"]
+            .map(String::from)
+            .to_vec();
+        let values = vec![
+            OwnedSQLiteValue::Integer(7),
+            OwnedSQLiteValue::Text("alice".into()),
+            OwnedSQLiteValue::Null,
+            OwnedSQLiteValue::Text("Admin".into()),
+            OwnedSQLiteValue::Text(r#"{"tags":["a","b"]}"#.into()),
+            OwnedSQLiteValue::Blob(vec![1, 2].into_boxed_slice()),
+            OwnedSQLiteValue::Integer(1),
+        ];
+        (columns, values)
+    }
+
+    #[test]
+    fn deserializes_struct_by_column_name() {
+        let (columns, values) = row();
+        let user: UserDto = from_row(&columns, &values).unwrap();
+        assert_eq!(
+            user,
+            UserDto {
+                user_id: 7,
+                name: "alice".into(),
+                email: None,
+                role: Role::Admin,
+                meta: Meta {
+                    tags: vec!["a".into(), "b".into()],
+                },
+                avatar: vec![1, 2],
+                active: true,
+            }
+        );
+        assert_ne!(user.role, Role::Member);
+    }
+
+    #[test]
+    fn deserializes_flattened_fields() {
+        let (columns, values) = row();
+        let flat: FlatDto = from_row(&columns[..3], &values[..3]).unwrap();
+        assert_eq!(
+            flat,
+            FlatDto {
+                id: 7,
+                contact: Contact {
+                    name: "alice".into(),
+                    email: None,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn deserializes_tuple_by_position() {
+        let (columns, values) = row();
+        let (id, name): (i64, &str) = from_row(&columns[..2], &values[..2]).unwrap();
+        assert_eq!((id, name), (7, "alice"));
+    }
+
+    #[test]
+    fn reports_type_mismatch_as_mapping_error() {
+        let columns = vec![String::from("id")];
+        let values = vec![OwnedSQLiteValue::Text("x".into())];
+
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct OnlyId {
+            id: i32,
+        }
+
+        let err = from_row::<OnlyId>(&columns, &values).unwrap_err();
+        assert!(matches!(err, DrizzleError::Mapping(_)), "{err:?}");
+    }
+}
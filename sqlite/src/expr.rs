@@ -6,7 +6,8 @@
 #[cfg(not(feature = "std"))]
 use crate::prelude::*;
 use crate::values::SQLiteValue;
-use drizzle_core::{SQL, ToSQL};
+use drizzle_core::expr::Expr;
+use drizzle_core::{OrderBy, SQL, ToSQL, Token};
 
 /// Wraps a value with the `SQLite` `json()` function, validating and returning JSON text.
 pub fn json<'a>(value: impl ToSQL<'a, SQLiteValue<'a>>) -> SQL<'a, SQLiteValue<'a>> {
@@ -279,7 +280,7 @@ where
 {
     left.to_sql()
         .append(SQL::raw(" ->> "))
-        .append(SQL::param(SQLiteValue::from(path.as_ref().to_owned())))
+        .append(SQL::param(SQLiteValue::from(String::from(path.as_ref()))))
 }
 
 /// Helper function for JSON extraction as JSON text using -> operator
@@ -304,6 +305,116 @@ where
         .append(SQL::param(SQLiteValue::from(path)))
 }
 
+/// sqlite-vec `vec_distance_l2()` - Euclidean (L2) distance between two
+/// vectors.
+///
+/// Requires the sqlite-vec extension to be loaded on the connection.
+/// `Vec<f32>` operands bind as `float32` blobs.
+///
+/// # Example
+/// ```
+/// # use drizzle_sqlite::expr::vec_distance_l2;
+/// # use drizzle_core::SQL;
+/// # use drizzle_sqlite::values::SQLiteValue;
+/// # fn main() {
+/// let a = SQL::<SQLiteValue>::raw("a.embedding");
+/// let b = SQL::<SQLiteValue>::raw("b.embedding");
+/// assert_eq!(
+///     vec_distance_l2(a, b).sql(),
+///     "vec_distance_l2 (a.embedding, b.embedding)"
+/// );
+/// # }
+/// ```
+pub fn vec_distance_l2<'a, L, R>(left: L, right: R) -> SQL<'a, SQLiteValue<'a>>
+where
+    L: Expr<'a, SQLiteValue<'a>>,
+    R: Expr<'a, SQLiteValue<'a>>,
+{
+    SQL::func(
+        "vec_distance_l2",
+        left.into_expr_sql()
+            .push(Token::COMMA)
+            .append(right.into_expr_sql()),
+    )
+}
+
+/// sqlite-vec `vec_distance_cosine()` - cosine distance between two vectors.
+///
+/// Requires the sqlite-vec extension to be loaded on the connection.
+///
+/// # Example
+/// ```
+/// # use drizzle_sqlite::expr::vec_distance_cosine;
+/// # use drizzle_core::SQL;
+/// # use drizzle_sqlite::values::SQLiteValue;
+/// # fn main() {
+/// let a = SQL::<SQLiteValue>::raw("a.embedding");
+/// let b = SQL::<SQLiteValue>::raw("b.embedding");
+/// assert_eq!(
+///     vec_distance_cosine(a, b).sql(),
+///     "vec_distance_cosine (a.embedding, b.embedding)"
+/// );
+/// # }
+/// ```
+pub fn vec_distance_cosine<'a, L, R>(left: L, right: R) -> SQL<'a, SQLiteValue<'a>>
+where
+    L: Expr<'a, SQLiteValue<'a>>,
+    R: Expr<'a, SQLiteValue<'a>>,
+{
+    SQL::func(
+        "vec_distance_cosine",
+        left.into_expr_sql()
+            .push(Token::COMMA)
+            .append(right.into_expr_sql()),
+    )
+}
+
+/// ORDER BY expression putting the rows closest to `embedding` (by L2
+/// distance) first. Follow it with `limit(k)` for the k nearest neighbours.
+///
+/// # Example
+/// ```
+/// # use drizzle_sqlite::expr::nearest;
+/// # use drizzle_core::SQL;
+/// # use drizzle_sqlite::values::SQLiteValue;
+/// # fn main() {
+/// let column = SQL::<SQLiteValue>::raw("embedding");
+/// let order = nearest(column, vec![1.0_f32, 0.0]);
+/// assert_eq!(order.sql(), "vec_distance_l2 (embedding, ?) ASC");
+/// assert!(matches!(order.params().next(), Some(SQLiteValue::Blob(_))));
+/// # }
+/// ```
+pub fn nearest<'a, L, R>(column: L, embedding: R) -> SQL<'a, SQLiteValue<'a>>
+where
+    L: Expr<'a, SQLiteValue<'a>>,
+    R: Expr<'a, SQLiteValue<'a>>,
+{
+    vec_distance_l2(column, embedding).append(&OrderBy::Asc)
+}
+
+/// Like [`nearest`], ranking by cosine distance.
+///
+/// # Example
+/// ```
+/// # use drizzle_sqlite::expr::nearest_cosine;
+/// # use drizzle_core::SQL;
+/// # use drizzle_sqlite::values::SQLiteValue;
+/// # fn main() {
+/// let column = SQL::<SQLiteValue>::raw("embedding");
+/// assert_eq!(
+///     nearest_cosine(column, vec![1.0_f32, 0.0]).sql(),
+///     "vec_distance_cosine (embedding, ?) ASC"
+/// );
+/// # }
+/// ```
+pub fn nearest_cosine<'a, L, R>(column: L, embedding: R) -> SQL<'a, SQLiteValue<'a>>
+where
+    L: Expr<'a, SQLiteValue<'a>>,
+    R: Expr<'a, SQLiteValue<'a>>,
+{
+    vec_distance_cosine(column, embedding).append(&OrderBy::Asc)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
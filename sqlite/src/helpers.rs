@@ -3,7 +3,7 @@ use crate::prelude::*;
 use crate::traits::SQLiteTable;
 use crate::values::SQLiteValue;
 use drizzle_core::{
-    ColumnRef, SQL, Token, helpers as core_helpers,
+    ColumnRef, SQL, SQLChunk, Token, helpers as core_helpers,
     traits::{SQLModel, ToSQL},
 };
 
@@ -30,7 +30,7 @@ fn columns_info_to_sql<'a>(columns: &[ColumnRef]) -> SQL<'a, SQLiteValue<'a>> {
         if idx > 0 {
             sql.push_mut(Token::COMMA);
         }
-        sql.append_mut(SQL::ident(col.name));
+        sql.push_mut(SQLChunk::Name(col.name));
     }
     sql
 }
@@ -66,18 +66,22 @@ where
         return SQL::from_iter([Token::DEFAULT, Token::VALUES]);
     }
 
-    let columns_sql = columns_info_to_sql(columns_slice);
-    let mut values_sql = SQL::with_capacity_chunks(rows.len().saturating_mul(4));
+    let mut sql = columns_info_to_sql(columns_slice)
+        .parens()
+        .push(Token::VALUES);
+    // Each row renders as `(p, p, ..., p)` plus a separating comma.
+    let row_chunks = columns_slice.len().saturating_mul(2).saturating_add(2);
+    sql.reserve(rows.len().saturating_mul(row_chunks));
     for (idx, row) in rows.iter().enumerate() {
         if idx > 0 {
-            values_sql.push_mut(Token::COMMA);
+            sql.push_mut(Token::COMMA);
         }
-        values_sql.push_mut(Token::LPAREN);
-        values_sql.append_mut(row.values());
-        values_sql.push_mut(Token::RPAREN);
+        sql.push_mut(Token::LPAREN);
+        sql.append_mut(row.values());
+        sql.push_mut(Token::RPAREN);
     }
 
-    columns_sql.parens().push(Token::VALUES).append(values_sql)
+    sql
 }
 
 /// Helper function to create a RETURNING clause - `SQLite` specific
@@ -1,6 +1,11 @@
 //! `SQLite` implementation for Drizzle
 //!
 //! This crate provides SQLite-specific types, query builders, and utilities.
+//!
+//! Without the default `std` feature (`default-features = false, features =
+//! ["alloc"]`) the query builders and [`values`] still compile for `no_std`
+//! targets, so queries can be built and rendered to SQL plus parameters on a
+//! device and executed elsewhere. Driver features all require `std`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -38,6 +43,8 @@ pub mod attrs;
 pub mod builder;
 pub mod common;
 pub mod connection;
+#[cfg(feature = "serde")]
+pub mod de;
 pub mod expr;
 pub mod helpers;
 pub mod pragma;
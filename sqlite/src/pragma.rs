@@ -43,9 +43,10 @@
 //! assert_eq!(pragma.to_sql().sql(), "PRAGMA integrity_check");
 //! ```
 
-#[cfg(not(feature = "std"))]
 use crate::prelude::*;
+use crate::traits::DrizzleRowByIndex;
 use crate::values::SQLiteValue;
+use drizzle_core::error::DrizzleError;
 use drizzle_core::{SQL, ToSQL};
 
 /// Auto-vacuum modes for `SQLite` databases
@@ -1334,6 +1335,119 @@ impl Pragma {
     }
 }
 
+// =============================================================================
+// Typed result rows
+// =============================================================================
+
+/// One column of a table, as reported by `PRAGMA table_info`.
+///
+/// [SQLite Documentation](https://sqlite.org/pragma.html#pragma_table_info)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableInfoRow {
+    /// Column position, starting at 0
+    pub cid: i64,
+    /// Column name
+    pub name: String,
+    /// Declared type, empty when the column has none
+    pub column_type: String,
+    /// Whether the column is `NOT NULL`
+    pub not_null: bool,
+    /// Default value expression as written in the schema
+    pub default_value: Option<String>,
+    /// 1-based position in the primary key, or 0 if not part of it
+    pub pk: i64,
+}
+
+impl TableInfoRow {
+    /// Decodes a `PRAGMA table_info` result row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if a column is missing or has an unexpected type.
+    pub fn from_row<R: DrizzleRowByIndex + ?Sized>(row: &R) -> Result<Self, DrizzleError> {
+        Ok(Self {
+            cid: row.get_column(0)?,
+            name: row.get_column(1)?,
+            column_type: row.get_column(2)?,
+            not_null: row.get_column(3)?,
+            default_value: row.get_column(4)?,
+            pk: row.get_column(5)?,
+        })
+    }
+}
+
+/// One foreign key violation, as reported by `PRAGMA foreign_key_check`.
+///
+/// [SQLite Documentation](https://sqlite.org/pragma.html#pragma_foreign_key_check)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignKeyCheckRow {
+    /// Table containing the violating row
+    pub table: String,
+    /// Rowid of the violating row, `None` for `WITHOUT ROWID` tables
+    pub rowid: Option<i64>,
+    /// Table the foreign key refers to
+    pub parent: String,
+    /// Index of the violated constraint in `PRAGMA foreign_key_list`
+    pub fkid: i64,
+}
+
+impl ForeignKeyCheckRow {
+    /// Decodes a `PRAGMA foreign_key_check` result row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if a column is missing or has an unexpected type.
+    pub fn from_row<R: DrizzleRowByIndex + ?Sized>(row: &R) -> Result<Self, DrizzleError> {
+        Ok(Self {
+            table: row.get_column(0)?,
+            rowid: row.get_column(1)?,
+            parent: row.get_column(2)?,
+            fkid: row.get_column(3)?,
+        })
+    }
+}
+
+/// Outcome of `PRAGMA wal_checkpoint`.
+///
+/// Outside WAL mode `SQLite` reports `-1` for both frame counts.
+///
+/// [SQLite Documentation](https://sqlite.org/pragma.html#pragma_wal_checkpoint)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalCheckpointRow {
+    /// Whether the checkpoint was blocked by another connection
+    pub busy: bool,
+    /// Frames in the WAL file
+    pub log_frames: i64,
+    /// Frames moved back into the database file
+    pub checkpointed_frames: i64,
+}
+
+impl WalCheckpointRow {
+    /// Decodes a `PRAGMA wal_checkpoint` result row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if a column is missing or has an unexpected type.
+    pub fn from_row<R: DrizzleRowByIndex + ?Sized>(row: &R) -> Result<Self, DrizzleError> {
+        Ok(Self {
+            busy: row.get_column(0)?,
+            log_frames: row.get_column(1)?,
+            checkpointed_frames: row.get_column(2)?,
+        })
+    }
+}
+
+/// Collects `PRAGMA integrity_check` messages, dropping the lone `ok` row
+/// `SQLite` returns for a healthy database.
+#[must_use]
+pub fn integrity_problems(messages: Vec<String>) -> Vec<String> {
+    if messages.len() == 1 && messages[0] == "ok" {
+        Vec::new()
+    } else {
+        messages
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,6 +74,81 @@ where
     }
 }
 
+/// Maps a custom Rust type onto an already-supported `SQLite` storage type.
+///
+/// Most wrapper types (`Email(ArrayString<64>)`, `Cents(i64)`, ...) only need
+/// to convert to and from a representation drizzle already knows how to bind
+/// and decode. Implementing this trait is enough to use the type directly as
+/// a table field: a blanket impl provides [`DrizzleSQLiteColumn`], so the
+/// table macros pick it up like any other custom column.
+///
+/// ```ignore
+/// struct Cents(i64);
+///
+/// impl CustomSQLiteType for Cents {
+///     type SQLType = drizzle::sqlite::types::Integer;
+///     const SQL_TYPE: &'static str = "INTEGER";
+///     type Repr = i64;
+///
+///     fn to_repr(&self) -> i64 {
+///         self.0
+///     }
+///
+///     fn from_repr(repr: i64) -> Result<Self, DrizzleError> {
+///         Ok(Self(repr))
+///     }
+/// }
+/// ```
+///
+/// Implement [`DrizzleSQLiteColumn`] directly instead when the type needs
+/// full control over which storage classes it accepts.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not map to a SQLite storage type",
+    note = "implement `CustomSQLiteType` with a supported `Repr` type such as `i64`, `String`, or `Vec<u8>`"
+)]
+pub trait CustomSQLiteType: Sized {
+    /// Drizzle SQL type marker for this column.
+    type SQLType: drizzle_core::types::DataType;
+
+    /// Declared `SQLite` column type used in DDL, e.g. `"TEXT"`.
+    const SQL_TYPE: &'static str;
+
+    /// The supported Rust type this value is stored as.
+    type Repr: FromSQLiteValue + Into<OwnedSQLiteValue>;
+
+    /// Convert self into its storage representation.
+    fn to_repr(&self) -> Self::Repr;
+
+    /// Rebuild self from its storage representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::ConversionError`] if `repr` is not a valid
+    /// value for this type.
+    fn from_repr(repr: Self::Repr) -> Result<Self, DrizzleError>;
+}
+
+impl<T> DrizzleSQLiteColumn for T
+where
+    T: CustomSQLiteType,
+{
+    type SQLType = <T as CustomSQLiteType>::SQLType;
+
+    const SQL_TYPE: &'static str = <T as CustomSQLiteType>::SQL_TYPE;
+
+    fn decode(value: SQLiteValueRef<'_>) -> Result<Self, DrizzleError> {
+        T::from_repr(<T::Repr as FromSQLiteValue>::from_sqlite_ref(value)?)
+    }
+
+    fn encode(&self) -> SQLiteValue<'_> {
+        Into::<OwnedSQLiteValue>::into(self.to_repr()).into()
+    }
+
+    fn encode_owned(self) -> OwnedSQLiteValue {
+        self.to_repr().into()
+    }
+}
+
 /// Trait for types that can be converted from `SQLite` values.
 ///
 /// `SQLite` has 5 storage classes: NULL, INTEGER, REAL, TEXT, BLOB.
@@ -278,7 +353,58 @@ fn i64_to_f64(i: i64) -> f64 {
 
 // Integer types
 impl_from_sqlite_value_int!(i64);
-impl_from_sqlite_value_int!(i8, i16, i32, isize, u8, u16, u32, u64, usize);
+impl_from_sqlite_value_int!(i8, i16, i32, isize, u8, u16, u32);
+
+/// Unsigned 64-bit widths: INTEGER for values up to `i64::MAX`, plus the
+/// 8-byte big-endian BLOB larger values are stored as.
+macro_rules! impl_from_sqlite_value_unsigned {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl FromSQLiteValue for $ty {
+                fn from_sqlite_integer(value: i64) -> Result<Self, DrizzleError> {
+                    value.try_into().map_err(|e| {
+                        DrizzleError::ConversionError(
+                            format!("i64 {} out of range for {}: {}", value, stringify!($ty), e).into(),
+                        )
+                    })
+                }
+
+                fn from_sqlite_text(value: &str) -> Result<Self, DrizzleError> {
+                    value.parse().map_err(|e| {
+                        DrizzleError::ConversionError(
+                            format!("cannot parse '{}' as {}: {}", value, stringify!($ty), e).into()
+                        )
+                    })
+                }
+
+                fn from_sqlite_real(value: f64) -> Result<Self, DrizzleError> {
+                    checked_float_to_int(value, stringify!($ty))
+                }
+
+                fn from_sqlite_blob(value: &[u8]) -> Result<Self, DrizzleError> {
+                    let bytes: [u8; 8] = value.try_into().map_err(|_| {
+                        DrizzleError::ConversionError(
+                            format!(
+                                "cannot convert {}-byte BLOB to {}, expected 8 bytes",
+                                value.len(),
+                                stringify!($ty)
+                            )
+                            .into(),
+                        )
+                    })?;
+                    let value = u64::from_be_bytes(bytes);
+                    value.try_into().map_err(|e| {
+                        DrizzleError::ConversionError(
+                            format!("u64 {} out of range for {}: {}", value, stringify!($ty), e).into(),
+                        )
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_from_sqlite_value_unsigned!(u64, usize);
 
 // f64 — `i64` widening via split-halves is exact in the mantissa range and
 // matches direct `as` cast beyond it; no cast is needed for f64→f64.
@@ -682,18 +808,26 @@ impl<T: FromSQLiteValue> FromSQLiteValue for Option<T> {
 impl DrizzleRowByIndex for rusqlite::Row<'_> {
     fn get_column<T: FromSQLiteValue>(&self, idx: usize) -> Result<T, DrizzleError> {
         let value_ref = self.get_ref(idx)?;
-        match value_ref {
-            rusqlite::types::ValueRef::Integer(i) => T::from_sqlite_integer(i),
+        let (decoded, actual) = match value_ref {
+            rusqlite::types::ValueRef::Integer(i) => (T::from_sqlite_integer(i), "INTEGER"),
             rusqlite::types::ValueRef::Text(s) => {
                 let s = core::str::from_utf8(s).map_err(|e| {
                     DrizzleError::ConversionError(format!("invalid UTF-8: {e}").into())
-                })?;
-                T::from_sqlite_text(s)
+                });
+                (s.and_then(T::from_sqlite_text), "TEXT")
             }
-            rusqlite::types::ValueRef::Real(r) => T::from_sqlite_real(r),
-            rusqlite::types::ValueRef::Blob(b) => T::from_sqlite_blob(b),
-            rusqlite::types::ValueRef::Null => T::from_sqlite_null(),
-        }
+            rusqlite::types::ValueRef::Real(r) => (T::from_sqlite_real(r), "REAL"),
+            rusqlite::types::ValueRef::Blob(b) => (T::from_sqlite_blob(b), "BLOB"),
+            rusqlite::types::ValueRef::Null => (T::from_sqlite_null(), "NULL"),
+        };
+        decoded.map_err(|e| {
+            e.decode_column(
+                idx,
+                self.as_ref().column_name(idx).ok(),
+                &drizzle_core::error::type_name::<T>(),
+                Some(actual),
+            )
+        })
     }
 }
 
@@ -712,13 +846,21 @@ impl DrizzleRowByIndex for libsql::Row {
             DrizzleError::ConversionError(format!("column index {idx} out of range: {e}").into())
         })?;
         let value = self.get_value(idx_i32)?;
-        match value {
-            libsql::Value::Integer(i) => T::from_sqlite_integer(i),
-            libsql::Value::Text(ref s) => T::from_sqlite_text(s),
-            libsql::Value::Real(r) => T::from_sqlite_real(r),
-            libsql::Value::Blob(ref b) => T::from_sqlite_blob(b),
-            libsql::Value::Null => T::from_sqlite_null(),
-        }
+        let (decoded, actual) = match value {
+            libsql::Value::Integer(i) => (T::from_sqlite_integer(i), "INTEGER"),
+            libsql::Value::Text(ref s) => (T::from_sqlite_text(s), "TEXT"),
+            libsql::Value::Real(r) => (T::from_sqlite_real(r), "REAL"),
+            libsql::Value::Blob(ref b) => (T::from_sqlite_blob(b), "BLOB"),
+            libsql::Value::Null => (T::from_sqlite_null(), "NULL"),
+        };
+        decoded.map_err(|e| {
+            e.decode_column(
+                idx,
+                self.column_name(idx_i32),
+                &drizzle_core::error::type_name::<T>(),
+                Some(actual),
+            )
+        })
     }
 }
 
@@ -742,21 +884,29 @@ impl DrizzleRowByName for libsql::Row {
 impl DrizzleRowByIndex for turso::Row {
     fn get_column<T: FromSQLiteValue>(&self, idx: usize) -> Result<T, DrizzleError> {
         let value = self.get_value(idx)?;
-        if value.is_null() {
-            T::from_sqlite_null()
+        let (decoded, actual) = if value.is_null() {
+            (T::from_sqlite_null(), "NULL")
         } else if let Some(&i) = value.as_integer() {
-            T::from_sqlite_integer(i)
+            (T::from_sqlite_integer(i), "INTEGER")
         } else if let Some(s) = value.as_text() {
-            T::from_sqlite_text(s)
+            (T::from_sqlite_text(s), "TEXT")
         } else if let Some(&r) = value.as_real() {
-            T::from_sqlite_real(r)
+            (T::from_sqlite_real(r), "REAL")
         } else if let Some(b) = value.as_blob() {
-            T::from_sqlite_blob(b)
+            (T::from_sqlite_blob(b), "BLOB")
         } else {
-            Err(DrizzleError::ConversionError(
+            return Err(DrizzleError::ConversionError(
                 "unknown SQLite value type".into(),
-            ))
-        }
+            ));
+        };
+        decoded.map_err(|e| {
+            e.decode_column(
+                idx,
+                None,
+                &drizzle_core::error::type_name::<T>(),
+                Some(actual),
+            )
+        })
     }
 }
 
@@ -854,10 +1004,9 @@ impl FromSQLiteValue for chrono::NaiveTime {
 
 #[cfg(feature = "chrono")]
 impl FromSQLiteValue for chrono::NaiveDateTime {
-    fn from_sqlite_integer(_value: i64) -> Result<Self, DrizzleError> {
-        Err(DrizzleError::ConversionError(
-            "cannot convert INTEGER to NaiveDateTime".into(),
-        ))
+    /// INTEGER storage holds unix seconds, read back as UTC wall-clock time.
+    fn from_sqlite_integer(value: i64) -> Result<Self, DrizzleError> {
+        chrono::DateTime::<chrono::Utc>::from_sqlite_integer(value).map(|dt| dt.naive_utc())
     }
 
     fn from_sqlite_text(value: &str) -> Result<Self, DrizzleError> {
@@ -883,10 +1032,8 @@ impl FromSQLiteValue for chrono::NaiveDateTime {
 
 #[cfg(feature = "chrono")]
 impl FromSQLiteValue for chrono::DateTime<chrono::FixedOffset> {
-    fn from_sqlite_integer(_value: i64) -> Result<Self, DrizzleError> {
-        Err(DrizzleError::ConversionError(
-            "cannot convert INTEGER to DateTime<FixedOffset>".into(),
-        ))
+    fn from_sqlite_integer(value: i64) -> Result<Self, DrizzleError> {
+        chrono::DateTime::<chrono::Utc>::from_sqlite_integer(value).map(|dt| dt.fixed_offset())
     }
 
     fn from_sqlite_text(value: &str) -> Result<Self, DrizzleError> {
@@ -912,10 +1059,13 @@ impl FromSQLiteValue for chrono::DateTime<chrono::FixedOffset> {
 
 #[cfg(feature = "chrono")]
 impl FromSQLiteValue for chrono::DateTime<chrono::Utc> {
-    fn from_sqlite_integer(_value: i64) -> Result<Self, DrizzleError> {
-        Err(DrizzleError::ConversionError(
-            "cannot convert INTEGER to DateTime<Utc>".into(),
-        ))
+    /// INTEGER storage holds unix seconds.
+    fn from_sqlite_integer(value: i64) -> Result<Self, DrizzleError> {
+        Self::from_timestamp(value, 0).ok_or_else(|| {
+            DrizzleError::ConversionError(
+                format!("unix timestamp {value} is out of range for DateTime<Utc>").into(),
+            )
+        })
     }
 
     fn from_sqlite_text(value: &str) -> Result<Self, DrizzleError> {
@@ -1003,10 +1153,9 @@ impl FromSQLiteValue for time::Time {
 
 #[cfg(feature = "time")]
 impl FromSQLiteValue for time::PrimitiveDateTime {
-    fn from_sqlite_integer(_value: i64) -> Result<Self, DrizzleError> {
-        Err(DrizzleError::ConversionError(
-            "cannot convert INTEGER to time::PrimitiveDateTime".into(),
-        ))
+    /// INTEGER storage holds unix seconds, read back as UTC wall-clock time.
+    fn from_sqlite_integer(value: i64) -> Result<Self, DrizzleError> {
+        time::OffsetDateTime::from_sqlite_integer(value).map(|dt| Self::new(dt.date(), dt.time()))
     }
 
     fn from_sqlite_text(value: &str) -> Result<Self, DrizzleError> {
@@ -1036,10 +1185,14 @@ impl FromSQLiteValue for time::PrimitiveDateTime {
 
 #[cfg(feature = "time")]
 impl FromSQLiteValue for time::OffsetDateTime {
-    fn from_sqlite_integer(_value: i64) -> Result<Self, DrizzleError> {
-        Err(DrizzleError::ConversionError(
-            "cannot convert INTEGER to time::OffsetDateTime".into(),
-        ))
+    /// INTEGER storage holds unix seconds.
+    fn from_sqlite_integer(value: i64) -> Result<Self, DrizzleError> {
+        Self::from_unix_timestamp(value).map_err(|e| {
+            DrizzleError::ConversionError(
+                format!("unix timestamp {value} is out of range for time::OffsetDateTime: {e}")
+                    .into(),
+            )
+        })
     }
 
     fn from_sqlite_text(value: &str) -> Result<Self, DrizzleError> {
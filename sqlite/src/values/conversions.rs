@@ -119,47 +119,47 @@ impl From<&i64> for SQLiteValue<'_> {
     }
 }
 
-// u64 → i64 by reinterpreting bits. SQLite stores INTEGER as signed 64-bit;
-// this matches the round-trip convention used by the matching `i64 → u64`
-// reinterpretation on read.
-impl From<u64> for SQLiteValue<'_> {
-    #[inline]
-    fn from(value: u64) -> Self {
-        SQLiteValue::Integer(value.cast_signed())
-    }
-}
-
-impl From<&u64> for SQLiteValue<'_> {
-    #[inline]
-    fn from(value: &u64) -> Self {
-        SQLiteValue::Integer(value.cast_signed())
-    }
-}
-
-/// Pointer-sized integer widths (usize/isize). All Rust-supported targets have
-/// pointers ≤ 64 bits, so `i64::try_from` succeeds; the saturating fallback is
-/// defensive only.
-macro_rules! impl_from_pointer_int_for_sqlite_value {
+/// Unsigned 64-bit widths (u64/usize). `SQLite` INTEGER is signed 64-bit, so
+/// values up to `i64::MAX` bind as INTEGER and larger ones as an 8-byte
+/// big-endian BLOB, which INTEGER affinity leaves untouched and
+/// `FromSQLiteValue` reads back losslessly.
+macro_rules! impl_from_unsigned_int_for_sqlite_value {
     ($($ty:ty),* $(,)?) => {
         $(
             impl<'a> From<$ty> for SQLiteValue<'a> {
                 #[inline]
                 fn from(value: $ty) -> Self {
-                    SQLiteValue::Integer(i64::try_from(value).unwrap_or(i64::MAX))
+                    OwnedSQLiteValue::from(value).into()
                 }
             }
 
             impl<'a> From<&$ty> for SQLiteValue<'a> {
                 #[inline]
                 fn from(value: &$ty) -> Self {
-                    SQLiteValue::Integer(i64::try_from(*value).unwrap_or(i64::MAX))
+                    OwnedSQLiteValue::from(*value).into()
                 }
             }
         )*
     };
 }
 
-impl_from_pointer_int_for_sqlite_value!(isize, usize);
+impl_from_unsigned_int_for_sqlite_value!(u64, usize);
+
+// isize → i64. All Rust-supported targets have pointers ≤ 64 bits, so
+// `i64::try_from` succeeds; the saturating fallback is defensive only.
+impl From<isize> for SQLiteValue<'_> {
+    #[inline]
+    fn from(value: isize) -> Self {
+        SQLiteValue::Integer(i64::try_from(value).unwrap_or(i64::MAX))
+    }
+}
+
+impl From<&isize> for SQLiteValue<'_> {
+    #[inline]
+    fn from(value: &isize) -> Self {
+        SQLiteValue::Integer(i64::try_from(*value).unwrap_or(i64::MAX))
+    }
+}
 
 // f32 widens exactly into f64.
 impl From<f32> for SQLiteValue<'_> {
@@ -0,0 +1,70 @@
+//! `geo_types` shapes stored as WKB blobs.
+//!
+//! `SQLite` has no geometry type, so shapes are declared as `BLOB` columns
+//! holding OGC well-known binary, the same encoding PostGIS uses.
+
+use crate::prelude::*;
+use crate::traits::DrizzleSQLiteColumn;
+use crate::values::{SQLiteValue, SQLiteValueRef};
+use drizzle_core::error::DrizzleError;
+use drizzle_core::expr::{Expr, NonNull, Scalar};
+use drizzle_core::sql::SQL;
+use drizzle_core::{ToSQL, wkb};
+use geo_types::{Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+
+fn decode_shape<T>(value: SQLiteValueRef<'_>, name: &str) -> Result<T, DrizzleError>
+where
+    T: TryFrom<Geometry<f64>>,
+{
+    let SQLiteValueRef::Blob(bytes) = value else {
+        return Err(DrizzleError::ConversionError(
+            format!("cannot convert {value:?} to {name}, expected a WKB blob").into(),
+        ));
+    };
+    let (geometry, _srid) = wkb::decode(bytes)?;
+    T::try_from(geometry)
+        .map_err(|_| DrizzleError::ConversionError(format!("WKB value is not a {name}").into()))
+}
+
+macro_rules! impl_wkb_column {
+    ($($ty:ident),+ $(,)?) => {
+        $(
+            impl DrizzleSQLiteColumn for $ty<f64> {
+                type SQLType = drizzle_types::sqlite::types::Blob;
+
+                const SQL_TYPE: &'static str = "BLOB";
+
+                fn decode(value: SQLiteValueRef<'_>) -> Result<Self, DrizzleError> {
+                    decode_shape(value, stringify!($ty))
+                }
+
+                fn encode(&self) -> SQLiteValue<'_> {
+                    let geometry = Geometry::from(self.clone());
+                    SQLiteValue::Blob(Cow::Owned(wkb::encode(&geometry, None)))
+                }
+            }
+
+            impl<'a> ToSQL<'a, SQLiteValue<'a>> for $ty<f64> {
+                fn to_sql(&self) -> SQL<'a, SQLiteValue<'a>> {
+                    SQL::param(SQLiteValue::from(self.clone()))
+                }
+            }
+
+            impl<'a> Expr<'a, SQLiteValue<'a>> for $ty<f64> {
+                type SQLType = drizzle_types::sqlite::types::Blob;
+                type Nullable = NonNull;
+                type Aggregate = Scalar;
+            }
+        )+
+    };
+}
+
+impl_wkb_column!(
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    Geometry,
+);
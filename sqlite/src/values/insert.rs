@@ -39,6 +39,50 @@ pub enum SQLiteInsertValue<'a, V: SQLParam, T> {
 }
 
 impl<'a, T> SQLiteInsertValue<'a, SQLiteValue<'a>, T> {
+    /// Pushes `column` onto `columns` unless this value is omitted.
+    ///
+    /// Used by generated `SQLModel::columns` so each field expands to one call.
+    #[doc(hidden)]
+    #[inline]
+    pub fn push_column(
+        &self,
+        columns: &mut Vec<drizzle_core::ColumnRef>,
+        column: drizzle_core::ColumnRef,
+    ) {
+        if !matches!(self, Self::Omit) {
+            columns.push(column);
+        }
+    }
+
+    /// Pushes this value's SQL onto `values` unless it is omitted.
+    ///
+    /// Used by generated `SQLModel::values` so each field expands to one call.
+    #[doc(hidden)]
+    #[inline]
+    pub fn push_value(&self, values: &mut Vec<drizzle_core::SQL<'a, SQLiteValue<'a>>>) {
+        match self {
+            Self::Omit => {}
+            Self::Null => values.push(drizzle_core::SQL::param(SQLiteValue::Null)),
+            Self::Value(wrapper) => values.push(wrapper.value.clone()),
+        }
+    }
+
+    /// Like [`push_value`](Self::push_value), but passes the bound value
+    /// through `retag`, e.g. to store a date-time as unix seconds.
+    #[doc(hidden)]
+    #[inline]
+    pub fn push_value_as(
+        &self,
+        values: &mut Vec<drizzle_core::SQL<'a, SQLiteValue<'a>>>,
+        retag: fn(SQLiteValue<'a>) -> SQLiteValue<'a>,
+    ) {
+        match self {
+            Self::Omit => {}
+            Self::Null => values.push(drizzle_core::SQL::param(SQLiteValue::Null)),
+            Self::Value(wrapper) => values.push(wrapper.value.clone().map_params(retag)),
+        }
+    }
+
     /// Converts this `InsertValue` to an owned version with 'static lifetime
     #[must_use]
     pub fn into_owned(self) -> SQLiteInsertValue<'static, SQLiteValue<'static>, T> {
@@ -4,9 +4,14 @@
 
 mod conversions;
 mod drivers;
+#[cfg(feature = "geo-types")]
+mod geometry;
 mod insert;
 pub mod owned;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod timestamp;
 mod update;
+mod vector;
 
 pub use insert::*;
 pub use owned::*;
@@ -50,45 +50,49 @@ impl From<&i64> for OwnedSQLiteValue {
     }
 }
 
-// u64 → i64 bit reinterpretation. SQLite INTEGER is signed 64-bit; this matches
-// the matching `i64 → u64` reinterpretation on read.
-impl From<u64> for OwnedSQLiteValue {
-    #[inline]
-    fn from(value: u64) -> Self {
-        Self::Integer(value.cast_signed())
-    }
-}
-
-impl From<&u64> for OwnedSQLiteValue {
-    #[inline]
-    fn from(value: &u64) -> Self {
-        Self::Integer(value.cast_signed())
-    }
-}
-
-/// Pointer-sized integer widths (usize/isize). All supported targets have
-/// pointers ≤ 64 bits; the saturating fallback is defensive only.
-macro_rules! impl_from_pointer_int_for_owned_sqlite_value {
+/// Unsigned 64-bit widths (u64/usize). Values above `i64::MAX` don't fit
+/// `SQLite`'s signed INTEGER and are stored as an 8-byte big-endian BLOB.
+macro_rules! impl_from_unsigned_int_for_owned_sqlite_value {
     ($($ty:ty),* $(,)?) => {
         $(
             impl From<$ty> for OwnedSQLiteValue {
                 #[inline]
                 fn from(value: $ty) -> Self {
-                    OwnedSQLiteValue::Integer(i64::try_from(value).unwrap_or(i64::MAX))
+                    let value = u64::try_from(value).unwrap_or(u64::MAX);
+                    i64::try_from(value).map_or_else(
+                        |_| OwnedSQLiteValue::Blob(Box::new(value.to_be_bytes())),
+                        OwnedSQLiteValue::Integer,
+                    )
                 }
             }
 
             impl From<&$ty> for OwnedSQLiteValue {
                 #[inline]
                 fn from(value: &$ty) -> Self {
-                    OwnedSQLiteValue::Integer(i64::try_from(*value).unwrap_or(i64::MAX))
+                    (*value).into()
                 }
             }
         )*
     };
 }
 
-impl_from_pointer_int_for_owned_sqlite_value!(isize, usize);
+impl_from_unsigned_int_for_owned_sqlite_value!(u64, usize);
+
+// isize → i64. All supported targets have pointers ≤ 64 bits; the
+// saturating fallback is defensive only.
+impl From<isize> for OwnedSQLiteValue {
+    #[inline]
+    fn from(value: isize) -> Self {
+        Self::Integer(i64::try_from(value).unwrap_or(i64::MAX))
+    }
+}
+
+impl From<&isize> for OwnedSQLiteValue {
+    #[inline]
+    fn from(value: &isize) -> Self {
+        Self::Integer(i64::try_from(*value).unwrap_or(i64::MAX))
+    }
+}
 
 // f32 widens exactly into f64.
 impl From<f32> for OwnedSQLiteValue {
@@ -125,3 +125,16 @@ impl From<&OwnedSQLiteValue> for libsql::Value {
         Self::from(SQLiteValue::from(value))
     }
 }
+
+#[cfg(feature = "libsql")]
+impl From<libsql::Value> for OwnedSQLiteValue {
+    fn from(value: libsql::Value) -> Self {
+        match value {
+            libsql::Value::Integer(i) => Self::Integer(i),
+            libsql::Value::Real(r) => Self::Real(r),
+            libsql::Value::Text(s) => Self::Text(s),
+            libsql::Value::Blob(b) => Self::Blob(b.into_boxed_slice()),
+            libsql::Value::Null => Self::Null,
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Unix-timestamp storage for date-time columns.
+//!
+//! Date-times bind as ISO-8601 TEXT by default. Columns declared
+//! `#[column(integer)]` store unix seconds instead; the generated insert and
+//! update models pass their values through [`SQLiteValue::into_unix_timestamp`]
+//! and `FromSQLiteValue` reads INTEGER values back as unix seconds.
+
+use crate::values::SQLiteValue;
+
+impl SQLiteValue<'_> {
+    /// Re-encodes an ISO-8601 / RFC 3339 date-time TEXT value as INTEGER
+    /// unix seconds. Date-times without an offset are taken as UTC.
+    ///
+    /// Other values, and text that does not parse as a date-time, are
+    /// returned unchanged.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn into_unix_timestamp(self) -> Self {
+        match &self {
+            SQLiteValue::Text(text) => parse_unix_seconds(text).map_or(self, SQLiteValue::Integer),
+            _ => self,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn parse_unix_seconds(text: &str) -> Option<i64> {
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(text) {
+        return Some(dt.timestamp());
+    }
+    text.parse::<chrono::NaiveDateTime>()
+        .or_else(|_| chrono::NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f"))
+        .ok()
+        .map(|dt| dt.and_utc().timestamp())
+}
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+fn parse_unix_seconds(text: &str) -> Option<i64> {
+    use time::format_description::well_known::{Iso8601, Rfc3339};
+
+    if let Ok(dt) = time::OffsetDateTime::parse(text, &Rfc3339) {
+        return Some(dt.unix_timestamp());
+    }
+    time::PrimitiveDateTime::parse(text, &Iso8601::DATE_TIME)
+        .ok()
+        .map(|dt| dt.assume_utc().unix_timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_text_becomes_unix_seconds() {
+        let utc = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        assert_eq!(
+            SQLiteValue::from(utc).into_unix_timestamp(),
+            SQLiteValue::Integer(1_700_000_000)
+        );
+        assert_eq!(
+            SQLiteValue::from(utc.naive_utc()).into_unix_timestamp(),
+            SQLiteValue::Integer(1_700_000_000)
+        );
+    }
+
+    #[test]
+    fn non_datetime_values_are_unchanged() {
+        assert_eq!(
+            SQLiteValue::from("not a date").into_unix_timestamp(),
+            SQLiteValue::from("not a date")
+        );
+        assert_eq!(
+            SQLiteValue::Integer(5).into_unix_timestamp(),
+            SQLiteValue::Integer(5)
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! `Vec<f32>` embeddings stored as sqlite-vec `float32` blobs.
+//!
+//! sqlite-vec's scalar functions (`vec_distance_l2`, `vec_distance_cosine`,
+//! ...) accept vectors as BLOBs of packed little-endian `f32`s, so
+//! embeddings are declared as `BLOB` columns holding that encoding.
+
+use crate::prelude::*;
+use crate::traits::DrizzleSQLiteColumn;
+use crate::values::{SQLiteValue, SQLiteValueRef};
+use drizzle_core::error::DrizzleError;
+use drizzle_core::expr::{Expr, NonNull, Scalar};
+use drizzle_core::sql::SQL;
+use drizzle_core::vector;
+
+impl DrizzleSQLiteColumn for Vec<f32> {
+    type SQLType = drizzle_types::sqlite::types::Blob;
+
+    const SQL_TYPE: &'static str = "BLOB";
+
+    fn decode(value: SQLiteValueRef<'_>) -> Result<Self, DrizzleError> {
+        match value {
+            SQLiteValueRef::Blob(bytes) => vector::decode_blob(bytes),
+            // sqlite-vec also accepts the JSON text form, e.g. `[1, 2.5]`
+            SQLiteValueRef::Text(text) => parse_json_vector(text),
+            other => Err(DrizzleError::ConversionError(
+                format!("cannot convert {other:?} to Vec<f32>, expected a float32 blob").into(),
+            )),
+        }
+    }
+
+    fn encode(&self) -> SQLiteValue<'_> {
+        SQLiteValue::Blob(Cow::Owned(vector::encode_blob(self)))
+    }
+}
+
+// `Vec<f32>` already has the generic comma-separated `ToSQL`, so as an
+// expression it renders the blob explicitly, like `Vec<u8>`
+impl<'a> Expr<'a, SQLiteValue<'a>> for Vec<f32> {
+    type SQLType = drizzle_types::sqlite::types::Blob;
+    type Nullable = NonNull;
+    type Aggregate = Scalar;
+
+    fn to_expr_sql(&self) -> SQL<'a, SQLiteValue<'a>> {
+        SQL::param(SQLiteValue::from(vector::encode_blob(self)))
+    }
+
+    fn into_expr_sql(self) -> SQL<'a, SQLiteValue<'a>> {
+        SQL::param(SQLiteValue::from(vector::encode_blob(&self)))
+    }
+}
+
+fn parse_json_vector(text: &str) -> Result<Vec<f32>, DrizzleError> {
+    let invalid = || DrizzleError::ConversionError(format!("invalid vector text {text:?}").into());
+    let inner = text
+        .trim()
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .ok_or_else(invalid)?
+        .trim();
+    if inner.is_empty() {
+        return Ok(Vec::new());
+    }
+    inner
+        .split(',')
+        .map(|component| component.trim().parse::<f32>().map_err(|_| invalid()))
+        .collect()
+}
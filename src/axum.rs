@@ -0,0 +1,235 @@
+//! [axum](https://docs.rs/axum) integration.
+//!
+//! [`Db`] extracts a cloned database handle from router state. Every async
+//! driver's `Drizzle` is cheap to clone, so it can be the state itself or a
+//! field pulled out with `FromRef`.
+//!
+//! ```rust
+//! # let _ = r####"
+//! use axum::{Router, routing::get};
+//! use drizzle::axum::Db;
+//! use drizzle::sqlite::libsql::Drizzle;
+//!
+//! async fn list_users(Db(db): Db<Drizzle<AppSchema>>) -> String {
+//!     let users: Vec<SelectUser> = db.select(()).from(db.schema().user).all().await.unwrap();
+//!     format!("{} users", users.len())
+//! }
+//!
+//! let app = Router::new().route("/users", get(list_users)).with_state(db);
+//! # "####;
+//! ```
+//!
+//! With the `libsql` driver, [`TxLayer`] wraps each request in a
+//! transaction that handlers reach through the [`Tx`] extractor. The
+//! transaction commits when the response status is a success or redirect
+//! and rolls back on a 4xx or 5xx.
+//!
+//! ```rust
+//! # let _ = r####"
+//! use drizzle::axum::{Tx, TxLayer};
+//!
+//! async fn create_user(tx: Tx<AppSchema>) -> StatusCode {
+//!     match tx.insert(tx.schema().user).values([InsertUser::new("Alice")]).execute().await {
+//!         Ok(_) => StatusCode::CREATED,
+//!         Err(_) => StatusCode::INTERNAL_SERVER_ERROR,
+//!     }
+//! }
+//!
+//! let app = Router::new()
+//!     .route("/users", post(create_user))
+//!     .layer(TxLayer::new(db));
+//! # "####;
+//! ```
+
+use axum_core::extract::{FromRef, FromRequestParts};
+use core::convert::Infallible;
+use core::ops::{Deref, DerefMut};
+use http::request::Parts;
+
+/// Extracts a database handle from router state.
+///
+/// `T` is usually a driver's `Drizzle` type; it is cloned out of the state
+/// via [`FromRef`], which the state itself satisfies when it is `T`.
+#[derive(Debug, Clone)]
+pub struct Db<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for Db<T>
+where
+    T: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self(T::from_ref(state)))
+    }
+}
+
+impl<T> Deref for Db<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Db<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "libsql")]
+pub use tx::{Tx, TxLayer, TxMiddleware};
+
+#[cfg(feature = "libsql")]
+mod tx {
+    use super::*;
+    use crate::builder::sqlite::libsql::Drizzle;
+    use crate::transaction::sqlite::libsql::Transaction;
+    use axum_core::response::{IntoResponse, Response};
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+    use drizzle_sqlite::connection::SQLiteTransactionType;
+    use http::{Request, StatusCode};
+    use std::sync::Arc;
+    use tower_layer::Layer;
+    use tower_service::Service;
+
+    /// Layer that runs every request inside a transaction.
+    ///
+    /// See the [module docs](super) for the commit and rollback rules.
+    #[derive(Debug, Clone)]
+    pub struct TxLayer<Schema> {
+        db: Drizzle<Schema>,
+        tx_type: SQLiteTransactionType,
+    }
+
+    impl<Schema> TxLayer<Schema> {
+        /// Creates a layer that opens deferred transactions on `db`.
+        pub const fn new(db: Drizzle<Schema>) -> Self {
+            Self {
+                db,
+                tx_type: SQLiteTransactionType::Deferred,
+            }
+        }
+
+        /// Sets the transaction type used for each request.
+        #[must_use]
+        pub const fn tx_type(mut self, tx_type: SQLiteTransactionType) -> Self {
+            self.tx_type = tx_type;
+            self
+        }
+    }
+
+    impl<Svc, Schema: Clone> Layer<Svc> for TxLayer<Schema> {
+        type Service = TxMiddleware<Svc, Schema>;
+
+        fn layer(&self, inner: Svc) -> Self::Service {
+            TxMiddleware {
+                inner,
+                db: self.db.clone(),
+                tx_type: self.tx_type,
+            }
+        }
+    }
+
+    /// Service produced by [`TxLayer`].
+    #[derive(Debug, Clone)]
+    pub struct TxMiddleware<Svc, Schema> {
+        inner: Svc,
+        db: Drizzle<Schema>,
+        tx_type: SQLiteTransactionType,
+    }
+
+    impl<Svc, Schema, ReqBody> Service<Request<ReqBody>> for TxMiddleware<Svc, Schema>
+    where
+        Svc: Service<Request<ReqBody>, Response = Response> + Clone + Send + 'static,
+        Svc::Future: Send,
+        Schema: Clone + Send + Sync + 'static,
+        ReqBody: Send + 'static,
+    {
+        type Response = Response;
+        type Error = Svc::Error;
+        type Future = Pin<Box<dyn Future<Output = Result<Response, Svc::Error>> + Send>>;
+
+        fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            self.inner.poll_ready(cx)
+        }
+
+        fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
+            // The clone may not be ready; call the instance `poll_ready` was
+            // driven on and leave the clone behind for the next request.
+            let clone = self.inner.clone();
+            let mut inner = core::mem::replace(&mut self.inner, clone);
+            let db = self.db.clone();
+            let tx_type = self.tx_type;
+
+            Box::pin(async move {
+                let tx = match db.conn().transaction_with_behavior(tx_type.into()).await {
+                    Ok(tx) => Arc::new(Transaction::new(tx, tx_type, db.schema().clone())),
+                    Err(_) => return Ok(StatusCode::INTERNAL_SERVER_ERROR.into_response()),
+                };
+
+                req.extensions_mut().insert(Tx(Arc::clone(&tx)));
+                let response = inner.call(req).await?;
+
+                // Handlers hand their `Tx` back when they finish; one that is
+                // still held elsewhere can't be committed safely.
+                let Ok(tx) = Arc::try_unwrap(tx) else {
+                    return Ok(StatusCode::INTERNAL_SERVER_ERROR.into_response());
+                };
+
+                let status = response.status();
+                if status.is_client_error() || status.is_server_error() {
+                    let _ = tx.rollback().await;
+                    Ok(response)
+                } else if tx.commit().await.is_ok() {
+                    Ok(response)
+                } else {
+                    Ok(StatusCode::INTERNAL_SERVER_ERROR.into_response())
+                }
+            })
+        }
+    }
+
+    /// Extracts the request's transaction opened by [`TxLayer`].
+    ///
+    /// Rejects with `500 Internal Server Error` when the route is not wrapped
+    /// in a [`TxLayer`].
+    #[derive(Debug)]
+    pub struct Tx<Schema>(Arc<Transaction<Schema>>);
+
+    impl<Schema> Clone for Tx<Schema> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+
+    impl<Schema, S> FromRequestParts<S> for Tx<Schema>
+    where
+        Schema: Send + Sync + 'static,
+        S: Send + Sync,
+    {
+        type Rejection = (StatusCode, &'static str);
+
+        async fn from_request_parts(
+            parts: &mut Parts,
+            _state: &S,
+        ) -> Result<Self, Self::Rejection> {
+            parts.extensions.get::<Self>().cloned().ok_or((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "drizzle: Tx extractor used on a route without TxLayer",
+            ))
+        }
+    }
+
+    impl<Schema> Deref for Tx<Schema> {
+        type Target = Transaction<Schema>;
+
+        fn deref(&self) -> &Transaction<Schema> {
+            &self.0
+        }
+    }
+}
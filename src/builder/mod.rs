@@ -6,6 +6,8 @@ pub mod sqlite;
 #[macro_use]
 pub mod postgres;
 
+pub mod read_only;
+
 #[macro_export]
 macro_rules! drizzle_prepare_impl {
     () => {
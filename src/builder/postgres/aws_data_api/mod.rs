@@ -332,7 +332,37 @@ where
     /// Returns [`DrizzleError`] if any CREATE statement fails via the Data API.
     pub async fn create(&self) -> drizzle_core::error::Result<()> {
         let schema = Schema::default();
-        let statements = schema.create_statements()?;
+        self.run_ddl(schema.create_statements()?).await
+    }
+
+    /// Like [`create`](Self::create), but objects that already exist are
+    /// left alone, so it is safe to call on every startup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if any CREATE statement fails to execute.
+    pub async fn create_if_not_exists(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.create_statements()?.map(super::ddl::if_not_exists))
+            .await
+    }
+
+    /// Drop every view, index, table and enum type of the schema, the inverse
+    /// of [`create`](Self::create). Tables are dropped referencing tables
+    /// first, with `CASCADE`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if any DROP statement fails to execute.
+    pub async fn drop(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.drop_statements()?).await
+    }
+
+    async fn run_ddl(
+        &self,
+        statements: impl IntoIterator<Item = String>,
+    ) -> drizzle_core::error::Result<()> {
         for statement in statements {
             self.run_statement(&statement, Vec::new(), None::<&str>)
                 .await?;
@@ -341,6 +371,65 @@ where
     }
 }
 
+impl<Schema> Drizzle<Schema> {
+    /// Empty `tables` with a single `TRUNCATE ... RESTART IDENTITY CASCADE`,
+    /// which also empties every table referencing them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the `TRUNCATE` fails.
+    pub async fn truncate(
+        &self,
+        tables: impl drizzle_core::traits::TableSet,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(&tables.table_refs()).await
+    }
+
+    /// Truncate every table of `schema`, e.g. before seeding or between tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the `TRUNCATE` fails.
+    pub async fn reset<S: drizzle_core::traits::SQLSchemaImpl>(
+        &self,
+        schema: &S,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(schema.table_refs()).await
+    }
+
+    async fn truncate_tables(
+        &self,
+        tables: &[&'static drizzle_core::TableRef],
+    ) -> drizzle_core::error::Result<()> {
+        if let Some(sql) = super::truncate::truncate_sql(tables) {
+            self.run_statement(&sql, Vec::new(), None::<&str>).await?;
+        }
+        Ok(())
+    }
+
+    /// Re-run `view`'s query with `REFRESH MATERIALIZED VIEW`.
+    ///
+    /// With `concurrently`, reads keep seeing the old rows during the
+    /// refresh. That needs a unique index on the view (declare one with
+    /// `#[PostgresIndex(unique)]`) and a view that has been populated
+    /// once, so a `WITH_NO_DATA` view's first refresh must not be
+    /// concurrent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if `view` is not materialized or the refresh
+    /// fails.
+    pub async fn refresh_materialized_view<V: drizzle_core::traits::SQLViewInfo>(
+        &self,
+        view: &V,
+        concurrently: bool,
+    ) -> drizzle_core::error::Result<()> {
+        let sql = super::refresh::refresh_sql(view, concurrently)?;
+        self.run_statement(&sql, Vec::new(), None::<&str>).await?;
+        Ok(())
+    }
+}
+
 impl<Schema> Drizzle<Schema> {
     /// Apply pending migrations from an embedded migration slice.
     ///
@@ -371,6 +460,9 @@ impl<Schema> Drizzle<Schema> {
 
         self.run_statement(&set.create_table_sql(), Vec::new(), None::<&str>)
             .await?;
+        if let Some(sql) = set.add_audit_columns_sql() {
+            self.run_statement(&sql, Vec::new(), None::<&str>).await?;
+        }
 
         let outcome = self
             .transaction(PostgresTransactionType::default(), async |tx| {
@@ -383,12 +475,16 @@ impl<Schema> Drizzle<Schema> {
                 )
                 .await?;
                 let applied_rows = tx
-                    .run_statement(&set.applied_names_sql(), Vec::new())
+                    .run_statement(&set.applied_hashes_sql(), Vec::new())
                     .await?;
-                let applied_names = decode_rows(applied_rows)
+                let applied_rows = decode_rows(applied_rows)
                     .into_iter()
-                    .map(|row| row.try_get::<String>(0))
+                    .map(|row| Ok((row.try_get::<String>(0)?, row.try_get::<String>(1)?)))
                     .collect::<drizzle_core::error::Result<Vec<_>>>()?;
+                set.check_checksums(&applied_rows)
+                    .map_err(|e| drizzle_core::error::DrizzleError::Other(e.to_string().into()))?;
+                let applied_names: Vec<&str> =
+                    applied_rows.iter().map(|(name, _)| name.as_str()).collect();
                 let pending: Vec<_> = set.pending(&applied_names).collect();
                 if pending.is_empty() {
                     return Ok(drizzle_migrations::MigrateOutcome::UpToDate);
@@ -396,19 +492,20 @@ impl<Schema> Drizzle<Schema> {
 
                 let mut applied = Vec::with_capacity(pending.len());
                 for migration in &pending {
-                    for statement in migration.statements() {
-                        if statement.trim().is_empty() {
-                            continue;
-                        }
-                        if drizzle_migrations::is_postgres_concurrent_index_statement(statement) {
-                            self.run_statement(statement, Vec::new(), None::<&str>)
+                    let started = std::time::Instant::now();
+                    for step in migration.steps() {
+                        if step.no_transaction {
+                            self.run_statement(step.sql, Vec::new(), None::<&str>)
                                 .await?;
                         } else {
-                            tx.execute(statement).await?;
+                            tx.execute(step.sql).await?;
                         }
                     }
-                    tx.execute(set.record_migration_sql(migration).as_str())
-                        .await?;
+                    tx.execute(
+                        set.record_applied_migration_sql(migration, started.elapsed())
+                            .as_str(),
+                    )
+                    .await?;
                     applied.push(migration.tag().to_string());
                 }
                 Ok(drizzle_migrations::MigrateOutcome::Applied { tags: applied })
@@ -563,7 +660,7 @@ where
         let rows = decode_rows(out);
         let mut decoded = Vec::with_capacity(rows.len());
         for row in &rows {
-            decoded.push(R::try_from(row).map_err(Into::into)?);
+            decoded.push(R::try_from(row).map_err(|e| e.into().at_row(decoded.len()))?);
         }
         Ok(decoded)
     }
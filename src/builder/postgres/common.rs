@@ -7,11 +7,11 @@ use crate::drizzle_pg_builder_join_using_impl;
 use drizzle_core::traits::{SQLModel, SQLTable, ToSQL};
 use drizzle_core::{ConflictTarget, NamedConstraint};
 use drizzle_postgres::builder::{
-    self, CTEView, DeleteInitial, DeleteReturningSet, DeleteWhereSet, InsertDoUpdateSet,
-    InsertInitial, InsertOnConflictSet, InsertReturningSet, InsertValuesSet, OnConflictBuilder,
-    QueryBuilder, SelectForSet, SelectFromSet, SelectGroupSet, SelectInitial, SelectJoinSet,
-    SelectLimitSet, SelectOffsetSet, SelectOrderSet, SelectWhereSet, UpdateFromSet, UpdateInitial,
-    UpdateReturningSet, UpdateSetClauseSet, UpdateWhereSet,
+    self, CTEView, DeleteInitial, DeleteReturningSet, DeleteUsingSet, DeleteWhereSet,
+    InsertDoUpdateSet, InsertInitial, InsertOnConflictSet, InsertReturningSet, InsertValuesSet,
+    OnConflictBuilder, QueryBuilder, SelectForSet, SelectFromSet, SelectGroupSet, SelectInitial,
+    SelectJoinSet, SelectLimitSet, SelectOffsetSet, SelectOrderSet, SelectWhereSet, UpdateFromSet,
+    UpdateInitial, UpdateReturningSet, UpdateSetClauseSet, UpdateWhereSet,
     delete::DeleteBuilder,
     insert::InsertBuilder,
     select::{AsCteState, IntoSelect, SelectBuilder, SelectSetOpSet},
@@ -21,6 +21,27 @@ use drizzle_postgres::common::PostgresSchemaType;
 use drizzle_postgres::traits::PostgresTable;
 use drizzle_postgres::values::PostgresValue;
 
+/// Builds `SELECT * FROM table WHERE column IN (...)` for one `get_many` chunk.
+pub(crate) fn get_many_sql<'a, T, C, K>(
+    table: T,
+    column: C,
+    keys: &'a [K],
+) -> drizzle_core::SQL<'a, PostgresValue<'a>>
+where
+    T: ToSQL<'a, PostgresValue<'a>>,
+    C: drizzle_core::expr::Expr<'a, PostgresValue<'a>>,
+    &'a K: drizzle_core::expr::Expr<'a, PostgresValue<'a>>,
+    C::SQLType: drizzle_core::types::Compatible<
+            <&'a K as drizzle_core::expr::Expr<'a, PostgresValue<'a>>>::SQLType,
+        >,
+{
+    drizzle_core::helpers::select(())
+        .append(drizzle_core::helpers::from(table))
+        .append(drizzle_core::helpers::r#where(
+            drizzle_core::expr::in_array(column, keys),
+        ))
+}
+
 /// Shared Postgres drizzle builder wrapper.
 #[derive(Debug)]
 pub struct DrizzleBuilder<'a, Runner, Schema, Builder, State> {
@@ -1088,6 +1109,37 @@ where
             state: PhantomData,
         }
     }
+
+    /// Adds `RETURNING` for the table's primary key.
+    pub fn returning_ids<Pk>(
+        self,
+    ) -> DrizzleBuilder<
+        'a,
+        Runner,
+        Schema,
+        InsertBuilder<
+            'b,
+            Schema,
+            InsertReturningSet,
+            Table,
+            drizzle_core::Scoped<Pk::Marker, drizzle_core::Cons<Table, drizzle_core::Nil>>,
+            <Pk::Marker as drizzle_core::ResolveRow<Table>>::Row,
+        >,
+        InsertReturningSet,
+    >
+    where
+        <Table as SQLTable<'b, PostgresSchemaType, PostgresValue<'b>>>::PrimaryKey:
+            drizzle_core::SQLPrimaryKey<Columns = (Pk,)>,
+        Pk: Default + ToSQL<'b, PostgresValue<'b>> + drizzle_core::IntoSelectTarget,
+        Pk::Marker: drizzle_core::ResolveRow<Table>,
+    {
+        let builder = self.builder.returning_ids();
+        DrizzleBuilder {
+            runner: self.runner,
+            builder,
+            state: PhantomData,
+        }
+    }
 }
 
 impl<'a, 'b, Runner, Schema, Table>
@@ -1412,6 +1464,87 @@ impl<'a, 'b, Runner, Schema, Table>
     >
 where
     Table: PostgresTable<'b>,
+{
+    pub fn using(
+        self,
+        source: impl ToSQL<'b, PostgresValue<'b>>,
+    ) -> DrizzleBuilder<
+        'a,
+        Runner,
+        Schema,
+        DeleteBuilder<'b, Schema, DeleteUsingSet, Table>,
+        DeleteUsingSet,
+    > {
+        let builder = self.builder.using(source.to_sql());
+        DrizzleBuilder {
+            runner: self.runner,
+            builder,
+            state: PhantomData,
+        }
+    }
+
+    pub fn r#where<E>(
+        self,
+        condition: E,
+    ) -> DrizzleBuilder<
+        'a,
+        Runner,
+        Schema,
+        DeleteBuilder<'b, Schema, DeleteWhereSet, Table>,
+        DeleteWhereSet,
+    >
+    where
+        E: drizzle_core::expr::Expr<'b, PostgresValue<'b>>,
+        E::SQLType: drizzle_core::types::BooleanLike,
+    {
+        let builder = self.builder.r#where(condition);
+        DrizzleBuilder {
+            runner: self.runner,
+            builder,
+            state: PhantomData,
+        }
+    }
+
+    pub fn returning<Columns>(
+        self,
+        columns: Columns,
+    ) -> DrizzleBuilder<
+        'a,
+        Runner,
+        Schema,
+        DeleteBuilder<
+            'b,
+            Schema,
+            DeleteReturningSet,
+            Table,
+            drizzle_core::Scoped<Columns::Marker, drizzle_core::Cons<Table, drizzle_core::Nil>>,
+            <Columns::Marker as drizzle_core::ResolveRow<Table>>::Row,
+        >,
+        DeleteReturningSet,
+    >
+    where
+        Columns: ToSQL<'b, PostgresValue<'b>> + drizzle_core::IntoSelectTarget,
+        Columns::Marker: drizzle_core::ResolveRow<Table>,
+    {
+        let builder = self.builder.returning(columns);
+        DrizzleBuilder {
+            runner: self.runner,
+            builder,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<'a, 'b, Runner, Schema, Table>
+    DrizzleBuilder<
+        'a,
+        Runner,
+        Schema,
+        DeleteBuilder<'b, Schema, DeleteUsingSet, Table>,
+        DeleteUsingSet,
+    >
+where
+    Table: PostgresTable<'b>,
 {
     pub fn r#where<E>(
         self,
@@ -0,0 +1,43 @@
+//! `IF NOT EXISTS` rewriting for `create_if_not_exists`, shared by the
+//! postgres drivers.
+
+/// `CREATE` prefixes emitted by the schema derive that accept
+/// `IF NOT EXISTS` right after them.
+const CREATE_PREFIXES: &[&str] = &[
+    "CREATE TABLE ",
+    "CREATE UNLOGGED TABLE ",
+    "CREATE TEMPORARY TABLE ",
+    "CREATE UNIQUE INDEX CONCURRENTLY ",
+    "CREATE UNIQUE INDEX ",
+    "CREATE INDEX CONCURRENTLY ",
+    "CREATE INDEX ",
+    "CREATE MATERIALIZED VIEW ",
+];
+
+/// Rewrites a statement from `create_statements` so it is a no-op when the
+/// object already exists.
+///
+/// Views are created with `OR REPLACE`. Enum types and policies have no
+/// `IF NOT EXISTS`, so they run in a `DO` block that ignores
+/// `duplicate_object`. Everything else (comments, `ENABLE ROW LEVEL
+/// SECURITY`) is already safe to repeat.
+pub(crate) fn if_not_exists(statement: String) -> String {
+    for prefix in CREATE_PREFIXES {
+        if let Some(rest) = statement.strip_prefix(prefix) {
+            if rest.starts_with("IF NOT EXISTS ") {
+                return statement;
+            }
+            return format!("{prefix}IF NOT EXISTS {rest}");
+        }
+    }
+    if let Some(rest) = statement.strip_prefix("CREATE VIEW ") {
+        return format!("CREATE OR REPLACE VIEW {rest}");
+    }
+    if statement.starts_with("CREATE TYPE ") || statement.starts_with("CREATE POLICY ") {
+        return format!(
+            "DO $drizzle$ BEGIN {}; EXCEPTION WHEN duplicate_object THEN NULL; END $drizzle$",
+            statement.trim_end().trim_end_matches(';')
+        );
+    }
+    statement
+}
@@ -115,6 +115,25 @@ macro_rules! postgres_builder_constructors {
                 state: ::std::marker::PhantomData,
             }
         }
+
+        /// Creates a `CREATE TEMP TABLE ... AS SELECT ...` statement.
+        ///
+        /// The view is the typed handle for the new table: select from or join it
+        /// in later queries on the same connection or transaction.
+        pub fn create_temp_table_as<'a, 'b, Table, Query>(
+            &'a self,
+            view: &builder::CTEView<'b, Table, Query>,
+        ) -> DrizzleBuilder<'a, Schema, QueryBuilder<'b, Schema, builder::CreateTempTableSet>, builder::CreateTempTableSet>
+        where
+            Query: ToSQL<'b, PostgresValue<'b>>,
+        {
+            let builder = QueryBuilder::new::<Schema>().create_temp_table_as(view);
+            DrizzleBuilder {
+                runner: self,
+                builder,
+                state: ::std::marker::PhantomData,
+            }
+        }
     };
     (mut) => {
         /// Creates a SELECT query builder.
@@ -230,6 +249,123 @@ macro_rules! postgres_builder_constructors {
                 state: ::std::marker::PhantomData,
             }
         }
+
+        /// Creates a `CREATE TEMP TABLE ... AS SELECT ...` statement.
+        ///
+        /// The view is the typed handle for the new table: select from or join it
+        /// in later queries on the same connection or transaction.
+        pub fn create_temp_table_as<'a, 'b, Table, Query>(
+            &'a mut self,
+            view: &builder::CTEView<'b, Table, Query>,
+        ) -> DrizzleBuilder<'a, Schema, QueryBuilder<'b, Schema, builder::CreateTempTableSet>, builder::CreateTempTableSet>
+        where
+            Query: ToSQL<'b, PostgresValue<'b>>,
+        {
+            let builder = QueryBuilder::new::<Schema>().create_temp_table_as(view);
+            DrizzleBuilder {
+                runner: self,
+                builder,
+                state: ::std::marker::PhantomData,
+            }
+        }
+    };
+}
+
+/// Read builders for [`crate::ReadOnly`], forwarded to the wrapped handle.
+macro_rules! postgres_read_only_constructors {
+    () => {
+        /// Creates a SELECT query builder.
+        pub fn select<'a, 'b, T>(
+            &'a self,
+            query: T,
+        ) -> DrizzleBuilder<'a, Schema, SelectBuilder<'b, Schema, SelectInitial, (), T::Marker>, SelectInitial>
+        where
+            T: ToSQL<'b, PostgresValue<'b>> + drizzle_core::IntoSelectTarget,
+        {
+            self.0.select(query)
+        }
+
+        /// Creates a SELECT DISTINCT query builder.
+        pub fn select_distinct<'a, 'b, T>(
+            &'a self,
+            query: T,
+        ) -> DrizzleBuilder<'a, Schema, SelectBuilder<'b, Schema, SelectInitial, (), T::Marker>, SelectInitial>
+        where
+            T: ToSQL<'b, PostgresValue<'b>> + drizzle_core::IntoSelectTarget,
+        {
+            self.0.select_distinct(query)
+        }
+
+        /// Creates a SELECT DISTINCT ON query builder.
+        pub fn select_distinct_on<'a, 'b, On, Columns>(
+            &'a self,
+            on: On,
+            columns: Columns,
+        ) -> DrizzleBuilder<'a, Schema, SelectBuilder<'b, Schema, SelectInitial, (), Columns::Marker>, SelectInitial>
+        where
+            On: ToSQL<'b, PostgresValue<'b>>,
+            Columns: ToSQL<'b, PostgresValue<'b>> + drizzle_core::IntoSelectTarget,
+        {
+            self.0.select_distinct_on(on, columns)
+        }
+
+        /// Creates a query with CTE (Common Table Expression).
+        pub fn with<'a, 'b, C>(
+            &'a self,
+            cte: &C,
+        ) -> DrizzleBuilder<'a, Schema, QueryBuilder<'b, Schema, builder::CTEInit>, builder::CTEInit>
+        where
+            C: builder::CTEDefinition<'b>,
+        {
+            self.0.with(cte)
+        }
+    };
+    (mut) => {
+        /// Creates a SELECT query builder.
+        pub fn select<'a, 'b, T>(
+            &'a mut self,
+            query: T,
+        ) -> DrizzleBuilder<'a, Schema, SelectBuilder<'b, Schema, SelectInitial, (), T::Marker>, SelectInitial>
+        where
+            T: ToSQL<'b, PostgresValue<'b>> + drizzle_core::IntoSelectTarget,
+        {
+            self.0.select(query)
+        }
+
+        /// Creates a SELECT DISTINCT query builder.
+        pub fn select_distinct<'a, 'b, T>(
+            &'a mut self,
+            query: T,
+        ) -> DrizzleBuilder<'a, Schema, SelectBuilder<'b, Schema, SelectInitial, (), T::Marker>, SelectInitial>
+        where
+            T: ToSQL<'b, PostgresValue<'b>> + drizzle_core::IntoSelectTarget,
+        {
+            self.0.select_distinct(query)
+        }
+
+        /// Creates a SELECT DISTINCT ON query builder.
+        pub fn select_distinct_on<'a, 'b, On, Columns>(
+            &'a mut self,
+            on: On,
+            columns: Columns,
+        ) -> DrizzleBuilder<'a, Schema, SelectBuilder<'b, Schema, SelectInitial, (), Columns::Marker>, SelectInitial>
+        where
+            On: ToSQL<'b, PostgresValue<'b>>,
+            Columns: ToSQL<'b, PostgresValue<'b>> + drizzle_core::IntoSelectTarget,
+        {
+            self.0.select_distinct_on(on, columns)
+        }
+
+        /// Creates a query with CTE (Common Table Expression).
+        pub fn with<'a, 'b, C>(
+            &'a mut self,
+            cte: &C,
+        ) -> DrizzleBuilder<'a, Schema, QueryBuilder<'b, Schema, builder::CTEInit>, builder::CTEInit>
+        where
+            C: builder::CTEDefinition<'b>,
+        {
+            self.0.with(cte)
+        }
     };
 }
 
@@ -243,5 +379,30 @@ pub mod tokio_postgres;
 pub mod aws_data_api;
 
 pub mod common;
+#[cfg(any(
+    feature = "postgres-sync",
+    feature = "tokio-postgres",
+    feature = "aws-data-api"
+))]
+pub(crate) mod ddl;
 pub mod prepared_common;
+#[cfg(any(
+    feature = "postgres-sync",
+    feature = "tokio-postgres",
+    feature = "aws-data-api"
+))]
+pub(crate) mod refresh;
 pub mod rows;
+#[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+pub(crate) mod tenant;
+#[cfg(all(
+    feature = "postgres-native-tls",
+    any(feature = "postgres-sync", feature = "tokio-postgres")
+))]
+pub mod tls;
+#[cfg(any(
+    feature = "postgres-sync",
+    feature = "tokio-postgres",
+    feature = "aws-data-api"
+))]
+pub(crate) mod truncate;
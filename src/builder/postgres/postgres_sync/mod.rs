@@ -145,6 +145,7 @@ use crate::transaction::savepoint::sync_transaction;
 pub type DrizzleBuilder<'a, Schema, Builder, State> =
     common::DrizzleBuilder<'a, &'a mut Drizzle<Schema>, Schema, Builder, State>;
 
+use crate::ReadOnly;
 use crate::transaction::postgres::postgres_sync::Transaction;
 
 #[cfg(feature = "query")]
@@ -161,7 +162,7 @@ crate::drizzle_prepare_impl!();
 pub struct Drizzle<Schema = ()> {
     client: Client,
     schema: Schema,
-    client_id: OnceLock<u64>,
+    client_id: prepared::ClientId,
     statement_cache: OnceLock<prepared::StatementCache>,
 }
 
@@ -201,18 +202,45 @@ impl Drizzle {
         let drizzle = Drizzle {
             client,
             schema,
-            client_id: OnceLock::new(),
+            client_id: prepared::ClientId::new(),
             statement_cache: OnceLock::new(),
         };
         (drizzle, schema)
     }
-}
 
-impl<Schema> Drop for Drizzle<Schema> {
-    fn drop(&mut self) {
-        if let Some(client_id) = self.client_id.get() {
-            prepared::unregister_client(*client_id);
-        }
+    /// Connects to `params` (a `key=value` string or `postgres://` URL) using
+    /// `tls`, whose mode replaces any `sslmode` in `params`.
+    ///
+    /// ```no_run
+    /// # use drizzle::postgres::sync::Drizzle;
+    /// # use drizzle::postgres::tls::{SslMode, TlsConfig};
+    /// # fn main() -> drizzle::Result<()> {
+    /// let tls = TlsConfig::new(SslMode::Require);
+    /// let (db, ()) = Drizzle::connect("host=db.example.com user=app", &tls, ())?;
+    /// # let _ = db;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if `params` doesn't parse, the TLS connector
+    /// can't be built, or the connection fails.
+    #[cfg(feature = "postgres-native-tls")]
+    pub fn connect<S: Copy>(
+        params: &str,
+        tls: &crate::builder::postgres::tls::TlsConfig,
+        schema: S,
+    ) -> drizzle_core::error::Result<(Drizzle<S>, S)> {
+        use crate::builder::postgres::tls::SslMode;
+
+        let mut config = params.parse::<postgres::Config>()?;
+        config.ssl_mode(tls.mode().wire());
+        let client = if tls.mode() == SslMode::Disable {
+            config.connect(postgres::NoTls)?
+        } else {
+            config.connect(tls.connector()?)?
+        };
+        Ok(Self::new(client, schema))
     }
 }
 
@@ -225,7 +253,7 @@ impl<S> AsRef<Self> for Drizzle<S> {
 
 impl<Schema> Drizzle<Schema> {
     fn client_id(&self) -> u64 {
-        *self.client_id.get_or_init(prepared::next_client_id)
+        self.client_id.get()
     }
 
     fn statement_cache(&self) -> prepared::StatementCache {
@@ -394,6 +422,63 @@ impl<Schema> Drizzle<Schema> {
         }
     }
 
+    /// Loads the rows whose `column` matches each of `keys`, in key order.
+    ///
+    /// Keys are sent in `IN (...)` chunks of at most
+    /// [`POSTGRES_MAX_BATCH_KEYS`](drizzle_core::batch::POSTGRES_MAX_BATCH_KEYS).
+    /// `key_of` reads the key back off a row; keys without a row yield `None`.
+    ///
+    /// ```rust
+    /// # let _ = r####"
+    /// let users: Vec<Option<SelectUser>> =
+    ///     db.get_many(user, user.id, &[3, 1, 2], |u: &SelectUser| u.id)?;
+    /// # "####;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if a query fails or row decoding fails.
+    pub fn get_many<'a, T, C, K, R>(
+        &mut self,
+        table: T,
+        column: C,
+        keys: &'a [K],
+        key_of: impl Fn(&R) -> K,
+    ) -> drizzle_core::error::Result<Vec<Option<R>>>
+    where
+        T: ToSQL<'a, PostgresValue<'a>> + Clone,
+        C: drizzle_core::expr::Expr<'a, PostgresValue<'a>> + Clone,
+        K: std::hash::Hash + Eq,
+        &'a K: drizzle_core::expr::Expr<'a, PostgresValue<'a>>,
+        C::SQLType: drizzle_core::types::Compatible<
+                <&'a K as drizzle_core::expr::Expr<'a, PostgresValue<'a>>>::SQLType,
+            >,
+        R: for<'r> TryFrom<&'r Row> + Clone,
+        for<'r> <R as TryFrom<&'r Row>>::Error: Into<drizzle_core::error::DrizzleError>,
+    {
+        let mut rows: Vec<R> = Vec::with_capacity(keys.len());
+        for chunk in keys.chunks(drizzle_core::batch::POSTGRES_MAX_BATCH_KEYS) {
+            // Runs the chunk inline: `all` would hold `&mut self` for `'a`.
+            let query = common::get_many_sql(table.clone(), column.clone(), chunk);
+            let (sql_str, params) = query.build();
+            drizzle_core::drizzle_trace_query!(&sql_str, params.len());
+
+            let (param_types, param_refs) = postgres_sync_materialize_params(&params);
+            let statement = self
+                .cached_statement(&sql_str, &param_types)
+                .with_query(|| QueryContext::new(&sql_str, &params))?;
+            let chunk_rows = self
+                .client
+                .query(&statement, &param_refs[..])
+                .with_query(|| QueryContext::new(&sql_str, &params))?;
+
+            for row in &chunk_rows {
+                rows.push(R::try_from(row).map_err(Into::into)?);
+            }
+        }
+        Ok(drizzle_core::batch::order_by_keys(keys, rows, key_of))
+    }
+
     /// Executes a transaction with the given callback.
     ///
     /// The transaction is committed when the callback returns `Ok` and
@@ -471,8 +556,36 @@ where
     /// Returns [`DrizzleError`] if any CREATE statement fails to execute.
     pub fn create(&mut self) -> drizzle_core::error::Result<()> {
         let schema = Schema::default();
-        let statements = schema.create_statements()?;
+        self.run_ddl(schema.create_statements()?)
+    }
+
+    /// Like [`create`](Self::create), but objects that already exist are
+    /// left alone, so it is safe to call on every startup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if any CREATE statement fails to execute.
+    pub fn create_if_not_exists(&mut self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.create_statements()?.map(super::ddl::if_not_exists))
+    }
 
+    /// Drop every view, index, table and enum type of the schema, the inverse
+    /// of [`create`](Self::create). Tables are dropped referencing tables
+    /// first, with `CASCADE`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if any DROP statement fails to execute.
+    pub fn drop(&mut self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.drop_statements()?)
+    }
+
+    fn run_ddl(
+        &mut self,
+        statements: impl IntoIterator<Item = String>,
+    ) -> drizzle_core::error::Result<()> {
         for statement in statements {
             self.client.execute(&statement, &[])?;
         }
@@ -481,6 +594,255 @@ where
     }
 }
 
+impl<Schema> Drizzle<Schema> {
+    /// Empty `tables` with a single `TRUNCATE ... RESTART IDENTITY CASCADE`,
+    /// which also empties every table referencing them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the `TRUNCATE` fails.
+    pub fn truncate(
+        &mut self,
+        tables: impl drizzle_core::traits::TableSet,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(&tables.table_refs())
+    }
+
+    /// Truncate every table of `schema`, e.g. before seeding or between tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the `TRUNCATE` fails.
+    pub fn reset<S: drizzle_core::traits::SQLSchemaImpl>(
+        &mut self,
+        schema: &S,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(schema.table_refs())
+    }
+
+    fn truncate_tables(
+        &mut self,
+        tables: &[&'static drizzle_core::TableRef],
+    ) -> drizzle_core::error::Result<()> {
+        if let Some(sql) = super::truncate::truncate_sql(tables) {
+            self.client.execute(&sql, &[])?;
+        }
+        Ok(())
+    }
+
+    /// Re-run `view`'s query with `REFRESH MATERIALIZED VIEW`.
+    ///
+    /// With `concurrently`, reads keep seeing the old rows during the
+    /// refresh. That needs a unique index on the view (declare one with
+    /// `#[PostgresIndex(unique)]`) and a view that has been populated
+    /// once, so a `WITH_NO_DATA` view's first refresh must not be
+    /// concurrent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if `view` is not materialized or the refresh
+    /// fails.
+    pub fn refresh_materialized_view<V: drizzle_core::traits::SQLViewInfo>(
+        &mut self,
+        view: &V,
+        concurrently: bool,
+    ) -> drizzle_core::error::Result<()> {
+        let sql = super::refresh::refresh_sql(view, concurrently)?;
+        self.client.execute(&sql, &[])?;
+        Ok(())
+    }
+}
+
+impl<Schema> Drizzle<Schema> {
+    /// Switches the session to read-only and returns a handle without write
+    /// builders, e.g. for a replica connection.
+    ///
+    /// Sets `default_transaction_read_only`, so raw writes through
+    /// [`ReadOnly::conn_mut`] fail in the server as well.
+    ///
+    /// ```no_run
+    /// # use drizzle::postgres::prelude::*;
+    /// # use drizzle::postgres::sync::Drizzle;
+    /// # #[PostgresTable] struct User { #[column(serial, primary)] id: i32, name: String }
+    /// # #[derive(PostgresSchema)] struct S { user: User }
+    /// # fn main() -> drizzle::Result<()> {
+    /// # let client = ::postgres::Client::connect("host=replica user=postgres", ::postgres::NoTls)?;
+    /// let (db, S { user }) = Drizzle::new(client, S::new());
+    /// let mut replica = db.read_only()?;
+    ///
+    /// let users: Vec<SelectUser> = replica.select(()).from(user).all()?;
+    /// // replica.insert(user) does not compile
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the `SET` fails.
+    pub fn read_only(mut self) -> drizzle_core::error::Result<ReadOnly<Self>> {
+        self.client
+            .batch_execute("SET default_transaction_read_only = on")?;
+        Ok(ReadOnly(self))
+    }
+}
+
+impl<Schema> ReadOnly<Drizzle<Schema>> {
+    /// Gets a reference to the underlying connection.
+    #[inline]
+    pub const fn conn(&self) -> &Client {
+        self.0.conn()
+    }
+
+    /// Gets a mutable reference to the underlying connection.
+    #[inline]
+    pub fn conn_mut(&mut self) -> &mut Client {
+        self.0.conn_mut()
+    }
+
+    /// Gets a reference to the schema.
+    #[inline]
+    pub const fn schema(&self) -> &Schema {
+        self.0.schema()
+    }
+
+    postgres_read_only_constructors!(mut);
+
+    /// Creates a relational query builder for the given table.
+    #[cfg(feature = "query")]
+    pub fn query<'a, T>(
+        &mut self,
+        table: T,
+    ) -> common::DrizzleQueryBuilder<'_, 'a, &mut Drizzle<Schema>, Schema, T>
+    where
+        T: drizzle_core::query::QueryTable,
+    {
+        self.0.query(table)
+    }
+}
+
+impl<Schema> Drizzle<Schema> {
+    /// Sets the session's `search_path`, so unqualified table names resolve
+    /// against `schemas` in order.
+    ///
+    /// Use [`Transaction::set_search_path`] to override it for one
+    /// transaction.
+    ///
+    /// ```no_run
+    /// # use drizzle::postgres::prelude::*;
+    /// # use drizzle::postgres::sync::Drizzle;
+    /// # #[PostgresTable] struct User { #[column(serial, primary)] id: i32, name: String }
+    /// # #[derive(PostgresSchema)] struct S { user: User }
+    /// # fn main() -> drizzle::Result<()> {
+    /// # let client = ::postgres::Client::connect("host=localhost user=postgres", ::postgres::NoTls)?;
+    /// let (mut db, S { user }) = Drizzle::new(client, S::new());
+    /// db.set_search_path(["tenant_a", "public"])?;
+    ///
+    /// // Reads "tenant_a"."user"
+    /// let users: Vec<SelectUser> = db.select(()).from(user).all()?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if `schemas` is empty or holds an empty name,
+    /// or if the `SET` fails.
+    pub fn set_search_path<I>(&mut self, schemas: I) -> drizzle_core::error::Result<()>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.client
+            .batch_execute(&super::tenant::search_path_sql(schemas)?)?;
+        Ok(())
+    }
+
+    /// Creates the `tenant` schema, runs `schema`'s CREATE statements inside
+    /// it, and returns a handle whose `search_path` is `tenant`.
+    ///
+    /// Everything runs in one implicit transaction, so a failure leaves no
+    /// half-created tenant. Tables with an explicit `schema = "..."` keep it.
+    ///
+    /// ```no_run
+    /// # use drizzle::postgres::prelude::*;
+    /// # use drizzle::postgres::sync::Drizzle;
+    /// # #[PostgresTable] struct User { #[column(serial, primary)] id: i32, name: String }
+    /// # #[derive(PostgresSchema)] struct S { user: User }
+    /// # fn main() -> drizzle::Result<()> {
+    /// # let client = ::postgres::Client::connect("host=localhost user=postgres", ::postgres::NoTls)?;
+    /// let (db, ()) = Drizzle::new(client, ());
+    /// let (mut tenant, S { user }) = db.create_tenant_schema("tenant_42", S::new())?;
+    ///
+    /// // Unqualified tables now resolve to "tenant_42"
+    /// tenant.insert(user).values([InsertUser::new("alice")]).execute()?;
+    /// assert_eq!(tenant.tenant_schemas("tenant_")?, ["tenant_42"]);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if `tenant` is empty or already exists, or if
+    /// any CREATE statement fails.
+    pub fn create_tenant_schema<S>(
+        mut self,
+        tenant: &str,
+        schema: S,
+    ) -> drizzle_core::error::Result<(Drizzle<S>, S)>
+    where
+        S: drizzle_core::traits::SQLSchemaImpl + Copy,
+    {
+        let sql = super::tenant::create_tenant_sql(tenant, schema.create_statements()?)?;
+        self.client.batch_execute(&sql)?;
+        Ok(self.with_tenant(schema))
+    }
+
+    /// Returns a handle scoped to an existing `tenant` schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if `tenant` is empty or the `SET` fails.
+    pub fn into_tenant_schema<S: Copy>(
+        mut self,
+        tenant: &str,
+        schema: S,
+    ) -> drizzle_core::error::Result<(Drizzle<S>, S)> {
+        self.client
+            .batch_execute(&super::tenant::search_path_sql([tenant])?)?;
+        Ok(self.with_tenant(schema))
+    }
+
+    /// Drops the `tenant` schema and everything in it, if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if `tenant` is empty or the `DROP` fails.
+    pub fn drop_tenant_schema(&mut self, tenant: &str) -> drizzle_core::error::Result<()> {
+        self.client
+            .batch_execute(&super::tenant::drop_tenant_sql(tenant)?)?;
+        Ok(())
+    }
+
+    /// Lists schema names starting with `prefix`, sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the catalog query fails.
+    pub fn tenant_schemas(&mut self, prefix: &str) -> drizzle_core::error::Result<Vec<String>> {
+        let rows = self
+            .client
+            .query(super::tenant::TENANT_SCHEMAS_SQL, &[&prefix])?;
+        Ok(rows.iter().map(|row| row.get(0)).collect())
+    }
+
+    fn with_tenant<S: Copy>(self, schema: S) -> (Drizzle<S>, S) {
+        let drizzle = Drizzle {
+            client: self.client,
+            schema,
+            client_id: self.client_id,
+            statement_cache: self.statement_cache,
+        };
+        (drizzle, schema)
+    }
+}
+
 impl<Schema> Drizzle<Schema> {
     /// Apply pending migrations from an embedded migration slice.
     ///
@@ -509,42 +871,49 @@ impl<Schema> Drizzle<Schema> {
 
         let result = (|| {
             ensure_postgres_migration_table(&mut self.client, &set)?;
-            let rows = self.client.query(&set.applied_names_sql(), &[])?;
-            let applied_names = rows
+            let rows = self.client.query(&set.applied_hashes_sql(), &[])?;
+            let applied_rows = rows
                 .iter()
-                .map(|row| row.try_get::<_, String>(0))
+                .map(|row| Ok((row.try_get::<_, String>(0)?, row.try_get::<_, String>(1)?)))
                 .collect::<Result<Vec<_>, postgres::Error>>()?;
+            set.check_checksums(&applied_rows)
+                .map_err(|e| drizzle_core::error::DrizzleError::Other(e.to_string().into()))?;
+            let applied_names: Vec<&str> =
+                applied_rows.iter().map(|(name, _)| name.as_str()).collect();
             let pending: Vec<_> = set.pending(&applied_names).collect();
 
             if pending.is_empty() {
+                if let Some(sql) = set.record_schema_hash_sql() {
+                    self.client.execute(&sql, &[])?;
+                }
                 return Ok(drizzle_migrations::MigrateOutcome::UpToDate);
             }
 
             let mut applied = Vec::with_capacity(pending.len());
-            if set.has_postgres_concurrent_index() {
-                for migration in &pending {
-                    for statement in migration.statements() {
-                        if !statement.trim().is_empty() {
-                            self.client.execute(statement, &[])?;
-                        }
-                    }
-                    self.client
-                        .execute(&set.record_migration_sql(migration), &[])?;
-                    applied.push(migration.tag().to_string());
-                }
-            } else {
-                let mut tx = self.client.transaction()?;
-                for migration in &pending {
-                    for statement in migration.statements() {
-                        if !statement.trim().is_empty() {
-                            tx.execute(statement, &[])?;
-                        }
+            let mut tx = self.client.transaction()?;
+            for migration in &pending {
+                let started = std::time::Instant::now();
+                for step in migration.steps() {
+                    if step.no_transaction {
+                        // e.g. CREATE INDEX CONCURRENTLY can't run inside a
+                        // transaction block: commit what ran so far first.
+                        tx.commit()?;
+                        self.client.execute(step.sql, &[])?;
+                        tx = self.client.transaction()?;
+                    } else {
+                        tx.execute(step.sql, &[])?;
                     }
-                    tx.execute(&set.record_migration_sql(migration), &[])?;
-                    applied.push(migration.tag().to_string());
                 }
-                tx.commit()?;
+                tx.execute(
+                    &set.record_applied_migration_sql(migration, started.elapsed()),
+                    &[],
+                )?;
+                applied.push(migration.tag().to_string());
             }
+            if let Some(sql) = set.record_schema_hash_sql() {
+                tx.execute(&sql, &[])?;
+            }
+            tx.commit()?;
 
             Ok(drizzle_migrations::MigrateOutcome::Applied { tags: applied })
         })();
@@ -560,6 +929,40 @@ impl<Schema> Drizzle<Schema> {
             (Ok(_), Err(error)) => Err(error.into()),
         }
     }
+
+    /// Check that the schema hash recorded by [`migrate`](Self::migrate)
+    /// matches `Schema::SCHEMA_HASH`.
+    ///
+    /// Call it at startup to catch an application deployed against the wrong
+    /// database. Record the hash with [`Tracking::schema_hash`](drizzle_migrations::Tracking::schema_hash).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if no hash was recorded or it differs.
+    pub fn assert_schema_matches(
+        &mut self,
+        tracking: drizzle_migrations::Tracking,
+    ) -> drizzle_core::error::Result<()>
+    where
+        Schema: drizzle_core::traits::SQLSchemaImpl,
+    {
+        let set = drizzle_migrations::Migrations::with_tracking(
+            Vec::new(),
+            drizzle_types::Dialect::PostgreSQL,
+            tracking,
+        );
+        let recorded: Option<String> =
+            if self.client.query(&set.table_exists_sql(), &[])?.is_empty() {
+                None
+            } else {
+                self.client
+                    .query_opt(&set.schema_hash_sql(), &[])?
+                    .map(|row| row.try_get(0))
+                    .transpose()?
+            };
+        drizzle_migrations::check_schema_hash(recorded.as_deref(), Schema::SCHEMA_HASH)
+            .map_err(|e| drizzle_core::error::DrizzleError::Schema(e.to_string().into()))
+    }
 }
 
 fn ensure_postgres_migration_table(
@@ -567,6 +970,9 @@ fn ensure_postgres_migration_table(
     set: &drizzle_migrations::Migrations,
 ) -> drizzle_core::error::Result<()> {
     client.execute(&set.create_table_sql(), &[])?;
+    if let Some(sql) = set.add_audit_columns_sql() {
+        client.execute(&sql, &[])?;
+    }
 
     let schema = set.schema_name().unwrap_or("public");
     let rows = client.query(
@@ -729,6 +1135,44 @@ fn pg_sync_query_enums(
         .collect())
 }
 
+fn pg_sync_query_domains(
+    client: &mut postgres::Client,
+) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::introspect::RawDomainInfo>> {
+    use drizzle_migrations::postgres::introspect::{RawDomainInfo, queries};
+
+    Ok(client
+        .query(queries::DOMAINS_QUERY, &[])
+        .map_err(|e| pg_sync_err("Failed to query domains", &e))?
+        .into_iter()
+        .map(|row| RawDomainInfo {
+            schema: row.get(0),
+            name: row.get(1),
+            base_type: row.get(2),
+            not_null: row.get(3),
+            default_value: row.get(4),
+            check: row.get(5),
+        })
+        .collect())
+}
+
+fn pg_sync_query_composites(
+    client: &mut postgres::Client,
+) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::introspect::RawCompositeInfo>> {
+    use drizzle_migrations::postgres::introspect::{RawCompositeInfo, queries};
+
+    Ok(client
+        .query(queries::COMPOSITES_QUERY, &[])
+        .map_err(|e| pg_sync_err("Failed to query composite types", &e))?
+        .into_iter()
+        .map(|row| RawCompositeInfo {
+            schema: row.get(0),
+            name: row.get(1),
+            field_names: row.get(2),
+            field_types: row.get(3),
+        })
+        .collect())
+}
+
 fn pg_sync_query_sequences(
     client: &mut postgres::Client,
 ) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::introspect::RawSequenceInfo>> {
@@ -894,6 +1338,37 @@ fn pg_sync_query_checks(
         .collect())
 }
 
+fn pg_sync_query_excludes(
+    client: &mut postgres::Client,
+    schema_filter: Option<&[String]>,
+) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::introspect::RawExcludeInfo>> {
+    use drizzle_migrations::postgres::introspect::{RawExcludeInfo, queries};
+
+    let rows = if let Some(schemas) = schema_filter {
+        client
+            .query(queries::EXCLUDES_QUERY_FILTERED, &[&schemas])
+            .map_err(|e| pg_sync_err("Failed to query exclusion constraints", &e))?
+    } else {
+        client
+            .query(queries::EXCLUDES_QUERY, &[])
+            .map_err(|e| pg_sync_err("Failed to query exclusion constraints", &e))?
+    };
+    Ok(rows
+        .into_iter()
+        .map(|row| RawExcludeInfo {
+            schema: row.get(0),
+            table: row.get(1),
+            name: row.get(2),
+            method: row.get(3),
+            columns: row.get(4),
+            operators: row.get(5),
+            where_clause: row.get(6),
+            deferrable: row.get(7),
+            initially_deferred: row.get(8),
+        })
+        .collect())
+}
+
 fn pg_sync_query_roles(
     client: &mut postgres::Client,
 ) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::introspect::RawRoleInfo>> {
@@ -938,8 +1413,8 @@ impl<Schema> Drizzle<Schema> {
     /// Introspect the connected `PostgreSQL` database and return a [`Snapshot`](drizzle_migrations::schema::Snapshot).
     ///
     /// Queries the `pg_catalog` and `information_schema` to extract tables, columns,
-    /// indexes, foreign keys, primary keys, unique/check constraints, enums, sequences,
-    /// views, roles, and policies.
+    /// indexes, foreign keys, primary keys, unique/check constraints, enums, domains,
+    /// composite types, sequences, views, roles, and policies.
     ///
     /// # Errors
     ///
@@ -960,6 +1435,22 @@ impl<Schema> Drizzle<Schema> {
         &mut self,
         schema_filter: Option<&[String]>,
     ) -> drizzle_core::error::Result<drizzle_migrations::schema::Snapshot> {
+        let ddl = self.introspect_ddl_impl(schema_filter)?;
+
+        // Build snapshot
+        let mut snap = drizzle_migrations::postgres::PostgresSnapshot::new();
+        for entity in ddl.to_entities() {
+            snap.add_entity(entity);
+        }
+
+        Ok(drizzle_migrations::schema::Snapshot::Postgres(snap))
+    }
+
+    /// Builds the DDL model for [`introspect_impl`](Self::introspect_impl).
+    fn introspect_ddl_impl(
+        &mut self,
+        schema_filter: Option<&[String]>,
+    ) -> drizzle_core::error::Result<drizzle_migrations::postgres::PostgresDDL> {
         use drizzle_migrations::postgres::ddl::Schema as PgSchema;
         use drizzle_migrations::postgres::introspect::{RawIntrospection, assemble_ddl};
 
@@ -972,6 +1463,8 @@ impl<Schema> Drizzle<Schema> {
         let raw_tables = pg_sync_query_tables(&mut self.client)?;
         let raw_columns = pg_sync_query_columns(&mut self.client)?;
         let raw_enums = pg_sync_query_enums(&mut self.client)?;
+        let raw_domains = pg_sync_query_domains(&mut self.client)?;
+        let raw_composites = pg_sync_query_composites(&mut self.client)?;
         let raw_sequences = pg_sync_query_sequences(&mut self.client)?;
         let raw_views = pg_sync_query_views(&mut self.client, effective_schema_filter)?;
         let raw_indexes = pg_sync_query_indexes(&mut self.client, effective_schema_filter)?;
@@ -979,14 +1472,17 @@ impl<Schema> Drizzle<Schema> {
         let raw_primary_keys = pg_sync_query_primary_keys(&mut self.client)?;
         let raw_uniques = pg_sync_query_uniques(&mut self.client)?;
         let raw_checks = pg_sync_query_checks(&mut self.client, effective_schema_filter)?;
+        let raw_excludes = pg_sync_query_excludes(&mut self.client, effective_schema_filter)?;
         let raw_roles = pg_sync_query_roles(&mut self.client)?;
         let raw_policies = pg_sync_query_policies(&mut self.client)?;
 
-        let ddl = assemble_ddl(RawIntrospection {
+        Ok(assemble_ddl(RawIntrospection {
             schemas,
             tables: raw_tables,
             columns: raw_columns,
             enums: raw_enums,
+            domains: raw_domains,
+            composites: raw_composites,
             sequences: raw_sequences,
             views: raw_views,
             indexes: raw_indexes,
@@ -994,17 +1490,73 @@ impl<Schema> Drizzle<Schema> {
             primary_keys: raw_primary_keys,
             unique_constraints: raw_uniques,
             check_constraints: raw_checks,
+            exclude_constraints: raw_excludes,
             roles: raw_roles,
             policies: raw_policies,
-        });
+        }))
+    }
 
-        // Build snapshot
-        let mut snap = drizzle_migrations::postgres::PostgresSnapshot::new();
-        for entity in ddl.to_entities() {
-            snap.add_entity(entity);
-        }
+    /// Introspect the connected database into the same DDL model the
+    /// migration differ works on.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub fn introspect_ddl(
+        &mut self,
+    ) -> drizzle_core::error::Result<drizzle_migrations::postgres::PostgresDDL> {
+        self.introspect_ddl_impl(None)
+    }
 
-        Ok(drizzle_migrations::schema::Snapshot::Postgres(snap))
+    /// Tables in every schema the connection can see.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub fn tables(
+        &mut self,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::ddl::Table>> {
+        Ok(self.introspect_ddl()?.tables.into_vec())
+    }
+
+    /// Columns of `schema.table`, in declaration order.
+    ///
+    /// Returns an empty list if the table doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub fn columns(
+        &mut self,
+        schema: &str,
+        table: &str,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::ddl::Column>> {
+        let ddl = self.introspect_ddl()?;
+        Ok(ddl
+            .columns
+            .for_table(schema, table)
+            .into_iter()
+            .cloned()
+            .collect())
+    }
+
+    /// Indexes on `schema.table`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub fn indexes(
+        &mut self,
+        schema: &str,
+        table: &str,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::ddl::Index>> {
+        let ddl = self.introspect_ddl()?;
+        Ok(ddl
+            .indexes
+            .for_table(schema, table)
+            .into_iter()
+            .cloned()
+            .collect())
     }
 
     /// Introspect the live database, diff against the desired schema, and
@@ -1109,10 +1661,10 @@ where
             .with_query(|| QueryContext::new(&sql_str, &params))?;
         let mut decoded = Vec::with_capacity(rows.len());
         for row in &rows {
-            decoded.push(<Mk as drizzle_core::row::DecodeSelectedRef<
-                &::postgres::Row,
-                R,
-            >>::decode(row)?);
+            decoded.push(
+                <Mk as drizzle_core::row::DecodeSelectedRef<&::postgres::Row, R>>::decode(row)
+                    .map_err(|e| e.at_row(decoded.len()))?,
+            );
         }
         Ok(decoded)
     }
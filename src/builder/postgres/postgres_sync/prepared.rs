@@ -2,7 +2,7 @@ use std::{
     borrow::Cow,
     marker::PhantomData,
     sync::{
-        Arc, Mutex,
+        Arc, Mutex, OnceLock,
         atomic::{AtomicU64, Ordering},
     },
 };
@@ -34,10 +34,31 @@ struct ClientRegistration {
     client_id: u64,
 }
 
-pub(crate) fn next_client_id() -> u64 {
+fn next_client_id() -> u64 {
     NEXT_CLIENT_ID.fetch_add(1, Ordering::Relaxed)
 }
 
+/// Lazily assigned client id; unregisters the client when dropped.
+pub(crate) struct ClientId(OnceLock<u64>);
+
+impl ClientId {
+    pub(crate) const fn new() -> Self {
+        Self(OnceLock::new())
+    }
+
+    pub(crate) fn get(&self) -> u64 {
+        *self.0.get_or_init(next_client_id)
+    }
+}
+
+impl Drop for ClientId {
+    fn drop(&mut self) {
+        if let Some(client_id) = self.0.get() {
+            unregister_client(*client_id);
+        }
+    }
+}
+
 pub(crate) fn register_client(client: &Client, client_id: u64) {
     let client_key = client as *const Client as usize;
     let mut registrations = REGISTERED_CLIENTS
@@ -58,7 +79,7 @@ pub(crate) fn register_client(client: &Client, client_id: u64) {
     }
 }
 
-pub(crate) fn unregister_client(client_id: u64) {
+fn unregister_client(client_id: u64) {
     let mut registrations = REGISTERED_CLIENTS
         .lock()
         .unwrap_or_else(|err| err.into_inner());
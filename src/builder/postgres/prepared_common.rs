@@ -58,7 +58,16 @@ pub const fn postgres_sync_param_type(
         PostgresValue::TimeTimestampTz(_) => Some(Type::TIMESTAMPTZ),
         #[cfg(feature = "time")]
         PostgresValue::TimeInterval(_) => Some(Type::INTERVAL),
-        PostgresValue::Null | PostgresValue::Enum(_) | PostgresValue::Array(_) => None,
+        // PostGIS types have no fixed OID; let the server infer them
+        #[cfg(feature = "geo-types")]
+        PostgresValue::Geometry(_) => None,
+        // pgvector is an extension too
+        PostgresValue::Vector(_) => None,
+        PostgresValue::Null
+        | PostgresValue::Enum(_)
+        | PostgresValue::Array(_)
+        | PostgresValue::Composite(_)
+        | PostgresValue::Range(_) => None,
     }
 }
 
@@ -137,7 +146,16 @@ pub const fn tokio_postgres_param_type(
         PostgresValue::TimeTimestampTz(_) => Some(Type::TIMESTAMPTZ),
         #[cfg(feature = "time")]
         PostgresValue::TimeInterval(_) => Some(Type::INTERVAL),
-        PostgresValue::Null | PostgresValue::Enum(_) | PostgresValue::Array(_) => None,
+        // PostGIS types have no fixed OID; let the server infer them
+        #[cfg(feature = "geo-types")]
+        PostgresValue::Geometry(_) => None,
+        // pgvector is an extension too
+        PostgresValue::Vector(_) => None,
+        PostgresValue::Null
+        | PostgresValue::Enum(_)
+        | PostgresValue::Array(_)
+        | PostgresValue::Composite(_)
+        | PostgresValue::Range(_) => None,
     }
 }
 
@@ -163,12 +181,12 @@ macro_rules! postgres_prepared_sync_impl {
             pub fn execute<const N: usize>(
                 &self,
                 client: &mut $client,
-                params: [drizzle_core::param::ParamBind<'a, drizzle_postgres::values::PostgresValue<'a>>; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_postgres::values::PostgresValue<'a>, N>>,
             ) -> drizzle_core::error::Result<u64> {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "sync.execute");
-                let (sql_str, bound_params) = self.inner.bind(params)?;
+                let (sql_str, bound_params) = self.inner.bind(params.into())?;
                 let (lower, upper) = bound_params.size_hint();
                 let mut params_vec: smallvec::SmallVec<
                     [drizzle_postgres::values::PostgresValue<'a>; 8],
@@ -203,7 +221,7 @@ macro_rules! postgres_prepared_sync_impl {
             pub fn all<T, const N: usize>(
                 &self,
                 client: &mut $client,
-                params: [drizzle_core::param::ParamBind<'a, drizzle_postgres::values::PostgresValue<'a>>; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_postgres::values::PostgresValue<'a>, N>>,
             ) -> drizzle_core::error::Result<Vec<T>>
             where
                 for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r $row, T>,
@@ -211,7 +229,7 @@ macro_rules! postgres_prepared_sync_impl {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "sync.all");
-                let (sql_str, bound_params) = self.inner.bind(params)?;
+                let (sql_str, bound_params) = self.inner.bind(params.into())?;
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "sync.all.collect");
                 let (lower, upper) = bound_params.size_hint();
@@ -240,7 +258,7 @@ macro_rules! postgres_prepared_sync_impl {
                     results.push(<Marker as drizzle_core::row::DecodeSelectedRef<
                         &$row,
                         T,
-                    >>::decode(&row)?);
+                    >>::decode(&row).map_err(|e| e.at_row(results.len()))?);
                 }
 
                 Ok(results)
@@ -250,7 +268,7 @@ macro_rules! postgres_prepared_sync_impl {
             pub fn get<T, const N: usize>(
                 &self,
                 client: &mut $client,
-                params: [drizzle_core::param::ParamBind<'a, drizzle_postgres::values::PostgresValue<'a>>; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_postgres::values::PostgresValue<'a>, N>>,
             ) -> drizzle_core::error::Result<T>
             where
                 for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r $row, T>,
@@ -258,7 +276,7 @@ macro_rules! postgres_prepared_sync_impl {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "sync.get");
-                let (sql_str, bound_params) = self.inner.bind(params)?;
+                let (sql_str, bound_params) = self.inner.bind(params.into())?;
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "sync.get.collect");
                 let (lower, upper) = bound_params.size_hint();
@@ -288,12 +306,12 @@ macro_rules! postgres_prepared_sync_impl {
             pub fn execute<'a, const N: usize>(
                 &self,
                 client: &mut $client,
-                params: [drizzle_core::param::ParamBind<'a, drizzle_postgres::values::PostgresValue<'a>>; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_postgres::values::PostgresValue<'a>, N>>,
             ) -> drizzle_core::error::Result<u64> {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "sync.owned_execute");
-                let (sql_str, bound_params) = self.inner.bind(params)?;
+                let (sql_str, bound_params) = self.inner.bind(params.into())?;
                 let (lower, upper) = bound_params.size_hint();
                 let mut params_vec: smallvec::SmallVec<
                     [drizzle_postgres::values::PostgresValue<'_>; 8],
@@ -329,7 +347,7 @@ macro_rules! postgres_prepared_sync_impl {
             pub fn all<'a, T, const N: usize>(
                 &self,
                 client: &mut $client,
-                params: [drizzle_core::param::ParamBind<'a, drizzle_postgres::values::PostgresValue<'a>>; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_postgres::values::PostgresValue<'a>, N>>,
             ) -> drizzle_core::error::Result<Vec<T>>
             where
                 for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r $row, T>,
@@ -337,7 +355,7 @@ macro_rules! postgres_prepared_sync_impl {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "sync.owned_all");
-                let (sql_str, bound_params) = self.inner.bind(params)?;
+                let (sql_str, bound_params) = self.inner.bind(params.into())?;
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "sync.owned_all.collect");
                 let (lower, upper) = bound_params.size_hint();
@@ -366,7 +384,7 @@ macro_rules! postgres_prepared_sync_impl {
                     results.push(<Marker as drizzle_core::row::DecodeSelectedRef<
                         &$row,
                         T,
-                    >>::decode(&row)?);
+                    >>::decode(&row).map_err(|e| e.at_row(results.len()))?);
                 }
 
                 Ok(results)
@@ -376,7 +394,7 @@ macro_rules! postgres_prepared_sync_impl {
             pub fn get<'a, T, const N: usize>(
                 &self,
                 client: &mut $client,
-                params: [drizzle_core::param::ParamBind<'a, drizzle_postgres::values::PostgresValue<'a>>; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_postgres::values::PostgresValue<'a>, N>>,
             ) -> drizzle_core::error::Result<T>
             where
                 for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r $row, T>,
@@ -384,7 +402,7 @@ macro_rules! postgres_prepared_sync_impl {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "sync.owned_get");
-                let (sql_str, bound_params) = self.inner.bind(params)?;
+                let (sql_str, bound_params) = self.inner.bind(params.into())?;
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "sync.owned_get.collect");
                 let (lower, upper) = bound_params.size_hint();
@@ -418,12 +436,12 @@ macro_rules! postgres_prepared_async_impl {
             pub async fn execute<const N: usize>(
                 &self,
                 client: &$client,
-                params: [drizzle_core::param::ParamBind<'a, drizzle_postgres::values::PostgresValue<'a>>; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_postgres::values::PostgresValue<'a>, N>>,
             ) -> drizzle_core::error::Result<u64> {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "async.execute");
-                let (sql_str, bound_params) = self.inner.bind(params)?;
+                let (sql_str, bound_params) = self.inner.bind(params.into())?;
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "async.execute.collect");
                 let (lower, upper) = bound_params.size_hint();
@@ -455,7 +473,7 @@ macro_rules! postgres_prepared_async_impl {
             pub async fn all<T, const N: usize>(
                 &self,
                 client: &$client,
-                params: [drizzle_core::param::ParamBind<'a, drizzle_postgres::values::PostgresValue<'a>>; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_postgres::values::PostgresValue<'a>, N>>,
             ) -> drizzle_core::error::Result<Vec<T>>
             where
                 for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r $row, T>,
@@ -463,7 +481,7 @@ macro_rules! postgres_prepared_async_impl {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "async.all");
-                let (sql_str, bound_params) = self.inner.bind(params)?;
+                let (sql_str, bound_params) = self.inner.bind(params.into())?;
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "async.all.collect");
                 let (lower, upper) = bound_params.size_hint();
@@ -494,7 +512,7 @@ macro_rules! postgres_prepared_async_impl {
                     results.push(<Marker as drizzle_core::row::DecodeSelectedRef<
                         &$row,
                         T,
-                    >>::decode(&row)?);
+                    >>::decode(&row).map_err(|e| e.at_row(results.len()))?);
                 }
 
                 Ok(results)
@@ -504,7 +522,7 @@ macro_rules! postgres_prepared_async_impl {
             pub async fn get<T, const N: usize>(
                 &self,
                 client: &$client,
-                params: [drizzle_core::param::ParamBind<'a, drizzle_postgres::values::PostgresValue<'a>>; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_postgres::values::PostgresValue<'a>, N>>,
             ) -> drizzle_core::error::Result<T>
             where
                 for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r $row, T>,
@@ -512,7 +530,7 @@ macro_rules! postgres_prepared_async_impl {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "async.get");
-                let (sql_str, bound_params) = self.inner.bind(params)?;
+                let (sql_str, bound_params) = self.inner.bind(params.into())?;
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "async.get.collect");
                 let (lower, upper) = bound_params.size_hint();
@@ -544,12 +562,12 @@ macro_rules! postgres_prepared_async_impl {
             pub async fn execute<'a, const N: usize>(
                 &self,
                 client: &$client,
-                params: [drizzle_core::param::ParamBind<'a, drizzle_postgres::values::PostgresValue<'a>>; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_postgres::values::PostgresValue<'a>, N>>,
             ) -> drizzle_core::error::Result<u64> {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "async.owned_execute");
-                let (sql_str, bound_params) = self.inner.bind(params)?;
+                let (sql_str, bound_params) = self.inner.bind(params.into())?;
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!(
                     "postgres.prepared",
@@ -584,7 +602,7 @@ macro_rules! postgres_prepared_async_impl {
             pub async fn all<'a, T, const N: usize>(
                 &self,
                 client: &$client,
-                params: [drizzle_core::param::ParamBind<'a, drizzle_postgres::values::PostgresValue<'a>>; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_postgres::values::PostgresValue<'a>, N>>,
             ) -> drizzle_core::error::Result<Vec<T>>
             where
                 for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r $row, T>,
@@ -592,7 +610,7 @@ macro_rules! postgres_prepared_async_impl {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "async.owned_all");
-                let (sql_str, bound_params) = self.inner.bind(params)?;
+                let (sql_str, bound_params) = self.inner.bind(params.into())?;
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!(
                     "postgres.prepared",
@@ -626,7 +644,7 @@ macro_rules! postgres_prepared_async_impl {
                     results.push(<Marker as drizzle_core::row::DecodeSelectedRef<
                         &$row,
                         T,
-                    >>::decode(&row)?);
+                    >>::decode(&row).map_err(|e| e.at_row(results.len()))?);
                 }
 
                 Ok(results)
@@ -636,7 +654,7 @@ macro_rules! postgres_prepared_async_impl {
             pub async fn get<'a, T, const N: usize>(
                 &self,
                 client: &$client,
-                params: [drizzle_core::param::ParamBind<'a, drizzle_postgres::values::PostgresValue<'a>>; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_postgres::values::PostgresValue<'a>, N>>,
             ) -> drizzle_core::error::Result<T>
             where
                 for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r $row, T>,
@@ -644,7 +662,7 @@ macro_rules! postgres_prepared_async_impl {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!("postgres.prepared", "async.owned_get");
-                let (sql_str, bound_params) = self.inner.bind(params)?;
+                let (sql_str, bound_params) = self.inner.bind(params.into())?;
                 #[cfg(feature = "profiling")]
                 drizzle_core::drizzle_profile_scope!(
                     "postgres.prepared",
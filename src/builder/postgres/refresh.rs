@@ -0,0 +1,28 @@
+//! SQL for `refresh_materialized_view`, shared by the postgres drivers.
+
+use drizzle_core::error::{DrizzleError, Result};
+use drizzle_core::traits::SQLViewInfo;
+
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// `REFRESH MATERIALIZED VIEW [CONCURRENTLY] view`. Errors for plain views,
+/// which have nothing to refresh.
+pub(crate) fn refresh_sql<V: SQLViewInfo>(view: &V, concurrently: bool) -> Result<String> {
+    if !view.is_materialized() {
+        return Err(DrizzleError::Statement(
+            format!("`{}` is not a materialized view", view.name()).into(),
+        ));
+    }
+    // Like the CREATE MATERIALIZED VIEW DDL, `public` views are left to the
+    // search path.
+    let name = match view.schema() {
+        Some(schema) if schema != "public" => {
+            format!("{}.{}", quote(schema), quote(view.name()))
+        }
+        _ => quote(view.name()),
+    };
+    let concurrently = if concurrently { "CONCURRENTLY " } else { "" };
+    Ok(format!("REFRESH MATERIALIZED VIEW {concurrently}{name}"))
+}
@@ -8,6 +8,7 @@ pub struct DecodeRows<RowT, R> {
     rows: std::vec::IntoIter<RowT>,
     #[cfg(not(feature = "std"))]
     rows: alloc::vec::IntoIter<RowT>,
+    position: usize,
     _marker: PhantomData<R>,
 }
 
@@ -16,6 +17,7 @@ impl<RowT, R> DecodeRows<RowT, R> {
     pub(crate) fn new(rows: Vec<RowT>) -> Self {
         Self {
             rows: rows.into_iter(),
+            position: 0,
             _marker: PhantomData,
         }
     }
@@ -24,6 +26,7 @@ impl<RowT, R> DecodeRows<RowT, R> {
     pub(crate) fn new(rows: alloc::vec::Vec<RowT>) -> Self {
         Self {
             rows: rows.into_iter(),
+            position: 0,
             _marker: PhantomData,
         }
     }
@@ -35,10 +38,7 @@ where
     for<'r> <R as TryFrom<&'r RowT>>::Error: Into<DrizzleError>,
 {
     pub fn next(&mut self) -> drizzle_core::error::Result<Option<R>> {
-        match self.rows.next() {
-            Some(row) => Ok(Some(R::try_from(&row).map_err(Into::into)?)),
-            None => Ok(None),
-        }
+        Iterator::next(self).transpose()
     }
 }
 
@@ -50,9 +50,10 @@ where
     type Item = drizzle_core::error::Result<R>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.rows
-            .next()
-            .map(|row| R::try_from(&row).map_err(Into::into))
+        let row = self.rows.next()?;
+        let position = self.position;
+        self.position += 1;
+        Some(R::try_from(&row).map_err(|e| e.into().at_row(position)))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -0,0 +1,82 @@
+//! SQL for `search_path` and schema-per-tenant setups, shared by the postgres
+//! drivers.
+
+use drizzle_core::error::{DrizzleError, Result};
+
+/// Schema names starting with `$1`, excluding system schemas.
+pub(crate) const TENANT_SCHEMAS_SQL: &str = "SELECT nspname::text FROM pg_namespace \
+     WHERE left(nspname, length($1)) = $1 \
+       AND nspname NOT LIKE 'pg\\_%' \
+       AND nspname <> 'information_schema' \
+     ORDER BY nspname";
+
+fn quote_schema(schema: &str) -> Result<String> {
+    if schema.is_empty() {
+        return Err(DrizzleError::Other("schema name must not be empty".into()));
+    }
+    Ok(format!("\"{}\"", schema.replace('"', "\"\"")))
+}
+
+fn quote_schemas<I>(schemas: I) -> Result<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let quoted = schemas
+        .into_iter()
+        .map(|schema| quote_schema(schema.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+    if quoted.is_empty() {
+        return Err(DrizzleError::Other(
+            "search_path must name at least one schema".into(),
+        ));
+    }
+    Ok(quoted.join(", "))
+}
+
+/// `SET search_path` for the session, in lookup order.
+pub(crate) fn search_path_sql<I>(schemas: I) -> Result<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    Ok(format!("SET search_path TO {}", quote_schemas(schemas)?))
+}
+
+/// `SET LOCAL search_path`, reverted when the transaction ends.
+pub(crate) fn local_search_path_sql<I>(schemas: I) -> Result<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    Ok(format!(
+        "SET LOCAL search_path TO {}",
+        quote_schemas(schemas)?
+    ))
+}
+
+/// One batch that creates `tenant`, switches to it, and runs `statements`.
+///
+/// A multi-statement batch runs as a single implicit transaction, so a failing
+/// statement leaves neither the schema nor the `search_path` change behind.
+pub(crate) fn create_tenant_sql(
+    tenant: &str,
+    statements: impl Iterator<Item = String>,
+) -> Result<String> {
+    let schema = quote_schema(tenant)?;
+    let mut sql = format!("CREATE SCHEMA {schema};\nSET search_path TO {schema};");
+    for statement in statements {
+        sql.push('\n');
+        sql.push_str(statement.trim_end().trim_end_matches(';'));
+        sql.push(';');
+    }
+    Ok(sql)
+}
+
+/// `DROP SCHEMA ... CASCADE` for `tenant`.
+pub(crate) fn drop_tenant_sql(tenant: &str) -> Result<String> {
+    Ok(format!(
+        "DROP SCHEMA IF EXISTS {} CASCADE",
+        quote_schema(tenant)?
+    ))
+}
@@ -0,0 +1,143 @@
+//! TLS for the `postgres` and `tokio-postgres` drivers through `native-tls`.
+//!
+//! Modes follow libpq's `sslmode`: below `verify-ca` the connection is
+//! encrypted but the server certificate isn't checked, unless a root
+//! certificate is supplied, in which case the chain is verified too.
+
+use drizzle_core::error::{DrizzleError, Result};
+use postgres_native_tls::MakeTlsConnector;
+
+#[cfg(feature = "tokio-postgres")]
+use tokio_postgres::config::SslMode as WireSslMode;
+
+#[cfg(not(feature = "tokio-postgres"))]
+use postgres::config::SslMode as WireSslMode;
+
+/// libpq `sslmode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SslMode {
+    /// Plaintext only.
+    Disable,
+    /// Treated like [`SslMode::Prefer`].
+    Allow,
+    /// TLS when the server offers it, plaintext otherwise.
+    #[default]
+    Prefer,
+    /// TLS, without checking the certificate.
+    Require,
+    /// TLS with a certificate signed by a trusted CA, any host name.
+    VerifyCa,
+    /// TLS with a trusted certificate issued for the host connected to.
+    VerifyFull,
+}
+
+impl SslMode {
+    /// The libpq spelling, e.g. `verify-full`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Disable => "disable",
+            Self::Allow => "allow",
+            Self::Prefer => "prefer",
+            Self::Require => "require",
+            Self::VerifyCa => "verify-ca",
+            Self::VerifyFull => "verify-full",
+        }
+    }
+
+    pub(crate) const fn wire(self) -> WireSslMode {
+        match self {
+            Self::Disable => WireSslMode::Disable,
+            Self::Allow | Self::Prefer => WireSslMode::Prefer,
+            Self::Require | Self::VerifyCa | Self::VerifyFull => WireSslMode::Require,
+        }
+    }
+}
+
+impl core::fmt::Display for SslMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::str::FromStr for SslMode {
+    type Err = DrizzleError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "disable" => Ok(Self::Disable),
+            "allow" => Ok(Self::Allow),
+            "prefer" => Ok(Self::Prefer),
+            "require" => Ok(Self::Require),
+            "verify-ca" => Ok(Self::VerifyCa),
+            "verify-full" => Ok(Self::VerifyFull),
+            _ => Err(DrizzleError::Other(format!("invalid sslmode `{s}`").into())),
+        }
+    }
+}
+
+/// TLS settings for `Drizzle::connect`.
+///
+/// ```no_run
+/// # use drizzle::postgres::tls::{SslMode, TlsConfig};
+/// # fn main() -> drizzle::Result<()> {
+/// let ca = std::fs::read("/etc/ssl/rds-ca.pem").expect("read CA bundle");
+/// let tls = TlsConfig::new(SslMode::VerifyFull).root_cert(ca);
+/// let connector = tls.connector()?;
+/// # let _ = connector;
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TlsConfig {
+    mode: SslMode,
+    root_certs: Vec<Vec<u8>>,
+}
+
+impl TlsConfig {
+    /// TLS settings for `mode`, trusting the system CA store.
+    #[must_use]
+    pub const fn new(mode: SslMode) -> Self {
+        Self {
+            mode,
+            root_certs: Vec::new(),
+        }
+    }
+
+    /// Also trust the PEM-encoded CA certificate(s) in `pem`.
+    #[must_use]
+    pub fn root_cert(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certs.push(pem.into());
+        self
+    }
+
+    /// The configured `sslmode`.
+    #[must_use]
+    pub const fn mode(&self) -> SslMode {
+        self.mode
+    }
+
+    /// Builds the connector to hand to `postgres::Config::connect` or
+    /// `tokio_postgres::connect`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::Other`] if a root certificate isn't valid PEM or
+    /// the platform TLS library fails to initialize.
+    pub fn connector(&self) -> Result<MakeTlsConnector> {
+        let tls_error = |error: native_tls::Error| DrizzleError::Other(error.to_string().into());
+
+        let mut builder = native_tls::TlsConnector::builder();
+        for pem in &self.root_certs {
+            for cert in native_tls::Certificate::stack_from_pem(pem).map_err(tls_error)? {
+                builder.add_root_certificate(cert);
+            }
+        }
+
+        let verify_chain = !self.root_certs.is_empty()
+            || matches!(self.mode, SslMode::VerifyCa | SslMode::VerifyFull);
+        builder.danger_accept_invalid_certs(!verify_chain);
+        builder.danger_accept_invalid_hostnames(self.mode != SslMode::VerifyFull);
+
+        Ok(MakeTlsConnector::new(builder.build().map_err(tls_error)?))
+    }
+}
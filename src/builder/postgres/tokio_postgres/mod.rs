@@ -150,6 +150,8 @@
 
 mod prepared;
 
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
 
 use drizzle_core::error::{DrizzleError, QueryContext, ResultExt};
@@ -177,6 +179,7 @@ use crate::builder::postgres::rows::DecodeRows;
 pub type DrizzleBuilder<'a, Schema, Builder, State> =
     common::DrizzleBuilder<'a, &'a Drizzle<Schema>, Schema, Builder, State>;
 
+use crate::ReadOnly;
 use crate::transaction::postgres::tokio_postgres::Transaction;
 
 #[cfg(feature = "query")]
@@ -198,6 +201,8 @@ pub struct Drizzle<Schema = ()> {
     client: Arc<Client>,
     schema: Schema,
     statement_cache: prepared::StatementCache,
+    connector: Option<Connector>,
+    search_path: Option<String>,
 }
 
 impl<S: Clone> Clone for Drizzle<S> {
@@ -207,10 +212,24 @@ impl<S: Clone> Clone for Drizzle<S> {
             client: self.client.clone(),
             schema: self.schema.clone(),
             statement_cache: self.statement_cache.clone(),
+            connector: self.connector.clone(),
+            search_path: self.search_path.clone(),
         }
     }
 }
 
+type ConnectFuture = Pin<Box<dyn Future<Output = Result<Client, tokio_postgres::Error>> + Send>>;
+
+/// Callback registered with [`Drizzle::with_reconnect`].
+#[derive(Clone)]
+struct Connector(Arc<dyn Fn() -> ConnectFuture + Send + Sync>);
+
+impl std::fmt::Debug for Connector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Connector").finish_non_exhaustive()
+    }
+}
+
 /// Lazy decoded row cursor for tokio-postgres queries.
 pub type Rows<R> = DecodeRows<Row, R>;
 
@@ -248,9 +267,51 @@ impl Drizzle {
             client: Arc::new(client),
             schema,
             statement_cache: prepared::StatementCache::default(),
+            connector: None,
+            search_path: None,
         };
         (drizzle, schema)
     }
+
+    /// Connects to `params` (a `key=value` string or `postgres://` URL) using
+    /// `tls`, whose mode replaces any `sslmode` in `params`. The connection
+    /// task is spawned on the current tokio runtime.
+    ///
+    /// ```no_run
+    /// # use drizzle::postgres::tokio::Drizzle;
+    /// # use drizzle::postgres::tls::{SslMode, TlsConfig};
+    /// # #[tokio::main] async fn main() -> drizzle::Result<()> {
+    /// let tls = TlsConfig::new(SslMode::VerifyFull);
+    /// let (db, ()) = Drizzle::connect("postgres://app@db.example.com/app", &tls, ()).await?;
+    /// # let _ = db;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if `params` doesn't parse, the TLS connector
+    /// can't be built, or the connection fails.
+    #[cfg(feature = "postgres-native-tls")]
+    pub async fn connect<S: Copy>(
+        params: &str,
+        tls: &crate::builder::postgres::tls::TlsConfig,
+        schema: S,
+    ) -> drizzle_core::error::Result<(Drizzle<S>, S)> {
+        use crate::builder::postgres::tls::SslMode;
+
+        let mut config = params.parse::<tokio_postgres::Config>()?;
+        config.ssl_mode(tls.mode().wire());
+        let client = if tls.mode() == SslMode::Disable {
+            let (client, connection) = config.connect(tokio_postgres::NoTls).await?;
+            tokio::spawn(connection);
+            client
+        } else {
+            let (client, connection) = config.connect(tls.connector()?).await?;
+            tokio::spawn(connection);
+            client
+        };
+        Ok(Self::new(client, schema))
+    }
 }
 
 impl<S> AsRef<Self> for Drizzle<S> {
@@ -281,6 +342,200 @@ impl<Schema> Drizzle<Schema> {
         &self.schema
     }
 
+    /// Returns `true` once the connection task has exited.
+    ///
+    /// A closed client fails every query; see [`Self::with_reconnect`].
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.client.is_closed()
+    }
+
+    /// Round-trips a trivial query to check that the server is reachable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the connection is closed or the server does not answer.
+    pub async fn ping(&self) -> drizzle_core::error::Result<()> {
+        self.client.batch_execute("SELECT 1").await?;
+        Ok(())
+    }
+
+    /// Registers a callback that opens a replacement client for [`Self::reconnect`].
+    ///
+    /// The callback owns the whole connection setup: connecting, spawning the
+    /// connection task, and any per-session state such as
+    /// `statement_timeout`, which is lost with the old connection. A
+    /// `search_path` set through [`Self::set_search_path`] is applied again
+    /// by [`Self::reconnect`].
+    ///
+    /// ```no_run
+    /// # use drizzle::postgres::tokio::Drizzle;
+    /// # #[tokio::main] async fn main() -> drizzle::Result<()> {
+    /// async fn connect() -> Result<tokio_postgres::Client, tokio_postgres::Error> {
+    ///     let (client, connection) =
+    ///         tokio_postgres::connect("host=localhost user=postgres", tokio_postgres::NoTls).await?;
+    ///     tokio::spawn(connection);
+    ///     client.batch_execute("SET statement_timeout = 5000").await?;
+    ///     Ok(client)
+    /// }
+    ///
+    /// let (db, ()) = Drizzle::new(connect().await?, ());
+    /// let mut db = db.with_reconnect(connect);
+    ///
+    /// // e.g. at the start of a unit of work
+    /// db.reconnect().await?;
+    /// # Ok(()) }
+    /// ```
+    #[must_use]
+    pub fn with_reconnect<F, Fut>(mut self, connect: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Client, tokio_postgres::Error>> + Send + 'static,
+    {
+        self.connector = Some(Connector(Arc::new(move || Box::pin(connect()))));
+        self
+    }
+
+    /// Replaces a closed client using the [`Self::with_reconnect`] callback.
+    ///
+    /// Does nothing while the current client is open. Returns `true` when a
+    /// new client was installed. Only this handle is updated; clones made
+    /// earlier keep the old client.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::Other`] if the client is closed and no
+    /// callback was registered, or the callback's error if reconnecting fails.
+    pub async fn reconnect(&mut self) -> drizzle_core::error::Result<bool> {
+        if !self.client.is_closed() {
+            return Ok(false);
+        }
+        let Some(Connector(connect)) = &self.connector else {
+            return Err(DrizzleError::Other(
+                "postgres connection is closed and no reconnect callback is registered".into(),
+            ));
+        };
+
+        let client = connect().await?;
+        if let Some(search_path) = &self.search_path {
+            client.batch_execute(search_path).await?;
+        }
+        self.client = Arc::new(client);
+        // Prepared statements belong to the old connection.
+        self.statement_cache = prepared::StatementCache::default();
+        Ok(true)
+    }
+
+    /// Sets the session's `search_path`, so unqualified table names resolve
+    /// against `schemas` in order.
+    ///
+    /// The value is remembered and applied again by [`Self::reconnect`].
+    /// Clones share the connection and see the change, but only this handle
+    /// re-applies it after reconnecting. Use
+    /// [`Transaction::set_search_path`] to override it for one transaction.
+    ///
+    /// ```no_run
+    /// # use drizzle::postgres::prelude::*;
+    /// # use drizzle::postgres::tokio::Drizzle;
+    /// # #[PostgresTable] struct User { #[column(serial, primary)] id: i32, name: String }
+    /// # #[derive(PostgresSchema)] struct S { user: User }
+    /// # #[tokio::main] async fn main() -> drizzle::Result<()> {
+    /// # let (client, conn) = ::tokio_postgres::connect("host=localhost user=postgres", ::tokio_postgres::NoTls).await?;
+    /// # tokio::spawn(async move { conn.await.unwrap() });
+    /// let (mut db, S { user }) = Drizzle::new(client, S::new());
+    /// db.set_search_path(["tenant_a", "public"]).await?;
+    ///
+    /// // Reads "tenant_a"."user"
+    /// let users: Vec<SelectUser> = db.select(()).from(user).all().await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if `schemas` is empty or holds an empty name,
+    /// or if the `SET` fails.
+    pub async fn set_search_path<I>(&mut self, schemas: I) -> drizzle_core::error::Result<()>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let sql = super::tenant::search_path_sql(schemas)?;
+        self.client.batch_execute(&sql).await?;
+        self.search_path = Some(sql);
+        Ok(())
+    }
+
+    /// Sets the session's default `statement_timeout`; `None` disables it.
+    ///
+    /// The server cancels any statement that runs longer, failing it with
+    /// [`DrizzleError::Timeout`]. The setting lives on the connection, so a
+    /// [`Self::with_reconnect`] callback has to apply it again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the `SET` statement fails.
+    pub async fn set_statement_timeout(
+        &self,
+        timeout: Option<std::time::Duration>,
+    ) -> drizzle_core::error::Result<()> {
+        let millis = timeout.map_or(0, |timeout| timeout.as_millis().max(1));
+        self.client
+            .batch_execute(&format!("SET statement_timeout = {millis}"))
+            .await?;
+        Ok(())
+    }
+
+    /// Runs `f`, canceling it if it has not finished after `timeout`.
+    ///
+    /// On expiry the future is dropped and a cancel request is sent for the
+    /// statement in flight. The request is sent without TLS, so servers that
+    /// require TLS only get the client-side timeout; pair this with
+    /// [`Self::set_statement_timeout`] there.
+    ///
+    /// ```no_run
+    /// # use drizzle::postgres::prelude::*;
+    /// # use drizzle::postgres::tokio::Drizzle;
+    /// # use std::time::Duration;
+    /// # #[PostgresTable] struct User { #[column(serial, primary)] id: i32, name: String }
+    /// # #[derive(PostgresSchema)] struct S { user: User }
+    /// # #[tokio::main] async fn main() -> drizzle::Result<()> {
+    /// # let (client, conn) = ::tokio_postgres::connect("host=localhost user=postgres", ::tokio_postgres::NoTls).await?;
+    /// # tokio::spawn(async move { conn.await.unwrap() });
+    /// # let (db, S { user }) = Drizzle::new(client, S::new());
+    /// let users: Vec<SelectUser> = db
+    ///     .with_timeout(Duration::from_secs(2), async |db| {
+    ///         db.select(()).from(user).all().await
+    ///     })
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::Timeout`] on expiry, otherwise the error from `f`.
+    pub async fn with_timeout<F, R>(
+        &self,
+        timeout: std::time::Duration,
+        f: F,
+    ) -> drizzle_core::error::Result<R>
+    where
+        F: AsyncFnOnce(&Self) -> drizzle_core::error::Result<R>,
+    {
+        match tokio::time::timeout(timeout, f(self)).await {
+            Ok(result) => result,
+            Err(_) => {
+                let _ = self
+                    .client
+                    .cancel_token()
+                    .cancel_query(tokio_postgres::NoTls)
+                    .await;
+                Err(DrizzleError::Timeout(
+                    format!("query did not finish within {timeout:?}").into(),
+                ))
+            }
+        }
+    }
+
     async fn cached_statement(
         &self,
         sql: &str,
@@ -416,6 +671,48 @@ impl<Schema> Drizzle<Schema> {
         }
     }
 
+    /// Loads the rows whose `column` matches each of `keys`, in key order.
+    ///
+    /// Keys are sent in `IN (...)` chunks of at most
+    /// [`POSTGRES_MAX_BATCH_KEYS`](drizzle_core::batch::POSTGRES_MAX_BATCH_KEYS).
+    /// `key_of` reads the key back off a row; keys without a row yield `None`.
+    ///
+    /// ```rust
+    /// # let _ = r####"
+    /// let users: Vec<Option<SelectUser>> =
+    ///     db.get_many(user, user.id, &[3, 1, 2], |u: &SelectUser| u.id).await?;
+    /// # "####;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if a query fails or row decoding fails.
+    pub async fn get_many<'a, T, C, K, R>(
+        &'a self,
+        table: T,
+        column: C,
+        keys: &'a [K],
+        key_of: impl Fn(&R) -> K,
+    ) -> drizzle_core::error::Result<Vec<Option<R>>>
+    where
+        T: ToSQL<'a, PostgresValue<'a>> + Clone,
+        C: drizzle_core::expr::Expr<'a, PostgresValue<'a>> + Clone,
+        K: std::hash::Hash + Eq,
+        &'a K: drizzle_core::expr::Expr<'a, PostgresValue<'a>>,
+        C::SQLType: drizzle_core::types::Compatible<
+                <&'a K as drizzle_core::expr::Expr<'a, PostgresValue<'a>>>::SQLType,
+            >,
+        R: for<'r> TryFrom<&'r Row> + Clone,
+        for<'r> <R as TryFrom<&'r Row>>::Error: Into<drizzle_core::error::DrizzleError>,
+    {
+        let mut rows: Vec<R> = Vec::with_capacity(keys.len());
+        for chunk in keys.chunks(drizzle_core::batch::POSTGRES_MAX_BATCH_KEYS) {
+            let query = common::get_many_sql(table.clone(), column.clone(), chunk);
+            rows.extend(self.all::<_, R, Vec<R>>(query).await?);
+        }
+        Ok(drizzle_core::batch::order_by_keys(keys, rows, key_of))
+    }
+
     /// Executes a transaction with the given callback.
     ///
     /// The transaction is committed when the callback returns `Ok` and
@@ -503,8 +800,37 @@ where
     /// Returns [`DrizzleError`] if any CREATE statement fails to execute.
     pub async fn create(&self) -> drizzle_core::error::Result<()> {
         let schema = Schema::default();
-        let statements = schema.create_statements()?;
+        self.run_ddl(schema.create_statements()?).await
+    }
+
+    /// Like [`create`](Self::create), but objects that already exist are
+    /// left alone, so it is safe to call on every startup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if any CREATE statement fails to execute.
+    pub async fn create_if_not_exists(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.create_statements()?.map(super::ddl::if_not_exists))
+            .await
+    }
+
+    /// Drop every view, index, table and enum type of the schema, the inverse
+    /// of [`create`](Self::create). Tables are dropped referencing tables
+    /// first, with `CASCADE`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if any DROP statement fails to execute.
+    pub async fn drop(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.drop_statements()?).await
+    }
 
+    async fn run_ddl(
+        &self,
+        statements: impl IntoIterator<Item = String>,
+    ) -> drizzle_core::error::Result<()> {
         for statement in statements {
             self.client.execute(&statement, &[]).await?;
         }
@@ -513,6 +839,252 @@ where
     }
 }
 
+impl<Schema> Drizzle<Schema> {
+    /// Empty `tables` with a single `TRUNCATE ... RESTART IDENTITY CASCADE`,
+    /// which also empties every table referencing them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the `TRUNCATE` fails.
+    pub async fn truncate(
+        &self,
+        tables: impl drizzle_core::traits::TableSet,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(&tables.table_refs()).await
+    }
+
+    /// Truncate every table of `schema`, e.g. before seeding or between tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the `TRUNCATE` fails.
+    pub async fn reset<S: drizzle_core::traits::SQLSchemaImpl>(
+        &self,
+        schema: &S,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(schema.table_refs()).await
+    }
+
+    async fn truncate_tables(
+        &self,
+        tables: &[&'static drizzle_core::TableRef],
+    ) -> drizzle_core::error::Result<()> {
+        if let Some(sql) = super::truncate::truncate_sql(tables) {
+            self.client.execute(&sql, &[]).await?;
+        }
+        Ok(())
+    }
+
+    /// Re-run `view`'s query with `REFRESH MATERIALIZED VIEW`.
+    ///
+    /// With `concurrently`, reads keep seeing the old rows during the
+    /// refresh. That needs a unique index on the view (declare one with
+    /// `#[PostgresIndex(unique)]`) and a view that has been populated
+    /// once, so a `WITH_NO_DATA` view's first refresh must not be
+    /// concurrent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if `view` is not materialized or the refresh
+    /// fails.
+    pub async fn refresh_materialized_view<V: drizzle_core::traits::SQLViewInfo>(
+        &self,
+        view: &V,
+        concurrently: bool,
+    ) -> drizzle_core::error::Result<()> {
+        let sql = super::refresh::refresh_sql(view, concurrently)?;
+        self.client.execute(&sql, &[]).await?;
+        Ok(())
+    }
+}
+
+impl<Schema> Drizzle<Schema> {
+    /// Switches the session to read-only and returns a handle without write
+    /// builders, e.g. for a replica connection.
+    ///
+    /// Sets `default_transaction_read_only`, so raw writes through
+    /// [`ReadOnly::conn`] fail in the server as well.
+    ///
+    /// ```no_run
+    /// # use drizzle::postgres::prelude::*;
+    /// # use drizzle::postgres::tokio::Drizzle;
+    /// # #[PostgresTable] struct User { #[column(serial, primary)] id: i32, name: String }
+    /// # #[derive(PostgresSchema)] struct S { user: User }
+    /// # #[tokio::main] async fn main() -> drizzle::Result<()> {
+    /// # let (client, conn) = ::tokio_postgres::connect("host=replica user=postgres", ::tokio_postgres::NoTls).await?;
+    /// # tokio::spawn(async move { conn.await.unwrap() });
+    /// let (db, S { user }) = Drizzle::new(client, S::new());
+    /// let replica = db.read_only().await?;
+    ///
+    /// let users: Vec<SelectUser> = replica.select(()).from(user).all().await?;
+    /// // replica.insert(user) does not compile
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the `SET` fails.
+    pub async fn read_only(self) -> drizzle_core::error::Result<ReadOnly<Self>> {
+        self.client.batch_execute(READ_ONLY_SQL).await?;
+        Ok(ReadOnly(self))
+    }
+}
+
+const READ_ONLY_SQL: &str = "SET default_transaction_read_only = on";
+
+impl<Schema> ReadOnly<Drizzle<Schema>> {
+    /// Gets a reference to the underlying connection.
+    #[inline]
+    pub fn conn(&self) -> &Client {
+        self.0.conn()
+    }
+
+    /// Gets a reference to the schema.
+    #[inline]
+    pub const fn schema(&self) -> &Schema {
+        self.0.schema()
+    }
+
+    /// Returns `true` once the connection task has exited.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.0.is_closed()
+    }
+
+    /// Round-trips a trivial query to check that the server is reachable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the connection is closed or the server does not answer.
+    pub async fn ping(&self) -> drizzle_core::error::Result<()> {
+        self.0.ping().await
+    }
+
+    /// Replaces a closed client like [`Drizzle::reconnect`] and makes the new
+    /// session read-only again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if reconnecting or the `SET` fails.
+    pub async fn reconnect(&mut self) -> drizzle_core::error::Result<bool> {
+        if !self.0.reconnect().await? {
+            return Ok(false);
+        }
+        self.0.client.batch_execute(READ_ONLY_SQL).await?;
+        Ok(true)
+    }
+
+    postgres_read_only_constructors!();
+
+    /// Creates a relational query builder for the given table.
+    #[cfg(feature = "query")]
+    pub fn query<'a, T>(
+        &self,
+        table: T,
+    ) -> common::DrizzleQueryBuilder<'_, 'a, &Drizzle<Schema>, Schema, T>
+    where
+        T: drizzle_core::query::QueryTable,
+    {
+        self.0.query(table)
+    }
+}
+
+impl<Schema> Drizzle<Schema> {
+    /// Creates the `tenant` schema, runs `schema`'s CREATE statements inside
+    /// it, and returns a handle whose `search_path` is `tenant`.
+    ///
+    /// Everything runs in one implicit transaction, so a failure leaves no
+    /// half-created tenant. Tables with an explicit `schema = "..."` keep it.
+    /// `search_path` is a connection setting: clones of this handle share it.
+    /// The returned handle applies it again after [`Self::reconnect`].
+    ///
+    /// ```no_run
+    /// # use drizzle::postgres::prelude::*;
+    /// # use drizzle::postgres::tokio::Drizzle;
+    /// # #[PostgresTable] struct User { #[column(serial, primary)] id: i32, name: String }
+    /// # #[derive(PostgresSchema)] struct S { user: User }
+    /// # #[tokio::main] async fn main() -> drizzle::Result<()> {
+    /// # let (client, conn) = ::tokio_postgres::connect("host=localhost user=postgres", ::tokio_postgres::NoTls).await?;
+    /// # tokio::spawn(async move { conn.await.unwrap() });
+    /// let (db, ()) = Drizzle::new(client, ());
+    /// let (tenant, S { user }) = db.create_tenant_schema("tenant_42", S::new()).await?;
+    ///
+    /// // Unqualified tables now resolve to "tenant_42"
+    /// tenant.insert(user).values([InsertUser::new("alice")]).execute().await?;
+    /// assert_eq!(tenant.tenant_schemas("tenant_").await?, ["tenant_42"]);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if `tenant` is empty or already exists, or if
+    /// any CREATE statement fails.
+    pub async fn create_tenant_schema<S>(
+        self,
+        tenant: &str,
+        schema: S,
+    ) -> drizzle_core::error::Result<(Drizzle<S>, S)>
+    where
+        S: drizzle_core::traits::SQLSchemaImpl + Copy,
+    {
+        let sql = super::tenant::create_tenant_sql(tenant, schema.create_statements()?)?;
+        self.client.batch_execute(&sql).await?;
+        let search_path = super::tenant::search_path_sql([tenant])?;
+        Ok(self.with_tenant(schema, search_path))
+    }
+
+    /// Returns a handle scoped to an existing `tenant` schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if `tenant` is empty or the `SET` fails.
+    pub async fn into_tenant_schema<S: Copy>(
+        self,
+        tenant: &str,
+        schema: S,
+    ) -> drizzle_core::error::Result<(Drizzle<S>, S)> {
+        let search_path = super::tenant::search_path_sql([tenant])?;
+        self.client.batch_execute(&search_path).await?;
+        Ok(self.with_tenant(schema, search_path))
+    }
+
+    /// Drops the `tenant` schema and everything in it, if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if `tenant` is empty or the `DROP` fails.
+    pub async fn drop_tenant_schema(&self, tenant: &str) -> drizzle_core::error::Result<()> {
+        self.client
+            .batch_execute(&super::tenant::drop_tenant_sql(tenant)?)
+            .await?;
+        Ok(())
+    }
+
+    /// Lists schema names starting with `prefix`, sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the catalog query fails.
+    pub async fn tenant_schemas(&self, prefix: &str) -> drizzle_core::error::Result<Vec<String>> {
+        let rows = self
+            .client
+            .query(super::tenant::TENANT_SCHEMAS_SQL, &[&prefix])
+            .await?;
+        Ok(rows.iter().map(|row| row.get(0)).collect())
+    }
+
+    fn with_tenant<S: Copy>(self, schema: S, search_path: String) -> (Drizzle<S>, S) {
+        let drizzle = Drizzle {
+            client: self.client,
+            schema,
+            statement_cache: self.statement_cache,
+            connector: self.connector,
+            search_path: Some(search_path),
+        };
+        (drizzle, schema)
+    }
+}
+
 impl<Schema> Drizzle<Schema> {
     /// Apply pending migrations from an embedded migration slice.
     ///
@@ -543,49 +1115,55 @@ impl<Schema> Drizzle<Schema> {
 
         let result = async {
             ensure_postgres_migration_table(&self.client, &set).await?;
-            let rows = self.client.query(&set.applied_names_sql(), &[]).await?;
-            let applied_names = rows
+            let rows = self.client.query(&set.applied_hashes_sql(), &[]).await?;
+            let applied_rows = rows
                 .iter()
-                .map(|row| row.try_get::<_, String>(0))
+                .map(|row| Ok((row.try_get::<_, String>(0)?, row.try_get::<_, String>(1)?)))
                 .collect::<Result<Vec<_>, tokio_postgres::Error>>()?;
+            set.check_checksums(&applied_rows)
+                .map_err(|e| drizzle_core::error::DrizzleError::Other(e.to_string().into()))?;
+            let applied_names: Vec<&str> =
+                applied_rows.iter().map(|(name, _)| name.as_str()).collect();
             let pending: Vec<_> = set.pending(&applied_names).collect();
 
             if pending.is_empty() {
+                if let Some(sql) = set.record_schema_hash_sql() {
+                    self.client.execute(&sql, &[]).await?;
+                }
                 return Ok(drizzle_migrations::MigrateOutcome::UpToDate);
             }
 
+            let client = Arc::get_mut(&mut self.client).ok_or_else(|| {
+                DrizzleError::Other(
+                    "cannot run migrations: outstanding Drizzle clones exist".into(),
+                )
+            })?;
             let mut applied = Vec::with_capacity(pending.len());
-            if set.has_postgres_concurrent_index() {
-                for migration in &pending {
-                    for statement in migration.statements() {
-                        if !statement.trim().is_empty() {
-                            self.client.execute(statement, &[]).await?;
-                        }
-                    }
-                    self.client
-                        .execute(&set.record_migration_sql(migration), &[])
-                        .await?;
-                    applied.push(migration.tag().to_string());
-                }
-            } else {
-                let client = Arc::get_mut(&mut self.client).ok_or_else(|| {
-                    DrizzleError::Other(
-                        "cannot run migrations: outstanding Drizzle clones exist".into(),
-                    )
-                })?;
-                let tx = client.transaction().await?;
-                for migration in &pending {
-                    for statement in migration.statements() {
-                        if !statement.trim().is_empty() {
-                            tx.execute(statement, &[]).await?;
-                        }
+            let mut tx = client.transaction().await?;
+            for migration in &pending {
+                let started = std::time::Instant::now();
+                for step in migration.steps() {
+                    if step.no_transaction {
+                        // e.g. CREATE INDEX CONCURRENTLY can't run inside a
+                        // transaction block: commit what ran so far first.
+                        tx.commit().await?;
+                        client.execute(step.sql, &[]).await?;
+                        tx = client.transaction().await?;
+                    } else {
+                        tx.execute(step.sql, &[]).await?;
                     }
-                    tx.execute(&set.record_migration_sql(migration), &[])
-                        .await?;
-                    applied.push(migration.tag().to_string());
                 }
-                tx.commit().await?;
+                tx.execute(
+                    &set.record_applied_migration_sql(migration, started.elapsed()),
+                    &[],
+                )
+                .await?;
+                applied.push(migration.tag().to_string());
+            }
+            if let Some(sql) = set.record_schema_hash_sql() {
+                tx.execute(&sql, &[]).await?;
             }
+            tx.commit().await?;
 
             Ok(drizzle_migrations::MigrateOutcome::Applied { tags: applied })
         }
@@ -602,6 +1180,45 @@ impl<Schema> Drizzle<Schema> {
             (Ok(_), Err(error)) => Err(error.into()),
         }
     }
+
+    /// Check that the schema hash recorded by [`migrate`](Self::migrate)
+    /// matches `Schema::SCHEMA_HASH`.
+    ///
+    /// Call it at startup to catch an application deployed against the wrong
+    /// database. Record the hash with [`Tracking::schema_hash`](drizzle_migrations::Tracking::schema_hash).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if no hash was recorded or it differs.
+    pub async fn assert_schema_matches(
+        &self,
+        tracking: drizzle_migrations::Tracking,
+    ) -> drizzle_core::error::Result<()>
+    where
+        Schema: drizzle_core::traits::SQLSchemaImpl,
+    {
+        let set = drizzle_migrations::Migrations::with_tracking(
+            Vec::new(),
+            drizzle_types::Dialect::PostgreSQL,
+            tracking,
+        );
+        let recorded: Option<String> = if self
+            .client
+            .query(&set.table_exists_sql(), &[])
+            .await?
+            .is_empty()
+        {
+            None
+        } else {
+            self.client
+                .query_opt(&set.schema_hash_sql(), &[])
+                .await?
+                .map(|row| row.try_get(0))
+                .transpose()?
+        };
+        drizzle_migrations::check_schema_hash(recorded.as_deref(), Schema::SCHEMA_HASH)
+            .map_err(|e| drizzle_core::error::DrizzleError::Schema(e.to_string().into()))
+    }
 }
 
 async fn ensure_postgres_migration_table(
@@ -609,6 +1226,9 @@ async fn ensure_postgres_migration_table(
     set: &drizzle_migrations::Migrations,
 ) -> drizzle_core::error::Result<()> {
     client.execute(&set.create_table_sql(), &[]).await?;
+    if let Some(sql) = set.add_audit_columns_sql() {
+        client.execute(&sql, &[]).await?;
+    }
 
     let schema = set.schema_name().unwrap_or("public");
     let rows = client
@@ -783,6 +1403,46 @@ async fn pg_async_query_enums(
         .collect())
 }
 
+async fn pg_async_query_domains(
+    client: &tokio_postgres::Client,
+) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::introspect::RawDomainInfo>> {
+    use drizzle_migrations::postgres::introspect::{RawDomainInfo, queries};
+
+    Ok(client
+        .query(queries::DOMAINS_QUERY, &[])
+        .await
+        .map_err(|e| pg_async_err("Failed to query domains", &e))?
+        .into_iter()
+        .map(|row| RawDomainInfo {
+            schema: row.get(0),
+            name: row.get(1),
+            base_type: row.get(2),
+            not_null: row.get(3),
+            default_value: row.get(4),
+            check: row.get(5),
+        })
+        .collect())
+}
+
+async fn pg_async_query_composites(
+    client: &tokio_postgres::Client,
+) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::introspect::RawCompositeInfo>> {
+    use drizzle_migrations::postgres::introspect::{RawCompositeInfo, queries};
+
+    Ok(client
+        .query(queries::COMPOSITES_QUERY, &[])
+        .await
+        .map_err(|e| pg_async_err("Failed to query composite types", &e))?
+        .into_iter()
+        .map(|row| RawCompositeInfo {
+            schema: row.get(0),
+            name: row.get(1),
+            field_names: row.get(2),
+            field_types: row.get(3),
+        })
+        .collect())
+}
+
 async fn pg_async_query_sequences(
     client: &tokio_postgres::Client,
 ) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::introspect::RawSequenceInfo>> {
@@ -957,6 +1617,39 @@ async fn pg_async_query_checks(
         .collect())
 }
 
+async fn pg_async_query_excludes(
+    client: &tokio_postgres::Client,
+    schema_filter: Option<&[String]>,
+) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::introspect::RawExcludeInfo>> {
+    use drizzle_migrations::postgres::introspect::{RawExcludeInfo, queries};
+
+    let rows = if let Some(schemas) = schema_filter {
+        client
+            .query(queries::EXCLUDES_QUERY_FILTERED, &[&schemas])
+            .await
+            .map_err(|e| pg_async_err("Failed to query exclusion constraints", &e))?
+    } else {
+        client
+            .query(queries::EXCLUDES_QUERY, &[])
+            .await
+            .map_err(|e| pg_async_err("Failed to query exclusion constraints", &e))?
+    };
+    Ok(rows
+        .into_iter()
+        .map(|row| RawExcludeInfo {
+            schema: row.get(0),
+            table: row.get(1),
+            name: row.get(2),
+            method: row.get(3),
+            columns: row.get(4),
+            operators: row.get(5),
+            where_clause: row.get(6),
+            deferrable: row.get(7),
+            initially_deferred: row.get(8),
+        })
+        .collect())
+}
+
 async fn pg_async_query_roles(
     client: &tokio_postgres::Client,
 ) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::introspect::RawRoleInfo>> {
@@ -1003,8 +1696,8 @@ impl<Schema> Drizzle<Schema> {
     /// Introspect the connected `PostgreSQL` database and return a [`Snapshot`](drizzle_migrations::schema::Snapshot).
     ///
     /// Queries the `pg_catalog` and `information_schema` to extract tables, columns,
-    /// indexes, foreign keys, primary keys, unique/check constraints, enums, sequences,
-    /// views, roles, and policies.
+    /// indexes, foreign keys, primary keys, unique/check constraints, enums, domains,
+    /// composite types, sequences, views, roles, and policies.
     ///
     /// # Errors
     ///
@@ -1025,6 +1718,22 @@ impl<Schema> Drizzle<Schema> {
         &self,
         schema_filter: Option<&[String]>,
     ) -> drizzle_core::error::Result<drizzle_migrations::schema::Snapshot> {
+        let ddl = self.introspect_ddl_impl(schema_filter).await?;
+
+        // Build snapshot
+        let mut snap = drizzle_migrations::postgres::PostgresSnapshot::new();
+        for entity in ddl.to_entities() {
+            snap.add_entity(entity);
+        }
+
+        Ok(drizzle_migrations::schema::Snapshot::Postgres(snap))
+    }
+
+    /// Builds the DDL model for [`introspect_impl`](Self::introspect_impl).
+    async fn introspect_ddl_impl(
+        &self,
+        schema_filter: Option<&[String]>,
+    ) -> drizzle_core::error::Result<drizzle_migrations::postgres::PostgresDDL> {
         use drizzle_migrations::postgres::ddl::Schema as PgSchema;
         use drizzle_migrations::postgres::introspect::{RawIntrospection, assemble_ddl};
 
@@ -1037,6 +1746,8 @@ impl<Schema> Drizzle<Schema> {
         let raw_tables = pg_async_query_tables(&self.client).await?;
         let raw_columns = pg_async_query_columns(&self.client).await?;
         let raw_enums = pg_async_query_enums(&self.client).await?;
+        let raw_domains = pg_async_query_domains(&self.client).await?;
+        let raw_composites = pg_async_query_composites(&self.client).await?;
         let raw_sequences = pg_async_query_sequences(&self.client).await?;
         let raw_views = pg_async_query_views(&self.client, effective_schema_filter).await?;
         let raw_indexes = pg_async_query_indexes(&self.client, effective_schema_filter).await?;
@@ -1044,14 +1755,17 @@ impl<Schema> Drizzle<Schema> {
         let raw_primary_keys = pg_async_query_primary_keys(&self.client).await?;
         let raw_uniques = pg_async_query_uniques(&self.client).await?;
         let raw_checks = pg_async_query_checks(&self.client, effective_schema_filter).await?;
+        let raw_excludes = pg_async_query_excludes(&self.client, effective_schema_filter).await?;
         let raw_roles = pg_async_query_roles(&self.client).await?;
         let raw_policies = pg_async_query_policies(&self.client).await?;
 
-        let ddl = assemble_ddl(RawIntrospection {
+        Ok(assemble_ddl(RawIntrospection {
             schemas,
             tables: raw_tables,
             columns: raw_columns,
             enums: raw_enums,
+            domains: raw_domains,
+            composites: raw_composites,
             sequences: raw_sequences,
             views: raw_views,
             indexes: raw_indexes,
@@ -1059,17 +1773,73 @@ impl<Schema> Drizzle<Schema> {
             primary_keys: raw_primary_keys,
             unique_constraints: raw_uniques,
             check_constraints: raw_checks,
+            exclude_constraints: raw_excludes,
             roles: raw_roles,
             policies: raw_policies,
-        });
+        }))
+    }
 
-        // Build snapshot
-        let mut snap = drizzle_migrations::postgres::PostgresSnapshot::new();
-        for entity in ddl.to_entities() {
-            snap.add_entity(entity);
-        }
+    /// Introspect the connected database into the same DDL model the
+    /// migration differ works on.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub async fn introspect_ddl(
+        &self,
+    ) -> drizzle_core::error::Result<drizzle_migrations::postgres::PostgresDDL> {
+        self.introspect_ddl_impl(None).await
+    }
 
-        Ok(drizzle_migrations::schema::Snapshot::Postgres(snap))
+    /// Tables in every schema the connection can see.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub async fn tables(
+        &self,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::ddl::Table>> {
+        Ok(self.introspect_ddl().await?.tables.into_vec())
+    }
+
+    /// Columns of `schema.table`, in declaration order.
+    ///
+    /// Returns an empty list if the table doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub async fn columns(
+        &self,
+        schema: &str,
+        table: &str,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::ddl::Column>> {
+        let ddl = self.introspect_ddl().await?;
+        Ok(ddl
+            .columns
+            .for_table(schema, table)
+            .into_iter()
+            .cloned()
+            .collect())
+    }
+
+    /// Indexes on `schema.table`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub async fn indexes(
+        &self,
+        schema: &str,
+        table: &str,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::postgres::ddl::Index>> {
+        let ddl = self.introspect_ddl().await?;
+        Ok(ddl
+            .indexes
+            .for_table(schema, table)
+            .into_iter()
+            .cloned()
+            .collect())
     }
 
     /// Introspect the live database, diff against the desired schema, and
@@ -1177,10 +1947,12 @@ where
             .with_query(|| QueryContext::new(&sql_str, &params))?;
         let mut decoded = Vec::with_capacity(rows.len());
         for row in &rows {
-            decoded.push(<Mk as drizzle_core::row::DecodeSelectedRef<
-                &::tokio_postgres::Row,
-                R,
-            >>::decode(row)?);
+            decoded.push(
+                <Mk as drizzle_core::row::DecodeSelectedRef<&::tokio_postgres::Row, R>>::decode(
+                    row,
+                )
+                .map_err(|e| e.at_row(decoded.len()))?,
+            );
         }
         Ok(decoded)
     }
@@ -0,0 +1,33 @@
+//! SQL for `truncate` and `reset`, shared by the postgres drivers.
+
+use drizzle_core::TableRef;
+
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// A single `TRUNCATE` of every table in `tables`, restarting their identity
+/// sequences and cascading to tables that reference them. `None` when
+/// `tables` is empty.
+pub(crate) fn truncate_sql(tables: &[&'static TableRef]) -> Option<String> {
+    let mut names: Vec<String> = Vec::with_capacity(tables.len());
+    for table in tables {
+        // Like the CREATE TABLE DDL, `public` tables are left to the search path.
+        let name = match table.schema {
+            Some(schema) if schema != "public" => {
+                format!("{}.{}", quote(schema), quote(table.name))
+            }
+            _ => quote(table.name),
+        };
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    if names.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "TRUNCATE {} RESTART IDENTITY CASCADE",
+        names.join(", ")
+    ))
+}
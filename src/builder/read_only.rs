@@ -0,0 +1,21 @@
+//! Read-only wrapper shared by the drivers.
+
+/// Handle that only builds reads.
+///
+/// Created by each driver's `read_only()`, which also switches the connection
+/// itself to read-only, so raw SQL sent through `conn()` cannot write either.
+/// The wrapper exposes `select`, `select_distinct`, `with` and `query`, but no
+/// `insert`, `update` or `delete`, so routing a write to a replica handle
+/// fails to compile.
+#[derive(Debug, Clone)]
+pub struct ReadOnly<D>(pub(crate) D);
+
+impl<D> ReadOnly<D> {
+    /// Returns the wrapped handle, giving back access to the write builders.
+    ///
+    /// The connection stays read-only.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.0
+    }
+}
@@ -0,0 +1,25 @@
+//! SQL for `attach` and `detach`, shared by the sqlite drivers.
+
+use drizzle_core::error::{DrizzleError, Result};
+use std::path::Path;
+
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// `ATTACH DATABASE ?1 AS "name"`, with the path bound as `?1`.
+pub(crate) fn attach_sql(name: &str) -> String {
+    format!("ATTACH DATABASE ?1 AS {}", quote(name))
+}
+
+pub(crate) fn detach_sql(name: &str) -> String {
+    format!("DETACH DATABASE {}", quote(name))
+}
+
+/// The path as SQLite receives it. SQLite takes UTF-8 filenames, so other
+/// paths are rejected rather than attached under a lossy name.
+pub(crate) fn path_param(path: &Path) -> Result<&str> {
+    path.to_str().ok_or_else(|| {
+        DrizzleError::Statement(format!("`{}` is not valid UTF-8", path.display()).into())
+    })
+}
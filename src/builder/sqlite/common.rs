@@ -11,8 +11,8 @@ use drizzle_sqlite::{
         self, CTEView, DeleteInitial, DeleteReturningSet, DeleteWhereSet, InsertDoUpdateSet,
         InsertInitial, InsertOnConflictSet, InsertReturningSet, InsertValuesSet, OnConflictBuilder,
         QueryBuilder, SelectFromSet, SelectGroupSet, SelectInitial, SelectJoinSet, SelectLimitSet,
-        SelectOffsetSet, SelectOrderSet, SelectWhereSet, UpdateInitial, UpdateReturningSet,
-        UpdateSetClauseSet, UpdateWhereSet,
+        SelectOffsetSet, SelectOrderSet, SelectWhereSet, UpdateFromSet, UpdateInitial,
+        UpdateReturningSet, UpdateSetClauseSet, UpdateWhereSet,
         delete::DeleteBuilder,
         insert::InsertBuilder,
         select::{AsCteState, IntoSelect, SelectBuilder, SelectSetOpSet},
@@ -23,6 +23,27 @@ use drizzle_sqlite::{
     values::SQLiteValue,
 };
 
+/// Builds `SELECT * FROM table WHERE column IN (...)` for one `get_many` chunk.
+pub(crate) fn get_many_sql<'a, T, C, K>(
+    table: T,
+    column: C,
+    keys: &'a [K],
+) -> drizzle_core::SQL<'a, SQLiteValue<'a>>
+where
+    T: ToSQL<'a, SQLiteValue<'a>>,
+    C: drizzle_core::expr::Expr<'a, SQLiteValue<'a>>,
+    &'a K: drizzle_core::expr::Expr<'a, SQLiteValue<'a>>,
+    C::SQLType: drizzle_core::types::Compatible<
+            <&'a K as drizzle_core::expr::Expr<'a, SQLiteValue<'a>>>::SQLType,
+        >,
+{
+    drizzle_core::helpers::select(())
+        .append(drizzle_core::helpers::from(table))
+        .append(drizzle_core::helpers::r#where(
+            drizzle_core::expr::in_array(column, keys),
+        ))
+}
+
 /// Shared `SQLite` drizzle builder wrapper for all `SQLite` drivers.
 #[derive(Debug)]
 pub struct DrizzleBuilder<'a, Runner, Schema, Builder, State> {
@@ -146,6 +167,8 @@ pub struct Drizzle<Conn, Schema = ()> {
     pub(crate) schema: Schema,
     #[cfg(feature = "libsql")]
     pub(crate) libsql_statement_cache: LibsqlStatementCache,
+    #[cfg(feature = "libsql")]
+    pub(crate) libsql_source: Option<crate::builder::sqlite::libsql::LibsqlSource>,
 }
 
 impl<Conn: Clone, S: Clone> Clone for Drizzle<Conn, S> {
@@ -156,6 +179,8 @@ impl<Conn: Clone, S: Clone> Clone for Drizzle<Conn, S> {
             schema: self.schema.clone(),
             #[cfg(feature = "libsql")]
             libsql_statement_cache: self.libsql_statement_cache.clone(),
+            #[cfg(feature = "libsql")]
+            libsql_source: self.libsql_source.clone(),
         }
     }
 }
@@ -171,6 +196,8 @@ impl<Conn> Drizzle<Conn> {
             schema,
             #[cfg(feature = "libsql")]
             libsql_statement_cache: LibsqlStatementCache::new(),
+            #[cfg(feature = "libsql")]
+            libsql_source: None,
         };
         (drizzle, schema)
     }
@@ -340,6 +367,109 @@ impl<Conn, Schema> Drizzle<Conn, Schema> {
             state: PhantomData,
         }
     }
+
+    /// Creates a `CREATE TEMP TABLE ... AS SELECT ...` statement.
+    ///
+    /// The view is the typed handle for the new table: select from or join it in
+    /// later queries on the same connection or transaction.
+    #[cfg(feature = "sqlite")]
+    pub fn create_temp_table_as<'a, 'b, Table, Query>(
+        &'a self,
+        view: &builder::CTEView<'b, Table, Query>,
+    ) -> DrizzleBuilder<
+        'a,
+        Self,
+        Schema,
+        QueryBuilder<'b, Schema, builder::CreateTempTableSet>,
+        builder::CreateTempTableSet,
+    >
+    where
+        Query: ToSQL<'b, SQLiteValue<'b>>,
+    {
+        let builder = QueryBuilder::new::<Schema>().create_temp_table_as(view);
+        DrizzleBuilder {
+            runner: self,
+            builder,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<Conn, Schema> crate::ReadOnly<Drizzle<Conn, Schema>> {
+    /// Gets a reference to the underlying connection.
+    #[inline]
+    pub const fn conn(&self) -> &Conn {
+        &self.0.conn
+    }
+
+    /// Gets a reference to the schema.
+    #[inline]
+    pub const fn schema(&self) -> &Schema {
+        &self.0.schema
+    }
+
+    /// Creates a SELECT query builder.
+    #[cfg(feature = "sqlite")]
+    pub fn select<'a, 'b, T>(
+        &'a self,
+        query: T,
+    ) -> DrizzleBuilder<
+        'a,
+        Drizzle<Conn, Schema>,
+        Schema,
+        SelectBuilder<'b, Schema, drizzle_sqlite::builder::select::SelectInitial, (), T::Marker>,
+        drizzle_sqlite::builder::select::SelectInitial,
+    >
+    where
+        T: ToSQL<'b, SQLiteValue<'b>> + drizzle_core::IntoSelectTarget,
+    {
+        self.0.select(query)
+    }
+
+    /// Creates a SELECT DISTINCT query builder.
+    #[cfg(feature = "sqlite")]
+    pub fn select_distinct<'a, 'b, T>(
+        &'a self,
+        query: T,
+    ) -> DrizzleBuilder<
+        'a,
+        Drizzle<Conn, Schema>,
+        Schema,
+        SelectBuilder<'b, Schema, drizzle_sqlite::builder::select::SelectInitial, (), T::Marker>,
+        drizzle_sqlite::builder::select::SelectInitial,
+    >
+    where
+        T: ToSQL<'b, SQLiteValue<'b>> + drizzle_core::IntoSelectTarget,
+    {
+        self.0.select_distinct(query)
+    }
+
+    /// Creates a query with CTE (Common Table Expression).
+    #[cfg(feature = "sqlite")]
+    pub fn with<'a, 'b, C>(
+        &'a self,
+        cte: &C,
+    ) -> DrizzleBuilder<
+        'a,
+        Drizzle<Conn, Schema>,
+        Schema,
+        QueryBuilder<'b, Schema, builder::CTEInit>,
+        builder::CTEInit,
+    >
+    where
+        C: builder::CTEDefinition<'b>,
+    {
+        self.0.with(cte)
+    }
+
+    /// Creates a relational query builder for the given table.
+    #[cfg(all(feature = "sqlite", feature = "query"))]
+    pub fn query<'a, T>(&self, table: T) -> DrizzleQueryBuilder<'_, 'a, Conn, Schema, T>
+    where
+        T: drizzle_core::query::QueryTable,
+    {
+        self.0.query(table)
+    }
 }
 
 // =============================================================================
@@ -1300,6 +1430,37 @@ where
             state: PhantomData,
         }
     }
+
+    /// Adds `RETURNING` for the table's primary key.
+    pub fn returning_ids<Pk>(
+        self,
+    ) -> DrizzleBuilder<
+        'a,
+        Runner,
+        Schema,
+        InsertBuilder<
+            'b,
+            Schema,
+            InsertReturningSet,
+            Table,
+            drizzle_core::Scoped<Pk::Marker, drizzle_core::Cons<Table, drizzle_core::Nil>>,
+            <Pk::Marker as drizzle_core::ResolveRow<Table>>::Row,
+        >,
+        InsertReturningSet,
+    >
+    where
+        <Table as SQLTable<'b, SQLiteSchemaType, SQLiteValue<'b>>>::PrimaryKey:
+            drizzle_core::SQLPrimaryKey<Columns = (Pk,)>,
+        Pk: Default + ToSQL<'b, SQLiteValue<'b>> + drizzle_core::IntoSelectTarget,
+        Pk::Marker: drizzle_core::ResolveRow<Table>,
+    {
+        let builder = self.builder.returning_ids();
+        DrizzleBuilder {
+            runner: self.runner,
+            builder,
+            state: PhantomData,
+        }
+    }
 }
 
 impl<'a, 'b, Runner, Schema, Table>
@@ -1443,6 +1604,85 @@ impl<'a, 'b, Runner, Schema, Table>
         UpdateBuilder<'b, Schema, UpdateSetClauseSet, Table>,
         UpdateSetClauseSet,
     >
+{
+    pub fn from(
+        self,
+        source: impl ToSQL<'b, SQLiteValue<'b>>,
+    ) -> DrizzleBuilder<
+        'a,
+        Runner,
+        Schema,
+        UpdateBuilder<'b, Schema, UpdateFromSet, Table>,
+        UpdateFromSet,
+    > {
+        let builder = self.builder.from(source.to_sql());
+        DrizzleBuilder {
+            runner: self.runner,
+            builder,
+            state: PhantomData,
+        }
+    }
+
+    pub fn r#where<E>(
+        self,
+        condition: E,
+    ) -> DrizzleBuilder<
+        'a,
+        Runner,
+        Schema,
+        UpdateBuilder<'b, Schema, UpdateWhereSet, Table>,
+        UpdateWhereSet,
+    >
+    where
+        E: drizzle_core::expr::Expr<'b, SQLiteValue<'b>>,
+        E::SQLType: drizzle_core::types::BooleanLike,
+    {
+        let builder = self.builder.r#where(condition);
+        DrizzleBuilder {
+            runner: self.runner,
+            builder,
+            state: PhantomData,
+        }
+    }
+
+    pub fn returning<Columns>(
+        self,
+        columns: Columns,
+    ) -> DrizzleBuilder<
+        'a,
+        Runner,
+        Schema,
+        UpdateBuilder<
+            'b,
+            Schema,
+            UpdateReturningSet,
+            Table,
+            drizzle_core::Scoped<Columns::Marker, drizzle_core::Cons<Table, drizzle_core::Nil>>,
+            <Columns::Marker as drizzle_core::ResolveRow<Table>>::Row,
+        >,
+        UpdateReturningSet,
+    >
+    where
+        Columns: ToSQL<'b, SQLiteValue<'b>> + drizzle_core::IntoSelectTarget,
+        Columns::Marker: drizzle_core::ResolveRow<Table>,
+    {
+        let builder = self.builder.returning(columns);
+        DrizzleBuilder {
+            runner: self.runner,
+            builder,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<'a, 'b, Runner, Schema, Table>
+    DrizzleBuilder<
+        'a,
+        Runner,
+        Schema,
+        UpdateBuilder<'b, Schema, UpdateFromSet, Table>,
+        UpdateFromSet,
+    >
 {
     pub fn r#where<E>(
         self,
@@ -255,7 +255,29 @@ where
     /// through [`D1Database::batch`] for atomicity.
     pub async fn create(&self) -> drizzle_core::error::Result<()> {
         let schema = Schema::default();
-        let stmts: Vec<String> = schema.create_statements()?.collect();
+        self.run_ddl(schema.create_statements()?).await
+    }
+
+    /// Like [`create`](Self::create), but objects that already exist are
+    /// left alone, so it is safe to call on every startup.
+    pub async fn create_if_not_exists(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.create_statements()?.map(super::ddl::if_not_exists))
+            .await
+    }
+
+    /// Drop every view, index and table of the schema, the inverse of
+    /// [`create`](Self::create). Tables are dropped referencing tables first.
+    pub async fn drop(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.drop_statements()?).await
+    }
+
+    async fn run_ddl(
+        &self,
+        statements: impl IntoIterator<Item = String>,
+    ) -> drizzle_core::error::Result<()> {
+        let stmts: Vec<String> = statements.into_iter().collect();
         if stmts.is_empty() {
             return Ok(());
         }
@@ -269,11 +291,62 @@ where
     }
 }
 
+impl<Schema> Drizzle<Schema> {
+    /// Delete every row of `tables` and reset their `AUTOINCREMENT` counters.
+    ///
+    /// Tables that reference others are emptied first, so a parent can be
+    /// truncated together with its children: `db.truncate((users, posts))`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the tables reference each other in a cycle
+    /// or a `DELETE` fails, e.g. on a foreign key from a table left out.
+    pub async fn truncate(
+        &self,
+        tables: impl drizzle_core::traits::TableSet,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(&tables.table_refs()).await
+    }
+
+    /// Truncate every table of `schema`, e.g. before seeding or between tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the tables reference each other in a cycle
+    /// or a `DELETE` fails.
+    pub async fn reset<S: drizzle_core::traits::SQLSchemaImpl>(
+        &self,
+        schema: &S,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(schema.table_refs()).await
+    }
+
+    async fn truncate_tables(
+        &self,
+        tables: &[&'static drizzle_core::TableRef],
+    ) -> drizzle_core::error::Result<()> {
+        let statements = super::truncate::truncate_statements(tables)?;
+        if statements.is_empty() {
+            return Ok(());
+        }
+        let prepared: Vec<D1PreparedStatement> = statements
+            .into_iter()
+            .map(|s| self.conn.prepare(s))
+            .collect();
+        self.conn
+            .batch(prepared)
+            .await
+            .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
+        Ok(())
+    }
+}
+
 impl<Schema> common::Drizzle<D1Database, Schema> {
     /// Apply pending migrations from an embedded migration slice.
     ///
     /// Creates the migrations table if needed and applies pending migrations
-    /// as a single atomic batch.
+    /// as a single atomic batch. Statements marked `-- drizzle:no-transaction`
+    /// are sent on their own, splitting the batch around them.
     ///
     /// # Prefer deploy-time migration
     ///
@@ -305,55 +378,66 @@ impl<Schema> common::Drizzle<D1Database, Schema> {
         // Read already-applied migration names
         let applied = self
             .conn
-            .prepare(set.applied_names_sql())
+            .prepare(set.applied_hashes_sql())
             .all()
             .await
             .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
 
-        let applied_names: Vec<String> = applied
-            .results::<AppliedName>()
+        let applied_rows: Vec<(String, String)> = applied
+            .results::<AppliedRecord>()
             .map_err(|e| DrizzleError::Other(e.to_string().into()))?
             .into_iter()
-            .map(|r| r.name)
+            .map(|r| (r.name, r.hash))
             .collect();
+        set.check_checksums(&applied_rows)
+            .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
+        let applied_names: Vec<&str> = applied_rows.iter().map(|(name, _)| name.as_str()).collect();
 
         let pending: Vec<_> = set.pending(&applied_names).collect();
         if pending.is_empty() {
             return Ok(drizzle_migrations::MigrateOutcome::UpToDate);
         }
 
-        // Build all statements (DDL + tracking insert) into a single batch.
+        // Build all statements (DDL + tracking insert) into a single batch,
+        // splitting it around statements that can't run inside a transaction.
+        let mut batches: Vec<Vec<D1PreparedStatement>> = Vec::new();
         let mut batch: Vec<D1PreparedStatement> = Vec::new();
         let mut applied_tags = Vec::with_capacity(pending.len());
         for migration in &pending {
-            for stmt in migration.statements() {
-                if !stmt.trim().is_empty() {
-                    batch.push(self.conn.prepare(stmt));
+            for step in migration.steps() {
+                if step.no_transaction {
+                    batches.push(std::mem::take(&mut batch));
+                    batches.push(vec![self.conn.prepare(step.sql)]);
+                } else {
+                    batch.push(self.conn.prepare(step.sql));
                 }
             }
             batch.push(self.conn.prepare(set.record_migration_sql(migration)));
             applied_tags.push(migration.tag().to_string());
         }
-
-        let results = match self.conn.batch(batch).await {
-            Ok(results) => results,
-            Err(error) => {
-                if d1_migrations_are_applied(&self.conn, &set, &pending).await? {
-                    return Ok(drizzle_migrations::MigrateOutcome::UpToDate);
+        batches.push(batch);
+
+        for batch in batches.into_iter().filter(|batch| !batch.is_empty()) {
+            let results = match self.conn.batch(batch).await {
+                Ok(results) => results,
+                Err(error) => {
+                    if d1_migrations_are_applied(&self.conn, &set, &pending).await? {
+                        return Ok(drizzle_migrations::MigrateOutcome::UpToDate);
+                    }
+                    return Err(DrizzleError::Other(error.to_string().into()));
                 }
-                return Err(DrizzleError::Other(error.to_string().into()));
-            }
-        };
-        for r in &results {
-            if !r.success() {
-                if d1_migrations_are_applied(&self.conn, &set, &pending).await? {
-                    return Ok(drizzle_migrations::MigrateOutcome::UpToDate);
+            };
+            for r in &results {
+                if !r.success() {
+                    if d1_migrations_are_applied(&self.conn, &set, &pending).await? {
+                        return Ok(drizzle_migrations::MigrateOutcome::UpToDate);
+                    }
+                    return Err(DrizzleError::Other(
+                        r.error()
+                            .unwrap_or_else(|| "D1 migration batch failed".into())
+                            .into(),
+                    ));
                 }
-                return Err(DrizzleError::Other(
-                    r.error()
-                        .unwrap_or_else(|| "D1 migration batch failed".into())
-                        .into(),
-                ));
             }
         }
         Ok(drizzle_migrations::MigrateOutcome::Applied { tags: applied_tags })
@@ -361,8 +445,9 @@ impl<Schema> common::Drizzle<D1Database, Schema> {
 }
 
 #[derive(serde::Deserialize)]
-struct AppliedName {
+struct AppliedRecord {
     name: String,
+    hash: String,
 }
 
 async fn d1_migrations_are_applied(
@@ -371,12 +456,12 @@ async fn d1_migrations_are_applied(
     migrations: &[&drizzle_migrations::Migration],
 ) -> drizzle_core::error::Result<bool> {
     let applied = conn
-        .prepare(set.applied_names_sql())
+        .prepare(set.applied_hashes_sql())
         .all()
         .await
         .map_err(|error| DrizzleError::Other(error.to_string().into()))?;
     let names = applied
-        .results::<AppliedName>()
+        .results::<AppliedRecord>()
         .map_err(|error| DrizzleError::Other(error.to_string().into()))?
         .into_iter()
         .map(|record| record.name)
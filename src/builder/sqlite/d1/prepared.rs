@@ -117,7 +117,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
     pub async fn execute<const N: usize>(
         &self,
         conn: &D1Database,
-        params: [drizzle_core::param::ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<drizzle_core::param::ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> drizzle_core::error::Result<u64> {
         debug_assert_eq!(
             N,
@@ -126,7 +126,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
             self.inner.external_param_count(),
             N
         );
-        let (sql_str, bound) = self.inner.bind(params)?;
+        let (sql_str, bound) = self.inner.bind(params.into())?;
         let values = borrowed_values_to_js(bound);
         let stmt = bind_statement(conn.prepare(sql_str), &values)?;
         run_execute(stmt).await
@@ -136,7 +136,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
     pub async fn all<T, const N: usize>(
         &self,
         conn: &D1Database,
-        params: [drizzle_core::param::ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<drizzle_core::param::ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> drizzle_core::error::Result<Vec<T>>
     where
         T: for<'de> serde::Deserialize<'de>,
@@ -148,7 +148,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
             self.inner.external_param_count(),
             N
         );
-        let (sql_str, bound) = self.inner.bind(params)?;
+        let (sql_str, bound) = self.inner.bind(params.into())?;
         let values = borrowed_values_to_js(bound);
         let stmt = bind_statement(conn.prepare(sql_str), &values)?;
         run_all::<T>(stmt).await
@@ -158,7 +158,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
     pub async fn get<T, const N: usize>(
         &self,
         conn: &D1Database,
-        params: [drizzle_core::param::ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<drizzle_core::param::ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> drizzle_core::error::Result<T>
     where
         T: for<'de> serde::Deserialize<'de>,
@@ -170,7 +170,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
             self.inner.external_param_count(),
             N
         );
-        let (sql_str, bound) = self.inner.bind(params)?;
+        let (sql_str, bound) = self.inner.bind(params.into())?;
         let values = borrowed_values_to_js(bound);
         let stmt = bind_statement(conn.prepare(sql_str), &values)?;
         run_get::<T>(stmt).await
@@ -182,7 +182,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
     pub async fn execute<'a, const N: usize>(
         &self,
         conn: &D1Database,
-        params: [drizzle_core::param::ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<drizzle_core::param::ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> drizzle_core::error::Result<u64> {
         debug_assert_eq!(
             N,
@@ -191,7 +191,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
             self.inner.external_param_count(),
             N
         );
-        let (sql_str, bound) = self.inner.bind(params)?;
+        let (sql_str, bound) = self.inner.bind(params.into())?;
         let values = owned_values_to_js(bound);
         let stmt = bind_statement(conn.prepare(sql_str), &values)?;
         run_execute(stmt).await
@@ -201,7 +201,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
     pub async fn all<'a, T, const N: usize>(
         &self,
         conn: &D1Database,
-        params: [drizzle_core::param::ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<drizzle_core::param::ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> drizzle_core::error::Result<Vec<T>>
     where
         T: for<'de> serde::Deserialize<'de>,
@@ -213,7 +213,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
             self.inner.external_param_count(),
             N
         );
-        let (sql_str, bound) = self.inner.bind(params)?;
+        let (sql_str, bound) = self.inner.bind(params.into())?;
         let values = owned_values_to_js(bound);
         let stmt = bind_statement(conn.prepare(sql_str), &values)?;
         run_all::<T>(stmt).await
@@ -223,7 +223,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
     pub async fn get<'a, T, const N: usize>(
         &self,
         conn: &D1Database,
-        params: [drizzle_core::param::ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<drizzle_core::param::ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> drizzle_core::error::Result<T>
     where
         T: for<'de> serde::Deserialize<'de>,
@@ -235,7 +235,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
             self.inner.external_param_count(),
             N
         );
-        let (sql_str, bound) = self.inner.bind(params)?;
+        let (sql_str, bound) = self.inner.bind(params.into())?;
         let values = owned_values_to_js(bound);
         let stmt = bind_statement(conn.prepare(sql_str), &values)?;
         run_get::<T>(stmt).await
@@ -0,0 +1,25 @@
+//! `IF NOT EXISTS` rewriting for `create_if_not_exists`, shared by the sqlite
+//! drivers.
+
+/// `CREATE` prefixes emitted by the schema derive, all of which accept
+/// `IF NOT EXISTS` right after them.
+const CREATE_PREFIXES: &[&str] = &[
+    "CREATE TABLE ",
+    "CREATE UNIQUE INDEX ",
+    "CREATE INDEX ",
+    "CREATE VIEW ",
+];
+
+/// Rewrites a statement from `create_statements` so it is a no-op when the
+/// object already exists.
+pub(crate) fn if_not_exists(statement: String) -> String {
+    for prefix in CREATE_PREFIXES {
+        if let Some(rest) = statement.strip_prefix(prefix) {
+            if rest.starts_with("IF NOT EXISTS ") {
+                return statement;
+            }
+            return format!("{prefix}IF NOT EXISTS {rest}");
+        }
+    }
+    statement
+}
@@ -227,7 +227,72 @@ where
     /// Create schema objects in the DO's SQL storage.
     pub fn create(&self) -> drizzle_core::error::Result<()> {
         let schema = Schema::default();
-        for stmt in schema.create_statements()? {
+        self.run_ddl(schema.create_statements()?)
+    }
+
+    /// Like [`create`](Self::create), but objects that already exist are
+    /// left alone, so it is safe to call on every startup.
+    pub fn create_if_not_exists(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.create_statements()?.map(super::ddl::if_not_exists))
+    }
+
+    /// Drop every view, index and table of the schema, the inverse of
+    /// [`create`](Self::create). Tables are dropped referencing tables first.
+    pub fn drop(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.drop_statements()?)
+    }
+
+    fn run_ddl(
+        &self,
+        statements: impl IntoIterator<Item = String>,
+    ) -> drizzle_core::error::Result<()> {
+        for stmt in statements {
+            self.conn
+                .exec(&stmt, None)
+                .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
+        }
+        Ok(())
+    }
+}
+
+impl<Schema> Drizzle<Schema> {
+    /// Delete every row of `tables` and reset their `AUTOINCREMENT` counters.
+    ///
+    /// Tables that reference others are emptied first, so a parent can be
+    /// truncated together with its children: `db.truncate((users, posts))`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the tables reference each other in a cycle
+    /// or a `DELETE` fails, e.g. on a foreign key from a table left out.
+    pub fn truncate(
+        &self,
+        tables: impl drizzle_core::traits::TableSet,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(&tables.table_refs())
+    }
+
+    /// Truncate every table of `schema`, e.g. before seeding or between tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the tables reference each other in a cycle
+    /// or a `DELETE` fails.
+    pub fn reset<S: drizzle_core::traits::SQLSchemaImpl>(
+        &self,
+        schema: &S,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(schema.table_refs())
+    }
+
+    fn truncate_tables(
+        &self,
+        tables: &[&'static drizzle_core::TableRef],
+    ) -> drizzle_core::error::Result<()> {
+        let statements = super::truncate::truncate_statements(tables)?;
+        for stmt in statements {
             self.conn
                 .exec(&stmt, None)
                 .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
@@ -274,6 +339,9 @@ where
     ///
     /// Calling `migrate` from `fetch` instead pays a tracking-table
     /// round-trip on every request and is almost always wrong.
+    ///
+    /// Pending migrations run in one transaction; statements marked
+    /// `-- drizzle:no-transaction` run on their own between two transactions.
     pub fn migrate(
         &self,
         migrations: &[drizzle_migrations::Migration],
@@ -288,47 +356,63 @@ where
         ensure_durable_migration_table(&self.conn, &set)?;
 
         // Read already-applied migration names
-        let applied_sql = set.applied_names_sql();
+        let applied_sql = set.applied_hashes_sql();
         let applied_cursor = self
             .conn
             .exec(&applied_sql, None)
             .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
-        let applied_names: Vec<String> = applied_cursor
-            .to_array::<AppliedName>()
+        let applied_rows: Vec<(String, String)> = applied_cursor
+            .to_array::<AppliedRecord>()
             .map_err(|e| DrizzleError::Other(e.to_string().into()))?
             .into_iter()
-            .map(|r| r.name)
+            .map(|r| (r.name, r.hash))
             .collect();
+        set.check_checksums(&applied_rows)
+            .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
+        let applied_names: Vec<&str> = applied_rows.iter().map(|(name, _)| name.as_str()).collect();
 
         let pending: Vec<_> = set.pending(&applied_names).collect();
         if pending.is_empty() {
             return Ok(drizzle_migrations::MigrateOutcome::UpToDate);
         }
 
-        let applied = self.transaction(|tx| {
-            let mut applied = Vec::with_capacity(pending.len());
-            for migration in &pending {
-                for stmt in migration.statements() {
-                    if !stmt.trim().is_empty() {
-                        tx.inner()
-                            .exec(stmt, None)
-                            .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
-                    }
+        let run_queued = |queued: &[String]| {
+            self.transaction(|tx| {
+                for sql in queued {
+                    tx.inner()
+                        .exec(sql, None)
+                        .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
+                }
+                Ok(())
+            })
+        };
+
+        let mut applied = Vec::with_capacity(pending.len());
+        let mut queued = Vec::new();
+        for migration in &pending {
+            for step in migration.steps() {
+                if step.no_transaction {
+                    // Commit what is queued so far and run the statement on its own.
+                    run_queued(&std::mem::take(&mut queued))?;
+                    self.conn
+                        .exec(step.sql, None)
+                        .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
+                } else {
+                    queued.push(step.sql.to_string());
                 }
-                tx.inner()
-                    .exec(&set.record_migration_sql(migration), None)
-                    .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
-                applied.push(migration.tag().to_string());
             }
-            Ok(applied)
-        })?;
+            queued.push(set.record_migration_sql(migration));
+            applied.push(migration.tag().to_string());
+        }
+        run_queued(&queued)?;
         Ok(drizzle_migrations::MigrateOutcome::Applied { tags: applied })
     }
 }
 
 #[derive(serde::Deserialize)]
-struct AppliedName {
+struct AppliedRecord {
     name: String,
+    hash: String,
 }
 
 fn ensure_durable_migration_table(
@@ -118,7 +118,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
     pub fn execute<const N: usize>(
         &self,
         conn: &SqlStorage,
-        params: [drizzle_core::param::ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<drizzle_core::param::ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> drizzle_core::error::Result<u64> {
         debug_assert_eq!(
             N,
@@ -127,7 +127,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
             self.inner.external_param_count(),
             N
         );
-        let (sql_str, bound) = self.inner.bind(params)?;
+        let (sql_str, bound) = self.inner.bind(params.into())?;
         let values = borrowed_values_to_storage(bound);
         run_execute(conn, sql_str, values)
     }
@@ -136,7 +136,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
     pub fn all<T, const N: usize>(
         &self,
         conn: &SqlStorage,
-        params: [drizzle_core::param::ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<drizzle_core::param::ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> drizzle_core::error::Result<Vec<T>>
     where
         T: for<'de> serde::Deserialize<'de>,
@@ -148,7 +148,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
             self.inner.external_param_count(),
             N
         );
-        let (sql_str, bound) = self.inner.bind(params)?;
+        let (sql_str, bound) = self.inner.bind(params.into())?;
         let values = borrowed_values_to_storage(bound);
         run_all::<T>(conn, sql_str, values)
     }
@@ -157,7 +157,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
     pub fn get<T, const N: usize>(
         &self,
         conn: &SqlStorage,
-        params: [drizzle_core::param::ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<drizzle_core::param::ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> drizzle_core::error::Result<T>
     where
         T: for<'de> serde::Deserialize<'de>,
@@ -169,7 +169,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
             self.inner.external_param_count(),
             N
         );
-        let (sql_str, bound) = self.inner.bind(params)?;
+        let (sql_str, bound) = self.inner.bind(params.into())?;
         let values = borrowed_values_to_storage(bound);
         run_get::<T>(conn, sql_str, values)
     }
@@ -180,7 +180,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
     pub fn execute<'a, const N: usize>(
         &self,
         conn: &SqlStorage,
-        params: [drizzle_core::param::ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<drizzle_core::param::ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> drizzle_core::error::Result<u64> {
         debug_assert_eq!(
             N,
@@ -189,7 +189,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
             self.inner.external_param_count(),
             N
         );
-        let (sql_str, bound) = self.inner.bind(params)?;
+        let (sql_str, bound) = self.inner.bind(params.into())?;
         let values = owned_values_to_storage(bound);
         run_execute(conn, sql_str, values)
     }
@@ -198,7 +198,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
     pub fn all<'a, T, const N: usize>(
         &self,
         conn: &SqlStorage,
-        params: [drizzle_core::param::ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<drizzle_core::param::ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> drizzle_core::error::Result<Vec<T>>
     where
         T: for<'de> serde::Deserialize<'de>,
@@ -210,7 +210,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
             self.inner.external_param_count(),
             N
         );
-        let (sql_str, bound) = self.inner.bind(params)?;
+        let (sql_str, bound) = self.inner.bind(params.into())?;
         let values = owned_values_to_storage(bound);
         run_all::<T>(conn, sql_str, values)
     }
@@ -219,7 +219,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
     pub fn get<'a, T, const N: usize>(
         &self,
         conn: &SqlStorage,
-        params: [drizzle_core::param::ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<drizzle_core::param::ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> drizzle_core::error::Result<T>
     where
         T: for<'de> serde::Deserialize<'de>,
@@ -231,7 +231,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
             self.inner.external_param_count(),
             N
         );
-        let (sql_str, bound) = self.inner.bind(params)?;
+        let (sql_str, bound) = self.inner.bind(params.into())?;
         let values = owned_values_to_storage(bound);
         run_get::<T>(conn, sql_str, values)
     }
@@ -82,6 +82,8 @@
 //! # "####;
 //! ```
 
+mod open;
+mod pragma;
 mod prepared;
 
 use drizzle_core::error::{DrizzleError, QueryContext, ResultExt};
@@ -92,7 +94,7 @@ use libsql::{Connection, Row};
 #[cfg(feature = "sqlite")]
 use drizzle_sqlite::{
     builder::{self, QueryBuilder},
-    connection::SQLiteTransactionType,
+    connection::{ExecuteResult, SQLiteTransactionType},
     values::SQLiteValue,
 };
 
@@ -102,7 +104,35 @@ use crate::builder::sqlite::common;
 use crate::builder::sqlite::rows::LibsqlRows as Rows;
 use crate::transaction::sqlite::libsql::Transaction;
 
+pub(crate) use open::LibsqlSource;
+pub use open::OpenOptions;
+pub use pragma::Pragmas;
+
 pub type Drizzle<Schema = ()> = common::Drizzle<Connection, Schema>;
+
+/// Cloneable handle that interrupts a libsql connection's running statement.
+///
+/// Returned by [`Drizzle::interrupt_handle`]. The interrupted statement fails
+/// with [`DrizzleError::Timeout`]; later statements run normally.
+#[derive(Clone)]
+pub struct InterruptHandle(Connection);
+
+impl std::fmt::Debug for InterruptHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InterruptHandle").finish_non_exhaustive()
+    }
+}
+
+impl InterruptHandle {
+    /// Interrupts the statement running on the connection, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the connection does not support interrupts.
+    pub fn interrupt(&self) -> drizzle_core::error::Result<()> {
+        Ok(self.0.interrupt()?)
+    }
+}
 pub type DrizzleBuilder<'a, Schema, Builder, State> =
     common::DrizzleBuilder<'a, common::Drizzle<Connection, Schema>, Schema, Builder, State>;
 
@@ -199,13 +229,16 @@ impl<Schema> common::Drizzle<Connection, Schema> {
             .with_query(|| QueryContext::new(&sql_str, &params))?;
 
         let mut out = C::default();
+        let mut index = 0;
         while let Some(row) = rows
             .next()
             .await
             .map_err(DrizzleError::from)
             .with_query(|| QueryContext::new(&sql_str, &params))?
         {
-            out.extend(core::iter::once(R::try_from(&row).map_err(Into::into)?));
+            let decoded = R::try_from(&row).map_err(|e| e.into().at_row(index))?;
+            out.extend(core::iter::once(decoded));
+            index += 1;
         }
         drop(rows);
         self.libsql_statement_cache.store(cached);
@@ -275,6 +308,60 @@ impl<Schema> common::Drizzle<Connection, Schema> {
         result
     }
 
+    /// Loads the rows whose `column` matches each of `keys`, in key order.
+    ///
+    /// Keys are sent in `IN (...)` chunks of at most
+    /// [`SQLITE_MAX_BATCH_KEYS`](drizzle_core::batch::SQLITE_MAX_BATCH_KEYS).
+    /// `key_of` reads the key back off a row; keys without a row yield `None`.
+    ///
+    /// ```rust
+    /// # let _ = r####"
+    /// let users: Vec<Option<SelectUser>> =
+    ///     db.get_many(user, user.id, &[3, 1, 2], |u: &SelectUser| u.id).await?;
+    /// # "####;
+    /// ```
+    pub async fn get_many<'a, T, C, K, R>(
+        &'a self,
+        table: T,
+        column: C,
+        keys: &'a [K],
+        key_of: impl Fn(&R) -> K,
+    ) -> drizzle_core::error::Result<Vec<Option<R>>>
+    where
+        T: ToSQL<'a, SQLiteValue<'a>> + Clone,
+        C: drizzle_core::expr::Expr<'a, SQLiteValue<'a>> + Clone,
+        K: std::hash::Hash + Eq,
+        &'a K: drizzle_core::expr::Expr<'a, SQLiteValue<'a>>,
+        C::SQLType: drizzle_core::types::Compatible<
+                <&'a K as drizzle_core::expr::Expr<'a, SQLiteValue<'a>>>::SQLType,
+            >,
+        R: for<'r> TryFrom<&'r Row> + Clone,
+        for<'r> <R as TryFrom<&'r Row>>::Error: Into<DrizzleError>,
+    {
+        let mut rows: Vec<R> = Vec::with_capacity(keys.len());
+        for chunk in keys.chunks(drizzle_core::batch::SQLITE_MAX_BATCH_KEYS) {
+            let query = common::get_many_sql(table.clone(), column.clone(), chunk);
+            rows.extend(self.all::<_, R, Vec<R>>(query).await?);
+        }
+        Ok(drizzle_core::batch::order_by_keys(keys, rows, key_of))
+    }
+
+    /// Returns a handle that can interrupt this connection's running statement
+    /// from another task or thread.
+    ///
+    /// ```rust
+    /// # let _ = r####"
+    /// let cancel = db.interrupt_handle();
+    /// std::thread::spawn(move || {
+    ///     // e.g. when the user presses "Cancel"
+    ///     let _ = cancel.interrupt();
+    /// });
+    /// # "####;
+    /// ```
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        InterruptHandle(self.conn.clone())
+    }
+
     /// Executes a transaction with the given callback.
     ///
     /// The transaction is committed when the callback returns `Ok` and
@@ -319,6 +406,129 @@ impl<Schema> common::Drizzle<Connection, Schema> {
     }
 }
 
+impl<Schema> common::Drizzle<Connection, Schema> {
+    /// Attaches the database file at `path` to this connection as `name`.
+    ///
+    /// Tables declared with `#[SQLiteTable(DATABASE = "name")]` then resolve
+    /// to it, so they can be joined with, or copied into from, tables in the
+    /// main database. Only local
+    /// connections can attach; remote ones reject the statement.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or `name` is already in
+    /// use.
+    pub async fn attach(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        name: &str,
+    ) -> drizzle_core::error::Result<()> {
+        let path = super::attach::path_param(path.as_ref())?;
+        self.conn
+            .execute(&super::attach::attach_sql(name), [path])
+            .await?;
+        Ok(())
+    }
+
+    /// Detaches the database attached as `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no database is attached as `name`.
+    pub async fn detach(&self, name: &str) -> drizzle_core::error::Result<()> {
+        self.conn
+            .execute(&super::attach::detach_sql(name), ())
+            .await?;
+        Ok(())
+    }
+}
+
+impl common::Drizzle<Connection> {
+    /// Opens the database described by `options`.
+    ///
+    /// Unlike [`Drizzle::new`], the handle remembers how it was opened, so
+    /// [`Drizzle::sync`] and [`Drizzle::set_auth_token`] work on it.
+    ///
+    /// ```no_run
+    /// # use drizzle::sqlite::libsql::{Drizzle, OpenOptions};
+    /// # #[tokio::main] async fn main() -> drizzle::Result<()> {
+    /// let options = OpenOptions::replica("replica.db", "libsql://app.turso.io", "token")
+    ///     .encryption_key("local-secret")
+    ///     .sync_interval(std::time::Duration::from_secs(60));
+    /// let (db, ()) = Drizzle::open(options, ()).await?;
+    /// # let _ = db;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the database can't be opened, e.g. when an
+    /// encryption key is set for a local file without the
+    /// `libsql-encryption` feature.
+    pub async fn open<S: Copy>(
+        options: OpenOptions,
+        schema: S,
+    ) -> drizzle_core::error::Result<(common::Drizzle<Connection, S>, S)> {
+        let (source, conn) = LibsqlSource::open(options).await?;
+        let (mut db, schema) = Self::new(conn, schema);
+        db.libsql_source = Some(source);
+        Ok((db, schema))
+    }
+}
+
+impl<Schema> common::Drizzle<Connection, Schema> {
+    /// Pulls new frames from the primary into an embedded replica.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if this handle wasn't opened as a replica
+    /// with [`Drizzle::open`], or the sync fails.
+    pub async fn sync(&self) -> drizzle_core::error::Result<()> {
+        self.libsql_source()?.sync().await
+    }
+
+    /// Replaces the auth token of a database opened with [`Drizzle::open`].
+    ///
+    /// Embedded replicas send the new token from their next request on,
+    /// including from clones of this handle. Remote databases are reopened
+    /// with it; clones keep the old connection.
+    ///
+    /// ```no_run
+    /// # use drizzle::sqlite::libsql::{Drizzle, OpenOptions};
+    /// # async fn refresh(db: &mut Drizzle) -> drizzle::Result<()> {
+    /// # let fresh_token = String::new();
+    /// db.set_auth_token(fresh_token).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] for handles not opened with [`Drizzle::open`]
+    /// or opened on a local file, or if reopening a remote database fails.
+    pub async fn set_auth_token(
+        &mut self,
+        token: impl Into<String>,
+    ) -> drizzle_core::error::Result<()> {
+        let source = self
+            .libsql_source
+            .as_mut()
+            .ok_or_else(|| DrizzleError::Other(NOT_OPENED.into()))?;
+        if let Some(conn) = source.set_auth_token(token.into()).await? {
+            self.conn = conn;
+            self.libsql_statement_cache = common::LibsqlStatementCache::new();
+        }
+        Ok(())
+    }
+
+    fn libsql_source(&self) -> drizzle_core::error::Result<&LibsqlSource> {
+        self.libsql_source
+            .as_ref()
+            .ok_or_else(|| DrizzleError::Other(NOT_OPENED.into()))
+    }
+}
+
+const NOT_OPENED: &str = "this handle was not opened with Drizzle::open";
+
 impl<Schema> Drizzle<Schema>
 where
     Schema: drizzle_core::traits::SQLSchemaImpl + Default,
@@ -326,7 +536,29 @@ where
     /// Create schema objects from `SQLSchemaImpl`.
     pub async fn create(&self) -> drizzle_core::error::Result<()> {
         let schema = Schema::default();
-        let statements: Vec<_> = schema.create_statements()?.collect();
+        self.run_ddl(schema.create_statements()?).await
+    }
+
+    /// Like [`create`](Self::create), but objects that already exist are
+    /// left alone, so it is safe to call on every startup.
+    pub async fn create_if_not_exists(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.create_statements()?.map(super::ddl::if_not_exists))
+            .await
+    }
+
+    /// Drop every view, index and table of the schema, the inverse of
+    /// [`create`](Self::create). Tables are dropped referencing tables first.
+    pub async fn drop(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.drop_statements()?).await
+    }
+
+    async fn run_ddl(
+        &self,
+        statements: impl IntoIterator<Item = String>,
+    ) -> drizzle_core::error::Result<()> {
+        let statements: Vec<_> = statements.into_iter().collect();
         if !statements.is_empty() {
             let batch_sql = statements.join(";");
             self.conn.execute_batch(&batch_sql).await?;
@@ -335,10 +567,55 @@ where
     }
 }
 
+impl<Schema> Drizzle<Schema> {
+    /// Delete every row of `tables` and reset their `AUTOINCREMENT` counters.
+    ///
+    /// Tables that reference others are emptied first, so a parent can be
+    /// truncated together with its children: `db.truncate((users, posts))`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the tables reference each other in a cycle
+    /// or a `DELETE` fails, e.g. on a foreign key from a table left out.
+    pub async fn truncate(
+        &self,
+        tables: impl drizzle_core::traits::TableSet,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(&tables.table_refs()).await
+    }
+
+    /// Truncate every table of `schema`, e.g. before seeding or between tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the tables reference each other in a cycle
+    /// or a `DELETE` fails.
+    pub async fn reset<S: drizzle_core::traits::SQLSchemaImpl>(
+        &self,
+        schema: &S,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(schema.table_refs()).await
+    }
+
+    async fn truncate_tables(
+        &self,
+        tables: &[&'static drizzle_core::TableRef],
+    ) -> drizzle_core::error::Result<()> {
+        let statements = super::truncate::truncate_statements(tables)?;
+        if !statements.is_empty() {
+            self.conn.execute_batch(&statements.join(";")).await?;
+        }
+        Ok(())
+    }
+}
+
 impl<Schema> common::Drizzle<Connection, Schema> {
     /// Apply pending migrations from an embedded migration slice.
     ///
     /// Creates the migrations table if needed and runs pending migrations in a transaction.
+    /// Concurrent runners are serialized by `BEGIN IMMEDIATE` plus a lock marker row:
+    /// one applies the pending migrations while the others wait, then find nothing to do.
+    /// Statements marked `-- drizzle:no-transaction` run between two transactions.
     pub async fn migrate(
         &self,
         migrations: &[drizzle_migrations::Migration],
@@ -351,33 +628,70 @@ impl<Schema> common::Drizzle<Connection, Schema> {
         );
 
         ensure_sqlite_migration_table(&self.conn, &set).await?;
-        let tx = self
+        self.conn
+            .busy_timeout(std::time::Duration::from_secs(30))
+            .map_err(DrizzleError::from)?;
+        let mut tx = self
             .conn
             .transaction_with_behavior(libsql::TransactionBehavior::Immediate)
             .await
             .map_err(DrizzleError::from)?;
+        tx.execute(&set.claim_lock_sql(), ())
+            .await
+            .map_err(DrizzleError::from)?;
         let mut rows = tx
-            .query(&set.applied_names_sql(), ())
+            .query(&set.applied_hashes_sql(), ())
             .await
             .map_err(DrizzleError::from)?;
 
-        let mut applied_names: Vec<String> = Vec::new();
+        let mut applied_rows: Vec<(String, String)> = Vec::new();
         while let Some(row) = rows.next().await.map_err(DrizzleError::from)? {
-            applied_names.push(row.get::<String>(0).map_err(DrizzleError::from)?);
+            applied_rows.push((
+                row.get::<String>(0).map_err(DrizzleError::from)?,
+                row.get::<String>(1).map_err(DrizzleError::from)?,
+            ));
         }
+        set.check_checksums(&applied_rows)
+            .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
+        let applied_names: Vec<&str> = applied_rows.iter().map(|(name, _)| name.as_str()).collect();
 
         let pending: Vec<_> = set.pending(&applied_names).collect();
 
         if pending.is_empty() {
+            if let Some(sql) = set.record_schema_hash_sql() {
+                tx.execute(&sql, ()).await.map_err(DrizzleError::from)?;
+            }
+            tx.execute(&set.release_lock_sql(), ())
+                .await
+                .map_err(DrizzleError::from)?;
             tx.commit().await.map_err(DrizzleError::from)?;
             return Ok(drizzle_migrations::MigrateOutcome::UpToDate);
         }
 
         let mut applied = Vec::with_capacity(pending.len());
         for migration in &pending {
-            for stmt in migration.statements() {
-                if !stmt.trim().is_empty() {
-                    tx.execute(stmt, ()).await.map_err(DrizzleError::from)?;
+            for step in migration.steps() {
+                if step.no_transaction {
+                    // e.g. VACUUM can't run inside a transaction: commit what
+                    // ran so far and pick the lock up again after.
+                    tx.execute(&set.release_lock_sql(), ())
+                        .await
+                        .map_err(DrizzleError::from)?;
+                    tx.commit().await.map_err(DrizzleError::from)?;
+                    self.conn
+                        .execute_batch(step.sql)
+                        .await
+                        .map_err(DrizzleError::from)?;
+                    tx = self
+                        .conn
+                        .transaction_with_behavior(libsql::TransactionBehavior::Immediate)
+                        .await
+                        .map_err(DrizzleError::from)?;
+                    tx.execute(&set.claim_lock_sql(), ())
+                        .await
+                        .map_err(DrizzleError::from)?;
+                } else {
+                    tx.execute(step.sql, ()).await.map_err(DrizzleError::from)?;
                 }
             }
             tx.execute(&set.record_migration_sql(migration), ())
@@ -386,10 +700,59 @@ impl<Schema> common::Drizzle<Connection, Schema> {
             applied.push(migration.tag().to_string());
         }
 
+        if let Some(sql) = set.record_schema_hash_sql() {
+            tx.execute(&sql, ()).await.map_err(DrizzleError::from)?;
+        }
+        tx.execute(&set.release_lock_sql(), ())
+            .await
+            .map_err(DrizzleError::from)?;
         tx.commit().await.map_err(DrizzleError::from)?;
 
         Ok(drizzle_migrations::MigrateOutcome::Applied { tags: applied })
     }
+
+    /// Check that the schema hash recorded by [`migrate`](Self::migrate)
+    /// matches `Schema::SCHEMA_HASH`.
+    ///
+    /// Call it at startup to catch an application deployed against the wrong
+    /// database. Record the hash with [`Tracking::schema_hash`](drizzle_migrations::Tracking::schema_hash).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::Schema`] if no hash was recorded or it differs.
+    pub async fn assert_schema_matches(
+        &self,
+        tracking: drizzle_migrations::Tracking,
+    ) -> drizzle_core::error::Result<()>
+    where
+        Schema: drizzle_core::traits::SQLSchemaImpl,
+    {
+        let set = drizzle_migrations::Migrations::with_tracking(
+            Vec::new(),
+            drizzle_types::Dialect::SQLite,
+            tracking,
+        );
+        let mut tables = self
+            .conn
+            .query(&set.table_exists_sql(), ())
+            .await
+            .map_err(DrizzleError::from)?;
+        let recorded = if tables.next().await.map_err(DrizzleError::from)?.is_some() {
+            let mut rows = self
+                .conn
+                .query(&set.schema_hash_sql(), ())
+                .await
+                .map_err(DrizzleError::from)?;
+            match rows.next().await.map_err(DrizzleError::from)? {
+                Some(row) => Some(row.get::<String>(0).map_err(DrizzleError::from)?),
+                None => None,
+            }
+        } else {
+            None
+        };
+        drizzle_migrations::check_schema_hash(recorded.as_deref(), Schema::SCHEMA_HASH)
+            .map_err(|e| DrizzleError::Schema(e.to_string().into()))
+    }
 }
 
 async fn ensure_sqlite_migration_table(
@@ -483,163 +846,71 @@ async fn ensure_sqlite_migration_table(
     Ok(())
 }
 
-async fn libsql_introspect_query_tables(
-    conn: &libsql::Connection,
-) -> drizzle_core::error::Result<Vec<(String, Option<String>)>> {
-    use drizzle_migrations::sqlite::introspect::queries;
-
-    let mut tables_rows = conn
-        .query(queries::TABLES_QUERY, ())
-        .await
-        .map_err(DrizzleError::from)?;
-
-    let mut tables: Vec<(String, Option<String>)> = Vec::new();
-    while let Some(row) = tables_rows.next().await.map_err(DrizzleError::from)? {
-        let name: String = row.get(0).map_err(DrizzleError::from)?;
-        let sql: Option<String> = row.get(1).ok();
-        tables.push((name, sql));
-    }
-    Ok(tables)
-}
-
-async fn libsql_introspect_query_columns(
-    conn: &libsql::Connection,
-) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::introspect::RawColumnInfo>> {
-    use drizzle_migrations::sqlite::introspect::{RawColumnInfo, queries};
-
-    let mut columns_rows = conn
-        .query(queries::COLUMNS_QUERY, ())
-        .await
-        .map_err(DrizzleError::from)?;
-
-    let mut raw_columns: Vec<RawColumnInfo> = Vec::new();
-    while let Some(row) = columns_rows.next().await.map_err(DrizzleError::from)? {
-        raw_columns.push(RawColumnInfo {
-            table: row.get(0).map_err(DrizzleError::from)?,
-            cid: row.get(1).map_err(DrizzleError::from)?,
-            name: row.get(2).map_err(DrizzleError::from)?,
-            column_type: row.get(3).map_err(DrizzleError::from)?,
-            not_null: row.get::<i32>(4).map_err(DrizzleError::from)? != 0,
-            default_value: row.get(5).ok(),
-            pk: row.get(6).map_err(DrizzleError::from)?,
-            hidden: row.get(7).map_err(DrizzleError::from)?,
-            sql: row.get(8).ok(),
-        });
-    }
-    Ok(raw_columns)
-}
-
-async fn libsql_introspect_query_indexes_and_fks(
-    conn: &libsql::Connection,
-) -> drizzle_core::error::Result<(
-    Vec<drizzle_migrations::sqlite::introspect::RawIndexInfo>,
-    Vec<drizzle_migrations::sqlite::introspect::RawIndexColumn>,
-    Vec<drizzle_migrations::sqlite::introspect::RawForeignKey>,
-)> {
-    use drizzle_migrations::sqlite::introspect::{
-        RawForeignKey, RawIndexColumn, RawIndexInfo, queries,
-    };
-
-    let mut all_indexes = Vec::<RawIndexInfo>::new();
-    let mut index_rows = conn
-        .query(queries::INDEXES_QUERY, ())
-        .await
-        .map_err(DrizzleError::from)?;
-    while let Some(row) = index_rows.next().await.map_err(DrizzleError::from)? {
-        all_indexes.push(RawIndexInfo {
-            table: row.get(0).map_err(DrizzleError::from)?,
-            name: row.get(1).map_err(DrizzleError::from)?,
-            unique: row.get::<i32>(2).map_err(DrizzleError::from)? != 0,
-            origin: row.get(3).map_err(DrizzleError::from)?,
-            partial: row.get::<i32>(4).map_err(DrizzleError::from)? != 0,
-        });
+impl<Schema> common::Drizzle<Connection, Schema> {
+    /// Introspect the live database and return a [`Snapshot`] of its current schema.
+    pub async fn introspect(
+        &self,
+    ) -> drizzle_core::error::Result<drizzle_migrations::schema::Snapshot> {
+        drizzle_migrations::sqlite::SqliteIntrospector::new(&self.conn)
+            .snapshot_async()
+            .await
+            .map(drizzle_migrations::schema::Snapshot::Sqlite)
+            .map_err(|e| DrizzleError::Other(e.to_string().into()))
     }
 
-    let mut all_index_columns = Vec::<RawIndexColumn>::new();
-    let mut column_rows = conn
-        .query(queries::INDEX_COLUMNS_QUERY, ())
-        .await
-        .map_err(DrizzleError::from)?;
-    while let Some(row) = column_rows.next().await.map_err(DrizzleError::from)? {
-        all_index_columns.push(RawIndexColumn {
-            index_name: row.get(0).map_err(DrizzleError::from)?,
-            seqno: row.get(1).map_err(DrizzleError::from)?,
-            cid: row.get(2).map_err(DrizzleError::from)?,
-            name: row.get(3).ok(),
-            desc: row.get::<i32>(4).map_err(DrizzleError::from)? != 0,
-            coll: row.get(5).map_err(DrizzleError::from)?,
-            key: row.get::<i32>(6).map_err(DrizzleError::from)? != 0,
-        });
+    /// Introspect the live database into the same DDL model the migration
+    /// differ works on.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub async fn introspect_ddl(
+        &self,
+    ) -> drizzle_core::error::Result<drizzle_migrations::sqlite::SQLiteDDL> {
+        drizzle_migrations::sqlite::SqliteIntrospector::new(&self.conn)
+            .ddl_async()
+            .await
+            .map_err(|e| DrizzleError::Other(e.to_string().into()))
     }
 
-    let mut all_fks = Vec::<RawForeignKey>::new();
-    let mut foreign_key_rows = conn
-        .query(queries::FOREIGN_KEYS_QUERY, ())
-        .await
-        .map_err(DrizzleError::from)?;
-    while let Some(row) = foreign_key_rows.next().await.map_err(DrizzleError::from)? {
-        all_fks.push(RawForeignKey {
-            table: row.get(0).map_err(DrizzleError::from)?,
-            id: row.get(1).map_err(DrizzleError::from)?,
-            seq: row.get(2).map_err(DrizzleError::from)?,
-            to_table: row.get(3).map_err(DrizzleError::from)?,
-            from_column: row.get(4).map_err(DrizzleError::from)?,
-            to_column: row.get(5).map_err(DrizzleError::from)?,
-            on_update: row.get(6).map_err(DrizzleError::from)?,
-            on_delete: row.get(7).map_err(DrizzleError::from)?,
-            r#match: row.get(8).map_err(DrizzleError::from)?,
-        });
+    /// Tables in the live database, excluding `SQLite` internals and the
+    /// migrations table.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub async fn tables(
+        &self,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::ddl::Table>> {
+        Ok(self.introspect_ddl().await?.tables.into_vec())
     }
 
-    Ok((all_indexes, all_index_columns, all_fks))
-}
-
-async fn libsql_introspect_query_views(
-    conn: &libsql::Connection,
-) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::introspect::RawViewInfo>> {
-    use drizzle_migrations::sqlite::introspect::{RawViewInfo, queries};
-
-    let mut all_views: Vec<RawViewInfo> = Vec::new();
-    let mut views_rows = conn
-        .query(queries::VIEWS_QUERY, ())
-        .await
-        .map_err(DrizzleError::from)?;
-    while let Some(row) = views_rows.next().await.map_err(DrizzleError::from)? {
-        let name: String = row.get(0).map_err(DrizzleError::from)?;
-        let sql: String = row.get(1).map_err(DrizzleError::from)?;
-        all_views.push(RawViewInfo { name, sql });
+    /// Columns of `table` in the live database, in declaration order.
+    ///
+    /// Returns an empty list if the table doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub async fn columns(
+        &self,
+        table: &str,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::ddl::Column>> {
+        let ddl = self.introspect_ddl().await?;
+        Ok(ddl.columns.for_table(table).into_iter().cloned().collect())
     }
-    Ok(all_views)
-}
 
-impl<Schema> common::Drizzle<Connection, Schema> {
-    /// Introspect the live database and return a [`Snapshot`] of its current schema.
-    pub async fn introspect(
+    /// Indexes on `table` in the live database.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub async fn indexes(
         &self,
-    ) -> drizzle_core::error::Result<drizzle_migrations::schema::Snapshot> {
-        let tables = libsql_introspect_query_tables(&self.conn).await?;
-        let raw_columns = libsql_introspect_query_columns(&self.conn).await?;
-        let (all_indexes, all_index_columns, all_fks) =
-            libsql_introspect_query_indexes_and_fks(&self.conn).await?;
-        let all_views = libsql_introspect_query_views(&self.conn).await?;
-
-        let ddl = drizzle_migrations::sqlite::introspect::assemble_ddl(
-            drizzle_migrations::sqlite::introspect::RawIntrospection {
-                tables,
-                columns: raw_columns,
-                indexes: all_indexes,
-                index_columns: all_index_columns,
-                foreign_keys: all_fks,
-                views: all_views,
-            },
-        );
-
-        let mut snapshot = drizzle_migrations::sqlite::SQLiteSnapshot::new();
-        for entity in ddl.to_entities() {
-            snapshot.add_entity(entity);
-        }
-
-        Ok(drizzle_migrations::schema::Snapshot::Sqlite(snapshot))
+        table: &str,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::ddl::Index>> {
+        let ddl = self.introspect_ddl().await?;
+        Ok(ddl.indexes.for_table(table).into_iter().cloned().collect())
     }
 
     /// Introspect the live database, diff against the desired schema, and
@@ -650,16 +921,65 @@ impl<Schema> common::Drizzle<Connection, Schema> {
         &self,
         schema: &S,
     ) -> drizzle_core::error::Result<()> {
-        let live = self.introspect().await?;
+        self.sync_schema_with(
+            schema,
+            drizzle_migrations::SyncOptions::new().allow_destructive(true),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Bring the live database in line with `schema` at startup, applying
+    /// only additive changes (new tables, columns, indexes and views).
+    ///
+    /// Meant for prototypes; use migrations once the schema settles.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::Schema`] without changing anything if the diff
+    /// would drop or rebuild something, or [`DrizzleError`] if introspection
+    /// or a statement fails.
+    pub async fn sync_schema<S: drizzle_migrations::Schema>(
+        &self,
+        schema: &S,
+    ) -> drizzle_core::error::Result<drizzle_migrations::Plan> {
+        self.sync_schema_with(schema, drizzle_migrations::SyncOptions::new())
+            .await
+    }
+
+    /// [`sync_schema`](Self::sync_schema) with explicit options, e.g.
+    /// `SyncOptions::new().allow_destructive(true)`.
+    ///
+    /// Returns the plan that was applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::Schema`] if `options` reject the diff, or
+    /// [`DrizzleError`] if introspection or a statement fails.
+    pub async fn sync_schema_with<S: drizzle_migrations::Schema>(
+        &self,
+        schema: &S,
+        options: drizzle_migrations::SyncOptions,
+    ) -> drizzle_core::error::Result<drizzle_migrations::Plan> {
         let desired = schema.to_snapshot();
-        let generated = drizzle_migrations::diff(&live, &desired)
+        let live = match (self.introspect().await?, &desired) {
+            (
+                drizzle_migrations::schema::Snapshot::Sqlite(live),
+                drizzle_migrations::schema::Snapshot::Sqlite(desired),
+            ) => drizzle_migrations::schema::Snapshot::Sqlite(live.prepare_for_push(desired)),
+            (live, _) => live,
+        };
+        let plan = drizzle_migrations::diff(&live, &desired)
             .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
-        for stmt in generated.statements {
+        options
+            .check(&plan)
+            .map_err(|e| DrizzleError::Schema(e.to_string().into()))?;
+        for stmt in &plan.statements {
             if !stmt.trim().is_empty() {
-                self.conn.execute(&stmt, ()).await?;
+                self.conn.execute(stmt, ()).await?;
             }
         }
-        Ok(())
+        Ok(plan)
     }
 }
 
@@ -1149,6 +1469,18 @@ where
             .with_query(|| QueryContext::new(&sql_str, &params))
     }
 
+    /// Runs the query and returns the affected row count together with the
+    /// rowid of the last inserted row.
+    pub async fn run(self) -> drizzle_core::error::Result<ExecuteResult> {
+        let conn = &self.runner.conn;
+        let rows_affected = self.execute().await?;
+        Ok(ExecuteResult::new(
+            rows_affected,
+            State::INSERTS,
+            conn.last_insert_rowid(),
+        ))
+    }
+
     /// Runs the query and returns all matching rows using the builder's row type.
     pub async fn all<R, Proof, AggProof>(self) -> drizzle_core::error::Result<Vec<R>>
     where
@@ -1176,10 +1508,58 @@ where
             .await
             .with_query(|| QueryContext::new(&sql_str, &params))?
         {
-            decoded.push(<Mk as drizzle_core::row::DecodeSelectedRef<
-                &::libsql::Row,
-                R,
-            >>::decode(&row)?);
+            decoded.push(
+                <Mk as drizzle_core::row::DecodeSelectedRef<&::libsql::Row, R>>::decode(&row)
+                    .map_err(|e| e.at_row(decoded.len()))?,
+            );
+        }
+        drop(rows);
+        self.runner.libsql_statement_cache.store(cached);
+        Ok(decoded)
+    }
+
+    /// Runs the query and deserializes each row into `T` through serde.
+    ///
+    /// Rows are read by column name with
+    /// [`RowDeserializer`](drizzle_sqlite::de::RowDeserializer), so existing
+    /// `Deserialize` types work without also deriving `FromRow`.
+    #[cfg(feature = "serde")]
+    pub async fn all_de<T>(self) -> drizzle_core::error::Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let (sql_str, params) = self.builder.sql.build();
+        let driver_params: Vec<libsql::Value> = params
+            .iter()
+            .copied()
+            .map(std::convert::Into::into)
+            .collect();
+        let (mut rows, cached) = self
+            .runner
+            .libsql_statement_cache
+            .query(&self.runner.conn, &sql_str, driver_params)
+            .await
+            .with_query(|| QueryContext::new(&sql_str, &params))?;
+        let mut columns: Option<Vec<String>> = None;
+        let mut values = Vec::new();
+        let mut decoded = Vec::new();
+        while let Some(row) = rows
+            .next()
+            .await
+            .with_query(|| QueryContext::new(&sql_str, &params))?
+        {
+            let columns = columns.get_or_insert_with(|| {
+                (0..row.column_count())
+                    .map(|idx| row.column_name(idx).unwrap_or_default().to_owned())
+                    .collect()
+            });
+            values.clear();
+            for idx in 0..row.column_count() {
+                values.push(drizzle_sqlite::values::OwnedSQLiteValue::from(
+                    row.get_value(idx)?,
+                ));
+            }
+            decoded.push(drizzle_sqlite::de::from_row(columns, &values)?);
         }
         drop(rows);
         self.runner.libsql_statement_cache.store(cached);
@@ -0,0 +1,219 @@
+//! Opening libsql databases for [`Drizzle::open`](super::Drizzle::open).
+
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use drizzle_core::error::{DrizzleError, Result};
+use libsql::{Builder, Cipher, Connection, Database, EncryptionConfig};
+
+/// Where and how [`Drizzle::open`](super::Drizzle::open) opens a libsql
+/// database.
+///
+/// The encryption key is used as-is for local files and embedded replicas
+/// (encryption at rest, needs the `libsql-encryption` feature). For remote
+/// databases it is the base64 key of a Turso encrypted database, sent with
+/// every request.
+#[derive(Clone)]
+pub struct OpenOptions {
+    target: Target,
+    auth_token: String,
+    encryption_key: Option<String>,
+    sync_interval: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+enum Target {
+    Local(PathBuf),
+    Remote(String),
+    Replica { path: PathBuf, url: String },
+}
+
+impl std::fmt::Debug for OpenOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpenOptions")
+            .field("target", &self.target)
+            .field("auth_token", &"[REDACTED]")
+            .field(
+                "encryption_key",
+                &self.encryption_key.as_ref().map(|_| "[REDACTED]"),
+            )
+            .field("sync_interval", &self.sync_interval)
+            .finish()
+    }
+}
+
+impl OpenOptions {
+    /// A local database file.
+    pub fn local(path: impl Into<PathBuf>) -> Self {
+        Self::new(Target::Local(path.into()), String::new())
+    }
+
+    /// A remote database reached over HTTP, e.g. `libsql://<db>.turso.io`.
+    pub fn remote(url: impl Into<String>, auth_token: impl Into<String>) -> Self {
+        Self::new(Target::Remote(url.into()), auth_token.into())
+    }
+
+    /// An embedded replica: reads from the file at `path`, syncs from and
+    /// forwards writes to `url`.
+    pub fn replica(
+        path: impl Into<PathBuf>,
+        url: impl Into<String>,
+        auth_token: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            Target::Replica {
+                path: path.into(),
+                url: url.into(),
+            },
+            auth_token.into(),
+        )
+    }
+
+    const fn new(target: Target, auth_token: String) -> Self {
+        Self {
+            target,
+            auth_token,
+            encryption_key: None,
+            sync_interval: None,
+        }
+    }
+
+    /// Encrypts the database with `key`.
+    #[must_use]
+    pub fn encryption_key(mut self, key: impl Into<String>) -> Self {
+        self.encryption_key = Some(key.into());
+        self
+    }
+
+    /// Syncs an embedded replica in the background every `interval`.
+    /// Ignored for other targets.
+    #[must_use]
+    pub const fn sync_interval(mut self, interval: Duration) -> Self {
+        self.sync_interval = Some(interval);
+        self
+    }
+}
+
+/// An opened database, kept by `Drizzle` so it can sync replicas and rotate
+/// the auth token.
+#[derive(Clone)]
+pub(crate) struct LibsqlSource {
+    options: OpenOptions,
+    auth_token: Arc<RwLock<String>>,
+    database: Arc<Database>,
+}
+
+impl std::fmt::Debug for LibsqlSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LibsqlSource")
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+impl LibsqlSource {
+    pub(crate) async fn open(options: OpenOptions) -> Result<(Self, Connection)> {
+        let auth_token = Arc::new(RwLock::new(options.auth_token.clone()));
+        let database = build(&options, &auth_token).await?;
+        let conn = database.connect()?;
+        let source = Self {
+            options,
+            auth_token,
+            database: Arc::new(database),
+        };
+        Ok((source, conn))
+    }
+
+    pub(crate) async fn sync(&self) -> Result<()> {
+        match self.options.target {
+            Target::Replica { .. } => {
+                self.database.sync().await?;
+                Ok(())
+            }
+            Target::Local(_) | Target::Remote(_) => Err(DrizzleError::Other(
+                "sync is only available on embedded replicas".into(),
+            )),
+        }
+    }
+
+    /// Swaps in `token`. Replicas pick it up on their next request; remote
+    /// databases are reopened, and the new connection is returned.
+    pub(crate) async fn set_auth_token(&mut self, token: String) -> Result<Option<Connection>> {
+        match self.options.target {
+            Target::Local(_) => Err(DrizzleError::Other(
+                "local libsql databases have no auth token".into(),
+            )),
+            Target::Replica { .. } => {
+                *self
+                    .auth_token
+                    .write()
+                    .unwrap_or_else(|err| err.into_inner()) = token.clone();
+                self.options.auth_token = token;
+                Ok(None)
+            }
+            Target::Remote(_) => {
+                let mut options = self.options.clone();
+                options.auth_token = token;
+                let (source, conn) = Self::open(options).await?;
+                *self = source;
+                Ok(Some(conn))
+            }
+        }
+    }
+}
+
+async fn build(options: &OpenOptions, auth_token: &Arc<RwLock<String>>) -> Result<Database> {
+    let local_encryption = || {
+        options
+            .encryption_key
+            .as_ref()
+            .map(|key| EncryptionConfig::new(Cipher::Aes256Cbc, key.clone().into_bytes().into()))
+    };
+    let remote_encryption = || {
+        options
+            .encryption_key
+            .as_ref()
+            .map(|key| libsql::EncryptionContext {
+                key: libsql::EncryptionKey::Base64Encoded(key.clone()),
+            })
+    };
+
+    let database = match &options.target {
+        Target::Local(path) => {
+            let mut builder = Builder::new_local(path);
+            if let Some(config) = local_encryption() {
+                builder = builder.encryption_config(config);
+            }
+            builder.build().await?
+        }
+        Target::Remote(url) => {
+            let mut builder = Builder::new_remote(url.clone(), options.auth_token.clone());
+            if let Some(context) = remote_encryption() {
+                builder = builder.remote_encryption(context);
+            }
+            builder.build().await?
+        }
+        Target::Replica { path, url } => {
+            // The replicator keeps the token it was built with; overwrite the
+            // header per request so `set_auth_token` takes effect.
+            let token = Arc::clone(auth_token);
+            let mut builder =
+                Builder::new_remote_replica(path, url.clone(), options.auth_token.clone())
+                    .http_request_callback(move |request| {
+                        let token = token.read().unwrap_or_else(|err| err.into_inner());
+                        if let Ok(value) = format!("Bearer {token}").try_into() {
+                            request.headers_mut().insert("authorization", value);
+                        }
+                    });
+            if let Some(config) = local_encryption() {
+                builder = builder.encryption_config(config);
+            }
+            if let Some(interval) = options.sync_interval {
+                builder = builder.sync_interval(interval);
+            }
+            builder.build().await?
+        }
+    };
+    Ok(database)
+}
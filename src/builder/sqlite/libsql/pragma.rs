@@ -0,0 +1,102 @@
+//! Typed results for introspection and maintenance pragmas.
+
+use drizzle_core::error::{DrizzleError, QueryContext, ResultExt};
+use drizzle_core::traits::{SQLTableInfo, ToSQL};
+use drizzle_sqlite::pragma::{
+    ForeignKeyCheckRow, Pragma, TableInfoRow, WalCheckpointMode, WalCheckpointRow,
+    integrity_problems,
+};
+use drizzle_sqlite::traits::DrizzleRowByIndex;
+use drizzle_sqlite::values::SQLiteValue;
+use libsql::Connection;
+
+use crate::builder::sqlite::common;
+
+/// Runs pragmas and decodes their result rows.
+///
+/// Returned by [`Drizzle::pragma`](common::Drizzle::pragma).
+#[derive(Debug, Clone, Copy)]
+pub struct Pragmas<'a> {
+    conn: &'a Connection,
+}
+
+impl<Schema> common::Drizzle<Connection, Schema> {
+    /// Typed helpers for pragmas that return rows.
+    pub const fn pragma(&self) -> Pragmas<'_> {
+        Pragmas { conn: &self.conn }
+    }
+}
+
+impl Pragmas<'_> {
+    /// Columns of `table`, from `PRAGMA table_info`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the pragma fails or a row can't be decoded.
+    pub async fn table_info<T: SQLTableInfo>(
+        &self,
+        table: T,
+    ) -> drizzle_core::error::Result<Vec<TableInfoRow>> {
+        self.rows(&Pragma::TableInfo(table.name()), |row| {
+            TableInfoRow::from_row(row)
+        })
+        .await
+    }
+
+    /// Problems reported by `PRAGMA integrity_check`; empty when the database is healthy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the pragma fails.
+    pub async fn integrity_check(&self) -> drizzle_core::error::Result<Vec<String>> {
+        let messages = self
+            .rows(&Pragma::IntegrityCheck(None), |row| row.get_column(0))
+            .await?;
+        Ok(integrity_problems(messages))
+    }
+
+    /// Rows violating foreign key constraints, from `PRAGMA foreign_key_check`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the pragma fails or a row can't be decoded.
+    pub async fn foreign_key_check(&self) -> drizzle_core::error::Result<Vec<ForeignKeyCheckRow>> {
+        self.rows(&Pragma::ForeignKeyCheck(None), |row| {
+            ForeignKeyCheckRow::from_row(row)
+        })
+        .await
+    }
+
+    /// Checkpoints the WAL file with `mode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the pragma fails or returns no row.
+    pub async fn wal_checkpoint(
+        &self,
+        mode: WalCheckpointMode,
+    ) -> drizzle_core::error::Result<WalCheckpointRow> {
+        self.rows(&Pragma::WalCheckpoint(Some(mode)), |row| {
+            WalCheckpointRow::from_row(row)
+        })
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(DrizzleError::NotFound)
+    }
+
+    async fn rows<T>(
+        &self,
+        pragma: &Pragma,
+        decode: impl Fn(&libsql::Row) -> drizzle_core::error::Result<T>,
+    ) -> drizzle_core::error::Result<Vec<T>> {
+        let sql = ToSQL::<SQLiteValue<'_>>::to_sql(pragma).sql();
+        let ctx = || QueryContext::new::<SQLiteValue<'_>>(&sql, &[]);
+        let mut rows = self.conn.query(&sql, ()).await.with_query(ctx)?;
+        let mut decoded = Vec::new();
+        while let Some(row) = rows.next().await.with_query(ctx)? {
+            decoded.push(decode(&row)?);
+        }
+        Ok(decoded)
+    }
+}
@@ -7,12 +7,35 @@ pub mod turso;
 #[cfg(feature = "libsql")]
 pub mod libsql;
 
+#[cfg(feature = "turso-http")]
+pub mod turso_http;
+
 #[cfg(all(feature = "d1", target_arch = "wasm32"))]
 pub mod d1;
 
 #[cfg(all(feature = "durable", target_arch = "wasm32"))]
 pub mod durable;
 
+#[cfg(any(feature = "rusqlite", feature = "turso", feature = "libsql"))]
+pub(crate) mod attach;
 pub mod common;
+#[cfg(any(
+    feature = "rusqlite",
+    feature = "turso",
+    feature = "libsql",
+    feature = "turso-http",
+    all(feature = "d1", target_arch = "wasm32"),
+    all(feature = "durable", target_arch = "wasm32")
+))]
+pub(crate) mod ddl;
 pub mod prepared_common;
 pub mod rows;
+#[cfg(any(
+    feature = "rusqlite",
+    feature = "turso",
+    feature = "libsql",
+    feature = "turso-http",
+    all(feature = "d1", target_arch = "wasm32"),
+    all(feature = "durable", target_arch = "wasm32")
+))]
+pub(crate) mod truncate;
@@ -5,13 +5,10 @@ macro_rules! sqlite_async_prepared_impl {
             pub async fn execute<const N: usize>(
                 &self,
                 conn: &impl $executor,
-                params: [drizzle_core::param::ParamBind<
-                    'a,
-                    drizzle_sqlite::values::SQLiteValue<'a>,
-                >; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_sqlite::values::SQLiteValue<'a>, N>>,
             ) -> drizzle_core::error::Result<u64> {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
-                let (sql_str, params) = self.inner.bind(params)?;
+                let (sql_str, params) = self.inner.bind(params.into())?;
                 let mut driver_params = Vec::with_capacity(self.inner.params.len());
                 driver_params.extend(params.map(Into::into));
 
@@ -22,16 +19,13 @@ macro_rules! sqlite_async_prepared_impl {
             pub async fn all<T, const N: usize>(
                 &self,
                 conn: &impl $executor,
-                params: [drizzle_core::param::ParamBind<
-                    'a,
-                    drizzle_sqlite::values::SQLiteValue<'a>,
-                >; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_sqlite::values::SQLiteValue<'a>, N>>,
             ) -> drizzle_core::error::Result<Vec<T>>
             where
                 for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r $row, T>,
             {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
-                let (sql_str, params) = self.inner.bind(params)?;
+                let (sql_str, params) = self.inner.bind(params.into())?;
                 let mut driver_params = Vec::with_capacity(self.inner.params.len());
                 driver_params.extend(params.map(Into::into));
 
@@ -42,7 +36,8 @@ macro_rules! sqlite_async_prepared_impl {
                     let converted = <Marker as drizzle_core::row::DecodeSelectedRef<
                         &$row,
                         T,
-                    >>::decode(&row)?;
+                    >>::decode(&row)
+                        .map_err(|e| e.at_row(results.len()))?;
                     results.push(converted);
                 }
 
@@ -53,16 +48,13 @@ macro_rules! sqlite_async_prepared_impl {
             pub async fn get<T, const N: usize>(
                 &self,
                 conn: &impl $executor,
-                params: [drizzle_core::param::ParamBind<
-                    'a,
-                    drizzle_sqlite::values::SQLiteValue<'a>,
-                >; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_sqlite::values::SQLiteValue<'a>, N>>,
             ) -> drizzle_core::error::Result<T>
             where
                 for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r $row, T>,
             {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
-                let (sql_str, params) = self.inner.bind(params)?;
+                let (sql_str, params) = self.inner.bind(params.into())?;
                 let mut driver_params = Vec::with_capacity(self.inner.params.len());
                 driver_params.extend(params.map(Into::into));
                 let mut rows = conn.fetch(sql_str, driver_params).await?;
@@ -80,13 +72,10 @@ macro_rules! sqlite_async_prepared_impl {
             pub async fn execute<'a, const N: usize>(
                 &self,
                 conn: &impl $executor,
-                params: [drizzle_core::param::ParamBind<
-                    'a,
-                    drizzle_sqlite::values::SQLiteValue<'a>,
-                >; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_sqlite::values::SQLiteValue<'a>, N>>,
             ) -> drizzle_core::error::Result<u64> {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
-                let (sql_str, params) = self.inner.bind(params)?;
+                let (sql_str, params) = self.inner.bind(params.into())?;
                 let mut driver_params = Vec::with_capacity(self.inner.params.len());
                 driver_params.extend(params.map(Into::into));
 
@@ -97,16 +86,13 @@ macro_rules! sqlite_async_prepared_impl {
             pub async fn all<'a, T, const N: usize>(
                 &self,
                 conn: &impl $executor,
-                params: [drizzle_core::param::ParamBind<
-                    'a,
-                    drizzle_sqlite::values::SQLiteValue<'a>,
-                >; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_sqlite::values::SQLiteValue<'a>, N>>,
             ) -> drizzle_core::error::Result<Vec<T>>
             where
                 for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r $row, T>,
             {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
-                let (sql_str, params) = self.inner.bind(params)?;
+                let (sql_str, params) = self.inner.bind(params.into())?;
                 let mut driver_params = Vec::with_capacity(self.inner.params.len());
                 driver_params.extend(params.map(Into::into));
                 let mut rows = conn.fetch(sql_str, driver_params).await?;
@@ -116,7 +102,8 @@ macro_rules! sqlite_async_prepared_impl {
                     let converted = <Marker as drizzle_core::row::DecodeSelectedRef<
                         &$row,
                         T,
-                    >>::decode(&row)?;
+                    >>::decode(&row)
+                        .map_err(|e| e.at_row(results.len()))?;
                     results.push(converted);
                 }
 
@@ -127,16 +114,13 @@ macro_rules! sqlite_async_prepared_impl {
             pub async fn get<'a, T, const N: usize>(
                 &self,
                 conn: &impl $executor,
-                params: [drizzle_core::param::ParamBind<
-                    'a,
-                    drizzle_sqlite::values::SQLiteValue<'a>,
-                >; N],
+                params: impl Into<drizzle_core::param::ParamSet<'a, drizzle_sqlite::values::SQLiteValue<'a>, N>>,
             ) -> drizzle_core::error::Result<T>
             where
                 for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r $row, T>,
             {
                 debug_assert_eq!(N, self.inner.external_param_count(), "parameter count mismatch: expected {} params but got {}", self.inner.external_param_count(), N);
-                let (sql_str, params) = self.inner.bind(params)?;
+                let (sql_str, params) = self.inner.bind(params.into())?;
                 let mut driver_params = Vec::with_capacity(self.inner.params.len());
                 driver_params.extend(params.map(Into::into));
                 let mut rows = conn.fetch(sql_str, driver_params).await?;
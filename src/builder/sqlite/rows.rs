@@ -39,6 +39,7 @@ impl<R> ExactSizeIterator for Rows<R> {}
 #[cfg(feature = "libsql")]
 pub struct LibsqlRows<R> {
     rows: libsql::Rows,
+    position: usize,
     _marker: core::marker::PhantomData<R>,
 }
 
@@ -51,6 +52,7 @@ where
     pub(crate) const fn new(rows: libsql::Rows) -> Self {
         Self {
             rows,
+            position: 0,
             _marker: core::marker::PhantomData,
         }
     }
@@ -62,7 +64,13 @@ where
             .await
             .map_err(drizzle_core::error::DrizzleError::from)?
         {
-            Some(row) => Ok(Some(R::try_from(&row).map_err(Into::into)?)),
+            Some(row) => {
+                let position = self.position;
+                self.position += 1;
+                Ok(Some(
+                    R::try_from(&row).map_err(|e| e.into().at_row(position))?,
+                ))
+            }
             None => Ok(None),
         }
     }
@@ -83,6 +91,7 @@ where
 pub struct TursoRows<R> {
     rows: turso::Rows,
     sql: Option<Box<str>>,
+    position: usize,
     _marker: core::marker::PhantomData<R>,
 }
 
@@ -96,6 +105,7 @@ where
         Self {
             rows,
             sql: None,
+            position: 0,
             _marker: core::marker::PhantomData,
         }
     }
@@ -104,6 +114,7 @@ where
         Self {
             rows,
             sql: Some(sql.into()),
+            position: 0,
             _marker: core::marker::PhantomData,
         }
     }
@@ -125,7 +136,13 @@ where
         })?;
 
         match row {
-            Some(row) => Ok(Some(R::try_from(&row).map_err(Into::into)?)),
+            Some(row) => {
+                let position = self.position;
+                self.position += 1;
+                Ok(Some(
+                    R::try_from(&row).map_err(|e| e.into().at_row(position))?,
+                ))
+            }
             None => Ok(None),
         }
     }
@@ -122,6 +122,7 @@
 //! # Ok(()) }
 //! ```
 
+mod pragma;
 mod prepared;
 
 use drizzle_core::error::{DrizzleError, QueryContext, ResultExt};
@@ -132,19 +133,42 @@ use rusqlite::{Connection, params_from_iter};
 
 use drizzle_sqlite::{
     builder::{self, QueryBuilder},
-    connection::SQLiteTransactionType,
+    connection::{ExecuteResult, SQLiteTransactionType},
 };
 
+use crate::ReadOnly;
 use crate::builder::sqlite::common;
 use crate::builder::sqlite::rows::Rows;
 use crate::transaction::sqlite::rusqlite::Transaction;
 
+pub use pragma::Pragmas;
+
 pub type Drizzle<Schema = ()> = common::Drizzle<Connection, Schema>;
 pub type DrizzleBuilder<'a, Schema, Builder, State> =
     common::DrizzleBuilder<'a, common::Drizzle<Connection, Schema>, Schema, Builder, State>;
 
 crate::drizzle_prepare_impl!();
 
+/// Cloneable handle that interrupts a rusqlite connection's running statement.
+///
+/// Returned by [`Drizzle::interrupt_handle`]. The interrupted statement fails
+/// with [`DrizzleError::Timeout`]; later statements run normally.
+#[derive(Clone)]
+pub struct InterruptHandle(std::sync::Arc<rusqlite::InterruptHandle>);
+
+impl std::fmt::Debug for InterruptHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InterruptHandle").finish_non_exhaustive()
+    }
+}
+
+impl InterruptHandle {
+    /// Interrupts the statement running on the connection, if any.
+    pub fn interrupt(&self) {
+        self.0.interrupt();
+    }
+}
+
 impl<Schema> common::Drizzle<Connection, Schema> {
     pub fn execute<'a, T>(&'a self, query: T) -> rusqlite::Result<usize>
     where
@@ -195,9 +219,12 @@ impl<Schema> common::Drizzle<Connection, Schema> {
             .prepare(&sql_str)
             .with_query(|| QueryContext::new(&sql_str, &params))?;
 
+        let mut index = 0;
         let mut rows = stmt
             .query_and_then(params_from_iter(params.iter().copied()), |row| {
-                R::try_from(row).map_err(Into::into)
+                let decoded = R::try_from(row).map_err(|e| e.into().at_row(index));
+                index += 1;
+                decoded
             })
             .with_query(|| QueryContext::new(&sql_str, &params))?;
 
@@ -237,6 +264,198 @@ impl<Schema> common::Drizzle<Connection, Schema> {
         .with_query(|| QueryContext::new(&sql_str, &params))?
     }
 
+    /// Loads the rows whose `column` matches each of `keys`, in key order.
+    ///
+    /// Keys are sent in `IN (...)` chunks of at most
+    /// [`SQLITE_MAX_BATCH_KEYS`](drizzle_core::batch::SQLITE_MAX_BATCH_KEYS).
+    /// `key_of` reads the key back off a row; keys without a row yield `None`.
+    ///
+    /// ```no_run
+    /// # use drizzle::sqlite::rusqlite::Drizzle;
+    /// # use drizzle::sqlite::prelude::*;
+    /// # #[SQLiteTable] struct User { #[column(primary)] id: i32, name: String }
+    /// # #[derive(SQLiteSchema)] struct S { user: User }
+    /// # fn main() -> drizzle::Result<()> {
+    /// # let conn = ::rusqlite::Connection::open_in_memory()?;
+    /// # let (db, S { user, .. }) = Drizzle::new(conn, S::new());
+    /// let users: Vec<Option<SelectUser>> =
+    ///     db.get_many(user, user.id, &[3, 1, 2], |u: &SelectUser| u.id)?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_many<'a, T, C, K, R>(
+        &'a self,
+        table: T,
+        column: C,
+        keys: &'a [K],
+        key_of: impl Fn(&R) -> K,
+    ) -> drizzle_core::error::Result<Vec<Option<R>>>
+    where
+        T: ToSQL<'a, SQLiteValue<'a>> + Clone,
+        C: drizzle_core::expr::Expr<'a, SQLiteValue<'a>> + Clone,
+        K: std::hash::Hash + Eq,
+        &'a K: drizzle_core::expr::Expr<'a, SQLiteValue<'a>>,
+        C::SQLType: drizzle_core::types::Compatible<
+                <&'a K as drizzle_core::expr::Expr<'a, SQLiteValue<'a>>>::SQLType,
+            >,
+        R: for<'r> TryFrom<&'r ::rusqlite::Row<'r>> + Clone,
+        for<'r> <R as TryFrom<&'r ::rusqlite::Row<'r>>>::Error:
+            Into<drizzle_core::error::DrizzleError>,
+    {
+        let mut rows: Vec<R> = Vec::with_capacity(keys.len());
+        for chunk in keys.chunks(drizzle_core::batch::SQLITE_MAX_BATCH_KEYS) {
+            let query = common::get_many_sql(table.clone(), column.clone(), chunk);
+            rows.extend(self.all::<_, R, Vec<R>>(query)?);
+        }
+        Ok(drizzle_core::batch::order_by_keys(keys, rows, key_of))
+    }
+
+    /// Returns a handle that can interrupt this connection's running statement
+    /// from another thread.
+    ///
+    /// ```no_run
+    /// # use drizzle::sqlite::rusqlite::Drizzle;
+    /// # fn main() -> drizzle::Result<()> {
+    /// # let conn = rusqlite::Connection::open_in_memory()?;
+    /// # let (db, ()) = Drizzle::new(conn, ());
+    /// let cancel = db.interrupt_handle();
+    /// std::thread::spawn(move || {
+    ///     // e.g. when the user presses "Cancel"
+    ///     cancel.interrupt();
+    /// });
+    /// # Ok(()) }
+    /// ```
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        InterruptHandle(std::sync::Arc::new(self.conn.get_interrupt_handle()))
+    }
+
+    /// Runs `f`, interrupting any statement still running after `timeout`.
+    ///
+    /// The deadline covers everything `f` does, not each statement. A query
+    /// that runs past it fails with [`DrizzleError::Timeout`].
+    ///
+    /// ```no_run
+    /// # use drizzle::sqlite::prelude::*;
+    /// # use drizzle::sqlite::rusqlite::Drizzle;
+    /// # use std::time::Duration;
+    /// # #[SQLiteTable] struct User { #[column(primary)] id: i32, name: String }
+    /// # #[derive(SQLiteSchema)] struct Schema { user: User }
+    /// # fn main() -> drizzle::Result<()> {
+    /// # let conn = rusqlite::Connection::open_in_memory()?;
+    /// # let (db, Schema { user }) = Drizzle::new(conn, Schema::new());
+    /// let users: Vec<SelectUser> =
+    ///     db.with_timeout(Duration::from_secs(2), |db| db.select(()).from(user).all())?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `f`.
+    pub fn with_timeout<R>(
+        &self,
+        timeout: std::time::Duration,
+        f: impl FnOnce(&Self) -> drizzle_core::error::Result<R>,
+    ) -> drizzle_core::error::Result<R> {
+        /// Removes the progress handler even if `f` panics.
+        struct ClearHandler<'c>(&'c Connection);
+        impl Drop for ClearHandler<'_> {
+            fn drop(&mut self) {
+                let _ = self.0.progress_handler(0, None::<fn() -> bool>);
+            }
+        }
+
+        let deadline = std::time::Instant::now() + timeout;
+        // Checked every 1000 VM instructions.
+        let _ = self
+            .conn
+            .progress_handler(1000, Some(move || std::time::Instant::now() >= deadline));
+        let _clear = ClearHandler(&self.conn);
+        f(self)
+    }
+
+    /// Registers a scalar SQL function on this connection.
+    ///
+    /// `n_arg` is the number of arguments (`-1` for any). Call the function
+    /// from the builder with [`call`](drizzle_core::expr::call). Functions
+    /// belong to the connection, so register them again on every connection
+    /// that needs them.
+    ///
+    /// ```no_run
+    /// # use drizzle::sqlite::rusqlite::Drizzle;
+    /// # use drizzle::sqlite::prelude::*;
+    /// # use drizzle::core::expr::call;
+    /// # use rusqlite::functions::FunctionFlags;
+    /// # #[SQLiteTable] struct User { #[column(primary)] id: i32, name: String }
+    /// # #[derive(SQLiteSchema)] struct S { user: User }
+    /// # fn main() -> drizzle::Result<()> {
+    /// # let conn = ::rusqlite::Connection::open_in_memory()?;
+    /// # let (db, S { user, .. }) = Drizzle::new(conn, S::new());
+    /// db.create_scalar_function(
+    ///     "slugify",
+    ///     1,
+    ///     FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+    ///     |ctx| Ok(ctx.get::<String>(0)?.to_lowercase().replace(' ', "-")),
+    /// )?;
+    ///
+    /// let slugs: Vec<Option<String>> = db
+    ///     .select(call::<_, drizzle::sqlite::types::Text, _, _>("slugify", user.name))
+    ///     .from(user)
+    ///     .all()?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if SQLite rejects the registration.
+    pub fn create_scalar_function<F, T>(
+        &self,
+        name: &str,
+        n_arg: i32,
+        flags: rusqlite::functions::FunctionFlags,
+        f: F,
+    ) -> drizzle_core::error::Result<()>
+    where
+        F: Fn(&rusqlite::functions::Context<'_>) -> rusqlite::Result<T> + Send + 'static,
+        T: rusqlite::functions::SqlFnOutput,
+    {
+        Ok(self.conn.create_scalar_function(name, n_arg, flags, f)?)
+    }
+
+    /// Registers an aggregate SQL function on this connection.
+    ///
+    /// Call it from the builder with
+    /// [`call_agg`](drizzle_core::expr::call_agg).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if SQLite rejects the registration.
+    pub fn create_aggregate_function<A, D, T>(
+        &self,
+        name: &str,
+        n_arg: i32,
+        flags: rusqlite::functions::FunctionFlags,
+        aggregate: D,
+    ) -> drizzle_core::error::Result<()>
+    where
+        A: std::panic::RefUnwindSafe + std::panic::UnwindSafe,
+        D: rusqlite::functions::Aggregate<A, T> + 'static,
+        T: rusqlite::functions::SqlFnOutput,
+    {
+        Ok(self
+            .conn
+            .create_aggregate_function(name, n_arg, flags, aggregate)?)
+    }
+
+    /// Removes a function registered with
+    /// [`create_scalar_function`](Self::create_scalar_function) or
+    /// [`create_aggregate_function`](Self::create_aggregate_function).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no function with this name and `n_arg` exists.
+    pub fn remove_function(&self, name: &str, n_arg: i32) -> drizzle_core::error::Result<()> {
+        Ok(self.conn.remove_function(name, n_arg)?)
+    }
+
     /// Executes a transaction with the given callback.
     ///
     /// Returns the value produced by the callback on success. The transaction
@@ -306,7 +525,28 @@ where
     /// Create schema objects from `SQLSchemaImpl`.
     pub fn create(&self) -> drizzle_core::error::Result<()> {
         let schema = Schema::default();
-        let statements: Vec<_> = schema.create_statements()?.collect();
+        self.run_ddl(schema.create_statements()?)
+    }
+
+    /// Like [`create`](Self::create), but objects that already exist are
+    /// left alone, so it is safe to call on every startup.
+    pub fn create_if_not_exists(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.create_statements()?.map(super::ddl::if_not_exists))
+    }
+
+    /// Drop every view, index and table of the schema, the inverse of
+    /// [`create`](Self::create). Tables are dropped referencing tables first.
+    pub fn drop(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.drop_statements()?)
+    }
+
+    fn run_ddl(
+        &self,
+        statements: impl IntoIterator<Item = String>,
+    ) -> drizzle_core::error::Result<()> {
+        let statements: Vec<_> = statements.into_iter().collect();
         if !statements.is_empty() {
             let batch_sql = statements.join(";");
             self.conn.execute_batch(&batch_sql)?;
@@ -315,10 +555,160 @@ where
     }
 }
 
+impl<Schema> common::Drizzle<Connection, Schema> {
+    /// Delete every row of `tables` and reset their `AUTOINCREMENT` counters.
+    ///
+    /// Tables that reference others are emptied first, so a parent can be
+    /// truncated together with its children: `db.truncate((users, posts))`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the tables reference each other in a cycle
+    /// or a `DELETE` fails, e.g. on a foreign key from a table left out.
+    pub fn truncate(
+        &self,
+        tables: impl drizzle_core::traits::TableSet,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(&tables.table_refs())
+    }
+
+    /// Truncate every table of `schema`, e.g. before seeding or between tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the tables reference each other in a cycle
+    /// or a `DELETE` fails.
+    pub fn reset<S: drizzle_core::traits::SQLSchemaImpl>(
+        &self,
+        schema: &S,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(schema.table_refs())
+    }
+
+    fn truncate_tables(
+        &self,
+        tables: &[&'static drizzle_core::TableRef],
+    ) -> drizzle_core::error::Result<()> {
+        let statements = super::truncate::truncate_statements(tables)?;
+        if !statements.is_empty() {
+            self.conn.execute_batch(&statements.join(";"))?;
+        }
+        Ok(())
+    }
+}
+
+impl<Schema> common::Drizzle<Connection, Schema> {
+    /// Attaches the database file at `path` to this connection as `name`.
+    ///
+    /// Tables declared with `#[SQLiteTable(DATABASE = "name")]` then resolve
+    /// to it, so they can be joined with, or copied into from, tables in the
+    /// main database. `path` is created if it does not exist; `":memory:"`
+    /// attaches an empty in-memory database.
+    ///
+    /// ```no_run
+    /// # use drizzle::sqlite::rusqlite::Drizzle;
+    /// # use drizzle::sqlite::prelude::*;
+    /// # #[SQLiteTable] struct Order { #[column(primary)] id: i32, total: f64 }
+    /// # #[SQLiteTable(DATABASE = "archive")] struct ArchivedOrder { #[column(primary)] id: i32, total: f64 }
+    /// # #[derive(SQLiteSchema)] struct S { order: Order, archived_order: ArchivedOrder }
+    /// # fn main() -> drizzle::Result<()> {
+    /// # let conn = ::rusqlite::Connection::open_in_memory()?;
+    /// # let (db, S { order, archived_order }) = Drizzle::new(conn, S::new());
+    /// db.attach("archive.db", "archive")?;
+    ///
+    /// // INSERT INTO "archive"."archived_order" SELECT ... FROM "order"
+    /// db.insert(archived_order)
+    ///     .select(db.select((order.id, order.total)).from(order))
+    ///     .execute()?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or `name` is already in
+    /// use. `SQLite` cannot attach inside a transaction.
+    pub fn attach(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        name: &str,
+    ) -> drizzle_core::error::Result<()> {
+        let path = super::attach::path_param(path.as_ref())?;
+        self.conn.execute(&super::attach::attach_sql(name), [path])?;
+        Ok(())
+    }
+
+    /// Detaches the database attached as `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no database is attached as `name`.
+    pub fn detach(&self, name: &str) -> drizzle_core::error::Result<()> {
+        self.conn.execute(&super::attach::detach_sql(name), [])?;
+        Ok(())
+    }
+}
+
+impl common::Drizzle<Connection> {
+    /// Opens the database file at `path` read-only and wraps it in a
+    /// [`ReadOnly`] handle.
+    ///
+    /// The file is opened with `SQLITE_OPEN_READ_ONLY`, so it must already
+    /// exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the file cannot be opened.
+    pub fn open_read_only<S: Copy>(
+        path: impl AsRef<std::path::Path>,
+        schema: S,
+    ) -> drizzle_core::error::Result<(ReadOnly<common::Drizzle<Connection, S>>, S)> {
+        let flags = rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY
+            | rusqlite::OpenFlags::SQLITE_OPEN_URI
+            | rusqlite::OpenFlags::SQLITE_OPEN_NO_MUTEX;
+        let conn = Connection::open_with_flags(path, flags)?;
+        let (db, schema) = Self::new(conn, schema);
+        Ok((db.read_only()?, schema))
+    }
+}
+
+impl<Schema> common::Drizzle<Connection, Schema> {
+    /// Switches the connection to read-only and returns a handle without
+    /// write builders.
+    ///
+    /// Sets `PRAGMA query_only`, so raw writes through [`ReadOnly::conn`] fail
+    /// with `SQLITE_READONLY` as well.
+    ///
+    /// ```no_run
+    /// # use drizzle::sqlite::rusqlite::Drizzle;
+    /// # use drizzle::sqlite::prelude::*;
+    /// # #[SQLiteTable] struct User { #[column(primary)] id: i32, name: String }
+    /// # #[derive(SQLiteSchema)] struct S { user: User }
+    /// # fn main() -> drizzle::Result<()> {
+    /// # let conn = ::rusqlite::Connection::open_in_memory()?;
+    /// let (db, S { user }) = Drizzle::new(conn, S::new());
+    /// let replica = db.read_only()?;
+    ///
+    /// let users: Vec<SelectUser> = replica.select(()).from(user).all()?;
+    /// // replica.insert(user) does not compile
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the pragma cannot be set.
+    pub fn read_only(self) -> drizzle_core::error::Result<ReadOnly<Self>> {
+        self.conn.execute_batch("PRAGMA query_only = ON")?;
+        Ok(ReadOnly(self))
+    }
+}
+
 impl<Schema> common::Drizzle<Connection, Schema> {
     /// Apply pending migrations from an embedded migration slice.
     ///
     /// Creates the migrations table if needed and runs pending migrations in a transaction.
+    /// Concurrent runners are serialized by `BEGIN IMMEDIATE` plus a lock marker row:
+    /// one applies the pending migrations while the others wait, then find nothing to do.
+    /// Statements marked `-- drizzle:no-transaction` run between two transactions.
     pub fn migrate(
         &self,
         migrations: &[drizzle_migrations::Migration],
@@ -335,9 +725,16 @@ impl<Schema> common::Drizzle<Connection, Schema> {
         self.conn.execute("BEGIN IMMEDIATE", [])?;
 
         let result = (|| -> drizzle_core::error::Result<drizzle_migrations::MigrateOutcome> {
-            let mut statement = self.conn.prepare(&set.applied_names_sql())?;
-            let rows = statement.query_map([], |row| row.get::<_, String>(0))?;
-            let applied_names = rows.collect::<Result<Vec<_>, _>>()?;
+            self.conn.execute(&set.claim_lock_sql(), [])?;
+            let mut statement = self.conn.prepare(&set.applied_hashes_sql())?;
+            let rows = statement.query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?;
+            let applied_rows = rows.collect::<Result<Vec<_>, _>>()?;
+            set.check_checksums(&applied_rows)
+                .map_err(|e| drizzle_core::error::DrizzleError::Other(e.to_string().into()))?;
+            let applied_names: Vec<&str> =
+                applied_rows.iter().map(|(name, _)| name.as_str()).collect();
             let pending: Vec<_> = set.pending(&applied_names).collect();
             if pending.is_empty() {
                 return Ok(drizzle_migrations::MigrateOutcome::UpToDate);
@@ -345,9 +742,17 @@ impl<Schema> common::Drizzle<Connection, Schema> {
 
             let mut applied = Vec::with_capacity(pending.len());
             for migration in &pending {
-                for stmt in migration.statements() {
-                    if !stmt.trim().is_empty() {
-                        self.conn.execute(stmt, [])?;
+                for step in migration.steps() {
+                    if step.no_transaction {
+                        // e.g. VACUUM can't run inside a transaction: commit
+                        // what ran so far and pick the lock up again after.
+                        self.conn.execute(&set.release_lock_sql(), [])?;
+                        self.conn.execute("COMMIT", [])?;
+                        self.conn.execute_batch(step.sql)?;
+                        self.conn.execute("BEGIN IMMEDIATE", [])?;
+                        self.conn.execute(&set.claim_lock_sql(), [])?;
+                    } else {
+                        self.conn.execute(step.sql, [])?;
                     }
                 }
                 self.conn
@@ -355,7 +760,14 @@ impl<Schema> common::Drizzle<Connection, Schema> {
                 applied.push(migration.tag().to_string());
             }
             Ok(drizzle_migrations::MigrateOutcome::Applied { tags: applied })
-        })();
+        })()
+        .and_then(|outcome| {
+            if let Some(sql) = set.record_schema_hash_sql() {
+                self.conn.execute(&sql, [])?;
+            }
+            self.conn.execute(&set.release_lock_sql(), [])?;
+            Ok(outcome)
+        });
 
         match result {
             Ok(outcome) => {
@@ -368,6 +780,38 @@ impl<Schema> common::Drizzle<Connection, Schema> {
             }
         }
     }
+
+    /// Check that the schema hash recorded by [`migrate`](Self::migrate)
+    /// matches `Schema::SCHEMA_HASH`.
+    ///
+    /// Call it at startup to catch an application deployed against the wrong
+    /// database. Record the hash with [`Tracking::schema_hash`](drizzle_migrations::Tracking::schema_hash).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::Schema`] if no hash was recorded or it differs.
+    pub fn assert_schema_matches(
+        &self,
+        tracking: drizzle_migrations::Tracking,
+    ) -> drizzle_core::error::Result<()>
+    where
+        Schema: drizzle_core::traits::SQLSchemaImpl,
+    {
+        let set = drizzle_migrations::Migrations::with_tracking(
+            Vec::new(),
+            drizzle_types::Dialect::SQLite,
+            tracking,
+        );
+        let recorded: Option<String> = if self.conn.prepare(&set.table_exists_sql())?.exists([])? {
+            let mut statement = self.conn.prepare(&set.schema_hash_sql())?;
+            let mut rows = statement.query([])?;
+            rows.next()?.map(|row| row.get(0)).transpose()?
+        } else {
+            None
+        };
+        drizzle_migrations::check_schema_hash(recorded.as_deref(), Schema::SCHEMA_HASH)
+            .map_err(|e| DrizzleError::Schema(e.to_string().into()))
+    }
 }
 
 fn ensure_sqlite_migration_table(
@@ -456,145 +900,70 @@ fn ensure_sqlite_migration_table(
     }
 }
 
-fn introspect_query_tables(
-    conn: &rusqlite::Connection,
-) -> drizzle_core::error::Result<Vec<(String, Option<String>)>> {
-    use drizzle_migrations::sqlite::introspect::queries;
-    let mut tables_stmt = conn.prepare(queries::TABLES_QUERY)?;
-    let tables: Vec<(String, Option<String>)> = tables_stmt
-        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
-        .collect::<Result<Vec<_>, _>>()?;
-    Ok(tables)
-}
-
-fn introspect_query_columns(
-    conn: &rusqlite::Connection,
-) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::introspect::RawColumnInfo>> {
-    use drizzle_migrations::sqlite::introspect::{RawColumnInfo, queries};
-    let mut columns_stmt = conn.prepare(queries::COLUMNS_QUERY)?;
-    let raw_columns: Vec<RawColumnInfo> = columns_stmt
-        .query_map([], |row| {
-            Ok(RawColumnInfo {
-                table: row.get(0)?,
-                cid: row.get(1)?,
-                name: row.get(2)?,
-                column_type: row.get(3)?,
-                not_null: row.get(4)?,
-                default_value: row.get(5)?,
-                pk: row.get(6)?,
-                hidden: row.get(7)?,
-                sql: row.get(8)?,
-            })
-        })?
-        .collect::<Result<Vec<_>, _>>()?;
-    Ok(raw_columns)
-}
-
-fn introspect_query_indexes_and_fks(
-    conn: &rusqlite::Connection,
-) -> drizzle_core::error::Result<(
-    Vec<drizzle_migrations::sqlite::introspect::RawIndexInfo>,
-    Vec<drizzle_migrations::sqlite::introspect::RawIndexColumn>,
-    Vec<drizzle_migrations::sqlite::introspect::RawForeignKey>,
-)> {
-    use drizzle_migrations::sqlite::introspect::{
-        RawForeignKey, RawIndexColumn, RawIndexInfo, queries,
-    };
-
-    let mut index_stmt = conn.prepare(queries::INDEXES_QUERY)?;
-    let all_indexes = index_stmt
-        .query_map([], |row| {
-            Ok(RawIndexInfo {
-                table: row.get(0)?,
-                name: row.get(1)?,
-                unique: row.get::<_, i32>(2)? != 0,
-                origin: row.get(3)?,
-                partial: row.get::<_, i32>(4)? != 0,
-            })
-        })?
-        .collect::<Result<Vec<_>, _>>()?;
-
-    let mut index_columns_stmt = conn.prepare(queries::INDEX_COLUMNS_QUERY)?;
-    let all_index_columns = index_columns_stmt
-        .query_map([], |row| {
-            Ok(RawIndexColumn {
-                index_name: row.get(0)?,
-                seqno: row.get(1)?,
-                cid: row.get(2)?,
-                name: row.get(3)?,
-                desc: row.get::<_, i32>(4)? != 0,
-                coll: row.get(5)?,
-                key: row.get::<_, i32>(6)? != 0,
-            })
-        })?
-        .collect::<Result<Vec<_>, _>>()?;
-
-    let mut foreign_keys_stmt = conn.prepare(queries::FOREIGN_KEYS_QUERY)?;
-    let all_fks = foreign_keys_stmt
-        .query_map([], |row| {
-            Ok(RawForeignKey {
-                table: row.get(0)?,
-                id: row.get(1)?,
-                seq: row.get(2)?,
-                to_table: row.get(3)?,
-                from_column: row.get(4)?,
-                to_column: row.get(5)?,
-                on_update: row.get(6)?,
-                on_delete: row.get(7)?,
-                r#match: row.get(8)?,
-            })
-        })?
-        .collect::<Result<Vec<_>, _>>()?;
-
-    Ok((all_indexes, all_index_columns, all_fks))
-}
-
-fn introspect_query_views(
-    conn: &rusqlite::Connection,
-) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::introspect::RawViewInfo>> {
-    use drizzle_migrations::sqlite::introspect::{RawViewInfo, queries};
-
-    let mut all_views: Vec<RawViewInfo> = Vec::new();
-    let mut views_stmt = conn.prepare(queries::VIEWS_QUERY)?;
-    let view_iter = views_stmt.query_map([], |row| {
-        Ok(RawViewInfo {
-            name: row.get(0)?,
-            sql: row.get(1)?,
-        })
-    })?;
-    all_views.extend(view_iter.collect::<Result<Vec<_>, _>>()?);
-    Ok(all_views)
-}
-
 impl<Schema> common::Drizzle<Connection, Schema> {
     /// Introspect the live database and return a [`Snapshot`] of its current schema.
     ///
     /// This queries `sqlite_master` and the various PRAGMAs to reconstruct the
     /// full DDL state, then packages it as a `Snapshot::Sqlite(...)`.
     pub fn introspect(&self) -> drizzle_core::error::Result<drizzle_migrations::schema::Snapshot> {
-        let tables = introspect_query_tables(&self.conn)?;
-        let raw_columns = introspect_query_columns(&self.conn)?;
-        let (all_indexes, all_index_columns, all_fks) =
-            introspect_query_indexes_and_fks(&self.conn)?;
-        let all_views = introspect_query_views(&self.conn)?;
-
-        let ddl = drizzle_migrations::sqlite::introspect::assemble_ddl(
-            drizzle_migrations::sqlite::introspect::RawIntrospection {
-                tables,
-                columns: raw_columns,
-                indexes: all_indexes,
-                index_columns: all_index_columns,
-                foreign_keys: all_fks,
-                views: all_views,
-            },
-        );
+        drizzle_migrations::sqlite::SqliteIntrospector::new(&self.conn)
+            .snapshot()
+            .map(drizzle_migrations::schema::Snapshot::Sqlite)
+            .map_err(|e| DrizzleError::Other(e.to_string().into()))
+    }
 
-        let mut snapshot = drizzle_migrations::sqlite::SQLiteSnapshot::new();
-        for entity in ddl.to_entities() {
-            snapshot.add_entity(entity);
-        }
+    /// Introspect the live database into the same DDL model the migration
+    /// differ works on.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub fn introspect_ddl(
+        &self,
+    ) -> drizzle_core::error::Result<drizzle_migrations::sqlite::SQLiteDDL> {
+        drizzle_migrations::sqlite::SqliteIntrospector::new(&self.conn)
+            .ddl()
+            .map_err(|e| DrizzleError::Other(e.to_string().into()))
+    }
 
-        Ok(drizzle_migrations::schema::Snapshot::Sqlite(snapshot))
+    /// Tables in the live database, excluding `SQLite` internals and the
+    /// migrations table.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub fn tables(
+        &self,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::ddl::Table>> {
+        Ok(self.introspect_ddl()?.tables.into_vec())
+    }
+
+    /// Columns of `table` in the live database, in declaration order.
+    ///
+    /// Returns an empty list if the table doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub fn columns(
+        &self,
+        table: &str,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::ddl::Column>> {
+        let ddl = self.introspect_ddl()?;
+        Ok(ddl.columns.for_table(table).into_iter().cloned().collect())
+    }
+
+    /// Indexes on `table` in the live database.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub fn indexes(
+        &self,
+        table: &str,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::ddl::Index>> {
+        let ddl = self.introspect_ddl()?;
+        Ok(ddl.indexes.for_table(table).into_iter().cloned().collect())
     }
 
     /// Introspect the live database, diff against the desired schema, and
@@ -605,16 +974,63 @@ impl<Schema> common::Drizzle<Connection, Schema> {
         &self,
         schema: &S,
     ) -> drizzle_core::error::Result<()> {
-        let live = self.introspect()?;
+        self.sync_schema_with(
+            schema,
+            drizzle_migrations::SyncOptions::new().allow_destructive(true),
+        )?;
+        Ok(())
+    }
+
+    /// Bring the live database in line with `schema` at startup, applying
+    /// only additive changes (new tables, columns, indexes and views).
+    ///
+    /// Meant for prototypes; use migrations once the schema settles.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::Schema`] without changing anything if the diff
+    /// would drop or rebuild something, or [`DrizzleError`] if introspection
+    /// or a statement fails.
+    pub fn sync_schema<S: drizzle_migrations::Schema>(
+        &self,
+        schema: &S,
+    ) -> drizzle_core::error::Result<drizzle_migrations::Plan> {
+        self.sync_schema_with(schema, drizzle_migrations::SyncOptions::new())
+    }
+
+    /// [`sync_schema`](Self::sync_schema) with explicit options, e.g.
+    /// `SyncOptions::new().allow_destructive(true)`.
+    ///
+    /// Returns the plan that was applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::Schema`] if `options` reject the diff, or
+    /// [`DrizzleError`] if introspection or a statement fails.
+    pub fn sync_schema_with<S: drizzle_migrations::Schema>(
+        &self,
+        schema: &S,
+        options: drizzle_migrations::SyncOptions,
+    ) -> drizzle_core::error::Result<drizzle_migrations::Plan> {
         let desired = schema.to_snapshot();
-        let generated = drizzle_migrations::diff(&live, &desired)
+        let live = match (self.introspect()?, &desired) {
+            (
+                drizzle_migrations::schema::Snapshot::Sqlite(live),
+                drizzle_migrations::schema::Snapshot::Sqlite(desired),
+            ) => drizzle_migrations::schema::Snapshot::Sqlite(live.prepare_for_push(desired)),
+            (live, _) => live,
+        };
+        let plan = drizzle_migrations::diff(&live, &desired)
             .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
-        for stmt in generated.statements {
+        options
+            .check(&plan)
+            .map_err(|e| DrizzleError::Schema(e.to_string().into()))?;
+        for stmt in &plan.statements {
             if !stmt.trim().is_empty() {
-                self.conn.execute(&stmt, [])?;
+                self.conn.execute(stmt, [])?;
             }
         }
-        Ok(())
+        Ok(plan)
     }
 }
 
@@ -1066,6 +1482,18 @@ where
             .with_query(|| QueryContext::new(&sql_str, &params))
     }
 
+    /// Runs the query and returns the affected row count together with the
+    /// rowid of the last inserted row.
+    pub fn run(self) -> drizzle_core::error::Result<ExecuteResult> {
+        let conn = &self.runner.conn;
+        let rows_affected = self.execute()?;
+        Ok(ExecuteResult::new(
+            rows_affected as u64,
+            State::INSERTS,
+            conn.last_insert_rowid(),
+        ))
+    }
+
     /// Runs the query and returns all matching rows using the builder's row type.
     pub fn all<R, Proof, AggProof>(self) -> drizzle_core::error::Result<Vec<R>>
     where
@@ -1093,10 +1521,68 @@ where
             .next()
             .with_query(|| QueryContext::new(&sql_str, &params))?
         {
-            decoded.push(<Mk as drizzle_core::row::DecodeSelectedRef<
-                &::rusqlite::Row<'_>,
-                R,
-            >>::decode(row)?);
+            decoded.push(
+                <Mk as drizzle_core::row::DecodeSelectedRef<&::rusqlite::Row<'_>, R>>::decode(row)
+                    .map_err(|e| e.at_row(decoded.len()))?,
+            );
+        }
+        Ok(decoded)
+    }
+
+    /// Runs the query and deserializes each row into `T` through serde.
+    ///
+    /// Rows are read by column name with
+    /// [`RowDeserializer`](drizzle_sqlite::de::RowDeserializer), so existing
+    /// `Deserialize` types work without also deriving `FromRow`.
+    ///
+    /// ```no_run
+    /// # use drizzle::sqlite::rusqlite::Drizzle;
+    /// # use drizzle::sqlite::prelude::*;
+    /// # #[SQLiteTable] struct User { #[column(primary)] id: i32, name: String }
+    /// # #[derive(SQLiteSchema)] struct S { user: User }
+    /// #[derive(serde::Deserialize)]
+    /// struct UserDto {
+    ///     #[serde(rename = "name")]
+    ///     display_name: String,
+    /// }
+    /// # fn main() -> drizzle::Result<()> {
+    /// # let conn = ::rusqlite::Connection::open_in_memory()?;
+    /// # let (db, S { user, .. }) = Drizzle::new(conn, S::new());
+    /// let users: Vec<UserDto> = db.select(()).from(user).all_de()?;
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn all_de<T>(self) -> drizzle_core::error::Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        #[cfg(feature = "profiling")]
+        drizzle_core::drizzle_profile_scope!("sqlite.rusqlite", "builder.all_de");
+        let (sql_str, params) = self.builder.sql.build();
+        drizzle_core::drizzle_trace_query!(&sql_str, params.len());
+
+        let mut stmt = self
+            .runner
+            .conn
+            .prepare(&sql_str)
+            .with_query(|| QueryContext::new(&sql_str, &params))?;
+        let columns: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+        let mut raw_rows = stmt
+            .query(params_from_iter(params.iter().copied()))
+            .with_query(|| QueryContext::new(&sql_str, &params))?;
+        let mut values = Vec::with_capacity(columns.len());
+        let mut decoded = Vec::new();
+        while let Some(row) = raw_rows
+            .next()
+            .with_query(|| QueryContext::new(&sql_str, &params))?
+        {
+            values.clear();
+            for idx in 0..columns.len() {
+                values.push(drizzle_sqlite::values::OwnedSQLiteValue::from(
+                    row.get_ref(idx)?,
+                ));
+            }
+            decoded.push(drizzle_sqlite::de::from_row(&columns, &values)?);
         }
         Ok(decoded)
     }
@@ -1118,9 +1604,12 @@ where
             .conn
             .prepare(&sql_str)
             .with_query(|| QueryContext::new(&sql_str, &params))?;
+        let mut index = 0;
         let mut rows = stmt
             .query_and_then(params_from_iter(params.iter().copied()), |row| {
-                Rw::try_from(row).map_err(Into::into)
+                let decoded = Rw::try_from(row).map_err(|e| e.into().at_row(index));
+                index += 1;
+                decoded
             })
             .with_query(|| QueryContext::new(&sql_str, &params))?;
 
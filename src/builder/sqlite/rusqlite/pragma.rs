@@ -0,0 +1,111 @@
+//! Typed results for introspection and maintenance pragmas.
+
+use drizzle_core::error::{DrizzleError, QueryContext, ResultExt};
+use drizzle_core::traits::{SQLTableInfo, ToSQL};
+use drizzle_sqlite::pragma::{
+    ForeignKeyCheckRow, Pragma, TableInfoRow, WalCheckpointMode, WalCheckpointRow,
+    integrity_problems,
+};
+use drizzle_sqlite::traits::DrizzleRowByIndex;
+use drizzle_sqlite::values::SQLiteValue;
+use rusqlite::Connection;
+
+use crate::builder::sqlite::common;
+
+/// Runs pragmas and decodes their result rows.
+///
+/// Returned by [`Drizzle::pragma`](common::Drizzle::pragma).
+#[derive(Debug, Clone, Copy)]
+pub struct Pragmas<'a> {
+    conn: &'a Connection,
+}
+
+impl<Schema> common::Drizzle<Connection, Schema> {
+    /// Typed helpers for pragmas that return rows.
+    ///
+    /// ```no_run
+    /// # use drizzle::sqlite::rusqlite::Drizzle;
+    /// # use drizzle::sqlite::prelude::*;
+    /// # #[SQLiteTable] struct User { #[column(primary)] id: i32, name: String }
+    /// # #[derive(SQLiteSchema)] struct S { user: User }
+    /// # fn main() -> drizzle::Result<()> {
+    /// # let conn = ::rusqlite::Connection::open_in_memory()?;
+    /// # let (db, S { user }) = Drizzle::new(conn, S::new());
+    /// let columns = db.pragma().table_info(user)?;
+    /// assert!(db.pragma().integrity_check()?.is_empty());
+    /// # Ok(()) }
+    /// ```
+    pub const fn pragma(&self) -> Pragmas<'_> {
+        Pragmas { conn: &self.conn }
+    }
+}
+
+impl Pragmas<'_> {
+    /// Columns of `table`, from `PRAGMA table_info`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the pragma fails or a row can't be decoded.
+    pub fn table_info<T: SQLTableInfo>(
+        &self,
+        table: T,
+    ) -> drizzle_core::error::Result<Vec<TableInfoRow>> {
+        self.rows(&Pragma::TableInfo(table.name()), |row| {
+            TableInfoRow::from_row(row)
+        })
+    }
+
+    /// Problems reported by `PRAGMA integrity_check`; empty when the database is healthy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the pragma fails.
+    pub fn integrity_check(&self) -> drizzle_core::error::Result<Vec<String>> {
+        let messages = self.rows(&Pragma::IntegrityCheck(None), |row| row.get_column(0))?;
+        Ok(integrity_problems(messages))
+    }
+
+    /// Rows violating foreign key constraints, from `PRAGMA foreign_key_check`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the pragma fails or a row can't be decoded.
+    pub fn foreign_key_check(&self) -> drizzle_core::error::Result<Vec<ForeignKeyCheckRow>> {
+        self.rows(&Pragma::ForeignKeyCheck(None), |row| {
+            ForeignKeyCheckRow::from_row(row)
+        })
+    }
+
+    /// Checkpoints the WAL file with `mode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the pragma fails or returns no row.
+    pub fn wal_checkpoint(
+        &self,
+        mode: WalCheckpointMode,
+    ) -> drizzle_core::error::Result<WalCheckpointRow> {
+        self.rows(&Pragma::WalCheckpoint(Some(mode)), |row| {
+            WalCheckpointRow::from_row(row)
+        })?
+        .into_iter()
+        .next()
+        .ok_or(DrizzleError::NotFound)
+    }
+
+    fn rows<T>(
+        &self,
+        pragma: &Pragma,
+        decode: impl Fn(&rusqlite::Row<'_>) -> drizzle_core::error::Result<T>,
+    ) -> drizzle_core::error::Result<Vec<T>> {
+        let sql = ToSQL::<SQLiteValue<'_>>::to_sql(pragma).sql();
+        let ctx = || QueryContext::new::<SQLiteValue<'_>>(&sql, &[]);
+        let mut stmt = self.conn.prepare(&sql).with_query(ctx)?;
+        let mut rows = stmt.query([]).with_query(ctx)?;
+        let mut decoded = Vec::new();
+        while let Some(row) = rows.next().with_query(ctx)? {
+            decoded.push(decode(row)?);
+        }
+        Ok(decoded)
+    }
+}
@@ -1,5 +1,5 @@
 use drizzle_core::error::Result;
-use drizzle_core::param::{OwnedParam, Param, ParamBind};
+use drizzle_core::param::{OwnedParam, Param, ParamSet};
 use drizzle_core::prepared::{
     OwnedPreparedStatement as CoreOwnedPreparedStatement,
     PreparedStatement as CorePreparedStatement,
@@ -50,7 +50,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
     pub fn execute<const N: usize>(
         &self,
         conn: &Connection,
-        params: [ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> Result<usize> {
         debug_assert_eq!(
             N,
@@ -64,7 +64,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
         let (sql_str, params) = {
             #[cfg(feature = "profiling")]
             drizzle_core::drizzle_profile_scope!("sqlite.rusqlite", "prepared.execute.bind");
-            self.inner.bind(params)?
+            self.inner.bind(params.into())?
         };
 
         #[cfg(feature = "profiling")]
@@ -77,7 +77,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
     pub fn all<T, const N: usize>(
         &self,
         conn: &Connection,
-        params: [ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> Result<Vec<T>>
     where
         for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r Row<'r>, T>,
@@ -91,12 +91,17 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
         );
         #[cfg(feature = "profiling")]
         drizzle_core::drizzle_profile_scope!("sqlite.rusqlite", "prepared.all");
-        let (sql_str, params) = self.inner.bind(params)?;
+        let (sql_str, params) = self.inner.bind(params.into())?;
 
         let mut stmt = conn.prepare_cached(sql_str)?;
 
+        let mut index = 0;
         let mut rows = stmt.query_and_then(params_from_iter(params), |row| {
-            <Marker as drizzle_core::row::DecodeSelectedRef<&Row<'_>, T>>::decode(row)
+            let decoded =
+                <Marker as drizzle_core::row::DecodeSelectedRef<&Row<'_>, T>>::decode(row)
+                    .map_err(|e| e.at_row(index));
+            index += 1;
+            decoded
         })?;
 
         let (lower, _) = rows.size_hint();
@@ -112,7 +117,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
     pub fn get<T, const N: usize>(
         &self,
         conn: &Connection,
-        params: [ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> Result<T>
     where
         for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r Row<'r>, T>,
@@ -126,7 +131,7 @@ impl<'a, Marker, DecodedRow> PreparedStatement<'a, Marker, DecodedRow> {
         );
         #[cfg(feature = "profiling")]
         drizzle_core::drizzle_profile_scope!("sqlite.rusqlite", "prepared.get");
-        let (sql_str, params) = self.inner.bind(params)?;
+        let (sql_str, params) = self.inner.bind(params.into())?;
 
         let mut stmt = conn.prepare_cached(sql_str)?;
 
@@ -178,7 +183,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
     pub fn execute<'a, const N: usize>(
         &self,
         conn: &Connection,
-        params: [ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> Result<usize> {
         debug_assert_eq!(
             N,
@@ -192,7 +197,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
         let (sql_str, params) = {
             #[cfg(feature = "profiling")]
             drizzle_core::drizzle_profile_scope!("sqlite.rusqlite", "owned_prepared.execute.bind");
-            self.inner.bind(params)?
+            self.inner.bind(params.into())?
         };
 
         #[cfg(feature = "profiling")]
@@ -205,7 +210,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
     pub fn all<'a, T, const N: usize>(
         &self,
         conn: &Connection,
-        params: [ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> Result<Vec<T>>
     where
         for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r Row<'r>, T>,
@@ -219,12 +224,17 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
         );
         #[cfg(feature = "profiling")]
         drizzle_core::drizzle_profile_scope!("sqlite.rusqlite", "owned_prepared.all");
-        let (sql_str, params) = self.inner.bind(params)?;
+        let (sql_str, params) = self.inner.bind(params.into())?;
 
         let mut stmt = conn.prepare_cached(sql_str)?;
 
+        let mut index = 0;
         let mut rows = stmt.query_and_then(params_from_iter(params), |row| {
-            <Marker as drizzle_core::row::DecodeSelectedRef<&Row<'_>, T>>::decode(row)
+            let decoded =
+                <Marker as drizzle_core::row::DecodeSelectedRef<&Row<'_>, T>>::decode(row)
+                    .map_err(|e| e.at_row(index));
+            index += 1;
+            decoded
         })?;
 
         let (lower, _) = rows.size_hint();
@@ -240,7 +250,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
     pub fn get<'a, T, const N: usize>(
         &self,
         conn: &Connection,
-        params: [ParamBind<'a, SQLiteValue<'a>>; N],
+        params: impl Into<ParamSet<'a, SQLiteValue<'a>, N>>,
     ) -> Result<T>
     where
         for<'r> Marker: drizzle_core::row::DecodeSelectedRef<&'r Row<'r>, T>,
@@ -254,7 +264,7 @@ impl<Marker, DecodedRow> OwnedPreparedStatement<Marker, DecodedRow> {
         );
         #[cfg(feature = "profiling")]
         drizzle_core::drizzle_profile_scope!("sqlite.rusqlite", "owned_prepared.get");
-        let (sql_str, params) = self.inner.bind(params)?;
+        let (sql_str, params) = self.inner.bind(params.into())?;
 
         let mut stmt = conn.prepare_cached(sql_str)?;
 
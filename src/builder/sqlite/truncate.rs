@@ -0,0 +1,61 @@
+//! SQL for `truncate` and `reset`, shared by the sqlite drivers.
+
+use drizzle_core::TableRef;
+use drizzle_core::error::Result;
+
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// The table's name, qualified with its attached database if it has one.
+fn qualified(table: &TableRef) -> String {
+    match table.schema {
+        Some(database) => format!("{}.{}", quote(database), quote(table.name)),
+        None => quote(table.name),
+    }
+}
+
+fn has_autoincrement(table: &TableRef) -> bool {
+    table.columns.iter().any(|col| {
+        matches!(
+            col.dialect,
+            drizzle_core::ColumnDialect::SQLite {
+                autoincrement: true,
+                ..
+            }
+        )
+    })
+}
+
+/// `SQLite` has no `TRUNCATE`: one `DELETE` per table, referencing tables
+/// first, then a reset of the `AUTOINCREMENT` counters of the emptied tables.
+///
+/// `sqlite_sequence` only exists once an `AUTOINCREMENT` table has been
+/// created, so it is touched only when one of `tables` has such a column.
+pub(crate) fn truncate_statements(tables: &[&'static TableRef]) -> Result<Vec<String>> {
+    let tables = drizzle_core::schema::truncation_order(tables)?;
+    let mut statements: Vec<String> = tables
+        .iter()
+        .map(|table| format!("DELETE FROM {}", qualified(table)))
+        .collect();
+    // Each database keeps its own `sqlite_sequence`.
+    let mut sequenced: Vec<(Option<&str>, Vec<String>)> = Vec::new();
+    for table in tables.iter().filter(|table| has_autoincrement(table)) {
+        let name = format!("'{}'", table.name.replace('\'', "''"));
+        match sequenced.iter_mut().find(|(db, _)| *db == table.schema) {
+            Some((_, names)) => names.push(name),
+            None => sequenced.push((table.schema, vec![name])),
+        }
+    }
+    for (database, names) in sequenced {
+        let sequence = database.map_or_else(
+            || "sqlite_sequence".to_string(),
+            |database| format!("{}.sqlite_sequence", quote(database)),
+        );
+        statements.push(format!(
+            "DELETE FROM {sequence} WHERE name IN ({})",
+            names.join(", ")
+        ));
+    }
+    Ok(statements)
+}
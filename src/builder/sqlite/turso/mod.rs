@@ -119,6 +119,7 @@
 //! # Ok(()) }
 //! ```
 
+mod pragma;
 mod prepared;
 
 use drizzle_core::error::{DrizzleError, QueryContext, ResultExt};
@@ -130,7 +131,7 @@ use turso::{Connection, IntoValue, Row};
 #[cfg(feature = "sqlite")]
 use drizzle_sqlite::{
     builder::{self, QueryBuilder},
-    connection::SQLiteTransactionType,
+    connection::{ExecuteResult, SQLiteTransactionType},
     values::SQLiteValue,
 };
 
@@ -139,6 +140,8 @@ use crate::builder::sqlite::common;
 use crate::builder::sqlite::rows::TursoRows as Rows;
 use crate::transaction::sqlite::turso::Transaction;
 
+pub use pragma::Pragmas;
+
 pub type Drizzle<Schema = ()> = common::Drizzle<Connection, Schema>;
 pub type DrizzleBuilder<'a, Schema, Builder, State> =
     common::DrizzleBuilder<'a, common::Drizzle<Connection, Schema>, Schema, Builder, State>;
@@ -210,13 +213,16 @@ impl<Schema> common::Drizzle<Connection, Schema> {
             .with_query(|| QueryContext::new(&sql_str, &params))?;
 
         let mut out = C::default();
+        let mut index = 0;
         while let Some(row) = rows
             .next()
             .await
             .map_err(DrizzleError::from)
             .with_query(|| QueryContext::new(&sql_str, &params))?
         {
-            out.extend(core::iter::once(R::try_from(&row).map_err(Into::into)?));
+            let decoded = R::try_from(&row).map_err(|e| e.into().at_row(index))?;
+            out.extend(core::iter::once(decoded));
+            index += 1;
         }
         Ok(out)
     }
@@ -278,6 +284,44 @@ impl<Schema> common::Drizzle<Connection, Schema> {
             )
     }
 
+    /// Loads the rows whose `column` matches each of `keys`, in key order.
+    ///
+    /// Keys are sent in `IN (...)` chunks of at most
+    /// [`SQLITE_MAX_BATCH_KEYS`](drizzle_core::batch::SQLITE_MAX_BATCH_KEYS).
+    /// `key_of` reads the key back off a row; keys without a row yield `None`.
+    ///
+    /// ```rust
+    /// # let _ = r####"
+    /// let users: Vec<Option<SelectUser>> =
+    ///     db.get_many(user, user.id, &[3, 1, 2], |u: &SelectUser| u.id).await?;
+    /// # "####;
+    /// ```
+    pub async fn get_many<'a, T, C, K, R>(
+        &'a self,
+        table: T,
+        column: C,
+        keys: &'a [K],
+        key_of: impl Fn(&R) -> K,
+    ) -> drizzle_core::error::Result<Vec<Option<R>>>
+    where
+        T: ToSQL<'a, SQLiteValue<'a>> + Clone,
+        C: drizzle_core::expr::Expr<'a, SQLiteValue<'a>> + Clone,
+        K: std::hash::Hash + Eq,
+        &'a K: drizzle_core::expr::Expr<'a, SQLiteValue<'a>>,
+        C::SQLType: drizzle_core::types::Compatible<
+                <&'a K as drizzle_core::expr::Expr<'a, SQLiteValue<'a>>>::SQLType,
+            >,
+        R: for<'r> TryFrom<&'r Row> + Clone,
+        for<'r> <R as TryFrom<&'r Row>>::Error: Into<DrizzleError>,
+    {
+        let mut rows: Vec<R> = Vec::with_capacity(keys.len());
+        for chunk in keys.chunks(drizzle_core::batch::SQLITE_MAX_BATCH_KEYS) {
+            let query = common::get_many_sql(table.clone(), column.clone(), chunk);
+            rows.extend(self.all::<_, R, Vec<R>>(query).await?);
+        }
+        Ok(drizzle_core::batch::order_by_keys(keys, rows, key_of))
+    }
+
     /// Executes a transaction with the given callback.
     ///
     /// The transaction is committed when the callback returns `Ok` and
@@ -341,7 +385,28 @@ where
     /// Create schema objects from `SQLSchemaImpl`.
     pub async fn create(&self) -> drizzle_core::error::Result<()> {
         let schema = Schema::default();
-        let statements = schema.create_statements()?;
+        self.run_ddl(schema.create_statements()?).await
+    }
+
+    /// Like [`create`](Self::create), but objects that already exist are
+    /// left alone, so it is safe to call on every startup.
+    pub async fn create_if_not_exists(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.create_statements()?.map(super::ddl::if_not_exists))
+            .await
+    }
+
+    /// Drop every view, index and table of the schema, the inverse of
+    /// [`create`](Self::create). Tables are dropped referencing tables first.
+    pub async fn drop(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.drop_statements()?).await
+    }
+
+    async fn run_ddl(
+        &self,
+        statements: impl IntoIterator<Item = String>,
+    ) -> drizzle_core::error::Result<()> {
         for sql in statements {
             self.conn.execute(&sql, ()).await?;
         }
@@ -349,10 +414,92 @@ where
     }
 }
 
+impl<Schema> Drizzle<Schema> {
+    /// Delete every row of `tables` and reset their `AUTOINCREMENT` counters.
+    ///
+    /// Tables that reference others are emptied first, so a parent can be
+    /// truncated together with its children: `db.truncate((users, posts))`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the tables reference each other in a cycle
+    /// or a `DELETE` fails, e.g. on a foreign key from a table left out.
+    pub async fn truncate(
+        &self,
+        tables: impl drizzle_core::traits::TableSet,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(&tables.table_refs()).await
+    }
+
+    /// Truncate every table of `schema`, e.g. before seeding or between tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the tables reference each other in a cycle
+    /// or a `DELETE` fails.
+    pub async fn reset<S: drizzle_core::traits::SQLSchemaImpl>(
+        &self,
+        schema: &S,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(schema.table_refs()).await
+    }
+
+    async fn truncate_tables(
+        &self,
+        tables: &[&'static drizzle_core::TableRef],
+    ) -> drizzle_core::error::Result<()> {
+        let statements = super::truncate::truncate_statements(tables)?;
+        for sql in statements {
+            self.conn.execute(&sql, ()).await?;
+        }
+        Ok(())
+    }
+}
+
+impl<Schema> common::Drizzle<Connection, Schema> {
+    /// Attaches the database file at `path` to this connection as `name`.
+    ///
+    /// Tables declared with `#[SQLiteTable(DATABASE = "name")]` then resolve
+    /// to it, so they can be joined with, or copied into from, tables in the
+    /// main database. Turso treats `ATTACH` as experimental: build the
+    /// database with `turso::Builder::experimental_attach(true)` first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or `name` is already in
+    /// use.
+    pub async fn attach(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        name: &str,
+    ) -> drizzle_core::error::Result<()> {
+        let path = super::attach::path_param(path.as_ref())?;
+        self.conn
+            .execute(&super::attach::attach_sql(name), [path])
+            .await?;
+        Ok(())
+    }
+
+    /// Detaches the database attached as `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no database is attached as `name`.
+    pub async fn detach(&self, name: &str) -> drizzle_core::error::Result<()> {
+        self.conn
+            .execute(&super::attach::detach_sql(name), ())
+            .await?;
+        Ok(())
+    }
+}
+
 impl<Schema> common::Drizzle<Connection, Schema> {
     /// Apply pending migrations from an embedded migration slice.
     ///
     /// Creates the migrations table if needed and runs pending migrations in a transaction.
+    /// Concurrent runners are serialized by `BEGIN IMMEDIATE` plus a lock marker row:
+    /// one applies the pending migrations while the others wait, then find nothing to do.
+    /// Statements marked `-- drizzle:no-transaction` run between two transactions.
     pub async fn migrate(
         &mut self,
         migrations: &[drizzle_migrations::Migration],
@@ -365,33 +512,69 @@ impl<Schema> common::Drizzle<Connection, Schema> {
         );
 
         ensure_sqlite_migration_table(&mut self.conn, &set).await?;
-        let tx = self
+        let mut tx = self
             .conn
             .transaction_with_behavior(turso::transaction::TransactionBehavior::Immediate)
             .await
             .map_err(DrizzleError::from)?;
+        tx.execute(&set.claim_lock_sql(), ())
+            .await
+            .map_err(DrizzleError::from)?;
         let mut rows = tx
-            .query(&set.applied_names_sql(), ())
+            .query(&set.applied_hashes_sql(), ())
             .await
             .map_err(DrizzleError::from)?;
 
-        let mut applied_names: Vec<String> = Vec::new();
+        let mut applied_rows: Vec<(String, String)> = Vec::new();
         while let Some(row) = rows.next().await.map_err(DrizzleError::from)? {
-            applied_names.push(row.get::<String>(0).map_err(DrizzleError::from)?);
+            applied_rows.push((
+                row.get::<String>(0).map_err(DrizzleError::from)?,
+                row.get::<String>(1).map_err(DrizzleError::from)?,
+            ));
         }
+        set.check_checksums(&applied_rows)
+            .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
+        let applied_names: Vec<&str> = applied_rows.iter().map(|(name, _)| name.as_str()).collect();
 
         let pending: Vec<_> = set.pending(&applied_names).collect();
 
         if pending.is_empty() {
+            if let Some(sql) = set.record_schema_hash_sql() {
+                tx.execute(&sql, ()).await.map_err(DrizzleError::from)?;
+            }
+            tx.execute(&set.release_lock_sql(), ())
+                .await
+                .map_err(DrizzleError::from)?;
             tx.commit().await.map_err(DrizzleError::from)?;
             return Ok(drizzle_migrations::MigrateOutcome::UpToDate);
         }
 
         let mut applied = Vec::with_capacity(pending.len());
         for migration in &pending {
-            for stmt in migration.statements() {
-                if !stmt.trim().is_empty() {
-                    tx.execute(stmt, ()).await.map_err(DrizzleError::from)?;
+            for step in migration.steps() {
+                if step.no_transaction {
+                    // e.g. VACUUM can't run inside a transaction: commit what
+                    // ran so far and pick the lock up again after.
+                    tx.execute(&set.release_lock_sql(), ())
+                        .await
+                        .map_err(DrizzleError::from)?;
+                    tx.commit().await.map_err(DrizzleError::from)?;
+                    self.conn
+                        .execute(step.sql, ())
+                        .await
+                        .map_err(DrizzleError::from)?;
+                    tx = self
+                        .conn
+                        .transaction_with_behavior(
+                            turso::transaction::TransactionBehavior::Immediate,
+                        )
+                        .await
+                        .map_err(DrizzleError::from)?;
+                    tx.execute(&set.claim_lock_sql(), ())
+                        .await
+                        .map_err(DrizzleError::from)?;
+                } else {
+                    tx.execute(step.sql, ()).await.map_err(DrizzleError::from)?;
                 }
             }
             tx.execute(&set.record_migration_sql(migration), ())
@@ -400,10 +583,59 @@ impl<Schema> common::Drizzle<Connection, Schema> {
             applied.push(migration.tag().to_string());
         }
 
+        if let Some(sql) = set.record_schema_hash_sql() {
+            tx.execute(&sql, ()).await.map_err(DrizzleError::from)?;
+        }
+        tx.execute(&set.release_lock_sql(), ())
+            .await
+            .map_err(DrizzleError::from)?;
         tx.commit().await.map_err(DrizzleError::from)?;
 
         Ok(drizzle_migrations::MigrateOutcome::Applied { tags: applied })
     }
+
+    /// Check that the schema hash recorded by [`migrate`](Self::migrate)
+    /// matches `Schema::SCHEMA_HASH`.
+    ///
+    /// Call it at startup to catch an application deployed against the wrong
+    /// database. Record the hash with [`Tracking::schema_hash`](drizzle_migrations::Tracking::schema_hash).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::Schema`] if no hash was recorded or it differs.
+    pub async fn assert_schema_matches(
+        &self,
+        tracking: drizzle_migrations::Tracking,
+    ) -> drizzle_core::error::Result<()>
+    where
+        Schema: drizzle_core::traits::SQLSchemaImpl,
+    {
+        let set = drizzle_migrations::Migrations::with_tracking(
+            Vec::new(),
+            drizzle_types::Dialect::SQLite,
+            tracking,
+        );
+        let mut tables = self
+            .conn
+            .query(&set.table_exists_sql(), ())
+            .await
+            .map_err(DrizzleError::from)?;
+        let recorded = if tables.next().await.map_err(DrizzleError::from)?.is_some() {
+            let mut rows = self
+                .conn
+                .query(&set.schema_hash_sql(), ())
+                .await
+                .map_err(DrizzleError::from)?;
+            match rows.next().await.map_err(DrizzleError::from)? {
+                Some(row) => Some(row.get::<String>(0).map_err(DrizzleError::from)?),
+                None => None,
+            }
+        } else {
+            None
+        };
+        drizzle_migrations::check_schema_hash(recorded.as_deref(), Schema::SCHEMA_HASH)
+            .map_err(|e| DrizzleError::Schema(e.to_string().into()))
+    }
 }
 
 async fn migration_table_has_name_column(
@@ -523,153 +755,71 @@ async fn ensure_sqlite_migration_table(
     backfill_migration_name_column(conn, set, matched).await
 }
 
-async fn turso_introspect_query_tables(
-    conn: &turso::Connection,
-) -> drizzle_core::error::Result<Vec<(String, Option<String>)>> {
-    use drizzle_migrations::sqlite::introspect::queries;
-    let err = DrizzleError::from;
-
-    let mut tables_rows = conn.query(queries::TABLES_QUERY, ()).await.map_err(err)?;
-    let mut tables: Vec<(String, Option<String>)> = Vec::new();
-    while let Some(row) = tables_rows.next().await.map_err(err)? {
-        let name: String = row.get(0).map_err(err)?;
-        let sql: Option<String> = row.get(1).ok();
-        tables.push((name, sql));
-    }
-    Ok(tables)
-}
-
-async fn turso_introspect_query_columns(
-    conn: &turso::Connection,
-) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::introspect::RawColumnInfo>> {
-    use drizzle_migrations::sqlite::introspect::{RawColumnInfo, queries};
-    let err = DrizzleError::from;
-
-    let mut columns_rows = conn.query(queries::COLUMNS_QUERY, ()).await.map_err(err)?;
-    let mut raw_columns: Vec<RawColumnInfo> = Vec::new();
-    while let Some(row) = columns_rows.next().await.map_err(err)? {
-        raw_columns.push(RawColumnInfo {
-            table: row.get(0).map_err(err)?,
-            cid: row.get(1).map_err(err)?,
-            name: row.get(2).map_err(err)?,
-            column_type: row.get(3).map_err(err)?,
-            not_null: row.get::<i32>(4).map_err(err)? != 0,
-            default_value: row.get(5).ok(),
-            pk: row.get(6).map_err(err)?,
-            hidden: row.get(7).map_err(err)?,
-            sql: row.get(8).ok(),
-        });
-    }
-    Ok(raw_columns)
-}
-
-async fn turso_introspect_query_indexes_and_fks(
-    conn: &turso::Connection,
-) -> drizzle_core::error::Result<(
-    Vec<drizzle_migrations::sqlite::introspect::RawIndexInfo>,
-    Vec<drizzle_migrations::sqlite::introspect::RawIndexColumn>,
-    Vec<drizzle_migrations::sqlite::introspect::RawForeignKey>,
-)> {
-    use drizzle_migrations::sqlite::introspect::{
-        RawForeignKey, RawIndexColumn, RawIndexInfo, queries,
-    };
-    let err = DrizzleError::from;
-
-    let mut all_indexes = Vec::<RawIndexInfo>::new();
-    let mut index_rows = conn.query(queries::INDEXES_QUERY, ()).await.map_err(err)?;
-    while let Some(row) = index_rows.next().await.map_err(err)? {
-        all_indexes.push(RawIndexInfo {
-            table: row.get(0).map_err(err)?,
-            name: row.get(1).map_err(err)?,
-            unique: row.get::<i32>(2).map_err(err)? != 0,
-            origin: row.get(3).map_err(err)?,
-            partial: row.get::<i32>(4).map_err(err)? != 0,
-        });
+impl<Schema> common::Drizzle<Connection, Schema> {
+    /// Introspect the live database and return a [`Snapshot`] of its current schema.
+    pub async fn introspect(
+        &self,
+    ) -> drizzle_core::error::Result<drizzle_migrations::schema::Snapshot> {
+        drizzle_migrations::sqlite::SqliteIntrospector::new(&self.conn)
+            .snapshot_async()
+            .await
+            .map(drizzle_migrations::schema::Snapshot::Sqlite)
+            .map_err(|e| DrizzleError::Other(e.to_string().into()))
     }
 
-    let mut all_index_columns = Vec::<RawIndexColumn>::new();
-    let mut column_rows = conn
-        .query(queries::INDEX_COLUMNS_QUERY, ())
-        .await
-        .map_err(err)?;
-    while let Some(row) = column_rows.next().await.map_err(err)? {
-        all_index_columns.push(RawIndexColumn {
-            index_name: row.get(0).map_err(err)?,
-            seqno: row.get(1).map_err(err)?,
-            cid: row.get(2).map_err(err)?,
-            name: row.get(3).ok(),
-            desc: row.get::<i32>(4).map_err(err)? != 0,
-            coll: row.get(5).map_err(err)?,
-            key: row.get::<i32>(6).map_err(err)? != 0,
-        });
+    /// Introspect the live database into the same DDL model the migration
+    /// differ works on.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub async fn introspect_ddl(
+        &self,
+    ) -> drizzle_core::error::Result<drizzle_migrations::sqlite::SQLiteDDL> {
+        drizzle_migrations::sqlite::SqliteIntrospector::new(&self.conn)
+            .ddl_async()
+            .await
+            .map_err(|e| DrizzleError::Other(e.to_string().into()))
     }
 
-    let mut all_fks = Vec::<RawForeignKey>::new();
-    let mut foreign_key_rows = conn
-        .query(queries::FOREIGN_KEYS_QUERY, ())
-        .await
-        .map_err(err)?;
-    while let Some(row) = foreign_key_rows.next().await.map_err(err)? {
-        all_fks.push(RawForeignKey {
-            table: row.get(0).map_err(err)?,
-            id: row.get(1).map_err(err)?,
-            seq: row.get(2).map_err(err)?,
-            to_table: row.get(3).map_err(err)?,
-            from_column: row.get(4).map_err(err)?,
-            to_column: row.get(5).map_err(err)?,
-            on_update: row.get(6).map_err(err)?,
-            on_delete: row.get(7).map_err(err)?,
-            r#match: row.get(8).map_err(err)?,
-        });
+    /// Tables in the live database, excluding `SQLite` internals and the
+    /// migrations table.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub async fn tables(
+        &self,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::ddl::Table>> {
+        Ok(self.introspect_ddl().await?.tables.into_vec())
     }
 
-    Ok((all_indexes, all_index_columns, all_fks))
-}
-
-async fn turso_introspect_query_views(
-    conn: &turso::Connection,
-) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::introspect::RawViewInfo>> {
-    use drizzle_migrations::sqlite::introspect::{RawViewInfo, queries};
-
-    let mut all_views: Vec<RawViewInfo> = Vec::new();
-    let err = DrizzleError::from;
-    let mut views_rows = conn.query(queries::VIEWS_QUERY, ()).await.map_err(err)?;
-    while let Some(row) = views_rows.next().await.map_err(err)? {
-        let name: String = row.get(0).map_err(err)?;
-        let sql: String = row.get(1).map_err(err)?;
-        all_views.push(RawViewInfo { name, sql });
+    /// Columns of `table` in the live database, in declaration order.
+    ///
+    /// Returns an empty list if the table doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub async fn columns(
+        &self,
+        table: &str,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::ddl::Column>> {
+        let ddl = self.introspect_ddl().await?;
+        Ok(ddl.columns.for_table(table).into_iter().cloned().collect())
     }
-    Ok(all_views)
-}
 
-impl<Schema> common::Drizzle<Connection, Schema> {
-    /// Introspect the live database and return a [`Snapshot`] of its current schema.
-    pub async fn introspect(
+    /// Indexes on `table` in the live database.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the underlying introspection queries fail.
+    pub async fn indexes(
         &self,
-    ) -> drizzle_core::error::Result<drizzle_migrations::schema::Snapshot> {
-        let tables = turso_introspect_query_tables(&self.conn).await?;
-        let raw_columns = turso_introspect_query_columns(&self.conn).await?;
-        let (all_indexes, all_index_columns, all_fks) =
-            turso_introspect_query_indexes_and_fks(&self.conn).await?;
-        let all_views = turso_introspect_query_views(&self.conn).await?;
-
-        let ddl = drizzle_migrations::sqlite::introspect::assemble_ddl(
-            drizzle_migrations::sqlite::introspect::RawIntrospection {
-                tables,
-                columns: raw_columns,
-                indexes: all_indexes,
-                index_columns: all_index_columns,
-                foreign_keys: all_fks,
-                views: all_views,
-            },
-        );
-
-        let mut snapshot = drizzle_migrations::sqlite::SQLiteSnapshot::new();
-        for entity in ddl.to_entities() {
-            snapshot.add_entity(entity);
-        }
-
-        Ok(drizzle_migrations::schema::Snapshot::Sqlite(snapshot))
+        table: &str,
+    ) -> drizzle_core::error::Result<Vec<drizzle_migrations::sqlite::ddl::Index>> {
+        let ddl = self.introspect_ddl().await?;
+        Ok(ddl.indexes.for_table(table).into_iter().cloned().collect())
     }
 
     /// Introspect the live database, diff against the desired schema, and
@@ -680,19 +830,68 @@ impl<Schema> common::Drizzle<Connection, Schema> {
         &self,
         schema: &S,
     ) -> drizzle_core::error::Result<()> {
-        let live = self.introspect().await?;
+        self.sync_schema_with(
+            schema,
+            drizzle_migrations::SyncOptions::new().allow_destructive(true),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Bring the live database in line with `schema` at startup, applying
+    /// only additive changes (new tables, columns, indexes and views).
+    ///
+    /// Meant for prototypes; use migrations once the schema settles.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::Schema`] without changing anything if the diff
+    /// would drop or rebuild something, or [`DrizzleError`] if introspection
+    /// or a statement fails.
+    pub async fn sync_schema<S: drizzle_migrations::Schema>(
+        &self,
+        schema: &S,
+    ) -> drizzle_core::error::Result<drizzle_migrations::Plan> {
+        self.sync_schema_with(schema, drizzle_migrations::SyncOptions::new())
+            .await
+    }
+
+    /// [`sync_schema`](Self::sync_schema) with explicit options, e.g.
+    /// `SyncOptions::new().allow_destructive(true)`.
+    ///
+    /// Returns the plan that was applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError::Schema`] if `options` reject the diff, or
+    /// [`DrizzleError`] if introspection or a statement fails.
+    pub async fn sync_schema_with<S: drizzle_migrations::Schema>(
+        &self,
+        schema: &S,
+        options: drizzle_migrations::SyncOptions,
+    ) -> drizzle_core::error::Result<drizzle_migrations::Plan> {
         let desired = schema.to_snapshot();
-        let generated = drizzle_migrations::diff(&live, &desired)
+        let live = match (self.introspect().await?, &desired) {
+            (
+                drizzle_migrations::schema::Snapshot::Sqlite(live),
+                drizzle_migrations::schema::Snapshot::Sqlite(desired),
+            ) => drizzle_migrations::schema::Snapshot::Sqlite(live.prepare_for_push(desired)),
+            (live, _) => live,
+        };
+        let plan = drizzle_migrations::diff(&live, &desired)
             .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
-        for stmt in generated.statements {
+        options
+            .check(&plan)
+            .map_err(|e| DrizzleError::Schema(e.to_string().into()))?;
+        for stmt in &plan.statements {
             if !stmt.trim().is_empty() {
                 self.conn
-                    .execute(&stmt, ())
+                    .execute(stmt, ())
                     .await
                     .map_err(DrizzleError::from)?;
             }
         }
-        Ok(())
+        Ok(plan)
     }
 }
 
@@ -1156,6 +1355,18 @@ where
             .with_query(|| QueryContext::new(&sql_str, &params))
     }
 
+    /// Runs the query and returns the affected row count together with the
+    /// rowid of the last inserted row.
+    pub async fn run(self) -> drizzle_core::error::Result<ExecuteResult> {
+        let conn = &self.runner.conn;
+        let rows_affected = self.execute().await?;
+        Ok(ExecuteResult::new(
+            rows_affected,
+            State::INSERTS,
+            conn.last_insert_rowid(),
+        ))
+    }
+
     /// Runs the query and returns all matching rows using the builder's row type.
     pub async fn all<R, Proof, AggProof>(self) -> drizzle_core::error::Result<Vec<R>>
     where
@@ -1182,10 +1393,10 @@ where
             .map_err(drizzle_core::error::DrizzleError::from)
             .with_query(|| QueryContext::new(&sql_str, &params))?
         {
-            decoded.push(<Mk as drizzle_core::row::DecodeSelectedRef<
-                &::turso::Row,
-                R,
-            >>::decode(&row)?);
+            decoded.push(
+                <Mk as drizzle_core::row::DecodeSelectedRef<&::turso::Row, R>>::decode(&row)
+                    .map_err(|e| e.at_row(decoded.len()))?,
+            );
         }
         Ok(decoded)
     }
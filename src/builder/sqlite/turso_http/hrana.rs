@@ -0,0 +1,391 @@
+//! Hrana-over-HTTP wire types (`POST /v2/pipeline`).
+//!
+//! Only the subset drizzle needs: `execute`, `batch` and `close` stream
+//! requests, plus the value encoding shared by arguments and result rows.
+
+use base64::Engine;
+use base64::engine::DecodePaddingMode;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig, STANDARD_NO_PAD};
+use drizzle_core::error::{DrizzleError, Result};
+use drizzle_sqlite::values::{OwnedSQLiteValue, SQLiteValue};
+use serde::{Deserialize, Serialize};
+
+/// Servers may or may not pad blobs, so accept both.
+const BASE64_DECODE: GeneralPurpose = GeneralPurpose::new(
+    &base64::alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum Value {
+    Null,
+    /// Integers travel as strings so they survive JSON's 53-bit numbers.
+    Integer {
+        #[serde(with = "i64_string")]
+        value: i64,
+    },
+    Float {
+        value: f64,
+    },
+    Text {
+        value: String,
+    },
+    Blob {
+        base64: String,
+    },
+}
+
+mod i64_string {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub(super) fn serialize<S: Serializer>(value: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl From<&SQLiteValue<'_>> for Value {
+    fn from(value: &SQLiteValue<'_>) -> Self {
+        match value {
+            SQLiteValue::Null => Self::Null,
+            SQLiteValue::Integer(value) => Self::Integer { value: *value },
+            SQLiteValue::Real(value) => Self::Float { value: *value },
+            SQLiteValue::Text(value) => Self::Text {
+                value: value.to_string(),
+            },
+            SQLiteValue::Blob(value) => Self::Blob {
+                base64: STANDARD_NO_PAD.encode(value),
+            },
+        }
+    }
+}
+
+impl From<SQLiteValue<'_>> for Value {
+    fn from(value: SQLiteValue<'_>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<OwnedSQLiteValue> for Value {
+    fn from(value: OwnedSQLiteValue) -> Self {
+        Self::from(&SQLiteValue::from(value))
+    }
+}
+
+impl Value {
+    /// JSON form used for serde row decoding, matching what D1 hands back:
+    /// blobs become byte arrays.
+    fn into_json(self) -> Result<serde_json::Value> {
+        Ok(match self {
+            Self::Null => serde_json::Value::Null,
+            Self::Integer { value } => value.into(),
+            Self::Float { value } => serde_json::Number::from_f64(value)
+                .map_or(serde_json::Value::Null, serde_json::Value::Number),
+            Self::Text { value } => value.into(),
+            Self::Blob { base64 } => BASE64_DECODE
+                .decode(base64)
+                .map_err(|e| DrizzleError::Other(e.to_string().into()))?
+                .into(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct Stmt {
+    pub(crate) sql: String,
+    pub(crate) args: Vec<Value>,
+    pub(crate) want_rows: bool,
+}
+
+impl Stmt {
+    pub(crate) fn new(sql: impl Into<String>, args: Vec<Value>) -> Self {
+        Self {
+            sql: sql.into(),
+            args,
+            want_rows: true,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum StreamRequest {
+    Execute { stmt: Stmt },
+    Batch { batch: Batch },
+    Close,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Batch {
+    pub(crate) steps: Vec<BatchStep>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct BatchStep {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) condition: Option<BatchCond>,
+    pub(crate) stmt: Stmt,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum BatchCond {
+    Ok { step: usize },
+    Not { cond: Box<BatchCond> },
+}
+
+impl Batch {
+    /// Wraps `stmts` in `BEGIN` / `COMMIT`, each step running only if the
+    /// previous one succeeded, with a trailing `ROLLBACK` when the commit
+    /// did not happen.
+    pub(crate) fn transactional(stmts: Vec<Stmt>) -> Self {
+        let mut steps = Vec::with_capacity(stmts.len() + 3);
+        steps.push(BatchStep {
+            condition: None,
+            stmt: Stmt::new("BEGIN", Vec::new()),
+        });
+        for stmt in stmts {
+            steps.push(BatchStep {
+                condition: Some(BatchCond::Ok {
+                    step: steps.len() - 1,
+                }),
+                stmt,
+            });
+        }
+        let commit = steps.len();
+        steps.push(BatchStep {
+            condition: Some(BatchCond::Ok { step: commit - 1 }),
+            stmt: Stmt::new("COMMIT", Vec::new()),
+        });
+        steps.push(BatchStep {
+            condition: Some(BatchCond::Not {
+                cond: Box::new(BatchCond::Ok { step: commit }),
+            }),
+            stmt: Stmt::new("ROLLBACK", Vec::new()),
+        });
+        Self { steps }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct PipelineRequest<'a> {
+    pub(crate) baton: Option<&'a str>,
+    pub(crate) requests: Vec<StreamRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct PipelineResponse {
+    pub(crate) baton: Option<String>,
+    pub(crate) base_url: Option<String>,
+    pub(crate) results: Vec<StreamResult>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum StreamResult {
+    Ok { response: StreamResponse },
+    Error { error: HranaError },
+}
+
+impl StreamResult {
+    pub(crate) fn into_response(self) -> Result<StreamResponse> {
+        match self {
+            Self::Ok { response } => Ok(response),
+            Self::Error { error } => Err(error.into()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum StreamResponse {
+    Execute { result: StmtResult },
+    Batch { result: BatchResult },
+    Close,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct HranaError {
+    pub(crate) message: String,
+    #[serde(default)]
+    pub(crate) code: Option<String>,
+}
+
+impl From<HranaError> for DrizzleError {
+    fn from(error: HranaError) -> Self {
+        match error.code {
+            Some(code) => Self::Other(format!("{} ({code})", error.message).into()),
+            None => Self::Other(error.message.into()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Col {
+    pub(crate) name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct StmtResult {
+    pub(crate) cols: Vec<Col>,
+    pub(crate) rows: Vec<Vec<Value>>,
+    pub(crate) affected_row_count: u64,
+    #[serde(default)]
+    pub(crate) last_insert_rowid: Option<String>,
+}
+
+impl StmtResult {
+    /// Decodes each row as a column-keyed object into `R`.
+    pub(crate) fn decode_rows<R>(self) -> Result<Vec<R>>
+    where
+        R: for<'de> Deserialize<'de>,
+    {
+        let names: Vec<String> = self
+            .cols
+            .into_iter()
+            .enumerate()
+            .map(|(i, col)| col.name.unwrap_or_else(|| i.to_string()))
+            .collect();
+        self.rows
+            .into_iter()
+            .map(|row| {
+                let object = names
+                    .iter()
+                    .cloned()
+                    .zip(row)
+                    .map(|(name, value)| Ok((name, value.into_json()?)))
+                    .collect::<Result<serde_json::Map<_, _>>>()?;
+                Ok(serde_json::from_value(serde_json::Value::Object(object))?)
+            })
+            .collect()
+    }
+
+    /// Rowid of the last inserted row, if the statement inserted one.
+    pub(crate) fn last_insert_rowid(&self) -> Option<i64> {
+        self.last_insert_rowid.as_deref()?.parse().ok()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct BatchResult {
+    pub(crate) step_results: Vec<Option<StmtResult>>,
+    pub(crate) step_errors: Vec<Option<HranaError>>,
+}
+
+impl BatchResult {
+    /// Returns the first failing step's error, if any step failed.
+    pub(crate) fn into_result(self) -> Result<Vec<Option<StmtResult>>> {
+        if let Some(error) = self.step_errors.into_iter().flatten().next() {
+            return Err(error.into());
+        }
+        Ok(self.step_results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_use_hrana_encoding() {
+        let args: Vec<Value> = [
+            SQLiteValue::Null,
+            SQLiteValue::Integer(i64::MAX),
+            SQLiteValue::Real(1.5),
+            SQLiteValue::Text("hi".into()),
+            SQLiteValue::Blob(vec![1, 2, 3, 4].into()),
+        ]
+        .into_iter()
+        .map(Value::from)
+        .collect();
+
+        assert_eq!(
+            serde_json::to_value(&args).unwrap(),
+            serde_json::json!([
+                {"type": "null"},
+                {"type": "integer", "value": "9223372036854775807"},
+                {"type": "float", "value": 1.5},
+                {"type": "text", "value": "hi"},
+                {"type": "blob", "base64": "AQIDBA"},
+            ])
+        );
+    }
+
+    #[test]
+    fn transactional_batch_chains_conditions() {
+        let batch = Batch::transactional(vec![Stmt::new("INSERT INTO t VALUES (1)", Vec::new())]);
+        let steps = serde_json::to_value(&batch).unwrap()["steps"].clone();
+
+        assert_eq!(steps[0].get("condition"), None);
+        assert_eq!(
+            steps[1]["condition"],
+            serde_json::json!({"type": "ok", "step": 0})
+        );
+        assert_eq!(steps[2]["stmt"]["sql"], "COMMIT");
+        assert_eq!(
+            steps[3]["condition"],
+            serde_json::json!({"type": "not", "cond": {"type": "ok", "step": 2}})
+        );
+    }
+
+    #[test]
+    fn decodes_rows_by_column_name() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Row {
+            id: i64,
+            name: String,
+            data: Option<Vec<u8>>,
+        }
+
+        let response: PipelineResponse = serde_json::from_value(serde_json::json!({
+            "baton": null,
+            "base_url": null,
+            "results": [{
+                "type": "ok",
+                "response": {"type": "execute", "result": {
+                    "cols": [{"name": "id"}, {"name": "name"}, {"name": "data"}],
+                    "rows": [
+                        [{"type": "integer", "value": "1"}, {"type": "text", "value": "a"}, {"type": "blob", "base64": "AQI="}],
+                        [{"type": "integer", "value": "2"}, {"type": "text", "value": "b"}, {"type": "null"}],
+                    ],
+                    "affected_row_count": 0,
+                    "last_insert_rowid": null,
+                }},
+            }],
+        }))
+        .unwrap();
+
+        let StreamResponse::Execute { result } = response
+            .results
+            .into_iter()
+            .next()
+            .unwrap()
+            .into_response()
+            .unwrap()
+        else {
+            panic!("expected execute response");
+        };
+        let rows: Vec<Row> = result.decode_rows().unwrap();
+        assert_eq!(
+            rows,
+            [
+                Row {
+                    id: 1,
+                    name: "a".into(),
+                    data: Some(vec![1, 2]),
+                },
+                Row {
+                    id: 2,
+                    name: "b".into(),
+                    data: None,
+                },
+            ]
+        );
+    }
+}
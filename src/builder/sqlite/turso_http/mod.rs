@@ -0,0 +1,547 @@
+//! Turso / libSQL server driver speaking Hrana over HTTP (async).
+//!
+//! Talks to `sqld` / Turso directly through its `/v2/pipeline` endpoint with
+//! [`reqwest`], so it does not pull in the `libsql` client. On `wasm32`
+//! reqwest goes through `fetch`, which makes this the driver to reach for on
+//! edge runtimes.
+//!
+//! ```toml
+//! [dependencies]
+//! drizzle = { version = "*", features = ["turso-http"] }
+//! ```
+//!
+//! # Quick start
+//!
+//! ```no_run
+//! use drizzle::sqlite::prelude::*;
+//! use drizzle::sqlite::turso_http::{Client, Drizzle};
+//!
+//! #[SQLiteTable]
+//! struct User {
+//!     #[column(primary)]
+//!     id: i32,
+//!     name: String,
+//! }
+//!
+//! #[derive(SQLiteSchema)]
+//! struct AppSchema {
+//!     user: User,
+//! }
+//!
+//! #[derive(serde::Deserialize)]
+//! struct UserRow {
+//!     id: i32,
+//!     name: String,
+//! }
+//!
+//! # async fn run() -> drizzle::Result<()> {
+//! let client = Client::new("libsql://my-db.turso.io", Some("token".into()));
+//! let (db, AppSchema { user }) = Drizzle::new(client, AppSchema::new());
+//!
+//! db.insert(user).values([InsertUser::new("Alice")]).execute().await?;
+//! let users: Vec<UserRow> = db.select(()).from(user).all().await?;
+//! # Ok(()) }
+//! ```
+//!
+//! # Transactions
+//!
+//! [`Drizzle::transaction`] opens a Hrana stream and keeps it alive between
+//! requests, so the callback can read its own writes. Return `Ok(value)` to
+//! commit, `Err(...)` to roll back. [`Drizzle::batch`] runs a list of
+//! statements atomically in a single round trip instead.
+//!
+//! # Notes
+//!
+//! - **Row decoding is serde-based.** Rows come back as column-keyed objects,
+//!   so `all`/`get` decode into any type implementing `serde::Deserialize`.
+//! - **No runtime migrations.** Apply migrations with `drizzle migrate` from
+//!   your deploy pipeline.
+
+mod hrana;
+mod prepared;
+
+use drizzle_core::error::DrizzleError;
+use drizzle_core::prepared::prepare_render;
+use drizzle_core::traits::ToSQL;
+use futures_util::FutureExt;
+
+#[cfg(feature = "sqlite")]
+use drizzle_sqlite::{
+    builder::{self, QueryBuilder},
+    connection::{ExecuteResult, SQLiteTransactionType},
+    values::SQLiteValue,
+};
+
+use hrana::{
+    Batch, PipelineRequest, PipelineResponse, StreamRequest, StreamResponse, StreamResult, Value,
+};
+pub(crate) use hrana::{Stmt, StmtResult};
+
+use crate::transaction::sqlite::turso_http::Transaction;
+
+crate::drizzle_prepare_impl!();
+
+use crate::builder::sqlite::common;
+
+pub type Drizzle<Schema = ()> = common::Drizzle<Client, Schema>;
+pub type DrizzleBuilder<'a, Schema, Builder, State> =
+    common::DrizzleBuilder<'a, common::Drizzle<Client, Schema>, Schema, Builder, State>;
+
+/// HTTP connection to a Turso / libSQL server.
+///
+/// Cheap to clone; clones share the underlying [`reqwest::Client`] pool.
+#[derive(Clone)]
+pub struct Client {
+    http: reqwest::Client,
+    url: String,
+    auth_token: Option<String>,
+}
+
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("url", &self.url)
+            .field(
+                "auth_token",
+                &self.auth_token.as_ref().map(|_| "[REDACTED]"),
+            )
+            .finish()
+    }
+}
+
+impl Client {
+    /// Creates a client for `url`. `libsql://` URLs are sent over `https://`.
+    pub fn new(url: impl AsRef<str>, auth_token: Option<String>) -> Self {
+        Self::with_http_client(reqwest::Client::new(), url, auth_token)
+    }
+
+    /// Creates a client that sends requests through an existing
+    /// [`reqwest::Client`], e.g. one with custom timeouts or proxies.
+    pub fn with_http_client(
+        http: reqwest::Client,
+        url: impl AsRef<str>,
+        auth_token: Option<String>,
+    ) -> Self {
+        Self {
+            http,
+            url: normalize_url(url.as_ref()),
+            auth_token,
+        }
+    }
+
+    /// The server URL requests are sent to.
+    #[inline]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Sends one pipeline request. `base_url` is the stream's sticky URL, if
+    /// the server handed one out.
+    async fn pipeline(
+        &self,
+        base_url: Option<&str>,
+        baton: Option<&str>,
+        requests: Vec<StreamRequest>,
+    ) -> drizzle_core::error::Result<PipelineResponse> {
+        let url = base_url.map_or(self.url.as_str(), |url| url.trim_end_matches('/'));
+        let mut request = self
+            .http
+            .post(format!("{url}/v2/pipeline"))
+            .json(&PipelineRequest { baton, requests });
+        if let Some(token) = &self.auth_token {
+            request = request.bearer_auth(token);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| DrizzleError::Other(e.to_string().into()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(DrizzleError::Other(
+                format!("Hrana request failed with HTTP {status}: {body}").into(),
+            ));
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| DrizzleError::Other(e.to_string().into()))
+    }
+
+    /// Runs `request` on a fresh stream and closes it in the same round trip.
+    async fn request(&self, request: StreamRequest) -> drizzle_core::error::Result<StreamResponse> {
+        let response = self
+            .pipeline(None, None, vec![request, StreamRequest::Close])
+            .await?;
+        first_response(response.results)
+    }
+
+    pub(crate) async fn execute(&self, stmt: Stmt) -> drizzle_core::error::Result<StmtResult> {
+        match self.request(StreamRequest::Execute { stmt }).await? {
+            StreamResponse::Execute { result } => Ok(result),
+            _ => Err(unexpected_response()),
+        }
+    }
+
+    /// Runs `stmts` atomically as one conditional batch.
+    pub(crate) async fn batch(
+        &self,
+        stmts: Vec<Stmt>,
+    ) -> drizzle_core::error::Result<Vec<Option<StmtResult>>> {
+        let batch = Batch::transactional(stmts);
+        match self.request(StreamRequest::Batch { batch }).await? {
+            StreamResponse::Batch { result } => result.into_result(),
+            _ => Err(unexpected_response()),
+        }
+    }
+
+    pub(crate) fn stream(&self) -> Stream {
+        Stream {
+            client: self.clone(),
+            baton: None,
+            base_url: None,
+        }
+    }
+}
+
+/// An open Hrana stream; each request carries the baton from the last one.
+#[derive(Debug)]
+pub(crate) struct Stream {
+    client: Client,
+    baton: Option<String>,
+    base_url: Option<String>,
+}
+
+impl Stream {
+    pub(crate) async fn execute(&mut self, stmt: Stmt) -> drizzle_core::error::Result<StmtResult> {
+        let response = self
+            .client
+            .pipeline(
+                self.base_url.as_deref(),
+                self.baton.as_deref(),
+                vec![StreamRequest::Execute { stmt }],
+            )
+            .await?;
+        self.baton = response.baton;
+        if response.base_url.is_some() {
+            self.base_url = response.base_url;
+        }
+        match first_response(response.results)? {
+            StreamResponse::Execute { result } => Ok(result),
+            _ => Err(unexpected_response()),
+        }
+    }
+
+    /// Closes the stream, rolling back anything left uncommitted.
+    pub(crate) async fn close(&mut self) -> drizzle_core::error::Result<()> {
+        let Some(baton) = self.baton.take() else {
+            return Ok(());
+        };
+        self.client
+            .pipeline(
+                self.base_url.as_deref(),
+                Some(&baton),
+                vec![StreamRequest::Close],
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+fn first_response(results: Vec<StreamResult>) -> drizzle_core::error::Result<StreamResponse> {
+    results
+        .into_iter()
+        .next()
+        .ok_or_else(unexpected_response)?
+        .into_response()
+}
+
+fn unexpected_response() -> DrizzleError {
+    DrizzleError::Other("unexpected Hrana response".into())
+}
+
+/// `libsql://` is the client-side scheme; the HTTP API lives on `https://`.
+fn normalize_url(url: &str) -> String {
+    let url = url.trim_end_matches('/');
+    match url.strip_prefix("libsql://") {
+        Some(rest) => format!("https://{rest}"),
+        None => url.to_string(),
+    }
+}
+
+pub(crate) fn to_stmt<'a, T>(query: &T) -> Stmt
+where
+    T: ToSQL<'a, SQLiteValue<'a>>,
+{
+    let sql = query.to_sql();
+    let (sql_str, params) = sql.build();
+    Stmt::new(sql_str, params.into_iter().map(Value::from).collect())
+}
+
+pub(crate) fn begin_sql(tx_type: SQLiteTransactionType) -> &'static str {
+    match tx_type {
+        SQLiteTransactionType::Deferred => "BEGIN DEFERRED",
+        SQLiteTransactionType::Immediate => "BEGIN IMMEDIATE",
+        SQLiteTransactionType::Exclusive => "BEGIN EXCLUSIVE",
+    }
+}
+
+impl<Schema> common::Drizzle<Client, Schema> {
+    /// Executes a statement and returns the number of affected rows.
+    pub async fn execute<'a, T>(&'a self, query: T) -> drizzle_core::error::Result<u64>
+    where
+        T: ToSQL<'a, SQLiteValue<'a>>,
+    {
+        let result = self.conn.execute(to_stmt(&query)).await?;
+        Ok(result.affected_row_count)
+    }
+
+    /// Runs the query and returns all matching rows deserialized into `R`.
+    ///
+    /// Rows are decoded as objects keyed by column name, so `R` must
+    /// implement [`serde::Deserialize`].
+    pub async fn all<'a, T, R, C>(&'a self, query: T) -> drizzle_core::error::Result<C>
+    where
+        R: for<'de> serde::Deserialize<'de>,
+        T: ToSQL<'a, SQLiteValue<'a>>,
+        C: Default + Extend<R>,
+    {
+        let rows: Vec<R> = self.conn.execute(to_stmt(&query)).await?.decode_rows()?;
+        let mut out = C::default();
+        out.extend(rows);
+        Ok(out)
+    }
+
+    /// Runs the query and returns the first matching row.
+    pub async fn get<'a, T, R>(&'a self, query: T) -> drizzle_core::error::Result<R>
+    where
+        R: for<'de> serde::Deserialize<'de>,
+        T: ToSQL<'a, SQLiteValue<'a>>,
+    {
+        self.all::<_, R, Vec<R>>(query)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(DrizzleError::NotFound)
+    }
+
+    /// Runs multiple statements atomically in a single round trip.
+    ///
+    /// The statements are sent as one Hrana batch wrapped in
+    /// `BEGIN`/`COMMIT`; if any statement fails, the whole batch is rolled
+    /// back and its error returned.
+    pub async fn batch<'a, I, T>(&'a self, statements: I) -> drizzle_core::error::Result<()>
+    where
+        I: IntoIterator<Item = T>,
+        T: ToSQL<'a, SQLiteValue<'a>>,
+    {
+        let stmts: Vec<Stmt> = statements.into_iter().map(|q| to_stmt(&q)).collect();
+        if stmts.is_empty() {
+            return Ok(());
+        }
+        self.conn.batch(stmts).await?;
+        Ok(())
+    }
+
+    /// Executes a transaction with the given callback.
+    ///
+    /// The transaction runs on its own Hrana stream. It commits if the
+    /// callback returns `Ok` and rolls back if it returns `Err` or panics.
+    pub async fn transaction<F, R>(
+        &mut self,
+        tx_type: SQLiteTransactionType,
+        f: F,
+    ) -> drizzle_core::error::Result<R>
+    where
+        Schema: Copy,
+        F: AsyncFnOnce(&Transaction<Schema>) -> drizzle_core::error::Result<R>,
+    {
+        let mut stream = self.conn.stream();
+        stream
+            .execute(Stmt::new(begin_sql(tx_type), Vec::new()))
+            .await?;
+        let transaction = Transaction::new(stream, tx_type, self.schema);
+
+        let outcome = std::panic::AssertUnwindSafe(f(&transaction))
+            .catch_unwind()
+            .await;
+
+        match outcome {
+            Ok(Ok(result)) => {
+                transaction.commit().await?;
+                Ok(result)
+            }
+            Ok(Err(e)) => {
+                let _ = transaction.rollback().await;
+                Err(e)
+            }
+            Err(panic_payload) => {
+                let _ = transaction.rollback().await;
+                std::panic::resume_unwind(panic_payload);
+            }
+        }
+    }
+}
+
+impl<Schema> Drizzle<Schema>
+where
+    Schema: drizzle_core::traits::SQLSchemaImpl + Default,
+{
+    /// Create schema objects from `SQLSchemaImpl` in a single atomic batch.
+    pub async fn create(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.create_statements()?).await
+    }
+
+    /// Like [`create`](Self::create), but objects that already exist are
+    /// left alone, so it is safe to call on every startup.
+    pub async fn create_if_not_exists(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.create_statements()?.map(super::ddl::if_not_exists))
+            .await
+    }
+
+    /// Drop every view, index and table of the schema, the inverse of
+    /// [`create`](Self::create). Tables are dropped referencing tables first.
+    pub async fn drop(&self) -> drizzle_core::error::Result<()> {
+        let schema = Schema::default();
+        self.run_ddl(schema.drop_statements()?).await
+    }
+
+    async fn run_ddl(
+        &self,
+        statements: impl IntoIterator<Item = String>,
+    ) -> drizzle_core::error::Result<()> {
+        let stmts: Vec<Stmt> = statements
+            .into_iter()
+            .map(|sql| Stmt::new(sql, Vec::new()))
+            .collect();
+        if stmts.is_empty() {
+            return Ok(());
+        }
+        self.conn.batch(stmts).await?;
+        Ok(())
+    }
+}
+
+impl<Schema> Drizzle<Schema> {
+    /// Delete every row of `tables` and reset their `AUTOINCREMENT` counters.
+    ///
+    /// Tables that reference others are emptied first, so a parent can be
+    /// truncated together with its children: `db.truncate((users, posts))`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the tables reference each other in a cycle
+    /// or a `DELETE` fails, e.g. on a foreign key from a table left out.
+    pub async fn truncate(
+        &self,
+        tables: impl drizzle_core::traits::TableSet,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(&tables.table_refs()).await
+    }
+
+    /// Truncate every table of `schema`, e.g. before seeding or between tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the tables reference each other in a cycle
+    /// or a `DELETE` fails.
+    pub async fn reset<S: drizzle_core::traits::SQLSchemaImpl>(
+        &self,
+        schema: &S,
+    ) -> drizzle_core::error::Result<()> {
+        self.truncate_tables(schema.table_refs()).await
+    }
+
+    async fn truncate_tables(
+        &self,
+        tables: &[&'static drizzle_core::TableRef],
+    ) -> drizzle_core::error::Result<()> {
+        let statements = super::truncate::truncate_statements(tables)?;
+        let stmts: Vec<Stmt> = statements
+            .into_iter()
+            .map(|sql| Stmt::new(sql, Vec::new()))
+            .collect();
+        if !stmts.is_empty() {
+            self.conn.batch(stmts).await?;
+        }
+        Ok(())
+    }
+}
+
+// =============================================================================
+// Terminal methods on DrizzleBuilder (execute / run / all / get)
+// =============================================================================
+
+#[cfg(feature = "turso-http")]
+impl<'a, 'b, Schema, State, Table, Mk, Rw, Grouped>
+    DrizzleBuilder<'a, Schema, QueryBuilder<'b, Schema, State, Table, Mk, Rw, Grouped>, State>
+where
+    State: builder::ExecutableState,
+{
+    /// Runs the query and returns the number of affected rows.
+    pub async fn execute(self) -> drizzle_core::error::Result<u64> {
+        let result = self.runner.conn.execute(to_stmt(&self.builder.sql)).await?;
+        Ok(result.affected_row_count)
+    }
+
+    /// Runs the query and returns the affected row count together with the
+    /// rowid of the last inserted row.
+    pub async fn run(self) -> drizzle_core::error::Result<ExecuteResult> {
+        let result = self.runner.conn.execute(to_stmt(&self.builder.sql)).await?;
+        Ok(ExecuteResult::new(
+            result.affected_row_count,
+            State::INSERTS,
+            result.last_insert_rowid().unwrap_or_default(),
+        ))
+    }
+
+    /// Runs the query and returns all matching rows deserialized into `R`.
+    pub async fn all<R>(self) -> drizzle_core::error::Result<Vec<R>>
+    where
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runner
+            .conn
+            .execute(to_stmt(&self.builder.sql))
+            .await?
+            .decode_rows()
+    }
+
+    /// Runs the query and returns the first matching row.
+    pub async fn get<R>(self) -> drizzle_core::error::Result<R>
+    where
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.all::<R>()
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(DrizzleError::NotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn libsql_urls_use_https() {
+        assert_eq!(
+            normalize_url("libsql://db-org.turso.io/"),
+            "https://db-org.turso.io"
+        );
+        assert_eq!(
+            normalize_url("http://127.0.0.1:8080"),
+            "http://127.0.0.1:8080"
+        );
+    }
+
+    #[test]
+    fn debug_redacts_auth_token() {
+        let client = Client::new("libsql://db.turso.io", Some("secret".into()));
+        assert!(!format!("{client:?}").contains("secret"));
+    }
+}
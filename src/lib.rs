@@ -45,9 +45,15 @@
 //! | `SQLite`     | rusqlite       | `rusqlite`       | ✅     |
 //! | `SQLite`     | libsql         | `libsql`         | ✅     |
 //! | `SQLite`     | turso          | `turso`          | ✅     |
+//! | `SQLite`     | Turso HTTP     | `turso-http`     | ✅     |
 //! | `PostgreSQL` | postgres       | `postgres-sync`  | ✅     |
 //! | `PostgreSQL` | tokio-postgres | `tokio-postgres` | ✅     |
 //!
+//! Add `postgres-native-tls` for TLS connections to `PostgreSQL`
+//! (`drizzle::postgres::tls`).
+//! Add `libsql-encryption` to encrypt local libsql files
+//! (`drizzle::sqlite::libsql::OpenOptions`).
+//!
 //! For schema declarations, import the database prelude:
 //! - `drizzle::sqlite::prelude::*`
 //! - `drizzle::postgres::prelude::*`
@@ -100,12 +106,19 @@ pub use drizzle_macros::include_migrations;
 /// SQL template macro.
 #[doc(inline)]
 pub use drizzle_macros::sql;
+/// SQL template macro that splices plain `{expr}` instead of binding it.
+#[doc(inline)]
+pub use drizzle_macros::sql_unsafe_raw;
 
 /// Attribute macro for integration tests with DI-style `db` / `schema`
 /// parameters. See [`drizzle_macros::test`] for full documentation.
 #[doc(inline)]
 pub use drizzle_macros::test;
 
+/// Handle restricted to read queries.
+#[doc(inline)]
+pub use builder::read_only::ReadOnly;
+
 /// Database dialect enum.
 #[doc(inline)]
 pub use drizzle_types::Dialect;
@@ -124,7 +137,7 @@ pub use const_format;
 /// Error types.
 pub mod error {
     #[doc(inline)]
-    pub use drizzle_core::error::DrizzleError;
+    pub use drizzle_core::error::{DecodeContext, DrizzleError, type_name};
 }
 
 /// DDL types and schema definitions.
@@ -135,6 +148,14 @@ pub mod ddl {
     pub use drizzle_types::sqlite;
 }
 
+#[cfg(feature = "loader")]
+#[cfg_attr(docsrs, doc(cfg(feature = "loader")))]
+pub mod loader;
+
+#[cfg(feature = "axum")]
+#[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
+pub mod axum;
+
 /// Migration helpers and schema snapshots.
 #[cfg(feature = "std")]
 pub mod migrations {
@@ -147,11 +168,17 @@ pub mod core {
     /// SQL building blocks.
     #[doc(inline)]
     pub use drizzle_core::{
-        ColumnDialect, ColumnFlags, ColumnRef, ConstraintRef, ForeignKeyRef, OrderBy, Param,
-        ParamBind, ParamSet, Placeholder, PrimaryKeyRef, SQL, SQLChunk, TableDialect, TableRef,
-        Token, TypedPlaceholder, asc, desc,
+        BindParams, ColumnDialect, ColumnFlags, ColumnRef, ConstraintRef, ExclusionRef,
+        ForeignKeyRef, OrderBy, Param, ParamBind, ParamSet, Placeholder, PrimaryKeyRef, SQL,
+        SQLChunk, TableDialect, TableRef, Token, TypedPlaceholder, TypedSQL, asc, desc,
+        schema_item_hash, undeferred_fk_cycle,
     };
 
+    /// Children-first table order, for deleting rows or dropping tables.
+    #[cfg(feature = "std")]
+    #[doc(inline)]
+    pub use drizzle_core::truncation_order;
+
     /// Conversion trait for SQL generation.
     #[doc(inline)]
     pub use drizzle_core::ToSQL;
@@ -182,12 +209,25 @@ pub mod core {
     #[doc(inline)]
     pub use drizzle_core::expr;
 
+    /// Heterogeneous-list select targets for any column count.
+    #[doc(inline)]
+    pub use drizzle_core::{HList, hlist, hlist_pat};
+
     #[doc(hidden)]
     pub use drizzle_core::impl_try_from_int;
 
     #[doc(hidden)]
     pub use drizzle_core::schema::SQLEnumInfo;
 
+    #[doc(hidden)]
+    pub use drizzle_core::schema::SQLDomainInfo;
+
+    #[doc(hidden)]
+    pub use drizzle_core::schema::SQLCompositeInfo;
+
+    #[doc(hidden)]
+    pub use drizzle_core::sql_interpolate;
+
     /// Bind parameter type mapping trait.
     #[doc(inline)]
     pub use drizzle_core::ValueTypeForDialect;
@@ -217,10 +257,10 @@ pub mod core {
     #[doc(inline)]
     pub use drizzle_core::row::{
         AfterFullJoin, AfterJoin, AfterLeftJoin, AfterRightJoin, DecodeSelectedRef, ExprValueType,
-        FromDrizzleRow, GroupByIdentity, HasSelectModel, IntoGroupBy, IntoSelectTarget,
-        MarkerColumnCountValid, MarkerScopeValidFor, NullProbeRow, ResolveRow, RowColumnList,
-        SQLTypeToRust, ScopePush, Scoped, SelectAs, SelectAsFrom, SelectCols, SelectExpr,
-        SelectRequiredTables, SelectStar, WrapNullable,
+        FromDrizzleRow, GroupByIdentity, HCons, HNil, HasSelectModel, IntoGroupBy,
+        IntoSelectTarget, MarkerColumnCountValid, MarkerScopeValidFor, NullProbeRow, ResolveRow,
+        RowColumnList, SQLTypeToRust, ScopePush, Scoped, SelectAs, SelectAsFrom, SelectCols,
+        SelectExpr, SelectRequiredTables, SelectStar, SelectTyped, WrapNullable,
     };
 }
 
@@ -241,7 +281,9 @@ pub mod sqlite {
     #[cfg_attr(docsrs, doc(cfg(feature = "rusqlite")))]
     pub mod rusqlite {
         #[doc(inline)]
-        pub use crate::builder::sqlite::rusqlite::{Drizzle, DrizzleBuilder};
+        pub use crate::builder::sqlite::rusqlite::{
+            Drizzle, DrizzleBuilder, InterruptHandle, Pragmas,
+        };
         #[doc(inline)]
         pub use crate::transaction::sqlite::rusqlite::Transaction;
         #[doc(hidden)]
@@ -252,7 +294,9 @@ pub mod sqlite {
     #[cfg_attr(docsrs, doc(cfg(feature = "libsql")))]
     pub mod libsql {
         #[doc(inline)]
-        pub use crate::builder::sqlite::libsql::{Drizzle, DrizzleBuilder};
+        pub use crate::builder::sqlite::libsql::{
+            Drizzle, DrizzleBuilder, InterruptHandle, OpenOptions, Pragmas,
+        };
         #[doc(inline)]
         pub use crate::transaction::sqlite::libsql::Transaction;
         #[doc(hidden)]
@@ -263,13 +307,23 @@ pub mod sqlite {
     #[cfg_attr(docsrs, doc(cfg(feature = "turso")))]
     pub mod turso {
         #[doc(inline)]
-        pub use crate::builder::sqlite::turso::{Drizzle, DrizzleBuilder};
+        pub use crate::builder::sqlite::turso::{Drizzle, DrizzleBuilder, Pragmas};
         #[doc(inline)]
         pub use crate::transaction::sqlite::turso::Transaction;
         #[doc(hidden)]
         pub use ::turso::{Error, IntoValue, Result, Row, Value};
     }
 
+    /// Turso / libSQL server over Hrana-over-HTTP (async, no libsql client).
+    #[cfg(feature = "turso-http")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "turso-http")))]
+    pub mod turso_http {
+        #[doc(inline)]
+        pub use crate::builder::sqlite::turso_http::{Client, Drizzle, DrizzleBuilder};
+        #[doc(inline)]
+        pub use crate::transaction::sqlite::turso_http::Transaction;
+    }
+
     /// Cloudflare D1 driver (async, WASM-only).
     #[cfg(all(feature = "d1", target_arch = "wasm32"))]
     #[cfg_attr(docsrs, doc(cfg(all(feature = "d1", target_arch = "wasm32"))))]
@@ -292,22 +346,25 @@ pub mod sqlite {
     pub mod prelude {
         // Core types and traits
         pub use crate::core::ToSQL;
-        pub use crate::core::{Joinable, Relation, SchemaHasTable};
         pub use crate::core::{
-            OrderBy, Param, ParamBind, ParamSet, Placeholder, SQL, SQLChunk, Token,
+            BindParams, OrderBy, Param, ParamBind, ParamSet, Placeholder, SQL, SQLChunk, Token,
             TypedPlaceholder, asc, desc,
         };
+        pub use crate::core::{Joinable, Relation, SchemaHasTable};
         pub use crate::core::{OwnedPreparedStatement, PreparedStatement};
         pub use drizzle_core::tag;
         pub use drizzle_core::traits::*;
         // SQLite macros
         pub use drizzle_macros::{
-            SQLiteEnum, SQLiteFromRow, SQLiteIndex, SQLiteSchema, SQLiteTable, SQLiteView,
+            BindParams, SQLiteEnum, SQLiteFromRow, SQLiteIndex, SQLiteSchema, SQLiteTable,
+            SQLiteView,
         };
         // SQLite types
         pub use drizzle_sqlite::attrs::*;
         pub use drizzle_sqlite::common::SQLiteSchemaType;
-        pub use drizzle_sqlite::traits::{DrizzleSQLiteColumn, SQLiteColumn, SQLiteTable};
+        pub use drizzle_sqlite::traits::{
+            CustomSQLiteType, DrizzleSQLiteColumn, SQLiteColumn, SQLiteTable,
+        };
         pub use drizzle_sqlite::values::{
             OwnedSQLiteValue, SQLiteInsertValue, SQLiteUpdateValue, SQLiteValue, SQLiteValueRef,
         };
@@ -320,14 +377,18 @@ pub mod sqlite {
 pub mod postgres {
     #[doc(inline)]
     pub use drizzle_macros::{
-        PostgresEnum, PostgresFromRow, PostgresIndex, PostgresPolicy, PostgresSchema,
-        PostgresTable, PostgresView,
+        PostgresComposite, PostgresDomain, PostgresEnum, PostgresFromRow, PostgresIndex,
+        PostgresPolicy, PostgresSchema, PostgresTable, PostgresView,
     };
     #[doc(hidden)]
     pub use drizzle_postgres::driver_types;
     #[doc(inline)]
     pub use drizzle_postgres::{attrs, builder, common, expr, helpers, traits, types, values};
 
+    #[cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+    #[doc(hidden)]
+    pub use drizzle_postgres::composite;
+
     #[cfg(all(feature = "postgres-sync", not(feature = "tokio-postgres")))]
     #[doc(inline)]
     pub use drizzle_postgres::Row;
@@ -364,6 +425,17 @@ pub mod postgres {
         pub use crate::transaction::postgres::tokio_postgres::Transaction;
     }
 
+    /// TLS for the `postgres` / `tokio-postgres` drivers, via `native-tls`.
+    #[cfg(all(
+        feature = "postgres-native-tls",
+        any(feature = "postgres-sync", feature = "tokio-postgres")
+    ))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "postgres-native-tls")))]
+    pub mod tls {
+        #[doc(inline)]
+        pub use crate::builder::postgres::tls::{SslMode, TlsConfig};
+    }
+
     /// AWS Aurora Serverless Data API driver (HTTP-based, async).
     #[cfg(feature = "aws-data-api")]
     #[cfg_attr(docsrs, doc(cfg(feature = "aws-data-api")))]
@@ -378,25 +450,27 @@ pub mod postgres {
     pub mod prelude {
         // Core types and traits
         pub use crate::core::ToSQL;
-        pub use crate::core::{Joinable, Relation, SchemaHasTable};
         pub use crate::core::{
-            OrderBy, Param, ParamBind, ParamSet, Placeholder, SQL, SQLChunk, Token,
+            BindParams, OrderBy, Param, ParamBind, ParamSet, Placeholder, SQL, SQLChunk, Token,
             TypedPlaceholder, asc, desc,
         };
+        pub use crate::core::{Joinable, Relation, SchemaHasTable};
         pub use crate::core::{OwnedPreparedStatement, PreparedStatement};
         pub use drizzle_core::tag;
         pub use drizzle_core::traits::*;
         // PostgreSQL macros
         pub use drizzle_macros::{
-            PostgresEnum, PostgresFromRow, PostgresIndex, PostgresPolicy, PostgresSchema,
-            PostgresTable, PostgresView,
+            BindParams, PostgresComposite, PostgresDomain, PostgresEnum, PostgresFromRow,
+            PostgresIndex, PostgresPolicy, PostgresSchema, PostgresTable, PostgresView,
         };
         // PostgreSQL types
         pub use drizzle_postgres::attrs::*;
         pub use drizzle_postgres::common::PostgresSchemaType;
-        pub use drizzle_postgres::traits::{DrizzlePostgresColumn, PostgresColumn, PostgresTable};
+        pub use drizzle_postgres::traits::{
+            CustomPostgresType, DrizzlePostgresColumn, PostgresColumn, PostgresTable,
+        };
         pub use drizzle_postgres::values::{
-            OwnedPostgresValue, PostgresInsertValue, PostgresUpdateValue, PostgresValue,
+            OwnedPostgresValue, PgRange, PostgresInsertValue, PostgresUpdateValue, PostgresValue,
         };
     }
 }
@@ -0,0 +1,149 @@
+//! Batching, caching loader for async drivers.
+//!
+//! A [`Loader`] wraps a fetch function, usually one that calls `get_many` on a
+//! primary key or unique column, and answers single and multi-key lookups
+//! from one query per batch of uncached keys. Results (including misses) are
+//! cached for the lifetime of the loader, so create one per request.
+//!
+//! ```rust
+//! # let _ = r####"
+//! use drizzle::loader::Loader;
+//! use drizzle::sqlite::libsql::Drizzle;
+//!
+//! let users = Loader::new(move |ids: Vec<i32>| {
+//!     let db = db.clone();
+//!     async move { db.get_many(user, user.id, &ids, |u: &SelectUser| u.id).await }
+//! });
+//!
+//! let alice = users.load_one(1).await?;
+//! let team = users.load_many(&[1, 2, 3]).await?;
+//! # "####;
+//! ```
+//!
+//! With the `async-graphql` feature, [`Loader`] also implements
+//! `async_graphql::dataloader::Loader`, so it can be handed to
+//! `DataLoader::new` to batch lookups across concurrent resolvers.
+
+use core::future::Future;
+use core::hash::Hash;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use drizzle_core::error::Result;
+
+/// Batches key lookups into a single fetch and caches the results.
+///
+/// `fetch` receives the deduplicated keys missing from the cache and must
+/// return one entry per key, in the same order, with `None` for keys that
+/// have no row. `get_many` already has that shape.
+///
+/// The loader is `Send + Sync` whenever the fetch function, keys and values
+/// are, so it can be shared across tasks behind an `Arc`.
+pub struct Loader<K, V, F> {
+    fetch: F,
+    cache: Mutex<HashMap<K, Option<V>>>,
+}
+
+impl<K, V, F> core::fmt::Debug for Loader<K, V, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Loader").finish_non_exhaustive()
+    }
+}
+
+impl<K, V, F, Fut> Loader<K, V, F>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(Vec<K>) -> Fut,
+    Fut: Future<Output = Result<Vec<Option<V>>>>,
+{
+    /// Creates a loader around `fetch`.
+    pub fn new(fetch: F) -> Self {
+        Self {
+            fetch,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Loads the value for `key`, fetching it if it is not cached.
+    pub async fn load_one(&self, key: K) -> Result<Option<V>> {
+        let mut values = self.load_many(core::slice::from_ref(&key)).await?;
+        Ok(values.pop().flatten())
+    }
+
+    /// Loads the values for `keys`, in the same order.
+    ///
+    /// Uncached keys are fetched in one call; repeated keys are only fetched
+    /// once. If the fetch fails nothing is cached.
+    pub async fn load_many(&self, keys: &[K]) -> Result<Vec<Option<V>>> {
+        let missing: Vec<K> = {
+            let cache = self.lock();
+            let mut seen = HashSet::new();
+            keys.iter()
+                .filter(|key| !cache.contains_key(key) && seen.insert(*key))
+                .cloned()
+                .collect()
+        };
+
+        if !missing.is_empty() {
+            let values = (self.fetch)(missing.clone()).await?;
+            let mut cache = self.lock();
+            for (key, value) in missing.into_iter().zip(values) {
+                cache.insert(key, value);
+            }
+        }
+
+        let cache = self.lock();
+        Ok(keys
+            .iter()
+            .map(|key| cache.get(key).cloned().flatten())
+            .collect())
+    }
+
+    /// Seeds the cache with a known value, e.g. a row that was just inserted.
+    pub fn prime(&self, key: K, value: V) {
+        self.lock().insert(key, Some(value));
+    }
+
+    /// Drops the cached entry for `key` so the next load refetches it.
+    pub fn clear(&self, key: &K) {
+        self.lock().remove(key);
+    }
+
+    /// Drops every cached entry.
+    pub fn clear_all(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<K, Option<V>>> {
+        self.cache.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// Bypasses the loader's own cache: `DataLoader` keeps its own, and keys it
+/// asks for are already deduplicated. Errors are wrapped in an `Arc` because
+/// `DataLoader` hands the same error to every waiting resolver.
+#[cfg(feature = "async-graphql")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async-graphql")))]
+impl<K, V, F, Fut> async_graphql::dataloader::Loader<K> for Loader<K, V, F>
+where
+    K: Hash + Eq + Clone + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+    F: Fn(Vec<K>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<Vec<Option<V>>>> + Send,
+{
+    type Value = V;
+    type Error = std::sync::Arc<drizzle_core::error::DrizzleError>;
+
+    async fn load(&self, keys: &[K]) -> core::result::Result<HashMap<K, V>, Self::Error> {
+        let values = (self.fetch)(keys.to_vec())
+            .await
+            .map_err(std::sync::Arc::new)?;
+        Ok(keys
+            .iter()
+            .cloned()
+            .zip(values)
+            .filter_map(|(key, value)| Some((key, value?)))
+            .collect())
+    }
+}
@@ -355,6 +355,29 @@ macro_rules! sqlite_transaction_constructors {
                 state: PhantomData,
             }
         }
+
+        /// Creates a `CREATE TEMP TABLE ... AS SELECT ...` statement within the transaction
+        #[cfg(feature = "sqlite")]
+        pub fn create_temp_table_as<'tx, 'q, Table, Query>(
+            &'tx self,
+            view: &builder::CTEView<'q, Table, Query>,
+        ) -> TransactionBuilder<
+            'tx,
+            $($conn_lt,)*
+            Schema,
+            QueryBuilder<'q, Schema, builder::CreateTempTableSet>,
+            builder::CreateTempTableSet,
+        >
+        where
+            Query: ToSQL<'q, SQLiteValue<'q>>,
+        {
+            let builder = QueryBuilder::new::<Schema>().create_temp_table_as(view);
+            TransactionBuilder {
+                runner: self,
+                builder,
+                state: PhantomData,
+            }
+        }
     };
 }
 
@@ -498,5 +521,27 @@ macro_rules! postgres_transaction_constructors {
                 state: PhantomData,
             }
         }
+
+        /// Creates a `CREATE TEMP TABLE ... AS SELECT ...` statement within the transaction
+        pub fn create_temp_table_as<'tx, 'q, Table, Query>(
+            &'tx self,
+            view: &builder::CTEView<'q, Table, Query>,
+        ) -> TransactionBuilder<
+            'tx,
+            $($conn_lt,)*
+            Schema,
+            QueryBuilder<'q, Schema, builder::CreateTempTableSet>,
+            builder::CreateTempTableSet,
+        >
+        where
+            Query: ToSQL<'q, PostgresValue<'q>>,
+        {
+            let builder = QueryBuilder::new::<Schema>().create_temp_table_as(view);
+            TransactionBuilder {
+                runner: self,
+                builder,
+                state: PhantomData,
+            }
+        }
     };
 }
@@ -143,6 +143,52 @@ impl<'conn, Schema> Transaction<'conn, Schema> {
         )
     }
 
+    /// Overrides `search_path` until this transaction ends.
+    ///
+    /// Runs `SET LOCAL`, so the session's own `search_path` comes back on
+    /// commit or rollback. Inside a savepoint that rolls back, the previous
+    /// value is restored too.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if `schemas` is empty or holds an empty name,
+    /// or if the `SET` fails.
+    pub fn set_search_path<I>(&self, schemas: I) -> drizzle_core::error::Result<()>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let sql = crate::builder::postgres::tenant::local_search_path_sql(schemas)?;
+        self.execute_raw(&sql)
+    }
+
+    /// Defers every deferrable constraint check to commit.
+    ///
+    /// Runs `SET CONSTRAINTS ALL DEFERRED`, which lets rows that reference
+    /// each other in a cycle be inserted in any order. Only constraints
+    /// declared `DEFERRABLE` are affected; the setting ends with the
+    /// transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the `SET` fails.
+    pub fn set_constraints_deferred(&self) -> drizzle_core::error::Result<()> {
+        self.execute_raw("SET CONSTRAINTS ALL DEFERRED")
+    }
+
+    /// Checks every deferrable constraint immediately again.
+    ///
+    /// Runs `SET CONSTRAINTS ALL IMMEDIATE`, which also validates any checks
+    /// still pending from earlier statements in this transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the `SET` fails or a pending check is
+    /// violated.
+    pub fn set_constraints_immediate(&self) -> drizzle_core::error::Result<()> {
+        self.execute_raw("SET CONSTRAINTS ALL IMMEDIATE")
+    }
+
     postgres_transaction_constructors!('conn);
 
     /// Execute a statement within the transaction and return the number of affected rows.
@@ -2,6 +2,7 @@
     feature = "rusqlite",
     feature = "turso",
     feature = "libsql",
+    feature = "turso-http",
     feature = "durable"
 ))]
 pub mod typestate;
@@ -15,5 +16,8 @@ pub mod turso;
 #[cfg(feature = "libsql")]
 pub mod libsql;
 
+#[cfg(feature = "turso-http")]
+pub mod turso_http;
+
 #[cfg(all(feature = "durable", target_arch = "wasm32"))]
 pub mod durable;
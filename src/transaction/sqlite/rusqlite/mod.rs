@@ -17,7 +17,7 @@ use drizzle_sqlite::{
         self, QueryBuilder, delete::DeleteBuilder, insert::InsertBuilder, select::SelectBuilder,
         update::UpdateBuilder,
     },
-    connection::SQLiteTransactionType,
+    connection::{ExecuteResult, SQLiteTransactionType},
     values::SQLiteValue,
 };
 
@@ -265,6 +265,18 @@ where
         Ok(self.runner.tx.execute(&sql_str, params_from_iter(params))?)
     }
 
+    /// Runs the query and returns the affected row count together with the
+    /// rowid of the last inserted row.
+    pub fn run(self) -> drizzle_core::error::Result<ExecuteResult> {
+        let tx = &self.runner.tx;
+        let rows_affected = self.execute()?;
+        Ok(ExecuteResult::new(
+            rows_affected as u64,
+            State::INSERTS,
+            tx.last_insert_rowid(),
+        ))
+    }
+
     /// Runs the query and returns all matching rows using the builder's row type.
     pub fn all<R, Proof, AggProof>(self) -> drizzle_core::error::Result<Vec<R>>
     where
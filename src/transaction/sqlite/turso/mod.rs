@@ -16,7 +16,7 @@ use drizzle_sqlite::{
         self, QueryBuilder, delete::DeleteBuilder, insert::InsertBuilder, select::SelectBuilder,
         update::UpdateBuilder,
     },
-    connection::SQLiteTransactionType,
+    connection::{ExecuteResult, SQLiteTransactionType},
     values::SQLiteValue,
 };
 
@@ -247,6 +247,18 @@ where
         Ok(self.runner.tx.execute(&sql_str, params).await?)
     }
 
+    /// Runs the query and returns the affected row count together with the
+    /// rowid of the last inserted row.
+    pub async fn run(self) -> drizzle_core::error::Result<ExecuteResult> {
+        let tx = &self.runner.tx;
+        let rows_affected = self.execute().await?;
+        Ok(ExecuteResult::new(
+            rows_affected,
+            State::INSERTS,
+            tx.last_insert_rowid(),
+        ))
+    }
+
     /// Runs the query and returns all matching rows using the builder's row type.
     pub async fn all<R, Proof, AggProof>(self) -> drizzle_core::error::Result<Vec<R>>
     where
@@ -0,0 +1,232 @@
+//! Transaction wrapper for the Turso HTTP driver.
+//!
+//! Obtained via
+//! [`Drizzle::transaction`](crate::builder::sqlite::turso_http::Drizzle::transaction).
+//! Every query runs on the transaction's Hrana stream, one request at a time.
+
+use drizzle_core::error::DrizzleError;
+use drizzle_core::traits::ToSQL;
+use futures_util::lock::Mutex;
+use std::marker::PhantomData;
+
+use crate::builder::sqlite::turso_http::{Stmt, StmtResult, Stream, to_stmt};
+use crate::transaction::savepoint::{AsyncSavepointState, async_savepoint};
+
+#[cfg(feature = "sqlite")]
+use drizzle_sqlite::{
+    builder::{
+        self, DeleteInitial, InsertInitial, QueryBuilder, SelectInitial, UpdateInitial,
+        delete::DeleteBuilder, insert::InsertBuilder, select::SelectBuilder, update::UpdateBuilder,
+    },
+    connection::{ExecuteResult, SQLiteTransactionType},
+    traits::SQLiteTable,
+    values::SQLiteValue,
+};
+
+/// Query builder scoped to a [`Transaction`]. See
+/// [`crate::transaction::sqlite::typestate::TransactionBuilder`] for the
+/// typestate-advancing methods; executor methods live below in this module.
+pub type TransactionBuilder<'tx, Schema, Builder, State> =
+    crate::transaction::sqlite::typestate::TransactionBuilder<
+        'tx,
+        Transaction<Schema>,
+        Schema,
+        Builder,
+        State,
+    >;
+
+/// Transaction handle bound to an open Hrana stream.
+///
+/// Provides the same query-building surface as
+/// [`Drizzle`](crate::builder::sqlite::turso_http::Drizzle) plus
+/// [`Transaction::savepoint`] for nested savepoints.
+#[derive(Debug)]
+pub struct Transaction<Schema = ()> {
+    stream: Mutex<Stream>,
+    tx_type: SQLiteTransactionType,
+    savepoints: AsyncSavepointState,
+    schema: Schema,
+}
+
+impl<Schema> Transaction<Schema> {
+    pub(crate) fn new(stream: Stream, tx_type: SQLiteTransactionType, schema: Schema) -> Self {
+        Self {
+            stream: Mutex::new(stream),
+            tx_type,
+            savepoints: AsyncSavepointState::new(),
+            schema,
+        }
+    }
+
+    /// Gets a reference to the schema.
+    #[inline]
+    pub const fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Gets the transaction type
+    #[inline]
+    pub const fn tx_type(&self) -> SQLiteTransactionType {
+        self.tx_type
+    }
+
+    /// Sends a statement on the transaction's stream.
+    async fn run_stmt(&self, stmt: Stmt) -> drizzle_core::error::Result<StmtResult> {
+        self.savepoints.ensure_usable()?;
+        self.stream.lock().await.execute(stmt).await
+    }
+
+    /// Executes a nested savepoint within this transaction.
+    ///
+    /// The callback receives a reference to this transaction for executing
+    /// queries. If the callback returns `Ok`, the savepoint is released.
+    /// If it returns `Err`, the savepoint is rolled back.
+    /// The outer transaction is unaffected either way.
+    ///
+    /// Savepoints can be nested — each level gets its own savepoint name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the savepoint cannot be created/released, or the inner closure returns an error.
+    pub async fn savepoint<F, R>(&self, f: F) -> drizzle_core::error::Result<R>
+    where
+        F: AsyncFnOnce(&Self) -> drizzle_core::error::Result<R>,
+    {
+        async_savepoint(
+            &self.savepoints,
+            |sql| async move { self.run_stmt(Stmt::new(sql, Vec::new())).await.map(|_| ()) },
+            f(self),
+        )
+        .await
+    }
+
+    sqlite_transaction_constructors!();
+
+    /// Executes a raw query within the transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the request fails or the SQL is invalid.
+    pub async fn execute<'q, T>(&self, query: T) -> drizzle_core::error::Result<u64>
+    where
+        T: ToSQL<'q, SQLiteValue<'q>>,
+    {
+        Ok(self.run_stmt(to_stmt(&query)).await?.affected_row_count)
+    }
+
+    /// Runs a query and returns all matching rows within the transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the query fails or row decoding fails.
+    pub async fn all<'q, T, R, C>(&self, query: T) -> drizzle_core::error::Result<C>
+    where
+        R: for<'de> serde::Deserialize<'de>,
+        T: ToSQL<'q, SQLiteValue<'q>>,
+        C: Default + Extend<R>,
+    {
+        let rows: Vec<R> = self.run_stmt(to_stmt(&query)).await?.decode_rows()?;
+        let mut out = C::default();
+        out.extend(rows);
+        Ok(out)
+    }
+
+    /// Runs a query and returns a single row within the transaction
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the query fails, no rows match (returns `DrizzleError::NotFound`), or decoding fails.
+    pub async fn get<'q, T, R>(&self, query: T) -> drizzle_core::error::Result<R>
+    where
+        R: for<'de> serde::Deserialize<'de>,
+        T: ToSQL<'q, SQLiteValue<'q>>,
+    {
+        self.all::<_, R, Vec<R>>(query)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(DrizzleError::NotFound)
+    }
+
+    /// Commits the transaction and closes its stream
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the commit fails.
+    pub async fn commit(self) -> drizzle_core::error::Result<()> {
+        if let Err(error) = self.savepoints.ensure_usable() {
+            self.rollback().await?;
+            return Err(error);
+        }
+        let mut stream = self.stream.into_inner();
+        let committed = stream.execute(Stmt::new("COMMIT", Vec::new())).await;
+        let closed = stream.close().await;
+        committed?;
+        closed
+    }
+
+    /// Rolls back the transaction and closes its stream
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DrizzleError`] if the rollback fails.
+    pub async fn rollback(self) -> drizzle_core::error::Result<()> {
+        let mut stream = self.stream.into_inner();
+        let rolled_back = stream.execute(Stmt::new("ROLLBACK", Vec::new())).await;
+        // Closing the stream rolls back whatever is still open.
+        let closed = stream.close().await;
+        rolled_back?;
+        closed
+    }
+}
+
+// =============================================================================
+// Terminal methods on TransactionBuilder (execute / run / all / get)
+// =============================================================================
+
+#[cfg(feature = "turso-http")]
+impl<'tx, 'q, Schema, State, Table, Mk, Rw, Grouped>
+    TransactionBuilder<'tx, Schema, QueryBuilder<'q, Schema, State, Table, Mk, Rw, Grouped>, State>
+where
+    State: builder::ExecutableState,
+{
+    /// Runs the query and returns the number of affected rows.
+    pub async fn execute(self) -> drizzle_core::error::Result<u64> {
+        let result = self.runner.run_stmt(to_stmt(&self.builder.sql)).await?;
+        Ok(result.affected_row_count)
+    }
+
+    /// Runs the query and returns the affected row count together with the
+    /// rowid of the last inserted row.
+    pub async fn run(self) -> drizzle_core::error::Result<ExecuteResult> {
+        let result = self.runner.run_stmt(to_stmt(&self.builder.sql)).await?;
+        Ok(ExecuteResult::new(
+            result.affected_row_count,
+            State::INSERTS,
+            result.last_insert_rowid().unwrap_or_default(),
+        ))
+    }
+
+    /// Runs the query and returns all matching rows deserialized into `R`.
+    pub async fn all<R>(self) -> drizzle_core::error::Result<Vec<R>>
+    where
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.runner
+            .run_stmt(to_stmt(&self.builder.sql))
+            .await?
+            .decode_rows()
+    }
+
+    /// Runs the query and returns the first matching row.
+    pub async fn get<R>(self) -> drizzle_core::error::Result<R>
+    where
+        R: for<'de> serde::Deserialize<'de>,
+    {
+        self.all::<R>()
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(DrizzleError::NotFound)
+    }
+}
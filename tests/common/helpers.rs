@@ -974,6 +974,70 @@ pub mod turso_setup {
     }
 }
 
+/// Embedded PostgreSQL server for the Postgres test variants, used when
+/// `DATABASE_URL` is unset and the `postgres-embedded` feature is enabled.
+#[cfg(feature = "postgres-embedded")]
+pub mod postgres_embedded {
+    use postgresql_embedded::blocking::PostgreSQL;
+    use postgresql_embedded::{SettingsBuilder, Status};
+    use std::path::PathBuf;
+    use std::sync::OnceLock;
+    use std::thread;
+
+    const PORT: u16 = 54329;
+    const DATABASE: &str = "drizzle_test";
+
+    static URL: OnceLock<String> = OnceLock::new();
+
+    /// URL of the embedded server, starting it on first use.
+    ///
+    /// The server keeps running after the test binary exits, like the Docker
+    /// container does, so later runs reuse it instead of starting a new one.
+    pub fn database_url() -> String {
+        URL.get_or_init(|| {
+            // The blocking API drives its own runtime, which panics when called
+            // from a `#[tokio::test]`, so start the server on a plain thread.
+            thread::spawn(start)
+                .join()
+                .expect("embedded PostgreSQL failed to start")
+        })
+        .clone()
+    }
+
+    fn start() -> String {
+        let root = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("postgres-embedded");
+        let settings = SettingsBuilder::new()
+            .data_dir(root.join("data"))
+            .password_file(root.join(".pgpass"))
+            .port(PORT)
+            .username("postgres")
+            .password("postgres")
+            .temporary(false)
+            .build();
+        let url = settings.url(DATABASE);
+
+        let mut server = PostgreSQL::new(settings);
+        server
+            .setup()
+            .expect("Failed to install embedded PostgreSQL");
+        if server.status() != Status::Started {
+            println!("Starting embedded PostgreSQL on port {PORT}...");
+            server.start().expect("Failed to start embedded PostgreSQL");
+        }
+        if !server
+            .database_exists(DATABASE)
+            .expect("Failed to query embedded PostgreSQL")
+        {
+            server
+                .create_database(DATABASE)
+                .expect("Failed to create test database");
+        }
+        // Dropping the handle would stop the server.
+        std::mem::forget(server);
+        url
+    }
+}
+
 #[cfg(feature = "postgres-sync")]
 pub mod postgres_sync_setup {
     use super::{CapturedStatement, FailureContext, failure_report};
@@ -992,9 +1056,13 @@ pub mod postgres_sync_setup {
     static TEST_COUNTER: AtomicU64 = AtomicU64::new(0);
 
     fn get_database_url() -> String {
-        std::env::var("DATABASE_URL").unwrap_or_else(|_| {
-            "host=localhost user=postgres password=postgres dbname=drizzle_test".to_string()
-        })
+        if let Ok(url) = std::env::var("DATABASE_URL") {
+            return url;
+        }
+        #[cfg(feature = "postgres-embedded")]
+        return super::postgres_embedded::database_url();
+        #[cfg(not(feature = "postgres-embedded"))]
+        "host=localhost user=postgres password=postgres dbname=drizzle_test".to_string()
     }
 
     fn ensure_postgres_running() {
@@ -1314,10 +1382,14 @@ pub mod tokio_postgres_setup {
     static DOCKER_STARTED: Once = Once::new();
     static TEST_COUNTER: AtomicU64 = AtomicU64::new(0);
 
-    fn get_database_url() -> String {
-        std::env::var("DATABASE_URL").unwrap_or_else(|_| {
-            "host=localhost user=postgres password=postgres dbname=drizzle_test".to_string()
-        })
+    pub fn get_database_url() -> String {
+        if let Ok(url) = std::env::var("DATABASE_URL") {
+            return url;
+        }
+        #[cfg(feature = "postgres-embedded")]
+        return super::postgres_embedded::database_url();
+        #[cfg(not(feature = "postgres-embedded"))]
+        "host=localhost user=postgres password=postgres dbname=drizzle_test".to_string()
     }
 
     /// Check if postgres is reachable (runs on a separate thread with its own runtime)
@@ -120,6 +120,24 @@ fn sqlite_macro_errors_ui() {
     must_fail("tests/ui/sqlite_macro_errors/fail/*.rs");
 }
 
+#[cfg(feature = "postgres")]
+#[test]
+fn postgres_macro_errors_ui() {
+    must_fail("tests/ui/postgres_macro_errors/fail/*.rs");
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn timestamp_policy_postgres_ui() {
+    must_fail("tests/ui/timestamp_policy/postgres/fail/*.rs");
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn timestamp_policy_sqlite_ui() {
+    must_fail("tests/ui/timestamp_policy/sqlite/fail/*.rs");
+}
+
 #[cfg(feature = "postgres")]
 #[test]
 fn boolean_enforcement_ui() {
@@ -154,6 +172,13 @@ fn pagination_sqlite_ui() {
     must_fail("tests/ui/pagination_sqlite/fail/*.rs");
 }
 
+#[cfg(feature = "rusqlite")]
+#[test]
+fn typed_placeholder_sqlite_ui() {
+    must_pass("tests/ui/typed_placeholder_sqlite/pass/*.rs");
+    must_fail("tests/ui/typed_placeholder_sqlite/fail/*.rs");
+}
+
 #[cfg(feature = "rusqlite")]
 #[test]
 fn aggregate_mixing_sqlite_ui() {
@@ -361,4 +361,77 @@ mod tokio_postgres_edge_cases {
         // Clone dropped — conn_mut should work again
         assert!(db.conn_mut().is_some());
     }
+
+    #[tokio::test]
+    async fn ping_succeeds_and_reconnect_is_noop_while_open() {
+        let (mut db, _) = tokio_postgres_setup::setup_db::<SimpleSchema>().await;
+
+        db.ping().await.unwrap();
+        assert!(!db.is_closed());
+        assert!(!db.reconnect().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn reconnect_replaces_closed_client() {
+        async fn connect() -> Result<tokio_postgres::Client, tokio_postgres::Error> {
+            let url = tokio_postgres_setup::get_database_url();
+            let (client, connection) = tokio_postgres::connect(&url, tokio_postgres::NoTls).await?;
+            tokio::spawn(connection);
+            Ok(client)
+        }
+
+        // Starts the database if needed.
+        let _setup = tokio_postgres_setup::setup_db::<SimpleSchema>().await;
+
+        let url = tokio_postgres_setup::get_database_url();
+        let (client, connection) = tokio_postgres::connect(&url, tokio_postgres::NoTls)
+            .await
+            .unwrap();
+        let task = tokio::spawn(connection);
+        task.abort();
+        let _ = task.await;
+
+        let (mut db, ()) = drizzle::postgres::tokio::Drizzle::new(client, ());
+        assert!(db.is_closed());
+        assert!(db.ping().await.unwrap_err().is_connection_error());
+        assert!(db.reconnect().await.is_err(), "no reconnect callback");
+
+        let mut db = db.with_reconnect(connect);
+        assert!(db.reconnect().await.unwrap());
+        assert!(!db.is_closed());
+        db.ping().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn statement_timeout_cancels_slow_queries() {
+        let (db, _) = tokio_postgres_setup::setup_db::<SimpleSchema>().await;
+
+        db.set_statement_timeout(Some(Duration::from_millis(50)))
+            .await
+            .unwrap();
+        let error: drizzle::error::DrizzleError = db
+            .conn()
+            .batch_execute("SELECT pg_sleep(5)")
+            .await
+            .unwrap_err()
+            .into();
+        assert!(
+            matches!(error, drizzle::error::DrizzleError::Timeout(_)),
+            "{error:?}"
+        );
+
+        db.set_statement_timeout(None).await.unwrap();
+        let error = db
+            .with_timeout(Duration::from_millis(50), async |db| {
+                db.conn().batch_execute("SELECT pg_sleep(5)").await?;
+                Ok(())
+            })
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, drizzle::error::DrizzleError::Timeout(_)),
+            "{error:?}"
+        );
+        db.ping().await.unwrap();
+    }
 }
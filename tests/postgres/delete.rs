@@ -251,3 +251,31 @@ fn delete_all_rows(db: &mut TestDb<SimpleSchema>) {
 
     assert_eq!(results.len(), 0);
 }
+
+#[drizzle::test]
+fn delete_using_joined_table(db: &mut TestDb<FullBlogSchema>) {
+    let FullBlogSchema {
+        simple, category, ..
+    } = schema;
+
+    db.insert(simple)
+        .values([InsertSimple::new("Alice"), InsertSimple::new("Bob")])
+        .execute();
+    db.insert(category)
+        .values([InsertCategory::new("Bob")])
+        .execute();
+
+    let stmt = db
+        .delete(simple)
+        .using(category)
+        .r#where(eq(simple.name, category.name));
+    assert_eq!(
+        stmt.to_sql().sql(),
+        r#"DELETE FROM "simple" USING "category" WHERE "simple"."name" = "category"."name""#
+    );
+    stmt.execute();
+
+    let results: Vec<SelectSimple> = db.select((simple.id, simple.name)).from(simple).all();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name, "Alice");
+}
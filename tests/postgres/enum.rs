@@ -4,6 +4,7 @@
 #[cfg(feature = "postgres")]
 mod unit_tests {
     use crate::common::schema::postgres::*;
+    use drizzle::postgres::prelude::*;
 
     #[test]
     fn test_enum_from_str() {
@@ -35,6 +36,37 @@ mod unit_tests {
         assert!(matches!(default, Role::User));
     }
 
+    #[derive(PostgresEnum, Default, Clone, PartialEq, Debug)]
+    #[drizzle(rename_all = "snake_case")]
+    enum TicketState {
+        #[default]
+        InProgress,
+        OnHold,
+        #[drizzle(rename = "done")]
+        Closed,
+    }
+
+    #[test]
+    fn test_enum_rename_attributes() {
+        use drizzle::core::SQLEnumInfo;
+
+        assert_eq!(TicketState::InProgress.to_string(), "in_progress");
+        assert_eq!(TicketState::Closed.to_string(), "done");
+        assert_eq!(
+            "on_hold".parse::<TicketState>().unwrap(),
+            TicketState::OnHold
+        );
+        assert!("OnHold".parse::<TicketState>().is_err());
+        assert_eq!(
+            TicketState::default().variants(),
+            &["in_progress", "on_hold", "done"]
+        );
+        assert_eq!(
+            TicketState::default().create_type_sql(),
+            "CREATE TYPE TicketState AS ENUM ('in_progress', 'on_hold', 'done')"
+        );
+    }
+
     #[test]
     fn test_enum_from_i64() {
         let user = Role::try_from(0i64).expect("Should convert 0 to User");
@@ -644,3 +644,61 @@ fn test_both_delete_cascade_and_update_set_null(db: &mut TestDb<FkBothActionsSch
     let children: Vec<ChildResult> = db.select(()).from(fk_both_actions).all();
     assert_eq!(children.len(), 1, "Child1 should still exist");
 }
+
+//------------------------------------------------------------------------------
+// Truncate / Reset Tests
+//------------------------------------------------------------------------------
+
+#[drizzle::test]
+fn test_truncate_cascades_and_restarts_identity(db: &mut TestDb<FkRestrictSchema>) {
+    let FkRestrictSchema {
+        fk_parent,
+        fk_restrict,
+    } = schema;
+
+    db.insert(fk_parent)
+        .values([InsertFkParent::new(1, "Parent1")])
+        .execute();
+    db.insert(fk_restrict)
+        .values([
+            InsertFkRestrict::new("Child1").with_parent_id(1),
+            InsertFkRestrict::new("Child2").with_parent_id(1),
+        ])
+        .execute();
+
+    // CASCADE empties the referencing table too.
+    db.truncate(fk_parent);
+
+    let children: Vec<ChildResult> = db.select(()).from(fk_restrict).all();
+    assert!(children.is_empty());
+
+    // RESTART IDENTITY starts the serial over.
+    db.insert(fk_restrict)
+        .values([InsertFkRestrict::new("Child3")])
+        .execute();
+    let children: Vec<ChildResult> = db.select(()).from(fk_restrict).all();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].id, 1);
+}
+
+#[drizzle::test]
+fn test_reset_truncates_every_table(db: &mut TestDb<FkRestrictSchema>) {
+    let FkRestrictSchema {
+        fk_parent,
+        fk_restrict,
+    } = schema;
+
+    db.insert(fk_parent)
+        .values([InsertFkParent::new(1, "Parent1")])
+        .execute();
+    db.insert(fk_restrict)
+        .values([InsertFkRestrict::new("Child1").with_parent_id(1)])
+        .execute();
+
+    db.reset(&schema);
+
+    let children: Vec<ChildResult> = db.select(()).from(fk_restrict).all();
+    assert!(children.is_empty());
+    let parents: Vec<SelectFkParent> = db.select(()).from(fk_parent).all();
+    assert!(parents.is_empty());
+}
@@ -72,6 +72,33 @@ fn insert_from_select_with_returning(db: &mut TestDb<SimpleSchema>) {
     assert_eq!(results[0].id, 9001);
 }
 
+#[drizzle::test]
+fn insert_returning_ids(db: &mut TestDb<SimpleSchema>) {
+    let SimpleSchema { simple } = schema;
+
+    let stmt = db
+        .insert(simple)
+        .values([
+            InsertSimple::new("first"),
+            InsertSimple::new("second"),
+            InsertSimple::new("third"),
+        ])
+        .returning_ids();
+
+    assert!(stmt.to_sql().sql().ends_with(r#" RETURNING "simple"."id""#));
+
+    let ids: Vec<i32> = stmt.all();
+    assert_eq!(ids.len(), 3);
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let names: Vec<SelectSimple> = db
+        .select((simple.id, simple.name))
+        .from(simple)
+        .r#where(eq(simple.id, ids[1]))
+        .all();
+    assert_eq!(names[0].name, "second");
+}
+
 #[drizzle::test]
 fn insert_multiple_rows(db: &mut TestDb<SimpleSchema>) {
     let SimpleSchema { simple } = schema;
@@ -200,3 +227,24 @@ fn insert_large_batch(db: &mut TestDb<SimpleSchema>) {
 
     assert_eq!(results.len(), 100);
 }
+
+#[test]
+fn bulk_insert_renders_at_precomputed_length() {
+    let builder = drizzle::postgres::builder::QueryBuilder::new::<SimpleSchema>();
+    let SimpleSchema { simple } = SimpleSchema::new();
+
+    let rows = (0..1_000).map(|i| InsertSimple::new(format!("name_{i}")));
+    let sql = builder
+        .insert(simple)
+        .values(rows)
+        .returning(simple.id)
+        .to_sql();
+    let (text, params) = sql.build();
+
+    assert_eq!(text.len(), sql.rendered_len());
+    assert!(text.contains("$1000"));
+    assert_eq!(params.len(), 1_000);
+
+    let select = builder.select(()).from(simple).to_sql();
+    assert_eq!(select.sql().len(), select.rendered_len());
+}
@@ -233,6 +233,77 @@ fn postgres_sync_runtime_migrate_upgrades_legacy_tracking_table() {
     );
 }
 
+#[cfg(feature = "postgres-sync")]
+#[test]
+fn postgres_sync_runtime_migrate_records_audit_metadata() {
+    let mut db =
+        crate::common::helpers::postgres_sync_setup::setup_empty_named("runtime_audit_sync_test");
+    let schema_name = db.schema_name().to_string();
+
+    let migration = Migration::new(
+        "20230331141203_audited",
+        &format!(
+            "CREATE TABLE \"{schema_name}\".audited_a (id INTEGER PRIMARY KEY);\n--> statement-breakpoint\nCREATE TABLE \"{schema_name}\".audited_b (id INTEGER PRIMARY KEY);"
+        ),
+    );
+    db.migrate(
+        &[migration],
+        Tracking::POSTGRES.schema(schema_name.clone()).audit(true),
+    )
+    .expect("apply audited migration");
+
+    let row = db
+        .conn_mut()
+        .query_one(
+            &format!(
+                "SELECT applied_by, duration_ms, statement_count FROM \"{schema_name}\".\"__drizzle_migrations\""
+            ),
+            &[],
+        )
+        .expect("select audit columns");
+    let applied_by: String = row.get(0);
+    let duration_ms: i64 = row.get(1);
+    let statement_count: i32 = row.get(2);
+    assert!(applied_by.contains('@'), "{applied_by}");
+    assert!(duration_ms >= 0);
+    assert_eq!(statement_count, 2);
+}
+
+#[cfg(feature = "postgres-sync")]
+#[test]
+fn postgres_sync_assert_schema_matches_recorded_hash() {
+    let (mut db, _) = crate::common::helpers::postgres_sync_setup::setup_empty_named_db(
+        "schema_hash_sync_test",
+        PushUsableSchema::new(),
+    );
+    let schema_name = db.schema_name().to_string();
+    let tracking = Tracking::POSTGRES.schema(schema_name.clone());
+
+    let err = db
+        .assert_schema_matches(tracking.clone())
+        .expect_err("no tracking table yet");
+    assert!(err.to_string().contains("No schema hash recorded"), "{err}");
+
+    let migration = Migration::new(
+        "20260801000000_hashed",
+        &format!("CREATE TABLE \"{schema_name}\".hashed (id INTEGER PRIMARY KEY);"),
+    );
+    db.migrate(
+        std::slice::from_ref(&migration),
+        tracking.clone().schema_hash(PushUsableSchema::SCHEMA_HASH),
+    )
+    .expect("apply migration");
+    db.assert_schema_matches(tracking.clone())
+        .expect("recorded hash matches");
+
+    db.migrate(&[migration], tracking.clone().schema_hash(1))
+        .expect("up-to-date migrate");
+    let err = db
+        .assert_schema_matches(tracking)
+        .expect_err("recorded hash differs");
+    assert!(err.to_string().contains("does not match"), "{err}");
+}
+
 #[cfg(feature = "postgres-sync")]
 #[test]
 fn postgres_sync_runtime_migrate_upgrade_uses_hash_for_same_timestamp() {
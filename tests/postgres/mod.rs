@@ -21,9 +21,14 @@ pub mod index;
 pub mod insert;
 pub mod joins;
 pub mod migrations;
+#[cfg(feature = "cidr")]
+pub mod network;
+#[cfg(feature = "geo-types")]
+pub mod postgis;
 pub mod prepare;
 #[cfg(feature = "query")]
 pub mod query;
+pub mod range;
 pub mod schema;
 pub mod select;
 pub mod subquery;
@@ -31,5 +36,6 @@ pub mod transaction;
 pub mod type_inference;
 pub mod types;
 pub mod update;
+pub mod vector;
 #[cfg(any(feature = "compact-str", feature = "bytes", feature = "smallvec-types"))]
 pub mod wrappers;
@@ -0,0 +1,151 @@
+//! PostgreSQL network address tests
+//!
+//! Tests for `#[column(inet)]`, `#[column(cidr)]` and `#[column(macaddr)]`
+//! columns and the network containment operators (<<, <<=, >>, >>=).
+
+#![cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+
+use cidr::{IpCidr, IpInet};
+use drizzle::postgres::expr::{
+    inet_contained_by, inet_contained_by_or_equals, inet_contains, inet_contains_or_equals,
+};
+use drizzle::postgres::prelude::*;
+
+#[PostgresTable(name = "pg_network_hosts")]
+struct PgNetworkHost {
+    #[column(primary, serial)]
+    id: i32,
+    name: String,
+    // cidr types don't implement Default, so the address columns are nullable
+    #[column(inet)]
+    addr: Option<IpInet>,
+    #[column(cidr)]
+    subnet: Option<IpCidr>,
+    #[column(macaddr)]
+    mac: [u8; 6],
+    #[column(macaddr8)]
+    eui64: Option<[u8; 8]>,
+}
+
+#[derive(PostgresSchema)]
+struct PgNetworkSchema {
+    hosts: PgNetworkHost,
+}
+
+#[derive(Debug, PostgresFromRow)]
+struct HostMac {
+    name: String,
+    mac: [u8; 6],
+    eui64: Option<[u8; 8]>,
+}
+
+fn inet(value: &str) -> IpInet {
+    value.parse().unwrap()
+}
+
+fn cidr(value: &str) -> IpCidr {
+    value.parse().unwrap()
+}
+
+#[test]
+fn network_columns_ddl() {
+    let ddl = PgNetworkHost::ddl_sql();
+    assert!(ddl.contains("\"addr\" INET"), "{ddl}");
+    assert!(ddl.contains("\"subnet\" CIDR"), "{ddl}");
+    assert!(ddl.contains("\"mac\" MACADDR NOT NULL"), "{ddl}");
+    assert!(ddl.contains("\"eui64\" MACADDR8"), "{ddl}");
+}
+
+#[test]
+fn network_operators_sql_generation() {
+    let PgNetworkSchema { hosts } = PgNetworkSchema::new();
+
+    assert_eq!(
+        inet_contained_by(hosts.addr, cidr("10.0.0.0/8"))
+            .to_sql()
+            .sql(),
+        r#""pg_network_hosts"."addr" << $1"#
+    );
+    assert_eq!(
+        inet_contained_by_or_equals(hosts.addr, cidr("10.0.0.0/8"))
+            .to_sql()
+            .sql(),
+        r#""pg_network_hosts"."addr" <<= $1"#
+    );
+    assert_eq!(
+        inet_contains(hosts.subnet, hosts.addr).to_sql().sql(),
+        r#""pg_network_hosts"."subnet" >> "pg_network_hosts"."addr""#
+    );
+    assert_eq!(
+        inet_contains_or_equals(hosts.subnet, inet("10.1.2.3"))
+            .to_sql()
+            .sql(),
+        r#""pg_network_hosts"."subnet" >>= $1"#
+    );
+}
+
+#[drizzle::test]
+fn network_roundtrip_and_operators(db: &mut TestDb<PgNetworkSchema>) {
+    let PgNetworkSchema { hosts } = schema;
+
+    let mac = [0x08, 0x00, 0x2b, 0x01, 0x02, 0x03];
+    let eui64 = [0x08, 0x00, 0x2b, 0x01, 0x02, 0x03, 0x04, 0x05];
+    db.insert(hosts)
+        .values([InsertPgNetworkHost::new("gateway", mac)
+            .with_addr(inet("10.1.0.1/16"))
+            .with_subnet(cidr("10.1.0.0/16"))
+            .with_eui64(eui64)])
+        .execute();
+    db.insert(hosts)
+        .values([InsertPgNetworkHost::new("laptop", [0xaa; 6])
+            .with_addr(inet("192.168.1.20/24"))
+            .with_subnet(cidr("192.168.1.0/24"))])
+        .execute();
+
+    let rows: Vec<SelectPgNetworkHost> = db.select(()).from(hosts).order_by(hosts.id).all();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].addr, Some(inet("10.1.0.1/16")));
+    assert_eq!(rows[0].subnet, Some(cidr("10.1.0.0/16")));
+    assert_eq!(rows[0].mac, mac);
+    assert_eq!(rows[0].eui64, Some(eui64));
+    assert_eq!(rows[1].mac, [0xaa; 6]);
+    assert_eq!(rows[1].eui64, None);
+
+    let names: Vec<String> = db
+        .select(hosts.name)
+        .from(hosts)
+        .r#where(inet_contained_by(hosts.addr, cidr("10.0.0.0/8")))
+        .all();
+    assert_eq!(names, vec!["gateway".to_string()]);
+
+    let names: Vec<String> = db
+        .select(hosts.name)
+        .from(hosts)
+        .r#where(inet_contains(hosts.subnet, inet("192.168.1.77")))
+        .all();
+    assert_eq!(names, vec!["laptop".to_string()]);
+
+    // A network contains itself only under the `or equals` operator
+    let names: Vec<String> = db
+        .select(hosts.name)
+        .from(hosts)
+        .r#where(inet_contains(hosts.subnet, cidr("10.1.0.0/16")))
+        .all();
+    assert!(names.is_empty());
+    let names: Vec<String> = db
+        .select(hosts.name)
+        .from(hosts)
+        .r#where(inet_contains_or_equals(hosts.subnet, cidr("10.1.0.0/16")))
+        .all();
+    assert_eq!(names, vec!["gateway".to_string()]);
+
+    let macs: Vec<HostMac> = db
+        .select((hosts.name, hosts.mac, hosts.eui64))
+        .from(hosts)
+        .order_by(hosts.id)
+        .all();
+    assert_eq!(macs[0].name, "gateway");
+    assert_eq!(macs[0].mac, mac);
+    assert_eq!(macs[0].eui64, Some(eui64));
+    assert_eq!(macs[1].eui64, None);
+}
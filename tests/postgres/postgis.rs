@@ -0,0 +1,124 @@
+//! PostGIS geometry tests
+//!
+//! Tests for `#[column(geometry(...))]` columns, the `ST_*` condition
+//! helpers and GIST indexes. The test database has no PostGIS extension, so
+//! these cover DDL, SQL generation and parameter binding.
+
+#![cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+
+use drizzle::postgres::expr::{st_contains, st_distance, st_dwithin, st_set_srid};
+use drizzle::postgres::prelude::*;
+use drizzle::postgres::values::PostgresValue;
+use geo_types::{Geometry, MultiPolygon, Point, Polygon, point, polygon};
+
+#[PostgresTable(name = "pg_postgis_places")]
+struct PgPostgisPlace {
+    #[column(primary, serial)]
+    id: i32,
+    name: String,
+    #[column(geometry(point, srid = 4326))]
+    location: Point<f64>,
+    #[column(geometry(multipolygon))]
+    area: Option<MultiPolygon<f64>>,
+    #[column(geometry)]
+    shape: Option<Geometry<f64>>,
+}
+
+#[PostgresIndex(method = "gist")]
+struct PgPostgisPlaceLocationIdx(PgPostgisPlace::location);
+
+#[derive(PostgresSchema)]
+struct PgPostgisSchema {
+    places: PgPostgisPlace,
+    location_idx: PgPostgisPlaceLocationIdx,
+}
+
+fn square() -> Polygon<f64> {
+    polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0), (x: 0.0, y: 1.0)]
+}
+
+#[test]
+fn geometry_columns_ddl() {
+    let ddl = PgPostgisPlace::ddl_sql();
+    assert!(
+        ddl.contains("\"location\" geometry(Point,4326) NOT NULL"),
+        "{ddl}"
+    );
+    assert!(ddl.contains("\"area\" geometry(MultiPolygon)"), "{ddl}");
+    assert!(ddl.contains("\"shape\" geometry"), "{ddl}");
+}
+
+#[test]
+fn geometry_gist_index_ddl() {
+    assert_eq!(
+        PgPostgisPlaceLocationIdx::create_index_sql(),
+        "CREATE INDEX \"pg_postgis_place_location_idx\" ON \"pg_postgis_places\" USING gist(\"location\");"
+    );
+}
+
+#[test]
+fn geometry_insert_binds_wkb() {
+    let PgPostgisSchema { places, .. } = PgPostgisSchema::new();
+    let db = drizzle::postgres::builder::QueryBuilder::new::<PgPostgisSchema>();
+
+    let query =
+        db.insert(places).values([
+            InsertPgPostgisPlace::new("origin", point!(x: 13.4, y: 52.5))
+                .with_area(MultiPolygon::new(vec![square()])),
+        ]);
+    let sql = query.to_sql();
+    let (_, params) = sql.build();
+
+    assert!(matches!(
+        params[1],
+        PostgresValue::Geometry(Geometry::Point(point)) if *point == point!(x: 13.4, y: 52.5)
+    ));
+    assert!(matches!(
+        params[2],
+        PostgresValue::Geometry(Geometry::MultiPolygon(_))
+    ));
+
+    let update = db
+        .update(places)
+        .set(UpdatePgPostgisPlace::default().with_location(point!(x: 1.0, y: 2.0)));
+    let sql = update.to_sql();
+    let (_, params) = sql.build();
+    assert!(matches!(
+        params[0],
+        PostgresValue::Geometry(Geometry::Point(_))
+    ));
+}
+
+#[test]
+fn geometry_functions_sql_generation() {
+    let PgPostgisSchema { places, .. } = PgPostgisSchema::new();
+    let origin = st_set_srid(Geometry::Point(point!(x: 13.4, y: 52.5)), 4326);
+
+    assert_eq!(
+        st_dwithin(places.location, origin, 0.5).to_sql().sql(),
+        r#"ST_DWithin ("pg_postgis_places"."location", ST_SetSRID (CAST ($1 AS GEOMETRY), $2), $3)"#
+    );
+    assert_eq!(
+        st_distance(places.location, places.shape).to_sql().sql(),
+        r#"ST_Distance ("pg_postgis_places"."location", "pg_postgis_places"."shape")"#
+    );
+    assert_eq!(
+        st_contains(places.area, places.location).to_sql().sql(),
+        r#"ST_Contains ("pg_postgis_places"."area", "pg_postgis_places"."location")"#
+    );
+
+    let db = drizzle::postgres::builder::QueryBuilder::new::<PgPostgisSchema>();
+    let query = db
+        .select(places.name)
+        .from(places)
+        .r#where(st_dwithin(
+            places.location,
+            st_set_srid(Geometry::Point(point!(x: 0.0, y: 0.0)), 4326),
+            10.0,
+        ))
+        .order_by(asc(st_distance(places.location, places.shape)));
+    assert_eq!(
+        query.to_sql().sql(),
+        r#"SELECT "pg_postgis_places"."name" FROM "pg_postgis_places" WHERE ST_DWithin ("pg_postgis_places"."location", ST_SetSRID (CAST ($1 AS GEOMETRY), $2), $3) ORDER BY ST_Distance ("pg_postgis_places"."location", "pg_postgis_places"."shape") ASC"#
+    );
+}
@@ -0,0 +1,122 @@
+//! PostgreSQL range type tests
+//!
+//! Tests for range columns (int4range, int8range) and the range operators
+//! (@>, <@, &&).
+
+#![cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+
+use drizzle::migrations::Schema as MigrationSchema;
+use drizzle::postgres::expr::{contained_by, contains, overlaps};
+use drizzle::postgres::prelude::*;
+use std::ops::Bound;
+
+#[PostgresTable(name = "pg_range_bookings")]
+struct PgRangeBooking {
+    #[column(primary, serial)]
+    id: i32,
+    room: String,
+    during: PgRange<i32>,
+    window: Option<PgRange<i64>>,
+}
+
+#[derive(PostgresSchema)]
+struct PgRangeSchema {
+    bookings: PgRangeBooking,
+}
+
+#[test]
+fn range_columns_use_builtin_types() {
+    let ddl = PgRangeBooking::ddl_sql();
+    assert!(ddl.contains("\"during\" int4range NOT NULL"), "{ddl}");
+    assert!(ddl.contains("\"window\" int8range"), "{ddl}");
+
+    let drizzle::migrations::Snapshot::Postgres(snapshot) = PgRangeSchema::new().to_snapshot()
+    else {
+        panic!("expected postgres snapshot");
+    };
+    let during = snapshot
+        .ddl
+        .iter()
+        .find_map(|entity| match entity {
+            drizzle::migrations::postgres::PostgresEntity::Column(column)
+                if column.name == "during" =>
+            {
+                Some(column)
+            }
+            _ => None,
+        })
+        .expect("during column in snapshot");
+    assert_eq!(during.sql_type, "int4range");
+    assert_eq!(during.type_schema, None);
+}
+
+#[test]
+fn range_operators_sql_generation() {
+    let PgRangeSchema { bookings } = PgRangeSchema::new();
+
+    assert_eq!(
+        contains(bookings.during, 5).to_sql().sql(),
+        r#""pg_range_bookings"."during" @> $1"#
+    );
+    assert_eq!(
+        contained_by(bookings.during, PgRange::from(0..100))
+            .to_sql()
+            .sql(),
+        r#""pg_range_bookings"."during" <@ $1"#
+    );
+    assert_eq!(
+        overlaps(bookings.during, PgRange::from(3..7))
+            .to_sql()
+            .sql(),
+        r#""pg_range_bookings"."during" && $1"#
+    );
+}
+
+#[drizzle::test]
+fn range_roundtrip_and_operators(db: &mut TestDb<PgRangeSchema>) {
+    let PgRangeSchema { bookings } = schema;
+
+    db.insert(bookings)
+        .values([
+            InsertPgRangeBooking::new("a", PgRange::from(1..5)).with_window(PgRange::from(10..=20)),
+            InsertPgRangeBooking::new("b", PgRange::from(5..10)).with_window(PgRange::from(..0)),
+            InsertPgRangeBooking::new("c", PgRange::Empty).with_window(PgRange::from(..)),
+        ])
+        .execute();
+
+    let rows: Vec<SelectPgRangeBooking> = db.select(()).from(bookings).order_by(bookings.id).all();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0].during, PgRange::from(1..5));
+    // int8range canonicalizes inclusive upper bounds to exclusive ones
+    assert_eq!(rows[0].window, Some(PgRange::from(10..21)));
+    assert_eq!(
+        rows[1].window,
+        Some(PgRange::new(Bound::Unbounded, Bound::Excluded(0)))
+    );
+    assert_eq!(rows[2].during, PgRange::Empty);
+    assert_eq!(rows[2].window, Some(PgRange::from(..)));
+
+    let rooms: Vec<String> = db
+        .select(bookings.room)
+        .from(bookings)
+        .r#where(contains(bookings.during, 5))
+        .all();
+    assert_eq!(rooms, vec!["b".to_string()]);
+
+    let rooms: Vec<String> = db
+        .select(bookings.room)
+        .from(bookings)
+        .r#where(overlaps(bookings.during, PgRange::from(3..7)))
+        .order_by(bookings.room)
+        .all();
+    assert_eq!(rooms, vec!["a".to_string(), "b".to_string()]);
+
+    let rooms: Vec<String> = db
+        .select(bookings.room)
+        .from(bookings)
+        .r#where(contained_by(bookings.during, PgRange::from(0..6)))
+        .order_by(bookings.room)
+        .all();
+    // The empty range is contained by every range
+    assert_eq!(rooms, vec!["a".to_string(), "c".to_string()]);
+}
@@ -6,6 +6,7 @@
 #![cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
 
 use crate::common::schema::postgres::*;
+use drizzle::core::SQL;
 use drizzle::core::expr::eq;
 use drizzle::ddl::postgres::ddl::ViewWithOptionDef;
 use drizzle::migrations::Schema as MigrationSchema;
@@ -72,10 +73,21 @@ struct PgCasingExplicit {
     display_name: String,
 }
 
+#[PostgresTable(CASING = "camelCase")]
+struct PgCasingCamel {
+    #[column(PRIMARY)]
+    id: i32,
+    created_at: String,
+    #[column(name = "legacy_id")]
+    legacy_id: Option<i32>,
+}
+
 #[PostgresTable(NAME = "macro_snapshot_ddl")]
 struct PgMacroSnapshotDdl {
     #[column(PRIMARY, identity(by_default))]
     id: i32,
+    #[column(identity = always)]
+    ticket: i64,
     #[column(COLLATE = C)]
     name: String,
     #[column(generated(stored, "length(name)"))]
@@ -84,6 +96,8 @@ struct PgMacroSnapshotDdl {
     score: i32,
     #[column(enum)]
     role: Role,
+    #[column(DEFAULT = sql!("now()::text"))]
+    created_at: String,
 }
 
 #[derive(PostgresSchema)]
@@ -91,14 +105,18 @@ struct PgMacroSnapshotSchema {
     table: PgMacroSnapshotDdl,
 }
 
-/// Macro-generated table comment.
-#[PostgresTable(NAME = "macro_array_comments")]
+/// Overridden by the table attribute.
+#[PostgresTable(
+    NAME = "macro_array_comments",
+    COMMENT = "Macro-generated table comment."
+)]
 struct PgMacroArrayComments {
     #[column(PRIMARY)]
     id: i32,
     /// Integer array comment.
     numbers: Vec<i32>,
-    /// Text array comment.
+    /// Overridden by the column attribute.
+    #[column(COMMENT = "Text array comment.")]
     tags: Vec<String>,
 }
 
@@ -235,6 +253,140 @@ struct PgMacroExecutableFeatureSchema {
     policy: PgMacroExecSelectPolicy,
 }
 
+#[PostgresTable(
+    NAME = "macro_room_bookings",
+    EXCLUDE(
+        using = btree,
+        columns(room_id WITH =, slot WITH =),
+        where = "NOT cancelled",
+        name = "macro_room_bookings_no_double_booking"
+    )
+)]
+struct PgMacroRoomBooking {
+    #[column(PRIMARY)]
+    id: i32,
+    room_id: i32,
+    slot: i32,
+    cancelled: bool,
+}
+
+#[PostgresTable(
+    NAME = "macro_reservations",
+    EXCLUDE(columns(room_id WITH =, during WITH &&), initially_deferred)
+)]
+struct PgMacroReservation {
+    #[column(PRIMARY)]
+    id: i32,
+    room_id: i32,
+    during: String,
+}
+
+#[derive(PostgresSchema)]
+struct PgMacroExclusionSchema {
+    booking: PgMacroRoomBooking,
+}
+
+#[PostgresDomain(name = "macro_email", base = text, check = "VALUE ~ '^[^@]+@[^@]+$'")]
+struct PgMacroEmail;
+
+#[PostgresTable(NAME = "macro_subscribers")]
+struct PgMacroSubscriber {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(DOMAIN = PgMacroEmail)]
+    email: String,
+    #[column(DOMAIN = PgMacroEmail)]
+    backup_email: Option<String>,
+}
+
+#[derive(PostgresSchema)]
+struct PgMacroDomainSchema {
+    email: PgMacroEmail,
+    subscriber: PgMacroSubscriber,
+}
+
+#[PostgresComposite(name = "macro_address")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct PgMacroAddress {
+    street: Option<String>,
+    zip: Option<i32>,
+}
+
+#[PostgresTable(NAME = "macro_customers")]
+struct PgMacroCustomer {
+    #[column(PRIMARY)]
+    id: i32,
+    home: PgMacroAddress,
+    work: Option<PgMacroAddress>,
+}
+
+#[derive(PostgresSchema)]
+struct PgMacroCompositeSchema {
+    address: PgMacroAddressComposite,
+    customer: PgMacroCustomer,
+}
+
+#[PostgresTable(NAME = "macro_collated_labels")]
+struct PgMacroCollatedLabel {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(collation = "C")]
+    code: String,
+    label: String,
+}
+
+#[PostgresIndex(unique)]
+struct PgMacroCollatedLabelIdx(#[collation = "POSIX"] PgMacroCollatedLabel::label);
+
+#[derive(PostgresSchema)]
+struct PgMacroCollationSchema {
+    label: PgMacroCollatedLabel,
+    label_idx: PgMacroCollatedLabelIdx,
+}
+
+#[test]
+fn postgres_macro_collations_reach_ddl_and_snapshot() {
+    assert!(
+        PgMacroCollatedLabel::create_table_sql().contains("\"code\" TEXT COLLATE \"C\" NOT NULL")
+    );
+    assert_eq!(
+        PgMacroCollatedLabelIdx::create_index_sql(),
+        "CREATE UNIQUE INDEX \"pg_macro_collated_label_idx\" ON \"macro_collated_labels\" USING btree(\"label\" COLLATE \"POSIX\");"
+    );
+    assert_eq!(
+        PgMacroCollatedLabelIdx::ddl_sql(),
+        "CREATE UNIQUE INDEX \"pg_macro_collated_label_idx\" ON \"public\".\"macro_collated_labels\" USING btree(\"label\" COLLATE \"POSIX\")"
+    );
+
+    let snapshot = PgMacroCollationSchema::new().to_snapshot();
+    let drizzle::migrations::Snapshot::Postgres(snapshot) = snapshot else {
+        panic!("expected postgres snapshot");
+    };
+    let code = snapshot
+        .ddl
+        .iter()
+        .find_map(|entity| match entity {
+            drizzle::migrations::postgres::PostgresEntity::Column(column)
+                if column.name == "code" =>
+            {
+                Some(column)
+            }
+            _ => None,
+        })
+        .expect("code column");
+    assert_eq!(code.collate.as_deref(), Some("C"));
+    let index = snapshot
+        .ddl
+        .iter()
+        .find_map(|entity| match entity {
+            drizzle::migrations::postgres::PostgresEntity::Index(index) => Some(index),
+            _ => None,
+        })
+        .expect("index in snapshot");
+    assert_eq!(index.columns[0].collate.as_deref(), Some("POSIX"));
+}
+
 #[test]
 fn postgres_macro_snapshot_carries_column_ddl_metadata() {
     let snapshot = PgMacroSnapshotSchema::new().to_snapshot();
@@ -257,6 +409,19 @@ fn postgres_macro_snapshot_carries_column_ddl_metadata() {
         .expect("id column");
     assert!(id.identity.is_some(), "identity metadata missing");
 
+    let ticket = columns
+        .iter()
+        .find(|column| column.name == "ticket")
+        .expect("ticket column");
+    assert_eq!(
+        ticket.identity.as_ref().map(|identity| identity.type_),
+        Some(drizzle::ddl::postgres::ddl::IdentityType::Always)
+    );
+    assert!(
+        PgMacroSnapshotDdl::create_table_sql()
+            .contains("\"ticket\" BIGINT GENERATED ALWAYS AS IDENTITY NOT NULL")
+    );
+
     let name = columns
         .iter()
         .find(|column| column.name == "name")
@@ -275,6 +440,12 @@ fn postgres_macro_snapshot_carries_column_ddl_metadata() {
         .expect("role column");
     assert_eq!(role.type_schema.as_deref(), Some("public"));
 
+    let created_at = columns
+        .iter()
+        .find(|column| column.name == "created_at")
+        .expect("created_at column");
+    assert_eq!(created_at.default.as_deref(), Some("now()::text"));
+
     assert!(
         snapshot.ddl.iter().any(|entity| matches!(
             entity,
@@ -364,6 +535,221 @@ fn postgres_macro_create_table_sql_carries_table_storage_and_constraints() {
     assert!(child_sql.contains("FOREIGN KEY (\"tenant_id\", \"parent_id\") REFERENCES \"macro_constraints_parent\"(\"tenant_id\", \"id\") DEFERRABLE INITIALLY DEFERRED"));
 }
 
+#[test]
+fn postgres_macro_exclude_constraints_reach_ddl_and_snapshot() {
+    let booking_sql = PgMacroRoomBooking::create_table_sql();
+    assert!(booking_sql.contains(
+        "CONSTRAINT \"macro_room_bookings_no_double_booking\" EXCLUDE USING btree (\"room_id\" WITH =, \"slot\" WITH =) WHERE (NOT cancelled)"
+    ));
+    assert_eq!(PgMacroRoomBooking::ddl_sql(), booking_sql);
+
+    let reservation_sql = PgMacroReservation::create_table_sql();
+    assert!(reservation_sql.contains(
+        "CONSTRAINT \"macro_reservations_room_id_during_excl\" EXCLUDE USING gist (\"room_id\" WITH =, \"during\" WITH &&) DEFERRABLE INITIALLY DEFERRED"
+    ));
+    assert_eq!(PgMacroReservation::ddl_sql(), reservation_sql);
+
+    let snapshot = PgMacroExclusionSchema::new().to_snapshot();
+    let drizzle::migrations::Snapshot::Postgres(snapshot) = snapshot else {
+        panic!("expected postgres snapshot");
+    };
+    let exclude = snapshot
+        .ddl
+        .iter()
+        .find_map(|entity| match entity {
+            drizzle::migrations::postgres::PostgresEntity::ExcludeConstraint(exclude) => {
+                Some(exclude)
+            }
+            _ => None,
+        })
+        .expect("exclude constraint in snapshot");
+    assert_eq!(exclude.name, "macro_room_bookings_no_double_booking");
+    assert!(exclude.name_explicit);
+    assert_eq!(exclude.using, "btree");
+    assert_eq!(exclude.where_clause.as_deref(), Some("NOT cancelled"));
+    let elements: Vec<(&str, &str)> = exclude
+        .elements
+        .iter()
+        .map(|e| (e.value.as_ref(), e.operator.as_ref()))
+        .collect();
+    assert_eq!(elements, [("room_id", "="), ("slot", "=")]);
+}
+
+#[drizzle::test]
+fn postgres_macro_exclude_constraint_rejects_conflicting_rows(
+    db: &mut TestDb<PgMacroExclusionSchema>,
+) {
+    let PgMacroExclusionSchema { booking } = schema;
+
+    db.insert(booking)
+        .values([
+            InsertPgMacroRoomBooking::new(1, 1, 9, false),
+            InsertPgMacroRoomBooking::new(2, 1, 9, true),
+            InsertPgMacroRoomBooking::new(3, 2, 9, false),
+        ])
+        .execute();
+
+    let conflict = result!(
+        db.insert(booking)
+            .values([InsertPgMacroRoomBooking::new(4, 1, 9, false)])
+            .execute()
+    );
+    assert!(conflict.is_err());
+}
+
+#[test]
+fn postgres_macro_domains_reach_ddl_and_snapshot() {
+    let statements: Vec<_> = PgMacroDomainSchema::new()
+        .create_statements()
+        .expect("create statements")
+        .collect();
+    assert_eq!(
+        statements[0],
+        r#"CREATE DOMAIN "macro_email" AS text CONSTRAINT "macro_email_check" CHECK (VALUE ~ '^[^@]+@[^@]+$');"#
+    );
+    assert!(statements[1].contains(r#""email" macro_email NOT NULL"#));
+    assert!(statements[1].contains(r#""backup_email" macro_email"#));
+
+    let drops: Vec<_> = PgMacroDomainSchema::new()
+        .drop_statements()
+        .expect("drop statements")
+        .collect();
+    assert_eq!(
+        drops.last().map(String::as_str),
+        Some(r#"DROP DOMAIN IF EXISTS "macro_email" CASCADE"#)
+    );
+
+    let snapshot = PgMacroDomainSchema::new().to_snapshot();
+    let drizzle::migrations::Snapshot::Postgres(snapshot) = snapshot else {
+        panic!("expected postgres snapshot");
+    };
+    let domain = snapshot
+        .ddl
+        .iter()
+        .find_map(|entity| match entity {
+            drizzle::migrations::postgres::PostgresEntity::Domain(domain) => Some(domain),
+            _ => None,
+        })
+        .expect("domain in snapshot");
+    assert_eq!(domain.name, "macro_email");
+    assert_eq!(domain.base_type, "text");
+    assert_eq!(domain.check.as_deref(), Some("VALUE ~ '^[^@]+@[^@]+$'"));
+    let column = snapshot
+        .ddl
+        .iter()
+        .find_map(|entity| match entity {
+            drizzle::migrations::postgres::PostgresEntity::Column(column)
+                if column.name == "email" =>
+            {
+                Some(column)
+            }
+            _ => None,
+        })
+        .expect("email column in snapshot");
+    assert_eq!(column.sql_type, "macro_email");
+    assert_eq!(column.type_schema.as_deref(), Some("public"));
+}
+
+#[test]
+fn postgres_macro_composites_reach_ddl_and_snapshot() {
+    let statements: Vec<_> = PgMacroCompositeSchema::new()
+        .create_statements()
+        .expect("create statements")
+        .collect();
+    assert_eq!(
+        statements[0],
+        r#"CREATE TYPE "macro_address" AS ("street" TEXT, "zip" INTEGER);"#
+    );
+    assert!(statements[1].contains(r#""home" macro_address NOT NULL"#));
+    assert!(statements[1].contains(r#""work" macro_address"#));
+
+    let drops: Vec<_> = PgMacroCompositeSchema::new()
+        .drop_statements()
+        .expect("drop statements")
+        .collect();
+    assert_eq!(
+        drops.last().map(String::as_str),
+        Some(r#"DROP TYPE IF EXISTS "macro_address" CASCADE"#)
+    );
+
+    let snapshot = PgMacroCompositeSchema::new().to_snapshot();
+    let drizzle::migrations::Snapshot::Postgres(snapshot) = snapshot else {
+        panic!("expected postgres snapshot");
+    };
+    let composite = snapshot
+        .ddl
+        .iter()
+        .find_map(|entity| match entity {
+            drizzle::migrations::postgres::PostgresEntity::Composite(composite) => Some(composite),
+            _ => None,
+        })
+        .expect("composite in snapshot");
+    assert_eq!(composite.name, "macro_address");
+    let fields: Vec<_> = composite
+        .fields
+        .iter()
+        .map(|field| (field.name.as_ref(), field.sql_type.as_ref()))
+        .collect();
+    assert_eq!(fields, [("street", "TEXT"), ("zip", "INTEGER")]);
+}
+
+#[drizzle::test]
+fn postgres_macro_composite_columns_roundtrip(db: &mut TestDb<PgMacroCompositeSchema>) {
+    let PgMacroCompositeSchema { customer, .. } = schema;
+
+    let home = PgMacroAddress {
+        street: Some("1 Main St".to_string()),
+        zip: None,
+    };
+    db.insert(customer)
+        .values([
+            InsertPgMacroCustomer::new(1, home.clone()).with_work(PgMacroAddress {
+                street: None,
+                zip: Some(12345),
+            }),
+        ])
+        .execute();
+    db.insert(customer)
+        .values([InsertPgMacroCustomer::new(2, home.clone())])
+        .execute();
+
+    let rows: Vec<SelectPgMacroCustomer> = db
+        .select(())
+        .from(customer)
+        .order_by([asc(customer.id)])
+        .all();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].home, home);
+    assert_eq!(
+        rows[0].work,
+        Some(PgMacroAddress {
+            street: None,
+            zip: Some(12345),
+        })
+    );
+    assert_eq!(rows[1].work, None);
+}
+
+#[drizzle::test]
+fn postgres_macro_domain_check_rejects_invalid_values(db: &mut TestDb<PgMacroDomainSchema>) {
+    let PgMacroDomainSchema { subscriber, .. } = schema;
+
+    db.insert(subscriber)
+        .values([InsertPgMacroSubscriber::new(1, "ada@example.com")])
+        .execute();
+
+    let rows: Vec<SelectPgMacroSubscriber> = db.select(()).from(subscriber).all();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].email, "ada@example.com");
+
+    let invalid = result!(
+        db.insert(subscriber)
+            .values([InsertPgMacroSubscriber::new(2, "not-an-email")])
+            .execute()
+    );
+    assert!(invalid.unwrap_err().is_constraint_violation());
+}
+
 #[test]
 fn postgres_macro_snapshot_carries_table_constraints_rls_policy_and_defaults() {
     let snapshot = PgMacroFeatureSchema::new().to_snapshot();
@@ -652,6 +1038,21 @@ fn postgres_casing_is_schema_definition_scoped() {
     assert!(sql.contains(r#""pg_casing_default"."created_at""#));
 }
 
+#[test]
+fn postgres_casing_attribute_renames_table_and_columns() {
+    let table = PgCasingCamel::new();
+    assert_eq!(table.name(), "pgCasingCamel");
+    assert_eq!(table.created_at.name(), "createdAt");
+    assert_eq!(table.legacy_id.name(), "legacy_id");
+
+    let sql = drizzle::postgres::builder::QueryBuilder::new::<SimpleSchema>()
+        .select((table.id, table.created_at))
+        .from(table)
+        .to_sql()
+        .sql();
+    assert!(sql.contains(r#""pgCasingCamel"."createdAt""#));
+}
+
 #[drizzle::test]
 fn view_definition_with_options_sql(db: &mut TestDb<SimpleSchema>) {
     let sql = SimpleViewWithOptions::create_view_sql();
@@ -665,6 +1066,95 @@ fn view_definition_with_options_sql(db: &mut TestDb<SimpleSchema>) {
     assert!(sql.contains("fast_storage"));
 }
 
+#[PostgresView(
+    NAME = "simple_name_counts",
+    DEFINITION = "SELECT name, count(*)::int4 AS total FROM simple GROUP BY name",
+    MATERIALIZED,
+    WITH_NO_DATA
+)]
+struct SimpleNameCounts {
+    name: String,
+    total: i32,
+}
+
+#[PostgresIndex(unique)]
+struct SimpleNameCountsNameIdx(SimpleNameCounts::name);
+
+#[derive(PostgresSchema)]
+struct MaterializedViewSchema {
+    simple: Simple,
+    simple_name_counts: SimpleNameCounts,
+    simple_name_counts_name_idx: SimpleNameCountsNameIdx,
+}
+
+#[derive(PostgresSchema)]
+struct MaterializedViewRefreshSchema {
+    simple: Simple,
+    simple_view: SimpleView,
+    simple_name_counts: SimpleNameCounts,
+}
+
+#[test]
+fn materialized_view_index_is_created_after_the_view() {
+    let statements: Vec<_> = MaterializedViewSchema::new()
+        .create_statements()
+        .expect("create statements")
+        .collect();
+    let view_pos = statements
+        .iter()
+        .position(|sql| sql.contains("CREATE MATERIALIZED VIEW"))
+        .expect("materialized view statement");
+    let index_pos = statements
+        .iter()
+        .position(|sql| sql.contains("CREATE UNIQUE INDEX \"simple_name_counts_name_idx\""))
+        .expect("view index statement");
+    assert!(view_pos < index_pos, "{statements:?}");
+    assert!(statements[view_pos].ends_with("WITH NO DATA"));
+}
+
+#[drizzle::test]
+fn refresh_materialized_view_repopulates_rows(db: &mut TestDb<MaterializedViewRefreshSchema>) {
+    let MaterializedViewRefreshSchema {
+        simple,
+        simple_view,
+        simple_name_counts: counts,
+    } = schema;
+
+    // CONCURRENTLY needs a unique index on the view
+    result!(db.execute(SQL::raw(SimpleNameCountsNameIdx::create_index_sql()))).unwrap();
+
+    db.insert(simple)
+        .values([
+            InsertSimple::new("alpha"),
+            InsertSimple::new("alpha"),
+            InsertSimple::new("beta"),
+        ])
+        .execute();
+
+    // Created WITH NO DATA, so the first refresh cannot be concurrent
+    db.refresh_materialized_view(&counts, false);
+    let rows: Vec<(String, i32)> = db
+        .select((counts.name, counts.total))
+        .from(counts)
+        .order_by([asc(counts.name)])
+        .all();
+    assert_eq!(rows, [("alpha".to_string(), 2), ("beta".to_string(), 1)]);
+
+    db.insert(simple)
+        .values([InsertSimple::new("gamma")])
+        .execute();
+    db.refresh_materialized_view(&counts, true);
+    let rows: Vec<(String, i32)> = db
+        .select((counts.name, counts.total))
+        .from(counts)
+        .order_by([asc(counts.name)])
+        .all();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[2], ("gamma".to_string(), 1));
+
+    assert!(result!(db.refresh_materialized_view(&simple_view, false)).is_err());
+}
+
 #[cfg(feature = "uuid")]
 #[drizzle::test]
 fn schema_complex_works(db: &mut TestDb<ComplexSchema>) {
@@ -702,6 +1192,71 @@ fn schema_with_enum_works(db: &mut TestDb<ComplexSchema>) {
     assert_eq!(results.len(), 3);
 }
 
+#[cfg(feature = "uuid")]
+#[drizzle::test]
+fn schema_drop_removes_enum_types(db: &mut TestDb<ComplexSchema>) {
+    let ComplexSchema { complex, .. } = schema;
+
+    // Every object already exists, so this is a no-op
+    db.create_if_not_exists();
+
+    // `TestDb` implements `Drop`, so reach through to the driver explicitly
+    (**db).drop();
+    // The enum type went with the table, so a plain create succeeds again
+    db.create();
+
+    let stmt = db
+        .insert(complex)
+        .values([InsertComplex::new("test", true, Role::Admin)]);
+    stmt.execute();
+
+    let stmt = db.select(()).from(complex);
+    let results: Vec<PgComplexResult> = stmt.all();
+
+    assert_eq!(results.len(), 1);
+}
+
+#[drizzle::test]
+fn schema_drop_and_create_if_not_exists(db: &mut TestDb<ViewTestSchema>) {
+    let ViewTestSchema {
+        simple,
+        simple_view,
+        ..
+    } = schema;
+
+    db.create_if_not_exists();
+    (**db).drop();
+    db.create_if_not_exists();
+    db.create_if_not_exists();
+
+    let stmt = db.insert(simple).values([InsertSimple::new("alpha")]);
+    stmt.execute();
+
+    let stmt = db.select(PgSimpleViewResult::Select).from(simple_view);
+    let results: Vec<PgSimpleViewResult> = stmt.all();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name, "alpha");
+}
+
+#[test]
+fn schema_drop_statements_cascade() {
+    let statements: Vec<_> = ViewTestSchema::new()
+        .drop_statements()
+        .expect("drop statements")
+        .collect();
+
+    assert_eq!(
+        statements,
+        [
+            r#"DROP VIEW IF EXISTS "simple_view" CASCADE"#,
+            r#"DROP MATERIALIZED VIEW IF EXISTS "simple_view_mat" CASCADE"#,
+            r#"DROP VIEW IF EXISTS "default_name_view" CASCADE"#,
+            r#"DROP TABLE IF EXISTS "simple" CASCADE"#,
+        ]
+    );
+}
+
 #[drizzle::test]
 fn schema_multiple_inserts(db: &mut TestDb<SimpleSchema>) {
     let SimpleSchema { simple } = schema;
@@ -742,7 +1297,7 @@ struct PgCycleB {
 struct PgCycleC {
     #[column(PRIMARY)]
     id: i32,
-    #[column(REFERENCES = PgCycleA::id)]
+    #[column(REFERENCES = PgCycleA::id, DEFERRABLE, INITIALLY_DEFERRED)]
     a_id: i32,
 }
 
@@ -754,17 +1309,121 @@ struct PgCycleSchema {
 }
 
 #[test]
-fn postgres_cycle_reports_structured_error() {
-    let schema = PgCycleSchema::new();
-    let err = match schema.create_statements() {
-        Ok(_) => panic!("expected cycle detection error"),
-        Err(err) => err,
-    };
+fn postgres_deferrable_cycle_adds_foreign_key_last() {
+    let statements: Vec<_> = PgCycleSchema::new()
+        .create_statements()
+        .expect("create statements")
+        .collect();
+
+    assert_eq!(statements.len(), 4, "{statements:?}");
+    assert!(statements[0].starts_with(r#"CREATE TABLE "cycle_c""#));
+    assert!(statements[1].starts_with(r#"CREATE TABLE "cycle_b""#));
+    assert!(statements[2].starts_with(r#"CREATE TABLE "cycle_a""#));
     assert!(
-        err.to_string()
-            .contains("Cyclic table dependency detected in PostgresSchema"),
-        "unexpected error: {err}"
+        !statements[0].contains("FOREIGN KEY"),
+        "deferred key should leave CREATE TABLE: {}",
+        statements[0]
+    );
+    assert_eq!(
+        statements[3],
+        r#"ALTER TABLE "cycle_c" ADD CONSTRAINT "cycle_c_a_id_fkey" FOREIGN KEY ("a_id") REFERENCES "cycle_a"("id") DEFERRABLE INITIALLY DEFERRED;"#
+    );
+}
+
+#[drizzle::test]
+fn postgres_deferrable_cycle_accepts_circular_rows(db: &mut TestDb<PgCycleSchema>) {
+    let PgCycleSchema { a, b, c } = schema;
+
+    // `cycle_c` points at a row that only exists once the transaction ends
+    db.transaction(
+        drizzle::postgres::common::PostgresTransactionType::default(),
+        |tx| {
+            result!(tx.insert(c).values([InsertPgCycleC::new(1, 1)]).execute())?;
+            result!(tx.insert(b).values([InsertPgCycleB::new(1, 1)]).execute())?;
+            result!(tx.insert(a).values([InsertPgCycleA::new(1, 1)]).execute())?;
+            Ok(())
+        },
+    );
+
+    let rows: Vec<SelectPgCycleC> = db.select(()).from(c).all();
+    assert_eq!(rows.len(), 1);
+}
+
+#[PostgresTable(NAME = "deferrable_a")]
+struct PgDeferrableA {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(REFERENCES = PgDeferrableB::id)]
+    b_id: i32,
+}
+
+#[PostgresTable(NAME = "deferrable_b")]
+struct PgDeferrableB {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(REFERENCES = PgDeferrableC::id)]
+    c_id: i32,
+}
+
+#[PostgresTable(NAME = "deferrable_c")]
+struct PgDeferrableC {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(REFERENCES = PgDeferrableA::id, DEFERRABLE)]
+    a_id: i32,
+}
+
+#[derive(PostgresSchema)]
+struct PgDeferrableSchema {
+    a: PgDeferrableA,
+    b: PgDeferrableB,
+    c: PgDeferrableC,
+}
+
+#[drizzle::test]
+fn postgres_set_constraints_deferred_accepts_circular_rows(db: &mut TestDb<PgDeferrableSchema>) {
+    let PgDeferrableSchema { a, b, c } = schema;
+
+    // DEFERRABLE alone still checks each statement until deferred
+    let immediate = result!(db.transaction(
+        drizzle::postgres::common::PostgresTransactionType::default(),
+        |tx| {
+            result!(
+                tx.insert(c)
+                    .values([InsertPgDeferrableC::new(1, 1)])
+                    .execute()
+            )?;
+            Ok(())
+        },
+    ));
+    assert!(immediate.is_err());
+
+    db.transaction(
+        drizzle::postgres::common::PostgresTransactionType::default(),
+        |tx| {
+            result!(tx.set_constraints_deferred())?;
+            result!(
+                tx.insert(c)
+                    .values([InsertPgDeferrableC::new(1, 1)])
+                    .execute()
+            )?;
+            result!(
+                tx.insert(b)
+                    .values([InsertPgDeferrableB::new(1, 1)])
+                    .execute()
+            )?;
+            result!(
+                tx.insert(a)
+                    .values([InsertPgDeferrableA::new(1, 1)])
+                    .execute()
+            )?;
+            result!(tx.set_constraints_immediate())?;
+            Ok(())
+        },
     );
+
+    let rows: Vec<SelectPgDeferrableC> = db.select(()).from(c).all();
+    assert_eq!(rows.len(), 1);
 }
 
 #[PostgresTable(NAME = "dup_table")]
@@ -1037,3 +1696,120 @@ mod view_query {
         );
     }
 }
+
+#[cfg(feature = "tokio-postgres")]
+#[tokio::test]
+async fn tokio_postgres_tenant_schema_lifecycle() {
+    use crate::common::helpers::tokio_postgres_setup;
+
+    // Starts the database if needed.
+    let _setup = tokio_postgres_setup::setup_db::<SimpleSchema>().await;
+
+    let url = tokio_postgres_setup::get_database_url();
+    let (client, connection) = tokio_postgres::connect(&url, tokio_postgres::NoTls)
+        .await
+        .unwrap();
+    tokio::spawn(connection);
+    let (db, ()) = drizzle::postgres::tokio::Drizzle::new(client, ());
+
+    let prefix = format!("tenant_{}_", std::process::id());
+    let tenant = format!("{prefix}42");
+    db.drop_tenant_schema(&tenant).await.unwrap();
+
+    let (tenant_db, SimpleSchema { simple }) = db
+        .create_tenant_schema(&tenant, SimpleSchema::new())
+        .await
+        .unwrap();
+    tenant_db
+        .insert(simple)
+        .values([InsertSimple::new("scoped")])
+        .execute()
+        .await
+        .unwrap();
+    let rows: Vec<SelectSimple> = tenant_db.select(()).from(simple).all().await.unwrap();
+    assert_eq!(rows.len(), 1);
+
+    // The unqualified insert landed in the tenant schema
+    let count: i64 = tenant_db
+        .conn()
+        .query_one(&format!("SELECT count(*) FROM \"{tenant}\".simple"), &[])
+        .await
+        .unwrap()
+        .get(0);
+    assert_eq!(count, 1);
+
+    assert_eq!(
+        tenant_db.tenant_schemas(&prefix).await.unwrap(),
+        std::slice::from_ref(&tenant)
+    );
+    tenant_db.drop_tenant_schema(&tenant).await.unwrap();
+    assert!(tenant_db.tenant_schemas(&prefix).await.unwrap().is_empty());
+}
+
+#[cfg(feature = "tokio-postgres")]
+#[tokio::test]
+async fn tokio_postgres_search_path_session_and_transaction() {
+    use crate::common::helpers::tokio_postgres_setup;
+    use drizzle::postgres::common::PostgresTransactionType;
+
+    // Starts the database if needed.
+    let _setup = tokio_postgres_setup::setup_db::<SimpleSchema>().await;
+
+    let url = tokio_postgres_setup::get_database_url();
+    let (client, connection) = tokio_postgres::connect(&url, tokio_postgres::NoTls)
+        .await
+        .unwrap();
+    tokio::spawn(connection);
+    let (db, ()) = drizzle::postgres::tokio::Drizzle::new(client, ());
+
+    let schema_a = format!("search_path_{}_a", std::process::id());
+    let schema_b = format!("search_path_{}_b", std::process::id());
+    db.drop_tenant_schema(&schema_a).await.unwrap();
+    db.drop_tenant_schema(&schema_b).await.unwrap();
+    let (db, _) = db
+        .create_tenant_schema(&schema_a, SimpleSchema::new())
+        .await
+        .unwrap();
+    let (mut db, SimpleSchema { simple }) = db
+        .create_tenant_schema(&schema_b, SimpleSchema::new())
+        .await
+        .unwrap();
+
+    db.set_search_path([schema_a.as_str(), "public"])
+        .await
+        .unwrap();
+    db.insert(simple)
+        .values([InsertSimple::new("session")])
+        .execute()
+        .await
+        .unwrap();
+
+    db.transaction(PostgresTransactionType::ReadCommitted, async |tx| {
+        tx.set_search_path([&schema_b]).await?;
+        tx.insert(simple)
+            .values([InsertSimple::new("transaction")])
+            .execute()
+            .await?;
+        Ok(())
+    })
+    .await
+    .unwrap();
+
+    // SET LOCAL ended with the transaction
+    let rows: Vec<SelectSimple> = db.select(()).from(simple).all().await.unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].name, "session");
+
+    let count: i64 = db
+        .conn()
+        .query_one(&format!("SELECT count(*) FROM \"{schema_b}\".simple"), &[])
+        .await
+        .unwrap()
+        .get(0);
+    assert_eq!(count, 1);
+
+    assert!(db.set_search_path(Vec::<&str>::new()).await.is_err());
+
+    db.drop_tenant_schema(&schema_a).await.unwrap();
+    db.drop_tenant_schema(&schema_b).await.unwrap();
+}
@@ -384,6 +384,50 @@ fn cte_after_order_limit_offset(db: &mut TestDb<SimpleSchema>) {
     assert_eq!(results[1].id, 3);
 }
 
+#[drizzle::test]
+fn temp_table_as_joinable_handle(db: &mut TestDb<SimpleSchema>) {
+    let SimpleSchema { simple } = schema;
+
+    let test_data = [
+        InsertSimple::new("one"),
+        InsertSimple::new("two"),
+        InsertSimple::new("three"),
+    ];
+    db.insert(simple).values(test_data).execute();
+
+    let results: Vec<SelectSimple> = {
+        struct LaterSimpleTag;
+        impl drizzle::core::Tag for LaterSimpleTag {
+            const NAME: &'static str = "later_simple";
+        }
+
+        let builder = drizzle::postgres::builder::QueryBuilder::new::<SimpleSchema>();
+        let later_simple: drizzle_postgres::builder::CTEView<'static, _, _> = builder
+            .select(simple.id)
+            .from(simple)
+            .r#where(gt(simple.id, 1))
+            .into_cte::<LaterSimpleTag>();
+        let later_alias = later_simple.table;
+
+        let create = db.create_temp_table_as(&later_simple);
+        assert_eq!(
+            create.to_sql().sql(),
+            r#"CREATE TEMP TABLE "later_simple" AS SELECT "simple"."id" FROM "simple" WHERE "simple"."id" > $1"#
+        );
+        create.execute();
+
+        db.select((simple.id, simple.name))
+            .from(simple)
+            .join((&later_simple, eq(later_alias.id, simple.id)))
+            .order_by([asc(simple.id)])
+            .all()
+    };
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].name, "two");
+    assert_eq!(results[1].name, "three");
+}
+
 // Validate that the generated Select model can be used directly
 #[drizzle::test]
 fn select_with_generated_model(db: &mut TestDb<SimpleSchema>) {
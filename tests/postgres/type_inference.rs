@@ -340,6 +340,26 @@ mod chrono_tests {
         assert_eq!(results[0].timestamp_val, timestamp);
         // Note: timestamptz comparison may need timezone handling
     }
+
+    #[PostgresTable(name = "pg_explicit_timestamps")]
+    struct PgExplicitTimestamps {
+        #[column(serial, primary)]
+        id: i32,
+        #[column(timestamp)]
+        local_at: NaiveDateTime,
+        #[column(timestamptz)]
+        happened_at: DateTime<Utc>,
+        #[column(timestamp_with_time_zone)]
+        deleted_at: Option<DateTime<Utc>>,
+    }
+
+    #[test]
+    fn explicit_timestamp_attributes_ddl() {
+        let ddl = PgExplicitTimestamps::ddl_sql();
+        assert!(ddl.contains("\"local_at\" TIMESTAMP NOT NULL"), "{ddl}");
+        assert!(ddl.contains("\"happened_at\" TIMESTAMPTZ NOT NULL"), "{ddl}");
+        assert!(ddl.contains("\"deleted_at\" TIMESTAMPTZ,"), "{ddl}");
+    }
 }
 
 // ============================================================================
@@ -265,3 +265,36 @@ fn update_with_placeholders_execute(db: &mut TestDb<SimpleSchema>) {
     assert_eq!(rows.len(), 1);
     assert_eq!(rows[0].name, "updated_name");
 }
+
+#[drizzle::test]
+fn update_from_joined_table(db: &mut TestDb<FullBlogSchema>) {
+    let FullBlogSchema {
+        simple, category, ..
+    } = schema;
+
+    db.insert(simple)
+        .values([InsertSimple::new("Alice"), InsertSimple::new("Bob")])
+        .execute();
+    db.insert(category)
+        .values([InsertCategory::new("Renamed")])
+        .execute();
+
+    let stmt = db
+        .update(simple)
+        .set(UpdateSimple::default().with_name(category.name))
+        .from(category)
+        .r#where(eq(simple.id, category.id));
+    assert_eq!(
+        stmt.to_sql().sql(),
+        r#"UPDATE "simple" SET "name" = "category"."name" FROM "category" WHERE "simple"."id" = "category"."id""#
+    );
+    stmt.execute();
+
+    let results: Vec<SelectSimple> = db
+        .select((simple.id, simple.name))
+        .from(simple)
+        .order_by(asc(simple.id))
+        .all();
+    let names: Vec<_> = results.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, ["Renamed", "Bob"]);
+}
@@ -0,0 +1,106 @@
+//! pgvector tests
+//!
+//! Tests for `#[column(vector(N))]` columns, the distance operators and
+//! HNSW / IVFFlat indexes. The test database has no pgvector extension, so
+//! these cover DDL, SQL generation and parameter binding.
+
+#![cfg(any(feature = "postgres-sync", feature = "tokio-postgres"))]
+
+use drizzle::postgres::expr::{PgVector, cosine_distance, nearest, nearest_cosine};
+use drizzle::postgres::prelude::*;
+use drizzle::postgres::values::PostgresValue;
+
+#[PostgresTable(name = "pg_vector_docs")]
+struct PgVectorDoc {
+    #[column(primary, serial)]
+    id: i32,
+    body: String,
+    #[column(vector(3))]
+    embedding: Vec<f32>,
+    #[column(vector(3))]
+    summary: Option<Vec<f32>>,
+}
+
+#[PostgresIndex(method = "hnsw", with = "m = 16, ef_construction = 64")]
+struct PgVectorDocEmbeddingIdx(#[opclass = "vector_cosine_ops"] PgVectorDoc::embedding);
+
+#[PostgresIndex(method = "ivfflat", with = "lists = 100")]
+struct PgVectorDocSummaryIdx(#[opclass = "vector_l2_ops"] PgVectorDoc::summary);
+
+#[derive(PostgresSchema)]
+struct PgVectorSchema {
+    docs: PgVectorDoc,
+    embedding_idx: PgVectorDocEmbeddingIdx,
+    summary_idx: PgVectorDocSummaryIdx,
+}
+
+#[test]
+fn vector_columns_ddl() {
+    let ddl = PgVectorDoc::ddl_sql();
+    assert!(ddl.contains("\"embedding\" vector(3) NOT NULL"), "{ddl}");
+    assert!(ddl.contains("\"summary\" vector(3)"), "{ddl}");
+}
+
+#[test]
+fn vector_index_ddl() {
+    assert_eq!(
+        PgVectorDocEmbeddingIdx::create_index_sql(),
+        "CREATE INDEX \"pg_vector_doc_embedding_idx\" ON \"pg_vector_docs\" USING hnsw(\"embedding\" vector_cosine_ops) WITH (m = 16, ef_construction = 64);"
+    );
+    assert_eq!(
+        PgVectorDocSummaryIdx::create_index_sql(),
+        "CREATE INDEX \"pg_vector_doc_summary_idx\" ON \"pg_vector_docs\" USING ivfflat(\"summary\" vector_l2_ops) WITH (lists = 100);"
+    );
+}
+
+#[test]
+fn vector_insert_and_update_bind_as_vector() {
+    let PgVectorSchema { docs, .. } = PgVectorSchema::new();
+    let db = drizzle::postgres::builder::QueryBuilder::new::<PgVectorSchema>();
+
+    let query = db
+        .insert(docs)
+        .values([InsertPgVectorDoc::new("hello", vec![0.1_f32, 0.2, 0.3])
+            .with_summary(vec![1.0_f32, 0.0, 0.0])]);
+    let sql = query.to_sql();
+    let (_, params) = sql.build();
+    assert_eq!(params[1].as_vector(), Some(&[0.1_f32, 0.2, 0.3][..]));
+    assert_eq!(params[2].as_vector(), Some(&[1.0_f32, 0.0, 0.0][..]));
+
+    let update = db
+        .update(docs)
+        .set(UpdatePgVectorDoc::default().with_embedding(vec![0.5_f32, 0.5, 0.5]));
+    let sql = update.to_sql();
+    let (_, params) = sql.build();
+    assert!(matches!(params[0], PostgresValue::Vector(_)));
+}
+
+#[test]
+fn nearest_neighbour_query() {
+    let PgVectorSchema { docs, .. } = PgVectorSchema::new();
+    let db = drizzle::postgres::builder::QueryBuilder::new::<PgVectorSchema>();
+
+    let query = db
+        .select(docs.body)
+        .from(docs)
+        .order_by(nearest(docs.embedding, PgVector(vec![0.1, 0.2, 0.3])))
+        .limit(5);
+    let sql = query.to_sql();
+    assert_eq!(
+        sql.sql(),
+        r#"SELECT "pg_vector_docs"."body" FROM "pg_vector_docs" ORDER BY "pg_vector_docs"."embedding" <-> $1 ASC LIMIT 5"#
+    );
+    let (_, params) = sql.build();
+    assert!(matches!(params[0], PostgresValue::Vector(_)));
+
+    assert_eq!(
+        nearest_cosine(docs.embedding, docs.summary).sql(),
+        r#""pg_vector_docs"."embedding" <=> "pg_vector_docs"."summary" ASC"#
+    );
+    assert_eq!(
+        cosine_distance(docs.embedding, PgVector(vec![1.0, 0.0, 0.0]))
+            .to_sql()
+            .sql(),
+        r#""pg_vector_docs"."embedding" <=> $1"#
+    );
+}
@@ -0,0 +1,231 @@
+#![cfg(any(feature = "rusqlite", feature = "turso", feature = "libsql"))]
+
+use drizzle::core::expr::*;
+use drizzle::migrations::Schema as MigrationSchema;
+use drizzle::sqlite::prelude::*;
+
+#[SQLiteTable]
+struct Invoice {
+    #[column(PRIMARY)]
+    id: i32,
+    customer: String,
+    total: f64,
+}
+
+#[SQLiteTable(DATABASE = "archive")]
+struct ArchivedInvoice {
+    #[column(PRIMARY, AUTOINCREMENT)]
+    id: i32,
+    customer: String,
+    total: f64,
+}
+
+#[SQLiteIndex]
+struct ArchivedInvoiceCustomerIdx(ArchivedInvoice::customer);
+
+#[derive(SQLiteSchema)]
+struct ArchiveSchema {
+    invoice: Invoice,
+    archived_invoice: ArchivedInvoice,
+    archived_invoice_customer_idx: ArchivedInvoiceCustomerIdx,
+}
+
+#[derive(SQLiteFromRow, Debug)]
+struct InvoiceHistory {
+    customer: String,
+    live: f64,
+    archived: f64,
+}
+
+#[test]
+fn attached_table_renders_with_database_qualifier() {
+    let ArchiveSchema {
+        invoice,
+        archived_invoice,
+        ..
+    } = ArchiveSchema::new();
+
+    let query = drizzle::sqlite::builder::QueryBuilder::new::<ArchiveSchema>()
+        .select(archived_invoice.total)
+        .from(archived_invoice)
+        .inner_join((invoice, eq(invoice.id, archived_invoice.id)));
+    assert_eq!(
+        query.to_sql().sql(),
+        r#"SELECT "archived_invoice"."total" FROM "archive"."archived_invoice" INNER JOIN "invoice" ON "invoice"."id" = "archived_invoice"."id""#
+    );
+
+    assert_eq!(
+        ArchivedInvoice::ddl_sql(),
+        "CREATE TABLE `archive`.`archived_invoice` (\n\t`id` INTEGER PRIMARY KEY AUTOINCREMENT,\n\t`customer` TEXT NOT NULL,\n\t`total` REAL NOT NULL\n);"
+    );
+    assert_eq!(
+        ArchivedInvoiceCustomerIdx::ddl_sql(),
+        r#"CREATE INDEX "archive"."archived_invoice_customer_idx" ON "archived_invoice" ("customer")"#
+    );
+}
+
+#[test]
+fn attached_tables_are_left_out_of_migration_snapshots() {
+    let drizzle::migrations::Snapshot::Sqlite(snapshot) = ArchiveSchema::new().to_snapshot() else {
+        panic!("expected sqlite snapshot");
+    };
+
+    let tables: Vec<&str> = snapshot
+        .ddl
+        .iter()
+        .filter_map(|entity| match entity {
+            drizzle::migrations::sqlite::SqliteEntity::Table(table) => Some(table.name.as_ref()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(tables, ["invoice"]);
+    assert!(
+        !snapshot
+            .ddl
+            .iter()
+            .any(|entity| matches!(entity, drizzle::migrations::sqlite::SqliteEntity::Index(_)))
+    );
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn attach_copies_and_joins_across_databases() {
+    let archive_path = crate::common::helpers::temp_db_path();
+    let (db, schema) = crate::common::helpers::rusqlite_setup::setup_empty_db(ArchiveSchema::new());
+    let ArchiveSchema {
+        invoice,
+        archived_invoice,
+        ..
+    } = schema;
+
+    db.attach(&archive_path, "archive").unwrap();
+    db.create().unwrap();
+
+    db.insert(invoice)
+        .values([
+            InsertInvoice::new("alice", 10.0).with_id(1),
+            InsertInvoice::new("bob", 20.0).with_id(2),
+        ])
+        .execute()
+        .unwrap();
+
+    // INSERT INTO "archive"."archived_invoice" SELECT ... FROM "invoice"
+    db.insert(archived_invoice)
+        .select(
+            db.select((invoice.id, invoice.customer, invoice.total))
+                .from(invoice)
+                .r#where(eq(invoice.customer, "alice")),
+        )
+        .execute()
+        .unwrap();
+    db.update(archived_invoice)
+        .set(UpdateArchivedInvoice::default().with_total(12.5))
+        .r#where(eq(archived_invoice.id, 1))
+        .execute()
+        .unwrap();
+
+    let archived: Vec<SelectArchivedInvoice> = db.select(()).from(archived_invoice).all().unwrap();
+    assert_eq!(archived.len(), 1);
+    assert_eq!(archived[0].customer, "alice");
+
+    let history: Vec<InvoiceHistory> = db
+        .select((
+            invoice.customer,
+            invoice.total.alias("live"),
+            archived_invoice.total.alias("archived"),
+        ))
+        .from(invoice)
+        .inner_join((archived_invoice, eq(invoice.id, archived_invoice.id)))
+        .all()
+        .unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].customer, "alice");
+    assert_eq!(history[0].live, 10.0);
+    assert_eq!(history[0].archived, 12.5);
+
+    // The row landed in the attached file, not the main database
+    let main_tables: i64 = db
+        .conn()
+        .query_row(
+            "SELECT COUNT(*) FROM main.sqlite_master WHERE name = 'archived_invoice'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(main_tables, 0);
+
+    db.truncate(archived_invoice).unwrap();
+    let archived: Vec<SelectArchivedInvoice> = db.select(()).from(archived_invoice).all().unwrap();
+    assert!(archived.is_empty());
+
+    db.detach("archive").unwrap();
+    let detached: drizzle::Result<Vec<SelectArchivedInvoice>> =
+        db.select(()).from(archived_invoice).all();
+    assert!(detached.is_err());
+
+    let archive = rusqlite::Connection::open(&archive_path).unwrap();
+    let indexes: i64 = archive
+        .query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE name = 'archived_invoice_customer_idx'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(indexes, 1);
+}
+
+#[cfg(feature = "libsql")]
+#[tokio::test]
+async fn libsql_attach_queries_attached_table() {
+    let (db, schema) =
+        crate::common::helpers::libsql_setup::setup_empty_db(ArchiveSchema::new()).await;
+    let ArchiveSchema {
+        archived_invoice, ..
+    } = schema;
+
+    db.attach(":memory:", "archive").await.unwrap();
+    db.create().await.unwrap();
+    db.insert(archived_invoice)
+        .values([InsertArchivedInvoice::new("alice", 10.0)])
+        .execute()
+        .await
+        .unwrap();
+
+    let archived: Vec<SelectArchivedInvoice> =
+        db.select(()).from(archived_invoice).all().await.unwrap();
+    assert_eq!(archived.len(), 1);
+
+    db.detach("archive").await.unwrap();
+}
+
+#[cfg(feature = "turso")]
+#[tokio::test]
+async fn turso_attach_queries_attached_table() {
+    // Turso only accepts ATTACH on databases built with the experimental flag
+    let database = turso::Builder::new_local(":memory:")
+        .experimental_attach(true)
+        .build()
+        .await
+        .unwrap();
+    let conn = database.connect().unwrap();
+    let (
+        db,
+        ArchiveSchema {
+            archived_invoice, ..
+        },
+    ) = drizzle::sqlite::turso::Drizzle::new(conn, ArchiveSchema::new());
+
+    db.attach(":memory:", "archive").await.unwrap();
+    db.create().await.unwrap();
+    db.insert(archived_invoice)
+        .values([InsertArchivedInvoice::new("alice", 10.0)])
+        .execute()
+        .await
+        .unwrap();
+
+    let archived: Vec<SelectArchivedInvoice> =
+        db.select(()).from(archived_invoice).all().await.unwrap();
+    assert_eq!(archived.len(), 1);
+
+    db.detach("archive").await.unwrap();
+}
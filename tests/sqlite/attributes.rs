@@ -74,6 +74,19 @@ struct RuntimeDefaults {
     name: String,
 }
 
+// Test default values - SQL expressions evaluated by the database
+#[SQLiteTable(NAME = "sql_defaults")]
+struct SqlExpressionDefaults {
+    #[column(PRIMARY, AUTOINCREMENT)]
+    id: i32,
+    #[column(DEFAULT = sql!("(6 * 7)"))]
+    answer: i32,
+    #[column(DEFAULT = sql!("lower('PENDING')"))]
+    status: String,
+    #[column(DEFAULT = sql!("json_object('tags', json('[]'), 'meta', '{{}}')"))]
+    meta: String,
+}
+
 // Test enums with different storage types
 #[derive(SQLiteEnum, Default, Clone, PartialEq, Debug, Copy)]
 enum Priority {
@@ -187,6 +200,11 @@ struct RuntimeDefaultsSchema {
     runtime_defaults: RuntimeDefaults,
 }
 
+#[derive(SQLiteSchema)]
+struct SqlExpressionDefaultsSchema {
+    sql_defaults: SqlExpressionDefaults,
+}
+
 #[derive(SQLiteSchema)]
 struct EnumFieldsSchema {
     enum_fields: EnumFields,
@@ -293,7 +311,15 @@ fn test_unique_constraints(db: &mut TestDb<UniqueFieldsSchema>) {
         .with_display_name("Another User");
 
     let result2 = result!(db.insert(unique_table).values([data2]).execute());
-    assert!(result2.is_err()); // Should fail due to unique constraint
+    let error = result2.unwrap_err();
+    assert!(error.is_constraint_violation());
+    assert!(!error.is_retryable() && !error.is_connection_error());
+    match error.root() {
+        drizzle::error::DrizzleError::UniqueViolation { columns, .. } => {
+            assert_eq!(columns, &["email"]);
+        }
+        other => panic!("expected a unique violation, got {other:?}"),
+    }
 }
 
 #[drizzle::test]
@@ -330,6 +356,35 @@ fn test_compile_time_defaults(db: &mut TestDb<CompileTimeDefaultsSchema>) {
     assert_eq!(results[0].4, "pending");
 }
 
+#[drizzle::test]
+fn test_sql_expression_defaults(db: &mut TestDb<SqlExpressionDefaultsSchema>) {
+    let SqlExpressionDefaultsSchema { sql_defaults } = schema;
+
+    let ddl = SqlExpressionDefaults::ddl_sql();
+    assert!(ddl.contains("`answer` INTEGER DEFAULT (6 * 7) NOT NULL"));
+    assert!(ddl.contains("`status` TEXT DEFAULT (lower('PENDING')) NOT NULL"));
+    assert!(ddl.contains("DEFAULT (json_object('tags', json('[]'), 'meta', '{}'))"));
+
+    let result = db
+        .insert(sql_defaults)
+        .values([InsertSqlExpressionDefaults::new()])
+        .execute();
+    assert_eq!(result, 1);
+
+    let select_query = db
+        .select((sql_defaults.answer, sql_defaults.status, sql_defaults.meta))
+        .from(sql_defaults);
+
+    #[derive(SQLiteFromRow, Debug)]
+    struct ReturnResult(i32, String, String);
+    let results: Vec<ReturnResult> = db.all(select_query);
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].0, 42);
+    assert_eq!(results[0].1, "pending");
+    assert_eq!(results[0].2, r#"{"tags":[],"meta":"{}"}"#);
+}
+
 #[drizzle::test]
 fn test_runtime_defaults(db: &mut TestDb<RuntimeDefaultsSchema>) {
     let RuntimeDefaultsSchema { runtime_defaults } = schema;
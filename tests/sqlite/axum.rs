@@ -0,0 +1,74 @@
+//! Tests for the `drizzle::axum` extractors and `TxLayer`.
+
+#![cfg(all(feature = "axum", feature = "libsql"))]
+
+use crate::common::helpers::libsql_setup;
+use crate::common::schema::sqlite::{InsertSimple, SelectSimple, SimpleSchema};
+use axum::Router;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use axum::routing::{get, post};
+use drizzle::axum::{Db, Tx, TxLayer};
+use drizzle::sqlite::libsql::Drizzle;
+use tower::ServiceExt;
+
+async fn insert_then(tx: Tx<SimpleSchema>, status: StatusCode) -> StatusCode {
+    let SimpleSchema { simple } = *tx.schema();
+    tx.insert(simple)
+        .values([InsertSimple::new("from request")])
+        .execute()
+        .await
+        .unwrap();
+    status
+}
+
+async fn count(Db(db): Db<Drizzle<SimpleSchema>>) -> String {
+    let SimpleSchema { simple } = *db.schema();
+    let rows: Vec<SelectSimple> = db.select(()).from(simple).all().await.unwrap();
+    rows.len().to_string()
+}
+
+async fn send(app: &Router, method: &str, uri: &str) -> (StatusCode, String) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn tx_layer_commits_on_success_and_rolls_back_on_error() {
+    let (test_db, _) = libsql_setup::setup_db::<SimpleSchema>().await;
+    let db = test_db.db.clone();
+
+    let writes = Router::new()
+        .route("/ok", post(|tx| insert_then(tx, StatusCode::CREATED)))
+        .route("/fail", post(|tx| insert_then(tx, StatusCode::BAD_REQUEST)))
+        .layer(TxLayer::new(db.clone()));
+    let app = Router::new()
+        .route("/count", get(count))
+        .with_state(db)
+        .merge(writes);
+
+    assert_eq!(send(&app, "POST", "/ok").await.0, StatusCode::CREATED);
+    assert_eq!(send(&app, "POST", "/fail").await.0, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        send(&app, "GET", "/count").await,
+        (StatusCode::OK, "1".into())
+    );
+}
+
+#[tokio::test]
+async fn tx_extractor_requires_tx_layer() {
+    let app = Router::new().route("/", post(|_: Tx<SimpleSchema>| async {}));
+    assert_eq!(
+        send(&app, "POST", "/").await.0,
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+}
@@ -429,3 +429,55 @@ fn test_condition_edge_cases(db: &mut TestDb<SimpleSchema>) {
         .all();
     assert_eq!(result.len(), 1);
 }
+
+#[drizzle::test]
+fn test_condition_set_from_optional_filters(db: &mut TestDb<SimpleSchema>) {
+    let SimpleSchema { simple } = schema;
+
+    db.insert(simple)
+        .values([
+            InsertSimple::new("Alpha").with_id(1),
+            InsertSimple::new("Beta").with_id(2),
+            InsertSimple::new("Gamma").with_id(3),
+        ])
+        .execute();
+
+    let name: Option<&str> = None;
+    let min_id: Option<i32> = Some(1);
+
+    let filter = ConditionSet::all()
+        .push(maybe(name.map(|name| eq(simple.name, name))))
+        .push(maybe(min_id.map(|id| gt(simple.id, id))))
+        .push(neq(simple.name, "Gamma"));
+
+    let stmt = db.select(()).from(simple).r#where(filter.clone());
+    assert_eq!(
+        stmt.to_sql().sql(),
+        r#"SELECT "simple"."id", "simple"."name" FROM "simple" WHERE ("simple"."id" > ? AND "simple"."name" <> ?)"#
+    );
+    let result: Vec<SelectSimple> = db.select(()).from(simple).r#where(filter).all();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].name, "Beta");
+
+    // A single condition is rendered without parentheses
+    let mut filter = ConditionSet::any();
+    filter.extend(["Alpha"].into_iter().map(|name| eq(simple.name, name)));
+    let stmt = db.select(()).from(simple).r#where(filter);
+    assert_eq!(
+        stmt.to_sql().sql(),
+        r#"SELECT "simple"."id", "simple"."name" FROM "simple" WHERE "simple"."name" = ?"#
+    );
+
+    // An empty set omits the WHERE clause
+    let stmt = db.select(()).from(simple).r#where(ConditionSet::all());
+    assert_eq!(
+        stmt.to_sql().sql(),
+        r#"SELECT "simple"."id", "simple"."name" FROM "simple""#
+    );
+    let result: Vec<SelectSimple> = db
+        .select(())
+        .from(simple)
+        .r#where(ConditionSet::any())
+        .all();
+    assert_eq!(result.len(), 3);
+}
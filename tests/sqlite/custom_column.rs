@@ -127,3 +127,71 @@ fn custom_sqlite_column_roundtrip_and_metadata(db: &mut TestDb<CustomU32BeSchema
 
     assert_eq!(ty.0, "blob");
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct Cents(i64);
+
+impl CustomSQLiteType for Cents {
+    type SQLType = drizzle::sqlite::types::Integer;
+
+    const SQL_TYPE: &'static str = "INTEGER";
+
+    type Repr = i64;
+
+    fn to_repr(&self) -> i64 {
+        self.0
+    }
+
+    fn from_repr(repr: i64) -> Result<Self, DrizzleError> {
+        if repr < 0 {
+            return Err(DrizzleError::ConversionError(
+                format!("Cents must be non-negative, got {repr}").into(),
+            ));
+        }
+        Ok(Self(repr))
+    }
+}
+
+#[test]
+fn custom_sqlite_type_maps_through_repr() {
+    assert_eq!(Cents(250).encode(), SQLiteValue::Integer(250));
+    assert_eq!(
+        Cents::decode(SQLiteValueRef::Integer(99)).unwrap(),
+        Cents(99)
+    );
+    assert!(Cents::decode(SQLiteValueRef::Integer(-1)).is_err());
+    assert!(Cents::decode(SQLiteValueRef::Blob(&[1, 2])).is_err());
+}
+
+#[SQLiteTable(NAME = "custom_cents_test")]
+struct CustomCentsTest {
+    #[column(PRIMARY)]
+    id: i32,
+    price: Cents,
+}
+
+#[derive(SQLiteSchema)]
+struct CustomCentsSchema {
+    custom_cents_test: CustomCentsTest,
+}
+
+#[drizzle::test]
+fn custom_sqlite_type_roundtrip(db: &mut TestDb<CustomCentsSchema>) {
+    let table = schema.custom_cents_test;
+
+    assert!(CustomCentsTest::ddl_sql().contains("`price` INTEGER NOT NULL"));
+
+    db.insert(table)
+        .values([InsertCustomCentsTest::new(Cents(1999))])
+        .execute();
+
+    let out: Vec<SelectCustomCentsTest> = db
+        .select((table.id, table.price))
+        .from(table)
+        .r#where(eq(table.price, Cents(1999)))
+        .all();
+
+    assert_eq!(out.len(), 1);
+    assert_eq!(out[0].price, Cents(1999));
+}
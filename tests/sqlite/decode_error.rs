@@ -0,0 +1,140 @@
+#![cfg(any(feature = "rusqlite", feature = "turso", feature = "libsql"))]
+
+use drizzle::error::DrizzleError;
+use drizzle::sql;
+use drizzle::sqlite::prelude::*;
+
+#[SQLiteTable(NAME = "decode_probes")]
+struct DecodeProbe {
+    #[column(PRIMARY)]
+    id: i32,
+    label: String,
+}
+
+#[derive(SQLiteSchema)]
+struct DecodeSchema {
+    decode_probes: DecodeProbe,
+}
+
+#[test]
+fn decode_error_display() {
+    let error = DrizzleError::ConversionError("invalid digit found in string".into())
+        .decode_column(2, Some("age"), "i32", Some("TEXT"))
+        .at_row(4);
+    assert_eq!(
+        error.to_string(),
+        r#"Decode error at row 4, column "age" (index 2): expected i32, found TEXT: Type conversion error: invalid digit found in string"#
+    );
+
+    let ctx = error.decode_context().unwrap();
+    assert_eq!(ctx.row, Some(4));
+    assert_eq!(ctx.column_index, Some(2));
+
+    assert_eq!(
+        drizzle::error::type_name::<Option<String>>(),
+        "Option<String>"
+    );
+    assert_eq!(
+        drizzle::error::type_name::<Vec<(i64, std::string::String)>>(),
+        "Vec<(i64, String)>"
+    );
+}
+
+#[drizzle::test]
+fn mistyped_column_reports_decode_context(db: &mut TestDb<DecodeSchema>) {
+    let table = schema.decode_probes;
+
+    db.insert(table)
+        .values([
+            InsertDecodeProbe::new("first").with_id(1),
+            InsertDecodeProbe::new("second").with_id(2),
+        ])
+        .execute();
+
+    #[allow(dead_code)]
+    #[derive(SQLiteFromRow, Debug, Default)]
+    struct LabelAsNumber {
+        #[column(DecodeProbe::label)]
+        label: i64,
+    }
+
+    let result: Result<Vec<LabelAsNumber>, DrizzleError> = result!(
+        db.select(LabelAsNumber::default())
+            .from(table)
+            .order_by(asc(table.id))
+            .all()
+    );
+    let error = result.unwrap_err();
+    let ctx = error
+        .decode_context()
+        .unwrap_or_else(|| panic!("expected a decode error, got {error:?}"));
+    assert_eq!(ctx.row, Some(0));
+    assert_eq!(ctx.column_index, Some(0));
+    assert!(
+        ctx.column.as_deref().is_none_or(|c| c == "label"),
+        "{ctx:?}"
+    );
+    assert_eq!(ctx.expected.as_deref(), Some("i64"));
+    assert_eq!(ctx.actual.as_deref(), Some("TEXT"));
+}
+
+#[drizzle::test]
+fn decode_error_reports_failing_row(db: &mut TestDb<DecodeSchema>) {
+    let table = schema.decode_probes;
+
+    db.insert(table)
+        .values([
+            InsertDecodeProbe::new("one").with_id(1),
+            InsertDecodeProbe::new("two").with_id(2),
+            InsertDecodeProbe::new("three").with_id(3),
+        ])
+        .execute();
+
+    // Only the third row yields TEXT where an integer is expected
+    let result: Result<Vec<i64>, DrizzleError> = result!(
+        db.select(sql!(
+            "CASE WHEN {#table.id} = 3 THEN {#table.label} ELSE {#table.id} END",
+            as i64
+        ))
+        .from(table)
+        .order_by(asc(table.id))
+        .all()
+    );
+    let error = result.unwrap_err();
+    let ctx = error
+        .decode_context()
+        .unwrap_or_else(|| panic!("expected a decode error, got {error:?}"));
+    assert_eq!(ctx.row, Some(2));
+    assert_eq!(ctx.column_index, Some(0));
+    assert_eq!(ctx.expected.as_deref(), Some("i64"));
+    assert_eq!(ctx.actual.as_deref(), Some("TEXT"));
+    assert!(
+        error.to_string().starts_with("Decode error at row 2"),
+        "{error}"
+    );
+}
+
+#[drizzle::test]
+fn narrow_select_reports_column_count_mismatch(db: &mut TestDb<DecodeSchema>) {
+    let table = schema.decode_probes;
+
+    db.insert(table)
+        .values([InsertDecodeProbe::new("only").with_id(1)])
+        .execute();
+
+    // `SELECT *` yields two columns, but the target reads three by offset
+    let result: Result<Vec<(i64, String, i64)>, DrizzleError> =
+        result!(db.select(()).from(table).all());
+    let error = result.unwrap_err();
+    let ctx = error
+        .decode_context()
+        .unwrap_or_else(|| panic!("expected a decode error, got {error:?}"));
+    assert_eq!(ctx.row, Some(0));
+    assert_eq!(ctx.expected.as_deref(), Some("(i64, String, i64)"));
+    assert!(
+        error
+            .to_string()
+            .contains("row has 2 column(s) but `(i64, String, i64)` reads 3 by offset"),
+        "{error}"
+    );
+}
@@ -55,6 +55,27 @@ fn test_enum() {
     );
 }
 
+#[derive(SQLiteEnum, PartialEq, Clone, Default, Debug)]
+#[drizzle(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Plan {
+    #[default]
+    FreeTier,
+    #[drizzle(rename = "pro")]
+    Professional,
+}
+
+#[test]
+fn test_enum_rename_attributes() {
+    assert_eq!(Plan::FreeTier.to_string(), "FREE_TIER");
+    assert_eq!(Plan::Professional.to_string(), "pro");
+    assert_eq!("FREE_TIER".parse::<Plan>().unwrap(), Plan::FreeTier);
+    assert_eq!(Plan::try_from("pro").unwrap(), Plan::Professional);
+    assert!("Professional".parse::<Plan>().is_err());
+
+    let stored: &str = Plan::Professional.into();
+    assert_eq!(stored, "pro");
+}
+
 #[test]
 fn test_table_generation() {
     // Just test that the table compiles and has the expected structure
@@ -157,3 +178,102 @@ fn test_enum_database_roundtrip(db: &mut TestDb<Schema>) {
     assert_eq!(suspended_users.len(), 1);
     assert_eq!(suspended_users[0].name, "admin_user");
 }
+
+#[cfg(feature = "serde")]
+mod json_enum {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(SQLiteEnum, Serialize, Deserialize, PartialEq, Clone, Default, Debug)]
+    #[serde(tag = "state", rename_all = "snake_case")]
+    pub enum RunState {
+        #[default]
+        Queued,
+        Running {
+            attempt: i64,
+        },
+        Failed {
+            reason: String,
+        },
+    }
+
+    #[SQLiteTable]
+    struct Job {
+        #[column(primary, autoincrement)]
+        id: i64,
+        #[column(json_enum)]
+        status: RunState,
+    }
+
+    #[derive(SQLiteSchema)]
+    pub struct Schema {
+        job: Job,
+    }
+
+    #[derive(Debug, SQLiteFromRow)]
+    struct JobResult {
+        id: i64,
+        status: RunState,
+    }
+
+    #[test]
+    fn json_enum_conversions() {
+        let failed = RunState::Failed {
+            reason: "timeout".into(),
+        };
+
+        let value = SQLiteValue::from(failed.clone());
+        assert_eq!(
+            value,
+            SQLiteValue::from(r#"{"state":"failed","reason":"timeout"}"#)
+        );
+        assert_eq!(RunState::try_from(value).unwrap(), failed);
+        assert_eq!(
+            r#"{"state":"queued"}"#.parse::<RunState>().unwrap(),
+            RunState::Queued
+        );
+        assert!("Queued".parse::<RunState>().is_err());
+    }
+
+    #[drizzle::test]
+    fn json_enum_database_roundtrip(db: &mut TestDb<Schema>) {
+        let Schema { job } = schema;
+
+        db.insert(job)
+            .values([
+                InsertJob::new(RunState::Running { attempt: 2 }),
+                InsertJob::new(RunState::Failed {
+                    reason: "timeout".into(),
+                }),
+            ])
+            .execute();
+
+        let results: Vec<JobResult> = db
+            .select((job.id, job.status))
+            .from(job)
+            .order_by(asc(job.id))
+            .all();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].status, RunState::Running { attempt: 2 });
+        assert_eq!(
+            results[1].status,
+            RunState::Failed {
+                reason: "timeout".into()
+            }
+        );
+        assert!(results[1].id > results[0].id);
+
+        let failed: Vec<JobResult> = db
+            .select((job.id, job.status))
+            .from(job)
+            .r#where(eq(
+                job.status,
+                RunState::Failed {
+                    reason: "timeout".into(),
+                },
+            ))
+            .all();
+        assert_eq!(failed.len(), 1);
+    }
+}
@@ -642,6 +642,57 @@ fn test_cte_integration_with_aggregation(db: &mut TestDb<SimpleSchema>) {
     assert_eq!(result[0].count, 3);
 }
 
+#[drizzle::test]
+fn test_temp_table_as_joinable_handle(db: &mut TestDb<SimpleSchema>) {
+    let SimpleSchema { simple } = schema;
+
+    struct HighIdsTag;
+    impl drizzle::core::Tag for HighIdsTag {
+        const NAME: &'static str = "high_ids";
+    }
+
+    #[derive(SQLiteFromRow)]
+    struct NameRow {
+        name: String,
+    }
+
+    let test_data = [
+        InsertSimple::new("Alice").with_id(1),
+        InsertSimple::new("Bob").with_id(2),
+        InsertSimple::new("Charlie").with_id(3),
+    ];
+    db.insert(simple).values(test_data).execute();
+
+    let high_ids = db
+        .select(simple.id)
+        .from(simple)
+        .r#where(gt(simple.id, 1))
+        .into_cte::<HighIdsTag>();
+
+    let create = db.create_temp_table_as(&high_ids);
+    assert_eq!(
+        create.to_sql().sql(),
+        r#"CREATE TEMP TABLE "high_ids" AS SELECT "simple"."id" FROM "simple" WHERE "simple"."id" > ?"#
+    );
+    create.execute();
+
+    // Rows added after the snapshot are not visible through the temp table
+    db.insert(simple)
+        .values([InsertSimple::new("Dave").with_id(4)])
+        .execute();
+
+    let result: Vec<NameRow> = db
+        .select(NameRow::Select)
+        .from(simple)
+        .join((&high_ids, eq(high_ids.id, simple.id)))
+        .order_by(asc(simple.id))
+        .all();
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].name, "Bob");
+    assert_eq!(result[1].name, "Charlie");
+}
+
 #[drizzle::test]
 fn test_cte_complex_two_levels(db: &mut TestDb<SimpleSchema>) {
     let SimpleSchema { simple } = schema;
@@ -1823,6 +1874,82 @@ fn test_total_aggregate(db: &mut TestDb<SimpleSchema>) {
     assert_eq!(result[0].total, 60.0);
 }
 
+#[derive(Debug, SQLiteFromRow)]
+struct EmptyAggregateResult {
+    count: i64,
+    sum: Option<i32>,
+    avg: Option<f64>,
+    min: Option<i32>,
+    max: Option<i32>,
+    names: Option<String>,
+}
+
+#[drizzle::test]
+fn test_aggregates_over_empty_set_are_null(db: &mut TestDb<SimpleSchema>) {
+    let SimpleSchema { simple } = schema;
+
+    db.insert(simple)
+        .values([InsertSimple::new("a").with_id(10)])
+        .execute();
+
+    // No row matches, so every aggregate except COUNT is NULL
+    let result: Vec<EmptyAggregateResult> = db
+        .select((
+            alias(count(simple.id), "count"),
+            alias(sum(simple.id), "sum"),
+            alias(avg(simple.id), "avg"),
+            alias(min(simple.id), "min"),
+            alias(max(simple.id), "max"),
+            alias(group_concat(simple.name), "names"),
+        ))
+        .from(simple)
+        .r#where(eq(simple.id, 999))
+        .all();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].count, 0);
+    assert_eq!(result[0].sum, None);
+    assert_eq!(result[0].avg, None);
+    assert_eq!(result[0].min, None);
+    assert_eq!(result[0].max, None);
+    assert_eq!(result[0].names, None);
+}
+
+// =============================================================================
+// GROUP_CONCAT (SQLite-specific) Test
+// =============================================================================
+
+#[derive(Debug, SQLiteFromRow)]
+struct GroupConcatResult {
+    names: Option<String>,
+}
+
+#[drizzle::test]
+fn test_group_concat_with_separator(db: &mut TestDb<SimpleSchema>) {
+    let SimpleSchema { simple } = schema;
+
+    db.insert(simple)
+        .values([
+            InsertSimple::new("a").with_id(1),
+            InsertSimple::new("b").with_id(2),
+            InsertSimple::new("c").with_id(3),
+        ])
+        .execute();
+
+    let result: Vec<GroupConcatResult> = db
+        .select(alias(group_concat_with(simple.name, " | "), "names"))
+        .from(simple)
+        .all();
+    let mut names: Vec<String> = result[0]
+        .names
+        .as_deref()
+        .expect("names")
+        .split(" | ")
+        .map(String::from)
+        .collect();
+    names.sort();
+    assert_eq!(names, ["a", "b", "c"]);
+}
+
 // =============================================================================
 // CHAR_LENGTH / OCTET_LENGTH Tests
 // =============================================================================
@@ -1918,3 +2045,101 @@ fn test_timediff(db: &mut TestDb<SimpleSchema>) {
         .all();
     assert!(result[0].result.contains("1"));
 }
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_call_registered_scalar_function() {
+    use rusqlite::functions::FunctionFlags;
+
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let (db, SimpleSchema { simple }) =
+        drizzle::sqlite::rusqlite::Drizzle::new(conn, SimpleSchema::new());
+    db.create().unwrap();
+    db.create_scalar_function(
+        "slugify",
+        1,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| Ok(ctx.get::<String>(0)?.to_lowercase().replace(' ', "-")),
+    )
+    .unwrap();
+    db.insert(simple)
+        .values([InsertSimple::new("Hello World")])
+        .execute()
+        .unwrap();
+
+    let slug = || call::<_, drizzle::sqlite::types::Text, _, _>("slugify", simple.name);
+    assert_eq!(
+        db.select(slug()).from(simple).to_sql().sql(),
+        r#"SELECT slugify ("simple"."name") FROM "simple""#
+    );
+
+    let rows: Vec<Option<String>> = db.select(slug()).from(simple).all().unwrap();
+    assert_eq!(rows, [Some("hello-world".to_string())]);
+
+    let rows: Vec<SelectSimple> = db
+        .select(())
+        .from(simple)
+        .r#where(eq(slug(), "hello-world"))
+        .all()
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+
+    db.remove_function("slugify", 1).unwrap();
+    let missing: drizzle::Result<Vec<Option<String>>> = db.select(slug()).from(simple).all();
+    assert!(missing.is_err());
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_call_agg_registered_aggregate_function() {
+    use rusqlite::functions::{Aggregate, Context, FunctionFlags};
+
+    struct LongestName;
+
+    impl Aggregate<String, Option<String>> for LongestName {
+        fn init(&self, _: &mut Context<'_>) -> rusqlite::Result<String> {
+            Ok(String::new())
+        }
+
+        fn step(&self, ctx: &mut Context<'_>, longest: &mut String) -> rusqlite::Result<()> {
+            let name = ctx.get::<String>(0)?;
+            if name.len() > longest.len() {
+                *longest = name;
+            }
+            Ok(())
+        }
+
+        fn finalize(
+            &self,
+            _: &mut Context<'_>,
+            longest: Option<String>,
+        ) -> rusqlite::Result<Option<String>> {
+            Ok(longest)
+        }
+    }
+
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let (db, SimpleSchema { simple }) =
+        drizzle::sqlite::rusqlite::Drizzle::new(conn, SimpleSchema::new());
+    db.create().unwrap();
+    db.create_aggregate_function("longest", 1, FunctionFlags::SQLITE_UTF8, LongestName)
+        .unwrap();
+    db.insert(simple)
+        .values([
+            InsertSimple::new("Al"),
+            InsertSimple::new("Beatrice"),
+            InsertSimple::new("Cy"),
+        ])
+        .execute()
+        .unwrap();
+
+    let longest: Option<String> = db
+        .select(call_agg::<_, drizzle::sqlite::types::Text, _, _>(
+            "longest",
+            simple.name,
+        ))
+        .from(simple)
+        .get()
+        .unwrap();
+    assert_eq!(longest.as_deref(), Some("Beatrice"));
+}
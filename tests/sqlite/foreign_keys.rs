@@ -464,6 +464,25 @@ fn test_cascade_deletes_children(db: &mut TestDb<FkCascadeSchema>) {
     assert_eq!(0, children.len(), "Child should be deleted by CASCADE");
 }
 
+#[drizzle::test]
+fn test_missing_parent_is_foreign_key_violation(db: &mut TestDb<FkCascadeSchema>) {
+    let FkCascadeSchema { fk_cascade, .. } = schema;
+
+    let result = result!(
+        db.insert(fk_cascade)
+            .values([InsertFkCascade::new("Orphan").with_parent_id(999)])
+            .execute()
+    );
+    let error = result.unwrap_err();
+    assert!(
+        matches!(
+            error.root(),
+            drizzle::error::DrizzleError::ForeignKeyViolation { .. }
+        ),
+        "expected a foreign key violation, got {error:?}"
+    );
+}
+
 #[drizzle::test]
 fn test_set_null_nullifies_children(db: &mut TestDb<FkSetNullSchema>) {
     let FkSetNullSchema {
@@ -725,6 +744,88 @@ fn test_both_delete_cascade_and_update_set_null(db: &mut TestDb<FkBothActionsSch
     assert_eq!(1, children.len(), "Child1 should still exist");
 }
 
+//------------------------------------------------------------------------------
+// Truncate / Reset Tests
+//------------------------------------------------------------------------------
+
+#[drizzle::test]
+fn test_truncate_empties_parent_before_restricting_child(db: &mut TestDb<FkRestrictSchema>) {
+    let FkRestrictSchema {
+        fk_parent,
+        fk_restrict,
+    } = schema;
+
+    db.insert(fk_parent)
+        .values([
+            InsertFkParent::new("Parent1"),
+            InsertFkParent::new("Parent2"),
+        ])
+        .execute();
+    db.insert(fk_restrict)
+        .values([InsertFkRestrict::new("Child1").with_parent_id(2)])
+        .execute();
+
+    // Parent listed first: the child still has to be emptied before it.
+    db.truncate((fk_parent, fk_restrict));
+
+    let parents: Vec<ParentResult> = db.select(()).from(fk_parent).all();
+    let children: Vec<ChildResult> = db.select(()).from(fk_restrict).all();
+    assert!(parents.is_empty());
+    assert!(children.is_empty());
+
+    // AUTOINCREMENT counters start over.
+    db.insert(fk_parent)
+        .values([InsertFkParent::new("Parent3")])
+        .execute();
+    let parents: Vec<ParentResult> = db.select(()).from(fk_parent).all();
+    assert_eq!(1, parents[0].id);
+    assert_eq!("Parent3", parents[0].name);
+}
+
+#[drizzle::test]
+fn test_truncate_leaves_other_tables_alone(db: &mut TestDb<FkRestrictSchema>) {
+    let FkRestrictSchema {
+        fk_parent,
+        fk_restrict,
+    } = schema;
+
+    db.insert(fk_parent)
+        .values([InsertFkParent::new("Parent1")])
+        .execute();
+    db.insert(fk_restrict)
+        .values([InsertFkRestrict::new("Child1").with_parent_id(1)])
+        .execute();
+
+    db.truncate(fk_restrict);
+
+    let parents: Vec<ParentResult> = db.select(()).from(fk_parent).all();
+    let children: Vec<ChildResult> = db.select(()).from(fk_restrict).all();
+    assert_eq!(1, parents.len());
+    assert!(children.is_empty());
+}
+
+#[drizzle::test]
+fn test_reset_truncates_every_table(db: &mut TestDb<FkRestrictSchema>) {
+    let FkRestrictSchema {
+        fk_parent,
+        fk_restrict,
+    } = schema;
+
+    db.insert(fk_parent)
+        .values([InsertFkParent::new("Parent1")])
+        .execute();
+    db.insert(fk_restrict)
+        .values([InsertFkRestrict::new("Child1").with_parent_id(1)])
+        .execute();
+
+    db.reset(&schema);
+
+    let parents: Vec<ParentResult> = db.select(()).from(fk_parent).all();
+    let children: Vec<ChildResult> = db.select(()).from(fk_restrict).all();
+    assert!(parents.is_empty());
+    assert!(children.is_empty());
+}
+
 //------------------------------------------------------------------------------
 // Original Complex/Post Schema Integration Tests
 //------------------------------------------------------------------------------
@@ -212,6 +212,24 @@ fn test_fromrow_with_column_mapping(db: &mut TestDb<TypeTestSchema>) {
     assert_eq!(result.table_name, "column_test");
 }
 
+#[drizzle::test]
+fn test_fromrow_columns_builds_select_list(db: &mut TestDb<TypeTestSchema>) {
+    let TypeTestSchema { type_test } = schema;
+
+    let test_data = InsertTypeTest::new("columns_test", 25, 98.5, true, [1, 2, 3]).with_id(7);
+    db.insert(type_test).values([test_data]).execute();
+
+    let columns = DerivedSimpleWithColumns::columns();
+    assert_eq!(
+        ToSQL::<SQLiteValue<'_>>::to_sql(&columns).sql(),
+        r#""type_test"."id" AS "table_id", "type_test"."name" AS "table_name""#
+    );
+
+    let result: DerivedSimpleWithColumns = db.select(columns).from(type_test).get();
+    assert_eq!(result.table_id, 7);
+    assert_eq!(result.table_name, "columns_test");
+}
+
 #[drizzle::test]
 fn test_insert_returning_select_target_infers_row(db: &mut TestDb<TypeTestSchema>) {
     let TypeTestSchema { type_test } = schema;
@@ -0,0 +1,73 @@
+#![cfg(all(
+    any(feature = "rusqlite", feature = "turso", feature = "libsql"),
+    feature = "geo-types"
+))]
+
+use drizzle::core::expr::*;
+use drizzle::sqlite::prelude::*;
+use geo_types::{Geometry, LineString, Point, Polygon, line_string, point, polygon};
+
+// geo_types shapes are stored as WKB blobs
+#[SQLiteTable(NAME = "geometry_places")]
+struct GeometryPlace {
+    #[column(PRIMARY)]
+    id: i32,
+    location: Point<f64>,
+    route: Option<LineString<f64>>,
+    area: Option<Polygon<f64>>,
+    shape: Option<Geometry<f64>>,
+}
+
+#[derive(SQLiteSchema)]
+struct GeometrySchema {
+    geometry_places: GeometryPlace,
+}
+
+#[test]
+fn geometry_columns_ddl() {
+    let ddl = GeometryPlace::ddl_sql();
+    assert!(ddl.contains("`location` BLOB NOT NULL"), "{ddl}");
+    assert!(ddl.contains("`area` BLOB"), "{ddl}");
+}
+
+#[drizzle::test]
+fn geometry_wkb_roundtrip(db: &mut TestDb<GeometrySchema>) {
+    let table = schema.geometry_places;
+    let location = point!(x: 13.4, y: 52.5);
+    let route = line_string![(x: 0.0, y: 0.0), (x: 1.5, y: 2.5)];
+    let area = polygon![(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0)];
+    let shape = Geometry::Point(point!(x: -1.0, y: 1.0));
+
+    db.insert(table)
+        .values([InsertGeometryPlace::new(location)
+            .with_id(1)
+            .with_route(route.clone())
+            .with_area(area.clone())
+            .with_shape(shape.clone())])
+        .execute();
+    db.insert(table)
+        .values([InsertGeometryPlace::new(point!(x: 0.0, y: 0.0)).with_id(2)])
+        .execute();
+
+    let rows: Vec<SelectGeometryPlace> = db
+        .select(())
+        .from(table)
+        .r#where(eq(table.location, location))
+        .all();
+
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].id, 1);
+    assert_eq!(rows[0].location, location);
+    assert_eq!(rows[0].route, Some(route));
+    assert_eq!(rows[0].area, Some(area));
+    assert_eq!(rows[0].shape, Some(shape));
+
+    #[derive(SQLiteFromRow, Debug)]
+    struct ReturnResult(String);
+    let result: ReturnResult = db
+        .select(r#typeof(table.location).alias("location_type"))
+        .from(table)
+        .r#where(eq(table.id, 2))
+        .get();
+    assert_eq!(result.0, "blob");
+}
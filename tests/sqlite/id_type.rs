@@ -0,0 +1,72 @@
+#![cfg(any(feature = "rusqlite", feature = "turso", feature = "libsql"))]
+
+use drizzle::core::expr::*;
+use drizzle::sqlite::prelude::*;
+
+#[SQLiteTable(NAME = "id_type_authors", ID_TYPE = AuthorId)]
+struct IdTypeAuthor {
+    #[column(PRIMARY)]
+    id: i64,
+    name: String,
+}
+
+#[SQLiteTable(NAME = "id_type_books", ID_TYPE = BookId)]
+struct IdTypeBook {
+    #[column(PRIMARY)]
+    id: String,
+    #[column(references = IdTypeAuthor::id)]
+    author_id: AuthorId,
+    title: String,
+}
+
+#[derive(SQLiteSchema)]
+struct IdTypeSchema {
+    id_type_author: IdTypeAuthor,
+    id_type_book: IdTypeBook,
+}
+
+#[test]
+fn id_type_keeps_column_sql_type() {
+    assert!(IdTypeAuthor::ddl_sql().contains("`id` INTEGER PRIMARY KEY"));
+    assert!(IdTypeBook::ddl_sql().contains("`id` TEXT PRIMARY KEY"));
+    assert!(IdTypeBook::ddl_sql().contains("`author_id` INTEGER NOT NULL"));
+
+    assert_eq!(AuthorId::from(7), AuthorId(7));
+    assert_eq!(i64::from(AuthorId(7)), 7);
+}
+
+#[drizzle::test]
+fn id_type_roundtrip(db: &mut TestDb<IdTypeSchema>) {
+    let IdTypeSchema {
+        id_type_author,
+        id_type_book,
+    } = schema;
+
+    db.insert(id_type_author)
+        .values([InsertIdTypeAuthor::new("Ursula").with_id(AuthorId(1))])
+        .execute();
+    db.insert(id_type_book)
+        .values([InsertIdTypeBook::new(
+            BookId("earthsea".into()),
+            AuthorId(1),
+            "A Wizard of Earthsea",
+        )])
+        .execute();
+
+    let authors: Vec<SelectIdTypeAuthor> = db
+        .select(())
+        .from(id_type_author)
+        .r#where(eq(id_type_author.id, AuthorId(1)))
+        .all();
+    assert_eq!(authors.len(), 1);
+    assert_eq!(authors[0].id, AuthorId(1));
+
+    let books: Vec<SelectIdTypeBook> = db
+        .select(())
+        .from(id_type_book)
+        .r#where(eq(id_type_book.author_id, authors[0].id))
+        .all();
+    assert_eq!(books.len(), 1);
+    assert_eq!(books[0].id, BookId("earthsea".into()));
+    assert_eq!(books[0].author_id, AuthorId(1));
+}
@@ -190,6 +190,60 @@ fn conflict_resolution(db: &mut TestDb<SimpleSchema>) {
     assert_eq!(results[0].name, "conflict_test");
 }
 
+#[drizzle::test]
+fn run_reports_last_insert_rowid(db: &mut TestDb<SimpleSchema>) {
+    let SimpleSchema { simple } = schema;
+
+    let result = db
+        .insert(simple)
+        .values([InsertSimple::new("first").with_id(7)])
+        .run();
+    assert_eq!(result.rows_affected, 1);
+    assert_eq!(result.last_insert_rowid, Some(7));
+
+    let result = db
+        .insert(simple)
+        .values([InsertSimple::new("duplicate").with_id(7)])
+        .on_conflict_do_nothing()
+        .run();
+    assert_eq!(result.rows_affected, 0);
+    assert_eq!(result.last_insert_rowid, None);
+
+    let result = db
+        .update(simple)
+        .set(UpdateSimple::default().with_name("renamed"))
+        .r#where(eq(simple.id, 7))
+        .run();
+    assert_eq!(result.rows_affected, 1);
+    assert_eq!(result.last_insert_rowid, None);
+}
+
+#[drizzle::test]
+fn returning_ids_in_values_order(db: &mut TestDb<SimpleSchema>) {
+    let SimpleSchema { simple } = schema;
+
+    db.insert(simple)
+        .values([InsertSimple::new("existing").with_id(10)])
+        .execute();
+
+    let stmt = db
+        .insert(simple)
+        .values([
+            InsertSimple::new("a"),
+            InsertSimple::new("b"),
+            InsertSimple::new("c"),
+        ])
+        .returning_ids();
+
+    assert_eq!(
+        stmt.to_sql().sql(),
+        r#"INSERT INTO "simple" ("name") VALUES (?), (?), (?) RETURNING "simple"."id""#
+    );
+
+    let ids: Vec<i32> = stmt.all();
+    assert_eq!(ids, vec![11, 12, 13]);
+}
+
 #[cfg(all(feature = "serde", feature = "uuid"))]
 #[drizzle::test]
 fn feature_gated_insert(db: &mut TestDb<ComplexSchema>) {
@@ -469,3 +523,16 @@ fn on_conflict_do_update_excluded_e2e(db: &mut TestDb<SimpleSchema>) {
     assert_eq!(results.len(), 1);
     assert_eq!(results[0].name, "from_excluded");
 }
+
+#[test]
+fn bulk_insert_renders_at_precomputed_length() {
+    let builder = drizzle::sqlite::builder::QueryBuilder::new::<SimpleSchema>();
+    let SimpleSchema { simple } = SimpleSchema::new();
+
+    let rows = (0..1_000).map(|i| InsertSimple::new(format!("name_{i}")).with_id(i));
+    let sql = builder.insert(simple).values(rows).to_sql();
+    let (text, params) = sql.build();
+
+    assert_eq!(text.len(), sql.rendered_len());
+    assert_eq!(params.len(), 2_000);
+}
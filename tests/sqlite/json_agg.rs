@@ -0,0 +1,71 @@
+#![cfg(all(
+    any(feature = "rusqlite", feature = "turso", feature = "libsql"),
+    feature = "query",
+    feature = "serde",
+    feature = "uuid"
+))]
+
+use drizzle::core::asc;
+use drizzle::core::expr::{AliasExt, eq, json_agg_rows};
+use drizzle::sqlite::prelude::*;
+
+use crate::common::schema::sqlite::{
+    ComplexPostSchema, InsertComplex, InsertPost, Role, SelectComplex, SelectPost,
+};
+
+#[derive(SQLiteFromRow, Debug)]
+struct AuthorWithPosts {
+    name: String,
+    #[json]
+    posts: Vec<SelectPost>,
+}
+
+#[drizzle::test]
+fn json_agg_rows_groups_children_per_parent(db: &mut TestDb<ComplexPostSchema>) {
+    let ComplexPostSchema { complex, post } = schema;
+
+    db.insert(complex)
+        .values([
+            InsertComplex::new("Alice", true, Role::User),
+            InsertComplex::new("Bob", true, Role::User),
+            InsertComplex::new("Charlie", true, Role::User),
+        ])
+        .execute();
+
+    let users: Vec<SelectComplex> = db.select(()).from(complex).all();
+    let alice_id = users.iter().find(|u| u.name == "Alice").unwrap().id;
+    let bob_id = users.iter().find(|u| u.name == "Bob").unwrap().id;
+
+    db.insert(post)
+        .values([
+            InsertPost::new("Alice Post 1", true).with_author_id(alice_id),
+            InsertPost::new("Alice Post 2", false).with_author_id(alice_id),
+            InsertPost::new("Bob Post 1", true).with_author_id(bob_id),
+        ])
+        .execute();
+
+    let rows: Vec<AuthorWithPosts> = db
+        .select((complex.name, json_agg_rows(post).alias("posts")))
+        .from(complex)
+        .left_join((post, eq(complex.id, post.author_id)))
+        .group_by((complex.id, complex.name))
+        .order_by(asc(complex.name))
+        .all();
+
+    assert_eq!(rows.len(), 3);
+
+    assert_eq!(rows[0].name, "Alice");
+    let mut titles: Vec<&str> = rows[0].posts.iter().map(|p| p.title.as_str()).collect();
+    titles.sort_unstable();
+    assert_eq!(titles, ["Alice Post 1", "Alice Post 2"]);
+    assert!(rows[0].posts.iter().all(|p| p.author_id == Some(alice_id)));
+    assert!(rows[0].posts.iter().any(|p| !p.published));
+
+    assert_eq!(rows[1].name, "Bob");
+    assert_eq!(rows[1].posts.len(), 1);
+    assert_eq!(rows[1].posts[0].title, "Bob Post 1");
+
+    // LEFT JOIN with no match aggregates to an empty array, not a null row
+    assert_eq!(rows[2].name, "Charlie");
+    assert!(rows[2].posts.is_empty());
+}
@@ -0,0 +1,75 @@
+//! Tests for `drizzle::loader::Loader` on the async SQLite drivers.
+
+#![cfg(all(feature = "loader", feature = "libsql"))]
+
+use crate::common::helpers::libsql_setup;
+use crate::common::schema::sqlite::{InsertSimple, SelectSimple, SimpleSchema};
+use drizzle::loader::Loader;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[tokio::test]
+async fn loader_batches_and_caches_lookups() {
+    let (db, SimpleSchema { simple }) = libsql_setup::setup_db::<SimpleSchema>().await;
+
+    db.insert(simple)
+        .values([
+            InsertSimple::new("alpha").with_id(1),
+            InsertSimple::new("beta").with_id(2),
+        ])
+        .execute()
+        .await
+        .unwrap();
+
+    let fetches = AtomicUsize::new(0);
+    let loader = Loader::new(|ids: Vec<i32>| {
+        fetches.fetch_add(1, Ordering::SeqCst);
+        let db = &db;
+        async move {
+            db.get_many(simple, simple.id, &ids, |row: &SelectSimple| row.id)
+                .await
+        }
+    });
+
+    let names = |rows: Vec<Option<SelectSimple>>| -> Vec<Option<String>> {
+        rows.into_iter()
+            .map(|row| row.map(|row| row.name))
+            .collect()
+    };
+
+    let rows = loader.load_many(&[2, 7, 1, 2]).await.unwrap();
+    assert_eq!(
+        names(rows),
+        [
+            Some("beta".into()),
+            None,
+            Some("alpha".into()),
+            Some("beta".into())
+        ]
+    );
+    assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+    // Hits and cached misses don't refetch.
+    assert_eq!(loader.load_one(1).await.unwrap().unwrap().name, "alpha");
+    assert!(loader.load_one(7).await.unwrap().is_none());
+    assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+    // Cleared keys are refetched and see new data.
+    db.insert(simple)
+        .values([InsertSimple::new("gamma").with_id(7)])
+        .execute()
+        .await
+        .unwrap();
+    loader.clear(&7);
+    assert_eq!(loader.load_one(7).await.unwrap().unwrap().name, "gamma");
+    assert_eq!(fetches.load(Ordering::SeqCst), 2);
+
+    loader.prime(
+        3,
+        SelectSimple {
+            id: 3,
+            name: "primed".into(),
+        },
+    );
+    assert_eq!(loader.load_one(3).await.unwrap().unwrap().name, "primed");
+    assert_eq!(fetches.load(Ordering::SeqCst), 2);
+}
@@ -18,6 +18,35 @@ struct PushSchema {
     push_user: PushUser,
 }
 
+#[cfg(any(feature = "rusqlite", feature = "libsql"))]
+#[SQLiteTable(NAME = "sync_users")]
+struct SyncUser {
+    #[column(PRIMARY)]
+    id: i32,
+    name: String,
+}
+
+#[cfg(any(feature = "rusqlite", feature = "libsql"))]
+#[derive(SQLiteSchema)]
+struct SyncSchema {
+    sync_user: SyncUser,
+}
+
+#[cfg(any(feature = "rusqlite", feature = "libsql"))]
+#[SQLiteTable(NAME = "sync_users")]
+struct SyncUserWithNickname {
+    #[column(PRIMARY)]
+    id: i32,
+    name: String,
+    nickname: Option<String>,
+}
+
+#[cfg(any(feature = "rusqlite", feature = "libsql"))]
+#[derive(SQLiteSchema)]
+struct SyncSchemaWithNickname {
+    sync_user: SyncUserWithNickname,
+}
+
 #[cfg(feature = "rusqlite")]
 #[test]
 fn rusqlite_runtime_migrate_serializes_concurrent_runners() {
@@ -71,10 +100,112 @@ fn rusqlite_runtime_migrate_serializes_concurrent_runners() {
         })
         .expect("count migration effects");
     assert_eq!(effect_count, 1, "migration body must execute exactly once");
+    let tracking_rows: i64 = connection
+        .query_row("SELECT COUNT(*) FROM __drizzle_migrations", [], |row| {
+            row.get(0)
+        })
+        .expect("count tracking rows");
+    assert_eq!(tracking_rows, 1, "lock marker row must not outlive the run");
     drop(connection);
     let _ = std::fs::remove_file(path);
 }
 
+#[cfg(feature = "libsql")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn libsql_runtime_migrate_serializes_concurrent_runners() {
+    use std::sync::Arc;
+    use tokio::sync::Barrier;
+
+    let path = crate::common::helpers::temp_db_path();
+    let barrier = Arc::new(Barrier::new(2));
+    let migration = Migration::new(
+        "20260712000000_concurrent",
+        "CREATE TABLE IF NOT EXISTS migration_effects(value INTEGER NOT NULL);
+         INSERT INTO migration_effects(value) VALUES (1);",
+    );
+
+    let handles = (0..2)
+        .map(|_| {
+            let path = path.clone();
+            let barrier = Arc::clone(&barrier);
+            let migration = migration.clone();
+            tokio::spawn(async move {
+                let database = libsql::Builder::new_local(path)
+                    .build()
+                    .await
+                    .expect("open concurrent DB");
+                let connection = database.connect().expect("connect concurrent DB");
+                let (db, ()) = drizzle::sqlite::libsql::Drizzle::new(connection, ());
+                barrier.wait().await;
+                db.migrate(&[migration], Tracking::SQLITE).await
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut outcomes = Vec::new();
+    for handle in handles {
+        outcomes.push(handle.await.expect("migration task").expect("migrate"));
+    }
+    assert_eq!(
+        outcomes
+            .iter()
+            .filter(|outcome| outcome.is_up_to_date())
+            .count(),
+        1
+    );
+
+    let database = libsql::Builder::new_local(&path)
+        .build()
+        .await
+        .expect("reopen concurrent DB");
+    let connection = database.connect().expect("reconnect concurrent DB");
+    let mut rows = connection
+        .query(
+            "SELECT (SELECT COUNT(*) FROM migration_effects), \
+                    (SELECT COUNT(*) FROM __drizzle_migrations)",
+            (),
+        )
+        .await
+        .expect("count migration effects");
+    let row = rows.next().await.expect("next row").expect("count row");
+    assert_eq!(row.get::<i64>(0).expect("effect count"), 1);
+    assert_eq!(row.get::<i64>(1).expect("tracking rows"), 1);
+    drop(rows);
+    drop(connection);
+    drop(database);
+    let _ = std::fs::remove_file(path);
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn rusqlite_runtime_migrate_runs_no_transaction_migrations_outside_transaction() {
+    let db = crate::common::helpers::rusqlite_setup::setup_empty();
+
+    let migrations = vec![
+        Migration::new(
+            "20240101000000_logs",
+            "CREATE TABLE runtime_logs (id INTEGER PRIMARY KEY);",
+        ),
+        Migration::new(
+            "20240102000000_vacuum",
+            "-- drizzle:no-transaction\nDELETE FROM runtime_logs;\n\
+             --> statement-breakpoint\nVACUUM;",
+        ),
+    ];
+    let outcome = db
+        .migrate(&migrations, Tracking::SQLITE)
+        .expect("VACUUM runs outside the migration transaction");
+    assert_eq!(outcome.applied_count(), 2);
+
+    let applied_rows: i64 = db
+        .conn()
+        .query_row("SELECT COUNT(*) FROM __drizzle_migrations", [], |row| {
+            row.get(0)
+        })
+        .expect("count migrations rows");
+    assert_eq!(applied_rows, 2, "lock marker row must be released");
+}
+
 #[cfg(feature = "rusqlite")]
 #[test]
 fn rusqlite_runtime_migrate_runs_both_when_created_at_collides() {
@@ -590,8 +721,20 @@ fn rusqlite_push_is_idempotent() {
     let (db, schema) =
         crate::common::helpers::rusqlite_setup::setup_empty_db(PushSchema::default());
 
+    db.conn()
+        .execute("CREATE TABLE unmanaged (id INTEGER PRIMARY KEY)", [])
+        .expect("create unmanaged table");
     db.push(&schema).expect("first push");
     db.push(&schema).expect("second push should be a no-op");
+
+    let plan = db.sync_schema(&schema).expect("sync after push");
+    assert!(
+        plan.statements.is_empty(),
+        "push should leave nothing to apply: {:?}",
+        plan.statements
+    );
+    let unmanaged = crate::common::helpers::rusqlite_setup::table_exists(db.conn(), "unmanaged");
+    assert_eq!(unmanaged, 1, "push should leave unmanaged tables alone");
 }
 
 #[cfg(feature = "rusqlite")]
@@ -633,10 +776,24 @@ async fn libsql_push_is_idempotent() {
     let (db, schema) =
         crate::common::helpers::libsql_setup::setup_empty_db(PushSchema::default()).await;
 
+    db.conn()
+        .execute("CREATE TABLE unmanaged (id INTEGER PRIMARY KEY)", ())
+        .await
+        .expect("create unmanaged table");
     db.push(&schema).await.expect("first push");
     db.push(&schema)
         .await
         .expect("second push should be a no-op");
+
+    let plan = db.sync_schema(&schema).await.expect("sync after push");
+    assert!(
+        plan.statements.is_empty(),
+        "push should leave nothing to apply: {:?}",
+        plan.statements
+    );
+    let unmanaged =
+        crate::common::helpers::libsql_setup::table_exists(db.conn(), "unmanaged").await;
+    assert_eq!(unmanaged, 1, "push should leave unmanaged tables alone");
 }
 
 #[cfg(feature = "libsql")]
@@ -684,10 +841,23 @@ async fn turso_push_is_idempotent() {
     let (db, schema) =
         crate::common::helpers::turso_setup::setup_empty_db(PushSchema::default()).await;
 
+    db.conn()
+        .execute("CREATE TABLE unmanaged (id INTEGER PRIMARY KEY)", ())
+        .await
+        .expect("create unmanaged table");
     db.push(&schema).await.expect("first push");
     db.push(&schema)
         .await
         .expect("second push should be a no-op");
+
+    let plan = db.sync_schema(&schema).await.expect("sync after push");
+    assert!(
+        plan.statements.is_empty(),
+        "push should leave nothing to apply: {:?}",
+        plan.statements
+    );
+    let unmanaged = crate::common::helpers::turso_setup::table_exists(db.conn(), "unmanaged").await;
+    assert_eq!(unmanaged, 1, "push should leave unmanaged tables alone");
 }
 
 #[cfg(feature = "turso")]
@@ -715,3 +885,126 @@ async fn turso_push_table_is_usable() {
     let name = row.get::<String>(0).expect("selected name");
     assert_eq!(name, "Alice");
 }
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn rusqlite_assert_schema_matches_recorded_hash() {
+    let (db, _) = crate::common::helpers::rusqlite_setup::setup_empty_db(PushSchema::default());
+    let migration = Migration::new(
+        "20260801000000_push_users",
+        "CREATE TABLE push_users (id INTEGER PRIMARY KEY, name TEXT NOT NULL, email TEXT);",
+    );
+
+    let err = db
+        .assert_schema_matches(Tracking::SQLITE)
+        .expect_err("nothing recorded yet");
+    assert!(err.to_string().contains("No schema hash recorded"), "{err}");
+
+    let outcome = db
+        .migrate(
+            std::slice::from_ref(&migration),
+            Tracking::SQLITE.schema_hash(PushSchema::SCHEMA_HASH),
+        )
+        .expect("migrate");
+    assert!(matches!(
+        outcome,
+        drizzle_migrations::MigrateOutcome::Applied { .. }
+    ));
+    db.assert_schema_matches(Tracking::SQLITE)
+        .expect("recorded hash matches");
+
+    db.migrate(&[migration], Tracking::SQLITE.schema_hash(1))
+        .expect("up-to-date migrate still records the hash");
+    let err = db
+        .assert_schema_matches(Tracking::SQLITE)
+        .expect_err("recorded hash differs");
+    assert!(err.to_string().contains("does not match"), "{err}");
+}
+
+#[cfg(feature = "libsql")]
+#[tokio::test]
+async fn libsql_assert_schema_matches_recorded_hash() {
+    let (db, _) = crate::common::helpers::libsql_setup::setup_empty_db(PushSchema::default()).await;
+    let migration = Migration::new(
+        "20260801000000_push_users",
+        "CREATE TABLE push_users (id INTEGER PRIMARY KEY, name TEXT NOT NULL, email TEXT);",
+    );
+
+    let tracking = Tracking::SQLITE.schema_hash(PushSchema::SCHEMA_HASH);
+
+    db.migrate(std::slice::from_ref(&migration), tracking.clone())
+        .await
+        .expect("migrate");
+    db.assert_schema_matches(Tracking::SQLITE)
+        .await
+        .expect("recorded hash matches");
+
+    let outcome = db
+        .migrate(&[migration], tracking)
+        .await
+        .expect("re-migrate");
+    assert!(matches!(
+        outcome,
+        drizzle_migrations::MigrateOutcome::UpToDate
+    ));
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn rusqlite_sync_schema_applies_additive_changes_only() {
+    let (db, _) = crate::common::helpers::rusqlite_setup::setup_empty_db(SyncSchema::default());
+
+    let plan = db.sync_schema(&SyncSchema::new()).expect("create table");
+    assert!(!plan.is_empty());
+    assert!(
+        db.sync_schema(&SyncSchema::new())
+            .expect("no-op sync")
+            .is_empty()
+    );
+
+    db.sync_schema(&SyncSchemaWithNickname::new())
+        .expect("add column");
+    db.conn()
+        .execute(
+            "INSERT INTO sync_users (id, name, nickname) VALUES (1, 'Alice', 'Al')",
+            [],
+        )
+        .expect("insert with new column");
+
+    let err = db
+        .sync_schema(&SyncSchema::new())
+        .expect_err("dropping a column is destructive");
+    assert!(err.to_string().contains("Refusing destructive"), "{err}");
+    let nickname: String = db
+        .conn()
+        .query_row("SELECT nickname FROM sync_users WHERE id = 1", [], |row| {
+            row.get(0)
+        })
+        .expect("column still there");
+    assert_eq!(nickname, "Al");
+
+    db.sync_schema_with(
+        &SyncSchema::new(),
+        drizzle_migrations::SyncOptions::new().allow_destructive(true),
+    )
+    .expect("destructive sync when allowed");
+}
+
+#[cfg(feature = "libsql")]
+#[tokio::test]
+async fn libsql_sync_schema_applies_additive_changes_only() {
+    let (db, _) = crate::common::helpers::libsql_setup::setup_empty_db(SyncSchema::default()).await;
+
+    db.sync_schema(&SyncSchema::new())
+        .await
+        .expect("create table");
+    db.sync_schema(&SyncSchemaWithNickname::new())
+        .await
+        .expect("add column");
+
+    let err = db
+        .sync_schema(&SyncSchema::new())
+        .await
+        .expect_err("dropping a column is destructive");
+    assert!(err.to_string().contains("Refusing destructive"), "{err}");
+}
@@ -1,24 +1,33 @@
 pub mod alias;
+pub mod attach;
 pub mod arrayvec;
 pub mod async_edge_cases;
 pub mod attributes;
+pub mod axum;
 pub mod blob_filter;
 pub mod comment;
 pub mod conditions;
 pub mod custom_column;
+pub mod decode_error;
 pub mod delete;
 pub mod r#enum;
 pub mod expr;
 pub mod foreign_keys;
 pub mod fromrow;
+pub mod geometry;
 pub mod group_by;
+pub mod id_type;
 pub mod include_migrations;
 pub mod index;
 pub mod insert;
 pub mod joins;
 pub mod json;
+#[cfg(feature = "query")]
+pub mod json_agg;
+pub mod loader;
 pub mod migrations;
 pub mod parameters;
+pub mod pragma;
 pub mod prepare;
 #[cfg(feature = "query")]
 pub mod query;
@@ -27,9 +36,12 @@ pub mod seed;
 pub mod select;
 pub mod sql_generation;
 pub mod subquery;
+pub mod timestamp;
 pub mod transaction;
 pub mod types;
+pub mod unsigned;
 pub mod update;
 pub mod uuid;
+pub mod vector;
 #[cfg(any(feature = "compact-str", feature = "bytes", feature = "smallvec-types"))]
 pub mod wrappers;
@@ -0,0 +1,30 @@
+#![cfg(feature = "rusqlite")]
+use crate::common::schema::sqlite::SimpleSchema;
+use drizzle::sqlite::pragma::WalCheckpointMode;
+
+#[test]
+fn pragma_helpers_decode_rows() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let (db, SimpleSchema { simple }) =
+        drizzle::sqlite::rusqlite::Drizzle::new(conn, SimpleSchema::new());
+    db.create().unwrap();
+
+    let columns = db.pragma().table_info(simple).unwrap();
+    let names: Vec<&str> = columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["id", "name"]);
+    assert_eq!(columns[0].column_type, "INTEGER");
+    assert_eq!(columns[0].pk, 1);
+    assert_eq!(columns[1].pk, 0);
+    assert!(columns[1].not_null);
+
+    assert!(db.pragma().integrity_check().unwrap().is_empty());
+    assert!(db.pragma().foreign_key_check().unwrap().is_empty());
+
+    // In-memory databases aren't in WAL mode, so SQLite reports -1 frames
+    let checkpoint = db
+        .pragma()
+        .wal_checkpoint(WalCheckpointMode::Passive)
+        .unwrap();
+    assert!(!checkpoint.busy);
+    assert_eq!(checkpoint.log_frames, -1);
+}
@@ -105,6 +105,51 @@ fn test_prepared_get_single_row(db: &mut TestDb<SimpleSchema>) {
     assert_eq!(result.name, "UniqueUser");
 }
 
+#[drizzle::test]
+fn test_prepared_bind_from_struct(db: &mut TestDb<SimpleSchema>) {
+    let SimpleSchema { simple } = schema;
+
+    db.insert(simple)
+        .values([
+            InsertSimple::new("Alice").with_id(1),
+            InsertSimple::new("Alice").with_id(2),
+            InsertSimple::new("Bob").with_id(3),
+        ])
+        .execute();
+
+    #[derive(BindParams)]
+    struct SearchParams {
+        name: String,
+        min_id: i32,
+    }
+
+    let name = simple.name.placeholder("name");
+    let min_id = simple.id.placeholder("min_id");
+    let prepared = db
+        .select(())
+        .from(simple)
+        .r#where(and(eq(simple.name, name), gte(simple.id, min_id)))
+        .prepare();
+
+    let params = SearchParams {
+        name: "Alice".to_string(),
+        min_id: 2,
+    };
+    let result: Vec<SelectSimple> = prepared.all(db.conn(), &params);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].id, 2);
+
+    let owned = prepared.into_owned();
+    let result: Vec<SelectSimple> = owned.all(
+        db.conn(),
+        ParamSet::bind_from(&SearchParams {
+            min_id: 0,
+            ..params
+        }),
+    );
+    assert_eq!(result.len(), 2);
+}
+
 #[drizzle::test]
 fn test_prepared_missing_named_param_fails(db: &mut TestDb<SimpleSchema>) {
     let SimpleSchema { simple } = schema;
@@ -12,6 +12,15 @@ struct TestTable {
     email: Option<String>,
 }
 
+#[SQLiteTable(CASING = "camelCase")]
+struct UserAccounts {
+    #[column(PRIMARY)]
+    id: i32,
+    display_name: String,
+    #[column(name = "legacy_flag")]
+    legacy_flag: Option<bool>,
+}
+
 #[SQLiteTable(STRICT)]
 struct StrictTable {
     #[column(PRIMARY)]
@@ -45,6 +54,16 @@ struct SQLiteMacroDdl {
     name_len_virtual: i32,
 }
 
+#[SQLiteIndex(unique)]
+struct TestTableNameIdx(TestTable::name);
+
+#[derive(SQLiteSchema)]
+struct ReflectedSchema {
+    test_table: TestTable,
+    test_table_name_idx: TestTableNameIdx,
+    strict_table: StrictTable,
+}
+
 #[derive(SQLiteSchema)]
 struct SQLiteMacroDdlSchema {
     table: SQLiteMacroDdl,
@@ -76,6 +95,36 @@ struct SQLiteGeneratedRebuildStoredSchema {
     table: SQLiteGeneratedRebuildStored,
 }
 
+/// Accounts visible to the billing service.
+#[SQLiteTable(NAME = "macro_comments")]
+struct SQLiteMacroComments {
+    #[column(PRIMARY)]
+    id: i32,
+    /// Display name.
+    name: String,
+    /// Superseded by the column attribute.
+    #[column(COMMENT = "Billing email address.")]
+    email: Option<String>,
+}
+
+#[SQLiteTable(NAME = "macro_comments")]
+struct SQLiteMacroUncommented {
+    #[column(PRIMARY)]
+    id: i32,
+    name: String,
+    email: Option<String>,
+}
+
+#[derive(SQLiteSchema)]
+struct SQLiteMacroCommentsSchema {
+    table: SQLiteMacroComments,
+}
+
+#[derive(SQLiteSchema)]
+struct SQLiteMacroUncommentedSchema {
+    table: SQLiteMacroUncommented,
+}
+
 #[test]
 fn table_sql() {
     assert_eq!(
@@ -267,6 +316,32 @@ fn rusqlite_executes_strict_without_rowid_create_table_sql() {
         .expect("execute strict without rowid create table");
 }
 
+#[cfg(feature = "rusqlite")]
+#[test]
+fn rusqlite_reflects_live_tables_columns_and_indexes() {
+    let conn = rusqlite::Connection::open_in_memory().expect("open in-memory sqlite");
+    let (db, _) = drizzle::sqlite::rusqlite::Drizzle::new(conn, ReflectedSchema::new());
+    db.create().expect("create schema");
+
+    let tables = db.tables().expect("list tables");
+    let names: Vec<&str> = tables.iter().map(|t| t.name.as_ref()).collect();
+    assert_eq!(names, ["strict_table", "test_table"]);
+    assert!(tables[0].strict);
+
+    let columns = db.columns("test_table").expect("list columns");
+    let names: Vec<&str> = columns.iter().map(|c| c.name.as_ref()).collect();
+    assert_eq!(names, ["id", "name", "email"]);
+    assert!(columns[1].not_null);
+    assert!(!columns[2].not_null);
+
+    let indexes = db.indexes("test_table").expect("list indexes");
+    assert_eq!(indexes.len(), 1);
+    assert_eq!(indexes[0].name, "test_table_name_idx");
+    assert!(indexes[0].is_unique);
+
+    assert!(db.columns("missing").expect("list columns").is_empty());
+}
+
 #[test]
 fn name_attribute() {
     assert_eq!(
@@ -275,6 +350,18 @@ fn name_attribute() {
     );
 }
 
+#[test]
+fn casing_attribute() {
+    assert_eq!(
+        UserAccounts::create_table_sql(),
+        "CREATE TABLE `userAccounts` (\n\t`id` INTEGER PRIMARY KEY,\n\t`displayName` TEXT NOT NULL,\n\t`legacy_flag` INTEGER\n);"
+    );
+
+    let table = UserAccounts::new();
+    assert_eq!(table.display_name.name(), "displayName");
+    assert_eq!(table.legacy_flag.name(), "legacy_flag");
+}
+
 #[test]
 fn column_types() {
     assert_eq!(
@@ -316,6 +403,72 @@ fn collate_emits_in_const_ddl() {
     );
 }
 
+// Index members take a `#[collate = ...]` attribute so a case-insensitive
+// unique index doesn't need a `lower(...)` expression index.
+#[SQLiteTable(NAME = "collate_accounts")]
+struct CollateAccount {
+    #[column(PRIMARY)]
+    id: i32,
+    email: String,
+}
+
+#[SQLiteIndex(unique)]
+struct CollateAccountEmailIdx(#[collate = "NOCASE"] CollateAccount::email);
+
+#[derive(SQLiteSchema)]
+struct CollateIndexSchema {
+    account: CollateAccount,
+    account_email_idx: CollateAccountEmailIdx,
+}
+
+#[test]
+fn index_member_collate_emits_in_ddl() {
+    assert_eq!(
+        CollateAccountEmailIdx::create_index_sql(),
+        "CREATE UNIQUE INDEX `collate_account_email_idx` ON `collate_accounts`(`email` COLLATE NOCASE);"
+    );
+    assert_eq!(
+        CollateAccountEmailIdx::ddl_sql(),
+        "CREATE UNIQUE INDEX \"collate_account_email_idx\" ON \"collate_accounts\" (\"email\" COLLATE NOCASE)"
+    );
+    assert_eq!(
+        CollateAccountEmailIdx::DDL_INDEX.into_index().columns[0]
+            .collate
+            .as_deref(),
+        Some("NOCASE")
+    );
+
+    let drizzle::migrations::Snapshot::Sqlite(snapshot) = CollateIndexSchema::new().to_snapshot()
+    else {
+        panic!("expected sqlite snapshot");
+    };
+    let index = snapshot
+        .ddl
+        .iter()
+        .find_map(|entity| match entity {
+            drizzle::migrations::sqlite::SqliteEntity::Index(index) => Some(index),
+            _ => None,
+        })
+        .expect("index in snapshot");
+    assert_eq!(index.columns[0].collate.as_deref(), Some("NOCASE"));
+}
+
+#[drizzle::test]
+fn index_member_collate_rejects_case_variant_duplicates(db: &mut TestDb<CollateIndexSchema>) {
+    let CollateIndexSchema { account, .. } = schema;
+
+    db.insert(account)
+        .values([InsertCollateAccount::new("Ada@Example.com")])
+        .execute();
+
+    let duplicate = result!(
+        db.insert(account)
+            .values([InsertCollateAccount::new("ada@example.com")])
+            .execute()
+    );
+    assert!(duplicate.unwrap_err().is_constraint_violation());
+}
+
 #[test]
 fn collate_expression_combinator_emits_collate_clause() {
     use drizzle::core::ToSQL;
@@ -751,49 +904,55 @@ fn test_deterministic_ordering(db: &mut TestDb<ComplexTestSchema>) {
     assert_eq!(lead_fk.target_columns, &["id"]);
 }
 
-#[SQLiteTable(NAME = "cycle_a")]
-struct CycleA {
-    #[column(PRIMARY)]
-    id: i32,
-    #[column(REFERENCES = CycleB::id)]
-    b_id: i32,
-}
+#[test]
+fn test_drop_statements_reverse_dependency_order() {
+    let statements: Vec<_> = ComplexTestSchema::new()
+        .drop_statements()
+        .expect("drop statements")
+        .collect();
 
-#[SQLiteTable(NAME = "cycle_b")]
-struct CycleB {
-    #[column(PRIMARY)]
-    id: i32,
-    #[column(REFERENCES = CycleC::id)]
-    c_id: i32,
+    // Indexes first, then tables with referencing tables before referenced ones
+    assert_eq!(statements.len(), 6, "{statements:?}");
+    assert!(
+        statements[..3]
+            .iter()
+            .all(|s| s.starts_with("DROP INDEX IF EXISTS")),
+        "{statements:?}"
+    );
+    assert_eq!(
+        statements[3..],
+        [
+            r#"DROP TABLE IF EXISTS "projects""#,
+            r#"DROP TABLE IF EXISTS "employees""#,
+            r#"DROP TABLE IF EXISTS "departments""#,
+        ]
+    );
 }
 
-#[SQLiteTable(NAME = "cycle_c")]
-struct CycleC {
-    #[column(PRIMARY)]
-    id: i32,
-    #[column(REFERENCES = CycleA::id)]
-    a_id: i32,
-}
+#[drizzle::test]
+fn test_drop_and_create_if_not_exists(db: &mut TestDb<ViewTestSchema>) {
+    let ViewTestSchema {
+        user, user_emails, ..
+    } = schema;
 
-#[derive(SQLiteSchema)]
-struct CycleSchema {
-    a: CycleA,
-    b: CycleB,
-    c: CycleC,
-}
+    // Every object already exists, so this is a no-op
+    db.create_if_not_exists();
 
-#[test]
-fn sqlite_cycle_reports_structured_error() {
-    let schema = CycleSchema::new();
-    let err = match schema.create_statements() {
-        Ok(_) => panic!("expected cycle detection error"),
-        Err(err) => err,
-    };
-    assert!(
-        err.to_string()
-            .contains("Cyclic table dependency detected in SQLiteSchema"),
-        "unexpected error: {err}"
-    );
+    // `TestDb` implements `Drop`, so reach through to the driver explicitly
+    (**db).drop();
+    // Nothing left behind: a plain create succeeds again
+    db.create();
+    db.create_if_not_exists();
+
+    let result = db
+        .insert(user)
+        .values([InsertUser::new("a@example.com", "User A")])
+        .execute();
+    assert_eq!(result, 1);
+
+    let results: Vec<UserEmailRow> = db.select(UserEmailRow::Select).from(user_emails).all();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].email, "a@example.com");
 }
 
 #[SQLiteTable(NAME = "dup_table")]
@@ -1338,3 +1497,49 @@ mod view_query {
         assert_eq!(results[1].name, "Bob");
     }
 }
+
+#[test]
+fn sqlite_macro_comments_reach_snapshot_without_changing_ddl() {
+    assert_eq!(
+        SQLiteMacroComments::create_table_sql(),
+        SQLiteMacroUncommented::create_table_sql()
+    );
+
+    let drizzle::migrations::Snapshot::Sqlite(commented) =
+        SQLiteMacroCommentsSchema::new().to_snapshot()
+    else {
+        panic!("expected sqlite snapshot");
+    };
+    let drizzle::migrations::Snapshot::Sqlite(uncommented) =
+        SQLiteMacroUncommentedSchema::new().to_snapshot()
+    else {
+        panic!("expected sqlite snapshot");
+    };
+
+    assert!(commented.ddl.iter().any(|entity| matches!(
+        entity,
+        drizzle::migrations::sqlite::SqliteEntity::Table(table)
+            if table.name == "macro_comments"
+                && table.comment.as_deref() == Some("Accounts visible to the billing service.")
+    )));
+    assert!(commented.ddl.iter().any(|entity| matches!(
+        entity,
+        drizzle::migrations::sqlite::SqliteEntity::Column(column)
+            if column.name == "name" && column.comment.as_deref() == Some("Display name.")
+    )));
+    assert!(commented.ddl.iter().any(|entity| matches!(
+        entity,
+        drizzle::migrations::sqlite::SqliteEntity::Column(column)
+            if column.name == "email"
+                && column.comment.as_deref() == Some("Billing email address.")
+    )));
+
+    let prev = drizzle::migrations::sqlite::SQLiteDDL::from_entities(uncommented.ddl);
+    let cur = drizzle::migrations::sqlite::SQLiteDDL::from_entities(commented.ddl);
+    let migration = drizzle::migrations::sqlite::compute_migration(&prev, &cur);
+    assert!(
+        migration.sql_statements.is_empty(),
+        "comment-only changes must not emit SQL, got: {:?}",
+        migration.sql_statements
+    );
+}
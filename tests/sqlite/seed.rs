@@ -1,8 +1,8 @@
 #![cfg(any(feature = "rusqlite", feature = "turso", feature = "libsql"))]
 
 use crate::common::schema::sqlite::*;
-use crate::sqlite::foreign_keys::{CompositeFkSchema, FkCascadeSchema};
-use drizzle_seed::{Generator, GeneratorKind, RngCore, SeedConfig, SeedValue};
+use crate::sqlite::foreign_keys::{CompositeFkSchema, FkCascade, FkCascadeSchema};
+use drizzle_seed::{Distribution, Generator, GeneratorKind, RngCore, SeedConfig, SeedValue};
 
 // ---------------------------------------------------------------------------
 // SeedConfig type-safe builder tests
@@ -109,6 +109,151 @@ fn config_custom_generator_via_column_ref() {
     assert_eq!(fixed_count, 4, "custom generator should produce FIXED");
 }
 
+#[test]
+fn config_value_pools_and_sequences() {
+    use drizzle::sqlite::values::OwnedSQLiteValue;
+
+    let schema = SimpleSchema::new();
+    let stmts = SeedConfig::sqlite(&schema)
+        .seed(3)
+        .count(&schema.simple, 4)
+        .sequence(&Simple::id, 100, 10)
+        .cycle(&Simple::name, vec!["free", "pro", "team"])
+        .generate();
+
+    let (_sql, params) = stmts[0].build();
+    assert_eq!(
+        params,
+        vec![
+            OwnedSQLiteValue::Integer(100),
+            OwnedSQLiteValue::Text("free".to_string()),
+            OwnedSQLiteValue::Integer(110),
+            OwnedSQLiteValue::Text("pro".to_string()),
+            OwnedSQLiteValue::Integer(120),
+            OwnedSQLiteValue::Text("team".to_string()),
+            OwnedSQLiteValue::Integer(130),
+            OwnedSQLiteValue::Text("free".to_string()),
+        ]
+    );
+
+    let stmts = SeedConfig::sqlite(&schema)
+        .seed(3)
+        .count(&schema.simple, 20)
+        .values(&Simple::name, vec!["us", "de"])
+        .generate();
+    let (_sql, params) = stmts[0].build();
+    for name in params.iter().skip(1).step_by(2) {
+        assert!(
+            matches!(name, OwnedSQLiteValue::Text(code) if code == "us" || code == "de"),
+            "sampled value outside the pool: {name:?}"
+        );
+    }
+}
+
+#[test]
+fn config_null_ratios_only_touch_nullable_columns() {
+    use drizzle::sqlite::values::OwnedSQLiteValue;
+
+    let schema = ComplexSchema::new();
+    let rows = |config: SeedConfig<'_, drizzle_seed::Sqlite, ComplexSchema>| {
+        let (sql, params) = config.generate()[0].build();
+        let columns = sql[sql.find('(').unwrap()..sql.find(')').unwrap()]
+            .split(',')
+            .map(|c| c.trim_matches(|ch: char| ch == '(' || ch == '"' || ch == ' '))
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(params.len() % columns.len(), 0, "every column is bound");
+        let column = |name: &str| columns.iter().position(|c| c == name).unwrap();
+        let (name, email) = (column("name"), column("email"));
+        params
+            .chunks(columns.len())
+            .map(|row| (row[name].clone(), row[email].clone()))
+            .collect::<Vec<_>>()
+    };
+    let base = || {
+        SeedConfig::sqlite(&schema)
+            .seed(9)
+            .count(&schema.complex, 200)
+    };
+
+    let filled = rows(base());
+    assert!(
+        filled
+            .iter()
+            .all(|(_, email)| *email != OwnedSQLiteValue::Null)
+    );
+
+    let mixed = rows(base().null_ratio(&Complex::email, 0.3));
+    let nulls = mixed
+        .iter()
+        .filter(|(_, email)| *email == OwnedSQLiteValue::Null)
+        .count();
+    assert!(
+        (30..=90).contains(&nulls),
+        "expected ~60 NULL emails, got {nulls}"
+    );
+    // Rows that stay non-NULL keep the values they had without a ratio.
+    for ((_, before), (_, after)) in filled.iter().zip(&mixed) {
+        assert!(*after == OwnedSQLiteValue::Null || after == before);
+    }
+
+    let all_null = rows(base().default_null_ratio(1.0));
+    assert!(all_null.iter().all(|(name, email)| {
+        *email == OwnedSQLiteValue::Null && matches!(name, OwnedSQLiteValue::Text(_))
+    }));
+}
+
+#[test]
+fn config_refine_derives_columns_from_the_row() {
+    use drizzle::sqlite::values::OwnedSQLiteValue;
+
+    let schema = ComplexSchema::new();
+    let stmts = SeedConfig::sqlite(&schema)
+        .seed(4)
+        .count(&schema.complex, 20)
+        .null_ratio(&Complex::email, 0.5)
+        .refine(&schema.complex, |row| {
+            let name: String = row.get(&Complex::name);
+            let handle = name.to_lowercase().replace(' ', ".");
+            row.set(&Complex::email, Some(format!("{handle}@test.local")));
+        })
+        .refine(&schema.complex, |row| {
+            let email = row.get(&Complex::email).expect("set by the first refine");
+            assert!(email.ends_with("@test.local"));
+            row.set(&Complex::age, Some(18 + row.index() as i32));
+        })
+        .generate();
+
+    let (sql, params) = stmts[0].build();
+    let columns = sql[sql.find('(').unwrap()..sql.find(')').unwrap()]
+        .split(',')
+        .map(|c| c.trim_matches(|ch: char| ch == '(' || ch == '"' || ch == ' '))
+        .collect::<Vec<_>>();
+    let column = |name: &str| columns.iter().position(|c| *c == name).unwrap();
+    let (name, email, age) = (column("name"), column("email"), column("age"));
+    let rows = params.chunks(columns.len()).collect::<Vec<_>>();
+    assert_eq!(rows.len(), 20);
+    for (idx, row) in rows.iter().enumerate() {
+        let (OwnedSQLiteValue::Text(name), OwnedSQLiteValue::Text(email)) =
+            (&row[name], &row[email])
+        else {
+            panic!("expected text name and email, got {row:?}");
+        };
+        assert_eq!(
+            *email,
+            format!("{}@test.local", name.to_lowercase().replace(' ', "."))
+        );
+        assert_eq!(row[age], OwnedSQLiteValue::Integer(18 + idx as i64));
+    }
+}
+
+#[test]
+#[should_panic(expected = "null ratio must be within 0.0..=1.0")]
+fn config_null_ratio_rejects_out_of_range() {
+    let schema = ComplexSchema::new();
+    let _ = SeedConfig::sqlite(&schema).null_ratio(&Complex::email, 1.5);
+}
+
 #[test]
 fn config_generator_accepts_column_generator() {
     let schema = SimpleSchema::new();
@@ -307,6 +452,74 @@ fn config_relation_groups_composite_fk_values_per_parent() {
     );
 }
 
+fn fk_cascade_parent_ids(stmts: &[drizzle_seed::SQLiteSeedStatement]) -> Vec<i64> {
+    stmts
+        .iter()
+        .map(|s| s.build())
+        .filter(|(sql, _)| sql.contains("INSERT INTO") && sql.contains("fk_cascade"))
+        .flat_map(|(_, params)| params.into_iter().skip(1).step_by(3))
+        .map(|param| {
+            let drizzle::sqlite::values::OwnedSQLiteValue::Integer(v) = param else {
+                panic!("expected integer parent_id param");
+            };
+            v
+        })
+        .collect()
+}
+
+#[test]
+fn config_relation_range_samples_children_per_parent() {
+    let schema = FkCascadeSchema::new();
+    let config = SeedConfig::sqlite(&schema)
+        .seed(7)
+        .count(&schema.fk_parent, 50)
+        .relation(&schema.fk_parent, &schema.fk_cascade, 1..=4);
+    let parent_ids = fk_cascade_parent_ids(&config.generate());
+    assert_eq!(parent_ids, fk_cascade_parent_ids(&config.generate()));
+
+    // Children stay grouped by parent, and every parent gets 1 to 4 of them.
+    assert!(parent_ids.is_sorted());
+    let per_parent: Vec<usize> = (1..=50)
+        .map(|id| parent_ids.iter().filter(|&&p| p == id).count())
+        .collect();
+    assert!(per_parent.iter().all(|n| (1..=4).contains(n)));
+    assert!(per_parent.contains(&1) && per_parent.contains(&4));
+    assert_eq!(parent_ids.len(), per_parent.iter().sum::<usize>());
+}
+
+#[test]
+fn config_relation_poisson_skews_children_per_parent() {
+    let schema = FkCascadeSchema::new();
+    let parent_ids = fk_cascade_parent_ids(
+        &SeedConfig::sqlite(&schema)
+            .seed(3)
+            .count(&schema.fk_parent, 200)
+            .relation_by_name("fk_parent", "fk_cascade", Distribution::Poisson(3.0))
+            .generate(),
+    );
+    let per_parent: Vec<usize> = (1..=200)
+        .map(|id| parent_ids.iter().filter(|&&p| p == id).count())
+        .collect();
+    let mean = parent_ids.len() as f64 / 200.0;
+    assert!(
+        (2.5..3.5).contains(&mean),
+        "mean children per parent {mean}"
+    );
+    assert!(per_parent.contains(&0), "some parents get no children");
+    assert!(per_parent.iter().any(|&n| n >= 7), "a few get many");
+}
+
+#[test]
+#[should_panic(expected = "Poisson mean must be finite and >= 0")]
+fn config_relation_rejects_negative_poisson_mean() {
+    let schema = FkCascadeSchema::new();
+    let _ = SeedConfig::sqlite(&schema).relation(
+        &schema.fk_parent,
+        &schema.fk_cascade,
+        Distribution::Poisson(-1.0),
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Seeder end-to-end: output validation
 // ---------------------------------------------------------------------------
@@ -584,3 +797,258 @@ fn seeder_email_column_produces_emails() {
         "should have produced at least one non-NULL email"
     );
 }
+
+/// `FkParent` and `FkCascade`, as `drizzle seed` would read them from disk.
+const FK_CASCADE_SOURCE: &str = r#"
+#[SQLiteTable]
+pub struct FkParent {
+    #[column(primary, autoincrement)]
+    pub id: i32,
+    pub name: String,
+}
+
+#[SQLiteTable]
+pub struct FkCascade {
+    #[column(primary, autoincrement)]
+    pub id: i32,
+    #[column(references = FkParent::id, on_delete = CASCADE)]
+    pub parent_id: Option<i32>,
+    pub value: String,
+}
+"#;
+
+fn parsed_sqlite_snapshot(source: &str) -> drizzle::migrations::sqlite::SQLiteSnapshot {
+    let parsed = drizzle::migrations::parser::SchemaParser::parse(source);
+    let snapshot =
+        drizzle::migrations::parse_result_to_snapshot(&parsed, drizzle::Dialect::SQLite, None);
+    let drizzle::migrations::Snapshot::Sqlite(snapshot) = snapshot else {
+        panic!("expected sqlite snapshot");
+    };
+    snapshot
+}
+
+#[test]
+fn snapshot_config_matches_derived_schema() {
+    let schema = FkCascadeSchema::new();
+    let snapshot = parsed_sqlite_snapshot(FK_CASCADE_SOURCE);
+
+    let derived = SeedConfig::sqlite(&schema)
+        .seed(7)
+        .count(&schema.fk_parent, 3)
+        .relation(&schema.fk_parent, &schema.fk_cascade, 2)
+        .kind(&FkCascade::value, GeneratorKind::Email)
+        .generate();
+    let from_snapshot = SeedConfig::from_snapshot(&snapshot)
+        .seed(7)
+        .count_by_name("fk_parent", 3)
+        .relation_by_name("fk_parent", "fk_cascade", 2)
+        .kind_by_name("fk_cascade", "value", GeneratorKind::Email)
+        .generate();
+
+    let build = |stmts: &[drizzle_seed::SQLiteSeedStatement]| {
+        stmts.iter().map(|s| s.build()).collect::<Vec<_>>()
+    };
+    assert_eq!(build(&from_snapshot), build(&derived));
+    assert_eq!(from_snapshot.len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "unknown table `missing`")]
+fn snapshot_config_rejects_unknown_tables() {
+    let snapshot = parsed_sqlite_snapshot(FK_CASCADE_SOURCE);
+    let _ = SeedConfig::from_snapshot(&snapshot).count_by_name("missing", 1);
+}
+
+#[test]
+fn generate_sql_inlines_literals() {
+    let schema = SimpleSchema::new();
+    let config = SeedConfig::sqlite(&schema)
+        .seed(42)
+        .count(&schema.simple, 3);
+
+    let script = config.generate_sql();
+    assert_eq!(script.len(), config.generate().len());
+    assert!(script[0].starts_with("INSERT INTO"));
+    assert!(!script[0].contains('?'));
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn write_sql_script_matches_bound_statements() {
+    use drizzle::core::SQLSchemaImpl;
+    use rusqlite::{Connection, params_from_iter};
+
+    fn dump(conn: &Connection) -> Vec<Vec<rusqlite::types::Value>> {
+        let mut stmt = conn
+            .prepare("SELECT * FROM \"complex\" ORDER BY rowid")
+            .expect("prepare dump");
+        let width = stmt.column_count();
+        stmt.query_map([], |row| {
+            (0..width)
+                .map(|idx| row.get::<_, rusqlite::types::Value>(idx))
+                .collect()
+        })
+        .expect("query dump")
+        .collect::<Result<_, _>>()
+        .expect("collect dump")
+    }
+
+    let schema = ComplexSchema::new();
+    let config = SeedConfig::sqlite(&schema)
+        .seed(7)
+        .count(&schema.complex, 25);
+    let open = || {
+        let conn = Connection::open_in_memory().expect("open sqlite");
+        for ddl in schema.create_statements().expect("create statements") {
+            conn.execute_batch(&ddl).expect("create table");
+        }
+        conn
+    };
+
+    let bound = open();
+    for stmt in config.generate() {
+        let (sql, params) = stmt.build();
+        bound
+            .execute(&sql, params_from_iter(params))
+            .expect("run bound insert");
+    }
+
+    let path = crate::common::helpers::temp_db_path().with_extension("sql");
+    config.write_sql(&path).expect("write seed script");
+    let script = std::fs::read_to_string(&path).expect("read seed script");
+    let _ = std::fs::remove_file(&path);
+
+    let scripted = open();
+    scripted.execute_batch(&script).expect("run seed script");
+
+    assert_eq!(dump(&scripted), dump(&bound));
+    assert_eq!(dump(&scripted).len(), 25);
+}
+
+#[cfg(all(feature = "rusqlite", feature = "uuid"))]
+#[test]
+fn seeder_blob_uuid_keys_are_valid_and_repeatable() {
+    use drizzle::core::SQLSchemaImpl;
+    use rusqlite::{Connection, params_from_iter};
+
+    let schema = ComplexSchema::new();
+    let config = SeedConfig::sqlite(&schema)
+        .seed(3)
+        .count(&schema.complex, 20);
+    let stmts = config.generate();
+    assert_eq!(
+        stmts.iter().map(|s| s.sql()).collect::<Vec<_>>(),
+        config
+            .generate()
+            .iter()
+            .map(|s| s.sql())
+            .collect::<Vec<_>>()
+    );
+
+    let conn = Connection::open_in_memory().expect("open sqlite");
+    for ddl in schema.create_statements().expect("create statements") {
+        conn.execute_batch(&ddl).expect("create table");
+    }
+    for stmt in &stmts {
+        let (sql, params) = stmt.build();
+        conn.execute(&sql, params_from_iter(params))
+            .expect("run seed insert");
+    }
+
+    let mut query = conn.prepare("SELECT id FROM \"complex\"").expect("prepare");
+    let ids: Vec<uuid::Uuid> = query
+        .query_map([], |row| row.get::<_, Vec<u8>>(0))
+        .expect("query ids")
+        .map(|bytes| uuid::Uuid::from_slice(&bytes.expect("id blob")).expect("16-byte uuid"))
+        .collect();
+    assert_eq!(ids.len(), 20);
+    assert!(ids.iter().all(|id| id.get_version_num() == 4));
+}
+
+#[test]
+fn config_uuid_v7_and_ulid_kinds() {
+    let schema = SimpleSchema::new();
+    let text_values = |kind| {
+        let stmts = SeedConfig::sqlite(&schema)
+            .seed(1)
+            .count(&schema.simple, 5)
+            .kind(&schema.simple.name, kind)
+            .generate();
+        let (_sql, params) = stmts[0].build();
+        params
+            .into_iter()
+            .filter_map(|p| match p {
+                drizzle::sqlite::values::OwnedSQLiteValue::Text(text) => Some(text),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let v7 = text_values(GeneratorKind::UuidV7);
+    assert_eq!(v7.len(), 5);
+    assert!(v7.windows(2).all(|w| w[0] < w[1]));
+    assert!(v7.iter().all(|k| k.len() == 36 && &k[14..15] == "7"));
+
+    let ulids = text_values(GeneratorKind::Ulid);
+    assert!(ulids.windows(2).all(|w| w[0] < w[1]));
+    assert!(ulids.iter().all(|k| k.len() == 26));
+}
+
+#[cfg(all(feature = "rusqlite", feature = "uuid", feature = "serde"))]
+#[test]
+fn json_template_documents_decode_into_column_types() {
+    use drizzle::core::SQLSchemaImpl;
+    use drizzle_seed::json_template;
+    use rusqlite::{Connection, params_from_iter};
+    use serde_json::json;
+
+    let schema = ComplexSchema::new();
+    let stmts = SeedConfig::sqlite(&schema)
+        .seed(5)
+        .count(&schema.complex, 10)
+        .generator(
+            &schema.complex.metadata,
+            json_template(json!({
+                "preferences": ["{{lorem}}", "{{city}}"],
+                "last_login": "{{timestamp}}",
+                "theme": "dark",
+            })),
+        )
+        .generator(
+            &schema.complex.config,
+            json_template(json!({
+                "notifications": "{{bool}}",
+                "language": "{{country}}",
+                "settings": { "timezone": "{{city}}" },
+            })),
+        )
+        .generate();
+
+    let conn = Connection::open_in_memory().expect("open sqlite");
+    for ddl in schema.create_statements().expect("create statements") {
+        conn.execute_batch(&ddl).expect("create table");
+    }
+    for stmt in &stmts {
+        let (sql, params) = stmt.build();
+        conn.execute(&sql, params_from_iter(params))
+            .expect("run seed insert");
+    }
+
+    let mut query = conn
+        .prepare("SELECT metadata, config FROM \"complex\"")
+        .expect("prepare");
+    let rows: Vec<(String, Vec<u8>)> = query
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .expect("query json columns")
+        .collect::<Result<_, _>>()
+        .expect("collect json columns");
+    assert_eq!(rows.len(), 10);
+    for (metadata, config) in rows {
+        let metadata: UserMetadata = serde_json::from_str(&metadata).expect("metadata JSON");
+        assert_eq!(metadata.preferences.len(), 2);
+        assert_eq!(metadata.theme, "dark");
+        assert!(metadata.last_login.is_some());
+        let config: UserConfig = serde_json::from_slice(&config).expect("config JSONB");
+        assert!(config.settings.contains_key("timezone"));
+    }
+}
@@ -232,3 +232,293 @@ fn feature_gated_select(db: &mut TestDb<ComplexSchema>) {
     assert_eq!(metadata_results.len(), 1);
     assert_eq!(metadata_results[0].name, "feature_user");
 }
+
+#[drizzle::test]
+fn get_many_returns_rows_in_key_order(db: &mut TestDb<SimpleSchema>) {
+    let SimpleSchema { simple } = schema;
+
+    db.insert(simple)
+        .values([
+            InsertSimple::new("alpha").with_id(1),
+            InsertSimple::new("beta").with_id(2),
+            InsertSimple::new("gamma").with_id(3),
+        ])
+        .execute();
+
+    let rows: Vec<Option<SelectSimple>> =
+        db.get_many(simple, simple.id, &[3, 42, 1, 3], |row: &SelectSimple| {
+            row.id
+        });
+    let names: Vec<Option<&str>> = rows
+        .iter()
+        .map(|row| row.as_ref().map(|row| row.name.as_str()))
+        .collect();
+    assert_eq!(names, [Some("gamma"), None, Some("alpha"), Some("gamma")]);
+
+    // More keys than fit in one IN list are split across queries.
+    let keys: Vec<i32> = (0..2500).collect();
+    let rows: Vec<Option<SelectSimple>> =
+        db.get_many(simple, simple.id, &keys, |row: &SelectSimple| row.id);
+    assert_eq!(rows.len(), keys.len());
+    assert_eq!(rows.iter().flatten().count(), 3);
+    assert_eq!(rows[2].as_ref().map(|row| row.id), Some(2));
+}
+
+#[drizzle::test]
+fn hlist_select_exceeds_tuple_arity(db: &mut TestDb<SimpleSchema>) {
+    use drizzle::core::{HList, hlist, hlist_pat};
+
+    let SimpleSchema { simple } = schema;
+
+    db.insert(simple)
+        .values([InsertSimple::new("alpha").with_id(7)])
+        .execute();
+
+    // Ten columns: wider than the tuple impls compiled without `col16`.
+    let stmt = db
+        .select(hlist![
+            simple.id,
+            simple.name,
+            simple.id,
+            simple.name,
+            simple.id,
+            simple.name,
+            simple.id,
+            simple.name,
+            simple.id,
+            simple.name,
+        ])
+        .from(simple);
+    type WideRow = HList![
+        i32, String, i32, String, i32, String, i32, String, i32, String
+    ];
+    let rows: Vec<WideRow> = stmt.all();
+
+    assert_eq!(rows.len(), 1);
+    let hlist_pat![id, name, _, _, _, _, _, _, last_id, last_name] = rows[0].clone();
+    assert_eq!((id, name.as_str()), (7, "alpha"));
+    assert_eq!((last_id, last_name.as_str()), (7, "alpha"));
+}
+
+#[cfg(all(feature = "rusqlite", feature = "serde"))]
+#[test]
+fn all_de_deserializes_rows_by_column_name() {
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct SimpleDto {
+        #[serde(rename = "name")]
+        label: String,
+        #[serde(flatten)]
+        key: SimpleKey,
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct SimpleKey {
+        id: i64,
+    }
+
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let (db, SimpleSchema { simple }) =
+        drizzle::sqlite::rusqlite::Drizzle::new(conn, SimpleSchema::new());
+    db.create().unwrap();
+    db.insert(simple)
+        .values([
+            InsertSimple::new("alpha").with_id(1),
+            InsertSimple::new("beta").with_id(2),
+        ])
+        .execute()
+        .unwrap();
+
+    let rows: Vec<SimpleDto> = db
+        .select(())
+        .from(simple)
+        .order_by(simple.id)
+        .all_de()
+        .unwrap();
+    assert_eq!(
+        rows,
+        [
+            SimpleDto {
+                label: "alpha".into(),
+                key: SimpleKey { id: 1 },
+            },
+            SimpleDto {
+                label: "beta".into(),
+                key: SimpleKey { id: 2 },
+            },
+        ]
+    );
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn with_timeout_interrupts_runaway_queries() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let (db, ()) = drizzle::sqlite::rusqlite::Drizzle::new(conn, ());
+
+    let endless = "WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c) \
+                   SELECT count(*) FROM c";
+    let error = db
+        .with_timeout(std::time::Duration::from_millis(50), |db| {
+            Ok(db
+                .conn()
+                .query_row(endless, [], |row| row.get::<_, i64>(0))?)
+        })
+        .unwrap_err();
+    assert!(
+        matches!(error, drizzle::error::DrizzleError::Timeout(_)),
+        "{error:?}"
+    );
+
+    // The handler is removed afterwards.
+    let one: i64 = db
+        .conn()
+        .query_row("SELECT 1", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(one, 1);
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn interrupt_handle_cancels_running_query() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let (db, ()) = drizzle::sqlite::rusqlite::Drizzle::new(conn, ());
+
+    let cancel = db.interrupt_handle();
+    let canceller = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        cancel.interrupt();
+    });
+
+    let endless = "WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c) \
+                   SELECT count(*) FROM c";
+    let error: drizzle::error::DrizzleError = db
+        .conn()
+        .query_row(endless, [], |row| row.get::<_, i64>(0))
+        .unwrap_err()
+        .into();
+    canceller.join().unwrap();
+    assert!(
+        matches!(error, drizzle::error::DrizzleError::Timeout(_)),
+        "{error:?}"
+    );
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn read_only_handle_reads_and_rejects_raw_writes() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let (db, SimpleSchema { simple }) =
+        drizzle::sqlite::rusqlite::Drizzle::new(conn, SimpleSchema::new());
+    db.create().unwrap();
+    db.insert(simple)
+        .values([InsertSimple::new("alice")])
+        .execute()
+        .unwrap();
+
+    let replica = db.read_only().unwrap();
+    let rows: Vec<SelectSimple> = replica.select(()).from(simple).all().unwrap();
+    assert_eq!(rows.len(), 1);
+
+    let error = replica
+        .conn()
+        .execute("DELETE FROM simple", [])
+        .unwrap_err();
+    assert!(
+        matches!(
+            error.sqlite_error_code(),
+            Some(rusqlite::ErrorCode::ReadOnly)
+        ),
+        "{error:?}"
+    );
+
+    // Unwrapping gives the write builders back, but the connection stays read-only
+    let db = replica.into_inner();
+    assert!(
+        db.insert(simple)
+            .values([InsertSimple::new("bob")])
+            .execute()
+            .is_err()
+    );
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn open_read_only_opens_existing_file() {
+    let path = std::env::temp_dir().join(format!("drizzle_read_only_{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let conn = rusqlite::Connection::open(&path).unwrap();
+    let (db, SimpleSchema { simple }) =
+        drizzle::sqlite::rusqlite::Drizzle::new(conn, SimpleSchema::new());
+    db.create().unwrap();
+    db.insert(simple)
+        .values([InsertSimple::new("alice")])
+        .execute()
+        .unwrap();
+    drop(db);
+
+    let (replica, SimpleSchema { simple }) =
+        drizzle::sqlite::rusqlite::Drizzle::open_read_only(&path, SimpleSchema::new()).unwrap();
+    let rows: Vec<SelectSimple> = replica.select(()).from(simple).all().unwrap();
+    assert_eq!(rows.len(), 1);
+    assert!(replica.conn().execute("DELETE FROM simple", []).is_err());
+    drop(replica);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(feature = "libsql")]
+#[tokio::test(flavor = "multi_thread")]
+async fn libsql_interrupt_handle_cancels_running_query() {
+    let (db, _) = crate::common::helpers::libsql_setup::setup_db::<SimpleSchema>().await;
+
+    let cancel = db.interrupt_handle();
+    let canceller = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        cancel.interrupt().unwrap();
+    });
+
+    let endless = "WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c) \
+                   SELECT count(*) FROM c";
+    let result = async {
+        let mut rows = db.conn().query(endless, ()).await?;
+        rows.next().await?;
+        Ok::<_, libsql::Error>(())
+    }
+    .await;
+    canceller.join().unwrap();
+    let error: drizzle::error::DrizzleError = result.unwrap_err().into();
+    assert!(
+        matches!(error, drizzle::error::DrizzleError::Timeout(_)),
+        "{error:?}"
+    );
+}
+
+#[cfg(feature = "libsql")]
+#[tokio::test]
+async fn libsql_open_local_file() {
+    use drizzle::sqlite::libsql::{Drizzle, OpenOptions};
+
+    let path = std::env::temp_dir().join(format!("drizzle_libsql_open_{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let (mut db, SimpleSchema { simple }) =
+        Drizzle::open(OpenOptions::local(&path), SimpleSchema::new())
+            .await
+            .unwrap();
+    db.create().await.unwrap();
+    db.insert(simple)
+        .values([InsertSimple::new("alice")])
+        .execute()
+        .await
+        .unwrap();
+    let rows: Vec<SelectSimple> = db.select(()).from(simple).all().await.unwrap();
+    assert_eq!(rows.len(), 1);
+
+    // Local files have no token to rotate and nothing to sync from.
+    assert!(db.set_auth_token("token").await.is_err());
+    assert!(db.sync().await.is_err());
+    drop(db);
+
+    std::fs::remove_file(&path).unwrap();
+}
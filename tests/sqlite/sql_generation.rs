@@ -1,6 +1,6 @@
 #![cfg(any(feature = "rusqlite", feature = "turso", feature = "libsql"))]
 use drizzle::core::expr::*;
-use drizzle::{sql, sqlite::prelude::*};
+use drizzle::{sql, sql_unsafe_raw, sqlite::prelude::*};
 use drizzle_sqlite::values::SQLiteValue;
 
 #[cfg(feature = "uuid")]
@@ -125,7 +125,7 @@ fn test_sql_macro(db: &mut TestDb<SimpleSchema>) {
             .execute()
     )?;
 
-    let query = sql!("SELECT * FROM {simple} where {simple.id} = {id}");
+    let query = sql!("SELECT * FROM {#simple} where {#simple.id} = {id}");
     let sql = query.sql();
     let params: Vec<_> = query.params().collect();
 
@@ -152,7 +152,7 @@ fn test_sql_printf_style(db: &mut TestDb<SimpleSchema>) {
     )?;
 
     // Test printf-style syntax: sql!("template", arg1, arg2, ...)
-    let query = sql!("SELECT * FROM {} WHERE {} = {}", simple, simple.id, id);
+    let query = sql!("SELECT * FROM {#} WHERE {#} = {}", simple, simple.id, id);
     let sql = query.sql();
     let params: Vec<_> = query.params().collect();
 
@@ -174,7 +174,7 @@ fn test_sql_mixed_named_positional(db: &mut TestDb<SimpleSchema>) {
     )?;
 
     // Test mixing positional {} and named {simple.id} expressions
-    let query = sql!("SELECT * FROM {} WHERE {simple.id} = {}", simple, id);
+    let query = sql!("SELECT * FROM {#} WHERE {#simple.id} = {}", simple, id);
     let sql = query.sql();
     let params: Vec<_> = query.params().collect();
 
@@ -183,6 +183,79 @@ fn test_sql_mixed_named_positional(db: &mut TestDb<SimpleSchema>) {
     assert_eq!(params[0], &SQLiteValue::Integer(id as i64));
 }
 
+#[drizzle::test]
+fn test_sql_param_spec(db: &mut TestDb<SimpleSchema>) {
+    let SimpleSchema { simple } = schema;
+    let name = "param_test";
+
+    let query = sql!(
+        "SELECT * FROM {#simple} WHERE {#simple.name} = {name:param} AND {:param} > 0",
+        7
+    );
+    let sql = query.sql();
+    let params: Vec<_> = query.params().collect();
+
+    assert_eq!(
+        sql,
+        r#"SELECT * FROM "simple" WHERE "simple"."name" = ? AND ? > 0"#
+    );
+    assert_eq!(params.len(), 2);
+    assert_eq!(params[0], &SQLiteValue::from(name));
+    assert_eq!(params[1], &SQLiteValue::Integer(7));
+}
+
+#[drizzle::test]
+fn test_sql_splice_and_raw(db: &mut TestDb<SimpleSchema>) {
+    let SimpleSchema { simple } = schema;
+    let name = Some("splice_test");
+    let order = "DESC";
+
+    // `{name}` binds, `{#...}` splices SQL, `{!order}` splices raw text
+    let filter = sql!("{#simple.name} = {name}");
+    let query = sql!("SELECT * FROM {#simple} WHERE {#filter} ORDER BY {#simple.id} {!order}");
+    let sql = query.sql();
+    let params: Vec<_> = query.params().collect();
+
+    assert_eq!(
+        sql,
+        r#"SELECT * FROM "simple" WHERE "simple"."name" = ? ORDER BY "simple"."id" DESC"#
+    );
+    assert_eq!(params.len(), 1);
+    assert_eq!(params[0], &SQLiteValue::from("splice_test"));
+
+    // The legacy macro still splices plain `{expr}` through `ToSQL`
+    let legacy = sql_unsafe_raw!("SELECT * FROM {simple} WHERE {simple.id} = {}", 1);
+    assert_eq!(
+        legacy.sql(),
+        r#"SELECT * FROM "simple" WHERE "simple"."id" = ?"#
+    );
+}
+
+#[drizzle::test]
+fn test_sql_typed_output_in_select(db: &mut TestDb<SimpleSchema>) {
+    let SimpleSchema { simple } = schema;
+
+    db.insert(simple)
+        .values([
+            InsertSimple::new("alpha").with_id(1),
+            InsertSimple::new("beta").with_id(2),
+        ])
+        .execute();
+
+    let query = db
+        .select(sql!("count(*), max({#simple.name})", as (i64, String)))
+        .from(simple);
+    assert_eq!(
+        query.to_sql().sql(),
+        r#"SELECT count(*), max( "simple"."name" ) FROM "simple""#
+    );
+
+    // The row type comes from the annotation, no FromRow target needed
+    let (count, max_name) = query.get();
+    assert_eq!(count, 2);
+    assert_eq!(max_name, "beta");
+}
+
 #[drizzle::test]
 fn test_with_subquery_parenthesized_in_comparison(db: &mut TestDb<SimpleSchema>) {
     let SimpleSchema { simple } = schema;
@@ -0,0 +1,100 @@
+#![cfg(all(
+    any(feature = "rusqlite", feature = "turso", feature = "libsql"),
+    feature = "chrono"
+))]
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use drizzle::core::expr::*;
+use drizzle::sqlite::prelude::*;
+
+// `created_at` keeps the default ISO-8601 TEXT storage; the INTEGER columns
+// store unix seconds
+#[SQLiteTable(NAME = "timestamp_events")]
+struct TimestampEvent {
+    #[column(PRIMARY)]
+    id: i32,
+    created_at: DateTime<Utc>,
+    #[column(INTEGER)]
+    happened_at: DateTime<Utc>,
+    #[column(INTEGER)]
+    local_at: NaiveDateTime,
+    #[column(INTEGER)]
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(SQLiteSchema)]
+struct TimestampSchema {
+    timestamp_events: TimestampEvent,
+}
+
+fn at(seconds: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(seconds, 0).unwrap()
+}
+
+#[test]
+fn timestamp_storage_ddl() {
+    let ddl = TimestampEvent::ddl_sql();
+    assert!(ddl.contains("`created_at` TEXT NOT NULL"), "{ddl}");
+    assert!(ddl.contains("`happened_at` INTEGER NOT NULL"), "{ddl}");
+    assert!(ddl.contains("`deleted_at` INTEGER"), "{ddl}");
+}
+
+#[test]
+fn integer_timestamps_bind_as_unix_seconds() {
+    let TimestampSchema { timestamp_events } = TimestampSchema::new();
+    let db = drizzle::sqlite::builder::QueryBuilder::new::<TimestampSchema>();
+
+    let insert = db
+        .insert(timestamp_events)
+        .values([InsertTimestampEvent::new(
+            at(1_700_000_000),
+            at(1_700_000_060),
+            at(1_700_000_120).naive_utc(),
+        )
+        .with_id(1)]);
+    let sql = insert.to_sql();
+    let params: Vec<_> = sql.params().cloned().collect();
+    assert!(matches!(params[1], SQLiteValue::Text(_)));
+    assert_eq!(params[2], SQLiteValue::Integer(1_700_000_060));
+    assert_eq!(params[3], SQLiteValue::Integer(1_700_000_120));
+
+    let update = db
+        .update(timestamp_events)
+        .set(UpdateTimestampEvent::default().with_deleted_at(at(1_700_000_180)));
+    let sql = update.to_sql();
+    let params: Vec<_> = sql.params().cloned().collect();
+    assert_eq!(params[0], SQLiteValue::Integer(1_700_000_180));
+}
+
+#[drizzle::test]
+fn timestamp_storage_roundtrip(db: &mut TestDb<TimestampSchema>) {
+    let table = schema.timestamp_events;
+
+    db.insert(table)
+        .values([InsertTimestampEvent::new(
+            at(1_700_000_000),
+            at(1_700_000_060),
+            at(1_700_000_120).naive_utc(),
+        )
+        .with_id(1)
+        .with_deleted_at(at(1_700_000_180))])
+        .execute();
+
+    let row: SelectTimestampEvent = db.select(()).from(table).get();
+    assert_eq!(row.created_at, at(1_700_000_000));
+    assert_eq!(row.happened_at, at(1_700_000_060));
+    assert_eq!(row.local_at, at(1_700_000_120).naive_utc());
+    assert_eq!(row.deleted_at, Some(at(1_700_000_180)));
+
+    #[derive(SQLiteFromRow, Debug)]
+    struct StorageTypes(String, String);
+    let types: StorageTypes = db
+        .select((
+            r#typeof(table.created_at).alias("created_type"),
+            r#typeof(table.happened_at).alias("happened_type"),
+        ))
+        .from(table)
+        .get();
+    assert_eq!(types.0, "text");
+    assert_eq!(types.1, "integer");
+}
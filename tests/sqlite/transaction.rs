@@ -1239,3 +1239,23 @@ fn test_sqlite_owned_prepared_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<drizzle_sqlite::builder::prepared::OwnedPreparedStatement>();
 }
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_busy_database_is_retryable() {
+    let path = crate::common::helpers::temp_db_path();
+    let writer = rusqlite::Connection::open(&path).unwrap();
+    writer
+        .execute_batch("CREATE TABLE t (id INTEGER); BEGIN IMMEDIATE;")
+        .unwrap();
+
+    let conn = rusqlite::Connection::open(&path).unwrap();
+    conn.busy_timeout(std::time::Duration::ZERO).unwrap();
+    let (mut db, _) = drizzle::sqlite::rusqlite::Drizzle::new(conn, ());
+
+    let error = db
+        .transaction(SQLiteTransactionType::Immediate, |_| Ok(()))
+        .unwrap_err();
+    assert!(error.is_retryable(), "expected SQLITE_BUSY, got {error:?}");
+    assert!(!error.is_constraint_violation());
+}
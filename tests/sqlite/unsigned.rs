@@ -0,0 +1,83 @@
+#![cfg(any(feature = "rusqlite", feature = "turso", feature = "libsql"))]
+
+use drizzle::core::expr::*;
+use drizzle::sqlite::prelude::*;
+use drizzle::sqlite::values::SQLiteValue;
+
+#[SQLiteTable(NAME = "unsigned_counters")]
+struct UnsignedCounter {
+    #[column(PRIMARY)]
+    id: i32,
+    total: u64,
+    peak: Option<u64>,
+    len: usize,
+}
+
+#[derive(SQLiteSchema)]
+struct UnsignedSchema {
+    unsigned_counters: UnsignedCounter,
+}
+
+#[test]
+fn large_unsigned_values_bind_as_blob() {
+    assert_eq!(SQLiteValue::from(42_u64), SQLiteValue::Integer(42));
+    assert_eq!(
+        SQLiteValue::from(i64::MAX.unsigned_abs()),
+        SQLiteValue::Integer(i64::MAX)
+    );
+    assert_eq!(
+        SQLiteValue::from(u64::MAX),
+        SQLiteValue::from(u64::MAX.to_be_bytes().to_vec())
+    );
+    assert_eq!(
+        SQLiteValue::from(u64::MAX).convert::<u64>().unwrap(),
+        u64::MAX
+    );
+    assert_eq!(
+        SQLiteValue::from(usize::MAX).convert::<usize>().unwrap(),
+        usize::MAX
+    );
+    assert!(SQLiteValue::Integer(-1).convert::<u64>().is_err());
+}
+
+#[test]
+fn unsigned_columns_ddl() {
+    let ddl = UnsignedCounter::ddl_sql();
+    assert!(ddl.contains("`total` INTEGER NOT NULL"), "{ddl}");
+    assert!(ddl.contains("`peak` INTEGER"), "{ddl}");
+}
+
+#[drizzle::test]
+fn unsigned_roundtrip(db: &mut TestDb<UnsignedSchema>) {
+    let table = schema.unsigned_counters;
+
+    db.insert(table)
+        .values([InsertUnsignedCounter::new(7_u64, 3_usize).with_id(1)])
+        .execute();
+    db.insert(table)
+        .values([InsertUnsignedCounter::new(u64::MAX, usize::MAX)
+            .with_id(2)
+            .with_peak(u64::MAX - 1)])
+        .execute();
+
+    let rows: Vec<SelectUnsignedCounter> = db.select(()).from(table).order_by(asc(table.id)).all();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].total, 7);
+    assert_eq!(rows[0].peak, None);
+    assert_eq!(rows[0].len, 3);
+    assert_eq!(rows[1].total, u64::MAX);
+    assert_eq!(rows[1].peak, Some(u64::MAX - 1));
+    assert_eq!(rows[1].len, usize::MAX);
+
+    #[derive(SQLiteFromRow, Debug, Default)]
+    struct TotalOnly {
+        #[column(UnsignedCounter::total)]
+        total: u64,
+    }
+    let found: TotalOnly = db
+        .select(TotalOnly::default())
+        .from(table)
+        .r#where(eq(table.total, u64::MAX))
+        .get();
+    assert_eq!(found.total, u64::MAX);
+}
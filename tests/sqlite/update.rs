@@ -4,7 +4,7 @@ use crate::common::schema::sqlite::Role;
 #[cfg(feature = "uuid")]
 use crate::common::schema::sqlite::{Complex, ComplexSchema, InsertComplex, UpdateComplex};
 use crate::common::schema::sqlite::{
-    InsertSimple, SelectSimple, Simple, SimpleSchema, UpdateSimple,
+    FullBlogSchema, InsertCategory, InsertSimple, SelectSimple, Simple, SimpleSchema, UpdateSimple,
 };
 #[cfg(all(feature = "serde", feature = "uuid"))]
 use crate::common::schema::sqlite::{UserConfig, UserMetadata};
@@ -264,6 +264,46 @@ fn update_no_matching_rows(db: &mut TestDb<SimpleSchema>) {
     assert_eq!(results[0].name, "Alice");
 }
 
+#[drizzle::test]
+fn update_from_joined_table(db: &mut TestDb<FullBlogSchema>) {
+    let FullBlogSchema {
+        simple, category, ..
+    } = schema;
+
+    db.insert(simple)
+        .values([
+            InsertSimple::new("Alice").with_id(1),
+            InsertSimple::new("Bob").with_id(2),
+            InsertSimple::new("Carol").with_id(3),
+        ])
+        .execute();
+    db.insert(category)
+        .values([
+            InsertCategory::new("Renamed One").with_id(1),
+            InsertCategory::new("Renamed Two").with_id(2),
+        ])
+        .execute();
+
+    let stmt = db
+        .update(simple)
+        .set(UpdateSimple::default().with_name(category.name))
+        .from(category)
+        .r#where(eq(simple.id, category.id));
+    assert_eq!(
+        stmt.to_sql().sql(),
+        r#"UPDATE "simple" SET "name" = "categories"."name" FROM "categories" WHERE "simple"."id" = "categories"."id""#
+    );
+    stmt.execute();
+
+    let results: Vec<SelectSimple> = db
+        .select((simple.id, simple.name))
+        .from(simple)
+        .order_by(asc(simple.id))
+        .all();
+    let names: Vec<_> = results.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, ["Renamed One", "Renamed Two", "Carol"]);
+}
+
 #[cfg(all(feature = "serde", feature = "uuid"))]
 #[drizzle::test]
 fn feature_gated_update(db: &mut TestDb<ComplexSchema>) {
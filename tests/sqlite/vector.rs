@@ -0,0 +1,74 @@
+#![cfg(any(feature = "rusqlite", feature = "turso", feature = "libsql"))]
+
+use drizzle::core::expr::*;
+use drizzle::sqlite::expr::nearest;
+use drizzle::sqlite::prelude::*;
+
+// Embeddings are stored as sqlite-vec float32 blobs
+#[SQLiteTable(NAME = "vector_docs")]
+struct VectorDoc {
+    #[column(PRIMARY)]
+    id: i32,
+    embedding: Vec<f32>,
+    summary: Option<Vec<f32>>,
+}
+
+#[derive(SQLiteSchema)]
+struct VectorSchema {
+    vector_docs: VectorDoc,
+}
+
+#[test]
+fn vector_columns_ddl() {
+    let ddl = VectorDoc::ddl_sql();
+    assert!(ddl.contains("`embedding` BLOB NOT NULL"), "{ddl}");
+    assert!(ddl.contains("`summary` BLOB"), "{ddl}");
+}
+
+#[test]
+fn nearest_neighbour_query() {
+    let VectorSchema { vector_docs } = VectorSchema::new();
+    let db = drizzle::sqlite::builder::QueryBuilder::new::<VectorSchema>();
+
+    let query = db
+        .select(vector_docs.id)
+        .from(vector_docs)
+        .order_by(nearest(vector_docs.embedding, vec![0.1_f32, 0.2, 0.3]))
+        .limit(5);
+    assert_eq!(
+        query.to_sql().sql(),
+        r#"SELECT "vector_docs"."id" FROM "vector_docs" ORDER BY vec_distance_l2 ("vector_docs"."embedding", ?) ASC LIMIT 5"#
+    );
+}
+
+#[drizzle::test]
+fn vector_blob_roundtrip(db: &mut TestDb<VectorSchema>) {
+    let table = schema.vector_docs;
+    let embedding = vec![0.25_f32, -1.0, 3.5];
+
+    db.insert(table)
+        .values([InsertVectorDoc::new(embedding.clone())
+            .with_id(1)
+            .with_summary(vec![1.0_f32])])
+        .execute();
+    db.insert(table)
+        .values([InsertVectorDoc::new(Vec::<f32>::new()).with_id(2)])
+        .execute();
+
+    let rows: Vec<SelectVectorDoc> = db.select(()).from(table).order_by(asc(table.id)).all();
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].embedding, embedding);
+    assert_eq!(rows[0].summary, Some(vec![1.0]));
+    assert!(rows[1].embedding.is_empty());
+    assert_eq!(rows[1].summary, None);
+
+    #[derive(SQLiteFromRow, Debug)]
+    struct ReturnResult(String);
+    let result: ReturnResult = db
+        .select(r#typeof(table.embedding).alias("embedding_type"))
+        .from(table)
+        .r#where(eq(table.embedding, embedding))
+        .get();
+    assert_eq!(result.0, "blob");
+}
@@ -0,0 +1,34 @@
+use drizzle::postgres::prelude::*;
+
+#[PostgresTable(NAME = "cycle_a")]
+struct PgCycleA {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(REFERENCES = PgCycleB::id)]
+    b_id: i32,
+}
+
+#[PostgresTable(NAME = "cycle_b")]
+struct PgCycleB {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(REFERENCES = PgCycleC::id)]
+    c_id: i32,
+}
+
+#[PostgresTable(NAME = "cycle_c")]
+struct PgCycleC {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(REFERENCES = PgCycleA::id)]
+    a_id: i32,
+}
+
+#[derive(PostgresSchema)]
+struct PgCycleSchema {
+    a: PgCycleA,
+    b: PgCycleB,
+    c: PgCycleC,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use drizzle::sqlite::prelude::*;
+
+#[SQLiteTable]
+struct TextKey {
+    #[column(text, primary, autoincrement)]
+    id: String,
+}
+
+#[SQLiteTable]
+struct NotPrimary {
+    #[column(primary)]
+    id: i64,
+    #[column(autoincrement)]
+    counter: i64,
+}
+
+fn main() {}
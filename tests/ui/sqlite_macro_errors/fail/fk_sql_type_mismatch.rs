@@ -0,0 +1,25 @@
+use drizzle::sqlite::prelude::*;
+
+#[SQLiteTable]
+struct Users {
+    #[column(primary)]
+    id: i64,
+}
+
+#[SQLiteTable]
+struct Posts {
+    #[column(primary)]
+    id: i64,
+    #[column(text, references = Users::id)]
+    author_id: String,
+}
+
+#[SQLiteTable]
+struct Comments {
+    #[column(primary)]
+    id: i64,
+    #[column(real, references = Users::id)]
+    author_id: i64,
+}
+
+fn main() {}
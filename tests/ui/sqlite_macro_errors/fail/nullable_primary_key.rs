@@ -0,0 +1,10 @@
+use drizzle::sqlite::prelude::*;
+
+#[SQLiteTable]
+struct NullableKey {
+    #[column(primary)]
+    id: Option<i64>,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+use drizzle::sqlite::prelude::*;
+
+#[SQLiteTable(NAME = "cycle_a")]
+struct CycleA {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(REFERENCES = CycleB::id)]
+    b_id: i32,
+}
+
+#[SQLiteTable(NAME = "cycle_b")]
+struct CycleB {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(REFERENCES = CycleC::id)]
+    c_id: i32,
+}
+
+#[SQLiteTable(NAME = "cycle_c")]
+struct CycleC {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(REFERENCES = CycleA::id)]
+    a_id: i32,
+}
+
+#[derive(SQLiteSchema)]
+struct CycleSchema {
+    a: CycleA,
+    b: CycleB,
+    c: CycleC,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use drizzle::sqlite::prelude::*;
+
+#[SQLiteTable]
+struct Typo {
+    #[column(primay)]
+    id: i32,
+}
+
+fn main() {}
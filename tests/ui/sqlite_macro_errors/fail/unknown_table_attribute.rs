@@ -0,0 +1,9 @@
+use drizzle::sqlite::prelude::*;
+
+#[SQLiteTable(stirct)]
+struct Typo {
+    #[column(primary)]
+    id: i32,
+}
+
+fn main() {}
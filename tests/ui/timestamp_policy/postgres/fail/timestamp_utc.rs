@@ -0,0 +1,11 @@
+use drizzle::postgres::prelude::*;
+
+#[PostgresTable(NAME = "events")]
+struct Event {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(TIMESTAMP)]
+    happened_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use drizzle::postgres::prelude::*;
+
+#[PostgresTable(NAME = "events")]
+struct Event {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(TIMESTAMPTZ)]
+    happened_at: chrono::NaiveDateTime,
+}
+
+fn main() {}
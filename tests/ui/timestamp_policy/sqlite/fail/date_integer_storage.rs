@@ -0,0 +1,11 @@
+use drizzle::sqlite::prelude::*;
+
+#[SQLiteTable(NAME = "events")]
+struct Event {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(INTEGER)]
+    day: chrono::NaiveDate,
+}
+
+fn main() {}
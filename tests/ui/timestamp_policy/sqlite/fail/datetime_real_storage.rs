@@ -0,0 +1,11 @@
+use drizzle::sqlite::prelude::*;
+
+#[SQLiteTable(NAME = "events")]
+struct Event {
+    #[column(PRIMARY)]
+    id: i32,
+    #[column(REAL)]
+    happened_at: chrono::DateTime<chrono::Utc>,
+}
+
+fn main() {}
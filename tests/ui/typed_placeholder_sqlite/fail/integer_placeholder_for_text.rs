@@ -0,0 +1,25 @@
+use drizzle::core::Placeholder;
+use drizzle::core::expr::eq;
+use drizzle::sqlite::prelude::*;
+use drizzle::sqlite::rusqlite::Drizzle;
+use drizzle::sqlite::types::Integer;
+
+#[SQLiteTable]
+struct User {
+    #[column(primary)]
+    id: i32,
+    name: String,
+}
+
+#[derive(SQLiteSchema)]
+struct Schema {
+    user: User,
+}
+
+fn main() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let (db, Schema { user, .. }) = Drizzle::new(conn, Schema::default());
+
+    let name = Placeholder::typed::<Integer>("name");
+    let _ = db.select(()).from(user).r#where(eq(user.name, name));
+}
@@ -0,0 +1,15 @@
+use drizzle::sqlite::prelude::*;
+
+#[SQLiteTable]
+struct User {
+    #[column(primary)]
+    id: i32,
+    name: String,
+}
+
+fn main() {
+    let user = User::default();
+
+    let id = user.id.placeholder("id");
+    let _: drizzle::core::ParamBind<'_, SQLiteValue<'_>> = id.bind("string");
+}
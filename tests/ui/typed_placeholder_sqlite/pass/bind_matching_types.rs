@@ -0,0 +1,38 @@
+use drizzle::core::Placeholder;
+use drizzle::core::expr::eq;
+use drizzle::sqlite::prelude::*;
+use drizzle::sqlite::rusqlite::Drizzle;
+use drizzle::sqlite::types::Integer;
+
+#[SQLiteTable]
+struct User {
+    #[column(primary)]
+    id: i32,
+    name: String,
+    age: Option<i32>,
+}
+
+#[derive(SQLiteSchema)]
+struct Schema {
+    user: User,
+}
+
+fn main() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let (db, Schema { user, .. }) = Drizzle::new(conn, Schema::default());
+
+    let name = user.name.placeholder("name");
+    let _ = db
+        .select(())
+        .from(user)
+        .r#where(eq(user.name, name))
+        .prepare();
+    let _: drizzle::core::ParamBind<'_, SQLiteValue<'_>> = name.bind("Alice");
+
+    let id = Placeholder::typed::<Integer>("id");
+    let _ = db.select(()).from(user).r#where(eq(user.id, id)).prepare();
+    let _: drizzle::core::ParamBind<'_, SQLiteValue<'_>> = id.bind(1);
+
+    let age = user.age.placeholder("age");
+    let _: drizzle::core::ParamBind<'_, SQLiteValue<'_>> = age.bind_opt(None::<i32>);
+}
@@ -55,6 +55,15 @@ pub struct MigrationTracking {
     pub table: Cow<'static, str>,
     /// Optional schema name for the tracking table (`PostgreSQL`).
     pub schema: Option<Cow<'static, str>>,
+    /// Fail `migrate()` when an applied migration's SQL no longer matches
+    /// the hash recorded in the tracking table.
+    pub strict: bool,
+    /// Record who applied each migration, how long it took and how many
+    /// statements it ran (`PostgreSQL` only).
+    pub audit: bool,
+    /// Schema hash `migrate()` records in the tracking table, checked later
+    /// by `assert_schema_matches()`.
+    pub schema_hash: Option<u64>,
 }
 
 impl MigrationTracking {
@@ -62,12 +71,18 @@ impl MigrationTracking {
     pub const SQLITE: Self = Self {
         table: Cow::Borrowed("__drizzle_migrations"),
         schema: None,
+        strict: false,
+        audit: false,
+        schema_hash: None,
     };
 
     /// Default `PostgreSQL` migration tracking metadata.
     pub const POSTGRES: Self = Self {
         table: Cow::Borrowed("__drizzle_migrations"),
         schema: Some(Cow::Borrowed("drizzle")),
+        strict: false,
+        audit: false,
+        schema_hash: None,
     };
 
     /// Create tracking metadata from table/schema values.
@@ -78,6 +93,9 @@ impl MigrationTracking {
         Self {
             table: table.into(),
             schema: schema.map(Into::into),
+            strict: false,
+            audit: false,
+            schema_hash: None,
         }
     }
 
@@ -101,6 +119,29 @@ impl MigrationTracking {
         self.schema = None;
         self
     }
+
+    /// Fail on checksum drift of already-applied migrations.
+    #[must_use]
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Add `applied_by`, `duration_ms` and `statement_count` columns to the
+    /// tracking table and fill them in as migrations run (`PostgreSQL` only).
+    #[must_use]
+    pub const fn audit(mut self, audit: bool) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    /// Record `hash` (usually the schema's `SCHEMA_HASH`) whenever
+    /// migrations run.
+    #[must_use]
+    pub const fn schema_hash(mut self, hash: u64) -> Self {
+        self.schema_hash = Some(hash);
+        self
+    }
 }
 impl Default for MigrationTracking {
     fn default() -> Self {
@@ -412,6 +412,17 @@ impl ColumnDef {
         }
     }
 
+    /// Set type schema only when `condition` holds (for custom types that may
+    /// map onto built-in types)
+    #[must_use]
+    pub const fn type_schema_if(self, condition: bool, schema: &'static str) -> Self {
+        if condition {
+            self.type_schema(schema)
+        } else {
+            self
+        }
+    }
+
     /// Set NOT NULL constraint
     #[must_use]
     pub const fn not_null(self) -> Self {
@@ -567,11 +578,7 @@ pub struct Column {
     /// Type schema (for custom types)
     #[cfg_attr(
         feature = "serde",
-        serde(
-            default,
-            skip_serializing_if = "Option::is_none",
-            deserialize_with = "cow_option_from_string"
-        )
+        serde(default, deserialize_with = "cow_option_from_string")
     )]
     pub type_schema: Option<Cow<'static, str>>,
 
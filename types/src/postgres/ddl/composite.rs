@@ -0,0 +1,201 @@
+//! `PostgreSQL` Composite type DDL types
+//!
+//! A composite type is a named row type (`CREATE TYPE ... AS (...)`) that can
+//! be used as the type of a column.
+//!
+//! See: <https://www.postgresql.org/docs/current/rowtypes.html>
+
+use crate::alloc_prelude::*;
+
+#[cfg(feature = "serde")]
+use crate::serde_helpers::cow_from_string;
+
+// =============================================================================
+// Const-friendly Definition Types
+// =============================================================================
+
+/// Const-friendly composite attribute definition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositeFieldDef {
+    /// Attribute name
+    pub name: &'static str,
+    /// Attribute SQL type
+    pub sql_type: &'static str,
+}
+
+impl CompositeFieldDef {
+    /// Create a new composite attribute definition
+    #[must_use]
+    pub const fn new(name: &'static str, sql_type: &'static str) -> Self {
+        Self { name, sql_type }
+    }
+}
+
+/// Const-friendly composite type definition for compile-time schema definitions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositeDef {
+    /// Schema name
+    pub schema: &'static str,
+    /// Type name
+    pub name: &'static str,
+    /// Attributes, in declaration order
+    pub fields: &'static [CompositeFieldDef],
+}
+
+impl CompositeDef {
+    /// Create a new composite type definition
+    #[must_use]
+    pub const fn new(
+        schema: &'static str,
+        name: &'static str,
+        fields: &'static [CompositeFieldDef],
+    ) -> Self {
+        Self {
+            schema,
+            name,
+            fields,
+        }
+    }
+
+    /// Convert to runtime [`Composite`] type
+    #[must_use]
+    pub fn into_composite(self) -> Composite {
+        Composite {
+            schema: Cow::Borrowed(self.schema),
+            name: Cow::Borrowed(self.name),
+            fields: self.fields.iter().map(|f| CompositeField::from(*f)).collect(),
+        }
+    }
+}
+
+impl Default for CompositeDef {
+    fn default() -> Self {
+        Self::new("public", "", &[])
+    }
+}
+
+// =============================================================================
+// Runtime Types for Serde
+// =============================================================================
+
+/// Runtime composite attribute for serde serialization.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct CompositeField {
+    /// Attribute name
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "cow_from_string"))]
+    pub name: Cow<'static, str>,
+
+    /// Attribute SQL type
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "type", deserialize_with = "cow_from_string")
+    )]
+    pub sql_type: Cow<'static, str>,
+}
+
+impl CompositeField {
+    /// Create a new composite attribute
+    #[must_use]
+    pub fn new(name: impl Into<Cow<'static, str>>, sql_type: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            sql_type: sql_type.into(),
+        }
+    }
+}
+
+impl From<CompositeFieldDef> for CompositeField {
+    fn from(def: CompositeFieldDef) -> Self {
+        Self {
+            name: Cow::Borrowed(def.name),
+            sql_type: Cow::Borrowed(def.sql_type),
+        }
+    }
+}
+
+/// Runtime composite type entity for serde serialization.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Composite {
+    /// Schema name
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "cow_from_string"))]
+    pub schema: Cow<'static, str>,
+
+    /// Type name
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "cow_from_string"))]
+    pub name: Cow<'static, str>,
+
+    /// Attributes, in declaration order
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fields: Vec<CompositeField>,
+}
+
+impl Composite {
+    /// Create a new composite type (runtime)
+    #[must_use]
+    pub fn new(
+        schema: impl Into<Cow<'static, str>>,
+        name: impl Into<Cow<'static, str>>,
+        fields: Vec<CompositeField>,
+    ) -> Self {
+        Self {
+            schema: schema.into(),
+            name: name.into(),
+            fields,
+        }
+    }
+
+    /// Get the schema name
+    #[inline]
+    #[must_use]
+    pub fn schema(&self) -> &str {
+        &self.schema
+    }
+
+    /// Get the type name
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Default for Composite {
+    fn default() -> Self {
+        Self::new("public", "", Vec::new())
+    }
+}
+
+impl From<CompositeDef> for Composite {
+    fn from(def: CompositeDef) -> Self {
+        def.into_composite()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_composite_def_to_composite() {
+        const FIELDS: &[CompositeFieldDef] = &[
+            CompositeFieldDef::new("street", "text"),
+            CompositeFieldDef::new("zip", "integer"),
+        ];
+        const DEF: CompositeDef = CompositeDef::new("public", "address_type", FIELDS);
+
+        let composite = DEF.into_composite();
+        assert_eq!(composite.schema(), "public");
+        assert_eq!(composite.name(), "address_type");
+        assert_eq!(
+            composite.fields,
+            vec![
+                CompositeField::new("street", "text"),
+                CompositeField::new("zip", "integer"),
+            ]
+        );
+    }
+}
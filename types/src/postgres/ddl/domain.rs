@@ -0,0 +1,227 @@
+//! `PostgreSQL` Domain DDL types
+//!
+//! A domain is a named base type with optional `NOT NULL`, `DEFAULT`, and
+//! `CHECK` constraints that every column of that domain inherits.
+//!
+//! See: <https://www.postgresql.org/docs/current/sql-createdomain.html>
+
+use crate::alloc_prelude::*;
+
+#[cfg(feature = "serde")]
+use crate::serde_helpers::{cow_from_string, cow_option_from_string};
+
+// =============================================================================
+// Const-friendly Definition Type
+// =============================================================================
+
+/// Const-friendly domain definition for compile-time schema definitions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DomainDef {
+    /// Schema name
+    pub schema: &'static str,
+    /// Domain name
+    pub name: &'static str,
+    /// Underlying data type (e.g. `text`, `integer`, `varchar(255)`)
+    pub base_type: &'static str,
+    /// Whether values of the domain must be non-null
+    pub not_null: bool,
+    /// Default expression
+    pub default: Option<&'static str>,
+    /// Check expression, referring to the value as `VALUE`
+    pub check: Option<&'static str>,
+}
+
+impl DomainDef {
+    /// Create a new domain definition
+    #[must_use]
+    pub const fn new(schema: &'static str, name: &'static str, base_type: &'static str) -> Self {
+        Self {
+            schema,
+            name,
+            base_type,
+            not_null: false,
+            default: None,
+            check: None,
+        }
+    }
+
+    /// Mark the domain as NOT NULL
+    #[must_use]
+    pub const fn not_null(self) -> Self {
+        Self {
+            not_null: true,
+            ..self
+        }
+    }
+
+    /// Set the default expression
+    #[must_use]
+    pub const fn default_value(self, value: &'static str) -> Self {
+        Self {
+            default: Some(value),
+            ..self
+        }
+    }
+
+    /// Set the check expression
+    #[must_use]
+    pub const fn check(self, expression: &'static str) -> Self {
+        Self {
+            check: Some(expression),
+            ..self
+        }
+    }
+
+    /// Convert to runtime [`Domain`] type
+    #[must_use]
+    pub const fn into_domain(self) -> Domain {
+        Domain {
+            schema: Cow::Borrowed(self.schema),
+            name: Cow::Borrowed(self.name),
+            base_type: Cow::Borrowed(self.base_type),
+            not_null: self.not_null,
+            default: match self.default {
+                Some(s) => Some(Cow::Borrowed(s)),
+                None => None,
+            },
+            check: match self.check {
+                Some(s) => Some(Cow::Borrowed(s)),
+                None => None,
+            },
+        }
+    }
+}
+
+impl Default for DomainDef {
+    fn default() -> Self {
+        Self::new("public", "", "")
+    }
+}
+
+// =============================================================================
+// Runtime Type for Serde
+// =============================================================================
+
+/// Runtime domain entity for serde serialization.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Domain {
+    /// Schema name
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "cow_from_string"))]
+    pub schema: Cow<'static, str>,
+
+    /// Domain name
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "cow_from_string"))]
+    pub name: Cow<'static, str>,
+
+    /// Underlying data type
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "cow_from_string"))]
+    pub base_type: Cow<'static, str>,
+
+    /// Whether values of the domain must be non-null
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub not_null: bool,
+
+    /// Default expression
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "cow_option_from_string"
+        )
+    )]
+    pub default: Option<Cow<'static, str>>,
+
+    /// Check expression
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "cow_option_from_string"
+        )
+    )]
+    pub check: Option<Cow<'static, str>>,
+}
+
+impl Domain {
+    /// Create a new domain (runtime)
+    #[must_use]
+    pub fn new(
+        schema: impl Into<Cow<'static, str>>,
+        name: impl Into<Cow<'static, str>>,
+        base_type: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            schema: schema.into(),
+            name: name.into(),
+            base_type: base_type.into(),
+            not_null: false,
+            default: None,
+            check: None,
+        }
+    }
+
+    /// Get the schema name
+    #[inline]
+    #[must_use]
+    pub fn schema(&self) -> &str {
+        &self.schema
+    }
+
+    /// Get the domain name
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Name of the domain's CHECK constraint (`<domain>_check`, the name
+    /// `PostgreSQL` itself picks for an unnamed domain check)
+    #[must_use]
+    pub fn check_name(&self) -> String {
+        format!("{}_check", self.name)
+    }
+}
+
+impl Default for Domain {
+    fn default() -> Self {
+        Self::new("public", "", "")
+    }
+}
+
+impl From<DomainDef> for Domain {
+    fn from(def: DomainDef) -> Self {
+        def.into_domain()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_const_domain_def() {
+        const EMAIL: DomainDef = DomainDef::new("public", "email", "text")
+            .not_null()
+            .check("VALUE ~ '^[^@]+@[^@]+$'");
+
+        assert_eq!(EMAIL.name, "email");
+        assert_eq!(EMAIL.base_type, "text");
+        const { assert!(EMAIL.not_null) };
+        assert_eq!(EMAIL.default, None);
+        assert_eq!(EMAIL.check, Some("VALUE ~ '^[^@]+@[^@]+$'"));
+    }
+
+    #[test]
+    fn test_domain_def_to_domain() {
+        const DEF: DomainDef = DomainDef::new("public", "positive", "integer").default_value("1");
+        let domain = DEF.into_domain();
+        assert_eq!(domain.schema(), "public");
+        assert_eq!(domain.name(), "positive");
+        assert_eq!(domain.default.as_deref(), Some("1"));
+        assert_eq!(domain.check_name(), "positive_check");
+    }
+}
@@ -0,0 +1,338 @@
+//! `PostgreSQL` Exclusion Constraint DDL types
+//!
+//! See: <https://www.postgresql.org/docs/current/sql-createtable.html#SQL-CREATETABLE-EXCLUDE>
+
+use crate::alloc_prelude::*;
+
+#[cfg(feature = "serde")]
+use crate::serde_helpers::{cow_from_string, cow_option_from_string};
+
+// =============================================================================
+// Exclusion Element
+// =============================================================================
+
+/// One `element WITH operator` pair of an EXCLUDE constraint
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ExcludeElement {
+    /// Column name or expression
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "cow_from_string"))]
+    pub value: Cow<'static, str>,
+
+    /// Whether `value` is an expression rather than a column name
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub is_expression: bool,
+
+    /// Operator compared between rows (e.g. `=`, `&&`)
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "cow_from_string"))]
+    pub operator: Cow<'static, str>,
+}
+
+impl ExcludeElement {
+    /// Create an element comparing a column with `operator`
+    #[must_use]
+    pub const fn column(name: &'static str, operator: &'static str) -> Self {
+        Self {
+            value: Cow::Borrowed(name),
+            is_expression: false,
+            operator: Cow::Borrowed(operator),
+        }
+    }
+
+    /// Create an element comparing an expression with `operator`
+    #[must_use]
+    pub const fn expression(expression: &'static str, operator: &'static str) -> Self {
+        Self {
+            value: Cow::Borrowed(expression),
+            is_expression: true,
+            operator: Cow::Borrowed(operator),
+        }
+    }
+}
+
+// =============================================================================
+// Const-friendly Definition Type
+// =============================================================================
+
+/// Const-friendly exclusion constraint definition
+///
+/// # Examples
+///
+/// ```
+/// use drizzle_types::postgres::ddl::{ExcludeConstraintDef, ExcludeElement};
+///
+/// const ELEMENTS: &[ExcludeElement] = &[
+///     ExcludeElement::column("room_id", "="),
+///     ExcludeElement::column("during", "&&"),
+/// ];
+/// const EXCL: ExcludeConstraintDef =
+///     ExcludeConstraintDef::new("public", "bookings", "bookings_room_id_during_excl")
+///         .elements(ELEMENTS);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExcludeConstraintDef {
+    /// Schema name
+    pub schema: &'static str,
+    /// Parent table name
+    pub table: &'static str,
+    /// Constraint name
+    pub name: &'static str,
+    /// Whether the constraint name was explicitly specified
+    pub name_explicit: bool,
+    /// Index method backing the constraint (`gist` unless overridden)
+    pub using: &'static str,
+    /// Elements compared between rows
+    pub elements: &'static [ExcludeElement],
+    /// Partial constraint predicate
+    pub where_clause: Option<&'static str>,
+    /// Whether the constraint is DEFERRABLE
+    pub deferrable: bool,
+    /// Whether the constraint is INITIALLY DEFERRED
+    pub initially_deferred: bool,
+}
+
+impl ExcludeConstraintDef {
+    /// Create a new exclusion constraint definition
+    #[must_use]
+    pub const fn new(schema: &'static str, table: &'static str, name: &'static str) -> Self {
+        Self {
+            schema,
+            table,
+            name,
+            name_explicit: false,
+            using: "gist",
+            elements: &[],
+            where_clause: None,
+            deferrable: false,
+            initially_deferred: false,
+        }
+    }
+
+    /// Set the index method
+    #[must_use]
+    pub const fn using(self, method: &'static str) -> Self {
+        Self {
+            using: method,
+            ..self
+        }
+    }
+
+    /// Set the compared elements
+    #[must_use]
+    pub const fn elements(self, elements: &'static [ExcludeElement]) -> Self {
+        Self { elements, ..self }
+    }
+
+    /// Restrict the constraint to rows matching `predicate`
+    #[must_use]
+    pub const fn where_clause(self, predicate: &'static str) -> Self {
+        Self {
+            where_clause: Some(predicate),
+            ..self
+        }
+    }
+
+    /// Mark the name as explicitly specified
+    #[must_use]
+    pub const fn explicit_name(self) -> Self {
+        Self {
+            name_explicit: true,
+            ..self
+        }
+    }
+
+    /// Mark the constraint DEFERRABLE.
+    #[must_use]
+    pub const fn deferrable(self) -> Self {
+        Self {
+            deferrable: true,
+            ..self
+        }
+    }
+
+    /// Mark the constraint DEFERRABLE INITIALLY DEFERRED.
+    #[must_use]
+    pub const fn initially_deferred(self) -> Self {
+        Self {
+            deferrable: true,
+            initially_deferred: true,
+            ..self
+        }
+    }
+
+    /// Convert to runtime [`ExcludeConstraint`] type
+    #[must_use]
+    pub const fn into_exclude_constraint(self) -> ExcludeConstraint {
+        ExcludeConstraint {
+            schema: Cow::Borrowed(self.schema),
+            table: Cow::Borrowed(self.table),
+            name: Cow::Borrowed(self.name),
+            name_explicit: self.name_explicit,
+            using: Cow::Borrowed(self.using),
+            elements: Cow::Borrowed(self.elements),
+            where_clause: match self.where_clause {
+                Some(predicate) => Some(Cow::Borrowed(predicate)),
+                None => None,
+            },
+            deferrable: self.deferrable,
+            initially_deferred: self.initially_deferred,
+        }
+    }
+}
+
+impl Default for ExcludeConstraintDef {
+    fn default() -> Self {
+        Self::new("public", "", "")
+    }
+}
+
+// =============================================================================
+// Runtime Type for Serde
+// =============================================================================
+
+/// Runtime exclusion constraint entity
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ExcludeConstraint {
+    /// Schema name
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "cow_from_string"))]
+    pub schema: Cow<'static, str>,
+
+    /// Parent table name
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "cow_from_string"))]
+    pub table: Cow<'static, str>,
+
+    /// Constraint name
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "cow_from_string"))]
+    pub name: Cow<'static, str>,
+
+    /// Whether the constraint name was explicitly specified
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name_explicit: bool,
+
+    /// Index method backing the constraint
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "cow_from_string"))]
+    pub using: Cow<'static, str>,
+
+    /// Elements compared between rows
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub elements: Cow<'static, [ExcludeElement]>,
+
+    /// Partial constraint predicate
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "cow_option_from_string",
+            rename = "where"
+        )
+    )]
+    pub where_clause: Option<Cow<'static, str>>,
+
+    /// Whether the constraint is DEFERRABLE
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub deferrable: bool,
+
+    /// Whether the constraint is INITIALLY DEFERRED
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub initially_deferred: bool,
+}
+
+impl ExcludeConstraint {
+    /// Create a new exclusion constraint backed by a `gist` index
+    #[must_use]
+    pub fn new(
+        schema: impl Into<Cow<'static, str>>,
+        table: impl Into<Cow<'static, str>>,
+        name: impl Into<Cow<'static, str>>,
+        elements: impl Into<Cow<'static, [ExcludeElement]>>,
+    ) -> Self {
+        Self {
+            schema: schema.into(),
+            table: table.into(),
+            name: name.into(),
+            name_explicit: false,
+            using: Cow::Borrowed("gist"),
+            elements: elements.into(),
+            where_clause: None,
+            deferrable: false,
+            initially_deferred: false,
+        }
+    }
+
+    /// Get the schema name
+    #[inline]
+    #[must_use]
+    pub fn schema(&self) -> &str {
+        &self.schema
+    }
+
+    /// Get the constraint name
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the table name
+    #[inline]
+    #[must_use]
+    pub fn table(&self) -> &str {
+        &self.table
+    }
+}
+
+impl Default for ExcludeConstraint {
+    fn default() -> Self {
+        Self::new("public", "", "", &[] as &[ExcludeElement])
+    }
+}
+
+impl From<ExcludeConstraintDef> for ExcludeConstraint {
+    fn from(def: ExcludeConstraintDef) -> Self {
+        def.into_exclude_constraint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ELEMENTS: &[ExcludeElement] = &[
+        ExcludeElement::column("room_id", "="),
+        ExcludeElement::column("during", "&&"),
+    ];
+
+    #[test]
+    fn test_const_exclude_def() {
+        const EXCL: ExcludeConstraintDef =
+            ExcludeConstraintDef::new("public", "bookings", "bookings_room_id_during_excl")
+                .elements(ELEMENTS)
+                .where_clause("NOT cancelled");
+
+        assert_eq!(EXCL.using, "gist");
+        assert_eq!(EXCL.elements.len(), 2);
+        assert_eq!(EXCL.where_clause, Some("NOT cancelled"));
+    }
+
+    #[test]
+    fn test_exclude_def_to_exclude_constraint() {
+        const DEF: ExcludeConstraintDef =
+            ExcludeConstraintDef::new("public", "bookings", "no_overlap")
+                .explicit_name()
+                .using("spgist")
+                .elements(ELEMENTS)
+                .initially_deferred();
+        let excl = DEF.into_exclude_constraint();
+
+        assert_eq!(excl.name(), "no_overlap");
+        assert_eq!(&*excl.using, "spgist");
+        assert_eq!(excl.elements[1].operator, "&&");
+        assert!(excl.name_explicit);
+        assert!(excl.deferrable);
+        assert!(excl.initially_deferred);
+    }
+}
@@ -121,6 +121,16 @@ pub struct IndexColumn {
         serde(default, skip_serializing_if = "Option::is_none")
     )]
     pub opclass: Option<Opclass>,
+    /// Collation for this index member (e.g. `und-x-icu`)
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "cow_option_from_string"
+        )
+    )]
+    pub collate: Option<Cow<'static, str>>,
 }
 
 impl IndexColumn {
@@ -133,6 +143,7 @@ impl IndexColumn {
             asc: true,
             nulls_first: false,
             opclass: None,
+            collate: None,
         }
     }
 
@@ -145,6 +156,7 @@ impl IndexColumn {
             asc: true,
             nulls_first: false,
             opclass: None,
+            collate: None,
         }
     }
 
@@ -168,6 +180,13 @@ impl IndexColumn {
         self.opclass = Some(opclass);
         self
     }
+
+    /// Set collation
+    #[must_use]
+    pub fn with_collate(mut self, collate: impl Into<Cow<'static, str>>) -> Self {
+        self.collate = Some(collate.into());
+        self
+    }
 }
 
 impl IndexColumn {
@@ -180,6 +199,9 @@ impl IndexColumn {
             format!("\"{}\"", self.value)
         };
 
+        if let Some(ref collate) = self.collate {
+            let _ = write!(sql, " COLLATE \"{collate}\"");
+        }
         if let Some(ref op) = self.opclass {
             let _ = write!(sql, " {op}");
         }
@@ -202,6 +224,7 @@ impl From<IndexColumnDef> for IndexColumn {
             asc: def.asc,
             nulls_first: def.nulls_first,
             opclass: def.opclass.map(OpclassDef::into_opclass),
+            collate: def.collate.map(Cow::Borrowed),
         }
     }
 }
@@ -234,6 +257,12 @@ pub struct IndexColumnDef {
         serde(default, skip_serializing_if = "Option::is_none")
     )]
     pub opclass: Option<OpclassDef>,
+    /// Collation (optional)
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub collate: Option<&'static str>,
 }
 
 #[cfg(feature = "serde")]
@@ -251,6 +280,7 @@ impl IndexColumnDef {
             asc: true,
             nulls_first: false,
             opclass: None,
+            collate: None,
         }
     }
 
@@ -263,6 +293,7 @@ impl IndexColumnDef {
             asc: true,
             nulls_first: false,
             opclass: None,
+            collate: None,
         }
     }
 
@@ -298,6 +329,15 @@ impl IndexColumnDef {
             ..self
         }
     }
+
+    /// Set collation
+    #[must_use]
+    pub const fn collate(self, collate: &'static str) -> Self {
+        Self {
+            collate: Some(collate),
+            ..self
+        }
+    }
 }
 
 /// Const-friendly index definition
@@ -603,4 +643,20 @@ mod tests {
         assert_eq!(idx.name, Cow::Borrowed("idx_email"));
         assert!(idx.is_unique);
     }
+
+    #[test]
+    fn test_collated_column_sql() {
+        const COLS: &[IndexColumnDef] = &[IndexColumnDef::new("email")
+            .collate("und-x-icu")
+            .opclass_name("text_pattern_ops")
+            .desc()];
+        const DEF: IndexDef = IndexDef::new("public", "users", "idx_email", COLS);
+        let idx = DEF.into_index();
+
+        assert_eq!(idx.columns[0].collate.as_deref(), Some("und-x-icu"));
+        assert_eq!(
+            idx.columns[0].to_sql(),
+            "\"email\" COLLATE \"und-x-icu\" text_pattern_ops DESC"
+        );
+    }
 }
@@ -29,6 +29,8 @@
 //!
 //! - **Schemas** - Namespace support (`public`, `custom_schema`, etc.)
 //! - **Enums** - User-defined enumerated types
+//! - **Domains** - Base types with inherited constraints
+//! - **Composites** - Named row types usable as column types
 //! - **Sequences** - Auto-increment sequences (alternative to SERIAL)
 //! - **Roles** - Database roles/permissions
 //! - **Policies** - Row-level security policies
@@ -38,7 +40,10 @@
 
 mod check_constraint;
 mod column;
+mod composite;
+mod domain;
 mod enum_type;
+mod exclude_constraint;
 mod foreign_key;
 mod index;
 mod policy;
@@ -54,7 +59,10 @@ mod view;
 // Const-friendly definition types
 pub use check_constraint::CheckConstraintDef;
 pub use column::{ColumnDef, GeneratedDef, GeneratedType, IdentityDef, IdentityType};
+pub use composite::{CompositeDef, CompositeFieldDef};
+pub use domain::DomainDef;
 pub use enum_type::EnumDef;
+pub use exclude_constraint::{ExcludeConstraintDef, ExcludeElement};
 pub use foreign_key::{ForeignKeyDef, ReferentialAction};
 pub use index::{IndexColumn, IndexColumnDef, IndexDef, OpclassDef};
 pub use policy::PolicyDef;
@@ -69,7 +77,10 @@ pub use view::{ViewDef, ViewWithOptionDef};
 // Runtime types for serde
 pub use check_constraint::CheckConstraint;
 pub use column::{Column, Generated, Identity};
+pub use composite::{Composite, CompositeField};
+pub use domain::Domain;
 pub use enum_type::Enum;
+pub use exclude_constraint::ExcludeConstraint;
 pub use foreign_key::ForeignKey;
 pub use index::{Index, Opclass};
 pub use policy::Policy;
@@ -97,6 +108,10 @@ pub use crate::serde_helpers::{
 pub const ENTITY_TYPE_SCHEMAS: &str = "schemas";
 /// Entity type discriminator for enums
 pub const ENTITY_TYPE_ENUMS: &str = "enums";
+/// Entity type discriminator for domains
+pub const ENTITY_TYPE_DOMAINS: &str = "domains";
+/// Entity type discriminator for composite types
+pub const ENTITY_TYPE_COMPOSITES: &str = "composites";
 /// Entity type discriminator for sequences
 pub const ENTITY_TYPE_SEQUENCES: &str = "sequences";
 /// Entity type discriminator for roles
@@ -117,6 +132,8 @@ pub const ENTITY_TYPE_PKS: &str = "pks";
 pub const ENTITY_TYPE_UNIQUES: &str = "uniques";
 /// Entity type discriminator for check constraints
 pub const ENTITY_TYPE_CHECKS: &str = "checks";
+/// Entity type discriminator for exclusion constraints
+pub const ENTITY_TYPE_EXCLUDES: &str = "excludes";
 /// Entity type discriminator for views
 pub const ENTITY_TYPE_VIEWS: &str = "views";
 /// Entity type discriminator for privileges
@@ -142,6 +159,10 @@ pub enum PostgresEntity {
     Schema(Schema),
     #[cfg_attr(feature = "serde", serde(rename = "enums"))]
     Enum(Enum),
+    #[cfg_attr(feature = "serde", serde(rename = "domains"))]
+    Domain(Domain),
+    #[cfg_attr(feature = "serde", serde(rename = "composites"))]
+    Composite(Composite),
     #[cfg_attr(feature = "serde", serde(rename = "sequences"))]
     Sequence(Sequence),
     #[cfg_attr(feature = "serde", serde(rename = "roles"))]
@@ -164,6 +185,8 @@ pub enum PostgresEntity {
     UniqueConstraint(UniqueConstraint),
     #[cfg_attr(feature = "serde", serde(rename = "checks"))]
     CheckConstraint(CheckConstraint),
+    #[cfg_attr(feature = "serde", serde(rename = "excludes"))]
+    ExcludeConstraint(ExcludeConstraint),
     #[cfg_attr(feature = "serde", serde(rename = "views"))]
     View(View),
 }
@@ -7,9 +7,10 @@ use crate::alloc_prelude::*;
 use core::fmt::Write;
 
 use super::{
-    CheckConstraint, Column, Enum, ForeignKey, Generated, GeneratedType, Identity, IdentityType,
-    Index, IndexColumn, IndexColumnDef, Policy, PrimaryKey, Sequence, Table, UniqueConstraint,
-    View,
+    CheckConstraint, CheckConstraintDef, Column, ColumnDef, Composite, Domain, Enum, ExcludeConstraint,
+    ExcludeConstraintDef, ForeignKey, ForeignKeyDef, Generated, GeneratedType, Identity,
+    IdentityType, Index, IndexColumn, IndexColumnDef, Policy, PrimaryKey, PrimaryKeyDef, Sequence,
+    Table, TableDef, UniqueConstraint, UniqueConstraintDef, View,
 };
 
 fn quote_ident(ident: &str) -> String {
@@ -39,6 +40,10 @@ fn index_column_sql(column: &IndexColumn) -> String {
         quote_ident(&column.value)
     };
 
+    if let Some(collate) = column.collate.as_ref() {
+        let _ = write!(sql, " COLLATE {}", quote_ident(collate));
+    }
+
     if let Some(op) = column.opclass.as_ref() {
         let _ = write!(sql, " {op}");
     }
@@ -67,6 +72,7 @@ pub struct TableSql<'a> {
     pub foreign_keys: &'a [ForeignKey],
     pub unique_constraints: &'a [UniqueConstraint],
     pub check_constraints: &'a [CheckConstraint],
+    pub exclude_constraints: &'a [ExcludeConstraint],
     pub indexes: &'a [Index],
     pub policies: &'a [Policy],
 }
@@ -82,6 +88,7 @@ impl<'a> TableSql<'a> {
             foreign_keys: &[],
             unique_constraints: &[],
             check_constraints: &[],
+            exclude_constraints: &[],
             indexes: &[],
             policies: &[],
         }
@@ -122,6 +129,13 @@ impl<'a> TableSql<'a> {
         self
     }
 
+    /// Set exclusion constraints
+    #[must_use]
+    pub const fn exclude_constraints(mut self, excludes: &'a [ExcludeConstraint]) -> Self {
+        self.exclude_constraints = excludes;
+        self
+    }
+
     /// Set indexes
     #[must_use]
     pub const fn indexes(mut self, indexes: &'a [Index]) -> Self {
@@ -136,6 +150,47 @@ impl<'a> TableSql<'a> {
         self
     }
 
+    /// Generate CREATE TABLE SQL straight from the const `*Def` metadata that
+    /// `#[PostgresTable]` emits.
+    ///
+    /// Table macros call this instead of expanding the conversion per table.
+    #[must_use]
+    pub fn create_table_sql_from_defs(
+        table: &TableDef,
+        columns: &[ColumnDef],
+        primary_key: Option<&PrimaryKeyDef>,
+        foreign_keys: &[ForeignKeyDef],
+        unique_constraints: &[UniqueConstraintDef],
+        check_constraints: &[CheckConstraintDef],
+        exclude_constraints: &[ExcludeConstraintDef],
+    ) -> String {
+        let table = table.into_table();
+        let columns: Vec<_> = columns.iter().map(|c| c.into_column()).collect();
+        let pk = primary_key.map(|p| p.into_primary_key());
+        let fks: Vec<_> = foreign_keys.iter().map(|f| f.into_foreign_key()).collect();
+        let uniques: Vec<_> = unique_constraints
+            .iter()
+            .map(|u| u.into_unique_constraint())
+            .collect();
+        let checks: Vec<_> = check_constraints
+            .iter()
+            .map(|c| c.into_check_constraint())
+            .collect();
+        let excludes: Vec<_> = exclude_constraints
+            .iter()
+            .map(|e| e.into_exclude_constraint())
+            .collect();
+
+        TableSql::new(&table)
+            .columns(&columns)
+            .primary_key(pk.as_ref())
+            .foreign_keys(&fks)
+            .unique_constraints(&uniques)
+            .check_constraints(&checks)
+            .exclude_constraints(&excludes)
+            .create_table_sql()
+    }
+
     /// Generate CREATE TABLE SQL
     #[must_use]
     pub fn create_table_sql(&self) -> String {
@@ -197,6 +252,11 @@ impl<'a> TableSql<'a> {
             ));
         }
 
+        // Exclusion constraints
+        for exclude in self.exclude_constraints {
+            lines.push(format!("\t{}", exclude.to_constraint_sql()));
+        }
+
         sql.push_str(&lines.join(",\n"));
         sql.push('\n');
         sql.push(')');
@@ -621,6 +681,151 @@ impl Enum {
             },
         )
     }
+
+    /// Generate ALTER TYPE ... RENAME VALUE SQL
+    #[must_use]
+    pub fn rename_value_sql(&self, from: &str, to: &str) -> String {
+        format!(
+            "ALTER TYPE {} RENAME VALUE {} TO {};",
+            qualified_name(self.schema(), self.name()),
+            quote_literal(from),
+            quote_literal(to)
+        )
+    }
+}
+
+// =============================================================================
+// Domain SQL Generation
+// =============================================================================
+
+impl Domain {
+    /// Generate CREATE DOMAIN SQL
+    #[must_use]
+    pub fn create_domain_sql(&self) -> String {
+        let mut sql = format!(
+            "CREATE DOMAIN {} AS {}",
+            qualified_name(self.schema(), self.name()),
+            self.base_type
+        );
+        if let Some(default) = self.default.as_ref() {
+            let _ = write!(sql, " DEFAULT {default}");
+        }
+        if self.not_null {
+            sql.push_str(" NOT NULL");
+        }
+        if let Some(check) = self.check.as_ref() {
+            let _ = write!(
+                sql,
+                " CONSTRAINT {} CHECK ({check})",
+                quote_ident(&self.check_name())
+            );
+        }
+        sql.push(';');
+        sql
+    }
+
+    /// Generate DROP DOMAIN SQL
+    #[must_use]
+    pub fn drop_domain_sql(&self) -> String {
+        format!("DROP DOMAIN {};", qualified_name(self.schema(), self.name()))
+    }
+
+    /// Generate the ALTER DOMAIN statements that turn `self` into `to`.
+    ///
+    /// The base type of a domain cannot be altered in place; callers must
+    /// drop and recreate the domain when it changes.
+    #[must_use]
+    pub fn alter_domain_sql(&self, to: &Self) -> Vec<String> {
+        let name = qualified_name(to.schema(), to.name());
+        let mut stmts = Vec::new();
+        if self.default != to.default {
+            stmts.push(to.default.as_ref().map_or_else(
+                || format!("ALTER DOMAIN {name} DROP DEFAULT;"),
+                |default| format!("ALTER DOMAIN {name} SET DEFAULT {default};"),
+            ));
+        }
+        if self.not_null != to.not_null {
+            let action = if to.not_null { "SET" } else { "DROP" };
+            stmts.push(format!("ALTER DOMAIN {name} {action} NOT NULL;"));
+        }
+        if self.check != to.check {
+            if self.check.is_some() {
+                stmts.push(format!(
+                    "ALTER DOMAIN {name} DROP CONSTRAINT {};",
+                    quote_ident(&self.check_name())
+                ));
+            }
+            if let Some(check) = to.check.as_ref() {
+                stmts.push(format!(
+                    "ALTER DOMAIN {name} ADD CONSTRAINT {} CHECK ({check});",
+                    quote_ident(&to.check_name())
+                ));
+            }
+        }
+        stmts
+    }
+}
+
+// =============================================================================
+// Composite SQL Generation
+// =============================================================================
+
+impl Composite {
+    /// Generate CREATE TYPE ... AS (...) SQL
+    #[must_use]
+    pub fn create_composite_sql(&self) -> String {
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| format!("{} {}", quote_ident(&f.name), f.sql_type))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "CREATE TYPE {} AS ({});",
+            qualified_name(self.schema(), self.name()),
+            fields
+        )
+    }
+
+    /// Generate DROP TYPE SQL
+    #[must_use]
+    pub fn drop_composite_sql(&self) -> String {
+        format!("DROP TYPE {};", qualified_name(self.schema(), self.name()))
+    }
+
+    /// Generate the ALTER TYPE statements that turn `self` into `to`.
+    ///
+    /// Attributes are matched by name: removed ones are dropped, new ones
+    /// added, and retyped ones altered in place.
+    #[must_use]
+    pub fn alter_composite_sql(&self, to: &Self) -> Vec<String> {
+        let name = qualified_name(to.schema(), to.name());
+        let mut stmts = Vec::new();
+        for field in &self.fields {
+            if !to.fields.iter().any(|f| f.name == field.name) {
+                stmts.push(format!(
+                    "ALTER TYPE {name} DROP ATTRIBUTE {};",
+                    quote_ident(&field.name)
+                ));
+            }
+        }
+        for field in &to.fields {
+            match self.fields.iter().find(|f| f.name == field.name) {
+                None => stmts.push(format!(
+                    "ALTER TYPE {name} ADD ATTRIBUTE {} {};",
+                    quote_ident(&field.name),
+                    field.sql_type
+                )),
+                Some(old) if old.sql_type != field.sql_type => stmts.push(format!(
+                    "ALTER TYPE {name} ALTER ATTRIBUTE {} TYPE {};",
+                    quote_ident(&field.name),
+                    field.sql_type
+                )),
+                Some(_) => {}
+            }
+        }
+        stmts
+    }
 }
 
 // =============================================================================
@@ -1050,6 +1255,66 @@ impl CheckConstraint {
     }
 }
 
+// =============================================================================
+// Exclusion Constraint SQL Generation
+// =============================================================================
+
+impl ExcludeConstraint {
+    /// Generate the EXCLUDE constraint clause
+    #[must_use]
+    pub fn to_constraint_sql(&self) -> String {
+        let elements = self
+            .elements
+            .iter()
+            .map(|e| {
+                let value = if e.is_expression {
+                    format!("({})", e.value)
+                } else {
+                    quote_ident(&e.value)
+                };
+                format!("{value} WITH {}", e.operator)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut sql = format!(
+            "CONSTRAINT {} EXCLUDE USING {} ({elements})",
+            quote_ident(self.name()),
+            self.using
+        );
+        if let Some(predicate) = self.where_clause.as_ref() {
+            let _ = write!(sql, " WHERE ({predicate})");
+        }
+        if self.deferrable || self.initially_deferred {
+            sql.push_str(" DEFERRABLE");
+            if self.initially_deferred {
+                sql.push_str(" INITIALLY DEFERRED");
+            }
+        }
+        sql
+    }
+
+    /// Generate ADD EXCLUDE SQL
+    #[must_use]
+    pub fn add_exclude_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} ADD {};",
+            qualified_name(self.schema(), self.table()),
+            self.to_constraint_sql()
+        )
+    }
+
+    /// Generate DROP EXCLUDE SQL
+    #[must_use]
+    pub fn drop_exclude_sql(&self) -> String {
+        format!(
+            "ALTER TABLE {} DROP CONSTRAINT {};",
+            qualified_name(self.schema(), self.table()),
+            quote_ident(self.name())
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1091,6 +1356,66 @@ mod tests {
         assert!(sql.contains("\"myschema\".\"users\""));
     }
 
+    #[test]
+    fn test_domain_create_and_alter_sql() {
+        let from = crate::postgres::ddl::DomainDef::new("public", "email", "text")
+            .check("VALUE ~ '@'")
+            .into_domain();
+        assert_eq!(
+            from.create_domain_sql(),
+            "CREATE DOMAIN \"email\" AS text CONSTRAINT \"email_check\" CHECK (VALUE ~ '@');"
+        );
+
+        let mut to = from.clone();
+        to.not_null = true;
+        to.check = Some(Cow::Borrowed("VALUE ~ '^.+@.+$'"));
+        assert_eq!(
+            from.alter_domain_sql(&to),
+            vec![
+                "ALTER DOMAIN \"email\" SET NOT NULL;".to_string(),
+                "ALTER DOMAIN \"email\" DROP CONSTRAINT \"email_check\";".to_string(),
+                "ALTER DOMAIN \"email\" ADD CONSTRAINT \"email_check\" CHECK (VALUE ~ '^.+@.+$');"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_composite_create_and_alter_sql() {
+        use crate::postgres::ddl::CompositeField;
+
+        let from = Composite::new(
+            "public",
+            "address_type",
+            vec![
+                CompositeField::new("street", "text"),
+                CompositeField::new("zip", "integer"),
+            ],
+        );
+        assert_eq!(
+            from.create_composite_sql(),
+            "CREATE TYPE \"address_type\" AS (\"street\" text, \"zip\" integer);"
+        );
+
+        let to = Composite::new(
+            "public",
+            "address_type",
+            vec![
+                CompositeField::new("street", "varchar(255)"),
+                CompositeField::new("city", "text"),
+            ],
+        );
+        assert_eq!(
+            from.alter_composite_sql(&to),
+            vec![
+                "ALTER TYPE \"address_type\" DROP ATTRIBUTE \"zip\";".to_string(),
+                "ALTER TYPE \"address_type\" ALTER ATTRIBUTE \"street\" TYPE varchar(255);"
+                    .to_string(),
+                "ALTER TYPE \"address_type\" ADD ATTRIBUTE \"city\" text;".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn test_unique_concurrently_index_word_order() {
         let mut index = Index::new(
@@ -1118,4 +1443,34 @@ mod tests {
             "CREATE POLICY \"users_policy\" ON \"users\" AS PERMISSIVE TO PUBLIC;"
         );
     }
+    #[test]
+    fn test_exclude_constraint_sql() {
+        use crate::postgres::ddl::{ExcludeConstraintDef, ExcludeElement};
+
+        const ELEMENTS: &[ExcludeElement] = &[
+            ExcludeElement::column("room_id", "="),
+            ExcludeElement::expression("tsrange(starts_at, ends_at)", "&&"),
+        ];
+        let exclude = ExcludeConstraintDef::new("app", "bookings", "bookings_no_overlap")
+            .elements(ELEMENTS)
+            .where_clause("NOT cancelled")
+            .deferrable()
+            .into_exclude_constraint();
+
+        assert_eq!(
+            exclude.add_exclude_sql(),
+            "ALTER TABLE \"app\".\"bookings\" ADD CONSTRAINT \"bookings_no_overlap\" EXCLUDE USING gist (\"room_id\" WITH =, (tsrange(starts_at, ends_at)) WITH &&) WHERE (NOT cancelled) DEFERRABLE;"
+        );
+        assert_eq!(
+            exclude.drop_exclude_sql(),
+            "ALTER TABLE \"app\".\"bookings\" DROP CONSTRAINT \"bookings_no_overlap\";"
+        );
+
+        let table = TableDef::new("app", "bookings").into_table();
+        let excludes = [exclude];
+        let sql = TableSql::new(&table)
+            .exclude_constraints(&excludes)
+            .create_table_sql();
+        assert!(sql.contains("\tCONSTRAINT \"bookings_no_overlap\" EXCLUDE USING gist ("));
+    }
 }
@@ -110,6 +110,12 @@ pub mod types {
     #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
     pub struct Circle;
 
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct Geometry;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct Vector;
+
     #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
     pub struct Enum;
 }
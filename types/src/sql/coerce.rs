@@ -109,6 +109,7 @@ macro_rules! any_assign {
 // =============================================================================
 
 impl<T: DataType> Compatible<Self> for crate::Array<T> {}
+impl<M: 'static> Compatible<Self> for crate::JsonRows<M> {}
 impl Compatible<Self> for crate::Placeholder {}
 impl<T: DataType> Assignable<Self> for crate::Array<T> {}
 
@@ -153,6 +154,8 @@ impl_reflexive_compat!(
     crate::postgres::types::LineSegment,
     crate::postgres::types::Polygon,
     crate::postgres::types::Circle,
+    crate::postgres::types::Geometry,
+    crate::postgres::types::Vector,
     crate::postgres::types::Enum,
 );
 
@@ -196,6 +199,8 @@ impl_reflexive_assign!(
     crate::postgres::types::LineSegment,
     crate::postgres::types::Polygon,
     crate::postgres::types::Circle,
+    crate::postgres::types::Geometry,
+    crate::postgres::types::Vector,
     crate::postgres::types::Enum,
 );
 
@@ -324,6 +329,12 @@ mutual_compat!(
     crate::postgres::types::MacAddr8
 );
 
+// pgvector VECTOR ↔ REAL[]: both carry `Vec<f32>`
+mutual_compat!(
+    crate::postgres::types::Vector,
+    crate::Array<crate::postgres::types::Float4>
+);
+
 // Enum ↔ Text family
 cross_compat!(
     [crate::postgres::types::Enum],
@@ -368,6 +379,8 @@ any_compat!(crate::postgres::types::Any;
     crate::postgres::types::LineSegment,
     crate::postgres::types::Polygon,
     crate::postgres::types::Circle,
+    crate::postgres::types::Geometry,
+    crate::postgres::types::Vector,
     crate::postgres::types::Enum
 );
 
@@ -405,6 +418,8 @@ impl_placeholder_compat!(
     crate::postgres::types::LineSegment,
     crate::postgres::types::Polygon,
     crate::postgres::types::Circle,
+    crate::postgres::types::Geometry,
+    crate::postgres::types::Vector,
     crate::postgres::types::Enum
 );
 
@@ -457,6 +472,9 @@ mutual_assign!(
     crate::postgres::types::Char
 );
 
+// REAL[] → VECTOR
+assign_to!(crate::postgres::types::Vector; crate::Array<crate::postgres::types::Float4>);
+
 // Enum → Text family
 assign_to!(crate::postgres::types::Varchar; crate::postgres::types::Enum);
 assign_to!(crate::postgres::types::Text; crate::postgres::types::Enum);
@@ -504,6 +522,8 @@ any_assign!(crate::postgres::types::Any;
     crate::postgres::types::LineSegment,
     crate::postgres::types::Polygon,
     crate::postgres::types::Circle,
+    crate::postgres::types::Geometry,
+    crate::postgres::types::Vector,
     crate::postgres::types::Enum
 );
 
@@ -78,12 +78,34 @@ pub trait BooleanLike: DataType {}
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub struct Array<T: DataType>(pub PhantomData<T>);
 
+/// JSON array of table rows, decoded as `Vec<M>`.
+///
+/// Produced by row-aggregating expressions such as `json_agg_rows`, where `M`
+/// is the table's select model.
+pub struct JsonRows<M>(pub PhantomData<fn() -> M>);
+
+impl<M> Clone for JsonRows<M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M> Copy for JsonRows<M> {}
+
+impl<M> core::fmt::Debug for JsonRows<M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("JsonRows")
+    }
+}
+
 /// Placeholder marker used for bind parameters before concrete typing.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub struct Placeholder;
 
 impl<T: DataType> private::Sealed for Array<T> {}
 impl<T: DataType> DataType for Array<T> {}
+impl<M: 'static> private::Sealed for JsonRows<M> {}
+impl<M: 'static> DataType for JsonRows<M> {}
 impl private::Sealed for Placeholder {}
 impl DataType for Placeholder {}
 impl Textual for Placeholder {}
@@ -164,6 +186,8 @@ impl private::Sealed for crate::postgres::types::Line {}
 impl private::Sealed for crate::postgres::types::LineSegment {}
 impl private::Sealed for crate::postgres::types::Polygon {}
 impl private::Sealed for crate::postgres::types::Circle {}
+impl private::Sealed for crate::postgres::types::Geometry {}
+impl private::Sealed for crate::postgres::types::Vector {}
 impl private::Sealed for crate::postgres::types::Enum {}
 
 impl DataType for crate::postgres::types::Int2 {}
@@ -199,6 +223,8 @@ impl DataType for crate::postgres::types::Line {}
 impl DataType for crate::postgres::types::LineSegment {}
 impl DataType for crate::postgres::types::Polygon {}
 impl DataType for crate::postgres::types::Circle {}
+impl DataType for crate::postgres::types::Geometry {}
+impl DataType for crate::postgres::types::Vector {}
 impl DataType for crate::postgres::types::Enum {}
 
 impl Numeric for crate::postgres::types::Int2 {}
@@ -134,6 +134,8 @@ pub struct ColumnDef {
     /// Collation name (`BINARY`, `NOCASE`, `RTRIM`, or a custom registered collation).
     /// `None` means the default collation (`BINARY`) and no `COLLATE` clause is emitted.
     pub collate: Option<&'static str>,
+    /// Column comment (snapshot metadata only; `SQLite` has no `COMMENT ON`)
+    pub comment: Option<&'static str>,
 }
 
 impl ColumnDef {
@@ -150,6 +152,7 @@ impl ColumnDef {
             default: None,
             generated: None,
             collate: None,
+            comment: None,
         }
     }
 
@@ -241,6 +244,15 @@ impl ColumnDef {
         }
     }
 
+    /// Set the column comment
+    #[must_use]
+    pub const fn comment(self, comment: &'static str) -> Self {
+        Self {
+            comment: Some(comment),
+            ..self
+        }
+    }
+
     /// Convert to runtime [`Column`] type
     #[must_use]
     pub const fn into_column(self) -> Column {
@@ -271,6 +283,10 @@ impl ColumnDef {
                 Some(s) => Some(Cow::Borrowed(s)),
                 None => None,
             },
+            comment: match self.comment {
+                Some(s) => Some(Cow::Borrowed(s)),
+                None => None,
+            },
             ordinal_position: None,
         }
     }
@@ -347,6 +363,18 @@ pub struct Column {
     )]
     pub collate: Option<Cow<'static, str>>,
 
+    /// Column comment. `SQLite` has no `COMMENT ON`, so this only lives in
+    /// snapshots and is used to keep documentation in generated schemas.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "cow_option_from_string"
+        )
+    )]
+    pub comment: Option<Cow<'static, str>>,
+
     /// Ordinal position within the table (cid, 0-based).
     ///
     /// This is primarily populated by introspection and used for stable codegen ordering.
@@ -376,6 +404,7 @@ impl Column {
             default: None,
             generated: None,
             collate: None,
+            comment: None,
             ordinal_position: None,
         }
     }
@@ -401,6 +430,13 @@ impl Column {
         self
     }
 
+    /// Set the column comment
+    #[must_use]
+    pub fn comment(mut self, comment: impl Into<Cow<'static, str>>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
     /// Get the column name
     #[inline]
     #[must_use]
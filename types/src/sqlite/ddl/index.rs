@@ -39,6 +39,12 @@ pub struct IndexColumnDef {
     /// Whether this is an expression (vs column name)
     #[cfg_attr(feature = "serde", serde(default))]
     pub is_expression: bool,
+    /// Collation sequence for this index member (e.g. `NOCASE`)
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub collate: Option<&'static str>,
 }
 
 impl IndexColumnDef {
@@ -48,6 +54,7 @@ impl IndexColumnDef {
         Self {
             value,
             is_expression: false,
+            collate: None,
         }
     }
 
@@ -57,6 +64,16 @@ impl IndexColumnDef {
         Self {
             value,
             is_expression: true,
+            collate: None,
+        }
+    }
+
+    /// Set the collation sequence used for this index member
+    #[must_use]
+    pub const fn collate(self, collate: &'static str) -> Self {
+        Self {
+            collate: Some(collate),
+            ..self
         }
     }
 
@@ -66,6 +83,10 @@ impl IndexColumnDef {
         IndexColumn {
             value: Cow::Borrowed(self.value),
             is_expression: self.is_expression,
+            collate: match self.collate {
+                Some(collate) => Some(Cow::Borrowed(collate)),
+                None => None,
+            },
         }
     }
 }
@@ -81,6 +102,16 @@ pub struct IndexColumn {
     /// Whether this is an expression (vs column name)
     #[cfg_attr(feature = "serde", serde(default))]
     pub is_expression: bool,
+    /// Collation sequence for this index member (e.g. `NOCASE`)
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "cow_option_from_string"
+        )
+    )]
+    pub collate: Option<Cow<'static, str>>,
 }
 
 impl IndexColumn {
@@ -90,6 +121,7 @@ impl IndexColumn {
         Self {
             value: value.into(),
             is_expression: false,
+            collate: None,
         }
     }
 
@@ -99,19 +131,32 @@ impl IndexColumn {
         Self {
             value: value.into(),
             is_expression: true,
+            collate: None,
         }
     }
+
+    /// Set the collation sequence used for this index member
+    #[must_use]
+    pub fn with_collate(mut self, collate: impl Into<Cow<'static, str>>) -> Self {
+        self.collate = Some(collate.into());
+        self
+    }
 }
 
 impl IndexColumn {
     /// Generate SQL for this index column
     #[must_use]
     pub fn to_sql(&self) -> String {
-        if self.is_expression {
+        let mut sql = if self.is_expression {
             format!("({})", self.value)
         } else {
             format!("`{}`", self.value)
+        };
+        if let Some(collate) = &self.collate {
+            sql.push_str(" COLLATE ");
+            sql.push_str(collate);
         }
+        sql
     }
 }
 
@@ -365,4 +410,14 @@ mod tests {
         assert_eq!(idx.name, Cow::Borrowed("idx_email"));
         assert!(idx.is_unique);
     }
+
+    #[test]
+    fn test_collated_column_sql() {
+        const COLS: &[IndexColumnDef] = &[IndexColumnDef::new("email").collate("NOCASE")];
+        const DEF: IndexDef = IndexDef::new("users", "idx_email").unique().columns(COLS);
+        let idx = DEF.into_index();
+
+        assert_eq!(idx.columns[0].collate.as_deref(), Some("NOCASE"));
+        assert_eq!(idx.columns[0].to_sql(), "`email` COLLATE NOCASE");
+    }
 }
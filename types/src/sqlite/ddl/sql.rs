@@ -7,8 +7,9 @@ use crate::alloc_prelude::*;
 use core::fmt::Write;
 
 use super::{
-    CheckConstraint, Column, ForeignKey, Generated, GeneratedType, Index, IndexColumnDef,
-    PrimaryKey, Table, UniqueConstraint, View,
+    CheckConstraint, CheckConstraintDef, Column, ColumnDef, ForeignKey, ForeignKeyDef, Generated,
+    GeneratedType, Index, IndexColumnDef, PrimaryKey, PrimaryKeyDef, Table, TableDef,
+    UniqueConstraint, UniqueConstraintDef, View,
 };
 
 fn quote_ident(ident: &str) -> String {
@@ -79,6 +80,41 @@ impl<'a> TableSql<'a> {
         self
     }
 
+    /// Generate CREATE TABLE SQL straight from the const `*Def` metadata that
+    /// `#[SQLiteTable]` emits.
+    ///
+    /// Table macros call this instead of expanding the conversion per table.
+    #[must_use]
+    pub fn create_table_sql_from_defs(
+        table: &TableDef,
+        columns: &[ColumnDef],
+        primary_key: Option<&PrimaryKeyDef>,
+        foreign_keys: &[ForeignKeyDef],
+        unique_constraints: &[UniqueConstraintDef],
+        check_constraints: &[CheckConstraintDef],
+    ) -> String {
+        let table = table.into_table();
+        let columns: Vec<_> = columns.iter().map(|c| c.into_column()).collect();
+        let pk = primary_key.map(|p| p.into_primary_key());
+        let fks: Vec<_> = foreign_keys.iter().map(|f| f.into_foreign_key()).collect();
+        let uniques: Vec<_> = unique_constraints
+            .iter()
+            .map(|u| u.into_unique_constraint())
+            .collect();
+        let checks: Vec<_> = check_constraints
+            .iter()
+            .map(|c| c.into_check_constraint())
+            .collect();
+
+        TableSql::new(&table)
+            .columns(&columns)
+            .primary_key(pk.as_ref())
+            .foreign_keys(&fks)
+            .unique_constraints(&uniques)
+            .check_constraints(&checks)
+            .create_table_sql()
+    }
+
     /// Generate CREATE TABLE SQL
     #[must_use]
     pub fn create_table_sql(&self) -> String {
@@ -7,7 +7,7 @@
 use crate::alloc_prelude::*;
 
 #[cfg(feature = "serde")]
-use crate::serde_helpers::cow_from_string;
+use crate::serde_helpers::{cow_from_string, cow_option_from_string};
 
 // =============================================================================
 // Const-friendly Definition Type
@@ -38,6 +38,8 @@ pub struct TableDef {
     pub strict: bool,
     /// Is this a WITHOUT ROWID table?
     pub without_rowid: bool,
+    /// Table comment (snapshot metadata only; `SQLite` has no `COMMENT ON`)
+    pub comment: Option<&'static str>,
 }
 
 impl TableDef {
@@ -48,6 +50,7 @@ impl TableDef {
             name,
             strict: false,
             without_rowid: false,
+            comment: None,
         }
     }
 
@@ -55,9 +58,8 @@ impl TableDef {
     #[must_use]
     pub const fn strict(self) -> Self {
         Self {
-            name: self.name,
             strict: true,
-            without_rowid: self.without_rowid,
+            ..self
         }
     }
 
@@ -65,9 +67,17 @@ impl TableDef {
     #[must_use]
     pub const fn without_rowid(self) -> Self {
         Self {
-            name: self.name,
-            strict: self.strict,
             without_rowid: true,
+            ..self
+        }
+    }
+
+    /// Set the table comment
+    #[must_use]
+    pub const fn comment(self, comment: &'static str) -> Self {
+        Self {
+            comment: Some(comment),
+            ..self
         }
     }
 
@@ -78,6 +88,10 @@ impl TableDef {
             name: Cow::Borrowed(self.name),
             strict: self.strict,
             without_rowid: self.without_rowid,
+            comment: match self.comment {
+                Some(s) => Some(Cow::Borrowed(s)),
+                None => None,
+            },
         }
     }
 }
@@ -139,6 +153,18 @@ pub struct Table {
         serde(default, skip_serializing_if = "core::ops::Not::not")
     )]
     pub without_rowid: bool,
+
+    /// Table comment. `SQLite` has no `COMMENT ON`, so this only lives in
+    /// snapshots and is used to keep documentation in generated schemas.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "cow_option_from_string"
+        )
+    )]
+    pub comment: Option<Cow<'static, str>>,
 }
 
 impl Table {
@@ -149,6 +175,7 @@ impl Table {
             name: name.into(),
             strict: false,
             without_rowid: false,
+            comment: None,
         }
     }
 
@@ -166,6 +193,13 @@ impl Table {
         self
     }
 
+    /// Set the table comment
+    #[must_use]
+    pub fn comment(mut self, comment: impl Into<Cow<'static, str>>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
     /// Get the table name as a string slice
     #[inline]
     #[must_use]
@@ -136,7 +136,7 @@ impl TypeCategory {
 
         // Primitives - check exact matches for simple types
         match type_str.as_str() {
-            "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "isize" | "usize" => {
+            "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "isize" | "usize" => {
                 Self::Integer
             }
             "f32" | "f64" => Self::Real,
@@ -321,6 +321,7 @@ mod tests {
         );
         assert_eq!(TypeCategory::from_type_string("i32"), TypeCategory::Integer);
         assert_eq!(TypeCategory::from_type_string("i64"), TypeCategory::Integer);
+        assert_eq!(TypeCategory::from_type_string("u64"), TypeCategory::Integer);
         assert_eq!(TypeCategory::from_type_string("f64"), TypeCategory::Real);
         assert_eq!(TypeCategory::from_type_string("bool"), TypeCategory::Bool);
         assert_eq!(